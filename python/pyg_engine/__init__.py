@@ -4,7 +4,7 @@
 [pyg_engine](https://www.github.com/aram-ap/pyg-engine) - A Python game engine with Rust-powered native performance.
 """
 
-from pyg_engine.engine import DrawCommand, Engine, EngineHandle, Input, UpdateContext, UIManager
+from pyg_engine.engine import DrawCommand, Engine, EngineHandle, Input, PlayerInput, UpdateContext, UIManager
 from pyg_engine.shapes import Arc, Circle, Line, Mesh, Polygon, Rect, Text
 
 try:
@@ -17,16 +17,44 @@ try:
         MeshGeometry,
         MeshComponent,
         TextMeshComponent,
+        WorldLabelComponent,
         TransformComponent,
         ButtonComponent,
         PanelComponent,
         LabelComponent,
+        TextInputComponent,
+        SliderComponent,
+        CheckboxComponent,
+        ToggleSwitchComponent,
+        ScrollViewComponent,
+        ProgressBarComponent,
+        CustomWidgetComponent,
+        UIEvent,
+        InputEvent,
+        CanvasComponent,
+        CanvasPainter,
+        ImageComponent,
+        StackComponent,
+        CurveEditorComponent,
+        AnimationCurve,
+        RadioButtonComponent,
+        ListViewComponent,
+        EngineCapabilities,
+        DrawRecorder,
+        RendererCapabilities,
+        RenderStats,
+        FrameStats,
+        AssetHandle,
+        CallbackError,
         CameraAspectMode,
+        UIScaleMode,
         MouseButton,
         Keys,
         PhysicsLayers,
         ColliderShape,
         Collider,
+        AudioSourceComponent,
+        AudioListener,
         version as _version_func,
     )
     # Expose version as a module-level attribute (from native binary)
@@ -41,16 +69,44 @@ except ImportError:
     MeshComponent = None  # type: ignore
     MeshGeometry = None  # type: ignore
     TextMeshComponent = None  # type: ignore
+    WorldLabelComponent = None  # type: ignore
     TransformComponent = None  # type: ignore
     ButtonComponent = None  # type: ignore
     PanelComponent = None  # type: ignore
     LabelComponent = None  # type: ignore
+    TextInputComponent = None  # type: ignore
+    SliderComponent = None  # type: ignore
+    CheckboxComponent = None  # type: ignore
+    ToggleSwitchComponent = None  # type: ignore
+    ScrollViewComponent = None  # type: ignore
+    ProgressBarComponent = None  # type: ignore
+    CustomWidgetComponent = None  # type: ignore
+    UIEvent = None  # type: ignore
+    InputEvent = None  # type: ignore
+    CanvasComponent = None  # type: ignore
+    CanvasPainter = None  # type: ignore
+    ImageComponent = None  # type: ignore
+    StackComponent = None  # type: ignore
+    CurveEditorComponent = None  # type: ignore
+    AnimationCurve = None  # type: ignore
+    RadioButtonComponent = None  # type: ignore
+    ListViewComponent = None  # type: ignore
+    EngineCapabilities = None  # type: ignore
+    DrawRecorder = None  # type: ignore
+    RendererCapabilities = None  # type: ignore
+    RenderStats = None  # type: ignore
+    FrameStats = None  # type: ignore
+    AssetHandle = None  # type: ignore
+    CallbackError = None  # type: ignore
     CameraAspectMode = None  # type: ignore
+    UIScaleMode = None  # type: ignore
     MouseButton = None  # type: ignore
     Keys = None  # type: ignore
     PhysicsLayers = None  # type: ignore
     ColliderShape = None  # type: ignore
     Collider = None  # type: ignore
+    AudioSourceComponent = None  # type: ignore
+    AudioListener = None  # type: ignore
     version = None  # type: ignore
 
 # Auto-generated version from git tags via setuptools-scm
@@ -62,8 +118,34 @@ except ImportError:
 __author__ = "Aram Aprahamian"
 __description__ = "A Python game engine with Rust-powered native performance"
 
+# Import script hot reloading
+from pyg_engine.scripts import ScriptReloader
+
+# Import Python script components
+from pyg_engine.components import Script
+
+# Import named timers and background jobs
+from pyg_engine.timers import Timer, TimerManager
+from pyg_engine.jobs import JobHandle, JobManager
+
+# Import save data
+from pyg_engine.save_data import SaveData, default_save_directory
+
+# Import shared assets
+from pyg_engine.assets import AssetManager
+
+# Import audio
+from pyg_engine.audio import AudioBus, AudioManager, SoundStream
+
+# Import scenes
+from pyg_engine.scenes import SceneManager
+
+# Import intro sequences
+from pyg_engine.intro import IntroSequence, IntroSlide
+
 # Import UI wrappers
-from pyg_engine.ui import Button, Panel, Label
+from pyg_engine.ui import Button, Panel, Label, TextInput, Slider, Checkbox, ToggleSwitch, ScrollView, ProgressBar, CustomWidget, Canvas, Image, VStack, HStack, CurveEditor, RadioButton, RadioGroup, ListView
+from pyg_engine.ui_loader import UIDocument
 
 __all__ = [
     "Engine",
@@ -77,7 +159,23 @@ __all__ = [
     "Mesh",
     "Text",
     "Input",
+    "PlayerInput",
     "UpdateContext",
+    "ScriptReloader",
+    "Script",
+    "Timer",
+    "TimerManager",
+    "JobHandle",
+    "JobManager",
+    "SaveData",
+    "default_save_directory",
+    "AssetManager",
+    "AudioBus",
+    "AudioManager",
+    "SoundStream",
+    "SceneManager",
+    "IntroSequence",
+    "IntroSlide",
     "Vec2",
     "Vec3",
     "Color",
@@ -87,18 +185,62 @@ __all__ = [
     "UIManager",
     "MeshComponent",
     "TextMeshComponent",
+    "WorldLabelComponent",
     "TransformComponent",
     "ButtonComponent",
     "PanelComponent",
     "LabelComponent",
+    "TextInputComponent",
+    "SliderComponent",
+    "CheckboxComponent",
+    "ToggleSwitchComponent",
+    "ScrollViewComponent",
+    "ProgressBarComponent",
+    "CustomWidgetComponent",
+    "UIEvent",
+    "InputEvent",
+    "CanvasComponent",
+    "CanvasPainter",
+    "ImageComponent",
+    "StackComponent",
+    "CurveEditorComponent",
+    "AnimationCurve",
+    "RadioButtonComponent",
+    "ListViewComponent",
+    "EngineCapabilities",
+    "DrawRecorder",
+    "RendererCapabilities",
+    "RenderStats",
+    "FrameStats",
+    "AssetHandle",
+    "CallbackError",
     "Button",
     "Panel",
     "Label",
+    "TextInput",
+    "Slider",
+    "Checkbox",
+    "ToggleSwitch",
+    "ScrollView",
+    "ProgressBar",
+    "CustomWidget",
+    "Canvas",
+    "Image",
+    "VStack",
+    "HStack",
+    "CurveEditor",
+    "RadioButton",
+    "RadioGroup",
+    "ListView",
+    "UIDocument",
     "CameraAspectMode",
+    "UIScaleMode",
     "MouseButton",
     "Keys",
     "PhysicsLayers",
     "ColliderShape",
     "Collider",
+    "AudioSourceComponent",
+    "AudioListener",
     "version",
 ]
@@ -0,0 +1,202 @@
+"""
+Shared, reference-counted assets managed by the engine.
+
+`engine.assets` loads each texture, font, audio or data file once and hands
+out an `AssetHandle` for it. Loading the same file again returns the same
+handle and adds a reference; `release()` drops one, and the file is unloaded
+when the last reference goes:
+
+    player = engine.assets.load_texture("assets/player.png")
+    engine.draw_image(100, 100, 64, 64, player)
+    ...
+    engine.assets.release(player)
+
+The renderer and UI read textures and fonts through the same store, so a file
+drawn by path and loaded as an asset is decoded only once. Texture handles
+are accepted anywhere a texture path is.
+
+During development, `engine.assets.hot_reload = True` watches every file the
+engine has read and picks up edits while the game runs: textures and fonts
+are re-read and re-uploaded, data files are re-read under the same handle, and
+UI documents loaded with `engine.ui.load(path)` are rebuilt.
+"""
+
+from __future__ import annotations
+
+import json
+import time
+from typing import TYPE_CHECKING, Any, Optional, Union
+
+from .pyg_engine_native import AssetHandle
+
+if TYPE_CHECKING:
+    from .engine import Engine
+
+TEXTURE = "texture"
+FONT = "font"
+AUDIO = "audio"
+DATA = "data"
+
+
+def asset_path(value: Union[str, AssetHandle, None]) -> Optional[str]:
+    """Turn an `AssetHandle` into its file path; paths pass through unchanged."""
+    if isinstance(value, AssetHandle):
+        return value.path
+    return value
+
+
+class AssetManager:
+    """
+    Loads asset files once and tracks who uses them, see the module docs.
+
+    Relative paths resolve from the engine's source root.
+
+    Textures and fonts hot reload in every loop, since the native update
+    checks them. UI documents reload only where Python drives the frame,
+    like `engine.scripts`: `engine.run(update=...)`, manual `engine.update()`
+    calls and `check()`. Each reloaded file emits an `asset_reloaded` event
+    with its path.
+    """
+
+    def __init__(self, engine: "Engine"):
+        self._engine = engine
+        self._native = engine._engine
+        self._next_check = 0.0
+
+    def load(self, kind: str, path: str) -> AssetHandle:
+        """
+        Load a file, or add a reference to it if it is already loaded.
+
+        Args:
+            kind: "texture", "font", "audio" or "data".
+            path: File path, relative to the source root.
+
+        Returns:
+            AssetHandle: The same handle for every load of the same file.
+
+        Raises:
+            ValueError: If `kind` is unknown.
+            RuntimeError: If the file cannot be read or decoded.
+        """
+        return self._native.load_asset(kind, path)
+
+    def load_texture(self, path: str) -> AssetHandle:
+        """Load and decode an image file (PNG, JPG, etc.)."""
+        return self.load(TEXTURE, path)
+
+    def load_font(self, path: str) -> AssetHandle:
+        """Load a TTF/OTF font file."""
+        return self.load(FONT, path)
+
+    def load_audio(self, path: str) -> AssetHandle:
+        """Load a sound file's bytes for playback."""
+        return self.load(AUDIO, path)
+
+    def load_data(self, path: str) -> AssetHandle:
+        """Load any file's bytes, such as JSON levels or dialogue."""
+        return self.load(DATA, path)
+
+    def retain(self, handle: AssetHandle) -> bool:
+        """
+        Add a reference to a loaded asset.
+
+        Returns:
+            False if the asset was already unloaded.
+        """
+        return self._native.retain_asset(handle)
+
+    def release(self, handle: AssetHandle) -> bool:
+        """
+        Drop a reference to an asset.
+
+        Returns:
+            True if this was the last reference and the asset was unloaded.
+        """
+        return self._native.release_asset(handle)
+
+    def ref_count(self, handle: AssetHandle) -> int:
+        """Get the number of references to an asset, 0 once it is unloaded."""
+        return self._native.asset_ref_count(handle)
+
+    def is_loaded(self, handle: AssetHandle) -> bool:
+        """Check whether a handle still refers to a loaded asset."""
+        return self.ref_count(handle) > 0
+
+    def read_bytes(self, handle: AssetHandle) -> bytes:
+        """
+        Get the contents of a loaded audio or data asset.
+
+        Raises:
+            ValueError: If the asset is unloaded or is a texture or font.
+        """
+        data = self._native.asset_bytes(handle)
+        if data is None:
+            raise ValueError(f"{handle!r} is not a loaded audio or data asset")
+        return data
+
+    def read_text(self, handle: AssetHandle, encoding: str = "utf-8") -> str:
+        """Get the contents of a loaded data asset as text."""
+        return self.read_bytes(handle).decode(encoding)
+
+    def read_json(self, handle: AssetHandle) -> Any:
+        """Parse a loaded data asset as JSON."""
+        return json.loads(self.read_bytes(handle))
+
+    def texture_size(self, handle: AssetHandle) -> tuple[int, int]:
+        """
+        Get the `(width, height)` of a loaded texture.
+
+        Raises:
+            ValueError: If the asset is unloaded or is not a texture.
+        """
+        size = self._native.asset_texture_size(handle)
+        if size is None:
+            raise ValueError(f"{handle!r} is not a loaded texture")
+        return size
+
+    @property
+    def hot_reload(self) -> bool:
+        """Get whether changed asset files are reloaded while the engine runs."""
+        return self._native.asset_hot_reload
+
+    @hot_reload.setter
+    def hot_reload(self, value: bool) -> None:
+        """Set whether changed asset files are reloaded while the engine runs."""
+        self._native.asset_hot_reload = bool(value)
+        self._next_check = 0.0
+
+    @property
+    def hot_reload_interval(self) -> float:
+        """Get the minimum time in seconds between hot reload checks."""
+        return self._native.asset_reload_interval
+
+    @hot_reload_interval.setter
+    def hot_reload_interval(self, value: float) -> None:
+        """Set the minimum time in seconds between hot reload checks."""
+        if value < 0.0:
+            raise ValueError("hot_reload_interval must be >= 0.0")
+        self._native.asset_reload_interval = value
+
+    def poll(self) -> list[str]:
+        """Reload changed UI documents if hot reload is on and the interval has elapsed."""
+        if not self.hot_reload:
+            return []
+        now = time.monotonic()
+        if now < self._next_check:
+            return []
+        self._next_check = now + self.hot_reload_interval
+        return self._engine.ui._reload_changed_documents()
+
+    def check(self) -> list[str]:
+        """Reload every changed asset file and UI document now. Returns their paths."""
+        return self._native.reload_changed_assets() + self._engine.ui._reload_changed_documents()
+
+    @property
+    def loaded_count(self) -> int:
+        """Number of loaded assets."""
+        return self._native.loaded_asset_count()
+
+    @property
+    def memory_usage(self) -> int:
+        """Approximate memory held by loaded assets, in bytes."""
+        return self._native.asset_memory_usage()
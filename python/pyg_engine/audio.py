@@ -0,0 +1,725 @@
+"""
+Sound playback and positional audio.
+
+`engine.audio.play()` plays a WAV file and returns a voice id to adjust or stop
+it. Sounds can come from a point in the world, getting quieter with distance
+from the listener and panning to the side they are on:
+
+    engine.audio.play("sounds/click.wav", volume=0.5)
+    engine.audio.play("sounds/explosion.wav", position=Vec2(400, 120))
+
+For sounds that follow an object, add an `AudioSourceComponent` to it. The
+listener is the first object with an `AudioListener` component, or the active
+camera without one:
+
+    player.add_component(AudioListener())
+    engine_obj.add_component(AudioSourceComponent("sounds/hum.wav", looping=True, autoplay=True))
+
+Every sound plays through a mixer bus: "music", "sfx" (the default) or "ui",
+which all feed "master". Each bus has a volume, mute and simple effects, so
+a settings menu can drive them directly:
+
+    engine.audio.play("music/theme.wav", looping=True, bus="music")
+    engine.audio.bus("music").volume = 0.4
+    engine.audio.bus("sfx").low_pass = 800.0  # muffled, e.g. under water
+
+Snapshots save bus settings under a name and fade every bus to them, for
+soundscapes that follow the game's state. Settings a snapshot leaves out keep
+their value, so menu volumes survive:
+
+    engine.audio.add_snapshot("underwater", {"sfx": {"low_pass": 500.0}, "music": {"low_pass": 1200.0}})
+    engine.audio.add_snapshot("surface", {"sfx": {"low_pass": None}, "music": {"low_pass": None}})
+    engine.audio.transition_to("underwater", 0.5)
+
+Sounds can also be made from samples instead of files. `create_sound()` takes
+raw 16-bit PCM bytes, a list of floats in -1..1 or a numpy array and makes it
+playable by name; `play_stream()` calls a generator for more samples each frame,
+for synthesized effects or dynamic music:
+
+    engine.audio.create_sound("beep", samples, sample_rate=22050)
+    engine.audio.play("beep")
+    engine.audio.play_stream(synth.generate, sample_rate=44100)
+
+To chain sounds, pass `on_finished` to `play()` or `play_stream()`; it runs in
+the engine update after the sound plays to the end (not when it is stopped).
+`engine.audio.take_finished()` returns the same voice ids for polling, and the
+engine emits a `"sound_finished"` event with each one:
+
+    lines = ["dialogue/line1.wav", "dialogue/line2.wav", "dialogue/line3.wav"]
+
+    def play_next_line(voice_id=None):
+        if lines:
+            engine.audio.play(lines.pop(0), on_finished=play_next_line)
+
+    play_next_line()
+
+Collisions can make sounds on their own: `set_impact_sound()` maps a pair of
+physics layers, or of collider materials, to a sound played where colliders
+meet, louder the harder they hit:
+
+    engine.audio.set_impact_sound("sounds/clang.wav", materials=("metal", "metal"))
+    engine.audio.set_impact_sound("sounds/thud.wav", layers=(PhysicsLayers.PLAYER, PhysicsLayers.ENVIRONMENT))
+
+Sounds reach the speakers when the engine is built with the `audio` feature;
+without it they still play silently and end on time.
+"""
+
+from __future__ import annotations
+
+import sys
+from array import array
+from collections import deque
+from typing import TYPE_CHECKING, Any, Callable, Dict, List, Optional, Tuple, Union
+
+from .assets import asset_path
+from .pyg_engine_native import AssetHandle, AudioSourceComponent, Vec2
+
+if TYPE_CHECKING:
+    from .engine import Engine
+
+BUS_NAMES = ("master", "music", "sfx", "ui")
+
+# Finished voice ids kept for `take_finished()`; older ones are dropped.
+MAX_FINISHED = 256
+
+SampleData = Union[bytes, bytearray, memoryview, Any]
+
+
+def _pcm_bytes(samples: SampleData, channels: Optional[int]) -> Tuple[bytes, str, int]:
+    """
+    Convert samples to little-endian PCM bytes for the native engine.
+
+    Bytes are taken as 16-bit integers. numpy arrays keep 16-bit integers and
+    send anything else as 32-bit floats; a 2D array is (frames, channels).
+    Other sequences are floats in -1..1.
+
+    Returns:
+        The bytes, their sample format and the channel count (1 unless given
+        or taken from a 2D array).
+    """
+    if isinstance(samples, (bytes, bytearray, memoryview)):
+        return bytes(samples), "int16", channels or 1
+    dtype = getattr(samples, "dtype", None)
+    if dtype is not None and hasattr(samples, "astype"):
+        if channels is None:
+            channels = samples.shape[1] if samples.ndim == 2 else 1
+        if dtype.kind == "i" and dtype.itemsize == 2:
+            return samples.astype("<i2", copy=False).tobytes(), "int16", channels
+        return samples.astype("<f4", copy=False).tobytes(), "float32", channels
+    data = array("f", samples)
+    if sys.byteorder == "big":
+        data.byteswap()
+    return data.tobytes(), "float32", channels or 1
+
+
+class SoundStream:
+    """
+    A stream of generated samples from `engine.audio.play_stream()`.
+
+    Each engine update asks the generator for enough frames to stay
+    `buffer_seconds` ahead of playback. The stream ends after the generator
+    returns None or no samples and what it queued has played.
+    """
+
+    def __init__(
+        self,
+        manager: "AudioManager",
+        voice_id: int,
+        generate: Callable[[int], Optional[SampleData]],
+        sample_rate: int,
+        channels: int,
+        buffer_seconds: float,
+    ):
+        self._manager = manager
+        self._native = manager._native
+        self._generate = generate
+        self._ended = False
+        self.id = voice_id
+        self.sample_rate = sample_rate
+        self.channels = channels
+        self.buffer_seconds = buffer_seconds
+
+    @property
+    def playing(self) -> bool:
+        """Check whether the stream still plays; False once it finished or was stopped."""
+        return self._native.is_sound_playing(self.id)
+
+    @property
+    def queued_frames(self) -> int:
+        """Frames generated but not played yet."""
+        return self._native.sound_stream_queued_frames(self.id) or 0
+
+    def queue(self, samples: SampleData) -> bool:
+        """
+        Queue samples yourself, in addition to what the generator returns.
+
+        Returns:
+            bool: False once the stream ended.
+        """
+        data, sample_format, _ = _pcm_bytes(samples, self.channels)
+        return self._native.queue_sound_stream(self.id, data, sample_format)
+
+    def end(self) -> None:
+        """Stop asking the generator for samples and end after the queued ones play."""
+        self._ended = True
+        self._native.end_sound_stream(self.id)
+
+    def stop(self) -> bool:
+        """Stop the stream now. Returns False if it already finished."""
+        self._ended = True
+        return self._manager.stop(self.id)
+
+    def _refill(self) -> bool:
+        """Top up the queue from the generator. Returns False once the stream finished."""
+        queued = self._native.sound_stream_queued_frames(self.id)
+        if queued is None:
+            return False
+        if self._ended:
+            return True
+        wanted = int(self.buffer_seconds * self.sample_rate) - queued
+        if wanted <= 0:
+            return True
+        try:
+            samples = self._generate(wanted)
+            if samples is not None and len(samples) > 0:
+                self.queue(samples)
+                return True
+        except Exception as exc:
+            self._native.report_callback_error("sound stream generator", exc)
+        self.end()
+        return True
+
+    def __repr__(self) -> str:
+        return f"SoundStream(id={self.id}, sample_rate={self.sample_rate}, channels={self.channels})"
+
+
+class AudioBus:
+    """
+    Volume, mute and effects of one mixer bus, from `engine.audio.bus(name)`.
+
+    Changes apply to sounds already playing; volume and mute fade over a few
+    milliseconds instead of clicking.
+    """
+
+    def __init__(self, native: Any, name: str):
+        self._native = native
+        self._name = name
+
+    @property
+    def name(self) -> str:
+        """Bus name: "master", "music", "sfx" or "ui"."""
+        return self._name
+
+    def _set(self, **changes: Any) -> None:
+        volume, muted, low_pass, reverb_send = self._native.get_audio_bus(self._name)
+        settings = {"volume": volume, "muted": muted, "low_pass": low_pass, "reverb_send": reverb_send}
+        unknown = set(changes) - set(settings)
+        if unknown:
+            raise ValueError(f"Unknown bus settings: {', '.join(sorted(unknown))}")
+        settings.update(changes)
+        self._native.set_audio_bus(self._name, **settings)
+
+    @property
+    def volume(self) -> float:
+        """Get the bus volume, 1.0 by default."""
+        return self._native.get_audio_bus(self._name)[0]
+
+    @volume.setter
+    def volume(self, value: float) -> None:
+        """Set the bus volume (>= 0.0)."""
+        self._set(volume=value)
+
+    @property
+    def muted(self) -> bool:
+        """Get whether the bus is silenced; its volume is kept for unmuting."""
+        return self._native.get_audio_bus(self._name)[1]
+
+    @muted.setter
+    def muted(self, value: bool) -> None:
+        """Silence or restore the bus."""
+        self._set(muted=value)
+
+    @property
+    def low_pass(self) -> Optional[float]:
+        """Get the low-pass cutoff in Hz, or None when the filter is off."""
+        return self._native.get_audio_bus(self._name)[2]
+
+    @low_pass.setter
+    def low_pass(self, value: Optional[float]) -> None:
+        """Set the low-pass cutoff in Hz (lower is more muffled), or None to turn it off."""
+        self._set(low_pass=value)
+
+    @property
+    def reverb_send(self) -> float:
+        """Get the share of the bus sent to the shared reverb, from 0.0 to 1.0."""
+        return self._native.get_audio_bus(self._name)[3]
+
+    @reverb_send.setter
+    def reverb_send(self, value: float) -> None:
+        """Set the reverb send, from 0.0 (dry) to 1.0. The master bus ignores it."""
+        self._set(reverb_send=value)
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Get the bus settings as a JSON-friendly dict."""
+        volume, muted, low_pass, reverb_send = self._native.get_audio_bus(self._name)
+        return {"volume": volume, "muted": muted, "low_pass": low_pass, "reverb_send": reverb_send}
+
+    def __repr__(self) -> str:
+        return f"AudioBus({self._name!r}, volume={self.volume}, muted={self.muted})"
+
+
+class AudioManager:
+    """
+    Plays sounds and reports on positional audio, see the module docs.
+
+    Relative paths resolve from the engine's source root. Each file is
+    decoded once and kept for later plays; files loaded with
+    `engine.assets.load_audio()` are decoded from the loaded bytes.
+
+    Positional sounds and `AudioSourceComponent`s update in the engine
+    update, in every loop.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine, Vec2
+
+        engine = Engine()
+        engine.audio.master_volume = 0.8
+
+        def update(ctx):
+            if ctx.input.key_pressed("space"):
+                engine.audio.play("sounds/jump.wav", position=player.position, pitch_range=(0.9, 1.1))
+
+        engine.run(update=update)
+        ```
+    """
+
+    def __init__(self, engine: "Engine"):
+        self._engine = engine
+        self._native = engine._engine
+        self._buses = {name: AudioBus(self._native, name) for name in BUS_NAMES}
+        self._streams: Dict[int, SoundStream] = {}
+        self._finished_callbacks: Dict[int, Callable[[int], Any]] = {}
+        self._finished: deque[int] = deque(maxlen=MAX_FINISHED)
+
+    def play(
+        self,
+        sound: Union[str, AssetHandle],
+        volume: float = 1.0,
+        pan: float = 0.0,
+        looping: bool = False,
+        position: Optional[Vec2] = None,
+        attenuation: str = "linear",
+        min_distance: float = 100.0,
+        max_distance: float = 1000.0,
+        rolloff: float = 1.0,
+        bus: str = "sfx",
+        pitch: float = 1.0,
+        pitch_range: Optional[Tuple[float, float]] = None,
+        priority: int = 0,
+        on_finished: Optional[Callable[[int], Any]] = None,
+    ) -> int:
+        """
+        Play a sound.
+
+        Args:
+            sound: WAV file path, audio `AssetHandle`, or name from `create_sound()`.
+            volume: Linear gain, 1.0 as recorded.
+            pan: Balance from -1.0 (left) to 1.0 (right), for sounds without a position.
+            looping: Start over at the end until stopped.
+            position: World point to play the sound from, or None for a plain sound.
+            attenuation: "linear", "inverse", "exponential" or "none"; see
+                `AudioSourceComponent` for the curves.
+            min_distance: Distance within which a positional sound is at full volume.
+            max_distance: Distance where the falloff ends.
+            rolloff: Steepness of the falloff.
+            bus: Mixer bus to play through: "music", "sfx", "ui" or "master".
+            pitch: Playback rate; 2.0 plays twice as fast and an octave higher.
+            pitch_range: `(min, max)` to pick a random pitch from instead, so
+                repeated sounds like footsteps don't sound identical.
+            priority: Past `max_sounds`, sounds with lower priorities are cut
+                off first, so rapid-fire effects can't silence music or dialogue.
+            on_finished: Called with the voice id in the engine update after
+                the sound plays to the end or is cut off by `max_sounds`; not
+                called if it is stopped.
+
+        Returns:
+            int: Voice id for `stop()`, `set_volume()` and `is_playing()`.
+
+        Raises:
+            RuntimeError: If the file cannot be read or is not a WAV file.
+            ValueError: If the attenuation settings, bus or pitch are invalid.
+        """
+        voice_id = self._native.play_sound(
+            asset_path(sound),
+            volume=volume,
+            pan=pan,
+            looping=looping,
+            position=position,
+            attenuation=attenuation,
+            min_distance=min_distance,
+            max_distance=max_distance,
+            rolloff=rolloff,
+            bus=bus,
+            pitch=pitch,
+            pitch_range=pitch_range,
+            priority=priority,
+        )
+        if on_finished is not None:
+            self._finished_callbacks[voice_id] = on_finished
+        return voice_id
+
+    def create_sound(
+        self,
+        name: str,
+        samples: SampleData,
+        sample_rate: int = 44100,
+        channels: Optional[int] = None,
+    ) -> str:
+        """
+        Make samples playable as `play(name)`, without a file.
+
+        Args:
+            name: Name to play the sound by; replaces a sound created with the same name.
+            samples: Raw 16-bit PCM bytes, a sequence of floats in -1..1, or a
+                numpy array (int16, or floats in -1..1; 2D arrays are frames by channels).
+            sample_rate: Samples per second, per channel.
+            channels: 1 or 2, interleaved. Taken from a 2D array, 1 otherwise.
+
+        Returns:
+            str: The name, for `play()`.
+
+        Raises:
+            ValueError: If the samples do not fill whole frames or the layout is invalid.
+        """
+        data, sample_format, channels = _pcm_bytes(samples, channels)
+        self._native.create_sound(name, data, sample_rate, channels, sample_format)
+        return name
+
+    def remove_sound(self, name: str) -> bool:
+        """Remove a sound made with `create_sound()`. Playing copies finish normally."""
+        return self._native.remove_sound(name)
+
+    def play_stream(
+        self,
+        generate: Callable[[int], Optional[SampleData]],
+        sample_rate: int = 44100,
+        channels: int = 1,
+        volume: float = 1.0,
+        pan: float = 0.0,
+        bus: str = "sfx",
+        priority: int = 0,
+        buffer_seconds: float = 0.1,
+        on_finished: Optional[Callable[[int], Any]] = None,
+    ) -> SoundStream:
+        """
+        Play samples as a generator produces them.
+
+        `generate(frame_count)` runs on the main thread in the engine update
+        and returns up to that many frames, in any format `create_sound()`
+        takes, or None to end the stream. Exceptions are reported like other
+        callback errors and end the stream.
+
+        Args:
+            generate: Called with the number of frames wanted.
+            sample_rate: Samples per second, per channel.
+            channels: 1 or 2, interleaved.
+            volume: Linear gain, 1.0 as generated.
+            pan: Balance from -1.0 (left) to 1.0 (right).
+            bus: Mixer bus to play through.
+            priority: Past `max_sounds`, sounds with lower priorities are cut off first.
+            buffer_seconds: How far ahead of playback to generate. Longer
+                survives slow frames; shorter reacts faster to changes.
+            on_finished: Called with the voice id once the stream ended and
+                its samples played; not called if it is stopped.
+
+        Returns:
+            SoundStream: Handle to queue extra samples, end or stop the stream.
+
+        **Example:**
+
+            ```python
+            import math
+
+            phase = 0.0
+
+            def sine(frames):
+                global phase
+                samples = [0.2 * math.sin(phase + i * math.tau * 440 / 44100) for i in range(frames)]
+                phase += frames * math.tau * 440 / 44100
+                return samples
+
+            engine.audio.play_stream(sine, sample_rate=44100)
+            ```
+        """
+        if buffer_seconds <= 0:
+            raise ValueError("buffer_seconds must be > 0")
+        voice_id = self._native.play_sound_stream(
+            sample_rate, channels, volume=volume, pan=pan, bus=bus, priority=priority
+        )
+        stream = SoundStream(self, voice_id, generate, sample_rate, channels, buffer_seconds)
+        self._streams[voice_id] = stream
+        if on_finished is not None:
+            self._finished_callbacks[voice_id] = on_finished
+        stream._refill()
+        return stream
+
+    def take_finished(self) -> List[int]:
+        """
+        Get the voice ids of the sounds that played to the end, or were cut
+        off by `max_sounds`, since the last call, oldest first. Stopped
+        sounds are not included.
+        """
+        finished = list(self._finished)
+        self._finished.clear()
+        return finished
+
+    def _poll(self) -> None:
+        """Run `on_finished` callbacks, then top up every stream from its generator."""
+        for voice_id in self._native.take_finished_sounds():
+            self._finished.append(voice_id)
+            callback = self._finished_callbacks.pop(voice_id, None)
+            if callback is None:
+                continue
+            try:
+                callback(voice_id)
+            except Exception as exc:
+                self._native.report_callback_error("sound on_finished callback", exc)
+        for voice_id, stream in list(self._streams.items()):
+            if not stream._refill():
+                del self._streams[voice_id]
+
+    def stop(self, voice_id: int) -> bool:
+        """Stop a sound without calling its `on_finished`. Returns False if it already ended."""
+        self._finished_callbacks.pop(voice_id, None)
+        return self._native.stop_sound(voice_id)
+
+    def stop_all(self) -> None:
+        """Stop every sound, including those of audio sources."""
+        self._finished_callbacks.clear()
+        self._native.stop_all_sounds()
+
+    def is_playing(self, sound: Union[int, AudioSourceComponent]) -> bool:
+        """Check whether a voice id, or an `AudioSourceComponent`, is still playing."""
+        if isinstance(sound, AudioSourceComponent):
+            return self._native.is_audio_source_playing(sound.id)
+        return self._native.is_sound_playing(sound)
+
+    def set_volume(self, voice_id: int, volume: float) -> bool:
+        """Set a sound's volume, before distance attenuation. Returns False if it ended."""
+        return self._native.set_sound_volume(voice_id, volume)
+
+    def set_pitch(self, voice_id: int, pitch: float) -> bool:
+        """Set a sound's playback rate (> 0.0). Returns False if it ended."""
+        return self._native.set_sound_pitch(voice_id, pitch)
+
+    def set_pan(self, voice_id: int, pan: float) -> bool:
+        """Set a sound's pan. Returns False if it ended or has a position."""
+        return self._native.set_sound_pan(voice_id, pan)
+
+    def set_impact_sound(
+        self,
+        sound: Union[str, AssetHandle],
+        layers: Optional[Tuple[int, int]] = None,
+        materials: Optional[Tuple[str, str]] = None,
+        volume: float = 1.0,
+        full_volume_depth: float = 1.0,
+        min_depth: float = 0.0,
+        pitch_range: Optional[Tuple[float, float]] = None,
+        bus: str = "sfx",
+        priority: int = 0,
+        spatial: bool = True,
+        attenuation: str = "linear",
+        min_distance: float = 100.0,
+        max_distance: float = 1000.0,
+        rolloff: float = 1.0,
+    ) -> None:
+        """
+        Play a sound whenever two colliders start touching.
+
+        The pair is either two physics layers or two collider materials (see
+        `Collider.set_material()`), in any order; a material pair wins over a
+        layer pair. Trigger colliders never make impact sounds. Setting a
+        pair again replaces its sound.
+
+        Args:
+            sound: WAV file path, audio `AssetHandle`, or name from `create_sound()`.
+            layers: Pair of physics layers, e.g. `(PhysicsLayers.PLAYER, PhysicsLayers.ENVIRONMENT)`.
+            materials: Pair of material names, e.g. `("metal", "wood")`.
+            volume: Linear gain of the hardest hits.
+            full_volume_depth: How deep the colliders overlap on the step they
+                meet for the sound to play at `volume`; shallower hits are
+                quieter in proportion.
+            min_depth: Hits shallower than this are silent.
+            pitch_range: `(min, max)` to pick a random pitch from for each hit.
+            bus: Mixer bus to play through.
+            priority: Past `max_sounds`, sounds with lower priorities are cut off first.
+            spatial: Play from the contact point, attenuated like `play(position=...)`.
+            attenuation: "linear", "inverse", "exponential" or "none".
+            min_distance: Distance within which the sound is at full volume.
+            max_distance: Distance where the falloff ends.
+            rolloff: Steepness of the falloff.
+
+        Raises:
+            ValueError: If neither or both of `layers` and `materials` are given,
+                or another setting is invalid.
+        """
+        self._native.set_impact_sound(
+            asset_path(sound),
+            layers=layers,
+            materials=materials,
+            volume=volume,
+            full_volume_depth=full_volume_depth,
+            min_depth=min_depth,
+            pitch_range=pitch_range,
+            bus=bus,
+            priority=priority,
+            spatial=spatial,
+            attenuation=attenuation,
+            min_distance=min_distance,
+            max_distance=max_distance,
+            rolloff=rolloff,
+        )
+
+    def remove_impact_sound(
+        self,
+        layers: Optional[Tuple[int, int]] = None,
+        materials: Optional[Tuple[str, str]] = None,
+    ) -> bool:
+        """Stop playing a sound for a layer or material pair. Returns False if none was set."""
+        return self._native.remove_impact_sound(layers=layers, materials=materials)
+
+    def clear_impact_sounds(self) -> None:
+        """Remove every impact sound."""
+        self._native.clear_impact_sounds()
+
+    @property
+    def master_volume(self) -> float:
+        """Get the volume applied to every sound, the same as `bus("master").volume`."""
+        return self._native.master_volume
+
+    @master_volume.setter
+    def master_volume(self, value: float) -> None:
+        """Set the volume applied to every sound, 1.0 by default."""
+        self._native.master_volume = value
+
+    def bus(self, name: str) -> AudioBus:
+        """
+        Get a mixer bus by name.
+
+        Raises:
+            ValueError: If the name is not "master", "music", "sfx" or "ui".
+        """
+        bus = self._buses.get(name.lower())
+        if bus is None:
+            raise ValueError(f"Unknown bus {name!r}, expected one of {', '.join(BUS_NAMES)}")
+        return bus
+
+    @property
+    def buses(self) -> List[AudioBus]:
+        """Every mixer bus, master first."""
+        return list(self._buses.values())
+
+    def get_bus_settings(self) -> Dict[str, Dict[str, Any]]:
+        """
+        Get every bus's settings by bus name, e.g. to keep a settings menu's
+        choices in `engine.save_data`.
+        """
+        return {name: bus.to_dict() for name, bus in self._buses.items()}
+
+    def set_bus_settings(self, settings: Dict[str, Dict[str, Any]]) -> None:
+        """
+        Apply settings from `get_bus_settings()`. Buses and keys left out keep
+        their current values.
+
+        Raises:
+            ValueError: If a bus name or value is invalid.
+        """
+        for name, values in settings.items():
+            self.bus(name)._set(**values)
+
+    def add_snapshot(self, name: str, settings: Optional[Dict[str, Dict[str, Any]]] = None) -> None:
+        """
+        Save bus settings as a snapshot to fade to with `transition_to()`.
+
+        Args:
+            name: Snapshot name; replaces a snapshot saved under it before.
+            settings: `{bus: {setting: value}}` like `get_bus_settings()`.
+                Buses and settings left out are not changed by the snapshot.
+                None saves every bus's current settings.
+
+        Raises:
+            ValueError: If a bus name, setting or value is invalid.
+        """
+        if settings is None:
+            settings = self.get_bus_settings()
+        self._native.add_audio_snapshot(name, settings)
+
+    def remove_snapshot(self, name: str) -> bool:
+        """Forget a snapshot. Returns False if there was none."""
+        return self._native.remove_audio_snapshot(name)
+
+    @property
+    def snapshots(self) -> List[str]:
+        """Get the names of the saved snapshots, sorted."""
+        return self._native.audio_snapshots()
+
+    def transition_to(self, name: str, duration: float = 0.0) -> None:
+        """
+        Fade every bus from its current settings to a snapshot's.
+
+        The fade runs in the engine update on real time, so it keeps going
+        while the game is paused. It replaces a fade in progress, starting from
+        wherever that one got to. Bus changes made during a fade are
+        overridden by it until it ends.
+
+        Args:
+            name: Snapshot saved with `add_snapshot()`.
+            duration: Seconds the fade takes; 0 applies the snapshot at once.
+
+        Raises:
+            ValueError: If there is no snapshot called `name` or duration is negative.
+        """
+        self._native.transition_audio_snapshot(name, duration)
+
+    @property
+    def snapshot(self) -> Optional[str]:
+        """Get the snapshot last transitioned to, or None."""
+        return self._native.current_audio_snapshot
+
+    @property
+    def transitioning(self) -> bool:
+        """Check whether a snapshot fade is still running."""
+        return self._native.audio_snapshot_transitioning
+
+    @property
+    def playing_count(self) -> int:
+        """Number of sounds playing."""
+        return self._native.playing_sound_count
+
+    @property
+    def max_sounds(self) -> int:
+        """
+        Get the most sounds that play at once, 64 by default.
+
+        Past the limit a new sound replaces the oldest playing sound with the
+        lowest priority, or is dropped if every playing sound has a higher
+        priority than it. Either way the sound that loses is reported as
+        finished.
+        """
+        return self._native.max_sounds
+
+    @max_sounds.setter
+    def max_sounds(self, value: int) -> None:
+        self._native.max_sounds = value
+
+    @property
+    def output_open(self) -> bool:
+        """Check whether sounds reach an output device; False until the first sound plays."""
+        return self._native.audio_output_open
+
+    @property
+    def listener_position(self) -> Any:
+        """Get the world position positional sounds were last heard from."""
+        return self._native.audio_listener_position()
@@ -0,0 +1,94 @@
+"""
+Python scripts that run as components on GameObjects.
+
+Subclass `Script`, override the hooks you need and add an instance to a
+GameObject with `add_component()`. The engine calls the hooks at the right
+points of its update loop, so game logic can live on the objects it drives
+instead of in one large update callback.
+"""
+
+from __future__ import annotations
+
+from typing import Any, Optional
+
+
+class Script:
+    """
+    Base class for Python components.
+
+    Each hook is optional and is called by the engine while the script and
+    its GameObject are enabled:
+
+    - `on_start()` once, before the first `update()`.
+    - `update(dt)` every frame, after the native components update.
+    - `fixed_update(dt)` every fixed step, before collision detection.
+    - `on_collision_enter(other_id, normal, penetration)`,
+      `on_collision_stay(...)` and `on_collision_exit(other_id)` for the
+      collisions of the fixed step. `normal` points from this object towards
+      the other one.
+    - `on_message(name, payload)` for each message sent to the object with
+      `engine.send_message()`, in the update phase before `update()`.
+
+    Hooks run with the engine free, so they can query it and change the
+    scene, e.g. `engine.destroy_object(other_id)`. The native blocking
+    `engine.run()` loop is the exception: it keeps the engine busy, so scripts
+    there should only use their `game_object`. Exceptions raised by a hook
+    are logged and the script keeps running.
+
+    The engine sets two attributes when the script is added:
+
+    - `id`: the component id, for `GameObject.remove_component(script)`.
+    - `game_object`: a live handle to the runtime GameObject, set once the
+      object is in the engine.
+
+    `GameObject.get_component(MyScript)` returns the script instance. Any
+    object with at least one of the hook methods can be added the same way;
+    subclassing `Script` only provides the defaults.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine, GameObject, Script, Vec2
+
+        class Mover(Script):
+            def __init__(self, engine, speed):
+                self.engine = engine
+                self.speed = speed
+
+            def update(self, dt):
+                position = self.game_object.position
+                self.game_object.position = Vec2(position.x + self.speed * dt, position.y)
+
+            def on_collision_enter(self, other_id, normal, penetration):
+                self.engine.destroy_object(other_id)
+
+        engine = Engine()
+        player = GameObject("Player")
+        player.add_component(Mover(engine, speed=2.0))
+        engine.add_game_object(player)
+        ```
+    """
+
+    id: Optional[int] = None
+    game_object: Any = None
+
+    def on_start(self) -> None:
+        """Called once, before the first `update()`."""
+
+    def update(self, dt: float) -> None:
+        """Called every frame with the frame's delta time."""
+
+    def fixed_update(self, dt: float) -> None:
+        """Called every fixed step with the fixed timestep."""
+
+    def on_collision_enter(self, other_id: int, normal: Any, penetration: float) -> None:
+        """Called when a collision with the object `other_id` starts."""
+
+    def on_collision_stay(self, other_id: int, normal: Any, penetration: float) -> None:
+        """Called each fixed step while a collision with `other_id` goes on."""
+
+    def on_collision_exit(self, other_id: int) -> None:
+        """Called when a collision with `other_id` ends."""
+
+    def on_message(self, name: str, payload: Any) -> None:
+        """Called for each message sent to the object with `engine.send_message()`."""
@@ -6,12 +6,24 @@ with comprehensive logging capabilities.
 """
 
 import inspect
-from collections.abc import Callable
+import sys
+import threading
+import time
+import weakref
+from collections.abc import Callable, Iterable
 from pathlib import Path
-from typing import TYPE_CHECKING, Any, Optional
+from typing import TYPE_CHECKING, Any, Optional, Union
 
 if TYPE_CHECKING:
-    pass
+    from .pyg_engine_native import (
+        CallbackError,
+        DrawRecorder,
+        EngineCapabilities,
+        FrameStats,
+        InputEvent,
+        RenderStats,
+    )
+    from .ui_loader import UIDocument
 
 try:
     from .pyg_engine_native import DrawCommand as _RustDrawCommand
@@ -28,10 +40,23 @@ except ImportError as e:
 
 DrawCommand = _RustDrawCommand
 
+# RGBA pixels for `draw_image_from_bytes()`: bytes or any byte buffer, such as
+# a bytearray, memoryview or uint8 numpy array.
+PixelData = Union[bytes, bytearray, memoryview, Any]
+
+from .scripts import ScriptReloader
+from .scenes import SceneManager
+from .timers import TimerManager
+from .jobs import JobHandle, JobManager
+from .save_data import SaveData, app_name_from_path
+from .assets import AssetHandle, AssetManager, asset_path
+from .audio import AudioManager
+from .intro import IntroSequence, IntroSlide
 from .shapes import to_draw_commands
 
 
 _PACKAGE_ROOT = Path(__file__).resolve().parent
+_CONFIG_FILE_NAME = "pyg_engine.toml"
 
 
 def _detect_source_root() -> Path:
@@ -46,6 +71,18 @@ def _detect_source_root() -> Path:
     return Path.cwd()
 
 
+def _theme_color(key: str, color: Any) -> tuple[float, float, float, float]:
+    """Convert a `Color` or an (r, g, b[, a]) tuple for `register_ui_theme()`."""
+    if hasattr(color, "r"):
+        return (color.r, color.g, color.b, color.a)
+    values = tuple(float(component) for component in color)
+    if len(values) == 3:
+        values += (1.0,)
+    if len(values) != 4:
+        raise ValueError(f"Theme color '{key}' must have 3 or 4 components")
+    return values
+
+
 class EngineHandle:
     """
     Thread-safe handle to the engine that can be passed to background threads.
@@ -102,6 +139,39 @@ class EngineHandle:
         """
         self._inner.remove_game_object(object_id)
 
+    def emit(self, name: str, payload: Any = None) -> None:
+        """
+        Emit an event on the engine's event bus via the command queue.
+
+        This is thread-safe; the event is queued on the next engine update and
+        dispatched at the end of it. See `Engine.emit()`.
+        """
+        self._inner.emit_event(name, payload)
+
+    def request_quit(self) -> None:
+        """End the game loop after the current frame via the command queue. See `Engine.request_quit()`."""
+        self._inner.request_quit()
+
+    def set_time_scale(self, time_scale: float) -> None:
+        """Set the game time scale via the command queue. See `Engine.time_scale`."""
+        self._inner.set_time_scale(time_scale)
+
+    def set_paused(self, paused: bool) -> None:
+        """Pause or resume game time via the command queue. See `Engine.paused`."""
+        self._inner.set_paused(paused)
+
+    def set_fixed_timestep(self, seconds: float) -> None:
+        """Set the seconds per fixed update via the command queue. See `Engine.set_fixed_timestep()`."""
+        self._inner.set_fixed_timestep(seconds)
+
+    def send_message(self, target_id: int, name: str, payload: Any = None) -> None:
+        """
+        Send a message to a runtime GameObject's components via the command queue.
+
+        This is thread-safe. See `Engine.send_message()`.
+        """
+        self._inner.send_message(target_id, name, payload)
+
     def set_game_object_position(self, object_id: int, position: Any) -> None:
         """
         Update a runtime GameObject position by ID via command queue.
@@ -202,6 +272,10 @@ class EngineHandle:
         """Draw one shape or a batch of shapes via the command queue."""
         self._inner.add_draw_commands(to_draw_commands(drawable))
 
+    def draw_recorder(self, capacity: int = 1024) -> "DrawRecorder":
+        """Create a DrawRecorder that submits its draw calls via command queue in one batch."""
+        return self._inner.draw_recorder(capacity)
+
     def draw_pixel(
         self,
         x: int,
@@ -437,7 +511,7 @@ class EngineHandle:
         y: float,
         width: float,
         height: float,
-        texture_path: str,
+        texture_path: Union[str, AssetHandle],
         draw_order: float = 0.0,
     ) -> None:
         """
@@ -451,7 +525,7 @@ class EngineHandle:
             y: Top-left Y coordinate in pixels.
             width: Display width in pixels.
             height: Display height in pixels.
-            texture_path: File path to the image (PNG, JPG, etc.).
+            texture_path: File path to the image (PNG, JPG, etc.), or a texture `AssetHandle`.
             draw_order: Rendering order (higher values drawn on top).
 
         Example:
@@ -470,7 +544,7 @@ class EngineHandle:
             y,
             width,
             height,
-            texture_path,
+            asset_path(texture_path),
             draw_order=draw_order,
         )
 
@@ -481,7 +555,7 @@ class EngineHandle:
         width: float,
         height: float,
         texture_key: str,
-        rgba: bytes,
+        rgba: PixelData,
         texture_width: int,
         texture_height: int,
         draw_order: float = 0.0,
@@ -497,8 +571,12 @@ class EngineHandle:
             y: Top-left Y coordinate in pixels.
             width: Display width in pixels.
             height: Display height in pixels.
-            texture_key: Unique identifier for caching this texture.
-            rgba: Raw RGBA bytes (4 bytes per pixel: R, G, B, A).
+            texture_key: Unique identifier for caching this texture. Drawing
+                new pixels of the same size under a known key updates the
+                cached GPU texture instead of creating another.
+            rgba: Raw RGBA bytes (4 bytes per pixel: R, G, B, A), from any
+                byte buffer such as a bytearray, memoryview or uint8 numpy
+                array, which is copied once.
             texture_width: Width of the source texture in pixels.
             texture_height: Height of the source texture in pixels.
             draw_order: Rendering order (higher values drawn on top).
@@ -539,7 +617,7 @@ class EngineHandle:
         y: float,
         color: Any,
         font_size: float = 24.0,
-        font_path: Optional[str] = None,
+        font_path: Union[str, AssetHandle, None] = None,
         font_family: Optional[str] = None,
         font_weight: Optional[str] = None,
         font_style: Optional[str] = None,
@@ -601,7 +679,7 @@ class EngineHandle:
             y,
             color,
             font_size=font_size,
-            font_path=font_path,
+            font_path=asset_path(font_path),
             font_family=font_family,
             font_weight=font_weight,
             font_style=font_style,
@@ -611,6 +689,33 @@ class EngineHandle:
             draw_order=draw_order,
         )
 
+    def draw_world_text(
+        self,
+        text: str,
+        x: float,
+        y: float,
+        color: Any,
+        font_size: float = 24.0,
+        font_path: Union[str, AssetHandle, None] = None,
+        font_family: Optional[str] = None,
+        font_weight: Optional[str] = None,
+        font_style: Optional[str] = None,
+        draw_order: float = 0.0,
+    ) -> None:
+        """Draw text centered on a world-space point via command queue."""
+        self._inner.draw_world_text(
+            text,
+            x,
+            y,
+            color,
+            font_size=font_size,
+            font_path=asset_path(font_path),
+            font_family=font_family,
+            font_weight=font_weight,
+            font_style=font_style,
+            draw_order=draw_order,
+        )
+
     def register_font_family(
         self,
         family: str,
@@ -629,6 +734,51 @@ class EngineHandle:
             bold_italic=bold_italic,
         )
 
+    def set_font_fallbacks(self, fallbacks: list[str]) -> None:
+        """Set the default fallback fonts for all text via command queue."""
+        self._inner.set_font_fallbacks(list(fallbacks))
+
+    def set_ui_font_fallbacks(self, fallbacks: list[str]) -> None:
+        """Set the UI theme's fallback fonts via command queue."""
+        self._inner.set_ui_font_fallbacks(list(fallbacks))
+
+    def set_ui_theme(self, name: str) -> None:
+        """Switch the UI to a registered theme via command queue."""
+        self._inner.set_ui_theme(name)
+
+    def set_ui_scale_mode(
+        self,
+        mode: str,
+        reference_width: float = 1920,
+        reference_height: float = 1080,
+    ) -> None:
+        """Set the UI scale mode via command queue."""
+        self._inner.set_ui_scale_mode(mode, reference_width, reference_height)
+
+    def set_ui_focus(self, object_id: Optional[int]) -> None:
+        """Focus a UI widget by object ID, or clear focus, via command queue."""
+        self._inner.set_ui_focus(object_id)
+
+    def set_ui_interactable(self, object_id: int, interactable: bool) -> None:
+        """Set whether a UI element takes input via command queue."""
+        self._inner.set_ui_interactable(object_id, interactable)
+
+    def set_ui_hover_cursor(self, object_id: int, cursor: Optional[str] = None) -> None:
+        """Set the cursor shown while hovering a UI element via command queue."""
+        self._inner.set_ui_hover_cursor(object_id, cursor)
+
+    def set_ui_input_context(self, object_id: int, context: Optional[str] = None) -> None:
+        """Set the input context a UI element takes input in via command queue."""
+        self._inner.set_ui_input_context(object_id, context)
+
+    def clipboard_get(self) -> str:
+        """Text on the system clipboard, or "" when it holds no text."""
+        return self._inner.clipboard_get()
+
+    def clipboard_set(self, text: str) -> None:
+        """Put text on the system clipboard."""
+        self._inner.clipboard_set(text)
+
     def update_ui_label_text(self, object_id: int, text: str) -> None:
         """
         Update a UI label's text at runtime by object ID via command queue.
@@ -653,6 +803,144 @@ class EngineHandle:
         """
         self._inner.update_ui_button_text(object_id, text)
 
+    def update_ui_text_input_text(self, object_id: int, text: str) -> None:
+        """
+        Update a UI text input's text at runtime by object ID via command queue.
+
+        Args:
+            object_id: The GameObject ID of the text input to update.
+            text: The new text.
+        """
+        self._inner.update_ui_text_input_text(object_id, text)
+
+    def update_ui_slider_value(self, object_id: int, value: float) -> None:
+        """
+        Update a UI slider's value at runtime by object ID via command queue.
+
+        Args:
+            object_id: The GameObject ID of the slider to update.
+            value: The new value; it is clamped to the slider's range.
+        """
+        self._inner.update_ui_slider_value(object_id, value)
+
+    def update_ui_curve(self, object_id: int, curve: Any) -> None:
+        """
+        Replace a UI curve editor's curve at runtime by object ID via command queue.
+
+        Args:
+            object_id: The GameObject ID of the curve editor to update.
+            curve: The new AnimationCurve.
+        """
+        self._inner.update_ui_curve(object_id, curve)
+
+    def update_ui_checked(self, object_id: int, checked: bool) -> None:
+        """
+        Update a UI checkbox or toggle switch's checked state at runtime by object ID via command queue.
+
+        Args:
+            object_id: The GameObject ID of the checkbox or toggle switch.
+            checked: The new checked state.
+        """
+        self._inner.update_ui_checked(object_id, checked)
+
+    def update_ui_scroll_position(self, object_id: int, x: float, y: float) -> None:
+        """
+        Update a UI scroll view's scroll offset at runtime by object ID via command queue.
+
+        List views scroll vertically to `y` and ignore `x`.
+
+        Args:
+            object_id: The GameObject ID of the scroll view or list view.
+            x: Horizontal scroll offset in pixels.
+            y: Vertical scroll offset in pixels.
+        """
+        self._inner.update_ui_scroll_position(object_id, x, y)
+
+    def update_ui_list_item_count(self, object_id: int, item_count: int) -> None:
+        """
+        Update a UI list view's item count at runtime by object ID via command queue.
+
+        Args:
+            object_id: The GameObject ID of the list view.
+            item_count: The new number of rows.
+        """
+        self._inner.update_ui_list_item_count(object_id, item_count)
+
+    def update_ui_list_selection(self, object_id: int, selected: Optional[int]) -> None:
+        """
+        Select a UI list view row at runtime by object ID via command queue.
+
+        The row is scrolled into view. Does not fire `on_selection_changed`.
+
+        Args:
+            object_id: The GameObject ID of the list view.
+            selected: The row index, or None to clear the selection.
+        """
+        self._inner.update_ui_list_selection(object_id, selected)
+
+    def refresh_ui_list(self, object_id: int) -> None:
+        """
+        Fetch a UI list view's visible rows from its item provider again by object ID via command queue.
+
+        Args:
+            object_id: The GameObject ID of the list view.
+        """
+        self._inner.refresh_ui_list(object_id)
+
+    def update_ui_progress_value(self, object_id: int, value: float) -> None:
+        """
+        Update a UI progress bar's value (0.0-1.0) at runtime by object ID via command queue.
+
+        Args:
+            object_id: The GameObject ID of the progress bar.
+            value: The new value.
+        """
+        self._inner.update_ui_progress_value(object_id, value)
+
+    def update_ui_image_texture(
+        self, object_id: int, texture_path: Union[str, AssetHandle, None]
+    ) -> None:
+        """
+        Update a UI image's texture at runtime by object ID via command queue.
+
+        Args:
+            object_id: The GameObject ID of the image.
+            texture_path: The new texture path or handle, or None to hide the image.
+        """
+        self._inner.update_ui_image_texture(object_id, asset_path(texture_path))
+
+    def update_ui_image_scale_mode(self, object_id: int, scale_mode: str) -> None:
+        """
+        Update a UI image's scale mode at runtime by object ID via command queue.
+
+        Args:
+            object_id: The GameObject ID of the image.
+            scale_mode: "stretch", "fit", "fill" or "tile".
+        """
+        self._inner.update_ui_image_scale_mode(object_id, scale_mode)
+
+    def update_ui_stack(
+        self,
+        object_id: int,
+        direction: str,
+        spacing: float,
+        padding: tuple[float, float, float, float],
+        alignment: str,
+        fit_content: bool,
+    ) -> None:
+        """
+        Update a UI stack's arrangement at runtime by object ID via command queue.
+
+        Args:
+            object_id: The GameObject ID of the stack.
+            direction: "vertical" or "horizontal".
+            spacing: Gap between children in pixels.
+            padding: (left, right, top, bottom) inset in pixels.
+            alignment: "start", "center", "end" or "stretch".
+            fit_content: Whether the stack resizes along its axis to wrap its children.
+        """
+        self._inner.update_ui_stack(object_id, direction, spacing, padding, alignment, fit_content)
+
     def log(self, message: str) -> None:
         """
         Log a message at INFO level (default log method).
@@ -862,6 +1150,22 @@ class CameraProxy:
     def background_color(self, value: Any) -> None:
         self._engine_wrapper._engine.set_camera_background_color(value)
 
+    @property
+    def pixel_perfect_resolution(self) -> Optional[tuple[int, int]]:
+        return self._engine_wrapper._engine.get_camera_pixel_perfect()
+
+    @pixel_perfect_resolution.setter
+    def pixel_perfect_resolution(self, value: Optional[tuple[int, int]]) -> None:
+        if value is None:
+            self._engine_wrapper._engine.set_camera_pixel_perfect()
+            return
+        width, height = value
+        if not self._engine_wrapper._engine.set_camera_pixel_perfect(int(width), int(height)):
+            raise ValueError(
+                f"pixel-perfect resolution {width}x{height} must be positive and fit the GPU's "
+                "maximum texture size"
+            )
+
     def __repr__(self) -> str:
         game_object = self._game_object()
         if game_object is None:
@@ -872,6 +1176,10 @@ class CameraProxy:
         return self._game_object() is not None
 
 
+# Alternative tween property names, mapped to the name the engine reports.
+_TWEEN_PROPERTY_ALIASES = {"color": "text_color"}
+
+
 class UIManager:
     """
     Manages UI elements like buttons, panels, and labels.
@@ -899,13 +1207,21 @@ class UIManager:
     """
     def __init__(self, engine: "Engine") -> None:
         self._engine = engine
+        # List views whose visible rows changed since their row elements were synced.
+        self._pending_list_views: set[Any] = set()
+        # Tween id -> ((object id, property), on_complete callback)
+        self._tween_callbacks: dict[int, tuple[tuple[int, str], Callable[[], None]]] = {}
+        # Object id -> added UI element, for `get_focused()`
+        self._elements: "weakref.WeakValueDictionary[int, Any]" = weakref.WeakValueDictionary()
+        # Documents loaded from files, rebuilt by asset hot reload
+        self._documents: "list[weakref.ref[UIDocument]]" = []
 
     def add(self, ui_component: Any) -> Optional[int]:
         """
-        Add a UI component (Button, Panel, or Label) to the engine.
+        Add a UI component (Button, Panel, Label, TextInput, Slider, Checkbox, RadioButton, ToggleSwitch, ScrollView, ProgressBar, CustomWidget, Canvas, Image, VStack, HStack, CurveEditor, or ListView) to the engine.
 
         Args:
-            ui_component: A Button, Panel, or Label instance.
+            ui_component: A Button, Panel, Label, TextInput, Slider, Checkbox, RadioButton, ToggleSwitch, ScrollView, ProgressBar, CustomWidget, Canvas, Image, VStack, HStack, CurveEditor, or ListView instance.
 
         Returns:
             The runtime object ID, or None if add failed.
@@ -925,10 +1241,30 @@ class UIManager:
         # Import here to avoid circular dependency
         from . import ui as ui_module
 
-        if isinstance(ui_component, (ui_module.Button, ui_module.Panel, ui_module.Label)):
+        if isinstance(
+            ui_component,
+            (
+                ui_module.Button,
+                ui_module.Panel,
+                ui_module.Label,
+                ui_module.TextInput,
+                ui_module.Slider,
+                ui_module.Checkbox,
+                ui_module.RadioButton,
+                ui_module.ToggleSwitch,
+                ui_module.ScrollView,
+                ui_module.ProgressBar,
+                ui_module.CustomWidget,
+                ui_module.Canvas,
+                ui_module.Image,
+                ui_module.Stack,
+                ui_module.CurveEditor,
+                ui_module.ListView,
+            ),
+        ):
             return self._add_tree(ui_component)
         raise TypeError(
-            f"Expected Button, Panel, or Label, got {type(ui_component).__name__}"
+            f"Expected Button, Panel, Label, TextInput, Slider, Checkbox, RadioButton, ToggleSwitch, ScrollView, ProgressBar, CustomWidget, Canvas, Image, VStack, HStack, CurveEditor, or ListView, got {type(ui_component).__name__}"
         )
 
     def get_id(self, object_id: int) -> Optional[Any]:
@@ -946,8 +1282,365 @@ class UIManager:
             if getattr(obj, "object_type", None) == "UIObject"
         ]
 
+    def set_layout(
+        self,
+        ui_component: Any,
+        anchor: str = "top_left",
+        x: float = 0,
+        y: float = 0,
+        width: Optional[Union[float, str]] = None,
+        height: Optional[Union[float, str]] = None,
+        pivot: Optional[tuple[float, float]] = None,
+    ) -> None:
+        """
+        Anchor a UI element to its parent, or to the window for top-level
+        elements, so it keeps its place when the window is resized.
+
+        The layout replaces the element's position (and size, unless `width`
+        and `height` are None) every frame. It can be set before or after the
+        element is added.
+
+        Args:
+            ui_component: Any UI element accepted by `add()`
+            anchor: "top_left", "top_center", "top_right", "middle_left",
+                "center", "middle_right", "bottom_left", "bottom_center",
+                "bottom_right", or "stretch" to fill the parent
+            x: Horizontal offset from the anchor (inset on both sides for "stretch")
+            y: Vertical offset from the anchor (inset on both sides for "stretch")
+            width: Pixels, a percentage of the parent like "50%", "fill", or
+                None to keep the element's own width
+            height: Same as `width`, for the height
+            pivot: Point of the element placed at the anchor, from (0, 0) top-left
+                to (1, 1) bottom-right; defaults to the anchor's own corner
+
+        Example:
+        ```python
+            pause = Button("Pause", width=100, height=32)
+            engine.ui.add(pause)
+            engine.ui.set_layout(pause, "top_right", x=-10, y=10)
+
+            hud = Panel()
+            engine.ui.add(hud)
+            engine.ui.set_layout(hud, "bottom_center", width="100%", height=80)
+        ```
+        """
+        ui_component._layout = (anchor, x, y, width, height, pivot)
+        object_id = getattr(ui_component, "_object_id", None)
+        if object_id is not None:
+            self._engine._engine.set_ui_layout(object_id, anchor, x, y, width, height, pivot)
+
+    def tween(
+        self,
+        ui_component: Any,
+        property: str,
+        to: Union[float, tuple[float, ...]],
+        duration: float,
+        easing: str = "linear",
+        delay: float = 0.0,
+        from_: Optional[Union[float, tuple[float, ...]]] = None,
+        on_complete: Optional[Callable[[], None]] = None,
+    ) -> int:
+        """
+        Animate a property of an added UI element from its current value to `to`.
+
+        Starting a tween on a property that is already tweening replaces the
+        running tween (its `on_complete` is not called).
+
+        Properties:
+            - "x", "y", "width", "height": the element's bounds. A layout set
+              with `set_layout()` takes over again once the tween ends.
+            - "offset_x", "offset_y": translation on top of the bounds and
+              layout, moving the element's children and hit area with it
+            - "opacity": 0.0 to 1.0 for the element and its children; fully
+              transparent elements ignore input. Images are not faded.
+            - "background_color", "border_color": a panel's colors
+            - "text_color" (or "color"): a label's text color
+
+        Args:
+            ui_component: Any UI element accepted by `add()`, already added
+            property: Property name from the list above
+            to: Target number, or an (r, g, b[, a]) tuple for colors
+            duration: Seconds from start to target
+            easing: "linear", "ease_in", "ease_out", "ease_in_out",
+                "ease_in_quad", "ease_out_quad", "ease_in_out_quad",
+                "ease_out_back" or "ease_out_bounce"
+            delay: Seconds to wait before starting
+            from_: Start value instead of the value the property has when the delay ends
+            on_complete: Called once the property reaches `to`
+
+        Returns:
+            The tween id, for `cancel_tween()`.
+
+        Raises:
+            ValueError: If the element has not been added yet.
+
+        Example:
+        ```python
+            engine.ui.tween(menu, "opacity", 1.0, 0.3, from_=0.0)
+            engine.ui.tween(menu, "offset_y", 0.0, 0.4, easing="ease_out_back", from_=-40.0)
+            engine.ui.tween(toast, "opacity", 0.0, 0.5, delay=2.0,
+                            on_complete=lambda: engine.destroy(toast._object_id))
+        ```
+        """
+        object_id = getattr(ui_component, "_object_id", None)
+        if object_id is None:
+            raise ValueError("Add the UI element with engine.ui.add() before tweening it")
+
+        def values(value: Union[float, tuple[float, ...]]) -> list[float]:
+            return [float(value)] if isinstance(value, (int, float)) else [float(v) for v in value]
+
+        tween_id = self._engine._engine.tween_ui(
+            object_id,
+            property,
+            values(to),
+            duration,
+            easing=easing,
+            delay=delay,
+            from_=None if from_ is None else values(from_),
+        )
+        key = (object_id, _TWEEN_PROPERTY_ALIASES.get(property, property))
+        self._tween_callbacks = {
+            other_id: entry for other_id, entry in self._tween_callbacks.items() if entry[0] != key
+        }
+        if on_complete is not None:
+            self._tween_callbacks[tween_id] = (key, on_complete)
+        return tween_id
+
+    def cancel_tween(self, tween_id: int) -> bool:
+        """
+        Stop a tween, leaving the property at its current value. Its
+        `on_complete` is not called.
+
+        Returns:
+            True if the tween was still running.
+        """
+        self._tween_callbacks.pop(tween_id, None)
+        return self._engine._engine.cancel_ui_tween(tween_id)
+
+    def set_focus(self, ui_component: Optional[Any]) -> bool:
+        """
+        Give an added widget keyboard focus, or clear focus with None.
+
+        Focus set from code or with Tab shows the focus ring; focus from a
+        click does not.
+
+        Returns:
+            False if the widget is hidden, non-interactable, can't take focus
+            (e.g. a label) or hasn't been added.
+        """
+        if ui_component is None:
+            return self._engine._engine.set_ui_focus(None)
+        object_id = getattr(ui_component, "_object_id", None)
+        if object_id is None:
+            return False
+        return self._engine._engine.set_ui_focus(object_id)
+
+    def get_focused(self) -> Optional[Any]:
+        """Get the widget with keyboard focus, or None."""
+        object_id = self._engine._engine.get_ui_focus()
+        if object_id is None:
+            return None
+        return self._elements.get(object_id)
+
+    def set_interactable(self, ui_component: Any, interactable: bool) -> None:
+        """
+        Grey out a widget and its children and stop them taking input, or
+        restore them.
+
+        Unlike disabling, the widget stays visible and keeps its place in the
+        layout. How grey and faded it gets comes from the theme.
+
+        Raises:
+            ValueError: If the element has not been added yet.
+        """
+        object_id = getattr(ui_component, "_object_id", None)
+        if object_id is None:
+            raise ValueError("Add the UI element with engine.ui.add() before changing it")
+        self._engine._engine.set_ui_interactable(object_id, interactable)
+
+    def is_interactable(self, ui_component: Any) -> bool:
+        """Whether the element itself takes input; its parents are not checked."""
+        object_id = getattr(ui_component, "_object_id", None)
+        return object_id is None or self._engine._engine.is_ui_interactable(object_id)
+
+    def set_hover_cursor(self, ui_component: Any, cursor: Optional[str]) -> None:
+        """
+        Show a cursor while the pointer is over a widget or its children.
+
+        Args:
+            ui_component: The widget, already added with `engine.ui.add()`.
+            cursor: A shape name as for `engine.set_cursor()`, e.g. "hand",
+                or None to remove it.
+
+        Raises:
+            ValueError: If the element has not been added yet or the cursor
+                name is unknown.
+
+        Example:
+            ```python
+            engine.ui.set_hover_cursor(play_button, "hand")
+            engine.ui.set_hover_cursor(name_input, "text")
+            ```
+        """
+        object_id = getattr(ui_component, "_object_id", None)
+        if object_id is None:
+            raise ValueError("Add the UI element with engine.ui.add() before changing it")
+        self._engine._engine.set_ui_hover_cursor(object_id, cursor)
+
+    def get_hover_cursor(self, ui_component: Any) -> Optional[str]:
+        """Hover cursor set on the widget itself as a CSS cursor name, or None."""
+        object_id = getattr(ui_component, "_object_id", None)
+        if object_id is None:
+            return None
+        return self._engine._engine.get_ui_hover_cursor(object_id)
+
+    def set_input_context(self, ui_component: Any, context: Optional[str]) -> None:
+        """
+        Make a widget and its children take input only while `context` is the
+        top input context (see `engine.input.push_context()`).
+
+        Args:
+            ui_component: The widget, already added with `engine.ui.add()`.
+            context: A context name such as "menu", or None to take input in
+                any context.
+
+        Raises:
+            ValueError: If the element has not been added yet.
+
+        Example:
+            ```python
+            engine.ui.set_input_context(pause_menu, "menu")
+            engine.ui.set_input_context(hud, "gameplay")
+            engine.input.push_context("menu")  # only the pause menu responds
+            ```
+        """
+        object_id = getattr(ui_component, "_object_id", None)
+        if object_id is None:
+            raise ValueError("Add the UI element with engine.ui.add() before changing it")
+        self._engine._engine.set_ui_input_context(object_id, context)
+
+    def get_input_context(self, ui_component: Any) -> Optional[str]:
+        """Input context set on the widget itself, or None."""
+        object_id = getattr(ui_component, "_object_id", None)
+        if object_id is None:
+            return None
+        return self._engine._engine.get_ui_input_context(object_id)
+
+    def set_focus_ring(self, width: float = 2.0, offset: float = 2.0, corner_radius: float = 4.0) -> None:
+        """
+        Set the outline drawn around the focused widget.
+
+        Args:
+            width: Line width; 0 hides the ring
+            offset: Gap between the widget and the ring
+            corner_radius: Corner radius of the ring
+
+        The ring color is the theme's "focus_ring" color.
+        """
+        self._engine._engine.set_ui_focus_ring(width, offset, corner_radius)
+
+    def load(
+        self,
+        source: Union[str, Path, dict, list],
+        callbacks: Optional[dict[str, Callable[..., Any]]] = None,
+        add: bool = True,
+    ) -> "UIDocument":
+        """
+        Build a widget tree from a declarative UI document.
+
+        `source` is a path to a `.json` file (or `.yaml`/`.yml` with PyYAML
+        installed), or an already parsed document. The document holds a
+        `"widgets"` list; each widget is an object with:
+
+        - `"type"`: `button`, `panel`, `label`, `text_input`, `slider`,
+          `checkbox`, `toggle_switch`, `radio_button`, `scroll_view`,
+          `progress_bar`, `image`, `vstack`, `hstack`, `curve_editor` or
+          `list_view`
+        - `"id"` (optional): key for the widget in the returned document
+        - Constructor arguments of the matching class, e.g. `"text"`, `"x"`,
+          `"width"`. Callback arguments (`on_*` and `get_item`) name an entry
+          in `callbacks`; a radio button's `"group"` names a group shared by
+          the document.
+        - `"style"` (optional): calls the widget's `set_<name>()` methods, with
+          lists passed as arguments, e.g. `"background_color": [0.1, 0.1, 0.1]`
+          or `"border": [2, 0.5, 0.5, 0.5]`
+        - `"layout"` (optional): arguments for `set_layout()`
+        - `"children"` (optional): child widgets of panels, scroll views and stacks
+
+        With `engine.assets.hot_reload` on, a document loaded from a file is
+        rebuilt in place when the file changes, see `UIDocument`.
+
+        Args:
+            source: Document path or parsed document
+            callbacks: Callbacks referenced by name from the document
+            add: Add the top-level widgets to the engine
+
+        Returns:
+            A UIDocument mapping ids to widgets, with the top-level widgets in `roots`.
+
+        Raises:
+            ValueError: If the document is malformed; the message names the widget.
+
+        Example:
+            ```python
+            # ui/main_menu.json
+            # {
+            #   "widgets": [{
+            #     "type": "panel", "id": "menu", "width": 300, "height": 220,
+            #     "layout": {"anchor": "center"},
+            #     "style": {"background_color": [0.1, 0.1, 0.12, 0.9]},
+            #     "children": [
+            #       {"type": "label", "id": "title", "text": "My Game", "x": 20, "y": 20, "font_size": 28},
+            #       {"type": "button", "id": "play", "text": "Play", "x": 20, "y": 90, "width": 260, "height": 40},
+            #       {"type": "button", "text": "Quit", "x": 20, "y": 150, "width": 260, "height": 40,
+            #        "on_click": "quit"}
+            #     ]
+            #   }]
+            # }
+            menu = engine.ui.load("ui/main_menu.json", callbacks={"quit": engine.request_quit})
+            menu["play"].set_on_click(start_game)
+            ```
+        """
+        from .ui_loader import build_document, read_document
+
+        document = source if isinstance(source, (dict, list)) else read_document(source)
+        loaded = build_document(self, document, callbacks)
+        if add:
+            for root in loaded.roots:
+                self.add(root)
+        if not isinstance(source, (dict, list)):
+            loaded._watch(Path(source), callbacks or {}, add)
+            self._documents.append(weakref.ref(loaded))
+        return loaded
+
+    def _reload_changed_documents(self) -> list[str]:
+        """Rebuild loaded documents whose file changed. Returns their paths."""
+        reloaded = []
+        live = []
+        for reference in self._documents:
+            document = reference()
+            if document is None:
+                continue
+            live.append(reference)
+            if document._changed() and document._reload(self):
+                reloaded.append(str(document.path))
+        self._documents = live
+        return reloaded
+
+    def clear_layout(self, ui_component: Any) -> None:
+        """Remove an element's layout; it keeps its last position and size."""
+        ui_component._layout = None
+        object_id = getattr(ui_component, "_object_id", None)
+        if object_id is not None:
+            self._engine._engine.clear_ui_layout(object_id)
+
     def _add_tree(self, ui_component: Any) -> Optional[int]:
         object_id = self._add_single(ui_component)
+        if object_id is not None:
+            self._elements[object_id] = ui_component
+        layout = getattr(ui_component, "_layout", None)
+        if object_id is not None and layout is not None:
+            self._engine._engine.set_ui_layout(object_id, *layout)
         for child in list(getattr(ui_component, "_children", [])):
             self._add_tree(child)
             ui_component._game_object.add_child(child._game_object)
@@ -962,8 +1655,34 @@ class UIManager:
             return self._add_panel(ui_component)
         if isinstance(ui_component, ui_module.Label):
             return self._add_label(ui_component)
+        if isinstance(ui_component, ui_module.TextInput):
+            return self._add_text_input(ui_component)
+        if isinstance(ui_component, ui_module.Slider):
+            return self._add_slider(ui_component)
+        if isinstance(ui_component, ui_module.Checkbox):
+            return self._add_toggle(ui_component, "Checkbox")
+        if isinstance(ui_component, ui_module.RadioButton):
+            return self._add_toggle(ui_component, "RadioButton")
+        if isinstance(ui_component, ui_module.ToggleSwitch):
+            return self._add_toggle(ui_component, "ToggleSwitch")
+        if isinstance(ui_component, ui_module.ScrollView):
+            return self._add_scroll_view(ui_component)
+        if isinstance(ui_component, ui_module.ProgressBar):
+            return self._add_progress_bar(ui_component)
+        if isinstance(ui_component, ui_module.CustomWidget):
+            return self._add_custom_widget(ui_component)
+        if isinstance(ui_component, ui_module.Canvas):
+            return self._add_canvas(ui_component)
+        if isinstance(ui_component, ui_module.Image):
+            return self._add_image(ui_component)
+        if isinstance(ui_component, ui_module.Stack):
+            return self._add_stack(ui_component)
+        if isinstance(ui_component, ui_module.CurveEditor):
+            return self._add_curve_editor(ui_component)
+        if isinstance(ui_component, ui_module.ListView):
+            return self._add_list_view(ui_component)
         raise TypeError(
-            f"Expected Button, Panel, or Label, got {type(ui_component).__name__}"
+            f"Expected Button, Panel, Label, TextInput, Slider, Checkbox, RadioButton, ToggleSwitch, ScrollView, ProgressBar, CustomWidget, Canvas, Image, VStack, HStack, CurveEditor, or ListView, got {type(ui_component).__name__}"
         )
 
     def _add_button(self, button: Any) -> Optional[int]:
@@ -994,6 +1713,8 @@ class UIManager:
         if getattr(panel, "_object_id", None) is not None:
             return panel._object_id
 
+        panel._engine = self._engine.get_handle()
+        panel._ui = self
         panel._game_object = GameObject()
         panel._game_object.set_name("Panel")
         panel._game_object.set_object_type("UIObject")
@@ -1017,43 +1738,226 @@ class UIManager:
         label._object_id = self._engine.add_game_object(label._game_object)
         return label._object_id
 
+    def _add_text_input(self, text_input: Any) -> Optional[int]:
+        """Internal: Add a TextInput to the engine."""
+        from .pyg_engine_native import GameObject
 
-class Input:
-    """
-    Handles keyboard, mouse, and joystick input.
+        if getattr(text_input, "_object_id", None) is not None:
+            return text_input._object_id
 
-    This class provides methods to check the current state of input devices
-    and events. It is accessed via the `engine.input` property.
+        text_input._engine = self._engine.get_handle()
+        text_input._game_object = GameObject()
+        text_input._game_object.set_name("TextInput")
+        text_input._game_object.set_object_type("UIObject")
+        text_input._game_object.add_component(text_input._component)
+        text_input._object_id = self._engine.add_game_object(text_input._game_object)
+        return text_input._object_id
 
-    Example:
-        ```python
-        from pyg_engine import Engine, MouseButton
+    def _add_slider(self, slider: Any) -> Optional[int]:
+        """Internal: Add a Slider to the engine."""
+        from .pyg_engine_native import GameObject
 
-        engine = Engine()
+        if getattr(slider, "_object_id", None) is not None:
+            return slider._object_id
 
-        def update(ctx):
-            # Check if space key is pressed this frame
-            if ctx.input.key_pressed("Space"):
-                print("Jump!")
+        slider._engine = self._engine.get_handle()
+        slider._game_object = GameObject()
+        slider._game_object.set_name("Slider")
+        slider._game_object.set_object_type("UIObject")
+        slider._game_object.add_component(slider._component)
+        slider._object_id = self._engine.add_game_object(slider._game_object)
+        return slider._object_id
 
-            # Check if W key is held down
-            if ctx.input.key_down("W"):
-                print("Moving forward")
+    def _add_curve_editor(self, editor: Any) -> Optional[int]:
+        """Internal: Add a CurveEditor to the engine."""
+        from .pyg_engine_native import GameObject
 
-            # Check mouse button
-            if ctx.input.mouse_button_pressed(MouseButton.Left):
-                x, y = ctx.input.mouse_position
-                print(f"Clicked at {x}, {y}")
+        if getattr(editor, "_object_id", None) is not None:
+            return editor._object_id
 
-        engine.run(update=update)
-        ```
-    """
-    def __init__(self, engine: "Engine") -> None:
-        self._engine = engine._engine
+        editor._engine = self._engine.get_handle()
+        editor._game_object = GameObject()
+        editor._game_object.set_name("CurveEditor")
+        editor._game_object.set_object_type("UIObject")
+        editor._game_object.add_component(editor._component)
+        editor._object_id = self._engine.add_game_object(editor._game_object)
+        return editor._object_id
 
-    def key_down(self, key: str) -> bool:
-        """
-        Check if a keyboard key is currently held down.
+    def _add_toggle(self, toggle: Any, name: str) -> Optional[int]:
+        """Internal: Add a Checkbox, RadioButton or ToggleSwitch to the engine."""
+        from .pyg_engine_native import GameObject
+
+        if getattr(toggle, "_object_id", None) is not None:
+            return toggle._object_id
+
+        toggle._engine = self._engine.get_handle()
+        toggle._game_object = GameObject()
+        toggle._game_object.set_name(name)
+        toggle._game_object.set_object_type("UIObject")
+        toggle._game_object.add_component(toggle._component)
+        toggle._object_id = self._engine.add_game_object(toggle._game_object)
+        return toggle._object_id
+
+    def _add_scroll_view(self, scroll_view: Any) -> Optional[int]:
+        """Internal: Add a ScrollView to the engine."""
+        from .pyg_engine_native import GameObject
+
+        if getattr(scroll_view, "_object_id", None) is not None:
+            return scroll_view._object_id
+
+        scroll_view._engine = self._engine.get_handle()
+        scroll_view._ui = self
+        scroll_view._game_object = GameObject()
+        scroll_view._game_object.set_name("ScrollView")
+        scroll_view._game_object.set_object_type("UIObject")
+        scroll_view._game_object.add_component(scroll_view._component)
+        scroll_view._object_id = self._engine.add_game_object(scroll_view._game_object)
+        return scroll_view._object_id
+
+    def _add_list_view(self, list_view: Any) -> Optional[int]:
+        """Internal: Add a ListView to the engine."""
+        from .pyg_engine_native import GameObject
+
+        if getattr(list_view, "_object_id", None) is not None:
+            return list_view._object_id
+
+        list_view._engine = self._engine.get_handle()
+        list_view._ui = self
+        list_view._game_object = GameObject()
+        list_view._game_object.set_name("ListView")
+        list_view._game_object.set_object_type("UIObject")
+        list_view._game_object.add_component(list_view._component)
+        list_view._object_id = self._engine.add_game_object(list_view._game_object)
+        return list_view._object_id
+
+    def _sync_list_views(self) -> None:
+        """Internal: Add and remove list view row elements after the native update."""
+        while self._pending_list_views:
+            list_view = self._pending_list_views.pop()
+            if list_view._object_id is not None:
+                list_view._sync_row_elements()
+
+    def _sync_tweens(self) -> None:
+        """Internal: Call `on_complete` for the tweens that ended during the native update."""
+        for tween_id in self._engine._engine.take_finished_ui_tweens():
+            entry = self._tween_callbacks.pop(tween_id, None)
+            if entry is not None:
+                entry[1]()
+
+    def _add_progress_bar(self, progress_bar: Any) -> Optional[int]:
+        """Internal: Add a ProgressBar to the engine."""
+        from .pyg_engine_native import GameObject
+
+        if getattr(progress_bar, "_object_id", None) is not None:
+            return progress_bar._object_id
+
+        progress_bar._engine = self._engine.get_handle()
+        progress_bar._game_object = GameObject()
+        progress_bar._game_object.set_name("ProgressBar")
+        progress_bar._game_object.set_object_type("UIObject")
+        progress_bar._game_object.add_component(progress_bar._component)
+        progress_bar._object_id = self._engine.add_game_object(progress_bar._game_object)
+        return progress_bar._object_id
+
+    def _add_custom_widget(self, widget: Any) -> Optional[int]:
+        """Internal: Add a CustomWidget to the engine."""
+        from .pyg_engine_native import GameObject
+
+        if getattr(widget, "_object_id", None) is not None:
+            return widget._object_id
+
+        widget._engine = self._engine.get_handle()
+        widget._ui = self
+        widget._game_object = GameObject()
+        widget._game_object.set_name(type(widget).__name__)
+        widget._game_object.set_object_type("UIObject")
+        widget._game_object.add_component(widget._component)
+        widget._object_id = self._engine.add_game_object(widget._game_object)
+        return widget._object_id
+
+    def _add_canvas(self, canvas: Any) -> Optional[int]:
+        """Internal: Add a Canvas to the engine."""
+        from .pyg_engine_native import GameObject
+
+        if getattr(canvas, "_object_id", None) is not None:
+            return canvas._object_id
+
+        canvas._engine = self._engine.get_handle()
+        canvas._game_object = GameObject()
+        canvas._game_object.set_name("Canvas")
+        canvas._game_object.set_object_type("UIObject")
+        canvas._game_object.add_component(canvas._component)
+        canvas._object_id = self._engine.add_game_object(canvas._game_object)
+        return canvas._object_id
+
+    def _add_image(self, image: Any) -> Optional[int]:
+        """Internal: Add an Image to the engine."""
+        from .pyg_engine_native import GameObject
+
+        if getattr(image, "_object_id", None) is not None:
+            return image._object_id
+
+        image._engine = self._engine.get_handle()
+        image._game_object = GameObject()
+        image._game_object.set_name("Image")
+        image._game_object.set_object_type("UIObject")
+        image._game_object.add_component(image._component)
+        image._object_id = self._engine.add_game_object(image._game_object)
+        return image._object_id
+
+    def _add_stack(self, stack: Any) -> Optional[int]:
+        """Internal: Add a VStack or HStack to the engine."""
+        from .pyg_engine_native import GameObject
+
+        if getattr(stack, "_object_id", None) is not None:
+            return stack._object_id
+
+        stack._engine = self._engine.get_handle()
+        stack._ui = self
+        stack._game_object = GameObject()
+        stack._game_object.set_name(type(stack).__name__)
+        stack._game_object.set_object_type("UIObject")
+        stack._game_object.add_component(stack._component)
+        stack._object_id = self._engine.add_game_object(stack._game_object)
+        return stack._object_id
+
+
+class Input:
+    """
+    Handles keyboard, mouse, and joystick input.
+
+    This class provides methods to check the current state of input devices
+    and events. It is accessed via the `engine.input` property.
+
+    Example:
+        ```python
+        from pyg_engine import Engine, MouseButton
+
+        engine = Engine()
+
+        def update(ctx):
+            # Check if space key is pressed this frame
+            if ctx.input.key_pressed("Space"):
+                print("Jump!")
+
+            # Check if W key is held down
+            if ctx.input.key_down("W"):
+                print("Moving forward")
+
+            # Check mouse button
+            if ctx.input.mouse_button_pressed(MouseButton.Left):
+                x, y = ctx.input.mouse_position
+                print(f"Clicked at {x}, {y}")
+
+        engine.run(update=update)
+        ```
+    """
+    def __init__(self, engine: "Engine") -> None:
+        self._engine = engine._engine
+
+    def key_down(self, key: str) -> bool:
+        """
+        Check if a keyboard key is currently held down.
 
         This returns True for every frame while the key is pressed.
 
@@ -1184,6 +2088,19 @@ class Input:
         """
         return self._engine.mouse_button_released(button)
 
+    def any_pressed(self) -> bool:
+        """
+        Check if any key, mouse button or joystick button was pressed this frame.
+
+        Example:
+            ```python
+            # "Press any key to continue"
+            if engine.input.any_pressed():
+                show_main_menu()
+            ```
+        """
+        return self._engine.any_pressed()
+
     @property
     def mouse_position(self) -> tuple[float, float]:
         """
@@ -1259,6 +2176,8 @@ class Input:
         Get the current value of a logical axis (-1.0 to 1.0).
 
         Axes are useful for smooth, analog-style input from keyboard or gamepad.
+        Smoothed axes (see `set_axis_smoothing`) ramp toward the input over
+        time; use `axis_raw` for the unfiltered value.
 
         Args:
             name: The axis name (e.g., "horizontal", "vertical").
@@ -1305,6 +2224,65 @@ class Input:
         """
         return self._engine.axis_previous(name)
 
+    def axis_raw(self, name: str) -> float:
+        """
+        Get the current value of a logical axis without smoothing.
+
+        `axis()` ramps smoothed axes over time; `axis_raw()` returns the input
+        as-is, so keyboard axes read exactly -1, 0, or 1. Use it for
+        menus or anything that should react instantly.
+
+        Args:
+            name: The axis name to query.
+
+        Returns:
+            The unsmoothed axis value (-1.0 to 1.0).
+        """
+        return self._engine.axis_raw(name)
+
+    def set_axis_smoothing(
+        self,
+        name: str,
+        gravity: float = 3.0,
+        sensitivity: float = 3.0,
+        snap: bool = True,
+    ) -> bool:
+        """
+        Smooth a logical axis over time (like Unity's `GetAxis`).
+
+        "Horizontal" and "Vertical" are smoothed by default with these values.
+        Only keyboard input ramps; gamepad sticks and mouse movement bound to
+        the axis are added on top as they are.
+
+        Args:
+            name: The axis name to configure.
+            gravity: Units per second the value falls back to 0 once input is released.
+            sensitivity: Units per second the value moves toward the pressed direction.
+            snap: If True, reversing direction jumps through 0 instead of ramping.
+
+        Returns:
+            False if the axis does not exist.
+
+        Example:
+            ```python
+            # Snappier movement
+            engine.input.set_axis_smoothing("Horizontal", gravity=8.0, sensitivity=6.0)
+            ```
+        """
+        return self._engine.set_axis_smoothing(name, gravity, sensitivity, snap)
+
+    def clear_axis_smoothing(self, name: str) -> bool:
+        """Remove smoothing so `axis(name)` matches `axis_raw(name)`. False if the axis does not exist."""
+        return self._engine.clear_axis_smoothing(name)
+
+    def get_axis_smoothing(self, name: str) -> Optional[dict[str, Any]]:
+        """Get an axis's smoothing as {"gravity", "sensitivity", "snap"}, or None if unsmoothed."""
+        smoothing = self._engine.get_axis_smoothing(name)
+        if smoothing is None:
+            return None
+        gravity, sensitivity, snap = smoothing
+        return {"gravity": gravity, "sensitivity": sensitivity, "snap": snap}
+
     def axis_names(self) -> list[str]:
         """List all configured logical axis names."""
         return self._engine.axis_names()
@@ -1361,6 +2339,83 @@ class Input:
         """Check whether an action was released this frame."""
         return self._engine.action_released(action_name)
 
+    def action_buffered(self, action_name: str, window: float) -> bool:
+        """
+        Check whether an action was pressed within the last `window` seconds.
+
+        Buffering lets a jump pressed just before landing still happen. A press
+        this frame counts too. Use `consume_buffered_action()` to act on a
+        buffered press only once.
+
+        Example:
+            ```python
+            if player.grounded and engine.input.consume_buffered_action("jump", 0.12):
+                player.jump()
+            ```
+        """
+        return self._engine.action_buffered(action_name, window)
+
+    def consume_buffered_action(self, action_name: str, window: float) -> bool:
+        """Like `action_buffered()`, but forget the press so it triggers only once."""
+        return self._engine.consume_buffered_action(action_name, window)
+
+    def set_context_actions(self, context: str, actions: list[str]) -> None:
+        """
+        Put actions in a named input context such as "gameplay" or "menu".
+
+        While a context is pushed, actions that belong to contexts only work
+        when one of them is the top context, so the same keys can drive
+        different actions in different game states. Actions in no context
+        always work, and every action works while no context is pushed.
+
+        Example:
+            ```python
+            engine.input.set_action_keys("confirm", ["space", "enter"])
+            engine.input.set_context_actions("gameplay", ["jump", "fire1"])
+            engine.input.set_context_actions("menu", ["confirm"])
+            engine.input.push_context("gameplay")
+            ...
+            engine.input.push_context("menu")  # Space confirms instead of jumping
+            ```
+        """
+        self._engine.set_input_context_actions(context, actions)
+
+    def context_actions(self, context: str) -> list[str]:
+        """Actions in an input context, sorted."""
+        return self._engine.get_input_context_actions(context)
+
+    def push_context(self, context: str) -> None:
+        """
+        Make an input context the top one until it is popped.
+
+        UI elements given a context with `engine.ui.set_input_context()` only
+        take input while it is on top.
+        """
+        self._engine.input_push_context(context)
+
+    def pop_context(self) -> Optional[str]:
+        """Remove the top input context and return it, or None if none was pushed."""
+        return self._engine.input_pop_context()
+
+    @property
+    def context(self) -> Optional[str]:
+        """The top input context, or None when none is pushed."""
+        return self._engine.input_context()
+
+    @property
+    def context_stack(self) -> list[str]:
+        """Pushed input contexts, bottom first."""
+        return self._engine.input_context_stack()
+
+    @property
+    def buffer_duration(self) -> float:
+        """How long action presses are remembered for buffering, in seconds (0.5 by default)."""
+        return self._engine.get_input_buffer_duration()
+
+    @buffer_duration.setter
+    def buffer_duration(self, seconds: float) -> None:
+        self._engine.set_input_buffer_duration(seconds)
+
     def action_names(self) -> list[str]:
         """List all configured action names."""
         return self._engine.action_names()
@@ -1369,6 +2424,49 @@ class Input:
         """Restore default axis and action bindings."""
         self._engine.reset_input_bindings_to_defaults()
 
+    def save_bindings(self, path: str) -> None:
+        """
+        Save every axis binding and action mapping to a JSON file.
+
+        Use this to keep controls the player customized between sessions.
+
+        Args:
+            path: File to write.
+
+        Raises:
+            RuntimeError: If the file cannot be written.
+
+        Example:
+            ```python
+            engine.input.set_action_keys("jump", ["W"])
+            engine.input.save_bindings("controls.json")
+            ```
+        """
+        self._engine.save_input_bindings(path)
+
+    def load_bindings(self, path: str) -> None:
+        """
+        Replace every axis binding and action mapping with ones saved by
+        `save_bindings()`.
+
+        The file is checked in full first; on error the current bindings are kept.
+
+        Args:
+            path: File to read.
+
+        Raises:
+            RuntimeError: If the file cannot be read or is not valid bindings.
+
+        Example:
+            ```python
+            import os
+
+            if os.path.exists("controls.json"):
+                engine.input.load_bindings("controls.json")
+            ```
+        """
+        self._engine.load_input_bindings(path)
+
     def set_axis_keys(
         self,
         name: str,
@@ -1449,6 +2547,24 @@ class Input:
         """Remove one keyboard key from an action binding."""
         return self._engine.remove_action_key(action_name, key)
 
+    def set_action_chords(self, action_name: str, chords: list[str]) -> None:
+        """
+        Set modifier chord bindings for an action (replaces existing chords).
+
+        A chord like "ctrl+s" or "shift+click" fires only while exactly its
+        modifiers are held and its final key or button goes down, so the bare
+        key never triggers it. Raises ValueError for unknown names.
+        """
+        self._engine.set_action_chords(action_name, chords)
+
+    def add_action_chord(self, action_name: str, chord: str) -> None:
+        """Add one modifier chord, e.g. "ctrl+s", to an action binding."""
+        self._engine.add_action_chord(action_name, chord)
+
+    def remove_action_chord(self, action_name: str, chord: str) -> bool:
+        """Remove one modifier chord from an action binding."""
+        return self._engine.remove_action_chord(action_name, chord)
+
     def set_action_mouse_buttons(self, action_name: str, buttons: list[str]) -> None:
         """Set mouse-button bindings for an action (replaces existing mouse buttons)."""
         self._engine.set_action_mouse_buttons(action_name, buttons)
@@ -1465,87 +2581,583 @@ class Input:
         """Clear all bindings (keyboard/mouse/joystick) for an action."""
         self._engine.clear_action_bindings(action_name)
 
+    def action_glyphs(self, action_name: str, device: Optional[str] = None) -> list[dict[str, Any]]:
+        """
+        Get display glyphs for everything currently bound to an action.
 
-class UpdateContext:
-    """
-    Mutable frame context passed to function-based engine update callbacks.
-
-    This object is reused each frame to keep callback overhead low.
-    It provides access to engine systems, timing information, and user data.
+        Args:
+            action_name: The action to describe.
+            device: "keyboard_mouse" or "gamepad". Defaults to the active prompt device.
+                Falls back to the other device if the action has no bindings on it.
 
-    Attributes:
-        engine: The Engine instance for this frame.
-        input: The Input manager (alias for engine.input).
-        delta_time: Time since last frame in seconds (clamped by max_delta_time).
-        elapsed_time: Total time since engine started in seconds.
-        frame: Current frame number (starts at 0).
-        user_data: Arbitrary user data passed to Engine.run().
+        Returns:
+            One dict per binding with keys "label" ("Space"), "text" ("[Space]"),
+            "icon" (built-in atlas icon name or None) and "device".
 
-    Callback Signature Examples:
-        ```python
-        # No arguments - callback takes nothing
-        def update():
-            print("Frame updated")
+        Example:
+            ```python
+            for glyph in engine.input.action_glyphs("jump"):
+                if glyph["icon"]:
+                    engine.draw_input_glyph(glyph["icon"], x, y)
+                else:
+                    engine.draw_text(glyph["text"], x, y, Color.WHITE)
+            ```
+        """
+        return [
+            {"label": label, "text": f"[{label}]", "icon": icon, "device": glyph_device}
+            for label, icon, glyph_device in self._engine.action_glyphs(action_name, device)
+        ]
 
-        # Single argument - receives UpdateContext
-        def update(ctx):
-            print(f"Frame {ctx.frame}, dt={ctx.delta_time}")
+    def action_prompt(
+        self,
+        action_name: str,
+        template: str = "{glyph}",
+        device: Optional[str] = None,
+    ) -> str:
+        """
+        Build prompt text for an action from its current bindings.
 
-        # Named arguments - auto-injected by name
-        def update(dt, engine):
-            player.move(dt)
-            engine.log("Moved player")
+        Args:
+            action_name: The action to describe.
+            template: Format string; "{glyph}" is replaced by e.g. "[Space]" or "[E]/[LMB]".
+            device: "keyboard_mouse" or "gamepad". Defaults to the active prompt device.
 
-        # All supported argument names:
-        def update(ctx, context, engine, input, dt, delta_time,
-                   elapsed, elapsed_time, frame, user_data):
-            pass  # Use any subset of these names
+        Example:
+            ```python
+            label.text = engine.input.action_prompt("interact", "Press {glyph} to open")
+            ```
+        """
+        return template.format(glyph=self._engine.action_prompt(action_name, device))
 
-        # Stopping the loop
-        def update(ctx):
-            if ctx.input.key_pressed("Escape"):
-                ctx.stop()  # Request exit after this frame
-        ```
+    @property
+    def glyph_device(self) -> str:
+        """Device prompts currently target: "keyboard_mouse" or "gamepad"."""
+        return self._engine.input_glyph_device()
 
-    See Also:
-        - `Engine.run()` - Pass update callbacks here
-        - `examples/python_function_update_demo.py` - Complete examples
-    """
+    def set_glyph_device(self, device: Optional[str]) -> bool:
+        """
+        Force prompts to "keyboard_mouse" or "gamepad", or pass None to follow
+        the active device. Returns False for unknown device names.
+        """
+        return self._engine.set_input_glyph_device(device)
 
-    __slots__ = (
-        "engine",
-        "input",
-        "delta_time",
-        "elapsed_time",
-        "frame",
-        "user_data",
-        "_should_stop",
-    )
+    @property
+    def glyph_version(self) -> int:
+        """
+        Counter that changes whenever action bindings or the prompt device change.
 
-    def __init__(self, engine: "Engine", user_data: Any = None) -> None:
-        self.engine = engine
-        self.input = engine.input
-        self.delta_time = 0.0
-        self.elapsed_time = 0.0
-        self.frame = 0
-        self.user_data = user_data
-        self._should_stop = False
+        Example:
+            ```python
+            if engine.input.glyph_version != cached_version:
+                cached_version = engine.input.glyph_version
+                prompt.text = engine.input.action_prompt("jump", "Press {glyph}")
+            ```
+        """
+        return self._engine.input_glyph_version()
 
-    def stop(self) -> None:
-        """Request that `Engine.run(update=...)` exits after this frame."""
-        self._should_stop = True
+    def glyph_icon(self, icon: str) -> Optional[tuple[int, int, bytes]]:
+        """Get a built-in atlas icon as (width, height, rgba_bytes), or None if unknown."""
+        return self._engine.input_glyph_icon(icon)
 
     @property
-    def should_stop(self) -> bool:
-        """Return whether `stop()` has been requested."""
-        return self._should_stop
+    def active_device(self) -> str:
+        """
+        Device the player used most recently: "keyboard_mouse" or "gamepad".
 
+        Key presses, mouse clicks, scrolling, and mouse movement select
+        "keyboard_mouse"; gamepad buttons and stick movement select "gamepad".
+        Prompt glyphs follow this device unless `set_glyph_device` overrides it.
+        """
+        return self._engine.input_active_device()
 
-_CallbackInvoker = Callable[[UpdateContext], object]
-_CallbackValueProvider = Callable[[UpdateContext], object]
+    @property
+    def device_changed(self) -> bool:
+        """
+        True on the frame the active device switched.
 
-_CALLBACK_VALUE_PROVIDERS: dict[str, _CallbackValueProvider] = {
-    "ctx": lambda context: context,
+        Example:
+            ```python
+            def update(ctx):
+                if ctx.input.device_changed:
+                    hint.text = ctx.input.action_prompt("jump", "Press {glyph}")
+            ```
+        """
+        return self._engine.input_active_device_changed()
+
+    def report_device_activity(self, device: str) -> bool:
+        """
+        Report input from a device the engine does not read itself.
+
+        Use this when polling a gamepad through another library. The switch is
+        applied on the next frame. Returns False for unknown device names.
+        """
+        return self._engine.report_input_device_activity(device)
+
+    def reset(self) -> None:
+        """
+        Release every held key, mouse button and gamepad button and zero all axes.
+
+        Release events fire for anything that was held, so `key_released(...)`
+        and `action_released(...)` report a normal release. Call this when
+        input may be stuck, e.g. when opening a pause menu mid-press.
+        """
+        self._engine.input_reset()
+
+    def player(self, number: int) -> "PlayerInput":
+        """
+        Get input for one local player (1-4), reading only that player's device.
+
+        Player 1 starts on keyboard and mouse; gamepads fill the next empty
+        slot as they connect, and an unplugged gamepad frees its slot.
+
+        Example:
+            ```python
+            def update(ctx):
+                for number in (1, 2):
+                    player = ctx.input.player(number)
+                    heroes[number].x += player.axis("Horizontal") * speed * ctx.delta_time
+                    if player.action_pressed("jump"):
+                        heroes[number].jump()
+            ```
+        """
+        if not 1 <= number <= 4:
+            raise ValueError(f"Player number must be 1-4, got {number}")
+        return PlayerInput(self._engine, number)
+
+    def assign_player(
+        self, number: int, device: Optional[str], joystick_id: Optional[int] = None
+    ) -> bool:
+        """
+        Assign a device to a player, or empty the slot with `device=None`.
+
+        Args:
+            number: Player number (1-4).
+            device: "keyboard_mouse", "keyboard_left", "keyboard_right", "gamepad", or None.
+            joystick_id: Joystick id, required for "gamepad".
+
+        Returns:
+            False if `number` is out of range.
+
+        Example:
+            ```python
+            # Two players sharing one keyboard: WASD vs. arrow keys.
+            engine.input.assign_player(1, "keyboard_left")
+            engine.input.assign_player(2, "keyboard_right")
+            ```
+        """
+        return self._engine.input_assign_player(number, device, joystick_id)
+
+    def assign_gamepad(self, player: int, joystick_id: int) -> bool:
+        """
+        Give a player (1-4) a gamepad, taking it from any other player.
+
+        Returns:
+            False if `player` is out of range.
+        """
+        return self._engine.input_assign_player(player, "gamepad", joystick_id)
+
+    def player_for_joystick(self, joystick_id: int) -> Optional[int]:
+        """Get the player number that owns a joystick, or None."""
+        return self._engine.input_player_for_joystick(joystick_id)
+
+    def events(self) -> list["InputEvent"]:
+        """
+        Get this frame's raw input events, in arrival order.
+
+        Each `InputEvent` has a `type` such as "key_pressed", "mouse_wheel" or
+        "joystick_button_pressed", the fields that apply to it, and the
+        `actions` it triggers. Use it for event-driven code instead of polling
+        every key; the list is replaced on each native update.
+
+        Example:
+            ```python
+            for ev in engine.input.events():
+                if ev.type == "key_pressed" and ev.key == "escape":
+                    toggle_pause()
+                elif "jump" in ev.actions:
+                    player.jump()
+            ```
+        """
+        return self._engine.input_events()
+
+    def inject_key(self, key: str, pressed: bool = True) -> None:
+        """
+        Press or release a key as if it came from the keyboard.
+
+        Injected input goes through the same pipeline as real devices, so key
+        and action queries, `events()`, the UI and device switching all see
+        it. Use it to drive headless tests, bots and tutorials. A press and a
+        release injected before the same native update cancel out, so release
+        on a later frame.
+
+        Example:
+            ```python
+            engine.input.inject_key("space")
+            engine.update()
+            engine.input.inject_key("space", False)
+            ```
+        """
+        self._engine.input_inject_key(key, pressed)
+
+    def inject_text(self, text: str) -> None:
+        """Deliver typed text, as the keyboard does alongside key presses."""
+        self._engine.input_inject_text(text)
+
+    def inject_mouse_button(self, button: str, pressed: bool = True) -> None:
+        """Press or release a mouse button ("left", "right" or "middle")."""
+        self._engine.input_inject_mouse_button(button, pressed)
+
+    def inject_mouse_move(self, x: float, y: float) -> None:
+        """Move the mouse cursor to a window position in pixels."""
+        self._engine.input_inject_mouse_move(x, y)
+
+    def inject_mouse_wheel(self, delta_x: float, delta_y: float) -> None:
+        """Scroll the mouse wheel by the given number of lines."""
+        self._engine.input_inject_mouse_wheel(delta_x, delta_y)
+
+    def inject_joystick_button(self, joystick_id: int, button_id: int, pressed: bool = True) -> None:
+        """Press or release a gamepad button, connecting the gamepad if it is new."""
+        self._engine.input_inject_joystick_button(joystick_id, button_id, pressed)
+
+    def inject_joystick_axis(self, joystick_id: int, axis_id: int, value: float) -> None:
+        """Move a gamepad axis (-1.0 to 1.0), connecting the gamepad if it is new."""
+        self._engine.input_inject_joystick_axis(joystick_id, axis_id, value)
+
+    @property
+    def typed_text(self) -> str:
+        """
+        Text typed this frame, including text committed by an input method (IME).
+
+        Use it for text entry instead of key presses: it follows the keyboard
+        layout, dead keys and composed characters such as Japanese or Chinese
+        input. Outside UI text inputs, call `engine.set_ime_allowed(True)` so
+        input methods can compose.
+
+        Example:
+            ```python
+            console_line += engine.input.typed_text
+            ```
+        """
+        return self._engine.input_typed_text()
+
+    @property
+    def composition(self) -> str:
+        """
+        Text an input method is composing but has not committed yet, e.g. kana
+        awaiting conversion. Empty when nothing is being composed. Show it at
+        the caret, underlined, until it arrives in `typed_text`.
+        """
+        return self._engine.input_composition()
+
+    @property
+    def composition_cursor(self) -> Optional[tuple[int, int]]:
+        """Character range of the input method's cursor within `composition`, or None."""
+        return self._engine.input_composition_cursor()
+
+    @property
+    def joysticks(self) -> list[int]:
+        """Ids of the connected joysticks, sorted."""
+        return self._engine.input_joysticks()
+
+    @property
+    def joysticks_connected(self) -> list[int]:
+        """
+        Ids of the joysticks plugged in this frame.
+
+        Controllers already connected at startup are reported on the first frame.
+
+        Example:
+            ```python
+            for joystick_id in engine.input.joysticks_connected:
+                print(f"{engine.input.joystick_name(joystick_id)} connected")
+            for joystick_id in engine.input.joysticks_disconnected:
+                show_reconnect_prompt(joystick_id)
+            ```
+        """
+        return self._engine.input_joystick_connection_changes(True)
+
+    @property
+    def joysticks_disconnected(self) -> list[int]:
+        """Ids of the joysticks unplugged this frame. Their buttons are released first."""
+        return self._engine.input_joystick_connection_changes(False)
+
+    def joystick_name(self, joystick_id: int) -> Optional[str]:
+        """Get the name a controller reports, e.g. "Xbox Wireless Controller"."""
+        return self._engine.input_joystick_name(joystick_id)
+
+    @property
+    def gamepads_available(self) -> bool:
+        """
+        Whether the engine reads game controllers itself.
+
+        This needs a native build with the `gamepad` feature. Without it,
+        joystick state can still be fed from another library.
+        """
+        return self._engine.input_gamepads_available()
+
+    def joystick_button_down(self, joystick_id: int, button: int) -> bool:
+        """
+        Check if a joystick button is held.
+
+        Buttons use the standard layout: 0-3 south/east/west/north face
+        buttons, 4-5 bumpers, 6-7 triggers, 8 back, 9 start, 10-11 stick
+        clicks, 12-15 d-pad up/down/left/right and 16 the guide button.
+        """
+        return self._engine.input_joystick_button_down(joystick_id, button)
+
+    def joystick_button_pressed(self, joystick_id: int, button: int) -> bool:
+        """Check if a joystick button was pressed this frame."""
+        return self._engine.input_joystick_button_pressed(joystick_id, button)
+
+    def joystick_button_released(self, joystick_id: int, button: int) -> bool:
+        """Check if a joystick button was released this frame."""
+        return self._engine.input_joystick_button_released(joystick_id, button)
+
+    def joystick_axis(self, joystick_id: int, axis: int) -> float:
+        """
+        Get a joystick axis value from -1.0 to 1.0, without deadzone.
+
+        Axes are 0-1 left stick X/Y, 2-3 right stick X/Y (up is positive) and
+        4-5 the left and right triggers (0.0 to 1.0).
+        """
+        return self._engine.input_joystick_axis(joystick_id, axis)
+
+    def trigger(self, joystick_id: int, trigger: str) -> float:
+        """
+        Get how far a gamepad trigger ("left" or "right") is pulled, from 0.0 to 1.0.
+
+        Travel inside the trigger's deadzone reads 0.0 and the rest is
+        rescaled so a full pull reads 1.0. Past half travel the trigger also
+        presses its button, which the default "fire1" (right) and "fire2"
+        (left) actions are bound to.
+
+        Example:
+            ```python
+            throttle = engine.input.trigger(0, "right")
+            brake = engine.input.trigger(0, "left")
+            ```
+        """
+        return self._engine.input_joystick_trigger(joystick_id, trigger)
+
+    def set_trigger_deadzone(self, trigger: str, deadzone: float) -> None:
+        """Set the deadzone of the "left" or "right" trigger (0.1 by default)."""
+        self._engine.set_input_trigger_deadzone(trigger, deadzone)
+
+    def get_trigger_deadzone(self, trigger: str) -> float:
+        """Get the deadzone of the "left" or "right" trigger."""
+        return self._engine.get_input_trigger_deadzone(trigger)
+
+    @property
+    def auto_assign_gamepads(self) -> bool:
+        """Whether newly connected gamepads fill the first empty player slot."""
+        return self._engine.get_input_auto_assign_gamepads()
+
+    @auto_assign_gamepads.setter
+    def auto_assign_gamepads(self, enabled: bool) -> None:
+        self._engine.set_input_auto_assign_gamepads(enabled)
+
+    @property
+    def assign_gamepads_on_press(self) -> bool:
+        """
+        Whether gamepads join on their first button press instead of on connect.
+
+        Off by default. With it on, a controller that is plugged in but unused
+        doesn't take a player slot.
+
+        Example:
+            ```python
+            engine.input.assign_gamepads_on_press = True
+
+            def update(ctx):
+                for number in (2, 3, 4):
+                    if ctx.input.player(number).connected and number not in heroes:
+                        heroes[number] = spawn_hero(number)
+            ```
+        """
+        return self._engine.get_input_assign_gamepads_on_press()
+
+    @assign_gamepads_on_press.setter
+    def assign_gamepads_on_press(self, enabled: bool) -> None:
+        self._engine.set_input_assign_gamepads_on_press(enabled)
+
+    @property
+    def debug_overlay(self) -> bool:
+        """
+        Whether the input debug overlay is shown.
+
+        The overlay draws live axis values, held keys and buttons, the active
+        device, player slot devices and recent action presses/releases in the
+        top-left corner. Use it to find out why a binding does not fire.
+
+        Example:
+            ```python
+            engine.input.debug_overlay = True
+            engine.input.debug_overlay_toggle_key = "F3"  # flip it while playing
+            ```
+        """
+        return self._engine.get_input_debug_overlay()
+
+    @debug_overlay.setter
+    def debug_overlay(self, enabled: bool) -> None:
+        self._engine.set_input_debug_overlay(enabled)
+
+    @property
+    def debug_overlay_toggle_key(self) -> Optional[str]:
+        """Key that shows or hides the input debug overlay, or None for no hotkey."""
+        return self._engine.get_input_debug_overlay_toggle_key()
+
+    @debug_overlay_toggle_key.setter
+    def debug_overlay_toggle_key(self, key: Optional[str]) -> None:
+        self._engine.set_input_debug_overlay_toggle_key(key)
+
+
+class PlayerInput:
+    """
+    Input for one local player, returned by `engine.input.player(number)`.
+
+    Axis and action queries see only the player's own device, so two players
+    on one keyboard or on separate gamepads never read each other's input.
+    """
+
+    def __init__(self, engine: Any, number: int) -> None:
+        self._engine = engine
+        self._number = number
+
+    @property
+    def number(self) -> int:
+        """Player number (1-4)."""
+        return self._number
+
+    @property
+    def device(self) -> Optional[str]:
+        """
+        Device kind ("keyboard_mouse", "keyboard_left", "keyboard_right",
+        "gamepad"), or None if the slot is empty.
+        """
+        device = self._engine.input_player_device(self._number)
+        return device[0] if device is not None else None
+
+    @property
+    def joystick_id(self) -> Optional[int]:
+        """Joystick id when the player uses a gamepad, otherwise None."""
+        device = self._engine.input_player_device(self._number)
+        return device[1] if device is not None else None
+
+    @property
+    def connected(self) -> bool:
+        """Whether the player has a device assigned."""
+        return self._engine.input_player_device(self._number) is not None
+
+    def assign(self, device: Optional[str], joystick_id: Optional[int] = None) -> bool:
+        """Assign a device to this player; see `Input.assign_player`."""
+        return self._engine.input_assign_player(self._number, device, joystick_id)
+
+    def axis(self, name: str) -> float:
+        """Get a logical axis (-1.0 to 1.0) from this player's device."""
+        return self._engine.input_player_axis(self._number, name)
+
+    def axis_raw(self, name: str) -> float:
+        """Get a logical axis from this player's device without smoothing."""
+        return self._engine.input_player_axis_raw(self._number, name)
+
+    def trigger(self, trigger: str) -> float:
+        """Get how far a trigger ("left" or "right") is pulled on this player's gamepad, 0.0 to 1.0."""
+        return self._engine.input_player_trigger(self._number, trigger)
+
+    def action_down(self, action_name: str) -> bool:
+        """Check if an action is held on this player's device."""
+        return self._engine.input_player_action_down(self._number, action_name)
+
+    def action_pressed(self, action_name: str) -> bool:
+        """Check if an action was pressed this frame on this player's device."""
+        return self._engine.input_player_action_pressed(self._number, action_name)
+
+    def action_released(self, action_name: str) -> bool:
+        """Check if an action was released this frame on this player's device."""
+        return self._engine.input_player_action_released(self._number, action_name)
+
+
+class UpdateContext:
+    """
+    Mutable frame context passed to function-based engine update callbacks.
+
+    This object is reused each frame to keep callback overhead low.
+    It provides access to engine systems, timing information, and user data.
+
+    Attributes:
+        engine: The Engine instance for this frame.
+        input: The Input manager (alias for engine.input).
+        delta_time: Time since last frame in seconds, scaled by `engine.time_scale`
+            and 0.0 while `engine.paused` (clamped by max_delta_time).
+        elapsed_time: Total time since engine started in seconds.
+        frame: Current frame number (starts at 0).
+        user_data: Arbitrary user data passed to Engine.run().
+
+    Callback Signature Examples:
+        ```python
+        # No arguments - callback takes nothing
+        def update():
+            print("Frame updated")
+
+        # Single argument - receives UpdateContext
+        def update(ctx):
+            print(f"Frame {ctx.frame}, dt={ctx.delta_time}")
+
+        # Named arguments - auto-injected by name
+        def update(dt, engine):
+            player.move(dt)
+            engine.log("Moved player")
+
+        # All supported argument names:
+        def update(ctx, context, engine, input, dt, delta_time,
+                   elapsed, elapsed_time, frame, user_data):
+            pass  # Use any subset of these names
+
+        # Stopping the loop
+        def update(ctx):
+            if ctx.input.key_pressed("Escape"):
+                ctx.stop()  # Request exit after this frame
+        ```
+
+    See Also:
+        - `Engine.run()` - Pass update callbacks here
+        - `examples/python_function_update_demo.py` - Complete examples
+    """
+
+    __slots__ = (
+        "engine",
+        "input",
+        "delta_time",
+        "elapsed_time",
+        "frame",
+        "user_data",
+        "_should_stop",
+    )
+
+    def __init__(self, engine: "Engine", user_data: Any = None) -> None:
+        self.engine = engine
+        self.input = engine.input
+        self.delta_time = 0.0
+        self.elapsed_time = 0.0
+        self.frame = 0
+        self.user_data = user_data
+        self._should_stop = False
+
+    def stop(self) -> None:
+        """Request that `Engine.run(update=...)` exits after this frame."""
+        self._should_stop = True
+
+    @property
+    def should_stop(self) -> bool:
+        """Return whether `stop()` has been requested."""
+        return self._should_stop
+
+
+_CallbackInvoker = Callable[[UpdateContext], object]
+_CallbackValueProvider = Callable[[UpdateContext], object]
+
+_CALLBACK_VALUE_PROVIDERS: dict[str, _CallbackValueProvider] = {
+    "ctx": lambda context: context,
     "context": lambda context: context,
     "engine": lambda context: context.engine,
     "input": lambda context: context.input,
@@ -1738,16 +3350,22 @@ class Engine:
 
     def __init__(
         self,
-        enable_file_logging: bool = False,
+        enable_file_logging: Optional[bool] = None,
         log_directory: Optional[str] = None,
         log_level: Optional[str] = None,
         source_root: Optional[str] = None,
+        config: Union[str, Path, None] = None,
     ) -> None:
         """
         Initialize a new Engine instance with optional logging configuration.
 
+        Settings are read from a `pyg_engine.toml` next to the game's main
+        script, or in the working directory, when there is one: window size,
+        vsync, logging, fixed timestep, frame cap, asset root and collisions.
+        Arguments given here and later calls override the file's values.
+
         Args:
-            enable_file_logging: Enable logging to files (default: False).
+            enable_file_logging: Enable logging to files.
                 Files are rotated daily and stored in the log directory.
             log_directory: Directory path for log files (default: "./logs").
                 Only used if enable_file_logging is True.
@@ -1757,6 +3375,11 @@ class Engine:
                 - "INFO": Info and higher (default)
                 - "WARN": Warnings and errors only
                 - "ERROR": Errors only
+            source_root: Directory relative asset paths resolve against
+                (default: `[assets] root` from the config, else the directory
+                of the game's main script).
+            config: Path of the config file to read instead of looking for
+                `pyg_engine.toml`.
 
         Example:
             ```python
@@ -1771,19 +3394,34 @@ class Engine:
             )
             ```
         """
+        detected_source_root = Path(source_root).expanduser() if source_root else _detect_source_root()
+        if config is None and (detected_source_root / _CONFIG_FILE_NAME).is_file():
+            config = detected_source_root / _CONFIG_FILE_NAME
         self._engine = _RustEngine(
             enable_file_logging=enable_file_logging,
             log_directory=log_directory,
             log_level=log_level,
+            config_path=None if config is None else str(Path(config).expanduser()),
         )
-        detected_source_root = Path(source_root).expanduser() if source_root else _detect_source_root()
-        self._engine.set_source_root(str(detected_source_root.resolve()))
+        if source_root or self._engine.config_asset_root is None:
+            self._engine.set_source_root(str(detected_source_root.resolve()))
         self._input = Input(self)
         self._ui = UIManager(self)
         self._objects = EngineObjects(self)
         self._camera = CameraProxy(self)
+        self._scripts = ScriptReloader(self)
+        self._timers = TimerManager(self)
+        self._jobs = JobManager(self)
+        self._save_data = SaveData(self, app_name_from_path(detected_source_root.resolve()))
+        self._assets = AssetManager(self)
+        self._audio = AudioManager(self)
+        self._scenes = SceneManager(self)
+        self._intro: Optional[IntroSequence] = None
         self._runtime_state = _RUNTIME_STATE_IDLE
         self._window_icon_path: Optional[str] = None
+        self._quit_callbacks: list[Callable[[], Any]] = []
+        # A blocking run() holds the native engine; quitting goes through the queue.
+        self._handle = self._engine.get_handle()
 
     @property
     def input(self) -> Input:
@@ -1805,6 +3443,269 @@ class Engine:
         """
         return self._ui
 
+    def show_message_box(
+        self,
+        title: str,
+        text: str,
+        buttons: Union[list[str], tuple[str, ...]] = ("OK",),
+        on_result: Optional[Callable[[str], None]] = None,
+        width: float = 360,
+        depth: float = 1000,
+    ) -> Any:
+        """
+        Show a modal message box centered in the window.
+
+        The rest of the UI is dimmed and ignores input until one of the
+        buttons is clicked, which closes the box and calls `on_result`
+        with that button's text.
+
+        Args:
+            title: Heading shown at the top of the box
+            text: Message body; newlines start new lines
+            buttons: Button labels, laid out left to right at the bottom
+            on_result: Called with the clicked button's text
+            width: Box width in pixels
+            depth: Rendering depth; keep it above the rest of the UI
+
+        Returns:
+            The modal `Panel`; call its `close()` to dismiss it from code.
+
+        Example:
+        ```python
+            def on_quit(choice):
+                if choice == "Quit":
+                    engine.request_quit()
+
+            engine.show_message_box("Quit?", "Unsaved progress will be lost.",
+                                    ["Cancel", "Quit"], on_result=on_quit)
+        ```
+        """
+        from .ui import Button, HStack, Label, Panel
+
+        if not buttons:
+            raise ValueError("show_message_box needs at least one button")
+        lines = text.split("\n")
+        button_width, button_height, margin = 88.0, 32.0, 16.0
+        height = 48 + len(lines) * 20 + margin + button_height + margin
+
+        panel = Panel(width=width, height=height, depth=depth, modal=True)
+        panel.set_background_color(0.96, 0.96, 0.96, 1.0)
+        panel.set_border(1, 0.6, 0.6, 0.6, 1.0)
+        panel.add_child(Label(title, x=margin, y=12, font_size=18, depth=depth))
+        for index, line in enumerate(lines):
+            panel.add_child(Label(line, x=margin, y=48 + index * 20, font_size=14, depth=depth))
+
+        def chooser(choice: str) -> Callable[[], None]:
+            # Buttons pass the engine handle to one-argument callbacks,
+            # so bind the choice in a closure rather than a default argument.
+            def choose() -> None:
+                panel.close()
+                if on_result is not None:
+                    on_result(choice)
+
+            return choose
+
+        row_width = len(buttons) * button_width + (len(buttons) - 1) * 8
+        row = HStack(
+            x=width - margin - row_width,
+            y=height - margin - button_height,
+            width=row_width,
+            height=button_height,
+            spacing=8,
+            depth=depth,
+        )
+        for choice in buttons:
+            row.add_child(
+                Button(
+                    choice,
+                    width=button_width,
+                    height=button_height,
+                    depth=depth,
+                    on_click=chooser(choice),
+                )
+            )
+        panel.add_child(row)
+
+        self._ui.add(panel)
+        self._ui.set_layout(panel, "center")
+        return panel
+
+    def play_intro(
+        self,
+        slides: list[Union[str, IntroSlide]],
+        *,
+        hold: float = 2.0,
+        fade: float = 0.5,
+        scale: float = 0.6,
+        background: tuple[float, ...] = (0.0, 0.0, 0.0, 1.0),
+        skip: Optional[str] = "slide",
+        preload: Iterable[Union[str, Callable[[], Any]]] = (),
+        preload_per_frame: int = 1,
+        on_finished: Optional[Callable[[], None]] = None,
+    ) -> IntroSequence:
+        """
+        Play logo images with fade transitions before the game starts.
+
+        While the intro plays, `engine.run(update=...)` shows it instead of
+        calling the update callback, and starts calling the callback on the
+        frame it finishes. In a manual loop, `engine.update()` advances it;
+        check `finished` on the returned sequence. The native blocking
+        `engine.run()` loop does not play intros.
+
+        Args:
+            slides: Image paths or `IntroSlide`s, shown in order
+            hold: Seconds each slide stays fully visible
+            fade: Seconds each slide takes to fade in, and again to fade out
+            scale: Largest fraction of the window a logo may cover
+            background: Background color as an (r, g, b[, a]) tuple
+            skip: "slide" to skip the current logo on any key, mouse or gamepad
+                press, "all" to skip the whole intro, or None to disable skipping
+            preload: Texture paths or callables to run while the logos show
+            preload_per_frame: Number of `preload` entries run each frame
+            on_finished: Called once the last slide is gone and preloading is done
+
+        Returns:
+            The IntroSequence, which replaces any intro that was still playing.
+
+        Example:
+            ```python
+            engine.play_intro(
+                ["assets/studio_logo.png", IntroSlide("assets/engine_logo.png", hold=1.0)],
+                preload=["assets/tiles.png", "assets/player.png", load_levels],
+                on_finished=lambda: engine.log_info("Intro done"),
+            )
+            engine.run(update=update)  # update() starts once the intro is over
+            ```
+        """
+        self._intro = IntroSequence(
+            self,
+            slides,
+            hold=hold,
+            fade=fade,
+            scale=scale,
+            background=background,
+            skip=skip,
+            preload=preload,
+            preload_per_frame=preload_per_frame,
+            on_finished=on_finished,
+        )
+        return self._intro
+
+    @property
+    def scripts(self) -> ScriptReloader:
+        """
+        Get the script reloader for hot reloading gameplay modules.
+
+        Returns:
+            ScriptReloader: Watches modules and reimports them on change.
+        """
+        return self._scripts
+
+    @property
+    def timers(self) -> TimerManager:
+        """
+        Get the named timer manager for cooldowns and other countdowns.
+
+        Returns:
+            TimerManager: Counts timers down each frame and can save them.
+        """
+        return self._timers
+
+    @property
+    def jobs(self) -> JobManager:
+        """
+        Get the background job manager.
+
+        Returns:
+            JobManager: Runs jobs on worker threads and dispatches their results.
+        """
+        return self._jobs
+
+    def run_job(
+        self,
+        fn: Callable[..., Any],
+        *args: Any,
+        name: Optional[str] = None,
+        on_complete: Optional[Callable[[Any], Any]] = None,
+        on_error: Optional[Callable[[BaseException], Any]] = None,
+        **kwargs: Any,
+    ) -> JobHandle:
+        """
+        Run `fn(*args, **kwargs)` on a background thread, see `JobManager.run()`.
+
+        `on_complete` is called with the return value, and `on_error` with the
+        exception the job raised, on the main thread in a later `update()`.
+
+        Example:
+            ```python
+            def find_path(start, goal):
+                return astar(grid, start, goal)  # Runs on a worker thread
+
+            def follow(path):
+                enemy.set_path(path)  # Runs in the engine update
+
+            engine.run_job(find_path, enemy_tile, player_tile, on_complete=follow)
+            ```
+        """
+        return self._jobs.run(
+            fn, *args, name=name, on_complete=on_complete, on_error=on_error, **kwargs
+        )
+
+    @property
+    def config_path(self) -> Optional[str]:
+        """Get the path of the `pyg_engine.toml` the engine read, or None."""
+        return self._engine.config_path
+
+    @property
+    def collisions_enabled(self) -> bool:
+        """Get whether collision detection runs each fixed step."""
+        return self._engine.collisions_enabled
+
+    @collisions_enabled.setter
+    def collisions_enabled(self, enabled: bool) -> None:
+        """Turn collision detection on or off; turning it off drops current contacts."""
+        self._engine.collisions_enabled = enabled
+
+    @property
+    def save_data(self) -> SaveData:
+        """
+        Get the persistent save data for values, blobs and autosave slots.
+
+        Returns:
+            SaveData: Saves to a per-user directory named after the game.
+        """
+        return self._save_data
+
+    @property
+    def assets(self) -> AssetManager:
+        """
+        Get the shared asset store for textures, fonts, audio and data files.
+
+        Returns:
+            AssetManager: Loads each file once and reference-counts its handles.
+        """
+        return self._assets
+
+    @property
+    def audio(self) -> AudioManager:
+        """
+        Get the audio manager for playing sounds.
+
+        Returns:
+            AudioManager: Plays WAV files, optionally from a point in the world.
+        """
+        return self._audio
+
+    @property
+    def scenes(self) -> SceneManager:
+        """
+        Get the scene manager for loading and unloading named scenes.
+
+        Returns:
+            SceneManager: Tracks which objects belong to which scene.
+        """
+        return self._scenes
+
     @property
     def objects(self) -> EngineObjects:
         """Get the runtime object lookup facade."""
@@ -1820,12 +3721,73 @@ class Engine:
         """Return whether the engine is currently running in any loop mode."""
         return self._runtime_state != _RUNTIME_STATE_IDLE
 
+    def request_quit(self) -> None:
+        """
+        End the game loop after the current frame.
+
+        `run()` returns, `run(update=...)` stops before the next frame and a
+        manual loop's next `poll_events()` returns False, as when the window
+        is closed. The window closes and `on_quit` callbacks run. The engine
+        keeps its scene and settings and can be started again with `run()` or
+        `start_manual()`.
+
+        Example:
+            ```python
+            menu = engine.ui.load("ui/main_menu.json", callbacks={"quit": engine.request_quit})
+            ```
+        """
+        if self._runtime_state == _RUNTIME_STATE_RUNNING_BLOCKING:
+            self._handle.request_quit()
+        else:
+            self._engine.request_quit()
+
+    @property
+    def quit_requested(self) -> bool:
+        """Return whether the running loop is about to end."""
+        return self._engine.quit_requested
+
+    def on_quit(self, callback: Callable[[], Any]) -> Callable[[], Any]:
+        """
+        Register a callback to run each time a game loop ends.
+
+        It runs however the loop ends: `request_quit()`, the window closing,
+        `UpdateContext.stop()` or an exception. After `run(update=...)` and
+        manual loops it runs before the window closes; after a blocking
+        `run()`, once it returns. Exceptions are handled by the
+        `callback_error_policy`.
+
+        Returns:
+            The callback, so `on_quit` can be used as a decorator.
+
+        Example:
+            ```python
+            @engine.on_quit
+            def save_progress():
+                engine.save_data.save()
+            ```
+        """
+        self._quit_callbacks.append(callback)
+        return callback
+
+    def _end_session(self) -> None:
+        """Leave the loop mode, run `on_quit` callbacks and close the window."""
+        self._runtime_state = _RUNTIME_STATE_IDLE
+        for callback in list(self._quit_callbacks):
+            try:
+                callback()
+            except Exception as exc:
+                self._engine.report_callback_error("on_quit callback", exc)
+        self._engine.end_session()
+
     def _ensure_not_running(self, entrypoint_name: str) -> None:
         if self._runtime_state != _RUNTIME_STATE_IDLE:
             raise RuntimeError(
                 f"Cannot call {entrypoint_name} while engine is already running "
                 f"in '{self._runtime_state}' mode."
             )
+        # AppKit only runs on the main thread; winit would abort the process.
+        if sys.platform == "darwin" and threading.current_thread() is not threading.main_thread():
+            raise RuntimeError(f"{entrypoint_name} must be called from the main thread on macOS.")
 
     def get_handle(self) -> EngineHandle:
         """
@@ -1952,16 +3914,198 @@ class Engine:
         """Get the current display size (window client size) in pixels."""
         return self._engine.get_display_size()
 
+    def set_vsync(self, enabled: bool) -> None:
+        """
+        Enable or disable VSync at runtime.
+
+        When a window exists the surface present mode is reconfigured immediately.
+        Before the window is created this overrides the `vsync` argument passed to
+        `run()` / `start_manual()`.
+
+        Args:
+            enabled: True to sync presentation to the display refresh rate.
+        """
+        self._engine.set_vsync(enabled)
+
+    def is_vsync_enabled(self) -> bool:
+        """Return whether VSync is currently enabled."""
+        return self._engine.is_vsync_enabled()
+
+    def set_target_fps(self, fps: Optional[float]) -> None:
+        """
+        Cap the frame rate.
+
+        In `run()` mode the native event loop sleeps until the next frame is due.
+        In manual mode `render()` blocks for the remainder of the frame.
+
+        Args:
+            fps: Target frames per second, or None (or <= 0) to remove the cap.
+
+        Example:
+            ```python
+            engine.set_vsync(False)
+            engine.set_target_fps(30)    # battery-friendly menu
+            engine.set_target_fps(None)  # uncapped gameplay
+            ```
+        """
+        self._engine.set_target_fps(fps)
+
+    def get_target_fps(self) -> Optional[float]:
+        """Get the frame rate cap, or None when uncapped."""
+        return self._engine.get_target_fps()
+
+    def set_cursor_visible(self, visible: bool) -> None:
+        """Show or hide the mouse cursor over the window."""
+        self._engine.set_cursor_visible(visible)
+
+    def is_cursor_visible(self) -> bool:
+        """Get whether the mouse cursor is shown (ignoring auto-hide)."""
+        return self._engine.is_cursor_visible()
+
+    def set_auto_hide_cursor(self, enabled: bool) -> None:
+        """
+        Hide the cursor automatically while a gamepad is the active input device.
+
+        The cursor reappears as soon as the mouse or keyboard is used again.
+        See `engine.input.active_device`.
+        """
+        self._engine.set_auto_hide_cursor(enabled)
+
+    def get_auto_hide_cursor(self) -> bool:
+        """Get whether the cursor is hidden automatically during gamepad use."""
+        return self._engine.get_auto_hide_cursor()
+
+    def set_cursor_grab(self, mode: str) -> None:
+        """
+        Confine the cursor to the window or lock it in place.
+
+        While grabbed, mouse movement is read from raw device motion, so
+        `engine.input.mouse_delta` and the "Mouse X"/"Mouse Y" axes keep working
+        after the cursor stops moving. Platforms that cannot honor a mode fall
+        back to the other one (Windows and X11 cannot lock, macOS cannot confine).
+
+        Args:
+            mode: "none", "confined" (cursor stays inside the window) or
+                "locked" (cursor stays in place).
+
+        Raises:
+            ValueError: If `mode` is not a known grab mode.
+
+        Example:
+            ```python
+            # First-person mouse look
+            engine.set_cursor_grab("locked")
+            engine.set_cursor_visible(False)
+            yaw += engine.input.axis("Mouse X") * 0.1
+            ```
+        """
+        self._engine.set_cursor_grab(mode)
+
+    def get_cursor_grab(self) -> str:
+        """Get the cursor grab mode: "none", "confined" or "locked"."""
+        return self._engine.get_cursor_grab()
+
+    def set_ime_allowed(self, allowed: bool) -> None:
+        """
+        Let the input method (IME) compose text even when no UI text input has focus.
+
+        Turn it on while an in-game console or chat box that reads
+        `engine.input.typed_text` is open. Focusing a UI text input enables the
+        input method on its own.
+        """
+        self._engine.set_ime_allowed(allowed)
+
+    def get_ime_allowed(self) -> bool:
+        """Get whether the input method was enabled with `set_ime_allowed`."""
+        return self._engine.get_ime_allowed()
+
+    def set_cursor(self, cursor: str) -> None:
+        """
+        Show a built-in cursor shape over the window.
+
+        Replaces a custom cursor image. Widget hover cursors set with
+        `engine.ui.set_hover_cursor()` take precedence while hovered.
+
+        Args:
+            cursor: "default", "hand", "crosshair", "text", "wait", "move",
+                "not_allowed", "resize_horizontal", "resize_vertical",
+                "resize_diagonal", "resize_anti_diagonal", or any CSS cursor name.
+
+        Raises:
+            ValueError: If the cursor name is unknown.
+        """
+        self._engine.set_cursor(cursor)
+
+    def get_cursor(self) -> Optional[str]:
+        """Get the cursor shape as a CSS cursor name, or None while a custom image is shown."""
+        return self._engine.get_cursor()
+
+    def set_custom_cursor(
+        self,
+        rgba: bytes,
+        width: int,
+        height: int,
+        hotspot_x: int = 0,
+        hotspot_y: int = 0,
+    ) -> None:
+        """
+        Show a custom cursor image over the window.
+
+        Args:
+            rgba: `width * height * 4` bytes of RGBA pixels, row by row.
+            width: Image width in pixels.
+            height: Image height in pixels.
+            hotspot_x: X of the pixel that points, from the left.
+            hotspot_y: Y of the pixel that points, from the top.
+
+        Raises:
+            ValueError: If the buffer size doesn't match or the hotspot is
+                outside the image.
+
+        Example:
+            ```python
+            # 16x16 red square cursor pointing from its center
+            engine.set_custom_cursor(bytes([255, 0, 0, 255]) * 256, 16, 16, 8, 8)
+            ```
+
+        Call `set_cursor()` to go back to a built-in shape.
+        """
+        self._engine.set_custom_cursor(rgba, width, height, hotspot_x, hotspot_y)
+
+    def clipboard_get(self) -> str:
+        """
+        Text on the system clipboard, or "" when it holds no text.
+
+        Raises:
+            RuntimeError: If the clipboard cannot be opened (e.g. no display).
+
+        Example:
+            ```python
+            if ctx.input.key_down("ctrl") and ctx.input.key_pressed("v"):
+                console_line += engine.clipboard_get()
+            ```
+        """
+        return self._engine.clipboard_get()
+
+    def clipboard_set(self, text: str) -> None:
+        """
+        Put text on the system clipboard.
+
+        Raises:
+            RuntimeError: If the clipboard cannot be opened (e.g. no display).
+        """
+        self._engine.clipboard_set(text)
+
     def start_manual(
         self,
-        title: str = "PyG Engine",
-        width: int = 1280,
-        height: int = 720,
-        resizable: bool = True,
+        title: Optional[str] = None,
+        width: Optional[int] = None,
+        height: Optional[int] = None,
+        resizable: Optional[bool] = None,
         background_color: Optional[Any] = None,
-        vsync: bool = True,
+        vsync: Optional[bool] = None,
         redraw_on_change_only: bool = True,
-        show_fps_in_title: bool = False,
+        show_fps_in_title: Optional[bool] = None,
         icon_path: Optional[str] = None,
         min_width: Optional[int] = None,
         min_height: Optional[int] = None,
@@ -1969,20 +4113,43 @@ class Engine:
         """
         Start the engine in manual-loop mode without entering a blocking loop.
 
-        This mode is for advanced use cases where Python controls the frame loop
-        manually using:
-        `poll_events()`, `update()`, and `render()`.
+        This mode is for hosts that own the frame loop, e.g. to embed the
+        engine in another framework's loop, and drives each frame with
+        `poll_events()`, `update()` and `render()`. The window opens on the
+        first `poll_events()`. `render()` paces the loop to `set_target_fps()`.
+
+        After the loop ends (see `request_quit()`) it can be started again,
+        with a new window. The window system's event loop is shared by every
+        session in the process, and once a blocking `run()` has exited it, it
+        cannot be pumped by a manual loop again. On macOS the loop must run on
+        the main thread and `poll_events()` must be called every frame, or the
+        window stops responding.
 
         Raises:
-            RuntimeError: If the engine is already running in another loop mode.
+            RuntimeError: If the engine is already running in another loop
+                mode, a blocking `run()` already exited the window system's
+                event loop, or, on macOS, when called from another thread.
+
+        Example:
+            ```python
+            engine = Engine()
+            engine.start_manual(title="Manual Loop")
+            while engine.poll_events():
+                engine.update()
+                # Game logic, using engine.delta_time and engine.input
+                engine.render()
+            ```
+
+        Window settings left as None come from the `[window]` table of
+        `pyg_engine.toml`, or the defaults below.
 
         Args:
-            title: Window title.
-            width: Initial window width.
-            height: Initial window height.
-            resizable: Whether the window can be resized.
+            title: Window title (default: "PyG Engine").
+            width: Initial window width (default: 1280).
+            height: Initial window height (default: 720).
+            resizable: Whether the window can be resized (default: True).
             background_color: Optional `pyg_engine.Color`.
-            vsync: Enable/disable vertical sync.
+            vsync: Enable/disable vertical sync (default: True).
             redraw_on_change_only: When True (default), only redraw on scene changes.
             show_fps_in_title: When True, appends current FPS to window title.
             icon_path: Optional icon file path. If omitted, uses the most recent
@@ -2012,37 +4179,67 @@ class Engine:
 
     def poll_events(self) -> bool:
         """
-        Poll events from the window system.
+        Poll events from the window system without blocking.
+
+        Delivers window and input events to the engine; call it once per
+        frame before `update()`. When the window is closed or `request_quit()`
+        was called, it runs the `on_quit` callbacks, closes the window,
+        returns False and the engine leaves manual mode.
 
         Returns:
             bool: True if the loop should continue, False if exit requested.
+
+        Raises:
+            RuntimeError: If `start_manual()` was not called.
         """
         should_continue = self._engine.poll_events()
         if not should_continue and self._runtime_state in (
             _RUNTIME_STATE_MANUAL,
             _RUNTIME_STATE_RUNNING_CALLBACK,
         ):
-            self._runtime_state = _RUNTIME_STATE_IDLE
+            self._end_session()
         return should_continue
 
     def update(self) -> None:
-        """Run a single update step."""
+        """
+        Run a single update step, reloading changed scripts and UI documents
+        first when enabled and then adding list view row elements, finishing UI
+        tweens, ticking `timers`, dispatching finished `jobs`, refilling audio
+        streams, ticking autosave and advancing an intro started with
+        `play_intro()`.
+        """
+        self._scripts.poll()
+        self._assets.poll()
         self._engine.update()
+        self._ui._sync_list_views()
+        self._ui._sync_tweens()
+        self._timers.tick(self._engine.unscaled_delta_time, self._engine.delta_time)
+        self._jobs.poll()
+        self._audio._poll()
+        self._save_data.tick(self._engine.unscaled_delta_time)
+        if self._intro is not None and not self._intro.update(self._engine.unscaled_delta_time):
+            self._intro = None
 
     def render(self) -> None:
-        """Render a single frame."""
+        """
+        Render a single frame.
+
+        In a manual loop this also waits for the frame's turn when
+        `set_target_fps()` caps the frame rate. Without a window it only
+        prepares the frame.
+        """
         self._engine.render()
 
     def run(
         self,
-        title: str = "PyG Engine",
-        width: int = 1280,
-        height: int = 720,
-        resizable: bool = True,
+        title: Optional[str] = None,
+        width: Optional[int] = None,
+        height: Optional[int] = None,
+        resizable: Optional[bool] = None,
         background_color: Optional[Any] = None,
-        vsync: bool = True,
+        vsync: Optional[bool] = None,
         redraw_on_change_only: bool = True,
-        show_fps_in_title: bool = False,
+        show_fps_in_title: Optional[bool] = None,
         icon_path: Optional[str] = None,
         min_width: Optional[int] = None,
         min_height: Optional[int] = None,
@@ -2050,6 +4247,7 @@ class Engine:
         update: Optional[Callable[..., object]] = None,
         max_delta_time: Optional[float] = 0.1,
         user_data: Any = None,
+        on_update: Optional[Callable[[float, "EngineHandle"], object]] = None,
     ) -> None:
         """
         Run the engine and start the frame loop.
@@ -2057,23 +4255,49 @@ class Engine:
         Default mode (no callback):
         - Enter the native blocking loop until close.
 
+        Native callback mode (`on_update=...`):
+        - Keep the native loop and call `on_update(dt, handle)` once per frame
+          from inside it, after the native update and before rendering.
+        - The engine is busy while the callback runs, so it acts through
+          `handle`, an `EngineHandle`; its commands apply before the frame
+          renders. `dt` is scaled by `time_scale`.
+        - Return `False` to exit. An exception exits the loop and is raised
+          from `run()`.
+        - Timers, `ListView` rows, UI tweens and script and UI document
+          reloading are not driven in this mode; use `update=...` when you
+          need them. Changed textures and fonts still reload.
+
         Callback mode (`update=...`):
         - Start a Python-managed loop and invoke callback once per frame.
         - Callback can return `False` or call `context.stop()` to exit.
         - See `UpdateContext` for injected values.
         - Frame order is: poll events -> native update -> callback -> render.
           (Future GameObject script updates are intended to run in native update.)
+        - With `engine.scripts.enabled`, changed watched modules are reloaded
+          before the native update, and an `update` function defined in one of
+          them is swapped for its new version. With `engine.assets.hot_reload`,
+          changed UI documents are rebuilt at the same point.
+        - `ListView` row elements are added and removed right after the
+          native update.
+        - `engine.timers` are ticked with the clamped `dt` right after the
+          native update, so timer callbacks run before the callback. The
+          `engine.save_data` autosave interval is counted at the same point.
+        - While an intro started with `play_intro()` plays, it is drawn in
+          place of calling the callback.
 
         Raises:
             RuntimeError: If the engine is already running in another loop mode.
 
+        Window settings left as None come from the `[window]` table of
+        `pyg_engine.toml`, or the defaults below.
+
         Args:
-            title: Window title.
-            width: Initial window width.
-            height: Initial window height.
-            resizable: Whether the window can be resized.
+            title: Window title (default: "PyG Engine").
+            width: Initial window width (default: 1280).
+            height: Initial window height (default: 720).
+            resizable: Whether the window can be resized (default: True).
             background_color: Optional `pyg_engine.Color`.
-            vsync: Enable/disable vertical sync.
+            vsync: Enable/disable vertical sync (default: True).
             redraw_on_change_only: When True (default), only redraw on scene changes.
             show_fps_in_title: When True, appends current FPS to window title.
             icon_path: Optional icon file path. If omitted, uses the most recent
@@ -2087,10 +4311,38 @@ class Engine:
                 Only used in callback mode (`update` provided).
             user_data: Arbitrary object exposed via callback context.
                 Only used in callback mode (`update` provided).
+            on_update: Optional callback invoked once per frame of the native
+                loop with `dt` and an `EngineHandle`. Cannot be combined with
+                `update`.
+
+        Example:
+            ```python
+            from pyg_engine import Engine, GameObject, Vec2
+
+            engine = Engine()
+            player_id = engine.add_game_object(GameObject("Player"))
+            x = 0.0
+
+            def on_update(dt, handle):
+                global x
+                x += 2.0 * dt
+                handle.set_game_object_position(player_id, Vec2(x, 0.0))
+
+            engine.run(title="Native Loop", on_update=on_update)
+            ```
         """
         self._ensure_not_running("run()")
+        if update is not None and on_update is not None:
+            raise ValueError("run() takes either update or on_update, not both")
 
         if update is None:
+            frame_callback = None
+            if on_update is not None:
+                handle = self.get_handle()
+
+                def frame_callback(dt: float) -> object:
+                    return on_update(dt, handle)
+
             resolved_icon_path = (
                 icon_path if icon_path is not None else self._window_icon_path
             )
@@ -2108,15 +4360,19 @@ class Engine:
                     icon_path=resolved_icon_path,
                     min_width=min_width,
                     min_height=min_height,
+                    on_update=frame_callback,
                 )
             finally:
-                self._runtime_state = _RUNTIME_STATE_IDLE
+                self._end_session()
             return
 
         if max_delta_time is not None and max_delta_time <= 0.0:
             raise ValueError("max_delta_time must be > 0.0 or None")
 
         invoke_callback = _compile_update_callback(update)
+        scripts = self._scripts
+        timers = self._timers
+        ui = self._ui
 
         self.start_manual(
             title=title,
@@ -2139,28 +4395,54 @@ class Engine:
         poll_events = native_engine.poll_events
         update_step = native_engine.update
         render_frame = native_engine.render
+        add_script_time = native_engine.add_script_time
+        perf_counter = time.perf_counter
 
         try:
             while True:
                 if not poll_events():
                     break
 
+                if scripts.enabled and scripts.poll():
+                    update = scripts.refresh(update)
+                    invoke_callback = _compile_update_callback(update)
+                self._assets.poll()
+
                 # Update native systems first so callback gets current dt/input.
                 update_step()
+                # Python work until the render counts as the frame's scripts scope.
+                python_started = perf_counter()
+                ui._sync_list_views()
+                ui._sync_tweens()
 
                 context.delta_time = native_engine.delta_time
-                if max_delta_time is not None and context.delta_time > max_delta_time:
-                    context.delta_time = max_delta_time
+                unscaled_delta_time = native_engine.unscaled_delta_time
+                if max_delta_time is not None:
+                    context.delta_time = min(context.delta_time, max_delta_time)
+                    unscaled_delta_time = min(unscaled_delta_time, max_delta_time)
                 context.elapsed_time = native_engine.elapsed_time
+                timers.tick(unscaled_delta_time, context.delta_time)
+                self._jobs.poll()
+                self._audio._poll()
+                self._save_data.tick(unscaled_delta_time)
+
+                if self._intro is not None:
+                    if self._intro.update(unscaled_delta_time):
+                        add_script_time(perf_counter() - python_started)
+                        render_frame()
+                        context.frame += 1
+                        continue
+                    self._intro = None
 
                 callback_result = invoke_callback(context)
                 if callback_result is False or context._should_stop:
                     break
 
+                add_script_time(perf_counter() - python_started)
                 render_frame()
                 context.frame += 1
         finally:
-            self._runtime_state = _RUNTIME_STATE_IDLE
+            self._end_session()
 
     def add_game_object(self, game_object: Any) -> Optional[int]:
         """
@@ -2215,46 +4497,229 @@ class Engine:
         """Destroy a runtime object by id or object handle."""
         self.remove_game_object(self._resolve_runtime_object_id(game_object_or_id))
 
-    def set_game_object_position(self, object_id: int, position: Any) -> bool:
+    def destroy_object(self, game_object_or_id: Any) -> bool:
         """
-        Update a runtime GameObject position by id.
+        Mark a runtime object, and its children, for removal at the end of the frame.
+
+        Unlike `destroy()`, the object stays usable for the rest of the frame,
+        so this is safe to call while iterating objects or from collision
+        handling. On removal its components get `on_destroy`, its collider
+        leaves the collision world and every collision it was part of ends
+        with a final exit event. Objects marked from the update callback are
+        removed before the frame renders.
 
         Returns:
-            True if the object exists and was updated, False otherwise.
+            False if the object does not exist or is already marked.
+
+        Example:
+            ```python
+            for enemy_id in engine.find_by_tag("enemy"):
+                if engine.objects.get_id(enemy_id).position.y < -10:
+                    engine.destroy_object(enemy_id)
+            ```
         """
-        return self._engine.set_game_object_position(object_id, position)
+        return self._engine.destroy_object(self._resolve_runtime_object_id(game_object_or_id))
 
-    @property
-    def camera_object_id(self) -> Optional[int]:
-        """Get the runtime id of the active camera GameObject."""
-        return self._engine.camera_object_id()
+    def is_destroy_pending(self, game_object_or_id: Any) -> bool:
+        """Get whether a runtime object is marked for removal by `destroy_object()`."""
+        return self._engine.is_destroy_pending(self._resolve_runtime_object_id(game_object_or_id))
 
-    def get_camera_position(self) -> Any:
+    def find_by_tag(self, tag: str) -> list[int]:
         """
-        Get the active camera world position.
+        Get the ids of runtime objects with a tag, in creation order.
 
-        Returns:
-            A `pyg_engine.Vec2` or `pyg_engine.Vec3` representing the camera position in world space.
+        The lookup is indexed in Rust, so gameplay code can query tags every
+        frame instead of keeping its own lists of ids.
 
         Example:
             ```python
-            cam_pos = engine.get_camera_position()
-            print(f"Camera at: {cam_pos.x}, {cam_pos.y}")
+            enemy = GameObject("Goblin")
+            enemy.add_tag("enemy")
+            engine.add_game_object(enemy)
+
+            for enemy_id in engine.find_by_tag("enemy"):
+                engine.destroy(enemy_id)
             ```
         """
-        return self._engine.get_camera_position()
+        return list(self._engine.find_by_tag(tag))
 
-    def set_camera_position(self, position: Any) -> bool:
-        """
-        Set the active camera world position.
+    def find_by_name(self, name: str) -> list[int]:
+        """Get the ids of runtime objects with a name, in creation order."""
+        return list(self._engine.find_by_name(name))
 
-        Legacy helper. Prefer `engine.camera.position = ...` in new code.
+    def get_all_ids(self) -> list[int]:
+        """Get the ids of every runtime object, in the order they were added."""
+        return list(self._engine.get_all_ids())
 
-        Args:
-            position: A `pyg_engine.Vec2` or `pyg_engine.Vec3` in world space.
+    def emit(self, name: str, payload: Any = None) -> None:
+        """
+        Emit an event on the engine's event bus.
 
-        Returns:
-            True if successful, False otherwise.
+        The event is queued and dispatched at the end of the next engine
+        update, so handlers never run in the middle of other game code.
+        Events emitted by a handler are dispatched after the following
+        update. `None`, bools, ints, floats, strings and `Vec2` payloads are
+        stored natively, so Rust systems can read them; any other object is
+        passed to handlers as is.
+
+        The engine emits `"scene_loaded"` and `"scene_unloaded"` with the
+        scene name, and `"sound_finished"` with the voice id of each sound
+        that plays to the end.
+
+        Example:
+            ```python
+            def on_player_died(score):
+                print(f"Game over, score {score}")
+
+            engine.on("player_died", on_player_died)
+            engine.emit("player_died", 1200)
+            ```
+        """
+        self._engine.emit_event(name, payload)
+
+    def on(self, name: str, callback: Callable[[Any], Any]) -> int:
+        """
+        Subscribe a callback to an event. It is called with the event payload.
+
+        Returns:
+            The subscription id, for `off()`.
+        """
+        return self._engine.on_event(name, callback)
+
+    def off(self, subscription: int) -> bool:
+        """
+        Remove an event subscription made with `on()`.
+
+        Returns:
+            False if the subscription does not exist.
+        """
+        return self._engine.off_event(subscription)
+
+    @property
+    def callback_error_policy(self) -> str:
+        """
+        Get or set what happens when a callback the engine calls raises.
+
+        Script hooks, event handlers, collider and UI callbacks and timer
+        callbacks have no caller for an exception to reach, so each exception
+        is logged with its traceback and kept for `get_callback_errors()`.
+        Then, by policy:
+
+        - ``"log"`` (the default): the game keeps running.
+        - ``"raise"``: the first such exception is raised again from the next
+          `update()`, `render()` or `poll_events()` call; `run()` stops and
+          raises it.
+        - ``"abort"``: like ``"raise"``, and the window closes, so a manual
+          loop ends even if it catches the exception.
+
+        The policy is shared by every engine in the process.
+
+        Example:
+            ```python
+            engine.callback_error_policy = "raise"  # fail fast in tests
+            ```
+        """
+        return self._engine.callback_error_policy
+
+    @callback_error_policy.setter
+    def callback_error_policy(self, value: str) -> None:
+        self._engine.callback_error_policy = value
+
+    def get_callback_errors(self, clear: bool = True) -> list["CallbackError"]:
+        """
+        Get the exceptions raised by engine-called callbacks, oldest first.
+
+        Up to the last 100 are kept, whatever the `callback_error_policy`.
+
+        Args:
+            clear: Empty the queue, so the next call only returns new errors.
+
+        Returns:
+            CallbackError objects with ``source`` (what raised it, such as
+            ``"script update hook"``), ``exception_type``, ``message`` and
+            ``traceback``.
+
+        Example:
+            ```python
+            for error in engine.get_callback_errors():
+                show_error_overlay(f"{error.source}: {error.message}")
+            ```
+        """
+        return list(self._engine.get_callback_errors(clear))
+
+    def send_message(self, target: Any, name: str, payload: Any = None) -> bool:
+        """
+        Send a message to the components of a runtime object.
+
+        Messages are delivered in the next update phase, before components
+        update: Rust components get it through `on_message` and Python
+        scripts through their `on_message(name, payload)` method. Unlike
+        keeping references between objects, the sender only needs the
+        target's id, and a destroyed or disabled target simply drops the
+        message. Payloads are stored like `emit()` payloads.
+
+        Args:
+            target: Object id or object handle.
+            name: Message name.
+            payload: Optional message data.
+
+        Returns:
+            False if the target does not exist.
+
+        Example:
+            ```python
+            class Health(Script):
+                hp = 100
+
+                def on_message(self, name, payload):
+                    if name == "damage":
+                        self.hp -= payload
+
+            engine.send_message(enemy_id, "damage", 25)
+            ```
+        """
+        return self._engine.send_message(self._resolve_runtime_object_id(target), name, payload)
+
+    def set_game_object_position(self, object_id: int, position: Any) -> bool:
+        """
+        Update a runtime GameObject position by id.
+
+        Returns:
+            True if the object exists and was updated, False otherwise.
+        """
+        return self._engine.set_game_object_position(object_id, position)
+
+    @property
+    def camera_object_id(self) -> Optional[int]:
+        """Get the runtime id of the active camera GameObject."""
+        return self._engine.camera_object_id()
+
+    def get_camera_position(self) -> Any:
+        """
+        Get the active camera world position.
+
+        Returns:
+            A `pyg_engine.Vec2` or `pyg_engine.Vec3` representing the camera position in world space.
+
+        Example:
+            ```python
+            cam_pos = engine.get_camera_position()
+            print(f"Camera at: {cam_pos.x}, {cam_pos.y}")
+            ```
+        """
+        return self._engine.get_camera_position()
+
+    def set_camera_position(self, position: Any) -> bool:
+        """
+        Set the active camera world position.
+
+        Legacy helper. Prefer `engine.camera.position = ...` in new code.
+
+        Args:
+            position: A `pyg_engine.Vec2` or `pyg_engine.Vec3` in world space.
+
+        Returns:
+            True if successful, False otherwise.
 
         Example:
             ```python
@@ -2368,6 +4833,38 @@ class Engine:
         """Get the active camera background clear color."""
         return self._engine.get_camera_background_color()
 
+    def set_camera_pixel_perfect(
+        self, width: Optional[int] = None, height: Optional[int] = None
+    ) -> bool:
+        """
+        Enable pixel-perfect camera rendering at a fixed virtual resolution.
+
+        Prefer `engine.camera.pixel_perfect_resolution = (w, h)` in new code.
+
+        The world renders to an offscreen target of `width` x `height` pixels which is
+        upscaled by the largest integer factor that fits the window and centered with
+        letterbox bars. Screen-space draws and UI are drawn on top at full resolution.
+        Call with no arguments to disable.
+
+        Args:
+            width: Virtual resolution width in pixels.
+            height: Virtual resolution height in pixels.
+
+        Returns:
+            True if the mode was applied, False for invalid dimensions: a zero
+            dimension or one larger than the GPU's maximum texture size.
+
+        Example:
+            ```python
+            engine.set_camera_pixel_perfect(320, 180)
+            ```
+        """
+        return self._engine.set_camera_pixel_perfect(width, height)
+
+    def get_camera_pixel_perfect(self) -> Optional[tuple[int, int]]:
+        """Get the pixel-perfect virtual resolution, or None when disabled."""
+        return self._engine.get_camera_pixel_perfect()
+
     def world_to_screen(self, world_position: Any) -> tuple[float, float]:
         """
         Convert world-space coordinates to screen-space pixel coordinates.
@@ -2439,6 +4936,29 @@ class Engine:
         """
         self._engine.add_draw_commands(to_draw_commands(drawable))
 
+    def draw_recorder(self, capacity: int = 1024) -> "DrawRecorder":
+        """
+        Create a recorder that batches immediate-mode draw calls.
+
+        Calls on the recorder append to a native buffer without building
+        ``DrawCommand`` objects or submitting each one, and the buffer is sent
+        to the engine once when the ``with`` block exits. A block that raises
+        discards what it recorded. Outside a ``with`` block, call ``flush()``.
+
+        Args:
+            capacity: Number of commands to preallocate room for.
+
+        Example:
+            ```python
+            with engine.draw_recorder() as d:
+                for star in stars:
+                    d.circle(star.x, star.y, star.size, Color.WHITE)
+                d.rectangle(0, 0, 200, 30, Color.BLACK)
+                d.text("Stars", 8, 4, Color.WHITE, font_size=18)
+            ```
+        """
+        return self._engine.draw_recorder(capacity)
+
     def draw_pixel(
         self,
         x: int,
@@ -2657,7 +5177,7 @@ class Engine:
         y: float,
         width: float,
         height: float,
-        texture_path: str,
+        texture_path: Union[str, AssetHandle],
         draw_order: float = 0.0,
     ) -> None:
         """
@@ -2670,7 +5190,7 @@ class Engine:
             y: Top-left Y coordinate in pixels.
             width: Display width in pixels.
             height: Display height in pixels.
-            texture_path: File path to image (PNG, JPG, etc.).
+            texture_path: File path to image (PNG, JPG, etc.), or a texture `AssetHandle`.
             draw_order: Rendering order (higher values drawn on top).
 
         Example:
@@ -2687,7 +5207,7 @@ class Engine:
             y,
             width,
             height,
-            texture_path,
+            asset_path(texture_path),
             draw_order=draw_order,
         )
 
@@ -2698,7 +5218,7 @@ class Engine:
         width: float,
         height: float,
         texture_key: str,
-        rgba: bytes,
+        rgba: PixelData,
         texture_width: int,
         texture_height: int,
         draw_order: float = 0.0,
@@ -2713,8 +5233,12 @@ class Engine:
             y: Top-left Y coordinate in pixels.
             width: Display width in pixels.
             height: Display height in pixels.
-            texture_key: Unique identifier for caching this texture.
-            rgba: Raw RGBA bytes (4 bytes per pixel: R, G, B, A).
+            texture_key: Unique identifier for caching this texture. Drawing
+                new pixels of the same size under a known key updates the
+                cached GPU texture instead of creating another.
+            rgba: Raw RGBA bytes (4 bytes per pixel: R, G, B, A), from any
+                byte buffer such as a bytearray, memoryview or uint8 numpy
+                array, which is copied once.
             texture_width: Width of source texture in pixels.
             texture_height: Height of source texture in pixels.
             draw_order: Rendering order (higher values drawn on top).
@@ -2746,6 +5270,238 @@ class Engine:
             draw_order=draw_order,
         )
 
+    def preload_texture(self, path: str) -> None:
+        """
+        Load a texture into the render cache before it is first drawn.
+
+        Relative paths resolve from the source root. Calling this before the window
+        exists queues the texture and loads it when the renderer starts.
+
+        Raises:
+            RuntimeError: If the texture cannot be read or decoded.
+        """
+        self._engine.preload_texture(path)
+
+    def texture_size(self, path: str) -> tuple[int, int]:
+        """
+        Get the `(width, height)` of an image file in pixels without loading it.
+
+        Raises:
+            RuntimeError: If the file cannot be read.
+        """
+        return self._engine.texture_size(path)
+
+    def unload_texture(self, path: str) -> bool:
+        """
+        Remove a texture from the render cache.
+
+        Returns:
+            True if the texture was cached. It reloads automatically on its next draw.
+        """
+        return self._engine.unload_texture(path)
+
+    def texture_memory_usage(self) -> int:
+        """Approximate GPU memory held by cached textures, in bytes."""
+        return self._engine.texture_memory_usage()
+
+    def cached_texture_count(self) -> int:
+        """Number of textures currently resident in the render cache."""
+        return self._engine.cached_texture_count()
+
+    def set_texture_budget(self, budget_bytes: Optional[int]) -> None:
+        """
+        Set the texture cache memory budget.
+
+        When usage exceeds the budget, least-recently-used textures are evicted at the
+        end of a frame. Textures drawn during the last frame are never evicted.
+
+        Args:
+            budget_bytes: Budget in bytes, or None for unlimited (default).
+
+        Example:
+            ```python
+            engine.set_texture_budget(256 * 1024 * 1024)  # 256 MiB
+            ```
+        """
+        self._engine.set_texture_budget(budget_bytes)
+
+    def get_texture_budget(self) -> Optional[int]:
+        """Get the texture cache budget in bytes, or None when unlimited."""
+        return self._engine.get_texture_budget()
+
+    def set_sort_sprites_by_texture(self, enabled: bool) -> None:
+        """
+        Group sprites of equal draw order by texture (on by default).
+
+        Sprites sharing an atlas then merge into one draw call instead of
+        breaking the batch wherever textures interleave. Direct draw commands
+        and UI always keep their submission order. Turn it off if overlapping
+        sprites with the same draw order must keep their creation order.
+        """
+        self._engine.set_sort_sprites_by_texture(enabled)
+
+    def get_sort_sprites_by_texture(self) -> bool:
+        """Get whether sprites of equal draw order are grouped by texture."""
+        return self._engine.get_sort_sprites_by_texture()
+
+    def render_stats(self) -> "RenderStats":
+        """
+        Batching statistics for the last rendered frame.
+
+        Returns:
+            RenderStats with ``draw_items``, ``draw_calls``, ``texture_switches``
+            and ``items_per_draw_call``.
+
+        Example:
+            ```python
+            stats = engine.render_stats()
+            print(f"{stats.draw_items} items in {stats.draw_calls} draw calls")
+            ```
+        """
+        return self._engine.render_stats()
+
+    def get_frame_stats(self) -> "FrameStats":
+        """
+        Per-scope timings of the last completed frame, in milliseconds.
+
+        A frame runs from one update to the next, so it covers the update, the
+        fixed steps and the render in between. The scopes are ``input``, ``ui``,
+        ``update``, ``fixed``, ``physics``, ``scripts`` (script hooks and event
+        handlers, and the update callback of ``run(update=...)``) and ``render``;
+        ``total_ms`` is their sum and ``frame_ms`` the wall-clock frame time,
+        which also includes vsync and frame-cap waits.
+
+        Returns:
+            FrameStats with ``frame``, ``frame_ms``, ``total_ms``, one ``<scope>_ms``
+            attribute per scope, ``scopes`` as ``(name, ms)`` pairs, ``slowest_scope``
+            and ``slow`` (whether the frame was flagged as over budget or a spike).
+
+        Example:
+            ```python
+            stats = engine.get_frame_stats()
+            print(f"update {stats.update_ms:.2f} ms, render {stats.render_ms:.2f} ms")
+            ```
+        """
+        return self._engine.get_frame_stats()
+
+    @property
+    def profiler_enabled(self) -> bool:
+        """Get or set whether frame timings are recorded, on by default."""
+        return self._engine.profiler_enabled
+
+    @profiler_enabled.setter
+    def profiler_enabled(self, value: bool) -> None:
+        self._engine.profiler_enabled = bool(value)
+
+    @property
+    def frame_budget_ms(self) -> Optional[float]:
+        """
+        Get or set the work budget per frame in milliseconds, or None (the default).
+
+        When a frame's measured work (``get_frame_stats().total_ms``) exceeds the
+        budget, a warning naming the slowest scope, with the per-scope breakdown,
+        is logged. Warnings are limited to one per second; the next one reports
+        how many slow frames were skipped.
+        """
+        return self._engine.frame_budget_ms
+
+    @frame_budget_ms.setter
+    def frame_budget_ms(self, value: Optional[float]) -> None:
+        if value is not None and value <= 0:
+            raise ValueError("frame_budget_ms must be positive or None")
+        self._engine.frame_budget_ms = value
+
+    @property
+    def frame_spike_factor(self) -> Optional[float]:
+        """
+        Get or set how many times slower than average a frame must be to count
+        as a spike, or None (the default).
+
+        Spikes are logged like frames over ``frame_budget_ms``, which catches
+        hitches in games that are well under budget most of the time. The
+        average is taken over recent frames once the first 30 have run.
+
+        Example:
+            ```python
+            engine.frame_spike_factor = 3.0
+            ```
+        """
+        return self._engine.frame_spike_factor
+
+    @frame_spike_factor.setter
+    def frame_spike_factor(self, value: Optional[float]) -> None:
+        if value is not None and value <= 1:
+            raise ValueError("frame_spike_factor must be greater than 1 or None")
+        self._engine.frame_spike_factor = value
+
+    @property
+    def slow_frame_count(self) -> int:
+        """Number of frames flagged as over budget or as spikes, logged or not."""
+        return self._engine.slow_frame_count
+
+    def dump_debug_snapshot(self, path: str) -> None:
+        """
+        Write a JSON snapshot of the engine state to a file, for bug reports.
+
+        The snapshot holds every object with its local and world transform,
+        components and collider, the collision broad-phase AABB tree, the last
+        256 collision events, and counts of the queued draw commands along with
+        last frame's render stats. Its top-level ``format`` and ``version``
+        keys identify the layout for viewers.
+
+        Raises:
+            RuntimeError: If the file cannot be written.
+
+        Example:
+            ```python
+            def update(ctx):
+                if ctx.input.key_pressed("F12"):
+                    engine.dump_debug_snapshot("snapshot.json")
+            ```
+        """
+        self._engine.dump_debug_snapshot(path)
+
+    def draw_input_glyph(
+        self,
+        icon: str,
+        x: float,
+        y: float,
+        size: float = 32.0,
+        draw_order: float = 0.0,
+    ) -> bool:
+        """
+        Draw an icon from the built-in input glyph atlas in window coordinates.
+
+        Icon names come from `engine.input.action_glyphs(...)`, e.g. "gamepad_south"
+        or "mouse_left".
+
+        Args:
+            icon: Atlas icon name.
+            x: Top-left X coordinate in pixels.
+            y: Top-left Y coordinate in pixels.
+            size: Display width and height in pixels.
+            draw_order: Rendering order (higher values drawn on top).
+
+        Returns:
+            False if the icon name is unknown.
+        """
+        icon_data = self._engine.input_glyph_icon(icon)
+        if icon_data is None:
+            return False
+        width, height, rgba = icon_data
+        self._engine.draw_image_from_bytes(
+            x,
+            y,
+            size,
+            size,
+            f"__pyg_input_glyph_{icon}",
+            rgba,
+            width,
+            height,
+            draw_order=draw_order,
+        )
+        return True
+
     def draw_text(
         self,
         text: str,
@@ -2753,7 +5509,7 @@ class Engine:
         y: float,
         color: Any,
         font_size: float = 24.0,
-        font_path: Optional[str] = None,
+        font_path: Union[str, AssetHandle, None] = None,
         font_family: Optional[str] = None,
         font_weight: Optional[str] = None,
         font_style: Optional[str] = None,
@@ -2776,7 +5532,7 @@ class Engine:
             y,
             color,
             font_size=font_size,
-            font_path=font_path,
+            font_path=asset_path(font_path),
             font_family=font_family,
             font_weight=font_weight,
             font_style=font_style,
@@ -2786,6 +5542,46 @@ class Engine:
             draw_order=draw_order,
         )
 
+    def draw_world_text(
+        self,
+        text: str,
+        x: float,
+        y: float,
+        color: Any,
+        font_size: float = 24.0,
+        font_path: Union[str, AssetHandle, None] = None,
+        font_family: Optional[str] = None,
+        font_weight: Optional[str] = None,
+        font_style: Optional[str] = None,
+        draw_order: float = 0.0,
+    ) -> None:
+        """
+        Draw text centered on a point in world space.
+
+        Unlike `draw_text`, the position is in world units: the text moves with
+        the camera and scales with zoom, one text pixel per world unit. For
+        text that follows an object, add a `WorldLabelComponent` instead.
+
+        Example:
+            ```python
+            for enemy in enemies:
+                pos = enemy.position
+                engine.draw_world_text(f"{enemy.hp}", pos.x, pos.y + 30, Color.RED, font_size=14)
+            ```
+        """
+        self._engine.draw_world_text(
+            text,
+            x,
+            y,
+            color,
+            font_size=font_size,
+            font_path=asset_path(font_path),
+            font_family=font_family,
+            font_weight=font_weight,
+            font_style=font_style,
+            draw_order=draw_order,
+        )
+
     def register_font_family(
         self,
         family: str,
@@ -2804,12 +5600,163 @@ class Engine:
             bold_italic=bold_italic,
         )
 
+    def set_font_fallbacks(self, fallbacks: list[str]) -> None:
+        """
+        Set the fonts tried, in order, for characters a text's own font lacks.
+
+        Entries are registered family names or TTF/OTF paths. Each character
+        is drawn with the first font that has it, so localized text stays
+        readable when the primary font only covers Latin.
+
+        Example:
+            ```python
+            engine.register_font_family("Noto Sans JP", regular="fonts/NotoSansJP-Regular.otf")
+            engine.set_font_fallbacks([
+                "Noto Sans JP",
+                "fonts/NotoNaskhArabic-Regular.ttf",
+                "fonts/NotoEmoji-Regular.ttf",
+            ])
+            ```
+        """
+        self._engine.set_font_fallbacks(list(fallbacks))
+
+    @property
+    def font_fallbacks(self) -> list[str]:
+        """Get the default fallback fonts for all text."""
+        return self._engine.get_font_fallbacks()
+
+    def set_ui_font_fallbacks(self, fallbacks: list[str]) -> None:
+        """
+        Set the UI theme's fallback fonts.
+
+        UI text that lists no fallbacks of its own (see
+        `Label.set_font_fallbacks()`) uses these instead of the defaults from
+        `set_font_fallbacks()`.
+        """
+        self._engine.set_ui_font_fallbacks(list(fallbacks))
+
+    @property
+    def ui_font_fallbacks(self) -> list[str]:
+        """Get the UI theme's fallback fonts."""
+        return self._engine.get_ui_font_fallbacks()
+
+    def register_ui_theme(
+        self,
+        name: str,
+        colors: dict[str, Any],
+        base: str = "light",
+    ) -> None:
+        """
+        Register a UI theme that copies `base` and replaces some of its colors.
+
+        Color keys are `"<widget>.<part>"` or `"<widget>.<state>.<part>"`:
+
+        - Widgets: `button`, `panel`, `label`, `text_input`, `slider`,
+          `checkbox`, `radio_button`, `toggle_switch`, `scroll_view`,
+          `progress_bar`, `curve_editor` and `list_view`.
+        - States (widgets with hover/press feedback only): `normal`,
+          `hovered`, `pressed`, `focused` and `disabled`. Leaving the state
+          out sets every state.
+        - Parts: `background`, `border` and `text`. Sliders and progress bars
+          draw their fill, scroll views their scrollbar and curve editors
+          their curve in the text color.
+        - Extra colors: `panel.dim`, `toggle_switch.on`, `toggle_switch.knob`,
+          `progress_bar.label`, `list_view.hover`, `list_view.selection`,
+          `list_view.selected_text` and `focus_ring` (the keyboard focus
+          outline).
+
+        Keys apply in order, so a general key can be followed by
+        state-specific ones. Registering the active theme's name restyles
+        the UI right away.
+
+        Args:
+            name: Theme name; an existing theme with this name is replaced
+            colors: Color keys mapped to a `Color` or an (r, g, b[, a]) tuple
+            base: Registered theme to start from, e.g. `"light"` or `"dark"`
+
+        Raises:
+            ValueError: If `base` or a color key is unknown.
+
+        Example:
+            ```python
+            engine.register_ui_theme("ocean", {
+                "button.background": (0.1, 0.3, 0.5),
+                "button.hovered.background": (0.15, 0.4, 0.65),
+                "button.text": (1.0, 1.0, 1.0),
+                "panel.background": (0.05, 0.15, 0.25),
+            }, base="dark")
+            engine.set_ui_theme("ocean")
+            ```
+        """
+        pairs = [(key, _theme_color(key, color)) for key, color in colors.items()]
+        self._engine.register_ui_theme(name, pairs, base)
+
+    def set_ui_theme(self, name: str) -> None:
+        """
+        Switch the UI to a registered theme, such as the built-in `"light"`
+        (the default) and `"dark"` themes.
+
+        Existing widgets are restyled in place on the next frame. Colors that
+        were changed from the previous theme's, e.g. with
+        `Label.set_color()`, are kept.
+
+        Raises:
+            ValueError: If no theme has this name.
+        """
+        self._engine.set_ui_theme(name)
+
+    @property
+    def ui_theme(self) -> str:
+        """Get the name of the active UI theme."""
+        return self._engine.get_ui_theme()
+
+    def ui_theme_names(self) -> list[str]:
+        """Get the names of the registered UI themes."""
+        return self._engine.ui_theme_names()
+
+    def set_ui_scale_mode(
+        self,
+        mode: str,
+        reference_width: float = 1920,
+        reference_height: float = 1080,
+    ) -> None:
+        """
+        Set how UI coordinates map to window pixels.
+
+        - `"constant_pixel_size"` (the default): one UI unit per logical
+          pixel, so widgets keep their size and bigger windows show more room.
+        - `"scale_with_height"`: the window is always `reference_height`
+          units tall; the width follows the window's aspect ratio.
+        - `"fixed_resolution"`: the UI is laid out at exactly
+          `reference_width` x `reference_height` and scaled to fit the window,
+          centered with empty margins where the aspect ratio differs.
+
+        Layout anchors, hit testing and mouse positions in UI events all use
+        the scaled coordinates.
+
+        **Example:**
+
+            ```python
+            # Designed at 1080p; the same layout at 720p and 4K.
+            engine.set_ui_scale_mode(pyg.UIScaleMode.SCALE_WITH_HEIGHT, reference_height=1080)
+            ```
+
+        Raises:
+            ValueError: For an unknown mode or a non-positive reference size.
+        """
+        self._engine.set_ui_scale_mode(mode, reference_width, reference_height)
+
+    @property
+    def ui_scale_mode(self) -> str:
+        """Get the name of the UI scale mode."""
+        return self._engine.get_ui_scale_mode()
+
     def measure_text(
         self,
         text: str,
         *,
         font_size: float = 24.0,
-        font_path: Optional[str] = None,
+        font_path: Union[str, AssetHandle, None] = None,
         font_family: Optional[str] = None,
         font_weight: Optional[str] = None,
         font_style: Optional[str] = None,
@@ -2821,7 +5768,7 @@ class Engine:
         return self._engine.measure_text(
             text,
             font_size=font_size,
-            font_path=font_path,
+            font_path=asset_path(font_path),
             font_family=font_family,
             font_weight=font_weight,
             font_style=font_style,
@@ -2860,6 +5807,36 @@ class Engine:
         """
         return self._engine.version
 
+    def capabilities(self) -> "EngineCapabilities":
+        """
+        Report GPU and engine capabilities.
+
+        Includes the GPU adapter and driver, supported texture formats, max
+        texture size, MSAA sample counts, present modes and enabled engine
+        features. Use it to gate optional effects, and include
+        ``str(engine.capabilities())`` in bug reports.
+
+        If no usable GPU is found the engine falls back to a software
+        adapter (``renderer.safe_mode`` is True and "safe_mode" is in
+        ``features``). If even that fails, a CPU display draws simple screen
+        shapes and text (rectangles, lines, circles, gradients, ``draw_text``
+        and UI built from them), or the renderer error when nothing is drawn,
+        and "software_display" is in ``features``. If no display works at all,
+        the engine keeps running without drawing and "headless" is in
+        ``features``. Both fallbacks run at 30 FPS unless ``target_fps`` is set.
+
+        Returns:
+            EngineCapabilities. Its ``renderer`` is None until the window
+            has been created.
+
+        Example:
+            >>> caps = engine.capabilities()
+            >>> if caps.renderer and 4 in caps.renderer.msaa_sample_counts:
+            ...     enable_fancy_effects()
+            >>> print(caps)
+        """
+        return self._engine.capabilities()
+
     @property
     def delta_time(self) -> float:
         """Get the time since the last frame in seconds."""
@@ -2869,3 +5846,127 @@ class Engine:
     def elapsed_time(self) -> float:
         """Get the total elapsed time in seconds since the engine started."""
         return self._engine.elapsed_time
+
+    @property
+    def unscaled_delta_time(self) -> float:
+        """Get the real time since the last frame in seconds, ignoring `time_scale` and `paused`."""
+        return self._engine.unscaled_delta_time
+
+    @property
+    def time_scale(self) -> float:
+        """
+        Get or set the multiplier applied to `delta_time` and the fixed-step rate.
+
+        1.0 is normal speed, 0.5 slow motion and 0.0 freezes game time. Input,
+        UI and tweens keep using real time. Negative values raise ValueError.
+
+        Example:
+            ```python
+            engine.time_scale = 0.25  # Bullet time for two real seconds
+            engine.timers.start(
+                "bullet_time",
+                2.0,
+                clock="unscaled",
+                on_expired=lambda name: setattr(engine, "time_scale", 1.0),
+            )
+            ```
+        """
+        return self._engine.time_scale
+
+    @time_scale.setter
+    def time_scale(self, value: float) -> None:
+        value = float(value)
+        if not value >= 0.0 or value == float("inf"):
+            raise ValueError("time_scale must be a finite value >= 0.0")
+        self._engine.time_scale = value
+
+    @property
+    def paused(self) -> bool:
+        """
+        Get or set whether game time is paused.
+
+        While paused `delta_time` is 0.0, so `timers` stop too; `update` and
+        `fixed_update` of components and scripts, collisions and messages are
+        suspended. Input, UI, tweens, events and the `run()` callback keep
+        running, so a pause menu can resume the game.
+
+        Example:
+            ```python
+            def update(ctx):
+                if ctx.input.key_pressed("Escape"):
+                    engine.paused = not engine.paused
+                    pause_menu.visible = engine.paused
+            ```
+        """
+        return self._engine.paused
+
+    @paused.setter
+    def paused(self, value: bool) -> None:
+        self._engine.paused = bool(value)
+
+    @property
+    def fixed_timestep(self) -> float:
+        """Get the seconds of game time per fixed update, 1/60 by default."""
+        return self._engine.fixed_timestep
+
+    def set_fixed_timestep(self, seconds: float) -> None:
+        """
+        Set the seconds of game time per fixed update.
+
+        Fixed steps run `fixed_update` and collision detection. Each update
+        adds the frame's game time to an accumulator and runs one fixed step
+        per `seconds` it holds, up to `max_fixed_steps`, so the simulation
+        rate does not depend on the frame rate.
+
+        Raises:
+            ValueError: If `seconds` is not a finite value > 0.0.
+
+        Example:
+            ```python
+            engine.set_fixed_timestep(1 / 120)  # 120 Hz physics
+            ```
+        """
+        seconds = float(seconds)
+        if not seconds > 0.0 or seconds == float("inf"):
+            raise ValueError("fixed timestep must be a finite value > 0.0")
+        self._engine.set_fixed_timestep(seconds)
+
+    @property
+    def max_fixed_steps(self) -> int:
+        """
+        Get or set the most fixed steps one update runs to catch up, 5 by default.
+
+        After a slow frame the fixed steps past the limit are dropped, so the
+        simulation slows down instead of falling further behind.
+        """
+        return self._engine.max_fixed_steps
+
+    @max_fixed_steps.setter
+    def max_fixed_steps(self, value: int) -> None:
+        if value < 1:
+            raise ValueError("max_fixed_steps must be >= 1")
+        self._engine.max_fixed_steps = int(value)
+
+    @property
+    def fixed_alpha(self) -> float:
+        """
+        Get how far game time is between the last fixed step and the next,
+        from 0.0 to 1.0, to interpolate what `fixed_update` simulates when drawing.
+        """
+        return self._engine.fixed_alpha
+
+    @property
+    def parallel_updates(self) -> bool:
+        """
+        Get or set whether opted-in native components update on a thread pool, off by default.
+
+        Only Rust components that declare themselves thread-safe leave the main
+        thread, and only when many objects hold them. Built-in components,
+        Python scripts and Python callbacks (such as a button's ``on_click``)
+        always update on the main thread, so Python code never runs concurrently.
+        """
+        return self._engine.parallel_updates
+
+    @parallel_updates.setter
+    def parallel_updates(self, value: bool) -> None:
+        self._engine.parallel_updates = bool(value)
@@ -0,0 +1,265 @@
+"""
+Intro and branding sequences.
+
+`engine.play_intro([...])` shows a list of logo images one after another,
+fading each in and out, before the game's own update callback takes over.
+Any key, mouse button or gamepad button skips ahead, and assets queued with
+`preload` are loaded a few at a time while the logos are on screen.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Callable, Iterable
+from dataclasses import dataclass
+from typing import TYPE_CHECKING, Any, Optional, Union
+
+from .pyg_engine_native import Color
+
+if TYPE_CHECKING:
+    from .engine import Engine
+
+SKIP_SLIDE = "slide"
+SKIP_ALL = "all"
+
+_SKIP_MODES = (SKIP_SLIDE, SKIP_ALL, None)
+
+# Drawn above anything else that might still be queued.
+_DRAW_ORDER = 1_000_000.0
+
+
+def _color(rgba: tuple[float, ...]) -> Color:
+    if len(rgba) == 3:
+        return Color(*rgba, 1.0)
+    return Color(*rgba)
+
+
+@dataclass
+class IntroSlide:
+    """
+    One logo in an intro sequence.
+
+    Fields left as None use the sequence's defaults.
+
+    Attributes:
+        image: Image file path, relative to the source root
+        hold: Seconds fully visible between fading in and out
+        fade: Seconds spent fading in, and again fading out
+        scale: Largest fraction of the window the image may cover, keeping its aspect ratio
+        background: Background color as an (r, g, b[, a]) tuple
+    """
+
+    image: str
+    hold: Optional[float] = None
+    fade: Optional[float] = None
+    scale: Optional[float] = None
+    background: Optional[tuple[float, ...]] = None
+
+
+class IntroSequence:
+    """
+    Logo slides played before the game starts, created by `engine.play_intro()`.
+
+    Each slide fades in from the background color, holds, and fades out.
+    While `skip` is "slide", a key, mouse or gamepad press fades the current
+    slide out early; with "all" it ends the whole sequence, and None makes
+    the intro unskippable.
+
+    The sequence draws with the engine's direct drawing commands and clears
+    them every frame while it plays, so start queuing the game's own drawing
+    in `on_finished` or in the update callback. Only still images are supported.
+
+    `preload` entries are texture paths (passed to `engine.preload_texture()`)
+    or callables, run `preload_per_frame` at a time. The intro finishes once
+    its last slide is gone *and* every entry has run, so a skipped intro
+    still hands over a fully loaded game.
+    """
+
+    def __init__(
+        self,
+        engine: "Engine",
+        slides: Iterable[Union[str, IntroSlide]],
+        *,
+        hold: float = 2.0,
+        fade: float = 0.5,
+        scale: float = 0.6,
+        background: tuple[float, ...] = (0.0, 0.0, 0.0, 1.0),
+        skip: Optional[str] = SKIP_SLIDE,
+        preload: Iterable[Union[str, Callable[[], Any]]] = (),
+        preload_per_frame: int = 1,
+        on_finished: Optional[Callable[[], None]] = None,
+    ) -> None:
+        if skip not in _SKIP_MODES:
+            raise ValueError(f"skip must be 'slide', 'all' or None, got {skip!r}")
+        if hold < 0.0 or fade < 0.0:
+            raise ValueError("hold and fade must be >= 0.0")
+        if preload_per_frame < 1:
+            raise ValueError("preload_per_frame must be >= 1")
+
+        self._engine = engine
+        self._slides = [
+            IntroSlide(slide) if isinstance(slide, str) else slide for slide in slides
+        ]
+        self._hold = hold
+        self._fade = fade
+        self._scale = scale
+        self._background = background
+        self._skip = skip
+        self._preload = list(preload)
+        self._preload_total = len(self._preload)
+        self._preload_per_frame = preload_per_frame
+        self._on_finished = on_finished
+
+        self._index = 0
+        self._elapsed = 0.0
+        self._sizes: dict[str, tuple[int, int]] = {}
+        self._finished = False
+
+        for slide in self._slides:
+            engine.preload_texture(slide.image)
+
+    @property
+    def finished(self) -> bool:
+        """Get whether the sequence has ended and handed over to the game."""
+        return self._finished
+
+    @property
+    def slide_index(self) -> Optional[int]:
+        """Get the index of the slide on screen, or None once all slides are done."""
+        return self._index if self._index < len(self._slides) else None
+
+    @property
+    def load_progress(self) -> float:
+        """Get how many `preload` entries have run, from 0.0 to 1.0."""
+        if self._preload_total == 0:
+            return 1.0
+        return 1.0 - len(self._preload) / self._preload_total
+
+    def skip_slide(self) -> None:
+        """Fade the current slide out now."""
+        slide = self._current_slide()
+        if slide is None:
+            return
+        fade, hold = self._timing(slide)
+        fade_out_start = fade + hold
+        if self._elapsed < fade_out_start:
+            # Start the fade-out at the opacity already reached while fading in.
+            opacity = self._opacity(slide)
+            self._elapsed = fade_out_start + (1.0 - opacity) * fade
+
+    def skip_all(self) -> None:
+        """Drop the remaining slides; preloading still completes before the game starts."""
+        self._index = len(self._slides)
+        self._elapsed = 0.0
+
+    def update(self, delta_time: float) -> bool:
+        """
+        Advance and draw the sequence by one frame.
+
+        The engine calls this itself in `engine.run(update=...)` and
+        `engine.update()`.
+
+        Returns:
+            True while the intro is still playing.
+        """
+        if self._finished:
+            return False
+
+        if self._skip is not None and self._engine.input.any_pressed():
+            if self._skip == SKIP_ALL:
+                self.skip_all()
+            else:
+                self.skip_slide()
+
+        self._run_preload()
+
+        slide = self._current_slide()
+        if slide is not None:
+            fade, hold = self._timing(slide)
+            self._elapsed += delta_time
+            if self._elapsed >= 2.0 * fade + hold:
+                self._index += 1
+                self._elapsed = 0.0
+
+        self._engine.clear_draw_commands()
+        slide = self._current_slide()
+        if slide is not None:
+            self._draw(slide)
+        elif self._preload:
+            self._draw_background(self._background)
+        else:
+            self._finished = True
+            if self._on_finished is not None:
+                self._on_finished()
+            return False
+        return True
+
+    def _current_slide(self) -> Optional[IntroSlide]:
+        if self._index < len(self._slides):
+            return self._slides[self._index]
+        return None
+
+    def _timing(self, slide: IntroSlide) -> tuple[float, float]:
+        fade = self._fade if slide.fade is None else slide.fade
+        hold = self._hold if slide.hold is None else slide.hold
+        return fade, hold
+
+    def _opacity(self, slide: IntroSlide) -> float:
+        fade, hold = self._timing(slide)
+        if self._elapsed < fade:
+            return self._elapsed / fade
+        if self._elapsed < fade + hold or fade <= 0.0:
+            return 1.0
+        return max(0.0, 1.0 - (self._elapsed - fade - hold) / fade)
+
+    def _run_preload(self) -> None:
+        for _ in range(min(self._preload_per_frame, len(self._preload))):
+            entry = self._preload.pop(0)
+            if callable(entry):
+                entry()
+            else:
+                self._engine.preload_texture(entry)
+
+    def _image_size(self, path: str) -> tuple[int, int]:
+        if path not in self._sizes:
+            self._sizes[path] = self._engine.texture_size(path)
+        return self._sizes[path]
+
+    def _draw_background(self, background: tuple[float, ...]) -> None:
+        width, height = self._engine.get_display_size()
+        self._engine.draw_rectangle(
+            0, 0, width, height, _color(background), draw_order=_DRAW_ORDER
+        )
+
+    def _draw(self, slide: IntroSlide) -> None:
+        background = self._background if slide.background is None else slide.background
+        self._draw_background(background)
+
+        window_width, window_height = self._engine.get_display_size()
+        image_width, image_height = self._image_size(slide.image)
+        scale = self._scale if slide.scale is None else slide.scale
+        fit = min(
+            window_width * scale / max(image_width, 1),
+            window_height * scale / max(image_height, 1),
+        )
+        width, height = image_width * fit, image_height * fit
+        self._engine.draw_image(
+            (window_width - width) / 2,
+            (window_height - height) / 2,
+            width,
+            height,
+            slide.image,
+            draw_order=_DRAW_ORDER + 1,
+        )
+
+        # Fade by covering the image with the background color.
+        opacity = self._opacity(slide)
+        if opacity < 1.0:
+            r, g, b = background[:3]
+            self._engine.draw_rectangle(
+                0,
+                0,
+                window_width,
+                window_height,
+                _color((r, g, b, 1.0 - opacity)),
+                draw_order=_DRAW_ORDER + 2,
+            )
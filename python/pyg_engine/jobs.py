@@ -0,0 +1,280 @@
+"""
+Background jobs run on a thread pool managed by the engine.
+
+`engine.run_job(fn, *args)` runs `fn` on a worker thread and returns a
+`JobHandle` right away, so pathfinding, procedural generation or file IO does
+not stall the frame. The result comes back on the main thread, in the engine's
+update, through the job's `on_complete` callback:
+
+    def build_level(seed):
+        return generate_tiles(seed)  # Slow, runs on a worker thread
+
+    def show_level(tiles):
+        place_tiles(tiles)  # Runs in the engine update, safe to touch the engine
+
+    engine.run_job(build_level, 42, on_complete=show_level)
+
+Jobs run Python code, which shares the interpreter lock with the game: they
+keep the frame responsive for IO and for work in libraries that release the
+lock, and interleave with the frame for pure Python loops.
+"""
+
+from __future__ import annotations
+
+import itertools
+import os
+import threading
+from collections import deque
+from concurrent.futures import Future, ThreadPoolExecutor
+from concurrent.futures import wait as wait_futures
+from typing import TYPE_CHECKING, Any, Callable, Optional
+
+if TYPE_CHECKING:
+    from .engine import Engine
+
+PENDING = "pending"
+RUNNING = "running"
+SUCCEEDED = "succeeded"
+FAILED = "failed"
+CANCELLED = "cancelled"
+
+
+class JobHandle:
+    """
+    A job started with `engine.run_job()`.
+
+    The status goes from `"pending"` (queued for a worker) through
+    `"running"` to `"succeeded"`, `"failed"` or `"cancelled"`. Only pending
+    jobs can be cancelled; a running job always finishes.
+    """
+
+    def __init__(
+        self,
+        job_id: int,
+        name: str,
+        future: Future,
+        on_complete: Optional[Callable[[Any], Any]],
+        on_error: Optional[Callable[[BaseException], Any]],
+    ) -> None:
+        self.id = job_id
+        self.name = name
+        self.on_complete = on_complete
+        self.on_error = on_error
+        self._future = future
+        self._dispatched = False
+
+    @property
+    def status(self) -> str:
+        """Get `"pending"`, `"running"`, `"succeeded"`, `"failed"` or `"cancelled"`."""
+        future = self._future
+        if future.cancelled():
+            return CANCELLED
+        if not future.done():
+            return RUNNING if future.running() else PENDING
+        return FAILED if future.exception() is not None else SUCCEEDED
+
+    @property
+    def done(self) -> bool:
+        """Check whether the job finished, failed or was cancelled."""
+        return self._future.done()
+
+    @property
+    def dispatched(self) -> bool:
+        """Check whether the job's callbacks have run on the main thread."""
+        return self._dispatched
+
+    @property
+    def error(self) -> Optional[BaseException]:
+        """Get the exception the job raised, or None."""
+        if not self._future.done() or self._future.cancelled():
+            return None
+        return self._future.exception()
+
+    def result(self, timeout: Optional[float] = None) -> Any:
+        """
+        Wait for the job and get its return value.
+
+        Blocking the main thread stalls the game, so this is meant for
+        loading screens and tests; callbacks are still dispatched by the
+        engine update.
+
+        Raises:
+            TimeoutError: If the job did not finish within `timeout` seconds.
+            CancelledError: If the job was cancelled.
+            Exception: Whatever the job raised.
+        """
+        return self._future.result(timeout)
+
+    def cancel(self) -> bool:
+        """Cancel the job if it has not started. Returns False if it is running or done."""
+        return self._future.cancel()
+
+    def __repr__(self) -> str:
+        return f"JobHandle(id={self.id}, name={self.name!r}, status={self.status!r})"
+
+
+class JobManager:
+    """
+    Runs background jobs and hands their results back to the main thread.
+
+    Results are dispatched in loops where Python drives the frame, i.e.
+    `engine.run(update=...)` and manual `engine.update()` calls, or whenever
+    `poll()` is called directly, like `engine.timers`. The native blocking
+    `engine.run()` loop does not dispatch them.
+
+    A job that raises calls its `on_error` callback with the exception; jobs
+    without one report it like other callback errors (see
+    `engine.callback_error_policy`), as do exceptions from the callbacks
+    themselves.
+
+    **Example:**
+
+        ```python
+        import json
+        from pyg_engine import Engine
+
+        engine = Engine()
+
+        def load_level(path):
+            with open(path) as file:
+                return json.load(file)
+
+        job = engine.run_job(load_level, "levels/forest.json", on_complete=build_level)
+
+        def update(ctx):
+            if not job.done:
+                draw_spinner()
+
+        engine.run(update=update)
+        ```
+    """
+
+    def __init__(self, engine: "Engine") -> None:
+        self._engine = engine
+        self._executor: Optional[ThreadPoolExecutor] = None
+        self._max_workers = min(4, os.cpu_count() or 1)
+        self._ids = itertools.count(1)
+        self._active: dict[int, JobHandle] = {}
+        # Filled by worker threads, drained by `poll()` on the main thread.
+        self._finished: deque[JobHandle] = deque()
+        self._lock = threading.Lock()
+
+    @property
+    def max_workers(self) -> int:
+        """Get the number of worker threads."""
+        return self._max_workers
+
+    @max_workers.setter
+    def max_workers(self, value: int) -> None:
+        """Set the number of worker threads; jobs already started keep their pool."""
+        if value < 1:
+            raise ValueError("max_workers must be >= 1")
+        self._max_workers = int(value)
+        if self._executor is not None:
+            self._executor.shutdown(wait=False)
+            self._executor = None
+
+    def run(
+        self,
+        fn: Callable[..., Any],
+        *args: Any,
+        name: Optional[str] = None,
+        on_complete: Optional[Callable[[Any], Any]] = None,
+        on_error: Optional[Callable[[BaseException], Any]] = None,
+        **kwargs: Any,
+    ) -> JobHandle:
+        """
+        Run `fn(*args, **kwargs)` on a worker thread.
+
+        The job must not call into the engine, which is not thread-safe;
+        use `on_complete`, which runs on the main thread, or an
+        `EngineHandle` from `engine.get_handle()`.
+
+        Args:
+            fn: Function to run.
+            name: Name for logs and errors (default: the function's name).
+            on_complete: Called on the main thread with the return value.
+            on_error: Called on the main thread with the exception the job raised.
+
+        Returns:
+            JobHandle: To check on, wait for or cancel the job.
+        """
+        if self._executor is None:
+            self._executor = ThreadPoolExecutor(
+                max_workers=self._max_workers, thread_name_prefix="pyg-job"
+            )
+        job_name = name if name is not None else getattr(fn, "__name__", "job")
+        future = self._executor.submit(fn, *args, **kwargs)
+        handle = JobHandle(next(self._ids), job_name, future, on_complete, on_error)
+        with self._lock:
+            self._active[handle.id] = handle
+        future.add_done_callback(lambda _: self._finished.append(handle))
+        return handle
+
+    @property
+    def active_count(self) -> int:
+        """Number of jobs whose callbacks have not been dispatched yet."""
+        with self._lock:
+            return len(self._active)
+
+    def active(self) -> list[JobHandle]:
+        """Get the jobs whose callbacks have not been dispatched yet, oldest first."""
+        with self._lock:
+            return list(self._active.values())
+
+    def poll(self) -> list[JobHandle]:
+        """Run the callbacks of finished jobs on this thread. Returns those jobs."""
+        dispatched = []
+        while self._finished:
+            handle = self._finished.popleft()
+            with self._lock:
+                self._active.pop(handle.id, None)
+            handle._dispatched = True
+            dispatched.append(handle)
+            self._dispatch(handle)
+        return dispatched
+
+    def wait_all(self, timeout: Optional[float] = None) -> bool:
+        """
+        Block until every active job finishes, then dispatch their callbacks.
+
+        Returns:
+            False if some job was still running after `timeout` seconds.
+        """
+        futures = [handle._future for handle in self.active()]
+        _, not_done = wait_futures(futures, timeout)
+        self.poll()
+        return not not_done
+
+    def shutdown(self, wait: bool = True) -> None:
+        """
+        Cancel pending jobs and stop the worker threads once running jobs end.
+
+        Later `run()` calls start a new pool.
+
+        Args:
+            wait: Block until running jobs finish and dispatch their callbacks.
+        """
+        if self._executor is None:
+            return
+        self._executor.shutdown(wait=wait, cancel_futures=True)
+        self._executor = None
+        if wait:
+            self.poll()
+
+    def _dispatch(self, handle: JobHandle) -> None:
+        future = handle._future
+        if future.cancelled():
+            return
+        native = self._engine._engine
+        error = future.exception()
+        try:
+            if error is None:
+                if handle.on_complete is not None:
+                    handle.on_complete(future.result())
+            elif handle.on_error is not None:
+                handle.on_error(error)
+            else:
+                native.report_callback_error(f"job '{handle.name}'", error)
+        except Exception as exc:
+            native.report_callback_error(f"job '{handle.name}' callback", exc)
@@ -0,0 +1,3021 @@
+"""
+Type stubs for the compiled `pyg_engine.pyg_engine_native` module.
+
+Generated by `tools/generate_stubs.py` from the pyo3 bindings; don't edit by
+hand.
+"""
+
+from typing import Any, ClassVar, Iterator, Mapping, Sequence
+
+
+class AnimationCurve:
+    """Python wrapper for AnimationCurve."""
+    def __init__(self, keys: Sequence[tuple[float, float]] = ..., interpolation: str = ...) -> None: ...
+    def add_key(self, time: float, value: float) -> int:
+        """Insert a key and return its index."""
+        ...
+    def clear(self) -> None: ...
+    def copy(self) -> AnimationCurve: ...
+    def evaluate(self, time: float) -> float:
+        """Sample the curve at `time`. Times outside the keys clamp to the first or last key."""
+        ...
+    def get_interpolation(self) -> str: ...
+    def get_keys(self) -> list[tuple[float, float]]:
+        """Get the keys as a list of `(time, value)` sorted by time."""
+        ...
+    @staticmethod
+    def linear(start_time: float, start_value: float, end_time: float, end_value: float) -> AnimationCurve:
+        """Create a straight line between two keys."""
+        ...
+    def remove_key(self, index: int) -> tuple[float, float]:
+        """Remove the key at `index`, returning it as `(time, value)`."""
+        ...
+    def set_interpolation(self, interpolation: str) -> None:
+        """Set the interpolation: "linear", "smooth" or "constant"."""
+        ...
+    def set_key(self, index: int, time: float, value: float) -> int:
+        """Move the key at `index` and return its new index after re-sorting."""
+        ...
+    def __len__(self, /) -> int: ...
+    def __repr__(self, /) -> str: ...
+
+class AssetHandle:
+    """A loaded asset file, see `Engine.assets`."""
+    @property
+    def id(self) -> int: ...
+    @property
+    def kind(self) -> str: ...
+    @property
+    def path(self) -> str: ...
+    def __repr__(self, /) -> str: ...
+
+class AudioListener:
+    """
+    Hears positional sounds from its `GameObject`, e.g. the player rather than
+    the camera.
+    """
+    def __init__(self, pan_width: float = ..., name: str | None = ...) -> None: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def pan_width(self) -> float: ...
+    @pan_width.setter
+    def pan_width(self, value: float) -> None: ...
+
+class AudioSourceComponent:
+    """Plays a sound from its `GameObject`'s position."""
+    def __init__(self, sound: Any = ..., volume: float = ..., looping: bool = ..., spatial: bool = ..., attenuation: str = ..., min_distance: float = ..., max_distance: float = ..., rolloff: float = ..., bus: str = ..., pitch: float = ..., pitch_range: tuple[float, float] | None = ..., priority: int = ..., autoplay: bool = ..., name: str | None = ...) -> None: ...
+    @property
+    def attenuation(self) -> str: ...
+    @attenuation.setter
+    def attenuation(self, value: str) -> None: ...
+    @property
+    def bus(self) -> str: ...
+    @bus.setter
+    def bus(self, value: str) -> None: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def looping(self) -> bool: ...
+    @looping.setter
+    def looping(self, value: bool) -> None: ...
+    @property
+    def max_distance(self) -> float: ...
+    @max_distance.setter
+    def max_distance(self, value: float) -> None: ...
+    @property
+    def min_distance(self) -> float: ...
+    @min_distance.setter
+    def min_distance(self, value: float) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def pitch(self) -> float: ...
+    @pitch.setter
+    def pitch(self, value: float) -> None: ...
+    @property
+    def pitch_range(self) -> tuple[float, float] | None: ...
+    @pitch_range.setter
+    def pitch_range(self, value: tuple[float, float] | None) -> None: ...
+    def play(self) -> None:
+        """Start the sound from the beginning, even if it is playing."""
+        ...
+    @property
+    def playing(self) -> bool: ...
+    @property
+    def priority(self) -> int: ...
+    @priority.setter
+    def priority(self, value: int) -> None: ...
+    @property
+    def rolloff(self) -> float: ...
+    @rolloff.setter
+    def rolloff(self, value: float) -> None: ...
+    @property
+    def sound(self) -> str | None: ...
+    @sound.setter
+    def sound(self, value: Any) -> None: ...
+    @property
+    def spatial(self) -> bool: ...
+    @spatial.setter
+    def spatial(self, value: bool) -> None: ...
+    def stop(self) -> None: ...
+    @property
+    def volume(self) -> float: ...
+    @volume.setter
+    def volume(self, value: float) -> None: ...
+    def __repr__(self, /) -> str: ...
+
+class ButtonComponent:
+    """Python wrapper for ButtonComponent."""
+    def __init__(self, text: str = ..., x: float = ..., y: float = ..., width: float = ..., height: float = ...) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    def get_text(self) -> str: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    def set_background_gradient(self, top: tuple[float, float, float, float], bottom: tuple[float, float, float, float] | None = ..., state: str | None = ...) -> None:
+        """
+        Fill the background with a vertical gradient, in one state or (with
+        `state=None`) all of them. `bottom=None` goes back to a solid `top` color.
+        """
+        ...
+    def set_corner_radius(self, radius: float) -> None:
+        """Round the corners of the background, border and shadow in every state."""
+        ...
+    def set_depth(self, depth: float) -> None: ...
+    def set_enabled(self, enabled: bool) -> None: ...
+    def set_font_fallbacks(self, fallbacks: Sequence[str]) -> None:
+        """
+        Set the fonts tried, in order, for characters the primary font lacks.
+        An empty list goes back to the UI theme's fallbacks.
+        """
+        ...
+    def set_font_family(self, font_family: str | None) -> None: ...
+    def set_font_path(self, font_path: str | None) -> None: ...
+    def set_font_size(self, font_size: float) -> None: ...
+    def set_font_style(self, font_style: str) -> None: ...
+    def set_font_weight(self, font_weight: str) -> None: ...
+    def set_kerning(self, kerning: bool) -> None: ...
+    def set_letter_spacing(self, letter_spacing: float) -> None: ...
+    def set_line_spacing(self, line_spacing: float) -> None: ...
+    def set_on_click(self, py_callback: Any) -> None:
+        """Set a Python callback for the button click event."""
+        ...
+    def set_on_hover_enter(self, py_callback: Any) -> None:
+        """Set a callback fired when the mouse starts hovering the button."""
+        ...
+    def set_on_hover_exit(self, py_callback: Any) -> None:
+        """Set a callback fired when the mouse stops hovering the button."""
+        ...
+    def set_on_press(self, py_callback: Any) -> None:
+        """Set a callback fired when a mouse button goes down on the button."""
+        ...
+    def set_on_release(self, py_callback: Any) -> None:
+        """
+        Set a callback fired once per press, when the mouse button is released
+        or the pointer is dragged off the held button.
+        """
+        ...
+    def set_position(self, x: float, y: float) -> None: ...
+    def set_repeat_interval(self, interval_ms: float | None) -> None:
+        """Set the repeat interval in milliseconds for when the button is held down."""
+        ...
+    def set_shadow(self, r: float, g: float, b: float, a: float, offset_x: float = ..., offset_y: float = ..., blur: float = ...) -> None:
+        """Set the drop shadow in every state. Alpha 0 removes it."""
+        ...
+    def set_size(self, width: float, height: float) -> None: ...
+    def set_text(self, text: str) -> None: ...
+    def set_trigger_on(self, trigger_on: str) -> None:
+        """Set when the button callback is triggered."""
+        ...
+
+class CallbackError:
+    """An exception raised by a Python callback the engine called."""
+    @property
+    def exception_type(self) -> str: ...
+    @property
+    def message(self) -> str: ...
+    @property
+    def source(self) -> Any: ...
+    @property
+    def traceback(self) -> Any: ...
+    def __repr__(self, /) -> str: ...
+
+class CameraAspectMode:
+    FILL_BOTH: ClassVar[str]
+    FIT_BOTH: ClassVar[str]
+    MATCH_HORIZONTAL: ClassVar[str]
+    MATCH_VERTICAL: ClassVar[str]
+    STRETCH: ClassVar[str]
+
+class CanvasComponent:
+    """Python wrapper for CanvasComponent."""
+    def __init__(self, x: float = ..., y: float = ..., width: float = ..., height: float = ..., redraw_every_frame: bool = ...) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    def get_bounds(self) -> tuple[float, float, float, float]: ...
+    def get_redraw_every_frame(self) -> bool: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    def request_redraw(self) -> None:
+        """
+        Run the render callback again on the next frame. Works on the
+        component after it was added to the engine too.
+        """
+        ...
+    def set_depth(self, depth: float) -> None: ...
+    def set_on_render(self, py_callback: Any) -> None:
+        """Set a Python callback fired with a `CanvasPainter` whenever the canvas is drawn."""
+        ...
+    def set_position(self, x: float, y: float) -> None: ...
+    def set_redraw_every_frame(self, redraw_every_frame: bool) -> None:
+        """Run the render callback on every frame, e.g. for live charts."""
+        ...
+    def set_size(self, width: float, height: float) -> None: ...
+
+class CanvasPainter:
+    """Local drawing surface handed to a `CanvasComponent` render callback."""
+    def arc(self, center_x: float, center_y: float, radius: float, start_angle: float, end_angle: float, color: Color, filled: bool = ..., thickness: float = ..., segments: int = ..., draw_order: float = ...) -> None:
+        """Draw an arc or pie slice at canvas coordinates."""
+        ...
+    def circle(self, center_x: float, center_y: float, radius: float, color: Color, filled: bool = ..., thickness: float = ..., segments: int = ..., draw_order: float = ...) -> None:
+        """Draw a circle at canvas coordinates."""
+        ...
+    @property
+    def height(self) -> float: ...
+    def image(self, x: float, y: float, width: float, height: float, texture_path: str, draw_order: float = ...) -> None:
+        """Draw an image from a file path at canvas coordinates."""
+        ...
+    def line(self, start_x: float, start_y: float, end_x: float, end_y: float, color: Color, thickness: float = ..., draw_order: float = ...) -> None:
+        """Draw a line at canvas coordinates."""
+        ...
+    def pixel(self, x: float, y: float, color: Color, draw_order: float = ...) -> None:
+        """Draw a pixel at canvas coordinates."""
+        ...
+    def polygon(self, points: Any, color: Color, filled: bool = ..., thickness: float = ..., draw_order: float = ...) -> None:
+        """
+        Draw a polygon, filled or as a closed outline, from `(x, y)` points,
+        `Vec2`s or a float array shaped `(n, 2)`.
+        """
+        ...
+    def pop_clip(self) -> None: ...
+    def push_clip(self, x: float, y: float, width: float, height: float) -> None:
+        """
+        Clip what is drawn from now on to a rectangle in canvas coordinates,
+        within the canvas bounds. Match each push with `pop_clip`.
+        """
+        ...
+    def rectangle(self, x: float, y: float, width: float, height: float, color: Color, filled: bool = ..., thickness: float = ..., draw_order: float = ...) -> None:
+        """Draw a rectangle at canvas coordinates."""
+        ...
+    def text(self, text: str, x: float, y: float, color: Color, font_size: float = ..., font_path: str | None = ..., font_family: str | None = ..., font_weight: str | None = ..., font_style: str | None = ..., draw_order: float = ...) -> None:
+        """Draw text at canvas coordinates."""
+        ...
+    @property
+    def width(self) -> float: ...
+    def __repr__(self, /) -> str: ...
+
+class CheckboxComponent:
+    """Python wrapper for CheckboxComponent."""
+    def __init__(self, label: str = ..., checked: bool = ..., x: float = ..., y: float = ..., width: float = ..., height: float = ...) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    def get_label(self) -> str: ...
+    @property
+    def id(self) -> int: ...
+    def is_checked(self) -> bool: ...
+    @property
+    def name(self) -> str: ...
+    def set_checked(self, checked: bool) -> None:
+        """Set the checked state without firing `on_toggled`."""
+        ...
+    def set_depth(self, depth: float) -> None: ...
+    def set_font_family(self, font_family: str | None) -> None: ...
+    def set_font_path(self, font_path: str | None) -> None: ...
+    def set_font_size(self, font_size: float) -> None: ...
+    def set_font_style(self, font_style: str) -> None: ...
+    def set_font_weight(self, font_weight: str) -> None: ...
+    def set_label(self, label: str) -> None: ...
+    def set_on_toggled(self, py_callback: Any) -> None:
+        """Set a Python callback fired with the new state whenever the user toggles the checkbox."""
+        ...
+    def set_position(self, x: float, y: float) -> None: ...
+    def set_size(self, width: float, height: float) -> None: ...
+
+class Collider:
+    """Collider component for collision detection."""
+    def __init__(self, name: str) -> None: ...
+    @property
+    def collision_mask(self) -> int: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def is_trigger(self) -> bool: ...
+    @property
+    def layer(self) -> int: ...
+    @property
+    def material(self) -> str | None: ...
+    @property
+    def name(self) -> str: ...
+    def set_collision_mask(self, mask: int) -> None:
+        """Set the collision mask."""
+        ...
+    def set_layer(self, layer: int) -> None:
+        """Set the physics layer (0-31)."""
+        ...
+    def set_material(self, material: str | None) -> None:
+        """Set the surface material, or None."""
+        ...
+    def set_offset(self, x: float, y: float) -> None:
+        """Set the collider's offset from the GameObject center."""
+        ...
+    def set_on_collision_enter(self, callback: Any) -> None:
+        """Set callback fired when collision starts."""
+        ...
+    def set_on_collision_exit(self, callback: Any) -> None:
+        """Set callback fired when collision ends."""
+        ...
+    def set_on_collision_stay(self, callback: Any) -> None:
+        """Set callback fired every frame during collision."""
+        ...
+    def set_shape(self, shape: ColliderShape) -> None:
+        """Set the collision shape."""
+        ...
+    def set_trigger(self, is_trigger: bool) -> None:
+        """Set whether this is a trigger collider."""
+        ...
+
+class ColliderShape:
+    """Collider shape builder for creating collision shapes."""
+    __hash__: ClassVar[None]  # type: ignore[assignment]
+
+    @staticmethod
+    def box_shape(half_width: float, half_height: float) -> ColliderShape:
+        """Create a box (rectangle) collision shape."""
+        ...
+    @staticmethod
+    def circle(radius: float) -> ColliderShape:
+        """Create a circular collision shape."""
+        ...
+    @staticmethod
+    def obb(half_width: float, half_height: float, local_rotation: float) -> ColliderShape:
+        """Create an oriented box (OBB) collision shape."""
+        ...
+    @staticmethod
+    def polygon(vertices: Any) -> ColliderShape:
+        """Create a custom polygon collision shape."""
+        ...
+    def __copy__(self) -> ColliderShape: ...
+    def __deepcopy__(self, _memo: Any) -> ColliderShape: ...
+    def __eq__(self, value: object, /) -> bool: ...
+    def __reduce__(self) -> tuple[Any, tuple[Any, ...]]: ...
+    def __repr__(self, /) -> str: ...
+
+class Color:
+    """RGBA color representation with float components in range [0.0, 1.0]."""
+    BLACK: ClassVar[Color]
+    BLUE: ClassVar[Color]
+    BROWN: ClassVar[Color]
+    CYAN: ClassVar[Color]
+    DARK_GRAY: ClassVar[Color]
+    DARK_GREY: ClassVar[Color]
+    GRAY: ClassVar[Color]
+    GREEN: ClassVar[Color]
+    GREY: ClassVar[Color]
+    LIGHT_GRAY: ClassVar[Color]
+    LIGHT_GREY: ClassVar[Color]
+    LIME: ClassVar[Color]
+    MAGENTA: ClassVar[Color]
+    ORANGE: ClassVar[Color]
+    PINK: ClassVar[Color]
+    PURPLE: ClassVar[Color]
+    RED: ClassVar[Color]
+    TRANSPARENT: ClassVar[Color]
+    WHITE: ClassVar[Color]
+    YELLOW: ClassVar[Color]
+    __hash__: ClassVar[None]  # type: ignore[assignment]
+
+    def __init__(self, r: float, g: float, b: float, a: float) -> None: ...
+    @property
+    def a(self) -> float: ...
+    @property
+    def b(self) -> float: ...
+    def darken(self, amount: float) -> Color:
+        """Create a darker version of this color."""
+        ...
+    @staticmethod
+    def from_hex(hex: str) -> Color:
+        """Create a color from a hex string."""
+        ...
+    @staticmethod
+    def from_hsv(h: float, s: float, v: float, a: float) -> Color:
+        """Create a color from HSV (Hue, Saturation, Value) color space."""
+        ...
+    @property
+    def g(self) -> float: ...
+    def grayscale(self) -> Color:
+        """Create the gray of the same perceived brightness, keeping alpha."""
+        ...
+    def inverted(self) -> Color:
+        """Create the photographic negative of this color."""
+        ...
+    def lerp(self, other: Color, t: float) -> Color:
+        """Linearly interpolate between two colors."""
+        ...
+    def lighten(self, amount: float) -> Color:
+        """Create a lighter version of this color."""
+        ...
+    @property
+    def r(self) -> float: ...
+    @staticmethod
+    def rgb(r: int, g: int, b: int) -> Color:
+        """Create an opaque color from RGB integer values in range [0, 255]."""
+        ...
+    @staticmethod
+    def rgba(r: int, g: int, b: int, a: int) -> Color:
+        """Create a color from RGBA integer values in range [0, 255]."""
+        ...
+    def set_a(self, a: float) -> Color:
+        """Create a new color with a different alpha component."""
+        ...
+    def set_b(self, b: float) -> Color:
+        """Create a new color with a different blue component."""
+        ...
+    def set_g(self, g: float) -> Color:
+        """Create a new color with a different green component."""
+        ...
+    def set_r(self, r: float) -> Color:
+        """Create a new color with a different red component."""
+        ...
+    def to_rgba8(self) -> tuple[int, int, int, int]:
+        """Get the components as integers in range [0, 255]."""
+        ...
+    def with_alpha(self, a: float) -> Color:
+        """Create a new color with a different alpha value."""
+        ...
+    def __abs__(self, /) -> Color: ...
+    def __add__(self, value: Color, /) -> Color: ...
+    def __copy__(self) -> Color: ...
+    def __deepcopy__(self, _memo: Any) -> Color: ...
+    def __eq__(self, value: object, /) -> bool: ...
+    def __mul__(self, value: Any, /) -> Color: ...
+    def __ne__(self, value: object, /) -> bool: ...
+    def __neg__(self, /) -> Color: ...
+    def __reduce__(self) -> tuple[type, tuple[float, float, float, float]]: ...
+    def __repr__(self, /) -> str: ...
+    def __rmul__(self, value: Any, /) -> Color: ...
+    def __str__(self, /) -> str: ...
+    def __sub__(self, value: Color, /) -> Color: ...
+    def __truediv__(self, value: Color, /) -> Color: ...
+
+class CurveEditorComponent:
+    """Python wrapper for CurveEditorComponent."""
+    def __init__(self, curve: AnimationCurve | None = ..., x: float = ..., y: float = ..., width: float = ..., height: float = ..., time_range: tuple[float, float] = ..., value_range: tuple[float, float] = ...) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    def get_curve(self) -> AnimationCurve:
+        """Get a copy of the edited curve."""
+        ...
+    def get_grid_divisions(self) -> int: ...
+    def get_selected_key(self) -> int | None:
+        """Index of the selected key, or None."""
+        ...
+    def get_time_range(self) -> tuple[float, float]: ...
+    def get_value_range(self) -> tuple[float, float]: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    def set_curve(self, curve: AnimationCurve) -> None:
+        """Replace the edited curve without firing `on_curve_changed`."""
+        ...
+    def set_depth(self, depth: float) -> None: ...
+    def set_grid_divisions(self, divisions: int) -> None:
+        """Set the number of grid divisions along each axis (0 hides the grid)."""
+        ...
+    def set_on_curve_changed(self, py_callback: Any) -> None:
+        """Set a Python callback fired with a copy of the curve whenever the user edits it."""
+        ...
+    def set_position(self, x: float, y: float) -> None: ...
+    def set_size(self, width: float, height: float) -> None: ...
+    def set_time_range(self, start: float, end: float) -> None: ...
+    def set_value_range(self, min_value: float, max_value: float) -> None: ...
+
+class CustomWidgetComponent:
+    """Python wrapper for CustomWidgetComponent."""
+    def __init__(self, x: float = ..., y: float = ..., width: float = ..., height: float = ...) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    def get_bounds(self) -> tuple[float, float, float, float]: ...
+    def get_capture_events(self) -> bool: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    def set_capture_events(self, capture_events: bool) -> None:
+        """
+        Also deliver pointer events headed for the widget's UI descendants to
+        the callback, in the "capture" phase before the descendant sees them.
+        """
+        ...
+    def set_depth(self, depth: float) -> None: ...
+    def set_on_event(self, py_callback: Any) -> None:
+        """Set a Python callback fired with a `UIEvent` for every event the widget receives."""
+        ...
+    def set_position(self, x: float, y: float) -> None: ...
+    def set_size(self, width: float, height: float) -> None: ...
+
+class DrawCommand:
+    """Python-side draw command builder used for bulk submission."""
+    @staticmethod
+    def arc(center_x: float, center_y: float, radius: float, start_angle: float, end_angle: float, color: Color, filled: bool = ..., thickness: float = ..., segments: int = ..., draw_order: float = ...) -> DrawCommand: ...
+    @staticmethod
+    def circle(center_x: float, center_y: float, radius: float, color: Color, filled: bool = ..., thickness: float = ..., segments: int = ..., draw_order: float = ...) -> DrawCommand:
+        """Draw a circle at the specified center position."""
+        ...
+    @staticmethod
+    def gradient_rect(x: float, y: float, width: float, height: float, top_left: Color, bottom_left: Color, bottom_right: Color, top_right: Color, draw_order: float = ...) -> DrawCommand:
+        """Draw a rectangle with gradient colors at each corner."""
+        ...
+    @staticmethod
+    def image(x: float, y: float, width: float, height: float, texture_path: str, draw_order: float = ...) -> DrawCommand:
+        """Draw an image loaded from a file path."""
+        ...
+    @staticmethod
+    def image_from_bytes(x: float, y: float, width: float, height: float, texture_key: str, rgba: Any, texture_width: int, texture_height: int, draw_order: float = ...) -> DrawCommand:
+        """Draw an image from raw RGBA pixel data."""
+        ...
+    @staticmethod
+    def line(start_x: float, start_y: float, end_x: float, end_y: float, color: Color, thickness: float = ..., draw_order: float = ...) -> DrawCommand:
+        """Draw a line segment between two points."""
+        ...
+    @staticmethod
+    def mesh(vertices: Any, indices: Sequence[int], color: Color, texture_path: str | None = ..., uvs: Any = ..., draw_order: float = ...) -> DrawCommand: ...
+    @staticmethod
+    def pixel(x: int, y: int, color: Color, draw_order: float = ...) -> DrawCommand:
+        """Draw a single pixel at the specified position."""
+        ...
+    @staticmethod
+    def polygon(points: Any, color: Color, filled: bool = ..., thickness: float = ..., draw_order: float = ...) -> DrawCommand:
+        """Polygon from `(x, y)` pairs, `Vec2`s, or a float array shaped `(n, 2)`."""
+        ...
+    @staticmethod
+    def rectangle(x: float, y: float, width: float, height: float, color: Color, filled: bool = ..., thickness: float = ..., draw_order: float = ...) -> DrawCommand:
+        """Draw a rectangle at the specified position."""
+        ...
+    @staticmethod
+    def text(text: str, x: float, y: float, color: Color, font_size: float = ..., font_path: str | None = ..., font_family: str | None = ..., font_weight: str | None = ..., font_style: str | None = ..., letter_spacing: float = ..., line_spacing: float = ..., kerning: bool = ..., draw_order: float = ...) -> DrawCommand:
+        """Draw text at the specified position."""
+        ...
+    @staticmethod
+    def world_text(text: str, x: float, y: float, color: Color, font_size: float = ..., font_path: str | None = ..., font_family: str | None = ..., font_weight: str | None = ..., font_style: str | None = ..., draw_order: float = ...) -> DrawCommand:
+        """Draw text centered on a point in world space."""
+        ...
+
+class DrawRecorder:
+    """Records draw calls into a native buffer and submits them in one batch."""
+    def add(self, command: DrawCommand) -> None:
+        """Record a prebuilt `DrawCommand`."""
+        ...
+    def circle(self, center_x: float, center_y: float, radius: float, color: Color, filled: bool = ..., thickness: float = ..., segments: int = ..., draw_order: float = ...) -> None:
+        """Record a circle at window coordinates."""
+        ...
+    def circles(self, centers: Any, radius: float, color: Color, filled: bool = ..., thickness: float = ..., segments: int = ..., draw_order: float = ...) -> None:
+        """
+        Record a circle at each of `centers`, which may be `(x, y)` pairs,
+        `Vec2`s or a float array shaped `(n, 2)` such as particle positions.
+        """
+        ...
+    def clear(self) -> None:
+        """Discard the recorded commands without submitting them."""
+        ...
+    def flush(self) -> None:
+        """Submit the recorded commands in one batch and start a new buffer."""
+        ...
+    def image(self, x: float, y: float, width: float, height: float, texture_path: str, draw_order: float = ...) -> None:
+        """Record an image from a file path at window coordinates."""
+        ...
+    def line(self, start_x: float, start_y: float, end_x: float, end_y: float, color: Color, thickness: float = ..., draw_order: float = ...) -> None:
+        """Record a line at window coordinates."""
+        ...
+    def pixel(self, x: float, y: float, color: Color, draw_order: float = ...) -> None:
+        """Record a pixel at window coordinates."""
+        ...
+    def rectangle(self, x: float, y: float, width: float, height: float, color: Color, filled: bool = ..., thickness: float = ..., draw_order: float = ...) -> None:
+        """Record a rectangle at window coordinates."""
+        ...
+    def text(self, text: str, x: float, y: float, color: Color, font_size: float = ..., draw_order: float = ...) -> None:
+        """Record text at window coordinates with the default font."""
+        ...
+    def __enter__(self) -> DrawRecorder: ...
+    def __exit__(self, exc_type: Any = ..., _exc_value: Any = ..., _traceback: Any = ...) -> bool: ...
+    def __len__(self, /) -> int: ...
+    def __repr__(self, /) -> str: ...
+
+class Engine:
+    """Python wrapper for the Rust Engine."""
+    def __init__(self, enable_file_logging: bool | None = ..., log_directory: str | None = ..., log_level: str | None = ..., config_path: str | None = ...) -> None: ...
+    def action_buffered(self, action_name: str, window: float) -> bool:
+        """Check if an action was pressed within the last `window` seconds."""
+        ...
+    def action_down(self, action_name: str) -> bool:
+        """Check whether an action is currently active (held)."""
+        ...
+    def action_glyphs(self, action_name: str, device: str | None = ...) -> list[tuple[str, str | None, str]]:
+        """Get display glyphs for the current bindings of an action."""
+        ...
+    def action_names(self) -> list[str]:
+        """List all configured action names."""
+        ...
+    def action_pressed(self, action_name: str) -> bool:
+        """Check whether an action was pressed this frame."""
+        ...
+    def action_prompt(self, action_name: str, device: str | None = ...) -> str:
+        """Get bracketed prompt text for an action, e.g. `"[Space]"` or `"[E]/[LMB]"`."""
+        ...
+    def action_released(self, action_name: str) -> bool:
+        """Check whether an action was released this frame."""
+        ...
+    def add_action_chord(self, action_name: str, chord: str) -> None:
+        """Add one modifier chord to an action, e.g. `"ctrl+s"`."""
+        ...
+    def add_action_key(self, action_name: str, key_name: str) -> None:
+        """Add one keyboard key to an action."""
+        ...
+    def add_action_mouse_button(self, action_name: str, button: str) -> None:
+        """Add one mouse button to an action."""
+        ...
+    def add_audio_snapshot(self, name: str, buses: Mapping[str, Mapping[str, Any]]) -> None:
+        """
+        Save bus settings as a snapshot for `transition_audio_snapshot`, as
+        `{bus: {setting: value}}` with the settings of `get_audio_bus`.
+        Settings left out are not changed by the snapshot.
+        """
+        ...
+    def add_axis_negative_key(self, axis_name: str, key_name: str) -> None:
+        """Add one negative key to an axis binding."""
+        ...
+    def add_axis_positive_key(self, axis_name: str, key_name: str) -> None:
+        """Add one positive key to an axis binding."""
+        ...
+    def add_draw_commands(self, commands: Sequence[DrawCommand]) -> None:
+        """Submit many draw commands in one call."""
+        ...
+    def add_game_object(self, game_object: GameObject) -> int | None:
+        """Add a GameObject to the engine."""
+        ...
+    def add_scene_file_objects(self, path: str) -> list[int]:
+        """Add the objects in a scene file to the active scene and return their ids."""
+        ...
+    def add_script_time(self, seconds: float) -> None:
+        """Add seconds spent in Python frame code to the current frame's "scripts" scope."""
+        ...
+    def any_pressed(self) -> bool:
+        """Check if any key, mouse button or joystick button was pressed this frame."""
+        ...
+    def asset_bytes(self, handle: AssetHandle) -> bytes | None:
+        """File contents of a loaded audio or data asset."""
+        ...
+    @property
+    def asset_hot_reload(self) -> bool: ...
+    @asset_hot_reload.setter
+    def asset_hot_reload(self, value: bool) -> None: ...
+    def asset_memory_usage(self) -> int:
+        """Approximate memory held by loaded assets, in bytes."""
+        ...
+    def asset_ref_count(self, handle: AssetHandle) -> int:
+        """Number of references to an asset, 0 once it is unloaded."""
+        ...
+    @property
+    def asset_reload_interval(self) -> float: ...
+    @asset_reload_interval.setter
+    def asset_reload_interval(self, value: float) -> None: ...
+    def asset_texture_size(self, handle: AssetHandle) -> tuple[int, int] | None:
+        """`(width, height)` of a loaded texture asset."""
+        ...
+    def audio_listener_position(self) -> Vec2:
+        """World position positional sounds were last heard from."""
+        ...
+    @property
+    def audio_output_open(self) -> bool: ...
+    @property
+    def audio_snapshot_transitioning(self) -> bool: ...
+    def audio_snapshots(self) -> list[str]:
+        """Names of the saved snapshots, sorted."""
+        ...
+    def axis(self, name: str) -> float:
+        """Get the current value of a logical axis."""
+        ...
+    def axis_names(self) -> list[str]:
+        """List all configured logical axis names."""
+        ...
+    def axis_previous(self, name: str) -> float:
+        """Get the previous frame's value of a logical axis."""
+        ...
+    def axis_raw(self, name: str) -> float:
+        """Get the current value of a logical axis without smoothing."""
+        ...
+    def cached_texture_count(self) -> int:
+        """Number of textures currently resident in the render cache."""
+        ...
+    @property
+    def callback_error_policy(self) -> str: ...
+    @callback_error_policy.setter
+    def callback_error_policy(self, value: str) -> None: ...
+    def camera_object_id(self) -> int | None:
+        """Get the active camera object id."""
+        ...
+    def cancel_ui_tween(self, tween_id: int) -> bool:
+        """Stop a UI tween where it is. Returns `True` if it was still running."""
+        ...
+    def capabilities(self) -> EngineCapabilities:
+        """
+        Report the GPU adapter, texture formats, limits, MSAA levels, present
+        modes and enabled engine features.
+        """
+        ...
+    def clear_action_bindings(self, action_name: str) -> None:
+        """Clear all bindings for an action."""
+        ...
+    def clear_axis_smoothing(self, axis_name: str) -> bool:
+        """Remove smoothing from a logical axis so `axis()` reports raw values."""
+        ...
+    def clear_draw_commands(self) -> None:
+        """Clear all immediate-mode draw commands."""
+        ...
+    def clear_impact_sounds(self) -> None: ...
+    def clear_ui_layout(self, object_id: int) -> None:
+        """Remove a UI object's layout; it keeps its last resolved bounds."""
+        ...
+    def clipboard_get(self) -> str:
+        """Text on the system clipboard, or an empty string when it holds no text."""
+        ...
+    def clipboard_set(self, text: str) -> None:
+        """Put text on the system clipboard."""
+        ...
+    @property
+    def collisions_enabled(self) -> bool: ...
+    @collisions_enabled.setter
+    def collisions_enabled(self, value: bool) -> None: ...
+    @property
+    def config_asset_root(self) -> str | None: ...
+    @property
+    def config_path(self) -> str | None: ...
+    def consume_buffered_action(self, action_name: str, window: float) -> bool:
+        """Like `action_buffered()`, but forget the press so it triggers only once."""
+        ...
+    def create_game_object(self, name: str | None = ...) -> int | None:
+        """Create and add a default GameObject (or named one) to the runtime scene."""
+        ...
+    def create_sound(self, name: str, data: Sequence[int], sample_rate: int, channels: int = ..., sample_format: str = ...) -> None:
+        """
+        Make raw PCM samples playable with `play_sound(name)`, replacing any
+        sound created under that name before.
+        """
+        ...
+    @property
+    def current_audio_snapshot(self) -> str | None: ...
+    @property
+    def delta_time(self) -> float: ...
+    def destroy_object(self, object_id: int) -> bool:
+        """Mark a runtime GameObject, and its children, for removal at the end of the frame."""
+        ...
+    def draw_circle(self, center_x: float, center_y: float, radius: float, color: Color, filled: bool = ..., thickness: float = ..., segments: int = ..., draw_order: float = ...) -> None:
+        """Draw a circle at window coordinates."""
+        ...
+    def draw_gradient_rect(self, x: float, y: float, width: float, height: float, top_left: Color, bottom_left: Color, bottom_right: Color, top_right: Color, draw_order: float = ...) -> None:
+        """Draw a gradient rectangle with per-corner colors."""
+        ...
+    def draw_image(self, x: float, y: float, width: float, height: float, texture_path: str, draw_order: float = ...) -> None:
+        """Draw an image from a filesystem path at window coordinates."""
+        ...
+    def draw_image_from_bytes(self, x: float, y: float, width: float, height: float, texture_key: str, rgba: Any, texture_width: int, texture_height: int, draw_order: float = ...) -> None:
+        """Draw an image from raw RGBA bytes at window coordinates."""
+        ...
+    def draw_line(self, start_x: float, start_y: float, end_x: float, end_y: float, color: Color, thickness: float = ..., draw_order: float = ...) -> None:
+        """Draw a line at window coordinates."""
+        ...
+    def draw_pixel(self, x: int, y: int, color: Color, draw_order: float = ...) -> None:
+        """Draw a pixel at window coordinates."""
+        ...
+    def draw_recorder(self, capacity: int = ...) -> DrawRecorder:
+        """Create a `DrawRecorder` that batches draw calls into one submission."""
+        ...
+    def draw_rectangle(self, x: float, y: float, width: float, height: float, color: Color, filled: bool = ..., thickness: float = ..., draw_order: float = ...) -> None:
+        """Draw a rectangle at window coordinates."""
+        ...
+    def draw_text(self, text: str, x: float, y: float, color: Color, font_size: float = ..., font_path: str | None = ..., font_family: str | None = ..., font_weight: str | None = ..., font_style: str | None = ..., letter_spacing: float = ..., line_spacing: float = ..., kerning: bool = ..., draw_order: float = ...) -> None:
+        """
+        Draw text in window coordinates. Uses built-in open-source font by default,
+        or a custom font file when `font_path` is provided.
+        """
+        ...
+    def draw_world_text(self, text: str, x: float, y: float, color: Color, font_size: float = ..., font_path: str | None = ..., font_family: str | None = ..., font_weight: str | None = ..., font_style: str | None = ..., draw_order: float = ...) -> None:
+        """
+        Draw text centered on a world-space point. It moves with the camera and
+        scales with zoom (one text pixel per world unit).
+        """
+        ...
+    def dump_debug_snapshot(self, path: str) -> None:
+        """Write a JSON snapshot of the scene, collision world and draw queue to `path`."""
+        ...
+    @property
+    def elapsed_time(self) -> float: ...
+    def emit_event(self, name: str, payload: Any = ...) -> None:
+        """Queue an event for dispatch at the end of the update."""
+        ...
+    def end_session(self) -> None:
+        """
+        End the session now: close the window, as if `poll_events` had
+        returned False, and clear a quit request.
+        """
+        ...
+    def end_sound_stream(self, voice_id: int) -> bool:
+        """Let a stream finish once its queued samples play."""
+        ...
+    def find_by_name(self, name: str) -> list[int]:
+        """Get the ids of runtime GameObjects with a name, in creation order."""
+        ...
+    def find_by_tag(self, tag: str) -> list[int]:
+        """Get the ids of runtime GameObjects with a tag, in creation order."""
+        ...
+    @property
+    def fixed_alpha(self) -> float: ...
+    @property
+    def fixed_timestep(self) -> float: ...
+    @property
+    def frame_budget_ms(self) -> float | None: ...
+    @frame_budget_ms.setter
+    def frame_budget_ms(self, value: float | None) -> None: ...
+    @property
+    def frame_spike_factor(self) -> float | None: ...
+    @frame_spike_factor.setter
+    def frame_spike_factor(self, value: float | None) -> None: ...
+    def get_active_scene(self) -> str | None:
+        """Scene that new objects are added to."""
+        ...
+    def get_all_ids(self) -> list[int]:
+        """Get the ids of every runtime GameObject, in the order they were added."""
+        ...
+    def get_audio_bus(self, bus: str) -> tuple[float, bool, float | None, float]:
+        """Settings of a mixer bus as `(volume, muted, low_pass, reverb_send)`."""
+        ...
+    def get_auto_hide_cursor(self) -> bool:
+        """Get whether the cursor is hidden automatically during gamepad use."""
+        ...
+    def get_axis_smoothing(self, axis_name: str) -> tuple[float, float, bool] | None:
+        """Get an axis's smoothing as `(gravity, sensitivity, snap)`, or `None` if unsmoothed."""
+        ...
+    def get_callback_errors(self, clear: bool = ...) -> list[CallbackError]:
+        """Exceptions raised by callbacks the engine called, oldest first."""
+        ...
+    def get_camera_aspect_mode(self) -> str:
+        """Get the camera aspect handling mode."""
+        ...
+    def get_camera_background_color(self) -> Color:
+        """Get the active camera background clear color."""
+        ...
+    def get_camera_object(self) -> GameObject | None: ...
+    def get_camera_pixel_perfect(self) -> tuple[int, int] | None:
+        """
+        Get the pixel-perfect virtual resolution as `(width, height)`, or `None` when disabled.
+        """
+        ...
+    def get_camera_position(self) -> Vec2:
+        """Get the active camera world position."""
+        ...
+    def get_camera_viewport_size(self) -> tuple[float, float]:
+        """Get the active camera viewport size in world units."""
+        ...
+    def get_cursor(self) -> str | None:
+        """
+        Get the cursor shape as a CSS cursor name, or `None` while a custom
+        cursor image is shown.
+        """
+        ...
+    def get_cursor_grab(self) -> str:
+        """Get the cursor grab mode: "none", "confined" or "locked"."""
+        ...
+    def get_display_size(self) -> tuple[int, int]:
+        """Get the current display size (window client size) in pixels."""
+        ...
+    def get_font_fallbacks(self) -> list[str]: ...
+    def get_frame_stats(self) -> FrameStats:
+        """Per-scope timings of the last completed frame."""
+        ...
+    def get_game_object_id(self, object_id: int) -> GameObject | None: ...
+    def get_game_object_name(self, name: str) -> list[GameObject]: ...
+    def get_handle(self) -> EngineHandle:
+        """Get a thread-safe handle to the engine that can be passed to other threads."""
+        ...
+    def get_ime_allowed(self) -> bool: ...
+    def get_input_assign_gamepads_on_press(self) -> bool: ...
+    def get_input_auto_assign_gamepads(self) -> bool: ...
+    def get_input_buffer_duration(self) -> float: ...
+    def get_input_context_actions(self, context: str) -> list[str]: ...
+    def get_input_debug_overlay(self) -> bool:
+        """Get whether the input debug overlay is shown."""
+        ...
+    def get_input_debug_overlay_toggle_key(self) -> str | None:
+        """Get the label of the input debug overlay hotkey, or None."""
+        ...
+    def get_input_trigger_deadzone(self, trigger: str) -> float: ...
+    def get_object_scene(self, object_id: int) -> str | None:
+        """Scene an object belongs to, or None."""
+        ...
+    def get_sort_sprites_by_texture(self) -> bool: ...
+    def get_target_fps(self) -> float | None:
+        """Get the frame rate cap, or `None` when uncapped."""
+        ...
+    def get_texture_budget(self) -> int | None:
+        """Get the texture cache budget in bytes, or `None` when unlimited."""
+        ...
+    def get_ui_focus(self) -> int | None:
+        """Object id of the focused UI widget, or `None`."""
+        ...
+    def get_ui_font_fallbacks(self) -> list[str]: ...
+    def get_ui_hover_cursor(self, object_id: int) -> str | None:
+        """Hover cursor set on a UI element as a CSS cursor name, or `None`."""
+        ...
+    def get_ui_input_context(self, object_id: int) -> str | None: ...
+    def get_ui_scale_mode(self) -> str: ...
+    def get_ui_theme(self) -> str: ...
+    def initialize(self, title: str | None = ..., width: int | None = ..., height: int | None = ..., resizable: bool | None = ..., background_color: Color | None = ..., vsync: bool | None = ..., redraw_on_change_only: bool = ..., show_fps_in_title: bool | None = ..., icon_path: str | None = ..., min_width: int | None = ..., min_height: int | None = ...) -> None:
+        """Initialize the engine with window configuration without starting the loop."""
+        ...
+    def input_active_device(self) -> str:
+        """Get the device the player used most recently (`"keyboard_mouse"` or `"gamepad"`)."""
+        ...
+    def input_active_device_changed(self) -> bool:
+        """Check whether the active input device switched this frame."""
+        ...
+    def input_assign_player(self, player: int, device: str | None = ..., joystick_id: int | None = ...) -> bool:
+        """Assign a device to a player (1-4), or empty the slot when `device` is `None`."""
+        ...
+    def input_composition(self) -> str:
+        """Text the input method is composing but has not committed."""
+        ...
+    def input_composition_cursor(self) -> tuple[int, int] | None:
+        """Character range of the input method's cursor in the composition, or `None`."""
+        ...
+    def input_context(self) -> str | None:
+        """The top input context, or `None` when none is pushed."""
+        ...
+    def input_context_stack(self) -> list[str]:
+        """Pushed input contexts, bottom first."""
+        ...
+    def input_events(self) -> list[InputEvent]:
+        """Input events collected for the current frame, in arrival order."""
+        ...
+    def input_gamepads_available(self) -> bool:
+        """
+        Whether the engine reads game controllers itself (built with the
+        `gamepad` feature and the platform API opened).
+        """
+        ...
+    def input_glyph_atlas(self) -> tuple[int, int, list[int]]:
+        """Get the full built-in glyph atlas as `(width, height, rgba_bytes)`."""
+        ...
+    def input_glyph_device(self) -> str:
+        """Get the device prompts currently target (`"keyboard_mouse"` or `"gamepad"`)."""
+        ...
+    def input_glyph_icon(self, icon: str) -> tuple[int, int, list[int]] | None:
+        """Get one icon from the built-in glyph atlas as `(width, height, rgba_bytes)`."""
+        ...
+    def input_glyph_icon_rect(self, icon: str) -> tuple[int, int, int, int] | None:
+        """Get the `(x, y, width, height)` pixel rectangle of an icon in the glyph atlas."""
+        ...
+    def input_glyph_version(self) -> int:
+        """Version counter that changes whenever action bindings or the prompt device change."""
+        ...
+    def input_inject_joystick_axis(self, joystick_id: int, axis_id: int, value: float) -> None:
+        """Move a gamepad axis, connecting the gamepad if needed."""
+        ...
+    def input_inject_joystick_button(self, joystick_id: int, button_id: int, pressed: bool) -> None:
+        """Press or release a gamepad button, connecting the gamepad if needed."""
+        ...
+    def input_inject_key(self, key_name: str, pressed: bool) -> None:
+        """Press or release a key through the same pipeline as the keyboard."""
+        ...
+    def input_inject_mouse_button(self, button: str, pressed: bool) -> None:
+        """Press or release a mouse button."""
+        ...
+    def input_inject_mouse_move(self, x: float, y: float) -> None:
+        """Move the mouse cursor to a window position in pixels."""
+        ...
+    def input_inject_mouse_wheel(self, delta_x: float, delta_y: float) -> None:
+        """Scroll the mouse wheel."""
+        ...
+    def input_inject_text(self, text: str) -> None:
+        """Deliver typed text as if it came from the keyboard."""
+        ...
+    def input_joystick_axis(self, joystick_id: int, axis_id: int) -> float: ...
+    def input_joystick_button_down(self, joystick_id: int, button_id: int) -> bool: ...
+    def input_joystick_button_pressed(self, joystick_id: int, button_id: int) -> bool: ...
+    def input_joystick_button_released(self, joystick_id: int, button_id: int) -> bool: ...
+    def input_joystick_connection_changes(self, connected: bool) -> list[int]:
+        """Ids of the joysticks connected (`True`) or disconnected (`False`) this frame."""
+        ...
+    def input_joystick_name(self, joystick_id: int) -> str | None:
+        """Name the controller reports, if the gamepad backend knows it."""
+        ...
+    def input_joystick_trigger(self, joystick_id: int, trigger: str) -> float:
+        """
+        How far a gamepad trigger (`"left"` or `"right"`) is pulled, from 0.0
+        to 1.0, after its deadzone.
+        """
+        ...
+    def input_joysticks(self) -> list[int]:
+        """Ids of the connected joysticks, sorted."""
+        ...
+    def input_player_action_down(self, player: int, action_name: str) -> bool:
+        """Check if an action is held on one player's device."""
+        ...
+    def input_player_action_pressed(self, player: int, action_name: str) -> bool:
+        """Check if an action was pressed this frame on one player's device."""
+        ...
+    def input_player_action_released(self, player: int, action_name: str) -> bool:
+        """Check if an action was released this frame on one player's device."""
+        ...
+    def input_player_axis(self, player: int, name: str) -> float:
+        """Get a logical axis for one player, reading only that player's device."""
+        ...
+    def input_player_axis_raw(self, player: int, name: str) -> float:
+        """Get a logical axis for one player without smoothing."""
+        ...
+    def input_player_device(self, player: int) -> tuple[str, int | None] | None:
+        """Get a player's device as `(kind, joystick_id)`, or `None` for an empty slot."""
+        ...
+    def input_player_for_joystick(self, joystick_id: int) -> int | None:
+        """Get the player (1-4) that owns a joystick, if any."""
+        ...
+    def input_player_trigger(self, player: int, trigger: str) -> float:
+        """How far a trigger is pulled on one player's gamepad, from 0.0 to 1.0."""
+        ...
+    def input_pop_context(self) -> str | None:
+        """Remove the top input context, returning it."""
+        ...
+    def input_push_context(self, context: str) -> None:
+        """
+        Make an input context the top one until it is popped. UI elements
+        given a context only take input while it is on top.
+        """
+        ...
+    def input_reset(self) -> None:
+        """Release all held keys, mouse buttons and gamepad buttons and zero all axes."""
+        ...
+    def input_typed_text(self) -> str:
+        """Text typed during the last frame, including input method commits."""
+        ...
+    def is_audio_source_playing(self, component_id: int) -> bool:
+        """Whether the `AudioSourceComponent` with this id has a sound playing."""
+        ...
+    def is_cursor_visible(self) -> bool:
+        """Get whether the mouse cursor is shown (ignoring auto-hide)."""
+        ...
+    def is_destroy_pending(self, object_id: int) -> bool:
+        """Whether a runtime GameObject is marked for removal at the end of the frame."""
+        ...
+    def is_sound_playing(self, voice_id: int) -> bool: ...
+    def is_ui_interactable(self, object_id: int) -> bool: ...
+    def is_vsync_enabled(self) -> bool:
+        """Get whether VSync is currently enabled."""
+        ...
+    def key_down(self, key_name: str) -> bool:
+        """Check if a keyboard key is currently held down."""
+        ...
+    def key_pressed(self, key_name: str) -> bool:
+        """Check if a keyboard key was pressed this frame."""
+        ...
+    def key_released(self, key_name: str) -> bool:
+        """Check if a keyboard key was released this frame."""
+        ...
+    def load_asset(self, kind: str, path: str) -> AssetHandle:
+        """Load an asset file, or add a reference to it if it is already loaded."""
+        ...
+    def load_input_bindings(self, path: str) -> None:
+        """
+        Replace every axis binding and action mapping with ones saved by
+        `save_input_bindings()`.
+        """
+        ...
+    def load_scene(self, name: str, additive: bool = ...) -> None:
+        """Load a scene and make it active, unloading the others unless `additive`."""
+        ...
+    def loaded_asset_count(self) -> int:
+        """Number of loaded assets."""
+        ...
+    def loaded_scenes(self) -> list[str]:
+        """Names of the loaded scenes, in load order."""
+        ...
+    def log(self, message: str) -> None:
+        """Log a message at INFO level (default log method)."""
+        ...
+    def log_debug(self, message: str) -> None:
+        """Log a message at DEBUG level."""
+        ...
+    def log_error(self, message: str) -> None:
+        """Log a message at ERROR level."""
+        ...
+    def log_info(self, message: str) -> None:
+        """Log a message at INFO level."""
+        ...
+    def log_trace(self, message: str) -> None:
+        """Log a message at TRACE level (most verbose)."""
+        ...
+    def log_warn(self, message: str) -> None:
+        """Log a message at WARN level."""
+        ...
+    @property
+    def master_volume(self) -> float: ...
+    @master_volume.setter
+    def master_volume(self, value: float) -> None: ...
+    @property
+    def max_fixed_steps(self) -> int: ...
+    @max_fixed_steps.setter
+    def max_fixed_steps(self, value: int) -> None: ...
+    @property
+    def max_sounds(self) -> int: ...
+    @max_sounds.setter
+    def max_sounds(self, value: int) -> None: ...
+    def measure_text(self, text: str, font_size: float = ..., font_path: str | None = ..., font_family: str | None = ..., font_weight: str | None = ..., font_style: str | None = ..., letter_spacing: float = ..., line_spacing: float = ..., kerning: bool = ...) -> tuple[float, float]: ...
+    def mouse_button_down(self, button: str) -> bool:
+        """Check if a mouse button is currently held down."""
+        ...
+    def mouse_button_pressed(self, button: str) -> bool:
+        """Check if a mouse button was pressed this frame."""
+        ...
+    def mouse_button_released(self, button: str) -> bool:
+        """Check if a mouse button was released this frame."""
+        ...
+    def mouse_delta(self) -> tuple[float, float]:
+        """Get the mouse movement delta for this frame."""
+        ...
+    def mouse_position(self) -> tuple[float, float]:
+        """Get the current mouse position in window coordinates."""
+        ...
+    def mouse_wheel(self) -> tuple[float, float]:
+        """Get the mouse wheel delta accumulated this frame."""
+        ...
+    def off_event(self, subscription: int) -> bool:
+        """Remove an event subscription. Returns False if it does not exist."""
+        ...
+    def on_event(self, name: str, callback: Any) -> int:
+        """
+        Subscribe a callable to an event; it is called with the event payload.
+        Returns the subscription id.
+        """
+        ...
+    @property
+    def parallel_updates(self) -> bool: ...
+    @parallel_updates.setter
+    def parallel_updates(self, value: bool) -> None: ...
+    @property
+    def paused(self) -> bool: ...
+    @paused.setter
+    def paused(self, value: bool) -> None: ...
+    def play_sound(self, sound: Any, volume: float = ..., pan: float = ..., looping: bool = ..., position: Vec2 | None = ..., attenuation: str = ..., min_distance: float = ..., max_distance: float = ..., rolloff: float = ..., bus: str = ..., pitch: float = ..., pitch_range: tuple[float, float] | None = ..., priority: int = ...) -> int:
+        """Play a WAV file and return its voice id."""
+        ...
+    def play_sound_stream(self, sample_rate: int, channels: int = ..., volume: float = ..., pan: float = ..., bus: str = ..., priority: int = ...) -> int:
+        """
+        Start a stream of generated samples and return its voice id. It is
+        silent until samples are queued with `queue_sound_stream`.
+        """
+        ...
+    @property
+    def playing_sound_count(self) -> int: ...
+    def poll_events(self) -> bool:
+        """
+        Poll events from the window system. Returns True if the loop should continue, False if exit requested.
+        """
+        ...
+    def preload_texture(self, path: str) -> None:
+        """Load a texture into the render cache ahead of its first draw."""
+        ...
+    @property
+    def profiler_enabled(self) -> bool: ...
+    @profiler_enabled.setter
+    def profiler_enabled(self, value: bool) -> None: ...
+    def queue_sound_stream(self, voice_id: int, data: Sequence[int], sample_format: str = ...) -> bool:
+        """Append raw interleaved samples to a stream. Returns False once the stream ended."""
+        ...
+    @property
+    def quit_requested(self) -> bool: ...
+    def refresh_ui_list(self, object_id: int) -> None:
+        """
+        Fetch a UI list view's visible rows from its item provider again at runtime by object ID.
+        """
+        ...
+    def register_font_family(self, family: str, regular: str | None = ..., bold: str | None = ..., italic: str | None = ..., bold_italic: str | None = ...) -> bool: ...
+    def register_ui_theme(self, name: str, colors: Sequence[tuple[str, tuple[float, float, float, float]]], base: str = ...) -> None:
+        """Register a UI theme made from a registered `base` theme with some colors replaced."""
+        ...
+    def release_asset(self, handle: AssetHandle) -> bool:
+        """Drop a reference to an asset. Returns `True` if this unloaded it."""
+        ...
+    def reload_changed_assets(self) -> list[str]:
+        """Re-read changed asset files now. Returns their resolved paths."""
+        ...
+    def remove_action_chord(self, action_name: str, chord: str) -> bool:
+        """Remove one modifier chord from an action."""
+        ...
+    def remove_action_key(self, action_name: str, key_name: str) -> bool:
+        """Remove one keyboard key from an action."""
+        ...
+    def remove_action_mouse_button(self, action_name: str, button: str) -> bool:
+        """Remove one mouse button from an action."""
+        ...
+    def remove_audio_snapshot(self, name: str) -> bool:
+        """Forget a snapshot. Returns False if there was none."""
+        ...
+    def remove_axis(self, axis_name: str) -> bool:
+        """Remove an entire logical axis binding."""
+        ...
+    def remove_axis_negative_key(self, axis_name: str, key_name: str) -> bool:
+        """Remove one negative key from an axis binding."""
+        ...
+    def remove_axis_positive_key(self, axis_name: str, key_name: str) -> bool:
+        """Remove one positive key from an axis binding."""
+        ...
+    def remove_game_object(self, object_id: int) -> None:
+        """Remove a runtime GameObject by id."""
+        ...
+    def remove_impact_sound(self, layers: tuple[int, int] | None = ..., materials: tuple[str, str] | None = ...) -> bool:
+        """Stop playing a sound for a layer or material pair. Returns False if none was set."""
+        ...
+    def remove_sound(self, name: str) -> bool:
+        """Remove a sound made with `create_sound`. Returns False if there was none."""
+        ...
+    def render(self) -> None:
+        """Render a single frame."""
+        ...
+    def render_stats(self) -> RenderStats:
+        """Batching statistics for the last rendered frame."""
+        ...
+    def report_callback_error(self, source: str, error: Any) -> None:
+        """
+        Report an exception from a callback run by Python code, such as a timer,
+        so it is logged and handled like the engine's own callbacks.
+        """
+        ...
+    def report_input_device_activity(self, device: str) -> bool:
+        """Report activity on a device, e.g. from a gamepad library polled in Python."""
+        ...
+    def request_quit(self) -> None:
+        """
+        End the game loop after the current frame: `run` returns and the next
+        `poll_events` returns False. The window closes and the engine, scene
+        included, can be started again.
+        """
+        ...
+    def reset_input_bindings_to_defaults(self) -> None:
+        """Restore default axis/action bindings."""
+        ...
+    def retain_asset(self, handle: AssetHandle) -> bool:
+        """Add a reference to a loaded asset. Returns `False` if it was unloaded."""
+        ...
+    def run(self, title: str | None = ..., width: int | None = ..., height: int | None = ..., resizable: bool | None = ..., background_color: Color | None = ..., vsync: bool | None = ..., redraw_on_change_only: bool = ..., show_fps_in_title: bool | None = ..., icon_path: str | None = ..., min_width: int | None = ..., min_height: int | None = ..., on_update: Any = ...) -> None:
+        """Run the engine with a basic window configuration (blocking)."""
+        ...
+    def save_input_bindings(self, path: str) -> None:
+        """Save every axis binding and action mapping to a JSON file."""
+        ...
+    def save_scene(self, name: str, path: str) -> None:
+        """Save a loaded scene's objects to a JSON scene file."""
+        ...
+    def scene_objects(self, name: str) -> list[int]:
+        """Ids of a loaded scene's objects, empty if it is not loaded."""
+        ...
+    def screen_to_world(self, screen_x: float, screen_y: float) -> Vec2:
+        """Convert screen-space pixel coordinates to world-space coordinates."""
+        ...
+    def send_message(self, target_id: int, name: str, payload: Any = ...) -> bool:
+        """
+        Send a message to the components of a runtime GameObject, delivered
+        in the next update phase. Returns False if the object does not exist.
+        """
+        ...
+    def set_action_chords(self, action_name: str, chords: Sequence[str]) -> None:
+        """Configure modifier chords for an action."""
+        ...
+    def set_action_keys(self, action_name: str, key_names: Sequence[str]) -> None:
+        """Configure keyboard keys for an action."""
+        ...
+    def set_action_mouse_buttons(self, action_name: str, buttons: Sequence[str]) -> None:
+        """Configure mouse buttons for an action."""
+        ...
+    def set_active_scene(self, name: str | None = ...) -> bool:
+        """Set the scene new objects are added to; None adds them to no scene."""
+        ...
+    def set_audio_bus(self, bus: str, volume: float, muted: bool, low_pass: float | None, reverb_send: float) -> None:
+        """Replace the settings of a mixer bus. `low_pass` is a cutoff in Hz, or None."""
+        ...
+    def set_auto_hide_cursor(self, enabled: bool) -> None:
+        """Hide the cursor automatically while a gamepad is the active input device."""
+        ...
+    def set_axis_keys(self, name: str, positive_keys: Sequence[str], negative_keys: Sequence[str], sensitivity: float = ...) -> None:
+        """Configure keyboard keys for a logical axis."""
+        ...
+    def set_axis_mouse(self, name: str, mouse_axis: str, sensitivity: float = ..., invert: bool = ...) -> bool:
+        """Configure a mouse-driven logical axis."""
+        ...
+    def set_axis_smoothing(self, axis_name: str, gravity: float = ..., sensitivity: float = ..., snap: bool = ...) -> bool:
+        """Smooth a logical axis over time (like Unity's `GetAxis`)."""
+        ...
+    def set_camera_aspect_mode(self, mode: str) -> bool:
+        """Set the camera aspect handling mode."""
+        ...
+    def set_camera_background_color(self, color: Color) -> None:
+        """Set the active camera background clear color."""
+        ...
+    def set_camera_pixel_perfect(self, width: int | None = ..., height: int | None = ...) -> bool:
+        """Enable pixel-perfect camera rendering at a fixed virtual resolution."""
+        ...
+    def set_camera_position(self, position: Vec2) -> bool:
+        """Set the active camera world position."""
+        ...
+    def set_camera_viewport_size(self, width: float, height: float) -> bool:
+        """Set the active camera viewport size in world units."""
+        ...
+    def set_cursor(self, cursor: str) -> None:
+        """Show a built-in cursor shape over the window."""
+        ...
+    def set_cursor_grab(self, mode: str) -> None:
+        """Confine the cursor to the window or lock it in place."""
+        ...
+    def set_cursor_visible(self, visible: bool) -> None:
+        """Show or hide the mouse cursor over the window."""
+        ...
+    def set_custom_cursor(self, rgba: Sequence[int], width: int, height: int, hotspot_x: int = ..., hotspot_y: int = ...) -> None:
+        """Show a custom cursor image over the window."""
+        ...
+    def set_fixed_timestep(self, fixed_timestep: float) -> None:
+        """Set the seconds of game time per fixed update, e.g. `1 / 120` for 120 Hz physics."""
+        ...
+    def set_font_fallbacks(self, fallbacks: Sequence[str]) -> None:
+        """Set the fonts tried, in order, for characters a text style's own font lacks."""
+        ...
+    def set_game_object_position(self, object_id: int, position: Vec2) -> bool:
+        """Update a runtime GameObject's position by id."""
+        ...
+    def set_ime_allowed(self, allowed: bool) -> None:
+        """
+        Let the input method (IME) compose text even when no UI text input
+        has focus, e.g. for an in-game console reading `input.typed_text`.
+        """
+        ...
+    def set_impact_sound(self, sound: Any, layers: tuple[int, int] | None = ..., materials: tuple[str, str] | None = ..., volume: float = ..., full_volume_depth: float = ..., min_depth: float = ..., pitch_range: tuple[float, float] | None = ..., bus: str = ..., priority: int = ..., spatial: bool = ..., attenuation: str = ..., min_distance: float = ..., max_distance: float = ..., rolloff: float = ...) -> None:
+        """
+        Play `sound` whenever colliders on these two physics layers, or with
+        these two materials, start touching. Material pairs win over layer
+        pairs. The volume grows with how deep the colliders overlap on the
+        step they meet, reaching `volume` at `full_volume_depth`; hits
+        shallower than `min_depth` are silent. With `spatial` the sound is
+        heard from the contact point.
+        """
+        ...
+    def set_input_assign_gamepads_on_press(self, enabled: bool) -> None:
+        """
+        Choose whether gamepads are assigned to a player on their first
+        button press instead of when they connect.
+        """
+        ...
+    def set_input_auto_assign_gamepads(self, enabled: bool) -> None:
+        """Choose whether newly connected gamepads fill the first empty player slot."""
+        ...
+    def set_input_buffer_duration(self, seconds: float) -> None:
+        """Set how long action presses are remembered for buffering, in seconds."""
+        ...
+    def set_input_context_actions(self, context: str, actions: Sequence[str]) -> None:
+        """Put actions in a named input context such as "gameplay" or "menu"."""
+        ...
+    def set_input_debug_overlay(self, enabled: bool) -> None:
+        """Show or hide the input debug overlay."""
+        ...
+    def set_input_debug_overlay_toggle_key(self, key: str | None = ...) -> None:
+        """
+        Set a key (e.g. "F3") that shows or hides the input debug overlay, or None for no hotkey.
+        """
+        ...
+    def set_input_glyph_device(self, device: str | None = ...) -> bool:
+        """Force prompts to a device, or pass `None` to follow the active device."""
+        ...
+    def set_input_trigger_deadzone(self, trigger: str, deadzone: float) -> None:
+        """Set a trigger's deadzone (0.1 by default)."""
+        ...
+    def set_object_scene(self, object_id: int, scene: str | None = ...) -> bool:
+        """Move an object to a loaded scene, or out of every scene with None."""
+        ...
+    def set_sort_sprites_by_texture(self, enabled: bool) -> None:
+        """
+        Group sprites of equal draw order by texture so they batch into fewer
+        draw calls (on by default). Turn it off if overlapping sprites with the
+        same draw order must keep their creation order.
+        """
+        ...
+    def set_sound_pan(self, voice_id: int, pan: float) -> bool:
+        """Set a sound's pan. Returns False for positional or ended sounds."""
+        ...
+    def set_sound_pitch(self, voice_id: int, pitch: float) -> bool:
+        """Set a sound's playback rate. Returns False if it ended."""
+        ...
+    def set_sound_volume(self, voice_id: int, volume: float) -> bool:
+        """Set a sound's volume, before distance attenuation for positional sounds."""
+        ...
+    def set_source_root(self, source_root: str | None) -> None: ...
+    def set_target_fps(self, fps: float | None) -> None:
+        """Cap the frame rate to `fps` frames per second."""
+        ...
+    def set_texture_budget(self, budget_bytes: int | None) -> None:
+        """Set the texture cache budget in bytes, or `None` for unlimited."""
+        ...
+    def set_ui_focus(self, object_id: int | None = ...) -> bool:
+        """Give a UI widget keyboard focus, or clear focus with `None`."""
+        ...
+    def set_ui_focus_ring(self, width: float = ..., offset: float = ..., corner_radius: float = ...) -> None:
+        """
+        Set the outline drawn around the focused widget. A width of 0 hides it;
+        the color comes from the theme's "focus_ring" color.
+        """
+        ...
+    def set_ui_font_fallbacks(self, fallbacks: Sequence[str]) -> None:
+        """Set the UI theme's fallback fonts, used instead of the renderer's for UI text."""
+        ...
+    def set_ui_hover_cursor(self, object_id: int, cursor: str | None = ...) -> None:
+        """
+        Set the cursor shown while the pointer is over a UI element or its
+        children, or `None` to remove it. Accepts the names `set_cursor` does.
+        """
+        ...
+    def set_ui_input_context(self, object_id: int, context: str | None = ...) -> None:
+        """
+        Make a UI element and its children take input only while `context`
+        is the top input context, or `None` to take input in any context.
+        """
+        ...
+    def set_ui_interactable(self, object_id: int, interactable: bool) -> None:
+        """
+        Grey out a UI element and its children and stop them taking input,
+        while keeping them visible.
+        """
+        ...
+    def set_ui_layout(self, object_id: int, anchor: str = ..., x: float = ..., y: float = ..., width: Any = ..., height: Any = ..., pivot: tuple[float, float] | None = ...) -> None:
+        """Anchor a UI object to its parent (or the window) so it follows resizes."""
+        ...
+    def set_ui_scale_mode(self, mode: str, reference_width: float = ..., reference_height: float = ...) -> None:
+        """
+        Set how UI units map to window pixels: "constant_pixel_size",
+        "scale_with_height" or "fixed_resolution" (letterboxed). The reference
+        size is the resolution the UI was designed at; "scale_with_height"
+        only uses its height.
+        """
+        ...
+    def set_ui_theme(self, name: str) -> None:
+        """Switch the UI to a registered theme, restyling existing widgets."""
+        ...
+    def set_vsync(self, enabled: bool) -> None:
+        """Enable or disable VSync at runtime."""
+        ...
+    def set_window_icon(self, icon_path: str) -> None:
+        """Set the window icon from an image path."""
+        ...
+    def set_window_title(self, title: str) -> None:
+        """Set the window title."""
+        ...
+    @property
+    def slow_frame_count(self) -> int: ...
+    def sound_stream_queued_frames(self, voice_id: int) -> int | None:
+        """Frames queued on a stream and not played yet, or None once it finished."""
+        ...
+    def stop_all_sounds(self) -> None: ...
+    def stop_sound(self, voice_id: int) -> bool:
+        """Stop a sound. Returns False if it already ended."""
+        ...
+    def take_finished_sounds(self) -> list[int]:
+        """
+        Voice ids of the sounds that played to the end, or were cut off by the
+        voice limit, since the last call.
+        """
+        ...
+    def take_finished_ui_tweens(self) -> list[int]:
+        """Ids of the UI tweens that ended since the last call."""
+        ...
+    def texture_memory_usage(self) -> int:
+        """Approximate GPU memory held by cached textures, in bytes."""
+        ...
+    def texture_size(self, path: str) -> tuple[int, int]:
+        """Get the `(width, height)` of an image file in pixels without loading it."""
+        ...
+    @property
+    def time_scale(self) -> float: ...
+    @time_scale.setter
+    def time_scale(self, value: float) -> None: ...
+    def transition_audio_snapshot(self, name: str, duration: float = ...) -> None:
+        """Blend every bus to a snapshot over `duration` seconds of real time."""
+        ...
+    def tween_ui(self, object_id: int, property: str, to: Sequence[float], duration: float, easing: str = ..., delay: float = ..., from_: Sequence[float] | None = ...) -> int:
+        """Animate a UI element's property to `to` over `duration` seconds."""
+        ...
+    def ui_theme_names(self) -> list[str]: ...
+    def unload_scene(self, name: str) -> bool:
+        """Unload a scene and destroy its objects. Returns false if it was not loaded."""
+        ...
+    def unload_texture(self, path: str) -> bool:
+        """Remove a texture from the render cache. Returns `True` if it was cached."""
+        ...
+    @property
+    def unscaled_delta_time(self) -> float: ...
+    def update(self) -> None:
+        """Run a single update step."""
+        ...
+    def update_ui_button_text(self, object_id: int, text: str) -> None:
+        """Update a UI button's text at runtime by object ID."""
+        ...
+    def update_ui_checked(self, object_id: int, checked: bool) -> None:
+        """
+        Update a UI checkbox, toggle switch or radio button's checked state at runtime by object ID.
+        """
+        ...
+    def update_ui_curve(self, object_id: int, curve: AnimationCurve) -> None:
+        """Replace a UI curve editor's curve at runtime by object ID."""
+        ...
+    def update_ui_image_scale_mode(self, object_id: int, scale_mode: str) -> None:
+        """
+        Update a UI image's scale mode ("stretch", "fit", "fill" or "tile") at runtime by object ID.
+        """
+        ...
+    def update_ui_image_texture(self, object_id: int, texture_path: str | None = ...) -> None:
+        """Update a UI image's texture at runtime by object ID (None hides it)."""
+        ...
+    def update_ui_label_text(self, object_id: int, text: str) -> None:
+        """Update a UI label's text at runtime by object ID."""
+        ...
+    def update_ui_list_item_count(self, object_id: int, item_count: int) -> None:
+        """Update a UI list view's item count at runtime by object ID."""
+        ...
+    def update_ui_list_selection(self, object_id: int, selected: int | None = ...) -> None:
+        """
+        Select a UI list view row (or clear the selection with `None`) at runtime by object ID.
+        """
+        ...
+    def update_ui_panel_clip(self, object_id: int, clip_children: bool) -> None:
+        """
+        Turn clipping of a UI panel's children to its bounds on or off at runtime by object ID.
+        """
+        ...
+    def update_ui_progress_value(self, object_id: int, value: float) -> None:
+        """Update a UI progress bar's value (0.0-1.0) at runtime by object ID."""
+        ...
+    def update_ui_scroll_position(self, object_id: int, x: float, y: float) -> None:
+        """Update a UI scroll view's scroll offset at runtime by object ID."""
+        ...
+    def update_ui_slider_value(self, object_id: int, value: float) -> None:
+        """Update a UI slider's value at runtime by object ID."""
+        ...
+    def update_ui_stack(self, object_id: int, direction: str = ..., spacing: float = ..., padding: tuple[float, float, float, float] = ..., alignment: str = ..., fit_content: bool = ...) -> None:
+        """
+        Update a UI stack's direction, spacing, padding (left, right, top, bottom),
+        alignment and fit_content at runtime by object ID.
+        """
+        ...
+    def update_ui_text_input_text(self, object_id: int, text: str) -> None:
+        """Update a UI text input's text at runtime by object ID."""
+        ...
+    @property
+    def version(self) -> str: ...
+    def world_to_screen(self, world_position: Vec2) -> tuple[float, float]:
+        """Convert world-space coordinates to screen-space pixel coordinates."""
+        ...
+
+class EngineCapabilities:
+    """Engine version, enabled features and renderer capabilities."""
+    @property
+    def engine_version(self) -> str: ...
+    @property
+    def features(self) -> list[str]: ...
+    def has_feature(self, feature: str) -> bool:
+        """Whether `feature` is in `features`."""
+        ...
+    @property
+    def renderer(self) -> RendererCapabilities | None: ...
+    def __repr__(self, /) -> str: ...
+    def __str__(self, /) -> str: ...
+
+class EngineHandle:
+    """A thread-safe handle to the engine that can be passed to background threads."""
+    def add_draw_commands(self, commands: Sequence[DrawCommand]) -> None:
+        """Submit many draw commands via command queue in one call."""
+        ...
+    def add_game_object(self, game_object: GameObject) -> None:
+        """Add a GameObject to the engine command queue."""
+        ...
+    def cancel_ui_tween(self, tween_id: int) -> None:
+        """Stop a UI tween via command queue."""
+        ...
+    def clear_draw_commands(self) -> None:
+        """Clear all immediate-mode draw commands via command queue."""
+        ...
+    def clear_ui_layout(self, object_id: int) -> None:
+        """Remove a UI object's layout via command queue."""
+        ...
+    def clipboard_get(self) -> str:
+        """
+        Text on the system clipboard. The clipboard is shared, so this reads it
+        directly rather than through the command queue.
+        """
+        ...
+    def clipboard_set(self, text: str) -> None:
+        """Put text on the system clipboard directly."""
+        ...
+    def draw_circle(self, center_x: float, center_y: float, radius: float, color: Color, filled: bool = ..., thickness: float = ..., segments: int = ..., draw_order: float = ...) -> None:
+        """Draw a circle at window coordinates via command queue."""
+        ...
+    def draw_gradient_rect(self, x: float, y: float, width: float, height: float, top_left: Color, bottom_left: Color, bottom_right: Color, top_right: Color, draw_order: float = ...) -> None:
+        """Draw a gradient rectangle with per-corner colors via command queue."""
+        ...
+    def draw_image(self, x: float, y: float, width: float, height: float, texture_path: str, draw_order: float = ...) -> None:
+        """Draw an image from a filesystem path via command queue."""
+        ...
+    def draw_image_from_bytes(self, x: float, y: float, width: float, height: float, texture_key: str, rgba: Any, texture_width: int, texture_height: int, draw_order: float = ...) -> None:
+        """Draw an image from raw RGBA bytes via command queue."""
+        ...
+    def draw_line(self, start_x: float, start_y: float, end_x: float, end_y: float, color: Color, thickness: float = ..., draw_order: float = ...) -> None:
+        """Draw a line at window coordinates via command queue."""
+        ...
+    def draw_pixel(self, x: int, y: int, color: Color, draw_order: float = ...) -> None:
+        """Draw a pixel at window coordinates via command queue."""
+        ...
+    def draw_recorder(self, capacity: int = ...) -> DrawRecorder:
+        """Create a `DrawRecorder` that submits via command queue in one batch."""
+        ...
+    def draw_rectangle(self, x: float, y: float, width: float, height: float, color: Color, filled: bool = ..., thickness: float = ..., draw_order: float = ...) -> None:
+        """Draw a rectangle at window coordinates via command queue."""
+        ...
+    def draw_text(self, text: str, x: float, y: float, color: Color, font_size: float = ..., font_path: str | None = ..., font_family: str | None = ..., font_weight: str | None = ..., font_style: str | None = ..., letter_spacing: float = ..., line_spacing: float = ..., kerning: bool = ..., draw_order: float = ...) -> None:
+        """Draw text in window coordinates via command queue."""
+        ...
+    def draw_world_text(self, text: str, x: float, y: float, color: Color, font_size: float = ..., font_path: str | None = ..., font_family: str | None = ..., font_weight: str | None = ..., font_style: str | None = ..., draw_order: float = ...) -> None:
+        """Draw text centered on a world-space point via command queue."""
+        ...
+    def emit_event(self, name: str, payload: Any = ...) -> None:
+        """Queue an event on the event bus via command queue."""
+        ...
+    def log(self, message: str) -> None:
+        """Log a message at INFO level (default log method)."""
+        ...
+    def log_debug(self, message: str) -> None:
+        """Log a message at DEBUG level."""
+        ...
+    def log_error(self, message: str) -> None:
+        """Log a message at ERROR level."""
+        ...
+    def log_info(self, message: str) -> None:
+        """Log a message at INFO level."""
+        ...
+    def log_trace(self, message: str) -> None:
+        """Log a message at TRACE level (most verbose)."""
+        ...
+    def log_warn(self, message: str) -> None:
+        """Log a message at WARN level."""
+        ...
+    def refresh_ui_list(self, object_id: int) -> None:
+        """
+        Fetch a UI list view's visible rows from its item provider again at runtime by object ID via command queue.
+        """
+        ...
+    def register_font_family(self, family: str, regular: str | None = ..., bold: str | None = ..., italic: str | None = ..., bold_italic: str | None = ...) -> None: ...
+    def remove_game_object(self, object_id: int) -> None:
+        """Remove a runtime GameObject by id via command queue."""
+        ...
+    def request_quit(self) -> None:
+        """End the game loop after the current frame via command queue."""
+        ...
+    def send_message(self, target_id: int, name: str, payload: Any = ...) -> None:
+        """Send a message to the components of a runtime GameObject via command queue."""
+        ...
+    def set_camera_aspect_mode(self, mode: str) -> None:
+        """Update camera aspect handling mode via command queue."""
+        ...
+    def set_camera_background_color(self, color: Color) -> None:
+        """Update the active camera background clear color via command queue."""
+        ...
+    def set_camera_position(self, position: Vec2) -> None:
+        """Update the active camera world position via command queue."""
+        ...
+    def set_camera_viewport_size(self, width: float, height: float) -> None:
+        """Update the active camera viewport size in world units via command queue."""
+        ...
+    def set_fixed_timestep(self, fixed_timestep: float) -> None:
+        """Set the seconds of game time per fixed update via command queue."""
+        ...
+    def set_font_fallbacks(self, fallbacks: Sequence[str]) -> None:
+        """Set the renderer's default fallback fonts via command queue."""
+        ...
+    def set_game_object_position(self, object_id: int, position: Vec2) -> None:
+        """Update a runtime GameObject position by id via command queue."""
+        ...
+    def set_paused(self, paused: bool) -> None:
+        """Pause or resume game time via command queue."""
+        ...
+    def set_time_scale(self, time_scale: float) -> None:
+        """Set the game time scale via command queue."""
+        ...
+    def set_ui_focus(self, object_id: int | None = ...) -> None:
+        """Focus a UI widget, or clear focus, via command queue."""
+        ...
+    def set_ui_focus_ring(self, width: float = ..., offset: float = ..., corner_radius: float = ...) -> None:
+        """Set the focus ring via command queue."""
+        ...
+    def set_ui_font_fallbacks(self, fallbacks: Sequence[str]) -> None:
+        """Set the UI theme's fallback fonts via command queue."""
+        ...
+    def set_ui_hover_cursor(self, object_id: int, cursor: str | None = ...) -> None:
+        """Set the cursor shown while hovering a UI element via command queue."""
+        ...
+    def set_ui_input_context(self, object_id: int, context: str | None = ...) -> None:
+        """Set the input context a UI element takes input in via command queue."""
+        ...
+    def set_ui_interactable(self, object_id: int, interactable: bool) -> None:
+        """Set whether a UI element takes input via command queue."""
+        ...
+    def set_ui_layout(self, object_id: int, anchor: str = ..., x: float = ..., y: float = ..., width: Any = ..., height: Any = ..., pivot: tuple[float, float] | None = ...) -> None:
+        """Anchor a UI object to its parent (or the window) via command queue."""
+        ...
+    def set_ui_scale_mode(self, mode: str, reference_width: float = ..., reference_height: float = ...) -> None:
+        """Set the UI scale mode via command queue."""
+        ...
+    def set_ui_theme(self, name: str) -> None:
+        """Switch the UI to a registered theme via command queue."""
+        ...
+    def tween_ui(self, object_id: int, property: str, to: Sequence[float], duration: float, easing: str = ..., delay: float = ..., from_: Sequence[float] | None = ...) -> int:
+        """Start a UI property tween via command queue. Returns the tween id."""
+        ...
+    def update_ui_button_text(self, object_id: int, text: str) -> None:
+        """Update a UI button's text at runtime by object ID via command queue."""
+        ...
+    def update_ui_checked(self, object_id: int, checked: bool) -> None:
+        """
+        Update a UI checkbox, toggle switch or radio button's checked state at runtime by object ID via command queue.
+        """
+        ...
+    def update_ui_curve(self, object_id: int, curve: AnimationCurve) -> None:
+        """Replace a UI curve editor's curve at runtime by object ID via command queue."""
+        ...
+    def update_ui_image_scale_mode(self, object_id: int, scale_mode: str) -> None:
+        """Update a UI image's scale mode at runtime by object ID via command queue."""
+        ...
+    def update_ui_image_texture(self, object_id: int, texture_path: str | None = ...) -> None:
+        """Update a UI image's texture at runtime by object ID via command queue (None hides it)."""
+        ...
+    def update_ui_label_text(self, object_id: int, text: str) -> None:
+        """Update a UI label's text at runtime by object ID via command queue."""
+        ...
+    def update_ui_list_item_count(self, object_id: int, item_count: int) -> None:
+        """Update a UI list view's item count at runtime by object ID via command queue."""
+        ...
+    def update_ui_list_selection(self, object_id: int, selected: int | None = ...) -> None:
+        """
+        Select a UI list view row (or clear the selection with `None`) at runtime by object ID via command queue.
+        """
+        ...
+    def update_ui_panel_clip(self, object_id: int, clip_children: bool) -> None:
+        """
+        Turn clipping of a UI panel's children on or off at runtime by object ID via command queue.
+        """
+        ...
+    def update_ui_progress_value(self, object_id: int, value: float) -> None:
+        """Update a UI progress bar's value (0.0-1.0) at runtime by object ID via command queue."""
+        ...
+    def update_ui_scroll_position(self, object_id: int, x: float, y: float) -> None:
+        """Update a UI scroll view's scroll offset at runtime by object ID via command queue."""
+        ...
+    def update_ui_slider_value(self, object_id: int, value: float) -> None:
+        """Update a UI slider's value at runtime by object ID via command queue."""
+        ...
+    def update_ui_stack(self, object_id: int, direction: str = ..., spacing: float = ..., padding: tuple[float, float, float, float] = ..., alignment: str = ..., fit_content: bool = ...) -> None:
+        """
+        Update a UI stack's direction, spacing, padding (left, right, top, bottom),
+        alignment and fit_content at runtime by object ID via command queue.
+        """
+        ...
+    def update_ui_text_input_text(self, object_id: int, text: str) -> None:
+        """Update a UI text input's text at runtime by object ID via command queue."""
+        ...
+
+class FrameStats:
+    """Per-scope timings of the last completed frame, see `Engine.get_frame_stats()`."""
+    @property
+    def fixed_ms(self) -> float: ...
+    @property
+    def frame(self) -> int: ...
+    @property
+    def frame_ms(self) -> float: ...
+    @property
+    def input_ms(self) -> float: ...
+    @property
+    def physics_ms(self) -> float: ...
+    @property
+    def render_ms(self) -> float: ...
+    @property
+    def scopes(self) -> list[tuple[str, float]]: ...
+    @property
+    def scripts_ms(self) -> float: ...
+    @property
+    def slow(self) -> bool: ...
+    @property
+    def slowest_scope(self) -> str: ...
+    @property
+    def total_ms(self) -> float: ...
+    @property
+    def ui_ms(self) -> float: ...
+    @property
+    def update_ms(self) -> float: ...
+    def __repr__(self, /) -> str: ...
+
+class GameObject:
+    """Container for game entities with transform, rendering, and behavior."""
+    def __init__(self, name: str | None = ...) -> None: ...
+    @property
+    def active(self) -> bool: ...
+    @active.setter
+    def active(self, value: bool) -> None: ...
+    def add_child(self, child: GameObject) -> None: ...
+    def add_component(self, component: Any) -> None:
+        """Add a component to this GameObject."""
+        ...
+    def add_mesh_component(self, mesh_component: MeshComponent) -> None:
+        """Add a mesh component to this GameObject."""
+        ...
+    def add_tag(self, tag: str) -> bool:
+        """Add a tag to this GameObject."""
+        ...
+    def add_text_mesh_component(self, text_mesh_component: TextMeshComponent) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    def get_child_count(self) -> int: ...
+    def get_child_id(self, child_id: int) -> GameObject | None: ...
+    def get_child_name(self, name: str) -> list[GameObject]: ...
+    def get_children(self) -> list[GameObject]: ...
+    def get_component(self, component_type: Any) -> Any: ...
+    def get_component_id(self, component_id: int) -> Any: ...
+    def get_component_name(self, name: str) -> Any: ...
+    def get_component_type(self, component_type: str) -> Any: ...
+    def get_components(self, component_type: Any) -> list[Any]: ...
+    def get_components_type(self, component_type: str) -> list[Any]: ...
+    def has_mesh_component(self) -> bool:
+        """Check if this GameObject has a mesh component attached."""
+        ...
+    def has_tag(self, tag: str) -> bool:
+        """Check whether this GameObject has a tag."""
+        ...
+    @property
+    def id(self) -> int: ...
+    def is_component_enabled(self, key: str) -> bool | None:
+        """Get a component's own enabled flag, matched by name first, then by type."""
+        ...
+    def mesh_component(self) -> MeshComponent | None:
+        """Get a copy of this GameObject's mesh component."""
+        ...
+    def mesh_draw_order(self) -> float | None:
+        """Get the draw order (z-index) of the mesh."""
+        ...
+    def mesh_fill_color(self) -> Color | None:
+        """Get the fill color of the mesh."""
+        ...
+    def mesh_image_path(self) -> str | None:
+        """Get the image/texture path of the mesh."""
+        ...
+    def mesh_visible(self) -> bool | None:
+        """Get the visibility state of the mesh."""
+        ...
+    @property
+    def name(self) -> str | None: ...
+    @property
+    def object_type(self) -> str: ...
+    @property
+    def position(self) -> Vec2: ...
+    @position.setter
+    def position(self, value: Vec2) -> None: ...
+    def remove_component(self, component: Any) -> bool: ...
+    def remove_component_id(self, component_id: int) -> bool: ...
+    def remove_component_name(self, name: str) -> bool: ...
+    def remove_mesh_component(self) -> MeshComponent | None:
+        """Remove the mesh component from this GameObject."""
+        ...
+    def remove_tag(self, tag: str) -> bool:
+        """Remove a tag from this GameObject."""
+        ...
+    @property
+    def rotation(self) -> float: ...
+    @rotation.setter
+    def rotation(self, value: float) -> None: ...
+    @property
+    def scale(self) -> Vec2: ...
+    @scale.setter
+    def scale(self, value: Vec2) -> None: ...
+    def set_component_enabled(self, key: str, enabled: bool) -> bool:
+        """Enable or disable a component at runtime."""
+        ...
+    def set_mesh_component(self, mesh_component: MeshComponent) -> None:
+        """Set the mesh component for this GameObject."""
+        ...
+    def set_mesh_draw_order(self, draw_order: float) -> None:
+        """Set the draw order (z-index) of the mesh."""
+        ...
+    def set_mesh_fill_color(self, color: Color | None) -> None:
+        """Set the fill color of the mesh."""
+        ...
+    def set_mesh_geometry_circle(self, radius: float, segments: int = ...) -> None:
+        """Set the mesh geometry to a circle."""
+        ...
+    def set_mesh_geometry_rectangle(self, width: float, height: float) -> None:
+        """Set the mesh geometry to a rectangle."""
+        ...
+    def set_mesh_image_path(self, image_path: str | None) -> None:
+        """Set the image/texture path for the mesh."""
+        ...
+    def set_mesh_visible(self, visible: bool) -> None:
+        """Set the visibility of the mesh."""
+        ...
+    def set_name(self, name: str) -> None:
+        """Set or change the name of this GameObject."""
+        ...
+    def set_object_type(self, object_type: str) -> None:
+        """Set the object type for specialized rendering or behavior."""
+        ...
+    @property
+    def tags(self) -> list[str]: ...
+    def text_mesh_component(self) -> Any: ...
+    def update(self, time: Time | None = ...) -> None:
+        """Manually update this GameObject."""
+        ...
+
+class ImageComponent:
+    """Python wrapper for ImageComponent."""
+    def __init__(self, texture_path: str | None = ..., x: float = ..., y: float = ..., width: float = ..., height: float = ..., scale_mode: str = ...) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    def get_bounds(self) -> tuple[float, float, float, float]: ...
+    def get_scale_mode(self) -> str: ...
+    def get_texture(self) -> str | None: ...
+    def get_texture_size(self) -> tuple[int, int] | None: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    def set_depth(self, depth: float) -> None: ...
+    def set_position(self, x: float, y: float) -> None: ...
+    def set_scale_mode(self, scale_mode: str) -> None:
+        """Set the scale mode: "stretch", "fit", "fill" or "tile"."""
+        ...
+    def set_size(self, width: float, height: float) -> None: ...
+    def set_texture(self, texture_path: str | None = ...) -> None:
+        """
+        Set the texture path (relative paths resolve against the source root), or None to hide it.
+        """
+        ...
+    def set_texture_size(self, width: int, height: int) -> None:
+        """Set the texture's pixel size; normally read from the file when the image is added."""
+        ...
+
+class InputEvent:
+    """A raw input event from `Input.events()`."""
+    @property
+    def actions(self) -> list[str]: ...
+    @property
+    def axis_id(self) -> int | None: ...
+    @property
+    def button(self) -> str | None: ...
+    @property
+    def button_id(self) -> int | None: ...
+    @property
+    def device(self) -> str | None: ...
+    @property
+    def dx(self) -> float | None: ...
+    @property
+    def dy(self) -> float | None: ...
+    @property
+    def joystick_id(self) -> int | None: ...
+    @property
+    def key(self) -> Any: ...
+    @property
+    def player(self) -> int | None: ...
+    @property
+    def text(self) -> str | None: ...
+    @property
+    def type(self) -> str: ...
+    @property
+    def value(self) -> float | None: ...
+    @property
+    def x(self) -> float | None: ...
+    @property
+    def y(self) -> float | None: ...
+    def __repr__(self, /) -> str: ...
+
+class Keys:
+    A: ClassVar[str]
+    ALT: ClassVar[str]
+    APOSTROPHE: ClassVar[str]
+    ARROW_DOWN: ClassVar[str]
+    ARROW_LEFT: ClassVar[str]
+    ARROW_RIGHT: ClassVar[str]
+    ARROW_UP: ClassVar[str]
+    B: ClassVar[str]
+    BACKSLASH: ClassVar[str]
+    BACKSPACE: ClassVar[str]
+    C: ClassVar[str]
+    CAPS_LOCK: ClassVar[str]
+    COMMA: ClassVar[str]
+    CONTEXT_MENU: ClassVar[str]
+    CONTROL: ClassVar[str]
+    D: ClassVar[str]
+    DELETE: ClassVar[str]
+    E: ClassVar[str]
+    END: ClassVar[str]
+    ENTER: ClassVar[str]
+    EQUALS: ClassVar[str]
+    ESCAPE: ClassVar[str]
+    F: ClassVar[str]
+    F1: ClassVar[str]
+    F10: ClassVar[str]
+    F11: ClassVar[str]
+    F12: ClassVar[str]
+    F2: ClassVar[str]
+    F3: ClassVar[str]
+    F4: ClassVar[str]
+    F5: ClassVar[str]
+    F6: ClassVar[str]
+    F7: ClassVar[str]
+    F8: ClassVar[str]
+    F9: ClassVar[str]
+    G: ClassVar[str]
+    GRAVE: ClassVar[str]
+    H: ClassVar[str]
+    HOME: ClassVar[str]
+    I: ClassVar[str]
+    INSERT: ClassVar[str]
+    J: ClassVar[str]
+    K: ClassVar[str]
+    L: ClassVar[str]
+    LEFT_BRACKET: ClassVar[str]
+    M: ClassVar[str]
+    MINUS: ClassVar[str]
+    N: ClassVar[str]
+    NUM_0: ClassVar[str]
+    NUM_1: ClassVar[str]
+    NUM_2: ClassVar[str]
+    NUM_3: ClassVar[str]
+    NUM_4: ClassVar[str]
+    NUM_5: ClassVar[str]
+    NUM_6: ClassVar[str]
+    NUM_7: ClassVar[str]
+    NUM_8: ClassVar[str]
+    NUM_9: ClassVar[str]
+    NUM_LOCK: ClassVar[str]
+    O: ClassVar[str]
+    P: ClassVar[str]
+    PAGE_DOWN: ClassVar[str]
+    PAGE_UP: ClassVar[str]
+    PAUSE: ClassVar[str]
+    PERIOD: ClassVar[str]
+    PRINT_SCREEN: ClassVar[str]
+    Q: ClassVar[str]
+    R: ClassVar[str]
+    RIGHT_BRACKET: ClassVar[str]
+    S: ClassVar[str]
+    SCROLL_LOCK: ClassVar[str]
+    SEMICOLON: ClassVar[str]
+    SHIFT: ClassVar[str]
+    SLASH: ClassVar[str]
+    SPACE: ClassVar[str]
+    SUPER: ClassVar[str]
+    T: ClassVar[str]
+    TAB: ClassVar[str]
+    U: ClassVar[str]
+    V: ClassVar[str]
+    W: ClassVar[str]
+    X: ClassVar[str]
+    Y: ClassVar[str]
+    Z: ClassVar[str]
+
+class LabelComponent:
+    """Python wrapper for LabelComponent."""
+    def __init__(self, text: str = ..., x: float = ..., y: float = ..., font_size: float = ...) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    def get_auto_height(self) -> bool: ...
+    def get_bounds(self) -> tuple[float, float, float, float]: ...
+    def get_font_fallbacks(self) -> list[str]: ...
+    def get_text(self) -> str: ...
+    def get_wrap(self) -> bool: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    def set_align(self, align: str) -> None: ...
+    def set_auto_height(self, auto_height: bool) -> None:
+        """Resize the label's height to fit its text every frame."""
+        ...
+    def set_color(self, r: float, g: float, b: float, a: float) -> None: ...
+    def set_depth(self, depth: float) -> None: ...
+    def set_font_fallbacks(self, fallbacks: Sequence[str]) -> None:
+        """
+        Set the fonts tried, in order, for characters the primary font lacks.
+        An empty list goes back to the UI theme's fallbacks.
+        """
+        ...
+    def set_font_family(self, font_family: str | None) -> None: ...
+    def set_font_path(self, font_path: str | None) -> None: ...
+    def set_font_size(self, size: float) -> None: ...
+    def set_font_style(self, font_style: str) -> None: ...
+    def set_font_weight(self, font_weight: str) -> None: ...
+    def set_kerning(self, kerning: bool) -> None: ...
+    def set_letter_spacing(self, letter_spacing: float) -> None: ...
+    def set_line_spacing(self, line_spacing: float) -> None: ...
+    def set_position(self, x: float, y: float) -> None: ...
+    def set_size(self, width: float, height: float) -> None: ...
+    def set_text(self, text: str) -> None: ...
+    def set_vertical_align(self, align: str) -> None: ...
+    def set_wrap(self, wrap: bool) -> None:
+        """Break the text into lines at the label's width."""
+        ...
+
+class ListViewComponent:
+    """Python wrapper for ListViewComponent."""
+    def __init__(self, item_count: int = ..., row_height: float = ..., x: float = ..., y: float = ..., width: float = ..., height: float = ...) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    def get_item_count(self) -> int: ...
+    def get_row_height(self) -> float: ...
+    def get_scroll_offset(self) -> float: ...
+    def get_selected(self) -> int | None: ...
+    def get_visible_range(self) -> tuple[int, int]:
+        """Get the visible rows as `(first, end)`, `end` excluded."""
+        ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    def refresh(self) -> None:
+        """Fetch the visible rows from the item provider again on the next update."""
+        ...
+    def scroll_to_item(self, index: int) -> None:
+        """Scroll by the smallest amount that shows the whole row `index`."""
+        ...
+    def set_background_color(self, r: float, g: float, b: float, a: float) -> None: ...
+    def set_border(self, width: float, r: float, g: float, b: float, a: float) -> None: ...
+    def set_depth(self, depth: float) -> None: ...
+    def set_font_family(self, font_family: str | None) -> None: ...
+    def set_font_path(self, font_path: str | None) -> None: ...
+    def set_font_size(self, font_size: float) -> None: ...
+    def set_font_style(self, font_style: str) -> None: ...
+    def set_font_weight(self, font_weight: str) -> None: ...
+    def set_hover_color(self, r: float, g: float, b: float, a: float) -> None: ...
+    def set_item_count(self, item_count: int) -> None: ...
+    def set_item_provider(self, py_callback: Any) -> None:
+        """Set a Python callback that returns the text of row `index`."""
+        ...
+    def set_on_selection_changed(self, py_callback: Any) -> None:
+        """
+        Set a Python callback fired with the row index whenever the user selects a different row.
+        """
+        ...
+    def set_on_visible_rows_changed(self, py_callback: Any) -> None:
+        """
+        Set a Python callback fired with the visible range `(first, end)` each
+        time its rows were fetched from the item provider.
+        """
+        ...
+    def set_position(self, x: float, y: float) -> None: ...
+    def set_row_height(self, row_height: float) -> None: ...
+    def set_scroll_offset(self, offset: float) -> None:
+        """Set the scroll offset in pixels, clamped to the rows."""
+        ...
+    def set_scroll_speed(self, speed: float) -> None:
+        """Set how many pixels one mouse wheel line scrolls."""
+        ...
+    def set_selected(self, index: int | None = ...) -> None:
+        """
+        Select a row, or clear the selection with `None`, without firing `on_selection_changed`.
+        """
+        ...
+    def set_selected_text_color(self, r: float, g: float, b: float, a: float) -> None: ...
+    def set_selection_color(self, r: float, g: float, b: float, a: float) -> None: ...
+    def set_show_scrollbar(self, show: bool) -> None: ...
+    def set_size(self, width: float, height: float) -> None: ...
+    def set_text_color(self, r: float, g: float, b: float, a: float) -> None: ...
+
+class MeshComponent:
+    """
+    Python wrapper for MeshComponent.
+    2D mesh rendering component for GameObjects.
+    """
+    def __init__(self, name: str | None = ...) -> None: ...
+    @property
+    def draw_order(self) -> float: ...
+    @draw_order.setter
+    def draw_order(self, value: float) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    def fill_color(self) -> Color | None: ...
+    def geometry(self) -> MeshGeometry: ...
+    @property
+    def id(self) -> int: ...
+    def image_path(self) -> str | None: ...
+    @property
+    def name(self) -> str: ...
+    def set_fill_color(self, color: Color | None) -> None: ...
+    def set_geometry(self, geometry: MeshGeometry) -> None: ...
+    def set_geometry_circle(self, radius: float, segments: int = ...) -> None: ...
+    def set_geometry_rectangle(self, width: float, height: float) -> None: ...
+    def set_image_path(self, image_path: str | None) -> None: ...
+    @property
+    def visible(self) -> bool: ...
+    @visible.setter
+    def visible(self, value: bool) -> None: ...
+
+class MeshGeometry:
+    def __init__(self, vertices: Any = ..., indices: Sequence[int] | None = ..., uvs: Any = ...) -> None: ...
+    @staticmethod
+    def circle(radius: float, segments: int = ...) -> MeshGeometry: ...
+    def indices(self) -> list[int]: ...
+    @staticmethod
+    def rectangle(width: float, height: float) -> MeshGeometry: ...
+    def uv_array(self) -> Any:
+        """UVs as a `float32` buffer shaped `(n, 2)`, like `vertex_array()`."""
+        ...
+    def uvs(self) -> list[tuple[float, float]]: ...
+    def vertex_array(self) -> Any:
+        """
+        Vertex positions as a `float32` buffer shaped `(n, 2)`, for
+        `numpy.asarray(geometry.vertex_array())`.
+        """
+        ...
+    def vertices(self) -> list[tuple[float, float]]: ...
+
+class MouseButton:
+    LEFT: ClassVar[str]
+    MIDDLE: ClassVar[str]
+    RIGHT: ClassVar[str]
+
+class PanelComponent:
+    """Python wrapper for PanelComponent."""
+    def __init__(self, x: float = ..., y: float = ..., width: float = ..., height: float = ...) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    def get_clip_children(self) -> bool: ...
+    def get_dim_color(self) -> tuple[float, float, float, float]: ...
+    @property
+    def id(self) -> int: ...
+    def is_modal(self) -> bool: ...
+    @property
+    def name(self) -> str: ...
+    def set_background_color(self, r: float, g: float, b: float, a: float) -> None: ...
+    def set_background_gradient(self, top: tuple[float, float, float, float], bottom: tuple[float, float, float, float] | None = ...) -> None:
+        """
+        Fill the background with a vertical gradient. `bottom=None` goes back
+        to a solid `top` color.
+        """
+        ...
+    def set_border(self, width: float, r: float, g: float, b: float, a: float) -> None: ...
+    def set_clip_children(self, clip: bool) -> None:
+        """Clip child widgets to the panel's bounds."""
+        ...
+    def set_corner_radius(self, radius: float) -> None:
+        """Round the corners of the background, border and shadow."""
+        ...
+    def set_depth(self, depth: float) -> None: ...
+    def set_dim_color(self, r: float, g: float, b: float, a: float) -> None:
+        """Set the backdrop color drawn behind a modal panel. Alpha 0 disables dimming."""
+        ...
+    def set_modal(self, modal: bool) -> None:
+        """
+        Make the panel modal: while it is shown, only it and its children get
+        UI input and the rest of the screen is dimmed.
+        """
+        ...
+    def set_position(self, x: float, y: float) -> None: ...
+    def set_shadow(self, r: float, g: float, b: float, a: float, offset_x: float = ..., offset_y: float = ..., blur: float = ...) -> None:
+        """Set the drop shadow. Alpha 0 removes it."""
+        ...
+    def set_size(self, width: float, height: float) -> None: ...
+
+class PhysicsLayers:
+    """Physics layer constants for collision filtering."""
+    DEFAULT: ClassVar[int]
+    ENEMY: ClassVar[int]
+    ENVIRONMENT: ClassVar[int]
+    PICKUP: ClassVar[int]
+    PLAYER: ClassVar[int]
+    PROJECTILE: ClassVar[int]
+    TRIGGER: ClassVar[int]
+    UI: ClassVar[int]
+
+    @staticmethod
+    def all() -> int:
+        """Create a mask that collides with all layers."""
+        ...
+    @staticmethod
+    def create_mask(layers: Sequence[int]) -> int:
+        """Create a collision mask from a list of layers."""
+        ...
+    @staticmethod
+    def none() -> int:
+        """Create a mask that collides with no layers."""
+        ...
+
+class ProgressBarComponent:
+    """Python wrapper for ProgressBarComponent."""
+    def __init__(self, value: float = ..., x: float = ..., y: float = ..., width: float = ..., height: float = ..., fill_direction: str = ..., text: str | None = ...) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    def get_fill_direction(self) -> str: ...
+    def get_text(self) -> str | None: ...
+    def get_value(self) -> float: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    def set_border(self, width: float, r: float, g: float, b: float, a: float) -> None: ...
+    def set_depth(self, depth: float) -> None: ...
+    def set_fill_color(self, r: float, g: float, b: float, a: float) -> None:
+        """Set the fill color (the `text_color` of the enabled style states)."""
+        ...
+    def set_fill_direction(self, fill_direction: str) -> None:
+        """
+        Set the fill direction: "left_to_right", "right_to_left", "bottom_to_top" or "top_to_bottom".
+        """
+        ...
+    def set_font_family(self, font_family: str | None) -> None: ...
+    def set_font_path(self, font_path: str | None) -> None: ...
+    def set_font_size(self, font_size: float) -> None: ...
+    def set_font_style(self, font_style: str) -> None: ...
+    def set_font_weight(self, font_weight: str) -> None: ...
+    def set_position(self, x: float, y: float) -> None: ...
+    def set_size(self, width: float, height: float) -> None: ...
+    def set_text(self, text: str | None = ...) -> None:
+        """Set the overlay text, or None to hide it. `{percent}` and `{value}` are filled in."""
+        ...
+    def set_text_color(self, r: float, g: float, b: float, a: float) -> None:
+        """Set the overlay text color."""
+        ...
+    def set_track_color(self, r: float, g: float, b: float, a: float) -> None:
+        """Set the track color (the `background_color` of every style state)."""
+        ...
+    def set_value(self, value: float) -> None:
+        """Set the value, clamped to 0.0-1.0."""
+        ...
+
+class RadioButtonComponent:
+    """Python wrapper for RadioButtonComponent."""
+    def __init__(self, label: str = ..., value: str = ..., group: RadioGroup | None = ..., selected: bool = ..., x: float = ..., y: float = ..., width: float = ..., height: float = ...) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    def get_label(self) -> str: ...
+    def get_value(self) -> str: ...
+    @property
+    def id(self) -> int: ...
+    def is_selected(self) -> bool: ...
+    @property
+    def name(self) -> str: ...
+    def set_depth(self, depth: float) -> None: ...
+    def set_font_family(self, font_family: str | None) -> None: ...
+    def set_font_path(self, font_path: str | None) -> None: ...
+    def set_font_size(self, font_size: float) -> None: ...
+    def set_font_style(self, font_style: str) -> None: ...
+    def set_font_weight(self, font_weight: str) -> None: ...
+    def set_group(self, group: RadioGroup | None = ...) -> None:
+        """Move the button into `group`, or out of any group with `None`."""
+        ...
+    def set_label(self, label: str) -> None: ...
+    def set_position(self, x: float, y: float) -> None: ...
+    def set_selected(self, selected: bool) -> None:
+        """Set the selected state without updating the rest of the group."""
+        ...
+    def set_size(self, width: float, height: float) -> None: ...
+    def set_value(self, value: str) -> None: ...
+
+class RadioGroup:
+    """Python wrapper for RadioGroup."""
+    def __init__(self, name: str = ...) -> None: ...
+    @property
+    def name(self) -> str: ...
+    def set_on_selection_changed(self, py_callback: Any) -> None:
+        """
+        Set a Python callback fired with the selected button's value whenever
+        the user picks a different member of the group.
+        """
+        ...
+
+class RenderStats:
+    """Batching statistics for the last rendered frame, see `Engine.render_stats()`."""
+    @property
+    def draw_calls(self) -> int: ...
+    @property
+    def draw_items(self) -> int: ...
+    @property
+    def items_per_draw_call(self) -> float: ...
+    @property
+    def texture_switches(self) -> int: ...
+    def __repr__(self, /) -> str: ...
+
+class RendererCapabilities:
+    """GPU adapter and window surface capabilities, see `Engine.capabilities()`."""
+    @property
+    def adapter_name(self) -> str: ...
+    @property
+    def backend(self) -> str: ...
+    @property
+    def device_id(self) -> int: ...
+    @property
+    def device_type(self) -> str: ...
+    @property
+    def driver(self) -> str: ...
+    @property
+    def driver_info(self) -> str: ...
+    @property
+    def max_texture_size(self) -> int: ...
+    @property
+    def msaa_sample_counts(self) -> list[int]: ...
+    @property
+    def present_mode(self) -> str: ...
+    @property
+    def present_modes(self) -> list[str]: ...
+    @property
+    def safe_mode(self) -> bool: ...
+    @property
+    def surface_format(self) -> str: ...
+    @property
+    def surface_formats(self) -> list[str]: ...
+    @property
+    def texture_formats(self) -> list[str]: ...
+    @property
+    def vendor_id(self) -> int: ...
+    def __repr__(self, /) -> str: ...
+
+class ScrollViewComponent:
+    """Python wrapper for ScrollViewComponent."""
+    def __init__(self, x: float = ..., y: float = ..., width: float = ..., height: float = ..., horizontal: bool = ..., vertical: bool = ...) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    def get_content_size(self) -> tuple[float, float]: ...
+    def get_max_scroll(self) -> tuple[float, float]: ...
+    def get_scroll(self) -> tuple[float, float]: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    def set_background_color(self, r: float, g: float, b: float, a: float) -> None: ...
+    def set_border(self, width: float, r: float, g: float, b: float, a: float) -> None: ...
+    def set_content_size(self, width: float | None = ..., height: float | None = ...) -> None:
+        """Fix the content size. Pass `None` to measure it from the children again."""
+        ...
+    def set_depth(self, depth: float) -> None: ...
+    def set_directions(self, horizontal: bool, vertical: bool) -> None: ...
+    def set_on_scrolled(self, py_callback: Any) -> None:
+        """Set a Python callback fired with the new `(x, y)` offset whenever the user scrolls."""
+        ...
+    def set_position(self, x: float, y: float) -> None: ...
+    def set_scroll(self, x: float, y: float) -> None:
+        """Set the scroll offset, clamped to the content, without firing `on_scrolled`."""
+        ...
+    def set_scroll_speed(self, speed: float) -> None:
+        """Set how many pixels one mouse wheel line scrolls."""
+        ...
+    def set_scrollbar_color(self, r: float, g: float, b: float, a: float) -> None: ...
+    def set_show_scrollbars(self, show: bool) -> None: ...
+    def set_size(self, width: float, height: float) -> None: ...
+
+class SliderComponent:
+    """Python wrapper for SliderComponent."""
+    def __init__(self, min_value: float = ..., max_value: float = ..., value: float = ..., x: float = ..., y: float = ..., width: float = ..., height: float = ..., step: float = ..., orientation: str = ...) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    def get_orientation(self) -> str: ...
+    def get_range(self) -> tuple[float, float]: ...
+    def get_step(self) -> float: ...
+    def get_value(self) -> float: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    def set_depth(self, depth: float) -> None: ...
+    def set_on_value_changed(self, py_callback: Any) -> None:
+        """Set a Python callback fired with the new value whenever the user moves the slider."""
+        ...
+    def set_orientation(self, orientation: str) -> None:
+        """Set the orientation: `"horizontal"` or `"vertical"`."""
+        ...
+    def set_position(self, x: float, y: float) -> None: ...
+    def set_range(self, min_value: float, max_value: float) -> None: ...
+    def set_size(self, width: float, height: float) -> None: ...
+    def set_step(self, step: float) -> None: ...
+    def set_value(self, value: float) -> None: ...
+
+class StackComponent:
+    """Python wrapper for StackComponent."""
+    def __init__(self, direction: str = ..., x: float = ..., y: float = ..., width: float = ..., height: float = ..., spacing: float = ..., padding: tuple[float, float, float, float] = ..., alignment: str = ..., fit_content: bool = ...) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    def get_alignment(self) -> str: ...
+    def get_bounds(self) -> tuple[float, float, float, float]: ...
+    def get_direction(self) -> str: ...
+    def get_fit_content(self) -> bool: ...
+    def get_padding(self) -> tuple[float, float, float, float]:
+        """Get the padding as (left, right, top, bottom)."""
+        ...
+    def get_spacing(self) -> float: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    def set_alignment(self, alignment: str) -> None:
+        """Set the cross-axis alignment: "start", "center", "end" or "stretch"."""
+        ...
+    def set_depth(self, depth: float) -> None: ...
+    def set_direction(self, direction: str) -> None:
+        """Set the direction: "vertical" or "horizontal"."""
+        ...
+    def set_fit_content(self, fit_content: bool) -> None:
+        """Resize the stack along its axis to wrap its children."""
+        ...
+    def set_padding(self, left: float, right: float, top: float, bottom: float) -> None:
+        """Set the padding inside the stack's edges."""
+        ...
+    def set_position(self, x: float, y: float) -> None: ...
+    def set_size(self, width: float, height: float) -> None: ...
+    def set_spacing(self, spacing: float) -> None: ...
+
+class TextInputComponent:
+    """Python wrapper for TextInputComponent."""
+    def __init__(self, text: str = ..., x: float = ..., y: float = ..., width: float = ..., height: float = ..., placeholder: str = ...) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    def get_placeholder(self) -> str: ...
+    def get_text(self) -> str: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    def set_depth(self, depth: float) -> None: ...
+    def set_font_family(self, font_family: str | None) -> None: ...
+    def set_font_path(self, font_path: str | None) -> None: ...
+    def set_font_size(self, font_size: float) -> None: ...
+    def set_font_style(self, font_style: str) -> None: ...
+    def set_font_weight(self, font_weight: str) -> None: ...
+    def set_kerning(self, kerning: bool) -> None: ...
+    def set_max_length(self, max_length: int | None = ...) -> None:
+        """Limit the number of characters, or `None` for no limit."""
+        ...
+    def set_on_changed(self, py_callback: Any) -> None:
+        """Set a Python callback fired with the new text whenever the user edits it."""
+        ...
+    def set_on_submit(self, py_callback: Any) -> None:
+        """Set a Python callback fired with the current text when Enter is pressed."""
+        ...
+    def set_placeholder(self, placeholder: str) -> None: ...
+    def set_position(self, x: float, y: float) -> None: ...
+    def set_size(self, width: float, height: float) -> None: ...
+    def set_text(self, text: str) -> None: ...
+
+class TextMeshComponent:
+    def __init__(self, text: str = ..., font_size: float = ..., name: str | None = ..., font_path: str | None = ..., font_family: str | None = ..., font_weight: str | None = ..., font_style: str | None = ..., letter_spacing: float = ..., line_spacing: float = ..., kerning: bool = ...) -> None: ...
+    @property
+    def color(self) -> Color: ...
+    @color.setter
+    def color(self, value: Color) -> None: ...
+    @property
+    def draw_order(self) -> float: ...
+    @draw_order.setter
+    def draw_order(self, value: float) -> None: ...
+    @property
+    def font_family(self) -> str | None: ...
+    @font_family.setter
+    def font_family(self, value: str | None) -> None: ...
+    @property
+    def font_path(self) -> str | None: ...
+    @font_path.setter
+    def font_path(self, value: str | None) -> None: ...
+    @property
+    def font_size(self) -> float: ...
+    @font_size.setter
+    def font_size(self, value: float) -> None: ...
+    @property
+    def font_style(self) -> str: ...
+    @font_style.setter
+    def font_style(self, value: str) -> None: ...
+    @property
+    def font_weight(self) -> str: ...
+    @font_weight.setter
+    def font_weight(self, value: str) -> None: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def kerning(self) -> bool: ...
+    @kerning.setter
+    def kerning(self, value: bool) -> None: ...
+    @property
+    def letter_spacing(self) -> float: ...
+    @letter_spacing.setter
+    def letter_spacing(self, value: float) -> None: ...
+    @property
+    def line_spacing(self) -> float: ...
+    @line_spacing.setter
+    def line_spacing(self, value: float) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def text(self) -> str: ...
+    @text.setter
+    def text(self, value: str) -> None: ...
+    @property
+    def visible(self) -> bool: ...
+    @visible.setter
+    def visible(self, value: bool) -> None: ...
+
+class Time:
+    """
+    Python wrapper for Time.
+    Frame timing information.
+    """
+    def __init__(self) -> None: ...
+    @property
+    def delta_time(self) -> float: ...
+    @property
+    def elapsed_time(self) -> float: ...
+    def tick(self) -> None: ...
+
+class ToggleSwitchComponent:
+    """Python wrapper for ToggleSwitchComponent."""
+    def __init__(self, label: str = ..., checked: bool = ..., x: float = ..., y: float = ..., width: float = ..., height: float = ...) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    def get_label(self) -> str: ...
+    @property
+    def id(self) -> int: ...
+    def is_checked(self) -> bool: ...
+    @property
+    def name(self) -> str: ...
+    def set_checked(self, checked: bool) -> None:
+        """Set the checked state without firing `on_toggled`."""
+        ...
+    def set_depth(self, depth: float) -> None: ...
+    def set_font_family(self, font_family: str | None) -> None: ...
+    def set_font_path(self, font_path: str | None) -> None: ...
+    def set_font_size(self, font_size: float) -> None: ...
+    def set_font_style(self, font_style: str) -> None: ...
+    def set_font_weight(self, font_weight: str) -> None: ...
+    def set_knob_color(self, r: float, g: float, b: float, a: float) -> None: ...
+    def set_label(self, label: str) -> None: ...
+    def set_on_color(self, r: float, g: float, b: float, a: float) -> None:
+        """Set the track color used while the switch is on."""
+        ...
+    def set_on_toggled(self, py_callback: Any) -> None:
+        """Set a Python callback fired with the new state whenever the user flips the switch."""
+        ...
+    def set_position(self, x: float, y: float) -> None: ...
+    def set_size(self, width: float, height: float) -> None: ...
+
+class TransformComponent:
+    """2D transform component for position, rotation, and scale."""
+    def __init__(self, name: str) -> None: ...
+    @property
+    def enabled(self) -> bool: ...
+    @enabled.setter
+    def enabled(self, value: bool) -> None: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def position(self) -> Vec2: ...
+    @position.setter
+    def position(self, value: Vec2) -> None: ...
+    @property
+    def rotation(self) -> float: ...
+    @rotation.setter
+    def rotation(self, value: float) -> None: ...
+    @property
+    def scale(self) -> Vec2: ...
+    @scale.setter
+    def scale(self, value: Vec2) -> None: ...
+
+class UIEvent:
+    """A UI event delivered to a `CustomWidgetComponent` callback."""
+    @property
+    def button(self) -> str | None: ...
+    @property
+    def ctrl(self) -> bool: ...
+    @property
+    def dx(self) -> Any: ...
+    @property
+    def dy(self) -> float | None: ...
+    @property
+    def key(self) -> str | None: ...
+    @property
+    def phase(self) -> str: ...
+    @property
+    def propagation_stopped(self) -> bool: ...
+    @property
+    def shift(self) -> bool: ...
+    def stop_propagation(self) -> None:
+        """Keep the event from reaching any other widget, the same as returning True."""
+        ...
+    @property
+    def text(self) -> str | None: ...
+    @property
+    def type(self) -> str: ...
+    @property
+    def x(self) -> float | None: ...
+    @property
+    def y(self) -> float | None: ...
+    def __repr__(self, /) -> str: ...
+
+class UIScaleMode:
+    """Names accepted by `Engine.set_ui_scale_mode()`."""
+    CONSTANT_PIXEL_SIZE: ClassVar[str]
+    FIXED_RESOLUTION: ClassVar[str]
+    SCALE_WITH_HEIGHT: ClassVar[str]
+
+class Vec2:
+    """2D vector for positions, directions, and mathematical operations."""
+    DOWN: ClassVar[Vec2]
+    LEFT: ClassVar[Vec2]
+    RIGHT: ClassVar[Vec2]
+    UP: ClassVar[Vec2]
+    ZERO: ClassVar[Vec2]
+
+    def __init__(self, x: float, y: float) -> None: ...
+    def add(self, other: Vec2) -> Vec2:
+        """Add two vectors component-wise."""
+        ...
+    def add_scalar(self, scalar: float) -> Vec2:
+        """Add a scalar value to both components."""
+        ...
+    def angle(self) -> float:
+        """Get the direction of the vector in radians, measured from the +x axis."""
+        ...
+    def angle_to(self, other: Vec2) -> float:
+        """Get the signed angle in radians that rotates this vector onto `other`."""
+        ...
+    def clamp_length(self, max: float) -> Vec2:
+        """Return the vector shortened to at most `max` length, keeping its direction."""
+        ...
+    def cross(self, other: Vec2) -> float:
+        """Calculate the 2D cross product (z-component) with another vector."""
+        ...
+    def distance(self, other: Vec2) -> float:
+        """Calculate the Euclidean distance to another vector."""
+        ...
+    def divide(self, other: Vec2) -> Vec2:
+        """Divide one vector by another component-wise."""
+        ...
+    def divide_scalar(self, scalar: float) -> Vec2:
+        """Divide both components by a scalar."""
+        ...
+    def dot(self, other: Vec2) -> float:
+        """Calculate the dot product with another vector."""
+        ...
+    def length(self) -> float:
+        """Calculate the magnitude (length) of the vector."""
+        ...
+    def lerp(self, other: Vec2, t: float) -> Vec2:
+        """Linearly interpolate between two vectors."""
+        ...
+    def move_towards(self, target: Vec2, max_delta: float) -> Vec2:
+        """Move toward `target` by at most `max_delta`, without overshooting."""
+        ...
+    def multiply(self, other: Vec2) -> Vec2:
+        """Multiply two vectors component-wise."""
+        ...
+    def multiply_scalar(self, scalar: float) -> Vec2:
+        """Multiply both components by a scalar (scale the vector)."""
+        ...
+    def normalize(self) -> Vec2:
+        """Return a normalized (unit length) vector in the same direction."""
+        ...
+    def perpendicular(self) -> Vec2:
+        """Return this vector rotated 90° counter-clockwise: `Vec2(-y, x)`."""
+        ...
+    def reflect(self, normal: Vec2) -> Vec2:
+        """Reflect the vector off a surface, like a ball bouncing off a wall."""
+        ...
+    def rotate(self, radians: float) -> Vec2:
+        """Rotate the vector by an angle in radians."""
+        ...
+    def subtract(self, other: Vec2) -> Vec2:
+        """Subtract one vector from another component-wise."""
+        ...
+    def subtract_scalar(self, scalar: float) -> Vec2:
+        """Subtract a scalar value from both components."""
+        ...
+    @property
+    def x(self) -> float: ...
+    @property
+    def y(self) -> float: ...
+    def __abs__(self, /) -> Vec2: ...
+    def __add__(self, value: Any, /) -> Vec2: ...
+    def __copy__(self) -> Vec2: ...
+    def __deepcopy__(self, _memo: Any) -> Vec2: ...
+    def __eq__(self, value: object, /) -> bool: ...
+    def __getitem__(self, key: int, /) -> float: ...
+    def __hash__(self, /) -> int: ...
+    def __iter__(self, /) -> Iterator[Any]: ...
+    def __len__(self, /) -> int: ...
+    def __mul__(self, value: Any, /) -> Vec2: ...
+    def __neg__(self, /) -> Vec2: ...
+    def __radd__(self, value: Any, /) -> Vec2: ...
+    def __reduce__(self) -> tuple[type, tuple[float, float]]: ...
+    def __repr__(self, /) -> str: ...
+    def __rmul__(self, value: Any, /) -> Vec2: ...
+    def __rsub__(self, value: Any, /) -> Vec2: ...
+    def __rtruediv__(self, value: Any, /) -> Vec2: ...
+    def __str__(self, /) -> str: ...
+    def __sub__(self, value: Any, /) -> Vec2: ...
+    def __truediv__(self, value: Any, /) -> Vec2: ...
+
+class Vec3:
+    """3D vector for positions, directions, and mathematical operations."""
+    BACK: ClassVar[Vec3]
+    DOWN: ClassVar[Vec3]
+    FORWARD: ClassVar[Vec3]
+    LEFT: ClassVar[Vec3]
+    RIGHT: ClassVar[Vec3]
+    UP: ClassVar[Vec3]
+    ZERO: ClassVar[Vec3]
+
+    def __init__(self, x: float, y: float, z: float) -> None: ...
+    def add(self, other: Vec3) -> Vec3:
+        """Add two vectors component-wise."""
+        ...
+    def add_scalar(self, scalar: float) -> Vec3:
+        """Add a scalar value to all components."""
+        ...
+    def angle_to(self, other: Vec3) -> float:
+        """Get the angle in radians between this vector and `other`."""
+        ...
+    def clamp_length(self, max: float) -> Vec3:
+        """Return the vector shortened to at most `max` length, keeping its direction."""
+        ...
+    def cross(self, other: Vec3) -> Vec3:
+        """Calculate the 3D cross product with another vector."""
+        ...
+    def distance(self, other: Vec3) -> float:
+        """Calculate the Euclidean distance to another vector."""
+        ...
+    def divide(self, other: Vec3) -> Vec3:
+        """Divide one vector by another component-wise."""
+        ...
+    def divide_scalar(self, scalar: float) -> Vec3:
+        """Divide all components by a scalar."""
+        ...
+    def dot(self, other: Vec3) -> float:
+        """Calculate the dot product with another vector."""
+        ...
+    def length(self) -> float:
+        """Calculate the magnitude (length) of the vector."""
+        ...
+    def lerp(self, other: Vec3, t: float) -> Vec3:
+        """Linearly interpolate between two vectors."""
+        ...
+    def move_towards(self, target: Vec3, max_delta: float) -> Vec3:
+        """Move toward `target` by at most `max_delta`, without overshooting."""
+        ...
+    def multiply(self, other: Vec3) -> Vec3:
+        """Multiply two vectors component-wise."""
+        ...
+    def multiply_scalar(self, scalar: float) -> Vec3:
+        """Multiply all components by a scalar (scale the vector)."""
+        ...
+    def normalize(self) -> Vec3:
+        """Return a normalized (unit length) vector in the same direction."""
+        ...
+    def reflect(self, normal: Vec3) -> Vec3:
+        """Reflect the vector off a surface with the given normal."""
+        ...
+    def rotate(self, radians: float, axis: Vec3 | None = ...) -> Vec3:
+        """Rotate the vector by an angle in radians around an axis."""
+        ...
+    def subtract(self, other: Vec3) -> Vec3:
+        """Subtract one vector from another component-wise."""
+        ...
+    def subtract_scalar(self, scalar: float) -> Vec3:
+        """Subtract a scalar value from all components."""
+        ...
+    @property
+    def x(self) -> float: ...
+    @property
+    def y(self) -> float: ...
+    @property
+    def z(self) -> float: ...
+    def __abs__(self, /) -> Vec3: ...
+    def __add__(self, value: Any, /) -> Vec3: ...
+    def __copy__(self) -> Vec3: ...
+    def __deepcopy__(self, _memo: Any) -> Vec3: ...
+    def __eq__(self, value: object, /) -> bool: ...
+    def __getitem__(self, key: int, /) -> float: ...
+    def __hash__(self, /) -> int: ...
+    def __iter__(self, /) -> Iterator[Any]: ...
+    def __len__(self, /) -> int: ...
+    def __mul__(self, value: Any, /) -> Vec3: ...
+    def __neg__(self, /) -> Vec3: ...
+    def __radd__(self, value: Any, /) -> Vec3: ...
+    def __reduce__(self) -> tuple[type, tuple[float, float, float]]: ...
+    def __repr__(self, /) -> str: ...
+    def __rmul__(self, value: Any, /) -> Vec3: ...
+    def __rsub__(self, value: Any, /) -> Vec3: ...
+    def __rtruediv__(self, value: Any, /) -> Vec3: ...
+    def __str__(self, /) -> str: ...
+    def __sub__(self, value: Any, /) -> Vec3: ...
+    def __truediv__(self, value: Any, /) -> Vec3: ...
+
+class WorldLabelComponent:
+    """Text that follows a `GameObject` in world space, e.g. a nameplate."""
+    def __init__(self, text: str = ..., font_size: float = ..., color: Color | None = ..., offset: Vec2 | None = ..., billboard: bool = ..., scale_with_zoom: bool = ..., name: str | None = ..., font_path: str | None = ..., font_family: str | None = ..., font_weight: str | None = ..., font_style: str | None = ...) -> None: ...
+    @property
+    def billboard(self) -> bool: ...
+    @billboard.setter
+    def billboard(self, value: bool) -> None: ...
+    @property
+    def color(self) -> Color: ...
+    @color.setter
+    def color(self, value: Color) -> None: ...
+    @property
+    def draw_order(self) -> float: ...
+    @draw_order.setter
+    def draw_order(self, value: float) -> None: ...
+    @property
+    def font_size(self) -> float: ...
+    @font_size.setter
+    def font_size(self, value: float) -> None: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def offset(self) -> Vec2: ...
+    @offset.setter
+    def offset(self, value: Vec2) -> None: ...
+    @property
+    def scale_with_zoom(self) -> bool: ...
+    @scale_with_zoom.setter
+    def scale_with_zoom(self, value: bool) -> None: ...
+    @property
+    def text(self) -> str: ...
+    @text.setter
+    def text(self, value: str) -> None: ...
+    @property
+    def visible(self) -> bool: ...
+    @visible.setter
+    def visible(self, value: bool) -> None: ...
+
+def version() -> str:
+    """Get the engine version (module-level function)."""
+    ...
@@ -0,0 +1,592 @@
+"""
+Persistent save data managed by the engine.
+
+`engine.save_data` stores JSON-compatible values by key and binary blobs
+(screenshots, serialized levels) in named slots under a per-user save
+directory:
+
+    engine.save_data.set("progress", {"level": 3, "coins": 120})
+    engine.save_data.save()
+
+Each slot is a directory holding a `values.json` manifest and a `blobs/`
+folder. Writes go to a temporary file that replaces the old one, so a crash
+mid-save never leaves a half-written file behind, and the previous manifest
+is kept as `values.json.bak`. The manifest carries a checksum; a corrupt or
+missing manifest falls back to the backup, and blobs are checked against
+their recorded hash when read.
+"""
+
+from __future__ import annotations
+
+import copy
+import hashlib
+import json
+import os
+import re
+import shutil
+import sys
+import time
+from pathlib import Path
+from typing import TYPE_CHECKING, Any, Optional, Union
+
+if TYPE_CHECKING:
+    from .engine import Engine
+
+DEFAULT_SLOT = "default"
+AUTOSAVE_PREFIX = "autosave_"
+
+_SAVE_VERSION = 1
+_MANIFEST = "values.json"
+_BACKUP_SUFFIX = ".bak"
+_CORRUPT_SUFFIX = ".corrupt"
+_BLOB_DIRECTORY = "blobs"
+_NAME_PATTERN = re.compile(r"^[A-Za-z0-9_][A-Za-z0-9_.-]{0,63}$")
+
+
+def default_save_directory(app_name: str) -> Path:
+    """
+    Get the platform's per-user directory for an app's saves.
+
+    - Windows: `%APPDATA%/<app_name>/saves`
+    - macOS: `~/Library/Application Support/<app_name>/saves`
+    - Linux and others: `$XDG_DATA_HOME/<app_name>/saves`, defaulting to
+      `~/.local/share/<app_name>/saves`
+    """
+    if sys.platform == "win32":
+        appdata = os.environ.get("APPDATA")
+        base = Path(appdata) if appdata else Path.home() / "AppData" / "Roaming"
+    elif sys.platform == "darwin":
+        base = Path.home() / "Library" / "Application Support"
+    else:
+        xdg_data_home = os.environ.get("XDG_DATA_HOME")
+        if xdg_data_home and Path(xdg_data_home).is_absolute():
+            base = Path(xdg_data_home)
+        else:
+            base = Path.home() / ".local" / "share"
+    return base / app_name / "saves"
+
+
+def app_name_from_path(path: Union[str, Path]) -> str:
+    """Turn a directory name into an app name usable by `default_save_directory()`."""
+    name = re.sub(r"[^A-Za-z0-9_.-]+", "_", Path(path).name).lstrip(".-")[:64]
+    return name or "pyg_engine"
+
+
+def _check_name(kind: str, name: str) -> str:
+    if not isinstance(name, str) or not _NAME_PATTERN.match(name):
+        raise ValueError(
+            f"Invalid {kind} name {name!r}: use up to 64 letters, digits, '_', '-' or '.',"
+            " not starting with '.' or '-'"
+        )
+    return name
+
+
+def _checksum(values: dict[str, Any], blobs: dict[str, dict[str, str]]) -> str:
+    canonical = json.dumps({"values": values, "blobs": blobs}, sort_keys=True, separators=(",", ":"))
+    return hashlib.sha256(canonical.encode("utf-8")).hexdigest()
+
+
+def _write_atomic(path: Path, data: bytes, keep_backup: bool = False) -> None:
+    """Replace `path` with `data` so readers see either the old or the new file."""
+    temporary = path.with_name(path.name + ".tmp")
+    with open(temporary, "wb") as file:
+        file.write(data)
+        file.flush()
+        os.fsync(file.fileno())
+    if keep_backup and path.exists():
+        os.replace(path, path.with_name(path.name + _BACKUP_SUFFIX))
+    os.replace(temporary, path)
+    if hasattr(os, "O_DIRECTORY"):
+        # Persist the rename itself, not just the file contents.
+        directory = os.open(path.parent, os.O_RDONLY | os.O_DIRECTORY)
+        try:
+            os.fsync(directory)
+        finally:
+            os.close(directory)
+
+
+def _read_manifest(path: Path) -> dict[str, Any]:
+    """Read and validate a manifest, raising ValueError if it is damaged."""
+    try:
+        with open(path, encoding="utf-8") as file:
+            data = json.load(file)
+    except (UnicodeDecodeError, json.JSONDecodeError) as exc:
+        raise ValueError(f"unreadable JSON: {exc}") from exc
+    if not isinstance(data, dict):
+        raise ValueError("not a save manifest")
+    version = data.get("version")
+    if version != _SAVE_VERSION:
+        raise ValueError(f"unsupported save version {version!r}")
+    values = data.get("values")
+    blobs = data.get("blobs")
+    if not isinstance(values, dict) or not isinstance(blobs, dict):
+        raise ValueError("not a save manifest")
+    if data.get("checksum") != _checksum(values, blobs):
+        raise ValueError("checksum mismatch")
+    return data
+
+
+class SaveData:
+    """
+    Key/value and blob storage for save games, reached through `engine.save_data`.
+
+    Values must be JSON-compatible (dicts, lists, strings, numbers, booleans
+    and None); `set()` stores a copy, so later changes to the original are not
+    saved until it is set again. Changes stay in memory until `save()`.
+
+    The current slot is loaded from disk the first time it is used. `load()`
+    switches slots, and `save(slot)` writes the current data to another slot
+    without switching, e.g. for "save as". `enable_autosave()` periodically
+    copies the data into rotating `autosave_<n>` slots.
+
+    Include other engine state by storing it as a value, for example
+    `save_data.set("timers", engine.timers.to_dict())`.
+    """
+
+    def __init__(self, engine: "Engine", app_name: str = "pyg_engine") -> None:
+        self._engine = engine
+        self._app_name = _check_name("app", app_name)
+        self._directory: Optional[Path] = None
+        self._slot = DEFAULT_SLOT
+        self._loaded = False
+        self._values: dict[str, Any] = {}
+        # Blob contents read or set this session, by key.
+        self._blobs: dict[str, bytes] = {}
+        # Saved blob files by key: {"file": ..., "sha256": ...}.
+        self._blob_files: dict[str, dict[str, str]] = {}
+        self._saved_at: Optional[float] = None
+        self._dirty = False
+        self._autosave_interval: Optional[float] = None
+        self._autosave_slots = 3
+        self._autosave_elapsed = 0.0
+        self._changed_since_autosave = False
+
+    # ========== Location ==========
+
+    @property
+    def app_name(self) -> str:
+        """
+        Get or set the app name used for the default save directory.
+
+        It defaults to the name of the game's source directory. Changing it
+        drops loaded data that was not saved.
+        """
+        return self._app_name
+
+    @app_name.setter
+    def app_name(self, value: str) -> None:
+        self._app_name = _check_name("app", value)
+        self._reset()
+
+    @property
+    def directory(self) -> Path:
+        """
+        Get or set the directory holding the save slots.
+
+        Defaults to `default_save_directory(app_name)`; set None to go back to
+        it. Changing it drops loaded data that was not saved.
+        """
+        if self._directory is not None:
+            return self._directory
+        return default_save_directory(self._app_name)
+
+    @directory.setter
+    def directory(self, value: Optional[Union[str, Path]]) -> None:
+        self._directory = Path(value).expanduser() if value is not None else None
+        self._reset()
+
+    @property
+    def slot(self) -> str:
+        """Get the name of the current slot, `"default"` until `load()` picks another."""
+        return self._slot
+
+    def slots(self) -> list[str]:
+        """Get the names of all slots saved on disk, sorted."""
+        directory = self.directory
+        if not directory.is_dir():
+            return []
+        return sorted(
+            entry.name
+            for entry in directory.iterdir()
+            if _NAME_PATTERN.match(entry.name)
+            and (
+                (entry / _MANIFEST).exists()
+                or (entry / (_MANIFEST + _BACKUP_SUFFIX)).exists()
+            )
+        )
+
+    def slot_saved_at(self, slot: Optional[str] = None) -> Optional[float]:
+        """
+        Get when a slot was last saved, as a Unix timestamp.
+        Returns None if the slot has no readable save.
+        """
+        slot = _check_name("slot", slot) if slot is not None else self._slot
+        if slot == self._slot and self._loaded:
+            return self._saved_at
+        manifest = self._read_slot(slot, repair=False)
+        return manifest.get("saved_at") if manifest is not None else None
+
+    # ========== Values ==========
+
+    def set(self, key: str, value: Any) -> None:
+        """
+        Store a JSON-compatible value under `key`.
+
+        Raises:
+            TypeError: If the key is not a string or the value cannot be saved as JSON.
+        """
+        if not isinstance(key, str):
+            raise TypeError(f"Save data keys must be strings, got {type(key).__name__}")
+        try:
+            stored = json.loads(json.dumps(value, allow_nan=False))
+        except (TypeError, ValueError) as exc:
+            raise TypeError(f"Save data '{key}' is not JSON-compatible: {exc}") from exc
+        self._ensure_loaded()
+        self._values[key] = stored
+        self._mark_changed()
+
+    def get(self, key: str, default: Any = None) -> Any:
+        """Get a copy of the value stored under `key`, or `default`."""
+        self._ensure_loaded()
+        if key not in self._values:
+            return default
+        return copy.deepcopy(self._values[key])
+
+    def has(self, key: str) -> bool:
+        """Check whether a value is stored under `key`."""
+        self._ensure_loaded()
+        return key in self._values
+
+    def __contains__(self, key: object) -> bool:
+        return isinstance(key, str) and self.has(key)
+
+    def delete(self, key: str) -> bool:
+        """Remove a value. Returns False if there was none."""
+        self._ensure_loaded()
+        if key not in self._values:
+            return False
+        del self._values[key]
+        self._mark_changed()
+        return True
+
+    def keys(self) -> list[str]:
+        """Get the keys of all stored values."""
+        self._ensure_loaded()
+        return list(self._values)
+
+    def clear(self) -> None:
+        """Remove every value and blob from the current slot."""
+        self._ensure_loaded()
+        if self._values or self._blob_files or self._blobs:
+            self._values.clear()
+            self._blobs.clear()
+            self._blob_files.clear()
+            self._mark_changed()
+
+    # ========== Blobs ==========
+
+    def set_blob(self, key: str, data: Union[bytes, bytearray, memoryview]) -> None:
+        """
+        Store binary data under `key`, e.g. a screenshot or a serialized level.
+
+        Blob keys name files, so they follow the slot name rules.
+        """
+        _check_name("blob", key)
+        self._ensure_loaded()
+        self._blobs[key] = bytes(data)
+        self._blob_files.pop(key, None)
+        self._mark_changed()
+
+    def get_blob(self, key: str) -> Optional[bytes]:
+        """
+        Get the binary data stored under `key`.
+        Returns None if there is none, or if its file is missing or damaged.
+        """
+        self._ensure_loaded()
+        if key in self._blobs:
+            return self._blobs[key]
+        saved = self._blob_files.get(key)
+        if saved is None:
+            return None
+
+        path = self._slot_directory(self._slot) / _BLOB_DIRECTORY / saved["file"]
+        try:
+            data = path.read_bytes()
+        except OSError as exc:
+            self._engine.log_error(f"Save blob '{key}' could not be read: {exc}")
+            return None
+        if hashlib.sha256(data).hexdigest() != saved["sha256"]:
+            self._engine.log_error(f"Save blob '{key}' is damaged and was ignored")
+            return None
+        self._blobs[key] = data
+        return data
+
+    def has_blob(self, key: str) -> bool:
+        """Check whether binary data is stored under `key`."""
+        self._ensure_loaded()
+        return key in self._blobs or key in self._blob_files
+
+    def delete_blob(self, key: str) -> bool:
+        """Remove binary data. Returns False if there was none."""
+        self._ensure_loaded()
+        removed = self._blobs.pop(key, None) is not None
+        removed = self._blob_files.pop(key, None) is not None or removed
+        if removed:
+            self._mark_changed()
+        return removed
+
+    def blob_keys(self) -> list[str]:
+        """Get the keys of all stored blobs."""
+        self._ensure_loaded()
+        return sorted(set(self._blobs) | set(self._blob_files))
+
+    # ========== Saving and loading ==========
+
+    @property
+    def has_unsaved_changes(self) -> bool:
+        """Get whether the current slot changed since it was loaded or saved."""
+        return self._dirty
+
+    def save(self, slot: Optional[str] = None) -> None:
+        """
+        Write the current data to disk, to the current slot by default.
+
+        Saving to another slot copies the data there and keeps the current
+        slot unchanged.
+
+        Raises:
+            OSError: If the save directory cannot be written.
+        """
+        target = _check_name("slot", slot) if slot is not None else self._slot
+        self._save(target)
+
+    def _save(self, target: str, autosave_count: Optional[int] = None) -> None:
+        self._ensure_loaded()
+        if target != self._slot:
+            # The other slot needs its own copy of every blob file.
+            for key in list(self._blob_files):
+                self.get_blob(key)
+        blob_files = self._write_slot(target, autosave_count)
+        if target == self._slot:
+            self._blob_files = blob_files
+            self._blobs.clear()
+            self._saved_at = self._read_saved_at(target)
+            self._dirty = False
+
+    def load(self, slot: Optional[str] = None) -> bool:
+        """
+        Make `slot` the current slot and read it from disk, dropping changes
+        that were not saved.
+
+        A damaged manifest is restored from its backup; if both are damaged
+        they are renamed to `*.corrupt` and the slot starts empty.
+
+        Returns:
+            True if saved data was found, False if the slot starts empty.
+        """
+        self._slot = _check_name("slot", slot) if slot is not None else self._slot
+        self._values = {}
+        self._blobs = {}
+        self._blob_files = {}
+        self._saved_at = None
+        self._dirty = False
+        self._loaded = True
+
+        manifest = self._read_slot(self._slot, repair=True)
+        if manifest is None:
+            return False
+        self._values = manifest["values"]
+        self._blob_files = manifest["blobs"]
+        self._saved_at = manifest.get("saved_at")
+        return True
+
+    def delete_slot(self, slot: str) -> bool:
+        """
+        Delete a slot from disk. Deleting the current slot also clears the data
+        in memory. Returns False if the slot was not saved.
+        """
+        _check_name("slot", slot)
+        slot_directory = self._slot_directory(slot)
+        existed = slot_directory.is_dir()
+        if existed:
+            shutil.rmtree(slot_directory)
+        if slot == self._slot:
+            self.load()
+        return existed
+
+    # ========== Autosave ==========
+
+    def enable_autosave(self, interval: float, slots: int = 3) -> None:
+        """
+        Copy the data into autosave slots every `interval` seconds of real time.
+
+        Autosaves rotate through `autosave_1` to `autosave_<slots>`, so a save
+        damaged by a crash never takes the older ones with it; use
+        `latest_autosave()` to find the newest. Nothing is written while the
+        data is unchanged since the last autosave. The interval is counted by
+        `update()` in the Python and manual loops.
+
+        Raises:
+            ValueError: If `interval` is not positive or `slots` is below 1.
+        """
+        if interval <= 0:
+            raise ValueError("Autosave interval must be positive")
+        if slots < 1:
+            raise ValueError("Autosave needs at least one slot")
+        self._autosave_interval = float(interval)
+        self._autosave_slots = int(slots)
+        self._autosave_elapsed = 0.0
+
+    def disable_autosave(self) -> None:
+        """Stop autosaving."""
+        self._autosave_interval = None
+
+    @property
+    def autosave_enabled(self) -> bool:
+        """Get whether autosave is on."""
+        return self._autosave_interval is not None
+
+    def autosave(self) -> str:
+        """
+        Write the data to the autosave slot after the newest one now.
+
+        Returns:
+            The name of the slot that was written.
+        """
+        latest = self._latest_autosave()
+        index, count = (int(latest[1][len(AUTOSAVE_PREFIX):]), latest[0]) if latest else (0, 0)
+        slot = f"{AUTOSAVE_PREFIX}{index % self._autosave_slots + 1}"
+        self._save(slot, autosave_count=count + 1)
+        self._autosave_elapsed = 0.0
+        self._changed_since_autosave = False
+        return slot
+
+    def latest_autosave(self) -> Optional[str]:
+        """Get the most recently written autosave slot, or None."""
+        latest = self._latest_autosave()
+        return latest[1] if latest is not None else None
+
+    def _latest_autosave(self) -> Optional[tuple[int, str]]:
+        # Autosaves are numbered, since clock resolution can tie quick saves.
+        newest: Optional[tuple[int, str]] = None
+        for index in range(1, self._autosave_slots + 1):
+            slot = f"{AUTOSAVE_PREFIX}{index}"
+            manifest = self._read_slot(slot, repair=False)
+            count = manifest.get("autosave_count") if manifest is not None else None
+            if isinstance(count, int) and (newest is None or count > newest[0]):
+                newest = (count, slot)
+        return newest
+
+    def tick(self, delta_time: float) -> None:
+        """Count down to the next autosave; the engine calls this every update."""
+        if self._autosave_interval is None:
+            return
+        self._autosave_elapsed += delta_time
+        if self._autosave_elapsed < self._autosave_interval:
+            return
+        self._autosave_elapsed = 0.0
+        if not self._changed_since_autosave:
+            return
+        try:
+            self.autosave()
+        except OSError as exc:
+            # Keep playing; the next interval tries again.
+            self._engine.log_error(f"Autosave failed: {exc!r}")
+
+    # ========== Internals ==========
+
+    def _reset(self) -> None:
+        self._loaded = False
+        self._values = {}
+        self._blobs = {}
+        self._blob_files = {}
+        self._saved_at = None
+        self._dirty = False
+
+    def _ensure_loaded(self) -> None:
+        if not self._loaded:
+            self.load()
+
+    def _mark_changed(self) -> None:
+        self._dirty = True
+        self._changed_since_autosave = True
+
+    def _slot_directory(self, slot: str) -> Path:
+        return self.directory / slot
+
+    def _read_saved_at(self, slot: str) -> Optional[float]:
+        manifest = self._read_slot(slot, repair=False)
+        return manifest.get("saved_at") if manifest is not None else None
+
+    def _read_slot(self, slot: str, repair: bool) -> Optional[dict[str, Any]]:
+        """
+        Read a slot's manifest, falling back to its backup.
+
+        With `repair`, damaged manifests are renamed to `*.corrupt` so the next
+        save does not keep a broken file as its backup.
+        """
+        path = self._slot_directory(slot) / _MANIFEST
+        backup = path.with_name(path.name + _BACKUP_SUFFIX)
+        damaged: list[Path] = []
+        manifest: Optional[dict[str, Any]] = None
+        for candidate in (path, backup):
+            if not candidate.exists():
+                continue
+            try:
+                manifest = _read_manifest(candidate)
+                break
+            except (OSError, ValueError) as exc:
+                if repair:
+                    self._engine.log_warn(f"Save file {candidate} is damaged: {exc}")
+                damaged.append(candidate)
+
+        if repair:
+            for candidate in damaged:
+                os.replace(candidate, candidate.with_name(candidate.name + _CORRUPT_SUFFIX))
+            if manifest is not None and path in damaged:
+                self._engine.log_warn(f"Restored save slot '{slot}' from its backup")
+        return manifest
+
+    def _write_slot(
+        self, slot: str, autosave_count: Optional[int] = None
+    ) -> dict[str, dict[str, str]]:
+        """Write blobs, then the manifest that commits them; returns the blob table."""
+        slot_directory = self._slot_directory(slot)
+        blob_directory = slot_directory / _BLOB_DIRECTORY
+        blob_directory.mkdir(parents=True, exist_ok=True)
+
+        blob_files = {} if slot != self._slot else dict(self._blob_files)
+        for key, data in self._blobs.items():
+            sha256 = hashlib.sha256(data).hexdigest()
+            # Content-addressed names never overwrite a file a manifest uses.
+            file_name = f"{key}-{sha256[:16]}.bin"
+            if not (blob_directory / file_name).exists():
+                _write_atomic(blob_directory / file_name, data)
+            blob_files[key] = {"file": file_name, "sha256": sha256}
+
+        manifest = {
+            "version": _SAVE_VERSION,
+            "saved_at": time.time(),
+            "values": self._values,
+            "blobs": blob_files,
+            "checksum": _checksum(self._values, blob_files),
+        }
+        if autosave_count is not None:
+            manifest["autosave_count"] = autosave_count
+        _write_atomic(
+            slot_directory / _MANIFEST,
+            json.dumps(manifest, indent=2).encode("utf-8"),
+            keep_backup=True,
+        )
+
+        # Keep blob files the new manifest or its backup still refer to.
+        referenced = {saved["file"] for saved in blob_files.values()}
+        backup = slot_directory / (_MANIFEST + _BACKUP_SUFFIX)
+        try:
+            referenced.update(saved["file"] for saved in _read_manifest(backup)["blobs"].values())
+        except (OSError, ValueError):
+            pass
+        for path in blob_directory.iterdir():
+            if path.name not in referenced:
+                path.unlink()
+        return blob_files
@@ -0,0 +1,204 @@
+"""
+Named scenes managed by the engine.
+
+A scene is a named collection of GameObjects that load and unload together.
+Register start and teardown hooks with `engine.scenes.register(...)`, then
+switch levels with `engine.scenes.load_scene(name)`. A scene can also name
+an audio snapshot to fade to when it loads. Scenes can be saved to
+and loaded from JSON files with `save_scene()` and `load_scene(path=...)`.
+"""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Any, Callable, Optional
+
+if TYPE_CHECKING:
+    from .engine import Engine
+
+SceneHook = Callable[["Engine"], None]
+
+
+class SceneManager:
+    """
+    Loads and unloads named scenes and runs their hooks.
+
+    Loading a scene makes it active: every object added while it is active,
+    including the objects its start hook creates, belongs to it and is
+    destroyed when it unloads. Objects added while no scene is active belong
+    to none and survive every unload; `move_to_scene(obj, None)` does the
+    same for an object already in a scene.
+
+    Loading replaces all loaded scenes unless `additive=True`, which keeps them
+    and loads the new scene alongside, e.g. a HUD on top of a level. Teardown
+    hooks run before a scene's objects are destroyed, so they can still read
+    them. Scenes do not need to be registered; an unregistered scene has no
+    hooks.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine
+
+        engine = Engine()
+
+        def start_level(engine):
+            engine.create_game_object("Player")
+
+        def start_hud(engine):
+            engine.create_game_object("ScoreLabel")
+
+        engine.scenes.register("level_1", start=start_level)
+        engine.scenes.register("hud", start=start_hud)
+        engine.scenes.load_scene("level_1")
+        engine.scenes.load_scene("hud", additive=True)
+        engine.scenes.active_scene = "level_1"  # Spawn new objects into the level
+
+        # Later: swap levels, keeping the HUD.
+        engine.scenes.unload_scene("level_1")
+        engine.scenes.load_scene("level_2", additive=True)
+        ```
+    """
+
+    def __init__(self, engine: "Engine") -> None:
+        self._engine = engine
+        self._hooks: dict[str, tuple[Optional[SceneHook], Optional[SceneHook]]] = {}
+        self._audio_snapshots: dict[str, tuple[str, float]] = {}
+
+    def register(
+        self,
+        name: str,
+        start: Optional[SceneHook] = None,
+        teardown: Optional[SceneHook] = None,
+        audio_snapshot: Optional[str] = None,
+        audio_fade: float = 0.5,
+    ) -> None:
+        """
+        Set the hooks for the scene called `name`, replacing any earlier ones.
+
+        Args:
+            name: Scene name.
+            start: Called with the engine after the scene loads and becomes
+                active. Objects it adds belong to the scene.
+            teardown: Called with the engine before the scene's objects are
+                destroyed.
+            audio_snapshot: Snapshot from `engine.audio.add_snapshot()` to
+                fade to when the scene loads, before its start hook runs.
+            audio_fade: Seconds the fade to `audio_snapshot` takes.
+        """
+        self._hooks[name] = (start, teardown)
+        if audio_snapshot is None:
+            self._audio_snapshots.pop(name, None)
+        else:
+            self._audio_snapshots[name] = (audio_snapshot, audio_fade)
+
+    def unregister(self, name: str) -> None:
+        """Forget the hooks for a scene. A loaded scene stays loaded."""
+        self._hooks.pop(name, None)
+        self._audio_snapshots.pop(name, None)
+
+    def load_scene(self, name: str, additive: bool = False, path: Optional[str] = None) -> None:
+        """
+        Load a scene, make it active and run its start hook.
+
+        Args:
+            name: Scene name.
+            additive: Keep the loaded scenes instead of unloading them first.
+                Loading a scene that is already loaded reloads it either way.
+            path: Scene file written by `save_scene()` (or by hand) to create
+                the scene's objects from. They exist before the start hook
+                runs, so it can find them by name and connect callbacks.
+
+        Raises:
+            RuntimeError: If the scene file cannot be read or is invalid. The
+                scene is still loaded, without the file's objects.
+        """
+        if additive:
+            self.unload_scene(name)
+        else:
+            for loaded in reversed(self.loaded_scenes):
+                self.unload_scene(loaded)
+        self._engine._engine.load_scene(name, True)
+        if path is not None:
+            self._engine._engine.add_scene_file_objects(str(path))
+        if name in self._audio_snapshots:
+            self._engine.audio.transition_to(*self._audio_snapshots[name])
+        start = self._hooks.get(name, (None, None))[0]
+        if start is not None:
+            start(self._engine)
+
+    def save_scene(self, name: str, path: str) -> None:
+        """
+        Save a loaded scene's objects to a JSON scene file.
+
+        Names, tags, types, transforms, hierarchy, mesh settings, colliders and the
+        content of panels, labels, buttons, checkboxes, toggle switches,
+        sliders, progress bars and images are saved. Callbacks, styles and
+        other components are not.
+
+        Raises:
+            RuntimeError: If the scene is not loaded or the file cannot be written.
+
+        Example:
+            ```python
+            engine.scenes.save_scene("level_1", "levels/level_1.json")
+            engine.scenes.load_scene("level_1", path="levels/level_1.json")
+            ```
+        """
+        self._engine._engine.save_scene(name, str(path))
+
+    def unload_scene(self, name: str) -> bool:
+        """
+        Run a scene's teardown hook, then destroy its objects.
+
+        Returns:
+            False if the scene was not loaded.
+        """
+        if not self.is_loaded(name):
+            return False
+        teardown = self._hooks.get(name, (None, None))[1]
+        if teardown is not None:
+            teardown(self._engine)
+        return self._engine._engine.unload_scene(name)
+
+    def is_loaded(self, name: str) -> bool:
+        """Get whether the scene called `name` is loaded."""
+        return name in self.loaded_scenes
+
+    @property
+    def loaded_scenes(self) -> list[str]:
+        """Get the names of the loaded scenes, in load order."""
+        return self._engine._engine.loaded_scenes()
+
+    @property
+    def active_scene(self) -> Optional[str]:
+        """Get the scene new objects are added to, or None."""
+        return self._engine._engine.get_active_scene()
+
+    @active_scene.setter
+    def active_scene(self, name: Optional[str]) -> None:
+        """Set the scene new objects are added to; None adds them to no scene."""
+        if not self._engine._engine.set_active_scene(name):
+            raise ValueError(f"Scene {name!r} is not loaded")
+
+    def objects(self, name: str) -> list[int]:
+        """Get the ids of a scene's objects, empty if it is not loaded."""
+        return self._engine._engine.scene_objects(name)
+
+    def scene_of(self, game_object_or_id: Any) -> Optional[str]:
+        """Get the name of the scene an object belongs to, or None."""
+        object_id = self._engine._resolve_runtime_object_id(game_object_or_id)
+        return self._engine._engine.get_object_scene(object_id)
+
+    def move_to_scene(self, game_object_or_id: Any, name: Optional[str]) -> bool:
+        """
+        Move an object to another loaded scene, or out of every scene with
+        None so it survives unloads.
+
+        Returns:
+            False if the scene is not loaded.
+        """
+        object_id = self._engine._resolve_runtime_object_id(game_object_or_id)
+        return self._engine._engine.set_object_scene(object_id, name)
+
+    def __repr__(self) -> str:
+        return f"SceneManager(loaded={self.loaded_scenes!r}, active={self.active_scene!r})"
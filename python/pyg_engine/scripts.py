@@ -0,0 +1,246 @@
+"""
+Hot reloading for Python gameplay scripts.
+
+Register the modules that hold your gameplay classes with
+`engine.scripts.watch(...)` and the live objects built from them with
+`engine.scripts.track(...)`. When reloading is enabled, the engine checks the
+watched files while it runs, reimports any that changed and moves tracked
+objects onto the new class definitions, keeping their attributes.
+"""
+
+from __future__ import annotations
+
+import importlib
+import os
+import sys
+import time
+import types
+import weakref
+from typing import TYPE_CHECKING, Any, Callable, Optional, Union
+
+if TYPE_CHECKING:
+    from .engine import Engine
+
+
+class ScriptReloader:
+    """
+    Watches Python modules and reimports them when their source changes.
+
+    Reloading only runs in loops where Python drives the frame, i.e.
+    `engine.run(update=...)` and manual `engine.update()` calls, or when
+    `check()` is called directly. The native blocking `engine.run()` loop does
+    not poll.
+
+    After a module is reimported, each tracked instance of a class from that
+    module is moved onto the new class with the same name:
+
+    - Its attributes (`__dict__`) are kept as they are.
+    - If the new class defines `__setstate__`, it is called with the state the
+      old class's `__getstate__` returned, so classes can migrate their data.
+    - If the new class defines `on_reload()`, it is called afterwards.
+
+    Functions and bound methods captured elsewhere (button callbacks, for
+    example) keep pointing at the old code until they are looked up again.
+    The `update` callback passed to `engine.run()` is looked up again by name.
+
+    A module that fails to import (a syntax error mid-edit, say) is logged and
+    the old version keeps running until the file changes again.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine
+        import enemies
+
+        engine = Engine()
+        engine.scripts.watch(enemies)
+        engine.scripts.enabled = True
+
+        enemy = engine.scripts.track(enemies.Enemy(x=100, y=50))
+
+        def update(dt):
+            enemy.update(dt)  # Edits to enemies.py apply while the game runs
+
+        engine.run(update=update)
+        ```
+    """
+
+    def __init__(self, engine: "Engine", interval: float = 0.5) -> None:
+        self._engine = engine
+        self._interval = interval
+        self._enabled = False
+        self._next_check = 0.0
+        # Module name -> last seen modification time of its source file.
+        self._watched: dict[str, float] = {}
+        self._instances: "weakref.WeakSet[Any]" = weakref.WeakSet()
+        self._reload_count = 0
+
+    @property
+    def enabled(self) -> bool:
+        """Get whether watched modules are checked automatically while the engine runs."""
+        return self._enabled
+
+    @enabled.setter
+    def enabled(self, value: bool) -> None:
+        """Set whether watched modules are checked automatically while the engine runs."""
+        self._enabled = bool(value)
+        self._next_check = 0.0
+
+    @property
+    def interval(self) -> float:
+        """Get the minimum time in seconds between automatic checks."""
+        return self._interval
+
+    @interval.setter
+    def interval(self, value: float) -> None:
+        """Set the minimum time in seconds between automatic checks."""
+        if value < 0.0:
+            raise ValueError("interval must be >= 0.0")
+        self._interval = value
+
+    @property
+    def reload_count(self) -> int:
+        """Get the number of module reloads performed so far."""
+        return self._reload_count
+
+    def watch(self, module: Union[types.ModuleType, str]) -> types.ModuleType:
+        """
+        Watch a module for changes. Accepts a module or a dotted module name,
+        which is imported if needed. Returns the module.
+        """
+        if isinstance(module, str):
+            module = importlib.import_module(module)
+        path = _source_path(module)
+        if path is None:
+            raise ValueError(f"Module '{module.__name__}' has no Python source file to watch")
+        self._watched[module.__name__] = _mtime(path)
+        return module
+
+    def unwatch(self, module: Union[types.ModuleType, str]) -> bool:
+        """Stop watching a module. Returns False if it was not watched."""
+        name = module if isinstance(module, str) else module.__name__
+        return self._watched.pop(name, None) is not None
+
+    def watched_modules(self) -> list[str]:
+        """Get the names of the watched modules."""
+        return list(self._watched)
+
+    def track(self, instance: Any) -> Any:
+        """
+        Move `instance` onto new class definitions when its module reloads.
+        Instances are held weakly, so tracking does not keep them alive.
+        Returns the instance.
+        """
+        self._instances.add(instance)
+        return instance
+
+    def untrack(self, instance: Any) -> None:
+        """Stop updating `instance` on reload."""
+        self._instances.discard(instance)
+
+    def poll(self) -> list[str]:
+        """Run `check()` if reloading is enabled and the interval has elapsed."""
+        if not self._enabled:
+            return []
+        now = time.monotonic()
+        if now < self._next_check:
+            return []
+        self._next_check = now + self._interval
+        return self.check()
+
+    def check(self) -> list[str]:
+        """Reload every watched module whose source changed. Returns their names."""
+        reloaded = []
+        for name, seen in list(self._watched.items()):
+            module = sys.modules.get(name)
+            path = _source_path(module) if module is not None else None
+            if path is None:
+                continue
+            modified = _mtime(path)
+            if modified == seen:
+                continue
+            self._watched[name] = modified
+            if self.reload(module):
+                reloaded.append(name)
+        return reloaded
+
+    def reload(self, module: Union[types.ModuleType, str]) -> bool:
+        """Reimport a module now and rebind tracked instances. Returns False on failure."""
+        if isinstance(module, str):
+            module = sys.modules[module]
+        name = module.__name__
+        old_classes = {
+            value: key
+            for key, value in vars(module).items()
+            if isinstance(value, type) and value.__module__ == name
+        }
+        try:
+            module = importlib.reload(module)
+        except Exception as exc:
+            # Keep the old code running until the file is fixed.
+            self._engine.log_error(f"Script reload of '{name}' failed: {exc!r}")
+            return False
+
+        for instance in list(self._instances):
+            class_name = old_classes.get(type(instance))
+            if class_name is None:
+                continue
+            new_class = getattr(module, class_name, None)
+            if not isinstance(new_class, type):
+                self._engine.log_warn(
+                    f"Script reload of '{name}': class '{class_name}' is gone, "
+                    "instance keeps the old definition"
+                )
+                continue
+            self._rebind(instance, new_class)
+
+        self._reload_count += 1
+        path = _source_path(module)
+        if path is not None:
+            self._watched[name] = _mtime(path)
+        self._engine.log_info(f"Reloaded script module '{name}'")
+        return True
+
+    def _rebind(self, instance: Any, new_class: type) -> None:
+        get_state = getattr(instance, "__getstate__", None)
+        restore = "__setstate__" in vars(new_class) and get_state is not None
+        state = get_state() if restore else None
+        try:
+            instance.__class__ = new_class
+        except TypeError as exc:
+            self._engine.log_warn(
+                f"Cannot rebind {new_class.__qualname__} instance after reload: {exc}"
+            )
+            return
+        if restore:
+            instance.__setstate__(state)
+        on_reload = getattr(instance, "on_reload", None)
+        if callable(on_reload):
+            on_reload()
+
+    def refresh(self, function: Callable[..., Any]) -> Callable[..., Any]:
+        """
+        Return the current version of a module-level function from a watched
+        module, or `function` itself if it cannot be found.
+        """
+        name = getattr(function, "__module__", None)
+        qualname = getattr(function, "__qualname__", "")
+        if name not in self._watched or "." in qualname:
+            return function
+        module = sys.modules.get(name)
+        current = getattr(module, qualname, None) if module is not None else None
+        return current if callable(current) else function
+
+
+def _source_path(module: Optional[types.ModuleType]) -> Optional[str]:
+    path = getattr(module, "__file__", None)
+    if not path or not path.endswith(".py"):
+        return None
+    return path
+
+
+def _mtime(path: str) -> float:
+    try:
+        return os.stat(path).st_mtime
+    except OSError:
+        return 0.0
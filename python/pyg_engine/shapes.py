@@ -1,10 +1,12 @@
 from __future__ import annotations
 
+from array import array
 from dataclasses import dataclass, field
 from typing import Any, Iterable, Sequence
 
 from .pyg_engine_native import DrawCommand as _RustDrawCommand
 from .pyg_engine_native import MeshGeometry as _RustMeshGeometry
+from .assets import AssetHandle, asset_path
 
 
 PointLike = Any
@@ -18,6 +20,14 @@ def _xy(point: PointLike) -> tuple[float, float]:
     raise TypeError(f"Expected a Vec2-like value, got {type(point).__name__}")
 
 
+def _points(points: Iterable[PointLike]) -> Any:
+    # Float arrays (numpy, array.array, memoryview) are passed through whole;
+    # the engine reads them with the buffer protocol in a single copy.
+    if isinstance(points, (array, memoryview)) or hasattr(points, "__array_interface__"):
+        return points
+    return [_xy(point) for point in points]
+
+
 @dataclass(slots=True)
 class Line:
     start: PointLike
@@ -126,7 +136,7 @@ class Polygon:
 
     def to_draw_command(self) -> Any:
         return _RustDrawCommand.polygon(
-            [_xy(point) for point in self.points],
+            _points(self.points),
             self.color,
             filled=self.filled,
             thickness=self.thickness,
@@ -139,17 +149,17 @@ class Mesh:
     vertices: Sequence[PointLike]
     indices: Sequence[int]
     color: Any
-    texture_path: str | None = None
+    texture_path: str | AssetHandle | None = None
     uvs: Sequence[PointLike] | None = None
     draw_order: float = 0.0
 
     def to_draw_command(self) -> Any:
         return _RustDrawCommand.mesh(
-            [_xy(vertex) for vertex in self.vertices],
+            _points(self.vertices),
             list(self.indices),
             self.color,
-            texture_path=self.texture_path,
-            uvs=None if self.uvs is None else [_xy(uv) for uv in self.uvs],
+            texture_path=asset_path(self.texture_path),
+            uvs=None if self.uvs is None else _points(self.uvs),
             draw_order=self.draw_order,
         )
 
@@ -0,0 +1,355 @@
+"""
+Named timers managed by the engine.
+
+Start a timer with `engine.timers.start(name, seconds)` and the engine counts
+it down every frame. Timers are identified by name, can be saved and
+restored with the rest of a save game, and report expiry through callbacks.
+"""
+
+from __future__ import annotations
+
+import json
+import time
+from typing import TYPE_CHECKING, Any, Callable, Optional
+
+if TYPE_CHECKING:
+    from .engine import Engine
+
+GAME_CLOCK = "game"
+UNSCALED_CLOCK = "unscaled"
+REALTIME_CLOCK = "realtime"
+
+_CLOCKS = (GAME_CLOCK, UNSCALED_CLOCK, REALTIME_CLOCK)
+_SAVE_VERSION = 1
+
+
+class Timer:
+    """
+    A single named countdown, created by `TimerManager.start()`.
+
+    Which clock a timer follows decides how it reacts to pausing, time scale
+    and time spent outside the game:
+
+    - `"game"`: counts game time multiplied by `timers.time_scale` and stops
+      while `timers.paused` is set. Game time also follows `engine.time_scale`
+      and stops while `engine.paused` is set. Saves keep the remaining game
+      time.
+    - `"unscaled"`: counts raw frame time, ignoring pause and time scale.
+      Saves keep the remaining time.
+    - `"realtime"`: follows the wall clock, so it keeps running while the game
+      is paused or closed. Saves keep the wall-clock deadline.
+    """
+
+    def __init__(
+        self,
+        name: str,
+        duration: float,
+        clock: str = GAME_CLOCK,
+        repeat: bool = False,
+        on_expired: Optional[Callable[[str], None]] = None,
+    ) -> None:
+        self.name = name
+        self.duration = duration
+        self.clock = clock
+        self.repeat = repeat
+        self.on_expired = on_expired
+        self._remaining = duration
+        # Wall-clock deadline, only used by realtime timers.
+        self._deadline = time.time() + duration
+
+    @property
+    def remaining(self) -> float:
+        """Get the seconds left until the timer expires."""
+        if self.clock == REALTIME_CLOCK:
+            return max(0.0, self._deadline - time.time())
+        return max(0.0, self._remaining)
+
+    @property
+    def elapsed(self) -> float:
+        """Get the seconds counted since the timer (or its current repeat) started."""
+        return self.duration - self.remaining
+
+    @property
+    def progress(self) -> float:
+        """Get how far the timer has run, from 0.0 to 1.0."""
+        if self.duration <= 0.0:
+            return 1.0
+        return min(1.0, self.elapsed / self.duration)
+
+    def _advance(self, delta_time: float) -> bool:
+        """Count down by `delta_time` and return True if the timer expired."""
+        if self.clock == REALTIME_CLOCK:
+            return time.time() >= self._deadline
+        self._remaining -= delta_time
+        return self._remaining <= 0.0
+
+    def _restart(self) -> None:
+        if self.clock == REALTIME_CLOCK:
+            # Skip periods missed while the game was closed instead of firing
+            # once for each of them.
+            now = time.time()
+            if self.duration > 0.0:
+                while self._deadline <= now:
+                    self._deadline += self.duration
+            else:
+                self._deadline = now
+        else:
+            self._remaining = max(self._remaining + self.duration, 0.0)
+
+    def _to_dict(self) -> dict[str, Any]:
+        data: dict[str, Any] = {
+            "duration": self.duration,
+            "clock": self.clock,
+            "repeat": self.repeat,
+        }
+        if self.clock == REALTIME_CLOCK:
+            data["deadline"] = self._deadline
+        else:
+            data["remaining"] = self._remaining
+        return data
+
+    def __repr__(self) -> str:
+        return (
+            f"Timer(name={self.name!r}, remaining={self.remaining:.3f}, "
+            f"duration={self.duration}, clock={self.clock!r}, repeat={self.repeat})"
+        )
+
+
+class TimerManager:
+    """
+    Named cooldown and encounter timers, counted down by the engine.
+
+    Timers advance once per frame in loops where Python drives the frame,
+    i.e. `engine.run(update=...)` and manual `engine.update()` calls, or
+    whenever `tick()` is called directly. The native blocking `engine.run()`
+    loop does not tick them.
+
+    Expired timers call their own `on_expired` callback and then every
+    listener added with `add_listener()`, each with the timer's name.
+    Non-repeating timers are removed before the callbacks run, so a callback
+    may start a timer with the same name again.
+
+    Callbacks are not saved. After `load()`, timers that were already started
+    under the same name keep their callbacks; listeners always fire, which
+    makes them the simplest way to react to restored timers.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine
+
+        engine = Engine()
+
+        def on_timer(name):
+            if name == "daily_chest":
+                engine.log_info("The daily chest is ready")
+
+        engine.timers.add_listener(on_timer)
+        engine.timers.load("timers.json")  # No-op on the first run
+        if not engine.timers.is_running("daily_chest"):
+            engine.timers.start("daily_chest", 86400, clock="realtime")
+        engine.timers.start("spawn_wave", 30, repeat=True)
+
+        def update(ctx):
+            if ctx.input.key_pressed("Escape"):
+                engine.timers.paused = not engine.timers.paused  # Waves stop, the chest doesn't
+
+        engine.run(update=update)
+        engine.timers.save("timers.json")
+        ```
+    """
+
+    def __init__(self, engine: "Engine") -> None:
+        self._engine = engine
+        self._timers: dict[str, Timer] = {}
+        self._listeners: list[Callable[[str], None]] = []
+        self._paused = False
+        self._time_scale = 1.0
+
+    @property
+    def paused(self) -> bool:
+        """Get whether game-clock timers are paused."""
+        return self._paused
+
+    @paused.setter
+    def paused(self, value: bool) -> None:
+        """Set whether game-clock timers are paused."""
+        self._paused = bool(value)
+
+    @property
+    def time_scale(self) -> float:
+        """Get the multiplier applied to frame time for game-clock timers."""
+        return self._time_scale
+
+    @time_scale.setter
+    def time_scale(self, value: float) -> None:
+        """Set the multiplier applied to frame time for game-clock timers."""
+        if value < 0.0:
+            raise ValueError("time_scale must be >= 0.0")
+        self._time_scale = value
+
+    def start(
+        self,
+        name: str,
+        duration: float,
+        *,
+        clock: str = GAME_CLOCK,
+        repeat: bool = False,
+        on_expired: Optional[Callable[[str], None]] = None,
+    ) -> Timer:
+        """
+        Start (or restart) the timer called `name`, expiring after `duration` seconds.
+
+        Args:
+            name: Unique timer name; an existing timer with this name is replaced
+            duration: Seconds until expiry
+            clock: `"game"`, `"unscaled"` or `"realtime"`, see `Timer`
+            repeat: Restart the timer each time it expires
+            on_expired: Called with the timer's name when it expires
+
+        Returns:
+            The started timer.
+        """
+        if duration < 0.0:
+            raise ValueError("duration must be >= 0.0")
+        if repeat and duration == 0.0:
+            raise ValueError("repeating timers need a duration > 0.0")
+        if clock not in _CLOCKS:
+            raise ValueError(f"clock must be one of {', '.join(_CLOCKS)}, got {clock!r}")
+        timer = Timer(name, duration, clock, repeat, on_expired)
+        self._timers[name] = timer
+        return timer
+
+    def cancel(self, name: str) -> bool:
+        """Stop and remove a timer without firing it. Returns False if it was not running."""
+        return self._timers.pop(name, None) is not None
+
+    def clear(self) -> None:
+        """Remove every timer without firing them."""
+        self._timers.clear()
+
+    def get(self, name: str) -> Optional[Timer]:
+        """Get a running timer by name, or None."""
+        return self._timers.get(name)
+
+    def is_running(self, name: str) -> bool:
+        """Check whether a timer with this name is counting down."""
+        return name in self._timers
+
+    def remaining(self, name: str) -> float:
+        """Get the seconds left on a timer, or 0.0 if it is not running."""
+        timer = self._timers.get(name)
+        return timer.remaining if timer is not None else 0.0
+
+    def names(self) -> list[str]:
+        """Get the names of the running timers."""
+        return list(self._timers)
+
+    def add_listener(self, callback: Callable[[str], None]) -> None:
+        """Call `callback` with the timer's name whenever any timer expires."""
+        self._listeners.append(callback)
+
+    def remove_listener(self, callback: Callable[[str], None]) -> bool:
+        """Remove a listener. Returns False if it was not registered."""
+        try:
+            self._listeners.remove(callback)
+        except ValueError:
+            return False
+        return True
+
+    def tick(self, delta_time: float, game_delta_time: Optional[float] = None) -> list[str]:
+        """
+        Advance every timer by one frame of `delta_time` seconds and fire the
+        ones that expired. Returns their names.
+
+        Args:
+            delta_time: Real frame time in seconds.
+            game_delta_time: Frame time of the game clock, already scaled by
+                `engine.time_scale`; defaults to `delta_time`.
+        """
+        if game_delta_time is None:
+            game_delta_time = delta_time
+        game_delta = 0.0 if self._paused else game_delta_time * self._time_scale
+        expired = []
+        for name, timer in list(self._timers.items()):
+            if timer.clock == GAME_CLOCK:
+                step = game_delta
+            else:
+                step = delta_time
+            if not timer._advance(step):
+                continue
+            if timer.repeat:
+                timer._restart()
+            elif self._timers.get(name) is timer:
+                del self._timers[name]
+            expired.append(timer)
+
+        for timer in expired:
+            self._fire(timer)
+        return [timer.name for timer in expired]
+
+    def _fire(self, timer: Timer) -> None:
+        callbacks = [timer.on_expired] if timer.on_expired is not None else []
+        callbacks.extend(self._listeners)
+        for callback in callbacks:
+            try:
+                callback(timer.name)
+            except Exception as exc:
+                # One broken callback should not stop other timers from firing.
+                self._engine._engine.report_callback_error(f"timer '{timer.name}' callback", exc)
+
+    def to_dict(self) -> dict[str, Any]:
+        """Get the running timers as JSON-compatible data, for a save game."""
+        return {
+            "version": _SAVE_VERSION,
+            "timers": {name: timer._to_dict() for name, timer in self._timers.items()},
+        }
+
+    def load_dict(self, data: dict[str, Any]) -> None:
+        """
+        Replace the running timers with ones saved by `to_dict()`.
+
+        Realtime timers that expired while the game was closed fire on the
+        next `tick()`.
+        """
+        version = data.get("version")
+        if version != _SAVE_VERSION:
+            raise ValueError(f"Unsupported timer save version: {version!r}")
+
+        timers: dict[str, Timer] = {}
+        for name, saved in data.get("timers", {}).items():
+            clock = saved.get("clock", GAME_CLOCK)
+            if clock not in _CLOCKS:
+                raise ValueError(f"Timer '{name}' has unknown clock {clock!r}")
+            previous = self._timers.get(name)
+            timer = Timer(
+                name,
+                float(saved["duration"]),
+                clock,
+                bool(saved.get("repeat", False)),
+                previous.on_expired if previous is not None else None,
+            )
+            if clock == REALTIME_CLOCK:
+                timer._deadline = float(saved["deadline"])
+            else:
+                timer._remaining = float(saved["remaining"])
+            timers[name] = timer
+        self._timers = timers
+
+    def save(self, path: str) -> None:
+        """Write the running timers to a JSON file."""
+        with open(path, "w", encoding="utf-8") as file:
+            json.dump(self.to_dict(), file, indent=2)
+
+    def load(self, path: str) -> bool:
+        """
+        Restore timers from a JSON file written by `save()`.
+        Returns False, leaving the timers unchanged, if the file does not exist.
+        """
+        try:
+            with open(path, encoding="utf-8") as file:
+                data = json.load(file)
+        except FileNotFoundError:
+            return False
+        self.load_dict(data)
+        return True
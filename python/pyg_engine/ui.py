@@ -1,14 +1,57 @@
 """
-UI system for PyG Engine - buttons, panels, labels, and more.
+UI system for PyG Engine - buttons, panels, labels, text inputs, sliders, checkboxes, toggle switches, scroll views, progress bars, images, stacks, curve editors, radio buttons, list views, custom widgets, canvases, and more.
 """
 
-from typing import Callable, Optional
+from typing import Callable, Optional, Union
 from .pyg_engine_native import (
     ButtonComponent,
     PanelComponent,
     LabelComponent,
+    TextInputComponent,
+    SliderComponent,
+    CheckboxComponent,
+    ToggleSwitchComponent,
+    ScrollViewComponent,
+    ProgressBarComponent,
+    CustomWidgetComponent,
+    CanvasComponent,
+    ImageComponent,
+    StackComponent,
+    CurveEditorComponent,
+    AnimationCurve,
+    RadioButtonComponent,
+    RadioGroup as _NativeRadioGroup,
+    ListViewComponent,
     GameObject,
 )
+from .assets import AssetHandle, asset_path
+
+
+def _attach_child(parent, child):
+    """Parent ``child`` under a container, moving it from any previous parent."""
+    if child is parent:
+        raise ValueError("A UI element cannot be its own child")
+    old_parent = getattr(child, "_parent", None)
+    if old_parent is not None and child in old_parent._children:
+        old_parent._children.remove(child)
+    parent._children.append(child)
+    child._parent = parent
+    ui = getattr(parent, "_ui", None)
+    if ui is not None and getattr(child, "_object_id", None) is None:
+        ui._add_tree(child)
+    if parent._game_object is not None and getattr(child, "_game_object", None) is not None:
+        parent._game_object.add_child(child._game_object)
+
+
+def _detach_child(parent, child):
+    """Remove ``child`` from a container and destroy it if it is in the engine."""
+    parent._children.remove(child)
+    child._parent = None
+    object_id = getattr(child, "_object_id", None)
+    if parent._engine is not None and object_id is not None:
+        parent._engine.remove_game_object(object_id)
+        child._object_id = None
+        child._game_object = None
 
 
 class Button:
@@ -87,6 +130,13 @@ class Button:
         font_weight: Optional[str] = None,
         font_style: Optional[str] = None,
         kerning: bool = True,
+        font_fallbacks: Optional[list[str]] = None,
+        letter_spacing: float = 0.0,
+        line_spacing: float = 0.0,
+        on_hover_enter: Optional[Callable[..., None]] = None,
+        on_hover_exit: Optional[Callable[..., None]] = None,
+        on_press: Optional[Callable[..., None]] = None,
+        on_release: Optional[Callable[..., None]] = None,
     ):
         """
         Create a new button.
@@ -104,6 +154,16 @@ class Button:
             trigger_on: When to trigger the callback - "press" (on mouse down) or "release" (on mouse up, default)
             repeat_interval_ms: If set, the callback will repeat every X milliseconds while the button is held.
                 Set to None (default) to disable repeating.
+            font_fallbacks: Fonts (family names or TTF/OTF paths) tried in order for characters
+                the button font lacks. Defaults to the UI theme's fallbacks.
+            letter_spacing: Extra space in pixels between characters
+            line_spacing: Extra space in pixels between lines
+            on_hover_enter: Called when the mouse starts hovering the button.
+            on_hover_exit: Called when the mouse stops hovering the button.
+            on_press: Called when a mouse button goes down on the button, whatever `trigger_on` is.
+            on_release: Called once per press, when the mouse button is released or the
+                pointer leaves the held button. Like `on_click`, these four callbacks may
+                take no parameters or a single `engine_handle` parameter.
         """
         self._component = ButtonComponent(text, x, y, width, height)
         self._game_object = None
@@ -130,6 +190,18 @@ class Button:
         if font_style is not None:
             self._component.set_font_style(font_style)
         self._component.set_kerning(kerning)
+        if font_fallbacks is not None:
+            self._component.set_font_fallbacks(list(font_fallbacks))
+        self._component.set_letter_spacing(letter_spacing)
+        self._component.set_line_spacing(line_spacing)
+        if on_hover_enter is not None:
+            self.set_on_hover_enter(on_hover_enter)
+        if on_hover_exit is not None:
+            self.set_on_hover_exit(on_hover_exit)
+        if on_press is not None:
+            self.set_on_press(on_press)
+        if on_release is not None:
+            self.set_on_release(on_release)
 
     def add_to_engine(self, engine) -> int:
         """
@@ -263,6 +335,72 @@ class Button:
             # Clear callback
             self._component.set_on_click(lambda: None)
 
+    def _wrap_callback(self, callback: Optional[Callable[..., None]]) -> Callable[[], None]:
+        """Internal: Adapt a 0- or 1-parameter callback to the zero-argument form the component expects."""
+        import inspect
+
+        if callback is None:
+            return lambda: None
+        num_params = len(inspect.signature(callback).parameters)
+        if num_params == 0:
+            return callback
+        if num_params == 1:
+            # The handle is looked up at call time, so this works before the button is added.
+            return lambda: callback(self._engine_handle)
+        raise ValueError(
+            f"Button callback must accept 0 or 1 parameters, got {num_params}. "
+            f"Use `def callback():` or `def callback(engine):`"
+        )
+
+    def set_on_hover_enter(self, callback: Optional[Callable[..., None]]):
+        """
+        Set the callback fired when the mouse starts hovering the button.
+
+        Args:
+            callback: `def callback():` or `def callback(engine):`, or None to clear it.
+
+        Example:
+            ```python
+            button = Button("Play", x=100, y=100, width=160, height=48)
+            button.set_on_hover_enter(lambda: setattr(button, "text", "> Play <"))
+            button.set_on_hover_exit(lambda: setattr(button, "text", "Play"))
+            engine.ui.add(button)
+            ```
+        """
+        self._component.set_on_hover_enter(self._wrap_callback(callback))
+
+    def set_on_hover_exit(self, callback: Optional[Callable[..., None]]):
+        """
+        Set the callback fired when the mouse stops hovering the button.
+
+        Args:
+            callback: `def callback():` or `def callback(engine):`, or None to clear it.
+        """
+        self._component.set_on_hover_exit(self._wrap_callback(callback))
+
+    def set_on_press(self, callback: Optional[Callable[..., None]]):
+        """
+        Set the callback fired when a mouse button goes down on the button.
+
+        Unlike `on_click`, this fires on press even when `trigger_on` is "release".
+
+        Args:
+            callback: `def callback():` or `def callback(engine):`, or None to clear it.
+        """
+        self._component.set_on_press(self._wrap_callback(callback))
+
+    def set_on_release(self, callback: Optional[Callable[..., None]]):
+        """
+        Set the callback fired when a press on the button ends.
+
+        Fires once per press: on mouse up, or when the pointer is dragged off the
+        held button. It does not mean the button was clicked; use `on_click` for that.
+
+        Args:
+            callback: `def callback():` or `def callback(engine):`, or None to clear it.
+        """
+        self._component.set_on_release(self._wrap_callback(callback))
+
     def set_trigger_on(self, trigger: str):
         """
         Set when the button callback is triggered.
@@ -325,6 +463,10 @@ class Button:
         """Set the registered font family for the button label."""
         self._component.set_font_family(font_family)
 
+    def set_font_fallbacks(self, fallbacks: list[str]):
+        """Set the fonts tried in order for characters the label font lacks. An empty list uses the UI theme's."""
+        self._component.set_font_fallbacks(list(fallbacks))
+
     def set_font_weight(self, font_weight: str):
         """Set the button label font weight (`regular` or `bold`)."""
         self._component.set_font_weight(font_weight)
@@ -337,6 +479,67 @@ class Button:
         """Enable or disable kerning for the button label."""
         self._component.set_kerning(kerning)
 
+    def set_letter_spacing(self, letter_spacing: float):
+        """Set extra space in pixels between characters of the button label."""
+        self._component.set_letter_spacing(letter_spacing)
+
+    def set_line_spacing(self, line_spacing: float):
+        """Set extra space in pixels between lines of the button label."""
+        self._component.set_line_spacing(line_spacing)
+
+    def set_corner_radius(self, radius: float):
+        """Round the button's corners, including its border and shadow, by `radius` pixels."""
+        self._component.set_corner_radius(radius)
+
+    def set_shadow(
+        self,
+        r: float,
+        g: float,
+        b: float,
+        a: float = 0.35,
+        offset_x: float = 0.0,
+        offset_y: float = 2.0,
+        blur: float = 4.0,
+    ):
+        """
+        Draw a drop shadow under the button in every state.
+
+        Args:
+            r, g, b, a: Shadow color; alpha 0 removes the shadow
+            offset_x: Horizontal shadow offset in pixels
+            offset_y: Vertical shadow offset in pixels
+            blur: Distance in pixels over which the shadow's edge fades out
+        """
+        self._component.set_shadow(r, g, b, a, offset_x, offset_y, blur)
+
+    def set_background_gradient(
+        self,
+        top: tuple[float, ...],
+        bottom: Optional[tuple[float, ...]] = None,
+        state: Optional[str] = None,
+    ):
+        """
+        Fill the button background with a vertical gradient.
+
+        Args:
+            top: (r, g, b[, a]) color at the top edge
+            bottom: (r, g, b[, a]) color at the bottom edge, or None for a solid `top` color
+            state: "normal", "hovered", "pressed", "focused" or "disabled"
+                to style one state, or None for all of them
+
+        Example:
+            ```python
+            button = Button("Play", width=160, height=44)
+            button.set_corner_radius(8)
+            button.set_background_gradient((0.3, 0.6, 1.0), (0.15, 0.35, 0.8))
+            button.set_background_gradient((0.4, 0.7, 1.0), (0.2, 0.45, 0.9), state="hovered")
+            button.set_shadow(0, 0, 0, 0.4, offset_y=3, blur=6)
+            ```
+        """
+        self._component.set_background_gradient(
+            _rgba(top), None if bottom is None else _rgba(bottom), state
+        )
+
 
 class Panel:
     """
@@ -375,6 +578,17 @@ class Panel:
         fg_panel.set_border(3, 0.0, 0.5, 1.0, 1.0)  # Blue border
         engine.ui.add(fg_panel)
         ```
+
+    **Modal Example:**
+
+        ```python
+        # While shown, only the dialog and its children get UI input and
+        # the rest of the screen is dimmed.
+        dialog = Panel(x=250, y=200, width=300, height=150, depth=100, modal=True)
+        dialog.add_child(Button("Close", x=200, y=100, depth=100,
+                                on_click=lambda: dialog.close()))
+        engine.ui.add(dialog)
+        ```
     """
 
     def __init__(
@@ -384,6 +598,8 @@ class Panel:
         width: float = 200,
         height: float = 200,
         depth: float = 0,
+        modal: bool = False,
+        clip_children: bool = False,
     ):
         """
         Create a new panel.
@@ -394,12 +610,21 @@ class Panel:
             width: Panel width in pixels
             height: Panel height in pixels
             depth: Rendering depth (higher = in front)
+            modal: Capture all UI input and dim the screen behind the panel
+                until it is closed. Give modal panels a depth above the rest
+                of the UI so the backdrop covers it.
+            clip_children: Cut off child elements, including overflowing
+                label text, at the panel's bounds
         """
         self._component = PanelComponent(x, y, width, height)
+        self._component.set_modal(modal)
+        self._component.set_clip_children(clip_children)
         self._game_object = None
         self._children: list[object] = []
         self._parent = None
         self._object_id = None
+        self._engine = None
+        self._ui = None
         self._enabled = True
         self._component.set_depth(depth)
 
@@ -513,6 +738,103 @@ class Panel:
         """
         self._component.set_border(width, r, g, b, a)
 
+    def set_corner_radius(self, radius: float):
+        """Round the panel's corners, including its border and shadow, by `radius` pixels."""
+        self._component.set_corner_radius(radius)
+
+    def set_shadow(
+        self,
+        r: float,
+        g: float,
+        b: float,
+        a: float = 0.35,
+        offset_x: float = 0.0,
+        offset_y: float = 4.0,
+        blur: float = 8.0,
+    ):
+        """
+        Draw a drop shadow under the panel.
+
+        Args:
+            r, g, b, a: Shadow color; alpha 0 removes the shadow
+            offset_x: Horizontal shadow offset in pixels
+            offset_y: Vertical shadow offset in pixels
+            blur: Distance in pixels over which the shadow's edge fades out
+
+        Example:
+            ```python
+            dialog = Panel(x=200, y=150, width=400, height=250)
+            dialog.set_corner_radius(12)
+            dialog.set_shadow(0.0, 0.0, 0.0, 0.5, offset_y=6, blur=16)
+            ```
+        """
+        self._component.set_shadow(r, g, b, a, offset_x, offset_y, blur)
+
+    def set_background_gradient(
+        self, top: tuple[float, ...], bottom: Optional[tuple[float, ...]] = None
+    ):
+        """
+        Fill the panel background with a vertical gradient from `top` to
+        `bottom`, given as (r, g, b[, a]) tuples. With `bottom=None` the
+        background is a solid `top` color.
+        """
+        self._component.set_background_gradient(
+            _rgba(top), None if bottom is None else _rgba(bottom)
+        )
+
+    @property
+    def clip_children(self) -> bool:
+        """Get whether child elements are cut off at the panel's bounds."""
+        return self._component.get_clip_children()
+
+    def set_clip_children(self, clip: bool):
+        """
+        Cut off child elements, including overflowing label text, at the
+        panel's bounds. Can be switched while the panel is shown.
+
+        Clipping is rectangular: rounded corners do not round the clip area.
+        Children of clipped children are clipped too, and nested clipping
+        panels clip to the overlap of their bounds. Clipped-away parts of a
+        child don't receive clicks.
+
+        Example:
+            ```python
+            # Long names are cut off at the card's edge instead of spilling out.
+            card = Panel(x=20, y=20, width=160, height=40)
+            card.add_child(Label("Sword of a Thousand Truths", x=8, y=12))
+            engine.ui.add(card)
+            card.set_clip_children(True)
+            ```
+        """
+        self._component.set_clip_children(clip)
+        if self._engine is not None and self._object_id is not None:
+            self._engine.update_ui_panel_clip(self._object_id, clip)
+
+    @property
+    def modal(self) -> bool:
+        """Get whether the panel captures all UI input while shown."""
+        return self._component.is_modal()
+
+    def set_modal(self, modal: bool):
+        """Set whether the panel is modal. Takes effect when the panel is added."""
+        self._component.set_modal(modal)
+
+    def set_dim_color(self, r: float, g: float, b: float, a: float = 0.5):
+        """
+        Set the backdrop color drawn over the rest of the screen while the
+        panel is modal. Use alpha 0 to block input without dimming.
+        """
+        self._component.set_dim_color(r, g, b, a)
+
+    def close(self):
+        """Remove the panel and its children from the engine."""
+        if self._parent is not None:
+            _detach_child(self._parent, self)
+        elif self._engine is not None and self._object_id is not None:
+            self._engine.remove_game_object(self._object_id)
+            self._object_id = None
+            self._game_object = None
+
     @property
     def enabled(self) -> bool:
         """Get whether the panel is enabled."""
@@ -530,11 +852,14 @@ class Panel:
         return self._object_id
 
     def add_child(self, child):
-        """Add a child UI element under this panel."""
-        self._children.append(child)
-        child._parent = self
-        if self._game_object is not None and getattr(child, "_game_object", None) is not None:
-            self._game_object.add_child(child._game_object)
+        """
+        Add a child UI element under this panel.
+
+        The child's position is relative to the panel's top-left corner, so
+        moving the panel moves the child. A child that already has a parent is
+        moved here. If the panel is already in the engine, so is the child.
+        """
+        _attach_child(self, child)
         return child
 
     def add_children(self, children):
@@ -543,6 +868,10 @@ class Panel:
             self.add_child(child)
         return list(children)
 
+    def remove_child(self, child):
+        """Remove a child UI element, destroying it if it was added to the engine."""
+        _detach_child(self, child)
+
     def get_children(self):
         """Get this panel's direct child UI elements."""
         return list(self._children)
@@ -617,6 +946,20 @@ class Label:
         timer.set_color(1.0, 1.0, 1.0, 1.0)  # White
         engine.ui.add(timer)
         ```
+
+    **Wrapped Paragraph Example:**
+
+        ```python
+        # Lines break at 280px and the label grows to fit them, so the
+        # VStack places the button below the last line.
+        column = VStack(x=20, y=20, width=280, spacing=8, alignment="stretch", fit_content=True)
+        column.add_child(Label(
+            "The lighthouse keeper had not seen a ship in forty days.",
+            wrap=True, auto_height=True, align="center",
+        ))
+        column.add_child(Button("Continue", height=32))
+        engine.ui.add(column)
+        ```
     """
 
     def __init__(
@@ -632,6 +975,14 @@ class Label:
         font_weight: Optional[str] = None,
         font_style: Optional[str] = None,
         kerning: bool = True,
+        font_fallbacks: Optional[list[str]] = None,
+        letter_spacing: float = 0.0,
+        line_spacing: float = 0.0,
+        width: Optional[float] = None,
+        height: Optional[float] = None,
+        wrap: bool = False,
+        auto_height: bool = False,
+        vertical_align: str = "top",
     ):
         """
         Create a new label.
@@ -643,6 +994,16 @@ class Label:
             font_size: Font size in pixels
             align: Text alignment ("left", "center", "right")
             depth: Rendering depth (higher = in front)
+            font_fallbacks: Fonts (family names or TTF/OTF paths) tried in order for characters
+                the label font lacks. Defaults to the UI theme's fallbacks.
+            letter_spacing: Extra space in pixels between characters
+            line_spacing: Extra space in pixels between lines
+            width: Label width in pixels, which alignment and wrapping use (default 100)
+            height: Label height in pixels (default 20)
+            wrap: Break the text into lines that fit the label's width
+            auto_height: Resize the label's height to its text every frame
+            vertical_align: Text position when the label is taller than its text
+                ("top", "center", "bottom")
         """
         self._component = LabelComponent(text, x, y, font_size)
         self._game_object = None
@@ -662,6 +1023,19 @@ class Label:
         if font_style is not None:
             self._component.set_font_style(font_style)
         self._component.set_kerning(kerning)
+        if font_fallbacks is not None:
+            self._component.set_font_fallbacks(list(font_fallbacks))
+        self._component.set_letter_spacing(letter_spacing)
+        self._component.set_line_spacing(line_spacing)
+        if width is not None or height is not None:
+            _, _, current_width, current_height = self._component.get_bounds()
+            self._component.set_size(
+                current_width if width is None else width,
+                current_height if height is None else height,
+            )
+        self._component.set_wrap(wrap)
+        self._component.set_auto_height(auto_height)
+        self._component.set_vertical_align(vertical_align)
 
     def add_to_engine(self, engine) -> int:
         """
@@ -790,6 +1164,15 @@ class Label:
         """Set the registered font family for this label."""
         self._component.set_font_family(font_family)
 
+    def set_font_fallbacks(self, fallbacks: list[str]):
+        """Set the fonts tried in order for characters this label's font lacks. An empty list uses the UI theme's."""
+        self._component.set_font_fallbacks(list(fallbacks))
+
+    @property
+    def font_fallbacks(self) -> list[str]:
+        """Get this label's own fallback fonts (empty when it uses the UI theme's)."""
+        return self._component.get_font_fallbacks()
+
     def set_font_weight(self, font_weight: str):
         """Set the label font weight (`regular` or `bold`)."""
         self._component.set_font_weight(font_weight)
@@ -802,6 +1185,14 @@ class Label:
         """Enable or disable kerning for this label."""
         self._component.set_kerning(kerning)
 
+    def set_letter_spacing(self, letter_spacing: float):
+        """Set extra space in pixels between characters."""
+        self._component.set_letter_spacing(letter_spacing)
+
+    def set_line_spacing(self, line_spacing: float):
+        """Set extra space in pixels between lines."""
+        self._component.set_line_spacing(line_spacing)
+
     def set_color(self, r: float, g: float, b: float, a: float = 1.0):
         """
         Set the label text color.
@@ -857,5 +1248,2272 @@ class Label:
         """
         self._component.set_align(align)
 
+    def set_vertical_align(self, align: str):
+        """Set where the text sits when the label is taller than it: "top", "center" or "bottom"."""
+        self._component.set_vertical_align(align)
+
+    def set_size(self, width: float, height: float):
+        """Set the label size in pixels. With `auto_height` the height follows the text instead."""
+        self._component.set_size(width, height)
+
+    def set_wrap(self, wrap: bool):
+        """Break the text into lines at the label's width; long words break between characters."""
+        self._component.set_wrap(wrap)
+
+    @property
+    def wrap(self) -> bool:
+        """Whether the text wraps at the label's width."""
+        return self._component.get_wrap()
+
+    def set_auto_height(self, auto_height: bool):
+        """Resize the label's height to its (wrapped) text every frame, overriding any layout height."""
+        self._component.set_auto_height(auto_height)
+
+    @property
+    def auto_height(self) -> bool:
+        """Whether the label's height follows its text."""
+        return self._component.get_auto_height()
+
 
 __all__ = ["Button", "Panel", "Label"]
+
+
+class TextInput:
+    """
+    A single-line text field that accepts keyboard input when focused.
+
+    Click the field to focus it, then type. Supports caret movement with
+    Left/Right/Home/End, Shift+arrow selection, Ctrl+A, Backspace and Delete.
+    Clicking elsewhere removes focus. Enter fires `on_submit`.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine, TextInput, Label
+
+        engine = Engine()
+        greeting = Label("", x=100, y=160, font_size=18)
+
+        def on_submit(text):
+            greeting.text = f"Hello, {text}!"
+
+        name = TextInput(
+            x=100, y=100, width=240, height=32,
+            placeholder="Your name",
+            max_length=20,
+            on_submit=on_submit,
+        )
+        engine.ui.add(name)
+        engine.ui.add(greeting)
+        engine.run()
+        ```
+    """
+
+    def __init__(
+        self,
+        text: str = "",
+        x: float = 0,
+        y: float = 0,
+        width: float = 200,
+        height: float = 30,
+        placeholder: str = "",
+        max_length: Optional[int] = None,
+        on_changed: Optional[Callable[[str], None]] = None,
+        on_submit: Optional[Callable[[str], None]] = None,
+        enabled: bool = True,
+        depth: float = 0,
+        font_size: Optional[float] = None,
+        font_path: Optional[str] = None,
+        font_family: Optional[str] = None,
+        font_weight: Optional[str] = None,
+        font_style: Optional[str] = None,
+        kerning: bool = True,
+    ):
+        """
+        Create a new text input.
+
+        Args:
+            text: Initial text
+            x: X position in screen coordinates
+            y: Y position in screen coordinates
+            width: Field width in pixels
+            height: Field height in pixels
+            placeholder: Hint text shown while the field is empty and unfocused
+            max_length: Maximum number of characters, or None for no limit
+            on_changed: Called with the new text whenever the user edits it
+            on_submit: Called with the current text when Enter is pressed
+            enabled: Whether the field accepts input
+            depth: Rendering depth (higher = in front)
+        """
+        self._component = TextInputComponent(text, x, y, width, height, placeholder)
+        self._text = self._component.get_text()
+        self._game_object = None
+        self._engine = None
+        self._object_id = None
+        self._children: list[object] = []
+        self._parent = None
+        self._enabled = enabled
+        self._on_changed = on_changed
+        self._on_submit = on_submit
+        self._component.enabled = enabled
+        self._component.set_depth(depth)
+        self._component.set_max_length(max_length)
+        if font_size is not None:
+            self._component.set_font_size(font_size)
+        if font_path is not None:
+            self._component.set_font_path(font_path)
+        if font_family is not None:
+            self._component.set_font_family(font_family)
+        if font_weight is not None:
+            self._component.set_font_weight(font_weight)
+        if font_style is not None:
+            self._component.set_font_style(font_style)
+        self._component.set_kerning(kerning)
+        # The engine edits its own copy of the component; these hooks keep
+        # `text` in sync and forward to whichever user callback is current.
+        self._component.set_on_changed(self._handle_changed)
+        self._component.set_on_submit(self._handle_submit)
+
+    def _handle_changed(self, text: str):
+        self._text = text
+        if self._on_changed is not None:
+            self._on_changed(text)
+
+    def _handle_submit(self, text: str):
+        self._text = text
+        if self._on_submit is not None:
+            self._on_submit(text)
+
+    @property
+    def text(self) -> str:
+        """Get the current text, including edits made by the user."""
+        return self._text
+
+    @text.setter
+    def text(self, value: str):
+        """
+        Replace the text. Does not fire `on_changed`.
+
+        If the field has been added to the engine, the change is applied on the next frame.
+        """
+        self._component.set_text(value)
+        self._text = self._component.get_text()
+        if self._engine is not None and self._object_id is not None:
+            self._engine.update_ui_text_input_text(self._object_id, value)
+
+    @property
+    def placeholder(self) -> str:
+        """Get the placeholder text."""
+        return self._component.get_placeholder()
+
+    @placeholder.setter
+    def placeholder(self, value: str):
+        """Set the placeholder text. Only takes effect before the field is added."""
+        self._component.set_placeholder(value)
+
+    @property
+    def enabled(self) -> bool:
+        """Get whether the text input is enabled."""
+        return self._enabled
+
+    @enabled.setter
+    def enabled(self, value: bool):
+        """Set whether the text input is enabled."""
+        self._enabled = value
+        self._component.enabled = value
+
+    @property
+    def id(self) -> Optional[int]:
+        """Get the runtime object id after the text input is added."""
+        return self._object_id
+
+    def set_on_changed(self, callback: Optional[Callable[[str], None]]):
+        """Set the callback fired with the new text whenever the user edits it."""
+        self._on_changed = callback
+
+    def set_on_submit(self, callback: Optional[Callable[[str], None]]):
+        """Set the callback fired with the current text when Enter is pressed."""
+        self._on_submit = callback
+
+    def set_max_length(self, max_length: Optional[int]):
+        """Limit the number of characters, or pass None for no limit."""
+        self._component.set_max_length(max_length)
+
+    def set_position(self, x: float, y: float):
+        """Set the field position in screen coordinates."""
+        self._component.set_position(x, y)
+
+    def set_size(self, width: float, height: float):
+        """Set the field size in pixels."""
+        self._component.set_size(width, height)
+
+    def set_font_size(self, size: float):
+        """Set the font size in pixels."""
+        self._component.set_font_size(size)
+
+    def set_font_path(self, font_path: Optional[str]):
+        """Set a direct TTF/OTF file for this field."""
+        self._component.set_font_path(font_path)
+
+    def set_font_family(self, font_family: Optional[str]):
+        """Set the registered font family for this field."""
+        self._component.set_font_family(font_family)
+
+    def set_font_weight(self, font_weight: str):
+        """Set the font weight (`regular` or `bold`)."""
+        self._component.set_font_weight(font_weight)
+
+    def set_font_style(self, font_style: str):
+        """Set the font style (`normal` or `italic`)."""
+        self._component.set_font_style(font_style)
+
+    def set_kerning(self, kerning: bool):
+        """Enable or disable kerning for this field."""
+        self._component.set_kerning(kerning)
+
+
+class Slider:
+    """
+    A draggable slider for picking a number in a range.
+
+    Click or drag to set the value. After clicking, the arrow keys nudge it
+    by one step and Home/End jump to the ends. Handy for settings menus
+    such as volume or mouse sensitivity.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine, Slider, Label
+
+        engine = Engine()
+        volume_label = Label("Volume: 80", x=100, y=60, font_size=16)
+
+        def on_volume(value):
+            volume_label.text = f"Volume: {value:.0f}"
+
+        volume = Slider(
+            min_value=0, max_value=100, value=80, step=1,
+            x=100, y=90, width=240, height=24,
+            on_value_changed=on_volume,
+        )
+        engine.ui.add(volume_label)
+        engine.ui.add(volume)
+        engine.run()
+        ```
+    """
+
+    def __init__(
+        self,
+        min_value: float = 0.0,
+        max_value: float = 1.0,
+        value: Optional[float] = None,
+        x: float = 0,
+        y: float = 0,
+        width: float = 200,
+        height: float = 24,
+        step: float = 0.0,
+        orientation: str = "horizontal",
+        on_value_changed: Optional[Callable[[float], None]] = None,
+        enabled: bool = True,
+        depth: float = 0,
+    ):
+        """
+        Create a new slider.
+
+        Args:
+            min_value: Smallest selectable value
+            max_value: Largest selectable value
+            value: Initial value (defaults to `min_value`)
+            x: X position in screen coordinates
+            y: Y position in screen coordinates
+            width: Slider width in pixels
+            height: Slider height in pixels
+            step: Snap increment, or 0 for a continuous slider
+            orientation: "horizontal" (min on the left) or "vertical" (min at the bottom)
+            on_value_changed: Called with the new value whenever the user moves the slider
+            enabled: Whether the slider accepts input
+            depth: Rendering depth (higher = in front)
+        """
+        if value is None:
+            value = min_value
+        self._component = SliderComponent(
+            min_value, max_value, value, x, y, width, height, step, orientation
+        )
+        self._value = self._component.get_value()
+        self._game_object = None
+        self._engine = None
+        self._object_id = None
+        self._children: list[object] = []
+        self._parent = None
+        self._enabled = enabled
+        self._on_value_changed = on_value_changed
+        self._component.enabled = enabled
+        self._component.set_depth(depth)
+        # The engine drags its own copy of the component; this hook keeps
+        # `value` in sync and forwards to the current user callback.
+        self._component.set_on_value_changed(self._handle_value_changed)
+
+    def _handle_value_changed(self, value: float):
+        self._value = value
+        if self._on_value_changed is not None:
+            self._on_value_changed(value)
+
+    @property
+    def value(self) -> float:
+        """Get the current value, including changes made by the user."""
+        return self._value
+
+    @value.setter
+    def value(self, value: float):
+        """
+        Set the value. It is clamped to the range and snapped to `step`.
+        Does not fire `on_value_changed`.
+        """
+        self._component.set_value(value)
+        self._value = self._component.get_value()
+        if self._engine is not None and self._object_id is not None:
+            self._engine.update_ui_slider_value(self._object_id, self._value)
+
+    @property
+    def min_value(self) -> float:
+        """Get the smallest selectable value."""
+        return self._component.get_range()[0]
+
+    @property
+    def max_value(self) -> float:
+        """Get the largest selectable value."""
+        return self._component.get_range()[1]
+
+    @property
+    def step(self) -> float:
+        """Get the snap increment (0 for continuous)."""
+        return self._component.get_step()
+
+    @property
+    def orientation(self) -> str:
+        """Get the orientation: "horizontal" or "vertical"."""
+        return self._component.get_orientation()
+
+    @property
+    def enabled(self) -> bool:
+        """Get whether the slider is enabled."""
+        return self._enabled
+
+    @enabled.setter
+    def enabled(self, value: bool):
+        """Set whether the slider is enabled."""
+        self._enabled = value
+        self._component.enabled = value
+
+    @property
+    def id(self) -> Optional[int]:
+        """Get the runtime object id after the slider is added."""
+        return self._object_id
+
+    def set_on_value_changed(self, callback: Optional[Callable[[float], None]]):
+        """Set the callback fired with the new value whenever the user moves the slider."""
+        self._on_value_changed = callback
+
+    def set_position(self, x: float, y: float):
+        """Set the slider position in screen coordinates."""
+        self._component.set_position(x, y)
+
+    def set_size(self, width: float, height: float):
+        """Set the slider size in pixels."""
+        self._component.set_size(width, height)
+
+
+class Checkbox:
+    """
+    A checkbox with a text label.
+
+    Clicking the box or its label toggles it; Space or Enter toggles it
+    after it has been clicked.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine, Checkbox
+
+        engine = Engine()
+        fullscreen = Checkbox(
+            "Fullscreen", x=100, y=100,
+            on_toggled=lambda checked: print("Fullscreen:", checked),
+        )
+        engine.ui.add(fullscreen)
+        engine.run()
+        ```
+    """
+
+    def __init__(
+        self,
+        label: str = "",
+        checked: bool = False,
+        x: float = 0,
+        y: float = 0,
+        width: float = 160,
+        height: float = 24,
+        on_toggled: Optional[Callable[[bool], None]] = None,
+        font_size: Optional[float] = None,
+        enabled: bool = True,
+        depth: float = 0,
+    ):
+        """
+        Create a new checkbox.
+
+        Args:
+            label: Text shown to the right of the checkbox
+            checked: Initial state
+            x: X position in screen coordinates
+            y: Y position in screen coordinates
+            width: Total width in pixels, including the label
+            height: Height in pixels
+            on_toggled: Called with the new state whenever the user toggles the checkbox
+            font_size: Label font size in pixels
+            enabled: Whether the checkbox accepts input
+            depth: Rendering depth (higher = in front)
+        """
+        self._component = CheckboxComponent(label, checked, x, y, width, height)
+        self._checked = checked
+        self._game_object = None
+        self._engine = None
+        self._object_id = None
+        self._children: list[object] = []
+        self._parent = None
+        self._enabled = enabled
+        self._on_toggled = on_toggled
+        self._component.enabled = enabled
+        self._component.set_depth(depth)
+        if font_size is not None:
+            self._component.set_font_size(font_size)
+        # The engine toggles its own copy of the component; this hook keeps
+        # `checked` in sync and forwards to the current user callback.
+        self._component.set_on_toggled(self._handle_toggled)
+
+    def _handle_toggled(self, checked: bool):
+        self._checked = checked
+        if self._on_toggled is not None:
+            self._on_toggled(checked)
+
+    @property
+    def checked(self) -> bool:
+        """Get the current state, including changes made by the user."""
+        return self._checked
+
+    @checked.setter
+    def checked(self, value: bool):
+        """Set the state. Does not fire `on_toggled`."""
+        self._checked = bool(value)
+        self._component.set_checked(self._checked)
+        if self._engine is not None and self._object_id is not None:
+            self._engine.update_ui_checked(self._object_id, self._checked)
+
+    @property
+    def label(self) -> str:
+        """Get the label text."""
+        return self._component.get_label()
+
+    @property
+    def enabled(self) -> bool:
+        """Get whether the checkbox is enabled."""
+        return self._enabled
+
+    @enabled.setter
+    def enabled(self, value: bool):
+        """Set whether the checkbox is enabled."""
+        self._enabled = value
+        self._component.enabled = value
+
+    @property
+    def id(self) -> Optional[int]:
+        """Get the runtime object id after the checkbox is added."""
+        return self._object_id
+
+    def set_on_toggled(self, callback: Optional[Callable[[bool], None]]):
+        """Set the callback fired with the new state whenever the user toggles the checkbox."""
+        self._on_toggled = callback
+
+    def set_position(self, x: float, y: float):
+        """Set the checkbox position in screen coordinates."""
+        self._component.set_position(x, y)
+
+    def set_size(self, width: float, height: float):
+        """Set the checkbox size in pixels."""
+        self._component.set_size(width, height)
+
+
+class ToggleSwitch:
+    """
+    An on/off switch with a text label.
+
+    Works like `Checkbox` but draws a sliding knob, which reads better for
+    settings that take effect immediately.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine, ToggleSwitch
+
+        engine = Engine()
+        vsync = ToggleSwitch(
+            "VSync", checked=True, x=100, y=100,
+            on_toggled=lambda on: print("VSync:", on),
+        )
+        engine.ui.add(vsync)
+        engine.run()
+        ```
+    """
+
+    def __init__(
+        self,
+        label: str = "",
+        checked: bool = False,
+        x: float = 0,
+        y: float = 0,
+        width: float = 160,
+        height: float = 24,
+        on_toggled: Optional[Callable[[bool], None]] = None,
+        on_color: Optional[tuple[float, float, float, float]] = None,
+        font_size: Optional[float] = None,
+        enabled: bool = True,
+        depth: float = 0,
+    ):
+        """
+        Create a new switch.
+
+        Args:
+            label: Text shown to the right of the switch
+            checked: Initial state
+            x: X position in screen coordinates
+            y: Y position in screen coordinates
+            width: Total width in pixels, including the label
+            height: Height in pixels
+            on_toggled: Called with the new state whenever the user flips the switch
+            on_color: Track color (r, g, b, a) while the switch is on
+            font_size: Label font size in pixels
+            enabled: Whether the switch accepts input
+            depth: Rendering depth (higher = in front)
+        """
+        self._component = ToggleSwitchComponent(label, checked, x, y, width, height)
+        self._checked = checked
+        self._game_object = None
+        self._engine = None
+        self._object_id = None
+        self._children: list[object] = []
+        self._parent = None
+        self._enabled = enabled
+        self._on_toggled = on_toggled
+        self._component.enabled = enabled
+        self._component.set_depth(depth)
+        if font_size is not None:
+            self._component.set_font_size(font_size)
+        if on_color is not None:
+            self._component.set_on_color(*on_color)
+        # The engine toggles its own copy of the component; this hook keeps
+        # `checked` in sync and forwards to the current user callback.
+        self._component.set_on_toggled(self._handle_toggled)
+
+    def _handle_toggled(self, checked: bool):
+        self._checked = checked
+        if self._on_toggled is not None:
+            self._on_toggled(checked)
+
+    @property
+    def checked(self) -> bool:
+        """Get the current state, including changes made by the user."""
+        return self._checked
+
+    @checked.setter
+    def checked(self, value: bool):
+        """Set the state. Does not fire `on_toggled`."""
+        self._checked = bool(value)
+        self._component.set_checked(self._checked)
+        if self._engine is not None and self._object_id is not None:
+            self._engine.update_ui_checked(self._object_id, self._checked)
+
+    @property
+    def label(self) -> str:
+        """Get the label text."""
+        return self._component.get_label()
+
+    @property
+    def enabled(self) -> bool:
+        """Get whether the switch is enabled."""
+        return self._enabled
+
+    @enabled.setter
+    def enabled(self, value: bool):
+        """Set whether the switch is enabled."""
+        self._enabled = value
+        self._component.enabled = value
+
+    @property
+    def id(self) -> Optional[int]:
+        """Get the runtime object id after the switch is added."""
+        return self._object_id
+
+    def set_on_toggled(self, callback: Optional[Callable[[bool], None]]):
+        """Set the callback fired with the new state whenever the user flips the switch."""
+        self._on_toggled = callback
+
+    def set_on_color(self, r: float, g: float, b: float, a: float = 1.0):
+        """Set the track color used while the switch is on."""
+        self._component.set_on_color(r, g, b, a)
+
+    def set_position(self, x: float, y: float):
+        """Set the switch position in screen coordinates."""
+        self._component.set_position(x, y)
+
+    def set_size(self, width: float, height: float):
+        """Set the switch size in pixels."""
+        self._component.set_size(width, height)
+
+
+class ScrollView:
+    """
+    A scrollable container that clips its children to its bounds.
+
+    Children are positioned relative to the top-left of the scroll view's
+    content and move as it scrolls. Scroll with the mouse wheel over the
+    view, by dragging its background, or with the arrow, Page Up/Down,
+    Home and End keys after clicking it. The content size is measured from
+    the children unless set with `set_content_size`.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine, ScrollView, Label
+
+        engine = Engine()
+        log = ScrollView(x=20, y=20, width=300, height=200)
+        for i in range(50):
+            log.add_child(Label(f"Line {i}", x=8, y=8 + i * 20, depth=1))
+
+        log.set_on_scrolled(lambda x, y: print("scrolled to", y))
+        engine.ui.add(log)
+        engine.run()
+        ```
+    """
+
+    def __init__(
+        self,
+        x: float = 0,
+        y: float = 0,
+        width: float = 200,
+        height: float = 200,
+        horizontal: bool = False,
+        vertical: bool = True,
+        on_scrolled: Optional[Callable[[float, float], None]] = None,
+        enabled: bool = True,
+        depth: float = 0,
+    ):
+        """
+        Create a new scroll view.
+
+        Args:
+            x: X position in screen coordinates
+            y: Y position in screen coordinates
+            width: Visible width in pixels
+            height: Visible height in pixels
+            horizontal: Whether the view scrolls sideways
+            vertical: Whether the view scrolls up and down
+            on_scrolled: Called with the new `(x, y)` offset whenever the user scrolls
+            enabled: Whether the scroll view accepts input
+            depth: Rendering depth (higher = in front); give children a higher depth
+        """
+        self._component = ScrollViewComponent(x, y, width, height, horizontal, vertical)
+        self._scroll = (0.0, 0.0)
+        self._game_object = None
+        self._engine = None
+        self._ui = None
+        self._object_id = None
+        self._children: list[object] = []
+        self._parent = None
+        self._enabled = enabled
+        self._on_scrolled = on_scrolled
+        self._component.enabled = enabled
+        self._component.set_depth(depth)
+        # The engine scrolls its own copy of the component; this hook keeps
+        # `scroll_x`/`scroll_y` in sync and forwards to the current user callback.
+        self._component.set_on_scrolled(self._handle_scrolled)
+
+    def _handle_scrolled(self, x: float, y: float):
+        self._scroll = (x, y)
+        if self._on_scrolled is not None:
+            self._on_scrolled(x, y)
+
+    @property
+    def scroll_x(self) -> float:
+        """Get the horizontal scroll offset in pixels, including user scrolling."""
+        return self._scroll[0]
+
+    @property
+    def scroll_y(self) -> float:
+        """Get the vertical scroll offset in pixels, including user scrolling."""
+        return self._scroll[1]
+
+    def scroll_to(self, x: float, y: float):
+        """
+        Scroll to an offset. It is clamped to the content once the content
+        size is known. Does not fire `on_scrolled`.
+        """
+        self._component.set_scroll(x, y)
+        self._scroll = self._component.get_scroll()
+        if self._engine is not None and self._object_id is not None:
+            self._scroll = (max(x, 0.0), max(y, 0.0))
+            self._engine.update_ui_scroll_position(self._object_id, x, y)
+
+    def set_content_size(self, width: Optional[float] = None, height: Optional[float] = None):
+        """Fix the scrollable content size, or pass nothing to measure it from the children."""
+        self._component.set_content_size(width, height)
+
+    def set_scroll_speed(self, speed: float):
+        """Set how many pixels one mouse wheel step scrolls."""
+        self._component.set_scroll_speed(speed)
+
+    def set_show_scrollbars(self, show: bool):
+        """Show or hide the scrollbar thumbs."""
+        self._component.set_show_scrollbars(show)
+
+    def set_background_color(self, r: float, g: float, b: float, a: float = 1.0):
+        """Set the background color."""
+        self._component.set_background_color(r, g, b, a)
+
+    def set_border(self, width: float, r: float, g: float, b: float, a: float = 1.0):
+        """Set the border width and color."""
+        self._component.set_border(width, r, g, b, a)
+
+    def set_scrollbar_color(self, r: float, g: float, b: float, a: float = 1.0):
+        """Set the scrollbar thumb color."""
+        self._component.set_scrollbar_color(r, g, b, a)
+
+    @property
+    def enabled(self) -> bool:
+        """Get whether the scroll view is enabled."""
+        return self._enabled
+
+    @enabled.setter
+    def enabled(self, value: bool):
+        """Set whether the scroll view is enabled."""
+        self._enabled = value
+        self._component.enabled = value
+
+    @property
+    def id(self) -> Optional[int]:
+        """Get the runtime object id after the scroll view is added."""
+        return self._object_id
+
+    def set_on_scrolled(self, callback: Optional[Callable[[float, float], None]]):
+        """Set the callback fired with the new `(x, y)` offset whenever the user scrolls."""
+        self._on_scrolled = callback
+
+    def set_position(self, x: float, y: float):
+        """Set the scroll view position in screen coordinates."""
+        self._component.set_position(x, y)
+
+    def set_size(self, width: float, height: float):
+        """Set the visible size in pixels."""
+        self._component.set_size(width, height)
+
+    def add_child(self, child):
+        """Add a child UI element inside the scrolled content, relative to the content's top-left."""
+        _attach_child(self, child)
+        return child
+
+    def add_children(self, children):
+        """Add multiple child UI elements inside the scrolled content."""
+        for child in children:
+            self.add_child(child)
+        return list(children)
+
+    def remove_child(self, child):
+        """Remove a child UI element, destroying it if it was added to the engine."""
+        _detach_child(self, child)
+
+    def get_children(self):
+        """Get this scroll view's direct child UI elements."""
+        return list(self._children)
+
+    def get_child_count(self) -> int:
+        """Get the number of direct child UI elements."""
+        return len(self._children)
+
+
+class ProgressBar:
+    """
+    A bar showing a value from 0.0 to 1.0.
+
+    Handy for health bars, loading screens and cooldown indicators. The
+    optional text is centered on the bar; `{percent}` and `{value}` in it
+    are replaced with the current value.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine, ProgressBar
+
+        engine = Engine()
+        health = ProgressBar(value=1.0, x=20, y=20, width=200, height=18, text="HP {percent}%")
+        health.set_fill_color(0.85, 0.2, 0.2)
+        engine.ui.add(health)
+
+        def on_hit(damage):
+            health.value -= damage / 100
+        ```
+    """
+
+    def __init__(
+        self,
+        value: float = 0.0,
+        x: float = 0,
+        y: float = 0,
+        width: float = 200,
+        height: float = 20,
+        fill_direction: str = "left_to_right",
+        text: Optional[str] = None,
+        enabled: bool = True,
+        depth: float = 0,
+    ):
+        """
+        Create a new progress bar.
+
+        Args:
+            value: Initial value, clamped to 0.0-1.0
+            x: X position in screen coordinates
+            y: Y position in screen coordinates
+            width: Bar width in pixels
+            height: Bar height in pixels
+            fill_direction: "left_to_right", "right_to_left", "bottom_to_top" or "top_to_bottom"
+            text: Optional overlay text, e.g. "Loading {percent}%"
+            enabled: Whether the bar uses its normal (not disabled) style
+            depth: Rendering depth (higher = in front)
+        """
+        self._component = ProgressBarComponent(value, x, y, width, height, fill_direction, text)
+        self._game_object = None
+        self._engine = None
+        self._object_id = None
+        self._children: list[object] = []
+        self._parent = None
+        self._enabled = enabled
+        self._component.enabled = enabled
+        self._component.set_depth(depth)
+
+    @property
+    def value(self) -> float:
+        """Get the current value (0.0-1.0)."""
+        return self._component.get_value()
+
+    @value.setter
+    def value(self, value: float):
+        """Set the value. It is clamped to 0.0-1.0."""
+        self._component.set_value(value)
+        if self._engine is not None and self._object_id is not None:
+            self._engine.update_ui_progress_value(self._object_id, self._component.get_value())
+
+    @property
+    def fill_direction(self) -> str:
+        """Get the edge the bar fills from, e.g. "left_to_right"."""
+        return self._component.get_fill_direction()
+
+    @property
+    def text(self) -> Optional[str]:
+        """Get the overlay text template."""
+        return self._component.get_text()
+
+    @property
+    def enabled(self) -> bool:
+        """Get whether the progress bar is enabled."""
+        return self._enabled
+
+    @enabled.setter
+    def enabled(self, value: bool):
+        """Set whether the progress bar is enabled."""
+        self._enabled = value
+        self._component.enabled = value
+
+    @property
+    def id(self) -> Optional[int]:
+        """Get the runtime object id after the progress bar is added."""
+        return self._object_id
+
+    def set_text(self, text: Optional[str]):
+        """Set the overlay text template, or None to hide it."""
+        self._component.set_text(text)
+
+    def set_fill_direction(self, fill_direction: str):
+        """Set the edge the bar fills from."""
+        self._component.set_fill_direction(fill_direction)
+
+    def set_track_color(self, r: float, g: float, b: float, a: float = 1.0):
+        """Set the color of the unfilled track."""
+        self._component.set_track_color(r, g, b, a)
+
+    def set_fill_color(self, r: float, g: float, b: float, a: float = 1.0):
+        """Set the color of the filled part."""
+        self._component.set_fill_color(r, g, b, a)
+
+    def set_border(self, width: float, r: float, g: float, b: float, a: float = 1.0):
+        """Set the border width and color."""
+        self._component.set_border(width, r, g, b, a)
+
+    def set_text_color(self, r: float, g: float, b: float, a: float = 1.0):
+        """Set the overlay text color."""
+        self._component.set_text_color(r, g, b, a)
+
+    def set_position(self, x: float, y: float):
+        """Set the progress bar position in screen coordinates."""
+        self._component.set_position(x, y)
+
+    def set_size(self, width: float, height: float):
+        """Set the progress bar size in pixels."""
+        self._component.set_size(width, height)
+
+    def set_font_size(self, size: float):
+        """Set the overlay text size."""
+        self._component.set_font_size(size)
+
+
+class CustomWidget:
+    """
+    Base class for UI widgets written in Python.
+
+    The engine hit-tests the widget's bounds and routes UI events to it like
+    any built-in widget: hover, press, click, focus, keys, typed text and the
+    mouse wheel. Override the `on_*` methods you need and return True from
+    them to mark the event handled. Drawing is up to you, e.g. with
+    `engine.draw_rectangle(...)` from your update using `x`, `y`, `width` and
+    `height`. Handy for prototyping a widget before porting it to Rust.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine, CustomWidget
+
+        class ColorSwatch(CustomWidget):
+            def __init__(self, **kwargs):
+                super().__init__(**kwargs)
+                self.hovered = False
+                self.clicks = 0
+
+            def on_mouse_enter(self, event):
+                self.hovered = True
+
+            def on_mouse_exit(self, event):
+                self.hovered = False
+
+            def on_click(self, event):
+                self.clicks += 1
+                return True
+
+        engine = Engine()
+        swatch = ColorSwatch(x=40, y=40, width=64, height=64)
+        engine.ui.add(swatch)
+        ```
+
+    **Event propagation:** pointer events go to the frontmost widget under the
+    mouse. Events it leaves unhandled bubble up through its UI parents,
+    reaching their `on_*` methods with `event.phase == "bubble"`. With
+    `capture_events=True` a widget also sees its children's pointer events
+    before they do, in `on_capture`. Returning True from any handler, or
+    calling `event.stop_propagation()`, stops the event there.
+
+        ```python
+        class SwipeCard(CustomWidget):
+            def __init__(self, **kwargs):
+                super().__init__(capture_events=True, **kwargs)
+                self.swipe = 0.0
+
+            def on_capture(self, event):
+                if event.type == "mouse_down":
+                    self.swipe = 0.0
+                elif event.type == "mouse_move":
+                    self.swipe += event.dx
+                # A swipe that ends over the button must not click it.
+                return event.type == "click" and abs(self.swipe) > 8
+
+        card = SwipeCard(x=100, y=100, width=240, height=160)
+        card.add_child(Button("Buy", x=16, y=112, width=96, height=32))
+        engine.ui.add(card)
+        ```
+    """
+
+    def __init__(
+        self,
+        x: float = 0,
+        y: float = 0,
+        width: float = 100,
+        height: float = 100,
+        enabled: bool = True,
+        depth: float = 0,
+        capture_events: bool = False,
+    ):
+        """
+        Create a new custom widget.
+
+        Args:
+            x: X position in screen coordinates
+            y: Y position in screen coordinates
+            width: Hit area width in pixels
+            height: Hit area height in pixels
+            enabled: Whether the widget receives events
+            depth: Hit-test depth (higher = in front)
+            capture_events: Pass children's pointer events to `on_capture` before the children see them
+        """
+        self._component = CustomWidgetComponent(x, y, width, height)
+        self._game_object = None
+        self._engine = None
+        self._object_id = None
+        self._children: list[object] = []
+        self._parent = None
+        self._enabled = enabled
+        self._component.enabled = enabled
+        self._component.set_depth(depth)
+        self._component.set_capture_events(capture_events)
+        self._component.set_on_event(self._dispatch_event)
+
+    def _dispatch_event(self, event) -> bool:
+        if event.phase == "capture":
+            return bool(self.on_capture(event))
+        handler = getattr(self, f"on_{event.type}", None)
+        handled = self.on_event(event)
+        if handler is not None:
+            handled = bool(handler(event)) or handled
+        return handled
+
+    def on_event(self, event) -> bool:
+        """Called first for every event; override to handle events generically."""
+        return False
+
+    def on_capture(self, event) -> bool:
+        """With `capture_events`, called with a child's pointer event before the child gets it; return True to keep it."""
+        return False
+
+    def set_capture_events(self, capture_events: bool):
+        """Pass children's pointer events to `on_capture` before the children see them."""
+        self._component.set_capture_events(capture_events)
+
+    def on_mouse_enter(self, event) -> bool:
+        return False
+
+    def on_mouse_exit(self, event) -> bool:
+        return False
+
+    def on_mouse_move(self, event) -> bool:
+        return False
+
+    def on_mouse_down(self, event) -> bool:
+        return False
+
+    def on_mouse_up(self, event) -> bool:
+        return False
+
+    def on_click(self, event) -> bool:
+        return False
+
+    def on_double_click(self, event) -> bool:
+        return False
+
+    def on_mouse_wheel(self, event) -> bool:
+        """Wheel lines are in `event.dx`/`event.dy`; return True to stop scroll views scrolling."""
+        return False
+
+    def on_focus_gained(self, event) -> bool:
+        return False
+
+    def on_focus_lost(self, event) -> bool:
+        return False
+
+    def on_key_down(self, event) -> bool:
+        return False
+
+    def on_text_input(self, event) -> bool:
+        return False
+
+    @property
+    def x(self) -> float:
+        """Get the X position set on the widget."""
+        return self._component.get_bounds()[0]
+
+    @property
+    def y(self) -> float:
+        """Get the Y position set on the widget."""
+        return self._component.get_bounds()[1]
+
+    @property
+    def width(self) -> float:
+        """Get the hit area width."""
+        return self._component.get_bounds()[2]
+
+    @property
+    def height(self) -> float:
+        """Get the hit area height."""
+        return self._component.get_bounds()[3]
+
+    @property
+    def enabled(self) -> bool:
+        """Get whether the widget is enabled."""
+        return self._enabled
+
+    @enabled.setter
+    def enabled(self, value: bool):
+        """Set whether the widget is enabled."""
+        self._enabled = value
+        self._component.enabled = value
+
+    @property
+    def id(self) -> Optional[int]:
+        """Get the runtime object id after the widget is added."""
+        return self._object_id
+
+    def set_position(self, x: float, y: float):
+        """Set the widget position in screen coordinates (before it is added)."""
+        self._component.set_position(x, y)
+
+    def set_size(self, width: float, height: float):
+        """Set the hit area size in pixels (before it is added)."""
+        self._component.set_size(width, height)
+
+    def add_child(self, child):
+        """Add a child UI element, positioned relative to the widget's top-left."""
+        _attach_child(self, child)
+        return child
+
+    def add_children(self, children):
+        """Add multiple child UI elements."""
+        for child in children:
+            self.add_child(child)
+        return list(children)
+
+    def remove_child(self, child):
+        """Remove a child UI element and destroy it if it was added to the engine."""
+        _detach_child(self, child)
+
+    def get_children(self):
+        """Get this widget's direct child UI elements."""
+        return list(self._children)
+
+
+class Canvas:
+    """
+    A UI area you draw yourself, for charts, minimaps and similar.
+
+    The engine calls `on_render(painter)` with a `CanvasPainter` whose
+    coordinates start at the canvas' top-left corner and whose drawing is
+    clipped to the canvas, so it follows its parent panel, stacks and scroll
+    views like any other widget. Pass `on_render=` or override the method.
+
+    The drawing is kept between frames: call `redraw()` when the data
+    changes, or pass `redraw_every_frame=True` for content that changes
+    constantly. Moving or resizing the canvas redraws it too.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine, Canvas, Color
+
+        history = [0.2, 0.5, 0.4, 0.9, 0.7]
+
+        def draw_chart(painter):
+            painter.rectangle(0, 0, painter.width, painter.height, Color(0.1, 0.1, 0.12, 1.0))
+            step = painter.width / (len(history) - 1)
+            points = [(i * step, painter.height * (1 - value)) for i, value in enumerate(history)]
+            for start, end in zip(points, points[1:]):
+                painter.line(*start, *end, Color.GREEN, thickness=2, draw_order=0.01)
+
+        engine = Engine()
+        chart = Canvas(x=20, y=20, width=240, height=100, on_render=draw_chart)
+        engine.ui.add(chart)
+
+        history.append(0.3)
+        chart.redraw()
+        ```
+    """
+
+    def __init__(
+        self,
+        x: float = 0,
+        y: float = 0,
+        width: float = 100,
+        height: float = 100,
+        on_render: Optional[Callable[[object], None]] = None,
+        redraw_every_frame: bool = False,
+        enabled: bool = True,
+        depth: float = 0,
+    ):
+        """
+        Create a new canvas.
+
+        Args:
+            x: X position in screen coordinates
+            y: Y position in screen coordinates
+            width: Canvas width in pixels
+            height: Canvas height in pixels
+            on_render: Called with a `CanvasPainter` whenever the canvas is drawn
+            redraw_every_frame: Call `on_render` on every frame instead of only after `redraw()`
+            enabled: Whether the canvas is shown
+            depth: Rendering depth; painter draw orders are added to it
+        """
+        self._component = CanvasComponent(x, y, width, height, redraw_every_frame)
+        self._game_object = None
+        self._engine = None
+        self._object_id = None
+        self._children: list[object] = []
+        self._parent = None
+        self._enabled = enabled
+        self._render_callback = on_render
+        self._component.enabled = enabled
+        self._component.set_depth(depth)
+        self._component.set_on_render(self._dispatch_render)
+
+    def _dispatch_render(self, painter):
+        self.on_render(painter)
+
+    def on_render(self, painter):
+        """Draw the canvas contents with `painter`, in canvas coordinates."""
+        if self._render_callback is not None:
+            self._render_callback(painter)
+
+    def set_on_render(self, callback: Optional[Callable[[object], None]]):
+        """Set the render callback and redraw with it."""
+        self._render_callback = callback
+        self.redraw()
+
+    def redraw(self):
+        """Call `on_render` again on the next frame."""
+        self._component.request_redraw()
+
+    def set_redraw_every_frame(self, redraw_every_frame: bool):
+        """Call `on_render` on every frame (before the canvas is added)."""
+        self._component.set_redraw_every_frame(redraw_every_frame)
+
+    @property
+    def redraw_every_frame(self) -> bool:
+        """Get whether `on_render` runs on every frame."""
+        return self._component.get_redraw_every_frame()
+
+    @property
+    def x(self) -> float:
+        """Get the X position set on the canvas."""
+        return self._component.get_bounds()[0]
+
+    @property
+    def y(self) -> float:
+        """Get the Y position set on the canvas."""
+        return self._component.get_bounds()[1]
+
+    @property
+    def width(self) -> float:
+        """Get the canvas width."""
+        return self._component.get_bounds()[2]
+
+    @property
+    def height(self) -> float:
+        """Get the canvas height."""
+        return self._component.get_bounds()[3]
+
+    @property
+    def enabled(self) -> bool:
+        """Get whether the canvas is enabled."""
+        return self._enabled
+
+    @enabled.setter
+    def enabled(self, value: bool):
+        """Set whether the canvas is enabled."""
+        self._enabled = value
+        self._component.enabled = value
+
+    @property
+    def id(self) -> Optional[int]:
+        """Get the runtime object id after the canvas is added."""
+        return self._object_id
+
+    def set_position(self, x: float, y: float):
+        """Set the canvas position in screen coordinates (before it is added)."""
+        self._component.set_position(x, y)
+
+    def set_size(self, width: float, height: float):
+        """Set the canvas size in pixels (before it is added)."""
+        self._component.set_size(width, height)
+
+
+class Image:
+    """
+    A texture displayed inside the UI layout.
+
+    Unlike raw `engine.draw_image(...)` calls, an Image follows its parent
+    panel, scrolls and clips inside scroll views, and sorts by depth with the
+    other widgets. `scale_mode` controls how the texture fits the bounds:
+
+    - "stretch": fill the bounds, ignoring the aspect ratio
+    - "fit": as large as possible inside the bounds, keeping the aspect ratio
+    - "fill": cover the bounds, keeping the aspect ratio (overflow is clipped)
+    - "tile": repeat at the texture's native size
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine, Image
+
+        engine = Engine()
+        coin = Image("assets/coin.png", x=16, y=16, width=32, height=32, scale_mode="fit")
+        engine.ui.add(coin)
+        ```
+    """
+
+    def __init__(
+        self,
+        texture_path: Union[str, AssetHandle, None] = None,
+        x: float = 0,
+        y: float = 0,
+        width: float = 64,
+        height: float = 64,
+        scale_mode: str = "stretch",
+        enabled: bool = True,
+        depth: float = 0,
+    ):
+        """
+        Create a new image.
+
+        Args:
+            texture_path: Image file path, relative to the source root, or a texture handle; None shows nothing
+            x: X position in screen coordinates
+            y: Y position in screen coordinates
+            width: Width in pixels
+            height: Height in pixels
+            scale_mode: "stretch", "fit", "fill" or "tile"
+            enabled: Whether the image is enabled
+            depth: Rendering depth (higher = in front)
+        """
+        self._component = ImageComponent(asset_path(texture_path), x, y, width, height, scale_mode)
+        self._game_object = None
+        self._engine = None
+        self._object_id = None
+        self._children: list[object] = []
+        self._parent = None
+        self._enabled = enabled
+        self._component.enabled = enabled
+        self._component.set_depth(depth)
+
+    @property
+    def texture(self) -> Optional[str]:
+        """Get the texture path."""
+        return self._component.get_texture()
+
+    @texture.setter
+    def texture(self, texture_path: Union[str, AssetHandle, None]):
+        """Set the texture path or handle, or None to hide the image."""
+        texture_path = asset_path(texture_path)
+        self._component.set_texture(texture_path)
+        if self._engine is not None and self._object_id is not None:
+            self._engine.update_ui_image_texture(self._object_id, texture_path)
+
+    @property
+    def scale_mode(self) -> str:
+        """Get the scale mode."""
+        return self._component.get_scale_mode()
+
+    @scale_mode.setter
+    def scale_mode(self, scale_mode: str):
+        """Set the scale mode: "stretch", "fit", "fill" or "tile"."""
+        self._component.set_scale_mode(scale_mode)
+        if self._engine is not None and self._object_id is not None:
+            self._engine.update_ui_image_scale_mode(self._object_id, scale_mode)
+
+    @property
+    def enabled(self) -> bool:
+        """Get whether the image is enabled."""
+        return self._enabled
+
+    @enabled.setter
+    def enabled(self, value: bool):
+        """Set whether the image is enabled."""
+        self._enabled = value
+        self._component.enabled = value
+
+    @property
+    def id(self) -> Optional[int]:
+        """Get the runtime object id after the image is added."""
+        return self._object_id
+
+    def set_texture_size(self, width: int, height: int):
+        """Set the texture's pixel size; normally read from the file when the image is added."""
+        self._component.set_texture_size(width, height)
+
+    def set_position(self, x: float, y: float):
+        """Set the image position in screen coordinates."""
+        self._component.set_position(x, y)
+
+    def set_size(self, width: float, height: float):
+        """Set the image size in pixels."""
+        self._component.set_size(width, height)
+
+
+def _rgba(color) -> tuple[float, float, float, float]:
+    """Accept an (r, g, b) or (r, g, b, a) color."""
+    color = tuple(float(channel) for channel in color)
+    if len(color) == 3:
+        return (*color, 1.0)
+    if len(color) == 4:
+        return color
+    raise ValueError("color must be (r, g, b) or (r, g, b, a)")
+
+
+def _normalize_padding(padding) -> tuple[float, float, float, float]:
+    """Accept a number, (horizontal, vertical) or (left, right, top, bottom)."""
+    if isinstance(padding, (int, float)):
+        return (padding, padding, padding, padding)
+    padding = tuple(padding)
+    if len(padding) == 2:
+        return (padding[0], padding[0], padding[1], padding[1])
+    if len(padding) == 4:
+        return padding
+    raise ValueError("padding must be a number, (horizontal, vertical) or (left, right, top, bottom)")
+
+
+class Stack:
+    """
+    Invisible container that lines its children up one after another.
+
+    Use `VStack` (top to bottom) or `HStack` (left to right). Children are
+    placed in the order they were added, separated by `spacing` and inset by
+    `padding`; their own x/y are ignored. The stack re-flows every frame, so
+    adding, removing, hiding or resizing a child moves the others. Disabled
+    children take no space.
+
+    `alignment` places children across the stack's axis: "start", "center",
+    "end", or "stretch" to resize them to the stack's inner width (VStack) or
+    height (HStack). With `fit_content=True` the stack resizes along its axis
+    to wrap its children, e.g. to scroll a whole list inside a ScrollView.
+    Stacks draw nothing; put one inside a Panel for a background.
+    """
+
+    _direction = "vertical"
+
+    def __init__(
+        self,
+        x: float = 0,
+        y: float = 0,
+        width: float = 200,
+        height: float = 200,
+        spacing: float = 0,
+        padding=0,
+        alignment: str = "start",
+        fit_content: bool = False,
+        enabled: bool = True,
+        depth: float = 0,
+    ):
+        """
+        Create a new stack.
+
+        Args:
+            x: X position in screen coordinates
+            y: Y position in screen coordinates
+            width: Width in pixels
+            height: Height in pixels
+            spacing: Gap between children in pixels
+            padding: Inset from the edges: a number, (horizontal, vertical)
+                or (left, right, top, bottom)
+            alignment: "start", "center", "end" or "stretch"
+            fit_content: Resize along the stack's axis to wrap the children
+            enabled: Whether the stack and its children are shown
+            depth: Rendering depth (higher = in front); give children a higher depth
+        """
+        self._component = StackComponent(
+            self._direction,
+            x,
+            y,
+            width,
+            height,
+            spacing,
+            _normalize_padding(padding),
+            alignment,
+            fit_content,
+        )
+        self._game_object = None
+        self._engine = None
+        self._ui = None
+        self._object_id = None
+        self._children: list[object] = []
+        self._parent = None
+        self._enabled = enabled
+        self._component.enabled = enabled
+        self._component.set_depth(depth)
+
+    def _sync(self):
+        if self._engine is not None and self._object_id is not None:
+            self._engine.update_ui_stack(
+                self._object_id,
+                self._component.get_direction(),
+                self._component.get_spacing(),
+                self._component.get_padding(),
+                self._component.get_alignment(),
+                self._component.get_fit_content(),
+            )
+
+    @property
+    def spacing(self) -> float:
+        """Get the gap between children in pixels."""
+        return self._component.get_spacing()
+
+    @spacing.setter
+    def spacing(self, value: float):
+        """Set the gap between children in pixels."""
+        self._component.set_spacing(value)
+        self._sync()
+
+    @property
+    def padding(self) -> tuple[float, float, float, float]:
+        """Get the padding as (left, right, top, bottom)."""
+        return self._component.get_padding()
+
+    @padding.setter
+    def padding(self, value):
+        """Set the padding: a number, (horizontal, vertical) or (left, right, top, bottom)."""
+        self._component.set_padding(*_normalize_padding(value))
+        self._sync()
+
+    @property
+    def alignment(self) -> str:
+        """Get the cross-axis alignment."""
+        return self._component.get_alignment()
+
+    @alignment.setter
+    def alignment(self, value: str):
+        """Set the cross-axis alignment: "start", "center", "end" or "stretch"."""
+        self._component.set_alignment(value)
+        self._sync()
+
+    @property
+    def fit_content(self) -> bool:
+        """Get whether the stack resizes to wrap its children."""
+        return self._component.get_fit_content()
+
+    @fit_content.setter
+    def fit_content(self, value: bool):
+        """Set whether the stack resizes along its axis to wrap its children."""
+        self._component.set_fit_content(value)
+        self._sync()
+
+    @property
+    def enabled(self) -> bool:
+        """Get whether the stack is enabled."""
+        return self._enabled
+
+    @enabled.setter
+    def enabled(self, value: bool):
+        """Set whether the stack is enabled."""
+        self._enabled = value
+        self._component.enabled = value
+
+    @property
+    def id(self) -> Optional[int]:
+        """Get the runtime object id after the stack is added."""
+        return self._object_id
+
+    def set_position(self, x: float, y: float):
+        """Set the stack position in screen coordinates."""
+        self._component.set_position(x, y)
+
+    def set_size(self, width: float, height: float):
+        """Set the stack size in pixels."""
+        self._component.set_size(width, height)
+
+    def add_child(self, child):
+        """Append a child UI element; it is added to the engine too if the stack already is."""
+        _attach_child(self, child)
+        return child
+
+    def add_children(self, children):
+        """Append multiple child UI elements."""
+        for child in children:
+            self.add_child(child)
+        return list(children)
+
+    def remove_child(self, child):
+        """Remove a child UI element (and destroy it if it was added); the rest close the gap."""
+        _detach_child(self, child)
+
+    def get_children(self):
+        """Get this stack's direct child UI elements, in order."""
+        return list(self._children)
+
+    def get_child_count(self) -> int:
+        """Get the number of direct child UI elements."""
+        return len(self._children)
+
+
+class VStack(Stack):
+    """
+    Stack that lines its children up from top to bottom.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine, VStack, Button
+
+        engine = Engine()
+        menu = VStack(x=20, y=20, width=200, spacing=8, padding=10, alignment="stretch", fit_content=True)
+        menu.add_children([Button("Play"), Button("Options"), Button("Quit")])
+        engine.ui.add(menu)
+        ```
+    """
+
+    _direction = "vertical"
+
+
+class HStack(Stack):
+    """
+    Stack that lines its children up from left to right.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine, HStack, Image
+
+        engine = Engine()
+        hearts = HStack(x=10, y=10, height=32, spacing=4, fit_content=True)
+        for _ in range(3):
+            hearts.add_child(Image("assets/heart.png", width=32, height=32))
+        engine.ui.add(hearts)
+        ```
+    """
+
+    _direction = "horizontal"
+
+
+class CurveEditor:
+    """
+    A graph editor for tuning an `AnimationCurve` while the game runs.
+
+    Left-click a key to select and drag it, left-click empty space to add a
+    key, and right-click a key (or press Delete with it selected) to remove
+    it. With a key selected, the arrow keys nudge it by 1% of the range.
+
+    The edited curve is kept in sync on the Python side, so game code can
+    sample it every frame with `evaluate()`.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine, CurveEditor, AnimationCurve
+
+        engine = Engine()
+        falloff = CurveEditor(
+            AnimationCurve([(0.0, 1.0), (1.0, 0.0)], interpolation="smooth"),
+            x=20, y=20, width=320, height=180,
+        )
+        engine.ui.add(falloff)
+
+        # Later, e.g. in an update callback:
+        strength = falloff.evaluate(distance / max_distance)
+        ```
+    """
+
+    def __init__(
+        self,
+        curve: Optional[AnimationCurve] = None,
+        x: float = 0,
+        y: float = 0,
+        width: float = 240,
+        height: float = 160,
+        time_range: tuple[float, float] = (0.0, 1.0),
+        value_range: tuple[float, float] = (0.0, 1.0),
+        grid_divisions: int = 4,
+        on_curve_changed: Optional[Callable[[AnimationCurve], None]] = None,
+        enabled: bool = True,
+        depth: float = 0,
+    ):
+        """
+        Create a new curve editor.
+
+        Args:
+            curve: Curve to edit (defaults to an empty curve)
+            x: X position in screen coordinates
+            y: Y position in screen coordinates
+            width: Editor width in pixels
+            height: Editor height in pixels
+            time_range: Visible `(start, end)` time range
+            value_range: Visible `(min, max)` value range
+            grid_divisions: Grid lines per axis, or 0 to hide the grid
+            on_curve_changed: Called with a copy of the curve whenever the user edits it
+            enabled: Whether the editor accepts input
+            depth: Rendering depth (higher = in front)
+        """
+        self._component = CurveEditorComponent(
+            curve, x, y, width, height, time_range, value_range
+        )
+        self._curve = self._component.get_curve()
+        self._game_object = None
+        self._engine = None
+        self._object_id = None
+        self._children: list[object] = []
+        self._parent = None
+        self._enabled = enabled
+        self._on_curve_changed = on_curve_changed
+        self._component.set_grid_divisions(grid_divisions)
+        self._component.enabled = enabled
+        self._component.set_depth(depth)
+        # The engine edits its own copy of the component; this hook keeps
+        # `curve` in sync and forwards to the current user callback.
+        self._component.set_on_curve_changed(self._handle_curve_changed)
+
+    def _handle_curve_changed(self, curve: AnimationCurve):
+        self._curve = curve
+        if self._on_curve_changed is not None:
+            self._on_curve_changed(curve.copy())
+
+    @property
+    def curve(self) -> AnimationCurve:
+        """Get a copy of the current curve, including edits made by the user."""
+        return self._curve.copy()
+
+    @curve.setter
+    def curve(self, curve: AnimationCurve):
+        """Replace the edited curve. Does not fire `on_curve_changed`."""
+        self._component.set_curve(curve)
+        self._curve = self._component.get_curve()
+        if self._engine is not None and self._object_id is not None:
+            self._engine.update_ui_curve(self._object_id, self._curve)
+
+    def evaluate(self, time: float) -> float:
+        """Sample the current curve at `time`."""
+        return self._curve.evaluate(time)
+
+    @property
+    def time_range(self) -> tuple[float, float]:
+        """Get the visible `(start, end)` time range."""
+        return self._component.get_time_range()
+
+    @property
+    def value_range(self) -> tuple[float, float]:
+        """Get the visible `(min, max)` value range."""
+        return self._component.get_value_range()
+
+    @property
+    def enabled(self) -> bool:
+        """Get whether the editor is enabled."""
+        return self._enabled
+
+    @enabled.setter
+    def enabled(self, value: bool):
+        """Set whether the editor is enabled."""
+        self._enabled = value
+        self._component.enabled = value
+
+    @property
+    def id(self) -> Optional[int]:
+        """Get the runtime object id after the editor is added."""
+        return self._object_id
+
+    def set_on_curve_changed(self, callback: Optional[Callable[[AnimationCurve], None]]):
+        """Set the callback fired with a copy of the curve whenever the user edits it."""
+        self._on_curve_changed = callback
+
+    def set_position(self, x: float, y: float):
+        """Set the editor position in screen coordinates."""
+        self._component.set_position(x, y)
+
+    def set_size(self, width: float, height: float):
+        """Set the editor size in pixels."""
+        self._component.set_size(width, height)
+
+
+class RadioGroup:
+    """
+    A set of radio buttons of which at most one is selected.
+
+    Pass the group to each `RadioButton` that belongs to it. When the user
+    selects a button, the others in the group are cleared and
+    `on_selection_changed` is called with the selected button's value.
+    The group itself is not added to the UI; add its buttons instead.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine, RadioButton, RadioGroup, VStack
+
+        engine = Engine()
+        difficulty = RadioGroup(
+            "Difficulty",
+            on_selection_changed=lambda value: print("Difficulty:", value),
+        )
+        options = VStack(x=100, y=100, spacing=4, fit_content=True)
+        options.add_children([
+            RadioButton(name, group=difficulty, selected=name == "Normal")
+            for name in ("Easy", "Normal", "Hard")
+        ])
+        engine.ui.add(options)
+        engine.run()
+        ```
+    """
+
+    def __init__(
+        self,
+        name: str = "",
+        on_selection_changed: Optional[Callable[[str], None]] = None,
+    ):
+        """
+        Create a new radio group.
+
+        Args:
+            name: Name of the group, for debugging
+            on_selection_changed: Called with the selected button's value whenever the user picks a different button
+        """
+        self._native = _NativeRadioGroup(name)
+        self._buttons: list["RadioButton"] = []
+        self._on_selection_changed = on_selection_changed
+        self._native.set_on_selection_changed(self._handle_selection_changed)
+
+    def _handle_selection_changed(self, value: str):
+        selected = next((button for button in self._buttons if button.value == value), None)
+        self._mark_selected(selected)
+        if self._on_selection_changed is not None:
+            self._on_selection_changed(value)
+
+    def _add(self, button: "RadioButton"):
+        self._buttons.append(button)
+        if button.selected:
+            self._mark_selected(button)
+
+    def _mark_selected(self, selected: Optional["RadioButton"]):
+        for button in self._buttons:
+            button._selected = button is selected
+            button._component.set_selected(button._selected)
+
+    @property
+    def name(self) -> str:
+        """Get the group name."""
+        return self._native.name
+
+    @property
+    def buttons(self) -> list["RadioButton"]:
+        """Get the buttons in the group, in the order they were created."""
+        return list(self._buttons)
+
+    @property
+    def selected(self) -> Optional["RadioButton"]:
+        """Get the selected button, or None if nothing is selected."""
+        return next((button for button in self._buttons if button.selected), None)
+
+    @property
+    def value(self) -> Optional[str]:
+        """Get the selected button's value, or None if nothing is selected."""
+        selected = self.selected
+        return selected.value if selected is not None else None
+
+    def select(self, value: str) -> bool:
+        """
+        Select the button with `value`. Does not fire `on_selection_changed`.
+        Returns False if no button in the group has that value.
+        """
+        for button in self._buttons:
+            if button.value == value:
+                button.selected = True
+                return True
+        return False
+
+    def set_on_selection_changed(self, callback: Optional[Callable[[str], None]]):
+        """Set the callback fired with the selected value whenever the user picks a different button."""
+        self._on_selection_changed = callback
+
+
+class RadioButton:
+    """
+    A radio button with a text label, usually one of several in a `RadioGroup`.
+
+    Clicking the button or its label selects it; Space or Enter selects it
+    after it has been clicked. Selecting a button clears the rest of its
+    group, and clicking a selected button does nothing.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine, RadioButton, RadioGroup
+
+        engine = Engine()
+        mode = RadioGroup("Mode")
+        engine.ui.add(RadioButton("Windowed", group=mode, selected=True, x=100, y=100))
+        engine.ui.add(RadioButton("Fullscreen", group=mode, x=100, y=130))
+        engine.run()
+        ```
+    """
+
+    def __init__(
+        self,
+        label: str = "",
+        value: Optional[str] = None,
+        group: Optional[RadioGroup] = None,
+        selected: bool = False,
+        x: float = 0,
+        y: float = 0,
+        width: float = 160,
+        height: float = 24,
+        font_size: Optional[float] = None,
+        enabled: bool = True,
+        depth: float = 0,
+    ):
+        """
+        Create a new radio button.
+
+        Args:
+            label: Text shown to the right of the button
+            value: Value reported to the group when selected (defaults to the label)
+            group: Group the button belongs to
+            selected: Initial state; selecting clears the rest of the group
+            x: X position in screen coordinates
+            y: Y position in screen coordinates
+            width: Total width in pixels, including the label
+            height: Height in pixels
+            font_size: Label font size in pixels
+            enabled: Whether the button accepts input
+            depth: Rendering depth (higher = in front)
+        """
+        self._value = label if value is None else value
+        self._component = RadioButtonComponent(
+            label,
+            self._value,
+            group._native if group is not None else None,
+            selected,
+            x,
+            y,
+            width,
+            height,
+        )
+        self._selected = selected
+        self._group = group
+        self._game_object = None
+        self._engine = None
+        self._object_id = None
+        self._children: list[object] = []
+        self._parent = None
+        self._enabled = enabled
+        self._component.enabled = enabled
+        self._component.set_depth(depth)
+        if font_size is not None:
+            self._component.set_font_size(font_size)
+        if group is not None:
+            group._add(self)
+
+    @property
+    def selected(self) -> bool:
+        """Get whether the button is selected, including changes made by the user."""
+        return self._selected
+
+    @selected.setter
+    def selected(self, value: bool):
+        """Set the state. Selecting clears the rest of the group without firing `on_selection_changed`."""
+        value = bool(value)
+        if value and self._group is not None:
+            self._group._mark_selected(self)
+        else:
+            self._selected = value
+            self._component.set_selected(value)
+        if self._engine is not None and self._object_id is not None:
+            self._engine.update_ui_checked(self._object_id, value)
+
+    @property
+    def value(self) -> str:
+        """Get the value reported to the group when this button is selected."""
+        return self._value
+
+    @property
+    def group(self) -> Optional[RadioGroup]:
+        """Get the group the button belongs to."""
+        return self._group
+
+    @property
+    def label(self) -> str:
+        """Get the label text."""
+        return self._component.get_label()
+
+    @property
+    def enabled(self) -> bool:
+        """Get whether the button is enabled."""
+        return self._enabled
+
+    @enabled.setter
+    def enabled(self, value: bool):
+        """Set whether the button is enabled."""
+        self._enabled = value
+        self._component.enabled = value
+
+    @property
+    def id(self) -> Optional[int]:
+        """Get the runtime object id after the button is added."""
+        return self._object_id
+
+    def set_position(self, x: float, y: float):
+        """Set the button position in screen coordinates."""
+        self._component.set_position(x, y)
+
+    def set_size(self, width: float, height: float):
+        """Set the button size in pixels."""
+        self._component.set_size(width, height)
+
+
+class ListView:
+    """
+    A list that shows thousands of rows by only creating the visible ones.
+
+    The list asks `get_item(index)` for each row that scrolls into view and
+    for nothing else. Return a string to show it as the row's text, or a UI
+    element (a Button, HStack, ...) to place it in the row; it is positioned
+    and sized to fill the row. Row elements are added when their row scrolls
+    into view and destroyed when it scrolls out, so create them in
+    `get_item` rather than keeping them around. Give them a higher `depth`
+    than the list.
+
+    Text rows work in every run mode. Row elements are added after the
+    native update, so they need `engine.run(update=...)` or manual
+    `engine.update()` calls, like `engine.timers`.
+
+    Click a row to select it, or use the arrow, Page Up/Down, Home and End
+    keys after clicking the list.
+
+    **Example:**
+
+        ```python
+        from pyg_engine import Engine, ListView
+
+        engine = Engine()
+        scores = [(f"Player {i}", 10_000 - i) for i in range(10_000)]
+
+        board = ListView(
+            lambda index: f"{index + 1}. {scores[index][0]}: {scores[index][1]}",
+            item_count=len(scores),
+            x=20,
+            y=20,
+            width=320,
+            height=400,
+            on_selection_changed=lambda index: print("picked", scores[index][0]),
+        )
+        engine.ui.add(board)
+        engine.run(update=lambda ctx: None)
+        ```
+    """
+
+    def __init__(
+        self,
+        get_item: Optional[Callable[[int], object]] = None,
+        item_count: int = 0,
+        x: float = 0,
+        y: float = 0,
+        width: float = 200,
+        height: float = 200,
+        row_height: float = 24,
+        on_selection_changed: Optional[Callable[[int], None]] = None,
+        font_size: Optional[float] = None,
+        enabled: bool = True,
+        depth: float = 0,
+    ):
+        """
+        Create a new list view.
+
+        Args:
+            get_item: Called with a row index; returns the row's text or a UI element
+            item_count: Number of rows
+            x: X position in screen coordinates
+            y: Y position in screen coordinates
+            width: Width in pixels
+            height: Visible height in pixels
+            row_height: Height of every row in pixels
+            on_selection_changed: Called with the row index whenever the user selects a different row
+            font_size: Row text font size in pixels
+            enabled: Whether the list accepts input
+            depth: Rendering depth (higher = in front); give row elements a higher depth
+        """
+        if item_count < 0:
+            raise ValueError("item_count must be >= 0")
+        self._component = ListViewComponent(item_count, row_height, x, y, width, height)
+        self._get_item = get_item
+        self._item_count = item_count
+        self._row_height = self._component.get_row_height()
+        self._selected: Optional[int] = None
+        self._visible = (0, 0)
+        # Items returned by `get_item` since the last sync: a UI element, or
+        # None for text rows.
+        self._fetched: dict[int, object] = {}
+        self._row_elements: dict[int, object] = {}
+        self._game_object = None
+        self._engine = None
+        self._ui = None
+        self._object_id = None
+        self._children: list[object] = []
+        self._parent = None
+        self._enabled = enabled
+        self._on_selection_changed = on_selection_changed
+        self._component.enabled = enabled
+        self._component.set_depth(depth)
+        if font_size is not None:
+            self._component.set_font_size(font_size)
+        # The engine drives its own copy of the component; these hooks keep
+        # this wrapper's selection and row elements in sync with it.
+        self._component.set_item_provider(self._provide_item)
+        self._component.set_on_selection_changed(self._handle_selection_changed)
+        self._component.set_on_visible_rows_changed(self._handle_visible_rows_changed)
+
+    def _provide_item(self, index: int) -> Optional[str]:
+        if self._get_item is None:
+            return None
+        item = self._get_item(index)
+        if isinstance(item, str):
+            self._fetched[index] = None
+            return item
+        self._fetched[index] = item
+        return None
+
+    def _handle_selection_changed(self, index: int):
+        self._selected = index
+        if self._on_selection_changed is not None:
+            self._on_selection_changed(index)
+
+    def _handle_visible_rows_changed(self, first: int, end: int):
+        self._visible = (first, end)
+        if self._ui is not None:
+            self._ui._pending_list_views.add(self)
+
+    def _sync_row_elements(self):
+        """Add row elements that scrolled into view and destroy the ones that left."""
+        first, end = self._visible
+        fetched, self._fetched = self._fetched, {}
+        for index, element in list(self._row_elements.items()):
+            if first <= index < end and fetched.get(index, element) is element:
+                continue
+            del self._row_elements[index]
+            if element in self._children:
+                _detach_child(self, element)
+
+        for index, element in fetched.items():
+            if element is None or not first <= index < end or index in self._row_elements:
+                continue
+            element._layout = ("top_left", 0, index * self._row_height, "100%", self._row_height, None)
+            _attach_child(self, element)
+            self._row_elements[index] = element
+
+    @property
+    def item_count(self) -> int:
+        """Get the number of rows."""
+        return self._item_count
+
+    @item_count.setter
+    def item_count(self, value: int):
+        """Set the number of rows. A selection past the new end is cleared."""
+        if value < 0:
+            raise ValueError("item_count must be >= 0")
+        self._item_count = value
+        if self._selected is not None and self._selected >= value:
+            self._selected = None
+        self._component.set_item_count(value)
+        if self._engine is not None and self._object_id is not None:
+            self._engine.update_ui_list_item_count(self._object_id, value)
+
+    @property
+    def selected(self) -> Optional[int]:
+        """Get the selected row index, including changes made by the user, or None."""
+        return self._selected
+
+    @selected.setter
+    def selected(self, index: Optional[int]):
+        """Select a row and scroll it into view, or clear the selection with None. Does not fire `on_selection_changed`."""
+        if index is not None and not 0 <= index < self._item_count:
+            index = None
+        self._selected = index
+        self._component.set_selected(index)
+        if self._engine is not None and self._object_id is not None:
+            self._engine.update_ui_list_selection(self._object_id, index)
+
+    @property
+    def row_height(self) -> float:
+        """Get the height of every row in pixels."""
+        return self._row_height
+
+    @property
+    def visible_range(self) -> tuple[int, int]:
+        """Get the visible rows as `(first, end)`, `end` excluded, as of the last update."""
+        return self._visible
+
+    @property
+    def enabled(self) -> bool:
+        """Get whether the list is enabled."""
+        return self._enabled
+
+    @enabled.setter
+    def enabled(self, value: bool):
+        """Set whether the list is enabled."""
+        self._enabled = value
+        self._component.enabled = value
+
+    @property
+    def id(self) -> Optional[int]:
+        """Get the runtime object id after the list is added."""
+        return self._object_id
+
+    def refresh(self):
+        """Ask `get_item` for every visible row again, e.g. after the data changed."""
+        self._component.refresh()
+        if self._engine is not None and self._object_id is not None:
+            self._engine.refresh_ui_list(self._object_id)
+
+    def scroll_to_item(self, index: int):
+        """Scroll so row `index` is at the top, or as close as the list allows."""
+        offset = max(0, index) * self._row_height
+        self._component.set_scroll_offset(offset)
+        if self._engine is not None and self._object_id is not None:
+            self._engine.update_ui_scroll_position(self._object_id, 0.0, offset)
+
+    def set_item_provider(self, get_item: Optional[Callable[[int], object]]):
+        """Replace `get_item` and fetch the visible rows again."""
+        self._get_item = get_item
+        self.refresh()
+
+    def set_on_selection_changed(self, callback: Optional[Callable[[int], None]]):
+        """Set the callback fired with the row index whenever the user selects a different row."""
+        self._on_selection_changed = callback
+
+    def set_background_color(self, r: float, g: float, b: float, a: float = 1.0):
+        """Set the background color."""
+        self._component.set_background_color(r, g, b, a)
+
+    def set_text_color(self, r: float, g: float, b: float, a: float = 1.0):
+        """Set the row text color."""
+        self._component.set_text_color(r, g, b, a)
+
+    def set_border(self, width: float, r: float, g: float, b: float, a: float = 1.0):
+        """Set the border width and color; the scrollbar uses the same color."""
+        self._component.set_border(width, r, g, b, a)
+
+    def set_hover_color(self, r: float, g: float, b: float, a: float = 1.0):
+        """Set the background of the row under the mouse."""
+        self._component.set_hover_color(r, g, b, a)
+
+    def set_selection_color(self, r: float, g: float, b: float, a: float = 1.0):
+        """Set the background of the selected row."""
+        self._component.set_selection_color(r, g, b, a)
+
+    def set_selected_text_color(self, r: float, g: float, b: float, a: float = 1.0):
+        """Set the text color of the selected row."""
+        self._component.set_selected_text_color(r, g, b, a)
+
+    def set_scroll_speed(self, speed: float):
+        """Set how many pixels one mouse wheel step scrolls."""
+        self._component.set_scroll_speed(speed)
+
+    def set_show_scrollbar(self, show: bool):
+        """Show or hide the scrollbar thumb."""
+        self._component.set_show_scrollbar(show)
+
+    def set_font_size(self, font_size: float):
+        """Set the row text font size in pixels."""
+        self._component.set_font_size(font_size)
+
+    def set_position(self, x: float, y: float):
+        """Set the list position in screen coordinates."""
+        self._component.set_position(x, y)
+
+    def set_size(self, width: float, height: float):
+        """Set the list size in pixels."""
+        self._component.set_size(width, height)
+
+    def get_children(self):
+        """Get the row elements currently in view."""
+        return list(self._children)
@@ -0,0 +1,283 @@
+"""
+Declarative UI documents.
+
+`engine.ui.load("menu.json")` builds a whole widget tree from a JSON (or YAML)
+document and returns a `UIDocument` that maps the ids given in the document to
+the created widgets, so callbacks can be wired up by id.
+
+With `engine.assets.hot_reload` on, documents loaded from a file are rebuilt
+when the file changes.
+"""
+
+from __future__ import annotations
+
+import json
+import os
+from collections.abc import Callable, Iterator, Mapping
+from pathlib import Path
+from typing import TYPE_CHECKING, Any, Optional, Union
+
+from . import ui
+
+if TYPE_CHECKING:
+    from .engine import UIManager
+
+_DOCUMENT_VERSION = 1
+
+_WIDGET_TYPES: dict[str, type] = {
+    "button": ui.Button,
+    "panel": ui.Panel,
+    "label": ui.Label,
+    "text_input": ui.TextInput,
+    "slider": ui.Slider,
+    "checkbox": ui.Checkbox,
+    "toggle_switch": ui.ToggleSwitch,
+    "radio_button": ui.RadioButton,
+    "scroll_view": ui.ScrollView,
+    "progress_bar": ui.ProgressBar,
+    "image": ui.Image,
+    "vstack": ui.VStack,
+    "hstack": ui.HStack,
+    "curve_editor": ui.CurveEditor,
+    "list_view": ui.ListView,
+}
+
+# Keys handled by the loader instead of being passed to the widget's constructor.
+_RESERVED_KEYS = {"type", "id", "children", "style", "layout"}
+
+
+class UIDocument(Mapping[str, Any]):
+    """
+    Widgets built from a UI document, looked up by the ids given in it.
+
+    **Example:**
+
+        ```python
+        menu = engine.ui.load("ui/main_menu.json")
+        menu["play"].set_on_click(start_game)
+        menu["volume"].set_on_value_changed(set_volume)
+        menu["title"].text = "Paused"
+        ```
+
+    When hot reload rebuilds the document, the new widgets replace the old
+    ones under the same ids. Callbacks named in the document are looked up in
+    `callbacks` again; wiring done in code belongs in `set_on_reload()`.
+    """
+
+    def __init__(self, roots: list[Any], widgets: dict[str, Any]) -> None:
+        self._roots = roots
+        self._widgets = widgets
+        self._path: Optional[Path] = None
+        self._mtime = 0.0
+        self._callbacks: Mapping[str, Callable[..., Any]] = {}
+        self._added = False
+        self._on_reload: Optional[Callable[["UIDocument"], None]] = None
+
+    @property
+    def roots(self) -> list[Any]:
+        """Get the top-level widgets, in document order."""
+        return list(self._roots)
+
+    @property
+    def path(self) -> Optional[Path]:
+        """Get the file the document was loaded from, or None for a parsed document."""
+        return self._path
+
+    def set_on_reload(self, callback: Optional[Callable[["UIDocument"], None]]) -> None:
+        """Call `callback(document)` after hot reload rebuilds the widgets."""
+        self._on_reload = callback
+
+    def _watch(self, path: Path, callbacks: Mapping[str, Callable[..., Any]], added: bool) -> None:
+        self._path = path
+        self._mtime = _mtime(path)
+        self._callbacks = callbacks
+        self._added = added
+
+    def _changed(self) -> bool:
+        if self._path is None:
+            return False
+        modified = _mtime(self._path)
+        if modified == self._mtime:
+            return False
+        self._mtime = modified
+        return True
+
+    def _reload(self, ui_manager: "UIManager") -> bool:
+        """Rebuild the widgets from the file. A broken file keeps the old widgets."""
+        try:
+            rebuilt = build_document(ui_manager, read_document(self._path), self._callbacks)
+        except Exception as exc:
+            # Keep the old widgets until the file is fixed.
+            ui_manager._engine.log_warn(f"UI document reload of '{self._path}' failed: {exc!r}")
+            return False
+
+        if self._added:
+            for root in self._roots:
+                object_id = getattr(root, "_object_id", None)
+                if object_id is not None:
+                    ui_manager._engine.remove_game_object(object_id)
+                    root._object_id = None
+                    root._game_object = None
+            for root in rebuilt._roots:
+                ui_manager.add(root)
+        self._roots = rebuilt._roots
+        self._widgets = rebuilt._widgets
+        if self._on_reload is not None:
+            self._on_reload(self)
+        return True
+
+    def __getitem__(self, widget_id: str) -> Any:
+        try:
+            return self._widgets[widget_id]
+        except KeyError:
+            raise KeyError(f"No UI element with id {widget_id!r} in the document") from None
+
+    def __iter__(self) -> Iterator[str]:
+        return iter(self._widgets)
+
+    def __len__(self) -> int:
+        return len(self._widgets)
+
+    def __repr__(self) -> str:
+        return f"UIDocument(ids={list(self._widgets)!r})"
+
+
+def read_document(path: Union[str, Path]) -> Any:
+    """Parse a UI document file; `.yaml` and `.yml` files need PyYAML."""
+    path = Path(path)
+    with open(path, encoding="utf-8") as file:
+        if path.suffix.lower() in (".yaml", ".yml"):
+            try:
+                import yaml
+            except ImportError as exc:
+                raise ImportError(
+                    "Loading YAML UI documents requires PyYAML (pip install pyyaml)"
+                ) from exc
+            return yaml.safe_load(file)
+        return json.load(file)
+
+
+def _mtime(path: Path) -> float:
+    try:
+        return os.stat(path).st_mtime
+    except OSError:
+        return 0.0
+
+
+def build_document(
+    ui_manager: "UIManager",
+    document: Any,
+    callbacks: Optional[Mapping[str, Callable[..., Any]]] = None,
+) -> UIDocument:
+    """Create the widgets described by a parsed document without adding them."""
+    builder = _Builder(ui_manager, callbacks or {})
+    roots = [
+        builder.build(spec, f"widgets[{index}]")
+        for index, spec in enumerate(_root_specs(document))
+    ]
+    return UIDocument(roots, builder.widgets)
+
+
+def _root_specs(document: Any) -> list[Any]:
+    if isinstance(document, list):
+        return document
+    if not isinstance(document, dict):
+        raise ValueError("A UI document must be an object or a list of widgets")
+    if "type" in document:
+        return [document]
+    version = document.get("version", _DOCUMENT_VERSION)
+    if version != _DOCUMENT_VERSION:
+        raise ValueError(f"Unsupported UI document version: {version!r}")
+    widgets = document.get("widgets")
+    if not isinstance(widgets, list):
+        raise ValueError("A UI document needs a 'widgets' list")
+    return widgets
+
+
+def _is_callback_key(key: str) -> bool:
+    return key.startswith("on_") or key == "get_item"
+
+
+class _Builder:
+    def __init__(
+        self,
+        ui_manager: "UIManager",
+        callbacks: Mapping[str, Callable[..., Any]],
+    ) -> None:
+        self._ui_manager = ui_manager
+        self._callbacks = callbacks
+        self._radio_groups: dict[str, ui.RadioGroup] = {}
+        self.widgets: dict[str, Any] = {}
+
+    def build(self, spec: Any, where: str) -> Any:
+        if not isinstance(spec, dict):
+            raise ValueError(f"{where}: a widget must be an object")
+        widget_id = spec.get("id")
+        if widget_id is not None:
+            where = f"{where} ({widget_id!r})"
+            if widget_id in self.widgets:
+                raise ValueError(f"{where}: duplicate id")
+
+        widget_type = spec.get("type")
+        cls = _WIDGET_TYPES.get(widget_type)
+        if cls is None:
+            raise ValueError(
+                f"{where}: unknown widget type {widget_type!r}, expected one of "
+                f"{', '.join(_WIDGET_TYPES)}"
+            )
+
+        kwargs = {
+            key: self._resolve(key, value, where)
+            for key, value in spec.items()
+            if key not in _RESERVED_KEYS
+        }
+        try:
+            widget = cls(**kwargs)
+        except TypeError as exc:
+            raise ValueError(f"{where}: {exc}") from exc
+
+        for name, value in (spec.get("style") or {}).items():
+            self._apply_style(widget, name, value, where)
+
+        layout = spec.get("layout")
+        if layout is not None:
+            layout = {
+                key: tuple(value) if isinstance(value, list) else value
+                for key, value in layout.items()
+            }
+            self._ui_manager.set_layout(widget, **layout)
+
+        children = spec.get("children") or []
+        if children and not hasattr(widget, "add_child"):
+            raise ValueError(f"{where}: a {widget_type} cannot have children")
+        for index, child_spec in enumerate(children):
+            widget.add_child(self.build(child_spec, f"{where}.children[{index}]"))
+
+        if widget_id is not None:
+            self.widgets[widget_id] = widget
+        return widget
+
+    def _resolve(self, key: str, value: Any, where: str) -> Any:
+        if _is_callback_key(key) and isinstance(value, str):
+            try:
+                return self._callbacks[value]
+            except KeyError:
+                raise ValueError(f"{where}: unknown callback {value!r} for '{key}'") from None
+        if key == "group" and isinstance(value, str):
+            if value not in self._radio_groups:
+                self._radio_groups[value] = ui.RadioGroup(value)
+            return self._radio_groups[value]
+        if isinstance(value, list):
+            return tuple(value)
+        return value
+
+    def _apply_style(self, widget: Any, name: str, value: Any, where: str) -> None:
+        setter = getattr(widget, f"set_{name}", None)
+        if setter is None:
+            raise ValueError(f"{where}: {type(widget).__name__} has no style '{name}'")
+        if isinstance(value, list):
+            setter(*value)
+        elif isinstance(value, dict):
+            setter(**value)
+        else:
+            setter(value)
@@ -0,0 +1,593 @@
+use super::engine_bind::ComponentRuntimeBinding;
+use crate::core::audio::{
+    Attenuation, AttenuationCurve, AudioListenerComponent, AudioSnapshot, AudioSourceComponent,
+    Bus, BusOverrides, ImpactKey, PcmFormat,
+};
+use crate::core::command::EngineCommand;
+use crate::core::component::ComponentTrait;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+// ========== Audio Bindings ==========
+
+/// Sound file path from a path string or an `AssetHandle`.
+pub(crate) fn sound_path(sound: &Bound<'_, PyAny>) -> PyResult<String> {
+    if let Ok(path) = sound.extract::<String>() {
+        return Ok(path);
+    }
+    if let Ok(path) = sound
+        .getattr("path")
+        .and_then(|path| path.extract::<String>())
+    {
+        return Ok(path);
+    }
+    Err(PyTypeError::new_err(
+        "sound must be a file path or an AssetHandle",
+    ))
+}
+
+/// Build distance attenuation from its Python arguments.
+pub(crate) fn parse_attenuation(
+    curve: &str,
+    min_distance: f32,
+    max_distance: f32,
+    rolloff: f32,
+) -> PyResult<Attenuation> {
+    let curve = AttenuationCurve::from_name(curve).ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "Unknown attenuation '{curve}', expected 'linear', 'inverse', 'exponential' or 'none'"
+        ))
+    })?;
+    if min_distance < 0.0 || max_distance < min_distance {
+        return Err(PyValueError::new_err(
+            "expected 0 <= min_distance <= max_distance",
+        ));
+    }
+    if rolloff < 0.0 {
+        return Err(PyValueError::new_err("rolloff must be >= 0.0"));
+    }
+    Ok(Attenuation {
+        curve,
+        min_distance,
+        max_distance,
+        rolloff,
+    })
+}
+
+/// Mixer bus from its name.
+pub(crate) fn parse_bus(name: &str) -> PyResult<Bus> {
+    Bus::from_name(name).ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "Unknown bus '{name}', expected 'master', 'music', 'sfx' or 'ui'"
+        ))
+    })
+}
+
+/// Raw PCM sample layout from its name.
+pub(crate) fn parse_pcm_format(name: &str) -> PyResult<PcmFormat> {
+    PcmFormat::from_name(name).ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "Unknown sample format '{name}', expected 'int16' or 'float32'"
+        ))
+    })
+}
+
+/// Check a playback rate.
+pub(crate) fn check_pitch(pitch: f32) -> PyResult<()> {
+    if pitch <= 0.0 {
+        return Err(PyValueError::new_err("pitch must be > 0.0"));
+    }
+    Ok(())
+}
+
+/// Check a `(min, max)` range to pick random pitches from.
+pub(crate) fn check_pitch_range(range: Option<(f32, f32)>) -> PyResult<Option<(f32, f32)>> {
+    if let Some((min, max)) = range
+        && (min <= 0.0 || max < min)
+    {
+        return Err(PyValueError::new_err(
+            "pitch_range must be (min, max) with 0 < min <= max",
+        ));
+    }
+    Ok(range)
+}
+
+/// Check bus settings shared by `set_audio_bus` and snapshots.
+pub(crate) fn check_bus_values(
+    volume: f32,
+    low_pass: Option<f32>,
+    reverb_send: f32,
+) -> PyResult<()> {
+    if volume < 0.0 {
+        return Err(PyValueError::new_err("volume must be >= 0.0"));
+    }
+    if low_pass.is_some_and(|cutoff| cutoff <= 0.0) {
+        return Err(PyValueError::new_err("low_pass must be > 0.0 Hz or None"));
+    }
+    if !(0.0..=1.0).contains(&reverb_send) {
+        return Err(PyValueError::new_err(
+            "reverb_send must be between 0.0 and 1.0",
+        ));
+    }
+    Ok(())
+}
+
+/// Snapshot from `{bus: {setting: value}}`, with the settings of `get_audio_bus`.
+pub(crate) fn parse_snapshot(
+    buses: HashMap<String, HashMap<String, Bound<'_, PyAny>>>,
+) -> PyResult<AudioSnapshot> {
+    let mut snapshot = AudioSnapshot::new();
+    for (bus, values) in buses {
+        let bus = parse_bus(&bus)?;
+        let mut overrides = BusOverrides::default();
+        for (setting, value) in values {
+            match setting.as_str() {
+                "volume" => overrides.volume = Some(value.extract()?),
+                "muted" => overrides.muted = Some(value.extract()?),
+                "low_pass" => overrides.low_pass = Some(value.extract()?),
+                "reverb_send" => overrides.reverb_send = Some(value.extract()?),
+                other => {
+                    return Err(PyValueError::new_err(format!(
+                        "Unknown bus setting '{other}', expected 'volume', 'muted', 'low_pass' or 'reverb_send'"
+                    )));
+                }
+            }
+        }
+        check_bus_values(
+            overrides.volume.unwrap_or(0.0),
+            overrides.low_pass.flatten(),
+            overrides.reverb_send.unwrap_or(0.0),
+        )?;
+        snapshot.set_bus(bus, overrides);
+    }
+    Ok(snapshot)
+}
+
+/// Impact sound key from a pair of physics layers or a pair of materials;
+/// exactly one of them must be given.
+pub(crate) fn impact_key(
+    layers: Option<(u32, u32)>,
+    materials: Option<(String, String)>,
+) -> PyResult<ImpactKey> {
+    match (layers, materials) {
+        (Some((a, b)), None) if a < 32 && b < 32 => Ok(ImpactKey::layers(a, b)),
+        (Some(_), None) => Err(PyValueError::new_err("layers must be between 0 and 31")),
+        (None, Some((a, b))) => Ok(ImpactKey::materials(a, b)),
+        _ => Err(PyValueError::new_err(
+            "pass either layers=(a, b) or materials=(a, b)",
+        )),
+    }
+}
+
+/// Check the sample rate and channel count of generated samples.
+pub(crate) fn check_stream_layout(sample_rate: u32, channels: u16) -> PyResult<()> {
+    if sample_rate == 0 {
+        return Err(PyValueError::new_err("sample_rate must be > 0"));
+    }
+    if !(1..=2).contains(&channels) {
+        return Err(PyValueError::new_err("channels must be 1 or 2"));
+    }
+    Ok(())
+}
+
+/// Plays a sound from its `GameObject`'s position.
+///
+/// The sound's volume falls off with the object's distance from the listener
+/// (an object with an `AudioListener`, or the camera without one) along the
+/// `attenuation` curve, and it pans toward the side the object is on. With
+/// `spatial=False` it plays at `volume`, centered.
+///
+/// Attenuation curves, full volume within `min_distance`:
+/// - `"linear"` (default): fades to silence at `max_distance`
+/// - `"inverse"`: `min / (min + rolloff * (d - min))`, held past `max_distance`
+/// - `"exponential"`: `(d / min) ** -rolloff`, held past `max_distance`
+/// - `"none"`: same volume at any distance
+///
+/// The sound plays through the `bus` mixer bus, `"sfx"` by default. `pitch`
+/// sets its playback rate; with a `pitch_range` of `(min, max)` each `play()`
+/// picks a random pitch from it instead, so repeated sounds don't sound
+/// identical. When more sounds play than the engine's voice limit, those
+/// with the lowest `priority` are cut off first.
+///
+/// Sounds are WAV files. `play()` and `stop()` on a source reach the engine
+/// once its object is in the engine: use the source returned by
+/// `get_component("AudioSource")`, or one added to an object already added.
+///
+/// # Example
+/// ```python
+/// campfire = pyg.GameObject("Campfire")
+/// campfire.add_component(pyg.AudioSourceComponent(
+///     "sounds/fire.wav", looping=True, autoplay=True, attenuation="inverse", min_distance=50,
+/// ))
+/// engine.add_game_object(campfire)
+/// ```
+#[pyclass(name = "AudioSourceComponent", unsendable)]
+#[derive(Clone)]
+pub struct PyAudioSourceComponent {
+    pub(crate) inner: AudioSourceComponent,
+    pub(crate) runtime_binding: RefCell<Option<ComponentRuntimeBinding>>,
+}
+
+impl PyAudioSourceComponent {
+    fn sync_runtime(&self) {
+        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::SetAudioSourceComponent {
+                object_id: binding.object_id,
+                component_id: binding.component_id,
+                component: self.inner.clone(),
+            });
+        }
+    }
+
+    fn set_attenuation(&mut self, attenuation: Attenuation) {
+        *self.inner.attenuation_mut() = attenuation;
+        self.sync_runtime();
+    }
+}
+
+#[pymethods]
+impl PyAudioSourceComponent {
+    #[new]
+    #[pyo3(signature = (
+        sound=None,
+        volume=1.0,
+        looping=false,
+        spatial=true,
+        attenuation="linear",
+        min_distance=100.0,
+        max_distance=1000.0,
+        rolloff=1.0,
+        bus="sfx",
+        pitch=1.0,
+        pitch_range=None,
+        priority=0,
+        autoplay=false,
+        name=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        sound: Option<&Bound<'_, PyAny>>,
+        volume: f32,
+        looping: bool,
+        spatial: bool,
+        attenuation: &str,
+        min_distance: f32,
+        max_distance: f32,
+        rolloff: f32,
+        bus: &str,
+        pitch: f32,
+        pitch_range: Option<(f32, f32)>,
+        priority: i32,
+        autoplay: bool,
+        name: Option<String>,
+    ) -> PyResult<Self> {
+        let sound = sound.map(sound_path).transpose()?;
+        let mut component =
+            AudioSourceComponent::new(name.unwrap_or_else(|| "Audio Source".to_string()))
+                .with_sound(sound)
+                .with_volume(volume)
+                .with_looping(looping)
+                .with_spatial(spatial)
+                .with_attenuation(parse_attenuation(
+                    attenuation,
+                    min_distance,
+                    max_distance,
+                    rolloff,
+                )?)
+                .with_bus(parse_bus(bus)?)
+                .with_pitch_range(check_pitch_range(pitch_range)?)
+                .with_priority(priority);
+        check_pitch(pitch)?;
+        component.set_pitch(pitch);
+        if autoplay {
+            component.play();
+        }
+        Ok(Self {
+            inner: component,
+            runtime_binding: RefCell::new(None),
+        })
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    /// Sound file path, or None.
+    #[getter]
+    fn sound(&self) -> Option<String> {
+        self.inner.sound().map(str::to_string)
+    }
+
+    /// Set the sound from a path or an `AssetHandle`. Takes effect on the next `play()`.
+    #[setter]
+    fn set_sound(&mut self, sound: Option<&Bound<'_, PyAny>>) -> PyResult<()> {
+        self.inner.set_sound(sound.map(sound_path).transpose()?);
+        self.sync_runtime();
+        Ok(())
+    }
+
+    /// Volume before distance attenuation, 1.0 as recorded.
+    #[getter]
+    fn volume(&self) -> f32 {
+        self.inner.volume()
+    }
+
+    #[setter]
+    fn set_volume(&mut self, volume: f32) {
+        self.inner.set_volume(volume);
+        self.sync_runtime();
+    }
+
+    #[getter]
+    fn looping(&self) -> bool {
+        self.inner.looping()
+    }
+
+    #[setter]
+    fn set_looping(&mut self, looping: bool) {
+        self.inner.set_looping(looping);
+        self.sync_runtime();
+    }
+
+    /// Whether volume and pan follow the object's position.
+    #[getter]
+    fn spatial(&self) -> bool {
+        self.inner.spatial()
+    }
+
+    #[setter]
+    fn set_spatial(&mut self, spatial: bool) {
+        self.inner.set_spatial(spatial);
+        self.sync_runtime();
+    }
+
+    /// "linear", "inverse", "exponential" or "none".
+    #[getter]
+    fn attenuation(&self) -> &'static str {
+        self.inner.attenuation().curve.name()
+    }
+
+    #[setter(attenuation)]
+    fn set_attenuation_curve(&mut self, curve: &str) -> PyResult<()> {
+        let current = *self.inner.attenuation();
+        let attenuation = parse_attenuation(
+            curve,
+            current.min_distance,
+            current.max_distance,
+            current.rolloff,
+        )?;
+        self.set_attenuation(attenuation);
+        Ok(())
+    }
+
+    /// Distance within which the sound plays at full volume.
+    #[getter]
+    fn min_distance(&self) -> f32 {
+        self.inner.attenuation().min_distance
+    }
+
+    #[setter]
+    fn set_min_distance(&mut self, min_distance: f32) -> PyResult<()> {
+        let current = *self.inner.attenuation();
+        let attenuation = parse_attenuation(
+            current.curve.name(),
+            min_distance,
+            current.max_distance,
+            current.rolloff,
+        )?;
+        self.set_attenuation(attenuation);
+        Ok(())
+    }
+
+    /// Distance where linear attenuation reaches silence and the other curves stop falling.
+    #[getter]
+    fn max_distance(&self) -> f32 {
+        self.inner.attenuation().max_distance
+    }
+
+    #[setter]
+    fn set_max_distance(&mut self, max_distance: f32) -> PyResult<()> {
+        let current = *self.inner.attenuation();
+        let attenuation = parse_attenuation(
+            current.curve.name(),
+            current.min_distance,
+            max_distance,
+            current.rolloff,
+        )?;
+        self.set_attenuation(attenuation);
+        Ok(())
+    }
+
+    /// Steepness of the falloff, 1.0 for the curve's natural shape.
+    #[getter]
+    fn rolloff(&self) -> f32 {
+        self.inner.attenuation().rolloff
+    }
+
+    #[setter]
+    fn set_rolloff(&mut self, rolloff: f32) -> PyResult<()> {
+        let current = *self.inner.attenuation();
+        let attenuation = parse_attenuation(
+            current.curve.name(),
+            current.min_distance,
+            current.max_distance,
+            rolloff,
+        )?;
+        self.set_attenuation(attenuation);
+        Ok(())
+    }
+
+    /// Mixer bus: "master", "music", "sfx" or "ui".
+    #[getter]
+    fn bus(&self) -> &'static str {
+        self.inner.bus().name()
+    }
+
+    #[setter]
+    fn set_bus(&mut self, bus: &str) -> PyResult<()> {
+        self.inner.set_bus(parse_bus(bus)?);
+        self.sync_runtime();
+        Ok(())
+    }
+
+    /// Playback rate, 1.0 as recorded. Ignored while `pitch_range` is set.
+    #[getter]
+    fn pitch(&self) -> f32 {
+        self.inner.pitch()
+    }
+
+    #[setter]
+    fn set_pitch(&mut self, pitch: f32) -> PyResult<()> {
+        check_pitch(pitch)?;
+        self.inner.set_pitch(pitch);
+        self.sync_runtime();
+        Ok(())
+    }
+
+    /// `(min, max)` range each `play()` picks a random pitch from, or None.
+    #[getter]
+    fn pitch_range(&self) -> Option<(f32, f32)> {
+        self.inner.pitch_range()
+    }
+
+    #[setter]
+    fn set_pitch_range(&mut self, pitch_range: Option<(f32, f32)>) -> PyResult<()> {
+        self.inner.set_pitch_range(check_pitch_range(pitch_range)?);
+        self.sync_runtime();
+        Ok(())
+    }
+
+    /// Past the engine's voice limit, sounds with lower priorities are cut off first.
+    #[getter]
+    fn priority(&self) -> i32 {
+        self.inner.priority()
+    }
+
+    #[setter]
+    fn set_priority(&mut self, priority: i32) {
+        self.inner.set_priority(priority);
+        self.sync_runtime();
+    }
+
+    /// Whether `play()` was called since the last `stop()`. Use
+    /// `engine.audio.is_playing(source)` to know whether the sound is still going.
+    #[getter]
+    fn playing(&self) -> bool {
+        self.inner.playing()
+    }
+
+    /// Start the sound from the beginning, even if it is playing.
+    fn play(&mut self) {
+        self.inner.play();
+        self.sync_runtime();
+    }
+
+    fn stop(&mut self) {
+        self.inner.stop();
+        self.sync_runtime();
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "AudioSourceComponent(sound={:?}, volume={}, looping={})",
+            self.inner.sound(),
+            self.inner.volume(),
+            if self.inner.looping() {
+                "True"
+            } else {
+                "False"
+            }
+        )
+    }
+}
+
+/// Hears positional sounds from its `GameObject`, e.g. the player rather than
+/// the camera.
+///
+/// The first enabled listener in the scene is used; without one, sounds are
+/// heard from the active camera. A sound `pan_width` world units or more to
+/// the side plays fully in that speaker.
+///
+/// # Example
+/// ```python
+/// player.add_component(pyg.AudioListener(pan_width=300))
+/// ```
+#[pyclass(name = "AudioListener", unsendable)]
+#[derive(Clone)]
+pub struct PyAudioListener {
+    pub(crate) inner: AudioListenerComponent,
+    pub(crate) runtime_binding: RefCell<Option<ComponentRuntimeBinding>>,
+}
+
+impl PyAudioListener {
+    fn sync_runtime(&self) {
+        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding
+                .sender
+                .send(EngineCommand::SetAudioListenerComponent {
+                    object_id: binding.object_id,
+                    component_id: binding.component_id,
+                    component: self.inner.clone(),
+                });
+        }
+    }
+}
+
+#[pymethods]
+impl PyAudioListener {
+    #[new]
+    #[pyo3(signature = (pan_width=500.0, name=None))]
+    fn new(pan_width: f32, name: Option<String>) -> PyResult<Self> {
+        if pan_width < 0.0 {
+            return Err(PyValueError::new_err("pan_width must be >= 0.0"));
+        }
+        Ok(Self {
+            inner: AudioListenerComponent::new(
+                name.unwrap_or_else(|| "Audio Listener".to_string()),
+            )
+            .with_pan_width(pan_width),
+            runtime_binding: RefCell::new(None),
+        })
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    /// Horizontal distance at which a sound plays fully in one speaker; 0 disables panning.
+    #[getter]
+    fn pan_width(&self) -> f32 {
+        self.inner.pan_width()
+    }
+
+    #[setter]
+    fn set_pan_width(&mut self, pan_width: f32) -> PyResult<()> {
+        if pan_width < 0.0 {
+            return Err(PyValueError::new_err("pan_width must be >= 0.0"));
+        }
+        self.inner.set_pan_width(pan_width);
+        self.sync_runtime();
+        Ok(())
+    }
+}
+
+/// Register audio bindings with Python
+pub fn register_audio_bindings(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyAudioSourceComponent>()?;
+    m.add_class::<PyAudioListener>()?;
+    Ok(())
+}
@@ -0,0 +1,181 @@
+//! Handling of exceptions raised by Python callbacks.
+//!
+//! Script hooks, event handlers, collider callbacks and UI callbacks are called
+//! from inside the engine, where an exception has no Python caller to reach.
+//! Every such exception is reported here instead: it is logged with its
+//! traceback, kept in a queue for `Engine.get_callback_errors()`, and then
+//! handled by the `CallbackErrorPolicy`:
+//!
+//! - `Log` (the default) keeps the game running.
+//! - `Raise` re-raises the first unhandled error from the next `update`,
+//!   `render` or `poll_events` call, or ends `run` with it.
+//! - `Abort` does the same and also closes the window, so the loop stops even
+//!   if the caller catches the exception.
+//!
+//! Callbacks are not tied to one engine (a collider can get its callback before
+//! it is added to a scene), so the policy and queue are shared by the process.
+
+use crate::core::logging;
+use pyo3::prelude::*;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// Errors kept for `get_callback_errors`; older ones are dropped.
+pub const MAX_CALLBACK_ERRORS: usize = 100;
+
+static CALLBACK_ERRORS: Mutex<CallbackErrors> = Mutex::new(CallbackErrors::new());
+
+/// What happens after a callback exception is logged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallbackErrorPolicy {
+    Log,
+    Raise,
+    Abort,
+}
+
+impl CallbackErrorPolicy {
+    pub fn name(self) -> &'static str {
+        match self {
+            CallbackErrorPolicy::Log => "log",
+            CallbackErrorPolicy::Raise => "raise",
+            CallbackErrorPolicy::Abort => "abort",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "log" => Some(CallbackErrorPolicy::Log),
+            "raise" => Some(CallbackErrorPolicy::Raise),
+            "abort" => Some(CallbackErrorPolicy::Abort),
+            _ => None,
+        }
+    }
+}
+
+struct CallbackErrors {
+    policy: CallbackErrorPolicy,
+    queue: VecDeque<PyCallbackError>,
+    /// First error not yet raised under the `Raise` and `Abort` policies
+    pending: Option<PyErr>,
+    aborted: bool,
+}
+
+impl CallbackErrors {
+    const fn new() -> Self {
+        Self {
+            policy: CallbackErrorPolicy::Log,
+            queue: VecDeque::new(),
+            pending: None,
+            aborted: false,
+        }
+    }
+}
+
+fn with_errors<R>(f: impl FnOnce(&mut CallbackErrors) -> R) -> R {
+    let mut errors = CALLBACK_ERRORS.lock().unwrap_or_else(|e| e.into_inner());
+    f(&mut errors)
+}
+
+/// An exception raised by a Python callback the engine called.
+#[pyclass(name = "CallbackError", frozen)]
+#[derive(Clone)]
+pub struct PyCallbackError {
+    /// What raised it, like "script update hook" or "button 'Play' callback"
+    #[pyo3(get)]
+    source: String,
+    /// Exception class name
+    #[pyo3(get)]
+    exception_type: String,
+    #[pyo3(get)]
+    message: String,
+    /// Formatted traceback, or an empty string without one
+    #[pyo3(get)]
+    traceback: String,
+}
+
+#[pymethods]
+impl PyCallbackError {
+    fn __repr__(&self) -> String {
+        format!(
+            "CallbackError(source={:?}, exception_type={:?}, message={:?})",
+            self.source, self.exception_type, self.message
+        )
+    }
+}
+
+/// Log an exception raised by a Python callback and handle it by the policy.
+/// `source` names what raised it, for the log and `CallbackError.source`.
+pub fn report_callback_error(py: Python<'_>, source: &str, error: PyErr) {
+    let exception_type = error
+        .get_type(py)
+        .name()
+        .map(|name| name.to_string())
+        .unwrap_or_else(|_| "Exception".to_string());
+    let message = error.value(py).to_string();
+    let traceback = error
+        .traceback(py)
+        .and_then(|traceback| traceback.format().ok())
+        .unwrap_or_default();
+    logging::log_error(&format!(
+        "Error in {source}: {exception_type}: {message}\n{}",
+        traceback.trim_end()
+    ));
+
+    with_errors(|errors| {
+        if errors.queue.len() == MAX_CALLBACK_ERRORS {
+            errors.queue.pop_front();
+        }
+        errors.queue.push_back(PyCallbackError {
+            source: source.to_string(),
+            exception_type,
+            message,
+            traceback,
+        });
+        if errors.policy != CallbackErrorPolicy::Log && errors.pending.is_none() {
+            errors.aborted |= errors.policy == CallbackErrorPolicy::Abort;
+            errors.pending = Some(error);
+        }
+    });
+}
+
+/// Take the error the next engine call should raise, if any.
+pub fn take_pending_callback_error() -> Option<PyErr> {
+    with_errors(|errors| errors.pending.take())
+}
+
+/// Raise the pending callback error, if any.
+pub fn raise_pending_callback_error() -> PyResult<()> {
+    take_pending_callback_error().map_or(Ok(()), Err)
+}
+
+/// Whether a callback error under the `Abort` policy asked the game to stop.
+/// Clears the request.
+pub fn take_callback_abort() -> bool {
+    with_errors(|errors| std::mem::take(&mut errors.aborted))
+}
+
+pub fn callback_error_policy() -> CallbackErrorPolicy {
+    with_errors(|errors| errors.policy)
+}
+
+/// Set the policy; switching to `Log` drops an error waiting to be raised.
+pub fn set_callback_error_policy(policy: CallbackErrorPolicy) {
+    with_errors(|errors| {
+        errors.policy = policy;
+        if policy == CallbackErrorPolicy::Log {
+            errors.pending = None;
+            errors.aborted = false;
+        }
+    });
+}
+
+/// Recorded callback errors, oldest first, optionally clearing the queue.
+pub fn callback_errors(clear: bool) -> Vec<PyCallbackError> {
+    with_errors(|errors| {
+        if clear {
+            errors.queue.drain(..).collect()
+        } else {
+            errors.queue.iter().cloned().collect()
+        }
+    })
+}
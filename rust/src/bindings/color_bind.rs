@@ -1,5 +1,6 @@
 use crate::types::color::Color as RustColor;
 use pyo3::prelude::*;
+use pyo3::types::PyType;
 
 // ========== Color Bindings ==========
 
@@ -91,8 +92,27 @@ use pyo3::prelude::*;
 /// c1 = Color.rgb(100, 150, 200)
 /// c2 = Color.rgb(50, 50, 50)
 /// result = c1 + c2  # Adds each component
+/// result += c2       # Rebinds result to a new color
+///
+/// # Tint by another color, or scale RGB by a number (alpha unchanged)
+/// tinted = Color.WHITE * Color.RED
+/// dimmed = Color.ORANGE * 0.5
+///
+/// # Photographic negative (alpha unchanged)
+/// inverted = -Color.RED  # Cyan
+///
+/// # Shades
+/// shadow = Color.ORANGE.darken(0.4)
+/// highlight = Color.ORANGE.lighten(0.2)
+/// disabled = Color.ORANGE.grayscale()
+/// r, g, b, a = Color.ORANGE.to_rgba8()  # Back to 0-255
 /// ```
 ///
+/// Components are clamped to [0.0, 1.0] after every operation, so `abs()`
+/// returns an equal copy. Every operator returns a new color, so
+/// `c = Color.WHITE; c *= 0.5` leaves `Color.WHITE` unchanged. Colors
+/// support `pickle` and `copy`.
+///
 /// # Important: Value Ranges
 ///
 /// - **Internal storage**: RGBA values are **[0.0, 1.0]** floats
@@ -131,7 +151,7 @@ use pyo3::prelude::*;
 /// # See Also
 /// - `DrawCommand` methods - All accept `Color` parameters
 /// - `examples/python_rendering_showcase_demo.py` - Color usage examples
-#[pyclass(name = "Color")]
+#[pyclass(name = "Color", module = "pyg_engine.pyg_engine_native")]
 #[derive(Clone)]
 pub struct PyColor {
     pub(crate) inner: RustColor,
@@ -467,6 +487,84 @@ impl PyColor {
         }
     }
 
+    /// Create a darker version of this color.
+    ///
+    /// Blends RGB toward black, keeping alpha.
+    ///
+    /// # Arguments
+    /// * `amount` - How far toward black (0.0 = unchanged, 1.0 = black)
+    ///
+    /// # Example
+    /// ```python
+    /// shadow = pyg.Color.ORANGE.darken(0.4)
+    /// ```
+    fn darken(&self, amount: f32) -> PyColor {
+        PyColor {
+            inner: self.inner.darken(amount),
+        }
+    }
+
+    /// Create a lighter version of this color.
+    ///
+    /// Blends RGB toward white, keeping alpha.
+    ///
+    /// # Arguments
+    /// * `amount` - How far toward white (0.0 = unchanged, 1.0 = white)
+    ///
+    /// # Example
+    /// ```python
+    /// hovered = button_color.lighten(0.2)
+    /// ```
+    fn lighten(&self, amount: f32) -> PyColor {
+        PyColor {
+            inner: self.inner.lighten(amount),
+        }
+    }
+
+    /// Create the gray of the same perceived brightness, keeping alpha.
+    ///
+    /// Uses the Rec. 601 luma weights, so green counts for more than blue.
+    ///
+    /// # Example
+    /// ```python
+    /// disabled = icon_color.grayscale()
+    /// ```
+    fn grayscale(&self) -> PyColor {
+        PyColor {
+            inner: self.inner.grayscale(),
+        }
+    }
+
+    /// Create the photographic negative of this color.
+    ///
+    /// Each RGB component becomes `1.0 - c`, alpha is kept. Same as `-color`.
+    ///
+    /// # Example
+    /// ```python
+    /// pyg.Color.RED.inverted()  # Cyan
+    /// ```
+    fn inverted(&self) -> PyColor {
+        PyColor {
+            inner: self.inner.inverted(),
+        }
+    }
+
+    /// Get the components as integers in range [0, 255].
+    ///
+    /// The inverse of `Color.rgba()`, e.g. for image bytes or saving colors.
+    ///
+    /// # Returns
+    /// `(r, g, b, a)` tuple, each rounded to the nearest integer
+    ///
+    /// # Example
+    /// ```python
+    /// pyg.Color.rgba(255, 128, 0, 64).to_rgba8()  # (255, 128, 0, 64)
+    /// ```
+    fn to_rgba8(&self) -> (u8, u8, u8, u8) {
+        let [r, g, b, a] = self.inner.to_rgba8();
+        (r, g, b, a)
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
@@ -487,18 +585,42 @@ impl PyColor {
         }
     }
 
-    fn __mul__(&self, other: &PyColor) -> PyColor {
-        PyColor {
-            inner: self.inner * other.inner,
+    /// Tint by another color (component-wise), or scale RGB by a number.
+    fn __mul__(&self, other: &Bound<'_, PyAny>) -> PyResult<PyColor> {
+        if let Ok(color) = other.extract::<PyColor>() {
+            Ok(PyColor {
+                inner: self.inner * color.inner,
+            })
+        } else if let Ok(factor) = other.extract::<f32>() {
+            Ok(PyColor {
+                inner: self.inner * factor,
+            })
+        } else {
+            Err(pyo3::exceptions::PyTypeError::new_err(
+                "unsupported operand type(s) for *: 'Color' and unknown type",
+            ))
         }
     }
 
+    fn __rmul__(&self, other: &Bound<'_, PyAny>) -> PyResult<PyColor> {
+        self.__mul__(other)
+    }
+
     fn __truediv__(&self, other: &PyColor) -> PyColor {
         PyColor {
             inner: self.inner / other.inner,
         }
     }
 
+    /// The photographic negative, see `inverted()`.
+    fn __neg__(&self) -> PyColor {
+        self.inverted()
+    }
+
+    fn __abs__(&self) -> PyColor {
+        self.clone()
+    }
+
     fn __eq__(&self, other: &PyColor) -> bool {
         self.inner.approx_eq_default(&other.inner)
     }
@@ -507,6 +629,20 @@ impl PyColor {
         !self.inner.approx_eq_default(&other.inner)
     }
 
+    /// Pickle as `Color(r, g, b, a)`.
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (f32, f32, f32, f32)) {
+        let inner = slf.borrow().inner;
+        (slf.get_type(), (inner.r(), inner.g(), inner.b(), inner.a()))
+    }
+
+    fn __copy__(&self) -> PyColor {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> PyColor {
+        self.clone()
+    }
+
     // Color constants as class attributes
     #[classattr]
     fn TRANSPARENT() -> PyColor {
@@ -1,39 +1,90 @@
 use crossbeam_channel::Sender;
-use pyo3::exceptions::PyRuntimeError;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::buffer::PyBuffer;
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
 use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
-use winit::event_loop::{ControlFlow, EventLoop};
+use winit::event_loop::EventLoop;
 use winit::platform::pump_events::{EventLoopExtPumpEvents, PumpStatus};
+use winit::window::{CursorGrabMode, CursorIcon};
 
 use crate::core::logging;
 
+use crate::core::capabilities::{EngineCapabilities, RendererCapabilities};
+use crate::core::clipboard;
 use crate::core::command::EngineCommand;
 use crate::core::component::{
-    ComponentTrait, MeshComponent, MeshGeometry, TextMeshComponent, TransformComponent,
+    ComponentTrait, MeshComponent, MeshGeometry, TextMeshComponent, TransformComponent, WorldLabelComponent,
 };
-use crate::core::draw_manager::DrawCommand;
+use crate::core::draw_manager::{DrawCommand, DrawManager};
+use crate::core::config::EngineConfig;
 use crate::core::engine::Engine as RustEngine;
+use crate::core::event_bus::{EventCall, EventPayload};
 use crate::core::game_object::GameObject as RustGameObject;
-use crate::core::input_manager::{MouseAxisBinding, MouseAxisType};
+use crate::core::input_bindings;
+use crate::core::input_glyphs::{GLYPH_ICON_SIZE, GlyphIcon, glyph_atlas, key_label};
+use crate::core::input_manager::{
+    AxisSmoothing, GamepadTrigger, InputChord, InputDevice, InputEvent, InputManager, MouseAxisBinding,
+    MouseAxisType,
+};
+use crate::core::input_players::PlayerDevice;
 use crate::core::object_manager::ObjectManager;
-use crate::core::render_manager::CameraAspectMode;
+use crate::core::curve::{AnimationCurve, CurveInterpolation, Keyframe};
+use crate::core::asset_manager::{AssetHandle, AssetKind};
+use crate::core::audio::{
+    AudioListenerComponent, AudioSourceComponent, BusSettings, ImpactSound, Sound, VoiceParams,
+};
+use crate::core::profiler::{FrameStats, ProfileScope};
+use crate::core::render_manager::{CameraAspectMode, RenderStats};
+use crate::core::script::{ScriptBehaviour, ScriptCall, ScriptComponent};
 use crate::core::text::{
     FontFamilyDefinition, FontStyle, FontWeight, TextAlign, TextLayoutOptions, TextStyle,
+    VerticalTextAlign,
 };
 use crate::core::time::Time as RustTime;
-use crate::core::ui::{Rect, UIComponentTrait};
+use crate::core::ui::{Rect, SizeMode, StyleState, UIComponentTrait};
 use crate::core::ui::button::ButtonComponent;
 use crate::core::ui::panel::PanelComponent;
 use crate::core::ui::label::LabelComponent;
+use crate::core::ui::slider::{SliderComponent, SliderOrientation};
+use crate::core::ui::checkbox::CheckboxComponent;
+use crate::core::ui::toggle_switch::ToggleSwitchComponent;
+use crate::core::ui::scroll_view::ScrollViewComponent;
+use crate::core::ui::progress_bar::{FillDirection, ProgressBarComponent};
+use crate::core::ui::custom_widget::CustomWidgetComponent;
+use crate::core::ui::canvas::CanvasComponent;
+use crate::core::ui::image::{ImageComponent, ImageScaleMode};
+use crate::core::ui::stack::{StackAlignment, StackComponent, StackDirection};
+use crate::core::ui::curve_editor::CurveEditorComponent;
+use crate::core::ui::radio_button::{RadioButtonComponent, RadioGroup};
+use crate::core::ui::list_view::ListViewComponent;
+use crate::core::ui::style::{FocusRing, Padding, parse_style_state};
+use crate::core::ui::event::{EventPhase, UIEvent};
+use crate::core::ui::layout::{Anchor, AnchorOffset, UILayoutComponent};
+use crate::core::ui::text_input::TextInputComponent;
+use crate::core::ui::tween::{Easing, TweenProperty, UITween};
+use crate::core::ui_manager::UIScaleMode;
 use crate::core::window_manager::{FullscreenMode, WindowConfig, load_window_icon_from_path};
 
 // Import bindings from separate modules
+use super::callback_errors::{
+    CallbackErrorPolicy, PyCallbackError, callback_error_policy, callback_errors,
+    raise_pending_callback_error, report_callback_error, set_callback_error_policy,
+    take_callback_abort, take_pending_callback_error,
+};
 use super::color_bind::PyColor;
-use super::input_bind::{PyKeys, PyMouseButton, parse_key, parse_mouse_button};
+use super::input_bind::{PyKeys, PyMouseButton, mouse_button_name, parse_key, parse_mouse_button};
+use super::audio_bind::{
+    PyAudioListener, PyAudioSourceComponent, check_pitch, check_pitch_range, check_stream_layout,
+    check_bus_values, impact_key, parse_attenuation, parse_bus, parse_pcm_format, parse_snapshot,
+    sound_path,
+};
 use super::physics_bind::PyCollider;
-use super::vector_bind::{PyVec2, PyVec3};
+use super::vector_bind::{PyVec2, PyVec3, extract_points, points_to_buffer};
 use crate::core::physics::collider::ColliderComponent;
 use crate::types::vector::Vec2;
 
@@ -74,6 +125,83 @@ fn parse_camera_aspect_mode(mode_name: &str) -> Option<CameraAspectMode> {
     }
 }
 
+fn parse_trigger(trigger: &str) -> PyResult<GamepadTrigger> {
+    GamepadTrigger::parse(trigger)
+        .ok_or_else(|| PyValueError::new_err(format!("Unknown trigger '{trigger}', expected 'left' or 'right'")))
+}
+
+fn parse_chord(chord: &str) -> PyResult<InputChord> {
+    input_bindings::parse_chord(chord).ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "Unknown chord '{chord}', expected modifiers and a key or mouse button like 'ctrl+s' or 'shift+click'"
+        ))
+    })
+}
+
+fn parse_cursor_grab_mode(mode_name: &str) -> PyResult<CursorGrabMode> {
+    match mode_name.trim().to_lowercase().as_str() {
+        "none" => Ok(CursorGrabMode::None),
+        "confined" | "confine" => Ok(CursorGrabMode::Confined),
+        "locked" | "lock" => Ok(CursorGrabMode::Locked),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown cursor grab mode '{mode_name}', expected 'none', 'confined' or 'locked'"
+        ))),
+    }
+}
+
+fn cursor_grab_mode_name(mode: CursorGrabMode) -> &'static str {
+    match mode {
+        CursorGrabMode::None => "none",
+        CursorGrabMode::Confined => "confined",
+        CursorGrabMode::Locked => "locked",
+    }
+}
+
+fn parse_cursor_icon(cursor_name: &str) -> PyResult<CursorIcon> {
+    let normalized = cursor_name.trim().to_lowercase().replace('_', "-");
+    let alias = match normalized.as_str() {
+        "arrow" => "default",
+        "hand" => "pointer",
+        "ibeam" | "i-beam" => "text",
+        "resize-horizontal" => "ew-resize",
+        "resize-vertical" => "ns-resize",
+        "resize-diagonal" => "nwse-resize",
+        "resize-anti-diagonal" => "nesw-resize",
+        "resize" | "resize-all" => "move",
+        other => other,
+    };
+    alias.parse().map_err(|_| {
+        PyValueError::new_err(format!(
+            "Unknown cursor '{cursor_name}', expected a shape such as 'default', 'hand', 'crosshair', 'text', \
+             'resize_horizontal', 'resize_vertical' or a CSS cursor name"
+        ))
+    })
+}
+
+fn parse_ui_scale_mode(mode_name: &str, reference_width: f32, reference_height: f32) -> PyResult<UIScaleMode> {
+    if reference_width <= 0.0 || reference_height <= 0.0 {
+        return Err(PyValueError::new_err("UI reference resolution must be positive"));
+    }
+    match mode_name
+        .trim()
+        .chars()
+        .flat_map(|ch| ch.to_lowercase())
+        .filter(|ch| !matches!(ch, ' ' | '_' | '-'))
+        .collect::<String>()
+        .as_str()
+    {
+        "constantpixelsize" | "constant" => Ok(UIScaleMode::ConstantPixelSize),
+        "scalewithheight" | "height" => Ok(UIScaleMode::ScaleWithHeight { reference_height }),
+        "fixedresolution" | "fixed" | "letterbox" => Ok(UIScaleMode::FixedResolution {
+            width: reference_width,
+            height: reference_height,
+        }),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown UI scale mode '{mode_name}', expected 'constant_pixel_size', 'scale_with_height' or 'fixed_resolution'"
+        ))),
+    }
+}
+
 fn parse_font_weight(value: Option<&str>) -> PyResult<FontWeight> {
     let Some(value) = value else {
         return Ok(FontWeight::Regular);
@@ -96,6 +224,178 @@ fn parse_font_style(value: Option<&str>) -> PyResult<FontStyle> {
     })
 }
 
+fn parse_vertical_text_align(value: &str) -> PyResult<VerticalTextAlign> {
+    VerticalTextAlign::parse(value).ok_or_else(|| {
+        PyRuntimeError::new_err(format!(
+            "Invalid vertical_align '{value}'. Expected 'top', 'center' or 'bottom'."
+        ))
+    })
+}
+
+fn parse_slider_orientation(value: &str) -> PyResult<SliderOrientation> {
+    SliderOrientation::parse(value).ok_or_else(|| {
+        PyRuntimeError::new_err(format!(
+            "Invalid orientation '{value}'. Expected 'horizontal' or 'vertical'."
+        ))
+    })
+}
+
+fn parse_curve_interpolation(value: &str) -> PyResult<CurveInterpolation> {
+    CurveInterpolation::parse(value).ok_or_else(|| {
+        PyRuntimeError::new_err(format!(
+            "Invalid interpolation '{value}'. Expected 'linear', 'smooth' or 'constant'."
+        ))
+    })
+}
+
+fn parse_tween_property(value: &str) -> PyResult<TweenProperty> {
+    TweenProperty::parse(value).ok_or_else(|| {
+        PyRuntimeError::new_err(format!(
+            "Invalid tween property '{value}'. Expected 'x', 'y', 'width', 'height', 'offset_x', 'offset_y', 'opacity', 'background_color', 'border_color' or 'text_color'."
+        ))
+    })
+}
+
+fn parse_easing(value: &str) -> PyResult<Easing> {
+    Easing::parse(value).ok_or_else(|| {
+        PyRuntimeError::new_err(format!(
+            "Invalid easing '{value}'. Expected 'linear', 'ease_in', 'ease_out', 'ease_in_out', 'ease_in_quad', 'ease_out_quad', 'ease_in_out_quad', 'ease_out_back' or 'ease_out_bounce'."
+        ))
+    })
+}
+
+/// A tween value: one number, or an RGB(A) color for color properties.
+fn tween_value(property: TweenProperty, value: &[f32]) -> PyResult<[f32; 4]> {
+    match (property.is_color(), value) {
+        (false, [number]) => Ok([*number, 0.0, 0.0, 0.0]),
+        (true, [r, g, b]) => Ok([*r, *g, *b, 1.0]),
+        (true, [r, g, b, a]) => Ok([*r, *g, *b, *a]),
+        (true, _) => Err(PyRuntimeError::new_err(format!(
+            "Tween property '{}' expects an (r, g, b[, a]) color",
+            property.as_str()
+        ))),
+        (false, _) => Err(PyRuntimeError::new_err(format!(
+            "Tween property '{}' expects a single number",
+            property.as_str()
+        ))),
+    }
+}
+
+fn build_ui_tween(
+    object_id: u32,
+    property: &str,
+    to: &[f32],
+    duration: f32,
+    easing: &str,
+    delay: f32,
+    from_: Option<Vec<f32>>,
+) -> PyResult<UITween> {
+    let property = parse_tween_property(property)?;
+    let mut tween = UITween::new(object_id, property, tween_value(property, to)?, duration)
+        .with_easing(parse_easing(easing)?)
+        .with_delay(delay);
+    if let Some(from) = from_ {
+        tween = tween.with_from(tween_value(property, &from)?);
+    }
+    Ok(tween)
+}
+
+fn parse_fill_direction(value: &str) -> PyResult<FillDirection> {
+    FillDirection::parse(value).ok_or_else(|| {
+        PyRuntimeError::new_err(format!(
+            "Invalid fill_direction '{value}'. Expected 'left_to_right', 'right_to_left', 'bottom_to_top' or 'top_to_bottom'."
+        ))
+    })
+}
+
+fn parse_image_scale_mode(value: &str) -> PyResult<ImageScaleMode> {
+    ImageScaleMode::parse(value).ok_or_else(|| {
+        PyRuntimeError::new_err(format!(
+            "Invalid scale_mode '{value}'. Expected 'stretch', 'fit', 'fill' or 'tile'."
+        ))
+    })
+}
+
+fn parse_stack_direction(value: &str) -> PyResult<StackDirection> {
+    StackDirection::parse(value).ok_or_else(|| {
+        PyRuntimeError::new_err(format!(
+            "Invalid direction '{value}'. Expected 'vertical' or 'horizontal'."
+        ))
+    })
+}
+
+fn parse_stack_alignment(value: &str) -> PyResult<StackAlignment> {
+    StackAlignment::parse(value).ok_or_else(|| {
+        PyRuntimeError::new_err(format!(
+            "Invalid alignment '{value}'. Expected 'start', 'center', 'end' or 'stretch'."
+        ))
+    })
+}
+
+fn parse_anchor(value: &str) -> PyResult<Anchor> {
+    Anchor::parse(value).ok_or_else(|| {
+        PyRuntimeError::new_err(format!(
+            "Invalid anchor '{value}'. Expected 'top_left', 'top_center', 'top_right', 'middle_left', 'center', 'middle_right', 'bottom_left', 'bottom_center', 'bottom_right' or 'stretch'."
+        ))
+    })
+}
+
+/// Parse a layout size: None keeps the widget's own size, a number is fixed
+/// pixels, "50%" is a share of the parent and "fill" fills the parent.
+fn parse_size_mode(value: Option<&Bound<'_, PyAny>>) -> PyResult<SizeMode> {
+    let Some(value) = value else {
+        return Ok(SizeMode::FitContent);
+    };
+    if let Ok(pixels) = value.extract::<f32>() {
+        return Ok(SizeMode::Fixed(pixels));
+    }
+    let text: String = value.extract()?;
+    let text = text.trim().to_ascii_lowercase();
+    match text.as_str() {
+        "fill" | "fill_parent" => return Ok(SizeMode::FillParent),
+        "content" | "fit_content" => return Ok(SizeMode::FitContent),
+        _ => {}
+    }
+    text.strip_suffix('%')
+        .and_then(|percent| percent.trim().parse::<f32>().ok())
+        .map(|percent| SizeMode::Percentage(percent / 100.0))
+        .ok_or_else(|| {
+            PyRuntimeError::new_err(format!(
+                "Invalid size '{text}'. Expected a number of pixels, a percentage like '50%', 'fill' or 'content'."
+            ))
+        })
+}
+
+/// RGBA pixels for image drawing. Byte buffers (`bytes`, `bytearray`,
+/// `memoryview`, uint8 numpy arrays) are copied straight into the shared
+/// pixel buffer; other sequences of ints are converted one by one.
+fn extract_rgba(rgba: &Bound<'_, PyAny>) -> PyResult<Arc<[u8]>> {
+    let Ok(buffer) = PyBuffer::<u8>::get(rgba) else {
+        return Ok(Arc::from(rgba.extract::<Vec<u8>>()?));
+    };
+    let mut pixels: Arc<[u8]> = std::iter::repeat_n(0, buffer.item_count()).collect();
+    let target = Arc::get_mut(&mut pixels).expect("pixel buffer was just created");
+    buffer.copy_to_slice(rgba.py(), target)?;
+    Ok(pixels)
+}
+
+fn build_ui_layout(
+    anchor: &str,
+    x: f32,
+    y: f32,
+    width: Option<&Bound<'_, PyAny>>,
+    height: Option<&Bound<'_, PyAny>>,
+    pivot: Option<(f32, f32)>,
+) -> PyResult<UILayoutComponent> {
+    let mut layout = UILayoutComponent::with_anchor(parse_anchor(anchor)?, AnchorOffset::new(x, y));
+    layout.width_mode = parse_size_mode(width)?;
+    layout.height_mode = parse_size_mode(height)?;
+    if let Some(pivot) = pivot {
+        layout.pivot = pivot;
+    }
+    Ok(layout)
+}
+
 #[allow(clippy::too_many_arguments)]
 fn build_text_style(
     font_size: f32,
@@ -122,10 +422,163 @@ fn build_text_style(
 }
 
 #[derive(Clone)]
-struct ComponentRuntimeBinding {
-    sender: Sender<EngineCommand>,
-    object_id: u32,
-    component_id: u32,
+pub(crate) struct ComponentRuntimeBinding {
+    pub(crate) sender: Sender<EngineCommand>,
+    pub(crate) object_id: u32,
+    pub(crate) component_id: u32,
+}
+
+/// Hooks a Python object needs at least one of to be added as a script.
+const SCRIPT_HOOKS: [&str; 7] = [
+    "on_start",
+    "update",
+    "fixed_update",
+    "on_collision_enter",
+    "on_collision_stay",
+    "on_collision_exit",
+    "on_message",
+];
+
+/// Script behaviour backed by a Python object, usually an instance of a
+/// `pyg_engine.Script` subclass. Hooks are looked up on every call, so hooks
+/// the object does not define are skipped and hot-reloaded classes take
+/// effect right away.
+struct PyScriptBehaviour {
+    script: Py<PyAny>,
+}
+
+impl PyScriptBehaviour {
+    fn is_script(value: &Bound<'_, PyAny>) -> bool {
+        SCRIPT_HOOKS
+            .iter()
+            .any(|hook| value.hasattr(*hook).unwrap_or(false))
+    }
+
+    /// Call a hook if the script defines it, logging any exception it raises.
+    fn call_hook(&self, hook: &str, call: impl for<'py> FnOnce(&Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>>) {
+        Python::attach(|py| {
+            let Ok(method) = self.script.bind(py).getattr(hook) else {
+                return;
+            };
+            if let Err(e) = call(&method) {
+                report_callback_error(py, &format!("script {} hook", hook), e);
+            }
+        });
+    }
+}
+
+impl ScriptBehaviour for PyScriptBehaviour {
+    fn on_start(&self) {
+        self.call_hook("on_start", |method| method.call0());
+    }
+
+    fn update(&self, delta_time: f32) {
+        self.call_hook("update", |method| method.call1((delta_time,)));
+    }
+
+    fn fixed_update(&self, fixed_time: f32) {
+        self.call_hook("fixed_update", |method| method.call1((fixed_time,)));
+    }
+
+    fn on_collision_enter(&self, other_id: u32, normal: Vec2, penetration: f32) {
+        self.call_hook("on_collision_enter", |method| {
+            method.call1((other_id, PyVec2 { inner: normal }, penetration))
+        });
+    }
+
+    fn on_collision_stay(&self, other_id: u32, normal: Vec2, penetration: f32) {
+        self.call_hook("on_collision_stay", |method| {
+            method.call1((other_id, PyVec2 { inner: normal }, penetration))
+        });
+    }
+
+    fn on_collision_exit(&self, other_id: u32) {
+        self.call_hook("on_collision_exit", |method| method.call1((other_id,)));
+    }
+
+    fn on_message(&self, name: &str, payload: &EventPayload) {
+        self.call_hook("on_message", |method| {
+            method.call1((name, payload_to_py(method.py(), payload)?))
+        });
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+/// Get the Python object behind a script component.
+fn script_object(py: Python<'_>, script: &ScriptComponent) -> Option<Py<PyAny>> {
+    script
+        .behaviour()
+        .as_any()
+        .downcast_ref::<PyScriptBehaviour>()
+        .map(|behaviour| behaviour.script.clone_ref(py))
+}
+
+/// Whether a component has the given type, or is a script whose class has that name.
+fn component_matches_type(component: &dyn ComponentTrait, component_type: &str) -> bool {
+    component.component_type() == component_type
+        || (component.as_any().is::<ScriptComponent>() && component.name() == component_type)
+}
+
+/// Run script hooks queued by the engine, which must not be borrowed so the
+/// scripts can call back into it.
+fn run_script_calls(calls: Vec<ScriptCall>) {
+    for call in calls {
+        call.invoke();
+    }
+}
+
+/// Run event handlers; like `run_script_calls`, without the engine borrowed.
+fn run_event_calls(calls: Vec<EventCall>) {
+    for call in calls {
+        call.invoke();
+    }
+}
+
+/// Run Python hooks with the engine released, timed into the profiler's
+/// "scripts" scope.
+fn profile_scripts(engine: &Bound<'_, PyEngine>, run: impl FnOnce()) {
+    let started = engine.borrow().inner.start_profile_scope();
+    run();
+    engine.borrow_mut().inner.record_profile_scope(ProfileScope::Scripts, started);
+}
+
+/// Convert a Python value to an event payload. Values without a native
+/// payload type are carried as the Python object itself.
+fn payload_from_py(value: &Bound<'_, PyAny>) -> EventPayload {
+    if value.is_none() {
+        EventPayload::None
+    } else if value.is_instance_of::<pyo3::types::PyBool>() {
+        EventPayload::Bool(value.is_truthy().unwrap_or(false))
+    } else if let Ok(number) = value.extract::<i64>()
+        && value.is_instance_of::<pyo3::types::PyInt>()
+    {
+        EventPayload::Int(number)
+    } else if let Ok(number) = value.cast::<pyo3::types::PyFloat>() {
+        EventPayload::Float(number.value())
+    } else if let Ok(text) = value.cast::<pyo3::types::PyString>() {
+        EventPayload::Text(text.to_string())
+    } else if let Ok(vector) = value.extract::<PyRef<PyVec2>>() {
+        EventPayload::Vec2(vector.inner)
+    } else {
+        EventPayload::Any(Arc::new(value.clone().unbind()))
+    }
+}
+
+fn payload_to_py(py: Python<'_>, payload: &EventPayload) -> PyResult<Py<PyAny>> {
+    Ok(match payload {
+        EventPayload::None => py.None(),
+        EventPayload::Bool(value) => value.into_pyobject(py)?.to_owned().into_any().unbind(),
+        EventPayload::Int(value) => value.into_pyobject(py)?.into_any().unbind(),
+        EventPayload::Float(value) => value.into_pyobject(py)?.into_any().unbind(),
+        EventPayload::Text(value) => value.into_pyobject(py)?.into_any().unbind(),
+        EventPayload::Vec2(value) => Py::new(py, PyVec2 { inner: *value })?.into_any(),
+        EventPayload::Any(_) => payload
+            .downcast_ref::<Py<PyAny>>()
+            .map_or_else(|| py.None(), |object| object.clone_ref(py)),
+    })
 }
 
 fn component_to_pyobject(
@@ -133,6 +586,11 @@ fn component_to_pyobject(
     component: &dyn ComponentTrait,
     binding: Option<ComponentRuntimeBinding>,
 ) -> PyResult<Py<PyAny>> {
+    if let Some(script) = component.as_any().downcast_ref::<ScriptComponent>()
+        && let Some(object) = script_object(py, script)
+    {
+        return Ok(object);
+    }
     if let Some(transform) = component.as_any().downcast_ref::<TransformComponent>() {
         return Ok(Py::new(
             py,
@@ -161,6 +619,45 @@ fn component_to_pyobject(
     if let Some(label) = component.as_any().downcast_ref::<LabelComponent>() {
         return Ok(Py::new(py, PyLabelComponent { inner: label.clone() })?.into_any());
     }
+    if let Some(text_input) = component.as_any().downcast_ref::<TextInputComponent>() {
+        return Ok(Py::new(py, PyTextInputComponent { inner: text_input.clone() })?.into_any());
+    }
+    if let Some(slider) = component.as_any().downcast_ref::<SliderComponent>() {
+        return Ok(Py::new(py, PySliderComponent { inner: slider.clone() })?.into_any());
+    }
+    if let Some(checkbox) = component.as_any().downcast_ref::<CheckboxComponent>() {
+        return Ok(Py::new(py, PyCheckboxComponent { inner: checkbox.clone() })?.into_any());
+    }
+    if let Some(switch) = component.as_any().downcast_ref::<ToggleSwitchComponent>() {
+        return Ok(Py::new(py, PyToggleSwitchComponent { inner: switch.clone() })?.into_any());
+    }
+    if let Some(scroll_view) = component.as_any().downcast_ref::<ScrollViewComponent>() {
+        return Ok(Py::new(py, PyScrollViewComponent { inner: scroll_view.clone() })?.into_any());
+    }
+    if let Some(bar) = component.as_any().downcast_ref::<ProgressBarComponent>() {
+        return Ok(Py::new(py, PyProgressBarComponent { inner: bar.clone() })?.into_any());
+    }
+    if let Some(widget) = component.as_any().downcast_ref::<CustomWidgetComponent>() {
+        return Ok(Py::new(py, PyCustomWidgetComponent { inner: widget.clone() })?.into_any());
+    }
+    if let Some(canvas) = component.as_any().downcast_ref::<CanvasComponent>() {
+        return Ok(Py::new(py, PyCanvasComponent { inner: canvas.clone() })?.into_any());
+    }
+    if let Some(image) = component.as_any().downcast_ref::<ImageComponent>() {
+        return Ok(Py::new(py, PyImageComponent { inner: image.clone() })?.into_any());
+    }
+    if let Some(stack) = component.as_any().downcast_ref::<StackComponent>() {
+        return Ok(Py::new(py, PyStackComponent { inner: stack.clone() })?.into_any());
+    }
+    if let Some(editor) = component.as_any().downcast_ref::<CurveEditorComponent>() {
+        return Ok(Py::new(py, PyCurveEditorComponent { inner: editor.clone() })?.into_any());
+    }
+    if let Some(radio) = component.as_any().downcast_ref::<RadioButtonComponent>() {
+        return Ok(Py::new(py, PyRadioButtonComponent { inner: radio.clone() })?.into_any());
+    }
+    if let Some(list) = component.as_any().downcast_ref::<ListViewComponent>() {
+        return Ok(Py::new(py, PyListViewComponent { inner: list.clone() })?.into_any());
+    }
     if let Some(text_mesh) = component.as_any().downcast_ref::<TextMeshComponent>() {
         return Ok(Py::new(
             py,
@@ -171,6 +668,36 @@ fn component_to_pyobject(
         )?
         .into_any());
     }
+    if let Some(label) = component.as_any().downcast_ref::<WorldLabelComponent>() {
+        return Ok(Py::new(
+            py,
+            PyWorldLabelComponent {
+                inner: label.clone(),
+                runtime_binding: RefCell::new(binding),
+            },
+        )?
+        .into_any());
+    }
+    if let Some(source) = component.as_any().downcast_ref::<AudioSourceComponent>() {
+        return Ok(Py::new(
+            py,
+            PyAudioSourceComponent {
+                inner: source.clone(),
+                runtime_binding: RefCell::new(binding),
+            },
+        )?
+        .into_any());
+    }
+    if let Some(listener) = component.as_any().downcast_ref::<AudioListenerComponent>() {
+        return Ok(Py::new(
+            py,
+            PyAudioListener {
+                inner: listener.clone(),
+                runtime_binding: RefCell::new(binding),
+            },
+        )?
+        .into_any());
+    }
     if let Some(collider) = component.as_any().downcast_ref::<ColliderComponent>() {
         return Ok(Py::new(
             py,
@@ -232,6 +759,20 @@ impl PyCameraAspectMode {
     const FILL_BOTH: &'static str = "fill_both";
 }
 
+/// Names accepted by `Engine.set_ui_scale_mode()`.
+#[pyclass(name = "UIScaleMode")]
+pub struct PyUIScaleMode;
+
+#[pymethods]
+impl PyUIScaleMode {
+    #[classattr]
+    const CONSTANT_PIXEL_SIZE: &'static str = "constant_pixel_size";
+    #[classattr]
+    const SCALE_WITH_HEIGHT: &'static str = "scale_with_height";
+    #[classattr]
+    const FIXED_RESOLUTION: &'static str = "fixed_resolution";
+}
+
 /// Python-side draw command builder used for bulk submission.
 ///
 /// `DrawCommand` provides a static API for creating drawing operations that can be batched
@@ -596,27 +1137,25 @@ impl PyDrawCommand {
         }
     }
 
+    /// Polygon from `(x, y)` pairs, `Vec2`s, or a float array shaped `(n, 2)`.
     #[staticmethod]
     #[pyo3(signature = (points, color, filled=true, thickness=1.0, draw_order=0.0))]
     fn polygon(
-        points: Vec<(f32, f32)>,
+        points: &Bound<'_, PyAny>,
         color: &PyColor,
         filled: bool,
         thickness: f32,
         draw_order: f32,
-    ) -> Self {
-        Self {
+    ) -> PyResult<Self> {
+        Ok(Self {
             inner: DrawCommand::Polygon {
-                points: points
-                    .into_iter()
-                    .map(|(x, y)| crate::types::vector::Vec2::new(x, y))
-                    .collect(),
+                points: extract_points(points)?,
                 color: color.inner,
                 filled,
                 thickness,
                 draw_order,
             },
-        }
+        })
     }
 
     #[staticmethod]
@@ -825,7 +1364,9 @@ impl PyDrawCommand {
     /// * `width` - Display width in pixels (may scale from texture size)
     /// * `height` - Display height in pixels (may scale from texture size)
     /// * `texture_key` - Unique identifier for caching (e.g., `"procedural_1"`)
-    /// * `rgba` - Byte array of RGBA pixel data (must be `texture_width * texture_height * 4` bytes)
+    /// * `rgba` - RGBA pixel data (must be `texture_width * texture_height * 4` bytes): `bytes`,
+    ///   `bytearray`, `memoryview`, a uint8 numpy array or any other byte buffer, copied once
+    ///   without a Python object per byte
     /// * `texture_width` - Source texture width in pixels
     /// * `texture_height` - Source texture height in pixels
     /// * `draw_order` - Rendering layer (default: 0.0, higher = on top)
@@ -839,9 +1380,12 @@ impl PyDrawCommand {
     /// # Texture Key Caching
     /// Textures are cached by `texture_key`. If the same key is used multiple times:
     /// - First call: Creates and caches the texture
-    /// - Subsequent calls: Reuses cached texture (ignores new `rgba` data)
+    /// - Later calls with the same size: Reuse the GPU texture, uploading the new
+    ///   pixels only when they changed
+    /// - Later calls with a new size: Recreate the texture
     ///
-    /// Use unique keys for different textures: `"frame_0"`, `"frame_1"`, etc.
+    /// Use unique keys for different textures, and one key for a texture whose
+    /// pixels change over time, like a video frame or a procedural animation.
     ///
     /// # Errors
     /// Returns `PyRuntimeError` if:
@@ -897,32 +1441,29 @@ impl PyDrawCommand {
     ///
     /// # Animated Texture Example
     /// ```python
+    /// import numpy as np
     /// import pyg_engine as pyg
-    /// import time
     ///
     /// frame_count = 0
+    /// tex_w, tex_h = 32, 32
+    /// pixels = np.zeros((tex_h, tex_w, 4), dtype=np.uint8)
+    /// ys, xs = np.mgrid[0:tex_h, 0:tex_w]
     ///
     /// def update(dt, engine, data):
     ///     global frame_count
     ///     frame_count += 1
     ///
     ///     # Generate animated texture
-    ///     tex_w, tex_h = 32, 32
-    ///     rgba = bytearray(tex_w * tex_h * 4)
-    ///
-    ///     for y in range(tex_h):
-    ///         for x in range(tex_w):
-    ///             idx = (y * tex_w + x) * 4
-    ///             rgba[idx + 0] = (x + frame_count) % 255
-    ///             rgba[idx + 1] = (y + frame_count) % 255
-    ///             rgba[idx + 2] = 128
-    ///             rgba[idx + 3] = 255
-    ///
-    ///     # Use unique key per frame to avoid caching
+    ///     pixels[..., 0] = (xs + frame_count) % 255
+    ///     pixels[..., 1] = (ys + frame_count) % 255
+    ///     pixels[..., 2] = 128
+    ///     pixels[..., 3] = 255
+    ///
+    ///     # Same key every frame: the GPU texture is reused and its pixels updated
     ///     cmd = pyg.DrawCommand.image_from_bytes(
     ///         100, 100, 128, 128,
-    ///         f"animated_frame_{frame_count}",  # Unique key
-    ///         bytes(rgba),
+    ///         "animated",
+    ///         pixels,
     ///         tex_w, tex_h
     ///     )
     ///     engine.add_draw_commands([cmd])
@@ -950,11 +1491,12 @@ impl PyDrawCommand {
         width: f32,
         height: f32,
         texture_key: String,
-        rgba: Vec<u8>,
+        rgba: &Bound<'_, PyAny>,
         texture_width: u32,
         texture_height: u32,
         draw_order: f32,
     ) -> PyResult<Self> {
+        let rgba = extract_rgba(rgba)?;
         let expected_size = (texture_width as usize)
             .checked_mul(texture_height as usize)
             .and_then(|value| value.checked_mul(4))
@@ -978,7 +1520,7 @@ impl PyDrawCommand {
                 width,
                 height,
                 texture_key,
-                rgba: Arc::from(rgba),
+                rgba,
                 texture_width,
                 texture_height,
                 draw_order,
@@ -996,20 +1538,24 @@ impl PyDrawCommand {
         draw_order=0.0
     ))]
     fn mesh(
-        vertices: Vec<(f32, f32)>,
+        vertices: &Bound<'_, PyAny>,
         indices: Vec<u32>,
         color: &PyColor,
         texture_path: Option<String>,
-        uvs: Option<Vec<(f32, f32)>>,
+        uvs: Option<&Bound<'_, PyAny>>,
         draw_order: f32,
     ) -> PyResult<Self> {
+        let vertices = extract_points(vertices)?;
         if vertices.is_empty() {
             return Err(PyRuntimeError::new_err(
                 "mesh requires at least one vertex",
             ));
         }
 
-        let uvs = uvs.unwrap_or_else(|| vec![(0.0, 0.0); vertices.len()]);
+        let uvs = match uvs {
+            Some(uvs) => extract_points(uvs)?,
+            None => vec![Vec2::new(0.0, 0.0); vertices.len()],
+        };
         if uvs.len() != vertices.len() {
             return Err(PyRuntimeError::new_err(format!(
                 "mesh UV count mismatch: expected {} entries, got {}",
@@ -1021,12 +1567,7 @@ impl PyDrawCommand {
         let mesh_vertices = vertices
             .into_iter()
             .zip(uvs)
-            .map(|((x, y), (u, v))| {
-                crate::core::component::MeshVertex::new(
-                    crate::types::vector::Vec2::new(x, y),
-                    crate::types::vector::Vec2::new(u, v),
-                )
-            })
+            .map(|(position, uv)| crate::core::component::MeshVertex::new(position, uv))
             .collect();
 
         Ok(Self {
@@ -1207,39 +1748,374 @@ impl PyDrawCommand {
             },
         })
     }
-}
 
-/// Python wrapper for the Rust Engine.
-#[pyclass(name = "Engine", unsendable)]
-pub struct PyEngine {
-    inner: RustEngine,
-    event_loop: Option<EventLoop<()>>,
+    /// Draw text centered on a point in world space.
+    ///
+    /// Unlike `text()`, the position is in world units: the text moves with the
+    /// camera and scales with zoom, one text pixel per world unit.
+    ///
+    /// # Example
+    /// ```python
+    /// commands = [
+    ///     pyg.DrawCommand.world_text(f"{enemy.hp} HP", enemy.x, enemy.y + 40, pyg.Color.RED)
+    ///     for enemy in enemies
+    /// ]
+    /// engine.add_draw_commands(commands)
+    /// ```
+    #[staticmethod]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        text,
+        x,
+        y,
+        color,
+        font_size=24.0,
+        font_path=None,
+        font_family=None,
+        font_weight=None,
+        font_style=None,
+        draw_order=0.0
+    ))]
+    fn world_text(
+        text: String,
+        x: f32,
+        y: f32,
+        color: &PyColor,
+        font_size: f32,
+        font_path: Option<String>,
+        font_family: Option<String>,
+        font_weight: Option<&str>,
+        font_style: Option<&str>,
+        draw_order: f32,
+    ) -> PyResult<Self> {
+        let style = build_text_style(font_size, font_path, font_family, font_weight, font_style, 0.0, 0.0, true)?;
+        Ok(Self {
+            inner: DrawCommand::WorldText {
+                text,
+                x,
+                y,
+                style,
+                color: color.inner,
+                draw_order,
+            },
+        })
+    }
+}
+
+/// Records draw calls into a native buffer and submits them in one batch.
+///
+/// Each call appends a command to a preallocated Rust `Vec` instead of creating
+/// a `DrawCommand` object or crossing into the engine, and `flush()` hands the
+/// whole buffer over at once. Used as a context manager, the recorder flushes
+/// when the block exits without an exception and discards its commands otherwise.
+///
+/// # Example
+/// ```python
+/// with engine.draw_recorder() as d:
+///     for x, y in positions:
+///         d.circle(x, y, 4, pyg.Color.YELLOW)
+///     d.text(f"{len(positions)} particles", 10, 10, pyg.Color.WHITE)
+/// ```
+///
+/// `circles()` records a circle per point of a numpy array in one call:
+/// ```python
+/// with engine.draw_recorder() as d:
+///     d.circles(positions, 4, pyg.Color.YELLOW)  # positions.shape == (n, 2)
+/// ```
+#[pyclass(name = "DrawRecorder", unsendable)]
+pub struct PyDrawRecorder {
+    sender: Sender<EngineCommand>,
+    commands: Vec<DrawCommand>,
+    capacity: usize,
+}
+
+impl PyDrawRecorder {
+    fn new(sender: Sender<EngineCommand>, capacity: usize) -> Self {
+        Self {
+            sender,
+            commands: Vec::with_capacity(capacity),
+            capacity,
+        }
+    }
+}
+
+#[pymethods]
+impl PyDrawRecorder {
+    /// Record a pixel at window coordinates.
+    #[pyo3(signature = (x, y, color, draw_order=0.0))]
+    fn pixel(&mut self, x: f32, y: f32, color: &PyColor, draw_order: f32) {
+        self.commands.push(DrawCommand::Pixel {
+            x,
+            y,
+            color: color.inner,
+            draw_order,
+        });
+    }
+
+    /// Record a line at window coordinates.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (start_x, start_y, end_x, end_y, color, thickness=1.0, draw_order=0.0))]
+    fn line(
+        &mut self,
+        start_x: f32,
+        start_y: f32,
+        end_x: f32,
+        end_y: f32,
+        color: &PyColor,
+        thickness: f32,
+        draw_order: f32,
+    ) {
+        self.commands.push(DrawCommand::Line {
+            start_x,
+            start_y,
+            end_x,
+            end_y,
+            thickness,
+            color: color.inner,
+            draw_order,
+        });
+    }
+
+    /// Record a rectangle at window coordinates.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (x, y, width, height, color, filled=true, thickness=1.0, draw_order=0.0))]
+    fn rectangle(
+        &mut self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        color: &PyColor,
+        filled: bool,
+        thickness: f32,
+        draw_order: f32,
+    ) {
+        self.commands.push(DrawCommand::Rectangle {
+            x,
+            y,
+            width,
+            height,
+            color: color.inner,
+            filled,
+            thickness,
+            draw_order,
+        });
+    }
+
+    /// Record a circle at window coordinates.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        center_x,
+        center_y,
+        radius,
+        color,
+        filled=true,
+        thickness=1.0,
+        segments=32,
+        draw_order=0.0
+    ))]
+    fn circle(
+        &mut self,
+        center_x: f32,
+        center_y: f32,
+        radius: f32,
+        color: &PyColor,
+        filled: bool,
+        thickness: f32,
+        segments: u32,
+        draw_order: f32,
+    ) {
+        self.commands.push(DrawCommand::Circle {
+            center_x,
+            center_y,
+            radius,
+            color: color.inner,
+            filled,
+            thickness,
+            segments,
+            draw_order,
+        });
+    }
+
+    /// Record a circle at each of `centers`, which may be `(x, y)` pairs,
+    /// `Vec2`s or a float array shaped `(n, 2)` such as particle positions.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        centers,
+        radius,
+        color,
+        filled=true,
+        thickness=1.0,
+        segments=32,
+        draw_order=0.0
+    ))]
+    fn circles(
+        &mut self,
+        centers: &Bound<'_, PyAny>,
+        radius: f32,
+        color: &PyColor,
+        filled: bool,
+        thickness: f32,
+        segments: u32,
+        draw_order: f32,
+    ) -> PyResult<()> {
+        let centers = extract_points(centers)?;
+        self.commands
+            .extend(centers.into_iter().map(|center| DrawCommand::Circle {
+                center_x: center.x(),
+                center_y: center.y(),
+                radius,
+                color: color.inner,
+                filled,
+                thickness,
+                segments,
+                draw_order,
+            }));
+        Ok(())
+    }
+
+    /// Record an image from a file path at window coordinates.
+    #[pyo3(signature = (x, y, width, height, texture_path, draw_order=0.0))]
+    fn image(&mut self, x: f32, y: f32, width: f32, height: f32, texture_path: String, draw_order: f32) {
+        self.commands.push(DrawCommand::Image {
+            x,
+            y,
+            width,
+            height,
+            texture_path,
+            draw_order,
+        });
+    }
+
+    /// Record text at window coordinates with the default font.
+    ///
+    /// Use `add(DrawCommand.text(...))` for custom fonts and spacing.
+    #[pyo3(signature = (text, x, y, color, font_size=24.0, draw_order=0.0))]
+    fn text(
+        &mut self,
+        text: String,
+        x: f32,
+        y: f32,
+        color: &PyColor,
+        font_size: f32,
+        draw_order: f32,
+    ) -> PyResult<()> {
+        let style = build_text_style(font_size, None, None, None, None, 0.0, 0.0, true)?;
+        self.commands.push(DrawCommand::Text {
+            text,
+            x,
+            y,
+            style,
+            color: color.inner,
+            layout: TextLayoutOptions::default(),
+            draw_order,
+        });
+        Ok(())
+    }
+
+    /// Record a prebuilt `DrawCommand`.
+    fn add(&mut self, command: &PyDrawCommand) {
+        self.commands.push(command.inner.clone());
+    }
+
+    /// Submit the recorded commands in one batch and start a new buffer.
+    fn flush(&mut self) {
+        if self.commands.is_empty() {
+            return;
+        }
+        let capacity = self.capacity.max(self.commands.len());
+        let commands = std::mem::replace(&mut self.commands, Vec::with_capacity(capacity));
+        let _ = self.sender.send(EngineCommand::AddDrawCommands(commands));
+    }
+
+    /// Discard the recorded commands without submitting them.
+    fn clear(&mut self) {
+        self.commands.clear();
+    }
+
+    fn __len__(&self) -> usize {
+        self.commands.len()
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    #[pyo3(signature = (exc_type=None, _exc_value=None, _traceback=None))]
+    fn __exit__(
+        &mut self,
+        exc_type: Option<Bound<'_, PyAny>>,
+        _exc_value: Option<Bound<'_, PyAny>>,
+        _traceback: Option<Bound<'_, PyAny>>,
+    ) -> bool {
+        if exc_type.is_some_and(|exc_type| !exc_type.is_none()) {
+            self.clear();
+        } else {
+            self.flush();
+        }
+        false
+    }
+
+    fn __repr__(&self) -> String {
+        format!("DrawRecorder(recorded={})", self.commands.len())
+    }
+}
+
+/// Python wrapper for the Rust Engine.
+#[pyclass(name = "Engine", unsendable)]
+pub struct PyEngine {
+    inner: RustEngine,
+    /// Event loop of the running manual loop, returned to the engine when it ends
+    event_loop: Option<EventLoop<()>>,
+    /// The manual loop ended; `poll_events` returns False until `initialize`.
+    session_ended: bool,
+}
+
+impl Drop for PyEngine {
+    fn drop(&mut self) {
+        // Keep the event loop for engines created later in the process.
+        if let Some(event_loop) = self.event_loop.take() {
+            RustEngine::return_event_loop(event_loop, false);
+        }
+    }
 }
 
 impl PyEngine {
+    /// End the manual loop: close the window and keep the event loop for the
+    /// next session.
+    fn end_manual_session(&mut self, exited: bool) {
+        if let Some(event_loop) = self.event_loop.take() {
+            RustEngine::return_event_loop(event_loop, exited);
+        }
+        self.session_ended = true;
+        self.inner.end_session();
+    }
+
     #[allow(clippy::too_many_arguments)]
+    /// Window settings given from Python, on top of the config file's.
     fn build_window_config(
         &self,
-        title: String,
-        width: u32,
-        height: u32,
-        resizable: bool,
+        title: Option<String>,
+        width: Option<u32>,
+        height: Option<u32>,
+        resizable: Option<bool>,
         background_color: Option<PyColor>,
-        vsync: bool,
+        vsync: Option<bool>,
         redraw_on_change_only: bool,
-        show_fps_in_title: bool,
+        show_fps_in_title: Option<bool>,
         icon_path: Option<String>,
         min_width: Option<u32>,
         min_height: Option<u32>,
     ) -> PyResult<WindowConfig> {
+        let defaults = self.inner.config().window_config();
         let mut config = WindowConfig::new()
-            .with_title(title)
-            .with_size(width, height)
-            .with_resizable(resizable)
+            .with_title(title.unwrap_or(defaults.title))
+            .with_size(width.unwrap_or(defaults.width), height.unwrap_or(defaults.height))
+            .with_resizable(resizable.unwrap_or(defaults.resizable))
             .with_fullscreen(FullscreenMode::None)
-            .with_vsync(vsync)
+            .with_vsync(vsync.unwrap_or(defaults.vsync))
             .with_redraw_on_change_only(redraw_on_change_only)
-            .with_show_fps_in_title(show_fps_in_title);
+            .with_show_fps_in_title(show_fps_in_title.unwrap_or(defaults.show_fps_in_title));
 
         if let Some(color) = background_color {
             config = config.with_background_color(color.inner);
@@ -1252,6 +2128,8 @@ impl PyEngine {
             config = config.with_icon(icon);
         }
 
+        let min_width = min_width.or(defaults.min_width);
+        let min_height = min_height.or(defaults.min_height);
         if let (Some(min_w), Some(min_h)) = (min_width, min_height) {
             config = config.with_min_size(min_w, min_h);
         }
@@ -1263,22 +2141,36 @@ impl PyEngine {
 #[pymethods]
 impl PyEngine {
     /// Create a new Engine instance with default logging (console only, INFO level).
+    ///
+    /// Settings come from `config_path`, or `pyg_engine.toml` in the working
+    /// directory when it is None; the logging arguments override the file.
     #[new]
-    #[pyo3(signature = (enable_file_logging=false, log_directory=None, log_level=None))]
+    #[pyo3(signature = (enable_file_logging=None, log_directory=None, log_level=None, config_path=None))]
     fn new(
-        enable_file_logging: bool,
+        enable_file_logging: Option<bool>,
         log_directory: Option<String>,
         log_level: Option<String>,
+        config_path: Option<String>,
     ) -> Self {
-        let inner = if enable_file_logging || log_directory.is_some() || log_level.is_some() {
-            RustEngine::with_logging(enable_file_logging, log_directory, log_level)
-        } else {
-            RustEngine::new()
+        let mut config = match config_path {
+            Some(path) => EngineConfig::load_or_default(std::path::Path::new(&path)),
+            None => EngineConfig::discover(),
         };
+        if let Some(enable_file_logging) = enable_file_logging {
+            config.logging.file = Some(enable_file_logging);
+        }
+        if let Some(log_directory) = log_directory {
+            config.logging.directory = Some(std::path::PathBuf::from(log_directory));
+        }
+        if log_level.is_some() {
+            config.logging.level = log_level;
+        }
+        let inner = RustEngine::with_config(config);
 
         Self {
             inner,
             event_loop: None,
+            session_ended: false,
         }
     }
 
@@ -1294,30 +2186,63 @@ impl PyEngine {
             .set_source_root(source_root.map(std::path::PathBuf::from));
     }
 
+    /// Path of the config file the engine read, or None.
+    #[getter]
+    fn config_path(&self) -> Option<String> {
+        self.inner
+            .config()
+            .path
+            .as_ref()
+            .map(|path| path.to_string_lossy().into_owned())
+    }
+
+    /// `[assets] root` from the config file, or None.
+    #[getter]
+    fn config_asset_root(&self) -> Option<String> {
+        self.inner
+            .config()
+            .asset_root
+            .as_ref()
+            .map(|path| path.to_string_lossy().into_owned())
+    }
+
+    /// Whether collision detection runs each fixed step, on by default.
+    #[getter]
+    fn collisions_enabled(&self) -> bool {
+        self.inner.collisions_enabled()
+    }
+
+    #[setter]
+    fn set_collisions_enabled(&mut self, enabled: bool) {
+        self.inner.set_collisions_enabled(enabled);
+    }
+
     /// Initialize the engine with window configuration without starting the loop.
+    ///
+    /// Window settings left as None come from the config file or the defaults.
     #[pyo3(signature = (
-        title="PyG Engine".to_string(),
-        width=1280,
-        height=720,
-        resizable=true,
+        title=None,
+        width=None,
+        height=None,
+        resizable=None,
         background_color=None,
-        vsync=true,
+        vsync=None,
         redraw_on_change_only=true,
-        show_fps_in_title=false,
+        show_fps_in_title=None,
         icon_path=None,
         min_width=None,
         min_height=None
     ))]
     fn initialize(
         &mut self,
-        title: String,
-        width: u32,
-        height: u32,
-        resizable: bool,
+        title: Option<String>,
+        width: Option<u32>,
+        height: Option<u32>,
+        resizable: Option<bool>,
         background_color: Option<PyColor>,
-        vsync: bool,
+        vsync: Option<bool>,
         redraw_on_change_only: bool,
-        show_fps_in_title: bool,
+        show_fps_in_title: Option<bool>,
         icon_path: Option<String>,
         min_width: Option<u32>,
         min_height: Option<u32>,
@@ -1336,115 +2261,339 @@ impl PyEngine {
             min_height,
         )?;
 
-        self.inner.set_window_config(config);
-        self.inner.set_auto_step_on_redraw(false);
+        if self.event_loop.is_some() {
+            return Err(PyRuntimeError::new_err("A manual loop is already running."));
+        }
+        let (event_loop, exited) =
+            RustEngine::take_event_loop().map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+        if exited {
+            RustEngine::return_event_loop(event_loop, true);
+            return Err(PyRuntimeError::new_err(
+                "The window system event loop was exited by an earlier run() or a failed \
+                 window and cannot be pumped again in this process; use run() instead.",
+            ));
+        }
 
-        // Create the event loop here.
-        // On macOS, force regular activation/menu policy so OS fullscreen behavior
-        // (menu bar reveal, focus transitions) matches native app expectations.
-        let event_loop = {
-            #[cfg(target_os = "macos")]
-            {
-                use winit::platform::macos::{ActivationPolicy, EventLoopBuilderExtMacOS};
-
-                let mut builder = EventLoop::builder();
-                builder.with_activation_policy(ActivationPolicy::Regular);
-                builder.with_default_menu(true);
-                builder
-                    .build()
-                    .map_err(|e| PyRuntimeError::new_err(e.to_string()))?
-            }
-            #[cfg(not(target_os = "macos"))]
-            {
-                EventLoop::new().map_err(|e| PyRuntimeError::new_err(e.to_string()))?
-            }
-        };
-        #[cfg(target_os = "macos")]
-        event_loop.set_control_flow(ControlFlow::Wait);
-        #[cfg(not(target_os = "macos"))]
-        event_loop.set_control_flow(ControlFlow::Poll);
+        self.inner.begin_session(config);
+        self.inner.set_auto_step_on_redraw(false);
         self.event_loop = Some(event_loop);
+        self.session_ended = false;
 
         Ok(())
     }
 
     /// Poll events from the window system. Returns True if the loop should continue, False if exit requested.
+    ///
+    /// The window is created by the first call. Once the window is closed or
+    /// `request_quit` is called, the window closes and later calls return
+    /// False without pumping again until the next `initialize`.
     fn poll_events(&mut self) -> PyResult<bool> {
-        if let Some(event_loop) = &mut self.event_loop {
-            // Manual mode polling must be non-blocking so animation/render loops
-            // keep advancing even when there is no user input.
-            let status: PumpStatus =
-                event_loop.pump_app_events(Some(Duration::from_millis(0)), &mut self.inner);
-            match status {
-                PumpStatus::Continue => Ok(true),
-                PumpStatus::Exit(_) => Ok(false),
-            }
-        } else {
-            Err(PyRuntimeError::new_err(
+        if take_callback_abort() && !self.session_ended {
+            self.end_manual_session(false);
+        }
+        raise_pending_callback_error()?;
+        if self.session_ended {
+            return Ok(false);
+        }
+        let Some(event_loop) = &mut self.event_loop else {
+            return Err(PyRuntimeError::new_err(
                 "Engine not initialized. Call start_manual() first.",
-            ))
+            ));
+        };
+        // Manual mode polling must be non-blocking so animation/render loops
+        // keep advancing even when there is no user input.
+        let status: PumpStatus =
+            event_loop.pump_app_events(Some(Duration::from_millis(0)), &mut self.inner);
+        let exited = matches!(status, PumpStatus::Exit(_));
+        if exited || self.inner.quit_requested() {
+            self.end_manual_session(exited);
+            return Ok(false);
         }
+        Ok(true)
     }
 
-    /// Run a single update step.
-    fn update(&mut self) {
-        self.inner.update();
+    /// End the game loop after the current frame: `run` returns and the next
+    /// `poll_events` returns False. The window closes and the engine, scene
+    /// included, can be started again.
+    fn request_quit(&mut self) {
+        self.inner.request_quit();
     }
 
-    /// Render a single frame.
-    fn render(&mut self) {
-        self.inner.render();
+    /// Whether `request_quit` was called, or the window closed, this session.
+    #[getter]
+    fn quit_requested(&self) -> bool {
+        self.inner.quit_requested()
     }
 
-    /// Set the window title.
-    #[pyo3(signature = (title))]
-    fn set_window_title(&mut self, title: String) {
-        self.inner.set_window_title(title);
+    /// End the session now: close the window, as if `poll_events` had
+    /// returned False, and clear a quit request.
+    fn end_session(&mut self) {
+        if self.event_loop.is_some() {
+            self.end_manual_session(false);
+        } else {
+            self.inner.end_session();
+        }
     }
 
-    /// Set the window icon from an image path.
+    /// Run a single update step.
     ///
-    /// In manual mode this can update an already-created window at runtime.
-    /// In initialized-but-not-yet-resumed mode this updates pending window config.
-    fn set_window_icon(&mut self, icon_path: String) -> PyResult<()> {
-        let resolved_icon_path = self.inner.resolve_source_path(&icon_path);
-        let icon = load_window_icon_from_path(&resolved_icon_path).map_err(PyRuntimeError::new_err)?;
-        self.inner.set_window_icon(icon);
-        Ok(())
-    }
+    /// Script hooks run between the update phases with the engine released,
+    /// so scripts can call back into it.
+    ///
+    /// Raises:
+    ///     Exception: A callback error waiting to be raised under the "raise"
+    ///         or "abort" callback error policy.
+    fn update(slf: &Bound<'_, Self>) -> PyResult<()> {
+        raise_pending_callback_error()?;
+        let calls = {
+            let mut engine = slf.borrow_mut();
+            engine.inner.update_frame();
+            engine.inner.take_script_calls()
+        };
+        profile_scripts(slf, || run_script_calls(calls));
+
+        loop {
+            let (fixed, calls) = {
+                let mut engine = slf.borrow_mut();
+                let fixed = engine.inner.update_fixed();
+                (fixed, engine.inner.take_script_calls())
+            };
+            profile_scripts(slf, || run_script_calls(calls));
+            if !fixed {
+                break;
+            }
+
+            let calls = {
+                let mut engine = slf.borrow_mut();
+                engine.inner.update_collisions();
+                engine.inner.take_script_calls()
+            };
+            profile_scripts(slf, || run_script_calls(calls));
+        }
+
+        let calls = slf.borrow_mut().inner.event_bus.take_dispatch();
+        profile_scripts(slf, || run_event_calls(calls));
+        Ok(())
+    }
+
+    /// Render a single frame.
+    ///
+    /// Raises:
+    ///     Exception: A callback error waiting to be raised, as for `update`.
+    fn render(&mut self) -> PyResult<()> {
+        raise_pending_callback_error()?;
+        self.inner.render();
+        Ok(())
+    }
+
+    /// Set the window title.
+    #[pyo3(signature = (title))]
+    fn set_window_title(&mut self, title: String) {
+        self.inner.set_window_title(title);
+    }
+
+    /// Set the window icon from an image path.
+    ///
+    /// In manual mode this can update an already-created window at runtime.
+    /// In initialized-but-not-yet-resumed mode this updates pending window config.
+    fn set_window_icon(&mut self, icon_path: String) -> PyResult<()> {
+        let resolved_icon_path = self.inner.resolve_source_path(&icon_path);
+        let icon = load_window_icon_from_path(&resolved_icon_path).map_err(PyRuntimeError::new_err)?;
+        self.inner.set_window_icon(icon);
+        Ok(())
+    }
 
     /// Get the current display size (window client size) in pixels.
     fn get_display_size(&self) -> (u32, u32) {
         self.inner.get_display_size()
     }
 
+    /// Enable or disable VSync at runtime.
+    ///
+    /// Reconfigures the surface present mode immediately when a window exists.
+    /// Before the window is created, the value overrides the `vsync` argument
+    /// passed to `run()` / `start_manual()`.
+    fn set_vsync(&mut self, enabled: bool) {
+        self.inner.set_vsync(enabled);
+    }
+
+    /// Get whether VSync is currently enabled.
+    fn is_vsync_enabled(&self) -> bool {
+        self.inner.is_vsync_enabled()
+    }
+
+    /// Cap the frame rate to `fps` frames per second.
+    ///
+    /// Pass `None` (or a value <= 0) to remove the cap. Useful for keeping menus and
+    /// idle screens battery-friendly when VSync is off.
+    ///
+    /// # Example
+    /// ```python
+    /// engine.set_vsync(False)
+    /// engine.set_target_fps(30)   # menu
+    /// engine.set_target_fps(None) # gameplay, uncapped
+    /// ```
+    #[pyo3(signature = (fps))]
+    fn set_target_fps(&mut self, fps: Option<f32>) {
+        self.inner.set_target_fps(fps);
+    }
+
+    /// Get the frame rate cap, or `None` when uncapped.
+    fn get_target_fps(&self) -> Option<f32> {
+        self.inner.target_fps()
+    }
+
+    /// Show or hide the mouse cursor over the window.
+    fn set_cursor_visible(&mut self, visible: bool) {
+        self.inner.set_cursor_visible(visible);
+    }
+
+    /// Get whether the mouse cursor is shown (ignoring auto-hide).
+    fn is_cursor_visible(&self) -> bool {
+        self.inner.is_cursor_visible()
+    }
+
+    /// Hide the cursor automatically while a gamepad is the active input device.
+    ///
+    /// The cursor reappears as soon as the mouse or keyboard is used again.
+    fn set_auto_hide_cursor(&mut self, enabled: bool) {
+        self.inner.set_auto_hide_cursor(enabled);
+    }
+
+    /// Get whether the cursor is hidden automatically during gamepad use.
+    fn get_auto_hide_cursor(&self) -> bool {
+        self.inner.auto_hide_cursor()
+    }
+
+    /// Confine the cursor to the window or lock it in place.
+    ///
+    /// # Arguments
+    /// * `mode` - "none", "confined" (cursor stays inside the window) or
+    ///   "locked" (cursor stays in place)
+    ///
+    /// While grabbed, mouse movement is read from raw device motion so
+    /// `input.mouse_delta` and the "Mouse X"/"Mouse Y" axes keep working.
+    ///
+    /// Raises:
+    ///     ValueError: If `mode` is not a known grab mode.
+    fn set_cursor_grab(&mut self, mode: &str) -> PyResult<()> {
+        self.inner.set_cursor_grab(parse_cursor_grab_mode(mode)?);
+        Ok(())
+    }
+
+    /// Get the cursor grab mode: "none", "confined" or "locked".
+    fn get_cursor_grab(&self) -> &'static str {
+        cursor_grab_mode_name(self.inner.cursor_grab())
+    }
+
+    /// Show a built-in cursor shape over the window.
+    ///
+    /// # Arguments
+    /// * `cursor` - "default", "hand", "crosshair", "text", "wait", "move",
+    ///   "not_allowed", "resize_horizontal", "resize_vertical",
+    ///   "resize_diagonal", "resize_anti_diagonal", or any CSS cursor name
+    ///
+    /// Replaces a custom cursor image. UI hover cursors take precedence.
+    ///
+    /// Raises:
+    ///     ValueError: If the cursor name is unknown.
+    fn set_cursor(&mut self, cursor: &str) -> PyResult<()> {
+        self.inner.set_cursor_icon(parse_cursor_icon(cursor)?);
+        Ok(())
+    }
+
+    /// Get the cursor shape as a CSS cursor name, or `None` while a custom
+    /// cursor image is shown.
+    fn get_cursor(&self) -> Option<&'static str> {
+        self.inner.cursor_icon().map(|icon| icon.name())
+    }
+
+    /// Show a custom cursor image over the window.
+    ///
+    /// # Arguments
+    /// * `rgba` - `width * height * 4` bytes of RGBA pixels, row by row
+    /// * `width`, `height` - Image size in pixels
+    /// * `hotspot_x`, `hotspot_y` - Pixel that points, from the top-left
+    ///
+    /// Raises:
+    ///     ValueError: If the buffer size doesn't match or the hotspot is
+    ///     outside the image.
+    #[pyo3(signature = (rgba, width, height, hotspot_x=0, hotspot_y=0))]
+    fn set_custom_cursor(
+        &mut self,
+        rgba: Vec<u8>,
+        width: u16,
+        height: u16,
+        hotspot_x: u16,
+        hotspot_y: u16,
+    ) -> PyResult<()> {
+        self.inner
+            .set_custom_cursor(rgba, width, height, hotspot_x, hotspot_y)
+            .map_err(PyValueError::new_err)
+    }
+
+    /// Let the input method (IME) compose text even when no UI text input
+    /// has focus, e.g. for an in-game console reading `input.typed_text`.
+    fn set_ime_allowed(&mut self, allowed: bool) {
+        self.inner.set_ime_allowed(allowed);
+    }
+
+    fn get_ime_allowed(&self) -> bool {
+        self.inner.ime_allowed()
+    }
+
+    /// Text on the system clipboard, or an empty string when it holds no text.
+    ///
+    /// Raises:
+    ///     RuntimeError: If the clipboard cannot be opened.
+    fn clipboard_get(&self) -> PyResult<String> {
+        self.inner.clipboard_get().map_err(PyRuntimeError::new_err)
+    }
+
+    /// Put text on the system clipboard.
+    ///
+    /// Raises:
+    ///     RuntimeError: If the clipboard cannot be opened.
+    fn clipboard_set(&self, text: &str) -> PyResult<()> {
+        self.inner.clipboard_set(text).map_err(PyRuntimeError::new_err)
+    }
+
     /// Run the engine with a basic window configuration (blocking).
+    ///
+    /// `on_update` is called with the frame's delta time once per frame,
+    /// inside the native loop between update and render. The engine is busy
+    /// while it runs, so it should act through an `EngineHandle`. Returning
+    /// False exits the loop; an exception exits it and is raised from `run`,
+    /// as do callback errors under the "raise" and "abort" policies.
     #[pyo3(signature = (
-        title="PyG Engine".to_string(),
-        width=1280,
-        height=720,
-        resizable=true,
+        title=None,
+        width=None,
+        height=None,
+        resizable=None,
         background_color=None,
-        vsync=true,
+        vsync=None,
         redraw_on_change_only=true,
-        show_fps_in_title=false,
+        show_fps_in_title=None,
         icon_path=None,
         min_width=None,
-        min_height=None
+        min_height=None,
+        on_update=None
     ))]
+    #[allow(clippy::too_many_arguments)]
     fn run(
         &mut self,
-        title: String,
-        width: u32,
-        height: u32,
-        resizable: bool,
+        title: Option<String>,
+        width: Option<u32>,
+        height: Option<u32>,
+        resizable: Option<bool>,
         background_color: Option<PyColor>,
-        vsync: bool,
+        vsync: Option<bool>,
         redraw_on_change_only: bool,
-        show_fps_in_title: bool,
+        show_fps_in_title: Option<bool>,
         icon_path: Option<String>,
         min_width: Option<u32>,
         min_height: Option<u32>,
+        on_update: Option<Py<PyAny>>,
     ) -> PyResult<()> {
         let config = self.build_window_config(
             title,
@@ -1460,10 +2609,36 @@ impl PyEngine {
             min_height,
         )?;
 
+        // The first exception ends the loop and is raised once it returns.
+        let callback_error: Rc<RefCell<Option<PyErr>>> = Rc::default();
+        {
+            let callback_error = Rc::clone(&callback_error);
+            self.inner.set_frame_callback(Some(Box::new(move |delta_time| {
+                if let Some(e) = take_pending_callback_error() {
+                    *callback_error.borrow_mut() = Some(e);
+                    return false;
+                }
+                let Some(on_update) = &on_update else {
+                    return true;
+                };
+                Python::attach(|py| match on_update.call1(py, (delta_time,)) {
+                    Ok(result) => !matches!(result.extract::<bool>(py), Ok(false)),
+                    Err(e) => {
+                        *callback_error.borrow_mut() = Some(e);
+                        false
+                    }
+                })
+            })));
+        }
+
         self.inner.set_auto_step_on_redraw(true);
-        self.inner
-            .run(config)
-            .map_err(|e| PyRuntimeError::new_err(e.to_string()))
+        let result = self.inner.run(config);
+        self.inner.set_frame_callback(None);
+        take_callback_abort();
+        if let Some(e) = callback_error.take() {
+            return Err(e);
+        }
+        result.map_err(|e| PyRuntimeError::new_err(e.to_string()))
     }
 
     /// Add a GameObject to the engine.
@@ -1493,6 +2668,21 @@ impl PyEngine {
         })
     }
 
+    /// Get the ids of runtime GameObjects with a tag, in creation order.
+    fn find_by_tag(&self, tag: &str) -> Vec<u32> {
+        self.inner.find_by_tag(tag)
+    }
+
+    /// Get the ids of runtime GameObjects with a name, in creation order.
+    fn find_by_name(&self, name: &str) -> Vec<u32> {
+        self.inner.find_by_name(name)
+    }
+
+    /// Get the ids of every runtime GameObject, in the order they were added.
+    fn get_all_ids(&self) -> Vec<u32> {
+        self.inner.get_all_ids()
+    }
+
     fn get_game_object_name(&self, name: &str) -> Vec<PyGameObject> {
         self.inner
             .get_game_object_clones_by_name(name)
@@ -1528,6 +2718,147 @@ impl PyEngine {
         self.inner.remove_game_object(object_id);
     }
 
+    /// Queue an event for dispatch at the end of the update.
+    #[pyo3(signature = (name, payload=None))]
+    fn emit_event(&mut self, name: String, payload: Option<&Bound<'_, PyAny>>) {
+        let payload = payload.map_or(EventPayload::None, payload_from_py);
+        self.inner.event_bus.emit(name, payload);
+    }
+
+    /// Subscribe a callable to an event; it is called with the event payload.
+    /// Returns the subscription id.
+    fn on_event(&mut self, name: String, callback: Py<PyAny>) -> u64 {
+        let event_name = name.clone();
+        self.inner.event_bus.subscribe(name, move |event| {
+            pyo3::Python::attach(|py| {
+                let result = payload_to_py(py, event.payload()).and_then(|payload| callback.call1(py, (payload,)));
+                if let Err(e) = result {
+                    report_callback_error(py, &format!("'{}' event handler", event_name), e);
+                }
+            });
+        })
+    }
+
+    /// Remove an event subscription. Returns False if it does not exist.
+    fn off_event(&mut self, subscription: u64) -> bool {
+        self.inner.event_bus.unsubscribe(subscription)
+    }
+
+    /// What happens after a callback exception is logged: "log", "raise" or "abort".
+    #[getter]
+    fn callback_error_policy(&self) -> &'static str {
+        callback_error_policy().name()
+    }
+
+    #[setter]
+    fn set_callback_error_policy(&mut self, policy: &str) -> PyResult<()> {
+        let policy = CallbackErrorPolicy::from_name(policy).ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "Unknown callback error policy '{policy}'; expected 'log', 'raise' or 'abort'"
+            ))
+        })?;
+        set_callback_error_policy(policy);
+        Ok(())
+    }
+
+    /// Exceptions raised by callbacks the engine called, oldest first.
+    ///
+    /// Returns:
+    ///     list[CallbackError]
+    #[pyo3(signature = (clear=true))]
+    fn get_callback_errors(&self, clear: bool) -> Vec<PyCallbackError> {
+        callback_errors(clear)
+    }
+
+    /// Report an exception from a callback run by Python code, such as a timer,
+    /// so it is logged and handled like the engine's own callbacks.
+    fn report_callback_error(&self, py: Python<'_>, source: &str, error: Bound<'_, PyAny>) {
+        report_callback_error(py, source, PyErr::from_value(error));
+    }
+
+    /// Send a message to the components of a runtime GameObject, delivered
+    /// in the next update phase. Returns False if the object does not exist.
+    #[pyo3(signature = (target_id, name, payload=None))]
+    fn send_message(&mut self, target_id: u32, name: String, payload: Option<&Bound<'_, PyAny>>) -> bool {
+        let payload = payload.map_or(EventPayload::None, payload_from_py);
+        self.inner.send_message(target_id, name, payload)
+    }
+
+    /// Mark a runtime GameObject, and its children, for removal at the end of the frame.
+    fn destroy_object(&mut self, object_id: u32) -> bool {
+        self.inner.destroy_object(object_id)
+    }
+
+    /// Whether a runtime GameObject is marked for removal at the end of the frame.
+    fn is_destroy_pending(&self, object_id: u32) -> bool {
+        self.inner.is_destroy_pending(object_id)
+    }
+
+    /// Load a scene and make it active, unloading the others unless `additive`.
+    #[pyo3(signature = (name, additive=false))]
+    fn load_scene(&mut self, name: &str, additive: bool) {
+        self.inner.load_scene(name, additive);
+    }
+
+    /// Unload a scene and destroy its objects. Returns false if it was not loaded.
+    fn unload_scene(&mut self, name: &str) -> bool {
+        self.inner.unload_scene(name)
+    }
+
+    /// Names of the loaded scenes, in load order.
+    fn loaded_scenes(&self) -> Vec<String> {
+        self.inner
+            .scene_manager
+            .scenes()
+            .iter()
+            .map(|scene| scene.name().to_string())
+            .collect()
+    }
+
+    /// Scene that new objects are added to.
+    fn get_active_scene(&self) -> Option<String> {
+        self.inner.scene_manager.active_scene().map(str::to_string)
+    }
+
+    /// Set the scene new objects are added to; None adds them to no scene.
+    #[pyo3(signature = (name=None))]
+    fn set_active_scene(&mut self, name: Option<&str>) -> bool {
+        self.inner.scene_manager.set_active_scene(name)
+    }
+
+    /// Ids of a loaded scene's objects, empty if it is not loaded.
+    fn scene_objects(&self, name: &str) -> Vec<u32> {
+        self.inner
+            .scene_manager
+            .scene(name)
+            .map(|scene| scene.object_ids().to_vec())
+            .unwrap_or_default()
+    }
+
+    /// Scene an object belongs to, or None.
+    fn get_object_scene(&self, object_id: u32) -> Option<String> {
+        self.inner.scene_manager.scene_of(object_id).map(str::to_string)
+    }
+
+    /// Save a loaded scene's objects to a JSON scene file.
+    fn save_scene(&self, name: &str, path: &str) -> PyResult<()> {
+        self.inner.save_scene(name, path).map_err(PyRuntimeError::new_err)
+    }
+
+    /// Add the objects in a scene file to the active scene and return their ids.
+    fn add_scene_file_objects(&mut self, path: &str) -> PyResult<Vec<u32>> {
+        self.inner
+            .add_scene_file_objects(path)
+            .map(|(_, ids)| ids)
+            .map_err(PyRuntimeError::new_err)
+    }
+
+    /// Move an object to a loaded scene, or out of every scene with None.
+    #[pyo3(signature = (object_id, scene=None))]
+    fn set_object_scene(&mut self, object_id: u32, scene: Option<&str>) -> bool {
+        self.inner.scene_manager.move_object(object_id, scene)
+    }
+
     /// Update a runtime GameObject's position by id.
     fn set_game_object_position(&mut self, object_id: u32, position: PyVec2) -> bool {
         self.inner
@@ -1921,6 +3252,41 @@ impl PyEngine {
         }
     }
 
+    /// Enable pixel-perfect camera rendering at a fixed virtual resolution.
+    ///
+    /// The world is rendered to an offscreen target of `width` x `height` pixels,
+    /// then upscaled by the largest integer factor that fits the window and centered
+    /// with black letterbox bars. The camera snaps to whole virtual pixels so low-res
+    /// pixel art never shimmers or shows uneven pixels. Screen-space draws and UI are
+    /// composited on top at full window resolution.
+    ///
+    /// Call with no arguments (or `None`) to disable.
+    ///
+    /// # Returns
+    /// `true` if the mode was applied, `false` if only one dimension was given,
+    /// or a dimension is zero or larger than the GPU's maximum texture size.
+    ///
+    /// # Example
+    /// ```python
+    /// engine.set_camera_pixel_perfect(320, 180)
+    /// engine.set_camera_viewport_size(20.0, 11.25)
+    /// ```
+    #[pyo3(signature = (width=None, height=None))]
+    fn set_camera_pixel_perfect(&mut self, width: Option<u32>, height: Option<u32>) -> bool {
+        match (width, height) {
+            (Some(width), Some(height)) => self
+                .inner
+                .set_camera_pixel_perfect_resolution(Some((width, height))),
+            (None, None) => self.inner.set_camera_pixel_perfect_resolution(None),
+            _ => false,
+        }
+    }
+
+    /// Get the pixel-perfect virtual resolution as `(width, height)`, or `None` when disabled.
+    fn get_camera_pixel_perfect(&self) -> Option<(u32, u32)> {
+        self.inner.camera_pixel_perfect_resolution()
+    }
+
     /// Convert world-space coordinates to screen-space pixel coordinates.
     ///
     /// Transforms a position from the game world's coordinate system into window pixel
@@ -2124,6 +3490,12 @@ impl PyEngine {
         self.inner.add_draw_commands(runtime_commands);
     }
 
+    /// Create a `DrawRecorder` that batches draw calls into one submission.
+    #[pyo3(signature = (capacity=1024))]
+    fn draw_recorder(&self, capacity: usize) -> PyDrawRecorder {
+        PyDrawRecorder::new(self.inner.get_command_sender(), capacity)
+    }
+
     /// Draw a pixel at window coordinates.
     #[pyo3(signature = (x, y, color, draw_order=0.0))]
     fn draw_pixel(&mut self, x: u32, y: u32, color: &PyColor, draw_order: f32) {
@@ -2284,7 +3656,7 @@ impl PyEngine {
         width: f32,
         height: f32,
         texture_key: String,
-        rgba: Vec<u8>,
+        rgba: &Bound<'_, PyAny>,
         texture_width: u32,
         texture_height: u32,
         draw_order: f32,
@@ -2296,7 +3668,7 @@ impl PyEngine {
                 width,
                 height,
                 texture_key,
-                rgba,
+                extract_rgba(rgba)?,
                 texture_width,
                 texture_height,
                 draw_order,
@@ -2359,6 +3731,40 @@ impl PyEngine {
         Ok(())
     }
 
+    /// Draw text centered on a world-space point. It moves with the camera and
+    /// scales with zoom (one text pixel per world unit).
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        text,
+        x,
+        y,
+        color,
+        font_size=24.0,
+        font_path=None,
+        font_family=None,
+        font_weight=None,
+        font_style=None,
+        draw_order=0.0
+    ))]
+    fn draw_world_text(
+        &mut self,
+        text: String,
+        x: f32,
+        y: f32,
+        color: &PyColor,
+        font_size: f32,
+        font_path: Option<String>,
+        font_family: Option<String>,
+        font_weight: Option<&str>,
+        font_style: Option<&str>,
+        draw_order: f32,
+    ) -> PyResult<()> {
+        let style = build_text_style(font_size, font_path, font_family, font_weight, font_style, 0.0, 0.0, true)?;
+        self.inner
+            .draw_world_text(text, x, y, style, color.inner, draw_order);
+        Ok(())
+    }
+
     #[pyo3(signature = (
         family,
         regular=None,
@@ -2380,956 +3786,1385 @@ impl PyEngine {
         )
     }
 
-    #[pyo3(signature = (
-        text,
-        font_size=24.0,
-        font_path=None,
-        font_family=None,
-        font_weight=None,
-        font_style=None,
-        letter_spacing=0.0,
-        line_spacing=0.0,
-        kerning=true
-    ))]
-    fn measure_text(
-        &mut self,
-        text: String,
-        font_size: f32,
-        font_path: Option<String>,
-        font_family: Option<String>,
-        font_weight: Option<&str>,
-        font_style: Option<&str>,
-        letter_spacing: f32,
-        line_spacing: f32,
-        kerning: bool,
-    ) -> PyResult<(f32, f32)> {
-        let style = build_text_style(
-            font_size,
-            font_path,
-            font_family,
-            font_weight,
-            font_style,
-            letter_spacing,
-            line_spacing,
-            kerning,
-        )?;
-        Ok(self.inner.measure_text(&text, style))
+    /// Set the fonts tried, in order, for characters a text style's own font lacks.
+    ///
+    /// Entries are registered family names or `.ttf`/`.otf` paths. Applies to
+    /// all text whose style lists no fallbacks of its own.
+    fn set_font_fallbacks(&mut self, fallbacks: Vec<String>) {
+        self.inner.set_font_fallbacks(fallbacks);
     }
 
-    /// Update a UI label's text at runtime by object ID.
-    fn update_ui_label_text(&self, object_id: u32, text: String) {
-        let _ = self
-            .inner
-            .get_command_sender()
-            .send(EngineCommand::UpdateUILabelText { object_id, text });
+    fn get_font_fallbacks(&self) -> Vec<String> {
+        self.inner.font_fallbacks().to_vec()
     }
 
-    /// Update a UI button's text at runtime by object ID.
-    fn update_ui_button_text(&self, object_id: u32, text: String) {
-        let _ = self
-            .inner
-            .get_command_sender()
-            .send(EngineCommand::UpdateUIButtonText { object_id, text });
+    /// Set the UI theme's fallback fonts, used instead of the renderer's for UI text.
+    fn set_ui_font_fallbacks(&mut self, fallbacks: Vec<String>) {
+        self.inner.set_ui_font_fallbacks(fallbacks);
     }
 
-    /// Log a message at INFO level (default log method).
-    fn log(&self, message: &str) {
-        self.inner.log(message);
+    fn get_ui_font_fallbacks(&self) -> Vec<String> {
+        self.inner.ui_font_fallbacks().to_vec()
     }
 
-    /// Log a message at TRACE level (most verbose).
-    fn log_trace(&self, message: &str) {
-        self.inner.log_trace(message);
+    /// Register a UI theme made from a registered `base` theme with some colors replaced.
+    ///
+    /// Args:
+    ///     name: Theme name; registering an existing name replaces that theme
+    ///     colors: (key, (r, g, b, a)) pairs applied in order, with keys such as
+    ///         "button.background" or "button.hovered.background"
+    ///     base: Name of the theme to start from
+    ///
+    /// Raises:
+    ///     ValueError: If the base theme or a color key is unknown.
+    #[pyo3(signature = (name, colors, base="light"))]
+    fn register_ui_theme(
+        &mut self,
+        name: &str,
+        colors: Vec<(String, (f32, f32, f32, f32))>,
+        base: &str,
+    ) -> PyResult<()> {
+        let mut theme = self
+            .inner
+            .ui_theme(base)
+            .cloned()
+            .ok_or_else(|| PyValueError::new_err(format!("Unknown UI theme '{base}'")))?;
+        for (key, (r, g, b, a)) in colors {
+            theme.set_color(&key, [r, g, b, a]).map_err(PyValueError::new_err)?;
+        }
+        self.inner.register_ui_theme(name, theme);
+        Ok(())
     }
 
-    /// Log a message at DEBUG level.
-    fn log_debug(&self, message: &str) {
-        self.inner.log_debug(message);
+    /// Switch the UI to a registered theme, restyling existing widgets.
+    ///
+    /// Raises:
+    ///     ValueError: If no theme has this name.
+    fn set_ui_theme(&mut self, name: &str) -> PyResult<()> {
+        self.inner.set_ui_theme(name).map_err(PyValueError::new_err)
     }
 
-    /// Log a message at INFO level.
-    fn log_info(&self, message: &str) {
-        self.inner.log_info(message);
+    fn get_ui_theme(&self) -> String {
+        self.inner.ui_theme_name().to_string()
     }
 
-    /// Log a message at WARN level.
-    fn log_warn(&self, message: &str) {
-        self.inner.log_warn(message);
+    /// Set how UI units map to window pixels: "constant_pixel_size",
+    /// "scale_with_height" or "fixed_resolution" (letterboxed). The reference
+    /// size is the resolution the UI was designed at; "scale_with_height"
+    /// only uses its height.
+    ///
+    /// Raises:
+    ///     ValueError: For an unknown mode or a non-positive reference size.
+    #[pyo3(signature = (mode, reference_width=1920.0, reference_height=1080.0))]
+    fn set_ui_scale_mode(&mut self, mode: &str, reference_width: f32, reference_height: f32) -> PyResult<()> {
+        let scale_mode = parse_ui_scale_mode(mode, reference_width, reference_height)?;
+        self.inner.set_ui_scale_mode(scale_mode);
+        Ok(())
     }
 
-    /// Log a message at ERROR level.
-    fn log_error(&self, message: &str) {
-        self.inner.log_error(message);
+    fn get_ui_scale_mode(&self) -> String {
+        self.inner.ui_scale_mode().as_str().to_string()
     }
 
-    /// Get the engine version.
-    #[getter]
-    fn version(&self) -> String {
-        self.inner.version().to_string()
+    /// Give a UI widget keyboard focus, or clear focus with `None`.
+    ///
+    /// Returns `False` if the widget is hidden, non-interactable or can't
+    /// take focus.
+    #[pyo3(signature = (object_id=None))]
+    fn set_ui_focus(&mut self, object_id: Option<u32>) -> bool {
+        self.inner.set_ui_focus(object_id)
     }
 
-    /// Get the time since the last frame in seconds.
-    /// Get time since last frame in **seconds**.
-    ///
-    /// Returns delta time (dt) - the duration of the previous frame in **seconds**.
-    /// Use for frame-rate independent movement, animations, and time-based logic.
-    ///
-    /// # Time Units: Seconds
-    /// **IMPORTANT:** Returns time in **seconds**, not milliseconds.
-    ///
-    /// # Typical Values
-    /// - 60 FPS: ~0.016 seconds (16ms)
-    /// - 30 FPS: ~0.033 seconds (33ms)
-    /// - 120 FPS: ~0.008 seconds (8ms)
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// engine = pyg.Engine()
-    /// player_x = 0.0
-    /// speed = 200.0  # pixels per second
-    ///
-    /// def update(dt, engine, data):
-    ///     # dt is delta_time in seconds
-    ///     data['x'] += speed * dt  # Move 200 pixels/sec regardless of FPS
-    ///
-    ///     # Or access directly
-    ///     dt_direct = engine.delta_time
-    ///     print(f"Frame time: {dt_direct:.4f}s, FPS: {1.0/dt_direct:.1f}")
-    ///     return True
-    ///
-    /// engine.run(update=update, user_data={'x': 0.0})
-    /// ```
-    ///
-    /// # See Also
-    /// - `examples/python_input_demo.py` - Movement with delta time
-    /// - `Time` class - Standalone time tracking
-    #[getter]
-    fn delta_time(&self) -> f32 {
-        self.inner.time.delta_time()
+    /// Object id of the focused UI widget, or `None`.
+    fn get_ui_focus(&self) -> Option<u32> {
+        self.inner.ui_focused_object()
     }
 
-    /// Get total time since engine start in **seconds**.
-    ///
-    /// Returns elapsed time - the total duration since `Engine.start_manual()` or `Engine.run()`
-    /// was called, measured in **seconds**.
-    ///
-    /// Useful for time-based effects, periodic events, and timers.
-    ///
-    /// # Time Units: Seconds
-    /// **IMPORTANT:** Returns time in **seconds**, not milliseconds.
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    /// import math
-    ///
-    /// engine = pyg.Engine()
-    /// engine.start_manual()
-    ///
-    /// while engine.poll_events():
-    ///     # Get elapsed time
-    ///     t = engine.elapsed_time
+    /// Grey out a UI element and its children and stop them taking input,
+    /// while keeping them visible.
+    fn set_ui_interactable(&mut self, object_id: u32, interactable: bool) {
+        self.inner.set_ui_interactable(object_id, interactable);
+    }
+
+    fn is_ui_interactable(&self, object_id: u32) -> bool {
+        self.inner.is_ui_interactable(object_id)
+    }
+
+    /// Set the cursor shown while the pointer is over a UI element or its
+    /// children, or `None` to remove it. Accepts the names `set_cursor` does.
+    #[pyo3(signature = (object_id, cursor=None))]
+    fn set_ui_hover_cursor(&mut self, object_id: u32, cursor: Option<&str>) -> PyResult<()> {
+        let cursor = cursor.map(parse_cursor_icon).transpose()?;
+        self.inner.set_ui_hover_cursor(object_id, cursor);
+        Ok(())
+    }
+
+    /// Hover cursor set on a UI element as a CSS cursor name, or `None`.
+    fn get_ui_hover_cursor(&self, object_id: u32) -> Option<&'static str> {
+        self.inner.ui_hover_cursor(object_id).map(|icon| icon.name())
+    }
+
+    /// Make a UI element and its children take input only while `context`
+    /// is the top input context, or `None` to take input in any context.
+    #[pyo3(signature = (object_id, context=None))]
+    fn set_ui_input_context(&mut self, object_id: u32, context: Option<String>) {
+        self.inner.set_ui_input_context(object_id, context);
+    }
+
+    fn get_ui_input_context(&self, object_id: u32) -> Option<String> {
+        self.inner.ui_input_context(object_id)
+    }
+
+    /// Set the outline drawn around the focused widget. A width of 0 hides it;
+    /// the color comes from the theme's "focus_ring" color.
+    #[pyo3(signature = (width=2.0, offset=2.0, corner_radius=4.0))]
+    fn set_ui_focus_ring(&mut self, width: f32, offset: f32, corner_radius: f32) {
+        self.inner.set_ui_focus_ring(FocusRing {
+            width: width.max(0.0),
+            offset,
+            corner_radius: corner_radius.max(0.0),
+        });
+    }
+
+    /// Animate a UI element's property to `to` over `duration` seconds.
     ///
-    ///     # Pulsing scale based on time
-    ///     scale = 1.0 + 0.3 * math.sin(t * 2.0)
+    /// Number properties take a one-item list, color properties an RGB(A) list.
+    /// Starts from the property's value when the delay ends unless `from_`
+    /// is given. Returns the tween id.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (object_id, property, to, duration, easing="linear", delay=0.0, from_=None))]
+    fn tween_ui(
+        &mut self,
+        object_id: u32,
+        property: &str,
+        to: Vec<f32>,
+        duration: f32,
+        easing: &str,
+        delay: f32,
+        from_: Option<Vec<f32>>,
+    ) -> PyResult<u32> {
+        let tween = build_ui_tween(object_id, property, &to, duration, easing, delay, from_)?;
+        Ok(self.inner.start_ui_tween(tween))
+    }
+
+    /// Stop a UI tween where it is. Returns `True` if it was still running.
+    fn cancel_ui_tween(&mut self, tween_id: u32) -> bool {
+        self.inner.cancel_ui_tween(tween_id)
+    }
+
+    /// Ids of the UI tweens that ended since the last call.
+    fn take_finished_ui_tweens(&mut self) -> Vec<u32> {
+        self.inner.take_finished_ui_tweens()
+    }
+
+    fn ui_theme_names(&self) -> Vec<String> {
+        self.inner.ui_theme_names()
+    }
+
+    #[pyo3(signature = (
+        text,
+        font_size=24.0,
+        font_path=None,
+        font_family=None,
+        font_weight=None,
+        font_style=None,
+        letter_spacing=0.0,
+        line_spacing=0.0,
+        kerning=true
+    ))]
+    fn measure_text(
+        &mut self,
+        text: String,
+        font_size: f32,
+        font_path: Option<String>,
+        font_family: Option<String>,
+        font_weight: Option<&str>,
+        font_style: Option<&str>,
+        letter_spacing: f32,
+        line_spacing: f32,
+        kerning: bool,
+    ) -> PyResult<(f32, f32)> {
+        let style = build_text_style(
+            font_size,
+            font_path,
+            font_family,
+            font_weight,
+            font_style,
+            letter_spacing,
+            line_spacing,
+            kerning,
+        )?;
+        Ok(self.inner.measure_text(&text, style))
+    }
+
+    /// Load a texture into the render cache ahead of its first draw.
     ///
-    ///     # Spawn enemy every 5 seconds
-    ///     if int(t) % 5 == 0 and t - int(t) < engine.delta_time:
-    ///         spawn_enemy()
+    /// Relative paths resolve from the source root. Before the window exists the
+    /// path is queued and loaded when the renderer is created.
+    fn preload_texture(&mut self, path: &str) -> PyResult<()> {
+        self.inner
+            .preload_texture(path)
+            .map_err(PyRuntimeError::new_err)
+    }
+
+    /// Get the `(width, height)` of an image file in pixels without loading it.
     ///
-    ///     engine.update()
-    ///     engine.render()
-    /// ```
+    /// Relative paths resolve from the source root.
+    fn texture_size(&self, path: &str) -> PyResult<(u32, u32)> {
+        self.inner
+            .texture_size(path)
+            .map_err(PyRuntimeError::new_err)
+    }
+
+    /// Remove a texture from the render cache. Returns `True` if it was cached.
+    fn unload_texture(&mut self, path: &str) -> bool {
+        self.inner.unload_texture(path)
+    }
+
+    // ========== Asset Methods ==========
+
+    /// Load an asset file, or add a reference to it if it is already loaded.
     ///
-    /// # See Also
-    /// - `delta_time` - Time since last frame
-    /// - `Time` class - Standalone time tracking
+    /// `kind` is "texture", "font", "audio" or "data". Relative paths resolve
+    /// from the source root.
+    fn load_asset(&mut self, kind: &str, path: &str) -> PyResult<PyAssetHandle> {
+        let kind = AssetKind::parse(kind).ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "Unknown asset kind '{kind}', expected 'texture', 'font', 'audio' or 'data'"
+            ))
+        })?;
+        let handle = self
+            .inner
+            .load_asset(kind, path)
+            .map_err(PyRuntimeError::new_err)?;
+        let path = self
+            .inner
+            .get_asset_manager_handle()
+            .read()
+            .ok()
+            .and_then(|assets| assets.path(handle).map(str::to_string))
+            .unwrap_or_default();
+        Ok(PyAssetHandle {
+            inner: handle,
+            kind,
+            path,
+        })
+    }
+
+    /// Add a reference to a loaded asset. Returns `False` if it was unloaded.
+    fn retain_asset(&mut self, handle: &PyAssetHandle) -> bool {
+        self.inner.retain_asset(handle.inner)
+    }
+
+    /// Drop a reference to an asset. Returns `True` if this unloaded it.
+    fn release_asset(&mut self, handle: &PyAssetHandle) -> bool {
+        self.inner.release_asset(handle.inner)
+    }
+
+    /// Number of references to an asset, 0 once it is unloaded.
+    fn asset_ref_count(&self, handle: &PyAssetHandle) -> u32 {
+        let assets = self.inner.get_asset_manager_handle();
+        assets.read().map_or(0, |assets| assets.ref_count(handle.inner))
+    }
+
+    /// File contents of a loaded audio or data asset.
+    fn asset_bytes(&self, py: Python<'_>, handle: &PyAssetHandle) -> Option<Py<PyBytes>> {
+        let assets = self.inner.get_asset_manager_handle();
+        let assets = assets.read().ok()?;
+        assets
+            .bytes(handle.inner)
+            .map(|bytes| PyBytes::new(py, bytes).unbind())
+    }
+
+    /// `(width, height)` of a loaded texture asset.
+    fn asset_texture_size(&self, handle: &PyAssetHandle) -> Option<(u32, u32)> {
+        let assets = self.inner.get_asset_manager_handle();
+        let assets = assets.read().ok()?;
+        assets.texture(handle.inner).map(|image| image.dimensions())
+    }
+
+    /// Number of loaded assets.
+    fn loaded_asset_count(&self) -> usize {
+        let assets = self.inner.get_asset_manager_handle();
+        assets.read().map_or(0, |assets| assets.loaded_count())
+    }
+
+    /// Approximate memory held by loaded assets, in bytes.
+    fn asset_memory_usage(&self) -> usize {
+        let assets = self.inner.get_asset_manager_handle();
+        assets.read().map_or(0, |assets| assets.memory_usage())
+    }
+
+    /// Whether asset files are checked for changes each update, off by default.
     #[getter]
-    fn elapsed_time(&self) -> f32 {
-        self.inner.time.elapsed_time()
+    fn asset_hot_reload(&self) -> bool {
+        self.inner.asset_hot_reload()
     }
 
-    // ========== Input Methods ==========
+    #[setter]
+    fn set_asset_hot_reload(&mut self, enabled: bool) {
+        self.inner.set_asset_hot_reload(enabled);
+    }
 
-    /// Check if a keyboard key is currently held down.
-    ///
-    /// Returns `true` every frame while the key remains pressed, starting from the frame
-    /// it was pressed until the frame it's released.
-    ///
-    /// # Arguments
-    /// * `key_name` - Key identifier string. Can be:
-    ///   - Named keys from the `Keys` class (e.g., `Keys.W`, `Keys.ESCAPE`, `Keys.SPACE`)
-    ///   - String literals (e.g., `"w"`, `"escape"`, `"space"`, `"left"`, `"return"`)
-    ///   - Case-insensitive, supports aliases (e.g., `"esc"` = `"escape"`)
-    ///
-    /// # Returns
-    /// `true` if the key is currently held down, `false` otherwise
-    ///
-    /// # Comparison with `key_pressed()`
-    /// - `key_down()`: Returns `true` **every frame** while held
-    /// - `key_pressed()`: Returns `true` **only on the first frame** when pressed
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// engine = pyg.Engine()
-    /// engine.start_manual(title="Key Input Demo")
-    ///
-    /// while engine.poll_events():
-    ///     # Check if space is held down
-    ///     if engine.input.key_down(pyg.Keys.SPACE):
-    ///         print("Space is being held")  # Prints every frame
-    ///
-    ///     # Check if W is held (string form)
-    ///     if engine.input.key_down("w"):
-    ///         print("W key is down")
-    ///
-    ///     engine.update()
-    ///     engine.render()
-    /// ```
-    ///
-    /// # See Also
-    /// - `examples/python_input_demo.py` - Complete input system demonstration
-    /// - `key_pressed()` - Detect single key press events
-    /// - `key_released()` - Detect key release events
-    fn key_down(&self, key_name: &str) -> bool {
-        if let Some(input) = &self.inner.input_manager {
-            input.key_down(&parse_key(key_name))
-        } else {
-            false
-        }
+    /// Minimum seconds between hot reload checks.
+    #[getter]
+    fn asset_reload_interval(&self) -> f64 {
+        self.inner.asset_reload_interval().as_secs_f64()
     }
 
-    /// Check if a keyboard key was pressed this frame.
-    ///
-    /// Returns `true` **only on the first frame** when the key transitions from up to down.
-    /// Use this to detect discrete key press events (e.g., jumping, shooting, menu selection).
-    ///
-    /// # Arguments
-    /// * `key_name` - Key identifier string. Can be:
-    ///   - Named keys from the `Keys` class (e.g., `Keys.W`, `Keys.ESCAPE`, `Keys.SPACE`)
-    ///   - String literals (e.g., `"w"`, `"escape"`, `"space"`, `"left"`, `"return"`)
-    ///   - Case-insensitive, supports aliases (e.g., `"esc"` = `"escape"`)
-    ///
-    /// # Returns
-    /// `true` if the key was just pressed this frame, `false` otherwise
-    ///
-    /// # Comparison with `key_down()`
-    /// - `key_pressed()`: Returns `true` **only on the first frame** when pressed
-    /// - `key_down()`: Returns `true` **every frame** while held
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// engine = pyg.Engine()
-    /// engine.start_manual(title="Key Pressed Demo")
-    ///
-    /// jump_count = 0
-    ///
-    /// while engine.poll_events():
-    ///     # Detect single space press (won't repeat while held)
-    ///     if engine.input.key_pressed(pyg.Keys.SPACE):
-    ///         jump_count += 1
-    ///         print(f"Jump! (count: {jump_count})")
-    ///
-    ///     # Detect enter key press
-    ///     if engine.input.key_pressed("return"):
-    ///         print("Enter pressed - submit action")
-    ///
-    ///     engine.update()
-    ///     engine.render()
-    /// ```
-    ///
-    /// # See Also
-    /// - `examples/python_input_demo.py` - Input system demonstration
-    /// - `key_down()` - Check if key is currently held
-    /// - `key_released()` - Detect key release events
-    fn key_pressed(&self, key_name: &str) -> bool {
-        if let Some(input) = &self.inner.input_manager {
-            input.key_pressed(&parse_key(key_name))
-        } else {
-            false
-        }
+    #[setter]
+    fn set_asset_reload_interval(&mut self, seconds: f64) -> PyResult<()> {
+        let interval = std::time::Duration::try_from_secs_f64(seconds)
+            .map_err(|_| PyValueError::new_err("asset_reload_interval must be >= 0.0"))?;
+        self.inner.set_asset_reload_interval(interval);
+        Ok(())
     }
 
-    /// Check if a keyboard key was released this frame.
-    ///
-    /// Returns `true` **only on the first frame** when the key transitions from down to up.
-    /// Use this to detect when a user stops pressing a key (e.g., charge-up mechanics,
-    /// detecting hold duration).
-    ///
-    /// # Arguments
-    /// * `key_name` - Key identifier string. Can be:
-    ///   - Named keys from the `Keys` class (e.g., `Keys.W`, `Keys.ESCAPE`, `Keys.SPACE`)
-    ///   - String literals (e.g., `"w"`, `"escape"`, `"space"`, `"left"`, `"return"`)
-    ///   - Case-insensitive, supports aliases (e.g., `"esc"` = `"escape"`)
-    ///
-    /// # Returns
-    /// `true` if the key was just released this frame, `false` otherwise
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// engine = pyg.Engine()
-    /// engine.start_manual(title="Key Released Demo")
-    ///
-    /// charge_time = 0.0
-    /// charging = False
-    ///
-    /// while engine.poll_events():
-    ///     dt = engine.delta_time
-    ///
-    ///     # Start charging when space is pressed
-    ///     if engine.input.key_pressed(pyg.Keys.SPACE):
-    ///         charging = True
-    ///         charge_time = 0.0
-    ///
-    ///     # Accumulate charge time while held
-    ///     if engine.input.key_down(pyg.Keys.SPACE):
-    ///         charge_time += dt
-    ///
-    ///     # Release action when space is let go
-    ///     if engine.input.key_released(pyg.Keys.SPACE):
-    ///         print(f"Released charge: {charge_time:.2f}s")
-    ///         charging = False
-    ///         charge_time = 0.0
-    ///
-    ///     engine.update()
-    ///     engine.render()
-    /// ```
-    ///
-    /// # See Also
-    /// - `examples/python_input_demo.py` - Input system demonstration
-    /// - `key_down()` - Check if key is currently held
-    /// - `key_pressed()` - Detect key press events
-    fn key_released(&self, key_name: &str) -> bool {
-        if let Some(input) = &self.inner.input_manager {
-            input.key_released(&parse_key(key_name))
-        } else {
-            false
-        }
+    /// Re-read changed asset files now. Returns their resolved paths.
+    fn reload_changed_assets(&mut self) -> Vec<String> {
+        self.inner.reload_changed_assets()
     }
 
-    /// Check if a mouse button is currently held down.
-    ///
-    /// Returns `true` every frame while the button remains pressed, starting from the frame
-    /// it was pressed until the frame it's released.
-    ///
-    /// # Arguments
-    /// * `button` - Mouse button identifier. Can be:
-    ///   - Constants from `MouseButton` class: `MouseButton.LEFT`, `MouseButton.RIGHT`, `MouseButton.MIDDLE`
-    ///   - String literals: `"left"`, `"right"`, `"middle"` (case-insensitive)
-    ///
-    /// # Returns
-    /// `true` if the button is currently held down, `false` otherwise
-    ///
-    /// # Comparison with `mouse_button_pressed()`
-    /// - `mouse_button_down()`: Returns `true` **every frame** while held
-    /// - `mouse_button_pressed()`: Returns `true` **only on the first frame** when clicked
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// engine = pyg.Engine()
-    /// engine.start_manual(title="Mouse Button Demo")
-    ///
-    /// while engine.poll_events():
-    ///     # Check if left button is held (draws continuously)
-    ///     if engine.input.mouse_button_down(pyg.MouseButton.LEFT):
-    ///         mx, my = engine.input.mouse_position
-    ///         engine.draw_circle(mx, my, 10, pyg.Color.RED, filled=True)
-    ///         print(f"Dragging at ({mx}, {my})")
-    ///
-    ///     # String form also works
-    ///     if engine.input.mouse_button_down("right"):
-    ///         print("Right button held")
+    /// Play a WAV file and return its voice id.
     ///
-    ///     engine.update()
-    ///     engine.render()
-    /// ```
-    ///
-    /// # See Also
-    /// - `examples/python_input_demo.py` - Input demonstration including mouse
-    /// - `mouse_button_pressed()` - Detect single click events
-    /// - `mouse_button_released()` - Detect button release
-    /// - `mouse_position` - Get current mouse coordinates
-    fn mouse_button_down(&self, button: &str) -> bool {
-        if let Some(input) = &self.inner.input_manager {
-            input.mouse_button_down(parse_mouse_button(button))
-        } else {
-            false
+    /// With a `position` the sound is heard from that world point, attenuated
+    /// by distance from the listener and panned to its side; `pan` is ignored.
+    /// Past the voice limit, sounds with a lower `priority` are cut off first.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        sound,
+        volume=1.0,
+        pan=0.0,
+        looping=false,
+        position=None,
+        attenuation="linear",
+        min_distance=100.0,
+        max_distance=1000.0,
+        rolloff=1.0,
+        bus="sfx",
+        pitch=1.0,
+        pitch_range=None,
+        priority=0
+    ))]
+    fn play_sound(
+        &mut self,
+        sound: &Bound<'_, PyAny>,
+        volume: f32,
+        pan: f32,
+        looping: bool,
+        position: Option<PyVec2>,
+        attenuation: &str,
+        min_distance: f32,
+        max_distance: f32,
+        rolloff: f32,
+        bus: &str,
+        pitch: f32,
+        pitch_range: Option<(f32, f32)>,
+        priority: i32,
+    ) -> PyResult<u64> {
+        let path = sound_path(sound)?;
+        let attenuation = parse_attenuation(attenuation, min_distance, max_distance, rolloff)?;
+        check_pitch(pitch)?;
+        let pitch = match check_pitch_range(pitch_range)? {
+            Some(range) => self.inner.audio.random_pitch(range),
+            None => pitch,
+        };
+        let params = VoiceParams {
+            volume: volume.max(0.0),
+            pan: pan.clamp(-1.0, 1.0),
+            looping,
+            pitch,
+            bus: parse_bus(bus)?,
+            priority,
+        };
+        self.inner
+            .play_sound(&path, params, position.map(|position| (position.inner, attenuation)))
+            .map_err(PyRuntimeError::new_err)
+    }
+
+    /// Make raw PCM samples playable with `play_sound(name)`, replacing any
+    /// sound created under that name before.
+    #[pyo3(signature = (name, data, sample_rate, channels=1, sample_format="int16"))]
+    fn create_sound(
+        &mut self,
+        name: &str,
+        data: &[u8],
+        sample_rate: u32,
+        channels: u16,
+        sample_format: &str,
+    ) -> PyResult<()> {
+        if name.is_empty() {
+            return Err(PyValueError::new_err("sound name must not be empty"));
         }
+        check_stream_layout(sample_rate, channels)?;
+        let sound = Sound::from_pcm(sample_rate, channels, data, parse_pcm_format(sample_format)?)
+            .map_err(PyValueError::new_err)?;
+        self.inner.audio.add_sound(name, sound);
+        Ok(())
     }
 
-    /// Check if a mouse button was pressed this frame.
-    ///
-    /// Returns `true` **only on the first frame** when the button transitions from up to down.
-    /// Use this to detect discrete click events (e.g., shooting, selecting, spawning objects).
-    ///
-    /// # Arguments
-    /// * `button` - Mouse button identifier. Can be:
-    ///   - Constants from `MouseButton` class: `MouseButton.LEFT`, `MouseButton.RIGHT`, `MouseButton.MIDDLE`
-    ///   - String literals: `"left"`, `"right"`, `"middle"` (case-insensitive)
-    ///
-    /// # Returns
-    /// `true` if the button was just pressed this frame, `false` otherwise
-    ///
-    /// # Comparison with `mouse_button_down()`
-    /// - `mouse_button_pressed()`: Returns `true` **only on the first frame** when clicked
-    /// - `mouse_button_down()`: Returns `true` **every frame** while held
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// engine = pyg.Engine()
-    /// engine.start_manual(title="Mouse Click Demo")
-    ///
-    /// while engine.poll_events():
-    ///     # Teleport to mouse position on left click
-    ///     if engine.input.mouse_button_pressed(pyg.MouseButton.LEFT):
-    ///         mx, my = engine.input.mouse_position
-    ///         print(f"Clicked at ({mx}, {my})")
-    ///
-    ///     # Right click for alternative action
-    ///     if engine.input.mouse_button_pressed(pyg.MouseButton.RIGHT):
-    ///         print("Right click - open context menu")
-    ///
-    ///     engine.update()
-    ///     engine.render()
-    /// ```
-    ///
-    /// # See Also
-    /// - `examples/python_input_demo.py` - Complete input demonstration
-    /// - `mouse_button_down()` - Check if button is currently held
-    /// - `mouse_button_released()` - Detect button release
-    /// - `mouse_position` - Get mouse coordinates
-    fn mouse_button_pressed(&self, button: &str) -> bool {
-        if let Some(input) = &self.inner.input_manager {
-            input.mouse_button_pressed(parse_mouse_button(button))
-        } else {
-            false
+    /// Remove a sound made with `create_sound`. Returns False if there was none.
+    fn remove_sound(&mut self, name: &str) -> bool {
+        self.inner.audio.remove_sound(name)
+    }
+
+    /// Start a stream of generated samples and return its voice id. It is
+    /// silent until samples are queued with `queue_sound_stream`.
+    #[pyo3(signature = (sample_rate, channels=1, volume=1.0, pan=0.0, bus="sfx", priority=0))]
+    fn play_sound_stream(
+        &mut self,
+        sample_rate: u32,
+        channels: u16,
+        volume: f32,
+        pan: f32,
+        bus: &str,
+        priority: i32,
+    ) -> PyResult<u64> {
+        check_stream_layout(sample_rate, channels)?;
+        let params = VoiceParams {
+            volume: volume.max(0.0),
+            pan: pan.clamp(-1.0, 1.0),
+            looping: false,
+            pitch: 1.0,
+            bus: parse_bus(bus)?,
+            priority,
+        };
+        Ok(self.inner.audio.play_stream(sample_rate, channels, params))
+    }
+
+    /// Append raw interleaved samples to a stream. Returns False once the stream ended.
+    #[pyo3(signature = (voice_id, data, sample_format="int16"))]
+    fn queue_sound_stream(&mut self, voice_id: u64, data: &[u8], sample_format: &str) -> PyResult<bool> {
+        let samples = parse_pcm_format(sample_format)?
+            .decode(data)
+            .map_err(PyValueError::new_err)?;
+        Ok(self.inner.audio.queue_samples(voice_id, &samples))
+    }
+
+    /// Let a stream finish once its queued samples play.
+    fn end_sound_stream(&mut self, voice_id: u64) -> bool {
+        self.inner.audio.end_stream(voice_id)
+    }
+
+    /// Frames queued on a stream and not played yet, or None once it finished.
+    fn sound_stream_queued_frames(&self, voice_id: u64) -> Option<usize> {
+        self.inner.audio.queued_frames(voice_id)
+    }
+
+    /// Stop a sound. Returns False if it already ended.
+    fn stop_sound(&mut self, voice_id: u64) -> bool {
+        self.inner.audio.stop(voice_id)
+    }
+
+    fn stop_all_sounds(&mut self) {
+        self.inner.audio.stop_all();
+    }
+
+    fn is_sound_playing(&self, voice_id: u64) -> bool {
+        self.inner.audio.is_playing(voice_id)
+    }
+
+    /// Set a sound's volume, before distance attenuation for positional sounds.
+    fn set_sound_volume(&mut self, voice_id: u64, volume: f32) -> bool {
+        self.inner.audio.set_volume(voice_id, volume)
+    }
+
+    /// Set a sound's playback rate. Returns False if it ended.
+    fn set_sound_pitch(&mut self, voice_id: u64, pitch: f32) -> PyResult<bool> {
+        check_pitch(pitch)?;
+        Ok(self.inner.audio.set_pitch(voice_id, pitch))
+    }
+
+    /// Set a sound's pan. Returns False for positional or ended sounds.
+    fn set_sound_pan(&mut self, voice_id: u64, pan: f32) -> bool {
+        self.inner.audio.set_pan(voice_id, pan)
+    }
+
+    /// Voice ids of the sounds that played to the end, or were cut off by the
+    /// voice limit, since the last call.
+    fn take_finished_sounds(&mut self) -> Vec<u64> {
+        self.inner.audio.take_finished()
+    }
+
+    /// Play `sound` whenever colliders on these two physics layers, or with
+    /// these two materials, start touching. Material pairs win over layer
+    /// pairs. The volume grows with how deep the colliders overlap on the
+    /// step they meet, reaching `volume` at `full_volume_depth`; hits
+    /// shallower than `min_depth` are silent. With `spatial` the sound is
+    /// heard from the contact point.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        sound,
+        layers=None,
+        materials=None,
+        volume=1.0,
+        full_volume_depth=1.0,
+        min_depth=0.0,
+        pitch_range=None,
+        bus="sfx",
+        priority=0,
+        spatial=true,
+        attenuation="linear",
+        min_distance=100.0,
+        max_distance=1000.0,
+        rolloff=1.0
+    ))]
+    fn set_impact_sound(
+        &mut self,
+        sound: &Bound<'_, PyAny>,
+        layers: Option<(u32, u32)>,
+        materials: Option<(String, String)>,
+        volume: f32,
+        full_volume_depth: f32,
+        min_depth: f32,
+        pitch_range: Option<(f32, f32)>,
+        bus: &str,
+        priority: i32,
+        spatial: bool,
+        attenuation: &str,
+        min_distance: f32,
+        max_distance: f32,
+        rolloff: f32,
+    ) -> PyResult<()> {
+        let key = impact_key(layers, materials)?;
+        if volume < 0.0 {
+            return Err(PyValueError::new_err("volume must be >= 0.0"));
+        }
+        if full_volume_depth < 0.0 || min_depth < 0.0 {
+            return Err(PyValueError::new_err(
+                "full_volume_depth and min_depth must be >= 0.0",
+            ));
         }
+        let impact = ImpactSound {
+            sound: sound_path(sound)?,
+            volume,
+            full_volume_depth,
+            min_depth,
+            pitch_range: check_pitch_range(pitch_range)?,
+            bus: parse_bus(bus)?,
+            priority,
+            attenuation: spatial
+                .then(|| parse_attenuation(attenuation, min_distance, max_distance, rolloff))
+                .transpose()?,
+        };
+        self.inner.audio.impact_sounds_mut().set(key, impact);
+        Ok(())
     }
 
-    /// Check if a mouse button was released this frame.
-    ///
-    /// Returns `true` **only on the first frame** when the button transitions from down to up.
-    /// Use this to detect when a user stops clicking (e.g., completing drag operations,
-    /// finishing drawing strokes).
-    ///
-    /// # Arguments
-    /// * `button` - Mouse button identifier. Can be:
-    ///   - Constants from `MouseButton` class: `MouseButton.LEFT`, `MouseButton.RIGHT`, `MouseButton.MIDDLE`
-    ///   - String literals: `"left"`, `"right"`, `"middle"` (case-insensitive)
-    ///
-    /// # Returns
-    /// `true` if the button was just released this frame, `false` otherwise
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// engine = pyg.Engine()
-    /// engine.start_manual(title="Mouse Release Demo")
-    ///
-    /// dragging = False
-    /// drag_start = (0, 0)
-    ///
-    /// while engine.poll_events():
-    ///     # Start drag on press
-    ///     if engine.input.mouse_button_pressed(pyg.MouseButton.LEFT):
-    ///         dragging = True
-    ///         drag_start = engine.input.mouse_position
-    ///
-    ///     # Finish drag on release
-    ///     if engine.input.mouse_button_released(pyg.MouseButton.LEFT):
-    ///         if dragging:
-    ///             drag_end = engine.input.mouse_position
-    ///             print(f"Dragged from {drag_start} to {drag_end}")
-    ///             dragging = False
-    ///
-    ///     engine.update()
-    ///     engine.render()
-    /// ```
-    ///
-    /// # See Also
-    /// - `examples/python_input_demo.py` - Input system demonstration
-    /// - `mouse_button_down()` - Check if button is currently held
-    /// - `mouse_button_pressed()` - Detect button press
-    /// - `mouse_position` - Get mouse coordinates
-    fn mouse_button_released(&self, button: &str) -> bool {
-        if let Some(input) = &self.inner.input_manager {
-            input.mouse_button_released(parse_mouse_button(button))
-        } else {
-            false
+    /// Stop playing a sound for a layer or material pair. Returns False if none was set.
+    #[pyo3(signature = (layers=None, materials=None))]
+    fn remove_impact_sound(
+        &mut self,
+        layers: Option<(u32, u32)>,
+        materials: Option<(String, String)>,
+    ) -> PyResult<bool> {
+        let key = impact_key(layers, materials)?;
+        Ok(self.inner.audio.impact_sounds_mut().remove(&key))
+    }
+
+    fn clear_impact_sounds(&mut self) {
+        self.inner.audio.impact_sounds_mut().clear();
+    }
+
+    /// Whether the `AudioSourceComponent` with this id has a sound playing.
+    fn is_audio_source_playing(&self, component_id: u32) -> bool {
+        self.inner.audio.is_source_playing(component_id)
+    }
+
+    /// Volume applied to every sound, 1.0 by default.
+    #[getter]
+    fn master_volume(&self) -> f32 {
+        self.inner.audio.master_volume()
+    }
+
+    #[setter]
+    fn set_master_volume(&mut self, volume: f32) -> PyResult<()> {
+        if volume < 0.0 {
+            return Err(PyValueError::new_err("master_volume must be >= 0.0"));
         }
+        self.inner.audio.set_master_volume(volume);
+        Ok(())
     }
 
-    /// Get the current mouse position in window coordinates.
-    ///
-    /// Returns the mouse cursor position in **screen-space pixels** with origin at the
-    /// **top-left corner** of the window (0, 0). Coordinates increase right (X) and down (Y).
-    ///
-    /// # Returns
-    /// Tuple `(x, y)` where:
-    /// - `x` (float): Horizontal position from left edge in pixels
-    /// - `y` (float): Vertical position from top edge in pixels
-    ///
-    /// Returns `(0.0, 0.0)` if the mouse position is unavailable.
-    ///
-    /// # Coordinate System
-    /// **Screen-space coordinates:**
-    /// - Origin: Top-left corner (0, 0)
-    /// - X-axis: Increases to the right
-    /// - Y-axis: Increases downward
-    /// - Units: Pixels
-    ///
-    /// For **world-space coordinates** (relative to camera), use `screen_to_world()`.
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// engine = pyg.Engine()
-    /// engine.start_manual(title="Mouse Position Demo")
-    ///
-    /// while engine.poll_events():
-    ///     # Get screen-space mouse position
-    ///     mx, my = engine.input.mouse_position
-    ///
-    ///     # Draw a circle at mouse cursor
-    ///     engine.clear_draw_commands()
-    ///     engine.draw_circle(mx, my, 20, pyg.Color.RED, filled=True)
-    ///
-    ///     # Convert to world-space for GameObject positioning
-    ///     world_pos = engine.screen_to_world(pyg.Vec2(mx, my))
-    ///     print(f"Mouse - Screen: ({mx}, {my}), World: {world_pos}")
-    ///
-    ///     engine.update()
-    ///     engine.render()
-    /// ```
-    ///
-    /// # See Also
-    /// - `examples/python_input_demo.py` - Mouse input demonstration
-    /// - `examples/python_camera_worldspace_demo.py` - Screen/world conversion
-    /// - `screen_to_world()` - Convert screen coordinates to world-space
-    /// - `mouse_delta` - Get mouse movement since last frame
-    fn mouse_position(&self) -> (f64, f64) {
-        if let Some(input) = &self.inner.input_manager {
-            input.mouse_position()
-        } else {
-            (0.0, 0.0)
+    /// Settings of a mixer bus as `(volume, muted, low_pass, reverb_send)`.
+    fn get_audio_bus(&self, bus: &str) -> PyResult<(f32, bool, Option<f32>, f32)> {
+        let settings = self.inner.audio.bus_settings(parse_bus(bus)?);
+        Ok((
+            settings.volume,
+            settings.muted,
+            settings.low_pass,
+            settings.reverb_send,
+        ))
+    }
+
+    /// Replace the settings of a mixer bus. `low_pass` is a cutoff in Hz, or None.
+    fn set_audio_bus(
+        &mut self,
+        bus: &str,
+        volume: f32,
+        muted: bool,
+        low_pass: Option<f32>,
+        reverb_send: f32,
+    ) -> PyResult<()> {
+        let bus = parse_bus(bus)?;
+        check_bus_values(volume, low_pass, reverb_send)?;
+        self.inner.audio.set_bus_settings(
+            bus,
+            BusSettings {
+                volume,
+                muted,
+                low_pass,
+                reverb_send,
+            },
+        );
+        Ok(())
+    }
+
+    /// Save bus settings as a snapshot for `transition_audio_snapshot`, as
+    /// `{bus: {setting: value}}` with the settings of `get_audio_bus`.
+    /// Settings left out are not changed by the snapshot.
+    fn add_audio_snapshot(
+        &mut self,
+        name: &str,
+        buses: HashMap<String, HashMap<String, Bound<'_, PyAny>>>,
+    ) -> PyResult<()> {
+        if name.is_empty() {
+            return Err(PyValueError::new_err("snapshot name must not be empty"));
         }
+        let snapshot = parse_snapshot(buses)?;
+        self.inner.audio.add_snapshot(name, snapshot);
+        Ok(())
     }
 
-    /// Get the mouse movement delta for this frame.
-    ///
-    /// Returns the change in mouse position since the last frame in pixels.
-    /// Useful for camera controls, mouse look, and drag operations.
-    ///
-    /// # Returns
-    /// Tuple `(dx, dy)` where:
-    /// - `dx` (float): Horizontal movement (positive = right)
-    /// - `dy` (float): Vertical movement (positive = down)
-    ///
-    /// Returns `(0.0, 0.0)` if no movement or input unavailable.
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// engine = pyg.Engine()
-    /// engine.start_manual(title="Mouse Delta Demo")
-    ///
-    /// camera_x = 0.0
-    /// camera_y = 0.0
-    /// sensitivity = 2.0
-    ///
-    /// while engine.poll_events():
-    ///     # Get mouse movement
-    ///     dx, dy = engine.input.mouse_delta
-    ///
-    ///     # Pan camera with mouse movement when button held
-    ///     if engine.input.mouse_button_down(pyg.MouseButton.LEFT):
-    ///         camera_x -= dx * sensitivity
-    ///         camera_y -= dy * sensitivity
-    ///         engine.set_camera_position(pyg.Vec2(camera_x, camera_y))
+    /// Forget a snapshot. Returns False if there was none.
+    fn remove_audio_snapshot(&mut self, name: &str) -> bool {
+        self.inner.audio.remove_snapshot(name)
+    }
+
+    /// Names of the saved snapshots, sorted.
+    fn audio_snapshots(&self) -> Vec<String> {
+        self.inner.audio.snapshot_names()
+    }
+
+    /// Blend every bus to a snapshot over `duration` seconds of real time.
+    #[pyo3(signature = (name, duration=0.0))]
+    fn transition_audio_snapshot(&mut self, name: &str, duration: f32) -> PyResult<()> {
+        if duration < 0.0 {
+            return Err(PyValueError::new_err("duration must be >= 0.0"));
+        }
+        self.inner
+            .audio
+            .transition_to(name, duration)
+            .map_err(PyValueError::new_err)
+    }
+
+    /// Snapshot last transitioned to, or None.
+    #[getter]
+    fn current_audio_snapshot(&self) -> Option<String> {
+        self.inner.audio.current_snapshot().map(str::to_string)
+    }
+
+    /// Whether a snapshot transition is still blending.
+    #[getter]
+    fn audio_snapshot_transitioning(&self) -> bool {
+        self.inner.audio.is_transitioning()
+    }
+
+    /// Number of sounds playing.
+    #[getter]
+    fn playing_sound_count(&self) -> usize {
+        self.inner.audio.voice_count()
+    }
+
+    /// Most sounds that play at once, 64 by default. Past it a new sound
+    /// replaces the oldest one with the lowest priority, unless every
+    /// playing sound has a higher priority than it.
+    #[getter]
+    fn max_sounds(&self) -> usize {
+        self.inner.audio.max_voices()
+    }
+
+    #[setter]
+    fn set_max_sounds(&mut self, max_sounds: usize) -> PyResult<()> {
+        if max_sounds == 0 {
+            return Err(PyValueError::new_err("max_sounds must be >= 1"));
+        }
+        self.inner.audio.set_max_voices(max_sounds);
+        Ok(())
+    }
+
+    /// Whether sounds reach an output device. False until the first sound
+    /// plays, and without the `audio` build feature.
+    #[getter]
+    fn audio_output_open(&self) -> bool {
+        self.inner.audio.is_output_open()
+    }
+
+    /// World position positional sounds were last heard from.
+    fn audio_listener_position(&self) -> PyVec2 {
+        PyVec2 {
+            inner: self.inner.audio.listener_position(),
+        }
+    }
+
+    /// Approximate GPU memory held by cached textures, in bytes.
+    fn texture_memory_usage(&self) -> u64 {
+        self.inner.texture_memory_usage()
+    }
+
+    /// Number of textures currently resident in the render cache.
+    fn cached_texture_count(&self) -> usize {
+        self.inner.cached_texture_count()
+    }
+
+    /// Set the texture cache budget in bytes, or `None` for unlimited.
     ///
-    ///     engine.update()
-    ///     engine.render()
-    /// ```
+    /// When usage exceeds the budget, least-recently-used textures are evicted at
+    /// the end of a frame. Textures drawn in the last frame are never evicted.
+    #[pyo3(signature = (budget_bytes))]
+    fn set_texture_budget(&mut self, budget_bytes: Option<u64>) {
+        self.inner.set_texture_budget(budget_bytes);
+    }
+
+    /// Get the texture cache budget in bytes, or `None` when unlimited.
+    fn get_texture_budget(&self) -> Option<u64> {
+        self.inner.texture_budget()
+    }
+
+    /// Group sprites of equal draw order by texture so they batch into fewer
+    /// draw calls (on by default). Turn it off if overlapping sprites with the
+    /// same draw order must keep their creation order.
+    fn set_sort_sprites_by_texture(&mut self, enabled: bool) {
+        self.inner.set_sort_sprites_by_texture(enabled);
+    }
+
+    fn get_sort_sprites_by_texture(&self) -> bool {
+        self.inner.sort_sprites_by_texture()
+    }
+
+    /// Batching statistics for the last rendered frame.
     ///
-    /// # See Also
-    /// - `examples/python_camera_worldspace_demo.py` - Camera panning with mouse
-    /// - `mouse_position` - Get absolute mouse coordinates
-    /// - `mouse_wheel` - Get scroll wheel delta
-    fn mouse_delta(&self) -> (f64, f64) {
-        if let Some(input) = &self.inner.input_manager {
-            input.mouse_delta()
-        } else {
-            (0.0, 0.0)
+    /// Returns:
+    ///     RenderStats
+    fn render_stats(&self) -> PyRenderStats {
+        PyRenderStats {
+            inner: self.inner.render_stats(),
         }
     }
 
-    /// Get the mouse wheel delta accumulated this frame.
-    ///
-    /// Returns the scroll wheel movement for the current frame. Typically used for
-    /// zooming, scrolling content, or adjusting values.
+    /// Per-scope timings of the last completed frame.
     ///
-    /// # Returns
-    /// Tuple `(wheel_x, wheel_y)` where:
-    /// - `wheel_x` (float): Horizontal scroll (positive = right, rare on most mice)
-    /// - `wheel_y` (float): Vertical scroll (positive = scroll up/away from user)
+    /// Returns:
+    ///     FrameStats
+    fn get_frame_stats(&self) -> PyFrameStats {
+        PyFrameStats {
+            inner: self.inner.frame_stats(),
+        }
+    }
+
+    /// Whether frame timings are recorded, on by default.
+    #[getter]
+    fn profiler_enabled(&self) -> bool {
+        self.inner.profiler_enabled()
+    }
+
+    #[setter]
+    fn set_profiler_enabled(&mut self, enabled: bool) {
+        self.inner.set_profiler_enabled(enabled);
+    }
+
+    /// Milliseconds of measured work per frame before a warning is logged, or None.
+    #[getter]
+    fn frame_budget_ms(&self) -> Option<f32> {
+        self.inner.frame_budget_ms()
+    }
+
+    #[setter]
+    fn set_frame_budget_ms(&mut self, budget_ms: Option<f32>) {
+        self.inner.set_frame_budget_ms(budget_ms);
+    }
+
+    /// Multiple of the average frame work above which a frame is logged as a
+    /// spike, or None.
+    #[getter]
+    fn frame_spike_factor(&self) -> Option<f32> {
+        self.inner.frame_spike_factor()
+    }
+
+    #[setter]
+    fn set_frame_spike_factor(&mut self, factor: Option<f32>) {
+        self.inner.set_frame_spike_factor(factor);
+    }
+
+    /// Number of frames flagged as over budget or as spikes so far.
+    #[getter]
+    fn slow_frame_count(&self) -> u64 {
+        self.inner.slow_frame_count()
+    }
+
+    /// Add seconds spent in Python frame code to the current frame's "scripts" scope.
+    fn add_script_time(&mut self, seconds: f64) {
+        if seconds > 0.0 && seconds.is_finite() {
+            self.inner
+                .add_profile_time(ProfileScope::Scripts, Duration::from_secs_f64(seconds));
+        }
+    }
+
+    /// Write a JSON snapshot of the scene, collision world and draw queue to `path`.
     ///
-    /// Typical values per scroll notch: ±1.0 to ±3.0 (varies by device)
-    /// Returns `(0.0, 0.0)` if no scrolling this frame.
+    /// Raises:
+    ///     RuntimeError: If the file cannot be written.
+    fn dump_debug_snapshot(&self, path: &str) -> PyResult<()> {
+        self.inner
+            .dump_debug_snapshot(path)
+            .map_err(PyRuntimeError::new_err)
+    }
+
+    /// Update a UI label's text at runtime by object ID.
+    fn update_ui_label_text(&self, object_id: u32, text: String) {
+        let _ = self
+            .inner
+            .get_command_sender()
+            .send(EngineCommand::UpdateUILabelText { object_id, text });
+    }
+
+    /// Update a UI button's text at runtime by object ID.
+    fn update_ui_button_text(&self, object_id: u32, text: String) {
+        let _ = self
+            .inner
+            .get_command_sender()
+            .send(EngineCommand::UpdateUIButtonText { object_id, text });
+    }
+
+    /// Update a UI text input's text at runtime by object ID.
+    fn update_ui_text_input_text(&self, object_id: u32, text: String) {
+        let _ = self
+            .inner
+            .get_command_sender()
+            .send(EngineCommand::UpdateUITextInputText { object_id, text });
+    }
+
+    /// Update a UI slider's value at runtime by object ID.
+    fn update_ui_slider_value(&self, object_id: u32, value: f32) {
+        let _ = self
+            .inner
+            .get_command_sender()
+            .send(EngineCommand::UpdateUISliderValue { object_id, value });
+    }
+
+    /// Replace a UI curve editor's curve at runtime by object ID.
+    fn update_ui_curve(&self, object_id: u32, curve: PyRef<PyAnimationCurve>) {
+        let _ = self.inner.get_command_sender().send(EngineCommand::UpdateUICurve {
+            object_id,
+            curve: curve.inner.clone(),
+        });
+    }
+
+    /// Update a UI checkbox, toggle switch or radio button's checked state at runtime by object ID.
+    fn update_ui_checked(&self, object_id: u32, checked: bool) {
+        let _ = self
+            .inner
+            .get_command_sender()
+            .send(EngineCommand::UpdateUIChecked { object_id, checked });
+    }
+
+    /// Update a UI scroll view's scroll offset at runtime by object ID.
+    ///
+    /// List views scroll vertically to `y` and ignore `x`.
+    fn update_ui_scroll_position(&self, object_id: u32, x: f32, y: f32) {
+        let _ = self
+            .inner
+            .get_command_sender()
+            .send(EngineCommand::UpdateUIScrollPosition { object_id, x, y });
+    }
+
+    /// Update a UI list view's item count at runtime by object ID.
+    fn update_ui_list_item_count(&self, object_id: u32, item_count: usize) {
+        let _ = self
+            .inner
+            .get_command_sender()
+            .send(EngineCommand::UpdateUIListItemCount { object_id, item_count });
+    }
+
+    /// Select a UI list view row (or clear the selection with `None`) at runtime by object ID.
+    ///
+    /// The row is scrolled into view. Does not fire `on_selection_changed`.
+    #[pyo3(signature = (object_id, selected=None))]
+    fn update_ui_list_selection(&self, object_id: u32, selected: Option<usize>) {
+        let _ = self
+            .inner
+            .get_command_sender()
+            .send(EngineCommand::UpdateUIListSelection { object_id, selected });
+    }
+
+    /// Fetch a UI list view's visible rows from its item provider again at runtime by object ID.
+    fn refresh_ui_list(&self, object_id: u32) {
+        let _ = self
+            .inner
+            .get_command_sender()
+            .send(EngineCommand::RefreshUIList { object_id });
+    }
+
+    /// Update a UI progress bar's value (0.0-1.0) at runtime by object ID.
+    fn update_ui_progress_value(&self, object_id: u32, value: f32) {
+        let _ = self
+            .inner
+            .get_command_sender()
+            .send(EngineCommand::UpdateUIProgressValue { object_id, value });
+    }
+
+    /// Turn clipping of a UI panel's children to its bounds on or off at runtime by object ID.
+    fn update_ui_panel_clip(&self, object_id: u32, clip_children: bool) {
+        let _ = self
+            .inner
+            .get_command_sender()
+            .send(EngineCommand::UpdateUIPanelClip { object_id, clip_children });
+    }
+
+    /// Update a UI image's texture at runtime by object ID (None hides it).
+    #[pyo3(signature = (object_id, texture_path=None))]
+    fn update_ui_image_texture(&self, object_id: u32, texture_path: Option<String>) {
+        let _ = self
+            .inner
+            .get_command_sender()
+            .send(EngineCommand::UpdateUIImageTexture { object_id, texture_path });
+    }
+
+    /// Update a UI image's scale mode ("stretch", "fit", "fill" or "tile") at runtime by object ID.
+    fn update_ui_image_scale_mode(&self, object_id: u32, scale_mode: &str) -> PyResult<()> {
+        let scale_mode = parse_image_scale_mode(scale_mode)?;
+        let _ = self
+            .inner
+            .get_command_sender()
+            .send(EngineCommand::UpdateUIImageScaleMode { object_id, scale_mode });
+        Ok(())
+    }
+
+    /// Update a UI stack's direction, spacing, padding (left, right, top, bottom),
+    /// alignment and fit_content at runtime by object ID.
+    #[pyo3(signature = (object_id, direction="vertical", spacing=0.0, padding=(0.0, 0.0, 0.0, 0.0), alignment="start", fit_content=false))]
+    fn update_ui_stack(
+        &self,
+        object_id: u32,
+        direction: &str,
+        spacing: f32,
+        padding: (f32, f32, f32, f32),
+        alignment: &str,
+        fit_content: bool,
+    ) -> PyResult<()> {
+        let command = EngineCommand::UpdateUIStack {
+            object_id,
+            direction: parse_stack_direction(direction)?,
+            spacing,
+            padding: Padding::new(padding.0, padding.1, padding.2, padding.3),
+            alignment: parse_stack_alignment(alignment)?,
+            fit_content,
+        };
+        let _ = self.inner.get_command_sender().send(command);
+        Ok(())
+    }
+
+    /// Anchor a UI object to its parent (or the window) so it follows resizes.
+    ///
+    /// `x`/`y` offset the element from the anchor point (insets for "stretch").
+    /// `width`/`height` accept pixels, a percentage like "50%", "fill", or
+    /// None to keep the element's own size. `pivot` defaults to the anchor's
+    /// corner, so "bottom_right" keeps the element inside the window.
+    #[pyo3(signature = (object_id, anchor="top_left", x=0.0, y=0.0, width=None, height=None, pivot=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn set_ui_layout(
+        &self,
+        object_id: u32,
+        anchor: &str,
+        x: f32,
+        y: f32,
+        width: Option<&Bound<'_, PyAny>>,
+        height: Option<&Bound<'_, PyAny>>,
+        pivot: Option<(f32, f32)>,
+    ) -> PyResult<()> {
+        let layout = build_ui_layout(anchor, x, y, width, height, pivot)?;
+        let _ = self.inner.get_command_sender().send(EngineCommand::SetUILayout {
+            object_id,
+            layout: Some(layout),
+        });
+        Ok(())
+    }
+
+    /// Remove a UI object's layout; it keeps its last resolved bounds.
+    fn clear_ui_layout(&self, object_id: u32) {
+        let _ = self
+            .inner
+            .get_command_sender()
+            .send(EngineCommand::SetUILayout { object_id, layout: None });
+    }
+
+    /// Log a message at INFO level (default log method).
+    fn log(&self, message: &str) {
+        self.inner.log(message);
+    }
+
+    /// Log a message at TRACE level (most verbose).
+    fn log_trace(&self, message: &str) {
+        self.inner.log_trace(message);
+    }
+
+    /// Log a message at DEBUG level.
+    fn log_debug(&self, message: &str) {
+        self.inner.log_debug(message);
+    }
+
+    /// Log a message at INFO level.
+    fn log_info(&self, message: &str) {
+        self.inner.log_info(message);
+    }
+
+    /// Log a message at WARN level.
+    fn log_warn(&self, message: &str) {
+        self.inner.log_warn(message);
+    }
+
+    /// Log a message at ERROR level.
+    fn log_error(&self, message: &str) {
+        self.inner.log_error(message);
+    }
+
+    /// Get the engine version.
+    #[getter]
+    fn version(&self) -> String {
+        self.inner.version().to_string()
+    }
+
+    /// Report the GPU adapter, texture formats, limits, MSAA levels, present
+    /// modes and enabled engine features.
+    ///
+    /// Use it to gate optional effects on what the hardware supports, and
+    /// include `str(engine.capabilities())` in bug reports. `renderer` is
+    /// `None` before the window has been created.
+    ///
+    /// Returns:
+    ///     EngineCapabilities
+    fn capabilities(&self) -> PyEngineCapabilities {
+        PyEngineCapabilities {
+            inner: self.inner.capabilities(),
+        }
+    }
+
+    /// Get the time since the last frame in seconds.
+    /// Get time since last frame in **seconds**.
+    ///
+    /// Returns delta time (dt) - the duration of the previous frame in **seconds**.
+    /// Use for frame-rate independent movement, animations, and time-based logic.
+    ///
+    /// # Time Units: Seconds
+    /// **IMPORTANT:** Returns time in **seconds**, not milliseconds.
+    ///
+    /// # Typical Values
+    /// - 60 FPS: ~0.016 seconds (16ms)
+    /// - 30 FPS: ~0.033 seconds (33ms)
+    /// - 120 FPS: ~0.008 seconds (8ms)
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
     /// engine = pyg.Engine()
-    /// engine.start_manual(title="Mouse Wheel Demo")
-    ///
-    /// zoom = 1.0
-    ///
-    /// while engine.poll_events():
-    ///     # Get scroll wheel delta
-    ///     wheel_x, wheel_y = engine.input.mouse_wheel
+    /// player_x = 0.0
+    /// speed = 200.0  # pixels per second
     ///
-    ///     # Zoom camera with scroll wheel
-    ///     if wheel_y != 0:
-    ///         zoom += wheel_y * 0.1
-    ///         zoom = max(0.5, min(5.0, zoom))  # Clamp between 0.5x and 5x
-    ///         print(f"Zoom level: {zoom:.1f}x")
+    /// def update(dt, engine, data):
+    ///     # dt is delta_time in seconds
+    ///     data['x'] += speed * dt  # Move 200 pixels/sec regardless of FPS
     ///
-    ///         # Apply zoom by adjusting viewport size
-    ///         base_viewport = pyg.Vec2(24.0, 13.5)
-    ///         engine.set_camera_viewport_size(
-    ///             base_viewport.x / zoom,
-    ///             base_viewport.y / zoom
-    ///         )
+    ///     # Or access directly
+    ///     dt_direct = engine.delta_time
+    ///     print(f"Frame time: {dt_direct:.4f}s, FPS: {1.0/dt_direct:.1f}")
+    ///     return True
     ///
-    ///     engine.update()
-    ///     engine.render()
+    /// engine.run(update=update, user_data={'x': 0.0})
     /// ```
     ///
     /// # See Also
-    /// - `examples/python_camera_worldspace_demo.py` - Wheel-based camera zoom
-    /// - `mouse_delta` - Get mouse cursor movement
-    /// - `set_camera_viewport_size()` - Adjust camera zoom
-    fn mouse_wheel(&self) -> (f64, f64) {
-        if let Some(input) = &self.inner.input_manager {
-            input.mouse_wheel()
-        } else {
-            (0.0, 0.0)
-        }
+    /// - `examples/python_input_demo.py` - Movement with delta time
+    /// - `Time` class - Standalone time tracking
+    #[getter]
+    fn delta_time(&self) -> f32 {
+        self.inner.time.delta_time()
     }
 
-    /// Get the current value of a logical axis.
-    ///
-    /// Returns a float in the range **[-1.0, 1.0]** representing the axis state.
-    /// Axes provide a high-level input abstraction for movement and analog controls,
-    /// combining multiple input sources (keyboard keys, mouse axes) into a single value.
-    ///
-    /// # Built-in Axes
-    /// The engine provides **default axes** preconfigured for common game controls:
-    ///
-    /// - **"Horizontal"** - Left/Right movement
-    ///   - Negative (-1.0): `A`, `Left Arrow`
-    ///   - Positive (+1.0): `D`, `Right Arrow`
+    /// Get total time since engine start in **seconds**.
     ///
-    /// - **"Vertical"** - Up/Down movement
-    ///   - Negative (-1.0): `S`, `Down Arrow`
-    ///   - Positive (+1.0): `W`, `Up Arrow`
+    /// Returns elapsed time - the total duration since `Engine.start_manual()` or `Engine.run()`
+    /// was called, measured in **seconds**.
     ///
-    /// # Arguments
-    /// * `name` - Axis name (case-sensitive string). Can be built-in axes or custom
-    ///   axes created with `set_axis_keys()` or `set_axis_mouse()`.
+    /// Useful for time-based effects, periodic events, and timers.
     ///
-    /// # Returns
-    /// Float value from **-1.0 to 1.0**:
-    /// - `-1.0`: Full negative input (left, down, etc.)
-    /// - `0.0`: No input or axis doesn't exist
-    /// - `+1.0`: Full positive input (right, up, etc.)
+    /// # Time Units: Seconds
+    /// **IMPORTANT:** Returns time in **seconds**, not milliseconds.
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
+    /// import math
     ///
     /// engine = pyg.Engine()
-    /// engine.start_manual(title="Axis Input Demo")
-    ///
-    /// player_x = 400.0
-    /// player_y = 300.0
-    /// speed = 200.0  # pixels per second
+    /// engine.start_manual()
     ///
     /// while engine.poll_events():
-    ///     dt = engine.delta_time
+    ///     # Get elapsed time
+    ///     t = engine.elapsed_time
     ///
-    ///     # Read built-in axes for movement
-    ///     move_x = engine.input.axis("Horizontal")  # -1.0 to 1.0
-    ///     move_y = engine.input.axis("Vertical")    # -1.0 to 1.0
+    ///     # Pulsing scale based on time
+    ///     scale = 1.0 + 0.3 * math.sin(t * 2.0)
     ///
-    ///     # Apply frame-independent movement
-    ///     player_x += move_x * speed * dt
-    ///     player_y -= move_y * speed * dt  # Invert Y for up=positive
-    ///
-    ///     # Draw player
-    ///     engine.clear_draw_commands()
-    ///     engine.draw_circle(player_x, player_y, 20, pyg.Color.GREEN, filled=True)
-    ///
-    ///     # Show axis values
-    ///     if move_x != 0 or move_y != 0:
-    ///         print(f"Axis - H: {move_x:.2f}, V: {move_y:.2f}")
+    ///     # Spawn enemy every 5 seconds
+    ///     if int(t) % 5 == 0 and t - int(t) < engine.delta_time:
+    ///         spawn_enemy()
     ///
     ///     engine.update()
     ///     engine.render()
     /// ```
     ///
-    /// # Custom Axes
-    /// Create custom axes for specialized controls:
-    /// ```python
-    /// # Create a zoom axis with Q/E keys
-    /// engine.input.set_axis_keys(
-    ///     "CameraZoom",
-    ///     positive_keys=[pyg.Keys.E],  # Zoom out
-    ///     negative_keys=[pyg.Keys.Q],  # Zoom in
-    ///     sensitivity=1.0
-    /// )
-    ///
-    /// # Bind mouse X movement to an axis
-    /// engine.input.set_axis_mouse(
-    ///     "MouseLookX",
-    ///     mouse_axis="x",
-    ///     sensitivity=0.5,
-    ///     invert=False
-    /// )
-    ///
-    /// zoom_input = engine.input.axis("CameraZoom")
-    /// look_input = engine.input.axis("MouseLookX")
-    /// ```
-    ///
     /// # See Also
-    /// - `examples/python_input_demo.py` - Axis-based movement demonstration
-    /// - `examples/python_camera_worldspace_demo.py` - Camera control with axes
-    /// - `set_axis_keys()` - Create custom keyboard axes
-    /// - `set_axis_mouse()` - Bind axes to mouse movement
-    /// - `axis_names()` - List all configured axes
-    fn axis(&self, name: &str) -> f32 {
-        if let Some(input) = &self.inner.input_manager {
-            input.axis(name)
-        } else {
-            0.0
-        }
+    /// - `delta_time` - Time since last frame
+    /// - `Time` class - Standalone time tracking
+    #[getter]
+    fn elapsed_time(&self) -> f32 {
+        self.inner.time.elapsed_time()
     }
 
-    /// Get the previous frame's value of a logical axis.
-    fn axis_previous(&self, name: &str) -> f32 {
-        if let Some(input) = &self.inner.input_manager {
-            input.axis_previous(name)
-        } else {
-            0.0
-        }
+    /// Real time since the last frame in **seconds**, ignoring `time_scale` and pause.
+    ///
+    /// Use it for things that keep running in a pause menu or during slow motion.
+    #[getter]
+    fn unscaled_delta_time(&self) -> f32 {
+        self.inner.time.unscaled_delta_time()
     }
 
-    /// List all configured logical axis names.
-    ///
-    /// Returns a list of all axis names that have been configured, including
-    /// both built-in axes ("Horizontal", "Vertical") and custom axes.
-    ///
-    /// # Returns
-    /// Vector of axis name strings
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
+    /// Multiplier applied to `delta_time` and the fixed-step rate.
     ///
-    /// engine = pyg.Engine()
+    /// 1.0 is normal speed, 0.5 slow motion and 0.0 freezes game time. Input
+    /// and UI keep using real time. Negative or non-finite values are ignored.
+    #[getter]
+    fn time_scale(&self) -> f32 {
+        self.inner.time.time_scale()
+    }
+
+    #[setter]
+    fn set_time_scale(&mut self, time_scale: f32) {
+        self.inner.time.set_time_scale(time_scale);
+    }
+
+    /// Whether game time is paused.
     ///
-    /// # Create custom axes
-    /// engine.input.set_axis_keys("CameraZoom", [pyg.Keys.E], [pyg.Keys.Q])
-    /// engine.input.set_axis_mouse("MouseLookX", "x", 0.5)
+    /// While paused `delta_time` is 0.0, fixed steps and collisions stop and
+    /// only UI objects update; scripts get no hooks and messages wait. Input,
+    /// UI, tweens and event dispatch keep running, so pause menus work.
+    #[getter]
+    fn paused(&self) -> bool {
+        self.inner.time.is_paused()
+    }
+
+    #[setter]
+    fn set_paused(&mut self, paused: bool) {
+        self.inner.time.set_paused(paused);
+    }
+
+    /// Seconds of game time per fixed update, 1/60 by default.
+    #[getter]
+    fn fixed_timestep(&self) -> f32 {
+        self.inner.time.fixed_timestep()
+    }
+
+    /// Set the seconds of game time per fixed update, e.g. `1 / 120` for 120 Hz physics.
     ///
-    /// # List all axes
-    /// axes = engine.input.axis_names()
-    /// print(f"Available axes: {axes}")
-    /// # Output: ['Horizontal', 'Vertical', 'CameraZoom', 'MouseLookX']
-    /// ```
-    fn axis_names(&self) -> Vec<String> {
-        if let Some(input) = &self.inner.input_manager {
-            input.axis_names()
-        } else {
-            Vec::new()
-        }
+    /// Each `update()` runs as many fixed steps as the elapsed game time
+    /// covers, up to `max_fixed_steps`. Non-positive values are ignored.
+    fn set_fixed_timestep(&mut self, fixed_timestep: f32) {
+        self.inner.time.set_fixed_timestep(fixed_timestep);
     }
 
-    /// Check whether an action is currently active (held).
+    /// Most fixed steps one `update()` runs to catch up after a slow frame, 5 by default.
     ///
-    /// Actions provide high-level input abstractions for game events like jumping, shooting,
-    /// or pausing. Each action can be bound to multiple keys and mouse buttons, with any of
-    /// them activating the action.
+    /// Game time past the limit is dropped, so the simulation slows down
+    /// instead of falling further behind. Values below 1 are raised to 1.
+    #[getter]
+    fn max_fixed_steps(&self) -> u32 {
+        self.inner.time.max_fixed_steps()
+    }
+
+    #[setter]
+    fn set_max_fixed_steps(&mut self, max_fixed_steps: u32) {
+        self.inner.time.set_max_fixed_steps(max_fixed_steps);
+    }
+
+    /// How far game time is between the last fixed step and the next, from 0.0 to 1.0.
     ///
-    /// Returns `true` every frame while **any** bound input remains pressed.
+    /// Use it to interpolate positions simulated in `fixed_update` when rendering.
+    #[getter]
+    fn fixed_alpha(&self) -> f32 {
+        self.inner.time.fixed_alpha()
+    }
+
+    /// Whether opted-in native components update on a thread pool, off by default.
     ///
-    /// # Built-in Actions
-    /// The engine provides **default actions** preconfigured for common controls:
+    /// Components stay on the main thread unless they opt in, so Python scripts
+    /// and callbacks always run there.
+    #[getter]
+    fn parallel_updates(&self) -> bool {
+        self.inner.parallel_updates()
+    }
+
+    #[setter]
+    fn set_parallel_updates(&mut self, enabled: bool) {
+        self.inner.set_parallel_updates(enabled);
+    }
+
+    // ========== Input Methods ==========
+
+    /// Check if a keyboard key is currently held down.
     ///
-    /// - **"jump"** - Bound to `Space` key
-    /// - **"escape"** - Bound to `Escape` key
+    /// Returns `true` every frame while the key remains pressed, starting from the frame
+    /// it was pressed until the frame it's released.
     ///
     /// # Arguments
-    /// * `action_name` - Action name (case-sensitive). Can be built-in or custom actions
-    ///   created with `set_action_keys()` or `set_action_mouse_buttons()`.
+    /// * `key_name` - Key identifier string. Can be:
+    ///   - Named keys from the `Keys` class (e.g., `Keys.W`, `Keys.ESCAPE`, `Keys.SPACE`)
+    ///   - String literals (e.g., `"w"`, `"escape"`, `"space"`, `"left"`, `"return"`)
+    ///   - Case-insensitive, supports aliases (e.g., `"esc"` = `"escape"`)
     ///
     /// # Returns
-    /// `true` if any bound input is currently held down, `false` otherwise
+    /// `true` if the key is currently held down, `false` otherwise
     ///
-    /// # Comparison with `action_pressed()`
-    /// - `action_down()`: Returns `true` **every frame** while any input is held
-    /// - `action_pressed()`: Returns `true` **only on the first frame** when activated
+    /// # Comparison with `key_pressed()`
+    /// - `key_down()`: Returns `true` **every frame** while held
+    /// - `key_pressed()`: Returns `true` **only on the first frame** when pressed
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
     /// engine = pyg.Engine()
-    /// engine.start_manual(title="Action Demo")
-    ///
-    /// # Configure custom action
-    /// engine.input.set_action_keys("fire", [pyg.Keys.SPACE, pyg.Keys.CONTROL])
-    /// engine.input.set_action_mouse_buttons("fire", [pyg.MouseButton.LEFT])
+    /// engine.start_manual(title="Key Input Demo")
     ///
     /// while engine.poll_events():
-    ///     # Check if fire action is held (any of Space, Ctrl, or Left Mouse)
-    ///     if engine.input.action_down("fire"):
-    ///         print("Firing continuously!")
+    ///     # Check if space is held down
+    ///     if engine.input.key_down(pyg.Keys.SPACE):
+    ///         print("Space is being held")  # Prints every frame
     ///
-    ///     # Check built-in escape action
-    ///     if engine.input.action_down("escape"):
-    ///         print("Escape key held")
+    ///     # Check if W is held (string form)
+    ///     if engine.input.key_down("w"):
+    ///         print("W key is down")
     ///
     ///     engine.update()
     ///     engine.render()
     /// ```
     ///
     /// # See Also
-    /// - `examples/python_input_demo.py` - Action usage demonstration
-    /// - `action_pressed()` - Detect single action activation
-    /// - `action_released()` - Detect when action is deactivated
-    /// - `set_action_keys()` - Bind keys to an action
-    /// - `set_action_mouse_buttons()` - Bind mouse buttons to an action
-    fn action_down(&self, action_name: &str) -> bool {
+    /// - `examples/python_input_demo.py` - Complete input system demonstration
+    /// - `key_pressed()` - Detect single key press events
+    /// - `key_released()` - Detect key release events
+    fn key_down(&self, key_name: &str) -> bool {
         if let Some(input) = &self.inner.input_manager {
-            input.action_down(action_name)
+            input.key_down(&parse_key(key_name))
         } else {
             false
         }
     }
 
-    /// Check whether an action was pressed this frame.
-    ///
-    /// Returns `true` **only on the first frame** when any bound input transitions from
-    /// up to down. Use this for discrete game events like jumping, toggling menus, or
-    /// shooting single shots.
+    /// Check if a keyboard key was pressed this frame.
     ///
-    /// # Built-in Actions
-    /// - **"jump"** - Bound to `Space` key
-    /// - **"escape"** - Bound to `Escape` key
+    /// Returns `true` **only on the first frame** when the key transitions from up to down.
+    /// Use this to detect discrete key press events (e.g., jumping, shooting, menu selection).
     ///
     /// # Arguments
-    /// * `action_name` - Action name (case-sensitive)
+    /// * `key_name` - Key identifier string. Can be:
+    ///   - Named keys from the `Keys` class (e.g., `Keys.W`, `Keys.ESCAPE`, `Keys.SPACE`)
+    ///   - String literals (e.g., `"w"`, `"escape"`, `"space"`, `"left"`, `"return"`)
+    ///   - Case-insensitive, supports aliases (e.g., `"esc"` = `"escape"`)
     ///
     /// # Returns
-    /// `true` if any bound input was just pressed this frame, `false` otherwise
+    /// `true` if the key was just pressed this frame, `false` otherwise
     ///
-    /// # Comparison with `action_down()`
-    /// - `action_pressed()`: Returns `true` **only on the first frame** when activated
-    /// - `action_down()`: Returns `true` **every frame** while any input is held
+    /// # Comparison with `key_down()`
+    /// - `key_pressed()`: Returns `true` **only on the first frame** when pressed
+    /// - `key_down()`: Returns `true` **every frame** while held
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
     /// engine = pyg.Engine()
-    /// engine.start_manual(title="Action Pressed Demo")
-    ///
-    /// # Configure actions
-    /// engine.input.set_action_keys("jump", [pyg.Keys.SPACE, pyg.Keys.W])
-    /// engine.input.set_action_keys("pause", [pyg.Keys.ESCAPE, pyg.Keys.P])
+    /// engine.start_manual(title="Key Pressed Demo")
     ///
-    /// paused = False
+    /// jump_count = 0
     ///
     /// while engine.poll_events():
-    ///     # Toggle pause on press (won't repeat while held)
-    ///     if engine.input.action_pressed("pause"):
-    ///         paused = not paused
-    ///         print(f"Game {'paused' if paused else 'resumed'}")
+    ///     # Detect single space press (won't repeat while held)
+    ///     if engine.input.key_pressed(pyg.Keys.SPACE):
+    ///         jump_count += 1
+    ///         print(f"Jump! (count: {jump_count})")
     ///
-    ///     if not paused:
-    ///         # Single jump per press
-    ///         if engine.input.action_pressed("jump"):
-    ///             print("Jump!")
+    ///     # Detect enter key press
+    ///     if engine.input.key_pressed("return"):
+    ///         print("Enter pressed - submit action")
     ///
     ///     engine.update()
     ///     engine.render()
     /// ```
     ///
     /// # See Also
-    /// - `examples/python_input_demo.py` - Action system demonstration
-    /// - `action_down()` - Check if action is currently held
-    /// - `action_released()` - Detect action deactivation
-    /// - `set_action_keys()` - Bind keys to actions
-    fn action_pressed(&self, action_name: &str) -> bool {
+    /// - `examples/python_input_demo.py` - Input system demonstration
+    /// - `key_down()` - Check if key is currently held
+    /// - `key_released()` - Detect key release events
+    fn key_pressed(&self, key_name: &str) -> bool {
         if let Some(input) = &self.inner.input_manager {
-            input.action_pressed(action_name)
+            input.key_pressed(&parse_key(key_name))
         } else {
             false
         }
     }
 
-    /// Check whether an action was released this frame.
+    /// Check if a keyboard key was released this frame.
     ///
-    /// Returns `true` **only on the first frame** when **all** bound inputs transition
-    /// from down to up. Use this for charge-up mechanics or detecting when the user
-    /// stops performing an action.
+    /// Returns `true` **only on the first frame** when the key transitions from down to up.
+    /// Use this to detect when a user stops pressing a key (e.g., charge-up mechanics,
+    /// detecting hold duration).
     ///
     /// # Arguments
-    /// * `action_name` - Action name (case-sensitive)
+    /// * `key_name` - Key identifier string. Can be:
+    ///   - Named keys from the `Keys` class (e.g., `Keys.W`, `Keys.ESCAPE`, `Keys.SPACE`)
+    ///   - String literals (e.g., `"w"`, `"escape"`, `"space"`, `"left"`, `"return"`)
+    ///   - Case-insensitive, supports aliases (e.g., `"esc"` = `"escape"`)
     ///
     /// # Returns
-    /// `true` if the action was just released this frame, `false` otherwise
+    /// `true` if the key was just released this frame, `false` otherwise
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
     /// engine = pyg.Engine()
-    /// engine.start_manual(title="Action Released Demo")
-    ///
-    /// engine.input.set_action_keys("charge", [pyg.Keys.SPACE])
+    /// engine.start_manual(title="Key Released Demo")
     ///
     /// charge_time = 0.0
+    /// charging = False
     ///
     /// while engine.poll_events():
     ///     dt = engine.delta_time
     ///
-    ///     # Accumulate charge while held
-    ///     if engine.input.action_down("charge"):
-    ///         charge_time += dt
-    ///         print(f"Charging: {charge_time:.2f}s")
+    ///     # Start charging when space is pressed
+    ///     if engine.input.key_pressed(pyg.Keys.SPACE):
+    ///         charging = True
+    ///         charge_time = 0.0
     ///
-    ///     # Fire when released
-    ///     if engine.input.action_released("charge"):
-    ///         power = min(charge_time * 100, 300)  # Cap at 300
-    ///         print(f"Released! Power: {power:.0f}")
+    ///     # Accumulate charge time while held
+    ///     if engine.input.key_down(pyg.Keys.SPACE):
+    ///         charge_time += dt
+    ///
+    ///     # Release action when space is let go
+    ///     if engine.input.key_released(pyg.Keys.SPACE):
+    ///         print(f"Released charge: {charge_time:.2f}s")
+    ///         charging = False
     ///         charge_time = 0.0
     ///
     ///     engine.update()
@@ -3337,333 +5172,421 @@ impl PyEngine {
     /// ```
     ///
     /// # See Also
-    /// - `examples/python_input_demo.py` - Action system demonstration
-    /// - `action_down()` - Check if action is held
-    /// - `action_pressed()` - Detect action activation
-    /// - `set_action_keys()` - Configure action bindings
-    fn action_released(&self, action_name: &str) -> bool {
+    /// - `examples/python_input_demo.py` - Input system demonstration
+    /// - `key_down()` - Check if key is currently held
+    /// - `key_pressed()` - Detect key press events
+    fn key_released(&self, key_name: &str) -> bool {
         if let Some(input) = &self.inner.input_manager {
-            input.action_released(action_name)
+            input.key_released(&parse_key(key_name))
         } else {
             false
         }
     }
 
-    /// List all configured action names.
+    /// Check if a mouse button is currently held down.
     ///
-    /// Returns a list of all action names that have been configured, including
-    /// both built-in actions ("jump", "escape") and custom actions.
+    /// Returns `true` every frame while the button remains pressed, starting from the frame
+    /// it was pressed until the frame it's released.
+    ///
+    /// # Arguments
+    /// * `button` - Mouse button identifier. Can be:
+    ///   - Constants from `MouseButton` class: `MouseButton.LEFT`, `MouseButton.RIGHT`, `MouseButton.MIDDLE`
+    ///   - String literals: `"left"`, `"right"`, `"middle"` (case-insensitive)
     ///
     /// # Returns
-    /// Vector of action name strings
+    /// `true` if the button is currently held down, `false` otherwise
+    ///
+    /// # Comparison with `mouse_button_pressed()`
+    /// - `mouse_button_down()`: Returns `true` **every frame** while held
+    /// - `mouse_button_pressed()`: Returns `true` **only on the first frame** when clicked
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
     /// engine = pyg.Engine()
+    /// engine.start_manual(title="Mouse Button Demo")
     ///
-    /// # Create custom actions
-    /// engine.input.set_action_keys("fire", [pyg.Keys.SPACE])
-    /// engine.input.set_action_keys("reload", [pyg.Keys.R])
+    /// while engine.poll_events():
+    ///     # Check if left button is held (draws continuously)
+    ///     if engine.input.mouse_button_down(pyg.MouseButton.LEFT):
+    ///         mx, my = engine.input.mouse_position
+    ///         engine.draw_circle(mx, my, 10, pyg.Color.RED, filled=True)
+    ///         print(f"Dragging at ({mx}, {my})")
     ///
-    /// # List all actions
-    /// actions = engine.input.action_names()
-    /// print(f"Available actions: {actions}")
-    /// # Output: ['jump', 'escape', 'fire', 'reload']
+    ///     # String form also works
+    ///     if engine.input.mouse_button_down("right"):
+    ///         print("Right button held")
+    ///
+    ///     engine.update()
+    ///     engine.render()
     /// ```
-    fn action_names(&self) -> Vec<String> {
+    ///
+    /// # See Also
+    /// - `examples/python_input_demo.py` - Input demonstration including mouse
+    /// - `mouse_button_pressed()` - Detect single click events
+    /// - `mouse_button_released()` - Detect button release
+    /// - `mouse_position` - Get current mouse coordinates
+    fn mouse_button_down(&self, button: &str) -> bool {
         if let Some(input) = &self.inner.input_manager {
-            input.action_names()
+            input.mouse_button_down(parse_mouse_button(button))
         } else {
-            Vec::new()
+            false
         }
     }
 
-    /// Restore default axis/action bindings.
+    /// Check if a mouse button was pressed this frame.
     ///
-    /// Resets all input bindings back to the engine's default configuration:
-    /// - "Horizontal" axis: A/D and Left/Right arrow keys
-    /// - "Vertical" axis: W/S and Up/Down arrow keys
-    /// - "jump" action: Space key
-    /// - "escape" action: Escape key
+    /// Returns `true` **only on the first frame** when the button transitions from up to down.
+    /// Use this to detect discrete click events (e.g., shooting, selecting, spawning objects).
     ///
-    /// All custom axes and actions are removed.
+    /// # Arguments
+    /// * `button` - Mouse button identifier. Can be:
+    ///   - Constants from `MouseButton` class: `MouseButton.LEFT`, `MouseButton.RIGHT`, `MouseButton.MIDDLE`
+    ///   - String literals: `"left"`, `"right"`, `"middle"` (case-insensitive)
+    ///
+    /// # Returns
+    /// `true` if the button was just pressed this frame, `false` otherwise
+    ///
+    /// # Comparison with `mouse_button_down()`
+    /// - `mouse_button_pressed()`: Returns `true` **only on the first frame** when clicked
+    /// - `mouse_button_down()`: Returns `true` **every frame** while held
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
     /// engine = pyg.Engine()
+    /// engine.start_manual(title="Mouse Click Demo")
     ///
-    /// # Customize bindings
-    /// engine.input.set_axis_keys("Horizontal", [pyg.Keys.L], [pyg.Keys.J])
-    /// engine.input.set_action_keys("fire", [pyg.Keys.SPACE])
+    /// while engine.poll_events():
+    ///     # Teleport to mouse position on left click
+    ///     if engine.input.mouse_button_pressed(pyg.MouseButton.LEFT):
+    ///         mx, my = engine.input.mouse_position
+    ///         print(f"Clicked at ({mx}, {my})")
     ///
-    /// # Reset to defaults
-    /// engine.input.reset_input_bindings_to_defaults()
+    ///     # Right click for alternative action
+    ///     if engine.input.mouse_button_pressed(pyg.MouseButton.RIGHT):
+    ///         print("Right click - open context menu")
     ///
-    /// # Now back to default: WASD/Arrows for axes, Space for jump
+    ///     engine.update()
+    ///     engine.render()
     /// ```
-    fn reset_input_bindings_to_defaults(&mut self) {
-        if let Some(input) = &mut self.inner.input_manager {
-            input.reset_input_bindings_to_defaults();
+    ///
+    /// # See Also
+    /// - `examples/python_input_demo.py` - Complete input demonstration
+    /// - `mouse_button_down()` - Check if button is currently held
+    /// - `mouse_button_released()` - Detect button release
+    /// - `mouse_position` - Get mouse coordinates
+    fn mouse_button_pressed(&self, button: &str) -> bool {
+        if let Some(input) = &self.inner.input_manager {
+            input.mouse_button_pressed(parse_mouse_button(button))
+        } else {
+            false
         }
     }
 
-    /// Configure keyboard keys for a logical axis.
+    /// Check if a mouse button was released this frame.
     ///
-    /// Creates or updates a named axis that responds to keyboard input. Multiple keys
-    /// can be bound to each direction, and pressing any of them will activate the axis.
-    /// If both positive and negative keys are pressed simultaneously, they cancel out to 0.
+    /// Returns `true` **only on the first frame** when the button transitions from down to up.
+    /// Use this to detect when a user stops clicking (e.g., completing drag operations,
+    /// finishing drawing strokes).
     ///
     /// # Arguments
-    /// * `name` - Axis name (case-sensitive). Will create new axis or overwrite existing.
-    /// * `positive_keys` - List of keys that produce positive values (+1.0).
-    ///   Can use `Keys` constants or strings (e.g., `[Keys.D, Keys.RIGHT]` or `["d", "right"]`)
-    /// * `negative_keys` - List of keys that produce negative values (-1.0).
-    ///   Can use `Keys` constants or strings (e.g., `[Keys.A, Keys.LEFT]` or `["a", "left"]`)
-    /// * `sensitivity` - Multiplier for axis value (default: 1.0). Use values < 1.0 to reduce
-    ///   responsiveness or > 1.0 to amplify (though axis is typically clamped to [-1.0, 1.0])
+    /// * `button` - Mouse button identifier. Can be:
+    ///   - Constants from `MouseButton` class: `MouseButton.LEFT`, `MouseButton.RIGHT`, `MouseButton.MIDDLE`
+    ///   - String literals: `"left"`, `"right"`, `"middle"` (case-insensitive)
+    ///
+    /// # Returns
+    /// `true` if the button was just released this frame, `false` otherwise
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
     /// engine = pyg.Engine()
+    /// engine.start_manual(title="Mouse Release Demo")
     ///
-    /// # Create custom horizontal axis with different keys
-    /// engine.input.set_axis_keys(
-    ///     "Strafe",
-    ///     positive_keys=[pyg.Keys.E, pyg.Keys.RIGHT],  # Move right
-    ///     negative_keys=[pyg.Keys.Q, pyg.Keys.LEFT],   # Move left
-    ///     sensitivity=1.0
-    /// )
-    ///
-    /// # Create zoom axis
-    /// engine.input.set_axis_keys(
-    ///     "CameraZoom",
-    ///     positive_keys=[pyg.Keys.EQUAL, pyg.Keys.PAGEUP],      # Zoom in
-    ///     negative_keys=[pyg.Keys.MINUS, pyg.Keys.PAGEDOWN],    # Zoom out
-    ///     sensitivity=1.0
-    /// )
-    ///
-    /// engine.start_manual(title="Custom Axes Demo")
+    /// dragging = False
+    /// drag_start = (0, 0)
     ///
     /// while engine.poll_events():
-    ///     # Read custom axes
-    ///     strafe = engine.input.axis("Strafe")
-    ///     zoom = engine.input.axis("CameraZoom")
+    ///     # Start drag on press
+    ///     if engine.input.mouse_button_pressed(pyg.MouseButton.LEFT):
+    ///         dragging = True
+    ///         drag_start = engine.input.mouse_position
     ///
-    ///     print(f"Strafe: {strafe:.2f}, Zoom: {zoom:.2f}")
+    ///     # Finish drag on release
+    ///     if engine.input.mouse_button_released(pyg.MouseButton.LEFT):
+    ///         if dragging:
+    ///             drag_end = engine.input.mouse_position
+    ///             print(f"Dragged from {drag_start} to {drag_end}")
+    ///             dragging = False
     ///
     ///     engine.update()
     ///     engine.render()
     /// ```
     ///
-    /// # Modifying Built-in Axes
-    /// You can reconfigure the default "Horizontal" and "Vertical" axes:
-    /// ```python
-    /// # Change Horizontal to use J/L keys instead of A/D
-    /// engine.input.set_axis_keys(
-    ///     "Horizontal",
-    ///     positive_keys=[pyg.Keys.L],
-    ///     negative_keys=[pyg.Keys.J],
-    ///     sensitivity=1.0
-    /// )
-    /// ```
-    ///
     /// # See Also
-    /// - `examples/python_camera_worldspace_demo.py` - Custom axis configuration
-    /// - `axis()` - Read axis value
-    /// - `set_axis_mouse()` - Bind axes to mouse movement
-    /// - `add_axis_positive_key()` / `add_axis_negative_key()` - Add keys incrementally
-    /// - `remove_axis()` - Delete an axis
-    #[pyo3(signature = (name, positive_keys, negative_keys, sensitivity=1.0))]
-    fn set_axis_keys(
-        &mut self,
-        name: &str,
-        positive_keys: Vec<String>,
-        negative_keys: Vec<String>,
-        sensitivity: f32,
-    ) {
-        if let Some(input) = &mut self.inner.input_manager {
-            let positive = positive_keys.iter().map(|k| parse_key(k)).collect();
-            let negative = negative_keys.iter().map(|k| parse_key(k)).collect();
-            input.set_axis_keyboard_keys(name, positive, negative, sensitivity);
+    /// - `examples/python_input_demo.py` - Input system demonstration
+    /// - `mouse_button_down()` - Check if button is currently held
+    /// - `mouse_button_pressed()` - Detect button press
+    /// - `mouse_position` - Get mouse coordinates
+    fn mouse_button_released(&self, button: &str) -> bool {
+        if let Some(input) = &self.inner.input_manager {
+            input.mouse_button_released(parse_mouse_button(button))
+        } else {
+            false
         }
     }
 
-    /// Add one positive key to an axis binding.
+    /// Check if any key, mouse button or joystick button was pressed this frame.
     ///
-    /// Incrementally adds a key that produces positive axis values (+1.0)
-    /// without removing existing keys.
+    /// Useful for "press any key" prompts and skippable cutscenes.
     ///
-    /// # Arguments
-    /// * `axis_name` - Name of the axis to modify
-    /// * `key_name` - Key to add (e.g., `"d"`, `Keys.D`)
+    /// # Returns
+    /// `true` if at least one press happened this frame, `false` otherwise
+    fn any_pressed(&self) -> bool {
+        self.inner
+            .input_manager
+            .as_ref()
+            .is_some_and(|input| input.any_pressed())
+    }
+
+    /// Get the current mouse position in window coordinates.
+    ///
+    /// Returns the mouse cursor position in **screen-space pixels** with origin at the
+    /// **top-left corner** of the window (0, 0). Coordinates increase right (X) and down (Y).
+    ///
+    /// # Returns
+    /// Tuple `(x, y)` where:
+    /// - `x` (float): Horizontal position from left edge in pixels
+    /// - `y` (float): Vertical position from top edge in pixels
+    ///
+    /// Returns `(0.0, 0.0)` if the mouse position is unavailable.
+    ///
+    /// # Coordinate System
+    /// **Screen-space coordinates:**
+    /// - Origin: Top-left corner (0, 0)
+    /// - X-axis: Increases to the right
+    /// - Y-axis: Increases downward
+    /// - Units: Pixels
+    ///
+    /// For **world-space coordinates** (relative to camera), use `screen_to_world()`.
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
     /// engine = pyg.Engine()
+    /// engine.start_manual(title="Mouse Position Demo")
     ///
-    /// # Add extra positive keys to Horizontal axis
-    /// engine.input.add_axis_positive_key("Horizontal", pyg.Keys.L)
-    /// engine.input.add_axis_positive_key("Horizontal", pyg.Keys.NUMPAD6)
+    /// while engine.poll_events():
+    ///     # Get screen-space mouse position
+    ///     mx, my = engine.input.mouse_position
     ///
-    /// # Now Horizontal axis responds to: D, Right, L, Numpad6
-    /// ```
-    fn add_axis_positive_key(&mut self, axis_name: &str, key_name: &str) {
-        if let Some(input) = &mut self.inner.input_manager {
-            input.add_axis_positive_key(axis_name, parse_key(key_name));
-        }
-    }
-
-    /// Add one negative key to an axis binding.
-    ///
-    /// Incrementally adds a key that produces negative axis values (-1.0)
-    /// without removing existing keys.
-    ///
-    /// # Arguments
-    /// * `axis_name` - Name of the axis to modify
-    /// * `key_name` - Key to add (e.g., `"a"`, `Keys.A`)
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// engine = pyg.Engine()
+    ///     # Draw a circle at mouse cursor
+    ///     engine.clear_draw_commands()
+    ///     engine.draw_circle(mx, my, 20, pyg.Color.RED, filled=True)
     ///
-    /// # Add extra negative keys to Horizontal axis
-    /// engine.input.add_axis_negative_key("Horizontal", pyg.Keys.J)
-    /// engine.input.add_axis_negative_key("Horizontal", pyg.Keys.NUMPAD4)
+    ///     # Convert to world-space for GameObject positioning
+    ///     world_pos = engine.screen_to_world(pyg.Vec2(mx, my))
+    ///     print(f"Mouse - Screen: ({mx}, {my}), World: {world_pos}")
     ///
-    /// # Now Horizontal axis responds to: A, Left, J, Numpad4
+    ///     engine.update()
+    ///     engine.render()
     /// ```
-    fn add_axis_negative_key(&mut self, axis_name: &str, key_name: &str) {
-        if let Some(input) = &mut self.inner.input_manager {
-            input.add_axis_negative_key(axis_name, parse_key(key_name));
+    ///
+    /// # See Also
+    /// - `examples/python_input_demo.py` - Mouse input demonstration
+    /// - `examples/python_camera_worldspace_demo.py` - Screen/world conversion
+    /// - `screen_to_world()` - Convert screen coordinates to world-space
+    /// - `mouse_delta` - Get mouse movement since last frame
+    fn mouse_position(&self) -> (f64, f64) {
+        if let Some(input) = &self.inner.input_manager {
+            input.mouse_position()
+        } else {
+            (0.0, 0.0)
         }
     }
 
-    /// Remove one positive key from an axis binding.
+    /// Get the mouse movement delta for this frame.
     ///
-    /// # Arguments
-    /// * `axis_name` - Name of the axis to modify
-    /// * `key_name` - Key to remove
+    /// Returns the change in mouse position since the last frame in pixels.
+    /// Useful for camera controls, mouse look, and drag operations.
     ///
     /// # Returns
-    /// `true` if the key was found and removed, `false` otherwise
+    /// Tuple `(dx, dy)` where:
+    /// - `dx` (float): Horizontal movement (positive = right)
+    /// - `dy` (float): Vertical movement (positive = down)
+    ///
+    /// Returns `(0.0, 0.0)` if no movement or input unavailable.
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
     /// engine = pyg.Engine()
+    /// engine.start_manual(title="Mouse Delta Demo")
     ///
-    /// # Remove arrow key from Horizontal axis (keep WASD only)
-    /// if engine.input.remove_axis_positive_key("Horizontal", pyg.Keys.RIGHT):
-    ///     print("Removed Right arrow from Horizontal axis")
+    /// camera_x = 0.0
+    /// camera_y = 0.0
+    /// sensitivity = 2.0
+    ///
+    /// while engine.poll_events():
+    ///     # Get mouse movement
+    ///     dx, dy = engine.input.mouse_delta
+    ///
+    ///     # Pan camera with mouse movement when button held
+    ///     if engine.input.mouse_button_down(pyg.MouseButton.LEFT):
+    ///         camera_x -= dx * sensitivity
+    ///         camera_y -= dy * sensitivity
+    ///         engine.set_camera_position(pyg.Vec2(camera_x, camera_y))
+    ///
+    ///     engine.update()
+    ///     engine.render()
     /// ```
-    fn remove_axis_positive_key(&mut self, axis_name: &str, key_name: &str) -> bool {
-        if let Some(input) = &mut self.inner.input_manager {
-            input.remove_axis_positive_key(axis_name, &parse_key(key_name))
+    ///
+    /// # See Also
+    /// - `examples/python_camera_worldspace_demo.py` - Camera panning with mouse
+    /// - `mouse_position` - Get absolute mouse coordinates
+    /// - `mouse_wheel` - Get scroll wheel delta
+    fn mouse_delta(&self) -> (f64, f64) {
+        if let Some(input) = &self.inner.input_manager {
+            input.mouse_delta()
         } else {
-            false
+            (0.0, 0.0)
         }
     }
 
-    /// Remove one negative key from an axis binding.
+    /// Get the mouse wheel delta accumulated this frame.
     ///
-    /// # Arguments
-    /// * `axis_name` - Name of the axis to modify
-    /// * `key_name` - Key to remove
+    /// Returns the scroll wheel movement for the current frame. Typically used for
+    /// zooming, scrolling content, or adjusting values.
     ///
     /// # Returns
-    /// `true` if the key was found and removed, `false` otherwise
+    /// Tuple `(wheel_x, wheel_y)` where:
+    /// - `wheel_x` (float): Horizontal scroll (positive = right, rare on most mice)
+    /// - `wheel_y` (float): Vertical scroll (positive = scroll up/away from user)
+    ///
+    /// Typical values per scroll notch: ±1.0 to ±3.0 (varies by device)
+    /// Returns `(0.0, 0.0)` if no scrolling this frame.
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
     /// engine = pyg.Engine()
+    /// engine.start_manual(title="Mouse Wheel Demo")
     ///
-    /// # Remove arrow key from Horizontal axis
-    /// if engine.input.remove_axis_negative_key("Horizontal", pyg.Keys.LEFT):
-    ///     print("Removed Left arrow from Horizontal axis")
+    /// zoom = 1.0
+    ///
+    /// while engine.poll_events():
+    ///     # Get scroll wheel delta
+    ///     wheel_x, wheel_y = engine.input.mouse_wheel
+    ///
+    ///     # Zoom camera with scroll wheel
+    ///     if wheel_y != 0:
+    ///         zoom += wheel_y * 0.1
+    ///         zoom = max(0.5, min(5.0, zoom))  # Clamp between 0.5x and 5x
+    ///         print(f"Zoom level: {zoom:.1f}x")
+    ///
+    ///         # Apply zoom by adjusting viewport size
+    ///         base_viewport = pyg.Vec2(24.0, 13.5)
+    ///         engine.set_camera_viewport_size(
+    ///             base_viewport.x / zoom,
+    ///             base_viewport.y / zoom
+    ///         )
+    ///
+    ///     engine.update()
+    ///     engine.render()
     /// ```
-    fn remove_axis_negative_key(&mut self, axis_name: &str, key_name: &str) -> bool {
-        if let Some(input) = &mut self.inner.input_manager {
-            input.remove_axis_negative_key(axis_name, &parse_key(key_name))
+    ///
+    /// # See Also
+    /// - `examples/python_camera_worldspace_demo.py` - Wheel-based camera zoom
+    /// - `mouse_delta` - Get mouse cursor movement
+    /// - `set_camera_viewport_size()` - Adjust camera zoom
+    fn mouse_wheel(&self) -> (f64, f64) {
+        if let Some(input) = &self.inner.input_manager {
+            input.mouse_wheel()
         } else {
-            false
+            (0.0, 0.0)
         }
     }
 
-    /// Remove an entire logical axis binding.
+    /// Get the current value of a logical axis.
     ///
-    /// Completely removes an axis, including all key and mouse bindings.
-    /// Built-in axes ("Horizontal", "Vertical") can be removed but will
-    /// return if `reset_input_bindings_to_defaults()` is called.
+    /// Returns a float in the range **[-1.0, 1.0]** representing the axis state.
+    /// Axes provide a high-level input abstraction for movement and analog controls,
+    /// combining multiple input sources (keyboard keys, mouse axes) into a single value.
+    ///
+    /// # Built-in Axes
+    /// The engine provides **default axes** preconfigured for common game controls:
+    ///
+    /// - **"Horizontal"** - Left/Right movement
+    ///   - Negative (-1.0): `A`, `Left Arrow`
+    ///   - Positive (+1.0): `D`, `Right Arrow`
+    ///
+    /// - **"Vertical"** - Up/Down movement
+    ///   - Negative (-1.0): `S`, `Down Arrow`
+    ///   - Positive (+1.0): `W`, `Up Arrow`
     ///
     /// # Arguments
-    /// * `axis_name` - Name of the axis to remove
+    /// * `name` - Axis name (case-sensitive string). Can be built-in axes or custom
+    ///   axes created with `set_axis_keys()` or `set_axis_mouse()`.
     ///
     /// # Returns
-    /// `true` if the axis existed and was removed, `false` otherwise
+    /// Float value from **-1.0 to 1.0**:
+    /// - `-1.0`: Full negative input (left, down, etc.)
+    /// - `0.0`: No input or axis doesn't exist
+    /// - `+1.0`: Full positive input (right, up, etc.)
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
     /// engine = pyg.Engine()
+    /// engine.start_manual(title="Axis Input Demo")
     ///
-    /// # Create temporary axis
-    /// engine.input.set_axis_keys("TempAxis", [pyg.Keys.T], [pyg.Keys.G])
+    /// player_x = 400.0
+    /// player_y = 300.0
+    /// speed = 200.0  # pixels per second
     ///
-    /// # Later remove it
-    /// if engine.input.remove_axis("TempAxis"):
-    ///     print("TempAxis removed")
+    /// while engine.poll_events():
+    ///     dt = engine.delta_time
     ///
-    /// # Axis no longer exists
-    /// value = engine.input.axis("TempAxis")  # Returns 0.0
-    /// ```
-    fn remove_axis(&mut self, axis_name: &str) -> bool {
-        if let Some(input) = &mut self.inner.input_manager {
-            input.remove_axis(axis_name)
-        } else {
-            false
-        }
-    }
-
-    /// Configure a mouse-driven logical axis.
+    ///     # Read built-in axes for movement
+    ///     move_x = engine.input.axis("Horizontal")  # -1.0 to 1.0
+    ///     move_y = engine.input.axis("Vertical")    # -1.0 to 1.0
     ///
-    /// Creates or updates a named axis that responds to mouse movement or scroll wheel.
-    /// Useful for camera controls, mouse look, and zoom functionality.
+    ///     # Apply frame-independent movement
+    ///     player_x += move_x * speed * dt
+    ///     player_y -= move_y * speed * dt  # Invert Y for up=positive
     ///
-    /// # Arguments
-    /// * `name` - Axis name (case-sensitive). Will create new axis or overwrite existing.
-    /// * `mouse_axis` - Mouse input source (case-insensitive). Valid values:
-    ///   - **`"x"`** or **`"mouse_x"`** - Horizontal mouse movement (right = positive)
-    ///   - **`"y"`** or **`"mouse_y"`** - Vertical mouse movement (down = positive)
-    ///   - **`"wheel_x"`** or **`"scroll_x"`** - Horizontal scroll wheel
-    ///   - **`"wheel_y"`**, **`"scroll"`**, or **`"scroll_y"`** - Vertical scroll wheel (up = positive)
-    /// * `sensitivity` - Multiplier for mouse input (default: 1.0). Higher values = more responsive.
-    ///   Typical values: 0.1 - 2.0
-    /// * `invert` - Invert the axis direction (default: false). Set `true` to reverse polarity.
+    ///     # Draw player
+    ///     engine.clear_draw_commands()
+    ///     engine.draw_circle(player_x, player_y, 20, pyg.Color.GREEN, filled=True)
     ///
-    /// # Returns
-    /// `true` if axis was created successfully, `false` if `mouse_axis` was invalid
+    ///     # Show axis values
+    ///     if move_x != 0 or move_y != 0:
+    ///         print(f"Axis - H: {move_x:.2f}, V: {move_y:.2f}")
     ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
+    ///     engine.update()
+    ///     engine.render()
+    /// ```
     ///
-    /// engine = pyg.Engine()
+    /// # Custom Axes
+    /// Create custom axes for specialized controls:
+    /// ```python
+    /// # Create a zoom axis with Q/E keys
+    /// engine.input.set_axis_keys(
+    ///     "CameraZoom",
+    ///     positive_keys=[pyg.Keys.E],  # Zoom out
+    ///     negative_keys=[pyg.Keys.Q],  # Zoom in
+    ///     sensitivity=1.0
+    /// )
     ///
-    /// # Create mouse look axes
+    /// # Bind mouse X movement to an axis
     /// engine.input.set_axis_mouse(
     ///     "MouseLookX",
     ///     mouse_axis="x",
@@ -3671,166 +5594,97 @@ impl PyEngine {
     ///     invert=False
     /// )
     ///
-    /// engine.input.set_axis_mouse(
-    ///     "MouseLookY",
-    ///     mouse_axis="y",
-    ///     sensitivity=0.5,
-    ///     invert=True  # Invert Y for flight-stick style controls
-    /// )
-    ///
-    /// # Create scroll wheel zoom axis
-    /// engine.input.set_axis_mouse(
-    ///     "ScrollZoom",
-    ///     mouse_axis="wheel_y",
-    ///     sensitivity=0.1,
-    ///     invert=False
-    /// )
+    /// zoom_input = engine.input.axis("CameraZoom")
+    /// look_input = engine.input.axis("MouseLookX")
+    /// ```
     ///
-    /// engine.start_manual(title="Mouse Axis Demo")
-    ///
-    /// camera_x = 0.0
-    /// camera_y = 0.0
-    /// zoom = 1.0
-    ///
-    /// while engine.poll_events():
-    ///     dt = engine.delta_time
+    /// # See Also
+    /// - `examples/python_input_demo.py` - Axis-based movement demonstration
+    /// - `examples/python_camera_worldspace_demo.py` - Camera control with axes
+    /// - `set_axis_keys()` - Create custom keyboard axes
+    /// - `set_axis_mouse()` - Bind axes to mouse movement
+    /// - `axis_names()` - List all configured axes
+    fn axis(&self, name: &str) -> f32 {
+        if let Some(input) = &self.inner.input_manager {
+            input.axis(name)
+        } else {
+            0.0
+        }
+    }
+
+    /// Get the current value of a logical axis without smoothing.
     ///
-    ///     # Read mouse axes for camera control
-    ///     look_x = engine.input.axis("MouseLookX")
-    ///     look_y = engine.input.axis("MouseLookY")
-    ///     scroll_zoom = engine.input.axis("ScrollZoom")
+    /// Keyboard-driven axes read exactly -1.0, 0.0, or 1.0 (like Unity's `GetAxisRaw`).
+    fn axis_raw(&self, name: &str) -> f32 {
+        if let Some(input) = &self.inner.input_manager {
+            input.axis_raw(name)
+        } else {
+            0.0
+        }
+    }
+
+    /// Smooth a logical axis over time (like Unity's `GetAxis`).
     ///
-    ///     # Pan camera with mouse (when button held)
-    ///     if engine.input.mouse_button_down(pyg.MouseButton.LEFT):
-    ///         camera_x -= look_x * 10.0 * dt
-    ///         camera_y -= look_y * 10.0 * dt
-    ///         engine.set_camera_position(pyg.Vec2(camera_x, camera_y))
+    /// The value ramps toward the raw input at `sensitivity` units/second and
+    /// returns to zero at `gravity` units/second. With `snap`, reversing
+    /// direction jumps through zero. A rate of zero or less is instant.
+    /// "Horizontal" and "Vertical" default to gravity 3, sensitivity 3, snap on.
     ///
-    ///     # Zoom with scroll wheel
-    ///     if scroll_zoom != 0:
-    ///         zoom += scroll_zoom * 0.5
-    ///         zoom = max(0.5, min(3.0, zoom))
-    ///         print(f"Zoom: {zoom:.2f}x")
+    /// Returns `false` if the axis does not exist.
     ///
-    ///     engine.update()
-    ///     engine.render()
+    /// # Example
+    /// ```python
+    /// # Snappier movement
+    /// engine.input.set_axis_smoothing("Horizontal", gravity=8.0, sensitivity=6.0)
     /// ```
-    ///
-    /// # Notes
-    /// - Mouse axes return **delta values** (change since last frame), not absolute positions
-    /// - Mouse movement axes return 0 when the mouse isn't moving
-    /// - Scroll wheel axes return 0 when not scrolling
-    /// - Multiple axes can bind to the same mouse input with different sensitivity/inversion
-    ///
-    /// # See Also
-    /// - `examples/python_camera_worldspace_demo.py` - Mouse-based camera control
-    /// - `axis()` - Read axis value
-    /// - `set_axis_keys()` - Bind axes to keyboard keys
-    /// - `mouse_delta` - Direct access to mouse movement
-    /// - `mouse_wheel` - Direct access to scroll wheel
-    #[pyo3(signature = (name, mouse_axis, sensitivity=1.0, invert=false))]
-    fn set_axis_mouse(
+    #[pyo3(signature = (axis_name, gravity=3.0, sensitivity=3.0, snap=true))]
+    fn set_axis_smoothing(
         &mut self,
-        name: &str,
-        mouse_axis: &str,
+        axis_name: &str,
+        gravity: f32,
         sensitivity: f32,
-        invert: bool,
+        snap: bool,
     ) -> bool {
-        let Some(axis_type) = parse_mouse_axis_type(mouse_axis) else {
-            return false;
-        };
         if let Some(input) = &mut self.inner.input_manager {
-            input.set_axis_mouse_binding(
-                name,
-                MouseAxisBinding {
-                    axis: axis_type,
-                    sensitivity,
-                    invert,
-                },
-            );
-            true
+            input.set_axis_smoothing(axis_name, Some(AxisSmoothing::new(gravity, sensitivity, snap)))
         } else {
             false
         }
     }
 
-    /// Configure keyboard keys for an action.
-    ///
-    /// Creates or updates a named action that activates when **any** of the specified keys
-    /// are pressed. Use actions for discrete game events like jumping, shooting, pausing,
-    /// or interacting.
-    ///
-    /// # Arguments
-    /// * `action_name` - Action name (case-sensitive). Will create new or overwrite existing.
-    /// * `key_names` - List of keys that activate this action. Pressing **any** key in the
-    ///   list will trigger the action. Can use `Keys` constants or strings.
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// engine = pyg.Engine()
-    ///
-    /// # Create jump action with multiple keys
-    /// engine.input.set_action_keys(
-    ///     "jump",
-    ///     [pyg.Keys.SPACE, pyg.Keys.W, pyg.Keys.UP]
-    /// )
-    ///
-    /// # Create pause action
-    /// engine.input.set_action_keys(
-    ///     "pause",
-    ///     [pyg.Keys.ESCAPE, pyg.Keys.P]
-    /// )
-    ///
-    /// # Create interact action
-    /// engine.input.set_action_keys(
-    ///     "interact",
-    ///     [pyg.Keys.E, pyg.Keys.RETURN]
-    /// )
-    ///
-    /// engine.start_manual(title="Action Keys Demo")
-    ///
-    /// while engine.poll_events():
-    ///     # Any bound key triggers the action
-    ///     if engine.input.action_pressed("jump"):
-    ///         print("Jump! (Space, W, or Up pressed)")
-    ///
-    ///     if engine.input.action_pressed("interact"):
-    ///         print("Interacting (E or Enter pressed)")
-    ///
-    ///     engine.update()
-    ///     engine.render()
-    /// ```
-    ///
-    /// # Combining with Mouse Buttons
-    /// Actions can bind both keys and mouse buttons:
-    /// ```python
-    /// # Fire action works with Space OR left mouse button
-    /// engine.input.set_action_keys("fire", [pyg.Keys.SPACE])
-    /// engine.input.set_action_mouse_buttons("fire", [pyg.MouseButton.LEFT])
-    /// ```
+    /// Remove smoothing from a logical axis so `axis()` reports raw values.
     ///
-    /// # See Also
-    /// - `examples/python_camera_worldspace_demo.py` - Action configuration
-    /// - `action_pressed()` / `action_down()` - Check action state
-    /// - `set_action_mouse_buttons()` - Add mouse button bindings
-    /// - `add_action_key()` - Add a key incrementally
-    /// - `clear_action_bindings()` - Remove all bindings for an action
-    fn set_action_keys(&mut self, action_name: &str, key_names: Vec<String>) {
+    /// Returns `false` if the axis does not exist.
+    fn clear_axis_smoothing(&mut self, axis_name: &str) -> bool {
         if let Some(input) = &mut self.inner.input_manager {
-            let keys = key_names.iter().map(|k| parse_key(k)).collect();
-            input.set_action_keys(action_name, keys);
+            input.set_axis_smoothing(axis_name, None)
+        } else {
+            false
         }
     }
 
-    /// Add one keyboard key to an action.
+    /// Get an axis's smoothing as `(gravity, sensitivity, snap)`, or `None` if unsmoothed.
+    fn get_axis_smoothing(&self, axis_name: &str) -> Option<(f32, f32, bool)> {
+        let smoothing = self.inner.input_manager.as_ref()?.axis_smoothing(axis_name)?;
+        Some((smoothing.gravity, smoothing.sensitivity, smoothing.snap))
+    }
+
+    /// Get the previous frame's value of a logical axis.
+    fn axis_previous(&self, name: &str) -> f32 {
+        if let Some(input) = &self.inner.input_manager {
+            input.axis_previous(name)
+        } else {
+            0.0
+        }
+    }
+
+    /// List all configured logical axis names.
     ///
-    /// Incrementally adds a key to an action without removing existing bindings.
+    /// Returns a list of all axis names that have been configured, including
+    /// both built-in axes ("Horizontal", "Vertical") and custom axes.
     ///
-    /// # Arguments
-    /// * `action_name` - Name of the action to modify
-    /// * `key_name` - Key to add
+    /// # Returns
+    /// Vector of axis name strings
     ///
     /// # Example
     /// ```python
@@ -3838,141 +5692,302 @@ impl PyEngine {
     ///
     /// engine = pyg.Engine()
     ///
-    /// # Add multiple keys to jump action
-    /// engine.input.add_action_key("jump", pyg.Keys.W)
-    /// engine.input.add_action_key("jump", pyg.Keys.UP)
+    /// # Create custom axes
+    /// engine.input.set_axis_keys("CameraZoom", [pyg.Keys.E], [pyg.Keys.Q])
+    /// engine.input.set_axis_mouse("MouseLookX", "x", 0.5)
     ///
-    /// # Now jump triggers on Space, W, or Up arrow
+    /// # List all axes
+    /// axes = engine.input.axis_names()
+    /// print(f"Available axes: {axes}")
+    /// # Output: ['Horizontal', 'Vertical', 'CameraZoom', 'MouseLookX']
     /// ```
-    fn add_action_key(&mut self, action_name: &str, key_name: &str) {
-        if let Some(input) = &mut self.inner.input_manager {
-            input.add_action_key(action_name, parse_key(key_name));
+    fn axis_names(&self) -> Vec<String> {
+        if let Some(input) = &self.inner.input_manager {
+            input.axis_names()
+        } else {
+            Vec::new()
         }
     }
 
-    /// Remove one keyboard key from an action.
+    /// Check whether an action is currently active (held).
+    ///
+    /// Actions provide high-level input abstractions for game events like jumping, shooting,
+    /// or pausing. Each action can be bound to multiple keys and mouse buttons, with any of
+    /// them activating the action.
+    ///
+    /// Returns `true` every frame while **any** bound input remains pressed.
+    ///
+    /// # Built-in Actions
+    /// The engine provides **default actions** preconfigured for common controls:
+    ///
+    /// - **"jump"** - Bound to `Space` key
+    /// - **"escape"** - Bound to `Escape` key
     ///
     /// # Arguments
-    /// * `action_name` - Name of the action to modify
-    /// * `key_name` - Key to remove
+    /// * `action_name` - Action name (case-sensitive). Can be built-in or custom actions
+    ///   created with `set_action_keys()` or `set_action_mouse_buttons()`.
     ///
     /// # Returns
-    /// `true` if the key was found and removed, `false` otherwise
+    /// `true` if any bound input is currently held down, `false` otherwise
+    ///
+    /// # Comparison with `action_pressed()`
+    /// - `action_down()`: Returns `true` **every frame** while any input is held
+    /// - `action_pressed()`: Returns `true` **only on the first frame** when activated
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
     /// engine = pyg.Engine()
+    /// engine.start_manual(title="Action Demo")
     ///
-    /// # Remove Space from jump action
-    /// if engine.input.remove_action_key("jump", pyg.Keys.SPACE):
-    ///     print("Space key removed from jump")
+    /// # Configure custom action
+    /// engine.input.set_action_keys("fire", [pyg.Keys.SPACE, pyg.Keys.CONTROL])
+    /// engine.input.set_action_mouse_buttons("fire", [pyg.MouseButton.LEFT])
+    ///
+    /// while engine.poll_events():
+    ///     # Check if fire action is held (any of Space, Ctrl, or Left Mouse)
+    ///     if engine.input.action_down("fire"):
+    ///         print("Firing continuously!")
+    ///
+    ///     # Check built-in escape action
+    ///     if engine.input.action_down("escape"):
+    ///         print("Escape key held")
+    ///
+    ///     engine.update()
+    ///     engine.render()
     /// ```
-    fn remove_action_key(&mut self, action_name: &str, key_name: &str) -> bool {
-        if let Some(input) = &mut self.inner.input_manager {
-            input.remove_action_key(action_name, &parse_key(key_name))
+    ///
+    /// # See Also
+    /// - `examples/python_input_demo.py` - Action usage demonstration
+    /// - `action_pressed()` - Detect single action activation
+    /// - `action_released()` - Detect when action is deactivated
+    /// - `set_action_keys()` - Bind keys to an action
+    /// - `set_action_mouse_buttons()` - Bind mouse buttons to an action
+    fn action_down(&self, action_name: &str) -> bool {
+        if let Some(input) = &self.inner.input_manager {
+            input.action_down(action_name)
         } else {
             false
         }
     }
 
-    /// Configure mouse buttons for an action.
+    /// Check whether an action was pressed this frame.
     ///
-    /// Creates or updates the mouse button bindings for a named action. Pressing **any**
-    /// of the specified mouse buttons will activate the action. This complements keyboard
-    /// bindings set with `set_action_keys()`.
+    /// Returns `true` **only on the first frame** when any bound input transitions from
+    /// up to down. Use this for discrete game events like jumping, toggling menus, or
+    /// shooting single shots.
+    ///
+    /// # Built-in Actions
+    /// - **"jump"** - Bound to `Space` key
+    /// - **"escape"** - Bound to `Escape` key
     ///
     /// # Arguments
     /// * `action_name` - Action name (case-sensitive)
-    /// * `buttons` - List of mouse buttons that activate this action. Can use `MouseButton`
-    ///   constants or strings (e.g., `[MouseButton.LEFT, MouseButton.RIGHT]` or `["left", "right"]`)
     ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
+    /// # Returns
+    /// `true` if any bound input was just pressed this frame, `false` otherwise
     ///
-    /// engine = pyg.Engine()
+    /// # Comparison with `action_down()`
+    /// - `action_pressed()`: Returns `true` **only on the first frame** when activated
+    /// - `action_down()`: Returns `true` **every frame** while any input is held
     ///
-    /// # Create fire action with mouse buttons
-    /// engine.input.set_action_mouse_buttons(
-    ///     "fire",
-    ///     [pyg.MouseButton.LEFT]
-    /// )
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
     ///
-    /// # Create alternate fire with right mouse
-    /// engine.input.set_action_mouse_buttons(
-    ///     "alternate_fire",
-    ///     [pyg.MouseButton.RIGHT, pyg.MouseButton.MIDDLE]
-    /// )
+    /// engine = pyg.Engine()
+    /// engine.start_manual(title="Action Pressed Demo")
     ///
-    /// # Combine with keyboard for same action
-    /// engine.input.set_action_keys("fire", [pyg.Keys.SPACE, pyg.Keys.CONTROL])
+    /// # Configure actions
+    /// engine.input.set_action_keys("jump", [pyg.Keys.SPACE, pyg.Keys.W])
+    /// engine.input.set_action_keys("pause", [pyg.Keys.ESCAPE, pyg.Keys.P])
     ///
-    /// engine.start_manual(title="Mouse Action Demo")
+    /// paused = False
     ///
     /// while engine.poll_events():
-    ///     # Triggers with Space, Ctrl, OR Left Mouse
-    ///     if engine.input.action_pressed("fire"):
-    ///         print("Fire!")
+    ///     # Toggle pause on press (won't repeat while held)
+    ///     if engine.input.action_pressed("pause"):
+    ///         paused = not paused
+    ///         print(f"Game {'paused' if paused else 'resumed'}")
     ///
-    ///     # Triggers with Right Mouse OR Middle Mouse
-    ///     if engine.input.action_pressed("alternate_fire"):
-    ///         print("Alternate fire!")
+    ///     if not paused:
+    ///         # Single jump per press
+    ///         if engine.input.action_pressed("jump"):
+    ///             print("Jump!")
     ///
     ///     engine.update()
     ///     engine.render()
     /// ```
     ///
     /// # See Also
-    /// - `examples/python_input_demo.py` - Mouse button usage
-    /// - `set_action_keys()` - Bind keyboard keys to actions
-    /// - `action_pressed()` / `action_down()` - Check action state
-    /// - `add_action_mouse_button()` - Add a button incrementally
-    fn set_action_mouse_buttons(&mut self, action_name: &str, buttons: Vec<String>) {
-        if let Some(input) = &mut self.inner.input_manager {
-            let mapped = buttons
-                .iter()
-                .map(|button| parse_mouse_button(button))
-                .collect();
-            input.set_action_mouse_buttons(action_name, mapped);
+    /// - `examples/python_input_demo.py` - Action system demonstration
+    /// - `action_down()` - Check if action is currently held
+    /// - `action_released()` - Detect action deactivation
+    /// - `set_action_keys()` - Bind keys to actions
+    fn action_pressed(&self, action_name: &str) -> bool {
+        if let Some(input) = &self.inner.input_manager {
+            input.action_pressed(action_name)
+        } else {
+            false
         }
     }
 
-    /// Add one mouse button to an action.
+    /// Check whether an action was released this frame.
     ///
-    /// Incrementally adds a mouse button to an action without removing existing bindings.
+    /// Returns `true` **only on the first frame** when **all** bound inputs transition
+    /// from down to up. Use this for charge-up mechanics or detecting when the user
+    /// stops performing an action.
     ///
     /// # Arguments
-    /// * `action_name` - Name of the action to modify
-    /// * `button` - Mouse button to add (e.g., `MouseButton.LEFT`, `"left"`)
+    /// * `action_name` - Action name (case-sensitive)
+    ///
+    /// # Returns
+    /// `true` if the action was just released this frame, `false` otherwise
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
     /// engine = pyg.Engine()
+    /// engine.start_manual(title="Action Released Demo")
     ///
-    /// # Add mouse buttons to fire action
-    /// engine.input.add_action_mouse_button("fire", pyg.MouseButton.LEFT)
-    /// engine.input.add_action_mouse_button("fire", pyg.MouseButton.MIDDLE)
+    /// engine.input.set_action_keys("charge", [pyg.Keys.SPACE])
     ///
-    /// # Now fire triggers on Space, Left click, or Middle click
+    /// charge_time = 0.0
+    ///
+    /// while engine.poll_events():
+    ///     dt = engine.delta_time
+    ///
+    ///     # Accumulate charge while held
+    ///     if engine.input.action_down("charge"):
+    ///         charge_time += dt
+    ///         print(f"Charging: {charge_time:.2f}s")
+    ///
+    ///     # Fire when released
+    ///     if engine.input.action_released("charge"):
+    ///         power = min(charge_time * 100, 300)  # Cap at 300
+    ///         print(f"Released! Power: {power:.0f}")
+    ///         charge_time = 0.0
+    ///
+    ///     engine.update()
+    ///     engine.render()
     /// ```
-    fn add_action_mouse_button(&mut self, action_name: &str, button: &str) {
+    ///
+    /// # See Also
+    /// - `examples/python_input_demo.py` - Action system demonstration
+    /// - `action_down()` - Check if action is held
+    /// - `action_pressed()` - Detect action activation
+    /// - `set_action_keys()` - Configure action bindings
+    fn action_released(&self, action_name: &str) -> bool {
+        if let Some(input) = &self.inner.input_manager {
+            input.action_released(action_name)
+        } else {
+            false
+        }
+    }
+
+    /// Check if an action was pressed within the last `window` seconds.
+    ///
+    /// Buffers presses so a jump pressed shortly before landing still
+    /// happens. Includes a press this frame.
+    ///
+    /// # Example
+    /// ```python
+    /// if grounded and engine.input.consume_buffered_action("jump", 0.12):
+    ///     velocity_y = jump_speed
+    /// ```
+    fn action_buffered(&self, action_name: &str, window: f32) -> bool {
+        self.inner
+            .input_manager
+            .as_ref()
+            .is_some_and(|input| input.action_buffered(action_name, window))
+    }
+
+    /// Like `action_buffered()`, but forget the press so it triggers only once.
+    fn consume_buffered_action(&mut self, action_name: &str, window: f32) -> bool {
+        self.inner
+            .input_manager
+            .as_mut()
+            .is_some_and(|input| input.consume_buffered_action(action_name, window))
+    }
+
+    /// Put actions in a named input context such as "gameplay" or "menu".
+    ///
+    /// While a context is pushed, actions that belong to contexts only work
+    /// when one of them is the top context, so the same keys can drive
+    /// different actions in different game states. Actions in no context
+    /// always work.
+    ///
+    /// # Example
+    /// ```python
+    /// engine.input.set_context_actions("gameplay", ["jump", "fire1"])
+    /// engine.input.set_context_actions("menu", ["confirm", "back"])
+    /// engine.input.push_context("gameplay")
+    /// ```
+    fn set_input_context_actions(&mut self, context: &str, actions: Vec<String>) {
         if let Some(input) = &mut self.inner.input_manager {
-            input.add_action_mouse_button(action_name, parse_mouse_button(button));
+            input.set_context_actions(context, actions);
         }
     }
 
-    /// Remove one mouse button from an action.
+    fn get_input_context_actions(&self, context: &str) -> Vec<String> {
+        self.inner
+            .input_manager
+            .as_ref()
+            .map_or_else(Vec::new, |input| input.context_actions(context))
+    }
+
+    /// Make an input context the top one until it is popped. UI elements
+    /// given a context only take input while it is on top.
+    fn input_push_context(&mut self, context: &str) {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.push_input_context(context);
+        }
+    }
+
+    /// Remove the top input context, returning it.
+    fn input_pop_context(&mut self) -> Option<String> {
+        self.inner.input_manager.as_mut().and_then(|input| input.pop_input_context())
+    }
+
+    /// The top input context, or `None` when none is pushed.
+    fn input_context(&self) -> Option<String> {
+        self.inner
+            .input_manager
+            .as_ref()
+            .and_then(|input| input.input_context().map(str::to_string))
+    }
+
+    /// Pushed input contexts, bottom first.
+    fn input_context_stack(&self) -> Vec<String> {
+        self.inner
+            .input_manager
+            .as_ref()
+            .map_or_else(Vec::new, |input| input.input_context_stack().to_vec())
+    }
+
+    /// Set how long action presses are remembered for buffering, in seconds.
+    fn set_input_buffer_duration(&mut self, seconds: f32) {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.set_input_buffer_duration(seconds);
+        }
+    }
+
+    fn get_input_buffer_duration(&self) -> f32 {
+        self.inner
+            .input_manager
+            .as_ref()
+            .map_or(0.0, |input| input.input_buffer_duration())
+    }
+
+    /// List all configured action names.
     ///
-    /// # Arguments
-    /// * `action_name` - Name of the action to modify
-    /// * `button` - Mouse button to remove
+    /// Returns a list of all action names that have been configured, including
+    /// both built-in actions ("jump", "escape") and custom actions.
     ///
     /// # Returns
-    /// `true` if the button was found and removed, `false` otherwise
+    /// Vector of action name strings
     ///
     /// # Example
     /// ```python
@@ -3980,25 +5995,32 @@ impl PyEngine {
     ///
     /// engine = pyg.Engine()
     ///
-    /// # Remove right click from an action
-    /// if engine.input.remove_action_mouse_button("fire", pyg.MouseButton.RIGHT):
-    ///     print("Right click removed from fire action")
+    /// # Create custom actions
+    /// engine.input.set_action_keys("fire", [pyg.Keys.SPACE])
+    /// engine.input.set_action_keys("reload", [pyg.Keys.R])
+    ///
+    /// # List all actions
+    /// actions = engine.input.action_names()
+    /// print(f"Available actions: {actions}")
+    /// # Output: ['jump', 'escape', 'fire', 'reload']
     /// ```
-    fn remove_action_mouse_button(&mut self, action_name: &str, button: &str) -> bool {
-        if let Some(input) = &mut self.inner.input_manager {
-            input.remove_action_mouse_button(action_name, parse_mouse_button(button))
+    fn action_names(&self) -> Vec<String> {
+        if let Some(input) = &self.inner.input_manager {
+            input.action_names()
         } else {
-            false
+            Vec::new()
         }
     }
 
-    /// Clear all bindings for an action.
+    /// Restore default axis/action bindings.
     ///
-    /// Removes all keyboard and mouse button bindings from an action.
-    /// The action still exists but has no triggers.
+    /// Resets all input bindings back to the engine's default configuration:
+    /// - "Horizontal" axis: A/D and Left/Right arrow keys
+    /// - "Vertical" axis: W/S and Up/Down arrow keys
+    /// - "jump" action: Space key
+    /// - "escape" action: Escape key
     ///
-    /// # Arguments
-    /// * `action_name` - Name of the action to clear
+    /// All custom axes and actions are removed.
     ///
     /// # Example
     /// ```python
@@ -4006,3635 +6028,7931 @@ impl PyEngine {
     ///
     /// engine = pyg.Engine()
     ///
-    /// # Clear all jump bindings
-    /// engine.input.clear_action_bindings("jump")
+    /// # Customize bindings
+    /// engine.input.set_axis_keys("Horizontal", [pyg.Keys.L], [pyg.Keys.J])
+    /// engine.input.set_action_keys("fire", [pyg.Keys.SPACE])
     ///
-    /// # Re-bind to different keys
-    /// engine.input.set_action_keys("jump", [pyg.Keys.W, pyg.Keys.UP])
+    /// # Reset to defaults
+    /// engine.input.reset_input_bindings_to_defaults()
+    ///
+    /// # Now back to default: WASD/Arrows for axes, Space for jump
     /// ```
-    fn clear_action_bindings(&mut self, action_name: &str) {
+    fn reset_input_bindings_to_defaults(&mut self) {
         if let Some(input) = &mut self.inner.input_manager {
-            input.clear_action_bindings(action_name);
+            input.reset_input_bindings_to_defaults();
         }
     }
-}
 
-/// A thread-safe handle to the engine that can be passed to background threads.
-///
-/// Use this handle to queue commands like adding objects or drawing from other threads.
-#[pyclass(name = "EngineHandle")]
-#[derive(Clone)]
-pub struct PyEngineHandle {
-    sender: Sender<EngineCommand>,
-}
+    /// Save every axis binding and action mapping to a JSON file.
+    ///
+    /// Keys are stored by their script names ("a", "space", "f5"), so the
+    /// file can also be edited by hand. Mouse buttons other than left, right
+    /// and middle are not saved.
+    ///
+    /// # Arguments
+    /// * `path` - File to write
+    ///
+    /// Raises:
+    ///     RuntimeError: If the file cannot be written.
+    fn save_input_bindings(&self, path: &str) -> PyResult<()> {
+        let Some(input) = &self.inner.input_manager else {
+            return Err(PyRuntimeError::new_err("Input is not available"));
+        };
+        input_bindings::save_bindings(input, path).map_err(PyRuntimeError::new_err)
+    }
 
-#[pymethods]
-impl PyEngineHandle {
-    /// Add a GameObject to the engine command queue.
+    /// Replace every axis binding and action mapping with ones saved by
+    /// `save_input_bindings()`.
     ///
-    /// This is thread-safe and will be processed on the next engine update.
-    fn add_game_object(&self, game_object: &PyGameObject) {
-        let _ = self.sender.send(EngineCommand::AddGameObject(
-            game_object.to_runtime_game_object(),
-        ));
+    /// The file is checked in full first; if it cannot be read or contains
+    /// an unknown key or button, the current bindings are kept.
+    ///
+    /// # Arguments
+    /// * `path` - File to read
+    ///
+    /// Raises:
+    ///     RuntimeError: If the file cannot be read or is not valid bindings.
+    fn load_input_bindings(&mut self, path: &str) -> PyResult<()> {
+        let Some(input) = &mut self.inner.input_manager else {
+            return Err(PyRuntimeError::new_err("Input is not available"));
+        };
+        input_bindings::load_bindings(input, path).map_err(PyRuntimeError::new_err)
     }
 
-    /// Remove a runtime GameObject by id via command queue.
-    fn remove_game_object(&self, object_id: u32) {
-        let _ = self.sender.send(EngineCommand::RemoveGameObject(object_id));
+    /// Get display glyphs for the current bindings of an action.
+    ///
+    /// Each entry is a `(label, icon, device)` tuple, e.g. `("Space", None, "keyboard_mouse")`
+    /// or `("A", "gamepad_south", "gamepad")`. `icon` names a cell in the built-in glyph
+    /// atlas (see `input_glyph_icon()`), or is `None` when only a text label exists.
+    ///
+    /// # Arguments
+    /// * `action_name` - Action to describe
+    /// * `device` - `"keyboard_mouse"` or `"gamepad"`; `None` uses the active prompt device.
+    ///   Falls back to the other device if the action has no bindings on the requested one.
+    ///
+    /// # Example
+    /// ```python
+    /// engine.input.set_action_keys("interact", [pyg.Keys.E])
+    /// for glyph in engine.input.action_glyphs("interact"):
+    ///     print(glyph["text"])  # "[E]"
+    /// ```
+    #[pyo3(signature = (action_name, device=None))]
+    fn action_glyphs(
+        &self,
+        action_name: &str,
+        device: Option<&str>,
+    ) -> Vec<(String, Option<String>, String)> {
+        let Some(input) = &self.inner.input_manager else {
+            return Vec::new();
+        };
+        input
+            .action_glyphs(action_name, device.and_then(InputDevice::parse))
+            .into_iter()
+            .map(|glyph| {
+                (
+                    glyph.label,
+                    glyph.icon.map(|icon| icon.as_str().to_string()),
+                    glyph.device.as_str().to_string(),
+                )
+            })
+            .collect()
     }
 
-    /// Update a runtime GameObject position by id via command queue.
-    fn set_game_object_position(&self, object_id: u32, position: PyVec2) {
-        let _ = self.sender.send(EngineCommand::SetGameObjectPosition {
-            object_id,
-            position: position.inner,
-        });
+    /// Get bracketed prompt text for an action, e.g. `"[Space]"` or `"[E]/[LMB]"`.
+    ///
+    /// Returns an empty string if the action has no bindings.
+    #[pyo3(signature = (action_name, device=None))]
+    fn action_prompt(&self, action_name: &str, device: Option<&str>) -> String {
+        let Some(input) = &self.inner.input_manager else {
+            return String::new();
+        };
+        input.action_prompt(action_name, device.and_then(InputDevice::parse))
     }
 
-    /// Update the active camera world position via command queue.
-    fn set_camera_position(&self, position: PyVec2) {
-        let _ = self.sender.send(EngineCommand::SetCameraPosition {
-            position: position.inner,
-        });
+    /// Get the device prompts currently target (`"keyboard_mouse"` or `"gamepad"`).
+    fn input_glyph_device(&self) -> String {
+        self.inner
+            .input_manager
+            .as_ref()
+            .map(|input| input.glyph_device())
+            .unwrap_or(InputDevice::KeyboardMouse)
+            .as_str()
+            .to_string()
     }
 
-    /// Update the active camera viewport size in world units via command queue.
-    fn set_camera_viewport_size(&self, width: f32, height: f32) {
-        let _ = self
-            .sender
-            .send(EngineCommand::SetCameraViewportSize { width, height });
+    /// Force prompts to a device, or pass `None` to follow the active device.
+    ///
+    /// Returns `false` if `device` is not a recognized device name.
+    #[pyo3(signature = (device=None))]
+    fn set_input_glyph_device(&mut self, device: Option<&str>) -> bool {
+        let parsed = match device {
+            Some(name) => match InputDevice::parse(name) {
+                Some(parsed) => Some(parsed),
+                None => return false,
+            },
+            None => None,
+        };
+        if let Some(input) = &mut self.inner.input_manager {
+            input.set_glyph_device_override(parsed);
+        }
+        true
     }
 
-    /// Update camera aspect handling mode via command queue.
-    fn set_camera_aspect_mode(&self, mode: &str) {
-        if let Some(parsed) = parse_camera_aspect_mode(mode) {
-            let _ = self
-                .sender
-                .send(EngineCommand::SetCameraAspectMode { mode: parsed });
-        }
+    /// Version counter that changes whenever action bindings or the prompt device change.
+    ///
+    /// Cache prompt text and rebuild it only when this value differs from the cached one.
+    fn input_glyph_version(&self) -> u64 {
+        self.inner
+            .input_manager
+            .as_ref()
+            .map_or(0, |input| input.glyph_version())
     }
 
-    /// Update the active camera background clear color via command queue.
-    fn set_camera_background_color(&self, color: &PyColor) {
-        let _ = self
-            .sender
-            .send(EngineCommand::SetCameraBackgroundColor { color: color.inner });
+    /// Get one icon from the built-in glyph atlas as `(width, height, rgba_bytes)`.
+    ///
+    /// Returns `None` for unknown icon names.
+    fn input_glyph_icon(&self, icon: &str) -> Option<(u32, u32, Vec<u8>)> {
+        let icon = GlyphIcon::parse(icon)?;
+        Some((
+            GLYPH_ICON_SIZE,
+            GLYPH_ICON_SIZE,
+            glyph_atlas().icon_rgba(icon),
+        ))
     }
 
-    /// Clear all immediate-mode draw commands via command queue.
-    fn clear_draw_commands(&self) {
-        let _ = self.sender.send(EngineCommand::ClearDrawCommands);
+    /// Get the full built-in glyph atlas as `(width, height, rgba_bytes)`.
+    fn input_glyph_atlas(&self) -> (u32, u32, Vec<u8>) {
+        let atlas = glyph_atlas();
+        (atlas.width, atlas.height, atlas.rgba.clone())
     }
 
-    /// Submit many draw commands via command queue in one call.
-    fn add_draw_commands(&self, py: Python<'_>, commands: Vec<Py<PyDrawCommand>>) {
-        let runtime_commands: Vec<DrawCommand> = commands
-            .into_iter()
-            .map(|command| command.borrow(py).inner.clone())
-            .collect();
-        let _ = self
-            .sender
-            .send(EngineCommand::AddDrawCommands(runtime_commands));
+    /// Get the `(x, y, width, height)` pixel rectangle of an icon in the glyph atlas.
+    fn input_glyph_icon_rect(&self, icon: &str) -> Option<(u32, u32, u32, u32)> {
+        GlyphIcon::parse(icon).map(GlyphIcon::atlas_rect)
     }
 
-    /// Draw a pixel at window coordinates via command queue.
-    #[pyo3(signature = (x, y, color, draw_order=0.0))]
-    fn draw_pixel(&self, x: u32, y: u32, color: &PyColor, draw_order: f32) {
-        let _ = self.sender.send(EngineCommand::DrawPixel {
-            x,
-            y,
-            color: color.inner,
-            draw_order,
-        });
+    /// Get the device the player used most recently (`"keyboard_mouse"` or `"gamepad"`).
+    fn input_active_device(&self) -> String {
+        self.inner
+            .input_manager
+            .as_ref()
+            .map(|input| input.active_device())
+            .unwrap_or(InputDevice::KeyboardMouse)
+            .as_str()
+            .to_string()
     }
 
-    /// Draw a line at window coordinates via command queue.
-    #[pyo3(signature = (start_x, start_y, end_x, end_y, color, thickness=1.0, draw_order=0.0))]
-    fn draw_line(
-        &self,
-        start_x: f32,
-        start_y: f32,
-        end_x: f32,
-        end_y: f32,
-        color: &PyColor,
-        thickness: f32,
-        draw_order: f32,
-    ) {
-        let _ = self.sender.send(EngineCommand::DrawLine {
-            start_x,
-            start_y,
-            end_x,
-            end_y,
-            thickness,
-            color: color.inner,
-            draw_order,
-        });
+    /// Check whether the active input device switched this frame.
+    ///
+    /// # Example
+    /// ```python
+    /// def update(ctx):
+    ///     if ctx.input.device_changed:
+    ///         prompt.text = ctx.input.action_prompt("jump", "Press {glyph}")
+    /// ```
+    fn input_active_device_changed(&self) -> bool {
+        self.inner
+            .input_manager
+            .as_ref()
+            .is_some_and(|input| input.active_device_changed())
     }
 
-    /// Draw a rectangle at window coordinates via command queue.
-    #[pyo3(signature = (x, y, width, height, color, filled=true, thickness=1.0, draw_order=0.0))]
-    fn draw_rectangle(
-        &self,
-        x: f32,
-        y: f32,
-        width: f32,
-        height: f32,
-        color: &PyColor,
-        filled: bool,
-        thickness: f32,
-        draw_order: f32,
-    ) {
-        let _ = self.sender.send(EngineCommand::DrawRectangle {
-            x,
-            y,
-            width,
-            height,
-            color: color.inner,
-            filled,
-            thickness,
-            draw_order,
-        });
+    /// Report activity on a device, e.g. from a gamepad library polled in Python.
+    ///
+    /// The switch is applied on the next frame. Returns `false` if `device` is
+    /// not a recognized device name.
+    fn report_input_device_activity(&mut self, device: &str) -> bool {
+        let Some(device) = InputDevice::parse(device) else {
+            return false;
+        };
+        if let Some(input) = &mut self.inner.input_manager {
+            input.report_device_activity(device);
+        }
+        true
     }
 
-    /// Draw a circle at window coordinates via command queue.
-    #[pyo3(signature = (
-        center_x,
-        center_y,
-        radius,
-        color,
-        filled=true,
-        thickness=1.0,
-        segments=32,
-        draw_order=0.0
-    ))]
-    fn draw_circle(
-        &self,
-        center_x: f32,
-        center_y: f32,
-        radius: f32,
-        color: &PyColor,
-        filled: bool,
-        thickness: f32,
-        segments: u32,
-        draw_order: f32,
-    ) {
-        let _ = self.sender.send(EngineCommand::DrawCircle {
-            center_x,
-            center_y,
-            radius,
-            color: color.inner,
-            filled,
-            thickness,
-            segments,
-            draw_order,
-        });
+    /// Release all held keys, mouse buttons and gamepad buttons and zero all axes.
+    ///
+    /// Release events are emitted for anything that was held, so
+    /// `key_released()` and friends report a normal release.
+    ///
+    /// # Example
+    /// ```python
+    /// def on_pause():
+    ///     engine.input.reset()  # nothing stays held while the menu is open
+    /// ```
+    fn input_reset(&mut self) {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.reset();
+        }
     }
 
-    /// Draw a gradient rectangle with per-corner colors via command queue.
-    #[pyo3(signature = (
-        x,
-        y,
-        width,
-        height,
-        top_left,
-        bottom_left,
-        bottom_right,
-        top_right,
-        draw_order=0.0
-    ))]
-    fn draw_gradient_rect(
-        &self,
-        x: f32,
-        y: f32,
-        width: f32,
-        height: f32,
-        top_left: &PyColor,
-        bottom_left: &PyColor,
-        bottom_right: &PyColor,
-        top_right: &PyColor,
-        draw_order: f32,
-    ) {
-        let _ = self.sender.send(EngineCommand::DrawGradientRect {
-            x,
-            y,
-            width,
-            height,
-            top_left: top_left.inner,
-            bottom_left: bottom_left.inner,
-            bottom_right: bottom_right.inner,
-            top_right: top_right.inner,
-            draw_order,
-        });
+    /// Show or hide the input debug overlay.
+    ///
+    /// The overlay lists live axis values, held keys and buttons, the active
+    /// device, player slot devices and recent action presses and releases.
+    fn set_input_debug_overlay(&mut self, enabled: bool) {
+        self.inner.set_input_debug_overlay(enabled);
     }
 
-    /// Draw an image from a filesystem path via command queue.
-    #[pyo3(signature = (x, y, width, height, texture_path, draw_order=0.0))]
-    fn draw_image(
-        &self,
-        x: f32,
-        y: f32,
-        width: f32,
-        height: f32,
-        texture_path: String,
-        draw_order: f32,
-    ) {
-        let _ = self.sender.send(EngineCommand::DrawImage {
-            x,
-            y,
-            width,
-            height,
-            texture_path,
-            draw_order,
-        });
+    /// Get whether the input debug overlay is shown.
+    fn get_input_debug_overlay(&self) -> bool {
+        self.inner.input_debug_overlay()
     }
 
-    /// Draw an image from raw RGBA bytes via command queue.
-    #[pyo3(signature = (
-        x,
-        y,
-        width,
-        height,
-        texture_key,
-        rgba,
-        texture_width,
-        texture_height,
-        draw_order=0.0
-    ))]
-    fn draw_image_from_bytes(
-        &self,
-        x: f32,
-        y: f32,
-        width: f32,
-        height: f32,
-        texture_key: String,
-        rgba: Vec<u8>,
-        texture_width: u32,
-        texture_height: u32,
-        draw_order: f32,
-    ) {
-        let _ = self.sender.send(EngineCommand::DrawImageBytes {
-            x,
-            y,
-            width,
-            height,
-            texture_key,
-            rgba: Arc::from(rgba),
-            texture_width,
-            texture_height,
-            draw_order,
-        });
+    /// Set a key (e.g. "F3") that shows or hides the input debug overlay, or None for no hotkey.
+    #[pyo3(signature = (key=None))]
+    fn set_input_debug_overlay_toggle_key(&mut self, key: Option<&str>) {
+        self.inner
+            .set_input_debug_overlay_toggle_key(key.map(parse_key));
     }
 
-    /// Draw text in window coordinates via command queue.
-    #[pyo3(signature = (
-        text,
-        x,
-        y,
-        color,
-        font_size=24.0,
-        font_path=None,
-        font_family=None,
-        font_weight=None,
-        font_style=None,
-        letter_spacing=0.0,
-        line_spacing=0.0,
-        kerning=true,
-        draw_order=0.0
-    ))]
-    fn draw_text(
-        &self,
-        text: String,
-        x: f32,
-        y: f32,
-        color: &PyColor,
-        font_size: f32,
-        font_path: Option<String>,
-        font_family: Option<String>,
-        font_weight: Option<&str>,
-        font_style: Option<&str>,
-        letter_spacing: f32,
-        line_spacing: f32,
-        kerning: bool,
-        draw_order: f32,
-    ) -> PyResult<()> {
-        let style = build_text_style(
-            font_size,
-            font_path,
-            font_family,
-            font_weight,
-            font_style,
-            letter_spacing,
-            line_spacing,
-            kerning,
-        )?;
-        let _ = self.sender.send(EngineCommand::DrawText {
-            text,
-            x,
-            y,
-            style,
-            color: color.inner,
-            layout: TextLayoutOptions::default(),
-            draw_order,
-        });
-        Ok(())
+    /// Get the label of the input debug overlay hotkey, or None.
+    fn get_input_debug_overlay_toggle_key(&self) -> Option<String> {
+        self.inner.input_debug_overlay_toggle_key().map(key_label)
     }
 
-    #[pyo3(signature = (
-        family,
-        regular=None,
-        bold=None,
-        italic=None,
-        bold_italic=None
-    ))]
-    fn register_font_family(
-        &self,
-        family: String,
-        regular: Option<String>,
-        bold: Option<String>,
-        italic: Option<String>,
-        bold_italic: Option<String>,
-    ) {
-        let _ = self.sender.send(EngineCommand::RegisterFontFamily {
-            family,
-            definition: FontFamilyDefinition::new(regular, bold, italic, bold_italic),
-        });
+    /// Get a player's device as `(kind, joystick_id)`, or `None` for an empty slot.
+    ///
+    /// `kind` is `"keyboard_mouse"`, `"keyboard_left"`, `"keyboard_right"` or
+    /// `"gamepad"`; `joystick_id` is only set for gamepads.
+    fn input_player_device(&self, player: u8) -> Option<(String, Option<u32>)> {
+        let device = self.inner.input_manager.as_ref()?.player_device(player)?;
+        Some((device.as_str().to_string(), device.joystick_id()))
     }
 
-    /// Update a UI label's text at runtime by object ID via command queue.
-    fn update_ui_label_text(&self, object_id: u32, text: String) {
-        let _ = self
-            .sender
-            .send(EngineCommand::UpdateUILabelText { object_id, text });
+    /// Assign a device to a player (1-4), or empty the slot when `device` is `None`.
+    ///
+    /// `"gamepad"` requires `joystick_id`. The device is taken away from any
+    /// other player that had it.
+    ///
+    /// # Example
+    /// ```python
+    /// engine.input.assign_player(1, "keyboard_left")
+    /// engine.input.assign_player(2, "keyboard_right")
+    /// ```
+    #[pyo3(signature = (player, device=None, joystick_id=None))]
+    fn input_assign_player(
+        &mut self,
+        player: u8,
+        device: Option<&str>,
+        joystick_id: Option<u32>,
+    ) -> PyResult<bool> {
+        let device = match device {
+            Some(name) => Some(PlayerDevice::parse(name, joystick_id).ok_or_else(|| {
+                PyRuntimeError::new_err(format!(
+                    "Invalid player device '{name}'. Use 'keyboard_mouse', 'keyboard_left', 'keyboard_right', or 'gamepad' with a joystick_id."
+                ))
+            })?),
+            None => None,
+        };
+        Ok(self
+            .inner
+            .input_manager
+            .as_mut()
+            .is_some_and(|input| input.assign_player(player, device)))
     }
 
-    /// Update a UI button's text at runtime by object ID via command queue.
-    fn update_ui_button_text(&self, object_id: u32, text: String) {
-        let _ = self
-            .sender
-            .send(EngineCommand::UpdateUIButtonText { object_id, text });
+    /// Get the player (1-4) that owns a joystick, if any.
+    fn input_player_for_joystick(&self, joystick_id: u32) -> Option<u8> {
+        self.inner.input_manager.as_ref()?.player_for_joystick(joystick_id)
     }
 
-    /// Log a message at INFO level (default log method).
-    fn log(&self, message: &str) {
-        let _ = self.sender.send(EngineCommand::LogInfo(message.to_string()));
+    /// Input events collected for the current frame, in arrival order.
+    fn input_events(&self) -> Vec<PyInputEvent> {
+        self.inner.input_manager.as_ref().map_or_else(Vec::new, |input| {
+            input
+                .frame_events()
+                .iter()
+                .map(|event| PyInputEvent::from_event(event, input))
+                .collect()
+        })
     }
 
-    /// Log a message at TRACE level (most verbose).
-    fn log_trace(&self, message: &str) {
-        let _ = self.sender.send(EngineCommand::LogTrace(message.to_string()));
+    /// Press or release a key through the same pipeline as the keyboard.
+    fn input_inject_key(&mut self, key_name: &str, pressed: bool) {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.inject_key(parse_key(key_name), pressed);
+        }
     }
 
-    /// Log a message at DEBUG level.
-    fn log_debug(&self, message: &str) {
-        let _ = self.sender.send(EngineCommand::LogDebug(message.to_string()));
+    /// Deliver typed text as if it came from the keyboard.
+    fn input_inject_text(&mut self, text: &str) {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.inject_text(text);
+        }
     }
 
-    /// Log a message at INFO level.
-    fn log_info(&self, message: &str) {
-        let _ = self.sender.send(EngineCommand::LogInfo(message.to_string()));
+    /// Press or release a mouse button.
+    fn input_inject_mouse_button(&mut self, button: &str, pressed: bool) {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.inject_mouse_button(parse_mouse_button(button), pressed);
+        }
     }
 
-    /// Log a message at WARN level.
-    fn log_warn(&self, message: &str) {
-        let _ = self.sender.send(EngineCommand::LogWarn(message.to_string()));
+    /// Move the mouse cursor to a window position in pixels.
+    fn input_inject_mouse_move(&mut self, x: f64, y: f64) {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.inject_mouse_move(x, y);
+        }
     }
 
-    /// Log a message at ERROR level.
-    fn log_error(&self, message: &str) {
-        let _ = self.sender.send(EngineCommand::LogError(message.to_string()));
+    /// Scroll the mouse wheel.
+    fn input_inject_mouse_wheel(&mut self, delta_x: f64, delta_y: f64) {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.inject_mouse_wheel(delta_x, delta_y);
+        }
     }
-}
 
-/// Python wrapper for Time.
-/// Frame timing information.
-///
-/// Tracks delta time and elapsed time for implementing frame-independent movement,
-/// animations, and time-based game logic.
-///
-/// # Time Units: Seconds
-/// **IMPORTANT:** All time values are in **seconds** (not milliseconds).
-///
-/// # Attributes
-/// - `delta_time` - Time since last frame in **seconds** (float)
-/// - `elapsed_time` - Total time since engine start in **seconds** (float)
-///
-/// # Delta Time
-/// Delta time (dt) represents the duration of the last frame. Use it to make movement
-/// and animations frame-rate independent:
-///
-/// ```python
-/// # Frame-independent movement
-/// speed = 100.0  # pixels per second
-/// dt = engine.delta_time
-/// position += speed * dt  # Moves 100 pixels/sec regardless of FPS
-/// ```
-///
-/// **Typical delta_time values:**
-/// - 60 FPS: ~0.016 seconds (16ms)
-/// - 30 FPS: ~0.033 seconds (33ms)
-/// - 120 FPS: ~0.008 seconds (8ms)
-///
-/// # Elapsed Time
-/// Elapsed time tracks total time since engine startup, useful for time-based effects:
-///
-/// ```python
-/// # Pulsing animation
-/// import math
-/// elapsed = engine.elapsed_time
-/// scale = 1.0 + 0.2 * math.sin(elapsed * 2.0)  # Pulse every ~3 seconds
-/// ```
-///
-/// # Example
-/// ```python
-/// import pyg_engine as pyg
-///
-/// engine = pyg.Engine()
-/// engine.start_manual()
-///
-/// player_x = 400.0
-/// player_speed = 200.0  # pixels per second
-///
-/// while engine.poll_events():
-///     # Get delta time (in seconds)
-///     dt = engine.delta_time
-///
-///     # Frame-independent movement
-///     if engine.input.key_down(pyg.Keys.D):
-///         player_x += player_speed * dt  # Move 200 pixels/sec
-///
-///     # Show FPS based on delta time
-///     fps = 1.0 / dt if dt > 0 else 0
-///     print(f"FPS: {fps:.1f}, Delta: {dt:.4f}s")
-///
-///     engine.update()
-///     engine.render()
-/// ```
-///
-/// # Frame-Rate Independence
-/// Always multiply velocities by `delta_time` to ensure consistent behavior
-/// across different frame rates:
-///
-/// ```python
-/// # ❌ WRONG - Speed depends on FPS
-/// position += 5.0  # Moves 5 pixels PER FRAME (fast at high FPS, slow at low FPS)
-///
-/// # ✅ CORRECT - Speed independent of FPS
-/// speed = 300.0  # pixels per second
-/// position += speed * engine.delta_time  # Always 300 pixels/sec
-/// ```
-///
-/// # See Also
-/// - `examples/python_input_demo.py` - Delta time usage for movement
-/// - `examples/python_camera_worldspace_demo.py` - Frame-independent camera control
-#[pyclass(name = "Time")]
-pub struct PyTime {
-    inner: RustTime,
-}
+    /// Press or release a gamepad button, connecting the gamepad if needed.
+    fn input_inject_joystick_button(&mut self, joystick_id: u32, button_id: u8, pressed: bool) {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.handle_joystick_button(joystick_id, button_id, pressed);
+        }
+    }
 
-#[pymethods]
-impl PyTime {
-    #[new]
-    fn new() -> Self {
-        Self {
-            inner: RustTime::new(),
+    /// Move a gamepad axis, connecting the gamepad if needed.
+    fn input_inject_joystick_axis(&mut self, joystick_id: u32, axis_id: u8, value: f32) {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.handle_joystick_axis(joystick_id, axis_id, value);
         }
     }
 
-    fn tick(&mut self) {
-        self.inner.tick();
+    /// Text typed during the last frame, including input method commits.
+    fn input_typed_text(&self) -> String {
+        self.inner
+            .input_manager
+            .as_ref()
+            .map(|input| input.typed_text().to_string())
+            .unwrap_or_default()
     }
 
-    /// Time since last frame in **seconds**.
-    ///
-    /// Use for frame-independent movement and animations.
-    /// Typical values: 0.016 (60 FPS), 0.033 (30 FPS), 0.008 (120 FPS)
-    ///
-    /// # Example
-    /// ```python
-    /// dt = engine.delta_time
-    /// player_x += speed * dt  # pixels per second
-    /// ```
-    #[getter]
-    fn delta_time(&self) -> f32 {
-        self.inner.delta_time()
+    /// Text the input method is composing but has not committed.
+    fn input_composition(&self) -> String {
+        self.inner
+            .input_manager
+            .as_ref()
+            .map(|input| input.composition().to_string())
+            .unwrap_or_default()
     }
 
-    /// Total time since engine start in **seconds**.
-    ///
-    /// Useful for time-based effects like animations, timers, and periodic events.
-    ///
-    /// # Example
-    /// ```python
-    /// import math
-    /// t = engine.elapsed_time
-    /// wave = math.sin(t * 2.0)  # Oscillates every ~3 seconds
-    /// ```
-    #[getter]
-    fn elapsed_time(&self) -> f32 {
-        self.inner.elapsed_time()
+    /// Character range of the input method's cursor in the composition, or `None`.
+    fn input_composition_cursor(&self) -> Option<(usize, usize)> {
+        self.inner.input_manager.as_ref()?.composition_cursor()
     }
-}
 
-// ========== GameObject Bindings ==========
+    /// Ids of the connected joysticks, sorted.
+    fn input_joysticks(&self) -> Vec<u32> {
+        self.inner
+            .input_manager
+            .as_ref()
+            .map(|input| input.connected_joysticks())
+            .unwrap_or_default()
+    }
 
-/// Container for game entities with transform, rendering, and behavior.
-///
-/// `GameObject` is the fundamental building block of your game. Each GameObject has:
-/// - **Transform**: Position, rotation, scale
-/// - **Components**: Rendering, collision, and UI behavior attached through `add_component(...)`
-/// - **State**: Enabled/disabled, name, unique ID
-/// - **Hierarchy**: Optional parent/child relationships with inherited transforms
-///
-/// # Creation and Setup
-///
-/// ```python
-/// from pyg_engine import GameObject, MeshComponent, Vec2, Color
-///
-/// # Create a named GameObject
-/// player = GameObject("Player")
-/// player.position = Vec2(0, 0) # Note that we're using *World Position* not pixel location
-/// player.rotation = math.radians(45)  # Rotation in radians
-/// player.scale = Vec2(1.0, 1.0)
-///
-/// # Add mesh rendering
-/// mesh = MeshComponent("Rectangle")
-/// mesh.set_geometry_rectangle(1.0, 1.0)
-/// mesh.set_fill_color(Color.BLUE)
-/// player.add_component(mesh)
-///
-/// # Add to engine
-/// engine.add_game_object(player)
-/// ```
-///
-/// # Transform Properties
-///
-/// GameObjects use a 2D transform with position, rotation, and scale:
-/// - **position**: `Vec2` local to the parent (world-space when unparented)
-/// - **rotation**: Angle in **radians** (counter-clockwise)
-/// - **scale**: `Vec2` multiplier (1.0 = original size)
-///
-/// In Python, the direct convenience accessors are `obj.position`, `obj.rotation`,
-/// and `obj.scale`. `TransformComponent` is still the underlying runtime component type.
-///
-/// # Components
-///
-/// Attach components to add functionality:
-/// - `MeshComponent` - 2D rendering (rectangles, circles, images)
-/// - `Collider` - Collision and trigger callbacks
-/// - `ButtonComponent` - Clickable UI button
-/// - `PanelComponent` - UI container panel
-/// - `LabelComponent` - UI text label
-///
-/// Parent transforms propagate to children in world rendering and physics.
-///
-/// # Enabled State
-///
-/// GameObjects can be enabled/disabled:
-/// ```python
-/// player.enabled = False  # Disable object, children, and components
-/// player.enabled = True
-/// ```
-///
-/// # Object Types
-///
-/// GameObjects can be marked as `"UIObject"` for UI rendering:
-/// ```python
-/// obj.set_object_type("UIObject")  # Render as UI (screen space)
-/// ```
-///
-/// # See Also
-/// - `examples/python_game_object_transform_demo.py` - Transform operations
-/// - `examples/python_mesh_demo.py` - Mesh rendering
-/// - `examples/ui_demo.py` - UI components
-#[pyclass(name = "GameObject", unsendable)]
-pub struct PyGameObject {
-    inner: RustGameObject,
-    runtime_binding: RefCell<Option<RuntimeBinding>>,
-}
+    /// Ids of the joysticks connected (`True`) or disconnected (`False`) this frame.
+    fn input_joystick_connection_changes(&self, connected: bool) -> Vec<u32> {
+        self.inner
+            .input_manager
+            .as_ref()
+            .map(|input| input.joystick_connection_changes(connected))
+            .unwrap_or_default()
+    }
 
-#[derive(Clone)]
-struct RuntimeBinding {
-    sender: Sender<EngineCommand>,
-    object_id: u32,
-    objects: Arc<RwLock<ObjectManager>>,
-}
+    /// Name the controller reports, if the gamepad backend knows it.
+    fn input_joystick_name(&self, joystick_id: u32) -> Option<String> {
+        self.inner.gamepads().joystick_name(joystick_id).map(str::to_string)
+    }
 
-impl PyGameObject {
-    fn ensure_mesh_component(&mut self) -> &mut MeshComponent {
-        if self.inner.mesh_component().is_none() {
-            self.inner
-                .add_mesh_component(MeshComponent::new("Mesh Renderer".to_string()));
-        }
+    /// Whether the engine reads game controllers itself (built with the
+    /// `gamepad` feature and the platform API opened).
+    fn input_gamepads_available(&self) -> bool {
+        self.inner.gamepads().is_available()
+    }
+
+    fn input_joystick_button_down(&self, joystick_id: u32, button_id: u8) -> bool {
         self.inner
-            .mesh_component_mut()
-            .expect("mesh component should exist")
+            .input_manager
+            .as_ref()
+            .is_some_and(|input| input.joystick_button_down(joystick_id, button_id))
     }
 
-    fn sync_runtime_mesh_component(&self) {
-        let current_mesh = self.current_object().mesh_component().cloned();
-        if let Some(binding) = self.runtime_binding.borrow().as_ref()
-            && let Some(mesh) = current_mesh
-        {
-            let _ = binding.sender.send(EngineCommand::SetGameObjectMeshComponent {
-                object_id: binding.object_id,
-                mesh,
-            });
-        }
+    fn input_joystick_button_pressed(&self, joystick_id: u32, button_id: u8) -> bool {
+        self.inner
+            .input_manager
+            .as_ref()
+            .is_some_and(|input| input.joystick_button_pressed(joystick_id, button_id))
     }
 
-    fn to_runtime_game_object(&self) -> RustGameObject {
-        self.inner.clone()
+    fn input_joystick_button_released(&self, joystick_id: u32, button_id: u8) -> bool {
+        self.inner
+            .input_manager
+            .as_ref()
+            .is_some_and(|input| input.joystick_button_released(joystick_id, button_id))
     }
 
-    fn bind_runtime(
-        &self,
-        sender: Sender<EngineCommand>,
-        object_id: u32,
-        objects: Arc<RwLock<ObjectManager>>,
-    ) {
-        self.runtime_binding
-            .replace(Some(RuntimeBinding {
-                sender,
-                object_id,
-                objects,
-            }));
+    fn input_joystick_axis(&self, joystick_id: u32, axis_id: u8) -> f32 {
+        self.inner
+            .input_manager
+            .as_ref()
+            .map_or(0.0, |input| input.joystick_axis(joystick_id, axis_id))
     }
 
-    fn current_object(&self) -> RustGameObject {
-        self.runtime_binding
-            .borrow()
+    /// Choose whether gamepads are assigned to a player on their first
+    /// button press instead of when they connect.
+    fn set_input_assign_gamepads_on_press(&mut self, enabled: bool) {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.set_assign_gamepads_on_press(enabled);
+        }
+    }
+
+    fn get_input_assign_gamepads_on_press(&self) -> bool {
+        self.inner
+            .input_manager
             .as_ref()
-            .and_then(|binding| binding.objects.read().ok()?.get_object_clone(binding.object_id))
-            .unwrap_or_else(|| self.inner.clone())
+            .is_some_and(|input| input.assign_gamepads_on_press())
     }
 
-    fn refresh_from_runtime(&mut self) {
-        self.inner = self.current_object();
+    /// How far a gamepad trigger (`"left"` or `"right"`) is pulled, from 0.0
+    /// to 1.0, after its deadzone.
+    fn input_joystick_trigger(&self, joystick_id: u32, trigger: &str) -> PyResult<f32> {
+        let trigger = parse_trigger(trigger)?;
+        Ok(self
+            .inner
+            .input_manager
+            .as_ref()
+            .map_or(0.0, |input| input.joystick_trigger(joystick_id, trigger)))
     }
 
-    fn from_runtime(
-        object: RustGameObject,
-        sender: Sender<EngineCommand>,
-        objects: Arc<RwLock<ObjectManager>>,
-    ) -> Self {
-        let object_id = object.get_id();
-        Self {
-            inner: object,
-            runtime_binding: RefCell::new(Some(RuntimeBinding {
-                sender,
-                object_id,
-                objects,
-            })),
+    /// Set a trigger's deadzone (0.1 by default).
+    fn set_input_trigger_deadzone(&mut self, trigger: &str, deadzone: f32) -> PyResult<()> {
+        let trigger = parse_trigger(trigger)?;
+        if let Some(input) = &mut self.inner.input_manager {
+            input.set_trigger_deadzone(trigger, deadzone);
         }
+        Ok(())
     }
 
-    fn component_binding(&self, component: &dyn ComponentTrait) -> Option<ComponentRuntimeBinding> {
-        self.runtime_binding
-            .borrow()
+    fn get_input_trigger_deadzone(&self, trigger: &str) -> PyResult<f32> {
+        let trigger = parse_trigger(trigger)?;
+        Ok(self
+            .inner
+            .input_manager
             .as_ref()
-            .map(|binding| ComponentRuntimeBinding {
-                sender: binding.sender.clone(),
-                object_id: binding.object_id,
-                component_id: component.id(),
-            })
+            .map_or(0.0, |input| input.trigger_deadzone(trigger)))
     }
-}
 
-#[pymethods]
-impl PyGameObject {
-    #[new]
-    #[pyo3(signature = (name=None))]
-    fn new(name: Option<String>) -> Self {
-        let inner = if let Some(n) = name {
-            RustGameObject::new_named(n)
-        } else {
-            RustGameObject::new()
-        };
-        Self {
-            inner,
-            runtime_binding: RefCell::new(None),
+    /// Choose whether newly connected gamepads fill the first empty player slot.
+    fn set_input_auto_assign_gamepads(&mut self, enabled: bool) {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.set_auto_assign_gamepads(enabled);
         }
     }
 
-    /// Get the unique ID of this GameObject.
+    fn get_input_auto_assign_gamepads(&self) -> bool {
+        self.inner
+            .input_manager
+            .as_ref()
+            .is_some_and(|input| input.auto_assign_gamepads())
+    }
+
+    /// Get a logical axis for one player, reading only that player's device.
+    fn input_player_axis(&self, player: u8, name: &str) -> f32 {
+        self.inner
+            .input_manager
+            .as_ref()
+            .map_or(0.0, |input| input.player_axis(player, name))
+    }
+
+    /// Get a logical axis for one player without smoothing.
+    fn input_player_axis_raw(&self, player: u8, name: &str) -> f32 {
+        self.inner
+            .input_manager
+            .as_ref()
+            .map_or(0.0, |input| input.player_axis_raw(player, name))
+    }
+
+    /// How far a trigger is pulled on one player's gamepad, from 0.0 to 1.0.
+    fn input_player_trigger(&self, player: u8, trigger: &str) -> PyResult<f32> {
+        let trigger = parse_trigger(trigger)?;
+        Ok(self
+            .inner
+            .input_manager
+            .as_ref()
+            .map_or(0.0, |input| input.player_trigger(player, trigger)))
+    }
+
+    /// Check if an action is held on one player's device.
+    fn input_player_action_down(&self, player: u8, action_name: &str) -> bool {
+        self.inner
+            .input_manager
+            .as_ref()
+            .is_some_and(|input| input.player_action_down(player, action_name))
+    }
+
+    /// Check if an action was pressed this frame on one player's device.
+    fn input_player_action_pressed(&self, player: u8, action_name: &str) -> bool {
+        self.inner
+            .input_manager
+            .as_ref()
+            .is_some_and(|input| input.player_action_pressed(player, action_name))
+    }
+
+    /// Check if an action was released this frame on one player's device.
+    fn input_player_action_released(&self, player: u8, action_name: &str) -> bool {
+        self.inner
+            .input_manager
+            .as_ref()
+            .is_some_and(|input| input.player_action_released(player, action_name))
+    }
+
+    /// Configure keyboard keys for a logical axis.
     ///
-    /// Each GameObject is assigned a unique ID when created. This ID is used internally
-    /// by the engine for tracking objects and can be useful for debugging or looking up
-    /// objects.
+    /// Creates or updates a named axis that responds to keyboard input. Multiple keys
+    /// can be bound to each direction, and pressing any of them will activate the axis.
+    /// If both positive and negative keys are pressed simultaneously, they cancel out to 0.
     ///
-    /// # Returns
-    /// A unique unsigned 32-bit integer ID.
+    /// # Arguments
+    /// * `name` - Axis name (case-sensitive). Will create new axis or overwrite existing.
+    /// * `positive_keys` - List of keys that produce positive values (+1.0).
+    ///   Can use `Keys` constants or strings (e.g., `[Keys.D, Keys.RIGHT]` or `["d", "right"]`)
+    /// * `negative_keys` - List of keys that produce negative values (-1.0).
+    ///   Can use `Keys` constants or strings (e.g., `[Keys.A, Keys.LEFT]` or `["a", "left"]`)
+    /// * `sensitivity` - Multiplier for axis value (default: 1.0). Use values < 1.0 to reduce
+    ///   responsiveness or > 1.0 to amplify (though axis is typically clamped to [-1.0, 1.0])
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
-    /// obj1 = pyg.GameObject("Player")
-    /// obj2 = pyg.GameObject("Enemy")
+    /// engine = pyg.Engine()
     ///
-    /// print(f"Player ID: {obj1.id}")  # e.g., "Player ID: 1"
-    /// print(f"Enemy ID: {obj2.id}")   # e.g., "Enemy ID: 2"
+    /// # Create custom horizontal axis with different keys
+    /// engine.input.set_axis_keys(
+    ///     "Strafe",
+    ///     positive_keys=[pyg.Keys.E, pyg.Keys.RIGHT],  # Move right
+    ///     negative_keys=[pyg.Keys.Q, pyg.Keys.LEFT],   # Move left
+    ///     sensitivity=1.0
+    /// )
     ///
-    /// # IDs are unique
-    /// assert obj1.id != obj2.id
-    /// ```
+    /// # Create zoom axis
+    /// engine.input.set_axis_keys(
+    ///     "CameraZoom",
+    ///     positive_keys=[pyg.Keys.EQUAL, pyg.Keys.PAGEUP],      # Zoom in
+    ///     negative_keys=[pyg.Keys.MINUS, pyg.Keys.PAGEDOWN],    # Zoom out
+    ///     sensitivity=1.0
+    /// )
     ///
-    /// # Note
-    /// IDs are assigned sequentially and persist for the lifetime of the object.
-    /// Once an object is destroyed, its ID may be reused for new objects.
-    #[getter]
-    fn id(&self) -> u32 {
-        self.current_object().get_id()
-    }
-
-    /// Get the name of this GameObject.
+    /// engine.start_manual(title="Custom Axes Demo")
     ///
-    /// Returns the human-readable name assigned to this object, or `None` if no name was set.
-    /// Names are useful for debugging, logging, and identifying objects in your game.
+    /// while engine.poll_events():
+    ///     # Read custom axes
+    ///     strafe = engine.input.axis("Strafe")
+    ///     zoom = engine.input.axis("CameraZoom")
     ///
-    /// # Returns
-    /// - `Some(String)` - The object's name if one was set
-    /// - `None` - If no name was assigned
+    ///     print(f"Strafe: {strafe:.2f}, Zoom: {zoom:.2f}")
+    ///
+    ///     engine.update()
+    ///     engine.render()
+    /// ```
+    ///
+    /// # Modifying Built-in Axes
+    /// You can reconfigure the default "Horizontal" and "Vertical" axes:
+    /// ```python
+    /// # Change Horizontal to use J/L keys instead of A/D
+    /// engine.input.set_axis_keys(
+    ///     "Horizontal",
+    ///     positive_keys=[pyg.Keys.L],
+    ///     negative_keys=[pyg.Keys.J],
+    ///     sensitivity=1.0
+    /// )
+    /// ```
+    ///
+    /// # See Also
+    /// - `examples/python_camera_worldspace_demo.py` - Custom axis configuration
+    /// - `axis()` - Read axis value
+    /// - `set_axis_mouse()` - Bind axes to mouse movement
+    /// - `add_axis_positive_key()` / `add_axis_negative_key()` - Add keys incrementally
+    /// - `remove_axis()` - Delete an axis
+    #[pyo3(signature = (name, positive_keys, negative_keys, sensitivity=1.0))]
+    fn set_axis_keys(
+        &mut self,
+        name: &str,
+        positive_keys: Vec<String>,
+        negative_keys: Vec<String>,
+        sensitivity: f32,
+    ) {
+        if let Some(input) = &mut self.inner.input_manager {
+            let positive = positive_keys.iter().map(|k| parse_key(k)).collect();
+            let negative = negative_keys.iter().map(|k| parse_key(k)).collect();
+            input.set_axis_keyboard_keys(name, positive, negative, sensitivity);
+        }
+    }
+
+    /// Add one positive key to an axis binding.
+    ///
+    /// Incrementally adds a key that produces positive axis values (+1.0)
+    /// without removing existing keys.
+    ///
+    /// # Arguments
+    /// * `axis_name` - Name of the axis to modify
+    /// * `key_name` - Key to add (e.g., `"d"`, `Keys.D`)
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
-    /// # Create named object
-    /// player = pyg.GameObject("Player")
-    /// print(player.name)  # "Player"
+    /// engine = pyg.Engine()
     ///
-    /// # Create unnamed object
-    /// obj = pyg.GameObject()
-    /// print(obj.name)  # None
+    /// # Add extra positive keys to Horizontal axis
+    /// engine.input.add_axis_positive_key("Horizontal", pyg.Keys.L)
+    /// engine.input.add_axis_positive_key("Horizontal", pyg.Keys.NUMPAD6)
     ///
-    /// # Change name later
-    /// obj.set_name("Bullet")
-    /// print(obj.name)  # "Bullet"
+    /// # Now Horizontal axis responds to: D, Right, L, Numpad6
     /// ```
-    ///
-    /// # See Also
-    /// - `set_name()` - Change the object's name
-    #[getter]
-    fn name(&self) -> Option<String> {
-        self.current_object().name().map(|name| name.to_string())
-    }
-
-    #[getter]
-    fn object_type(&self) -> String {
-        match self.current_object().get_object_type() {
-            crate::core::game_object::ObjectType::GameObject => "GameObject",
-            crate::core::game_object::ObjectType::UIObject => "UIObject",
-            crate::core::game_object::ObjectType::ParticleSystem => "ParticleSystem",
-            crate::core::game_object::ObjectType::Sound => "Sound",
-            crate::core::game_object::ObjectType::Light => "Light",
-            crate::core::game_object::ObjectType::Camera => "Camera",
+    fn add_axis_positive_key(&mut self, axis_name: &str, key_name: &str) {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.add_axis_positive_key(axis_name, parse_key(key_name));
         }
-        .to_string()
     }
 
-    /// Set or change the name of this GameObject.
+    /// Add one negative key to an axis binding.
     ///
-    /// Assigns a human-readable name to the object. Names are useful for debugging,
-    /// logging, and organizing objects in your game.
+    /// Incrementally adds a key that produces negative axis values (-1.0)
+    /// without removing existing keys.
     ///
     /// # Arguments
-    /// * `name` - New name for the object (any string)
+    /// * `axis_name` - Name of the axis to modify
+    /// * `key_name` - Key to add (e.g., `"a"`, `Keys.A`)
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
-    /// obj = pyg.GameObject()
-    /// obj.set_name("Player")
-    /// print(obj.name)  # "Player"
+    /// engine = pyg.Engine()
     ///
-    /// # Rename object
-    /// obj.set_name("MainCharacter")
-    /// print(obj.name)  # "MainCharacter"
-    /// ```
+    /// # Add extra negative keys to Horizontal axis
+    /// engine.input.add_axis_negative_key("Horizontal", pyg.Keys.J)
+    /// engine.input.add_axis_negative_key("Horizontal", pyg.Keys.NUMPAD4)
     ///
-    /// # See Also
-    /// - `name` (property) - Get the current name
-    fn set_name(&mut self, name: String) {
-        self.inner.set_name(name.clone());
-        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
-            let _ = binding.sender.send(EngineCommand::SetGameObjectName {
-                object_id: binding.object_id,
-                name,
-            });
+    /// # Now Horizontal axis responds to: A, Left, J, Numpad4
+    /// ```
+    fn add_axis_negative_key(&mut self, axis_name: &str, key_name: &str) {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.add_axis_negative_key(axis_name, parse_key(key_name));
         }
     }
 
-    /// Compatibility alias for `enabled`.
+    /// Remove one positive key from an axis binding.
     ///
-    /// Prefer `enabled` in new code. This property remains available for older examples and
-    /// returns the same hierarchy-aware enabled state as `enabled`.
+    /// # Arguments
+    /// * `axis_name` - Name of the axis to modify
+    /// * `key_name` - Key to remove
     ///
     /// # Returns
-    /// - `True` - Object is enabled
-    /// - `False` - Object is disabled
+    /// `true` if the key was found and removed, `false` otherwise
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
-    /// player = pyg.GameObject("Player")
-    /// print(player.active)  # True
-    ///
-    /// # Disable object through compatibility alias
-    /// player.active = False
-    /// print(player.active)  # False
+    /// engine = pyg.Engine()
     ///
-    /// # Re-enable later
-    /// player.active = True
-    /// print(player.active)  # True
+    /// # Remove arrow key from Horizontal axis (keep WASD only)
+    /// if engine.input.remove_axis_positive_key("Horizontal", pyg.Keys.RIGHT):
+    ///     print("Removed Right arrow from Horizontal axis")
     /// ```
-    ///
-    /// # Use Cases
-    /// - **Object pooling**: Disable/re-enable objects instead of creating/destroying
-    /// - **Pause mechanics**: Disable enemies when the game is paused
-    /// - **Conditional rendering**: Hide objects without removing them from the scene
-    ///
-    /// # See Also
-    /// - `enabled` - Preferred property name in new code
-    #[getter]
-    fn active(&self) -> bool {
-        self.current_object().is_active()
+    fn remove_axis_positive_key(&mut self, axis_name: &str, key_name: &str) -> bool {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.remove_axis_positive_key(axis_name, &parse_key(key_name))
+        } else {
+            false
+        }
     }
 
-    /// Compatibility setter for `enabled`.
-    ///
-    /// Prefer `enabled` in new code. Disabled objects remain in the scene but are skipped
-    /// during update, render, and collision passes, and disabled parents cascade to children.
+    /// Remove one negative key from an axis binding.
     ///
     /// # Arguments
-    /// * `active` - `True` to enable, `False` to disable
+    /// * `axis_name` - Name of the axis to modify
+    /// * `key_name` - Key to remove
+    ///
+    /// # Returns
+    /// `true` if the key was found and removed, `false` otherwise
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
-    /// enemy = pyg.GameObject("Enemy")
-    /// engine.add_game_object(enemy)
-    ///
-    /// # Temporarily disable enemy
-    /// enemy.active = False  # Enemy no longer updates or renders
+    /// engine = pyg.Engine()
     ///
-    /// # Re-enable later
-    /// enemy.active = True  # Enemy resumes normal behavior
+    /// # Remove arrow key from Horizontal axis
+    /// if engine.input.remove_axis_negative_key("Horizontal", pyg.Keys.LEFT):
+    ///     print("Removed Left arrow from Horizontal axis")
     /// ```
+    fn remove_axis_negative_key(&mut self, axis_name: &str, key_name: &str) -> bool {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.remove_axis_negative_key(axis_name, &parse_key(key_name))
+        } else {
+            false
+        }
+    }
+
+    /// Remove an entire logical axis binding.
     ///
-    /// # Object Pooling Pattern
+    /// Completely removes an axis, including all key and mouse bindings.
+    /// Built-in axes ("Horizontal", "Vertical") can be removed but will
+    /// return if `reset_input_bindings_to_defaults()` is called.
+    ///
+    /// # Arguments
+    /// * `axis_name` - Name of the axis to remove
+    ///
+    /// # Returns
+    /// `true` if the axis existed and was removed, `false` otherwise
+    ///
+    /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
-    /// class BulletPool:
-    ///     def __init__(self, engine, size=10):
-    ///         self.bullets = []
-    ///         for i in range(size):
-    ///             bullet = pyg.GameObject(f"Bullet_{i}")
-    ///             bullet.active = False  # Compatibility alias for enabled = False
-    ///             engine.add_game_object(bullet)
-    ///             self.bullets.append(bullet)
+    /// engine = pyg.Engine()
     ///
-    ///     def spawn(self, position):
-    ///         # Find disabled bullet
-    ///         for bullet in self.bullets:
-    ///             if not bullet.active:
-    ///                 bullet.position = position
-    ///                 bullet.active = True  # Activate
-    ///                 return bullet
-    ///         return None  # Pool exhausted
+    /// # Create temporary axis
+    /// engine.input.set_axis_keys("TempAxis", [pyg.Keys.T], [pyg.Keys.G])
     ///
-    ///     def despawn(self, bullet):
-    ///         bullet.active = False  # Return to pool
-    /// ```
+    /// # Later remove it
+    /// if engine.input.remove_axis("TempAxis"):
+    ///     print("TempAxis removed")
     ///
-    /// # See Also
-    /// - `enabled` - Preferred property name in new code
-    #[setter]
-    fn set_active(&mut self, active: bool) {
-        self.inner.set_active(active);
-        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
-            let _ = binding.sender.send(EngineCommand::SetGameObjectEnabled {
-                object_id: binding.object_id,
-                enabled: active,
-            });
+    /// # Axis no longer exists
+    /// value = engine.input.axis("TempAxis")  # Returns 0.0
+    /// ```
+    fn remove_axis(&mut self, axis_name: &str) -> bool {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.remove_axis(axis_name)
+        } else {
+            false
         }
     }
 
-    #[getter]
-    fn enabled(&self) -> bool {
-        self.current_object().is_enabled()
-    }
-
-    #[setter]
-    fn set_enabled(&mut self, enabled: bool) {
-        self.set_active(enabled);
-    }
-
-    fn add_child(&mut self, child: &mut PyGameObject) -> PyResult<()> {
-        self.inner.add_child_id(child.inner.get_id());
-        child.inner.set_parent_id(Some(self.inner.get_id()));
-
-        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
-            let _ = binding.sender.send(EngineCommand::AddChild {
-                parent_id: binding.object_id,
-                child_id: child.inner.get_id(),
-            });
-        }
-        Ok(())
-    }
-
-    fn get_children(&self) -> Vec<PyGameObject> {
-        let binding_ref = self.runtime_binding.borrow();
-        let Some(binding) = binding_ref.as_ref() else {
-            return Vec::new();
-        };
-
-        binding
-            .objects
-            .read()
-            .map(|object_manager| {
-                self.current_object()
-                    .children()
-                    .iter()
-                    .filter_map(|child_id| object_manager.get_object_clone(*child_id))
-                    .map(|object| {
-                        PyGameObject::from_runtime(
-                            object,
-                            binding.sender.clone(),
-                            Arc::clone(&binding.objects),
-                        )
-                    })
-                    .collect()
-            })
-            .unwrap_or_default()
-    }
-
-    fn get_child_id(&self, child_id: u32) -> Option<PyGameObject> {
-        self.get_children()
-            .into_iter()
-            .find(|child| child.inner.get_id() == child_id)
-    }
-
-    fn get_child_name(&self, name: &str) -> Vec<PyGameObject> {
-        self.get_children()
-            .into_iter()
-            .filter(|child| child.inner.name() == Some(name))
-            .collect()
-    }
-
-    fn get_child_count(&self) -> usize {
-        self.current_object().child_count()
-    }
-
-    /// Get the position of this GameObject.
+    /// Configure a mouse-driven logical axis.
     ///
-    /// Returns the object's position in **world-space coordinates** as a `Vec2`.
-    /// The position represents the center point of the object.
+    /// Creates or updates a named axis that responds to mouse movement or scroll wheel.
+    /// Useful for camera controls, mouse look, and zoom functionality.
     ///
-    /// # Returns
-    /// `Vec2` containing the object's position:
-    /// - `x` - Horizontal position (right is positive)
-    /// - `y` - Vertical position (up is typically positive)
+    /// # Arguments
+    /// * `name` - Axis name (case-sensitive). Will create new axis or overwrite existing.
+    /// * `mouse_axis` - Mouse input source (case-insensitive). Valid values:
+    ///   - **`"x"`** or **`"mouse_x"`** - Horizontal mouse movement (right = positive)
+    ///   - **`"y"`** or **`"mouse_y"`** - Vertical mouse movement (down = positive)
+    ///   - **`"wheel_x"`** or **`"scroll_x"`** - Horizontal scroll wheel
+    ///   - **`"wheel_y"`**, **`"scroll"`**, or **`"scroll_y"`** - Vertical scroll wheel (up = positive)
+    /// * `sensitivity` - Multiplier for mouse input (default: 1.0). Higher values = more responsive.
+    ///   Typical values: 0.1 - 2.0
+    /// * `invert` - Invert the axis direction (default: false). Set `true` to reverse polarity.
     ///
-    /// # Coordinate System
-    /// - **World space**: Absolute positions in the game world
-    /// - **Origin (0, 0)**: Typically center of screen (configurable via camera)
-    /// - **Units**: Pixels by default
+    /// # Returns
+    /// `true` if axis was created successfully, `false` if `mouse_axis` was invalid
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
-    /// player = pyg.GameObject("Player")
-    /// player.position = pyg.Vec2(100.0, 200.0)
+    /// engine = pyg.Engine()
     ///
-    /// # Get current position
-    /// pos = player.position
-    /// print(f"Player at ({pos.x}, {pos.y})")  # "Player at (100.0, 200.0)"
+    /// # Create mouse look axes
+    /// engine.input.set_axis_mouse(
+    ///     "MouseLookX",
+    ///     mouse_axis="x",
+    ///     sensitivity=0.5,
+    ///     invert=False
+    /// )
     ///
-    /// # Check if player is at origin
-    /// if player.position == pyg.Vec2.ZERO:
-    ///     print("Player at origin")
-    /// ```
+    /// engine.input.set_axis_mouse(
+    ///     "MouseLookY",
+    ///     mouse_axis="y",
+    ///     sensitivity=0.5,
+    ///     invert=True  # Invert Y for flight-stick style controls
+    /// )
     ///
-    /// # Movement Example
-    /// ```python
-    /// import pyg_engine as pyg
+    /// # Create scroll wheel zoom axis
+    /// engine.input.set_axis_mouse(
+    ///     "ScrollZoom",
+    ///     mouse_axis="wheel_y",
+    ///     sensitivity=0.1,
+    ///     invert=False
+    /// )
     ///
-    /// def update(dt, engine, data):
-    ///     player = data['player']
+    /// engine.start_manual(title="Mouse Axis Demo")
     ///
-    ///     # Move right at 100 pixels/second
-    ///     new_pos = player.position + pyg.Vec2(100.0 * dt, 0.0)
-    ///     player.position = new_pos
+    /// camera_x = 0.0
+    /// camera_y = 0.0
+    /// zoom = 1.0
     ///
-    ///     return True
+    /// while engine.poll_events():
+    ///     dt = engine.delta_time
+    ///
+    ///     # Read mouse axes for camera control
+    ///     look_x = engine.input.axis("MouseLookX")
+    ///     look_y = engine.input.axis("MouseLookY")
+    ///     scroll_zoom = engine.input.axis("ScrollZoom")
+    ///
+    ///     # Pan camera with mouse (when button held)
+    ///     if engine.input.mouse_button_down(pyg.MouseButton.LEFT):
+    ///         camera_x -= look_x * 10.0 * dt
+    ///         camera_y -= look_y * 10.0 * dt
+    ///         engine.set_camera_position(pyg.Vec2(camera_x, camera_y))
+    ///
+    ///     # Zoom with scroll wheel
+    ///     if scroll_zoom != 0:
+    ///         zoom += scroll_zoom * 0.5
+    ///         zoom = max(0.5, min(3.0, zoom))
+    ///         print(f"Zoom: {zoom:.2f}x")
+    ///
+    ///     engine.update()
+    ///     engine.render()
     /// ```
     ///
+    /// # Notes
+    /// - Mouse axes return **delta values** (change since last frame), not absolute positions
+    /// - Mouse movement axes return 0 when the mouse isn't moving
+    /// - Scroll wheel axes return 0 when not scrolling
+    /// - Multiple axes can bind to the same mouse input with different sensitivity/inversion
+    ///
     /// # See Also
-    /// - `position` (setter) - Set object position
-    /// - `Vec2` - 2D vector class
-    /// - `examples/python_game_object_transform_demo.py` - Transform examples
-    #[getter]
-    fn position(&self) -> PyVec2 {
-        let object = self.current_object();
-        PyVec2 {
-            inner: *object.transform().position(),
+    /// - `examples/python_camera_worldspace_demo.py` - Mouse-based camera control
+    /// - `axis()` - Read axis value
+    /// - `set_axis_keys()` - Bind axes to keyboard keys
+    /// - `mouse_delta` - Direct access to mouse movement
+    /// - `mouse_wheel` - Direct access to scroll wheel
+    #[pyo3(signature = (name, mouse_axis, sensitivity=1.0, invert=false))]
+    fn set_axis_mouse(
+        &mut self,
+        name: &str,
+        mouse_axis: &str,
+        sensitivity: f32,
+        invert: bool,
+    ) -> bool {
+        let Some(axis_type) = parse_mouse_axis_type(mouse_axis) else {
+            return false;
+        };
+        if let Some(input) = &mut self.inner.input_manager {
+            input.set_axis_mouse_binding(
+                name,
+                MouseAxisBinding {
+                    axis: axis_type,
+                    sensitivity,
+                    invert,
+                },
+            );
+            true
+        } else {
+            false
         }
     }
 
-    /// Set the position of this GameObject.
+    /// Configure keyboard keys for an action.
     ///
-    /// Moves the object to the specified **world-space coordinates**. The position
-    /// represents the center point of the object.
+    /// Creates or updates a named action that activates when **any** of the specified keys
+    /// are pressed. Use actions for discrete game events like jumping, shooting, pausing,
+    /// or interacting.
     ///
     /// # Arguments
-    /// * `position` - Target position as `Vec2` (world-space coordinates)
+    /// * `action_name` - Action name (case-sensitive). Will create new or overwrite existing.
+    /// * `key_names` - List of keys that activate this action. Pressing **any** key in the
+    ///   list will trigger the action. Can use `Keys` constants or strings.
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
-    /// player = pyg.GameObject("Player")
-    ///
-    /// # Set position directly
-    /// player.position = pyg.Vec2(100.0, 200.0)
-    ///
-    /// # Move to origin
-    /// player.position = pyg.Vec2.ZERO
+    /// engine = pyg.Engine()
     ///
-    /// # Move relative to current position
-    /// player.position = player.position + pyg.Vec2(50.0, 0.0)  # Move right 50px
-    /// ```
+    /// # Create jump action with multiple keys
+    /// engine.input.set_action_keys(
+    ///     "jump",
+    ///     [pyg.Keys.SPACE, pyg.Keys.W, pyg.Keys.UP]
+    /// )
     ///
-    /// # Smooth Movement
-    /// ```python
-    /// import pyg_engine as pyg
+    /// # Create pause action
+    /// engine.input.set_action_keys(
+    ///     "pause",
+    ///     [pyg.Keys.ESCAPE, pyg.Keys.P]
+    /// )
     ///
-    /// def update(dt, engine, data):
-    ///     player = data['player']
-    ///     speed = 150.0  # pixels per second
+    /// # Create interact action
+    /// engine.input.set_action_keys(
+    ///     "interact",
+    ///     [pyg.Keys.E, pyg.Keys.RETURN]
+    /// )
     ///
-    ///     # Get input
-    ///     move_x = engine.input.axis("Horizontal")
-    ///     move_y = engine.input.axis("Vertical")
+    /// engine.start_manual(title="Action Keys Demo")
     ///
-    ///     # Calculate movement
-    ///     velocity = pyg.Vec2(move_x, move_y).normalize() * speed * dt
+    /// while engine.poll_events():
+    ///     # Any bound key triggers the action
+    ///     if engine.input.action_pressed("jump"):
+    ///         print("Jump! (Space, W, or Up pressed)")
     ///
-    ///     # Apply movement
-    ///     player.position = player.position + velocity
+    ///     if engine.input.action_pressed("interact"):
+    ///         print("Interacting (E or Enter pressed)")
     ///
-    ///     return True
+    ///     engine.update()
+    ///     engine.render()
     /// ```
     ///
-    /// # Lerp to Target
+    /// # Combining with Mouse Buttons
+    /// Actions can bind both keys and mouse buttons:
     /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// def update(dt, engine, data):
-    ///     player = data['player']
-    ///     target = data['target_pos']
-    ///
-    ///     # Smoothly interpolate to target (10% per frame)
-    ///     player.position = player.position.lerp(target, 0.1)
-    ///
-    ///     return True
-    /// ```
+    /// # Fire action works with Space OR left mouse button
+    /// engine.input.set_action_keys("fire", [pyg.Keys.SPACE])
+    /// engine.input.set_action_mouse_buttons("fire", [pyg.MouseButton.LEFT])
+    /// ```
     ///
     /// # See Also
-    /// - `position` (getter) - Get current position
-    /// - `Vec2` - 2D vector operations
-    /// - `Vec2.lerp()` - Linear interpolation for smooth movement
-    /// - `examples/python_game_object_transform_demo.py` - Transform examples
-    #[setter]
-    fn set_position(&mut self, position: PyVec2) {
-        self.inner.transform_mut().set_position(position.inner);
-        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
-            let _ = binding.sender.send(EngineCommand::SetGameObjectPosition {
-                object_id: binding.object_id,
-                position: position.inner,
-            });
+    /// - `examples/python_camera_worldspace_demo.py` - Action configuration
+    /// - `action_pressed()` / `action_down()` - Check action state
+    /// - `set_action_mouse_buttons()` - Add mouse button bindings
+    /// - `add_action_key()` - Add a key incrementally
+    /// - `clear_action_bindings()` - Remove all bindings for an action
+    fn set_action_keys(&mut self, action_name: &str, key_names: Vec<String>) {
+        if let Some(input) = &mut self.inner.input_manager {
+            let keys = key_names.iter().map(|k| parse_key(k)).collect();
+            input.set_action_keys(action_name, keys);
         }
     }
 
-    /// Get the object's rotation angle.
-    ///
-    /// Returns the current rotation in **radians** (not degrees). Positive values represent
-    /// counter-clockwise rotation.
+    /// Add one keyboard key to an action.
     ///
-    /// # Returns
-    /// Float representing rotation angle in **radians**:
-    /// - `0.0` = No rotation (facing right/east)
-    /// - `π/2` (≈1.571) = 90° counter-clockwise (facing up/north)
-    /// - `π` (≈3.142) = 180° (facing left/west)
-    /// - `3π/2` (≈4.712) = 270° counter-clockwise (facing down/south)
-    /// - `2π` (≈6.283) = 360° (full rotation, back to 0°)
+    /// Incrementally adds a key to an action without removing existing bindings.
     ///
-    /// # Units: Radians
-    /// **IMPORTANT:** Rotation uses **radians**, not degrees.
-    /// - To convert degrees to radians: Use `math.radians(degrees)`
-    /// - To convert radians to degrees: Use `math.degrees(radians)`
-    /// - Formula: `radians = degrees × (π / 180)`
+    /// # Arguments
+    /// * `action_name` - Name of the action to modify
+    /// * `key_name` - Key to add
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
-    /// import math
     ///
     /// engine = pyg.Engine()
-    /// obj = pyg.GameObject("RotatingObject")
     ///
-    /// # Get current rotation
-    /// current_rotation = obj.rotation
-    /// print(f"Rotation: {current_rotation} radians = {math.degrees(current_rotation)}°")
+    /// # Add multiple keys to jump action
+    /// engine.input.add_action_key("jump", pyg.Keys.W)
+    /// engine.input.add_action_key("jump", pyg.Keys.UP)
     ///
-    /// # Check if object is upside down
-    /// if abs(obj.rotation - math.pi) < 0.1:
-    ///     print("Object is approximately upside down")
+    /// # Now jump triggers on Space, W, or Up arrow
     /// ```
-    ///
-    /// # See Also
-    /// - `rotation` (setter) - Set rotation angle
-    /// - `GameObject.rotation` - Direct property access
-    #[getter]
-    fn rotation(&self) -> f32 {
-        self.current_object().transform().rotation()
+    fn add_action_key(&mut self, action_name: &str, key_name: &str) {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.add_action_key(action_name, parse_key(key_name));
+        }
     }
 
-    /// Set the object's rotation angle.
-    ///
-    /// Sets the rotation in **radians** (not degrees). Positive values rotate counter-clockwise.
+    /// Remove one keyboard key from an action.
     ///
     /// # Arguments
-    /// * `rotation` - Rotation angle in **radians** (not degrees):
-    ///   - Positive: Counter-clockwise rotation
-    ///   - Negative: Clockwise rotation
-    ///   - Range: Typically 0 to 2π, but any value is valid (wraps around)
+    /// * `action_name` - Name of the action to modify
+    /// * `key_name` - Key to remove
     ///
-    /// # Units: Radians
-    /// **IMPORTANT:** Rotation uses **radians**, not degrees!
-    /// - Use `math.radians(degrees)` to convert from degrees
-    /// - Common angles in radians:
-    ///   - 0° = 0.0 rad
-    ///   - 45° ≈ 0.785 rad (π/4)
-    ///   - 90° ≈ 1.571 rad (π/2)
-    ///   - 180° ≈ 3.142 rad (π)
-    ///   - 270° ≈ 4.712 rad (3π/2)
-    ///   - 360° ≈ 6.283 rad (2π)
+    /// # Returns
+    /// `true` if the key was found and removed, `false` otherwise
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
-    /// import math
     ///
     /// engine = pyg.Engine()
-    /// obj = pyg.GameObject("RotatingObject")
-    ///
-    /// # Rotate 45 degrees counter-clockwise
-    /// obj.rotation = math.radians(45)
-    ///
-    /// # Rotate 90 degrees clockwise (negative)
-    /// obj.rotation = math.radians(-90)
     ///
-    /// # Rotate using pi constants
-    /// obj.rotation = math.pi / 2  # 90 degrees
-    /// obj.rotation = math.pi       # 180 degrees
-    /// obj.rotation = 2 * math.pi   # 360 degrees (full rotation)
+    /// # Remove Space from jump action
+    /// if engine.input.remove_action_key("jump", pyg.Keys.SPACE):
+    ///     print("Space key removed from jump")
     /// ```
+    fn remove_action_key(&mut self, action_name: &str, key_name: &str) -> bool {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.remove_action_key(action_name, &parse_key(key_name))
+        } else {
+            false
+        }
+    }
+
+    /// Configure modifier chords for an action.
     ///
-    /// # Continuous Rotation
-    /// ```python
-    /// import pyg_engine as pyg
-    /// import math
+    /// A chord fires only while exactly its modifiers are held, and is pressed
+    /// only when its final key or mouse button goes down, so the bare key (or
+    /// pressing Ctrl while already holding S) never triggers it. Replaces the
+    /// action's existing chords; plain key and mouse bindings are kept.
     ///
-    /// engine = pyg.Engine()
-    /// obj = pyg.GameObject("Spinner")
-    /// engine.add_game_object(obj)
+    /// # Arguments
+    /// * `action_name` - Name of the action to configure
+    /// * `chords` - Chords like `"ctrl+s"`, `"ctrl+shift+z"` or `"shift+click"`
     ///
-    /// rotation_speed = math.radians(90)  # 90 degrees per second
+    /// # Raises
+    /// `ValueError` if a chord names an unknown modifier, key or button.
     ///
-    /// def update(dt, engine, data):
-    ///     # Rotate continuously
-    ///     obj.rotation += rotation_speed * dt
+    /// # Example
+    /// ```python
+    /// engine.input.set_action_chords("save", ["ctrl+s", "cmd+s"])
+    /// engine.input.set_action_chords("add_to_selection", ["shift+click"])
+    /// ```
+    fn set_action_chords(&mut self, action_name: &str, chords: Vec<String>) -> PyResult<()> {
+        let chords = chords.iter().map(|chord| parse_chord(chord)).collect::<PyResult<_>>()?;
+        if let Some(input) = &mut self.inner.input_manager {
+            input.set_action_chords(action_name, chords);
+        }
+        Ok(())
+    }
+
+    /// Add one modifier chord to an action, e.g. `"ctrl+s"`.
+    fn add_action_chord(&mut self, action_name: &str, chord: &str) -> PyResult<()> {
+        let chord = parse_chord(chord)?;
+        if let Some(input) = &mut self.inner.input_manager {
+            input.add_action_chord(action_name, chord);
+        }
+        Ok(())
+    }
+
+    /// Remove one modifier chord from an action.
     ///
-    ///     # Optional: Keep rotation in [0, 2π] range
-    ///     if obj.rotation > 2 * math.pi:
-    ///         obj.rotation -= 2 * math.pi
+    /// # Returns
+    /// `true` if the chord was found and removed, `false` otherwise
+    fn remove_action_chord(&mut self, action_name: &str, chord: &str) -> PyResult<bool> {
+        let chord = parse_chord(chord)?;
+        Ok(self
+            .inner
+            .input_manager
+            .as_mut()
+            .is_some_and(|input| input.remove_action_chord(action_name, &chord)))
+    }
+
+    /// Configure mouse buttons for an action.
     ///
-    ///     return True
+    /// Creates or updates the mouse button bindings for a named action. Pressing **any**
+    /// of the specified mouse buttons will activate the action. This complements keyboard
+    /// bindings set with `set_action_keys()`.
     ///
-    /// engine.run(update=update)
-    /// ```
+    /// # Arguments
+    /// * `action_name` - Action name (case-sensitive)
+    /// * `buttons` - List of mouse buttons that activate this action. Can use `MouseButton`
+    ///   constants or strings (e.g., `[MouseButton.LEFT, MouseButton.RIGHT]` or `["left", "right"]`)
     ///
-    /// # Rotation Towards Target
+    /// # Example
     /// ```python
     /// import pyg_engine as pyg
-    /// import math
     ///
-    /// def rotate_towards(obj, target_pos, dt, turn_speed):
-    ///     # Calculate angle to target
-    ///     dx = target_pos.x - obj.position.x
-    ///     dy = target_pos.y - obj.position.y
-    ///     target_angle = math.atan2(dy, dx)
+    /// engine = pyg.Engine()
     ///
-    ///     # Smoothly rotate towards target
-    ///     angle_diff = target_angle - obj.rotation
+    /// # Create fire action with mouse buttons
+    /// engine.input.set_action_mouse_buttons(
+    ///     "fire",
+    ///     [pyg.MouseButton.LEFT]
+    /// )
     ///
-    ///     # Normalize to [-π, π]
-    ///     while angle_diff > math.pi:
-    ///         angle_diff -= 2 * math.pi
-    ///     while angle_diff < -math.pi:
-    ///         angle_diff += 2 * math.pi
+    /// # Create alternate fire with right mouse
+    /// engine.input.set_action_mouse_buttons(
+    ///     "alternate_fire",
+    ///     [pyg.MouseButton.RIGHT, pyg.MouseButton.MIDDLE]
+    /// )
     ///
-    ///     # Apply rotation with speed limit
-    ///     max_rotation = turn_speed * dt
-    ///     obj.rotation += max(-max_rotation, min(max_rotation, angle_diff))
-    /// ```
+    /// # Combine with keyboard for same action
+    /// engine.input.set_action_keys("fire", [pyg.Keys.SPACE, pyg.Keys.CONTROL])
     ///
-    /// # Common Mistakes
-    /// ```python
-    /// # ❌ WRONG - Using degrees directly
-    /// obj.rotation = 90  # This is 90 radians, not 90 degrees!
+    /// engine.start_manual(title="Mouse Action Demo")
     ///
-    /// # ✅ CORRECT - Convert degrees to radians
-    /// obj.rotation = math.radians(90)  # 90 degrees
+    /// while engine.poll_events():
+    ///     # Triggers with Space, Ctrl, OR Left Mouse
+    ///     if engine.input.action_pressed("fire"):
+    ///         print("Fire!")
     ///
-    /// # ✅ CORRECT - Use pi constants
-    /// obj.rotation = math.pi / 2  # 90 degrees
-    /// ```
+    ///     # Triggers with Right Mouse OR Middle Mouse
+    ///     if engine.input.action_pressed("alternate_fire"):
+    ///         print("Alternate fire!")
     ///
-    /// # Direction Reference
-    /// Assuming default orientation (0° = facing right):
-    /// - `math.radians(0)` = Right (East)
-    /// - `math.radians(90)` = Up (North)
-    /// - `math.radians(180)` = Left (West)
-    /// - `math.radians(270)` = Down (South)
+    ///     engine.update()
+    ///     engine.render()
+    /// ```
     ///
     /// # See Also
-    /// - `examples/python_game_object_transform_demo.py` - Transform demonstrations
-    /// - `rotation` (getter) - Get current rotation
-    /// - `GameObject.rotation` - Direct property access
-    #[setter]
-    fn set_rotation(&mut self, rotation: f32) {
-        self.inner.transform_mut().set_rotation(rotation);
-        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
-            let _ = binding.sender.send(EngineCommand::SetGameObjectRotation {
-                object_id: binding.object_id,
-                rotation,
-            });
+    /// - `examples/python_input_demo.py` - Mouse button usage
+    /// - `set_action_keys()` - Bind keyboard keys to actions
+    /// - `action_pressed()` / `action_down()` - Check action state
+    /// - `add_action_mouse_button()` - Add a button incrementally
+    fn set_action_mouse_buttons(&mut self, action_name: &str, buttons: Vec<String>) {
+        if let Some(input) = &mut self.inner.input_manager {
+            let mapped = buttons
+                .iter()
+                .map(|button| parse_mouse_button(button))
+                .collect();
+            input.set_action_mouse_buttons(action_name, mapped);
         }
     }
 
-    /// Get the scale of this GameObject.
-    ///
-    /// Returns the object's scale as a `Vec2`. Scale controls the size of the object
-    /// relative to its original dimensions. Scale values are multiplicative:
-    /// - `1.0` = Original size (100%)
-    /// - `2.0` = Double size (200%)
-    /// - `0.5` = Half size (50%)
+    /// Add one mouse button to an action.
     ///
-    /// # Returns
-    /// `Vec2` containing the scale factors:
-    /// - `x` - Horizontal scale factor
-    /// - `y` - Vertical scale factor
+    /// Incrementally adds a mouse button to an action without removing existing bindings.
     ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// obj = pyg.GameObject("Sprite")
-    /// obj.scale = pyg.Vec2(2.0, 2.0)  # Double size
-    ///
-    /// # Get current scale
-    /// scale = obj.scale
-    /// print(f"Scale: {scale.x}x, {scale.y}x")  # "Scale: 2.0x, 2.0x"
-    ///
-    /// # Check if object is at original size
-    /// if obj.scale == pyg.Vec2(1.0, 1.0):
-    ///     print("Original size")
-    /// ```
+    /// # Arguments
+    /// * `action_name` - Name of the action to modify
+    /// * `button` - Mouse button to add (e.g., `MouseButton.LEFT`, `"left"`)
     ///
-    /// # Non-Uniform Scaling
+    /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
-    /// obj = pyg.GameObject("StretchedSprite")
+    /// engine = pyg.Engine()
     ///
-    /// # Different scale on each axis
-    /// obj.scale = pyg.Vec2(2.0, 0.5)  # Wide and short
-    /// obj.scale = pyg.Vec2(0.5, 2.0)  # Narrow and tall
+    /// # Add mouse buttons to fire action
+    /// engine.input.add_action_mouse_button("fire", pyg.MouseButton.LEFT)
+    /// engine.input.add_action_mouse_button("fire", pyg.MouseButton.MIDDLE)
     ///
-    /// # Flip horizontally (negative scale)
-    /// obj.scale = pyg.Vec2(-1.0, 1.0)  # Mirror on X-axis
+    /// # Now fire triggers on Space, Left click, or Middle click
     /// ```
-    ///
-    /// # See Also
-    /// - `scale` (setter) - Set object scale
-    /// - `Vec2` - 2D vector class
-    /// - `examples/python_game_object_transform_demo.py` - Transform examples
-    #[getter]
-    fn scale(&self) -> PyVec2 {
-        let object = self.current_object();
-        PyVec2 {
-            inner: *object.transform().scale(),
+    fn add_action_mouse_button(&mut self, action_name: &str, button: &str) {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.add_action_mouse_button(action_name, parse_mouse_button(button));
         }
     }
 
-    /// Set the scale of this GameObject.
-    ///
-    /// Controls the size of the object relative to its original dimensions.
-    /// Scale is applied to the object's components and propagates through child objects
-    /// when the object is part of a hierarchy.
+    /// Remove one mouse button from an action.
     ///
     /// # Arguments
-    /// * `scale` - Scale factors as `Vec2`:
-    ///   - `x` - Horizontal scale (1.0 = original width)
-    ///   - `y` - Vertical scale (1.0 = original height)
+    /// * `action_name` - Name of the action to modify
+    /// * `button` - Mouse button to remove
     ///
-    /// # Scale Values
-    /// - **1.0** = Original size (100%)
-    /// - **> 1.0** = Larger (e.g., 2.0 = double size)
-    /// - **< 1.0** = Smaller (e.g., 0.5 = half size)
-    /// - **0.0** = Invisible (zero size)
-    /// - **Negative** = Flipped (mirrored)
+    /// # Returns
+    /// `true` if the button was found and removed, `false` otherwise
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
-    /// obj = pyg.GameObject("Sprite")
-    ///
-    /// # Uniform scaling (same on both axes)
-    /// obj.scale = pyg.Vec2(2.0, 2.0)   # Double size
-    /// obj.scale = pyg.Vec2(0.5, 0.5)   # Half size
-    ///
-    /// # Non-uniform scaling
-    /// obj.scale = pyg.Vec2(3.0, 1.0)   # Stretched horizontally
-    /// obj.scale = pyg.Vec2(1.0, 0.5)   # Compressed vertically
-    ///
-    /// # Flipping
-    /// obj.scale = pyg.Vec2(-1.0, 1.0)  # Flip horizontally
-    /// obj.scale = pyg.Vec2(1.0, -1.0)  # Flip vertically
-    /// obj.scale = pyg.Vec2(-1.0, -1.0) # Flip both axes
-    /// ```
-    ///
-    /// # Pulsing Effect
-    /// ```python
-    /// import pyg_engine as pyg
-    /// import math
-    ///
-    /// def update(dt, engine, data):
-    ///     elapsed = data['elapsed']
-    ///     data['elapsed'] += dt
-    ///
-    ///     # Pulse between 0.5x and 1.5x size
-    ///     pulse = 1.0 + 0.5 * math.sin(elapsed * 2.0)
-    ///     data['obj'].scale = pyg.Vec2(pulse, pulse)
-    ///
-    ///     return True
-    ///
-    /// engine.run(update=update, user_data={'elapsed': 0.0, 'obj': obj})
-    /// ```
-    ///
-    /// # Grow Over Time
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// def update(dt, engine, data):
-    ///     obj = data['obj']
-    ///     grow_rate = 0.5  # 50% per second
-    ///
-    ///     # Grow uniformly
-    ///     current_scale = obj.scale.x  # Assuming uniform scale
-    ///     new_scale = current_scale + (grow_rate * dt)
-    ///     obj.scale = pyg.Vec2(new_scale, new_scale)
-    ///
-    ///     # Cap maximum size
-    ///     if obj.scale.x > 5.0:
-    ///         obj.scale = pyg.Vec2(5.0, 5.0)
+    /// engine = pyg.Engine()
     ///
-    ///     return True
+    /// # Remove right click from an action
+    /// if engine.input.remove_action_mouse_button("fire", pyg.MouseButton.RIGHT):
+    ///     print("Right click removed from fire action")
     /// ```
-    ///
-    /// # See Also
-    /// - `scale` (getter) - Get current scale
-    /// - `Vec2` - 2D vector operations
-    /// - `examples/python_game_object_transform_demo.py` - Transform examples
-    #[setter]
-    fn set_scale(&mut self, scale: PyVec2) {
-        self.inner.transform_mut().set_scale(scale.inner);
-        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
-            let _ = binding.sender.send(EngineCommand::SetGameObjectScale {
-                object_id: binding.object_id,
-                scale: scale.inner,
-            });
+    fn remove_action_mouse_button(&mut self, action_name: &str, button: &str) -> bool {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.remove_action_mouse_button(action_name, parse_mouse_button(button))
+        } else {
+            false
         }
     }
 
-    /// Manually update this GameObject.
+    /// Clear all bindings for an action.
     ///
-    /// Triggers the object's update lifecycle, including all attached components.
-    /// This is typically called automatically by the engine, but can be invoked
-    /// manually for custom update logic or testing.
+    /// Removes all keyboard and mouse button bindings from an action.
+    /// The action still exists but has no triggers.
     ///
     /// # Arguments
-    /// * `time` - Optional `Time` object for delta time. If `None`, uses current time.
+    /// * `action_name` - Name of the action to clear
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
-    /// obj = pyg.GameObject("Player")
+    /// engine = pyg.Engine()
     ///
-    /// # Manual update (uses current time)
-    /// obj.update()
+    /// # Clear all jump bindings
+    /// engine.input.clear_action_bindings("jump")
     ///
-    /// # Manual update with specific time
-    /// time = pyg.Time()
-    /// obj.update(time)
+    /// # Re-bind to different keys
+    /// engine.input.set_action_keys("jump", [pyg.Keys.W, pyg.Keys.UP])
     /// ```
-    ///
-    /// # Use Cases
-    /// - **Testing**: Manually tick objects in unit tests
-    /// - **Custom loops**: Control update timing outside the engine loop
-    /// - **Selective updates**: Update specific objects independently
-    ///
-    /// # Note
-    /// In normal usage, the engine calls `update()` automatically for all active
-    /// GameObjects each frame. Manual calling is rarely needed.
-    ///
-    /// # See Also
-    /// - `GameObject.active` - Control whether object updates automatically
-    #[pyo3(signature = (time=None))]
-    fn update(&self, time: Option<&PyTime>) {
-        if let Some(time) = time {
-            self.inner.update(&time.inner);
-        } else {
-            let local_time = RustTime::new();
-            self.inner.update(&local_time);
+    fn clear_action_bindings(&mut self, action_name: &str) {
+        if let Some(input) = &mut self.inner.input_manager {
+            input.clear_action_bindings(action_name);
         }
     }
+}
 
-    /// Check if this GameObject has a mesh component attached.
-    ///
-    /// Returns `True` if the object has a `MeshComponent` for rendering,
-    /// `False` otherwise.
-    ///
-    /// # Returns
-    /// - `True` - Object has a mesh component (is visible)
-    /// - `False` - Object has no mesh component (not rendered)
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// obj = pyg.GameObject("Sprite")
-    ///
-    /// # Check if mesh exists
-    /// if obj.has_mesh_component():
-    ///     print("Object has rendering")
-    /// else:
-    ///     print("Object is invisible")
-    ///
-    /// # Add mesh component
-    /// mesh = pyg.MeshComponent()
-    /// obj.add_mesh_component(mesh)
-    ///
-    /// # Now has mesh
-    /// assert obj.has_mesh_component() == True
-    /// ```
+/// A thread-safe handle to the engine that can be passed to background threads.
+///
+/// Use this handle to queue commands like adding objects or drawing from other threads.
+#[pyclass(name = "EngineHandle")]
+#[derive(Clone)]
+pub struct PyEngineHandle {
+    sender: Sender<EngineCommand>,
+}
+
+#[pymethods]
+impl PyEngineHandle {
+    /// Add a GameObject to the engine command queue.
     ///
-    /// # See Also
-    /// - `add_mesh_component()` - Add a mesh component
-    /// - `mesh_component()` - Get the mesh component
-    /// - `remove_mesh_component()` - Remove the mesh component
-    fn has_mesh_component(&self) -> bool {
-        self.current_object().has_mesh_component()
+    /// This is thread-safe and will be processed on the next engine update.
+    fn add_game_object(&self, game_object: &PyGameObject) {
+        let _ = self.sender.send(EngineCommand::AddGameObject(
+            game_object.to_runtime_game_object(),
+        ));
     }
 
-    /// Add a mesh component to this GameObject.
-    ///
-    /// Attaches a `MeshComponent` to make the object visible. If a mesh component
-    /// already exists, it will be replaced.
-    ///
-    /// # Arguments
-    /// * `mesh_component` - The `MeshComponent` to attach
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// obj = pyg.GameObject("Sprite")
-    ///
-    /// # Create and configure mesh
-    /// mesh = pyg.MeshComponent()
-    /// mesh.set_geometry_rectangle(64.0, 64.0)
-    /// mesh.set_fill_color(pyg.Color.RED)
-    ///
-    /// # Attach to object
-    /// obj.add_mesh_component(mesh)
-    /// ```
-    ///
-    /// # Quick Setup
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// # Create object with mesh in one go
-    /// obj = pyg.GameObject("Player")
-    /// obj.position = pyg.Vec2(0.5, 0.5)
-    ///
-    /// mesh = pyg.MeshComponent()
-    /// mesh.set_geometry_circle(32.0)
-    /// mesh.set_image_path("assets/player.png")
-    ///
-    /// obj.add_mesh_component(mesh)
-    /// engine.add_game_object(obj)
-    /// ```
-    ///
-    /// Compatibility helper for mesh attachment.
-    ///
-    /// Prefer `add_component(mesh_component)` in new code so mesh, collider, transform,
-    /// and UI components all follow the same attachment API.
-    ///
-    /// # See Also
-    /// - `add_component()` - Canonical component attachment API
-    /// - `has_mesh_component()` - Check if mesh exists
-    /// - `mesh_component()` - Get the mesh component
-    /// - `MeshComponent` - Mesh component class
-    fn add_mesh_component(&mut self, mesh_component: &PyMeshComponent) {
-        self.inner.add_mesh_component(mesh_component.inner.clone());
-        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
-            let _ = binding.sender.send(EngineCommand::AddComponent {
-                object_id: binding.object_id,
-                component: Box::new(mesh_component.inner.clone()),
-            });
-        }
+    /// Remove a runtime GameObject by id via command queue.
+    fn remove_game_object(&self, object_id: u32) {
+        let _ = self.sender.send(EngineCommand::RemoveGameObject(object_id));
     }
 
-    /// Set the mesh component for this GameObject.
-    ///
-    /// Compatibility helper for attaching or replacing the `MeshComponent`.
-    ///
-    /// This is functionally identical to `add_mesh_component()`. Prefer
-    /// `add_component(mesh_component)` in new code.
-    ///
-    /// # Arguments
-    /// * `mesh_component` - The `MeshComponent` to attach
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// obj = pyg.GameObject("Sprite")
-    ///
-    /// mesh = pyg.MeshComponent()
-    /// mesh.set_geometry_rectangle(64.0, 64.0)
-    /// mesh.set_fill_color(pyg.Color.BLUE)
-    ///
-    /// obj.add_component(mesh)  # Preferred
-    /// ```
-    ///
-    /// # See Also
-    /// - `add_component()` - Preferred method in new code
-    /// - `add_mesh_component()` - Compatibility helper with same behavior
-    fn set_mesh_component(&mut self, mesh_component: &PyMeshComponent) {
-        self.add_mesh_component(mesh_component);
+    /// Queue an event on the event bus via command queue.
+    #[pyo3(signature = (name, payload=None))]
+    fn emit_event(&self, name: String, payload: Option<&Bound<'_, PyAny>>) {
+        let payload = payload.map_or(EventPayload::None, payload_from_py);
+        let _ = self.sender.send(EngineCommand::EmitEvent { name, payload });
     }
 
-    /// Remove the mesh component from this GameObject.
-    ///
-    /// Detaches and returns the object's `MeshComponent`, making it invisible.
-    /// If no mesh component exists, returns `None`.
-    ///
-    /// # Returns
-    /// - `Some(MeshComponent)` - The removed mesh component
-    /// - `None` - No mesh component was attached
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// obj = pyg.GameObject("Sprite")
-    /// mesh = pyg.MeshComponent()
-    /// obj.add_mesh_component(mesh)
-    ///
-    /// # Remove mesh (object becomes invisible)
-    /// removed_mesh = obj.remove_mesh_component()
-    ///
-    /// if removed_mesh:
-    ///     print("Mesh removed")
-    /// else:
-    ///     print("No mesh to remove")
-    /// ```
-    ///
-    /// # Temporary Invisibility
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// obj = pyg.GameObject("Sprite")
-    /// mesh = pyg.MeshComponent()
-    /// obj.add_mesh_component(mesh)
-    ///
-    /// # Hide by removing mesh
-    /// saved_mesh = obj.remove_mesh_component()
-    ///
-    /// # Later, restore mesh
-    /// if saved_mesh:
-    ///     obj.add_mesh_component(saved_mesh)
-    /// ```
-    ///
-    /// # Note
-    /// For temporary hiding, consider using `mesh.visible = False` instead,
-    /// which is simpler and doesn't require removing/re-adding the component.
-    ///
-    /// # See Also
-    /// - `has_mesh_component()` - Check if mesh exists
-    /// - `mesh_component()` - Get mesh without removing
-    /// - `MeshComponent.visible` - Toggle visibility without removing
-    fn remove_mesh_component(&mut self) -> Option<PyMeshComponent> {
-        let removed = self
-            .inner
-            .remove_mesh_component()
-            .map(|inner| PyMeshComponent {
-                inner,
-                runtime_binding: RefCell::new(None),
-            });
-        if let Some(binding) = self.runtime_binding.borrow().as_ref()
-            && let Some(mesh) = self.current_object().mesh_component()
-        {
-            let _ = binding.sender.send(EngineCommand::RemoveComponentById {
-                object_id: binding.object_id,
-                component_id: mesh.id(),
-            });
-        }
-        removed
+    /// End the game loop after the current frame via command queue.
+    fn request_quit(&self) {
+        let _ = self.sender.send(EngineCommand::RequestQuit);
     }
 
-    /// Get a copy of this GameObject's mesh component.
-    ///
-    /// Returns a copy of the attached `MeshComponent` if one exists.
-    /// Changes to the returned mesh will **not** affect the original
-    /// (use direct mesh methods on the GameObject for in-place modification).
-    ///
-    /// # Returns
-    /// - `Some(MeshComponent)` - Copy of the mesh component
-    /// - `None` - No mesh component attached
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// obj = pyg.GameObject("Sprite")
-    /// mesh = pyg.MeshComponent()
-    /// obj.add_mesh_component(mesh)
-    ///
-    /// # Get mesh copy
-    /// mesh_copy = obj.mesh_component()
-    ///
-    /// if mesh_copy:
-    ///     print(f"Mesh visible: {mesh_copy.visible}")
-    /// ```
-    ///
-    /// # Note on Copying
-    /// ```python
-    /// # This returns a COPY, not a reference:
-    /// mesh_copy = obj.mesh_component()
-    /// mesh_copy.visible = False  # Does NOT affect obj's mesh!
-    ///
-    /// # To modify the object's mesh directly, use GameObject methods:
-    /// obj.set_mesh_visible(False)  # Modifies obj's mesh directly
-    /// ```
-    ///
-    /// # Cloning Objects
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// original = pyg.GameObject("Original")
-    /// mesh = pyg.MeshComponent()
-    /// mesh.set_geometry_circle(32.0)
-    /// original.add_mesh_component(mesh)
-    ///
-    /// # Clone object with mesh
-    /// clone = pyg.GameObject("Clone")
-    /// if original.mesh_component():
-    ///     clone.add_mesh_component(original.mesh_component())
-    /// ```
-    ///
-    /// # See Also
-    /// - `has_mesh_component()` - Check if mesh exists
-    /// - `remove_mesh_component()` - Remove and get mesh
-    /// - GameObject mesh methods: `set_mesh_visible()`, `set_mesh_fill_color()`, etc.
-    fn mesh_component(&self) -> Option<PyMeshComponent> {
-        let object = self.current_object();
-        object.mesh_component().cloned().map(|inner| PyMeshComponent {
-            runtime_binding: RefCell::new(
-                object
-                    .mesh_component()
-                    .and_then(|component| self.component_binding(component)),
-            ),
-            inner,
-        })
+    /// Set the game time scale via command queue.
+    fn set_time_scale(&self, time_scale: f32) {
+        let _ = self.sender.send(EngineCommand::SetTimeScale(time_scale));
     }
 
-    fn add_text_mesh_component(&mut self, text_mesh_component: &PyTextMeshComponent) {
-        self.inner
-            .add_component(Box::new(text_mesh_component.inner.clone()));
-        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
-            let _ = binding.sender.send(EngineCommand::AddComponent {
-                object_id: binding.object_id,
-                component: Box::new(text_mesh_component.inner.clone()),
-            });
-        }
+    /// Pause or resume game time via command queue.
+    fn set_paused(&self, paused: bool) {
+        let _ = self.sender.send(EngineCommand::SetPaused(paused));
     }
 
-    fn text_mesh_component(&self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
-        self.get_component_type(py, "TextMesh")
+    /// Set the seconds of game time per fixed update via command queue.
+    fn set_fixed_timestep(&self, fixed_timestep: f32) {
+        let _ = self.sender.send(EngineCommand::SetFixedTimestep(fixed_timestep));
     }
 
-    /// Set the mesh geometry to a rectangle.
-    ///
-    /// Configures the object's mesh as a rectangle with the specified dimensions.
-    /// If no mesh component exists, one is created automatically.
-    ///
-    /// # Arguments
-    /// * `width` - Rectangle width in pixels
-    /// * `height` - Rectangle height in pixels
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// obj = pyg.GameObject("Box")
-    /// obj.position = pyg.Vec2(0.2, 0.15)
-    ///
-    /// # Create 100x50 rectangle
-    /// obj.set_mesh_geometry_rectangle(100.0, 50.0)
-    /// obj.set_mesh_fill_color(pyg.Color.GREEN)
-    ///
-    /// engine.add_game_object(obj)
-    /// ```
-    ///
-    /// # Quick Sprite Setup
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// sprite = pyg.GameObject("Sprite")
-    /// sprite.position = pyg.Vec2(400.0, 300.0)
-    /// sprite.set_mesh_geometry_rectangle(64.0, 64.0)  # 64x64 square
-    /// sprite.set_mesh_image_path("assets/player.png")
-    /// ```
-    ///
-    /// # See Also
-    /// - `set_mesh_geometry_circle()` - Set mesh to circle
-    /// - `MeshComponent.set_geometry_rectangle()` - Direct mesh method
-    fn set_mesh_geometry_rectangle(&mut self, width: f32, height: f32) {
-        let mesh = self.ensure_mesh_component();
-        mesh.set_geometry(MeshGeometry::rectangle(width, height));
-        self.sync_runtime_mesh_component();
+    /// Send a message to the components of a runtime GameObject via command queue.
+    #[pyo3(signature = (target_id, name, payload=None))]
+    fn send_message(&self, target_id: u32, name: String, payload: Option<&Bound<'_, PyAny>>) {
+        let payload = payload.map_or(EventPayload::None, payload_from_py);
+        let _ = self.sender.send(EngineCommand::SendMessage {
+            target_id,
+            name,
+            payload,
+        });
     }
 
-    /// Set the mesh geometry to a circle.
-    ///
-    /// Configures the object's mesh as a circle with the specified radius and quality.
-    /// If no mesh component exists, one is created automatically.
-    ///
-    /// # Arguments
-    /// * `radius` - Circle radius in pixels
-    /// * `segments` - Number of segments for circle quality (default: 32)
-    ///   - Higher values = smoother circle, more vertices
-    ///   - Typical range: 16-64 segments
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// obj = pyg.GameObject("Ball")
-    /// obj.position = pyg.Vec2(200.0, 150.0)
-    ///
-    /// # Create smooth circle with radius 40
-    /// obj.set_mesh_geometry_circle(40.0, segments=64)
-    /// obj.set_mesh_fill_color(pyg.Color.RED)
-    ///
-    /// engine.add_game_object(obj)
-    /// ```
-    ///
-    /// # Segment Quality Comparison
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// # Low quality (fast, jagged)
-    /// low_poly = pyg.GameObject("LowPoly")
-    /// low_poly.set_mesh_geometry_circle(30.0, segments=8)
-    ///
-    /// # Medium quality (default)
-    /// medium = pyg.GameObject("Medium")
-    /// medium.set_mesh_geometry_circle(30.0, segments=32)
-    ///
-    /// # High quality (smooth, more expensive)
-    /// high_poly = pyg.GameObject("HighPoly")
-    /// high_poly.set_mesh_geometry_circle(30.0, segments=128)
-    /// ```
-    ///
-    /// # See Also
-    /// - `set_mesh_geometry_rectangle()` - Set mesh to rectangle
-    /// - `MeshComponent.set_geometry_circle()` - Direct mesh method
-    #[pyo3(signature = (radius, segments=32))]
-    fn set_mesh_geometry_circle(&mut self, radius: f32, segments: u32) {
-        let mesh = self.ensure_mesh_component();
-        mesh.set_geometry(MeshGeometry::circle(radius, segments));
-        self.sync_runtime_mesh_component();
+    /// Update a runtime GameObject position by id via command queue.
+    fn set_game_object_position(&self, object_id: u32, position: PyVec2) {
+        let _ = self.sender.send(EngineCommand::SetGameObjectPosition {
+            object_id,
+            position: position.inner,
+        });
     }
 
-    /// Set the fill color of the mesh.
-    ///
-    /// Sets a solid color for the mesh. If no mesh component exists, one is
-    /// created automatically. Pass `None` to remove the color (use image only).
-    ///
-    /// # Arguments
-    /// * `color` - `Color` instance, or `None` to remove fill color
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// obj = pyg.GameObject("ColoredRect")
-    /// obj.set_mesh_geometry_rectangle(100.0, 100.0)
-    ///
-    /// # Set solid color
-    /// obj.set_mesh_fill_color(pyg.Color.BLUE)
-    ///
-    /// # Change color later
-    /// obj.set_mesh_fill_color(pyg.Color.RED)
-    ///
-    /// # Remove color (transparent/image only)
-    /// obj.set_mesh_fill_color(None)
-    /// ```
-    ///
-    /// # Color Animation
-    /// ```python
-    /// import pyg_engine as pyg
-    /// import math
-    ///
-    /// def update(dt, engine, data):
-    ///     elapsed = data['elapsed']
-    ///     data['elapsed'] += dt
-    ///
-    ///     # Pulse red channel
-    ///     red_value = (math.sin(elapsed * 2.0) + 1.0) / 2.0  # 0.0 to 1.0
-    ///     color = pyg.Color.new(red_value, 0.0, 0.0, 1.0)
-    ///     data['obj'].set_mesh_fill_color(color)
-    ///
-    ///     return True
-    /// ```
-    ///
-    /// # See Also
-    /// - `mesh_fill_color()` - Get current fill color
-    /// - `set_mesh_image_path()` - Set texture image
-    /// - `Color` - Color class with creation methods
-    fn set_mesh_fill_color(&mut self, color: Option<PyColor>) {
-        let color_inner = color.map(|c| c.inner);
-        let updated_mesh = {
-            let mesh = self.ensure_mesh_component();
-            mesh.set_fill_color(color_inner);
-            mesh.clone()
-        };
+    /// Update the active camera world position via command queue.
+    fn set_camera_position(&self, position: PyVec2) {
+        let _ = self.sender.send(EngineCommand::SetCameraPosition {
+            position: position.inner,
+        });
+    }
 
-        // Update runtime object if it exists
-        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
-            let _ = binding.sender.send(EngineCommand::SetGameObjectMeshComponent {
-                object_id: binding.object_id,
-                mesh: updated_mesh,
-            });
+    /// Update the active camera viewport size in world units via command queue.
+    fn set_camera_viewport_size(&self, width: f32, height: f32) {
+        let _ = self
+            .sender
+            .send(EngineCommand::SetCameraViewportSize { width, height });
+    }
+
+    /// Update camera aspect handling mode via command queue.
+    fn set_camera_aspect_mode(&self, mode: &str) {
+        if let Some(parsed) = parse_camera_aspect_mode(mode) {
+            let _ = self
+                .sender
+                .send(EngineCommand::SetCameraAspectMode { mode: parsed });
         }
     }
 
-    /// Get the fill color of the mesh.
-    ///
-    /// Returns the mesh's solid fill color, or `None` if no color is set or
-    /// no mesh exists.
-    ///
-    /// # Returns
-    /// - `Some(Color)` - The mesh fill color
-    /// - `None` - No color set or no mesh component
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// obj = pyg.GameObject("Sprite")
-    /// obj.set_mesh_fill_color(pyg.Color.BLUE)
-    ///
-    /// # Get current color
-    /// color = obj.mesh_fill_color()
-    /// if color:
-    ///     print(f"Fill color: {color}")
-    /// ```
-    ///
-    /// # See Also
-    /// - `set_mesh_fill_color()` - Set the fill color
-    fn mesh_fill_color(&self) -> Option<PyColor> {
-        self.inner
-            .mesh_component()
-            .and_then(|mesh| mesh.fill_color().copied())
-            .map(|inner| PyColor { inner })
+    /// Update the active camera background clear color via command queue.
+    fn set_camera_background_color(&self, color: &PyColor) {
+        let _ = self
+            .sender
+            .send(EngineCommand::SetCameraBackgroundColor { color: color.inner });
     }
 
-    /// Set the image/texture path for the mesh.
-    ///
-    /// Loads and applies an image texture to the mesh. If no mesh component exists,
-    /// one is created automatically. Pass `None` to remove the texture (use color only).
-    ///
-    /// # Arguments
-    /// * `image_path` - Path to image file (PNG, JPG, etc.), or `None` to remove
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// sprite = pyg.GameObject("Sprite")
-    /// sprite.set_mesh_geometry_rectangle(64.0, 64.0)
-    ///
-    /// # Load texture
-    /// sprite.set_mesh_image_path("assets/player.png")
-    ///
-    /// # Change texture
-    /// sprite.set_mesh_image_path("assets/enemy.png")
-    ///
-    /// # Remove texture (use color instead)
-    /// sprite.set_mesh_image_path(None)
-    /// sprite.set_mesh_fill_color(pyg.Color.BLUE)
-    /// ```
-    ///
-    /// # Sprite Animation
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// class AnimatedSprite:
-    ///     def __init__(self, obj, frames):
-    ///         self.obj = obj
-    ///         self.frames = frames  # ["frame0.png", "frame1.png", ...]
-    ///         self.current_frame = 0
-    ///         self.frame_time = 0.0
-    ///         self.frame_duration = 0.1  # seconds per frame
-    ///
-    ///     def update(self, dt):
-    ///         self.frame_time += dt
-    ///         if self.frame_time >= self.frame_duration:
-    ///             self.frame_time = 0.0
-    ///             self.current_frame = (self.current_frame + 1) % len(self.frames)
-    ///             self.obj.set_mesh_image_path(self.frames[self.current_frame])
-    /// ```
-    ///
-    /// # See Also
-    /// - `mesh_image_path()` - Get current image path
-    /// - `set_mesh_fill_color()` - Set solid color
-    fn set_mesh_image_path(&mut self, image_path: Option<String>) {
-        let mesh = self.ensure_mesh_component();
-        mesh.set_image_path(image_path);
-        self.sync_runtime_mesh_component();
+    /// Clear all immediate-mode draw commands via command queue.
+    fn clear_draw_commands(&self) {
+        let _ = self.sender.send(EngineCommand::ClearDrawCommands);
     }
 
-    /// Get the image/texture path of the mesh.
-    ///
-    /// Returns the path to the mesh's texture image, or `None` if no image is set
-    /// or no mesh exists.
-    ///
-    /// # Returns
-    /// - `Some(String)` - Path to the image file
-    /// - `None` - No image set or no mesh component
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// sprite = pyg.GameObject("Sprite")
-    /// sprite.set_mesh_image_path("assets/player.png")
-    ///
-    /// # Get current texture path
-    /// path = sprite.mesh_image_path()
-    /// if path:
-    ///     print(f"Current texture: {path}")
-    /// ```
-    ///
-    /// # See Also
-    /// - `set_mesh_image_path()` - Set the image path
-    fn mesh_image_path(&self) -> Option<String> {
-        self.inner
-            .mesh_component()
-            .and_then(|mesh| mesh.image_path().map(|path| path.to_string()))
+    /// Submit many draw commands via command queue in one call.
+    fn add_draw_commands(&self, py: Python<'_>, commands: Vec<Py<PyDrawCommand>>) {
+        let runtime_commands: Vec<DrawCommand> = commands
+            .into_iter()
+            .map(|command| command.borrow(py).inner.clone())
+            .collect();
+        let _ = self
+            .sender
+            .send(EngineCommand::AddDrawCommands(runtime_commands));
     }
 
-    /// Set the visibility of the mesh.
-    ///
-    /// Controls whether the mesh is rendered. If no mesh component exists, one is
-    /// created automatically.
-    ///
-    /// # Arguments
-    /// * `visible` - `True` to show, `False` to hide
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// obj = pyg.GameObject("Sprite")
-    /// obj.set_mesh_geometry_rectangle(64.0, 64.0)
-    /// obj.set_mesh_fill_color(pyg.Color.RED)
-    ///
-    /// # Hide mesh
-    /// obj.set_mesh_visible(False)
-    ///
-    /// # Show mesh
-    /// obj.set_mesh_visible(True)
-    /// ```
-    ///
-    /// # Blinking Effect
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// def update(dt, engine, data):
-    ///     data['blink_timer'] += dt
-    ///
-    ///     # Blink every 0.5 seconds
-    ///     if data['blink_timer'] >= 0.5:
-    ///         data['blink_timer'] = 0.0
-    ///         current = data['obj'].mesh_visible()
-    ///         data['obj'].set_mesh_visible(not current)
-    ///
-    ///     return True
-    ///
-    /// engine.run(update=update, user_data={'blink_timer': 0.0, 'obj': obj})
-    /// ```
-    ///
-    /// # See Also
-    /// - `mesh_visible()` - Get visibility state
-    /// - `GameObject.active` - Control entire object update/render
-    fn set_mesh_visible(&mut self, visible: bool) {
-        let mesh = self.ensure_mesh_component();
-        mesh.set_visible(visible);
-        self.sync_runtime_mesh_component();
+    /// Create a `DrawRecorder` that submits via command queue in one batch.
+    #[pyo3(signature = (capacity=1024))]
+    fn draw_recorder(&self, capacity: usize) -> PyDrawRecorder {
+        PyDrawRecorder::new(self.sender.clone(), capacity)
     }
 
-    /// Get the visibility state of the mesh.
-    ///
-    /// Returns whether the mesh is currently visible, or `None` if no mesh exists.
-    ///
-    /// # Returns
-    /// - `Some(True)` - Mesh is visible
-    /// - `Some(False)` - Mesh is hidden
-    /// - `None` - No mesh component exists
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// obj = pyg.GameObject("Sprite")
-    /// obj.set_mesh_visible(False)
-    ///
-    /// # Check visibility
-    /// visible = obj.mesh_visible()
-    /// if visible == False:
-    ///     print("Mesh is hidden")
-    /// ```
-    ///
-    /// # See Also
-    /// - `set_mesh_visible()` - Set visibility
-    fn mesh_visible(&self) -> Option<bool> {
-        self.inner.mesh_component().map(|mesh| mesh.visible())
+    /// Draw a pixel at window coordinates via command queue.
+    #[pyo3(signature = (x, y, color, draw_order=0.0))]
+    fn draw_pixel(&self, x: u32, y: u32, color: &PyColor, draw_order: f32) {
+        let _ = self.sender.send(EngineCommand::DrawPixel {
+            x,
+            y,
+            color: color.inner,
+            draw_order,
+        });
     }
 
-    /// Set the draw order (z-index) of the mesh.
-    ///
-    /// Controls rendering order. Higher values render on top of lower values.
-    /// If no mesh component exists, one is created automatically.
-    ///
-    /// # Arguments
-    /// * `draw_order` - Rendering layer (higher = on top)
-    ///   - Typical range: -10.0 to 10.0
-    ///   - Default: 0.0
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// # Background layer
-    /// background = pyg.GameObject("Background")
-    /// background.set_mesh_draw_order(-5.0)
-    ///
-    /// # Game objects (default layer)
-    /// player = pyg.GameObject("Player")
-    /// player.set_mesh_draw_order(0.0)
-    ///
-    /// # UI elements (foreground)
-    /// ui_panel = pyg.GameObject("UI")
-    /// ui_panel.set_mesh_draw_order(10.0)
-    /// ```
-    ///
-    /// # Layering Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// # Create layers from back to front
-    /// layers = [
-    ///     ("Sky", -10.0),
-    ///     ("Background", -5.0),
-    ///     ("Terrain", -2.0),
-    ///     ("Player", 0.0),
-    ///     ("Effects", 2.0),
-    ///     ("UI", 10.0),
-    /// ]
-    ///
-    /// for name, depth in layers:
-    ///     obj = pyg.GameObject(name)
-    ///     obj.set_mesh_geometry_rectangle(800.0, 600.0)
-    ///     obj.set_mesh_draw_order(depth)
-    ///     engine.add_game_object(obj)
-    /// ```
-    ///
-    /// # See Also
-    /// - `mesh_draw_order()` - Get current draw order
-    /// - `DrawCommand` - Similar draw order for immediate drawing
-    fn set_mesh_draw_order(&mut self, draw_order: f32) {
-        let mesh = self.ensure_mesh_component();
-        mesh.set_draw_order(draw_order);
-        self.sync_runtime_mesh_component();
+    /// Draw a line at window coordinates via command queue.
+    #[pyo3(signature = (start_x, start_y, end_x, end_y, color, thickness=1.0, draw_order=0.0))]
+    fn draw_line(
+        &self,
+        start_x: f32,
+        start_y: f32,
+        end_x: f32,
+        end_y: f32,
+        color: &PyColor,
+        thickness: f32,
+        draw_order: f32,
+    ) {
+        let _ = self.sender.send(EngineCommand::DrawLine {
+            start_x,
+            start_y,
+            end_x,
+            end_y,
+            thickness,
+            color: color.inner,
+            draw_order,
+        });
     }
 
-    /// Get the draw order (z-index) of the mesh.
-    ///
-    /// Returns the rendering layer of the mesh, or `None` if no mesh exists.
-    ///
-    /// # Returns
-    /// - `Some(f32)` - The draw order value
-    /// - `None` - No mesh component exists
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// obj = pyg.GameObject("Sprite")
-    /// obj.set_mesh_draw_order(5.0)
-    ///
-    /// # Get draw order
-    /// order = obj.mesh_draw_order()
-    /// if order:
-    ///     print(f"Draw order: {order}")
-    /// ```
-    ///
-    /// # See Also
-    /// - `set_mesh_draw_order()` - Set the draw order
-    fn mesh_draw_order(&self) -> Option<f32> {
-        self.inner.mesh_component().map(|mesh| mesh.draw_order())
+    /// Draw a rectangle at window coordinates via command queue.
+    #[pyo3(signature = (x, y, width, height, color, filled=true, thickness=1.0, draw_order=0.0))]
+    fn draw_rectangle(
+        &self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        color: &PyColor,
+        filled: bool,
+        thickness: f32,
+        draw_order: f32,
+    ) {
+        let _ = self.sender.send(EngineCommand::DrawRectangle {
+            x,
+            y,
+            width,
+            height,
+            color: color.inner,
+            filled,
+            thickness,
+            draw_order,
+        });
     }
 
-    /// Set the object type for specialized rendering or behavior.
-    ///
-    /// Marks the object as a specific type, which affects how the engine handles it.
-    /// Most projects only need the default `"GameObject"` type plus `"UIObject"` for
-    /// objects that carry UI components.
-    ///
-    /// # Arguments
-    /// * `object_type` - Type string (case-sensitive):
-    ///   - `"UIObject"` - **UI element** (rendered in screen space, ignores camera)
-    ///   - `"GameObject"` - **Standard object** (world space, default)
-    ///   - `"ParticleSystem"` - Particle system (reserved for future use)
-    ///   - `"Sound"` - Audio source (reserved for future use)
-    ///   - `"Light"` - Light source (reserved for future use)
-    ///   - `"Camera"` - Camera object (reserved for future use)
-    ///
-    /// # UI Objects
-    ///
-    /// **UIObject** is intended for objects that use UI components such as buttons,
-    /// panels, and labels. Those components render in screen space and ignore the
-    /// world camera.
-    ///
-    /// # Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// # Standard world-space object
-    /// player = pyg.GameObject("Player")
-    /// player.set_object_type("GameObject")  # Default, usually not needed
-    ///
-    /// # UI button (screen space)
-    /// button = pyg.GameObject("PlayButton")
-    /// button.set_object_type("UIObject")  # Renders in screen space
-    /// ```
-    ///
-    /// # UI Button Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// engine = pyg.Engine()
-    ///
-    /// # Create UI button object
-    /// button_obj = pyg.GameObject("Button")
-    /// button_obj.set_object_type("UIObject")  # Mark as UI
-    ///
-    /// # Add button component
-    /// button = pyg.ButtonComponent("PlayButton", 100, 100, 200, 50)
-    /// button.set_text("Play Game")
-    /// button.set_on_click(lambda: print("Clicked!"))
-    /// button_obj.add_component(button)
-    ///
-    /// engine.add_game_object(button_obj)
-    /// engine.run(title="UI Demo")
-    /// ```
-    ///
-    /// # Preferred High-Level UI API
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// engine = pyg.Engine()
-    /// panel = pyg.Panel(x=20, y=20, width=220, height=120)
-    /// button = pyg.Button("Play", x=16, y=16, width=120, height=40)
-    /// panel.add_child(button)
-    /// engine.ui.add(panel)
-    /// ```
-    ///
-    /// # See Also
-    /// - `examples/ui_demo.py` - Complete UI system example
-    /// - `ButtonComponent`, `PanelComponent`, `LabelComponent` - UI components
-    fn set_object_type(&mut self, object_type: &str) {
-        use crate::core::game_object::ObjectType;
-        let obj_type = match object_type {
-            "UIObject" => ObjectType::UIObject,
-            "ParticleSystem" => ObjectType::ParticleSystem,
-            "Sound" => ObjectType::Sound,
-            "Light" => ObjectType::Light,
-            "Camera" => ObjectType::Camera,
-            _ => ObjectType::GameObject,
-        };
-        self.inner.set_object_type(obj_type);
-    }
-
-    /// Add a component to this GameObject.
-    ///
-    /// This is the canonical attachment API for runtime components. It accepts mesh,
-    /// transform, collider, and UI component types. If you attach a UI component, the
-    /// object should typically be marked as `"UIObject"` for screen-space rendering.
-    ///
-    /// # Supported Components
-    /// - `MeshComponent` - 2D rendering
-    /// - `TransformComponent` - Replace the object's local transform
-    /// - `Collider` - Collision / trigger behavior
-    /// - `ButtonComponent` - Clickable button with callback
-    /// - `PanelComponent` - Rectangular UI container/background
-    /// - `LabelComponent` - Text label for UI
-    ///
-    /// # Arguments
-    /// * `component` - The component to attach
-    ///
-    /// # Returns
-    /// - `Ok(())` - Component added successfully
-    /// - `Err(TypeError)` - Invalid component type
-    ///
-    /// # World Component Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// player = pyg.GameObject("Player")
-    /// mesh = pyg.MeshComponent()
-    /// mesh.set_geometry_rectangle(64.0, 64.0)
-    /// mesh.set_fill_color(pyg.Color.BLUE)
-    /// player.add_component(mesh)
-    ///
-    /// collider = pyg.Collider("PlayerCollider")
-    /// player.add_component(collider)
-    /// ```
-    ///
-    /// # UI Component Example
-    /// ```python
-    /// import pyg_engine as pyg
-    ///
-    /// engine = pyg.Engine()
-    ///
-    /// # Panel background
-    /// panel_obj = pyg.GameObject("Panel")
-    /// panel_obj.set_object_type("UIObject")
-    /// panel = pyg.PanelComponent("Background", 50, 50, 300, 200)
-    /// panel.set_background_color(pyg.Color.rgba(50, 50, 50, 200))
-    /// panel_obj.add_component(panel)
-    /// engine.add_game_object(panel_obj)
-    ///
-    /// # Title label
-    /// label_obj = pyg.GameObject("Title")
-    /// label_obj.set_object_type("UIObject")
-    /// label = pyg.LabelComponent("Game Menu", 100, 70, 24.0)
-    /// label.set_color(1.0, 1.0, 1.0, 1.0)
-    /// label_obj.add_component(label)
-    /// engine.add_game_object(label_obj)
-    ///
-    /// # Play button
-    /// button_obj = pyg.GameObject("PlayButton")
-    /// button_obj.set_object_type("UIObject")
-    /// button = pyg.ButtonComponent("Play", 100, 120, 150, 40)
-    /// button.set_text("Play Game")
-    /// button.set_on_click(lambda: print("Starting game..."))
-    /// button_obj.add_component(button)
-    /// engine.add_game_object(button_obj)
-    ///
-    /// engine.run(title="UI Example")
-    /// ```
-    ///
-    /// # Multiple Components (Not Recommended)
-    /// ```python
-    /// # While technically possible, avoid adding multiple components of the same type
-    /// # to a single GameObject. Instead, create separate GameObjects for each UI element.
-    ///
-    /// # ❌ DON'T: Multiple buttons on one object
-    /// obj = pyg.GameObject("Buttons")
-    /// obj.add_component(button1)
-    /// obj.add_component(button2)  # Replaces button1!
-    ///
-    /// # ✅ DO: Separate objects for each button
-    /// obj1 = pyg.GameObject("Button1")
-    /// obj1.add_component(button1)
-    ///
-    /// obj2 = pyg.GameObject("Button2")
-    /// obj2.add_component(button2)
-    /// ```
-    ///
-    /// # Errors
-    /// Raises `TypeError` if the component is not a supported runtime component type:
-    /// ```python
-    /// obj.add_component(mesh)      # OK
-    /// obj.add_component(collider)  # OK
-    /// obj.add_component(button)    # OK
-    /// ```
-    ///
-    /// # See Also
-    /// - `set_object_type()` - Mark object as UIObject
-    /// - `ButtonComponent` - Clickable button
-    /// - `PanelComponent` - UI panel/background
-    /// - `LabelComponent` - Text label
-    /// - `examples/ui_demo.py` - Complete UI examples
-    fn add_component(&mut self, component: &Bound<'_, PyAny>) -> PyResult<()> {
-        let component_box: Box<dyn ComponentTrait> =
-            if let Ok(button) = component.extract::<PyRef<PyButtonComponent>>() {
-                Box::new(button.inner.clone())
-            } else if let Ok(panel) = component.extract::<PyRef<PyPanelComponent>>() {
-                Box::new(panel.inner.clone())
-            } else if let Ok(label) = component.extract::<PyRef<PyLabelComponent>>() {
-                Box::new(label.inner.clone())
-            } else if let Ok(mesh) = component.extract::<PyRef<PyMeshComponent>>() {
-                Box::new(mesh.inner.clone())
-            } else if let Ok(text_mesh) = component.extract::<PyRef<PyTextMeshComponent>>() {
-                Box::new(text_mesh.inner.clone())
-            } else if let Ok(transform) = component.extract::<PyRef<PyTransformComponent>>() {
-                Box::new(transform.inner.clone())
-            } else if let Ok(collider) = component.extract::<PyRef<PyCollider>>() {
-                Box::new(collider.component.clone())
-            } else {
-                return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
-                    "Component must be MeshComponent, TextMeshComponent, TransformComponent, ButtonComponent, PanelComponent, LabelComponent, or Collider",
-                ));
-            };
-
-        let runtime_component = component_box.clone();
-        self.inner.add_component(component_box);
-        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
-            let _ = binding.sender.send(EngineCommand::AddComponent {
-                object_id: binding.object_id,
-                component: runtime_component,
-            });
-        }
-        Ok(())
-    }
-
-    fn get_component_name(&self, py: Python<'_>, name: &str) -> PyResult<Option<Py<PyAny>>> {
-        let object = self.current_object();
-        object
-            .get_component_by_name(name)
-            .map(|component| component_to_pyobject(py, component, self.component_binding(component)))
-            .transpose()
-    }
-
-    fn get_component_id(&self, py: Python<'_>, component_id: u32) -> PyResult<Option<Py<PyAny>>> {
-        let object = self.current_object();
-        object
-            .get_component_by_id(component_id)
-            .map(|component| component_to_pyobject(py, component, self.component_binding(component)))
-            .transpose()
+    /// Draw a circle at window coordinates via command queue.
+    #[pyo3(signature = (
+        center_x,
+        center_y,
+        radius,
+        color,
+        filled=true,
+        thickness=1.0,
+        segments=32,
+        draw_order=0.0
+    ))]
+    fn draw_circle(
+        &self,
+        center_x: f32,
+        center_y: f32,
+        radius: f32,
+        color: &PyColor,
+        filled: bool,
+        thickness: f32,
+        segments: u32,
+        draw_order: f32,
+    ) {
+        let _ = self.sender.send(EngineCommand::DrawCircle {
+            center_x,
+            center_y,
+            radius,
+            color: color.inner,
+            filled,
+            thickness,
+            segments,
+            draw_order,
+        });
     }
 
-    fn get_component_type(
+    /// Draw a gradient rectangle with per-corner colors via command queue.
+    #[pyo3(signature = (
+        x,
+        y,
+        width,
+        height,
+        top_left,
+        bottom_left,
+        bottom_right,
+        top_right,
+        draw_order=0.0
+    ))]
+    fn draw_gradient_rect(
         &self,
-        py: Python<'_>,
-        component_type: &str,
-    ) -> PyResult<Option<Py<PyAny>>> {
-        let object = self.current_object();
-        for component in object.all_components() {
-            if component.component_type() == component_type {
-                return component_to_pyobject(py, component, self.component_binding(component))
-                    .map(Some);
-            }
-        }
-        Ok(None)
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        top_left: &PyColor,
+        bottom_left: &PyColor,
+        bottom_right: &PyColor,
+        top_right: &PyColor,
+        draw_order: f32,
+    ) {
+        let _ = self.sender.send(EngineCommand::DrawGradientRect {
+            x,
+            y,
+            width,
+            height,
+            top_left: top_left.inner,
+            bottom_left: bottom_left.inner,
+            bottom_right: bottom_right.inner,
+            top_right: top_right.inner,
+            draw_order,
+        });
     }
 
-    fn get_components_type(
+    /// Draw an image from a filesystem path via command queue.
+    #[pyo3(signature = (x, y, width, height, texture_path, draw_order=0.0))]
+    fn draw_image(
         &self,
-        py: Python<'_>,
-        component_type: &str,
-    ) -> PyResult<Vec<Py<PyAny>>> {
-        let object = self.current_object();
-        object
-            .all_components()
-            .into_iter()
-            .filter(|component| component.component_type() == component_type)
-            .map(|component| component_to_pyobject(py, component, self.component_binding(component)))
-            .collect()
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        texture_path: String,
+        draw_order: f32,
+    ) {
+        let _ = self.sender.send(EngineCommand::DrawImage {
+            x,
+            y,
+            width,
+            height,
+            texture_path,
+            draw_order,
+        });
     }
 
-    #[pyo3(name = "get_component")]
-    fn py_get_component(
+    /// Draw an image from raw RGBA bytes via command queue.
+    #[pyo3(signature = (
+        x,
+        y,
+        width,
+        height,
+        texture_key,
+        rgba,
+        texture_width,
+        texture_height,
+        draw_order=0.0
+    ))]
+    fn draw_image_from_bytes(
         &self,
-        py: Python<'_>,
-        component_type: &Bound<'_, PyAny>,
-    ) -> PyResult<Option<Py<PyAny>>> {
-        let component_type_name = component_type_name_from_py(component_type)?;
-        self.get_component_type(py, &component_type_name)
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        texture_key: String,
+        rgba: &Bound<'_, PyAny>,
+        texture_width: u32,
+        texture_height: u32,
+        draw_order: f32,
+    ) -> PyResult<()> {
+        let _ = self.sender.send(EngineCommand::DrawImageBytes {
+            x,
+            y,
+            width,
+            height,
+            texture_key,
+            rgba: extract_rgba(rgba)?,
+            texture_width,
+            texture_height,
+            draw_order,
+        });
+        Ok(())
     }
 
-    #[pyo3(name = "get_components")]
-    fn py_get_components(
+    /// Draw text in window coordinates via command queue.
+    #[pyo3(signature = (
+        text,
+        x,
+        y,
+        color,
+        font_size=24.0,
+        font_path=None,
+        font_family=None,
+        font_weight=None,
+        font_style=None,
+        letter_spacing=0.0,
+        line_spacing=0.0,
+        kerning=true,
+        draw_order=0.0
+    ))]
+    fn draw_text(
         &self,
-        py: Python<'_>,
-        component_type: &Bound<'_, PyAny>,
-    ) -> PyResult<Vec<Py<PyAny>>> {
-        let component_type_name = component_type_name_from_py(component_type)?;
-        self.get_components_type(py, &component_type_name)
+        text: String,
+        x: f32,
+        y: f32,
+        color: &PyColor,
+        font_size: f32,
+        font_path: Option<String>,
+        font_family: Option<String>,
+        font_weight: Option<&str>,
+        font_style: Option<&str>,
+        letter_spacing: f32,
+        line_spacing: f32,
+        kerning: bool,
+        draw_order: f32,
+    ) -> PyResult<()> {
+        let style = build_text_style(
+            font_size,
+            font_path,
+            font_family,
+            font_weight,
+            font_style,
+            letter_spacing,
+            line_spacing,
+            kerning,
+        )?;
+        let _ = self.sender.send(EngineCommand::DrawText {
+            text,
+            x,
+            y,
+            style,
+            color: color.inner,
+            layout: TextLayoutOptions::default(),
+            draw_order,
+        });
+        Ok(())
     }
 
-    fn remove_component_name(&mut self, name: &str) -> bool {
-        let removed = self.inner.remove_component_by_name(name).is_some();
-        if removed && let Some(binding) = self.runtime_binding.borrow().as_ref() {
-            let _ = binding.sender.send(EngineCommand::RemoveComponentByName {
-                object_id: binding.object_id,
-                name: name.to_string(),
-            });
-        }
-        removed
+    /// Draw text centered on a world-space point via command queue.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        text,
+        x,
+        y,
+        color,
+        font_size=24.0,
+        font_path=None,
+        font_family=None,
+        font_weight=None,
+        font_style=None,
+        draw_order=0.0
+    ))]
+    fn draw_world_text(
+        &self,
+        text: String,
+        x: f32,
+        y: f32,
+        color: &PyColor,
+        font_size: f32,
+        font_path: Option<String>,
+        font_family: Option<String>,
+        font_weight: Option<&str>,
+        font_style: Option<&str>,
+        draw_order: f32,
+    ) -> PyResult<()> {
+        let style = build_text_style(font_size, font_path, font_family, font_weight, font_style, 0.0, 0.0, true)?;
+        let _ = self.sender.send(EngineCommand::AddDrawCommand(DrawCommand::WorldText {
+            text,
+            x,
+            y,
+            style,
+            color: color.inner,
+            draw_order,
+        }));
+        Ok(())
     }
 
-    fn remove_component_id(&mut self, component_id: u32) -> bool {
-        let removed = self.inner.remove_component_by_id(component_id).is_some();
-        if removed && let Some(binding) = self.runtime_binding.borrow().as_ref() {
-            let _ = binding.sender.send(EngineCommand::RemoveComponentById {
-                object_id: binding.object_id,
-                component_id,
-            });
-        }
-        removed
+    #[pyo3(signature = (
+        family,
+        regular=None,
+        bold=None,
+        italic=None,
+        bold_italic=None
+    ))]
+    fn register_font_family(
+        &self,
+        family: String,
+        regular: Option<String>,
+        bold: Option<String>,
+        italic: Option<String>,
+        bold_italic: Option<String>,
+    ) {
+        let _ = self.sender.send(EngineCommand::RegisterFontFamily {
+            family,
+            definition: FontFamilyDefinition::new(regular, bold, italic, bold_italic),
+        });
     }
 
-    fn remove_component(&mut self, component: &Bound<'_, PyAny>) -> PyResult<bool> {
-        let component_id = component_id_from_py(component)?;
-        Ok(self.remove_component_id(component_id))
+    /// Set the renderer's default fallback fonts via command queue.
+    fn set_font_fallbacks(&self, fallbacks: Vec<String>) {
+        let _ = self.sender.send(EngineCommand::SetFontFallbacks(fallbacks));
     }
-}
 
-// ========== MeshGeometry Bindings ==========
+    /// Set the UI theme's fallback fonts via command queue.
+    fn set_ui_font_fallbacks(&self, fallbacks: Vec<String>) {
+        let _ = self.sender.send(EngineCommand::SetUIFontFallbacks(fallbacks));
+    }
 
-#[pyclass(name = "MeshGeometry")]
-#[derive(Clone)]
-pub struct PyMeshGeometry {
-    inner: MeshGeometry,
-}
+    /// Switch the UI to a registered theme via command queue.
+    fn set_ui_theme(&self, name: String) {
+        let _ = self.sender.send(EngineCommand::SetUITheme(name));
+    }
 
-#[pymethods]
-impl PyMeshGeometry {
-    #[new]
-    #[pyo3(signature = (vertices=None, indices=None, uvs=None))]
-    fn new(
-        vertices: Option<Vec<(f32, f32)>>,
-        indices: Option<Vec<u32>>,
-        uvs: Option<Vec<(f32, f32)>>,
-    ) -> PyResult<Self> {
-        let vertices = vertices.unwrap_or_default();
-        let indices = indices.unwrap_or_default();
-        let uvs = uvs.unwrap_or_else(|| vec![(0.0, 0.0); vertices.len()]);
+    /// Set the UI scale mode via command queue.
+    #[pyo3(signature = (mode, reference_width=1920.0, reference_height=1080.0))]
+    fn set_ui_scale_mode(&self, mode: &str, reference_width: f32, reference_height: f32) -> PyResult<()> {
+        let scale_mode = parse_ui_scale_mode(mode, reference_width, reference_height)?;
+        let _ = self.sender.send(EngineCommand::SetUIScaleMode(scale_mode));
+        Ok(())
+    }
 
-        if uvs.len() != vertices.len() {
-            return Err(PyRuntimeError::new_err(
-                "uvs must have the same length as vertices",
-            ));
-        }
+    /// Focus a UI widget, or clear focus, via command queue.
+    #[pyo3(signature = (object_id=None))]
+    fn set_ui_focus(&self, object_id: Option<u32>) {
+        let _ = self.sender.send(EngineCommand::SetUIFocus(object_id));
+    }
 
-        let mesh_vertices = vertices
-            .into_iter()
-            .zip(uvs)
-            .map(|((x, y), (u, v))| {
-                crate::core::component::MeshVertex::new(Vec2::new(x, y), Vec2::new(u, v))
-            })
-            .collect();
+    /// Set whether a UI element takes input via command queue.
+    fn set_ui_interactable(&self, object_id: u32, interactable: bool) {
+        let _ = self.sender.send(EngineCommand::SetUIInteractable { object_id, interactable });
+    }
 
-        Ok(Self {
-            inner: MeshGeometry::new(mesh_vertices, indices),
-        })
+    /// Text on the system clipboard. The clipboard is shared, so this reads it
+    /// directly rather than through the command queue.
+    fn clipboard_get(&self) -> PyResult<String> {
+        clipboard::get_text().map_err(PyRuntimeError::new_err)
     }
 
-    #[staticmethod]
-    fn rectangle(width: f32, height: f32) -> Self {
-        Self {
-            inner: MeshGeometry::rectangle(width, height),
-        }
+    /// Put text on the system clipboard directly.
+    fn clipboard_set(&self, text: &str) -> PyResult<()> {
+        clipboard::set_text(text).map_err(PyRuntimeError::new_err)
     }
 
-    #[staticmethod]
-    #[pyo3(signature = (radius, segments=32))]
-    fn circle(radius: f32, segments: u32) -> Self {
-        Self {
-            inner: MeshGeometry::circle(radius, segments),
-        }
+    /// Set the cursor shown while hovering a UI element via command queue.
+    #[pyo3(signature = (object_id, cursor=None))]
+    fn set_ui_hover_cursor(&self, object_id: u32, cursor: Option<&str>) -> PyResult<()> {
+        let cursor = cursor.map(parse_cursor_icon).transpose()?;
+        let _ = self.sender.send(EngineCommand::SetUIHoverCursor { object_id, cursor });
+        Ok(())
     }
 
-    fn vertices(&self) -> Vec<(f32, f32)> {
-        self.inner
-            .vertices()
-            .iter()
-            .map(|vertex| (vertex.position().x(), vertex.position().y()))
-            .collect()
+    /// Set the input context a UI element takes input in via command queue.
+    #[pyo3(signature = (object_id, context=None))]
+    fn set_ui_input_context(&self, object_id: u32, context: Option<String>) {
+        let _ = self.sender.send(EngineCommand::SetUIInputContext { object_id, context });
     }
 
-    fn uvs(&self) -> Vec<(f32, f32)> {
-        self.inner
-            .vertices()
-            .iter()
-            .map(|vertex| (vertex.uv().x(), vertex.uv().y()))
-            .collect()
+    /// Set the focus ring via command queue.
+    #[pyo3(signature = (width=2.0, offset=2.0, corner_radius=4.0))]
+    fn set_ui_focus_ring(&self, width: f32, offset: f32, corner_radius: f32) {
+        let _ = self.sender.send(EngineCommand::SetUIFocusRing(FocusRing {
+            width: width.max(0.0),
+            offset,
+            corner_radius: corner_radius.max(0.0),
+        }));
     }
 
-    fn indices(&self) -> Vec<u32> {
-        self.inner.indices().to_vec()
+    /// Start a UI property tween via command queue. Returns the tween id.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (object_id, property, to, duration, easing="linear", delay=0.0, from_=None))]
+    fn tween_ui(
+        &self,
+        object_id: u32,
+        property: &str,
+        to: Vec<f32>,
+        duration: f32,
+        easing: &str,
+        delay: f32,
+        from_: Option<Vec<f32>>,
+    ) -> PyResult<u32> {
+        let tween = build_ui_tween(object_id, property, &to, duration, easing, delay, from_)?;
+        let tween_id = tween.id();
+        let _ = self.sender.send(EngineCommand::StartUITween(tween));
+        Ok(tween_id)
     }
-}
 
-// ========== MeshComponent Bindings ==========
+    /// Stop a UI tween via command queue.
+    fn cancel_ui_tween(&self, tween_id: u32) {
+        let _ = self.sender.send(EngineCommand::CancelUITween(tween_id));
+    }
 
-/// Python wrapper for MeshComponent.
-/// 2D mesh rendering component for GameObjects.
-///
-/// `MeshComponent` handles rendering of 2D shapes and images. Attach it to a GameObject
-/// to make it visible. Supports:
-/// - **Geometry**: Rectangles, circles (custom geometry coming soon)
-/// - **Rendering**: Solid colors, images/textures
-/// - **Control**: Visibility, draw order (z-index)
-///
-/// # Basic Usage
-///
-/// ## Rectangle with Color
-/// ```python
-/// from pyg_engine import GameObject, MeshComponent, Color, Vec2
-///
-/// obj = GameObject("ColoredRect")
-/// obj.set_position(Vec2(100.0, 100.0))
-///
-/// mesh = MeshComponent()
-/// mesh.set_geometry_rectangle(64.0, 64.0)  # 64x64 square
-/// mesh.set_fill_color(Color.BLUE)
-/// obj.add_component(mesh)
-///
-/// engine.add_game_object(obj)
-/// ```
-///
-/// ## Circle with Image
-/// ```python
-/// from pyg_engine import GameObject, MeshComponent, Vec2
-///
-/// obj = GameObject("Sprite")
-/// obj.set_position(Vec2(200.0, 150.0))
-///
-/// mesh = MeshComponent()
-/// mesh.set_geometry_circle(32.0, segments=64)  # Radius 32, smooth circle
-/// mesh.set_image_path("assets/player.png")
-/// obj.add_component(mesh)
-///
-/// engine.add_game_object(obj)
-/// ```
-///
-/// # Draw Order
-///
-/// Control rendering order with `draw_order` (higher values draw on top):
-/// ```python
-/// background.draw_order = -5.0  # Draw behind
-/// player.draw_order = 0.0       # Default layer
-/// ui_element.draw_order = 10.0  # Draw in front
-/// ```
-///
-/// # Visibility
-///
-/// Toggle visibility without removing the object:
-/// ```python
-/// mesh.visible = False  # Hide
-/// mesh.visible = True   # Show
-/// ```
-///
-/// # See Also
-/// - `examples/python_mesh_demo.py` - Complete mesh examples
-/// - `examples/python_game_object_transform_demo.py` - Transform + rendering
-#[pyclass(name = "MeshComponent", unsendable)]
-#[derive(Clone)]
-pub struct PyMeshComponent {
-    inner: MeshComponent,
-    runtime_binding: RefCell<Option<ComponentRuntimeBinding>>,
-}
+    /// Update a UI label's text at runtime by object ID via command queue.
+    fn update_ui_label_text(&self, object_id: u32, text: String) {
+        let _ = self
+            .sender
+            .send(EngineCommand::UpdateUILabelText { object_id, text });
+    }
 
-impl PyMeshComponent {
-    fn sync_runtime(&self) {
-        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
-            let _ = binding.sender.send(EngineCommand::SetGameObjectMeshComponent {
-                object_id: binding.object_id,
-                mesh: self.inner.clone(),
-            });
-        }
+    /// Update a UI button's text at runtime by object ID via command queue.
+    fn update_ui_button_text(&self, object_id: u32, text: String) {
+        let _ = self
+            .sender
+            .send(EngineCommand::UpdateUIButtonText { object_id, text });
     }
-}
 
-#[pymethods]
-impl PyMeshComponent {
-    #[new]
-    #[pyo3(signature = (name=None))]
-    fn new(name: Option<String>) -> Self {
-        Self {
-            inner: MeshComponent::new(name.unwrap_or_else(|| "Mesh Renderer".to_string())),
-            runtime_binding: RefCell::new(None),
-        }
+    /// Update a UI text input's text at runtime by object ID via command queue.
+    fn update_ui_text_input_text(&self, object_id: u32, text: String) {
+        let _ = self
+            .sender
+            .send(EngineCommand::UpdateUITextInputText { object_id, text });
     }
 
-    #[getter]
-    fn name(&self) -> String {
-        self.inner.name().to_string()
+    /// Update a UI slider's value at runtime by object ID via command queue.
+    fn update_ui_slider_value(&self, object_id: u32, value: f32) {
+        let _ = self
+            .sender
+            .send(EngineCommand::UpdateUISliderValue { object_id, value });
     }
 
-    #[getter]
-    fn id(&self) -> u32 {
-        self.inner.id()
+    /// Replace a UI curve editor's curve at runtime by object ID via command queue.
+    fn update_ui_curve(&self, object_id: u32, curve: PyRef<PyAnimationCurve>) {
+        let _ = self.sender.send(EngineCommand::UpdateUICurve {
+            object_id,
+            curve: curve.inner.clone(),
+        });
     }
 
-    #[getter]
-    fn enabled(&self) -> bool {
-        self.inner.is_enabled_self()
+    /// Update a UI checkbox, toggle switch or radio button's checked state at runtime by object ID via command queue.
+    fn update_ui_checked(&self, object_id: u32, checked: bool) {
+        let _ = self
+            .sender
+            .send(EngineCommand::UpdateUIChecked { object_id, checked });
     }
 
-    #[setter(enabled)]
-    fn set_enabled_property(&mut self, enabled: bool) {
-        self.inner.set_enabled_self(enabled);
+    /// Update a UI scroll view's scroll offset at runtime by object ID via command queue.
+    ///
+    /// List views scroll vertically to `y` and ignore `x`.
+    fn update_ui_scroll_position(&self, object_id: u32, x: f32, y: f32) {
+        let _ = self
+            .sender
+            .send(EngineCommand::UpdateUIScrollPosition { object_id, x, y });
     }
 
-    fn geometry(&self) -> PyMeshGeometry {
-        PyMeshGeometry {
-            inner: self.inner.geometry().clone(),
-        }
+    /// Update a UI list view's item count at runtime by object ID via command queue.
+    fn update_ui_list_item_count(&self, object_id: u32, item_count: usize) {
+        let _ = self
+            .sender
+            .send(EngineCommand::UpdateUIListItemCount { object_id, item_count });
     }
 
-    fn set_geometry(&mut self, geometry: &PyMeshGeometry) {
-        self.inner.set_geometry(geometry.inner.clone());
-        self.sync_runtime();
+    /// Select a UI list view row (or clear the selection with `None`) at runtime by object ID via command queue.
+    #[pyo3(signature = (object_id, selected=None))]
+    fn update_ui_list_selection(&self, object_id: u32, selected: Option<usize>) {
+        let _ = self
+            .sender
+            .send(EngineCommand::UpdateUIListSelection { object_id, selected });
     }
 
-    fn set_geometry_rectangle(&mut self, width: f32, height: f32) {
-        self.set_geometry(&PyMeshGeometry::rectangle(width, height));
+    /// Fetch a UI list view's visible rows from its item provider again at runtime by object ID via command queue.
+    fn refresh_ui_list(&self, object_id: u32) {
+        let _ = self.sender.send(EngineCommand::RefreshUIList { object_id });
     }
 
-    #[pyo3(signature = (radius, segments=32))]
-    fn set_geometry_circle(&mut self, radius: f32, segments: u32) {
-        self.set_geometry(&PyMeshGeometry::circle(radius, segments));
+    /// Update a UI progress bar's value (0.0-1.0) at runtime by object ID via command queue.
+    fn update_ui_progress_value(&self, object_id: u32, value: f32) {
+        let _ = self
+            .sender
+            .send(EngineCommand::UpdateUIProgressValue { object_id, value });
     }
 
-    fn set_fill_color(&mut self, color: Option<PyColor>) {
-        self.inner.set_fill_color(color.map(|c| c.inner));
-        self.sync_runtime();
+    /// Turn clipping of a UI panel's children on or off at runtime by object ID via command queue.
+    fn update_ui_panel_clip(&self, object_id: u32, clip_children: bool) {
+        let _ = self
+            .sender
+            .send(EngineCommand::UpdateUIPanelClip { object_id, clip_children });
     }
 
-    fn fill_color(&self) -> Option<PyColor> {
-        self.inner
-            .fill_color()
-            .copied()
-            .map(|inner| PyColor { inner })
+    /// Update a UI image's texture at runtime by object ID via command queue (None hides it).
+    #[pyo3(signature = (object_id, texture_path=None))]
+    fn update_ui_image_texture(&self, object_id: u32, texture_path: Option<String>) {
+        let _ = self
+            .sender
+            .send(EngineCommand::UpdateUIImageTexture { object_id, texture_path });
     }
 
-    fn set_image_path(&mut self, image_path: Option<String>) {
-        self.inner.set_image_path(image_path);
-        self.sync_runtime();
+    /// Update a UI image's scale mode at runtime by object ID via command queue.
+    fn update_ui_image_scale_mode(&self, object_id: u32, scale_mode: &str) -> PyResult<()> {
+        let scale_mode = parse_image_scale_mode(scale_mode)?;
+        let _ = self
+            .sender
+            .send(EngineCommand::UpdateUIImageScaleMode { object_id, scale_mode });
+        Ok(())
     }
 
-    fn image_path(&self) -> Option<String> {
-        self.inner.image_path().map(|path| path.to_string())
+    /// Update a UI stack's direction, spacing, padding (left, right, top, bottom),
+    /// alignment and fit_content at runtime by object ID via command queue.
+    #[pyo3(signature = (object_id, direction="vertical", spacing=0.0, padding=(0.0, 0.0, 0.0, 0.0), alignment="start", fit_content=false))]
+    fn update_ui_stack(
+        &self,
+        object_id: u32,
+        direction: &str,
+        spacing: f32,
+        padding: (f32, f32, f32, f32),
+        alignment: &str,
+        fit_content: bool,
+    ) -> PyResult<()> {
+        let command = EngineCommand::UpdateUIStack {
+            object_id,
+            direction: parse_stack_direction(direction)?,
+            spacing,
+            padding: Padding::new(padding.0, padding.1, padding.2, padding.3),
+            alignment: parse_stack_alignment(alignment)?,
+            fit_content,
+        };
+        let _ = self.sender.send(command);
+        Ok(())
     }
 
-    #[getter]
-    fn visible(&self) -> bool {
-        self.inner.visible()
+    /// Anchor a UI object to its parent (or the window) via command queue.
+    #[pyo3(signature = (object_id, anchor="top_left", x=0.0, y=0.0, width=None, height=None, pivot=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn set_ui_layout(
+        &self,
+        object_id: u32,
+        anchor: &str,
+        x: f32,
+        y: f32,
+        width: Option<&Bound<'_, PyAny>>,
+        height: Option<&Bound<'_, PyAny>>,
+        pivot: Option<(f32, f32)>,
+    ) -> PyResult<()> {
+        let layout = build_ui_layout(anchor, x, y, width, height, pivot)?;
+        let _ = self.sender.send(EngineCommand::SetUILayout {
+            object_id,
+            layout: Some(layout),
+        });
+        Ok(())
     }
 
-    #[setter]
-    fn set_visible(&mut self, visible: bool) {
-        self.inner.set_visible(visible);
-        self.sync_runtime();
+    /// Remove a UI object's layout via command queue.
+    fn clear_ui_layout(&self, object_id: u32) {
+        let _ = self
+            .sender
+            .send(EngineCommand::SetUILayout { object_id, layout: None });
     }
 
-    #[getter]
-    fn draw_order(&self) -> f32 {
-        self.inner.draw_order()
+    /// Log a message at INFO level (default log method).
+    fn log(&self, message: &str) {
+        let _ = self.sender.send(EngineCommand::LogInfo(message.to_string()));
     }
 
-    #[setter]
-    fn set_draw_order(&mut self, draw_order: f32) {
-        self.inner.set_draw_order(draw_order);
-        self.sync_runtime();
+    /// Log a message at TRACE level (most verbose).
+    fn log_trace(&self, message: &str) {
+        let _ = self.sender.send(EngineCommand::LogTrace(message.to_string()));
     }
-}
 
-#[pyclass(name = "TextMeshComponent", unsendable)]
-#[derive(Clone)]
-pub struct PyTextMeshComponent {
-    inner: TextMeshComponent,
-    runtime_binding: RefCell<Option<ComponentRuntimeBinding>>,
-}
+    /// Log a message at DEBUG level.
+    fn log_debug(&self, message: &str) {
+        let _ = self.sender.send(EngineCommand::LogDebug(message.to_string()));
+    }
 
-impl PyTextMeshComponent {
-    fn sync_runtime(&self) {
-        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
-            let _ = binding.sender.send(EngineCommand::SetTextMeshComponent {
-                object_id: binding.object_id,
-                component_id: binding.component_id,
-                component: self.inner.clone(),
-            });
-        }
+    /// Log a message at INFO level.
+    fn log_info(&self, message: &str) {
+        let _ = self.sender.send(EngineCommand::LogInfo(message.to_string()));
+    }
+
+    /// Log a message at WARN level.
+    fn log_warn(&self, message: &str) {
+        let _ = self.sender.send(EngineCommand::LogWarn(message.to_string()));
+    }
+
+    /// Log a message at ERROR level.
+    fn log_error(&self, message: &str) {
+        let _ = self.sender.send(EngineCommand::LogError(message.to_string()));
     }
 }
 
-#[pymethods]
-impl PyTextMeshComponent {
-    #[new]
-    #[pyo3(signature = (
-        text="",
-        font_size=24.0,
-        name=None,
-        font_path=None,
-        font_family=None,
-        font_weight=None,
-        font_style=None,
-        letter_spacing=0.0,
-        line_spacing=0.0,
-        kerning=true
-    ))]
-    #[allow(clippy::too_many_arguments)]
-    fn new(
-        text: &str,
-        font_size: f32,
-        name: Option<String>,
-        font_path: Option<String>,
-        font_family: Option<String>,
-        font_weight: Option<&str>,
-        font_style: Option<&str>,
-        letter_spacing: f32,
-        line_spacing: f32,
-        kerning: bool,
-    ) -> PyResult<Self> {
-        let text_style = build_text_style(
-            font_size,
-            font_path,
-            font_family,
-            font_weight,
-            font_style,
-            letter_spacing,
-            line_spacing,
-            kerning,
-        )?;
-        let mut component =
-            TextMeshComponent::new(name.unwrap_or_else(|| "Text Mesh".to_string())).with_text(text);
-        component.set_text_style(text_style);
-        Ok(Self {
-            inner: component,
-            runtime_binding: RefCell::new(None),
-        })
-    }
-
-    #[getter]
-    fn name(&self) -> String {
-        self.inner.name().to_string()
-    }
-
-    #[getter]
-    fn id(&self) -> u32 {
-        self.inner.id()
-    }
-
-    #[getter]
-    fn text(&self) -> String {
-        self.inner.text().to_string()
-    }
-
-    #[setter]
-    fn set_text(&mut self, text: String) {
-        self.inner.set_text(text);
-        self.sync_runtime();
-    }
-
-    #[getter]
-    fn color(&self) -> PyColor {
-        PyColor {
-            inner: self.inner.color(),
-        }
-    }
-
-    #[setter]
-    fn set_color(&mut self, color: PyColor) {
-        self.inner.set_color(color.inner);
-        self.sync_runtime();
-    }
-
-    #[getter]
-    fn font_size(&self) -> f32 {
-        self.inner.font_size()
-    }
-
-    #[setter]
-    fn set_font_size(&mut self, font_size: f32) {
-        self.inner.set_font_size(font_size);
-        self.sync_runtime();
-    }
-
-    #[getter]
-    fn font_path(&self) -> Option<String> {
-        self.inner.font_path().map(|path| path.to_string())
-    }
-
-    #[setter]
-    fn set_font_path(&mut self, font_path: Option<String>) {
-        self.inner.set_font_path(font_path);
-        self.sync_runtime();
-    }
-
-    #[getter]
-    fn font_family(&self) -> Option<String> {
-        self.inner.font_family().map(|family| family.to_string())
-    }
-
-    #[setter]
-    fn set_font_family(&mut self, font_family: Option<String>) {
-        self.inner.set_font_family(font_family);
-        self.sync_runtime();
-    }
-
-    #[getter]
-    fn font_weight(&self) -> String {
-        self.inner.font_weight().as_str().to_string()
-    }
-
-    #[setter]
-    fn set_font_weight(&mut self, font_weight: &str) -> PyResult<()> {
-        self.inner.set_font_weight(parse_font_weight(Some(font_weight))?);
-        self.sync_runtime();
-        Ok(())
-    }
-
-    #[getter]
-    fn font_style(&self) -> String {
-        self.inner.font_style().as_str().to_string()
-    }
-
-    #[setter]
-    fn set_font_style(&mut self, font_style: &str) -> PyResult<()> {
-        self.inner.set_font_style(parse_font_style(Some(font_style))?);
-        self.sync_runtime();
-        Ok(())
-    }
-
-    #[getter]
-    fn kerning(&self) -> bool {
-        self.inner.kerning()
-    }
-
-    #[setter]
-    fn set_kerning(&mut self, kerning: bool) {
-        self.inner.set_kerning(kerning);
-        self.sync_runtime();
-    }
-
-    #[getter]
-    fn letter_spacing(&self) -> f32 {
-        self.inner.letter_spacing()
-    }
-
-    #[setter]
-    fn set_letter_spacing(&mut self, letter_spacing: f32) {
-        self.inner.set_letter_spacing(letter_spacing);
-        self.sync_runtime();
-    }
-
-    #[getter]
-    fn line_spacing(&self) -> f32 {
-        self.inner.line_spacing()
-    }
-
-    #[setter]
-    fn set_line_spacing(&mut self, line_spacing: f32) {
-        self.inner.set_line_spacing(line_spacing);
-        self.sync_runtime();
-    }
-
-    #[getter]
-    fn visible(&self) -> bool {
-        self.inner.visible()
-    }
-
-    #[setter]
-    fn set_visible(&mut self, visible: bool) {
-        self.inner.set_visible(visible);
-        self.sync_runtime();
-    }
-
-    #[getter]
-    fn draw_order(&self) -> f32 {
-        self.inner.draw_order()
-    }
-
-    #[setter]
-    fn set_draw_order(&mut self, draw_order: f32) {
-        self.inner.set_draw_order(draw_order);
-        self.sync_runtime();
-    }
-}
-
-// ========== Component Bindings ==========
-
-/// 2D transform component for position, rotation, and scale.
-///
-/// `TransformComponent` defines the local spatial properties of a `GameObject` in 2D space.
-/// Every `GameObject` has one, and parent transforms are composed into world-space for
-/// rendering and physics when the object is part of a hierarchy.
-///
-/// # Properties
-///
-/// - **position**: `Vec2` - Location relative to the parent (world-space when unparented)
-/// - **rotation**: `float` - Angle in **radians** (counter-clockwise)
-/// - **scale**: `Vec2` - Size multiplier (1.0 = original size)
+/// Python wrapper for Time.
+/// Frame timing information.
 ///
-/// # Coordinate System
+/// Tracks delta time and elapsed time for implementing frame-independent movement,
+/// animations, and time-based game logic.
 ///
-/// - **Local space**: Stored on the object relative to its parent
-/// - **World space**: Computed from the full parent chain at runtime
-/// - **Origin**: Typically center of the screen, but configurable via camera
-/// - **Y-axis**: Typically up is positive, down is negative
+/// # Time Units: Seconds
+/// **IMPORTANT:** All time values are in **seconds** (not milliseconds).
 ///
-/// # Rotation
+/// # Attributes
+/// - `delta_time` - Time since last frame in **seconds** (float)
+/// - `elapsed_time` - Total time since engine start in **seconds** (float)
 ///
-/// Rotation is measured in **radians** (not degrees):
-/// - **0 radians**: Facing right (0°)
-/// - **π/2 radians**: Facing up (90°)
-/// - **π radians**: Facing left (180°)
-/// - **3π/2 radians**: Facing down (270°)
+/// # Delta Time
+/// Delta time (dt) represents the duration of the last frame. Use it to make movement
+/// and animations frame-rate independent:
 ///
-/// Use `math.radians()` to convert from degrees:
 /// ```python
-/// import math
-/// transform.rotation = math.radians(45)  # 45 degrees
+/// # Frame-independent movement
+/// speed = 100.0  # pixels per second
+/// dt = engine.delta_time
+/// position += speed * dt  # Moves 100 pixels/sec regardless of FPS
 /// ```
 ///
-/// # Examples
+/// **Typical delta_time values:**
+/// - 60 FPS: ~0.016 seconds (16ms)
+/// - 30 FPS: ~0.033 seconds (33ms)
+/// - 120 FPS: ~0.008 seconds (8ms)
+///
+/// # Elapsed Time
+/// Elapsed time tracks total time since engine startup, useful for time-based effects:
 ///
-/// ## Basic Transform
 /// ```python
-/// from pyg_engine import TransformComponent, Vec2
+/// # Pulsing animation
 /// import math
-///
-/// transform = TransformComponent("Transform")
-/// transform.position = Vec2(100.0, 200.0)
-/// transform.rotation = math.radians(30)  # 30 degrees
-/// transform.scale = Vec2(2.0, 2.0)       # Double size
+/// elapsed = engine.elapsed_time
+/// scale = 1.0 + 0.2 * math.sin(elapsed * 2.0)  # Pulse every ~3 seconds
 /// ```
 ///
-/// ## Movement Over Time
+/// # Example
 /// ```python
-/// import math
+/// import pyg_engine as pyg
 ///
-/// def update(dt, engine, data):
-///     # Move right at 100 pixels/second
-///     data['obj'].position = data['obj'].position + Vec2(100.0 * dt, 0.0)
+/// engine = pyg.Engine()
+/// engine.start_manual()
 ///
-///     # Rotate at 90 degrees/second
-///     data['obj'].rotation += math.radians(90) * dt
-/// ```
+/// player_x = 400.0
+/// player_speed = 200.0  # pixels per second
 ///
-/// ## Direction-Based Movement
-/// ```python
-/// import math
-/// from pyg_engine import Vec2
+/// while engine.poll_events():
+///     # Get delta time (in seconds)
+///     dt = engine.delta_time
 ///
-/// def update(dt, engine, data):
-///     obj = data['obj']
-///     speed = 150.0
+///     # Frame-independent movement
+///     if engine.input.key_down(pyg.Keys.D):
+///         player_x += player_speed * dt  # Move 200 pixels/sec
 ///
-///     # Move in the direction the object is facing
-///     angle = obj.rotation
-///     direction = Vec2(math.cos(angle), math.sin(angle))
-///     obj.position = obj.position + direction * speed * dt
+///     # Show FPS based on delta time
+///     fps = 1.0 / dt if dt > 0 else 0
+///     print(f"FPS: {fps:.1f}, Delta: {dt:.4f}s")
+///
+///     engine.update()
+///     engine.render()
+/// ```
+///
+/// # Frame-Rate Independence
+/// Always multiply velocities by `delta_time` to ensure consistent behavior
+/// across different frame rates:
+///
+/// ```python
+/// # ❌ WRONG - Speed depends on FPS
+/// position += 5.0  # Moves 5 pixels PER FRAME (fast at high FPS, slow at low FPS)
+///
+/// # ✅ CORRECT - Speed independent of FPS
+/// speed = 300.0  # pixels per second
+/// position += speed * engine.delta_time  # Always 300 pixels/sec
 /// ```
 ///
 /// # See Also
-/// - `examples/python_game_object_transform_demo.py` - Transform examples
-/// - `GameObject` - The container that owns this transform
-#[pyclass(name = "TransformComponent")]
-pub struct PyTransformComponent {
-    inner: TransformComponent,
+/// - `examples/python_input_demo.py` - Delta time usage for movement
+/// - `examples/python_camera_worldspace_demo.py` - Frame-independent camera control
+#[pyclass(name = "Time")]
+pub struct PyTime {
+    inner: RustTime,
 }
 
 #[pymethods]
-impl PyTransformComponent {
+impl PyTime {
     #[new]
-    fn new(name: String) -> Self {
+    fn new() -> Self {
         Self {
-            inner: TransformComponent::new(name),
+            inner: RustTime::new(),
         }
     }
 
-    #[getter]
-    fn position(&self) -> PyVec2 {
-        PyVec2 {
-            inner: *self.inner.position(),
-        }
+    fn tick(&mut self) {
+        self.inner.tick();
     }
 
-    #[setter]
-    fn set_position(&mut self, position: PyVec2) {
-        self.inner.set_position(position.inner);
+    /// Time since last frame in **seconds**.
+    ///
+    /// Use for frame-independent movement and animations.
+    /// Typical values: 0.016 (60 FPS), 0.033 (30 FPS), 0.008 (120 FPS)
+    ///
+    /// # Example
+    /// ```python
+    /// dt = engine.delta_time
+    /// player_x += speed * dt  # pixels per second
+    /// ```
+    #[getter]
+    fn delta_time(&self) -> f32 {
+        self.inner.delta_time()
     }
 
+    /// Total time since engine start in **seconds**.
+    ///
+    /// Useful for time-based effects like animations, timers, and periodic events.
+    ///
+    /// # Example
+    /// ```python
+    /// import math
+    /// t = engine.elapsed_time
+    /// wave = math.sin(t * 2.0)  # Oscillates every ~3 seconds
+    /// ```
     #[getter]
-    fn rotation(&self) -> f32 {
-        self.inner.rotation()
+    fn elapsed_time(&self) -> f32 {
+        self.inner.elapsed_time()
     }
+}
 
-    #[setter]
-    fn set_rotation(&mut self, rotation: f32) {
-        self.inner.set_rotation(rotation);
+// ========== GameObject Bindings ==========
+
+/// Container for game entities with transform, rendering, and behavior.
+///
+/// `GameObject` is the fundamental building block of your game. Each GameObject has:
+/// - **Transform**: Position, rotation, scale
+/// - **Components**: Rendering, collision, and UI behavior attached through `add_component(...)`
+/// - **State**: Enabled/disabled, name, unique ID
+/// - **Hierarchy**: Optional parent/child relationships with inherited transforms
+///
+/// # Creation and Setup
+///
+/// ```python
+/// from pyg_engine import GameObject, MeshComponent, Vec2, Color
+///
+/// # Create a named GameObject
+/// player = GameObject("Player")
+/// player.position = Vec2(0, 0) # Note that we're using *World Position* not pixel location
+/// player.rotation = math.radians(45)  # Rotation in radians
+/// player.scale = Vec2(1.0, 1.0)
+///
+/// # Add mesh rendering
+/// mesh = MeshComponent("Rectangle")
+/// mesh.set_geometry_rectangle(1.0, 1.0)
+/// mesh.set_fill_color(Color.BLUE)
+/// player.add_component(mesh)
+///
+/// # Add to engine
+/// engine.add_game_object(player)
+/// ```
+///
+/// # Transform Properties
+///
+/// GameObjects use a 2D transform with position, rotation, and scale:
+/// - **position**: `Vec2` local to the parent (world-space when unparented)
+/// - **rotation**: Angle in **radians** (counter-clockwise)
+/// - **scale**: `Vec2` multiplier (1.0 = original size)
+///
+/// In Python, the direct convenience accessors are `obj.position`, `obj.rotation`,
+/// and `obj.scale`. `TransformComponent` is still the underlying runtime component type.
+///
+/// # Components
+///
+/// Attach components to add functionality:
+/// - `MeshComponent` - 2D rendering (rectangles, circles, images)
+/// - `Collider` - Collision and trigger callbacks
+/// - `ButtonComponent` - Clickable UI button
+/// - `PanelComponent` - UI container panel
+/// - `LabelComponent` - UI text label
+///
+/// Parent transforms propagate to children in world rendering and physics.
+///
+/// # Enabled State
+///
+/// GameObjects can be enabled/disabled:
+/// ```python
+/// player.enabled = False  # Disable object, children, and components
+/// player.enabled = True
+/// ```
+///
+/// # Object Types
+///
+/// GameObjects can be marked as `"UIObject"` for UI rendering:
+/// ```python
+/// obj.set_object_type("UIObject")  # Render as UI (screen space)
+/// ```
+///
+/// # See Also
+/// - `examples/python_game_object_transform_demo.py` - Transform operations
+/// - `examples/python_mesh_demo.py` - Mesh rendering
+/// - `examples/ui_demo.py` - UI components
+#[pyclass(name = "GameObject", unsendable)]
+pub struct PyGameObject {
+    inner: RustGameObject,
+    runtime_binding: RefCell<Option<RuntimeBinding>>,
+}
+
+#[derive(Clone)]
+struct RuntimeBinding {
+    sender: Sender<EngineCommand>,
+    object_id: u32,
+    objects: Arc<RwLock<ObjectManager>>,
+}
+
+/// Give a script a live `game_object` for the runtime object it is attached to.
+fn bind_script_game_object(script: &Bound<'_, PyAny>, binding: &RuntimeBinding) -> PyResult<()> {
+    let object = binding
+        .objects
+        .read()
+        .ok()
+        .and_then(|objects| objects.get_object_clone(binding.object_id));
+    if let Some(object) = object {
+        let game_object = PyGameObject::from_runtime(object, binding.sender.clone(), binding.objects.clone());
+        script.setattr("game_object", Py::new(script.py(), game_object)?)?;
     }
+    Ok(())
+}
 
-    #[getter]
-    fn scale(&self) -> PyVec2 {
-        PyVec2 {
-            inner: *self.inner.scale(),
+impl PyGameObject {
+    fn ensure_mesh_component(&mut self) -> &mut MeshComponent {
+        if self.inner.mesh_component().is_none() {
+            self.inner
+                .add_mesh_component(MeshComponent::new("Mesh Renderer".to_string()));
         }
+        self.inner
+            .mesh_component_mut()
+            .expect("mesh component should exist")
     }
 
-    #[setter]
-    fn set_scale(&mut self, scale: PyVec2) {
-        self.inner.set_scale(scale.inner);
+    fn sync_runtime_mesh_component(&self) {
+        let current_mesh = self.current_object().mesh_component().cloned();
+        if let Some(binding) = self.runtime_binding.borrow().as_ref()
+            && let Some(mesh) = current_mesh
+        {
+            let _ = binding.sender.send(EngineCommand::SetGameObjectMeshComponent {
+                object_id: binding.object_id,
+                mesh,
+            });
+        }
     }
 
-    #[getter]
-    fn name(&self) -> String {
-        self.inner.name().to_string()
+    fn to_runtime_game_object(&self) -> RustGameObject {
+        self.inner.clone()
     }
 
-    #[getter]
-    fn id(&self) -> u32 {
-        self.inner.id()
+    fn bind_runtime(
+        &self,
+        sender: Sender<EngineCommand>,
+        object_id: u32,
+        objects: Arc<RwLock<ObjectManager>>,
+    ) {
+        let binding = RuntimeBinding {
+            sender,
+            object_id,
+            objects,
+        };
+        Python::attach(|py| {
+            for script in self.inner.get_components::<ScriptComponent>() {
+                if let Some(script) = script_object(py, script)
+                    && let Err(e) = bind_script_game_object(script.bind(py), &binding)
+                {
+                    logging::log_error(&format!("Error binding script to object {}: {:?}", object_id, e));
+                }
+            }
+        });
+        self.runtime_binding.replace(Some(binding));
     }
 
-    #[getter]
-    fn enabled(&self) -> bool {
-        self.inner.is_enabled_self()
+    fn current_object(&self) -> RustGameObject {
+        self.runtime_binding
+            .borrow()
+            .as_ref()
+            .and_then(|binding| binding.objects.read().ok()?.get_object_clone(binding.object_id))
+            .unwrap_or_else(|| self.inner.clone())
     }
 
-    #[setter(enabled)]
-    fn set_enabled_property(&mut self, enabled: bool) {
-        self.inner.set_enabled_self(enabled);
+    fn refresh_from_runtime(&mut self) {
+        self.inner = self.current_object();
     }
-}
 
-// ========== Module Functions ==========
+    fn from_runtime(
+        object: RustGameObject,
+        sender: Sender<EngineCommand>,
+        objects: Arc<RwLock<ObjectManager>>,
+    ) -> Self {
+        let object_id = object.get_id();
+        Self {
+            inner: object,
+            runtime_binding: RefCell::new(Some(RuntimeBinding {
+                sender,
+                object_id,
+                objects,
+            })),
+        }
+    }
 
-/// Get the engine version (module-level function).
-#[pyfunction]
-fn version() -> String {
-    crate::core::engine::VERSION.to_string()
-}
-
-// ========== UI Component Bindings ==========
-
-/// Python wrapper for ButtonComponent.
-#[pyclass(name = "ButtonComponent")]
-pub struct PyButtonComponent {
-    inner: ButtonComponent,
+    fn component_binding(&self, component: &dyn ComponentTrait) -> Option<ComponentRuntimeBinding> {
+        self.runtime_binding
+            .borrow()
+            .as_ref()
+            .map(|binding| ComponentRuntimeBinding {
+                sender: binding.sender.clone(),
+                object_id: binding.object_id,
+                component_id: component.id(),
+            })
+    }
 }
 
 #[pymethods]
-impl PyButtonComponent {
+impl PyGameObject {
     #[new]
-    #[pyo3(signature = (text="", x=0.0, y=0.0, width=100.0, height=30.0))]
-    fn new(text: &str, x: f32, y: f32, width: f32, height: f32) -> Self {
-        let button = ButtonComponent::new("Button")
-            .with_text(text)
-            .with_bounds(x, y, width, height);
-        Self { inner: button }
-    }
-
-    fn set_text(&mut self, text: &str) {
-        self.inner.set_text(text);
-    }
-
-    fn get_text(&self) -> String {
-        self.inner.text().to_string()
+    #[pyo3(signature = (name=None))]
+    fn new(name: Option<String>) -> Self {
+        let inner = if let Some(n) = name {
+            RustGameObject::new_named(n)
+        } else {
+            RustGameObject::new()
+        };
+        Self {
+            inner,
+            runtime_binding: RefCell::new(None),
+        }
     }
 
+    /// Get the unique ID of this GameObject.
+    ///
+    /// Each GameObject is assigned a unique ID when created. This ID is used internally
+    /// by the engine for tracking objects and can be useful for debugging or looking up
+    /// objects.
+    ///
+    /// # Returns
+    /// A unique unsigned 32-bit integer ID.
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// obj1 = pyg.GameObject("Player")
+    /// obj2 = pyg.GameObject("Enemy")
+    ///
+    /// print(f"Player ID: {obj1.id}")  # e.g., "Player ID: 1"
+    /// print(f"Enemy ID: {obj2.id}")   # e.g., "Enemy ID: 2"
+    ///
+    /// # IDs are unique
+    /// assert obj1.id != obj2.id
+    /// ```
+    ///
+    /// # Note
+    /// IDs are assigned sequentially and persist for the lifetime of the object.
+    /// Once an object is destroyed, its ID may be reused for new objects.
     #[getter]
     fn id(&self) -> u32 {
-        self.inner.id()
+        self.current_object().get_id()
     }
 
+    /// Get the name of this GameObject.
+    ///
+    /// Returns the human-readable name assigned to this object, or `None` if no name was set.
+    /// Names are useful for debugging, logging, and identifying objects in your game.
+    ///
+    /// # Returns
+    /// - `Some(String)` - The object's name if one was set
+    /// - `None` - If no name was assigned
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// # Create named object
+    /// player = pyg.GameObject("Player")
+    /// print(player.name)  # "Player"
+    ///
+    /// # Create unnamed object
+    /// obj = pyg.GameObject()
+    /// print(obj.name)  # None
+    ///
+    /// # Change name later
+    /// obj.set_name("Bullet")
+    /// print(obj.name)  # "Bullet"
+    /// ```
+    ///
+    /// # See Also
+    /// - `set_name()` - Change the object's name
     #[getter]
-    fn enabled(&self) -> bool {
-        self.inner.is_enabled_self()
-    }
-
-    fn set_enabled(&mut self, enabled: bool) {
-        self.inner.set_enabled(enabled);
-    }
-
-    #[setter(enabled)]
-    fn set_enabled_property(&mut self, enabled: bool) {
-        self.inner.set_enabled_self(enabled);
-    }
-
-    fn set_position(&mut self, x: f32, y: f32) {
-        let bounds = self.inner.bounds();
-        self.inner.set_bounds(Rect::new(x, y, bounds.width, bounds.height));
-    }
-
-    fn set_size(&mut self, width: f32, height: f32) {
-        let bounds = self.inner.bounds();
-        self.inner.set_bounds(Rect::new(bounds.x, bounds.y, width, height));
-    }
-
-    fn set_font_size(&mut self, font_size: f32) {
-        self.inner.set_font_size(font_size);
+    fn name(&self) -> Option<String> {
+        self.current_object().name().map(|name| name.to_string())
     }
 
-    fn set_font_path(&mut self, font_path: Option<String>) {
-        self.inner.set_font_path(font_path);
+    #[getter]
+    fn object_type(&self) -> String {
+        match self.current_object().get_object_type() {
+            crate::core::game_object::ObjectType::GameObject => "GameObject",
+            crate::core::game_object::ObjectType::UIObject => "UIObject",
+            crate::core::game_object::ObjectType::ParticleSystem => "ParticleSystem",
+            crate::core::game_object::ObjectType::Sound => "Sound",
+            crate::core::game_object::ObjectType::Light => "Light",
+            crate::core::game_object::ObjectType::Camera => "Camera",
+        }
+        .to_string()
     }
 
-    fn set_font_family(&mut self, font_family: Option<String>) {
-        self.inner.set_font_family(font_family);
+    /// Set or change the name of this GameObject.
+    ///
+    /// Assigns a human-readable name to the object. Names are useful for debugging,
+    /// logging, and organizing objects in your game.
+    ///
+    /// # Arguments
+    /// * `name` - New name for the object (any string)
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// obj = pyg.GameObject()
+    /// obj.set_name("Player")
+    /// print(obj.name)  # "Player"
+    ///
+    /// # Rename object
+    /// obj.set_name("MainCharacter")
+    /// print(obj.name)  # "MainCharacter"
+    /// ```
+    ///
+    /// # See Also
+    /// - `name` (property) - Get the current name
+    fn set_name(&mut self, name: String) {
+        self.inner.set_name(name.clone());
+        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::SetGameObjectName {
+                object_id: binding.object_id,
+                name,
+            });
+        }
     }
 
-    fn set_font_weight(&mut self, font_weight: &str) -> PyResult<()> {
-        self.inner.set_font_weight(parse_font_weight(Some(font_weight))?);
-        Ok(())
+    /// Tags of this GameObject, in the order they were added.
+    ///
+    /// Tags group objects for queries such as `engine.find_by_tag("enemy")`.
+    /// An object can have any number of tags.
+    ///
+    /// # See Also
+    /// - `add_tag()`, `remove_tag()`, `has_tag()`
+    #[getter]
+    fn tags(&self) -> Vec<String> {
+        self.current_object().tags().to_vec()
     }
 
-    fn set_font_style(&mut self, font_style: &str) -> PyResult<()> {
-        self.inner.set_font_style(parse_font_style(Some(font_style))?);
-        Ok(())
+    /// Add a tag to this GameObject.
+    ///
+    /// # Returns
+    /// `False` if the object already had the tag.
+    ///
+    /// # Example
+    /// ```python
+    /// enemy = pyg.GameObject("Goblin")
+    /// enemy.add_tag("enemy")
+    /// engine.add_game_object(enemy)
+    ///
+    /// for enemy_id in engine.find_by_tag("enemy"):
+    ///     engine.destroy(enemy_id)
+    /// ```
+    fn add_tag(&mut self, tag: String) -> bool {
+        let added = !self.current_object().has_tag(&tag);
+        self.inner.add_tag(tag.clone());
+        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::AddGameObjectTag {
+                object_id: binding.object_id,
+                tag,
+            });
+        }
+        added
     }
 
-    fn set_kerning(&mut self, kerning: bool) {
-        self.inner.set_kerning(kerning);
+    /// Remove a tag from this GameObject.
+    ///
+    /// # Returns
+    /// `False` if the object did not have the tag.
+    fn remove_tag(&mut self, tag: String) -> bool {
+        let removed = self.current_object().has_tag(&tag);
+        self.inner.remove_tag(&tag);
+        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::RemoveGameObjectTag {
+                object_id: binding.object_id,
+                tag,
+            });
+        }
+        removed
     }
 
-    fn set_depth(&mut self, depth: f32) {
-        self.inner = std::mem::replace(&mut self.inner, ButtonComponent::new("temp"))
-            .with_depth(depth);
+    /// Check whether this GameObject has a tag.
+    fn has_tag(&self, tag: &str) -> bool {
+        self.current_object().has_tag(tag)
     }
 
-    /// Set a Python callback for the button click event.
+    /// Compatibility alias for `enabled`.
     ///
-    /// Registers a function to be called when the button is clicked. The callback executes
-    /// on the **main engine thread** during event processing, ensuring safe interaction with
-    /// the engine and UI system.
+    /// Prefer `enabled` in new code. This property remains available for older examples and
+    /// returns the same hierarchy-aware enabled state as `enabled`.
     ///
-    /// # Arguments
-    /// * `py_callback` - A Python callable that takes **no arguments**. The callback should
-    ///   not accept any parameters (not even `self` if it's a method).
+    /// # Returns
+    /// - `True` - Object is enabled
+    /// - `False` - Object is disabled
     ///
-    /// # Callback Signature
-    /// The callback must have this signature:
+    /// # Example
     /// ```python
-    /// def callback() -> None:
-    ///     # Your code here
-    ///     pass
+    /// import pyg_engine as pyg
+    ///
+    /// player = pyg.GameObject("Player")
+    /// print(player.active)  # True
+    ///
+    /// # Disable object through compatibility alias
+    /// player.active = False
+    /// print(player.active)  # False
+    ///
+    /// # Re-enable later
+    /// player.active = True
+    /// print(player.active)  # True
     /// ```
     ///
-    /// # Error Handling
-    /// If the callback raises an exception:
-    /// - The exception is **logged** to the console with stack trace
-    /// - The exception **does not crash** the engine
-    /// - Other buttons and UI elements continue to function normally
+    /// # Use Cases
+    /// - **Object pooling**: Disable/re-enable objects instead of creating/destroying
+    /// - **Pause mechanics**: Disable enemies when the game is paused
+    /// - **Conditional rendering**: Hide objects without removing them from the scene
     ///
-    /// # Thread Safety
-    /// - Callbacks run on the **main engine thread**
-    /// - Safe to call any engine methods from the callback
-    /// - Safe to modify UI elements and game state
-    /// - No manual thread synchronization needed
+    /// # See Also
+    /// - `enabled` - Preferred property name in new code
+    #[getter]
+    fn active(&self) -> bool {
+        self.current_object().is_active()
+    }
+
+    /// Compatibility setter for `enabled`.
+    ///
+    /// Prefer `enabled` in new code. Disabled objects remain in the scene but are skipped
+    /// during update, render, and collision passes, and disabled parents cascade to children.
+    ///
+    /// # Arguments
+    /// * `active` - `True` to enable, `False` to disable
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
     ///
-    /// engine = pyg.Engine()
-    ///
-    /// # Counter for demonstration
-    /// click_count = [0]  # Use list for closure modification
-    ///
-    /// # Define callback (takes no arguments!)
-    /// def on_button_clicked():
-    ///     click_count[0] += 1
-    ///     print(f"Button clicked! Count: {click_count[0]}")
-    ///
-    /// # Create button with callback
-    /// button = pyg.Button(
-    ///     "Click Me",
-    ///     x=100,
-    ///     y=100,
-    ///     width=200,
-    ///     height=50
-    /// )
-    /// button.set_on_click(on_button_clicked)
-    /// button.set_trigger_on("release")  # Fire on mouse release (default)
+    /// enemy = pyg.GameObject("Enemy")
+    /// engine.add_game_object(enemy)
     ///
-    /// # Add to UI system
-    /// engine.ui.add(button)
+    /// # Temporarily disable enemy
+    /// enemy.active = False  # Enemy no longer updates or renders
     ///
-    /// # Run engine
-    /// engine.run(title="Button Callback Demo")
+    /// # Re-enable later
+    /// enemy.active = True  # Enemy resumes normal behavior
     /// ```
     ///
-    /// # Alternative: Pass Callback in Constructor
+    /// # Object Pooling Pattern
     /// ```python
-    /// # Shorter syntax using constructor parameter
-    /// button = pyg.Button(
-    ///     "Click Me",
-    ///     x=100,
-    ///     y=100,
-    ///     width=200,
-    ///     height=50,
-    ///     on_click=lambda: print("Clicked!")
-    /// )
-    /// engine.ui.add(button)
-    /// ```
+    /// import pyg_engine as pyg
     ///
-    /// # Closure Example (Modifying External State)
-    /// ```python
-    /// # Use lists or objects for mutable state in closures
-    /// state = {"enabled": True, "count": 0}
+    /// class BulletPool:
+    ///     def __init__(self, engine, size=10):
+    ///         self.bullets = []
+    ///         for i in range(size):
+    ///             bullet = pyg.GameObject(f"Bullet_{i}")
+    ///             bullet.active = False  # Compatibility alias for enabled = False
+    ///             engine.add_game_object(bullet)
+    ///             self.bullets.append(bullet)
     ///
-    /// def toggle_button():
-    ///     state["enabled"] = not state["enabled"]
-    ///     print(f"Enabled: {state['enabled']}")
+    ///     def spawn(self, position):
+    ///         # Find disabled bullet
+    ///         for bullet in self.bullets:
+    ///             if not bullet.active:
+    ///                 bullet.position = position
+    ///                 bullet.active = True  # Activate
+    ///                 return bullet
+    ///         return None  # Pool exhausted
     ///
-    /// button.set_on_click(toggle_button)
+    ///     def despawn(self, bullet):
+    ///         bullet.active = False  # Return to pool
     /// ```
     ///
-    /// # Updating UI from Callback
-    /// ```python
-    /// label = pyg.Label("Count: 0", x=100, y=50, font_size=18)
-    /// engine.ui.add(label)
+    /// # See Also
+    /// - `enabled` - Preferred property name in new code
+    #[setter]
+    fn set_active(&mut self, active: bool) {
+        self.inner.set_active(active);
+        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::SetGameObjectEnabled {
+                object_id: binding.object_id,
+                enabled: active,
+            });
+        }
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.current_object().is_enabled()
+    }
+
+    #[setter]
+    fn set_enabled(&mut self, enabled: bool) {
+        self.set_active(enabled);
+    }
+
+    fn add_child(&mut self, child: &mut PyGameObject) -> PyResult<()> {
+        self.inner.add_child_id(child.inner.get_id());
+        child.inner.set_parent_id(Some(self.inner.get_id()));
+
+        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::AddChild {
+                parent_id: binding.object_id,
+                child_id: child.inner.get_id(),
+            });
+        }
+        Ok(())
+    }
+
+    fn get_children(&self) -> Vec<PyGameObject> {
+        let binding_ref = self.runtime_binding.borrow();
+        let Some(binding) = binding_ref.as_ref() else {
+            return Vec::new();
+        };
+
+        binding
+            .objects
+            .read()
+            .map(|object_manager| {
+                self.current_object()
+                    .children()
+                    .iter()
+                    .filter_map(|child_id| object_manager.get_object_clone(*child_id))
+                    .map(|object| {
+                        PyGameObject::from_runtime(
+                            object,
+                            binding.sender.clone(),
+                            Arc::clone(&binding.objects),
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn get_child_id(&self, child_id: u32) -> Option<PyGameObject> {
+        self.get_children()
+            .into_iter()
+            .find(|child| child.inner.get_id() == child_id)
+    }
+
+    fn get_child_name(&self, name: &str) -> Vec<PyGameObject> {
+        self.get_children()
+            .into_iter()
+            .filter(|child| child.inner.name() == Some(name))
+            .collect()
+    }
+
+    fn get_child_count(&self) -> usize {
+        self.current_object().child_count()
+    }
+
+    /// Get the position of this GameObject.
     ///
-    /// count = [0]
+    /// Returns the object's position in **world-space coordinates** as a `Vec2`.
+    /// The position represents the center point of the object.
     ///
-    /// def increment():
-    ///     count[0] += 1
-    ///     label.text = f"Count: {count[0]}"  # Safe - on main thread
+    /// # Returns
+    /// `Vec2` containing the object's position:
+    /// - `x` - Horizontal position (right is positive)
+    /// - `y` - Vertical position (up is typically positive)
     ///
-    /// button = pyg.Button("Increment", x=100, y=100, width=150, height=40)
-    /// button.set_on_click(increment)
-    /// engine.ui.add(button)
-    /// ```
+    /// # Coordinate System
+    /// - **World space**: Absolute positions in the game world
+    /// - **Origin (0, 0)**: Typically center of screen (configurable via camera)
+    /// - **Units**: Pixels by default
     ///
-    /// # Trigger Timing
-    /// Control when the callback fires with `set_trigger_on()`:
+    /// # Example
     /// ```python
-    /// # Fire on mouse release (default - feels like a "click")
-    /// button.set_trigger_on("release")
+    /// import pyg_engine as pyg
     ///
-    /// # Fire immediately on mouse press (more responsive)
-    /// button.set_trigger_on("press")
-    /// ```
+    /// player = pyg.GameObject("Player")
+    /// player.position = pyg.Vec2(100.0, 200.0)
     ///
-    /// # Repeat Behavior
-    /// Enable continuous firing while button is held:
-    /// ```python
-    /// # Fire every 100ms while held down
-    /// button.set_repeat_interval(100.0)
+    /// # Get current position
+    /// pos = player.position
+    /// print(f"Player at ({pos.x}, {pos.y})")  # "Player at (100.0, 200.0)"
     ///
-    /// # Disable repeating (single fire per click)
-    /// button.set_repeat_interval(None)
+    /// # Check if player is at origin
+    /// if player.position == pyg.Vec2.ZERO:
+    ///     print("Player at origin")
     /// ```
     ///
-    /// # Common Patterns
-    ///
-    /// **Menu Button:**
+    /// # Movement Example
     /// ```python
-    /// def start_game():
-    ///     engine.log("Starting game...")
-    ///     # Load game scene, hide menu, etc.
-    ///
-    /// start_btn = pyg.Button("Start Game", x=400, y=300, width=200, height=60)
-    /// start_btn.set_on_click(start_game)
-    /// ```
+    /// import pyg_engine as pyg
     ///
-    /// **Toggle Button:**
-    /// ```python
-    /// paused = [False]
-    /// pause_btn = [None]
+    /// def update(dt, engine, data):
+    ///     player = data['player']
     ///
-    /// def toggle_pause():
-    ///     paused[0] = not paused[0]
-    ///     pause_btn[0].text = "Resume" if paused[0] else "Pause"
+    ///     # Move right at 100 pixels/second
+    ///     new_pos = player.position + pyg.Vec2(100.0 * dt, 0.0)
+    ///     player.position = new_pos
     ///
-    /// pause_btn[0] = pyg.Button("Pause", x=10, y=10, width=100, height=30)
-    /// pause_btn[0].set_on_click(toggle_pause)
+    ///     return True
     /// ```
     ///
     /// # See Also
-    /// - `examples/button_features_demo.py` - Button trigger modes and repeat functionality
-    /// - `examples/ui_demo.py` - Complete UI system demonstration
-    /// - `set_trigger_on()` - Configure when callback fires (press vs release)
-    /// - `set_repeat_interval()` - Enable continuous firing while held
-    fn set_on_click(&mut self, py_callback: Py<PyAny>) {
-        self.inner.set_on_click(move || {
-            // Use attach to ensure we have the GIL when calling Python callback
-            // from the Rust event loop context
-            let _ = pyo3::Python::attach(|py| {
-                match py_callback.call0(py) {
-                    Ok(_) => {},
-                    Err(e) => {
-                        e.print(py);
-
-                        logging::log_error(&format!(
-                                "Error calling button callback: {:?}",
-                                e
-                        ));
-                    }
-                }
-            });
-        });
+    /// - `position` (setter) - Set object position
+    /// - `Vec2` - 2D vector class
+    /// - `examples/python_game_object_transform_demo.py` - Transform examples
+    #[getter]
+    fn position(&self) -> PyVec2 {
+        let object = self.current_object();
+        PyVec2 {
+            inner: *object.transform().position(),
+        }
     }
 
-    /// Set when the button callback is triggered.
+    /// Set the position of this GameObject.
     ///
-    /// Controls whether the button's `on_click` callback fires when the mouse button goes
-    /// **down** (press) or **up** (release). This affects the button's responsiveness and
-    /// "feel" for different use cases.
+    /// Moves the object to the specified **world-space coordinates**. The position
+    /// represents the center point of the object.
     ///
     /// # Arguments
-    /// * `trigger_on` - Trigger mode string (case-insensitive). Valid values:
-    ///   - **`"release"`** (default) - Fire callback when mouse button is released
-    ///     - Standard button behavior, feels like a "click"
-    ///     - User can cancel by moving mouse away before releasing
-    ///     - Aliases: `"up"`, `"click"`
+    /// * `position` - Target position as `Vec2` (world-space coordinates)
     ///
-    ///   - **`"press"`** - Fire callback immediately when mouse button goes down
-    ///     - More responsive, instant feedback
-    ///     - No way to cancel once pressed
-    ///     - Aliases: `"down"`
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
     ///
-    /// Invalid values log a warning and default to `"release"`.
+    /// player = pyg.GameObject("Player")
     ///
-    /// # Press vs Release
+    /// # Set position directly
+    /// player.position = pyg.Vec2(100.0, 200.0)
     ///
-    /// **Release (default):**
-    /// - Traditional button behavior
-    /// - User can press, move away, and release to cancel
-    /// - Feels like "clicking" the button
-    /// - Best for: Menu buttons, confirmations, UI interactions
+    /// # Move to origin
+    /// player.position = pyg.Vec2.ZERO
     ///
-    /// **Press:**
-    /// - Instant response on mouse down
-    /// - No cancel mechanism
-    /// - Feels more immediate and game-like
-    /// - Best for: Game controls, rapid-fire actions, instant response needs
+    /// # Move relative to current position
+    /// player.position = player.position + pyg.Vec2(50.0, 0.0)  # Move right 50px
+    /// ```
     ///
-    /// # Example
+    /// # Smooth Movement
     /// ```python
     /// import pyg_engine as pyg
     ///
-    /// engine = pyg.Engine()
+    /// def update(dt, engine, data):
+    ///     player = data['player']
+    ///     speed = 150.0  # pixels per second
     ///
-    /// # Standard release button (default)
-    /// menu_btn = pyg.Button("Start Game", x=100, y=100, width=200, height=50)
-    /// menu_btn.set_on_click(lambda: print("Game started!"))
-    /// menu_btn.set_trigger_on("release")  # Fire on mouse up
-    /// engine.ui.add(menu_btn)
+    ///     # Get input
+    ///     move_x = engine.input.axis("Horizontal")
+    ///     move_y = engine.input.axis("Vertical")
     ///
-    /// # Instant press button (more responsive)
-    /// fire_btn = pyg.Button("Fire!", x=100, y=200, width=200, height=50)
-    /// fire_btn.set_on_click(lambda: print("Shooting!"))
-    /// fire_btn.set_trigger_on("press")  # Fire on mouse down
-    /// engine.ui.add(fire_btn)
+    ///     # Calculate movement
+    ///     velocity = pyg.Vec2(move_x, move_y).normalize() * speed * dt
     ///
-    /// engine.run(title="Button Trigger Demo")
+    ///     # Apply movement
+    ///     player.position = player.position + velocity
+    ///
+    ///     return True
     /// ```
     ///
-    /// # Comparison Demo
+    /// # Lerp to Target
     /// ```python
-    /// press_count = [0]
-    /// release_count = [0]
+    /// import pyg_engine as pyg
     ///
-    /// def on_press():
-    ///     press_count[0] += 1
-    ///     print(f"Press: {press_count[0]} (fires immediately)")
+    /// def update(dt, engine, data):
+    ///     player = data['player']
+    ///     target = data['target_pos']
     ///
-    /// def on_release():
-    ///     release_count[0] += 1
-    ///     print(f"Release: {release_count[0]} (fires on mouse up)")
+    ///     # Smoothly interpolate to target (10% per frame)
+    ///     player.position = player.position.lerp(target, 0.1)
     ///
-    /// press_btn = pyg.Button("Press Mode", x=100, y=100, width=150, height=40)
-    /// press_btn.set_on_click(on_press)
-    /// press_btn.set_trigger_on("press")
+    ///     return True
+    /// ```
     ///
-    /// release_btn = pyg.Button("Release Mode", x=100, y=150, width=150, height=40)
-    /// release_btn.set_on_click(on_release)
-    /// release_btn.set_trigger_on("release")  # Or omit - this is default
+    /// # See Also
+    /// - `position` (getter) - Get current position
+    /// - `Vec2` - 2D vector operations
+    /// - `Vec2.lerp()` - Linear interpolation for smooth movement
+    /// - `examples/python_game_object_transform_demo.py` - Transform examples
+    #[setter]
+    fn set_position(&mut self, position: PyVec2) {
+        self.inner.transform_mut().set_position(position.inner);
+        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::SetGameObjectPosition {
+                object_id: binding.object_id,
+                position: position.inner,
+            });
+        }
+    }
+
+    /// Get the object's rotation angle.
     ///
-    /// engine.ui.add(press_btn)
-    /// engine.ui.add(release_btn)
-    /// ```
+    /// Returns the current rotation in **radians** (not degrees). Positive values represent
+    /// counter-clockwise rotation.
     ///
-    /// # Use Cases
+    /// # Returns
+    /// Float representing rotation angle in **radians**:
+    /// - `0.0` = No rotation (facing right/east)
+    /// - `π/2` (≈1.571) = 90° counter-clockwise (facing up/north)
+    /// - `π` (≈3.142) = 180° (facing left/west)
+    /// - `3π/2` (≈4.712) = 270° counter-clockwise (facing down/south)
+    /// - `2π` (≈6.283) = 360° (full rotation, back to 0°)
     ///
-    /// **Use "release" for:**
-    /// - Menu navigation
-    /// - Confirmation dialogs
-    /// - Submit buttons
-    /// - Any action that should allow cancellation
+    /// # Units: Radians
+    /// **IMPORTANT:** Rotation uses **radians**, not degrees.
+    /// - To convert degrees to radians: Use `math.radians(degrees)`
+    /// - To convert radians to degrees: Use `math.degrees(radians)`
+    /// - Formula: `radians = degrees × (π / 180)`
     ///
-    /// **Use "press" for:**
-    /// - Game weapon firing
-    /// - Jump buttons
-    /// - Fast-paced interactions
-    /// - When instant response is critical
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    /// import math
+    ///
+    /// engine = pyg.Engine()
+    /// obj = pyg.GameObject("RotatingObject")
+    ///
+    /// # Get current rotation
+    /// current_rotation = obj.rotation
+    /// print(f"Rotation: {current_rotation} radians = {math.degrees(current_rotation)}°")
+    ///
+    /// # Check if object is upside down
+    /// if abs(obj.rotation - math.pi) < 0.1:
+    ///     print("Object is approximately upside down")
+    /// ```
     ///
     /// # See Also
-    /// - `examples/button_features_demo.py` - Trigger mode comparison
-    /// - `set_on_click()` - Set button callback
-    /// - `set_repeat_interval()` - Enable continuous firing while held
-    fn set_trigger_on(&mut self, trigger_on: &str) {
-        use crate::core::ui::button::ButtonTrigger;
-        let trigger = match trigger_on.to_lowercase().as_str() {
-            "press" | "down" => ButtonTrigger::Press,
-            "release" | "up" | "click" => ButtonTrigger::Release,
-            _ => {
-                logging::log_warn(&format!(
-                    "Warning: Invalid trigger_on value '{}'. Use 'press' or 'release'. Defaulting to 'release'.",
-                    trigger_on
-                ));
-                ButtonTrigger::Release
-            }
-        };
-        self.inner.set_trigger_on(trigger);
+    /// - `rotation` (setter) - Set rotation angle
+    /// - `GameObject.rotation` - Direct property access
+    #[getter]
+    fn rotation(&self) -> f32 {
+        self.current_object().transform().rotation()
     }
 
-    /// Set the repeat interval in milliseconds for when the button is held down.
+    /// Set the object's rotation angle.
     ///
-    /// Enables continuous callback firing while the button remains pressed. After the initial
-    /// trigger, the callback will fire repeatedly at the specified interval until the button
-    /// is released. Useful for increment/decrement buttons, scrolling, or auto-fire mechanics.
+    /// Sets the rotation in **radians** (not degrees). Positive values rotate counter-clockwise.
     ///
     /// # Arguments
-    /// * `interval_ms` - Repeat interval in **milliseconds**, or `None` to disable repeating:
-    ///   - **`None`** (default) - Single fire per click, no repeating
-    ///   - **`> 0`** - Fire callback every N milliseconds while held
-    ///   - Typical values: 50-500ms (50ms = 20Hz, 100ms = 10Hz, 500ms = 2Hz)
+    /// * `rotation` - Rotation angle in **radians** (not degrees):
+    ///   - Positive: Counter-clockwise rotation
+    ///   - Negative: Clockwise rotation
+    ///   - Range: Typically 0 to 2π, but any value is valid (wraps around)
     ///
-    /// # Behavior
-    /// 1. Button is clicked → Callback fires immediately
-    /// 2. Button remains held → Wait `interval_ms`
-    /// 3. While still held → Callback fires again
-    /// 4. Repeat step 3 until button released
+    /// # Units: Radians
+    /// **IMPORTANT:** Rotation uses **radians**, not degrees!
+    /// - Use `math.radians(degrees)` to convert from degrees
+    /// - Common angles in radians:
+    ///   - 0° = 0.0 rad
+    ///   - 45° ≈ 0.785 rad (π/4)
+    ///   - 90° ≈ 1.571 rad (π/2)
+    ///   - 180° ≈ 3.142 rad (π)
+    ///   - 270° ≈ 4.712 rad (3π/2)
+    ///   - 360° ≈ 6.283 rad (2π)
     ///
     /// # Example
     /// ```python
     /// import pyg_engine as pyg
+    /// import math
     ///
     /// engine = pyg.Engine()
+    /// obj = pyg.GameObject("RotatingObject")
     ///
-    /// counter = [0]
-    /// label = [None]
+    /// # Rotate 45 degrees counter-clockwise
+    /// obj.rotation = math.radians(45)
     ///
-    /// def increment():
-    ///     counter[0] += 1
-    ///     if label[0]:
-    ///         label[0].text = f"Count: {counter[0]}"
+    /// # Rotate 90 degrees clockwise (negative)
+    /// obj.rotation = math.radians(-90)
     ///
-    /// def decrement():
-    ///     counter[0] -= 1
-    ///     if label[0]:
-    ///         label[0].text = f"Count: {counter[0]}"
+    /// # Rotate using pi constants
+    /// obj.rotation = math.pi / 2  # 90 degrees
+    /// obj.rotation = math.pi       # 180 degrees
+    /// obj.rotation = 2 * math.pi   # 360 degrees (full rotation)
+    /// ```
     ///
-    /// # Counter display
-    /// counter_label = pyg.Label(f"Count: {counter[0]}", x=200, y=100, font_size=24)
-    /// engine.ui.add(counter_label)
-    /// label[0] = counter_label
+    /// # Continuous Rotation
+    /// ```python
+    /// import pyg_engine as pyg
+    /// import math
     ///
-    /// # Increment button with repeat (fires every 100ms when held)
-    /// inc_btn = pyg.Button("+", x=100, y=150, width=60, height=40)
-    /// inc_btn.set_on_click(increment)
-    /// inc_btn.set_repeat_interval(100.0)  # Repeat every 100ms
-    /// inc_btn.set_trigger_on("press")  # Start immediately on press
-    /// engine.ui.add(inc_btn)
+    /// engine = pyg.Engine()
+    /// obj = pyg.GameObject("Spinner")
+    /// engine.add_game_object(obj)
     ///
-    /// # Decrement button with repeat
-    /// dec_btn = pyg.Button("-", x=170, y=150, width=60, height=40)
-    /// dec_btn.set_on_click(decrement)
-    /// dec_btn.set_repeat_interval(100.0)
-    /// dec_btn.set_trigger_on("press")
-    /// engine.ui.add(dec_btn)
+    /// rotation_speed = math.radians(90)  # 90 degrees per second
     ///
-    /// engine.run(title="Repeat Button Demo")
-    /// ```
+    /// def update(dt, engine, data):
+    ///     # Rotate continuously
+    ///     obj.rotation += rotation_speed * dt
     ///
-    /// # Faster Repeat for Acceleration
-    /// ```python
-    /// # Fast repeating button for rapid increment
-    /// rapid_btn = pyg.Button("Fast +", x=100, y=200, width=80, height=40)
-    /// rapid_btn.set_on_click(increment)
-    /// rapid_btn.set_repeat_interval(50.0)  # 20 times per second
-    /// engine.ui.add(rapid_btn)
+    ///     # Optional: Keep rotation in [0, 2π] range
+    ///     if obj.rotation > 2 * math.pi:
+    ///         obj.rotation -= 2 * math.pi
+    ///
+    ///     return True
+    ///
+    /// engine.run(update=update)
     /// ```
     ///
-    /// # Disable Repeating
+    /// # Rotation Towards Target
     /// ```python
-    /// # Single fire only (default behavior)
-    /// single_btn = pyg.Button("Click Once", x=100, y=250, width=120, height=40)
-    /// single_btn.set_on_click(lambda: print("Single fire"))
-    /// single_btn.set_repeat_interval(None)  # No repeating
-    /// engine.ui.add(single_btn)
+    /// import pyg_engine as pyg
+    /// import math
+    ///
+    /// def rotate_towards(obj, target_pos, dt, turn_speed):
+    ///     # Calculate angle to target
+    ///     dx = target_pos.x - obj.position.x
+    ///     dy = target_pos.y - obj.position.y
+    ///     target_angle = math.atan2(dy, dx)
+    ///
+    ///     # Smoothly rotate towards target
+    ///     angle_diff = target_angle - obj.rotation
+    ///
+    ///     # Normalize to [-π, π]
+    ///     while angle_diff > math.pi:
+    ///         angle_diff -= 2 * math.pi
+    ///     while angle_diff < -math.pi:
+    ///         angle_diff += 2 * math.pi
+    ///
+    ///     # Apply rotation with speed limit
+    ///     max_rotation = turn_speed * dt
+    ///     obj.rotation += max(-max_rotation, min(max_rotation, angle_diff))
     /// ```
     ///
-    /// # Scroll Button Example
+    /// # Common Mistakes
     /// ```python
-    /// scroll_offset = [0]
+    /// # ❌ WRONG - Using degrees directly
+    /// obj.rotation = 90  # This is 90 radians, not 90 degrees!
     ///
-    /// def scroll_up():
-    ///     scroll_offset[0] -= 10
-    ///     print(f"Scroll offset: {scroll_offset[0]}")
+    /// # ✅ CORRECT - Convert degrees to radians
+    /// obj.rotation = math.radians(90)  # 90 degrees
     ///
-    /// def scroll_down():
-    ///     scroll_offset[0] += 10
-    ///     print(f"Scroll offset: {scroll_offset[0]}")
+    /// # ✅ CORRECT - Use pi constants
+    /// obj.rotation = math.pi / 2  # 90 degrees
+    /// ```
     ///
-    /// up_btn = pyg.Button("▲", x=700, y=50, width=40, height=40)
-    /// up_btn.set_on_click(scroll_up)
-    /// up_btn.set_repeat_interval(150.0)  # Smooth scrolling
-    /// engine.ui.add(up_btn)
+    /// # Direction Reference
+    /// Assuming default orientation (0° = facing right):
+    /// - `math.radians(0)` = Right (East)
+    /// - `math.radians(90)` = Up (North)
+    /// - `math.radians(180)` = Left (West)
+    /// - `math.radians(270)` = Down (South)
     ///
-    /// down_btn = pyg.Button("▼", x=700, y=100, width=40, height=40)
-    /// down_btn.set_on_click(scroll_down)
-    /// down_btn.set_repeat_interval(150.0)
-    /// engine.ui.add(down_btn)
+    /// # See Also
+    /// - `examples/python_game_object_transform_demo.py` - Transform demonstrations
+    /// - `rotation` (getter) - Get current rotation
+    /// - `GameObject.rotation` - Direct property access
+    #[setter]
+    fn set_rotation(&mut self, rotation: f32) {
+        self.inner.transform_mut().set_rotation(rotation);
+        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::SetGameObjectRotation {
+                object_id: binding.object_id,
+                rotation,
+            });
+        }
+    }
+
+    /// Get the scale of this GameObject.
+    ///
+    /// Returns the object's scale as a `Vec2`. Scale controls the size of the object
+    /// relative to its original dimensions. Scale values are multiplicative:
+    /// - `1.0` = Original size (100%)
+    /// - `2.0` = Double size (200%)
+    /// - `0.5` = Half size (50%)
+    ///
+    /// # Returns
+    /// `Vec2` containing the scale factors:
+    /// - `x` - Horizontal scale factor
+    /// - `y` - Vertical scale factor
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// obj = pyg.GameObject("Sprite")
+    /// obj.scale = pyg.Vec2(2.0, 2.0)  # Double size
+    ///
+    /// # Get current scale
+    /// scale = obj.scale
+    /// print(f"Scale: {scale.x}x, {scale.y}x")  # "Scale: 2.0x, 2.0x"
+    ///
+    /// # Check if object is at original size
+    /// if obj.scale == pyg.Vec2(1.0, 1.0):
+    ///     print("Original size")
     /// ```
     ///
-    /// # Typical Interval Values
-    /// - **50-80ms**: Very fast, rapid increment (shooting, fast scrolling)
-    /// - **100-150ms**: Fast, comfortable for continuous actions
-    /// - **200-300ms**: Moderate, good for volume/brightness controls
-    /// - **500ms+**: Slow, deliberate repeating
+    /// # Non-Uniform Scaling
+    /// ```python
+    /// import pyg_engine as pyg
     ///
-    /// # Notes
-    /// - First callback fires immediately on trigger (no delay)
-    /// - Subsequent callbacks fire at the specified interval
-    /// - Works with both `"press"` and `"release"` trigger modes
-    /// - Setting `None` disables repeating (reverts to single fire)
+    /// obj = pyg.GameObject("StretchedSprite")
+    ///
+    /// # Different scale on each axis
+    /// obj.scale = pyg.Vec2(2.0, 0.5)  # Wide and short
+    /// obj.scale = pyg.Vec2(0.5, 2.0)  # Narrow and tall
+    ///
+    /// # Flip horizontally (negative scale)
+    /// obj.scale = pyg.Vec2(-1.0, 1.0)  # Mirror on X-axis
+    /// ```
     ///
     /// # See Also
-    /// - `examples/button_features_demo.py` - Repeat functionality demonstration
-    /// - `set_on_click()` - Set button callback
-    /// - `set_trigger_on()` - Configure trigger timing
-    fn set_repeat_interval(&mut self, interval_ms: Option<f32>) {
-        self.inner.set_repeat_interval_ms(interval_ms);
+    /// - `scale` (setter) - Set object scale
+    /// - `Vec2` - 2D vector class
+    /// - `examples/python_game_object_transform_demo.py` - Transform examples
+    #[getter]
+    fn scale(&self) -> PyVec2 {
+        let object = self.current_object();
+        PyVec2 {
+            inner: *object.transform().scale(),
+        }
+    }
+
+    /// Set the scale of this GameObject.
+    ///
+    /// Controls the size of the object relative to its original dimensions.
+    /// Scale is applied to the object's components and propagates through child objects
+    /// when the object is part of a hierarchy.
+    ///
+    /// # Arguments
+    /// * `scale` - Scale factors as `Vec2`:
+    ///   - `x` - Horizontal scale (1.0 = original width)
+    ///   - `y` - Vertical scale (1.0 = original height)
+    ///
+    /// # Scale Values
+    /// - **1.0** = Original size (100%)
+    /// - **> 1.0** = Larger (e.g., 2.0 = double size)
+    /// - **< 1.0** = Smaller (e.g., 0.5 = half size)
+    /// - **0.0** = Invisible (zero size)
+    /// - **Negative** = Flipped (mirrored)
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// obj = pyg.GameObject("Sprite")
+    ///
+    /// # Uniform scaling (same on both axes)
+    /// obj.scale = pyg.Vec2(2.0, 2.0)   # Double size
+    /// obj.scale = pyg.Vec2(0.5, 0.5)   # Half size
+    ///
+    /// # Non-uniform scaling
+    /// obj.scale = pyg.Vec2(3.0, 1.0)   # Stretched horizontally
+    /// obj.scale = pyg.Vec2(1.0, 0.5)   # Compressed vertically
+    ///
+    /// # Flipping
+    /// obj.scale = pyg.Vec2(-1.0, 1.0)  # Flip horizontally
+    /// obj.scale = pyg.Vec2(1.0, -1.0)  # Flip vertically
+    /// obj.scale = pyg.Vec2(-1.0, -1.0) # Flip both axes
+    /// ```
+    ///
+    /// # Pulsing Effect
+    /// ```python
+    /// import pyg_engine as pyg
+    /// import math
+    ///
+    /// def update(dt, engine, data):
+    ///     elapsed = data['elapsed']
+    ///     data['elapsed'] += dt
+    ///
+    ///     # Pulse between 0.5x and 1.5x size
+    ///     pulse = 1.0 + 0.5 * math.sin(elapsed * 2.0)
+    ///     data['obj'].scale = pyg.Vec2(pulse, pulse)
+    ///
+    ///     return True
+    ///
+    /// engine.run(update=update, user_data={'elapsed': 0.0, 'obj': obj})
+    /// ```
+    ///
+    /// # Grow Over Time
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// def update(dt, engine, data):
+    ///     obj = data['obj']
+    ///     grow_rate = 0.5  # 50% per second
+    ///
+    ///     # Grow uniformly
+    ///     current_scale = obj.scale.x  # Assuming uniform scale
+    ///     new_scale = current_scale + (grow_rate * dt)
+    ///     obj.scale = pyg.Vec2(new_scale, new_scale)
+    ///
+    ///     # Cap maximum size
+    ///     if obj.scale.x > 5.0:
+    ///         obj.scale = pyg.Vec2(5.0, 5.0)
+    ///
+    ///     return True
+    /// ```
+    ///
+    /// # See Also
+    /// - `scale` (getter) - Get current scale
+    /// - `Vec2` - 2D vector operations
+    /// - `examples/python_game_object_transform_demo.py` - Transform examples
+    #[setter]
+    fn set_scale(&mut self, scale: PyVec2) {
+        self.inner.transform_mut().set_scale(scale.inner);
+        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::SetGameObjectScale {
+                object_id: binding.object_id,
+                scale: scale.inner,
+            });
+        }
+    }
+
+    /// Manually update this GameObject.
+    ///
+    /// Triggers the object's update lifecycle, including all attached components.
+    /// This is typically called automatically by the engine, but can be invoked
+    /// manually for custom update logic or testing.
+    ///
+    /// # Arguments
+    /// * `time` - Optional `Time` object for delta time. If `None`, uses current time.
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// obj = pyg.GameObject("Player")
+    ///
+    /// # Manual update (uses current time)
+    /// obj.update()
+    ///
+    /// # Manual update with specific time
+    /// time = pyg.Time()
+    /// obj.update(time)
+    /// ```
+    ///
+    /// # Use Cases
+    /// - **Testing**: Manually tick objects in unit tests
+    /// - **Custom loops**: Control update timing outside the engine loop
+    /// - **Selective updates**: Update specific objects independently
+    ///
+    /// # Note
+    /// In normal usage, the engine calls `update()` automatically for all active
+    /// GameObjects each frame. Manual calling is rarely needed.
+    ///
+    /// # See Also
+    /// - `GameObject.active` - Control whether object updates automatically
+    #[pyo3(signature = (time=None))]
+    fn update(&self, time: Option<&PyTime>) {
+        if let Some(time) = time {
+            self.inner.update(&time.inner);
+        } else {
+            let local_time = RustTime::new();
+            self.inner.update(&local_time);
+        }
+    }
+
+    /// Check if this GameObject has a mesh component attached.
+    ///
+    /// Returns `True` if the object has a `MeshComponent` for rendering,
+    /// `False` otherwise.
+    ///
+    /// # Returns
+    /// - `True` - Object has a mesh component (is visible)
+    /// - `False` - Object has no mesh component (not rendered)
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// obj = pyg.GameObject("Sprite")
+    ///
+    /// # Check if mesh exists
+    /// if obj.has_mesh_component():
+    ///     print("Object has rendering")
+    /// else:
+    ///     print("Object is invisible")
+    ///
+    /// # Add mesh component
+    /// mesh = pyg.MeshComponent()
+    /// obj.add_mesh_component(mesh)
+    ///
+    /// # Now has mesh
+    /// assert obj.has_mesh_component() == True
+    /// ```
+    ///
+    /// # See Also
+    /// - `add_mesh_component()` - Add a mesh component
+    /// - `mesh_component()` - Get the mesh component
+    /// - `remove_mesh_component()` - Remove the mesh component
+    fn has_mesh_component(&self) -> bool {
+        self.current_object().has_mesh_component()
+    }
+
+    /// Add a mesh component to this GameObject.
+    ///
+    /// Attaches a `MeshComponent` to make the object visible. If a mesh component
+    /// already exists, it will be replaced.
+    ///
+    /// # Arguments
+    /// * `mesh_component` - The `MeshComponent` to attach
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// obj = pyg.GameObject("Sprite")
+    ///
+    /// # Create and configure mesh
+    /// mesh = pyg.MeshComponent()
+    /// mesh.set_geometry_rectangle(64.0, 64.0)
+    /// mesh.set_fill_color(pyg.Color.RED)
+    ///
+    /// # Attach to object
+    /// obj.add_mesh_component(mesh)
+    /// ```
+    ///
+    /// # Quick Setup
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// # Create object with mesh in one go
+    /// obj = pyg.GameObject("Player")
+    /// obj.position = pyg.Vec2(0.5, 0.5)
+    ///
+    /// mesh = pyg.MeshComponent()
+    /// mesh.set_geometry_circle(32.0)
+    /// mesh.set_image_path("assets/player.png")
+    ///
+    /// obj.add_mesh_component(mesh)
+    /// engine.add_game_object(obj)
+    /// ```
+    ///
+    /// Compatibility helper for mesh attachment.
+    ///
+    /// Prefer `add_component(mesh_component)` in new code so mesh, collider, transform,
+    /// and UI components all follow the same attachment API.
+    ///
+    /// # See Also
+    /// - `add_component()` - Canonical component attachment API
+    /// - `has_mesh_component()` - Check if mesh exists
+    /// - `mesh_component()` - Get the mesh component
+    /// - `MeshComponent` - Mesh component class
+    fn add_mesh_component(&mut self, mesh_component: &PyMeshComponent) {
+        self.inner.add_mesh_component(mesh_component.inner.clone());
+        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::AddComponent {
+                object_id: binding.object_id,
+                component: Box::new(mesh_component.inner.clone()),
+            });
+        }
+    }
+
+    /// Set the mesh component for this GameObject.
+    ///
+    /// Compatibility helper for attaching or replacing the `MeshComponent`.
+    ///
+    /// This is functionally identical to `add_mesh_component()`. Prefer
+    /// `add_component(mesh_component)` in new code.
+    ///
+    /// # Arguments
+    /// * `mesh_component` - The `MeshComponent` to attach
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// obj = pyg.GameObject("Sprite")
+    ///
+    /// mesh = pyg.MeshComponent()
+    /// mesh.set_geometry_rectangle(64.0, 64.0)
+    /// mesh.set_fill_color(pyg.Color.BLUE)
+    ///
+    /// obj.add_component(mesh)  # Preferred
+    /// ```
+    ///
+    /// # See Also
+    /// - `add_component()` - Preferred method in new code
+    /// - `add_mesh_component()` - Compatibility helper with same behavior
+    fn set_mesh_component(&mut self, mesh_component: &PyMeshComponent) {
+        self.add_mesh_component(mesh_component);
+    }
+
+    /// Remove the mesh component from this GameObject.
+    ///
+    /// Detaches and returns the object's `MeshComponent`, making it invisible.
+    /// If no mesh component exists, returns `None`.
+    ///
+    /// # Returns
+    /// - `Some(MeshComponent)` - The removed mesh component
+    /// - `None` - No mesh component was attached
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// obj = pyg.GameObject("Sprite")
+    /// mesh = pyg.MeshComponent()
+    /// obj.add_mesh_component(mesh)
+    ///
+    /// # Remove mesh (object becomes invisible)
+    /// removed_mesh = obj.remove_mesh_component()
+    ///
+    /// if removed_mesh:
+    ///     print("Mesh removed")
+    /// else:
+    ///     print("No mesh to remove")
+    /// ```
+    ///
+    /// # Temporary Invisibility
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// obj = pyg.GameObject("Sprite")
+    /// mesh = pyg.MeshComponent()
+    /// obj.add_mesh_component(mesh)
+    ///
+    /// # Hide by removing mesh
+    /// saved_mesh = obj.remove_mesh_component()
+    ///
+    /// # Later, restore mesh
+    /// if saved_mesh:
+    ///     obj.add_mesh_component(saved_mesh)
+    /// ```
+    ///
+    /// # Note
+    /// For temporary hiding, consider using `mesh.visible = False` instead,
+    /// which is simpler and doesn't require removing/re-adding the component.
+    ///
+    /// # See Also
+    /// - `has_mesh_component()` - Check if mesh exists
+    /// - `mesh_component()` - Get mesh without removing
+    /// - `MeshComponent.visible` - Toggle visibility without removing
+    fn remove_mesh_component(&mut self) -> Option<PyMeshComponent> {
+        let removed = self
+            .inner
+            .remove_mesh_component()
+            .map(|inner| PyMeshComponent {
+                inner,
+                runtime_binding: RefCell::new(None),
+            });
+        if let Some(binding) = self.runtime_binding.borrow().as_ref()
+            && let Some(mesh) = self.current_object().mesh_component()
+        {
+            let _ = binding.sender.send(EngineCommand::RemoveComponentById {
+                object_id: binding.object_id,
+                component_id: mesh.id(),
+            });
+        }
+        removed
+    }
+
+    /// Get a copy of this GameObject's mesh component.
+    ///
+    /// Returns a copy of the attached `MeshComponent` if one exists.
+    /// Changes to the returned mesh will **not** affect the original
+    /// (use direct mesh methods on the GameObject for in-place modification).
+    ///
+    /// # Returns
+    /// - `Some(MeshComponent)` - Copy of the mesh component
+    /// - `None` - No mesh component attached
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// obj = pyg.GameObject("Sprite")
+    /// mesh = pyg.MeshComponent()
+    /// obj.add_mesh_component(mesh)
+    ///
+    /// # Get mesh copy
+    /// mesh_copy = obj.mesh_component()
+    ///
+    /// if mesh_copy:
+    ///     print(f"Mesh visible: {mesh_copy.visible}")
+    /// ```
+    ///
+    /// # Note on Copying
+    /// ```python
+    /// # This returns a COPY, not a reference:
+    /// mesh_copy = obj.mesh_component()
+    /// mesh_copy.visible = False  # Does NOT affect obj's mesh!
+    ///
+    /// # To modify the object's mesh directly, use GameObject methods:
+    /// obj.set_mesh_visible(False)  # Modifies obj's mesh directly
+    /// ```
+    ///
+    /// # Cloning Objects
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// original = pyg.GameObject("Original")
+    /// mesh = pyg.MeshComponent()
+    /// mesh.set_geometry_circle(32.0)
+    /// original.add_mesh_component(mesh)
+    ///
+    /// # Clone object with mesh
+    /// clone = pyg.GameObject("Clone")
+    /// if original.mesh_component():
+    ///     clone.add_mesh_component(original.mesh_component())
+    /// ```
+    ///
+    /// # See Also
+    /// - `has_mesh_component()` - Check if mesh exists
+    /// - `remove_mesh_component()` - Remove and get mesh
+    /// - GameObject mesh methods: `set_mesh_visible()`, `set_mesh_fill_color()`, etc.
+    fn mesh_component(&self) -> Option<PyMeshComponent> {
+        let object = self.current_object();
+        object.mesh_component().cloned().map(|inner| PyMeshComponent {
+            runtime_binding: RefCell::new(
+                object
+                    .mesh_component()
+                    .and_then(|component| self.component_binding(component)),
+            ),
+            inner,
+        })
+    }
+
+    fn add_text_mesh_component(&mut self, text_mesh_component: &PyTextMeshComponent) {
+        self.inner
+            .add_component(Box::new(text_mesh_component.inner.clone()));
+        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::AddComponent {
+                object_id: binding.object_id,
+                component: Box::new(text_mesh_component.inner.clone()),
+            });
+        }
+    }
+
+    fn text_mesh_component(&self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        self.get_component_type(py, "TextMesh")
+    }
+
+    /// Set the mesh geometry to a rectangle.
+    ///
+    /// Configures the object's mesh as a rectangle with the specified dimensions.
+    /// If no mesh component exists, one is created automatically.
+    ///
+    /// # Arguments
+    /// * `width` - Rectangle width in pixels
+    /// * `height` - Rectangle height in pixels
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// obj = pyg.GameObject("Box")
+    /// obj.position = pyg.Vec2(0.2, 0.15)
+    ///
+    /// # Create 100x50 rectangle
+    /// obj.set_mesh_geometry_rectangle(100.0, 50.0)
+    /// obj.set_mesh_fill_color(pyg.Color.GREEN)
+    ///
+    /// engine.add_game_object(obj)
+    /// ```
+    ///
+    /// # Quick Sprite Setup
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// sprite = pyg.GameObject("Sprite")
+    /// sprite.position = pyg.Vec2(400.0, 300.0)
+    /// sprite.set_mesh_geometry_rectangle(64.0, 64.0)  # 64x64 square
+    /// sprite.set_mesh_image_path("assets/player.png")
+    /// ```
+    ///
+    /// # See Also
+    /// - `set_mesh_geometry_circle()` - Set mesh to circle
+    /// - `MeshComponent.set_geometry_rectangle()` - Direct mesh method
+    fn set_mesh_geometry_rectangle(&mut self, width: f32, height: f32) {
+        let mesh = self.ensure_mesh_component();
+        mesh.set_geometry(MeshGeometry::rectangle(width, height));
+        self.sync_runtime_mesh_component();
+    }
+
+    /// Set the mesh geometry to a circle.
+    ///
+    /// Configures the object's mesh as a circle with the specified radius and quality.
+    /// If no mesh component exists, one is created automatically.
+    ///
+    /// # Arguments
+    /// * `radius` - Circle radius in pixels
+    /// * `segments` - Number of segments for circle quality (default: 32)
+    ///   - Higher values = smoother circle, more vertices
+    ///   - Typical range: 16-64 segments
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// obj = pyg.GameObject("Ball")
+    /// obj.position = pyg.Vec2(200.0, 150.0)
+    ///
+    /// # Create smooth circle with radius 40
+    /// obj.set_mesh_geometry_circle(40.0, segments=64)
+    /// obj.set_mesh_fill_color(pyg.Color.RED)
+    ///
+    /// engine.add_game_object(obj)
+    /// ```
+    ///
+    /// # Segment Quality Comparison
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// # Low quality (fast, jagged)
+    /// low_poly = pyg.GameObject("LowPoly")
+    /// low_poly.set_mesh_geometry_circle(30.0, segments=8)
+    ///
+    /// # Medium quality (default)
+    /// medium = pyg.GameObject("Medium")
+    /// medium.set_mesh_geometry_circle(30.0, segments=32)
+    ///
+    /// # High quality (smooth, more expensive)
+    /// high_poly = pyg.GameObject("HighPoly")
+    /// high_poly.set_mesh_geometry_circle(30.0, segments=128)
+    /// ```
+    ///
+    /// # See Also
+    /// - `set_mesh_geometry_rectangle()` - Set mesh to rectangle
+    /// - `MeshComponent.set_geometry_circle()` - Direct mesh method
+    #[pyo3(signature = (radius, segments=32))]
+    fn set_mesh_geometry_circle(&mut self, radius: f32, segments: u32) {
+        let mesh = self.ensure_mesh_component();
+        mesh.set_geometry(MeshGeometry::circle(radius, segments));
+        self.sync_runtime_mesh_component();
+    }
+
+    /// Set the fill color of the mesh.
+    ///
+    /// Sets a solid color for the mesh. If no mesh component exists, one is
+    /// created automatically. Pass `None` to remove the color (use image only).
+    ///
+    /// # Arguments
+    /// * `color` - `Color` instance, or `None` to remove fill color
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// obj = pyg.GameObject("ColoredRect")
+    /// obj.set_mesh_geometry_rectangle(100.0, 100.0)
+    ///
+    /// # Set solid color
+    /// obj.set_mesh_fill_color(pyg.Color.BLUE)
+    ///
+    /// # Change color later
+    /// obj.set_mesh_fill_color(pyg.Color.RED)
+    ///
+    /// # Remove color (transparent/image only)
+    /// obj.set_mesh_fill_color(None)
+    /// ```
+    ///
+    /// # Color Animation
+    /// ```python
+    /// import pyg_engine as pyg
+    /// import math
+    ///
+    /// def update(dt, engine, data):
+    ///     elapsed = data['elapsed']
+    ///     data['elapsed'] += dt
+    ///
+    ///     # Pulse red channel
+    ///     red_value = (math.sin(elapsed * 2.0) + 1.0) / 2.0  # 0.0 to 1.0
+    ///     color = pyg.Color.new(red_value, 0.0, 0.0, 1.0)
+    ///     data['obj'].set_mesh_fill_color(color)
+    ///
+    ///     return True
+    /// ```
+    ///
+    /// # See Also
+    /// - `mesh_fill_color()` - Get current fill color
+    /// - `set_mesh_image_path()` - Set texture image
+    /// - `Color` - Color class with creation methods
+    fn set_mesh_fill_color(&mut self, color: Option<PyColor>) {
+        let color_inner = color.map(|c| c.inner);
+        let updated_mesh = {
+            let mesh = self.ensure_mesh_component();
+            mesh.set_fill_color(color_inner);
+            mesh.clone()
+        };
+
+        // Update runtime object if it exists
+        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::SetGameObjectMeshComponent {
+                object_id: binding.object_id,
+                mesh: updated_mesh,
+            });
+        }
+    }
+
+    /// Get the fill color of the mesh.
+    ///
+    /// Returns the mesh's solid fill color, or `None` if no color is set or
+    /// no mesh exists.
+    ///
+    /// # Returns
+    /// - `Some(Color)` - The mesh fill color
+    /// - `None` - No color set or no mesh component
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// obj = pyg.GameObject("Sprite")
+    /// obj.set_mesh_fill_color(pyg.Color.BLUE)
+    ///
+    /// # Get current color
+    /// color = obj.mesh_fill_color()
+    /// if color:
+    ///     print(f"Fill color: {color}")
+    /// ```
+    ///
+    /// # See Also
+    /// - `set_mesh_fill_color()` - Set the fill color
+    fn mesh_fill_color(&self) -> Option<PyColor> {
+        self.inner
+            .mesh_component()
+            .and_then(|mesh| mesh.fill_color().copied())
+            .map(|inner| PyColor { inner })
+    }
+
+    /// Set the image/texture path for the mesh.
+    ///
+    /// Loads and applies an image texture to the mesh. If no mesh component exists,
+    /// one is created automatically. Pass `None` to remove the texture (use color only).
+    ///
+    /// # Arguments
+    /// * `image_path` - Path to image file (PNG, JPG, etc.), or `None` to remove
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// sprite = pyg.GameObject("Sprite")
+    /// sprite.set_mesh_geometry_rectangle(64.0, 64.0)
+    ///
+    /// # Load texture
+    /// sprite.set_mesh_image_path("assets/player.png")
+    ///
+    /// # Change texture
+    /// sprite.set_mesh_image_path("assets/enemy.png")
+    ///
+    /// # Remove texture (use color instead)
+    /// sprite.set_mesh_image_path(None)
+    /// sprite.set_mesh_fill_color(pyg.Color.BLUE)
+    /// ```
+    ///
+    /// # Sprite Animation
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// class AnimatedSprite:
+    ///     def __init__(self, obj, frames):
+    ///         self.obj = obj
+    ///         self.frames = frames  # ["frame0.png", "frame1.png", ...]
+    ///         self.current_frame = 0
+    ///         self.frame_time = 0.0
+    ///         self.frame_duration = 0.1  # seconds per frame
+    ///
+    ///     def update(self, dt):
+    ///         self.frame_time += dt
+    ///         if self.frame_time >= self.frame_duration:
+    ///             self.frame_time = 0.0
+    ///             self.current_frame = (self.current_frame + 1) % len(self.frames)
+    ///             self.obj.set_mesh_image_path(self.frames[self.current_frame])
+    /// ```
+    ///
+    /// # See Also
+    /// - `mesh_image_path()` - Get current image path
+    /// - `set_mesh_fill_color()` - Set solid color
+    fn set_mesh_image_path(&mut self, image_path: Option<String>) {
+        let mesh = self.ensure_mesh_component();
+        mesh.set_image_path(image_path);
+        self.sync_runtime_mesh_component();
+    }
+
+    /// Get the image/texture path of the mesh.
+    ///
+    /// Returns the path to the mesh's texture image, or `None` if no image is set
+    /// or no mesh exists.
+    ///
+    /// # Returns
+    /// - `Some(String)` - Path to the image file
+    /// - `None` - No image set or no mesh component
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// sprite = pyg.GameObject("Sprite")
+    /// sprite.set_mesh_image_path("assets/player.png")
+    ///
+    /// # Get current texture path
+    /// path = sprite.mesh_image_path()
+    /// if path:
+    ///     print(f"Current texture: {path}")
+    /// ```
+    ///
+    /// # See Also
+    /// - `set_mesh_image_path()` - Set the image path
+    fn mesh_image_path(&self) -> Option<String> {
+        self.inner
+            .mesh_component()
+            .and_then(|mesh| mesh.image_path().map(|path| path.to_string()))
+    }
+
+    /// Set the visibility of the mesh.
+    ///
+    /// Controls whether the mesh is rendered. If no mesh component exists, one is
+    /// created automatically.
+    ///
+    /// # Arguments
+    /// * `visible` - `True` to show, `False` to hide
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// obj = pyg.GameObject("Sprite")
+    /// obj.set_mesh_geometry_rectangle(64.0, 64.0)
+    /// obj.set_mesh_fill_color(pyg.Color.RED)
+    ///
+    /// # Hide mesh
+    /// obj.set_mesh_visible(False)
+    ///
+    /// # Show mesh
+    /// obj.set_mesh_visible(True)
+    /// ```
+    ///
+    /// # Blinking Effect
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// def update(dt, engine, data):
+    ///     data['blink_timer'] += dt
+    ///
+    ///     # Blink every 0.5 seconds
+    ///     if data['blink_timer'] >= 0.5:
+    ///         data['blink_timer'] = 0.0
+    ///         current = data['obj'].mesh_visible()
+    ///         data['obj'].set_mesh_visible(not current)
+    ///
+    ///     return True
+    ///
+    /// engine.run(update=update, user_data={'blink_timer': 0.0, 'obj': obj})
+    /// ```
+    ///
+    /// # See Also
+    /// - `mesh_visible()` - Get visibility state
+    /// - `GameObject.active` - Control entire object update/render
+    fn set_mesh_visible(&mut self, visible: bool) {
+        let mesh = self.ensure_mesh_component();
+        mesh.set_visible(visible);
+        self.sync_runtime_mesh_component();
+    }
+
+    /// Get the visibility state of the mesh.
+    ///
+    /// Returns whether the mesh is currently visible, or `None` if no mesh exists.
+    ///
+    /// # Returns
+    /// - `Some(True)` - Mesh is visible
+    /// - `Some(False)` - Mesh is hidden
+    /// - `None` - No mesh component exists
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// obj = pyg.GameObject("Sprite")
+    /// obj.set_mesh_visible(False)
+    ///
+    /// # Check visibility
+    /// visible = obj.mesh_visible()
+    /// if visible == False:
+    ///     print("Mesh is hidden")
+    /// ```
+    ///
+    /// # See Also
+    /// - `set_mesh_visible()` - Set visibility
+    fn mesh_visible(&self) -> Option<bool> {
+        self.inner.mesh_component().map(|mesh| mesh.visible())
+    }
+
+    /// Set the draw order (z-index) of the mesh.
+    ///
+    /// Controls rendering order. Higher values render on top of lower values.
+    /// If no mesh component exists, one is created automatically.
+    ///
+    /// # Arguments
+    /// * `draw_order` - Rendering layer (higher = on top)
+    ///   - Typical range: -10.0 to 10.0
+    ///   - Default: 0.0
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// # Background layer
+    /// background = pyg.GameObject("Background")
+    /// background.set_mesh_draw_order(-5.0)
+    ///
+    /// # Game objects (default layer)
+    /// player = pyg.GameObject("Player")
+    /// player.set_mesh_draw_order(0.0)
+    ///
+    /// # UI elements (foreground)
+    /// ui_panel = pyg.GameObject("UI")
+    /// ui_panel.set_mesh_draw_order(10.0)
+    /// ```
+    ///
+    /// # Layering Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// # Create layers from back to front
+    /// layers = [
+    ///     ("Sky", -10.0),
+    ///     ("Background", -5.0),
+    ///     ("Terrain", -2.0),
+    ///     ("Player", 0.0),
+    ///     ("Effects", 2.0),
+    ///     ("UI", 10.0),
+    /// ]
+    ///
+    /// for name, depth in layers:
+    ///     obj = pyg.GameObject(name)
+    ///     obj.set_mesh_geometry_rectangle(800.0, 600.0)
+    ///     obj.set_mesh_draw_order(depth)
+    ///     engine.add_game_object(obj)
+    /// ```
+    ///
+    /// # See Also
+    /// - `mesh_draw_order()` - Get current draw order
+    /// - `DrawCommand` - Similar draw order for immediate drawing
+    fn set_mesh_draw_order(&mut self, draw_order: f32) {
+        let mesh = self.ensure_mesh_component();
+        mesh.set_draw_order(draw_order);
+        self.sync_runtime_mesh_component();
+    }
+
+    /// Get the draw order (z-index) of the mesh.
+    ///
+    /// Returns the rendering layer of the mesh, or `None` if no mesh exists.
+    ///
+    /// # Returns
+    /// - `Some(f32)` - The draw order value
+    /// - `None` - No mesh component exists
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// obj = pyg.GameObject("Sprite")
+    /// obj.set_mesh_draw_order(5.0)
+    ///
+    /// # Get draw order
+    /// order = obj.mesh_draw_order()
+    /// if order:
+    ///     print(f"Draw order: {order}")
+    /// ```
+    ///
+    /// # See Also
+    /// - `set_mesh_draw_order()` - Set the draw order
+    fn mesh_draw_order(&self) -> Option<f32> {
+        self.inner.mesh_component().map(|mesh| mesh.draw_order())
+    }
+
+    /// Set the object type for specialized rendering or behavior.
+    ///
+    /// Marks the object as a specific type, which affects how the engine handles it.
+    /// Most projects only need the default `"GameObject"` type plus `"UIObject"` for
+    /// objects that carry UI components.
+    ///
+    /// # Arguments
+    /// * `object_type` - Type string (case-sensitive):
+    ///   - `"UIObject"` - **UI element** (rendered in screen space, ignores camera)
+    ///   - `"GameObject"` - **Standard object** (world space, default)
+    ///   - `"ParticleSystem"` - Particle system (reserved for future use)
+    ///   - `"Sound"` - Audio source (reserved for future use)
+    ///   - `"Light"` - Light source (reserved for future use)
+    ///   - `"Camera"` - Camera object (reserved for future use)
+    ///
+    /// # UI Objects
+    ///
+    /// **UIObject** is intended for objects that use UI components such as buttons,
+    /// panels, and labels. Those components render in screen space and ignore the
+    /// world camera.
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// # Standard world-space object
+    /// player = pyg.GameObject("Player")
+    /// player.set_object_type("GameObject")  # Default, usually not needed
+    ///
+    /// # UI button (screen space)
+    /// button = pyg.GameObject("PlayButton")
+    /// button.set_object_type("UIObject")  # Renders in screen space
+    /// ```
+    ///
+    /// # UI Button Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// engine = pyg.Engine()
+    ///
+    /// # Create UI button object
+    /// button_obj = pyg.GameObject("Button")
+    /// button_obj.set_object_type("UIObject")  # Mark as UI
+    ///
+    /// # Add button component
+    /// button = pyg.ButtonComponent("PlayButton", 100, 100, 200, 50)
+    /// button.set_text("Play Game")
+    /// button.set_on_click(lambda: print("Clicked!"))
+    /// button_obj.add_component(button)
+    ///
+    /// engine.add_game_object(button_obj)
+    /// engine.run(title="UI Demo")
+    /// ```
+    ///
+    /// # Preferred High-Level UI API
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// engine = pyg.Engine()
+    /// panel = pyg.Panel(x=20, y=20, width=220, height=120)
+    /// button = pyg.Button("Play", x=16, y=16, width=120, height=40)
+    /// panel.add_child(button)
+    /// engine.ui.add(panel)
+    /// ```
+    ///
+    /// # See Also
+    /// - `examples/ui_demo.py` - Complete UI system example
+    /// - `ButtonComponent`, `PanelComponent`, `LabelComponent` - UI components
+    fn set_object_type(&mut self, object_type: &str) {
+        use crate::core::game_object::ObjectType;
+        let obj_type = match object_type {
+            "UIObject" => ObjectType::UIObject,
+            "ParticleSystem" => ObjectType::ParticleSystem,
+            "Sound" => ObjectType::Sound,
+            "Light" => ObjectType::Light,
+            "Camera" => ObjectType::Camera,
+            _ => ObjectType::GameObject,
+        };
+        self.inner.set_object_type(obj_type);
+    }
+
+    /// Add a component to this GameObject.
+    ///
+    /// This is the canonical attachment API for runtime components. It accepts mesh,
+    /// transform, collider, and UI component types. If you attach a UI component, the
+    /// object should typically be marked as `"UIObject"` for screen-space rendering.
+    ///
+    /// # Supported Components
+    /// - `MeshComponent` - 2D rendering
+    /// - `TransformComponent` - Replace the object's local transform
+    /// - `Collider` - Collision / trigger behavior
+    /// - `ButtonComponent` - Clickable button with callback
+    /// - `PanelComponent` - Rectangular UI container/background
+    /// - `LabelComponent` - Text label for UI
+    ///
+    /// # Arguments
+    /// * `component` - The component to attach
+    ///
+    /// # Returns
+    /// - `Ok(())` - Component added successfully
+    /// - `Err(TypeError)` - Invalid component type
+    ///
+    /// # World Component Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// player = pyg.GameObject("Player")
+    /// mesh = pyg.MeshComponent()
+    /// mesh.set_geometry_rectangle(64.0, 64.0)
+    /// mesh.set_fill_color(pyg.Color.BLUE)
+    /// player.add_component(mesh)
+    ///
+    /// collider = pyg.Collider("PlayerCollider")
+    /// player.add_component(collider)
+    /// ```
+    ///
+    /// # UI Component Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// engine = pyg.Engine()
+    ///
+    /// # Panel background
+    /// panel_obj = pyg.GameObject("Panel")
+    /// panel_obj.set_object_type("UIObject")
+    /// panel = pyg.PanelComponent("Background", 50, 50, 300, 200)
+    /// panel.set_background_color(pyg.Color.rgba(50, 50, 50, 200))
+    /// panel_obj.add_component(panel)
+    /// engine.add_game_object(panel_obj)
+    ///
+    /// # Title label
+    /// label_obj = pyg.GameObject("Title")
+    /// label_obj.set_object_type("UIObject")
+    /// label = pyg.LabelComponent("Game Menu", 100, 70, 24.0)
+    /// label.set_color(1.0, 1.0, 1.0, 1.0)
+    /// label_obj.add_component(label)
+    /// engine.add_game_object(label_obj)
+    ///
+    /// # Play button
+    /// button_obj = pyg.GameObject("PlayButton")
+    /// button_obj.set_object_type("UIObject")
+    /// button = pyg.ButtonComponent("Play", 100, 120, 150, 40)
+    /// button.set_text("Play Game")
+    /// button.set_on_click(lambda: print("Starting game..."))
+    /// button_obj.add_component(button)
+    /// engine.add_game_object(button_obj)
+    ///
+    /// engine.run(title="UI Example")
+    /// ```
+    ///
+    /// # Multiple Components (Not Recommended)
+    /// ```python
+    /// # While technically possible, avoid adding multiple components of the same type
+    /// # to a single GameObject. Instead, create separate GameObjects for each UI element.
+    ///
+    /// # ❌ DON'T: Multiple buttons on one object
+    /// obj = pyg.GameObject("Buttons")
+    /// obj.add_component(button1)
+    /// obj.add_component(button2)  # Replaces button1!
+    ///
+    /// # ✅ DO: Separate objects for each button
+    /// obj1 = pyg.GameObject("Button1")
+    /// obj1.add_component(button1)
+    ///
+    /// obj2 = pyg.GameObject("Button2")
+    /// obj2.add_component(button2)
+    /// ```
+    ///
+    /// # Errors
+    /// Raises `TypeError` if the component is not a supported runtime component type:
+    /// ```python
+    /// obj.add_component(mesh)      # OK
+    /// obj.add_component(collider)  # OK
+    /// obj.add_component(button)    # OK
+    /// ```
+    ///
+    /// # See Also
+    /// - `set_object_type()` - Mark object as UIObject
+    /// - `ButtonComponent` - Clickable button
+    /// - `PanelComponent` - UI panel/background
+    /// - `LabelComponent` - Text label
+    /// - `TextInputComponent` - Single-line text field
+    /// - `SliderComponent` - Draggable value slider
+    /// - `CheckboxComponent` - Checkbox with label
+    /// - `ToggleSwitchComponent` - On/off switch with label
+    /// - `ScrollViewComponent` - Scrollable container that clips its children
+    /// - `ProgressBarComponent` - Bar showing a 0-1 value
+    /// - `CustomWidgetComponent` - Hit area forwarding UI events to Python
+    /// - `CanvasComponent` - Area drawn by a Python callback in local coordinates
+    /// - `ImageComponent` - Texture with stretch/fit/fill/tile scaling
+    /// - `StackComponent` - Container that lines up its children (VStack/HStack)
+    /// - `CurveEditorComponent` - Graph editor for an AnimationCurve
+    /// - `RadioButtonComponent` - Radio button in a RadioGroup
+    /// - `ListViewComponent` - Virtualized list that fetches only its visible rows
+    /// - `examples/ui_demo.py` - Complete UI examples
+    fn add_component(&mut self, component: &Bound<'_, PyAny>) -> PyResult<()> {
+        let component_box: Box<dyn ComponentTrait> =
+            if let Ok(button) = component.extract::<PyRef<PyButtonComponent>>() {
+                Box::new(button.inner.clone())
+            } else if let Ok(panel) = component.extract::<PyRef<PyPanelComponent>>() {
+                Box::new(panel.inner.clone())
+            } else if let Ok(label) = component.extract::<PyRef<PyLabelComponent>>() {
+                Box::new(label.inner.clone())
+            } else if let Ok(text_input) = component.extract::<PyRef<PyTextInputComponent>>() {
+                Box::new(text_input.inner.clone())
+            } else if let Ok(slider) = component.extract::<PyRef<PySliderComponent>>() {
+                Box::new(slider.inner.clone())
+            } else if let Ok(checkbox) = component.extract::<PyRef<PyCheckboxComponent>>() {
+                Box::new(checkbox.inner.clone())
+            } else if let Ok(switch) = component.extract::<PyRef<PyToggleSwitchComponent>>() {
+                Box::new(switch.inner.clone())
+            } else if let Ok(scroll_view) = component.extract::<PyRef<PyScrollViewComponent>>() {
+                Box::new(scroll_view.inner.clone())
+            } else if let Ok(bar) = component.extract::<PyRef<PyProgressBarComponent>>() {
+                Box::new(bar.inner.clone())
+            } else if let Ok(widget) = component.extract::<PyRef<PyCustomWidgetComponent>>() {
+                Box::new(widget.inner.clone())
+            } else if let Ok(canvas) = component.extract::<PyRef<PyCanvasComponent>>() {
+                Box::new(canvas.inner.clone())
+            } else if let Ok(image) = component.extract::<PyRef<PyImageComponent>>() {
+                Box::new(image.inner.clone())
+            } else if let Ok(stack) = component.extract::<PyRef<PyStackComponent>>() {
+                Box::new(stack.inner.clone())
+            } else if let Ok(editor) = component.extract::<PyRef<PyCurveEditorComponent>>() {
+                Box::new(editor.inner.clone())
+            } else if let Ok(radio) = component.extract::<PyRef<PyRadioButtonComponent>>() {
+                Box::new(radio.inner.clone())
+            } else if let Ok(list) = component.extract::<PyRef<PyListViewComponent>>() {
+                Box::new(list.inner.clone())
+            } else if let Ok(mesh) = component.extract::<PyRef<PyMeshComponent>>() {
+                Box::new(mesh.inner.clone())
+            } else if let Ok(text_mesh) = component.extract::<PyRef<PyTextMeshComponent>>() {
+                Box::new(text_mesh.inner.clone())
+            } else if let Ok(label) = component.extract::<PyRef<PyWorldLabelComponent>>() {
+                Box::new(label.inner.clone())
+            } else if let Ok(transform) = component.extract::<PyRef<PyTransformComponent>>() {
+                Box::new(transform.inner.clone())
+            } else if let Ok(collider) = component.extract::<PyRef<PyCollider>>() {
+                Box::new(collider.component.clone())
+            } else if let Ok(source) = component.extract::<PyRef<PyAudioSourceComponent>>() {
+                // Bind it so `play()` and `stop()` reach the engine.
+                if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+                    *source.runtime_binding.borrow_mut() = Some(ComponentRuntimeBinding {
+                        sender: binding.sender.clone(),
+                        object_id: binding.object_id,
+                        component_id: source.inner.id(),
+                    });
+                }
+                Box::new(source.inner.clone())
+            } else if let Ok(listener) = component.extract::<PyRef<PyAudioListener>>() {
+                if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+                    *listener.runtime_binding.borrow_mut() = Some(ComponentRuntimeBinding {
+                        sender: binding.sender.clone(),
+                        object_id: binding.object_id,
+                        component_id: listener.inner.id(),
+                    });
+                }
+                Box::new(listener.inner.clone())
+            } else if PyScriptBehaviour::is_script(component) {
+                let class_name = component.get_type().name()?.to_string();
+                let script = ScriptComponent::new(
+                    class_name,
+                    Box::new(PyScriptBehaviour {
+                        script: component.clone().unbind(),
+                    }),
+                );
+                component.setattr("id", script.id())?;
+                if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+                    bind_script_game_object(component, binding)?;
+                }
+                Box::new(script)
+            } else {
+                return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                    "Component must be MeshComponent, TextMeshComponent, WorldLabelComponent, TransformComponent, ButtonComponent, PanelComponent, LabelComponent, TextInputComponent, SliderComponent, CheckboxComponent, ToggleSwitchComponent, ScrollViewComponent, ProgressBarComponent, CustomWidgetComponent, CanvasComponent, ImageComponent, StackComponent, CurveEditorComponent, RadioButtonComponent, ListViewComponent, Collider, AudioSourceComponent, AudioListener, or a script object with on_start, update, fixed_update or on_collision_* methods",
+                ));
+            };
+
+        let runtime_component = component_box.clone();
+        self.inner.add_component(component_box);
+        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::AddComponent {
+                object_id: binding.object_id,
+                component: runtime_component,
+            });
+        }
+        Ok(())
+    }
+
+    fn get_component_name(&self, py: Python<'_>, name: &str) -> PyResult<Option<Py<PyAny>>> {
+        let object = self.current_object();
+        object
+            .get_component_by_name(name)
+            .map(|component| component_to_pyobject(py, component, self.component_binding(component)))
+            .transpose()
+    }
+
+    fn get_component_id(&self, py: Python<'_>, component_id: u32) -> PyResult<Option<Py<PyAny>>> {
+        let object = self.current_object();
+        object
+            .get_component_by_id(component_id)
+            .map(|component| component_to_pyobject(py, component, self.component_binding(component)))
+            .transpose()
+    }
+
+    fn get_component_type(
+        &self,
+        py: Python<'_>,
+        component_type: &str,
+    ) -> PyResult<Option<Py<PyAny>>> {
+        let object = self.current_object();
+        for component in object.all_components() {
+            if component_matches_type(component, component_type) {
+                return component_to_pyobject(py, component, self.component_binding(component))
+                    .map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    fn get_components_type(
+        &self,
+        py: Python<'_>,
+        component_type: &str,
+    ) -> PyResult<Vec<Py<PyAny>>> {
+        let object = self.current_object();
+        object
+            .all_components()
+            .into_iter()
+            .filter(|component| component_matches_type(*component, component_type))
+            .map(|component| component_to_pyobject(py, component, self.component_binding(component)))
+            .collect()
+    }
+
+    #[pyo3(name = "get_component")]
+    fn py_get_component(
+        &self,
+        py: Python<'_>,
+        component_type: &Bound<'_, PyAny>,
+    ) -> PyResult<Option<Py<PyAny>>> {
+        let component_type_name = component_type_name_from_py(component_type)?;
+        self.get_component_type(py, &component_type_name)
+    }
+
+    #[pyo3(name = "get_components")]
+    fn py_get_components(
+        &self,
+        py: Python<'_>,
+        component_type: &Bound<'_, PyAny>,
+    ) -> PyResult<Vec<Py<PyAny>>> {
+        let component_type_name = component_type_name_from_py(component_type)?;
+        self.get_components_type(py, &component_type_name)
+    }
+
+    fn remove_component_name(&mut self, name: &str) -> bool {
+        let removed = self.inner.remove_component_by_name(name).is_some();
+        if removed && let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::RemoveComponentByName {
+                object_id: binding.object_id,
+                name: name.to_string(),
+            });
+        }
+        removed
+    }
+
+    fn remove_component_id(&mut self, component_id: u32) -> bool {
+        let removed = self.inner.remove_component_by_id(component_id).is_some();
+        if removed && let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::RemoveComponentById {
+                object_id: binding.object_id,
+                component_id,
+            });
+        }
+        removed
+    }
+
+    fn remove_component(&mut self, component: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let component_id = component_id_from_py(component)?;
+        Ok(self.remove_component_id(component_id))
+    }
+
+    /// Enable or disable a component at runtime.
+    ///
+    /// The component is matched by name first, then by type (e.g. `"Collider"`,
+    /// `"Mesh"`). Disabled components stay attached but are skipped by the engine:
+    /// colliders leave the collision world, meshes and labels stop rendering, and
+    /// update hooks stop running. `on_enable`/`on_disable` fire when the component's
+    /// effective state changes.
+    ///
+    /// # Arguments
+    /// * `key` - Component name or type
+    /// * `enabled` - `True` to enable, `False` to disable
+    ///
+    /// # Returns
+    /// `True` if a matching component was found.
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// ghost = pyg.GameObject("Ghost")
+    /// ghost.add_component(pyg.Collider("Body"))
+    /// engine.add_game_object(ghost)
+    ///
+    /// ghost.set_component_enabled("Collider", False)  # pass through walls
+    /// ghost.set_component_enabled("Collider", True)
+    /// ```
+    ///
+    /// # See Also
+    /// - `is_component_enabled()` - Read a component's enabled flag
+    /// - `enabled` - Enable or disable the whole object
+    fn set_component_enabled(&mut self, key: &str, enabled: bool) -> bool {
+        let found = self.inner.set_component_enabled(key, enabled).is_some();
+        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::SetComponentEnabled {
+                object_id: binding.object_id,
+                key: key.to_string(),
+                enabled,
+            });
+        }
+        found
+    }
+
+    /// Get a component's own enabled flag, matched by name first, then by type.
+    ///
+    /// Returns `None` if no component matches.
+    fn is_component_enabled(&self, key: &str) -> Option<bool> {
+        self.current_object()
+            .get_component_by_name_or_type(key)
+            .map(|component| component.is_enabled_self())
+    }
+}
+
+// ========== MeshGeometry Bindings ==========
+
+#[pyclass(name = "MeshGeometry")]
+#[derive(Clone)]
+pub struct PyMeshGeometry {
+    inner: MeshGeometry,
+}
+
+#[pymethods]
+impl PyMeshGeometry {
+    /// Vertices and UVs may be `(x, y)` pairs, `Vec2`s or float arrays
+    /// shaped `(n, 2)`.
+    #[new]
+    #[pyo3(signature = (vertices=None, indices=None, uvs=None))]
+    fn new(
+        vertices: Option<&Bound<'_, PyAny>>,
+        indices: Option<Vec<u32>>,
+        uvs: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Self> {
+        let vertices = vertices.map(extract_points).transpose()?.unwrap_or_default();
+        let indices = indices.unwrap_or_default();
+        let uvs = match uvs {
+            Some(uvs) => extract_points(uvs)?,
+            None => vec![Vec2::new(0.0, 0.0); vertices.len()],
+        };
+
+        if uvs.len() != vertices.len() {
+            return Err(PyRuntimeError::new_err(
+                "uvs must have the same length as vertices",
+            ));
+        }
+
+        let mesh_vertices = vertices
+            .into_iter()
+            .zip(uvs)
+            .map(|(position, uv)| crate::core::component::MeshVertex::new(position, uv))
+            .collect();
+
+        Ok(Self {
+            inner: MeshGeometry::new(mesh_vertices, indices),
+        })
+    }
+
+    #[staticmethod]
+    fn rectangle(width: f32, height: f32) -> Self {
+        Self {
+            inner: MeshGeometry::rectangle(width, height),
+        }
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (radius, segments=32))]
+    fn circle(radius: f32, segments: u32) -> Self {
+        Self {
+            inner: MeshGeometry::circle(radius, segments),
+        }
+    }
+
+    fn vertices(&self) -> Vec<(f32, f32)> {
+        self.inner
+            .vertices()
+            .iter()
+            .map(|vertex| (vertex.position().x(), vertex.position().y()))
+            .collect()
+    }
+
+    fn uvs(&self) -> Vec<(f32, f32)> {
+        self.inner
+            .vertices()
+            .iter()
+            .map(|vertex| (vertex.uv().x(), vertex.uv().y()))
+            .collect()
+    }
+
+    /// Vertex positions as a `float32` buffer shaped `(n, 2)`, for
+    /// `numpy.asarray(geometry.vertex_array())`.
+    fn vertex_array<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        points_to_buffer(py, self.inner.vertices().iter().map(|vertex| vertex.position()))
+    }
+
+    /// UVs as a `float32` buffer shaped `(n, 2)`, like `vertex_array()`.
+    fn uv_array<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        points_to_buffer(py, self.inner.vertices().iter().map(|vertex| vertex.uv()))
+    }
+
+    fn indices(&self) -> Vec<u32> {
+        self.inner.indices().to_vec()
+    }
+}
+
+// ========== MeshComponent Bindings ==========
+
+/// Python wrapper for MeshComponent.
+/// 2D mesh rendering component for GameObjects.
+///
+/// `MeshComponent` handles rendering of 2D shapes and images. Attach it to a GameObject
+/// to make it visible. Supports:
+/// - **Geometry**: Rectangles, circles (custom geometry coming soon)
+/// - **Rendering**: Solid colors, images/textures
+/// - **Control**: Visibility, draw order (z-index)
+///
+/// # Basic Usage
+///
+/// ## Rectangle with Color
+/// ```python
+/// from pyg_engine import GameObject, MeshComponent, Color, Vec2
+///
+/// obj = GameObject("ColoredRect")
+/// obj.set_position(Vec2(100.0, 100.0))
+///
+/// mesh = MeshComponent()
+/// mesh.set_geometry_rectangle(64.0, 64.0)  # 64x64 square
+/// mesh.set_fill_color(Color.BLUE)
+/// obj.add_component(mesh)
+///
+/// engine.add_game_object(obj)
+/// ```
+///
+/// ## Circle with Image
+/// ```python
+/// from pyg_engine import GameObject, MeshComponent, Vec2
+///
+/// obj = GameObject("Sprite")
+/// obj.set_position(Vec2(200.0, 150.0))
+///
+/// mesh = MeshComponent()
+/// mesh.set_geometry_circle(32.0, segments=64)  # Radius 32, smooth circle
+/// mesh.set_image_path("assets/player.png")
+/// obj.add_component(mesh)
+///
+/// engine.add_game_object(obj)
+/// ```
+///
+/// # Draw Order
+///
+/// Control rendering order with `draw_order` (higher values draw on top):
+/// ```python
+/// background.draw_order = -5.0  # Draw behind
+/// player.draw_order = 0.0       # Default layer
+/// ui_element.draw_order = 10.0  # Draw in front
+/// ```
+///
+/// # Visibility
+///
+/// Toggle visibility without removing the object:
+/// ```python
+/// mesh.visible = False  # Hide
+/// mesh.visible = True   # Show
+/// ```
+///
+/// # See Also
+/// - `examples/python_mesh_demo.py` - Complete mesh examples
+/// - `examples/python_game_object_transform_demo.py` - Transform + rendering
+#[pyclass(name = "MeshComponent", unsendable)]
+#[derive(Clone)]
+pub struct PyMeshComponent {
+    inner: MeshComponent,
+    runtime_binding: RefCell<Option<ComponentRuntimeBinding>>,
+}
+
+impl PyMeshComponent {
+    fn sync_runtime(&self) {
+        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::SetGameObjectMeshComponent {
+                object_id: binding.object_id,
+                mesh: self.inner.clone(),
+            });
+        }
+    }
+}
+
+#[pymethods]
+impl PyMeshComponent {
+    #[new]
+    #[pyo3(signature = (name=None))]
+    fn new(name: Option<String>) -> Self {
+        Self {
+            inner: MeshComponent::new(name.unwrap_or_else(|| "Mesh Renderer".to_string())),
+            runtime_binding: RefCell::new(None),
+        }
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.inner.is_enabled_self()
+    }
+
+    #[setter(enabled)]
+    fn set_enabled_property(&mut self, enabled: bool) {
+        self.inner.set_enabled_self(enabled);
+    }
+
+    fn geometry(&self) -> PyMeshGeometry {
+        PyMeshGeometry {
+            inner: self.inner.geometry().clone(),
+        }
+    }
+
+    fn set_geometry(&mut self, geometry: &PyMeshGeometry) {
+        self.inner.set_geometry(geometry.inner.clone());
+        self.sync_runtime();
+    }
+
+    fn set_geometry_rectangle(&mut self, width: f32, height: f32) {
+        self.set_geometry(&PyMeshGeometry::rectangle(width, height));
+    }
+
+    #[pyo3(signature = (radius, segments=32))]
+    fn set_geometry_circle(&mut self, radius: f32, segments: u32) {
+        self.set_geometry(&PyMeshGeometry::circle(radius, segments));
+    }
+
+    fn set_fill_color(&mut self, color: Option<PyColor>) {
+        self.inner.set_fill_color(color.map(|c| c.inner));
+        self.sync_runtime();
+    }
+
+    fn fill_color(&self) -> Option<PyColor> {
+        self.inner
+            .fill_color()
+            .copied()
+            .map(|inner| PyColor { inner })
+    }
+
+    fn set_image_path(&mut self, image_path: Option<String>) {
+        self.inner.set_image_path(image_path);
+        self.sync_runtime();
+    }
+
+    fn image_path(&self) -> Option<String> {
+        self.inner.image_path().map(|path| path.to_string())
+    }
+
+    #[getter]
+    fn visible(&self) -> bool {
+        self.inner.visible()
+    }
+
+    #[setter]
+    fn set_visible(&mut self, visible: bool) {
+        self.inner.set_visible(visible);
+        self.sync_runtime();
+    }
+
+    #[getter]
+    fn draw_order(&self) -> f32 {
+        self.inner.draw_order()
+    }
+
+    #[setter]
+    fn set_draw_order(&mut self, draw_order: f32) {
+        self.inner.set_draw_order(draw_order);
+        self.sync_runtime();
+    }
+}
+
+#[pyclass(name = "TextMeshComponent", unsendable)]
+#[derive(Clone)]
+pub struct PyTextMeshComponent {
+    inner: TextMeshComponent,
+    runtime_binding: RefCell<Option<ComponentRuntimeBinding>>,
+}
+
+impl PyTextMeshComponent {
+    fn sync_runtime(&self) {
+        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::SetTextMeshComponent {
+                object_id: binding.object_id,
+                component_id: binding.component_id,
+                component: self.inner.clone(),
+            });
+        }
+    }
+}
+
+#[pymethods]
+impl PyTextMeshComponent {
+    #[new]
+    #[pyo3(signature = (
+        text="",
+        font_size=24.0,
+        name=None,
+        font_path=None,
+        font_family=None,
+        font_weight=None,
+        font_style=None,
+        letter_spacing=0.0,
+        line_spacing=0.0,
+        kerning=true
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        text: &str,
+        font_size: f32,
+        name: Option<String>,
+        font_path: Option<String>,
+        font_family: Option<String>,
+        font_weight: Option<&str>,
+        font_style: Option<&str>,
+        letter_spacing: f32,
+        line_spacing: f32,
+        kerning: bool,
+    ) -> PyResult<Self> {
+        let text_style = build_text_style(
+            font_size,
+            font_path,
+            font_family,
+            font_weight,
+            font_style,
+            letter_spacing,
+            line_spacing,
+            kerning,
+        )?;
+        let mut component =
+            TextMeshComponent::new(name.unwrap_or_else(|| "Text Mesh".to_string())).with_text(text);
+        component.set_text_style(text_style);
+        Ok(Self {
+            inner: component,
+            runtime_binding: RefCell::new(None),
+        })
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn text(&self) -> String {
+        self.inner.text().to_string()
+    }
+
+    #[setter]
+    fn set_text(&mut self, text: String) {
+        self.inner.set_text(text);
+        self.sync_runtime();
+    }
+
+    #[getter]
+    fn color(&self) -> PyColor {
+        PyColor {
+            inner: self.inner.color(),
+        }
+    }
+
+    #[setter]
+    fn set_color(&mut self, color: PyColor) {
+        self.inner.set_color(color.inner);
+        self.sync_runtime();
+    }
+
+    #[getter]
+    fn font_size(&self) -> f32 {
+        self.inner.font_size()
+    }
+
+    #[setter]
+    fn set_font_size(&mut self, font_size: f32) {
+        self.inner.set_font_size(font_size);
+        self.sync_runtime();
+    }
+
+    #[getter]
+    fn font_path(&self) -> Option<String> {
+        self.inner.font_path().map(|path| path.to_string())
+    }
+
+    #[setter]
+    fn set_font_path(&mut self, font_path: Option<String>) {
+        self.inner.set_font_path(font_path);
+        self.sync_runtime();
+    }
+
+    #[getter]
+    fn font_family(&self) -> Option<String> {
+        self.inner.font_family().map(|family| family.to_string())
+    }
+
+    #[setter]
+    fn set_font_family(&mut self, font_family: Option<String>) {
+        self.inner.set_font_family(font_family);
+        self.sync_runtime();
+    }
+
+    #[getter]
+    fn font_weight(&self) -> String {
+        self.inner.font_weight().as_str().to_string()
+    }
+
+    #[setter]
+    fn set_font_weight(&mut self, font_weight: &str) -> PyResult<()> {
+        self.inner.set_font_weight(parse_font_weight(Some(font_weight))?);
+        self.sync_runtime();
+        Ok(())
+    }
+
+    #[getter]
+    fn font_style(&self) -> String {
+        self.inner.font_style().as_str().to_string()
+    }
+
+    #[setter]
+    fn set_font_style(&mut self, font_style: &str) -> PyResult<()> {
+        self.inner.set_font_style(parse_font_style(Some(font_style))?);
+        self.sync_runtime();
+        Ok(())
+    }
+
+    #[getter]
+    fn kerning(&self) -> bool {
+        self.inner.kerning()
+    }
+
+    #[setter]
+    fn set_kerning(&mut self, kerning: bool) {
+        self.inner.set_kerning(kerning);
+        self.sync_runtime();
+    }
+
+    #[getter]
+    fn letter_spacing(&self) -> f32 {
+        self.inner.letter_spacing()
+    }
+
+    #[setter]
+    fn set_letter_spacing(&mut self, letter_spacing: f32) {
+        self.inner.set_letter_spacing(letter_spacing);
+        self.sync_runtime();
+    }
+
+    #[getter]
+    fn line_spacing(&self) -> f32 {
+        self.inner.line_spacing()
+    }
+
+    #[setter]
+    fn set_line_spacing(&mut self, line_spacing: f32) {
+        self.inner.set_line_spacing(line_spacing);
+        self.sync_runtime();
+    }
+
+    #[getter]
+    fn visible(&self) -> bool {
+        self.inner.visible()
+    }
+
+    #[setter]
+    fn set_visible(&mut self, visible: bool) {
+        self.inner.set_visible(visible);
+        self.sync_runtime();
+    }
+
+    #[getter]
+    fn draw_order(&self) -> f32 {
+        self.inner.draw_order()
+    }
+
+    #[setter]
+    fn set_draw_order(&mut self, draw_order: f32) {
+        self.inner.set_draw_order(draw_order);
+        self.sync_runtime();
+    }
+}
+
+/// Text that follows a `GameObject` in world space, e.g. a nameplate.
+///
+/// The label is drawn `offset` world units from the object. With `billboard`
+/// (the default) it stays upright and unscaled whatever the object's rotation
+/// and scale. With `scale_with_zoom` (the default) it zooms with the camera;
+/// turn it off to keep the label at `font_size` pixels on screen.
+///
+/// # Example
+/// ```python
+/// label = pyg.WorldLabelComponent("Player 1", font_size=16, offset=pyg.Vec2(0, 40))
+/// label.scale_with_zoom = False
+/// player.add_component(label)
+/// ```
+#[pyclass(name = "WorldLabelComponent", unsendable)]
+#[derive(Clone)]
+pub struct PyWorldLabelComponent {
+    inner: WorldLabelComponent,
+    runtime_binding: RefCell<Option<ComponentRuntimeBinding>>,
+}
+
+impl PyWorldLabelComponent {
+    fn sync_runtime(&self) {
+        if let Some(binding) = self.runtime_binding.borrow().as_ref() {
+            let _ = binding.sender.send(EngineCommand::SetWorldLabelComponent {
+                object_id: binding.object_id,
+                component_id: binding.component_id,
+                component: self.inner.clone(),
+            });
+        }
+    }
+}
+
+#[pymethods]
+impl PyWorldLabelComponent {
+    #[new]
+    #[pyo3(signature = (
+        text="",
+        font_size=24.0,
+        color=None,
+        offset=None,
+        billboard=true,
+        scale_with_zoom=true,
+        name=None,
+        font_path=None,
+        font_family=None,
+        font_weight=None,
+        font_style=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        text: &str,
+        font_size: f32,
+        color: Option<PyColor>,
+        offset: Option<PyVec2>,
+        billboard: bool,
+        scale_with_zoom: bool,
+        name: Option<String>,
+        font_path: Option<String>,
+        font_family: Option<String>,
+        font_weight: Option<&str>,
+        font_style: Option<&str>,
+    ) -> PyResult<Self> {
+        let text_style =
+            build_text_style(font_size, font_path, font_family, font_weight, font_style, 0.0, 0.0, true)?;
+        let mut component = WorldLabelComponent::new(name.unwrap_or_else(|| "World Label".to_string()))
+            .with_text(text)
+            .with_billboard(billboard)
+            .with_scale_with_zoom(scale_with_zoom);
+        component.set_text_style(text_style);
+        if let Some(color) = color {
+            component.set_color(color.inner);
+        }
+        if let Some(offset) = offset {
+            component.set_offset(offset.inner);
+        }
+        Ok(Self {
+            inner: component,
+            runtime_binding: RefCell::new(None),
+        })
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn text(&self) -> String {
+        self.inner.text().to_string()
+    }
+
+    #[setter]
+    fn set_text(&mut self, text: String) {
+        self.inner.set_text(text);
+        self.sync_runtime();
+    }
+
+    #[getter]
+    fn color(&self) -> PyColor {
+        PyColor {
+            inner: self.inner.color(),
+        }
+    }
+
+    #[setter]
+    fn set_color(&mut self, color: PyColor) {
+        self.inner.set_color(color.inner);
+        self.sync_runtime();
+    }
+
+    #[getter]
+    fn font_size(&self) -> f32 {
+        self.inner.font_size()
+    }
+
+    #[setter]
+    fn set_font_size(&mut self, font_size: f32) {
+        self.inner.set_font_size(font_size);
+        self.sync_runtime();
+    }
+
+    /// Offset from the object's world position, in world units.
+    #[getter]
+    fn offset(&self) -> PyVec2 {
+        PyVec2 {
+            inner: self.inner.offset(),
+        }
+    }
+
+    #[setter]
+    fn set_offset(&mut self, offset: PyVec2) {
+        self.inner.set_offset(offset.inner);
+        self.sync_runtime();
+    }
+
+    /// Whether the label ignores the object's rotation and scale.
+    #[getter]
+    fn billboard(&self) -> bool {
+        self.inner.billboard()
+    }
+
+    #[setter]
+    fn set_billboard(&mut self, billboard: bool) {
+        self.inner.set_billboard(billboard);
+        self.sync_runtime();
+    }
+
+    /// Whether the label zooms with the camera instead of keeping its screen size.
+    #[getter]
+    fn scale_with_zoom(&self) -> bool {
+        self.inner.scale_with_zoom()
+    }
+
+    #[setter]
+    fn set_scale_with_zoom(&mut self, scale_with_zoom: bool) {
+        self.inner.set_scale_with_zoom(scale_with_zoom);
+        self.sync_runtime();
+    }
+
+    #[getter]
+    fn visible(&self) -> bool {
+        self.inner.visible()
+    }
+
+    #[setter]
+    fn set_visible(&mut self, visible: bool) {
+        self.inner.set_visible(visible);
+        self.sync_runtime();
+    }
+
+    #[getter]
+    fn draw_order(&self) -> f32 {
+        self.inner.draw_order()
+    }
+
+    #[setter]
+    fn set_draw_order(&mut self, draw_order: f32) {
+        self.inner.set_draw_order(draw_order);
+        self.sync_runtime();
+    }
+}
+
+// ========== Component Bindings ==========
+
+/// 2D transform component for position, rotation, and scale.
+///
+/// `TransformComponent` defines the local spatial properties of a `GameObject` in 2D space.
+/// Every `GameObject` has one, and parent transforms are composed into world-space for
+/// rendering and physics when the object is part of a hierarchy.
+///
+/// # Properties
+///
+/// - **position**: `Vec2` - Location relative to the parent (world-space when unparented)
+/// - **rotation**: `float` - Angle in **radians** (counter-clockwise)
+/// - **scale**: `Vec2` - Size multiplier (1.0 = original size)
+///
+/// # Coordinate System
+///
+/// - **Local space**: Stored on the object relative to its parent
+/// - **World space**: Computed from the full parent chain at runtime
+/// - **Origin**: Typically center of the screen, but configurable via camera
+/// - **Y-axis**: Typically up is positive, down is negative
+///
+/// # Rotation
+///
+/// Rotation is measured in **radians** (not degrees):
+/// - **0 radians**: Facing right (0°)
+/// - **π/2 radians**: Facing up (90°)
+/// - **π radians**: Facing left (180°)
+/// - **3π/2 radians**: Facing down (270°)
+///
+/// Use `math.radians()` to convert from degrees:
+/// ```python
+/// import math
+/// transform.rotation = math.radians(45)  # 45 degrees
+/// ```
+///
+/// # Examples
+///
+/// ## Basic Transform
+/// ```python
+/// from pyg_engine import TransformComponent, Vec2
+/// import math
+///
+/// transform = TransformComponent("Transform")
+/// transform.position = Vec2(100.0, 200.0)
+/// transform.rotation = math.radians(30)  # 30 degrees
+/// transform.scale = Vec2(2.0, 2.0)       # Double size
+/// ```
+///
+/// ## Movement Over Time
+/// ```python
+/// import math
+///
+/// def update(dt, engine, data):
+///     # Move right at 100 pixels/second
+///     data['obj'].position = data['obj'].position + Vec2(100.0 * dt, 0.0)
+///
+///     # Rotate at 90 degrees/second
+///     data['obj'].rotation += math.radians(90) * dt
+/// ```
+///
+/// ## Direction-Based Movement
+/// ```python
+/// import math
+/// from pyg_engine import Vec2
+///
+/// def update(dt, engine, data):
+///     obj = data['obj']
+///     speed = 150.0
+///
+///     # Move in the direction the object is facing
+///     angle = obj.rotation
+///     direction = Vec2(math.cos(angle), math.sin(angle))
+///     obj.position = obj.position + direction * speed * dt
+/// ```
+///
+/// # See Also
+/// - `examples/python_game_object_transform_demo.py` - Transform examples
+/// - `GameObject` - The container that owns this transform
+#[pyclass(name = "TransformComponent")]
+pub struct PyTransformComponent {
+    inner: TransformComponent,
+}
+
+#[pymethods]
+impl PyTransformComponent {
+    #[new]
+    fn new(name: String) -> Self {
+        Self {
+            inner: TransformComponent::new(name),
+        }
+    }
+
+    #[getter]
+    fn position(&self) -> PyVec2 {
+        PyVec2 {
+            inner: *self.inner.position(),
+        }
+    }
+
+    #[setter]
+    fn set_position(&mut self, position: PyVec2) {
+        self.inner.set_position(position.inner);
+    }
+
+    #[getter]
+    fn rotation(&self) -> f32 {
+        self.inner.rotation()
+    }
+
+    #[setter]
+    fn set_rotation(&mut self, rotation: f32) {
+        self.inner.set_rotation(rotation);
+    }
+
+    #[getter]
+    fn scale(&self) -> PyVec2 {
+        PyVec2 {
+            inner: *self.inner.scale(),
+        }
+    }
+
+    #[setter]
+    fn set_scale(&mut self, scale: PyVec2) {
+        self.inner.set_scale(scale.inner);
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.inner.is_enabled_self()
+    }
+
+    #[setter(enabled)]
+    fn set_enabled_property(&mut self, enabled: bool) {
+        self.inner.set_enabled_self(enabled);
+    }
+}
+
+// ========== Module Functions ==========
+
+/// Get the engine version (module-level function).
+#[pyfunction]
+fn version() -> String {
+    crate::core::engine::VERSION.to_string()
+}
+
+// ========== UI Component Bindings ==========
+
+/// Wrap a Python callable as a button callback, logging any exception it raises.
+fn button_callback(py_callback: Py<PyAny>, name: &'static str) -> impl FnMut() + Send + Sync + 'static {
+    move || {
+        // Use attach to ensure we have the GIL when calling Python callback
+        // from the Rust event loop context
+        pyo3::Python::attach(|py| {
+            if let Err(e) = py_callback.call0(py) {
+                report_callback_error(py, &format!("button {} callback", name), e);
+            }
+        });
+    }
+}
+
+/// Python wrapper for ButtonComponent.
+#[pyclass(name = "ButtonComponent")]
+pub struct PyButtonComponent {
+    inner: ButtonComponent,
+}
+
+#[pymethods]
+impl PyButtonComponent {
+    #[new]
+    #[pyo3(signature = (text="", x=0.0, y=0.0, width=100.0, height=30.0))]
+    fn new(text: &str, x: f32, y: f32, width: f32, height: f32) -> Self {
+        let button = ButtonComponent::new("Button")
+            .with_text(text)
+            .with_bounds(x, y, width, height);
+        Self { inner: button }
+    }
+
+    fn set_text(&mut self, text: &str) {
+        self.inner.set_text(text);
+    }
+
+    fn get_text(&self) -> String {
+        self.inner.text().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.inner.is_enabled_self()
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.inner.set_enabled(enabled);
+    }
+
+    #[setter(enabled)]
+    fn set_enabled_property(&mut self, enabled: bool) {
+        self.inner.set_enabled_self(enabled);
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(x, y, bounds.width, bounds.height));
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(bounds.x, bounds.y, width, height));
+    }
+
+    fn set_font_size(&mut self, font_size: f32) {
+        self.inner.set_font_size(font_size);
+    }
+
+    fn set_font_path(&mut self, font_path: Option<String>) {
+        self.inner.set_font_path(font_path);
+    }
+
+    fn set_font_family(&mut self, font_family: Option<String>) {
+        self.inner.set_font_family(font_family);
+    }
+
+    /// Set the fonts tried, in order, for characters the primary font lacks.
+    /// An empty list goes back to the UI theme's fallbacks.
+    fn set_font_fallbacks(&mut self, fallbacks: Vec<String>) {
+        self.inner.set_font_fallbacks(fallbacks);
+    }
+
+    fn set_font_weight(&mut self, font_weight: &str) -> PyResult<()> {
+        self.inner.set_font_weight(parse_font_weight(Some(font_weight))?);
+        Ok(())
+    }
+
+    fn set_font_style(&mut self, font_style: &str) -> PyResult<()> {
+        self.inner.set_font_style(parse_font_style(Some(font_style))?);
+        Ok(())
+    }
+
+    fn set_kerning(&mut self, kerning: bool) {
+        self.inner.set_kerning(kerning);
+    }
+
+    fn set_letter_spacing(&mut self, letter_spacing: f32) {
+        self.inner.set_letter_spacing(letter_spacing);
+    }
+
+    fn set_line_spacing(&mut self, line_spacing: f32) {
+        self.inner.set_line_spacing(line_spacing);
+    }
+
+    /// Round the corners of the background, border and shadow in every state.
+    fn set_corner_radius(&mut self, radius: f32) {
+        self.inner.style_mut().set_border_radius(radius);
+    }
+
+    /// Set the drop shadow in every state. Alpha 0 removes it.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (r, g, b, a, offset_x=0.0, offset_y=2.0, blur=4.0))]
+    fn set_shadow(&mut self, r: f32, g: f32, b: f32, a: f32, offset_x: f32, offset_y: f32, blur: f32) {
+        self.inner
+            .style_mut()
+            .set_shadow([r, g, b, a], (offset_x, offset_y), blur);
+    }
+
+    /// Fill the background with a vertical gradient, in one state or (with
+    /// `state=None`) all of them. `bottom=None` goes back to a solid `top` color.
+    #[pyo3(signature = (top, bottom=None, state=None))]
+    fn set_background_gradient(
+        &mut self,
+        top: (f32, f32, f32, f32),
+        bottom: Option<(f32, f32, f32, f32)>,
+        state: Option<&str>,
+    ) -> PyResult<()> {
+        let states = match state {
+            Some(name) => vec![parse_style_state(name).ok_or_else(|| {
+                PyRuntimeError::new_err(format!(
+                    "Invalid state '{name}'. Expected 'normal', 'hovered', 'pressed', 'focused' or 'disabled'."
+                ))
+            })?],
+            None => vec![
+                StyleState::Normal,
+                StyleState::Hovered,
+                StyleState::Pressed,
+                StyleState::Focused,
+                StyleState::Disabled,
+            ],
+        };
+        for state in states {
+            let style = self.inner.style_mut().get_style_mut(state);
+            style.background_color = [top.0, top.1, top.2, top.3];
+            style.background_gradient = bottom.map(|(r, g, b, a)| [r, g, b, a]);
+        }
+        Ok(())
+    }
+
+    fn set_depth(&mut self, depth: f32) {
+        self.inner = std::mem::replace(&mut self.inner, ButtonComponent::new("temp"))
+            .with_depth(depth);
+    }
+
+    /// Set a Python callback for the button click event.
+    ///
+    /// Registers a function to be called when the button is clicked. The callback executes
+    /// on the **main engine thread** during event processing, ensuring safe interaction with
+    /// the engine and UI system.
+    ///
+    /// # Arguments
+    /// * `py_callback` - A Python callable that takes **no arguments**. The callback should
+    ///   not accept any parameters (not even `self` if it's a method).
+    ///
+    /// # Callback Signature
+    /// The callback must have this signature:
+    /// ```python
+    /// def callback() -> None:
+    ///     # Your code here
+    ///     pass
+    /// ```
+    ///
+    /// # Error Handling
+    /// If the callback raises an exception:
+    /// - The exception is **logged** to the console with stack trace
+    /// - The exception **does not crash** the engine
+    /// - Other buttons and UI elements continue to function normally
+    ///
+    /// # Thread Safety
+    /// - Callbacks run on the **main engine thread**
+    /// - Safe to call any engine methods from the callback
+    /// - Safe to modify UI elements and game state
+    /// - No manual thread synchronization needed
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// engine = pyg.Engine()
+    ///
+    /// # Counter for demonstration
+    /// click_count = [0]  # Use list for closure modification
+    ///
+    /// # Define callback (takes no arguments!)
+    /// def on_button_clicked():
+    ///     click_count[0] += 1
+    ///     print(f"Button clicked! Count: {click_count[0]}")
+    ///
+    /// # Create button with callback
+    /// button = pyg.Button(
+    ///     "Click Me",
+    ///     x=100,
+    ///     y=100,
+    ///     width=200,
+    ///     height=50
+    /// )
+    /// button.set_on_click(on_button_clicked)
+    /// button.set_trigger_on("release")  # Fire on mouse release (default)
+    ///
+    /// # Add to UI system
+    /// engine.ui.add(button)
+    ///
+    /// # Run engine
+    /// engine.run(title="Button Callback Demo")
+    /// ```
+    ///
+    /// # Alternative: Pass Callback in Constructor
+    /// ```python
+    /// # Shorter syntax using constructor parameter
+    /// button = pyg.Button(
+    ///     "Click Me",
+    ///     x=100,
+    ///     y=100,
+    ///     width=200,
+    ///     height=50,
+    ///     on_click=lambda: print("Clicked!")
+    /// )
+    /// engine.ui.add(button)
+    /// ```
+    ///
+    /// # Closure Example (Modifying External State)
+    /// ```python
+    /// # Use lists or objects for mutable state in closures
+    /// state = {"enabled": True, "count": 0}
+    ///
+    /// def toggle_button():
+    ///     state["enabled"] = not state["enabled"]
+    ///     print(f"Enabled: {state['enabled']}")
+    ///
+    /// button.set_on_click(toggle_button)
+    /// ```
+    ///
+    /// # Updating UI from Callback
+    /// ```python
+    /// label = pyg.Label("Count: 0", x=100, y=50, font_size=18)
+    /// engine.ui.add(label)
+    ///
+    /// count = [0]
+    ///
+    /// def increment():
+    ///     count[0] += 1
+    ///     label.text = f"Count: {count[0]}"  # Safe - on main thread
+    ///
+    /// button = pyg.Button("Increment", x=100, y=100, width=150, height=40)
+    /// button.set_on_click(increment)
+    /// engine.ui.add(button)
+    /// ```
+    ///
+    /// # Trigger Timing
+    /// Control when the callback fires with `set_trigger_on()`:
+    /// ```python
+    /// # Fire on mouse release (default - feels like a "click")
+    /// button.set_trigger_on("release")
+    ///
+    /// # Fire immediately on mouse press (more responsive)
+    /// button.set_trigger_on("press")
+    /// ```
+    ///
+    /// # Repeat Behavior
+    /// Enable continuous firing while button is held:
+    /// ```python
+    /// # Fire every 100ms while held down
+    /// button.set_repeat_interval(100.0)
+    ///
+    /// # Disable repeating (single fire per click)
+    /// button.set_repeat_interval(None)
+    /// ```
+    ///
+    /// # Common Patterns
+    ///
+    /// **Menu Button:**
+    /// ```python
+    /// def start_game():
+    ///     engine.log("Starting game...")
+    ///     # Load game scene, hide menu, etc.
+    ///
+    /// start_btn = pyg.Button("Start Game", x=400, y=300, width=200, height=60)
+    /// start_btn.set_on_click(start_game)
+    /// ```
+    ///
+    /// **Toggle Button:**
+    /// ```python
+    /// paused = [False]
+    /// pause_btn = [None]
+    ///
+    /// def toggle_pause():
+    ///     paused[0] = not paused[0]
+    ///     pause_btn[0].text = "Resume" if paused[0] else "Pause"
+    ///
+    /// pause_btn[0] = pyg.Button("Pause", x=10, y=10, width=100, height=30)
+    /// pause_btn[0].set_on_click(toggle_pause)
+    /// ```
+    ///
+    /// # See Also
+    /// - `examples/button_features_demo.py` - Button trigger modes and repeat functionality
+    /// - `examples/ui_demo.py` - Complete UI system demonstration
+    /// - `set_trigger_on()` - Configure when callback fires (press vs release)
+    /// - `set_repeat_interval()` - Enable continuous firing while held
+    fn set_on_click(&mut self, py_callback: Py<PyAny>) {
+        self.inner.set_on_click(button_callback(py_callback, "on_click"));
+    }
+
+    /// Set a callback fired when the mouse starts hovering the button.
+    fn set_on_hover_enter(&mut self, py_callback: Py<PyAny>) {
+        self.inner.set_on_hover_enter(button_callback(py_callback, "on_hover_enter"));
+    }
+
+    /// Set a callback fired when the mouse stops hovering the button.
+    fn set_on_hover_exit(&mut self, py_callback: Py<PyAny>) {
+        self.inner.set_on_hover_exit(button_callback(py_callback, "on_hover_exit"));
+    }
+
+    /// Set a callback fired when a mouse button goes down on the button.
+    ///
+    /// Fires regardless of `set_trigger_on()`, so it suits press sounds and
+    /// squash animations on buttons that only act on release.
+    fn set_on_press(&mut self, py_callback: Py<PyAny>) {
+        self.inner.set_on_press(button_callback(py_callback, "on_press"));
+    }
+
+    /// Set a callback fired once per press, when the mouse button is released
+    /// or the pointer is dragged off the held button.
+    fn set_on_release(&mut self, py_callback: Py<PyAny>) {
+        self.inner.set_on_release(button_callback(py_callback, "on_release"));
+    }
+
+    /// Set when the button callback is triggered.
+    ///
+    /// Controls whether the button's `on_click` callback fires when the mouse button goes
+    /// **down** (press) or **up** (release). This affects the button's responsiveness and
+    /// "feel" for different use cases.
+    ///
+    /// # Arguments
+    /// * `trigger_on` - Trigger mode string (case-insensitive). Valid values:
+    ///   - **`"release"`** (default) - Fire callback when mouse button is released
+    ///     - Standard button behavior, feels like a "click"
+    ///     - User can cancel by moving mouse away before releasing
+    ///     - Aliases: `"up"`, `"click"`
+    ///
+    ///   - **`"press"`** - Fire callback immediately when mouse button goes down
+    ///     - More responsive, instant feedback
+    ///     - No way to cancel once pressed
+    ///     - Aliases: `"down"`
+    ///
+    /// Invalid values log a warning and default to `"release"`.
+    ///
+    /// # Press vs Release
+    ///
+    /// **Release (default):**
+    /// - Traditional button behavior
+    /// - User can press, move away, and release to cancel
+    /// - Feels like "clicking" the button
+    /// - Best for: Menu buttons, confirmations, UI interactions
+    ///
+    /// **Press:**
+    /// - Instant response on mouse down
+    /// - No cancel mechanism
+    /// - Feels more immediate and game-like
+    /// - Best for: Game controls, rapid-fire actions, instant response needs
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// engine = pyg.Engine()
+    ///
+    /// # Standard release button (default)
+    /// menu_btn = pyg.Button("Start Game", x=100, y=100, width=200, height=50)
+    /// menu_btn.set_on_click(lambda: print("Game started!"))
+    /// menu_btn.set_trigger_on("release")  # Fire on mouse up
+    /// engine.ui.add(menu_btn)
+    ///
+    /// # Instant press button (more responsive)
+    /// fire_btn = pyg.Button("Fire!", x=100, y=200, width=200, height=50)
+    /// fire_btn.set_on_click(lambda: print("Shooting!"))
+    /// fire_btn.set_trigger_on("press")  # Fire on mouse down
+    /// engine.ui.add(fire_btn)
+    ///
+    /// engine.run(title="Button Trigger Demo")
+    /// ```
+    ///
+    /// # Comparison Demo
+    /// ```python
+    /// press_count = [0]
+    /// release_count = [0]
+    ///
+    /// def on_press():
+    ///     press_count[0] += 1
+    ///     print(f"Press: {press_count[0]} (fires immediately)")
+    ///
+    /// def on_release():
+    ///     release_count[0] += 1
+    ///     print(f"Release: {release_count[0]} (fires on mouse up)")
+    ///
+    /// press_btn = pyg.Button("Press Mode", x=100, y=100, width=150, height=40)
+    /// press_btn.set_on_click(on_press)
+    /// press_btn.set_trigger_on("press")
+    ///
+    /// release_btn = pyg.Button("Release Mode", x=100, y=150, width=150, height=40)
+    /// release_btn.set_on_click(on_release)
+    /// release_btn.set_trigger_on("release")  # Or omit - this is default
+    ///
+    /// engine.ui.add(press_btn)
+    /// engine.ui.add(release_btn)
+    /// ```
+    ///
+    /// # Use Cases
+    ///
+    /// **Use "release" for:**
+    /// - Menu navigation
+    /// - Confirmation dialogs
+    /// - Submit buttons
+    /// - Any action that should allow cancellation
+    ///
+    /// **Use "press" for:**
+    /// - Game weapon firing
+    /// - Jump buttons
+    /// - Fast-paced interactions
+    /// - When instant response is critical
+    ///
+    /// # See Also
+    /// - `examples/button_features_demo.py` - Trigger mode comparison
+    /// - `set_on_click()` - Set button callback
+    /// - `set_repeat_interval()` - Enable continuous firing while held
+    fn set_trigger_on(&mut self, trigger_on: &str) {
+        use crate::core::ui::button::ButtonTrigger;
+        let trigger = match trigger_on.to_lowercase().as_str() {
+            "press" | "down" => ButtonTrigger::Press,
+            "release" | "up" | "click" => ButtonTrigger::Release,
+            _ => {
+                logging::log_warn(&format!(
+                    "Warning: Invalid trigger_on value '{}'. Use 'press' or 'release'. Defaulting to 'release'.",
+                    trigger_on
+                ));
+                ButtonTrigger::Release
+            }
+        };
+        self.inner.set_trigger_on(trigger);
+    }
+
+    /// Set the repeat interval in milliseconds for when the button is held down.
+    ///
+    /// Enables continuous callback firing while the button remains pressed. After the initial
+    /// trigger, the callback will fire repeatedly at the specified interval until the button
+    /// is released. Useful for increment/decrement buttons, scrolling, or auto-fire mechanics.
+    ///
+    /// # Arguments
+    /// * `interval_ms` - Repeat interval in **milliseconds**, or `None` to disable repeating:
+    ///   - **`None`** (default) - Single fire per click, no repeating
+    ///   - **`> 0`** - Fire callback every N milliseconds while held
+    ///   - Typical values: 50-500ms (50ms = 20Hz, 100ms = 10Hz, 500ms = 2Hz)
+    ///
+    /// # Behavior
+    /// 1. Button is clicked → Callback fires immediately
+    /// 2. Button remains held → Wait `interval_ms`
+    /// 3. While still held → Callback fires again
+    /// 4. Repeat step 3 until button released
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// engine = pyg.Engine()
+    ///
+    /// counter = [0]
+    /// label = [None]
+    ///
+    /// def increment():
+    ///     counter[0] += 1
+    ///     if label[0]:
+    ///         label[0].text = f"Count: {counter[0]}"
+    ///
+    /// def decrement():
+    ///     counter[0] -= 1
+    ///     if label[0]:
+    ///         label[0].text = f"Count: {counter[0]}"
+    ///
+    /// # Counter display
+    /// counter_label = pyg.Label(f"Count: {counter[0]}", x=200, y=100, font_size=24)
+    /// engine.ui.add(counter_label)
+    /// label[0] = counter_label
+    ///
+    /// # Increment button with repeat (fires every 100ms when held)
+    /// inc_btn = pyg.Button("+", x=100, y=150, width=60, height=40)
+    /// inc_btn.set_on_click(increment)
+    /// inc_btn.set_repeat_interval(100.0)  # Repeat every 100ms
+    /// inc_btn.set_trigger_on("press")  # Start immediately on press
+    /// engine.ui.add(inc_btn)
+    ///
+    /// # Decrement button with repeat
+    /// dec_btn = pyg.Button("-", x=170, y=150, width=60, height=40)
+    /// dec_btn.set_on_click(decrement)
+    /// dec_btn.set_repeat_interval(100.0)
+    /// dec_btn.set_trigger_on("press")
+    /// engine.ui.add(dec_btn)
+    ///
+    /// engine.run(title="Repeat Button Demo")
+    /// ```
+    ///
+    /// # Faster Repeat for Acceleration
+    /// ```python
+    /// # Fast repeating button for rapid increment
+    /// rapid_btn = pyg.Button("Fast +", x=100, y=200, width=80, height=40)
+    /// rapid_btn.set_on_click(increment)
+    /// rapid_btn.set_repeat_interval(50.0)  # 20 times per second
+    /// engine.ui.add(rapid_btn)
+    /// ```
+    ///
+    /// # Disable Repeating
+    /// ```python
+    /// # Single fire only (default behavior)
+    /// single_btn = pyg.Button("Click Once", x=100, y=250, width=120, height=40)
+    /// single_btn.set_on_click(lambda: print("Single fire"))
+    /// single_btn.set_repeat_interval(None)  # No repeating
+    /// engine.ui.add(single_btn)
+    /// ```
+    ///
+    /// # Scroll Button Example
+    /// ```python
+    /// scroll_offset = [0]
+    ///
+    /// def scroll_up():
+    ///     scroll_offset[0] -= 10
+    ///     print(f"Scroll offset: {scroll_offset[0]}")
+    ///
+    /// def scroll_down():
+    ///     scroll_offset[0] += 10
+    ///     print(f"Scroll offset: {scroll_offset[0]}")
+    ///
+    /// up_btn = pyg.Button("▲", x=700, y=50, width=40, height=40)
+    /// up_btn.set_on_click(scroll_up)
+    /// up_btn.set_repeat_interval(150.0)  # Smooth scrolling
+    /// engine.ui.add(up_btn)
+    ///
+    /// down_btn = pyg.Button("▼", x=700, y=100, width=40, height=40)
+    /// down_btn.set_on_click(scroll_down)
+    /// down_btn.set_repeat_interval(150.0)
+    /// engine.ui.add(down_btn)
+    /// ```
+    ///
+    /// # Typical Interval Values
+    /// - **50-80ms**: Very fast, rapid increment (shooting, fast scrolling)
+    /// - **100-150ms**: Fast, comfortable for continuous actions
+    /// - **200-300ms**: Moderate, good for volume/brightness controls
+    /// - **500ms+**: Slow, deliberate repeating
+    ///
+    /// # Notes
+    /// - First callback fires immediately on trigger (no delay)
+    /// - Subsequent callbacks fire at the specified interval
+    /// - Works with both `"press"` and `"release"` trigger modes
+    /// - Setting `None` disables repeating (reverts to single fire)
+    ///
+    /// # See Also
+    /// - `examples/button_features_demo.py` - Repeat functionality demonstration
+    /// - `set_on_click()` - Set button callback
+    /// - `set_trigger_on()` - Configure trigger timing
+    fn set_repeat_interval(&mut self, interval_ms: Option<f32>) {
+        self.inner.set_repeat_interval_ms(interval_ms);
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+}
+
+/// Python wrapper for PanelComponent.
+#[pyclass(name = "PanelComponent")]
+pub struct PyPanelComponent {
+    inner: PanelComponent,
+}
+
+#[pymethods]
+impl PyPanelComponent {
+    #[new]
+    #[pyo3(signature = (x=0.0, y=0.0, width=200.0, height=200.0))]
+    fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        let panel = PanelComponent::new("Panel")
+            .with_bounds(x, y, width, height);
+        Self { inner: panel }
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(x, y, bounds.width, bounds.height));
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(bounds.x, bounds.y, width, height));
+    }
+
+    fn set_depth(&mut self, depth: f32) {
+        self.inner = std::mem::replace(&mut self.inner, PanelComponent::new("temp"))
+            .with_depth(depth);
+    }
+
+    fn set_background_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
+        self.inner.style_mut().background_color = [r, g, b, a];
+    }
+
+    fn set_border(&mut self, width: f32, r: f32, g: f32, b: f32, a: f32) {
+        let style = self.inner.style_mut();
+        style.border_width = width;
+        style.border_color = [r, g, b, a];
+    }
+
+    /// Round the corners of the background, border and shadow.
+    fn set_corner_radius(&mut self, radius: f32) {
+        self.inner.style_mut().border_radius = radius.max(0.0);
+    }
+
+    /// Set the drop shadow. Alpha 0 removes it.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (r, g, b, a, offset_x=0.0, offset_y=4.0, blur=8.0))]
+    fn set_shadow(&mut self, r: f32, g: f32, b: f32, a: f32, offset_x: f32, offset_y: f32, blur: f32) {
+        self.inner
+            .style_mut()
+            .set_shadow([r, g, b, a], (offset_x, offset_y), blur);
+    }
+
+    /// Fill the background with a vertical gradient. `bottom=None` goes back
+    /// to a solid `top` color.
+    #[pyo3(signature = (top, bottom=None))]
+    fn set_background_gradient(&mut self, top: (f32, f32, f32, f32), bottom: Option<(f32, f32, f32, f32)>) {
+        let style = self.inner.style_mut();
+        style.background_color = [top.0, top.1, top.2, top.3];
+        style.background_gradient = bottom.map(|(r, g, b, a)| [r, g, b, a]);
+    }
+
+    /// Clip child widgets to the panel's bounds.
+    fn set_clip_children(&mut self, clip: bool) {
+        self.inner.set_clip_children(clip);
+    }
+
+    fn get_clip_children(&self) -> bool {
+        self.inner.clip_children()
+    }
+
+    /// Make the panel modal: while it is shown, only it and its children get
+    /// UI input and the rest of the screen is dimmed.
+    fn set_modal(&mut self, modal: bool) {
+        self.inner.set_modal(modal);
+    }
+
+    fn is_modal(&self) -> bool {
+        self.inner.is_modal()
+    }
+
+    /// Set the backdrop color drawn behind a modal panel. Alpha 0 disables dimming.
+    fn set_dim_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
+        self.inner.set_dim_color([r, g, b, a]);
+    }
+
+    fn get_dim_color(&self) -> (f32, f32, f32, f32) {
+        let [r, g, b, a] = self.inner.dim_color();
+        (r, g, b, a)
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.inner.is_enabled_self()
+    }
+
+    #[setter(enabled)]
+    fn set_enabled_property(&mut self, enabled: bool) {
+        self.inner.set_enabled_self(enabled);
+    }
+}
+
+/// Python wrapper for LabelComponent.
+#[pyclass(name = "LabelComponent")]
+pub struct PyLabelComponent {
+    inner: LabelComponent,
+}
+
+#[pymethods]
+impl PyLabelComponent {
+    #[new]
+    #[pyo3(signature = (text="", x=0.0, y=0.0, font_size=14.0))]
+    fn new(text: &str, x: f32, y: f32, font_size: f32) -> Self {
+        let mut label = LabelComponent::new("Label")
+            .with_text(text)
+            .with_position(x, y);
+        label.set_font_size(font_size);
+        Self { inner: label }
+    }
+
+    fn set_text(&mut self, text: &str) {
+        self.inner.set_text(text);
+    }
+
+    fn get_text(&self) -> String {
+        self.inner.text().to_string()
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        self.inner.set_position(x, y);
+    }
+
+    fn set_font_size(&mut self, size: f32) {
+        self.inner.set_font_size(size);
+    }
+
+    fn set_font_path(&mut self, font_path: Option<String>) {
+        self.inner.set_font_path(font_path);
+    }
+
+    fn set_font_family(&mut self, font_family: Option<String>) {
+        self.inner.set_font_family(font_family);
+    }
+
+    /// Set the fonts tried, in order, for characters the primary font lacks.
+    /// An empty list goes back to the UI theme's fallbacks.
+    fn set_font_fallbacks(&mut self, fallbacks: Vec<String>) {
+        self.inner.set_font_fallbacks(fallbacks);
+    }
+
+    fn get_font_fallbacks(&self) -> Vec<String> {
+        self.inner.font_fallbacks().to_vec()
+    }
+
+    fn set_font_weight(&mut self, font_weight: &str) -> PyResult<()> {
+        self.inner.set_font_weight(parse_font_weight(Some(font_weight))?);
+        Ok(())
+    }
+
+    fn set_font_style(&mut self, font_style: &str) -> PyResult<()> {
+        self.inner.set_font_style(parse_font_style(Some(font_style))?);
+        Ok(())
+    }
+
+    fn set_kerning(&mut self, kerning: bool) {
+        self.inner.set_kerning(kerning);
+    }
+
+    fn set_letter_spacing(&mut self, letter_spacing: f32) {
+        self.inner.set_letter_spacing(letter_spacing);
+    }
+
+    fn set_line_spacing(&mut self, line_spacing: f32) {
+        self.inner.set_line_spacing(line_spacing);
+    }
+
+    fn set_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
+        self.inner.set_color([r, g, b, a]);
+    }
+
+    fn set_align(&mut self, align: &str) {
+        let text_align = match align.to_lowercase().as_str() {
+            "left" => TextAlign::Left,
+            "center" => TextAlign::Center,
+            "right" => TextAlign::Right,
+            _ => TextAlign::Left,
+        };
+        self.inner.set_align(text_align);
+    }
+
+    fn set_vertical_align(&mut self, align: &str) -> PyResult<()> {
+        self.inner.set_vertical_align(parse_vertical_text_align(align)?);
+        Ok(())
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(bounds.x, bounds.y, width, height));
+    }
+
+    fn get_bounds(&self) -> (f32, f32, f32, f32) {
+        let bounds = self.inner.bounds();
+        (bounds.x, bounds.y, bounds.width, bounds.height)
+    }
+
+    /// Break the text into lines at the label's width.
+    fn set_wrap(&mut self, wrap: bool) {
+        self.inner.set_wrap(wrap);
+    }
+
+    fn get_wrap(&self) -> bool {
+        self.inner.wrap()
+    }
+
+    /// Resize the label's height to fit its text every frame.
+    fn set_auto_height(&mut self, auto_height: bool) {
+        self.inner.set_auto_height(auto_height);
+    }
+
+    fn get_auto_height(&self) -> bool {
+        self.inner.auto_height()
+    }
+
+    fn set_depth(&mut self, depth: f32) {
+        self.inner = std::mem::replace(&mut self.inner, LabelComponent::new("temp"))
+            .with_depth(depth);
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.inner.is_enabled_self()
+    }
+
+    #[setter(enabled)]
+    fn set_enabled_property(&mut self, enabled: bool) {
+        self.inner.set_enabled_self(enabled);
+    }
+}
+
+/// Python wrapper for TextInputComponent.
+#[pyclass(name = "TextInputComponent")]
+pub struct PyTextInputComponent {
+    inner: TextInputComponent,
+}
+
+#[pymethods]
+impl PyTextInputComponent {
+    #[new]
+    #[pyo3(signature = (text="", x=0.0, y=0.0, width=200.0, height=30.0, placeholder=""))]
+    fn new(text: &str, x: f32, y: f32, width: f32, height: f32, placeholder: &str) -> Self {
+        let input = TextInputComponent::new("TextInput")
+            .with_text(text)
+            .with_bounds(x, y, width, height)
+            .with_placeholder(placeholder);
+        Self { inner: input }
+    }
+
+    fn set_text(&mut self, text: &str) {
+        self.inner.set_text(text);
+    }
+
+    fn get_text(&self) -> String {
+        self.inner.text().to_string()
+    }
+
+    fn set_placeholder(&mut self, placeholder: &str) {
+        self.inner.set_placeholder(placeholder);
+    }
+
+    fn get_placeholder(&self) -> String {
+        self.inner.placeholder().to_string()
+    }
+
+    /// Limit the number of characters, or `None` for no limit.
+    #[pyo3(signature = (max_length=None))]
+    fn set_max_length(&mut self, max_length: Option<usize>) {
+        self.inner.set_max_length(max_length);
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(x, y, bounds.width, bounds.height));
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(bounds.x, bounds.y, width, height));
+    }
+
+    fn set_font_size(&mut self, font_size: f32) {
+        self.inner.set_font_size(font_size);
+    }
+
+    fn set_font_path(&mut self, font_path: Option<String>) {
+        self.inner.set_font_path(font_path);
+    }
+
+    fn set_font_family(&mut self, font_family: Option<String>) {
+        self.inner.set_font_family(font_family);
+    }
+
+    fn set_font_weight(&mut self, font_weight: &str) -> PyResult<()> {
+        self.inner.set_font_weight(parse_font_weight(Some(font_weight))?);
+        Ok(())
+    }
+
+    fn set_font_style(&mut self, font_style: &str) -> PyResult<()> {
+        self.inner.set_font_style(parse_font_style(Some(font_style))?);
+        Ok(())
+    }
+
+    fn set_kerning(&mut self, kerning: bool) {
+        self.inner.set_kerning(kerning);
+    }
+
+    fn set_depth(&mut self, depth: f32) {
+        self.inner = std::mem::replace(&mut self.inner, TextInputComponent::new("temp"))
+            .with_depth(depth);
+    }
+
+    /// Set a Python callback fired with the new text whenever the user edits it.
+    ///
+    /// The callback takes a single `str` argument and runs on the main engine thread.
+    /// Exceptions are handled by the engine's `callback_error_policy`.
+    fn set_on_changed(&mut self, py_callback: Py<PyAny>) {
+        self.inner.set_on_changed(move |text| {
+            pyo3::Python::attach(|py| {
+                if let Err(e) = py_callback.call1(py, (text,)) {
+                    report_callback_error(py, "text input on_changed callback", e);
+                }
+            });
+        });
+    }
+
+    /// Set a Python callback fired with the current text when Enter is pressed.
+    ///
+    /// The callback takes a single `str` argument and runs on the main engine thread.
+    /// Exceptions are handled by the engine's `callback_error_policy`.
+    fn set_on_submit(&mut self, py_callback: Py<PyAny>) {
+        self.inner.set_on_submit(move |text| {
+            pyo3::Python::attach(|py| {
+                if let Err(e) = py_callback.call1(py, (text,)) {
+                    report_callback_error(py, "text input on_submit callback", e);
+                }
+            });
+        });
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.inner.is_enabled_self()
+    }
+
+    #[setter(enabled)]
+    fn set_enabled_property(&mut self, enabled: bool) {
+        self.inner.set_enabled_self(enabled);
+    }
+}
+
+/// Python wrapper for SliderComponent.
+#[pyclass(name = "SliderComponent")]
+pub struct PySliderComponent {
+    inner: SliderComponent,
+}
+
+#[pymethods]
+impl PySliderComponent {
+    #[new]
+    #[pyo3(signature = (
+        min_value=0.0,
+        max_value=1.0,
+        value=0.0,
+        x=0.0,
+        y=0.0,
+        width=200.0,
+        height=24.0,
+        step=0.0,
+        orientation="horizontal"
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        min_value: f32,
+        max_value: f32,
+        value: f32,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        step: f32,
+        orientation: &str,
+    ) -> PyResult<Self> {
+        let orientation = parse_slider_orientation(orientation)?;
+        let slider = SliderComponent::new("Slider")
+            .with_range(min_value, max_value)
+            .with_step(step)
+            .with_value(value)
+            .with_orientation(orientation)
+            .with_bounds(x, y, width, height);
+        Ok(Self { inner: slider })
+    }
+
+    fn set_value(&mut self, value: f32) {
+        self.inner.set_value(value);
+    }
+
+    fn get_value(&self) -> f32 {
+        self.inner.value()
+    }
+
+    fn set_range(&mut self, min_value: f32, max_value: f32) {
+        self.inner.set_range(min_value, max_value);
+    }
+
+    fn get_range(&self) -> (f32, f32) {
+        (self.inner.min(), self.inner.max())
+    }
+
+    fn set_step(&mut self, step: f32) {
+        self.inner.set_step(step);
+    }
+
+    fn get_step(&self) -> f32 {
+        self.inner.step()
+    }
+
+    /// Set the orientation: `"horizontal"` or `"vertical"`.
+    fn set_orientation(&mut self, orientation: &str) -> PyResult<()> {
+        self.inner.set_orientation(parse_slider_orientation(orientation)?);
+        Ok(())
+    }
+
+    fn get_orientation(&self) -> String {
+        self.inner.orientation().as_str().to_string()
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(x, y, bounds.width, bounds.height));
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(bounds.x, bounds.y, width, height));
+    }
+
+    fn set_depth(&mut self, depth: f32) {
+        self.inner = std::mem::replace(&mut self.inner, SliderComponent::new("temp"))
+            .with_depth(depth);
+    }
+
+    /// Set a Python callback fired with the new value whenever the user moves the slider.
+    ///
+    /// The callback takes a single `float` argument and runs on the main engine thread.
+    /// Exceptions are handled by the engine's `callback_error_policy`.
+    fn set_on_value_changed(&mut self, py_callback: Py<PyAny>) {
+        self.inner.set_on_value_changed(move |value| {
+            pyo3::Python::attach(|py| {
+                if let Err(e) = py_callback.call1(py, (value,)) {
+                    report_callback_error(py, "slider on_value_changed callback", e);
+                }
+            });
+        });
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.inner.is_enabled_self()
+    }
+
+    #[setter(enabled)]
+    fn set_enabled_property(&mut self, enabled: bool) {
+        self.inner.set_enabled_self(enabled);
+    }
+}
+
+/// Python wrapper for CheckboxComponent.
+#[pyclass(name = "CheckboxComponent")]
+pub struct PyCheckboxComponent {
+    inner: CheckboxComponent,
+}
+
+#[pymethods]
+impl PyCheckboxComponent {
+    #[new]
+    #[pyo3(signature = (label="", checked=false, x=0.0, y=0.0, width=160.0, height=24.0))]
+    fn new(label: &str, checked: bool, x: f32, y: f32, width: f32, height: f32) -> Self {
+        let component = CheckboxComponent::new("Checkbox")
+            .with_label(label)
+            .with_checked(checked)
+            .with_bounds(x, y, width, height);
+        Self { inner: component }
+    }
+
+    /// Set the checked state without firing `on_toggled`.
+    fn set_checked(&mut self, checked: bool) {
+        self.inner.set_checked(checked);
+    }
+
+    fn is_checked(&self) -> bool {
+        self.inner.is_checked()
+    }
+
+    fn set_label(&mut self, label: String) {
+        self.inner.set_label(label);
+    }
+
+    fn get_label(&self) -> String {
+        self.inner.label().to_string()
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(x, y, bounds.width, bounds.height));
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(bounds.x, bounds.y, width, height));
+    }
+
+    fn set_depth(&mut self, depth: f32) {
+        self.inner = std::mem::replace(&mut self.inner, CheckboxComponent::new("temp"))
+            .with_depth(depth);
+    }
+
+    fn set_font_size(&mut self, font_size: f32) {
+        self.inner.set_font_size(font_size);
+    }
+
+    fn set_font_path(&mut self, font_path: Option<String>) {
+        self.inner.set_font_path(font_path);
+    }
+
+    fn set_font_family(&mut self, font_family: Option<String>) {
+        self.inner.set_font_family(font_family);
+    }
+
+    fn set_font_weight(&mut self, font_weight: &str) -> PyResult<()> {
+        self.inner.set_font_weight(parse_font_weight(Some(font_weight))?);
+        Ok(())
+    }
+
+    fn set_font_style(&mut self, font_style: &str) -> PyResult<()> {
+        self.inner.set_font_style(parse_font_style(Some(font_style))?);
+        Ok(())
+    }
+
+    /// Set a Python callback fired with the new state whenever the user toggles the checkbox.
+    ///
+    /// The callback takes a single `bool` argument and runs on the main engine thread.
+    /// Exceptions are handled by the engine's `callback_error_policy`.
+    fn set_on_toggled(&mut self, py_callback: Py<PyAny>) {
+        self.inner.set_on_toggled(move |checked| {
+            pyo3::Python::attach(|py| {
+                if let Err(e) = py_callback.call1(py, (checked,)) {
+                    report_callback_error(py, "checkbox on_toggled callback", e);
+                }
+            });
+        });
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.inner.is_enabled_self()
+    }
+
+    #[setter(enabled)]
+    fn set_enabled_property(&mut self, enabled: bool) {
+        self.inner.set_enabled_self(enabled);
+    }
+}
+
+/// Python wrapper for ToggleSwitchComponent.
+#[pyclass(name = "ToggleSwitchComponent")]
+pub struct PyToggleSwitchComponent {
+    inner: ToggleSwitchComponent,
+}
+
+#[pymethods]
+impl PyToggleSwitchComponent {
+    #[new]
+    #[pyo3(signature = (label="", checked=false, x=0.0, y=0.0, width=160.0, height=24.0))]
+    fn new(label: &str, checked: bool, x: f32, y: f32, width: f32, height: f32) -> Self {
+        let component = ToggleSwitchComponent::new("ToggleSwitch")
+            .with_label(label)
+            .with_checked(checked)
+            .with_bounds(x, y, width, height);
+        Self { inner: component }
+    }
+
+    /// Set the checked state without firing `on_toggled`.
+    fn set_checked(&mut self, checked: bool) {
+        self.inner.set_checked(checked);
+    }
+
+    fn is_checked(&self) -> bool {
+        self.inner.is_checked()
+    }
+
+    fn set_label(&mut self, label: String) {
+        self.inner.set_label(label);
+    }
+
+    fn get_label(&self) -> String {
+        self.inner.label().to_string()
+    }
+
+    /// Set the track color used while the switch is on.
+    fn set_on_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
+        self.inner.set_on_color([r, g, b, a]);
+    }
+
+    fn set_knob_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
+        self.inner.set_knob_color([r, g, b, a]);
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(x, y, bounds.width, bounds.height));
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(bounds.x, bounds.y, width, height));
+    }
+
+    fn set_depth(&mut self, depth: f32) {
+        self.inner = std::mem::replace(&mut self.inner, ToggleSwitchComponent::new("temp"))
+            .with_depth(depth);
+    }
+
+    fn set_font_size(&mut self, font_size: f32) {
+        self.inner.set_font_size(font_size);
+    }
+
+    fn set_font_path(&mut self, font_path: Option<String>) {
+        self.inner.set_font_path(font_path);
+    }
+
+    fn set_font_family(&mut self, font_family: Option<String>) {
+        self.inner.set_font_family(font_family);
+    }
+
+    fn set_font_weight(&mut self, font_weight: &str) -> PyResult<()> {
+        self.inner.set_font_weight(parse_font_weight(Some(font_weight))?);
+        Ok(())
+    }
+
+    fn set_font_style(&mut self, font_style: &str) -> PyResult<()> {
+        self.inner.set_font_style(parse_font_style(Some(font_style))?);
+        Ok(())
+    }
+
+    /// Set a Python callback fired with the new state whenever the user flips the switch.
+    ///
+    /// The callback takes a single `bool` argument and runs on the main engine thread.
+    /// Exceptions are handled by the engine's `callback_error_policy`.
+    fn set_on_toggled(&mut self, py_callback: Py<PyAny>) {
+        self.inner.set_on_toggled(move |checked| {
+            pyo3::Python::attach(|py| {
+                if let Err(e) = py_callback.call1(py, (checked,)) {
+                    report_callback_error(py, "toggle switch on_toggled callback", e);
+                }
+            });
+        });
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.inner.is_enabled_self()
+    }
+
+    #[setter(enabled)]
+    fn set_enabled_property(&mut self, enabled: bool) {
+        self.inner.set_enabled_self(enabled);
+    }
+}
+
+/// Python wrapper for ScrollViewComponent.
+#[pyclass(name = "ScrollViewComponent")]
+pub struct PyScrollViewComponent {
+    inner: ScrollViewComponent,
+}
+
+#[pymethods]
+impl PyScrollViewComponent {
+    #[new]
+    #[pyo3(signature = (x=0.0, y=0.0, width=200.0, height=200.0, horizontal=false, vertical=true))]
+    fn new(x: f32, y: f32, width: f32, height: f32, horizontal: bool, vertical: bool) -> Self {
+        let component = ScrollViewComponent::new("ScrollView")
+            .with_bounds(x, y, width, height)
+            .with_directions(horizontal, vertical);
+        Self { inner: component }
+    }
+
+    /// Set the scroll offset, clamped to the content, without firing `on_scrolled`.
+    fn set_scroll(&mut self, x: f32, y: f32) {
+        self.inner.set_scroll(x, y);
+    }
+
+    fn get_scroll(&self) -> (f32, f32) {
+        self.inner.scroll()
+    }
+
+    fn get_max_scroll(&self) -> (f32, f32) {
+        self.inner.max_scroll()
+    }
+
+    /// Fix the content size. Pass `None` to measure it from the children again.
+    #[pyo3(signature = (width=None, height=None))]
+    fn set_content_size(&mut self, width: Option<f32>, height: Option<f32>) {
+        match (width, height) {
+            (Some(width), Some(height)) => self.inner.set_content_size(width, height),
+            _ => self.inner.set_auto_content_size(),
+        }
+    }
+
+    fn get_content_size(&self) -> (f32, f32) {
+        self.inner.content_size()
+    }
+
+    fn set_directions(&mut self, horizontal: bool, vertical: bool) {
+        self.inner.set_directions(horizontal, vertical);
+    }
+
+    /// Set how many pixels one mouse wheel line scrolls.
+    fn set_scroll_speed(&mut self, speed: f32) {
+        self.inner.set_scroll_speed(speed);
+    }
+
+    fn set_show_scrollbars(&mut self, show: bool) {
+        self.inner.set_show_scrollbars(show);
+    }
+
+    fn set_background_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
+        self.inner.style_mut().background_color = [r, g, b, a];
+    }
+
+    fn set_border(&mut self, width: f32, r: f32, g: f32, b: f32, a: f32) {
+        let style = self.inner.style_mut();
+        style.border_width = width;
+        style.border_color = [r, g, b, a];
+    }
+
+    fn set_scrollbar_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
+        self.inner.style_mut().text_color = [r, g, b, a];
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(x, y, bounds.width, bounds.height));
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(bounds.x, bounds.y, width, height));
+    }
+
+    fn set_depth(&mut self, depth: f32) {
+        self.inner = std::mem::replace(&mut self.inner, ScrollViewComponent::new("temp"))
+            .with_depth(depth);
+    }
+
+    /// Set a Python callback fired with the new `(x, y)` offset whenever the user scrolls.
+    ///
+    /// The callback takes two `float` arguments and runs on the main engine thread.
+    /// Exceptions are handled by the engine's `callback_error_policy`.
+    fn set_on_scrolled(&mut self, py_callback: Py<PyAny>) {
+        self.inner.set_on_scrolled(move |x, y| {
+            pyo3::Python::attach(|py| {
+                if let Err(e) = py_callback.call1(py, (x, y)) {
+                    report_callback_error(py, "scroll view on_scrolled callback", e);
+                }
+            });
+        });
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.inner.is_enabled_self()
+    }
+
+    #[setter(enabled)]
+    fn set_enabled_property(&mut self, enabled: bool) {
+        self.inner.set_enabled_self(enabled);
+    }
+}
+
+/// Python wrapper for ProgressBarComponent.
+#[pyclass(name = "ProgressBarComponent")]
+pub struct PyProgressBarComponent {
+    inner: ProgressBarComponent,
+}
+
+#[pymethods]
+impl PyProgressBarComponent {
+    #[new]
+    #[pyo3(signature = (value=0.0, x=0.0, y=0.0, width=200.0, height=20.0, fill_direction="left_to_right", text=None))]
+    fn new(
+        value: f32,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        fill_direction: &str,
+        text: Option<String>,
+    ) -> PyResult<Self> {
+        let mut component = ProgressBarComponent::new("ProgressBar")
+            .with_value(value)
+            .with_fill_direction(parse_fill_direction(fill_direction)?)
+            .with_bounds(x, y, width, height);
+        component.set_text(text);
+        Ok(Self { inner: component })
+    }
+
+    /// Set the value, clamped to 0.0-1.0.
+    fn set_value(&mut self, value: f32) {
+        self.inner.set_value(value);
+    }
+
+    fn get_value(&self) -> f32 {
+        self.inner.value()
+    }
+
+    /// Set the fill direction: "left_to_right", "right_to_left", "bottom_to_top" or "top_to_bottom".
+    fn set_fill_direction(&mut self, fill_direction: &str) -> PyResult<()> {
+        self.inner.set_fill_direction(parse_fill_direction(fill_direction)?);
+        Ok(())
+    }
+
+    fn get_fill_direction(&self) -> String {
+        self.inner.fill_direction().as_str().to_string()
+    }
+
+    /// Set the overlay text, or None to hide it. `{percent}` and `{value}` are filled in.
+    #[pyo3(signature = (text=None))]
+    fn set_text(&mut self, text: Option<String>) {
+        self.inner.set_text(text);
+    }
+
+    fn get_text(&self) -> Option<String> {
+        self.inner.text().map(str::to_string)
+    }
+
+    /// Set the track color (the `background_color` of every style state).
+    fn set_track_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
+        let style = self.inner.style_mut();
+        for state in [&mut style.normal, &mut style.hovered, &mut style.pressed, &mut style.focused] {
+            state.background_color = [r, g, b, a];
+        }
+    }
+
+    /// Set the fill color (the `text_color` of the enabled style states).
+    fn set_fill_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
+        let style = self.inner.style_mut();
+        for state in [&mut style.normal, &mut style.hovered, &mut style.pressed, &mut style.focused] {
+            state.text_color = [r, g, b, a];
+        }
+    }
+
+    fn set_border(&mut self, width: f32, r: f32, g: f32, b: f32, a: f32) {
+        let style = self.inner.style_mut();
+        for state in [
+            &mut style.normal,
+            &mut style.hovered,
+            &mut style.pressed,
+            &mut style.focused,
+            &mut style.disabled,
+        ] {
+            state.border_width = width;
+            state.border_color = [r, g, b, a];
+        }
+    }
+
+    /// Set the overlay text color.
+    fn set_text_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
+        self.inner.set_text_color([r, g, b, a]);
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(x, y, bounds.width, bounds.height));
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(bounds.x, bounds.y, width, height));
+    }
+
+    fn set_depth(&mut self, depth: f32) {
+        self.inner = std::mem::replace(&mut self.inner, ProgressBarComponent::new("temp"))
+            .with_depth(depth);
+    }
+
+    fn set_font_size(&mut self, font_size: f32) {
+        self.inner.set_font_size(font_size);
+    }
+
+    fn set_font_path(&mut self, font_path: Option<String>) {
+        self.inner.set_font_path(font_path);
+    }
+
+    fn set_font_family(&mut self, font_family: Option<String>) {
+        self.inner.set_font_family(font_family);
+    }
+
+    fn set_font_weight(&mut self, font_weight: &str) -> PyResult<()> {
+        self.inner.set_font_weight(parse_font_weight(Some(font_weight))?);
+        Ok(())
+    }
+
+    fn set_font_style(&mut self, font_style: &str) -> PyResult<()> {
+        self.inner.set_font_style(parse_font_style(Some(font_style))?);
+        Ok(())
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.inner.is_enabled_self()
+    }
+
+    #[setter(enabled)]
+    fn set_enabled_property(&mut self, enabled: bool) {
+        self.inner.set_enabled_self(enabled);
+    }
+}
+
+/// A UI event delivered to a `CustomWidgetComponent` callback.
+///
+/// `type` is one of "mouse_enter", "mouse_exit", "mouse_move", "mouse_down",
+/// "mouse_up", "click", "double_click", "mouse_wheel", "focus_gained",
+/// "focus_lost", "key_down", "text_input" or "text_composition" (the input
+/// method's uncommitted text in `text`, empty when composition ends). Fields
+/// that do not apply to the event type are `None`. Positions are in logical
+/// window pixels.
+///
+/// `phase` is "capture" when the event is headed for one of the widget's
+/// descendants, "target" when it is for the widget itself and "bubble" when
+/// a descendant left it unhandled.
+#[pyclass(name = "UIEvent")]
+pub struct PyUIEvent {
+    #[pyo3(get, name = "type")]
+    kind: &'static str,
+    #[pyo3(get)]
+    phase: &'static str,
+    propagation_stopped: bool,
+    #[pyo3(get)]
+    x: Option<f64>,
+    #[pyo3(get)]
+    y: Option<f64>,
+    /// Mouse movement for "mouse_move", wheel lines for "mouse_wheel"
+    #[pyo3(get)]
+    dx: Option<f64>,
+    #[pyo3(get)]
+    dy: Option<f64>,
+    #[pyo3(get)]
+    button: Option<&'static str>,
+    #[pyo3(get)]
+    key: Option<String>,
+    #[pyo3(get)]
+    shift: bool,
+    #[pyo3(get)]
+    ctrl: bool,
+    #[pyo3(get)]
+    text: Option<String>,
+}
+
+impl PyUIEvent {
+    fn from_event(event: &UIEvent, phase: EventPhase) -> Self {
+        let mut py_event = Self {
+            kind: event.name(),
+            phase: phase.as_str(),
+            propagation_stopped: false,
+            x: None,
+            y: None,
+            dx: None,
+            dy: None,
+            button: None,
+            key: None,
+            shift: false,
+            ctrl: false,
+            text: None,
+        };
+        match event {
+            UIEvent::MouseEnter { x, y } | UIEvent::MouseExit { x, y } => {
+                py_event.x = Some(*x);
+                py_event.y = Some(*y);
+            }
+            UIEvent::MouseMove { x, y, dx, dy }
+            | UIEvent::MouseWheel { x, y, delta_x: dx, delta_y: dy } => {
+                py_event.x = Some(*x);
+                py_event.y = Some(*y);
+                py_event.dx = Some(*dx);
+                py_event.dy = Some(*dy);
+            }
+            UIEvent::MouseDown { x, y, button }
+            | UIEvent::MouseUp { x, y, button }
+            | UIEvent::Click { x, y, button }
+            | UIEvent::DoubleClick { x, y, button } => {
+                py_event.x = Some(*x);
+                py_event.y = Some(*y);
+                py_event.button = Some(mouse_button_name(*button));
+            }
+            UIEvent::KeyDown { key, shift, ctrl } => {
+                py_event.key = Some(key_label(key));
+                py_event.shift = *shift;
+                py_event.ctrl = *ctrl;
+            }
+            UIEvent::TextInput { text } | UIEvent::TextComposition { text, .. } => py_event.text = Some(text.clone()),
+            UIEvent::FocusGained | UIEvent::FocusLost => {}
+        }
+        py_event
+    }
+}
+
+#[pymethods]
+impl PyUIEvent {
+    fn __repr__(&self) -> String {
+        format!("UIEvent(type={:?}, phase={:?}, x={:?}, y={:?})", self.kind, self.phase, self.x, self.y)
+    }
+
+    /// Keep the event from reaching any other widget, the same as returning True.
+    fn stop_propagation(&mut self) {
+        self.propagation_stopped = true;
+    }
+
+    #[getter]
+    fn propagation_stopped(&self) -> bool {
+        self.propagation_stopped
+    }
+}
+
+/// A raw input event from `Input.events()`.
+///
+/// `type` is one of "key_pressed", "key_released", "text_input",
+/// "text_composition", "mouse_button_pressed", "mouse_button_released",
+/// "mouse_moved", "mouse_wheel", "joystick_connected",
+/// "joystick_disconnected", "joystick_button_pressed",
+/// "joystick_button_released", "joystick_axis_moved",
+/// "active_device_changed" or "player_device_changed". Fields that do not
+/// apply to the event type are `None`. `actions` lists the actions the event
+/// triggers in the current input context.
+#[pyclass(name = "InputEvent")]
+pub struct PyInputEvent {
+    #[pyo3(get, name = "type")]
+    kind: &'static str,
+    /// Key name as accepted by `key_pressed`, e.g. "a" or "space"
+    #[pyo3(get)]
+    key: Option<String>,
+    #[pyo3(get)]
+    text: Option<String>,
+    #[pyo3(get)]
+    button: Option<&'static str>,
+    /// Mouse position in window pixels for "mouse_moved"
+    #[pyo3(get)]
+    x: Option<f64>,
+    #[pyo3(get)]
+    y: Option<f64>,
+    /// Wheel movement for "mouse_wheel"
+    #[pyo3(get)]
+    dx: Option<f64>,
+    #[pyo3(get)]
+    dy: Option<f64>,
+    #[pyo3(get)]
+    joystick_id: Option<u32>,
+    #[pyo3(get)]
+    button_id: Option<u8>,
+    #[pyo3(get)]
+    axis_id: Option<u8>,
+    #[pyo3(get)]
+    value: Option<f32>,
+    /// Device name for "active_device_changed" and "player_device_changed"
+    #[pyo3(get)]
+    device: Option<&'static str>,
+    #[pyo3(get)]
+    player: Option<u8>,
+    #[pyo3(get)]
+    actions: Vec<String>,
+}
+
+impl PyInputEvent {
+    fn from_event(event: &InputEvent, input: &InputManager) -> Self {
+        let mut py_event = Self {
+            kind: event.name(),
+            key: None,
+            text: None,
+            button: None,
+            x: None,
+            y: None,
+            dx: None,
+            dy: None,
+            joystick_id: None,
+            button_id: None,
+            axis_id: None,
+            value: None,
+            device: None,
+            player: None,
+            actions: input.actions_for_event(event),
+        };
+        match event {
+            InputEvent::KeyPressed { key } | InputEvent::KeyReleased { key } => {
+                py_event.key = input_bindings::key_name(key);
+            }
+            InputEvent::TextInput { text } | InputEvent::TextComposition { text, .. } => {
+                py_event.text = Some(text.clone());
+            }
+            InputEvent::MouseButtonPressed { button } | InputEvent::MouseButtonReleased { button } => {
+                py_event.button = Some(mouse_button_name(*button));
+            }
+            InputEvent::MouseMoved { x, y } => {
+                py_event.x = Some(*x);
+                py_event.y = Some(*y);
+            }
+            InputEvent::MouseWheel { delta_x, delta_y } => {
+                py_event.dx = Some(*delta_x);
+                py_event.dy = Some(*delta_y);
+            }
+            InputEvent::JoystickConnected { joystick_id } | InputEvent::JoystickDisconnected { joystick_id } => {
+                py_event.joystick_id = Some(*joystick_id);
+            }
+            InputEvent::JoystickButtonPressed { joystick_id, button_id }
+            | InputEvent::JoystickButtonReleased { joystick_id, button_id } => {
+                py_event.joystick_id = Some(*joystick_id);
+                py_event.button_id = Some(*button_id);
+            }
+            InputEvent::JoystickAxisMoved { joystick_id, axis_id, value } => {
+                py_event.joystick_id = Some(*joystick_id);
+                py_event.axis_id = Some(*axis_id);
+                py_event.value = Some(*value);
+            }
+            InputEvent::ActiveDeviceChanged { device } => py_event.device = Some(device.as_str()),
+            InputEvent::PlayerDeviceChanged { player, device } => {
+                py_event.player = Some(*player);
+                py_event.joystick_id = device.and_then(|device| device.joystick_id());
+                py_event.device = device.map(|device| device.as_str());
+            }
+        }
+        py_event
+    }
+}
+
+#[pymethods]
+impl PyInputEvent {
+    fn __repr__(&self) -> String {
+        format!("InputEvent(type={:?}, actions={:?})", self.kind, self.actions)
+    }
+}
+
+/// Python wrapper for CustomWidgetComponent.
+#[pyclass(name = "CustomWidgetComponent")]
+pub struct PyCustomWidgetComponent {
+    inner: CustomWidgetComponent,
+}
+
+#[pymethods]
+impl PyCustomWidgetComponent {
+    #[new]
+    #[pyo3(signature = (x=0.0, y=0.0, width=100.0, height=100.0))]
+    fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            inner: CustomWidgetComponent::new("CustomWidget").with_bounds(x, y, width, height),
+        }
+    }
+
+    /// Set a Python callback fired with a `UIEvent` for every event the widget receives.
+    ///
+    /// Return True from the callback, or call `event.stop_propagation()`, to
+    /// mark the event handled and stop it reaching other widgets; unhandled
+    /// mouse wheel events continue to the enclosing scroll view. The callback
+    /// runs on the main engine thread. Exceptions are logged and count as unhandled.
+    fn set_on_event(&mut self, py_callback: Py<PyAny>) {
+        self.inner.set_on_event(move |event, phase| {
+            pyo3::Python::attach(|py| {
+                let result = Py::new(py, PyUIEvent::from_event(event, phase)).and_then(|py_event| {
+                    let handled = py_callback.call1(py, (py_event.clone_ref(py),))?.is_truthy(py)?;
+                    Ok(handled || py_event.borrow(py).propagation_stopped)
+                });
+                match result {
+                    Ok(handled) => handled,
+                    Err(e) => {
+                        report_callback_error(py, "custom widget on_event callback", e);
+                        false
+                    }
+                }
+            })
+        });
+    }
+
+    /// Also deliver pointer events headed for the widget's UI descendants to
+    /// the callback, in the "capture" phase before the descendant sees them.
+    fn set_capture_events(&mut self, capture_events: bool) {
+        self.inner.set_capture_events(capture_events);
+    }
+
+    fn get_capture_events(&self) -> bool {
+        self.inner.capture_events()
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(x, y, bounds.width, bounds.height));
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(bounds.x, bounds.y, width, height));
+    }
+
+    fn get_bounds(&self) -> (f32, f32, f32, f32) {
+        let bounds = self.inner.bounds();
+        (bounds.x, bounds.y, bounds.width, bounds.height)
+    }
+
+    fn set_depth(&mut self, depth: f32) {
+        self.inner = std::mem::replace(&mut self.inner, CustomWidgetComponent::new("temp"))
+            .with_depth(depth);
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.inner.is_enabled_self()
+    }
+
+    #[setter(enabled)]
+    fn set_enabled_property(&mut self, enabled: bool) {
+        self.inner.set_enabled_self(enabled);
+    }
+}
+
+/// Local drawing surface handed to a `CanvasComponent` render callback.
+///
+/// Coordinates are relative to the canvas' top-left corner and everything is
+/// clipped to its bounds; `draw_order` is added to the canvas' depth. The
+/// painter is only valid during the callback.
+#[pyclass(name = "CanvasPainter", unsendable)]
+pub struct PyCanvasPainter {
+    region: DrawManager,
+    #[pyo3(get)]
+    width: f32,
+    #[pyo3(get)]
+    height: f32,
+}
+
+#[pymethods]
+impl PyCanvasPainter {
+    /// Draw a pixel at canvas coordinates.
+    #[pyo3(signature = (x, y, color, draw_order=0.0))]
+    fn pixel(&mut self, x: f32, y: f32, color: &PyColor, draw_order: f32) {
+        self.region.add_command(DrawCommand::Pixel {
+            x,
+            y,
+            color: color.inner,
+            draw_order,
+        });
+    }
+
+    /// Draw a line at canvas coordinates.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (start_x, start_y, end_x, end_y, color, thickness=1.0, draw_order=0.0))]
+    fn line(
+        &mut self,
+        start_x: f32,
+        start_y: f32,
+        end_x: f32,
+        end_y: f32,
+        color: &PyColor,
+        thickness: f32,
+        draw_order: f32,
+    ) {
+        self.region
+            .draw_line_with_options(start_x, start_y, end_x, end_y, thickness, color.inner, draw_order);
+    }
+
+    /// Draw a rectangle at canvas coordinates.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (x, y, width, height, color, filled=true, thickness=1.0, draw_order=0.0))]
+    fn rectangle(
+        &mut self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        color: &PyColor,
+        filled: bool,
+        thickness: f32,
+        draw_order: f32,
+    ) {
+        self.region
+            .draw_rectangle_with_options(x, y, width, height, color.inner, filled, thickness, draw_order);
+    }
+
+    /// Draw a circle at canvas coordinates.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        center_x,
+        center_y,
+        radius,
+        color,
+        filled=true,
+        thickness=1.0,
+        segments=32,
+        draw_order=0.0
+    ))]
+    fn circle(
+        &mut self,
+        center_x: f32,
+        center_y: f32,
+        radius: f32,
+        color: &PyColor,
+        filled: bool,
+        thickness: f32,
+        segments: u32,
+        draw_order: f32,
+    ) {
+        self.region.draw_circle_with_options(
+            center_x,
+            center_y,
+            radius,
+            color.inner,
+            filled,
+            thickness,
+            segments,
+            draw_order,
+        );
+    }
+
+    /// Draw an arc or pie slice at canvas coordinates.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        center_x,
+        center_y,
+        radius,
+        start_angle,
+        end_angle,
+        color,
+        filled=true,
+        thickness=1.0,
+        segments=32,
+        draw_order=0.0
+    ))]
+    fn arc(
+        &mut self,
+        center_x: f32,
+        center_y: f32,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        color: &PyColor,
+        filled: bool,
+        thickness: f32,
+        segments: u32,
+        draw_order: f32,
+    ) {
+        self.region.draw_arc_with_options(
+            center_x,
+            center_y,
+            radius,
+            start_angle,
+            end_angle,
+            color.inner,
+            filled,
+            thickness,
+            segments,
+            draw_order,
+        );
+    }
+
+    /// Draw a polygon, filled or as a closed outline, from `(x, y)` points,
+    /// `Vec2`s or a float array shaped `(n, 2)`.
+    #[pyo3(signature = (points, color, filled=true, thickness=1.0, draw_order=0.0))]
+    fn polygon(
+        &mut self,
+        points: &Bound<'_, PyAny>,
+        color: &PyColor,
+        filled: bool,
+        thickness: f32,
+        draw_order: f32,
+    ) -> PyResult<()> {
+        let points = extract_points(points)?;
+        self.region
+            .draw_polygon_with_options(points, color.inner, filled, thickness, draw_order);
+        Ok(())
+    }
+
+    /// Draw an image from a file path at canvas coordinates.
+    #[pyo3(signature = (x, y, width, height, texture_path, draw_order=0.0))]
+    fn image(&mut self, x: f32, y: f32, width: f32, height: f32, texture_path: String, draw_order: f32) {
+        self.region
+            .draw_image_with_options(x, y, width, height, texture_path, draw_order);
+    }
+
+    /// Draw text at canvas coordinates.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        text,
+        x,
+        y,
+        color,
+        font_size=16.0,
+        font_path=None,
+        font_family=None,
+        font_weight=None,
+        font_style=None,
+        draw_order=0.0
+    ))]
+    fn text(
+        &mut self,
+        text: String,
+        x: f32,
+        y: f32,
+        color: &PyColor,
+        font_size: f32,
+        font_path: Option<String>,
+        font_family: Option<String>,
+        font_weight: Option<&str>,
+        font_style: Option<&str>,
+        draw_order: f32,
+    ) -> PyResult<()> {
+        let style = build_text_style(font_size, font_path, font_family, font_weight, font_style, 0.0, 0.0, true)?;
+        self.region
+            .draw_text_with_options(text, x, y, style, color.inner, TextLayoutOptions::default(), draw_order);
+        Ok(())
+    }
+
+    /// Clip what is drawn from now on to a rectangle in canvas coordinates,
+    /// within the canvas bounds. Match each push with `pop_clip`.
+    fn push_clip(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.region.push_clip_rect(x, y, width, height);
+    }
+
+    fn pop_clip(&mut self) {
+        self.region.pop_clip_rect();
+    }
+
+    fn __repr__(&self) -> String {
+        format!("CanvasPainter(width={}, height={})", self.width, self.height)
+    }
+}
+
+/// Python wrapper for CanvasComponent.
+#[pyclass(name = "CanvasComponent")]
+pub struct PyCanvasComponent {
+    inner: CanvasComponent,
+}
+
+#[pymethods]
+impl PyCanvasComponent {
+    #[new]
+    #[pyo3(signature = (x=0.0, y=0.0, width=100.0, height=100.0, redraw_every_frame=false))]
+    fn new(x: f32, y: f32, width: f32, height: f32, redraw_every_frame: bool) -> Self {
+        Self {
+            inner: CanvasComponent::new("Canvas")
+                .with_bounds(x, y, width, height)
+                .with_redraw_every_frame(redraw_every_frame),
+        }
+    }
+
+    /// Set a Python callback fired with a `CanvasPainter` whenever the canvas is drawn.
+    ///
+    /// The callback runs on the main engine thread. Exceptions are logged and
+    /// whatever was drawn before the error is kept.
+    fn set_on_render(&mut self, py_callback: Py<PyAny>) {
+        self.inner.set_on_render(move |region, width, height| {
+            pyo3::Python::attach(|py| {
+                let painter = PyCanvasPainter {
+                    region: DrawManager::new(),
+                    width,
+                    height,
+                };
+                let result = Py::new(py, painter).and_then(|painter| {
+                    let call = py_callback.call1(py, (painter.clone_ref(py),));
+                    let drawn = std::mem::take(&mut painter.borrow_mut(py).region);
+                    region.append_commands_with_clips(drawn.commands(), drawn.clip_rects());
+                    call
+                });
+                if let Err(e) = result {
+                    report_callback_error(py, "canvas on_render callback", e);
+                }
+            })
+        });
+    }
+
+    /// Run the render callback again on the next frame. Works on the
+    /// component after it was added to the engine too.
+    fn request_redraw(&self) {
+        self.inner.request_redraw();
+    }
+
+    /// Run the render callback on every frame, e.g. for live charts.
+    fn set_redraw_every_frame(&mut self, redraw_every_frame: bool) {
+        self.inner.set_redraw_every_frame(redraw_every_frame);
+    }
+
+    fn get_redraw_every_frame(&self) -> bool {
+        self.inner.redraw_every_frame()
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(x, y, bounds.width, bounds.height));
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(bounds.x, bounds.y, width, height));
+    }
+
+    fn get_bounds(&self) -> (f32, f32, f32, f32) {
+        let bounds = self.inner.bounds();
+        (bounds.x, bounds.y, bounds.width, bounds.height)
+    }
+
+    fn set_depth(&mut self, depth: f32) {
+        self.inner = std::mem::replace(&mut self.inner, CanvasComponent::new("temp"))
+            .with_depth(depth);
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.inner.is_enabled_self()
+    }
+
+    #[setter(enabled)]
+    fn set_enabled_property(&mut self, enabled: bool) {
+        self.inner.set_enabled_self(enabled);
+    }
+}
+
+/// Python wrapper for ImageComponent.
+#[pyclass(name = "ImageComponent")]
+pub struct PyImageComponent {
+    inner: ImageComponent,
+}
+
+#[pymethods]
+impl PyImageComponent {
+    #[new]
+    #[pyo3(signature = (texture_path=None, x=0.0, y=0.0, width=64.0, height=64.0, scale_mode="stretch"))]
+    fn new(
+        texture_path: Option<String>,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        scale_mode: &str,
+    ) -> PyResult<Self> {
+        let mut component = ImageComponent::new("Image")
+            .with_bounds(x, y, width, height)
+            .with_scale_mode(parse_image_scale_mode(scale_mode)?);
+        component.set_texture(texture_path);
+        Ok(Self { inner: component })
+    }
+
+    /// Set the texture path (relative paths resolve against the source root), or None to hide it.
+    #[pyo3(signature = (texture_path=None))]
+    fn set_texture(&mut self, texture_path: Option<String>) {
+        self.inner.set_texture(texture_path);
+    }
+
+    fn get_texture(&self) -> Option<String> {
+        self.inner.texture().map(str::to_string)
+    }
+
+    /// Set the scale mode: "stretch", "fit", "fill" or "tile".
+    fn set_scale_mode(&mut self, scale_mode: &str) -> PyResult<()> {
+        self.inner.set_scale_mode(parse_image_scale_mode(scale_mode)?);
+        Ok(())
+    }
+
+    fn get_scale_mode(&self) -> String {
+        self.inner.scale_mode().as_str().to_string()
+    }
+
+    /// Set the texture's pixel size; normally read from the file when the image is added.
+    fn set_texture_size(&mut self, width: u32, height: u32) {
+        self.inner.set_texture_size(width, height);
+    }
+
+    fn get_texture_size(&self) -> Option<(u32, u32)> {
+        self.inner.texture_size()
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(x, y, bounds.width, bounds.height));
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(bounds.x, bounds.y, width, height));
+    }
+
+    fn get_bounds(&self) -> (f32, f32, f32, f32) {
+        let bounds = self.inner.bounds();
+        (bounds.x, bounds.y, bounds.width, bounds.height)
+    }
+
+    fn set_depth(&mut self, depth: f32) {
+        self.inner = std::mem::replace(&mut self.inner, ImageComponent::new("temp")).with_depth(depth);
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.inner.is_enabled_self()
+    }
+
+    #[setter(enabled)]
+    fn set_enabled_property(&mut self, enabled: bool) {
+        self.inner.set_enabled_self(enabled);
+    }
+}
+
+/// Python wrapper for StackComponent.
+#[pyclass(name = "StackComponent")]
+pub struct PyStackComponent {
+    inner: StackComponent,
+}
+
+#[pymethods]
+impl PyStackComponent {
+    #[new]
+    #[pyo3(signature = (direction="vertical", x=0.0, y=0.0, width=200.0, height=200.0, spacing=0.0, padding=(0.0, 0.0, 0.0, 0.0), alignment="start", fit_content=false))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        direction: &str,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        spacing: f32,
+        padding: (f32, f32, f32, f32),
+        alignment: &str,
+        fit_content: bool,
+    ) -> PyResult<Self> {
+        let component = StackComponent::new("Stack")
+            .with_bounds(x, y, width, height)
+            .with_direction(parse_stack_direction(direction)?)
+            .with_spacing(spacing)
+            .with_padding(Padding::new(padding.0, padding.1, padding.2, padding.3))
+            .with_alignment(parse_stack_alignment(alignment)?)
+            .with_fit_content(fit_content);
+        Ok(Self { inner: component })
+    }
+
+    /// Set the direction: "vertical" or "horizontal".
+    fn set_direction(&mut self, direction: &str) -> PyResult<()> {
+        self.inner.set_direction(parse_stack_direction(direction)?);
+        Ok(())
+    }
+
+    fn get_direction(&self) -> String {
+        self.inner.direction().as_str().to_string()
+    }
+
+    fn set_spacing(&mut self, spacing: f32) {
+        self.inner.set_spacing(spacing);
+    }
+
+    fn get_spacing(&self) -> f32 {
+        self.inner.spacing()
+    }
+
+    /// Set the padding inside the stack's edges.
+    fn set_padding(&mut self, left: f32, right: f32, top: f32, bottom: f32) {
+        self.inner.set_padding(Padding::new(left, right, top, bottom));
+    }
+
+    /// Get the padding as (left, right, top, bottom).
+    fn get_padding(&self) -> (f32, f32, f32, f32) {
+        let padding = self.inner.padding();
+        (padding.left, padding.right, padding.top, padding.bottom)
+    }
+
+    /// Set the cross-axis alignment: "start", "center", "end" or "stretch".
+    fn set_alignment(&mut self, alignment: &str) -> PyResult<()> {
+        self.inner.set_alignment(parse_stack_alignment(alignment)?);
+        Ok(())
+    }
+
+    fn get_alignment(&self) -> String {
+        self.inner.alignment().as_str().to_string()
+    }
+
+    /// Resize the stack along its axis to wrap its children.
+    fn set_fit_content(&mut self, fit_content: bool) {
+        self.inner.set_fit_content(fit_content);
+    }
+
+    fn get_fit_content(&self) -> bool {
+        self.inner.fit_content()
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(x, y, bounds.width, bounds.height));
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(bounds.x, bounds.y, width, height));
+    }
+
+    fn get_bounds(&self) -> (f32, f32, f32, f32) {
+        let bounds = self.inner.bounds();
+        (bounds.x, bounds.y, bounds.width, bounds.height)
+    }
+
+    fn set_depth(&mut self, depth: f32) {
+        self.inner = std::mem::replace(&mut self.inner, StackComponent::new("temp")).with_depth(depth);
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.inner.is_enabled_self()
+    }
+
+    #[setter(enabled)]
+    fn set_enabled_property(&mut self, enabled: bool) {
+        self.inner.set_enabled_self(enabled);
+    }
+}
+
+/// Python wrapper for AnimationCurve.
+#[pyclass(name = "AnimationCurve")]
+#[derive(Clone)]
+pub struct PyAnimationCurve {
+    inner: AnimationCurve,
+}
+
+#[pymethods]
+impl PyAnimationCurve {
+    /// Create a curve from `(time, value)` keys in any order.
+    #[new]
+    #[pyo3(signature = (keys=Vec::new(), interpolation="linear"))]
+    fn new(keys: Vec<(f32, f32)>, interpolation: &str) -> PyResult<Self> {
+        let curve = AnimationCurve::from_keys(keys.into_iter().map(|(time, value)| Keyframe::new(time, value)))
+            .with_interpolation(parse_curve_interpolation(interpolation)?);
+        Ok(Self { inner: curve })
+    }
+
+    /// Create a straight line between two keys.
+    #[staticmethod]
+    fn linear(start_time: f32, start_value: f32, end_time: f32, end_value: f32) -> Self {
+        Self {
+            inner: AnimationCurve::linear(start_time, start_value, end_time, end_value),
+        }
+    }
+
+    /// Sample the curve at `time`. Times outside the keys clamp to the first or last key.
+    fn evaluate(&self, time: f32) -> f32 {
+        self.inner.evaluate(time)
+    }
+
+    /// Insert a key and return its index.
+    fn add_key(&mut self, time: f32, value: f32) -> PyResult<usize> {
+        self.inner
+            .add_key(time, value)
+            .ok_or_else(|| PyRuntimeError::new_err("Keyframe time and value must be finite"))
+    }
+
+    /// Remove the key at `index`, returning it as `(time, value)`.
+    fn remove_key(&mut self, index: usize) -> PyResult<(f32, f32)> {
+        self.inner
+            .remove_key(index)
+            .map(|key| (key.time, key.value))
+            .ok_or_else(|| PyRuntimeError::new_err(format!("Keyframe index {index} out of range")))
+    }
+
+    /// Move the key at `index` and return its new index after re-sorting.
+    fn set_key(&mut self, index: usize, time: f32, value: f32) -> PyResult<usize> {
+        self.inner.set_key(index, time, value).ok_or_else(|| {
+            PyRuntimeError::new_err(format!(
+                "Keyframe index {index} out of range, or time/value not finite"
+            ))
+        })
+    }
+
+    /// Get the keys as a list of `(time, value)` sorted by time.
+    fn get_keys(&self) -> Vec<(f32, f32)> {
+        self.inner.keys().iter().map(|key| (key.time, key.value)).collect()
+    }
+
+    fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Set the interpolation: "linear", "smooth" or "constant".
+    fn set_interpolation(&mut self, interpolation: &str) -> PyResult<()> {
+        self.inner.set_interpolation(parse_curve_interpolation(interpolation)?);
+        Ok(())
+    }
+
+    fn get_interpolation(&self) -> String {
+        self.inner.interpolation().as_str().to_string()
+    }
+
+    fn copy(&self) -> Self {
+        self.clone()
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "AnimationCurve(keys={:?}, interpolation='{}')",
+            self.get_keys(),
+            self.inner.interpolation().as_str()
+        )
+    }
+}
+
+/// Python wrapper for CurveEditorComponent.
+#[pyclass(name = "CurveEditorComponent")]
+pub struct PyCurveEditorComponent {
+    inner: CurveEditorComponent,
+}
+
+#[pymethods]
+impl PyCurveEditorComponent {
+    #[new]
+    #[pyo3(signature = (
+        curve=None,
+        x=0.0,
+        y=0.0,
+        width=240.0,
+        height=160.0,
+        time_range=(0.0, 1.0),
+        value_range=(0.0, 1.0)
+    ))]
+    fn new(
+        curve: Option<PyRef<PyAnimationCurve>>,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        time_range: (f32, f32),
+        value_range: (f32, f32),
+    ) -> Self {
+        let component = CurveEditorComponent::new("CurveEditor")
+            .with_curve(curve.map(|curve| curve.inner.clone()).unwrap_or_default())
+            .with_time_range(time_range.0, time_range.1)
+            .with_value_range(value_range.0, value_range.1)
+            .with_bounds(x, y, width, height);
+        Self { inner: component }
+    }
+
+    /// Replace the edited curve without firing `on_curve_changed`.
+    fn set_curve(&mut self, curve: PyRef<PyAnimationCurve>) {
+        self.inner.set_curve(curve.inner.clone());
+    }
+
+    /// Get a copy of the edited curve.
+    fn get_curve(&self) -> PyAnimationCurve {
+        PyAnimationCurve {
+            inner: self.inner.curve().clone(),
+        }
+    }
+
+    fn set_time_range(&mut self, start: f32, end: f32) {
+        self.inner.set_time_range(start, end);
+    }
+
+    fn get_time_range(&self) -> (f32, f32) {
+        self.inner.time_range()
+    }
+
+    fn set_value_range(&mut self, min_value: f32, max_value: f32) {
+        self.inner.set_value_range(min_value, max_value);
+    }
+
+    fn get_value_range(&self) -> (f32, f32) {
+        self.inner.value_range()
+    }
+
+    /// Set the number of grid divisions along each axis (0 hides the grid).
+    fn set_grid_divisions(&mut self, divisions: u32) {
+        self.inner.set_grid_divisions(divisions);
+    }
+
+    fn get_grid_divisions(&self) -> u32 {
+        self.inner.grid_divisions()
+    }
+
+    /// Index of the selected key, or None.
+    fn get_selected_key(&self) -> Option<usize> {
+        self.inner.selected_key()
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(x, y, bounds.width, bounds.height));
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(bounds.x, bounds.y, width, height));
+    }
+
+    fn set_depth(&mut self, depth: f32) {
+        self.inner = std::mem::replace(&mut self.inner, CurveEditorComponent::new("temp"))
+            .with_depth(depth);
+    }
+
+    /// Set a Python callback fired with a copy of the curve whenever the user edits it.
+    ///
+    /// The callback takes a single `AnimationCurve` argument and runs on the main engine thread.
+    /// Exceptions are handled by the engine's `callback_error_policy`.
+    fn set_on_curve_changed(&mut self, py_callback: Py<PyAny>) {
+        self.inner.set_on_curve_changed(move |curve| {
+            pyo3::Python::attach(|py| {
+                let curve = PyAnimationCurve { inner: curve.clone() };
+                if let Err(e) = py_callback.call1(py, (curve,)) {
+                    report_callback_error(py, "curve editor on_curve_changed callback", e);
+                }
+            });
+        });
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.inner.is_enabled_self()
+    }
+
+    #[setter(enabled)]
+    fn set_enabled_property(&mut self, enabled: bool) {
+        self.inner.set_enabled_self(enabled);
+    }
+}
+
+/// Python wrapper for RadioGroup.
+///
+/// Pass the same group to every radio button that should be mutually exclusive.
+#[pyclass(name = "RadioGroup")]
+pub struct PyRadioGroup {
+    inner: RadioGroup,
+}
+
+#[pymethods]
+impl PyRadioGroup {
+    #[new]
+    #[pyo3(signature = (name=""))]
+    fn new(name: &str) -> Self {
+        Self { inner: RadioGroup::new(name) }
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    /// Set a Python callback fired with the selected button's value whenever
+    /// the user picks a different member of the group.
+    ///
+    /// The callback takes a single `str` argument and runs on the main engine thread.
+    /// Exceptions are handled by the engine's `callback_error_policy`.
+    fn set_on_selection_changed(&mut self, py_callback: Py<PyAny>) {
+        self.inner.set_on_selection_changed(move |value| {
+            pyo3::Python::attach(|py| {
+                if let Err(e) = py_callback.call1(py, (value,)) {
+                    report_callback_error(py, "radio group on_selection_changed callback", e);
+                }
+            });
+        });
+    }
+}
+
+/// Python wrapper for RadioButtonComponent.
+#[pyclass(name = "RadioButtonComponent")]
+pub struct PyRadioButtonComponent {
+    inner: RadioButtonComponent,
+}
+
+#[pymethods]
+impl PyRadioButtonComponent {
+    #[new]
+    #[pyo3(signature = (label="", value="", group=None, selected=false, x=0.0, y=0.0, width=160.0, height=24.0))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        label: &str,
+        value: &str,
+        group: Option<PyRef<PyRadioGroup>>,
+        selected: bool,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+    ) -> Self {
+        let mut component = RadioButtonComponent::new("RadioButton")
+            .with_label(label)
+            .with_value(value)
+            .with_selected(selected)
+            .with_bounds(x, y, width, height);
+        if let Some(group) = group {
+            component = component.with_group(group.inner.clone());
+        }
+        Self { inner: component }
+    }
+
+    /// Set the selected state without updating the rest of the group.
+    fn set_selected(&mut self, selected: bool) {
+        self.inner.set_selected(selected);
+    }
+
+    fn is_selected(&self) -> bool {
+        self.inner.is_selected()
+    }
+
+    fn set_label(&mut self, label: String) {
+        self.inner.set_label(label);
+    }
+
+    fn get_label(&self) -> String {
+        self.inner.label().to_string()
+    }
+
+    fn set_value(&mut self, value: String) {
+        self.inner.set_value(value);
+    }
+
+    fn get_value(&self) -> String {
+        self.inner.value().to_string()
+    }
+
+    /// Move the button into `group`, or out of any group with `None`.
+    #[pyo3(signature = (group=None))]
+    fn set_group(&mut self, group: Option<PyRef<PyRadioGroup>>) {
+        self.inner.set_group(group.map(|group| group.inner.clone()));
+    }
+
+    fn set_position(&mut self, x: f32, y: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(x, y, bounds.width, bounds.height));
+    }
+
+    fn set_size(&mut self, width: f32, height: f32) {
+        let bounds = self.inner.bounds();
+        self.inner.set_bounds(Rect::new(bounds.x, bounds.y, width, height));
+    }
+
+    fn set_depth(&mut self, depth: f32) {
+        self.inner = std::mem::replace(&mut self.inner, RadioButtonComponent::new("temp"))
+            .with_depth(depth);
+    }
+
+    fn set_font_size(&mut self, font_size: f32) {
+        self.inner.set_font_size(font_size);
+    }
+
+    fn set_font_path(&mut self, font_path: Option<String>) {
+        self.inner.set_font_path(font_path);
+    }
+
+    fn set_font_family(&mut self, font_family: Option<String>) {
+        self.inner.set_font_family(font_family);
+    }
+
+    fn set_font_weight(&mut self, font_weight: &str) -> PyResult<()> {
+        self.inner.set_font_weight(parse_font_weight(Some(font_weight))?);
+        Ok(())
+    }
+
+    fn set_font_style(&mut self, font_style: &str) -> PyResult<()> {
+        self.inner.set_font_style(parse_font_style(Some(font_style))?);
+        Ok(())
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.inner.is_enabled_self()
+    }
+
+    #[setter(enabled)]
+    fn set_enabled_property(&mut self, enabled: bool) {
+        self.inner.set_enabled_self(enabled);
+    }
+}
+
+/// Python wrapper for ListViewComponent.
+#[pyclass(name = "ListViewComponent")]
+pub struct PyListViewComponent {
+    inner: ListViewComponent,
+}
+
+#[pymethods]
+impl PyListViewComponent {
+    #[new]
+    #[pyo3(signature = (item_count=0, row_height=24.0, x=0.0, y=0.0, width=200.0, height=200.0))]
+    fn new(item_count: usize, row_height: f32, x: f32, y: f32, width: f32, height: f32) -> Self {
+        let component = ListViewComponent::new("ListView")
+            .with_bounds(x, y, width, height)
+            .with_row_height(row_height)
+            .with_item_count(item_count);
+        Self { inner: component }
+    }
+
+    fn set_item_count(&mut self, item_count: usize) {
+        self.inner.set_item_count(item_count);
+    }
+
+    fn get_item_count(&self) -> usize {
+        self.inner.item_count()
+    }
+
+    fn set_row_height(&mut self, row_height: f32) {
+        self.inner.set_row_height(row_height);
+    }
+
+    fn get_row_height(&self) -> f32 {
+        self.inner.row_height()
+    }
+
+    /// Fetch the visible rows from the item provider again on the next update.
+    fn refresh(&mut self) {
+        self.inner.refresh();
+    }
+
+    /// Select a row, or clear the selection with `None`, without firing `on_selection_changed`.
+    #[pyo3(signature = (index=None))]
+    fn set_selected(&mut self, index: Option<usize>) {
+        self.inner.set_selected(index);
+    }
+
+    fn get_selected(&self) -> Option<usize> {
+        self.inner.selected()
+    }
+
+    /// Set the scroll offset in pixels, clamped to the rows.
+    fn set_scroll_offset(&mut self, offset: f32) {
+        self.inner.set_scroll_offset(offset);
     }
 
-    #[getter]
-    fn name(&self) -> String {
-        self.inner.name().to_string()
+    fn get_scroll_offset(&self) -> f32 {
+        self.inner.scroll_offset()
     }
-}
 
-/// Python wrapper for PanelComponent.
-#[pyclass(name = "PanelComponent")]
-pub struct PyPanelComponent {
-    inner: PanelComponent,
-}
+    /// Scroll by the smallest amount that shows the whole row `index`.
+    fn scroll_to_item(&mut self, index: usize) {
+        self.inner.scroll_to_item(index);
+    }
 
-#[pymethods]
-impl PyPanelComponent {
-    #[new]
-    #[pyo3(signature = (x=0.0, y=0.0, width=200.0, height=200.0))]
-    fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
-        let panel = PanelComponent::new("Panel")
-            .with_bounds(x, y, width, height);
-        Self { inner: panel }
+    /// Get the visible rows as `(first, end)`, `end` excluded.
+    fn get_visible_range(&self) -> (usize, usize) {
+        let range = self.inner.visible_range();
+        (range.start, range.end)
+    }
+
+    /// Set how many pixels one mouse wheel line scrolls.
+    fn set_scroll_speed(&mut self, speed: f32) {
+        self.inner.set_scroll_speed(speed);
+    }
+
+    fn set_show_scrollbar(&mut self, show: bool) {
+        self.inner.set_show_scrollbar(show);
+    }
+
+    fn set_background_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
+        self.inner.style_mut().background_color = [r, g, b, a];
+    }
+
+    fn set_text_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
+        self.inner.style_mut().text_color = [r, g, b, a];
+    }
+
+    fn set_border(&mut self, width: f32, r: f32, g: f32, b: f32, a: f32) {
+        let style = self.inner.style_mut();
+        style.border_width = width;
+        style.border_color = [r, g, b, a];
+    }
+
+    fn set_hover_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
+        self.inner.set_hover_color([r, g, b, a]);
+    }
+
+    fn set_selection_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
+        self.inner.set_selection_color([r, g, b, a]);
+    }
+
+    fn set_selected_text_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
+        self.inner.set_selected_text_color([r, g, b, a]);
     }
 
     fn set_position(&mut self, x: f32, y: f32) {
@@ -7648,18 +13966,75 @@ impl PyPanelComponent {
     }
 
     fn set_depth(&mut self, depth: f32) {
-        self.inner = std::mem::replace(&mut self.inner, PanelComponent::new("temp"))
-            .with_depth(depth);
+        self.inner.set_depth(depth);
     }
 
-    fn set_background_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
-        self.inner.style_mut().background_color = [r, g, b, a];
+    fn set_font_size(&mut self, font_size: f32) {
+        self.inner.set_font_size(font_size);
     }
 
-    fn set_border(&mut self, width: f32, r: f32, g: f32, b: f32, a: f32) {
-        let style = self.inner.style_mut();
-        style.border_width = width;
-        style.border_color = [r, g, b, a];
+    fn set_font_path(&mut self, font_path: Option<String>) {
+        self.inner.set_font_path(font_path);
+    }
+
+    fn set_font_family(&mut self, font_family: Option<String>) {
+        self.inner.set_font_family(font_family);
+    }
+
+    fn set_font_weight(&mut self, font_weight: &str) -> PyResult<()> {
+        self.inner.set_font_weight(parse_font_weight(Some(font_weight))?);
+        Ok(())
+    }
+
+    fn set_font_style(&mut self, font_style: &str) -> PyResult<()> {
+        self.inner.set_font_style(parse_font_style(Some(font_style))?);
+        Ok(())
+    }
+
+    /// Set a Python callback that returns the text of row `index`.
+    ///
+    /// The callback takes an `int` and returns a `str`, or `None` to leave the
+    /// row empty. It is only called for rows scrolled into view, on the main
+    /// engine thread. Exceptions are logged and leave the row empty.
+    fn set_item_provider(&mut self, py_callback: Py<PyAny>) {
+        self.inner.set_item_provider(move |index| {
+            pyo3::Python::attach(|py| match py_callback.call1(py, (index,)) {
+                Ok(item) => item.extract::<String>(py).ok(),
+                Err(e) => {
+                    report_callback_error(py, "list view item provider", e);
+                    None
+                }
+            })
+        });
+    }
+
+    /// Set a Python callback fired with the row index whenever the user selects a different row.
+    ///
+    /// The callback takes one `int` argument and runs on the main engine thread.
+    /// Exceptions are handled by the engine's `callback_error_policy`.
+    fn set_on_selection_changed(&mut self, py_callback: Py<PyAny>) {
+        self.inner.set_on_selection_changed(move |index| {
+            pyo3::Python::attach(|py| {
+                if let Err(e) = py_callback.call1(py, (index,)) {
+                    report_callback_error(py, "list view on_selection_changed callback", e);
+                }
+            });
+        });
+    }
+
+    /// Set a Python callback fired with the visible range `(first, end)` each
+    /// time its rows were fetched from the item provider.
+    ///
+    /// The callback runs on the main engine thread. Exceptions are handled by
+    /// the engine's `callback_error_policy`.
+    fn set_on_visible_rows_changed(&mut self, py_callback: Py<PyAny>) {
+        self.inner.set_on_visible_rows_changed(move |first, end| {
+            pyo3::Python::attach(|py| {
+                if let Err(e) = py_callback.call1(py, (first, end)) {
+                    report_callback_error(py, "list view on_visible_rows_changed callback", e);
+                }
+            });
+        });
     }
 
     #[getter]
@@ -7683,99 +14058,320 @@ impl PyPanelComponent {
     }
 }
 
-/// Python wrapper for LabelComponent.
-#[pyclass(name = "LabelComponent")]
-pub struct PyLabelComponent {
-    inner: LabelComponent,
+/// Batching statistics for the last rendered frame, see `Engine.render_stats()`.
+#[pyclass(name = "RenderStats")]
+pub struct PyRenderStats {
+    inner: RenderStats,
 }
 
 #[pymethods]
-impl PyLabelComponent {
-    #[new]
-    #[pyo3(signature = (text="", x=0.0, y=0.0, font_size=14.0))]
-    fn new(text: &str, x: f32, y: f32, font_size: f32) -> Self {
-        let mut label = LabelComponent::new("Label")
-            .with_text(text)
-            .with_position(x, y);
-        label.set_font_size(font_size);
-        Self { inner: label }
+impl PyRenderStats {
+    /// Meshes, text and draw commands submitted.
+    #[getter]
+    fn draw_items(&self) -> usize {
+        self.inner.draw_items
     }
 
-    fn set_text(&mut self, text: &str) {
-        self.inner.set_text(text);
+    /// GPU draw calls after batching.
+    #[getter]
+    fn draw_calls(&self) -> usize {
+        self.inner.draw_calls
     }
 
-    fn get_text(&self) -> String {
-        self.inner.text().to_string()
+    /// Draw calls that bind a different texture than the call before them.
+    #[getter]
+    fn texture_switches(&self) -> usize {
+        self.inner.texture_switches
     }
 
-    fn set_position(&mut self, x: f32, y: f32) {
-        self.inner.set_position(x, y);
+    /// Average number of draw items merged into each draw call.
+    #[getter]
+    fn items_per_draw_call(&self) -> f32 {
+        self.inner.items_per_draw_call()
     }
 
-    fn set_font_size(&mut self, size: f32) {
-        self.inner.set_font_size(size);
+    fn __repr__(&self) -> String {
+        format!(
+            "RenderStats(draw_items={}, draw_calls={}, texture_switches={})",
+            self.inner.draw_items, self.inner.draw_calls, self.inner.texture_switches
+        )
     }
+}
 
-    fn set_font_path(&mut self, font_path: Option<String>) {
-        self.inner.set_font_path(font_path);
+/// Per-scope timings of the last completed frame, see `Engine.get_frame_stats()`.
+///
+/// All times are in milliseconds.
+#[pyclass(name = "FrameStats")]
+pub struct PyFrameStats {
+    inner: FrameStats,
+}
+
+#[pymethods]
+impl PyFrameStats {
+    /// Number of the frame, counting from 1; 0 until a frame completes.
+    #[getter]
+    fn frame(&self) -> u64 {
+        self.inner.frame
     }
 
-    fn set_font_family(&mut self, font_family: Option<String>) {
-        self.inner.set_font_family(font_family);
+    /// Wall-clock time from the start of the frame to the start of the next.
+    #[getter]
+    fn frame_ms(&self) -> f32 {
+        self.inner.frame_ms
     }
 
-    fn set_font_weight(&mut self, font_weight: &str) -> PyResult<()> {
-        self.inner.set_font_weight(parse_font_weight(Some(font_weight))?);
-        Ok(())
+    /// Measured work of the frame: the sum of every scope.
+    #[getter]
+    fn total_ms(&self) -> f32 {
+        self.inner.total_ms()
     }
 
-    fn set_font_style(&mut self, font_style: &str) -> PyResult<()> {
-        self.inner.set_font_style(parse_font_style(Some(font_style))?);
-        Ok(())
+    /// Whether the frame went over the budget or was a spike.
+    #[getter]
+    fn slow(&self) -> bool {
+        self.inner.slow
     }
 
-    fn set_kerning(&mut self, kerning: bool) {
-        self.inner.set_kerning(kerning);
+    /// Name of the scope that took the longest.
+    #[getter]
+    fn slowest_scope(&self) -> &'static str {
+        self.inner.slowest_scope().name()
     }
 
-    fn set_color(&mut self, r: f32, g: f32, b: f32, a: f32) {
-        self.inner.set_color([r, g, b, a]);
+    #[getter]
+    fn input_ms(&self) -> f32 {
+        self.inner.scope_ms(ProfileScope::Input)
     }
 
-    fn set_align(&mut self, align: &str) {
-        let text_align = match align.to_lowercase().as_str() {
-            "left" => TextAlign::Left,
-            "center" => TextAlign::Center,
-            "right" => TextAlign::Right,
-            _ => TextAlign::Left,
-        };
-        self.inner.set_align(text_align);
+    #[getter]
+    fn ui_ms(&self) -> f32 {
+        self.inner.scope_ms(ProfileScope::Ui)
     }
 
-    fn set_depth(&mut self, depth: f32) {
-        self.inner = std::mem::replace(&mut self.inner, LabelComponent::new("temp"))
-            .with_depth(depth);
+    #[getter]
+    fn update_ms(&self) -> f32 {
+        self.inner.scope_ms(ProfileScope::Update)
     }
 
     #[getter]
-    fn name(&self) -> String {
-        self.inner.name().to_string()
+    fn fixed_ms(&self) -> f32 {
+        self.inner.scope_ms(ProfileScope::Fixed)
     }
 
     #[getter]
-    fn id(&self) -> u32 {
-        self.inner.id()
+    fn physics_ms(&self) -> f32 {
+        self.inner.scope_ms(ProfileScope::Physics)
     }
 
     #[getter]
-    fn enabled(&self) -> bool {
-        self.inner.is_enabled_self()
+    fn scripts_ms(&self) -> f32 {
+        self.inner.scope_ms(ProfileScope::Scripts)
     }
 
-    #[setter(enabled)]
-    fn set_enabled_property(&mut self, enabled: bool) {
-        self.inner.set_enabled_self(enabled);
+    #[getter]
+    fn render_ms(&self) -> f32 {
+        self.inner.scope_ms(ProfileScope::Render)
+    }
+
+    /// `(name, ms)` pairs for every scope, in frame order.
+    #[getter]
+    fn scopes(&self) -> Vec<(&'static str, f32)> {
+        self.inner.scopes().collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "FrameStats(frame={}, frame_ms={:.2}, total_ms={:.2})",
+            self.inner.frame,
+            self.inner.frame_ms,
+            self.inner.total_ms()
+        )
+    }
+}
+
+/// A loaded asset file, see `Engine.assets`.
+///
+/// Handles compare equal when they refer to the same loaded file. Pass a
+/// texture handle wherever a texture path is accepted.
+#[pyclass(name = "AssetHandle", frozen, eq, hash)]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct PyAssetHandle {
+    inner: AssetHandle,
+    kind: AssetKind,
+    path: String,
+}
+
+#[pymethods]
+impl PyAssetHandle {
+    /// Integer id of the handle, unique among handles of loaded assets.
+    #[getter]
+    fn id(&self) -> u64 {
+        self.inner.to_bits()
+    }
+
+    /// "texture", "font", "audio" or "data".
+    #[getter]
+    fn kind(&self) -> &'static str {
+        self.kind.as_str()
+    }
+
+    /// Resolved path of the asset's file.
+    #[getter]
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn __repr__(&self) -> String {
+        format!("AssetHandle(kind='{}', path='{}')", self.kind.as_str(), self.path)
+    }
+}
+
+// ========== Capability Bindings ==========
+
+/// GPU adapter and window surface capabilities, see `Engine.capabilities()`.
+#[pyclass(name = "RendererCapabilities")]
+pub struct PyRendererCapabilities {
+    inner: RendererCapabilities,
+}
+
+#[pymethods]
+impl PyRendererCapabilities {
+    #[getter]
+    fn adapter_name(&self) -> String {
+        self.inner.adapter_name.clone()
+    }
+
+    /// Graphics API in use: "vulkan", "metal", "dx12", "gl", ...
+    #[getter]
+    fn backend(&self) -> String {
+        self.inner.backend.clone()
+    }
+
+    /// "discrete_gpu", "integrated_gpu", "virtual_gpu", "cpu" or "other".
+    #[getter]
+    fn device_type(&self) -> String {
+        self.inner.device_type.clone()
+    }
+
+    #[getter]
+    fn vendor_id(&self) -> u32 {
+        self.inner.vendor_id
+    }
+
+    #[getter]
+    fn device_id(&self) -> u32 {
+        self.inner.device_id
+    }
+
+    #[getter]
+    fn driver(&self) -> String {
+        self.inner.driver.clone()
+    }
+
+    #[getter]
+    fn driver_info(&self) -> String {
+        self.inner.driver_info.clone()
+    }
+
+    /// Largest width or height of a texture, in pixels.
+    #[getter]
+    fn max_texture_size(&self) -> u32 {
+        self.inner.max_texture_size
+    }
+
+    /// Probed texture formats the adapter can sample from.
+    #[getter]
+    fn texture_formats(&self) -> Vec<String> {
+        self.inner.texture_formats.clone()
+    }
+
+    #[getter]
+    fn surface_formats(&self) -> Vec<String> {
+        self.inner.surface_formats.clone()
+    }
+
+    #[getter]
+    fn surface_format(&self) -> String {
+        self.inner.surface_format.clone()
+    }
+
+    /// Supported MSAA sample counts, e.g. [1, 2, 4, 8].
+    #[getter]
+    fn msaa_sample_counts(&self) -> Vec<u32> {
+        self.inner.msaa_sample_counts.clone()
+    }
+
+    /// Supported present modes, e.g. ["fifo", "mailbox", "immediate"].
+    #[getter]
+    fn present_modes(&self) -> Vec<String> {
+        self.inner.present_modes.clone()
+    }
+
+    #[getter]
+    fn present_mode(&self) -> String {
+        self.inner.present_mode.clone()
+    }
+
+    /// True when no usable GPU was found and a software adapter is drawing.
+    #[getter]
+    fn safe_mode(&self) -> bool {
+        self.inner.safe_mode
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "RendererCapabilities(adapter_name={:?}, backend={:?}, max_texture_size={})",
+            self.inner.adapter_name, self.inner.backend, self.inner.max_texture_size
+        )
+    }
+}
+
+/// Engine version, enabled features and renderer capabilities.
+///
+/// `str(capabilities)` gives a multi-line summary for bug reports.
+#[pyclass(name = "EngineCapabilities")]
+pub struct PyEngineCapabilities {
+    inner: EngineCapabilities,
+}
+
+#[pymethods]
+impl PyEngineCapabilities {
+    #[getter]
+    fn engine_version(&self) -> String {
+        self.inner.engine_version.clone()
+    }
+
+    /// Enabled engine features, e.g. ["python-bindings", "vsync"].
+    #[getter]
+    fn features(&self) -> Vec<String> {
+        self.inner.features.clone()
+    }
+
+    /// Whether `feature` is in `features`.
+    fn has_feature(&self, feature: &str) -> bool {
+        self.inner.features.iter().any(|enabled| enabled == feature)
+    }
+
+    /// `None` until the window and renderer have been created.
+    #[getter]
+    fn renderer(&self) -> Option<PyRendererCapabilities> {
+        self.inner
+            .renderer
+            .clone()
+            .map(|inner| PyRendererCapabilities { inner })
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "EngineCapabilities(engine_version={:?}, features={:?}, renderer={})",
+            self.inner.engine_version,
+            self.inner.features,
+            if self.inner.renderer.is_some() { "..." } else { "None" }
+        )
     }
 }
 
@@ -7788,6 +14384,7 @@ fn pyg_engine_native(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyEngine>()?;
     m.add_class::<PyEngineHandle>()?;
     m.add_class::<PyDrawCommand>()?;
+    m.add_class::<PyDrawRecorder>()?;
     m.add_class::<PyVec2>()?;
     m.add_class::<PyVec3>()?;
     m.add_class::<PyColor>()?;
@@ -7796,16 +14393,45 @@ fn pyg_engine_native(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyMeshGeometry>()?;
     m.add_class::<PyMeshComponent>()?;
     m.add_class::<PyTextMeshComponent>()?;
+    m.add_class::<PyWorldLabelComponent>()?;
     m.add_class::<PyTransformComponent>()?;
     m.add_class::<PyButtonComponent>()?;
     m.add_class::<PyPanelComponent>()?;
     m.add_class::<PyLabelComponent>()?;
+    m.add_class::<PyTextInputComponent>()?;
+    m.add_class::<PySliderComponent>()?;
+    m.add_class::<PyCheckboxComponent>()?;
+    m.add_class::<PyToggleSwitchComponent>()?;
+    m.add_class::<PyScrollViewComponent>()?;
+    m.add_class::<PyProgressBarComponent>()?;
+    m.add_class::<PyCustomWidgetComponent>()?;
+    m.add_class::<PyCanvasComponent>()?;
+    m.add_class::<PyCanvasPainter>()?;
+    m.add_class::<PyImageComponent>()?;
+    m.add_class::<PyStackComponent>()?;
+    m.add_class::<PyCurveEditorComponent>()?;
+    m.add_class::<PyAnimationCurve>()?;
+    m.add_class::<PyRadioButtonComponent>()?;
+    m.add_class::<PyRadioGroup>()?;
+    m.add_class::<PyListViewComponent>()?;
+    m.add_class::<PyUIEvent>()?;
+    m.add_class::<PyInputEvent>()?;
+    m.add_class::<PyRenderStats>()?;
+    m.add_class::<PyFrameStats>()?;
+    m.add_class::<PyCallbackError>()?;
+    m.add_class::<PyAssetHandle>()?;
+    m.add_class::<PyEngineCapabilities>()?;
+    m.add_class::<PyRendererCapabilities>()?;
     m.add_class::<PyCameraAspectMode>()?;
+    m.add_class::<PyUIScaleMode>()?;
     m.add_class::<PyMouseButton>()?;
     m.add_class::<PyKeys>()?;
 
     // Register physics bindings
     crate::bindings::physics_bind::register_physics_bindings(m)?;
 
+    // Register audio bindings
+    crate::bindings::audio_bind::register_audio_bindings(m)?;
+
     Ok(())
 }
@@ -1,6 +1,6 @@
 use pyo3::prelude::*;
-use winit::keyboard::{Key, NamedKey, NativeKey};
 
+pub use crate::core::input_bindings::parse_key;
 use crate::core::input_manager::MouseButtonType;
 
 #[pyclass(name = "MouseButton")]
@@ -27,87 +27,13 @@ pub fn parse_mouse_button(button_name: &str) -> MouseButtonType {
     }
 }
 
-/// Helper to parse a string key code from Python into a winit Key
-pub fn parse_key(key_name: &str) -> Key {
-    let normalized: String = key_name
-        .trim()
-        .chars()
-        .flat_map(|ch| ch.to_lowercase())
-        .filter(|ch| !matches!(ch, ' ' | '_' | '-'))
-        .collect();
-
-    if normalized.len() == 1 {
-        return Key::Character(normalized.into());
-    }
-
-    if let Some(rest) = normalized.strip_prefix('f')
-        && let Ok(index) = rest.parse::<u8>()
-    {
-        return match index {
-            1 => Key::Named(NamedKey::F1),
-            2 => Key::Named(NamedKey::F2),
-            3 => Key::Named(NamedKey::F3),
-            4 => Key::Named(NamedKey::F4),
-            5 => Key::Named(NamedKey::F5),
-            6 => Key::Named(NamedKey::F6),
-            7 => Key::Named(NamedKey::F7),
-            8 => Key::Named(NamedKey::F8),
-            9 => Key::Named(NamedKey::F9),
-            10 => Key::Named(NamedKey::F10),
-            11 => Key::Named(NamedKey::F11),
-            12 => Key::Named(NamedKey::F12),
-            13 => Key::Named(NamedKey::F13),
-            14 => Key::Named(NamedKey::F14),
-            15 => Key::Named(NamedKey::F15),
-            16 => Key::Named(NamedKey::F16),
-            17 => Key::Named(NamedKey::F17),
-            18 => Key::Named(NamedKey::F18),
-            19 => Key::Named(NamedKey::F19),
-            20 => Key::Named(NamedKey::F20),
-            21 => Key::Named(NamedKey::F21),
-            22 => Key::Named(NamedKey::F22),
-            23 => Key::Named(NamedKey::F23),
-            24 => Key::Named(NamedKey::F24),
-            _ => Key::Unidentified(NativeKey::Unidentified),
-        };
-    }
-
-    match normalized.as_str() {
-        // Named keys
-        "escape" | "esc" => Key::Named(NamedKey::Escape),
-        "enter" | "return" => Key::Named(NamedKey::Enter),
-        "space" => Key::Named(NamedKey::Space),
-        "backspace" => Key::Named(NamedKey::Backspace),
-        "tab" => Key::Named(NamedKey::Tab),
-        "arrowup" | "up" => Key::Named(NamedKey::ArrowUp),
-        "arrowdown" | "down" => Key::Named(NamedKey::ArrowDown),
-        "arrowleft" | "left" => Key::Named(NamedKey::ArrowLeft),
-        "arrowright" | "right" => Key::Named(NamedKey::ArrowRight),
-        "insert" => Key::Named(NamedKey::Insert),
-        "delete" | "del" => Key::Named(NamedKey::Delete),
-        "home" => Key::Named(NamedKey::Home),
-        "end" => Key::Named(NamedKey::End),
-        "pageup" | "pgup" => Key::Named(NamedKey::PageUp),
-        "pagedown" | "pgdown" => Key::Named(NamedKey::PageDown),
-        "numlock" => Key::Named(NamedKey::NumLock),
-        "scrolllock" => Key::Named(NamedKey::ScrollLock),
-        "pause" => Key::Named(NamedKey::Pause),
-        "printscreen" | "prtsc" | "snapshot" => Key::Named(NamedKey::PrintScreen),
-        "shift" => Key::Named(NamedKey::Shift),
-        "leftshift" | "lshift" | "rightshift" | "rshift" => Key::Named(NamedKey::Shift),
-        "control" | "ctrl" => Key::Named(NamedKey::Control),
-        "leftcontrol" | "lcontrol" | "leftctrl" | "lctrl" => Key::Named(NamedKey::Control),
-        "rightcontrol" | "rcontrol" | "rightctrl" | "rctrl" => Key::Named(NamedKey::Control),
-        "alt" => Key::Named(NamedKey::Alt),
-        "leftalt" | "lalt" | "rightalt" | "ralt" | "altgr" | "option" => Key::Named(NamedKey::Alt),
-        "super" | "meta" | "command" | "cmd" | "win" | "windows" | "os" => {
-            Key::Named(NamedKey::Super)
-        }
-        "capslock" => Key::Named(NamedKey::CapsLock),
-        "menu" | "contextmenu" => Key::Named(NamedKey::ContextMenu),
-
-        // Fallback
-        _ => Key::Unidentified(NativeKey::Unidentified),
+/// Name of an engine mouse button as used by Python ("left", "right", "middle" or "other").
+pub fn mouse_button_name(button: MouseButtonType) -> &'static str {
+    match button {
+        MouseButtonType::Left => "left",
+        MouseButtonType::Right => "right",
+        MouseButtonType::Middle => "middle",
+        MouseButtonType::Other(_) => "other",
     }
 }
 
@@ -1,9 +1,13 @@
+mod audio_bind;
+mod callback_errors;
 mod color_bind;
 mod engine_bind;
 pub mod input_bind;
 mod physics_bind;
 mod vector_bind;
 
+pub use audio_bind::*;
+pub use callback_errors::*;
 pub use color_bind::*;
 pub use engine_bind::*;
 pub use input_bind::*;
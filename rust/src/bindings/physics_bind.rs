@@ -1,7 +1,10 @@
+use super::callback_errors::report_callback_error;
+use super::vector_bind::extract_points;
 use crate::core::component::ComponentTrait;
 use crate::core::physics::*;
 use crate::types::vector::Vec2;
 use pyo3::prelude::*;
+use pyo3::types::PyTuple;
 
 // ========== Collision Detection Bindings ==========
 
@@ -302,7 +305,10 @@ impl PyPhysicsLayers {
 /// - `Collider.set_shape()` - Apply shape to collider
 /// - `Collider.set_offset()` - Offset shape from center
 /// - `GameObject.scale` - Scales the collision shape
-#[pyclass(name = "ColliderShape")]
+///
+/// Shapes compare equal when they are the same kind with the same sizes, and
+/// support `pickle` and `copy`.
+#[pyclass(name = "ColliderShape", module = "pyg_engine.pyg_engine_native")]
 #[derive(Clone)]
 pub struct PyColliderShape {
     pub(crate) shape: ColliderShape,
@@ -523,7 +529,8 @@ impl PyColliderShape {
     /// Concave polygons may cause incorrect collision detection.
     ///
     /// # Arguments
-    /// * `vertices` - List of (x, y) tuples defining polygon vertices in local space
+    /// * `vertices` - (x, y) tuples or `Vec2`s defining polygon vertices in local
+    ///   space, or a float array shaped `(n, 2)`
     ///
     /// # Vertex Requirements
     /// - Must have at least 3 vertices (triangle)
@@ -591,12 +598,76 @@ impl PyColliderShape {
     /// - `box_shape()` - Rectangle
     /// - `obb()` - Rotating rectangle
     #[staticmethod]
-    fn polygon(vertices: Vec<(f32, f32)>) -> Self {
-        let verts: Vec<Vec2> = vertices.iter().map(|&(x, y)| Vec2::new(x, y)).collect();
-        Self {
-            shape: ColliderShape::polygon(verts),
+    fn polygon(vertices: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self {
+            shape: ColliderShape::polygon(extract_points(vertices)?),
+        })
+    }
+
+    fn __eq__(&self, other: &PyColliderShape) -> bool {
+        self.shape == other.shape
+    }
+
+    fn __repr__(&self) -> String {
+        match &self.shape {
+            ColliderShape::Circle { radius } => format!("ColliderShape.circle({radius})"),
+            ColliderShape::Box { half_extents } => format!(
+                "ColliderShape.box_shape({}, {})",
+                half_extents.x(),
+                half_extents.y()
+            ),
+            ColliderShape::OBB {
+                half_extents,
+                local_rotation,
+            } => format!(
+                "ColliderShape.obb({}, {}, {local_rotation})",
+                half_extents.x(),
+                half_extents.y()
+            ),
+            ColliderShape::Polygon { vertices } => {
+                let points: Vec<String> = vertices
+                    .iter()
+                    .map(|vertex| format!("({}, {})", vertex.x(), vertex.y()))
+                    .collect();
+                format!("ColliderShape.polygon([{}])", points.join(", "))
+            }
         }
     }
+
+    /// Pickle as a call to the static constructor that made the shape.
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyTuple>)> {
+        let py = slf.py();
+        let (constructor, args) = match &slf.borrow().shape {
+            ColliderShape::Circle { radius } => ("circle", PyTuple::new(py, [*radius])?),
+            ColliderShape::Box { half_extents } => (
+                "box_shape",
+                PyTuple::new(py, [half_extents.x(), half_extents.y()])?,
+            ),
+            ColliderShape::OBB {
+                half_extents,
+                local_rotation,
+            } => (
+                "obb",
+                PyTuple::new(py, [half_extents.x(), half_extents.y(), *local_rotation])?,
+            ),
+            ColliderShape::Polygon { vertices } => {
+                let points: Vec<(f32, f32)> = vertices
+                    .iter()
+                    .map(|vertex| (vertex.x(), vertex.y()))
+                    .collect();
+                ("polygon", PyTuple::new(py, [points])?)
+            }
+        };
+        Ok((slf.get_type().getattr(constructor)?, args))
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Self {
+        self.clone()
+    }
 }
 
 /// Collider component for collision detection.
@@ -1070,6 +1141,37 @@ impl PyCollider {
         self.component.is_trigger()
     }
 
+    /// Set the surface material, or None.
+    ///
+    /// Materials are free-form names like `"metal"` or `"wood"`. They pick the
+    /// sound played when this collider hits another with a material, see
+    /// `engine.audio.set_impact_sound()`.
+    ///
+    /// # Example
+    /// ```python
+    /// import pyg_engine as pyg
+    ///
+    /// crate_collider = pyg.Collider("Crate")
+    /// crate_collider.set_material("wood")
+    /// engine.audio.set_impact_sound("sounds/knock.wav", materials=("wood", "metal"))
+    /// ```
+    ///
+    /// # See Also
+    /// - `material` (property) - Get the material
+    #[pyo3(signature = (material))]
+    fn set_material(&mut self, material: Option<String>) {
+        self.component.set_material(material);
+    }
+
+    /// Get the surface material, or None.
+    ///
+    /// # See Also
+    /// - `set_material()` - Set the material
+    #[getter]
+    fn material(&self) -> Option<String> {
+        self.component.material().map(str::to_string)
+    }
+
     /// Set callback fired when collision starts.
     ///
     /// Registers a Python function to be called once when this collider first overlaps
@@ -1155,10 +1257,9 @@ impl PyCollider {
     fn set_on_collision_enter(&mut self, callback: Py<PyAny>) {
         self.component.set_on_collision_enter(move |other_id, normal, penetration| {
             Python::attach(|py| {
-                let _ = callback.call1(
-                    py,
-                    (other_id, normal.x(), normal.y(), penetration)
-                );
+                if let Err(e) = callback.call1(py, (other_id, normal.x(), normal.y(), penetration)) {
+                    report_callback_error(py, "collider on_collision_enter callback", e);
+                }
             });
         });
     }
@@ -1238,10 +1339,9 @@ impl PyCollider {
     fn set_on_collision_stay(&mut self, callback: Py<PyAny>) {
         self.component.set_on_collision_stay(move |other_id, normal, penetration| {
             Python::attach(|py| {
-                let _ = callback.call1(
-                    py,
-                    (other_id, normal.x(), normal.y(), penetration)
-                );
+                if let Err(e) = callback.call1(py, (other_id, normal.x(), normal.y(), penetration)) {
+                    report_callback_error(py, "collider on_collision_stay callback", e);
+                }
             });
         });
     }
@@ -1316,7 +1416,9 @@ impl PyCollider {
     fn set_on_collision_exit(&mut self, callback: Py<PyAny>) {
         self.component.set_on_collision_exit(move |other_id| {
             Python::attach(|py| {
-                let _ = callback.call1(py, (other_id,));
+                if let Err(e) = callback.call1(py, (other_id,)) {
+                    report_callback_error(py, "collider on_collision_exit callback", e);
+                }
             });
         });
     }
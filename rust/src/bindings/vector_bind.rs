@@ -1,8 +1,110 @@
 use crate::types::vector::{Vec2, Vec3};
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::{PyIndexError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyIterator, PyMemoryView, PyTuple, PyType};
 
 // ========== Vector Bindings ==========
 
+/// Hash vector components the way Python hashes a tuple of the same floats,
+/// so equal vectors hash alike (`0.0` and `-0.0` included).
+fn hash_components(py: Python<'_>, components: &[f32]) -> PyResult<isize> {
+    PyTuple::new(py, components.iter().map(|&component| component as f64))?.hash()
+}
+
+/// Iterate over vector components as Python floats.
+fn iter_components<'py>(py: Python<'py>, components: &[f32]) -> PyResult<Bound<'py, PyIterator>> {
+    PyTuple::new(py, components)?.try_iter()
+}
+
+/// Component at a Python index, counting from the end when negative.
+fn component_at(components: &[f32], index: isize) -> PyResult<f32> {
+    let len = components.len() as isize;
+    let position = if index < 0 { index + len } else { index };
+    if !(0..len).contains(&position) {
+        return Err(PyIndexError::new_err("vector index out of range"));
+    }
+    Ok(components[position as usize])
+}
+
+// ========== Point Data ==========
+
+/// Read points for bulk APIs like polygons and meshes.
+///
+/// Accepts a `float32` or `float64` buffer (a numpy array, `array.array` or
+/// `memoryview`) shaped `(n, 2)` or holding flat `x, y` pairs, which is copied
+/// in one go without a Python object per point. Anything else is iterated as
+/// `Vec2`s and `(x, y)` pairs.
+pub(crate) fn extract_points(points: &Bound<'_, PyAny>) -> PyResult<Vec<Vec2>> {
+    let py = points.py();
+    if let Ok(buffer) = PyBuffer::<f32>::get(points) {
+        check_point_shape(buffer.shape())?;
+        return Ok(pairs_to_points(&buffer.to_vec(py)?));
+    }
+    if let Ok(buffer) = PyBuffer::<f64>::get(points) {
+        check_point_shape(buffer.shape())?;
+        let values: Vec<f32> = buffer.to_vec(py)?.into_iter().map(|value| value as f32).collect();
+        return Ok(pairs_to_points(&values));
+    }
+    points
+        .try_iter()?
+        .map(|point| extract_point(&point?))
+        .collect()
+}
+
+fn check_point_shape(shape: &[usize]) -> PyResult<()> {
+    match shape {
+        [_, 2] => Ok(()),
+        [len] if len % 2 == 0 => Ok(()),
+        _ => Err(PyValueError::new_err(format!(
+            "point arrays must have shape (n, 2) or hold x, y pairs, got shape {shape:?}"
+        ))),
+    }
+}
+
+fn pairs_to_points(values: &[f32]) -> Vec<Vec2> {
+    values
+        .chunks_exact(2)
+        .map(|pair| Vec2::new(pair[0], pair[1]))
+        .collect()
+}
+
+fn extract_point(point: &Bound<'_, PyAny>) -> PyResult<Vec2> {
+    if let Ok(vec) = point.cast::<PyVec2>() {
+        return Ok(vec.borrow().inner);
+    }
+    let components = point
+        .try_iter()
+        .and_then(|items| items.map(|item| item?.extract::<f32>()).collect::<PyResult<Vec<f32>>>());
+    match components.as_deref() {
+        Ok([x, y]) => Ok(Vec2::new(*x, *y)),
+        _ => Err(PyTypeError::new_err(format!(
+            "expected a Vec2 or an (x, y) pair, got {}",
+            point.repr()?
+        ))),
+    }
+}
+
+/// Points as a read-only `float32` `memoryview` shaped `(n, 2)`, which
+/// `numpy.asarray()` wraps without copying. Empty point lists give an empty
+/// one-dimensional view, since a `memoryview` can't have a zero in its shape.
+pub(crate) fn points_to_buffer<'py>(
+    py: Python<'py>,
+    points: impl IntoIterator<Item = Vec2>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let values: Vec<f32> = points
+        .into_iter()
+        .flat_map(|point| [point.x(), point.y()])
+        .collect();
+    let bytes = PyBytes::new(py, bytemuck::cast_slice(&values));
+    let view = PyMemoryView::from(bytes.as_any())?;
+    if values.is_empty() {
+        view.call_method1("cast", ("f",))
+    } else {
+        view.call_method1("cast", ("f", (values.len() / 2, 2)))
+    }
+}
+
 /// 2D vector for positions, directions, and mathematical operations.
 ///
 /// `Vec2` represents a two-dimensional vector with `x` and `y` components.
@@ -16,6 +118,19 @@ use pyo3::prelude::*;
 /// - **Subtraction**: `v1 - v2` (component-wise), `v - scalar` (subtract from both)
 /// - **Multiplication**: `v1 * v2` (component-wise), `v * scalar` (scale)
 /// - **Division**: `v1 / v2` (component-wise), `v / scalar` (inverse scale)
+/// - **Negation**: `-v` (reverse direction), `abs(v)` (component-wise absolute value)
+/// - **Augmented assignment**: `v += other`, `v -= other`, `v *= other`, `v /= other`
+///
+/// Every operator returns a new vector, and augmented assignment rebinds the
+/// name to it, so `v = Vec2.ZERO; v += d` leaves `Vec2.ZERO` and any other
+/// reference to the old vector unchanged.
+///
+/// Vectors are immutable: `x` and `y` are read-only and no operator changes
+/// a vector after it is made. That is what makes them safe to hash, so they
+/// compare equal when their components are exactly equal and work as dict
+/// keys and in sets. They also behave like a
+/// sequence of their components: `x, y = v`, `tuple(v)`, `v[0]` and `len(v)`,
+/// and support `pickle` and `copy`.
 ///
 /// # Constants
 ///
@@ -43,6 +158,7 @@ use pyo3::prelude::*;
 /// new_pos = pos + velocity
 /// scaled = velocity * 2.0
 /// inverted = -velocity  # Same as velocity * -1.0
+/// pos += velocity  # Rebinds pos to a new vector
 /// ```
 ///
 /// ## Movement Example
@@ -76,6 +192,23 @@ use pyo3::prelude::*;
 /// # Move toward enemy
 /// speed = 50.0
 /// player = player + direction * speed * dt
+///
+/// # Or step toward it without overshooting
+/// player = player.move_towards(enemy, speed * dt)
+/// ```
+///
+/// ## Rotation and Angles
+/// Angles are in radians, counter-clockwise with y up.
+/// ```python
+/// import math
+/// from pyg_engine import Vec2
+///
+/// aim = Vec2(1.0, 0.0).rotate(math.pi / 4)
+/// heading = velocity.angle()            # Direction from the +x axis
+/// turn = aim.angle_to(velocity)         # Signed angle between them
+/// side = aim.perpendicular()            # Rotated 90° counter-clockwise
+/// bounced = velocity.reflect(Vec2.UP)   # Bounce off a floor
+/// velocity = velocity.clamp_length(10)  # Cap speed
 /// ```
 ///
 /// ## Interpolation
@@ -97,7 +230,7 @@ use pyo3::prelude::*;
 /// # See Also
 /// - `Vec3` - 3D vector with x, y, z components
 /// - `examples/python_game_object_transform_demo.py` - Transform operations
-#[pyclass(name = "Vec2")]
+#[pyclass(name = "Vec2", module = "pyg_engine.pyg_engine_native")]
 #[derive(Clone)]
 pub struct PyVec2 {
     pub(crate) inner: Vec2,
@@ -411,6 +544,120 @@ impl PyVec2 {
         }
     }
 
+    /// Rotate the vector by an angle in radians.
+    ///
+    /// Positive angles turn counter-clockwise with y up (world coordinates),
+    /// which is clockwise on screen in pixel coordinates where y points down.
+    ///
+    /// # Example
+    /// ```python
+    /// import math
+    /// Vec2(1.0, 0.0).rotate(math.pi / 2)  # Vec2(0.0, 1.0)
+    ///
+    /// # Fire a spread of bullets around the aim direction
+    /// for offset in (-0.2, 0.0, 0.2):
+    ///     spawn_bullet(position, aim.rotate(offset) * speed)
+    /// ```
+    fn rotate(&self, radians: f32) -> PyVec2 {
+        PyVec2 {
+            inner: self.inner.rotate(radians),
+        }
+    }
+
+    /// Get the direction of the vector in radians, measured from the +x axis.
+    ///
+    /// # Returns
+    /// Angle from -π to π, counter-clockwise with y up (`math.atan2(y, x)`).
+    /// A zero vector gives 0.0.
+    ///
+    /// # Example
+    /// ```python
+    /// # Point a sprite along its velocity
+    /// transform.rotation = velocity.angle()
+    /// ```
+    fn angle(&self) -> f32 {
+        self.inner.angle()
+    }
+
+    /// Get the signed angle in radians that rotates this vector onto `other`.
+    ///
+    /// # Returns
+    /// Angle from -π to π: positive if `other` is counter-clockwise from
+    /// `self`, negative if clockwise. 0.0 if either vector is zero.
+    ///
+    /// # Example
+    /// ```python
+    /// # Turn a turret toward its target at a limited rate
+    /// turn = facing.angle_to(target - position)
+    /// facing = facing.rotate(max(-turn_speed * dt, min(turn, turn_speed * dt)))
+    /// ```
+    fn angle_to(&self, other: &PyVec2) -> f32 {
+        self.inner.angle_to(&other.inner)
+    }
+
+    /// Return this vector rotated 90° counter-clockwise: `Vec2(-y, x)`.
+    ///
+    /// Useful for surface tangents, strafing directions and side offsets.
+    ///
+    /// # Example
+    /// ```python
+    /// forward = Vec2(1.0, 0.0)
+    /// left = forward.perpendicular()  # Vec2(0.0, 1.0)
+    /// ```
+    fn perpendicular(&self) -> PyVec2 {
+        PyVec2 {
+            inner: self.inner.perpendicular(),
+        }
+    }
+
+    /// Reflect the vector off a surface, like a ball bouncing off a wall.
+    ///
+    /// # Arguments
+    /// * `normal` - Surface normal; it is normalized first, so any length works.
+    ///   A zero normal returns the vector unchanged.
+    ///
+    /// # Example
+    /// ```python
+    /// velocity = Vec2(3.0, -4.0)
+    /// bounced = velocity.reflect(Vec2(0.0, 1.0))  # Vec2(3.0, 4.0)
+    /// ```
+    fn reflect(&self, normal: &PyVec2) -> PyVec2 {
+        PyVec2 {
+            inner: self.inner.reflect(&normal.inner),
+        }
+    }
+
+    /// Return the vector shortened to at most `max` length, keeping its direction.
+    ///
+    /// Shorter vectors are returned unchanged.
+    ///
+    /// # Example
+    /// ```python
+    /// # Cap speed
+    /// velocity = (velocity + acceleration * dt).clamp_length(max_speed)
+    /// ```
+    fn clamp_length(&self, max: f32) -> PyVec2 {
+        PyVec2 {
+            inner: self.inner.clamp_length(max),
+        }
+    }
+
+    /// Move toward `target` by at most `max_delta`, without overshooting.
+    ///
+    /// Returns `target` itself once it is within `max_delta`.
+    ///
+    /// # Example
+    /// ```python
+    /// def update(dt, engine, data):
+    ///     # Walk to the waypoint at a constant speed
+    ///     data.position = data.position.move_towards(waypoint, speed * dt)
+    /// ```
+    fn move_towards(&self, target: &PyVec2, max_delta: f32) -> PyVec2 {
+        PyVec2 {
+            inner: self.inner.move_towards(&target.inner, max_delta),
+        }
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
@@ -419,6 +666,42 @@ impl PyVec2 {
         format!("Vec2{}", self.inner.to_string())
     }
 
+    fn __eq__(&self, other: &PyVec2) -> bool {
+        self.inner == other.inner
+    }
+
+    fn __hash__(&self, py: Python<'_>) -> PyResult<isize> {
+        hash_components(py, self.inner.data())
+    }
+
+    /// Iterate over `x` then `y`, so `x, y = v` and `tuple(v)` work.
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        iter_components(py, self.inner.data())
+    }
+
+    /// `v[0]` is `x` and `v[1]` (or `v[-1]`) is `y`.
+    fn __getitem__(&self, index: isize) -> PyResult<f32> {
+        component_at(self.inner.data(), index)
+    }
+
+    fn __len__(&self) -> usize {
+        2
+    }
+
+    /// Pickle as `Vec2(x, y)`.
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (f32, f32)) {
+        let inner = slf.borrow().inner;
+        (slf.get_type(), (inner.x(), inner.y()))
+    }
+
+    fn __copy__(&self) -> PyVec2 {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> PyVec2 {
+        self.clone()
+    }
+
     // Vector constants as class attributes
     #[classattr]
     fn ZERO() -> PyVec2 {
@@ -535,6 +818,16 @@ impl PyVec2 {
             ))
         }
     }
+
+    fn __neg__(&self) -> PyVec2 {
+        self.multiply_scalar(-1.0)
+    }
+
+    fn __abs__(&self) -> PyVec2 {
+        PyVec2 {
+            inner: Vec2::from_array(self.inner.data().map(f32::abs)),
+        }
+    }
 }
 
 /// 3D vector for positions, directions, and mathematical operations.
@@ -550,6 +843,13 @@ impl PyVec2 {
 /// - **Subtraction**: `v1 - v2` (component-wise), `v - scalar` (subtract from all)
 /// - **Multiplication**: `v1 * v2` (component-wise), `v * scalar` (scale)
 /// - **Division**: `v1 / v2` (component-wise), `v / scalar` (inverse scale)
+/// - **Negation**: `-v` (reverse direction), `abs(v)` (component-wise absolute value)
+/// - **Augmented assignment**: `v += other`, `v -= other`, `v *= other`, `v /= other`,
+///   which rebind `v` to a new vector as with `Vec2`
+///
+/// Like `Vec2`, vectors are immutable, compare equal when their components
+/// are exactly equal, are hashable, unpack as `x, y, z = v`, and support
+/// `pickle` and `copy`.
 ///
 /// # Constants
 ///
@@ -578,6 +878,8 @@ impl PyVec2 {
 /// # Vector arithmetic
 /// new_pos = pos + velocity
 /// scaled = velocity * 2.0
+/// inverted = -velocity
+/// pos += velocity  # Rebinds pos to a new vector
 /// ```
 ///
 /// ## 3D Movement
@@ -610,7 +912,7 @@ impl PyVec2 {
 /// # See Also
 /// - `Vec2` - 2D vector with x, y components
 /// - `examples/python_game_object_transform_demo.py` - 3D transform examples
-#[pyclass(name = "Vec3")]
+#[pyclass(name = "Vec3", module = "pyg_engine.pyg_engine_native")]
 #[derive(Clone)]
 pub struct PyVec3 {
     inner: Vec3,
@@ -847,6 +1149,65 @@ impl PyVec3 {
         }
     }
 
+    /// Rotate the vector by an angle in radians around an axis.
+    ///
+    /// # Arguments
+    /// * `radians` - Angle to rotate by, counter-clockwise looking down the axis
+    /// * `axis` - Axis to rotate around, any length (default: +z, which turns the
+    ///   x/y components like `Vec2.rotate()`). A zero axis returns the vector unchanged.
+    ///
+    /// # Example
+    /// ```python
+    /// import math
+    /// Vec3(1.0, 0.0, 0.0).rotate(math.pi / 2)  # Vec3(0.0, 1.0, 0.0)
+    /// Vec3(1.0, 0.0, 0.0).rotate(math.pi / 2, Vec3(0.0, 1.0, 0.0))  # Vec3(0.0, 0.0, -1.0)
+    /// ```
+    #[pyo3(signature = (radians, axis=None))]
+    fn rotate(&self, radians: f32, axis: Option<&PyVec3>) -> PyVec3 {
+        let axis = axis.map_or(Vec3::new(0.0, 0.0, 1.0), |axis| axis.inner);
+        PyVec3 {
+            inner: self.inner.rotate(&axis, radians),
+        }
+    }
+
+    /// Get the angle in radians between this vector and `other`.
+    ///
+    /// # Returns
+    /// Unsigned angle from 0 to π, or 0.0 if either vector is zero.
+    ///
+    /// # Example
+    /// ```python
+    /// # Is the target inside a 90° view cone?
+    /// visible = forward.angle_to(target - eye) < math.pi / 4
+    /// ```
+    fn angle_to(&self, other: &PyVec3) -> f32 {
+        self.inner.angle_to(&other.inner)
+    }
+
+    /// Reflect the vector off a surface with the given normal.
+    ///
+    /// The normal is normalized first, so any length works. A zero normal
+    /// returns the vector unchanged.
+    fn reflect(&self, normal: &PyVec3) -> PyVec3 {
+        PyVec3 {
+            inner: self.inner.reflect(&normal.inner),
+        }
+    }
+
+    /// Return the vector shortened to at most `max` length, keeping its direction.
+    fn clamp_length(&self, max: f32) -> PyVec3 {
+        PyVec3 {
+            inner: self.inner.clamp_length(max),
+        }
+    }
+
+    /// Move toward `target` by at most `max_delta`, without overshooting.
+    fn move_towards(&self, target: &PyVec3, max_delta: f32) -> PyVec3 {
+        PyVec3 {
+            inner: self.inner.move_towards(&target.inner, max_delta),
+        }
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
@@ -855,6 +1216,39 @@ impl PyVec3 {
         format!("Vec3{}", self.inner.to_string())
     }
 
+    fn __eq__(&self, other: &PyVec3) -> bool {
+        self.inner == other.inner
+    }
+
+    fn __hash__(&self, py: Python<'_>) -> PyResult<isize> {
+        hash_components(py, self.inner.data())
+    }
+
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        iter_components(py, self.inner.data())
+    }
+
+    fn __getitem__(&self, index: isize) -> PyResult<f32> {
+        component_at(self.inner.data(), index)
+    }
+
+    fn __len__(&self) -> usize {
+        3
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (f32, f32, f32)) {
+        let inner = slf.borrow().inner;
+        (slf.get_type(), (inner.x(), inner.y(), inner.z()))
+    }
+
+    fn __copy__(&self) -> PyVec3 {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> PyVec3 {
+        self.clone()
+    }
+
     // Vector constants as class attributes
     #[classattr]
     fn ZERO() -> PyVec3 {
@@ -993,4 +1387,14 @@ impl PyVec3 {
             ))
         }
     }
+
+    fn __neg__(&self) -> PyVec3 {
+        self.multiply_scalar(-1.0)
+    }
+
+    fn __abs__(&self) -> PyVec3 {
+        PyVec3 {
+            inner: Vec3::from_array(self.inner.data().map(f32::abs)),
+        }
+    }
 }
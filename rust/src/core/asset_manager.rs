@@ -0,0 +1,523 @@
+//! Central loading and ownership of asset files.
+//!
+//! The asset manager reads textures, fonts, audio and data files once and
+//! hands out `AssetHandle`s for them. Loading the same file again returns the
+//! same handle and adds a reference; `release` drops one, and the asset is
+//! unloaded when the last reference goes. Handles carry a generation, so a
+//! handle kept after its asset was unloaded never resolves to whatever reuses
+//! its slot.
+//!
+//! Relative paths resolve against the source root, and assets are keyed by
+//! the resolved path, so `"art/hero.png"` and its absolute form share one
+//! entry. The renderer reads decoded textures and fonts from here instead of
+//! the disk; it only keeps its own GPU copies.
+//!
+//! # Hot Reload
+//!
+//! Every file read is recorded with its modification time. `reload_changed`
+//! re-reads the files that changed since, replacing loaded assets in place so
+//! their handles stay valid, and reports them so the renderer can drop the
+//! GPU copies built from the old contents.
+//!
+//! # Usage
+//!
+//! ```rust,no_run
+//! use pyg_engine_native::core::asset_manager::{AssetKind, AssetManager};
+//!
+//! let mut assets = AssetManager::new();
+//! let handle = assets.load(AssetKind::Data, "levels/1.json").unwrap();
+//! let text = assets.bytes(handle).map(|bytes| String::from_utf8_lossy(bytes).into_owned());
+//! assets.release(handle);
+//! ```
+
+use super::logging;
+use super::text::normalize_font_path;
+use fontdue::Font;
+use image::RgbaImage;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+/// What a file is loaded as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssetKind {
+    /// An image decoded to RGBA pixels
+    Texture,
+    /// A `.ttf`/`.otf` font
+    Font,
+    /// Encoded audio, kept as the file's bytes
+    Audio,
+    /// Any other file, kept as its bytes
+    Data,
+}
+
+impl AssetKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AssetKind::Texture => "texture",
+            AssetKind::Font => "font",
+            AssetKind::Audio => "audio",
+            AssetKind::Data => "data",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "texture" | "image" => Some(AssetKind::Texture),
+            "font" => Some(AssetKind::Font),
+            "audio" | "sound" => Some(AssetKind::Audio),
+            "data" => Some(AssetKind::Data),
+            _ => None,
+        }
+    }
+}
+
+/// A stable reference to a loaded asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AssetHandle {
+    index: u32,
+    generation: u32,
+}
+
+impl AssetHandle {
+    /// Pack the handle into one integer, e.g. for scripting languages.
+    pub fn to_bits(self) -> u64 {
+        (u64::from(self.generation) << 32) | u64::from(self.index)
+    }
+
+    pub fn from_bits(bits: u64) -> Self {
+        Self {
+            index: bits as u32,
+            generation: (bits >> 32) as u32,
+        }
+    }
+}
+
+/// Decoded contents of an asset.
+#[derive(Clone)]
+pub enum AssetData {
+    Texture(Arc<RgbaImage>),
+    Font(Arc<Font>),
+    Audio(Arc<[u8]>),
+    Data(Arc<[u8]>),
+}
+
+struct AssetEntry {
+    kind: AssetKind,
+    path: String,
+    data: AssetData,
+    /// Decoded pixels for textures, file size otherwise
+    size_bytes: usize,
+    ref_count: u32,
+}
+
+struct AssetSlot {
+    generation: u32,
+    entry: Option<AssetEntry>,
+}
+
+/// Loads asset files once and reference-counts their handles.
+#[derive(Default)]
+pub struct AssetManager {
+    source_root: Option<PathBuf>,
+    slots: Vec<AssetSlot>,
+    free_slots: Vec<u32>,
+    by_path: HashMap<(AssetKind, String), AssetHandle>,
+    /// Modification time of each file when it was last read
+    file_times: HashMap<(AssetKind, String), Option<SystemTime>>,
+}
+
+impl AssetManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the directory relative asset paths resolve against; a relative
+    /// root is taken from the working directory.
+    ///
+    /// Loaded assets keep the path they were resolved to.
+    /// @return: Whether the root changed.
+    pub fn set_source_root(&mut self, source_root: Option<PathBuf>) -> bool {
+        let normalized = source_root.map(|path| {
+            if path.is_absolute() {
+                path
+            } else {
+                std::env::current_dir()
+                    .unwrap_or_else(|_| PathBuf::from("."))
+                    .join(path)
+            }
+        });
+        if self.source_root == normalized {
+            return false;
+        }
+        self.source_root = normalized;
+        true
+    }
+
+    pub fn source_root(&self) -> Option<&Path> {
+        self.source_root.as_deref()
+    }
+
+    /// Resolve a path against the source root, with `/` separators.
+    pub fn resolve_path(&self, path: &str) -> String {
+        let input = Path::new(path);
+        if input.is_absolute() {
+            return normalize_font_path(path);
+        }
+        if let Some(source_root) = &self.source_root {
+            return normalize_font_path(&source_root.join(input).to_string_lossy());
+        }
+        normalize_font_path(path)
+    }
+
+    /// Load a file, or add a reference to it if it is already loaded.
+    /// @return: The asset's handle, or an error if the file cannot be read or decoded.
+    pub fn load(&mut self, kind: AssetKind, path: &str) -> Result<AssetHandle, String> {
+        let resolved_path = self.resolve_path(path);
+        if let Some(handle) = self.by_path.get(&(kind, resolved_path.clone())).copied() {
+            self.retain(handle);
+            return Ok(handle);
+        }
+
+        let modified = modified_time(&resolved_path);
+        let (data, size_bytes) = Self::read(kind, &resolved_path)?;
+        self.file_times
+            .insert((kind, resolved_path.clone()), modified);
+        let entry = AssetEntry {
+            kind,
+            path: resolved_path.clone(),
+            data,
+            size_bytes,
+            ref_count: 1,
+        };
+        let handle = match self.free_slots.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index as usize];
+                slot.entry = Some(entry);
+                AssetHandle {
+                    index,
+                    generation: slot.generation,
+                }
+            }
+            None => {
+                self.slots.push(AssetSlot {
+                    generation: 0,
+                    entry: Some(entry),
+                });
+                AssetHandle {
+                    index: (self.slots.len() - 1) as u32,
+                    generation: 0,
+                }
+            }
+        };
+        self.by_path.insert((kind, resolved_path), handle);
+        Ok(handle)
+    }
+
+    /// Add a reference to a loaded asset. Returns false for stale handles.
+    pub fn retain(&mut self, handle: AssetHandle) -> bool {
+        match self.entry_mut(handle) {
+            Some(entry) => {
+                entry.ref_count += 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Drop a reference, unloading the asset when it was the last one.
+    /// @return: The unloaded asset's resolved path, if this released it.
+    pub fn release(&mut self, handle: AssetHandle) -> Option<String> {
+        let entry = self.entry_mut(handle)?;
+        entry.ref_count -= 1;
+        if entry.ref_count > 0 {
+            return None;
+        }
+
+        let slot = &mut self.slots[handle.index as usize];
+        let entry = slot.entry.take()?;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free_slots.push(handle.index);
+        self.by_path.remove(&(entry.kind, entry.path.clone()));
+        // The renderer may still draw an unloaded texture by path.
+        if entry.kind != AssetKind::Texture {
+            self.file_times.remove(&(entry.kind, entry.path.clone()));
+        }
+        Some(entry.path)
+    }
+
+    /// Whether the handle still refers to a loaded asset.
+    pub fn is_loaded(&self, handle: AssetHandle) -> bool {
+        self.entry(handle).is_some()
+    }
+
+    /// Handle of an already loaded file, without adding a reference.
+    pub fn find(&self, kind: AssetKind, path: &str) -> Option<AssetHandle> {
+        self.by_path.get(&(kind, self.resolve_path(path))).copied()
+    }
+
+    pub fn kind(&self, handle: AssetHandle) -> Option<AssetKind> {
+        self.entry(handle).map(|entry| entry.kind)
+    }
+
+    /// Resolved path of the asset's file.
+    pub fn path(&self, handle: AssetHandle) -> Option<&str> {
+        self.entry(handle).map(|entry| entry.path.as_str())
+    }
+
+    pub fn ref_count(&self, handle: AssetHandle) -> u32 {
+        self.entry(handle).map_or(0, |entry| entry.ref_count)
+    }
+
+    pub fn data(&self, handle: AssetHandle) -> Option<&AssetData> {
+        self.entry(handle).map(|entry| &entry.data)
+    }
+
+    pub fn texture(&self, handle: AssetHandle) -> Option<&Arc<RgbaImage>> {
+        match self.data(handle)? {
+            AssetData::Texture(image) => Some(image),
+            _ => None,
+        }
+    }
+
+    pub fn font(&self, handle: AssetHandle) -> Option<&Arc<Font>> {
+        match self.data(handle)? {
+            AssetData::Font(font) => Some(font),
+            _ => None,
+        }
+    }
+
+    /// File bytes of an audio or data asset.
+    pub fn bytes(&self, handle: AssetHandle) -> Option<&[u8]> {
+        match self.data(handle)? {
+            AssetData::Audio(bytes) | AssetData::Data(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// Decoded pixels for a texture path: the loaded asset's if there is one,
+    /// otherwise freshly decoded from the file without keeping them.
+    pub fn texture_pixels(&mut self, path: &str) -> Result<Arc<RgbaImage>, String> {
+        if let Some(image) = self
+            .find(AssetKind::Texture, path)
+            .and_then(|handle| self.texture(handle))
+        {
+            return Ok(Arc::clone(image));
+        }
+        let resolved_path = self.resolve_path(path);
+        let modified = modified_time(&resolved_path);
+        let data = Self::read(AssetKind::Texture, &resolved_path)?.0;
+        self.file_times
+            .insert((AssetKind::Texture, resolved_path), modified);
+        match data {
+            AssetData::Texture(image) => Ok(image),
+            _ => unreachable!("texture reads decode to textures"),
+        }
+    }
+
+    /// Bytes of a sound path: the loaded asset's if there is one, otherwise
+    /// freshly read from the file without keeping them.
+    pub fn audio_bytes(&mut self, path: &str) -> Result<Arc<[u8]>, String> {
+        if let Some(AssetData::Audio(bytes)) = self
+            .find(AssetKind::Audio, path)
+            .and_then(|handle| self.data(handle))
+        {
+            return Ok(Arc::clone(bytes));
+        }
+        let resolved_path = self.resolve_path(path);
+        let modified = modified_time(&resolved_path);
+        let data = Self::read(AssetKind::Audio, &resolved_path)?.0;
+        self.file_times
+            .insert((AssetKind::Audio, resolved_path), modified);
+        match data {
+            AssetData::Audio(bytes) => Ok(bytes),
+            _ => unreachable!("audio reads return audio bytes"),
+        }
+    }
+
+    /// Re-read every file that changed on disk since it was last read.
+    ///
+    /// Loaded assets keep their handles and references. A file that fails to
+    /// load, say while it is still being written, is logged and keeps its old
+    /// contents until it changes again.
+    /// @return: Kind and resolved path of each file read again, including
+    /// textures the renderer decoded without loading them.
+    pub fn reload_changed(&mut self) -> Vec<(AssetKind, String)> {
+        let mut reloaded = Vec::new();
+        for ((kind, path), seen) in &mut self.file_times {
+            let modified = modified_time(path);
+            if modified == *seen {
+                continue;
+            }
+            *seen = modified;
+
+            let Some(handle) = self.by_path.get(&(*kind, path.clone())).copied() else {
+                reloaded.push((*kind, path.clone()));
+                continue;
+            };
+            let entry = self
+                .slots
+                .get_mut(handle.index as usize)
+                .and_then(|slot| slot.entry.as_mut());
+            let Some(entry) = entry else {
+                continue;
+            };
+            match Self::read(*kind, path) {
+                Ok((data, size_bytes)) => {
+                    entry.data = data;
+                    entry.size_bytes = size_bytes;
+                    reloaded.push((*kind, path.clone()));
+                }
+                Err(err) => {
+                    logging::log_warn(&format!("Asset reload failed: {err}. Keeping the old version."));
+                }
+            }
+        }
+        reloaded
+    }
+
+    /// Number of loaded assets.
+    pub fn loaded_count(&self) -> usize {
+        self.by_path.len()
+    }
+
+    /// Approximate memory held by loaded assets, in bytes.
+    pub fn memory_usage(&self) -> usize {
+        self.slots
+            .iter()
+            .filter_map(|slot| slot.entry.as_ref())
+            .map(|entry| entry.size_bytes)
+            .sum()
+    }
+
+    fn entry(&self, handle: AssetHandle) -> Option<&AssetEntry> {
+        let slot = self.slots.get(handle.index as usize)?;
+        if slot.generation != handle.generation {
+            return None;
+        }
+        slot.entry.as_ref()
+    }
+
+    fn entry_mut(&mut self, handle: AssetHandle) -> Option<&mut AssetEntry> {
+        let slot = self.slots.get_mut(handle.index as usize)?;
+        if slot.generation != handle.generation {
+            return None;
+        }
+        slot.entry.as_mut()
+    }
+
+    /// Read and decode a file. Returns the contents and their size in bytes.
+    fn read(kind: AssetKind, resolved_path: &str) -> Result<(AssetData, usize), String> {
+        let bytes = std::fs::read(resolved_path)
+            .map_err(|e| format!("failed to read {} '{resolved_path}': {e}", kind.as_str()))?;
+        let file_size = bytes.len();
+        match kind {
+            AssetKind::Texture => {
+                let image = image::load_from_memory(&bytes)
+                    .map_err(|e| format!("failed to decode texture '{resolved_path}': {e}"))?
+                    .to_rgba8();
+                let size = image.as_raw().len();
+                Ok((AssetData::Texture(Arc::new(image)), size))
+            }
+            AssetKind::Font => {
+                let font = Font::from_bytes(bytes, fontdue::FontSettings::default())
+                    .map_err(|e| format!("failed to decode font '{resolved_path}': {e}"))?;
+                Ok((AssetData::Font(Arc::new(font)), file_size))
+            }
+            AssetKind::Audio => Ok((AssetData::Audio(bytes.into()), file_size)),
+            AssetKind::Data => Ok((AssetData::Data(bytes.into()), file_size)),
+        }
+    }
+}
+
+fn modified_time(path: &str) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_temp(name: &str, bytes: &[u8]) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("pyg_assets_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join(name);
+        std::fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_handles_are_shared_and_reference_counted() {
+        let path = write_temp("level.json", b"{\"level\": 1}");
+        let mut assets = AssetManager::new();
+        assets.set_source_root(path.parent().map(Path::to_path_buf));
+
+        let first = assets.load(AssetKind::Data, "level.json").unwrap();
+        let second = assets.load(AssetKind::Data, path.to_str().unwrap()).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(assets.ref_count(first), 2);
+        assert_eq!(assets.bytes(first), Some(&b"{\"level\": 1}"[..]));
+        assert_eq!(assets.loaded_count(), 1);
+
+        assert_eq!(assets.release(first), None);
+        assert_eq!(assets.release(first), Some(assets.resolve_path("level.json")));
+        assert!(!assets.is_loaded(first));
+        assert_eq!(assets.release(first), None);
+
+        // The freed slot is reused, but the stale handle stays dead.
+        let reloaded = assets.load(AssetKind::Audio, "level.json").unwrap();
+        assert_ne!(reloaded, first);
+        assert!(assets.bytes(first).is_none());
+        assert_eq!(AssetHandle::from_bits(reloaded.to_bits()), reloaded);
+    }
+
+    #[test]
+    fn test_failed_loads_leave_no_entry() {
+        let path = write_temp("broken.png", b"not an image");
+        let mut assets = AssetManager::new();
+        let error = assets
+            .load(AssetKind::Texture, path.to_str().unwrap())
+            .unwrap_err();
+        assert!(error.contains("failed to decode texture"));
+        assert!(assets.load(AssetKind::Font, "missing.ttf").is_err());
+        assert_eq!(assets.loaded_count(), 0);
+    }
+
+    #[test]
+    fn test_changed_files_reload_in_place() {
+        let path = write_temp("dialogue.txt", b"hello");
+        let path_str = path.to_str().unwrap();
+        let mut assets = AssetManager::new();
+        let handle = assets.load(AssetKind::Data, path_str).unwrap();
+        assert!(assets.reload_changed().is_empty());
+
+        let touch = |bytes: &[u8], seconds: u64| {
+            std::fs::write(&path, bytes).unwrap();
+            let file = std::fs::File::options().write(true).open(&path).unwrap();
+            file.set_modified(SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(seconds))
+                .unwrap();
+        };
+        touch(b"goodbye", 1_000);
+        let reloaded = assets.reload_changed();
+        assert_eq!(reloaded, vec![(AssetKind::Data, assets.resolve_path(path_str))]);
+        assert_eq!(assets.bytes(handle), Some(&b"goodbye"[..]));
+        assert_eq!(assets.ref_count(handle), 1);
+        assert!(assets.reload_changed().is_empty());
+
+        // A texture that no longer decodes keeps its old pixels.
+        let image_path = write_temp("sprite.png", &[]);
+        let image_str = image_path.to_str().unwrap();
+        let mut buffer = std::io::Cursor::new(Vec::new());
+        RgbaImage::new(2, 1)
+            .write_to(&mut buffer, image::ImageFormat::Png)
+            .unwrap();
+        std::fs::write(&image_path, buffer.get_ref()).unwrap();
+        let sprite = assets.load(AssetKind::Texture, image_str).unwrap();
+        std::fs::write(&image_path, b"half written").unwrap();
+        let file = std::fs::File::options().write(true).open(&image_path).unwrap();
+        file.set_modified(SystemTime::UNIX_EPOCH).unwrap();
+        assert!(assets.reload_changed().is_empty());
+        assert_eq!(assets.texture(sprite).map(|image| image.dimensions()), Some((2, 1)));
+    }
+}
@@ -0,0 +1,102 @@
+//! Mixer buses: groups of sounds with a shared volume, mute and effects.
+//!
+//! Every voice plays through one of the `music`, `sfx` or `ui` buses (or
+//! straight into `master`). Each bus applies its low-pass filter and volume,
+//! sends part of its output to the shared reverb, and feeds the master bus,
+//! whose settings then apply to everything.
+
+/// A mixer bus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Bus {
+    /// Everything passes through master last
+    Master,
+    Music,
+    /// Sound effects, the default for new sounds
+    #[default]
+    Sfx,
+    Ui,
+}
+
+impl Bus {
+    /// Every bus, master first.
+    pub const ALL: [Bus; 4] = [Bus::Master, Bus::Music, Bus::Sfx, Bus::Ui];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Bus::Master => "master",
+            Bus::Music => "music",
+            Bus::Sfx => "sfx",
+            Bus::Ui => "ui",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "master" => Some(Bus::Master),
+            "music" => Some(Bus::Music),
+            "sfx" => Some(Bus::Sfx),
+            "ui" => Some(Bus::Ui),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Volume, mute and effect settings of a bus.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BusSettings {
+    /// Linear gain, 1.0 leaves the bus unchanged
+    pub volume: f32,
+    /// Silences the bus without losing its volume
+    pub muted: bool,
+    /// Low-pass cutoff in Hz, or None for no filtering
+    pub low_pass: Option<f32>,
+    /// Share of the bus output sent to the reverb, from 0 to 1. Ignored on master.
+    pub reverb_send: f32,
+}
+
+impl Default for BusSettings {
+    fn default() -> Self {
+        Self {
+            volume: 1.0,
+            muted: false,
+            low_pass: None,
+            reverb_send: 0.0,
+        }
+    }
+}
+
+impl BusSettings {
+    /// Gain the bus applies, zero when muted.
+    pub fn gain(&self) -> f32 {
+        if self.muted { 0.0 } else { self.volume }
+    }
+
+    /// Clamp every setting to its valid range.
+    pub fn sanitized(self) -> Self {
+        Self {
+            volume: self.volume.max(0.0),
+            muted: self.muted,
+            low_pass: self.low_pass.filter(|cutoff| *cutoff > 0.0),
+            reverb_send: self.reverb_send.clamp(0.0, 1.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bus_names_round_trip() {
+        for bus in Bus::ALL {
+            assert_eq!(Bus::from_name(bus.name()), Some(bus));
+        }
+        assert_eq!(Bus::from_name("SFX"), Some(Bus::Sfx));
+        assert_eq!(Bus::from_name("voice"), None);
+        assert_eq!(Bus::default(), Bus::Sfx);
+    }
+}
@@ -0,0 +1,181 @@
+//! Bus effects: a one-pole low-pass filter and a small stereo reverb.
+
+use std::f32::consts::TAU;
+
+/// One-pole low-pass filter over interleaved stereo frames.
+#[derive(Debug, Clone, Default)]
+pub struct LowPass {
+    state: [f32; 2],
+}
+
+impl LowPass {
+    /// Filter `frames` in place. Without a cutoff the signal passes through
+    /// and the filter resets, so turning it back on doesn't replay old state.
+    pub fn process(&mut self, frames: &mut [f32], cutoff: Option<f32>, sample_rate: u32) {
+        let Some(cutoff) = cutoff else {
+            self.state = [0.0; 2];
+            return;
+        };
+        let coefficient = 1.0 - (-TAU * cutoff / sample_rate.max(1) as f32).exp();
+        for frame in frames.chunks_exact_mut(2) {
+            for (sample, state) in frame.iter_mut().zip(&mut self.state) {
+                *state += coefficient * (*sample - *state);
+                *sample = *state;
+            }
+        }
+    }
+}
+
+/// Comb filter delays in samples at 44.1 kHz, from Freeverb.
+const COMB_DELAYS: [usize; 4] = [1116, 1188, 1277, 1356];
+/// Allpass delays in samples at 44.1 kHz, from Freeverb.
+const ALLPASS_DELAYS: [usize; 2] = [556, 441];
+/// Extra delay of the right channel, which decorrelates the two sides.
+const STEREO_SPREAD: usize = 23;
+const FEEDBACK: f32 = 0.84;
+const DAMPING: f32 = 0.2;
+const ALLPASS_FEEDBACK: f32 = 0.5;
+/// Keeps the wet signal near the level of the dry one.
+const WET_GAIN: f32 = 0.3;
+
+#[derive(Debug, Clone)]
+struct Comb {
+    buffer: Vec<f32>,
+    index: usize,
+    damped: f32,
+}
+
+impl Comb {
+    fn new(length: usize) -> Self {
+        Self {
+            buffer: vec![0.0; length.max(1)],
+            index: 0,
+            damped: 0.0,
+        }
+    }
+
+    fn process(&mut self, input: f32) -> f32 {
+        let output = self.buffer[self.index];
+        self.damped = output * (1.0 - DAMPING) + self.damped * DAMPING;
+        self.buffer[self.index] = input + self.damped * FEEDBACK;
+        self.index = (self.index + 1) % self.buffer.len();
+        output
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Allpass {
+    buffer: Vec<f32>,
+    index: usize,
+}
+
+impl Allpass {
+    fn new(length: usize) -> Self {
+        Self {
+            buffer: vec![0.0; length.max(1)],
+            index: 0,
+        }
+    }
+
+    fn process(&mut self, input: f32) -> f32 {
+        let delayed = self.buffer[self.index];
+        self.buffer[self.index] = input + delayed * ALLPASS_FEEDBACK;
+        self.index = (self.index + 1) % self.buffer.len();
+        delayed - input
+    }
+}
+
+/// Stereo reverb fed by the buses' reverb sends, after Freeverb: parallel
+/// damped comb filters into series allpass filters on each side.
+#[derive(Debug, Clone)]
+pub struct Reverb {
+    sample_rate: u32,
+    combs: [Vec<Comb>; 2],
+    allpasses: [Vec<Allpass>; 2],
+}
+
+impl Reverb {
+    pub fn new(sample_rate: u32) -> Self {
+        let scale = sample_rate.max(1) as f32 / 44_100.0;
+        let delay = |samples: usize, spread: usize| ((samples + spread) as f32 * scale) as usize;
+        let side = |spread: usize| {
+            (
+                COMB_DELAYS
+                    .iter()
+                    .map(|&samples| Comb::new(delay(samples, spread)))
+                    .collect(),
+                ALLPASS_DELAYS
+                    .iter()
+                    .map(|&samples| Allpass::new(delay(samples, spread)))
+                    .collect(),
+            )
+        };
+        let (left_combs, left_allpasses) = side(0);
+        let (right_combs, right_allpasses) = side(STEREO_SPREAD);
+        Self {
+            sample_rate,
+            combs: [left_combs, right_combs],
+            allpasses: [left_allpasses, right_allpasses],
+        }
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Add the reverb of the interleaved stereo `input` to `output`.
+    pub fn process(&mut self, input: &[f32], output: &mut [f32]) {
+        for (input, output) in input.chunks_exact(2).zip(output.chunks_exact_mut(2)) {
+            let mono = (input[0] + input[1]) * 0.5;
+            let sides = self.combs.iter_mut().zip(&mut self.allpasses);
+            for (sample, (combs, allpasses)) in output.iter_mut().zip(sides) {
+                let mut wet: f32 = combs.iter_mut().map(|comb| comb.process(mono)).sum();
+                for allpass in allpasses {
+                    wet = allpass.process(wet);
+                }
+                *sample += wet * WET_GAIN;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_low_pass_smooths_fast_changes() {
+        let mut filter = LowPass::default();
+        let mut frames = [1.0, -1.0, -1.0, 1.0, 1.0, -1.0, -1.0, 1.0];
+        filter.process(&mut frames, Some(100.0), 48_000);
+        assert!(frames.iter().all(|sample| sample.abs() < 0.05));
+
+        let mut frames = [1.0, -1.0];
+        filter.process(&mut frames, None, 48_000);
+        assert_eq!(frames, [1.0, -1.0]);
+    }
+
+    #[test]
+    fn test_reverb_rings_after_an_impulse() {
+        let mut reverb = Reverb::new(44_100);
+        let mut input = vec![0.0; 2 * 4000];
+        input[0] = 1.0;
+        input[1] = 1.0;
+        let mut output = vec![0.0; input.len()];
+        reverb.process(&input, &mut output);
+        // Silent until the shortest comb delay, then a tail on both sides
+        assert!(output[..2 * 1000].iter().all(|sample| *sample == 0.0));
+        assert!(
+            output[2 * 3000..]
+                .iter()
+                .step_by(2)
+                .any(|sample| *sample != 0.0)
+        );
+        assert!(
+            output[2 * 3000 + 1..]
+                .iter()
+                .step_by(2)
+                .any(|sample| *sample != 0.0)
+        );
+    }
+}
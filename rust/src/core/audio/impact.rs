@@ -0,0 +1,254 @@
+//! Impact sounds: sounds played when two colliders start touching, picked by
+//! the pair of materials or physics layers involved.
+//!
+//! Each collision enter event looks up its pair, materials first, then
+//! layers. The sound is louder the deeper the colliders overlap on the step
+//! they meet, which stands in for how hard they hit since there is no
+//! physics simulation to report speeds. Triggers never make impact sounds.
+
+use super::bus::Bus;
+use super::spatial::Attenuation;
+use crate::core::object_manager::ObjectManager;
+use crate::core::physics::{ColliderComponent, CollisionEvent, CollisionEventType};
+use crate::types::vector::Vec2;
+use std::collections::HashMap;
+
+/// The pair of colliders an impact sound is for. The order of the two
+/// sides doesn't matter.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImpactKey {
+    Layers(u32, u32),
+    Materials(String, String),
+}
+
+impl ImpactKey {
+    pub fn layers(a: u32, b: u32) -> Self {
+        ImpactKey::Layers(a.min(b), a.max(b))
+    }
+
+    pub fn materials(a: impl Into<String>, b: impl Into<String>) -> Self {
+        let (a, b) = (a.into(), b.into());
+        if a <= b {
+            ImpactKey::Materials(a, b)
+        } else {
+            ImpactKey::Materials(b, a)
+        }
+    }
+}
+
+/// A sound to play for an impact, and how loud.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImpactSound {
+    /// Sound path or name from `AudioSystem::add_sound`
+    pub sound: String,
+    /// Linear gain of the hardest hits
+    pub volume: f32,
+    /// Penetration depth at which the sound reaches `volume`; shallower
+    /// hits are quieter in proportion
+    pub full_volume_depth: f32,
+    /// Hits shallower than this make no sound
+    pub min_depth: f32,
+    /// Range a random pitch is picked from for each hit, or None for 1.0
+    pub pitch_range: Option<(f32, f32)>,
+    pub bus: Bus,
+    pub priority: i32,
+    /// Heard from the contact point with this falloff, or centered without one
+    pub attenuation: Option<Attenuation>,
+}
+
+impl ImpactSound {
+    pub fn new(sound: impl Into<String>) -> Self {
+        Self {
+            sound: sound.into(),
+            volume: 1.0,
+            full_volume_depth: 1.0,
+            min_depth: 0.0,
+            pitch_range: None,
+            bus: Bus::default(),
+            priority: 0,
+            attenuation: Some(Attenuation::default()),
+        }
+    }
+
+    /// Volume of a hit `depth` deep, or None if it is too shallow to hear.
+    pub fn volume_at(&self, depth: f32) -> Option<f32> {
+        if depth < self.min_depth {
+            return None;
+        }
+        let scale = if self.full_volume_depth > 0.0 {
+            (depth / self.full_volume_depth).min(1.0)
+        } else {
+            1.0
+        };
+        Some(self.volume * scale)
+    }
+}
+
+/// An impact sound due to play.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Impact {
+    pub sound: ImpactSound,
+    /// Where the colliders touch
+    pub position: Vec2,
+    /// Volume scaled by the hit's depth
+    pub volume: f32,
+}
+
+/// Impact sounds by the pair of colliders they are for.
+#[derive(Debug, Clone, Default)]
+pub struct ImpactSounds {
+    sounds: HashMap<ImpactKey, ImpactSound>,
+}
+
+impl ImpactSounds {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Play `sound` for impacts between this pair, replacing the previous one.
+    pub fn set(&mut self, key: ImpactKey, sound: ImpactSound) {
+        self.sounds.insert(key, sound);
+    }
+
+    pub fn get(&self, key: &ImpactKey) -> Option<&ImpactSound> {
+        self.sounds.get(key)
+    }
+
+    pub fn remove(&mut self, key: &ImpactKey) -> bool {
+        self.sounds.remove(key).is_some()
+    }
+
+    pub fn clear(&mut self) {
+        self.sounds.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sounds.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.sounds.len()
+    }
+
+    /// The sound for two colliders: their materials' if both have one and
+    /// it is set, their layers' otherwise.
+    pub fn sound_for(&self, a: &ColliderComponent, b: &ColliderComponent) -> Option<&ImpactSound> {
+        let by_materials = match (a.material(), b.material()) {
+            (Some(a), Some(b)) => self.sounds.get(&ImpactKey::materials(a, b)),
+            _ => None,
+        };
+        by_materials.or_else(|| self.sounds.get(&ImpactKey::layers(a.layer(), b.layer())))
+    }
+
+    /// The impact sounds due for the enter events among `events`.
+    pub fn impacts(&self, events: &[CollisionEvent], objects: &ObjectManager) -> Vec<Impact> {
+        if self.sounds.is_empty() {
+            return Vec::new();
+        }
+        let collider = |id| {
+            objects
+                .get_object_by_id(id)?
+                .get_component::<ColliderComponent>()
+        };
+        events
+            .iter()
+            .filter(|event| event.event_type == CollisionEventType::Enter)
+            .filter_map(|event| {
+                let manifold = event.manifold.as_ref()?;
+                let (a, b) = (collider(event.object_id_a)?, collider(event.object_id_b)?);
+                if a.is_trigger() || b.is_trigger() {
+                    return None;
+                }
+                let sound = self.sound_for(a, b)?;
+                let volume = sound.volume_at(manifold.penetration_depth)?;
+                Some(Impact {
+                    sound: sound.clone(),
+                    position: contact_position(event, objects)?,
+                    volume,
+                })
+            })
+            .collect()
+    }
+}
+
+/// The middle of the contact points, or between the two objects without any.
+fn contact_position(event: &CollisionEvent, objects: &ObjectManager) -> Option<Vec2> {
+    let points = event
+        .manifold
+        .as_ref()
+        .map(|manifold| manifold.contact_points.as_slice())
+        .unwrap_or_default();
+    if !points.is_empty() {
+        let sum = points
+            .iter()
+            .fold(Vec2::new(0.0, 0.0), |sum, point| sum.add(point));
+        return Some(sum.multiply_scalar(1.0 / points.len() as f32));
+    }
+    let a = objects.world_position(event.object_id_a)?;
+    let b = objects.world_position(event.object_id_b)?;
+    Some(a.add(&b).multiply_scalar(0.5))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game_object::GameObject;
+    use crate::core::physics::{ColliderShape, CollisionWorld};
+
+    fn add_ball(objects: &mut ObjectManager, x: f32, layer: u32, material: Option<&str>) -> u32 {
+        let mut object = GameObject::new_named("Ball".to_string());
+        object.set_position(Vec2::new(x, 0.0));
+        object.add_component(Box::new(
+            ColliderComponent::new("Collider")
+                .with_shape(ColliderShape::circle(1.0))
+                .with_layer(layer)
+                .with_material(material.map(str::to_string)),
+        ));
+        objects.add_object(object).unwrap()
+    }
+
+    #[test]
+    fn test_impacts_pick_materials_before_layers_and_scale_with_depth() {
+        let mut objects = ObjectManager::new();
+        add_ball(&mut objects, 0.0, 1, Some("metal"));
+        add_ball(&mut objects, 1.5, 2, Some("wood"));
+        add_ball(&mut objects, 10.0, 1, None);
+        add_ball(&mut objects, 11.0, 2, None);
+        let mut world = CollisionWorld::new();
+        world.step(&objects);
+
+        let mut sounds = ImpactSounds::new();
+        sounds.set(ImpactKey::layers(2, 1), ImpactSound::new("thud.wav"));
+        sounds.set(
+            ImpactKey::materials("wood", "metal"),
+            ImpactSound {
+                full_volume_depth: 2.0,
+                ..ImpactSound::new("clang.wav")
+            },
+        );
+        let mut impacts = sounds.impacts(world.collision_events(), &objects);
+        impacts.sort_by(|a, b| a.position.x().total_cmp(&b.position.x()));
+        assert_eq!(impacts.len(), 2);
+        assert_eq!(impacts[0].sound.sound, "clang.wav");
+        // The balls overlap by half a unit, a quarter of the way to full volume
+        assert!((impacts[0].volume - 0.25).abs() < 1e-5);
+        assert_eq!(impacts[1].sound.sound, "thud.wav");
+        assert!((impacts[1].volume - 1.0).abs() < 1e-5);
+
+        // Only the step they meet on makes a sound
+        world.step(&objects);
+        assert!(
+            sounds
+                .impacts(world.collision_events(), &objects)
+                .is_empty()
+        );
+
+        assert!(sounds.remove(&ImpactKey::materials("metal", "wood")));
+        let quiet = ImpactSound {
+            min_depth: 0.6,
+            ..ImpactSound::new("tap.wav")
+        };
+        assert_eq!(quiet.volume_at(0.5), None);
+        assert_eq!(quiet.volume_at(0.75), Some(0.75));
+    }
+}
@@ -0,0 +1,780 @@
+//! Software mixer shared by the engine and the audio output thread.
+//!
+//! The engine starts, stops and adjusts voices; the output callback calls
+//! [`Mixer::mix`] for each buffer the device asks for. Sounds are resampled
+//! to the output rate with linear interpolation, and volume and pan changes
+//! ramp across one buffer so moving sources don't click. Each voice plays
+//! through a [`Bus`], see the `bus` module.
+//!
+//! A voice reads either a decoded [`Sound`] or a stream: samples the engine
+//! queues as they are generated, played as they arrive and silent while the
+//! queue is empty.
+//!
+//! At most [`Mixer::max_voices`] voices play at once. A new voice past the
+//! limit takes the place of the oldest voice with the lowest priority, as long
+//! as that priority is not above its own; otherwise the new voice is dropped.
+//! Either way the voice that loses is reported as finished.
+
+use super::bus::{Bus, BusSettings};
+use super::effects::{LowPass, Reverb};
+use super::sound::Sound;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/// Identifies a playing sound. Ids are never reused.
+pub type VoiceId = u64;
+
+/// Output sample rate until a device reports its own.
+pub const DEFAULT_SAMPLE_RATE: u32 = 48_000;
+
+/// Voices that play at once until [`Mixer::set_max_voices`] changes it.
+pub const DEFAULT_MAX_VOICES: usize = 64;
+
+/// Finished voices kept for [`Mixer::take_finished`]; older ones are dropped.
+const MAX_FINISHED: usize = 1024;
+
+/// How a voice is played.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VoiceParams {
+    /// Linear gain, 1.0 plays the sound as recorded
+    pub volume: f32,
+    /// Stereo balance from -1 (left) to 1 (right)
+    pub pan: f32,
+    /// Start over at the end instead of stopping
+    pub looping: bool,
+    /// Playback rate; 2.0 plays twice as fast and an octave higher
+    pub pitch: f32,
+    /// Bus the voice plays through
+    pub bus: Bus,
+    /// Past the voice limit, lower priorities are cut off first
+    pub priority: i32,
+}
+
+impl Default for VoiceParams {
+    fn default() -> Self {
+        Self {
+            volume: 1.0,
+            pan: 0.0,
+            looping: false,
+            pitch: 1.0,
+            bus: Bus::default(),
+            priority: 0,
+        }
+    }
+}
+
+/// Samples queued for a stream voice.
+struct StreamQueue {
+    sample_rate: u32,
+    channels: usize,
+    /// Interleaved samples not played yet
+    samples: VecDeque<f32>,
+    /// No more samples will be queued; the voice ends once these play
+    ended: bool,
+}
+
+impl StreamQueue {
+    fn frame_count(&self) -> usize {
+        self.samples.len() / self.channels
+    }
+
+    fn stereo_frame(&self, frame: usize) -> Option<(f32, f32)> {
+        let start = frame * self.channels;
+        if start + self.channels > self.samples.len() {
+            return None;
+        }
+        let left = self.samples[start];
+        let right = if self.channels > 1 {
+            self.samples[start + 1]
+        } else {
+            left
+        };
+        Some((left, right))
+    }
+}
+
+/// What a voice plays.
+enum Source {
+    Sound(Arc<Sound>),
+    Stream(StreamQueue),
+}
+
+struct Voice {
+    id: VoiceId,
+    source: Source,
+    /// Read position in source frames; for streams, from the front of the queue
+    position: f64,
+    params: VoiceParams,
+    /// Left and right gains applied at the end of the last buffer
+    gains: [f32; 2],
+}
+
+impl Voice {
+    fn is_finished(&self) -> bool {
+        match &self.source {
+            Source::Sound(sound) => {
+                let frame_count = sound.frame_count();
+                frame_count == 0 || (!self.params.looping && self.position >= frame_count as f64)
+            }
+            Source::Stream(stream) => stream.ended && stream.frame_count() == 0,
+        }
+    }
+
+    /// Source frames played per output frame at `output_rate`.
+    fn step(&self, output_rate: u32) -> f64 {
+        let sample_rate = match &self.source {
+            Source::Sound(sound) => sound.sample_rate(),
+            Source::Stream(stream) => stream.sample_rate,
+        };
+        sample_rate as f64 / output_rate as f64 * self.params.pitch.max(0.0) as f64
+    }
+
+    /// The frame at the read position, interpolated between its neighbours,
+    /// or None while a stream waits for samples.
+    fn current_frame(&self) -> Option<(f32, f32)> {
+        let current = self.position.floor() as usize;
+        let fraction = (self.position - current as f64) as f32;
+        let ((left_a, right_a), (left_b, right_b)) = match &self.source {
+            Source::Sound(sound) => {
+                let next = if current + 1 < sound.frame_count() {
+                    current + 1
+                } else if self.params.looping {
+                    0
+                } else {
+                    current
+                };
+                (sound.stereo_frame(current), sound.stereo_frame(next))
+            }
+            Source::Stream(stream) => {
+                let frame = stream.stereo_frame(current)?;
+                (frame, stream.stereo_frame(current + 1).unwrap_or(frame))
+            }
+        };
+        Some((
+            left_a + (left_b - left_a) * fraction,
+            right_a + (right_b - right_a) * fraction,
+        ))
+    }
+
+    /// Move the read position by `frames` source frames.
+    fn advance(&mut self, frames: f64) {
+        self.position += frames;
+        match &mut self.source {
+            Source::Sound(sound) => {
+                let frame_count = sound.frame_count() as f64;
+                if self.params.looping && frame_count > 0.0 && self.position >= frame_count {
+                    self.position %= frame_count;
+                }
+            }
+            Source::Stream(stream) => {
+                // Played frames leave the queue; a starved stream waits at its start.
+                let played = (self.position.floor() as usize).min(stream.frame_count());
+                stream.samples.drain(..played * stream.channels);
+                self.position = if stream.frame_count() == 0 {
+                    0.0
+                } else {
+                    self.position - played as f64
+                };
+            }
+        }
+    }
+}
+
+/// Left and right gains for a volume and pan. The balance law keeps a
+/// centered sound at full volume on both sides and fades out the far side as
+/// it pans.
+pub fn pan_gains(volume: f32, pan: f32) -> [f32; 2] {
+    let pan = pan.clamp(-1.0, 1.0);
+    [volume * (1.0 - pan).min(1.0), volume * (1.0 + pan).min(1.0)]
+}
+
+struct BusState {
+    settings: BusSettings,
+    /// Gain applied at the end of the last buffer
+    gain: f32,
+    low_pass: LowPass,
+    /// Interleaved stereo mix of the bus's voices for the current buffer
+    buffer: Vec<f32>,
+}
+
+impl BusState {
+    fn new() -> Self {
+        Self {
+            settings: BusSettings::default(),
+            gain: 1.0,
+            low_pass: LowPass::default(),
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Filter the buffer and ramp it from the last gain to the current one.
+    fn process(&mut self, sample_rate: u32) {
+        self.low_pass
+            .process(&mut self.buffer, self.settings.low_pass, sample_rate);
+        let target = self.settings.gain();
+        let frames = self.buffer.len() / 2;
+        for (index, frame) in self.buffer.chunks_exact_mut(2).enumerate() {
+            let gain = self.gain + (target - self.gain) * (index + 1) as f32 / frames as f32;
+            frame[0] *= gain;
+            frame[1] *= gain;
+        }
+        self.gain = target;
+    }
+}
+
+/// Mixes the playing voices into interleaved output buffers.
+pub struct Mixer {
+    voices: Vec<Voice>,
+    next_id: VoiceId,
+    sample_rate: u32,
+    /// Indexed by `Bus::index`
+    buses: [BusState; 4],
+    reverb: Reverb,
+    /// Sum of the buses' reverb sends for the current buffer
+    reverb_input: Vec<f32>,
+    max_voices: usize,
+    /// Voices that played to the end or lost their place, oldest first
+    finished: Vec<VoiceId>,
+}
+
+impl Default for Mixer {
+    fn default() -> Self {
+        Self::new(DEFAULT_SAMPLE_RATE)
+    }
+}
+
+impl Mixer {
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            voices: Vec::new(),
+            next_id: 1,
+            sample_rate: sample_rate.max(1),
+            buses: std::array::from_fn(|_| BusState::new()),
+            reverb: Reverb::new(sample_rate.max(1)),
+            reverb_input: Vec::new(),
+            max_voices: DEFAULT_MAX_VOICES,
+            finished: Vec::new(),
+        }
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Set the output rate, e.g. once the device is open.
+    pub fn set_sample_rate(&mut self, sample_rate: u32) {
+        self.sample_rate = sample_rate.max(1);
+        if self.reverb.sample_rate() != self.sample_rate {
+            self.reverb = Reverb::new(self.sample_rate);
+        }
+    }
+
+    /// Most voices that play at once.
+    pub fn max_voices(&self) -> usize {
+        self.max_voices
+    }
+
+    /// Change the voice limit, at least 1. Voices past a lower limit are
+    /// cut off right away, lowest priority and oldest first.
+    pub fn set_max_voices(&mut self, max_voices: usize) {
+        self.max_voices = max_voices.max(1);
+        while self.voices.len() > self.max_voices {
+            if let Some(index) = self.steal_candidate() {
+                let voice = self.voices.remove(index);
+                self.push_finished(voice.id);
+            }
+        }
+    }
+
+    /// Volume of the master bus.
+    pub fn master_volume(&self) -> f32 {
+        self.buses[Bus::Master.index()].settings.volume
+    }
+
+    pub fn set_master_volume(&mut self, volume: f32) {
+        self.buses[Bus::Master.index()].settings.volume = volume.max(0.0);
+    }
+
+    pub fn bus_settings(&self, bus: Bus) -> BusSettings {
+        self.buses[bus.index()].settings
+    }
+
+    /// Change a bus's settings; volume and mute changes ramp over the next buffer.
+    pub fn set_bus_settings(&mut self, bus: Bus, settings: BusSettings) {
+        self.buses[bus.index()].settings = settings.sanitized();
+    }
+
+    /// Start playing a sound from the beginning.
+    pub fn play(&mut self, sound: Arc<Sound>, params: VoiceParams) -> VoiceId {
+        self.add_voice(Source::Sound(sound), params)
+    }
+
+    /// Start a stream voice, silent until samples are queued with
+    /// [`Mixer::queue_samples`]. `params.looping` is ignored.
+    pub fn play_stream(&mut self, sample_rate: u32, channels: u16, params: VoiceParams) -> VoiceId {
+        let stream = StreamQueue {
+            sample_rate: sample_rate.max(1),
+            channels: channels.max(1) as usize,
+            samples: VecDeque::new(),
+            ended: false,
+        };
+        self.add_voice(
+            Source::Stream(stream),
+            VoiceParams {
+                looping: false,
+                ..params
+            },
+        )
+    }
+
+    /// Append interleaved samples to a stream. Returns false if the voice
+    /// finished, was ended or is not a stream.
+    pub fn queue_samples(&mut self, id: VoiceId, samples: &[f32]) -> bool {
+        match self.stream_mut(id) {
+            Some(stream) if !stream.ended => {
+                stream.samples.extend(samples);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Let a stream finish once its queued samples play.
+    pub fn end_stream(&mut self, id: VoiceId) -> bool {
+        match self.stream_mut(id) {
+            Some(stream) => {
+                stream.ended = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Frames queued on a stream and not played yet.
+    pub fn queued_frames(&self, id: VoiceId) -> Option<usize> {
+        match &self.voice(id)?.source {
+            Source::Stream(stream) => Some(stream.frame_count()),
+            Source::Sound(_) => None,
+        }
+    }
+
+    fn stream_mut(&mut self, id: VoiceId) -> Option<&mut StreamQueue> {
+        match &mut self.voices.iter_mut().find(|voice| voice.id == id)?.source {
+            Source::Stream(stream) => Some(stream),
+            Source::Sound(_) => None,
+        }
+    }
+
+    fn add_voice(&mut self, source: Source, params: VoiceParams) -> VoiceId {
+        let id = self.next_id;
+        self.next_id += 1;
+        if self.voices.len() >= self.max_voices {
+            match self.steal_candidate() {
+                Some(index) if self.voices[index].params.priority <= params.priority => {
+                    let stolen = self.voices.remove(index);
+                    self.push_finished(stolen.id);
+                }
+                _ => {
+                    self.push_finished(id);
+                    return id;
+                }
+            }
+        }
+        self.voices.push(Voice {
+            id,
+            source,
+            position: 0.0,
+            params,
+            // New voices start at their gains; only later changes ramp.
+            gains: pan_gains(params.volume, params.pan),
+        });
+        id
+    }
+
+    /// Index of the voice to cut off for a new one: the lowest priority,
+    /// and the oldest of those.
+    fn steal_candidate(&self) -> Option<usize> {
+        self.voices
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, voice)| (voice.params.priority, voice.id))
+            .map(|(index, _)| index)
+    }
+
+    /// Stop a voice. Returns false if it already finished.
+    pub fn stop(&mut self, id: VoiceId) -> bool {
+        let count = self.voices.len();
+        self.voices.retain(|voice| voice.id != id);
+        self.voices.len() != count
+    }
+
+    pub fn stop_all(&mut self) {
+        self.voices.clear();
+    }
+
+    pub fn is_playing(&self, id: VoiceId) -> bool {
+        self.voices.iter().any(|voice| voice.id == id)
+    }
+
+    pub fn voice_count(&self) -> usize {
+        self.voices.len()
+    }
+
+    pub fn params(&self, id: VoiceId) -> Option<VoiceParams> {
+        self.voice(id).map(|voice| voice.params)
+    }
+
+    /// Change a playing voice's volume and pan. Returns false if it finished.
+    pub fn set_params(&mut self, id: VoiceId, params: VoiceParams) -> bool {
+        match self.voices.iter_mut().find(|voice| voice.id == id) {
+            Some(voice) => {
+                voice.params = params;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Voices that played to the end or were cut off by the voice limit
+    /// since the last call, oldest first. Stopped voices are not included.
+    pub fn take_finished(&mut self) -> Vec<VoiceId> {
+        std::mem::take(&mut self.finished)
+    }
+
+    fn push_finished(&mut self, id: VoiceId) {
+        self.finished.push(id);
+        if self.finished.len() > MAX_FINISHED {
+            self.finished.drain(..self.finished.len() - MAX_FINISHED);
+        }
+    }
+
+    fn remove_finished(&mut self) {
+        let finished = &mut self.finished;
+        self.voices.retain(|voice| {
+            let done = voice.is_finished();
+            if done {
+                finished.push(voice.id);
+            }
+            !done
+        });
+        if finished.len() > MAX_FINISHED {
+            finished.drain(..finished.len() - MAX_FINISHED);
+        }
+    }
+
+    /// Playback position of a sound voice in seconds. None for streams.
+    pub fn position(&self, id: VoiceId) -> Option<f32> {
+        let voice = self.voice(id)?;
+        match &voice.source {
+            Source::Sound(sound) => Some(voice.position as f32 / sound.sample_rate() as f32),
+            Source::Stream(_) => None,
+        }
+    }
+
+    fn voice(&self, id: VoiceId) -> Option<&Voice> {
+        self.voices.iter().find(|voice| voice.id == id)
+    }
+
+    /// Overwrite `output` with the next frames of every voice. `output` is
+    /// interleaved with `channels` samples per frame; mono output gets the
+    /// average of both sides and channels past the first two stay silent.
+    pub fn mix(&mut self, output: &mut [f32], channels: usize) {
+        output.fill(0.0);
+        let channels = channels.max(1);
+        let frames = output.len() / channels;
+        if frames == 0 {
+            return;
+        }
+
+        for bus in &mut self.buses {
+            bus.buffer.clear();
+            bus.buffer.resize(frames * 2, 0.0);
+        }
+        for voice in &mut self.voices {
+            let bus = &mut self.buses[voice.params.bus.index()].buffer;
+            let step = voice.step(self.sample_rate);
+            let target = pan_gains(voice.params.volume, voice.params.pan);
+            let start = voice.gains;
+            for (index, frame) in bus.chunks_exact_mut(2).enumerate() {
+                if voice.is_finished() {
+                    break;
+                }
+                let Some((left, right)) = voice.current_frame() else {
+                    continue;
+                };
+                let t = (index + 1) as f32 / frames as f32;
+                frame[0] += left * (start[0] + (target[0] - start[0]) * t);
+                frame[1] += right * (start[1] + (target[1] - start[1]) * t);
+                voice.advance(step);
+            }
+            voice.gains = target;
+        }
+        self.remove_finished();
+        self.mix_buses();
+
+        let master = &self.buses[Bus::Master.index()].buffer;
+        for (frame, mixed) in output
+            .chunks_exact_mut(channels)
+            .zip(master.chunks_exact(2))
+        {
+            if channels == 1 {
+                frame[0] = (mixed[0] + mixed[1]) * 0.5;
+            } else {
+                frame[0] = mixed[0];
+                frame[1] = mixed[1];
+            }
+        }
+    }
+
+    /// Run each bus's effects and sum them, with the reverb, into master.
+    fn mix_buses(&mut self) {
+        let sample_rate = self.sample_rate;
+        let (master, others) = self.buses.split_at_mut(1);
+        let master = &mut master[0];
+        self.reverb_input.clear();
+        self.reverb_input.resize(master.buffer.len(), 0.0);
+        for bus in others {
+            bus.process(sample_rate);
+            let send = bus.settings.reverb_send;
+            for ((master, reverb), sample) in master
+                .buffer
+                .iter_mut()
+                .zip(&mut self.reverb_input)
+                .zip(&bus.buffer)
+            {
+                *master += sample;
+                *reverb += sample * send;
+            }
+        }
+        // The reverb runs even with no sends so its tail dies out naturally.
+        self.reverb.process(&self.reverb_input, &mut master.buffer);
+        master.process(sample_rate);
+    }
+
+    /// Advance every voice by `frames` output frames without mixing them,
+    /// so playback keeps time while no device is open.
+    pub fn skip(&mut self, frames: usize) {
+        for voice in &mut self.voices {
+            voice.advance(frames as f64 * voice.step(self.sample_rate));
+            voice.gains = pan_gains(voice.params.volume, voice.params.pan);
+        }
+        self.remove_finished();
+        for bus in &mut self.buses {
+            bus.gain = bus.settings.gain();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constant(value: f32, frames: usize, sample_rate: u32) -> Arc<Sound> {
+        Arc::new(Sound::from_samples(sample_rate, 1, vec![value; frames]))
+    }
+
+    #[test]
+    fn test_pan_gains_follow_the_balance_law() {
+        assert_eq!(pan_gains(1.0, 0.0), [1.0, 1.0]);
+        assert_eq!(pan_gains(0.5, 1.0), [0.0, 0.5]);
+        assert_eq!(pan_gains(1.0, -0.5), [1.0, 0.5]);
+        assert_eq!(pan_gains(1.0, -3.0), [1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_mixes_voices_and_removes_finished_ones() {
+        let mut mixer = Mixer::new(100);
+        let short = mixer.play(constant(0.5, 2, 100), VoiceParams::default());
+        let long = mixer.play(
+            constant(0.25, 8, 100),
+            VoiceParams {
+                pan: 1.0,
+                ..VoiceParams::default()
+            },
+        );
+
+        let mut output = [0.0; 8];
+        mixer.mix(&mut output, 2);
+        assert_eq!(output, [0.5, 0.75, 0.5, 0.75, 0.0, 0.25, 0.0, 0.25]);
+        assert!(!mixer.is_playing(short));
+        assert!(mixer.is_playing(long));
+
+        mixer.skip(4);
+        assert!(!mixer.is_playing(long));
+        assert_eq!(mixer.voice_count(), 0);
+        assert_eq!(mixer.take_finished(), vec![short, long]);
+
+        let stopped = mixer.play(constant(0.5, 2, 100), VoiceParams::default());
+        mixer.stop(stopped);
+        mixer.skip(4);
+        assert!(mixer.take_finished().is_empty());
+    }
+
+    #[test]
+    fn test_resamples_loops_and_ramps_volume_changes() {
+        let mut mixer = Mixer::new(200);
+        let sound = Arc::new(Sound::from_samples(100, 1, vec![0.0, 1.0]));
+        let id = mixer.play(
+            sound,
+            VoiceParams {
+                looping: true,
+                ..VoiceParams::default()
+            },
+        );
+
+        let mut output = [0.0; 5];
+        mixer.mix(&mut output, 1);
+        assert_eq!(output, [0.0, 0.5, 1.0, 0.5, 0.0]);
+        assert!(mixer.is_playing(id));
+
+        mixer.set_params(
+            id,
+            VoiceParams {
+                volume: 0.0,
+                looping: true,
+                ..VoiceParams::default()
+            },
+        );
+        let mut output = [0.0; 4];
+        mixer.mix(&mut output, 1);
+        // Half a frame in after the wrap, fading from full to silent over the buffer
+        assert_eq!(output, [0.375, 0.5, 0.125, 0.0]);
+    }
+
+    #[test]
+    fn test_pitch_changes_the_playback_rate() {
+        let mut mixer = Mixer::new(100);
+        let ramp = Arc::new(Sound::from_samples(100, 1, vec![0.0, 0.25, 0.5, 0.75]));
+        let fast = mixer.play(
+            Arc::clone(&ramp),
+            VoiceParams {
+                pitch: 2.0,
+                ..VoiceParams::default()
+            },
+        );
+        let slow = mixer.play(
+            ramp,
+            VoiceParams {
+                pitch: 0.5,
+                bus: Bus::Music,
+                ..VoiceParams::default()
+            },
+        );
+        mixer.set_bus_settings(
+            Bus::Music,
+            BusSettings {
+                muted: true,
+                ..BusSettings::default()
+            },
+        );
+        mixer.skip(0);
+
+        let mut output = [0.0; 2];
+        mixer.mix(&mut output, 1);
+        assert_eq!(output, [0.0, 0.5]);
+        assert!(!mixer.is_playing(fast));
+        assert_eq!(mixer.position(slow), Some(0.01));
+    }
+
+    #[test]
+    fn test_streams_play_queued_samples_and_end_when_drained() {
+        let mut mixer = Mixer::new(100);
+        let id = mixer.play_stream(100, 2, VoiceParams::default());
+        let mut output = [1.0; 4];
+        mixer.mix(&mut output, 2);
+        // Starved streams are silent but keep playing
+        assert_eq!(output, [0.0; 4]);
+        assert!(mixer.is_playing(id));
+
+        assert!(mixer.queue_samples(id, &[0.5, -0.5, 0.25, -0.25, 0.125, -0.125]));
+        assert_eq!(mixer.queued_frames(id), Some(3));
+        mixer.mix(&mut output, 2);
+        assert_eq!(output, [0.5, -0.5, 0.25, -0.25]);
+        assert_eq!(mixer.queued_frames(id), Some(1));
+
+        assert!(mixer.end_stream(id));
+        assert!(!mixer.queue_samples(id, &[1.0, 1.0]));
+        mixer.mix(&mut output, 2);
+        assert_eq!(output, [0.125, -0.125, 0.0, 0.0]);
+        assert!(!mixer.is_playing(id));
+
+        let sound = mixer.play(constant(0.5, 4, 100), VoiceParams::default());
+        assert_eq!(mixer.queued_frames(sound), None);
+        assert!(!mixer.queue_samples(sound, &[0.0]));
+    }
+
+    #[test]
+    fn test_voice_limit_cuts_off_the_oldest_lowest_priority_voice() {
+        let mut mixer = Mixer::new(100);
+        mixer.set_max_voices(2);
+        let with_priority = |priority| VoiceParams {
+            priority,
+            ..VoiceParams::default()
+        };
+        let music = mixer.play(constant(0.5, 100, 100), with_priority(10));
+        let first = mixer.play(constant(0.5, 100, 100), with_priority(0));
+        let second = mixer.play(constant(0.5, 100, 100), with_priority(0));
+        assert_eq!(mixer.voice_count(), 2);
+        assert!(mixer.is_playing(music));
+        assert!(!mixer.is_playing(first));
+        assert!(mixer.is_playing(second));
+
+        // A lower priority than every playing voice is dropped instead
+        let quiet = mixer.play(constant(0.5, 100, 100), with_priority(-1));
+        assert!(!mixer.is_playing(quiet));
+        assert!(mixer.is_playing(second));
+        assert_eq!(mixer.take_finished(), vec![first, quiet]);
+
+        mixer.set_max_voices(0);
+        assert_eq!(mixer.max_voices(), 1);
+        assert!(mixer.is_playing(music));
+        assert_eq!(mixer.take_finished(), vec![second]);
+    }
+
+    #[test]
+    fn test_buses_scale_mute_and_send_to_the_reverb() {
+        let mut mixer = Mixer::new(100);
+        let looping = |bus| VoiceParams {
+            looping: true,
+            bus,
+            ..VoiceParams::default()
+        };
+        mixer.play(constant(0.5, 4, 100), looping(Bus::Music));
+        mixer.play(constant(0.25, 4, 100), looping(Bus::Ui));
+        mixer.set_bus_settings(
+            Bus::Music,
+            BusSettings {
+                volume: 0.5,
+                ..BusSettings::default()
+            },
+        );
+        mixer.set_bus_settings(
+            Bus::Ui,
+            BusSettings {
+                muted: true,
+                ..BusSettings::default()
+            },
+        );
+        mixer.set_master_volume(2.0);
+
+        // The first buffer ramps to the new gains, the next one holds them.
+        let mut output = [0.0; 8];
+        mixer.mix(&mut output, 2);
+        assert_eq!(output[6..], [0.5, 0.5]);
+        mixer.mix(&mut output, 2);
+        assert_eq!(output, [0.5; 8]);
+
+        mixer.set_bus_settings(
+            Bus::Music,
+            BusSettings {
+                volume: 0.5,
+                reverb_send: 1.0,
+                ..BusSettings::default()
+            },
+        );
+        mixer.mix(&mut output, 2);
+        mixer.mix(&mut output, 2);
+        // The wet signal adds to the unchanged dry one
+        assert!(output.iter().all(|sample| (sample - 0.5).abs() > 1e-3));
+    }
+}
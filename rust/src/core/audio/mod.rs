@@ -0,0 +1,25 @@
+// Audio module for pyg_engine
+// Decodes sounds, mixes them in software and plays them on the default
+// output device, with positional sources and listeners on game objects and
+// mixer buses for volume, mute and effects per group of sounds, snapshots
+// of bus settings to fade between, and impact sounds played when colliders meet
+
+pub mod bus;
+pub mod effects;
+pub mod impact;
+pub mod mixer;
+pub mod output;
+pub mod snapshot;
+pub mod sound;
+pub mod spatial;
+pub mod system;
+
+// Re-export commonly used types
+pub use bus::{Bus, BusSettings};
+pub use impact::{ImpactKey, ImpactSound, ImpactSounds};
+pub use mixer::{Mixer, VoiceId, VoiceParams};
+pub use output::AudioOutput;
+pub use snapshot::{AudioSnapshot, BusOverrides};
+pub use sound::{PcmFormat, Sound};
+pub use spatial::{Attenuation, AttenuationCurve, AudioListenerComponent, AudioSourceComponent};
+pub use system::AudioSystem;
@@ -0,0 +1,143 @@
+//! Audio device output through cpal.
+//!
+//! cpal is behind the `audio` cargo feature (it needs the ALSA development
+//! files on Linux). Without it no device opens, and the engine advances
+//! playing sounds on its own clock so they still end on time.
+
+use super::mixer::Mixer;
+use std::sync::{Arc, Mutex};
+
+/// An open output stream, mixing voices on its own thread until dropped.
+pub struct AudioOutput {
+    #[cfg(feature = "audio")]
+    shutdown: Option<crossbeam_channel::Sender<()>>,
+    #[cfg(feature = "audio")]
+    thread: Option<std::thread::JoinHandle<()>>,
+    sample_rate: u32,
+    channels: u16,
+}
+
+impl AudioOutput {
+    /// Open the default output device and start pulling from `mixer`. Sets
+    /// the mixer's sample rate to the device's.
+    #[cfg(feature = "audio")]
+    pub fn open(mixer: Arc<Mutex<Mixer>>) -> Result<Self, String> {
+        // The stream must stay on the thread that created it on some platforms.
+        let (ready_sender, ready_receiver) = crossbeam_channel::bounded(1);
+        let (shutdown_sender, shutdown_receiver) = crossbeam_channel::bounded::<()>(1);
+        let thread = std::thread::Builder::new()
+            .name("pyg-audio".to_string())
+            .spawn(move || match stream::start(mixer) {
+                Ok((stream, sample_rate, channels)) => {
+                    let _ = ready_sender.send(Ok((sample_rate, channels)));
+                    let _ = shutdown_receiver.recv();
+                    drop(stream);
+                }
+                Err(err) => {
+                    let _ = ready_sender.send(Err(err));
+                }
+            })
+            .map_err(|e| format!("failed to start the audio thread: {e}"))?;
+        let (sample_rate, channels) = ready_receiver
+            .recv()
+            .map_err(|_| "the audio thread stopped while opening the device".to_string())??;
+        Ok(Self {
+            shutdown: Some(shutdown_sender),
+            thread: Some(thread),
+            sample_rate,
+            channels,
+        })
+    }
+
+    #[cfg(not(feature = "audio"))]
+    pub fn open(_mixer: Arc<Mutex<Mixer>>) -> Result<Self, String> {
+        Err("the engine was built without the `audio` feature".to_string())
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    pub fn channels(&self) -> u16 {
+        self.channels
+    }
+}
+
+#[cfg(feature = "audio")]
+impl Drop for AudioOutput {
+    fn drop(&mut self) {
+        // Dropping the sender wakes the audio thread, which closes the stream.
+        self.shutdown.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(feature = "audio")]
+mod stream {
+    use super::Mixer;
+    use crate::core::logging;
+    use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+    use cpal::{FromSample, SizedSample};
+    use std::sync::{Arc, Mutex};
+
+    /// Build and start a stream on the default device.
+    /// @return: The stream, its sample rate and its channel count.
+    pub(super) fn start(mixer: Arc<Mutex<Mixer>>) -> Result<(cpal::Stream, u32, u16), String> {
+        let host = cpal::default_host();
+        let device = host
+            .default_output_device()
+            .ok_or_else(|| "no audio output device found".to_string())?;
+        let supported = device
+            .default_output_config()
+            .map_err(|e| format!("failed to query the audio device: {e}"))?;
+        let config: cpal::StreamConfig = supported.config();
+        let sample_rate = config.sample_rate.0;
+        let channels = config.channels;
+        if let Ok(mut mixer) = mixer.lock() {
+            mixer.set_sample_rate(sample_rate);
+        }
+
+        let stream = match supported.sample_format() {
+            cpal::SampleFormat::F32 => build::<f32>(&device, &config, mixer),
+            cpal::SampleFormat::I16 => build::<i16>(&device, &config, mixer),
+            cpal::SampleFormat::U16 => build::<u16>(&device, &config, mixer),
+            cpal::SampleFormat::I32 => build::<i32>(&device, &config, mixer),
+            format => return Err(format!("unsupported audio sample format {format}")),
+        }?;
+        stream
+            .play()
+            .map_err(|e| format!("failed to start audio playback: {e}"))?;
+        Ok((stream, sample_rate, channels))
+    }
+
+    fn build<T>(
+        device: &cpal::Device,
+        config: &cpal::StreamConfig,
+        mixer: Arc<Mutex<Mixer>>,
+    ) -> Result<cpal::Stream, String>
+    where
+        T: SizedSample + FromSample<f32>,
+    {
+        let channels = config.channels as usize;
+        let mut buffer = Vec::new();
+        device
+            .build_output_stream(
+                config,
+                move |output: &mut [T], _| {
+                    buffer.resize(output.len(), 0.0);
+                    match mixer.lock() {
+                        Ok(mut mixer) => mixer.mix(&mut buffer, channels),
+                        Err(_) => buffer.fill(0.0),
+                    }
+                    for (out, &sample) in output.iter_mut().zip(&buffer) {
+                        *out = T::from_sample(sample.clamp(-1.0, 1.0));
+                    }
+                },
+                |err| logging::log_error(&format!("Audio output error: {err}")),
+                None,
+            )
+            .map_err(|e| format!("failed to open the audio stream: {e}"))
+    }
+}
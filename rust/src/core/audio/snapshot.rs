@@ -0,0 +1,168 @@
+//! Audio snapshots: named sets of bus settings to blend the mixer to over
+//! time, e.g. muffling every bus under water or ducking music in a menu.
+//!
+//! A snapshot only changes the settings it lists, so it can apply a filter
+//! without undoing the volumes a settings menu chose. A transition fades
+//! volumes, reverb sends and low-pass cutoffs from their current values to
+//! the snapshot's; mutes fade like a volume going to zero.
+
+use super::bus::{Bus, BusSettings};
+
+/// Cutoff a missing low-pass filter blends from or to. Filtering at it is
+/// inaudible, so the filter fades in and out smoothly.
+const OPEN_CUTOFF: f32 = 20_000.0;
+
+/// Bus settings a snapshot changes; None leaves a setting as it is.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BusOverrides {
+    pub volume: Option<f32>,
+    pub muted: Option<bool>,
+    /// `Some(None)` removes the filter
+    pub low_pass: Option<Option<f32>>,
+    pub reverb_send: Option<f32>,
+}
+
+impl BusOverrides {
+    /// `settings` with these overrides applied.
+    pub fn apply(&self, settings: BusSettings) -> BusSettings {
+        BusSettings {
+            volume: self.volume.unwrap_or(settings.volume),
+            muted: self.muted.unwrap_or(settings.muted),
+            low_pass: self.low_pass.unwrap_or(settings.low_pass),
+            reverb_send: self.reverb_send.unwrap_or(settings.reverb_send),
+        }
+        .sanitized()
+    }
+}
+
+/// Overrides for any of the buses.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AudioSnapshot {
+    /// Indexed by `Bus::index`
+    buses: [BusOverrides; 4],
+}
+
+impl AudioSnapshot {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_bus(mut self, bus: Bus, overrides: BusOverrides) -> Self {
+        self.set_bus(bus, overrides);
+        self
+    }
+
+    pub fn bus(&self, bus: Bus) -> &BusOverrides {
+        &self.buses[bus.index()]
+    }
+
+    pub fn set_bus(&mut self, bus: Bus, overrides: BusOverrides) {
+        self.buses[bus.index()] = overrides;
+    }
+
+    /// The settings of every bus, `Bus::ALL` order, once applied to `current`.
+    pub fn apply(&self, current: [BusSettings; 4]) -> [BusSettings; 4] {
+        std::array::from_fn(|index| self.buses[index].apply(current[index]))
+    }
+}
+
+/// Settings `t` of the way from `from` to `to`, with `t` from 0 to 1.
+/// Cutoffs blend on a log scale, which sounds even to the ear.
+pub fn blend(from: BusSettings, to: BusSettings, t: f32) -> BusSettings {
+    if t >= 1.0 {
+        return to;
+    }
+    let t = t.max(0.0);
+    let lerp = |a: f32, b: f32| a + (b - a) * t;
+    let low_pass = match (from.low_pass, to.low_pass) {
+        (None, None) => None,
+        (from, to) => {
+            let (from, to) = (from.unwrap_or(OPEN_CUTOFF), to.unwrap_or(OPEN_CUTOFF));
+            Some(from * (to / from).powf(t))
+        }
+    };
+    BusSettings {
+        volume: lerp(from.gain(), to.gain()),
+        muted: false,
+        low_pass,
+        reverb_send: lerp(from.reverb_send, to.reverb_send),
+    }
+}
+
+/// A blend of every bus toward a snapshot.
+#[derive(Debug, Clone)]
+pub struct SnapshotTransition {
+    from: [BusSettings; 4],
+    to: [BusSettings; 4],
+    elapsed: f32,
+    duration: f32,
+}
+
+impl SnapshotTransition {
+    pub fn new(from: [BusSettings; 4], to: [BusSettings; 4], duration: f32) -> Self {
+        Self {
+            from,
+            to,
+            elapsed: 0.0,
+            duration: duration.max(0.0),
+        }
+    }
+
+    /// Move `delta_time` seconds on and get the settings of every bus.
+    pub fn advance(&mut self, delta_time: f32) -> [BusSettings; 4] {
+        self.elapsed += delta_time.max(0.0);
+        let t = if self.duration > 0.0 {
+            self.elapsed / self.duration
+        } else {
+            1.0
+        };
+        std::array::from_fn(|index| blend(self.from[index], self.to[index], t))
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transitions_blend_only_the_overridden_settings() {
+        let mut current = [BusSettings::default(); 4];
+        current[Bus::Music.index()].volume = 0.8;
+        let underwater = AudioSnapshot::new()
+            .with_bus(
+                Bus::Sfx,
+                BusOverrides {
+                    low_pass: Some(Some(200.0)),
+                    muted: Some(true),
+                    ..BusOverrides::default()
+                },
+            )
+            .with_bus(
+                Bus::Music,
+                BusOverrides {
+                    low_pass: Some(Some(2_000.0)),
+                    ..BusOverrides::default()
+                },
+            );
+        let target = underwater.apply(current);
+        assert_eq!(target[Bus::Music.index()].volume, 0.8);
+
+        let mut transition = SnapshotTransition::new(current, target, 1.0);
+        let halfway = transition.advance(0.5);
+        let sfx = halfway[Bus::Sfx.index()];
+        assert!((sfx.volume - 0.5).abs() < 1e-6 && !sfx.muted);
+        // Halfway between 20 kHz and 200 Hz on a log scale
+        assert!((sfx.low_pass.unwrap() - 2_000.0).abs() < 1.0);
+        assert_eq!(halfway[Bus::Master.index()], BusSettings::default());
+        assert!(!transition.is_done());
+
+        let done = transition.advance(0.75);
+        assert!(transition.is_done());
+        assert_eq!(done, target);
+        assert!(done[Bus::Sfx.index()].muted);
+    }
+}
@@ -0,0 +1,210 @@
+//! Decoded sound clips.
+
+use std::io::Cursor;
+
+/// Layout of raw little-endian PCM samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PcmFormat {
+    /// Signed 16-bit integers
+    Int16,
+    /// 32-bit floats in -1..1
+    Float32,
+}
+
+impl PcmFormat {
+    pub fn name(self) -> &'static str {
+        match self {
+            PcmFormat::Int16 => "int16",
+            PcmFormat::Float32 => "float32",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "int16" => Some(PcmFormat::Int16),
+            "float32" => Some(PcmFormat::Float32),
+            _ => None,
+        }
+    }
+
+    pub fn sample_size(self) -> usize {
+        match self {
+            PcmFormat::Int16 => 2,
+            PcmFormat::Float32 => 4,
+        }
+    }
+
+    /// Convert raw samples to floats in -1..1.
+    pub fn decode(self, bytes: &[u8]) -> Result<Vec<f32>, String> {
+        if !bytes.len().is_multiple_of(self.sample_size()) {
+            return Err(format!(
+                "{} bytes is not a whole number of {} samples",
+                bytes.len(),
+                self.name()
+            ));
+        }
+        Ok(match self {
+            PcmFormat::Int16 => bytes
+                .chunks_exact(2)
+                .map(|sample| i16::from_le_bytes([sample[0], sample[1]]) as f32 / 32768.0)
+                .collect(),
+            PcmFormat::Float32 => bytes
+                .chunks_exact(4)
+                .map(|sample| f32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]]))
+                .collect(),
+        })
+    }
+}
+
+/// A sound decoded to interleaved `f32` samples in -1..1, ready to mix.
+#[derive(Debug, Clone)]
+pub struct Sound {
+    sample_rate: u32,
+    channels: u16,
+    samples: Vec<f32>,
+}
+
+impl Sound {
+    /// Wrap interleaved samples. `channels` is clamped to at least 1.
+    pub fn from_samples(sample_rate: u32, channels: u16, samples: Vec<f32>) -> Self {
+        Self {
+            sample_rate: sample_rate.max(1),
+            channels: channels.max(1),
+            samples,
+        }
+    }
+
+    /// Wrap raw interleaved PCM samples, e.g. generated ones.
+    pub fn from_pcm(
+        sample_rate: u32,
+        channels: u16,
+        bytes: &[u8],
+        format: PcmFormat,
+    ) -> Result<Self, String> {
+        let channels = channels.max(1);
+        let samples = format.decode(bytes)?;
+        if !samples.len().is_multiple_of(channels as usize) {
+            return Err(format!(
+                "{} samples is not a whole number of {channels}-channel frames",
+                samples.len()
+            ));
+        }
+        Ok(Self::from_samples(sample_rate, channels, samples))
+    }
+
+    /// Decode a WAV file with 8 to 32-bit integer or 32-bit float samples.
+    pub fn from_wav_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let mut reader = hound::WavReader::new(Cursor::new(bytes))
+            .map_err(|e| format!("invalid WAV data: {e}"))?;
+        let spec = reader.spec();
+        let samples = match spec.sample_format {
+            hound::SampleFormat::Float => reader
+                .samples::<f32>()
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("invalid WAV data: {e}"))?,
+            hound::SampleFormat::Int => {
+                let scale = 1.0 / (1_i64 << (spec.bits_per_sample.clamp(1, 32) - 1)) as f32;
+                reader
+                    .samples::<i32>()
+                    .map(|sample| sample.map(|sample| sample as f32 * scale))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| format!("invalid WAV data: {e}"))?
+            }
+        };
+        Ok(Self::from_samples(spec.sample_rate, spec.channels, samples))
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    pub fn channels(&self) -> u16 {
+        self.channels
+    }
+
+    /// Number of sample frames, one sample per channel each.
+    pub fn frame_count(&self) -> usize {
+        self.samples.len() / self.channels as usize
+    }
+
+    /// Length in seconds.
+    pub fn duration(&self) -> f32 {
+        self.frame_count() as f32 / self.sample_rate as f32
+    }
+
+    /// Decoded size in bytes.
+    pub fn memory_usage(&self) -> usize {
+        self.samples.len() * std::mem::size_of::<f32>()
+    }
+
+    /// Left and right samples of a frame. Mono is played on both sides and
+    /// channels past the first two are dropped.
+    pub fn stereo_frame(&self, frame: usize) -> (f32, f32) {
+        let channels = self.channels as usize;
+        let start = frame * channels;
+        match self.samples.get(start..start + channels) {
+            Some([mono]) => (*mono, *mono),
+            Some([left, right, ..]) => (*left, *right),
+            _ => (0.0, 0.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wav_bytes(spec: hound::WavSpec, samples: &[i16]) -> Vec<u8> {
+        let mut bytes = Cursor::new(Vec::new());
+        let mut writer = hound::WavWriter::new(&mut bytes, spec).unwrap();
+        for &sample in samples {
+            writer.write_sample(sample).unwrap();
+        }
+        writer.finalize().unwrap();
+        bytes.into_inner()
+    }
+
+    #[test]
+    fn test_decodes_integer_wav_to_unit_range() {
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate: 22050,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let sound = Sound::from_wav_bytes(&wav_bytes(spec, &[i16::MIN, 16384, 0, -16384])).unwrap();
+
+        assert_eq!(sound.sample_rate(), 22050);
+        assert_eq!(sound.channels(), 2);
+        assert_eq!(sound.frame_count(), 2);
+        assert_eq!(sound.stereo_frame(0), (-1.0, 0.5));
+        assert_eq!(sound.stereo_frame(1), (0.0, -0.5));
+        assert_eq!(sound.stereo_frame(2), (0.0, 0.0));
+        assert!(Sound::from_wav_bytes(b"not a wav file").is_err());
+    }
+
+    #[test]
+    fn test_wraps_raw_pcm_in_either_format() {
+        let bytes: Vec<u8> = [16384_i16, -32768]
+            .iter()
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+        let sound = Sound::from_pcm(8000, 2, &bytes, PcmFormat::Int16).unwrap();
+        assert_eq!(sound.stereo_frame(0), (0.5, -1.0));
+
+        let bytes: Vec<u8> = [0.25_f32].iter().flat_map(|s| s.to_le_bytes()).collect();
+        let sound = Sound::from_pcm(8000, 1, &bytes, PcmFormat::Float32).unwrap();
+        assert_eq!(sound.stereo_frame(0), (0.25, 0.25));
+
+        assert!(Sound::from_pcm(8000, 1, &[0, 0, 0], PcmFormat::Int16).is_err());
+        assert!(Sound::from_pcm(8000, 2, &[0, 0], PcmFormat::Int16).is_err());
+        assert_eq!(PcmFormat::from_name("FLOAT32"), Some(PcmFormat::Float32));
+    }
+
+    #[test]
+    fn test_mono_frames_play_on_both_sides() {
+        let sound = Sound::from_samples(10, 1, vec![0.25, -0.5]);
+        assert_eq!(sound.stereo_frame(1), (-0.5, -0.5));
+        assert!((sound.duration() - 0.2).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,541 @@
+//! Positional sound: sources and listeners on game objects.
+//!
+//! An [`AudioSourceComponent`] plays a sound whose volume and pan follow its
+//! object's position relative to the listener: the first enabled
+//! [`AudioListenerComponent`] in the scene, or the active camera without one.
+//! Volume falls off with distance along the source's [`Attenuation`] curve,
+//! and the horizontal offset pans the sound, reaching full left or right at
+//! the listener's `pan_width`.
+
+use super::bus::Bus;
+use crate::core::component::{ComponentTrait, next_component_id};
+use crate::core::time::Time;
+use crate::types::vector::Vec2;
+use std::any::Any;
+
+/// How volume falls off between `min_distance` and `max_distance`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttenuationCurve {
+    /// Same volume at any distance
+    None,
+    /// Straight fade to silence at `max_distance`
+    Linear,
+    /// `min / (min + rolloff * (d - min))`, like sound in open space
+    Inverse,
+    /// `(d / min) ^ -rolloff`
+    Exponential,
+}
+
+impl AttenuationCurve {
+    pub fn name(self) -> &'static str {
+        match self {
+            AttenuationCurve::None => "none",
+            AttenuationCurve::Linear => "linear",
+            AttenuationCurve::Inverse => "inverse",
+            AttenuationCurve::Exponential => "exponential",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "none" => Some(AttenuationCurve::None),
+            "linear" => Some(AttenuationCurve::Linear),
+            "inverse" => Some(AttenuationCurve::Inverse),
+            "exponential" => Some(AttenuationCurve::Exponential),
+            _ => None,
+        }
+    }
+}
+
+/// Distance attenuation of a positional sound.
+///
+/// Within `min_distance` the sound plays at full volume. Past `max_distance`
+/// linear attenuation is silent, while the inverse and exponential curves
+/// stay at their `max_distance` volume.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Attenuation {
+    pub curve: AttenuationCurve,
+    pub min_distance: f32,
+    pub max_distance: f32,
+    /// Steepness of the falloff, 1.0 for the curve's natural shape
+    pub rolloff: f32,
+}
+
+impl Default for Attenuation {
+    fn default() -> Self {
+        Self {
+            curve: AttenuationCurve::Linear,
+            min_distance: 100.0,
+            max_distance: 1000.0,
+            rolloff: 1.0,
+        }
+    }
+}
+
+impl Attenuation {
+    /// Volume multiplier in 0..1 at a distance from the listener.
+    pub fn gain(&self, distance: f32) -> f32 {
+        let min_distance = self.min_distance.max(0.0);
+        let max_distance = self.max_distance.max(min_distance);
+        if distance <= min_distance || self.curve == AttenuationCurve::None {
+            return 1.0;
+        }
+        let distance = distance.min(max_distance);
+        let rolloff = self.rolloff.max(0.0);
+        let gain = match self.curve {
+            AttenuationCurve::None => 1.0,
+            AttenuationCurve::Linear => {
+                if max_distance <= min_distance {
+                    return 0.0;
+                }
+                1.0 - rolloff * (distance - min_distance) / (max_distance - min_distance)
+            }
+            // A zero `min_distance` would be silent everywhere, so it acts like one unit.
+            AttenuationCurve::Inverse => {
+                let min_distance = min_distance.max(1.0);
+                min_distance / (min_distance + rolloff * (distance - min_distance).max(0.0))
+            }
+            AttenuationCurve::Exponential => {
+                (distance / min_distance.max(1.0)).max(1.0).powf(-rolloff)
+            }
+        };
+        gain.clamp(0.0, 1.0)
+    }
+}
+
+/// Volume multiplier and pan of a source heard by a listener.
+pub fn spatial_gain_and_pan(
+    source: Vec2,
+    listener: Vec2,
+    attenuation: &Attenuation,
+    pan_width: f32,
+) -> (f32, f32) {
+    let dx = source.x() - listener.x();
+    let dy = source.y() - listener.y();
+    let gain = attenuation.gain((dx * dx + dy * dy).sqrt());
+    let pan = if pan_width > 0.0 {
+        (dx / pan_width).clamp(-1.0, 1.0)
+    } else {
+        0.0
+    };
+    (gain, pan)
+}
+
+/// Plays a sound from its object's position.
+///
+/// Set `playing` (or call `play`) to start it; the engine clears it when a
+/// sound that does not loop ends. With `spatial` off the sound plays at its
+/// volume, centered, wherever the object is.
+#[derive(Debug, Clone)]
+pub struct AudioSourceComponent {
+    component_id: u32,
+    name: String,
+    /// Sound file path, relative to the source root
+    sound: Option<String>,
+    volume: f32,
+    looping: bool,
+    spatial: bool,
+    attenuation: Attenuation,
+    bus: Bus,
+    pitch: f32,
+    /// Pick a random pitch in this range on each play, instead of `pitch`
+    pitch_range: Option<(f32, f32)>,
+    /// Past the voice limit, lower priorities are cut off first
+    priority: i32,
+    playing: bool,
+    /// Bumped by `play` so a playing source starts over
+    play_count: u32,
+    enabled_self: bool,
+    enabled_in_hierarchy: bool,
+}
+
+impl ComponentTrait for AudioSourceComponent {
+    fn new(name: String) -> Self {
+        Self {
+            component_id: next_component_id(),
+            name,
+            sound: None,
+            volume: 1.0,
+            looping: false,
+            spatial: true,
+            attenuation: Attenuation::default(),
+            bus: Bus::default(),
+            pitch: 1.0,
+            pitch_range: None,
+            priority: 0,
+            playing: false,
+            play_count: 0,
+            enabled_self: true,
+            enabled_in_hierarchy: true,
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn id(&self) -> u32 {
+        self.component_id
+    }
+
+    fn component_type(&self) -> &'static str {
+        "AudioSource"
+    }
+
+    fn is_enabled_self(&self) -> bool {
+        self.enabled_self
+    }
+
+    fn set_enabled_self(&mut self, enabled: bool) {
+        self.enabled_self = enabled;
+    }
+
+    fn is_enabled_in_hierarchy(&self) -> bool {
+        self.enabled_in_hierarchy
+    }
+
+    fn set_enabled_in_hierarchy(&mut self, enabled: bool) {
+        self.enabled_in_hierarchy = enabled;
+    }
+
+    fn update(&self, _time: &Time) {}
+    fn fixed_update(&self, _time: &Time, _fixed_time: f32) {}
+    fn on_start(&self) {}
+    fn on_destroy(&self) {}
+    fn on_enable(&self) {}
+    fn on_disable(&self) {}
+
+    fn clone_component(&self) -> Box<dyn ComponentTrait> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl AudioSourceComponent {
+    pub fn new(name: impl Into<String>) -> Self {
+        <Self as ComponentTrait>::new(name.into())
+    }
+
+    pub fn with_sound(mut self, sound: Option<String>) -> Self {
+        self.sound = sound;
+        self
+    }
+
+    pub fn with_volume(mut self, volume: f32) -> Self {
+        self.volume = volume.max(0.0);
+        self
+    }
+
+    pub fn with_looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    pub fn with_spatial(mut self, spatial: bool) -> Self {
+        self.spatial = spatial;
+        self
+    }
+
+    pub fn with_attenuation(mut self, attenuation: Attenuation) -> Self {
+        self.attenuation = attenuation;
+        self
+    }
+
+    pub fn with_bus(mut self, bus: Bus) -> Self {
+        self.bus = bus;
+        self
+    }
+
+    pub fn with_pitch(mut self, pitch: f32) -> Self {
+        self.pitch = pitch.max(0.0);
+        self
+    }
+
+    pub fn with_pitch_range(mut self, pitch_range: Option<(f32, f32)>) -> Self {
+        self.pitch_range = pitch_range;
+        self
+    }
+
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn sound(&self) -> Option<&str> {
+        self.sound.as_deref()
+    }
+
+    pub fn set_sound(&mut self, sound: Option<String>) {
+        self.sound = sound;
+    }
+
+    pub fn volume(&self) -> f32 {
+        self.volume
+    }
+
+    pub fn set_volume(&mut self, volume: f32) {
+        self.volume = volume.max(0.0);
+    }
+
+    pub fn looping(&self) -> bool {
+        self.looping
+    }
+
+    pub fn set_looping(&mut self, looping: bool) {
+        self.looping = looping;
+    }
+
+    /// Whether volume and pan follow the object's position
+    pub fn spatial(&self) -> bool {
+        self.spatial
+    }
+
+    pub fn set_spatial(&mut self, spatial: bool) {
+        self.spatial = spatial;
+    }
+
+    pub fn attenuation(&self) -> &Attenuation {
+        &self.attenuation
+    }
+
+    pub fn attenuation_mut(&mut self) -> &mut Attenuation {
+        &mut self.attenuation
+    }
+
+    /// Mixer bus the sound plays through
+    pub fn bus(&self) -> Bus {
+        self.bus
+    }
+
+    pub fn set_bus(&mut self, bus: Bus) {
+        self.bus = bus;
+    }
+
+    /// Playback rate, 1.0 as recorded
+    pub fn pitch(&self) -> f32 {
+        self.pitch
+    }
+
+    pub fn set_pitch(&mut self, pitch: f32) {
+        self.pitch = pitch.max(0.0);
+    }
+
+    /// Range a random pitch is picked from on each play, overriding `pitch`
+    pub fn pitch_range(&self) -> Option<(f32, f32)> {
+        self.pitch_range
+    }
+
+    pub fn set_pitch_range(&mut self, pitch_range: Option<(f32, f32)>) {
+        self.pitch_range = pitch_range;
+    }
+
+    /// Which sounds give way first when too many play at once
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    pub fn set_priority(&mut self, priority: i32) {
+        self.priority = priority;
+    }
+
+    /// Whether the sound should be playing; cleared when it ends
+    pub fn playing(&self) -> bool {
+        self.playing
+    }
+
+    pub fn set_playing(&mut self, playing: bool) {
+        self.playing = playing;
+    }
+
+    pub fn play_count(&self) -> u32 {
+        self.play_count
+    }
+
+    /// Start the sound from the beginning, even if it is playing.
+    pub fn play(&mut self) {
+        self.playing = true;
+        self.play_count = self.play_count.wrapping_add(1);
+    }
+
+    pub fn stop(&mut self) {
+        self.playing = false;
+    }
+}
+
+/// Hears positional sounds from its object's position, instead of the camera.
+#[derive(Debug, Clone)]
+pub struct AudioListenerComponent {
+    component_id: u32,
+    name: String,
+    /// Horizontal distance at which a sound is panned fully to one side
+    pan_width: f32,
+    enabled_self: bool,
+    enabled_in_hierarchy: bool,
+}
+
+/// `pan_width` of listeners, and of the camera when there is no listener.
+pub const DEFAULT_PAN_WIDTH: f32 = 500.0;
+
+impl ComponentTrait for AudioListenerComponent {
+    fn new(name: String) -> Self {
+        Self {
+            component_id: next_component_id(),
+            name,
+            pan_width: DEFAULT_PAN_WIDTH,
+            enabled_self: true,
+            enabled_in_hierarchy: true,
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn id(&self) -> u32 {
+        self.component_id
+    }
+
+    fn component_type(&self) -> &'static str {
+        "AudioListener"
+    }
+
+    fn is_enabled_self(&self) -> bool {
+        self.enabled_self
+    }
+
+    fn set_enabled_self(&mut self, enabled: bool) {
+        self.enabled_self = enabled;
+    }
+
+    fn is_enabled_in_hierarchy(&self) -> bool {
+        self.enabled_in_hierarchy
+    }
+
+    fn set_enabled_in_hierarchy(&mut self, enabled: bool) {
+        self.enabled_in_hierarchy = enabled;
+    }
+
+    fn update(&self, _time: &Time) {}
+    fn fixed_update(&self, _time: &Time, _fixed_time: f32) {}
+    fn on_start(&self) {}
+    fn on_destroy(&self) {}
+    fn on_enable(&self) {}
+    fn on_disable(&self) {}
+
+    fn clone_component(&self) -> Box<dyn ComponentTrait> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl AudioListenerComponent {
+    pub fn new(name: impl Into<String>) -> Self {
+        <Self as ComponentTrait>::new(name.into())
+    }
+
+    pub fn with_pan_width(mut self, pan_width: f32) -> Self {
+        self.pan_width = pan_width.max(0.0);
+        self
+    }
+
+    pub fn pan_width(&self) -> f32 {
+        self.pan_width
+    }
+
+    pub fn set_pan_width(&mut self, pan_width: f32) {
+        self.pan_width = pan_width.max(0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attenuation(curve: AttenuationCurve) -> Attenuation {
+        Attenuation {
+            curve,
+            min_distance: 100.0,
+            max_distance: 500.0,
+            rolloff: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_attenuation_curves() {
+        let linear = attenuation(AttenuationCurve::Linear);
+        assert_eq!(linear.gain(50.0), 1.0);
+        assert_eq!(linear.gain(300.0), 0.5);
+        assert_eq!(linear.gain(800.0), 0.0);
+
+        let inverse = attenuation(AttenuationCurve::Inverse);
+        assert_eq!(inverse.gain(200.0), 0.5);
+        // Clamped at max_distance rather than fading to silence
+        assert_eq!(inverse.gain(900.0), inverse.gain(500.0));
+        assert_eq!(inverse.gain(500.0), 0.2);
+
+        let exponential = attenuation(AttenuationCurve::Exponential);
+        assert_eq!(exponential.gain(400.0), 0.25);
+        assert_eq!(attenuation(AttenuationCurve::None).gain(10_000.0), 1.0);
+
+        let steep = Attenuation {
+            rolloff: 2.0,
+            ..linear
+        };
+        assert_eq!(steep.gain(300.0), 0.0);
+        assert_eq!(
+            AttenuationCurve::from_name("Inverse"),
+            Some(AttenuationCurve::Inverse)
+        );
+        assert_eq!(AttenuationCurve::from_name("log"), None);
+    }
+
+    #[test]
+    fn test_pan_follows_horizontal_offset() {
+        let curve = attenuation(AttenuationCurve::Linear);
+        let listener = Vec2::new(10.0, 0.0);
+
+        let (gain, pan) = spatial_gain_and_pan(Vec2::new(260.0, 0.0), listener, &curve, 500.0);
+        assert_eq!((gain, pan), (0.625, 0.5));
+
+        let (_, pan) = spatial_gain_and_pan(Vec2::new(-2000.0, 0.0), listener, &curve, 500.0);
+        assert_eq!(pan, -1.0);
+
+        // Straight above the listener: quieter but centered
+        let (gain, pan) = spatial_gain_and_pan(Vec2::new(10.0, 300.0), listener, &curve, 500.0);
+        assert_eq!((gain, pan), (0.5, 0.0));
+    }
+
+    #[test]
+    fn test_play_restarts_and_stop_clears() {
+        let mut source = AudioSourceComponent::new("Engine hum").with_looping(true);
+        assert!(!source.playing());
+        source.play();
+        source.play();
+        assert!(source.playing());
+        assert_eq!(source.play_count(), 2);
+        source.stop();
+        assert!(!source.playing());
+    }
+}
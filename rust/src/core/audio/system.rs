@@ -0,0 +1,836 @@
+//! The engine's audio state: decoded sounds, the mixer and its output.
+
+use super::bus::{Bus, BusSettings};
+use super::impact::ImpactSounds;
+use super::mixer::{Mixer, VoiceId, VoiceParams};
+use super::output::AudioOutput;
+use super::snapshot::{AudioSnapshot, SnapshotTransition};
+use super::sound::Sound;
+use super::spatial::{
+    Attenuation, AudioListenerComponent, AudioSourceComponent, DEFAULT_PAN_WIDTH,
+    spatial_gain_and_pan,
+};
+use crate::core::asset_manager::AssetManager;
+use crate::core::component::ComponentTrait;
+use crate::core::logging;
+use crate::core::object_manager::ObjectManager;
+use crate::core::physics::CollisionEvent;
+use crate::types::vector::Vec2;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// A sound started with `play` at a fixed world position.
+struct PositionalVoice {
+    position: Vec2,
+    volume: f32,
+    attenuation: Attenuation,
+}
+
+/// The voice of an `AudioSourceComponent`, and the `play` call it answered.
+struct SourceVoice {
+    /// None once the sound ended or was stopped
+    voice: Option<VoiceId>,
+    play_count: u32,
+    /// Pitch picked from the source's pitch range when it started
+    random_pitch: Option<f32>,
+}
+
+/// Finished voices kept for [`AudioSystem::take_finished`]; older ones are dropped.
+const MAX_FINISHED: usize = 256;
+
+/// Plays sounds for the engine and keeps positional voices in step with
+/// their sources and the listener, see [`AudioSystem::update`].
+pub struct AudioSystem {
+    mixer: Arc<Mutex<Mixer>>,
+    output: Option<AudioOutput>,
+    /// The device is opened on the first sound, and only tried once
+    output_tried: bool,
+    /// Decoded sounds by resolved path
+    sounds: HashMap<String, Arc<Sound>>,
+    /// Sounds added from samples by name, checked before paths
+    generated: HashMap<String, Arc<Sound>>,
+    positional: HashMap<VoiceId, PositionalVoice>,
+    /// Voices of `AudioSourceComponent`s by component id
+    sources: HashMap<u32, SourceVoice>,
+    listener_position: Vec2,
+    pan_width: f32,
+    /// xorshift state for random pitches
+    rng_state: u64,
+    /// Voices that played to the end, oldest first, until taken
+    finished: VecDeque<VoiceId>,
+    impact_sounds: ImpactSounds,
+    snapshots: HashMap<String, AudioSnapshot>,
+    /// Snapshot last transitioned to
+    snapshot: Option<String>,
+    transition: Option<SnapshotTransition>,
+}
+
+impl Default for AudioSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AudioSystem {
+    pub fn new() -> Self {
+        Self {
+            mixer: Arc::new(Mutex::new(Mixer::default())),
+            output: None,
+            output_tried: false,
+            sounds: HashMap::new(),
+            generated: HashMap::new(),
+            positional: HashMap::new(),
+            sources: HashMap::new(),
+            listener_position: Vec2::new(0.0, 0.0),
+            pan_width: DEFAULT_PAN_WIDTH,
+            rng_state: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_nanos() as u64)
+                .unwrap_or(0)
+                | 1,
+            finished: VecDeque::new(),
+            impact_sounds: ImpactSounds::new(),
+            snapshots: HashMap::new(),
+            snapshot: None,
+            transition: None,
+        }
+    }
+
+    /// A random pitch between `min` and `max`, so repeated sounds vary.
+    pub fn random_pitch(&mut self, (min, max): (f32, f32)) -> f32 {
+        self.rng_state ^= self.rng_state << 13;
+        self.rng_state ^= self.rng_state >> 7;
+        self.rng_state ^= self.rng_state << 17;
+        let unit = (self.rng_state >> 40) as f32 / (1_u64 << 24) as f32;
+        min + (max - min) * unit
+    }
+
+    fn mixer(&self) -> MutexGuard<'_, Mixer> {
+        self.mixer.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Whether sounds reach a device, i.e. the engine was built with the
+    /// `audio` feature and the default output opened.
+    pub fn is_output_open(&self) -> bool {
+        self.output.is_some()
+    }
+
+    fn ensure_output(&mut self) {
+        if self.output_tried {
+            return;
+        }
+        self.output_tried = true;
+        match AudioOutput::open(Arc::clone(&self.mixer)) {
+            Ok(output) => {
+                logging::log_info(&format!(
+                    "Opened audio output ({} Hz, {} channels)",
+                    output.sample_rate(),
+                    output.channels()
+                ));
+                self.output = Some(output);
+            }
+            Err(err) => logging::log_warn(&format!("Audio output unavailable: {err}")),
+        }
+    }
+
+    /// Get a sound added with `add_sound`, or decode one, reading it through
+    /// the asset store the first time.
+    pub fn sound(&mut self, assets: &mut AssetManager, path: &str) -> Result<Arc<Sound>, String> {
+        if let Some(sound) = self.generated.get(path) {
+            return Ok(Arc::clone(sound));
+        }
+        let resolved_path = assets.resolve_path(path);
+        if let Some(sound) = self.sounds.get(&resolved_path) {
+            return Ok(Arc::clone(sound));
+        }
+        let bytes = assets.audio_bytes(path)?;
+        let sound = Sound::from_wav_bytes(&bytes)
+            .map(Arc::new)
+            .map_err(|e| format!("failed to decode sound '{resolved_path}': {e}"))?;
+        self.sounds.insert(resolved_path, Arc::clone(&sound));
+        Ok(sound)
+    }
+
+    /// Make samples playable under `name`, in place of any sound added
+    /// under it before. Voices already playing the old one keep it.
+    pub fn add_sound(&mut self, name: impl Into<String>, sound: Sound) {
+        self.generated.insert(name.into(), Arc::new(sound));
+    }
+
+    /// Remove a sound added with `add_sound`. Voices playing it play on.
+    pub fn remove_sound(&mut self, name: &str) -> bool {
+        self.generated.remove(name).is_some()
+    }
+
+    /// Drop a decoded sound so the next play reads the file again.
+    /// Voices already playing it keep the old data.
+    pub fn forget_sound(&mut self, resolved_path: &str) {
+        self.sounds.remove(resolved_path);
+    }
+
+    /// Play a sound. With a `position` its volume and pan follow the
+    /// listener, and `params.pan` is ignored.
+    pub fn play(
+        &mut self,
+        sound: Arc<Sound>,
+        params: VoiceParams,
+        position: Option<(Vec2, Attenuation)>,
+    ) -> VoiceId {
+        self.ensure_output();
+        let Some((position, attenuation)) = position else {
+            return self.mixer().play(sound, params);
+        };
+        let (gain, pan) = spatial_gain_and_pan(
+            position,
+            self.listener_position,
+            &attenuation,
+            self.pan_width,
+        );
+        let id = self.mixer().play(
+            sound,
+            VoiceParams {
+                volume: params.volume * gain,
+                pan,
+                ..params
+            },
+        );
+        self.positional.insert(
+            id,
+            PositionalVoice {
+                position,
+                volume: params.volume,
+                attenuation,
+            },
+        );
+        id
+    }
+
+    /// Start a stream of generated samples, silent until some are queued.
+    pub fn play_stream(&mut self, sample_rate: u32, channels: u16, params: VoiceParams) -> VoiceId {
+        self.ensure_output();
+        self.mixer().play_stream(sample_rate, channels, params)
+    }
+
+    /// Append interleaved samples to a stream. A trailing partial frame waits
+    /// for the rest of its samples. Returns false once the stream ended.
+    pub fn queue_samples(&mut self, id: VoiceId, samples: &[f32]) -> bool {
+        self.mixer().queue_samples(id, samples)
+    }
+
+    /// Let a stream finish once its queued samples play.
+    pub fn end_stream(&mut self, id: VoiceId) -> bool {
+        self.mixer().end_stream(id)
+    }
+
+    /// Frames queued on a playing stream, or None once it finished.
+    pub fn queued_frames(&self, id: VoiceId) -> Option<usize> {
+        self.mixer().queued_frames(id)
+    }
+
+    pub fn stop(&mut self, id: VoiceId) -> bool {
+        self.positional.remove(&id);
+        self.mixer().stop(id)
+    }
+
+    /// Stop every sound. Playing sources start again on their next update
+    /// only if `play` is called on them.
+    pub fn stop_all(&mut self) {
+        self.mixer().stop_all();
+        self.positional.clear();
+    }
+
+    pub fn is_playing(&self, id: VoiceId) -> bool {
+        self.mixer().is_playing(id)
+    }
+
+    /// Set a voice's volume. For positional voices this is the volume before attenuation.
+    pub fn set_volume(&mut self, id: VoiceId, volume: f32) -> bool {
+        let volume = volume.max(0.0);
+        if let Some(voice) = self.positional.get_mut(&id) {
+            voice.volume = volume;
+            return self.mixer().is_playing(id);
+        }
+        let mut mixer = self.mixer();
+        match mixer.params(id) {
+            Some(params) => mixer.set_params(id, VoiceParams { volume, ..params }),
+            None => false,
+        }
+    }
+
+    /// Set a voice's playback rate.
+    pub fn set_pitch(&mut self, id: VoiceId, pitch: f32) -> bool {
+        let mut mixer = self.mixer();
+        match mixer.params(id) {
+            Some(params) => mixer.set_params(
+                id,
+                VoiceParams {
+                    pitch: pitch.max(0.0),
+                    ..params
+                },
+            ),
+            None => false,
+        }
+    }
+
+    /// Set a voice's pan. Positional voices take their pan from the listener.
+    pub fn set_pan(&mut self, id: VoiceId, pan: f32) -> bool {
+        if self.positional.contains_key(&id) {
+            return false;
+        }
+        let mut mixer = self.mixer();
+        match mixer.params(id) {
+            Some(params) => mixer.set_params(
+                id,
+                VoiceParams {
+                    pan: pan.clamp(-1.0, 1.0),
+                    ..params
+                },
+            ),
+            None => false,
+        }
+    }
+
+    pub fn master_volume(&self) -> f32 {
+        self.mixer().master_volume()
+    }
+
+    pub fn set_master_volume(&mut self, volume: f32) {
+        self.mixer().set_master_volume(volume);
+    }
+
+    pub fn bus_settings(&self, bus: Bus) -> BusSettings {
+        self.mixer().bus_settings(bus)
+    }
+
+    /// Change a bus's settings. A snapshot transition in progress keeps
+    /// blending the bus until it ends.
+    pub fn set_bus_settings(&mut self, bus: Bus, settings: BusSettings) {
+        self.mixer().set_bus_settings(bus, settings);
+    }
+
+    /// Save bus settings under `name` for `transition_to`, replacing any
+    /// snapshot added under it before.
+    pub fn add_snapshot(&mut self, name: impl Into<String>, snapshot: AudioSnapshot) {
+        self.snapshots.insert(name.into(), snapshot);
+    }
+
+    pub fn remove_snapshot(&mut self, name: &str) -> bool {
+        self.snapshots.remove(name).is_some()
+    }
+
+    pub fn snapshot_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.snapshots.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// The snapshot last transitioned to, even while the blend is running.
+    pub fn current_snapshot(&self) -> Option<&str> {
+        self.snapshot.as_deref()
+    }
+
+    pub fn is_transitioning(&self) -> bool {
+        self.transition.is_some()
+    }
+
+    /// Blend the buses from their current settings to a snapshot's over
+    /// `duration` seconds of real time, replacing any transition in progress.
+    pub fn transition_to(&mut self, name: &str, duration: f32) -> Result<(), String> {
+        let snapshot = self
+            .snapshots
+            .get(name)
+            .ok_or_else(|| format!("unknown audio snapshot '{name}'"))?;
+        let current = Bus::ALL.map(|bus| self.mixer().bus_settings(bus));
+        let mut transition = SnapshotTransition::new(current, snapshot.apply(current), duration);
+        self.snapshot = Some(name.to_string());
+        if duration > 0.0 {
+            self.transition = Some(transition);
+        } else {
+            self.transition = None;
+            self.apply_bus_settings(transition.advance(0.0));
+        }
+        Ok(())
+    }
+
+    fn apply_bus_settings(&mut self, settings: [BusSettings; 4]) {
+        let mut mixer = self.mixer();
+        for (bus, settings) in Bus::ALL.into_iter().zip(settings) {
+            mixer.set_bus_settings(bus, settings);
+        }
+    }
+
+    fn update_transition(&mut self, delta_time: f32) {
+        let Some(transition) = &mut self.transition else {
+            return;
+        };
+        let settings = transition.advance(delta_time);
+        if transition.is_done() {
+            self.transition = None;
+        }
+        self.apply_bus_settings(settings);
+    }
+
+    pub fn voice_count(&self) -> usize {
+        self.mixer().voice_count()
+    }
+
+    /// Most sounds that play at once, see [`Mixer::set_max_voices`].
+    pub fn max_voices(&self) -> usize {
+        self.mixer().max_voices()
+    }
+
+    pub fn set_max_voices(&mut self, max_voices: usize) {
+        self.mixer().set_max_voices(max_voices);
+    }
+
+    /// Where positional sounds were last heard from.
+    pub fn listener_position(&self) -> Vec2 {
+        self.listener_position
+    }
+
+    /// Sounds played when colliders start touching, see [`AudioSystem::play_impacts`].
+    pub fn impact_sounds(&self) -> &ImpactSounds {
+        &self.impact_sounds
+    }
+
+    pub fn impact_sounds_mut(&mut self) -> &mut ImpactSounds {
+        &mut self.impact_sounds
+    }
+
+    /// Play the impact sounds due for a collision step's events.
+    pub fn play_impacts(
+        &mut self,
+        events: &[CollisionEvent],
+        objects: &ObjectManager,
+        assets: &mut AssetManager,
+    ) -> Vec<VoiceId> {
+        let impacts = self.impact_sounds.impacts(events, objects);
+        let mut voices = Vec::with_capacity(impacts.len());
+        for impact in impacts {
+            let sound = match self.sound(assets, &impact.sound.sound) {
+                Ok(sound) => sound,
+                Err(err) => {
+                    logging::log_error(&format!("Impact sound: {err}"));
+                    continue;
+                }
+            };
+            let pitch = match impact.sound.pitch_range {
+                Some(range) => self.random_pitch(range),
+                None => 1.0,
+            };
+            let params = VoiceParams {
+                volume: impact.volume,
+                pitch,
+                bus: impact.sound.bus,
+                priority: impact.sound.priority,
+                ..VoiceParams::default()
+            };
+            let position = impact
+                .sound
+                .attenuation
+                .map(|attenuation| (impact.position, attenuation));
+            voices.push(self.play(sound, params, position));
+        }
+        voices
+    }
+
+    /// Whether the source with this component id has a voice playing.
+    pub fn is_source_playing(&self, component_id: u32) -> bool {
+        self.sources
+            .get(&component_id)
+            .and_then(|source| source.voice)
+            .is_some_and(|voice| self.mixer().is_playing(voice))
+    }
+
+    /// Voices that played to the end or were cut off by the voice limit
+    /// since the last call, oldest first. Stopped voices are not included.
+    pub fn take_finished(&mut self) -> Vec<VoiceId> {
+        self.finished.drain(..).collect()
+    }
+
+    /// Advance playback and any snapshot transition by `delta_time` seconds,
+    /// then bring every `AudioSourceComponent` and positional voice up to date
+    /// with object positions. Sources that stop are marked not playing.
+    /// Playback only advances here while no device is open.
+    ///
+    /// Returns the voices that played to the end since the last update; they
+    /// are also queued for `take_finished`.
+    pub fn update(
+        &mut self,
+        delta_time: f32,
+        objects: &mut ObjectManager,
+        assets: &mut AssetManager,
+        camera_position: Vec2,
+    ) -> Vec<VoiceId> {
+        self.update_transition(delta_time);
+        let finished = {
+            let mut mixer = self.mixer();
+            if self.output.is_none() {
+                let frames = (delta_time.max(0.0) * mixer.sample_rate() as f32).round() as usize;
+                mixer.skip(frames);
+            }
+            mixer.take_finished()
+        };
+        self.finished.extend(&finished);
+        if self.finished.len() > MAX_FINISHED {
+            self.finished.drain(..self.finished.len() - MAX_FINISHED);
+        }
+
+        self.update_listener(objects, camera_position);
+        let mut mixer = self.mixer.lock().unwrap_or_else(|e| e.into_inner());
+        self.positional.retain(|id, voice| {
+            let (gain, pan) = spatial_gain_and_pan(
+                voice.position,
+                self.listener_position,
+                &voice.attenuation,
+                self.pan_width,
+            );
+            match mixer.params(*id) {
+                Some(params) => mixer.set_params(
+                    *id,
+                    VoiceParams {
+                        volume: voice.volume * gain,
+                        pan,
+                        ..params
+                    },
+                ),
+                None => false,
+            }
+        });
+        drop(mixer);
+        self.update_sources(objects, assets);
+        finished
+    }
+
+    /// Hear from the first enabled listener, or the camera without one.
+    fn update_listener(&mut self, objects: &ObjectManager, camera_position: Vec2) {
+        let listener = objects
+            .iter()
+            .filter(|object| object.is_active())
+            .find_map(|object| {
+                object
+                    .get_component::<AudioListenerComponent>()
+                    .filter(|listener| listener.is_effectively_enabled())
+                    .map(|listener| (object.get_id(), listener.pan_width()))
+            });
+        match listener.and_then(|(id, pan_width)| Some((objects.world_position(id)?, pan_width))) {
+            Some((position, pan_width)) => {
+                self.listener_position = position;
+                self.pan_width = pan_width;
+            }
+            None => {
+                self.listener_position = camera_position;
+                self.pan_width = DEFAULT_PAN_WIDTH;
+            }
+        }
+    }
+
+    fn update_sources(&mut self, objects: &mut ObjectManager, assets: &mut AssetManager) {
+        let sources: Vec<(u32, AudioSourceComponent)> = objects
+            .iter()
+            .filter(|object| object.is_active())
+            .flat_map(|object| {
+                object
+                    .get_components::<AudioSourceComponent>()
+                    .into_iter()
+                    .filter(|source| source.is_effectively_enabled())
+                    .map(|source| (object.get_id(), source.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        // Sources that were removed, disabled or destroyed stop.
+        let live: HashSet<u32> = sources.iter().map(|(_, source)| source.id()).collect();
+        let gone: Vec<u32> = self
+            .sources
+            .keys()
+            .filter(|id| !live.contains(id))
+            .copied()
+            .collect();
+        for component_id in gone {
+            if let Some(voice) = self
+                .sources
+                .remove(&component_id)
+                .and_then(|source| source.voice)
+            {
+                self.mixer().stop(voice);
+            }
+        }
+
+        for (object_id, source) in sources {
+            let component_id = source.id();
+            let previous = self.sources.get(&component_id);
+            let restarted = previous.is_none_or(|voice| voice.play_count != source.play_count());
+            let wanted = source.playing() && source.sound().is_some();
+            let voice = previous.and_then(|previous| previous.voice);
+            let previous_pitch = previous.and_then(|previous| previous.random_pitch);
+            if (!wanted || restarted)
+                && let Some(voice) = voice
+            {
+                self.mixer().stop(voice);
+            }
+            if !wanted {
+                self.sources.insert(
+                    component_id,
+                    SourceVoice {
+                        voice: None,
+                        play_count: source.play_count(),
+                        random_pitch: None,
+                    },
+                );
+                continue;
+            }
+
+            let (volume, pan) = if source.spatial() {
+                let position = objects
+                    .world_position(object_id)
+                    .unwrap_or(Vec2::new(0.0, 0.0));
+                let (gain, pan) = spatial_gain_and_pan(
+                    position,
+                    self.listener_position,
+                    source.attenuation(),
+                    self.pan_width,
+                );
+                (source.volume() * gain, pan)
+            } else {
+                (source.volume(), 0.0)
+            };
+            // A random pitch is picked once per play and kept while it lasts.
+            let random_pitch = match source.pitch_range() {
+                Some(range) if restarted => Some(self.random_pitch(range)),
+                Some(_) => previous_pitch,
+                None => None,
+            };
+            let params = VoiceParams {
+                volume,
+                pan,
+                looping: source.looping(),
+                pitch: random_pitch.unwrap_or(source.pitch()),
+                bus: source.bus(),
+                priority: source.priority(),
+            };
+
+            if !restarted {
+                // A copy of the component synced after its sound ended still
+                // says it is playing; only `play` starts it again.
+                if !voice.is_some_and(|voice| self.mixer().set_params(voice, params)) {
+                    self.sources.insert(
+                        component_id,
+                        SourceVoice {
+                            voice: None,
+                            play_count: source.play_count(),
+                            random_pitch: None,
+                        },
+                    );
+                    set_source_playing(objects, object_id, component_id, false);
+                }
+                continue;
+            }
+
+            let voice = match self.sound(assets, source.sound().unwrap_or_default()) {
+                Ok(sound) => {
+                    self.ensure_output();
+                    Some(self.mixer().play(sound, params))
+                }
+                Err(err) => {
+                    logging::log_error(&format!("Audio source '{}': {err}", source.name()));
+                    set_source_playing(objects, object_id, component_id, false);
+                    None
+                }
+            };
+            self.sources.insert(
+                component_id,
+                SourceVoice {
+                    voice,
+                    play_count: source.play_count(),
+                    random_pitch,
+                },
+            );
+        }
+    }
+}
+
+fn set_source_playing(
+    objects: &mut ObjectManager,
+    object_id: u32,
+    component_id: u32,
+    playing: bool,
+) {
+    if let Some(source) = objects
+        .get_object_by_id_mut(object_id)
+        .and_then(|object| object.get_component_by_id_mut(component_id))
+        .and_then(|component| {
+            component
+                .as_any_mut()
+                .downcast_mut::<AudioSourceComponent>()
+        })
+    {
+        source.set_playing(playing);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game_object::GameObject;
+
+    fn object_with(
+        objects: &mut ObjectManager,
+        position: Vec2,
+        component: Box<dyn ComponentTrait>,
+    ) -> u32 {
+        let mut object = GameObject::new_named("Audio".to_string());
+        object.set_position(position);
+        object.add_component(component);
+        objects.add_object(object).unwrap()
+    }
+
+    fn tone(seconds: f32) -> Arc<Sound> {
+        let frames = (seconds * 1000.0) as usize;
+        Arc::new(Sound::from_samples(1000, 1, vec![0.5; frames]))
+    }
+
+    #[test]
+    fn test_sources_follow_the_listener_and_stop_when_done() {
+        let mut audio = AudioSystem::new();
+        // No device in tests; keep `update` from trying to open one.
+        audio.output_tried = true;
+        let mut assets = AssetManager::new();
+        let mut objects = ObjectManager::new();
+
+        let mut source = AudioSourceComponent::new("Beep");
+        source.set_sound(Some("beep.wav".to_string()));
+        source.play();
+        let source_id = source.id();
+        let object_id = object_with(&mut objects, Vec2::new(250.0, 0.0), Box::new(source));
+        object_with(
+            &mut objects,
+            Vec2::new(0.0, 0.0),
+            Box::new(AudioListenerComponent::new("Ears")),
+        );
+        let resolved = assets.resolve_path("beep.wav");
+        audio.sounds.insert(resolved, tone(0.1));
+
+        audio.update(0.0, &mut objects, &mut assets, Vec2::new(1000.0, 0.0));
+        assert!(audio.is_source_playing(source_id));
+        let voice = audio.sources[&source_id].voice.unwrap();
+        let params = audio.mixer().params(voice).unwrap();
+        // Default linear attenuation from 100 to 1000 units, panned by the listener's 500 unit width
+        assert!((params.volume - 5.0 / 6.0).abs() < 1e-6);
+        assert_eq!(params.pan, 0.5);
+
+        let finished = audio.update(0.2, &mut objects, &mut assets, Vec2::new(1000.0, 0.0));
+        assert_eq!(finished, vec![voice]);
+        assert_eq!(audio.take_finished(), vec![voice]);
+        assert!(audio.take_finished().is_empty());
+        assert!(!audio.is_source_playing(source_id));
+        let object = objects.get_object_by_id_mut(object_id).unwrap();
+        let source = object.get_component_mut::<AudioSourceComponent>().unwrap();
+        assert!(!source.playing());
+
+        // A stale copy still marked playing does not restart it; `play` does.
+        source.set_playing(true);
+        audio.update(0.0, &mut objects, &mut assets, Vec2::new(1000.0, 0.0));
+        assert!(!audio.is_source_playing(source_id));
+        let object = objects.get_object_by_id_mut(object_id).unwrap();
+        object
+            .get_component_mut::<AudioSourceComponent>()
+            .unwrap()
+            .play();
+        audio.update(0.0, &mut objects, &mut assets, Vec2::new(1000.0, 0.0));
+        assert!(audio.is_source_playing(source_id));
+    }
+
+    #[test]
+    fn test_positional_voices_use_the_camera_without_a_listener() {
+        let mut audio = AudioSystem::new();
+        audio.output_tried = true;
+        let mut assets = AssetManager::new();
+        let mut objects = ObjectManager::new();
+
+        let id = audio.play(
+            tone(1.0),
+            VoiceParams::default(),
+            Some((Vec2::new(-500.0, 0.0), Attenuation::default())),
+        );
+        audio.update(0.0, &mut objects, &mut assets, Vec2::new(-500.0, 0.0));
+        assert_eq!(audio.listener_position(), Vec2::new(-500.0, 0.0));
+        let params = audio.mixer().params(id).unwrap();
+        assert_eq!((params.volume, params.pan), (1.0, 0.0));
+
+        assert!(audio.stop(id));
+        assert!(!audio.is_playing(id));
+    }
+
+    #[test]
+    fn test_snapshot_transitions_run_on_the_update_clock() {
+        use crate::core::audio::snapshot::BusOverrides;
+
+        let mut audio = AudioSystem::new();
+        audio.output_tried = true;
+        let mut assets = AssetManager::new();
+        let mut objects = ObjectManager::new();
+        let menu = AudioSnapshot::new().with_bus(
+            Bus::Music,
+            BusOverrides {
+                volume: Some(0.2),
+                ..BusOverrides::default()
+            },
+        );
+        audio.add_snapshot("menu", menu);
+        assert!(audio.transition_to("missing", 1.0).is_err());
+
+        audio.transition_to("menu", 1.0).unwrap();
+        assert_eq!(audio.current_snapshot(), Some("menu"));
+        audio.update(0.5, &mut objects, &mut assets, Vec2::new(0.0, 0.0));
+        assert!((audio.bus_settings(Bus::Music).volume - 0.6).abs() < 1e-6);
+        assert!(audio.is_transitioning());
+        audio.update(0.5, &mut objects, &mut assets, Vec2::new(0.0, 0.0));
+        assert_eq!(audio.bus_settings(Bus::Music).volume, 0.2);
+        assert!(!audio.is_transitioning());
+
+        audio.add_snapshot(
+            "default",
+            AudioSnapshot::new().with_bus(
+                Bus::Music,
+                BusOverrides {
+                    volume: Some(1.0),
+                    ..BusOverrides::default()
+                },
+            ),
+        );
+        audio.transition_to("default", 0.0).unwrap();
+        assert_eq!(audio.bus_settings(Bus::Music).volume, 1.0);
+        assert_eq!(audio.snapshot_names(), vec!["default", "menu"]);
+    }
+
+    #[test]
+    fn test_sources_keep_the_random_pitch_picked_on_play() {
+        let mut audio = AudioSystem::new();
+        audio.output_tried = true;
+        for _ in 0..100 {
+            let pitch = audio.random_pitch((0.9, 1.1));
+            assert!((0.9..=1.1).contains(&pitch));
+        }
+
+        let mut assets = AssetManager::new();
+        let mut objects = ObjectManager::new();
+        let mut source = AudioSourceComponent::new("Steps")
+            .with_sound(Some("step.wav".to_string()))
+            .with_pitch_range(Some((1.5, 2.0)))
+            .with_spatial(false);
+        source.play();
+        let source_id = source.id();
+        object_with(&mut objects, Vec2::new(0.0, 0.0), Box::new(source));
+        audio
+            .sounds
+            .insert(assets.resolve_path("step.wav"), tone(1.0));
+
+        audio.update(0.0, &mut objects, &mut assets, Vec2::new(0.0, 0.0));
+        let voice = audio.sources[&source_id].voice.unwrap();
+        let pitch = audio.mixer().params(voice).unwrap().pitch;
+        assert!((1.5..=2.0).contains(&pitch));
+        audio.update(0.1, &mut objects, &mut assets, Vec2::new(0.0, 0.0));
+        assert_eq!(audio.mixer().params(voice).unwrap().pitch, pitch);
+    }
+}
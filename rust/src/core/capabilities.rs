@@ -0,0 +1,188 @@
+//! Engine and GPU capability reporting.
+//!
+//! Lets games gate optional effects on what the hardware supports and gives
+//! bug reports the adapter, driver and limits they were produced on.
+
+use wgpu::{AstcBlock, AstcChannel, DeviceType, PresentMode, TextureFormat, TextureUsages};
+
+/// Formats probed for `texture_formats`: the common color, HDR and depth
+/// formats plus the block-compressed families, so a report shows which
+/// compression the adapter could use.
+const PROBED_TEXTURE_FORMATS: &[TextureFormat] = &[
+    TextureFormat::R8Unorm,
+    TextureFormat::Rg8Unorm,
+    TextureFormat::Rgba8Unorm,
+    TextureFormat::Rgba8UnormSrgb,
+    TextureFormat::Bgra8Unorm,
+    TextureFormat::Bgra8UnormSrgb,
+    TextureFormat::Rgb10a2Unorm,
+    TextureFormat::Rgba16Float,
+    TextureFormat::Rgba32Float,
+    TextureFormat::Depth32Float,
+    TextureFormat::Depth24PlusStencil8,
+    TextureFormat::Bc1RgbaUnorm,
+    TextureFormat::Bc3RgbaUnorm,
+    TextureFormat::Bc7RgbaUnorm,
+    TextureFormat::Etc2Rgb8Unorm,
+    TextureFormat::Astc {
+        block: AstcBlock::B4x4,
+        channel: AstcChannel::Unorm,
+    },
+];
+
+/// What the GPU adapter and window surface support.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RendererCapabilities {
+    /// Adapter name, e.g. "NVIDIA GeForce RTX 3060"
+    pub adapter_name: String,
+    /// Graphics API in use: "vulkan", "metal", "dx12", "gl", ...
+    pub backend: String,
+    /// "discrete_gpu", "integrated_gpu", "virtual_gpu", "cpu" or "other"
+    pub device_type: String,
+    pub vendor_id: u32,
+    pub device_id: u32,
+    pub driver: String,
+    pub driver_info: String,
+    /// Largest width or height of a 2D texture, in pixels
+    pub max_texture_size: u32,
+    /// Probed formats the adapter can sample from, e.g. "Rgba8UnormSrgb"
+    pub texture_formats: Vec<String>,
+    /// Formats the window surface can present
+    pub surface_formats: Vec<String>,
+    /// Format the surface was configured with
+    pub surface_format: String,
+    /// MSAA sample counts supported for the surface format, including 1
+    pub msaa_sample_counts: Vec<u32>,
+    /// Present modes the surface supports, e.g. "fifo", "mailbox"
+    pub present_modes: Vec<String>,
+    /// Present mode currently in use
+    pub present_mode: String,
+    /// Running on the software fallback adapter because no usable GPU was found
+    pub safe_mode: bool,
+}
+
+impl RendererCapabilities {
+    pub fn query(
+        adapter: &wgpu::Adapter,
+        device: &wgpu::Device,
+        surface_caps: &wgpu::SurfaceCapabilities,
+        surface_format: TextureFormat,
+        present_mode: PresentMode,
+    ) -> Self {
+        let info = adapter.get_info();
+        let texture_formats = PROBED_TEXTURE_FORMATS
+            .iter()
+            .filter(|format| {
+                adapter
+                    .get_texture_format_features(**format)
+                    .allowed_usages
+                    .contains(TextureUsages::TEXTURE_BINDING)
+            })
+            .map(|format| format!("{format:?}"))
+            .collect();
+
+        Self {
+            adapter_name: info.name,
+            backend: info.backend.to_str().to_string(),
+            device_type: device_type_name(info.device_type).to_string(),
+            vendor_id: info.vendor,
+            device_id: info.device,
+            driver: info.driver,
+            driver_info: info.driver_info,
+            max_texture_size: device.limits().max_texture_dimension_2d,
+            texture_formats,
+            surface_formats: surface_caps.formats.iter().map(|format| format!("{format:?}")).collect(),
+            surface_format: format!("{surface_format:?}"),
+            msaa_sample_counts: adapter
+                .get_texture_format_features(surface_format)
+                .flags
+                .supported_sample_counts(),
+            present_modes: surface_caps
+                .present_modes
+                .iter()
+                .map(|mode| present_mode_name(*mode).to_string())
+                .collect(),
+            present_mode: present_mode_name(present_mode).to_string(),
+            safe_mode: false,
+        }
+    }
+}
+
+/// Engine build and runtime capabilities, see [`crate::core::engine::Engine::capabilities`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct EngineCapabilities {
+    /// Engine crate version, e.g. "1.3.2"
+    pub engine_version: String,
+    /// `None` until the window and renderer have been created
+    pub renderer: Option<RendererCapabilities>,
+    /// Enabled engine features: compile-time features such as
+    /// "python-bindings" and active modes such as "vsync" or "pixel_perfect"
+    pub features: Vec<String>,
+}
+
+pub fn present_mode_name(mode: PresentMode) -> &'static str {
+    match mode {
+        PresentMode::AutoVsync => "auto_vsync",
+        PresentMode::AutoNoVsync => "auto_no_vsync",
+        PresentMode::Fifo => "fifo",
+        PresentMode::FifoRelaxed => "fifo_relaxed",
+        PresentMode::Immediate => "immediate",
+        PresentMode::Mailbox => "mailbox",
+    }
+}
+
+fn device_type_name(device_type: DeviceType) -> &'static str {
+    match device_type {
+        DeviceType::Other => "other",
+        DeviceType::IntegratedGpu => "integrated_gpu",
+        DeviceType::DiscreteGpu => "discrete_gpu",
+        DeviceType::VirtualGpu => "virtual_gpu",
+        DeviceType::Cpu => "cpu",
+    }
+}
+
+/// Multi-line summary suitable for pasting into a bug report.
+impl std::fmt::Display for EngineCapabilities {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "pyg_engine {}", self.engine_version)?;
+        writeln!(f, "features: {}", self.features.join(", "))?;
+        let Some(renderer) = &self.renderer else {
+            return write!(f, "renderer: not initialized");
+        };
+        writeln!(
+            f,
+            "adapter: {} ({}, {}, vendor {:#06x}, device {:#06x}){}",
+            renderer.adapter_name,
+            renderer.backend,
+            renderer.device_type,
+            renderer.vendor_id,
+            renderer.device_id,
+            if renderer.safe_mode { " [safe mode]" } else { "" }
+        )?;
+        writeln!(f, "driver: {} {}", renderer.driver, renderer.driver_info)?;
+        writeln!(f, "max texture size: {}", renderer.max_texture_size)?;
+        writeln!(f, "surface format: {} (of {})", renderer.surface_format, renderer.surface_formats.join(", "))?;
+        writeln!(f, "texture formats: {}", renderer.texture_formats.join(", "))?;
+        let msaa: Vec<String> = renderer.msaa_sample_counts.iter().map(u32::to_string).collect();
+        writeln!(f, "msaa sample counts: {}", msaa.join(", "))?;
+        write!(f, "present mode: {} (of {})", renderer.present_mode, renderer.present_modes.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_without_renderer() {
+        let capabilities = EngineCapabilities {
+            engine_version: "1.2.3".to_string(),
+            renderer: None,
+            features: vec!["python-bindings".to_string(), "vsync".to_string()],
+        };
+        assert_eq!(
+            capabilities.to_string(),
+            "pyg_engine 1.2.3\nfeatures: python-bindings, vsync\nrenderer: not initialized"
+        );
+    }
+}
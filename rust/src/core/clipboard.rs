@@ -0,0 +1,32 @@
+//! System clipboard access through arboard.
+//!
+//! The clipboard is opened on first use and kept open for the life of the
+//! process: on X11 and Wayland the copied text is served by whoever copied
+//! it, so dropping the handle right after `set_text` would lose the text.
+
+use std::sync::Mutex;
+
+static CLIPBOARD: Mutex<Option<arboard::Clipboard>> = Mutex::new(None);
+
+fn with_clipboard<T>(f: impl FnOnce(&mut arboard::Clipboard) -> Result<T, arboard::Error>) -> Result<T, String> {
+    let mut guard = CLIPBOARD.lock().map_err(|_| "Clipboard lock poisoned".to_string())?;
+    if guard.is_none() {
+        let clipboard = arboard::Clipboard::new().map_err(|e| format!("Clipboard unavailable: {e}"))?;
+        *guard = Some(clipboard);
+    }
+    let clipboard = guard.as_mut().expect("clipboard was just opened");
+    f(clipboard).map_err(|e| format!("Clipboard error: {e}"))
+}
+
+/// Text on the clipboard, or an empty string when it holds no text.
+pub fn get_text() -> Result<String, String> {
+    with_clipboard(|clipboard| match clipboard.get_text() {
+        Err(arboard::Error::ContentNotAvailable) => Ok(String::new()),
+        result => result,
+    })
+}
+
+/// Put text on the clipboard.
+pub fn set_text(text: &str) -> Result<(), String> {
+    with_clipboard(|clipboard| clipboard.set_text(text))
+}
@@ -1,12 +1,23 @@
 use super::draw_manager::DrawCommand;
+use super::event_bus::EventPayload;
 use super::game_object::GameObject;
 use super::render_manager::CameraAspectMode;
+use crate::core::audio::{AudioListenerComponent, AudioSourceComponent};
 use crate::core::component::ComponentTrait;
-use crate::core::component::{MeshComponent, TextMeshComponent};
+use crate::core::component::{MeshComponent, TextMeshComponent, WorldLabelComponent};
+use crate::core::curve::AnimationCurve;
+use crate::core::ui::image::ImageScaleMode;
+use crate::core::ui::layout::UILayoutComponent;
+use crate::core::ui::tween::UITween;
+use crate::core::ui::style::FocusRing;
+use crate::core::ui_manager::UIScaleMode;
+use crate::core::ui::stack::{StackAlignment, StackDirection};
+use crate::core::ui::style::Padding;
 use crate::core::text::{FontFamilyDefinition, TextLayoutOptions, TextStyle};
 use crate::types::Color;
 use crate::types::vector::Vec2;
 use std::sync::Arc;
+use winit::window::CursorIcon;
 
 /// Commands that can be sent to the engine from any thread
 #[derive(Debug)]
@@ -17,12 +28,40 @@ pub enum EngineCommand {
     /// Remove a game object by ID
     RemoveGameObject(u32),
 
+    /// Queue an event on the event bus
+    EmitEvent { name: String, payload: EventPayload },
+
+    /// Send a message to the components of a runtime GameObject
+    SendMessage {
+        target_id: u32,
+        name: String,
+        payload: EventPayload,
+    },
+
+    /// End the game loop after the current frame
+    RequestQuit,
+
+    /// Set the game time scale
+    SetTimeScale(f32),
+
+    /// Pause or resume game time
+    SetPaused(bool),
+
+    /// Set the seconds of game time per fixed step
+    SetFixedTimestep(f32),
+
     /// Update a runtime GameObject position by id
     SetGameObjectPosition { object_id: u32, position: Vec2 },
 
     /// Update a runtime GameObject name by id
     SetGameObjectName { object_id: u32, name: String },
 
+    /// Add a tag to a runtime GameObject by id
+    AddGameObjectTag { object_id: u32, tag: String },
+
+    /// Remove a tag from a runtime GameObject by id
+    RemoveGameObjectTag { object_id: u32, tag: String },
+
     /// Update a runtime GameObject enabled state by id
     SetGameObjectEnabled { object_id: u32, enabled: bool },
 
@@ -45,6 +84,27 @@ pub enum EngineCommand {
         component: TextMeshComponent,
     },
 
+    /// Replace a runtime WorldLabel component on an object by component id
+    SetWorldLabelComponent {
+        object_id: u32,
+        component_id: u32,
+        component: WorldLabelComponent,
+    },
+
+    /// Replace a runtime AudioSource component on an object by component id
+    SetAudioSourceComponent {
+        object_id: u32,
+        component_id: u32,
+        component: AudioSourceComponent,
+    },
+
+    /// Replace a runtime AudioListener component on an object by component id
+    SetAudioListenerComponent {
+        object_id: u32,
+        component_id: u32,
+        component: AudioListenerComponent,
+    },
+
     /// Parent a child object under another object
     AddChild { parent_id: u32, child_id: u32 },
 
@@ -63,6 +123,13 @@ pub enum EngineCommand {
     /// Remove a component by id from a runtime object
     RemoveComponentById { object_id: u32, component_id: u32 },
 
+    /// Enable or disable a component, matched by name or type, on a runtime object
+    SetComponentEnabled {
+        object_id: u32,
+        key: String,
+        enabled: bool,
+    },
+
     /// Update the active camera world position
     SetCameraPosition { position: Vec2 },
 
@@ -169,6 +236,55 @@ pub enum EngineCommand {
     /// Update a UI button's text by object ID
     UpdateUIButtonText { object_id: u32, text: String },
 
+    /// Update a UI text input's text by object ID
+    UpdateUITextInputText { object_id: u32, text: String },
+
+    /// Update a UI slider's value by object ID
+    UpdateUISliderValue { object_id: u32, value: f32 },
+
+    /// Update a UI checkbox, toggle switch or radio button's checked state by object ID
+    UpdateUIChecked { object_id: u32, checked: bool },
+
+    /// Update a UI scroll view's (or, vertically, a list view's) scroll offset by object ID
+    UpdateUIScrollPosition { object_id: u32, x: f32, y: f32 },
+
+    /// Update a UI progress bar's value by object ID
+    UpdateUIProgressValue { object_id: u32, value: f32 },
+
+    /// Turn clipping of a UI panel's children to its bounds on or off by object ID
+    UpdateUIPanelClip { object_id: u32, clip_children: bool },
+
+    /// Update a UI image's texture by object ID (`None` hides it)
+    UpdateUIImageTexture { object_id: u32, texture_path: Option<String> },
+
+    /// Update a UI image's scale mode by object ID
+    UpdateUIImageScaleMode { object_id: u32, scale_mode: ImageScaleMode },
+
+    /// Attach, replace or (with `None`) remove a UI object's anchor layout by object ID
+    SetUILayout { object_id: u32, layout: Option<UILayoutComponent> },
+
+    /// Update a UI stack's arrangement settings by object ID
+    UpdateUIStack {
+        object_id: u32,
+        direction: StackDirection,
+        spacing: f32,
+        padding: Padding,
+        alignment: StackAlignment,
+        fit_content: bool,
+    },
+
+    /// Replace a UI curve editor's curve by object ID
+    UpdateUICurve { object_id: u32, curve: AnimationCurve },
+
+    /// Update a UI list view's item count by object ID
+    UpdateUIListItemCount { object_id: u32, item_count: usize },
+
+    /// Select a UI list view row (or clear the selection) and scroll it into view by object ID
+    UpdateUIListSelection { object_id: u32, selected: Option<usize> },
+
+    /// Fetch a UI list view's visible rows from its item provider again by object ID
+    RefreshUIList { object_id: u32 },
+
     /// Draw text with optional custom font (helper wrapper around AddDrawCommand)
     DrawText {
         text: String,
@@ -186,6 +302,39 @@ pub enum EngineCommand {
         definition: FontFamilyDefinition,
     },
 
+    /// Set the renderer's default fallback fonts (family names or font paths).
+    SetFontFallbacks(Vec<String>),
+
+    /// Set the UI theme's fallback fonts (family names or font paths).
+    SetUIFontFallbacks(Vec<String>),
+
+    /// Switch the UI to a registered theme by name.
+    SetUITheme(String),
+
+    /// Set how UI units map to window pixels.
+    SetUIScaleMode(UIScaleMode),
+
+    /// Give a UI widget keyboard focus, or clear focus with `None`.
+    SetUIFocus(Option<u32>),
+
+    /// Grey out a UI element and stop it taking input, or restore it.
+    SetUIInteractable { object_id: u32, interactable: bool },
+
+    /// Set the cursor shown while hovering a UI element, or remove it.
+    SetUIHoverCursor { object_id: u32, cursor: Option<CursorIcon> },
+
+    /// Limit a UI element's input to an input context, or remove the limit.
+    SetUIInputContext { object_id: u32, context: Option<String> },
+
+    /// Set the outline drawn around the focused UI widget.
+    SetUIFocusRing(FocusRing),
+
+    /// Start animating a UI element's property
+    StartUITween(UITween),
+
+    /// Stop a UI tween by id
+    CancelUITween(u32),
+
     /// Log a message at TRACE level
     LogTrace(String),
 
@@ -201,3 +350,34 @@ pub enum EngineCommand {
     /// Log a message at ERROR level
     LogError(String),
 }
+
+impl EngineCommand {
+    /// Object whose UI rendering this command can change, if any.
+    ///
+    /// Used to invalidate the object's retained UI draw commands.
+    pub fn ui_object_id(&self) -> Option<u32> {
+        match self {
+            EngineCommand::AddComponent { object_id, .. }
+            | EngineCommand::RemoveComponentByName { object_id, .. }
+            | EngineCommand::RemoveComponentById { object_id, .. }
+            | EngineCommand::SetComponentEnabled { object_id, .. }
+            | EngineCommand::UpdateUILabelText { object_id, .. }
+            | EngineCommand::UpdateUIButtonText { object_id, .. }
+            | EngineCommand::UpdateUITextInputText { object_id, .. }
+            | EngineCommand::UpdateUISliderValue { object_id, .. }
+            | EngineCommand::UpdateUIChecked { object_id, .. }
+            | EngineCommand::UpdateUIScrollPosition { object_id, .. }
+            | EngineCommand::UpdateUIProgressValue { object_id, .. }
+            | EngineCommand::UpdateUIPanelClip { object_id, .. }
+            | EngineCommand::UpdateUIImageTexture { object_id, .. }
+            | EngineCommand::UpdateUIImageScaleMode { object_id, .. }
+            | EngineCommand::SetUILayout { object_id, .. }
+            | EngineCommand::UpdateUIStack { object_id, .. }
+            | EngineCommand::UpdateUICurve { object_id, .. }
+            | EngineCommand::UpdateUIListItemCount { object_id, .. }
+            | EngineCommand::UpdateUIListSelection { object_id, .. }
+            | EngineCommand::RefreshUIList { object_id } => Some(*object_id),
+            _ => None,
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::event_bus::EventPayload;
 use super::time::Time;
 use super::text::{FontDescriptor, FontStyle, FontWeight, TextStyle};
 use crate::types::color::Color;
@@ -70,6 +71,23 @@ pub trait ComponentTrait: Send + Sync + std::fmt::Debug {
 
     /// Called when a collision ends
     fn on_collision_exit(&self, _other_id: u32) {}
+
+    // Messaging (optional)
+    /// Called during the update phase for each message sent to the object
+    /// with `Engine::send_message`
+    fn on_message(&self, _name: &str, _payload: &EventPayload) {}
+
+    // Scheduling (optional)
+    /// Whether `update`/`fixed_update` may run on a worker thread of the
+    /// parallel update passes.
+    ///
+    /// Returning true declares that both only read the shared `Time` and
+    /// write the component's own state, never another object or Python.
+    /// Components run on the main thread unless they opt in, and an object
+    /// joins the parallel passes only when all of its components do.
+    fn runs_in_parallel(&self) -> bool {
+        false
+    }
 }
 
 impl Clone for Box<dyn ComponentTrait> {
@@ -683,6 +701,217 @@ impl TextMeshComponent {
     }
 }
 
+/// Text drawn at its object's world position, for nameplates and debug
+/// annotations that follow an entity.
+///
+/// Unlike `TextMeshComponent`, the label sits `offset` world units from the
+/// object (e.g. above its head) and, when `billboard` is set, stays upright and
+/// unscaled whatever the object's rotation and scale. With `scale_with_zoom`
+/// (the default) one text pixel is one world unit, so the label grows and
+/// shrinks with the camera; without it the label keeps its font size on screen.
+#[derive(Clone, Debug)]
+pub struct WorldLabelComponent {
+    component_id: u32,
+    name: String,
+    text: String,
+    color: Color,
+    text_style: TextStyle,
+    offset: Vec2,
+    billboard: bool,
+    scale_with_zoom: bool,
+    visible: bool,
+    draw_order: f32,
+    enabled_self: bool,
+    enabled_in_hierarchy: bool,
+}
+
+impl ComponentTrait for WorldLabelComponent {
+    fn new(name: String) -> Self {
+        Self {
+            component_id: next_component_id(),
+            name,
+            text: String::new(),
+            color: Color::WHITE,
+            text_style: TextStyle::new(24.0),
+            offset: Vec2::new(0.0, 0.0),
+            billboard: true,
+            scale_with_zoom: true,
+            visible: true,
+            draw_order: 0.0,
+            enabled_self: true,
+            enabled_in_hierarchy: true,
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn id(&self) -> u32 {
+        self.component_id
+    }
+
+    fn component_type(&self) -> &'static str {
+        "WorldLabel"
+    }
+
+    fn is_enabled_self(&self) -> bool {
+        self.enabled_self
+    }
+
+    fn set_enabled_self(&mut self, enabled: bool) {
+        self.enabled_self = enabled;
+    }
+
+    fn is_enabled_in_hierarchy(&self) -> bool {
+        self.enabled_in_hierarchy
+    }
+
+    fn set_enabled_in_hierarchy(&mut self, enabled: bool) {
+        self.enabled_in_hierarchy = enabled;
+    }
+
+    fn update(&self, _time: &Time) {}
+    fn fixed_update(&self, _time: &Time, _fixed_time: f32) {}
+    fn on_start(&self) {}
+    fn on_destroy(&self) {}
+    fn on_enable(&self) {}
+    fn on_disable(&self) {}
+
+    fn clone_component(&self) -> Box<dyn ComponentTrait> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl WorldLabelComponent {
+    pub fn new(name: impl Into<String>) -> Self {
+        <Self as ComponentTrait>::new(name.into())
+    }
+
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn with_font_size(mut self, font_size: f32) -> Self {
+        self.text_style.font_size = font_size.max(1.0);
+        self
+    }
+
+    pub fn with_offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_billboard(mut self, billboard: bool) -> Self {
+        self.billboard = billboard;
+        self
+    }
+
+    pub fn with_scale_with_zoom(mut self, scale_with_zoom: bool) -> Self {
+        self.scale_with_zoom = scale_with_zoom;
+        self
+    }
+
+    pub fn with_draw_order(mut self, draw_order: f32) -> Self {
+        self.draw_order = draw_order;
+        self
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = text.into();
+    }
+
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    pub fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+
+    pub fn font_size(&self) -> f32 {
+        self.text_style.font_size
+    }
+
+    pub fn set_font_size(&mut self, font_size: f32) {
+        self.text_style.font_size = font_size.max(1.0);
+    }
+
+    pub fn text_style(&self) -> &TextStyle {
+        &self.text_style
+    }
+
+    pub fn set_text_style(&mut self, text_style: TextStyle) {
+        self.text_style = text_style;
+        self.text_style.font_size = self.text_style.font_size.max(1.0);
+    }
+
+    /// Offset from the object's world position, in world units
+    pub fn offset(&self) -> Vec2 {
+        self.offset
+    }
+
+    pub fn set_offset(&mut self, offset: Vec2) {
+        self.offset = offset;
+    }
+
+    /// Whether the label ignores the object's rotation and scale
+    pub fn billboard(&self) -> bool {
+        self.billboard
+    }
+
+    pub fn set_billboard(&mut self, billboard: bool) {
+        self.billboard = billboard;
+    }
+
+    /// Whether the label scales with camera zoom instead of keeping its screen size
+    pub fn scale_with_zoom(&self) -> bool {
+        self.scale_with_zoom
+    }
+
+    pub fn set_scale_with_zoom(&mut self, scale_with_zoom: bool) {
+        self.scale_with_zoom = scale_with_zoom;
+    }
+
+    pub fn visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    pub fn draw_order(&self) -> f32 {
+        self.draw_order
+    }
+
+    pub fn set_draw_order(&mut self, draw_order: f32) {
+        self.draw_order = draw_order;
+    }
+}
+
 #[derive(Debug)]
 pub struct SpriteComponent {
     component_id: u32,
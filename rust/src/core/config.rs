@@ -0,0 +1,392 @@
+//! Project configuration read from `pyg_engine.toml`.
+//!
+//! A project can keep its engine settings in a `pyg_engine.toml` next to its
+//! code instead of hard-coding them. Every key is optional; settings left out
+//! keep the engine's defaults, and values passed in code (the window size
+//! given to `run`, say) override the file.
+//!
+//! ```toml
+//! [window]
+//! title = "My Game"
+//! width = 1280
+//! height = 720
+//! resizable = true
+//! vsync = true
+//! min_width = 640
+//! min_height = 480
+//! show_fps_in_title = false
+//!
+//! [logging]
+//! level = "DEBUG"       # TRACE, DEBUG, INFO, WARN or ERROR
+//! file = true           # also write log files
+//! directory = "logs"    # relative to the config file
+//!
+//! [time]
+//! fixed_timestep = 0.02 # seconds per fixed update
+//! max_fixed_steps = 5
+//! target_fps = 60       # frame cap, 0 for uncapped
+//!
+//! [assets]
+//! root = "game"         # relative asset paths resolve here
+//!
+//! [physics]
+//! collisions = true     # run collision detection each fixed step
+//!
+//! [profiler]
+//! enabled = true
+//! frame_budget_ms = 16.7 # warn about frames with more work than this
+//! spike_factor = 3       # warn about frames 3x slower than the average
+//! ```
+//!
+//! Paths in the file are relative to the file's directory. Unknown keys are
+//! reported as warnings so typos do not go unnoticed; a file that cannot be
+//! read or has a wrongly typed value is reported and ignored as a whole.
+
+use super::window_manager::WindowConfig;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+/// Name of the file `discover` looks for.
+pub const CONFIG_FILE_NAME: &str = "pyg_engine.toml";
+
+/// Settings of the `[window]` table.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WindowSettings {
+    pub title: Option<String>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub resizable: Option<bool>,
+    pub vsync: Option<bool>,
+    pub min_width: Option<u32>,
+    pub min_height: Option<u32>,
+    pub show_fps_in_title: Option<bool>,
+}
+
+/// Settings of the `[logging]` table.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LogSettings {
+    pub level: Option<String>,
+    pub file: Option<bool>,
+    pub directory: Option<PathBuf>,
+}
+
+/// Settings of the `[time]` table.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TimeSettings {
+    pub fixed_timestep: Option<f32>,
+    pub max_fixed_steps: Option<u32>,
+    /// `Some(None)` when the file asks for an uncapped frame rate
+    pub target_fps: Option<Option<f32>>,
+}
+
+/// Settings of the `[physics]` table.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PhysicsSettings {
+    pub collisions: Option<bool>,
+}
+
+/// Settings of the `[profiler]` table.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProfilerSettings {
+    pub enabled: Option<bool>,
+    pub frame_budget_ms: Option<f32>,
+    pub spike_factor: Option<f32>,
+}
+
+/// Engine settings from a config file; unset values keep engine defaults.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EngineConfig {
+    /// File the settings were read from
+    pub path: Option<PathBuf>,
+    pub window: WindowSettings,
+    pub logging: LogSettings,
+    pub time: TimeSettings,
+    /// Directory relative asset paths resolve against, from `[assets] root`
+    pub asset_root: Option<PathBuf>,
+    pub physics: PhysicsSettings,
+    pub profiler: ProfilerSettings,
+    /// Problems found while reading, logged once logging is set up
+    pub warnings: Vec<String>,
+}
+
+impl EngineConfig {
+    /// Read `pyg_engine.toml` from the working directory if there is one.
+    pub fn discover() -> Self {
+        let path = PathBuf::from(CONFIG_FILE_NAME);
+        if path.is_file() {
+            Self::load_or_default(&path)
+        } else {
+            Self::default()
+        }
+    }
+
+    /// Read a config file, falling back to defaults with a warning on errors.
+    pub fn load_or_default(path: &Path) -> Self {
+        Self::load(path).unwrap_or_else(|err| Self {
+            warnings: vec![format!("Ignoring config file: {err}")],
+            ..Self::default()
+        })
+    }
+
+    /// Read a config file.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read '{}': {e}", path.display()))?;
+        let base_dir = path.parent().unwrap_or(Path::new(""));
+        let mut config = Self::parse(&text, base_dir)
+            .map_err(|e| format!("{e} in '{}'", path.display()))?;
+        config.path = Some(path.to_path_buf());
+        Ok(config)
+    }
+
+    /// Parse config text; relative paths in it resolve against `base_dir`.
+    pub fn parse(text: &str, base_dir: &Path) -> Result<Self, String> {
+        let table: Table = text.parse().map_err(|e| format!("invalid TOML: {e}"))?;
+        let mut config = Self::default();
+        let mut unknown = Vec::new();
+
+        for (section, value) in &table {
+            let Value::Table(fields) = value else {
+                unknown.push(section.clone());
+                continue;
+            };
+            match section.as_str() {
+                "window" => {
+                    let window = &mut config.window;
+                    for (key, value) in fields {
+                        let context = format!("window.{key}");
+                        match key.as_str() {
+                            "title" => window.title = Some(string(value, &context)?),
+                            "width" => window.width = Some(positive_u32(value, &context)?),
+                            "height" => window.height = Some(positive_u32(value, &context)?),
+                            "resizable" => window.resizable = Some(boolean(value, &context)?),
+                            "vsync" => window.vsync = Some(boolean(value, &context)?),
+                            "min_width" => window.min_width = Some(positive_u32(value, &context)?),
+                            "min_height" => window.min_height = Some(positive_u32(value, &context)?),
+                            "show_fps_in_title" => {
+                                window.show_fps_in_title = Some(boolean(value, &context)?)
+                            }
+                            _ => unknown.push(context),
+                        }
+                    }
+                }
+                "logging" => {
+                    let logging = &mut config.logging;
+                    for (key, value) in fields {
+                        let context = format!("logging.{key}");
+                        match key.as_str() {
+                            "level" => {
+                                let level = string(value, &context)?.to_uppercase();
+                                if !["TRACE", "DEBUG", "INFO", "WARN", "ERROR"].contains(&level.as_str()) {
+                                    return Err(format!(
+                                        "{context} must be TRACE, DEBUG, INFO, WARN or ERROR"
+                                    ));
+                                }
+                                logging.level = Some(level);
+                            }
+                            "file" => logging.file = Some(boolean(value, &context)?),
+                            "directory" => {
+                                logging.directory = Some(base_dir.join(string(value, &context)?))
+                            }
+                            _ => unknown.push(context),
+                        }
+                    }
+                }
+                "time" => {
+                    let time = &mut config.time;
+                    for (key, value) in fields {
+                        let context = format!("time.{key}");
+                        match key.as_str() {
+                            "fixed_timestep" => {
+                                time.fixed_timestep = Some(positive_f32(value, &context)?)
+                            }
+                            "max_fixed_steps" => {
+                                time.max_fixed_steps = Some(positive_u32(value, &context)?)
+                            }
+                            "target_fps" => {
+                                let fps = number(value, &context)?;
+                                if fps < 0.0 {
+                                    return Err(format!("{context} must be 0 or more"));
+                                }
+                                time.target_fps = Some((fps > 0.0).then_some(fps));
+                            }
+                            _ => unknown.push(context),
+                        }
+                    }
+                }
+                "assets" => {
+                    for (key, value) in fields {
+                        let context = format!("assets.{key}");
+                        match key.as_str() {
+                            "root" => config.asset_root = Some(base_dir.join(string(value, &context)?)),
+                            _ => unknown.push(context),
+                        }
+                    }
+                }
+                "physics" => {
+                    for (key, value) in fields {
+                        let context = format!("physics.{key}");
+                        match key.as_str() {
+                            "collisions" => {
+                                config.physics.collisions = Some(boolean(value, &context)?)
+                            }
+                            _ => unknown.push(context),
+                        }
+                    }
+                }
+                "profiler" => {
+                    let profiler = &mut config.profiler;
+                    for (key, value) in fields {
+                        let context = format!("profiler.{key}");
+                        match key.as_str() {
+                            "enabled" => profiler.enabled = Some(boolean(value, &context)?),
+                            "frame_budget_ms" => {
+                                profiler.frame_budget_ms = Some(positive_f32(value, &context)?)
+                            }
+                            "spike_factor" => {
+                                let factor = number(value, &context)?;
+                                if factor <= 1.0 {
+                                    return Err(format!("{context} must be greater than 1"));
+                                }
+                                profiler.spike_factor = Some(factor);
+                            }
+                            _ => unknown.push(context),
+                        }
+                    }
+                }
+                _ => unknown.push(section.clone()),
+            }
+        }
+        config.warnings = unknown
+            .into_iter()
+            .map(|key| format!("Unknown config key '{key}' in {CONFIG_FILE_NAME}"))
+            .collect();
+        Ok(config)
+    }
+
+    /// Window settings from the file on top of the window defaults.
+    pub fn window_config(&self) -> WindowConfig {
+        let window = &self.window;
+        let defaults = WindowConfig::default();
+        let mut config = WindowConfig::new()
+            .with_title(window.title.clone().unwrap_or(defaults.title))
+            .with_size(
+                window.width.unwrap_or(defaults.width),
+                window.height.unwrap_or(defaults.height),
+            )
+            .with_resizable(window.resizable.unwrap_or(defaults.resizable))
+            .with_vsync(window.vsync.unwrap_or(defaults.vsync))
+            .with_show_fps_in_title(window.show_fps_in_title.unwrap_or(defaults.show_fps_in_title));
+        if let (Some(min_width), Some(min_height)) = (window.min_width, window.min_height) {
+            config = config.with_min_size(min_width, min_height);
+        }
+        config
+    }
+}
+
+fn string(value: &Value, context: &str) -> Result<String, String> {
+    value
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| format!("{context} must be a string"))
+}
+
+fn boolean(value: &Value, context: &str) -> Result<bool, String> {
+    value
+        .as_bool()
+        .ok_or_else(|| format!("{context} must be true or false"))
+}
+
+fn number(value: &Value, context: &str) -> Result<f32, String> {
+    match value {
+        Value::Integer(number) => Ok(*number as f32),
+        Value::Float(number) if number.is_finite() => Ok(*number as f32),
+        _ => Err(format!("{context} must be a number")),
+    }
+}
+
+fn positive_f32(value: &Value, context: &str) -> Result<f32, String> {
+    let number = number(value, context)?;
+    if number > 0.0 {
+        Ok(number)
+    } else {
+        Err(format!("{context} must be greater than 0"))
+    }
+}
+
+fn positive_u32(value: &Value, context: &str) -> Result<u32, String> {
+    value
+        .as_integer()
+        .and_then(|number| u32::try_from(number).ok())
+        .filter(|number| *number > 0)
+        .ok_or_else(|| format!("{context} must be a positive integer"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_reads_every_section() {
+        let config = EngineConfig::parse(
+            r#"
+            [window]
+            title = "Demo"
+            width = 800
+            vsync = false
+
+            [logging]
+            level = "debug"
+            directory = "logs"
+
+            [time]
+            fixed_timestep = 0.02
+            target_fps = 0
+
+            [assets]
+            root = "game"
+
+            [physics]
+            collisions = false
+            colisions = true
+
+            [profiler]
+            frame_budget_ms = 8
+            spike_factor = 2.5
+            "#,
+            Path::new("project"),
+        )
+        .unwrap();
+
+        assert_eq!(config.window.title.as_deref(), Some("Demo"));
+        assert_eq!(config.window.width, Some(800));
+        assert_eq!(config.window.height, None);
+        assert_eq!(config.logging.level.as_deref(), Some("DEBUG"));
+        assert_eq!(config.logging.directory, Some(PathBuf::from("project/logs")));
+        assert_eq!(config.time.fixed_timestep, Some(0.02));
+        assert_eq!(config.time.target_fps, Some(None));
+        assert_eq!(config.asset_root, Some(PathBuf::from("project/game")));
+        assert_eq!(config.physics.collisions, Some(false));
+        assert_eq!(config.profiler.frame_budget_ms, Some(8.0));
+        assert_eq!(config.profiler.spike_factor, Some(2.5));
+        assert_eq!(config.warnings.len(), 1);
+        assert!(config.warnings[0].contains("physics.colisions"));
+
+        let window = config.window_config();
+        assert_eq!((window.width, window.height), (800, 720));
+        assert!(!window.vsync);
+    }
+
+    #[test]
+    fn test_parse_rejects_wrong_types() {
+        let error = EngineConfig::parse("[window]\nwidth = -5", Path::new("")).unwrap_err();
+        assert_eq!(error, "window.width must be a positive integer");
+        assert!(EngineConfig::parse("[logging]\nlevel = \"LOUD\"", Path::new("")).is_err());
+        assert!(EngineConfig::parse("[window", Path::new("")).is_err());
+        assert!(EngineConfig::parse("[profiler]\nspike_factor = 1", Path::new("")).is_err());
+
+        let fallback = EngineConfig::load_or_default(Path::new("missing/pyg_engine.toml"));
+        assert_eq!(fallback.window, WindowSettings::default());
+        assert_eq!(fallback.warnings.len(), 1);
+    }
+}
@@ -0,0 +1,194 @@
+/// A single point on an [`AnimationCurve`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Keyframe {
+    pub time: f32,
+    pub value: f32,
+}
+
+impl Keyframe {
+    pub fn new(time: f32, value: f32) -> Self {
+        Self { time, value }
+    }
+}
+
+/// How values are blended between neighbouring keyframes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CurveInterpolation {
+    /// Straight lines between keys.
+    #[default]
+    Linear,
+    /// Smoothstep easing between keys, flat at every key.
+    Smooth,
+    /// Hold each key's value until the next key.
+    Constant,
+}
+
+impl CurveInterpolation {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Linear => "linear",
+            Self::Smooth => "smooth",
+            Self::Constant => "constant",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "linear" => Some(Self::Linear),
+            "smooth" | "smoothstep" => Some(Self::Smooth),
+            "constant" | "step" => Some(Self::Constant),
+            _ => None,
+        }
+    }
+}
+
+/// Float curve defined by keyframes, for tuning values over time.
+///
+/// Keys are kept sorted by time. Evaluating before the first key or after the
+/// last one returns that key's value; an empty curve evaluates to 0.0.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyg_engine_native::core::curve::AnimationCurve;
+///
+/// let mut fade = AnimationCurve::linear(0.0, 1.0, 1.0, 0.0);
+/// fade.add_key(0.5, 0.8);
+/// assert_eq!(fade.evaluate(0.5), 0.8);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AnimationCurve {
+    keys: Vec<Keyframe>,
+    interpolation: CurveInterpolation,
+}
+
+impl AnimationCurve {
+    /// Create an empty curve with linear interpolation.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a straight line between two keys.
+    pub fn linear(start_time: f32, start_value: f32, end_time: f32, end_value: f32) -> Self {
+        let mut curve = Self::new();
+        curve.add_key(start_time, start_value);
+        curve.add_key(end_time, end_value);
+        curve
+    }
+
+    /// Build a curve from keys in any order. Non-finite keys are dropped.
+    pub fn from_keys(keys: impl IntoIterator<Item = Keyframe>) -> Self {
+        let mut curve = Self::new();
+        for key in keys {
+            curve.add_key(key.time, key.value);
+        }
+        curve
+    }
+
+    pub fn with_interpolation(mut self, interpolation: CurveInterpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    pub fn interpolation(&self) -> CurveInterpolation {
+        self.interpolation
+    }
+
+    pub fn set_interpolation(&mut self, interpolation: CurveInterpolation) {
+        self.interpolation = interpolation;
+    }
+
+    pub fn keys(&self) -> &[Keyframe] {
+        &self.keys
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Insert a key and return its index, or `None` if either value is not finite.
+    pub fn add_key(&mut self, time: f32, value: f32) -> Option<usize> {
+        if !(time.is_finite() && value.is_finite()) {
+            return None;
+        }
+        let index = self.keys.partition_point(|key| key.time <= time);
+        self.keys.insert(index, Keyframe::new(time, value));
+        Some(index)
+    }
+
+    pub fn remove_key(&mut self, index: usize) -> Option<Keyframe> {
+        (index < self.keys.len()).then(|| self.keys.remove(index))
+    }
+
+    /// Move the key at `index`, returning its new index after re-sorting.
+    pub fn set_key(&mut self, index: usize, time: f32, value: f32) -> Option<usize> {
+        if index >= self.keys.len() || !(time.is_finite() && value.is_finite()) {
+            return None;
+        }
+        self.keys.remove(index);
+        self.add_key(time, value)
+    }
+
+    pub fn clear(&mut self) {
+        self.keys.clear();
+    }
+
+    /// Sample the curve at `time`.
+    pub fn evaluate(&self, time: f32) -> f32 {
+        let (first, last) = match (self.keys.first(), self.keys.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return 0.0,
+        };
+        if time <= first.time {
+            return first.value;
+        }
+        if time >= last.time {
+            return last.value;
+        }
+
+        let next = self.keys.partition_point(|key| key.time <= time);
+        let (a, b) = (self.keys[next - 1], self.keys[next]);
+        let span = b.time - a.time;
+        if span <= f32::EPSILON {
+            return b.value;
+        }
+        let t = (time - a.time) / span;
+        let t = match self.interpolation {
+            CurveInterpolation::Linear => t,
+            CurveInterpolation::Smooth => t * t * (3.0 - 2.0 * t),
+            CurveInterpolation::Constant => 0.0,
+        };
+        a.value + (b.value - a.value) * t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_stay_sorted_and_evaluate_clamps() {
+        let mut curve = AnimationCurve::new();
+        assert_eq!(curve.evaluate(3.0), 0.0);
+
+        curve.add_key(1.0, 10.0);
+        curve.add_key(0.0, 0.0);
+        assert_eq!(curve.add_key(f32::NAN, 1.0), None);
+        assert_eq!(curve.keys(), &[Keyframe::new(0.0, 0.0), Keyframe::new(1.0, 10.0)]);
+
+        assert_eq!(curve.evaluate(-1.0), 0.0);
+        assert_eq!(curve.evaluate(0.25), 2.5);
+        assert_eq!(curve.evaluate(5.0), 10.0);
+
+        // Dragging the first key past the second swaps their order.
+        assert_eq!(curve.set_key(0, 2.0, 4.0), Some(1));
+        assert_eq!(curve.evaluate(1.5), 7.0);
+
+        curve.set_interpolation(CurveInterpolation::Constant);
+        assert_eq!(curve.evaluate(1.5), 10.0);
+    }
+}
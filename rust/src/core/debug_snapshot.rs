@@ -0,0 +1,235 @@
+//! JSON debug snapshots of the scene, collision world and draw queue.
+//!
+//! The snapshot is a single self-describing JSON document so it can be attached
+//! to a bug report and opened in a viewer without the game that produced it.
+
+use super::component::ComponentTrait;
+use super::draw_manager::DrawCommand;
+use super::game_object::GameObject;
+use super::object_manager::ObjectManager;
+use super::physics::{
+    AABB, AABBTreeNodeInfo, ColliderComponent, ColliderShape, CollisionEvent, CollisionEventType,
+    CollisionWorld,
+};
+use super::render_manager::RenderStats;
+use crate::types::vector::Vec2;
+use serde_json::{Map, Value, json};
+use std::collections::BTreeMap;
+
+/// Identifies snapshot files for viewers.
+pub const SNAPSHOT_FORMAT: &str = "pyg_engine.debug_snapshot";
+/// Bumped whenever the snapshot layout changes incompatibly.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+fn vec2(v: Vec2) -> Value {
+    json!([v.x(), v.y()])
+}
+
+fn aabb(bounds: &AABB) -> Value {
+    json!({ "min": vec2(bounds.min), "max": vec2(bounds.max) })
+}
+
+fn shape(shape: &ColliderShape) -> Value {
+    match shape {
+        ColliderShape::Circle { radius } => json!({ "type": "circle", "radius": radius }),
+        ColliderShape::Box { half_extents } => {
+            json!({ "type": "box", "half_extents": vec2(*half_extents) })
+        }
+        ColliderShape::OBB {
+            half_extents,
+            local_rotation,
+        } => json!({
+            "type": "obb",
+            "half_extents": vec2(*half_extents),
+            "local_rotation": local_rotation,
+        }),
+        ColliderShape::Polygon { vertices } => json!({
+            "type": "polygon",
+            "vertices": vertices.iter().copied().map(vec2).collect::<Vec<_>>(),
+        }),
+    }
+}
+
+fn collider(object: &GameObject, object_manager: &ObjectManager) -> Value {
+    let Some(collider) = object.get_component::<ColliderComponent>() else {
+        return Value::Null;
+    };
+    let world_aabb = object_manager
+        .world_transform(object.get_id())
+        .map(|world| aabb(&collider.compute_aabb(world.position, world.rotation, world.scale)))
+        .unwrap_or(Value::Null);
+
+    json!({
+        "shape": shape(collider.shape()),
+        "offset": vec2(collider.offset()),
+        "layer": collider.layer(),
+        "mask": collider.collision_mask(),
+        "trigger": collider.is_trigger(),
+        "material": collider.material(),
+        "enabled": collider.is_effectively_enabled(),
+        "world_aabb": world_aabb,
+    })
+}
+
+fn object(object: &GameObject, object_manager: &ObjectManager) -> Value {
+    let world = object_manager
+        .world_transform(object.get_id())
+        .map(|world| {
+            json!({
+                "position": vec2(world.position),
+                "rotation": world.rotation,
+                "scale": vec2(world.scale),
+            })
+        })
+        .unwrap_or(Value::Null);
+    let components: Vec<Value> = object
+        .components_iter()
+        .map(|component| {
+            json!({
+                "id": component.id(),
+                "name": component.name(),
+                "type": component.component_type(),
+                "enabled": component.is_enabled_self(),
+            })
+        })
+        .collect();
+
+    json!({
+        "id": object.get_id(),
+        "name": object.name(),
+        "type": format!("{:?}", object.get_object_type()),
+        "enabled_self": object.enabled_self(),
+        "enabled_in_hierarchy": object.enabled_in_hierarchy(),
+        "parent": object.parent_id(),
+        "children": object.children(),
+        "transform": {
+            "position": vec2(object.position()),
+            "rotation": object.rotation(),
+            "scale": vec2(object.scale()),
+        },
+        "world_transform": world,
+        "components": components,
+        "collider": collider(object, object_manager),
+    })
+}
+
+/// Every object in the scene, in id order.
+pub fn scene_snapshot(object_manager: &ObjectManager) -> Value {
+    let mut objects = object_manager.get_objects();
+    objects.sort_by_key(|object| object.get_id());
+    Value::Array(
+        objects
+            .into_iter()
+            .map(|obj| object(obj, object_manager))
+            .collect(),
+    )
+}
+
+fn tree_node(node: &AABBTreeNodeInfo) -> Value {
+    json!({
+        "index": node.index,
+        "aabb": aabb(&node.aabb),
+        "object_id": node.object_id,
+        "parent": node.parent,
+        "left": node.left,
+        "right": node.right,
+        "height": node.height,
+    })
+}
+
+fn collision_event(step: u64, event: &CollisionEvent) -> Value {
+    let event_type = match event.event_type {
+        CollisionEventType::Enter => "enter",
+        CollisionEventType::Stay => "stay",
+        CollisionEventType::Exit => "exit",
+    };
+    let mut value = json!({
+        "step": step,
+        "type": event_type,
+        "object_a": event.object_id_a,
+        "object_b": event.object_id_b,
+    });
+    if let Some(manifold) = &event.manifold {
+        value["normal"] = vec2(manifold.normal);
+        value["penetration"] = json!(manifold.penetration_depth);
+        value["contacts"] = manifold
+            .contact_points
+            .iter()
+            .copied()
+            .map(vec2)
+            .collect();
+    }
+    value
+}
+
+/// Broad-phase tree structure and the most recent collision events.
+pub fn collision_snapshot(collision_world: &CollisionWorld) -> Value {
+    let tree = collision_world.aabb_tree();
+    json!({
+        "step": collision_world.step_count(),
+        "aabb_tree": {
+            "root": tree.root_index(),
+            "nodes": tree.nodes().iter().map(tree_node).collect::<Vec<_>>(),
+        },
+        "recent_events": collision_world
+            .recent_collision_events()
+            .map(|(step, event)| collision_event(step, event))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Counts of the queued direct draw commands and last frame's batching stats.
+pub fn draw_snapshot(commands: &[DrawCommand], clipped: usize, stats: RenderStats) -> Value {
+    let mut by_kind: BTreeMap<&str, usize> = BTreeMap::new();
+    for command in commands {
+        *by_kind.entry(command.kind()).or_default() += 1;
+    }
+    let by_kind: Map<String, Value> = by_kind
+        .into_iter()
+        .map(|(kind, count)| (kind.to_string(), json!(count)))
+        .collect();
+
+    json!({
+        "commands": commands.len(),
+        "by_kind": by_kind,
+        "clipped": clipped,
+        "last_frame": {
+            "draw_items": stats.draw_items,
+            "draw_calls": stats.draw_calls,
+            "texture_switches": stats.texture_switches,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scene_snapshot_includes_hierarchy_and_colliders() {
+        let mut object_manager = ObjectManager::new();
+        let mut parent = GameObject::new_named("Parent".to_string());
+        parent.set_position(Vec2::new(10.0, 0.0));
+        parent.add_component(Box::new(
+            ColliderComponent::new("Collider").with_shape(ColliderShape::circle(2.0)),
+        ));
+        let parent_id = object_manager.add_object(parent).unwrap();
+        let child_id = object_manager
+            .add_object(GameObject::new_named("Child".to_string()))
+            .unwrap();
+        object_manager.add_child(parent_id, child_id).unwrap();
+
+        let scene = scene_snapshot(&object_manager);
+        let objects = scene.as_array().unwrap();
+        let parent = objects.iter().find(|o| o["id"] == parent_id).unwrap();
+        let child = objects.iter().find(|o| o["id"] == child_id).unwrap();
+
+        assert_eq!(parent["name"], "Parent");
+        assert_eq!(parent["children"], json!([child_id]));
+        assert_eq!(parent["collider"]["shape"]["type"], "circle");
+        assert_eq!(parent["collider"]["world_aabb"]["min"], json!([8.0, -2.0]));
+        assert_eq!(child["parent"], parent_id);
+        assert_eq!(child["world_transform"]["position"], json!([10.0, 0.0]));
+        assert!(child["collider"].is_null());
+    }
+}
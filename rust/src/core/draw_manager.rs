@@ -304,6 +304,78 @@ pub enum DrawCommand {
         layout: TextLayoutOptions,
         draw_order: f32,
     },
+
+    /// Draw text centered on a point in world space.
+    ///
+    /// Rendered with the scene rather than on top of it: the text moves with
+    /// the camera and one text pixel covers one world unit, so it scales with
+    /// zoom like a `TextMeshComponent`.
+    ///
+    /// # Fields
+    /// - `text`: String to render (supports newlines `\n`)
+    /// - `x`, `y`: Center of the text in world units
+    /// - `style`: Font/style configuration
+    /// - `color`: Text color
+    /// - `draw_order`: Rendering layer among world objects (higher = on top)
+    WorldText {
+        text: String,
+        x: f32,
+        y: f32,
+        style: TextStyle,
+        color: Color,
+        draw_order: f32,
+    },
+}
+
+impl DrawCommand {
+    /// Snake-case variant name, e.g. `"rectangle"` or `"world_text"`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Pixel { .. } => "pixel",
+            Self::Line { .. } => "line",
+            Self::Rectangle { .. } => "rectangle",
+            Self::Circle { .. } => "circle",
+            Self::Arc { .. } => "arc",
+            Self::Polygon { .. } => "polygon",
+            Self::GradientRect { .. } => "gradient_rect",
+            Self::Image { .. } => "image",
+            Self::ImageBytes { .. } => "image_bytes",
+            Self::Mesh { .. } => "mesh",
+            Self::Text { .. } => "text",
+            Self::WorldText { .. } => "world_text",
+        }
+    }
+}
+
+/// Screen-space rectangle that draw commands are clipped to.
+///
+/// Applied by the renderer as a scissor rect; see [`DrawManager::push_clip_rect`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClipRect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl ClipRect {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width: width.max(0.0),
+            height: height.max(0.0),
+        }
+    }
+
+    /// Overlap of two rectangles; zero-sized when they do not overlap.
+    pub fn intersect(&self, other: &ClipRect) -> ClipRect {
+        let left = self.x.max(other.x);
+        let top = self.y.max(other.y);
+        let right = (self.x + self.width).min(other.x + other.width);
+        let bottom = (self.y + self.height).min(other.y + other.height);
+        ClipRect::new(left, top, right - left, bottom - top)
+    }
 }
 
 /// Manages immediate-mode draw commands for 2D rendering.
@@ -340,6 +412,10 @@ pub enum DrawCommand {
 #[derive(Default)]
 pub struct DrawManager {
     commands: Vec<DrawCommand>,
+    /// Clip rect for each entry in `commands`, at the same index
+    clips: Vec<Option<ClipRect>>,
+    /// Clip rects pushed with `push_clip_rect`, innermost last
+    clip_stack: Vec<ClipRect>,
     scene_version: u64,
 }
 
@@ -350,6 +426,8 @@ impl DrawManager {
     pub fn new() -> Self {
         Self {
             commands: Vec::new(),
+            clips: Vec::new(),
+            clip_stack: Vec::new(),
             scene_version: 0,
         }
     }
@@ -362,11 +440,13 @@ impl DrawManager {
     /// This is typically called at the start of each frame to clear the
     /// previous frame's drawing operations.
     pub fn clear(&mut self) {
+        self.clip_stack.clear();
         if self.commands.is_empty() {
             return;
         }
 
         self.commands.clear();
+        self.clips.clear();
         self.bump_scene_version();
     }
 
@@ -377,6 +457,34 @@ impl DrawManager {
         &self.commands
     }
 
+    /// Get the clip rect of each command, parallel to [`Self::commands`].
+    pub fn clip_rects(&self) -> &[Option<ClipRect>] {
+        &self.clips
+    }
+
+    /// Clip every command added from now on to a screen-space rectangle.
+    ///
+    /// Nested pushes intersect with the enclosing clip rect. Each push must be
+    /// matched by a [`Self::pop_clip_rect`].
+    pub fn push_clip_rect(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        let rect = ClipRect::new(x, y, width, height);
+        let clip = match self.clip_stack.last() {
+            Some(outer) => outer.intersect(&rect),
+            None => rect,
+        };
+        self.clip_stack.push(clip);
+    }
+
+    /// Restore the clip rect that was active before the last [`Self::push_clip_rect`].
+    pub fn pop_clip_rect(&mut self) {
+        self.clip_stack.pop();
+    }
+
+    /// Clip rect applied to newly added commands, if any.
+    pub fn current_clip_rect(&self) -> Option<ClipRect> {
+        self.clip_stack.last().copied()
+    }
+
     /// Get the current scene version number.
     ///
     /// The scene version increments whenever the command list changes. This can be
@@ -395,6 +503,7 @@ impl DrawManager {
 
     fn push_command(&mut self, command: DrawCommand) {
         self.commands.push(command);
+        self.clips.push(self.current_clip_rect());
         self.bump_scene_version();
     }
 
@@ -420,22 +529,110 @@ impl DrawManager {
             return;
         }
 
+        let clip = self.current_clip_rect();
+        self.clips.resize(self.commands.len() + commands.len(), clip);
         self.commands.append(&mut commands);
         self.bump_scene_version();
     }
 
+    /// Append commands that carry their own clip rects, e.g. replayed from a cache.
+    ///
+    /// `clips` must be parallel to `commands`. The scene version is bumped once.
+    pub fn append_commands_with_clips(&mut self, commands: &[DrawCommand], clips: &[Option<ClipRect>]) {
+        debug_assert_eq!(commands.len(), clips.len());
+        if commands.is_empty() {
+            return;
+        }
+
+        self.commands.extend_from_slice(commands);
+        self.clips.extend_from_slice(clips);
+        self.bump_scene_version();
+    }
+
     /// Remove all draw commands from index `start` onward.
     /// Used by UIManager to clear previous frame's UI commands before re-rendering.
     pub fn truncate_from(&mut self, start: usize) {
         if start < self.commands.len() {
             self.commands.truncate(start);
+            self.clips.truncate(start);
             self.bump_scene_version();
         }
     }
 
+    /// Give text commands from index `start` onward that have no fallback
+    /// fonts of their own the `fallbacks` list.
+    pub fn apply_font_fallbacks_from(&mut self, start: usize, fallbacks: &[String]) {
+        if fallbacks.is_empty() {
+            return;
+        }
+        for cmd in self.commands[start..].iter_mut() {
+            if let DrawCommand::Text { style, .. } = cmd
+                && style.font.fallbacks().is_empty()
+            {
+                style.font.set_fallbacks(fallbacks.to_vec());
+            }
+        }
+    }
+
+    /// Multiply the alpha of every colored command from `start` on by `opacity`.
+    ///
+    /// Used to fade UI elements. Images carry no color and are left unchanged.
+    pub fn multiply_alpha_from(&mut self, start: usize, opacity: f32) {
+        self.map_colors_from(start, |color| color.with_alpha(color.a() * opacity));
+    }
+
+    /// Move the color of every colored command from `start` on toward its
+    /// grey by keeping `saturation` (0 = grey, 1 = unchanged) of the color.
+    ///
+    /// Used to grey out disabled UI elements. Images are left unchanged.
+    pub fn desaturate_from(&mut self, start: usize, saturation: f32) {
+        self.map_colors_from(start, |color| {
+            let grey = color.r() * 0.299 + color.g() * 0.587 + color.b() * 0.114;
+            let mix = |channel: f32| grey + (channel - grey) * saturation;
+            Color::new(mix(color.r()), mix(color.g()), mix(color.b()), color.a())
+        });
+    }
+
+    fn map_colors_from(&mut self, start: usize, map: impl Fn(Color) -> Color) {
+        let apply = |color: &mut Color| *color = map(*color);
+        for cmd in self.commands[start..].iter_mut() {
+            match cmd {
+                DrawCommand::Pixel { color, .. }
+                | DrawCommand::Line { color, .. }
+                | DrawCommand::Rectangle { color, .. }
+                | DrawCommand::Circle { color, .. }
+                | DrawCommand::Arc { color, .. }
+                | DrawCommand::Polygon { color, .. }
+                | DrawCommand::Mesh { color, .. }
+                | DrawCommand::Text { color, .. }
+                | DrawCommand::WorldText { color, .. } => apply(color),
+                DrawCommand::GradientRect {
+                    top_left,
+                    bottom_left,
+                    bottom_right,
+                    top_right,
+                    ..
+                } => {
+                    for color in [top_left, bottom_left, bottom_right, top_right] {
+                        apply(color);
+                    }
+                }
+                DrawCommand::Image { .. } | DrawCommand::ImageBytes { .. } => {}
+            }
+        }
+    }
+
     /// Scale all draw commands from index `start` onward by `scale`.
     /// Used to convert UI coordinates from logical to physical pixels.
     pub fn scale_commands_from(&mut self, start: usize, scale: f32) {
+        for clip in self.clips[start..].iter_mut().flatten() {
+            *clip = ClipRect::new(
+                clip.x * scale,
+                clip.y * scale,
+                clip.width * scale,
+                clip.height * scale,
+            );
+        }
         for cmd in self.commands[start..].iter_mut() {
             match cmd {
                 DrawCommand::Rectangle { x, y, width, height, thickness, .. } => {
@@ -525,6 +722,68 @@ impl DrawManager {
                         );
                     }
                 }
+                // World units are not affected by the UI scale.
+                DrawCommand::WorldText { .. } => {}
+            }
+        }
+        self.bump_scene_version();
+    }
+
+    /// Move all draw commands and clip rects from index `start` onward by
+    /// `(dx, dy)` and raise their draw order by `draw_order`.
+    /// Used to place commands drawn in a widget's local coordinates.
+    pub fn offset_commands_from(&mut self, start: usize, dx: f32, dy: f32, draw_order: f32) {
+        for clip in self.clips[start..].iter_mut().flatten() {
+            *clip = ClipRect::new(clip.x + dx, clip.y + dy, clip.width, clip.height);
+        }
+        for cmd in self.commands[start..].iter_mut() {
+            match cmd {
+                DrawCommand::Pixel { x, y, draw_order: order, .. }
+                | DrawCommand::Rectangle { x, y, draw_order: order, .. }
+                | DrawCommand::GradientRect { x, y, draw_order: order, .. }
+                | DrawCommand::Image { x, y, draw_order: order, .. }
+                | DrawCommand::ImageBytes { x, y, draw_order: order, .. }
+                | DrawCommand::Text { x, y, draw_order: order, .. } => {
+                    *x += dx;
+                    *y += dy;
+                    *order += draw_order;
+                }
+                DrawCommand::Line {
+                    start_x,
+                    start_y,
+                    end_x,
+                    end_y,
+                    draw_order: order,
+                    ..
+                } => {
+                    *start_x += dx;
+                    *start_y += dy;
+                    *end_x += dx;
+                    *end_y += dy;
+                    *order += draw_order;
+                }
+                DrawCommand::Circle { center_x, center_y, draw_order: order, .. }
+                | DrawCommand::Arc { center_x, center_y, draw_order: order, .. } => {
+                    *center_x += dx;
+                    *center_y += dy;
+                    *order += draw_order;
+                }
+                DrawCommand::Polygon { points, draw_order: order, .. } => {
+                    for point in points {
+                        *point = Vec2::new(point.x() + dx, point.y() + dy);
+                    }
+                    *order += draw_order;
+                }
+                DrawCommand::Mesh { vertices, draw_order: order, .. } => {
+                    for vertex in vertices {
+                        let position = vertex.position();
+                        let uv = vertex.uv();
+                        *vertex = MeshVertex::new(Vec2::new(position.x() + dx, position.y() + dy), uv);
+                    }
+                    *order += draw_order;
+                }
+                // World text is placed in world units, not screen pixels.
+                DrawCommand::WorldText { .. } => {}
             }
         }
         self.bump_scene_version();
@@ -825,6 +1084,26 @@ impl DrawManager {
         });
     }
 
+    /// Draw text centered on a world-space point.
+    pub fn draw_world_text(
+        &mut self,
+        text: String,
+        x: f32,
+        y: f32,
+        style: TextStyle,
+        color: Color,
+        draw_order: f32,
+    ) {
+        self.push_command(DrawCommand::WorldText {
+            text,
+            x,
+            y,
+            style,
+            color,
+            draw_order,
+        });
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn draw_text_legacy(
         &mut self,
@@ -881,6 +1160,7 @@ impl DrawManager {
                 height: Some(height),
                 horizontal_align,
                 vertical_align,
+                wrap: false,
             },
             draw_order,
         );
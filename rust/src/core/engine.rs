@@ -1,39 +1,123 @@
+use super::asset_manager::{AssetHandle, AssetKind, AssetManager};
+use super::audio::{Attenuation, AudioListenerComponent, AudioSourceComponent, AudioSystem, VoiceId, VoiceParams};
+use super::capabilities::EngineCapabilities;
+use super::clipboard;
 use super::command::EngineCommand;
+use super::config::EngineConfig;
+use super::debug_snapshot;
+use super::event_bus::{EventBus, EventPayload, Message};
 use super::draw_manager::{DrawCommand, DrawManager};
 use super::game_object::{GameObject, ObjectType};
-use super::input_manager::InputManager;
+use super::input_debug::InputDebugOverlay;
+use super::input_manager::{InputDevice, InputManager};
 /// Core engine functionality
 use super::logging;
+use super::gamepad::GamepadBackend;
 use super::object_manager::ObjectManager;
 use super::physics::CollisionWorld;
-use super::render_manager::{CameraAspectMode, RenderManager};
+use super::profiler::{FrameProfiler, FrameStats, ProfileScope};
+use super::render_manager::{CameraAspectMode, RenderManager, RenderStats};
+use super::scene_file;
+use super::scene_manager::SceneManager;
+use super::software_display::SoftwareDisplay;
+use super::script::{self, ScriptCall};
 use super::text::{FontFamilyDefinition, TextLayoutOptions, TextStyle};
-use super::time::Time;
-use super::ui_manager::UIManager;
+use super::time::{FrameLimiter, Time};
+use super::ui::image::ImageComponent;
+use super::ui::stack::StackComponent;
+use super::ui::curve_editor::CurveEditorComponent;
+use super::ui::radio_button::RadioButtonComponent;
+use super::ui::list_view::ListViewComponent;
+use super::ui::panel::PanelComponent;
+use super::ui::Rect;
+use super::ui::style::{FocusRing, UITheme};
+use super::ui::tween::UITween;
+use super::ui_manager::{UIManager, UIScaleMode};
 use super::window_manager::{WindowConfig, WindowManager};
 use crate::types::Color;
 use crate::types::vector::Vec2;
 use crossbeam_channel::{Receiver, Sender, unbounded};
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tracing::Level;
 use winit::application::ApplicationHandler;
-use winit::event::WindowEvent;
+use winit::error::EventLoopError;
+use winit::event::{DeviceEvent, DeviceId, WindowEvent};
 use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
-use winit::window::{Icon, WindowId};
+use winit::keyboard::Key;
+use winit::platform::run_on_demand::EventLoopExtRunOnDemand;
+use winit::window::{CursorGrabMode, CursorIcon, CustomCursor, CustomCursorSource, Icon, WindowId};
+
+/// Per-frame logic run by `Engine::run`, called with the frame's delta time.
+/// Returns false to exit the loop.
+pub type FrameCallback = Box<dyn FnMut(f32) -> bool>;
+
+/// The window system event loop between sessions, with whether it was exited.
+struct IdleEventLoop {
+    event_loop: EventLoop<()>,
+    exited: bool,
+}
+
+thread_local! {
+    // winit allows one event loop per process, so every session reuses it.
+    static IDLE_EVENT_LOOP: RefCell<Option<IdleEventLoop>> = const { RefCell::new(None) };
+}
+
+/// A custom cursor image, created on the event loop the first time it is shown.
+enum CustomCursorState {
+    Pending(CustomCursorSource),
+    Ready(CustomCursor),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum AppliedCursor {
+    Icon(CursorIcon),
+    Custom,
+}
 
 pub struct Engine {
     version: String,
     window_manager: Option<WindowManager>,
     render_manager: Option<RenderManager>,
+    /// CPU display used when no GPU renderer could be created
+    software_display: Option<SoftwareDisplay>,
     object_manager: Arc<RwLock<ObjectManager>>,
+    /// Loaded asset files, shared with the renderer
+    assets: Arc<RwLock<AssetManager>>,
     pub input_manager: Option<InputManager>,
+    /// Game controllers, read into the input manager each update
+    gamepads: GamepadBackend,
+    /// Sound playback and positional audio sources
+    pub audio: AudioSystem,
     pub draw_manager: DrawManager,
     pub time: Time,
     pub ui_manager: Option<UIManager>,
     pub collision_world: Option<CollisionWorld>,
+    /// Named scenes the runtime objects belong to
+    pub scene_manager: SceneManager,
+    /// Objects marked by `destroy_object`, removed at the end of the frame
+    pending_destroy: Vec<u32>,
+    /// Script hooks queued by the current update phase
+    script_calls: Vec<ScriptCall>,
+    /// Events emitted by engine systems and game code, dispatched after each update
+    pub event_bus: EventBus,
+    /// Messages from `send_message`, delivered in the next update phase
+    pending_messages: Vec<Message>,
+    /// Per-frame host logic for `run`, see `set_frame_callback`
+    frame_callback: Option<FrameCallback>,
+    /// `request_quit` was called or the window was closed this session
+    quit_requested: bool,
+    /// Spread native component updates across the rayon pool
+    parallel_updates: bool,
+    /// Per-scope timings of each frame, see `frame_stats`
+    profiler: FrameProfiler,
+    /// Check asset files for changes while running, see `set_asset_hot_reload`
+    asset_hot_reload: bool,
+    asset_reload_interval: Duration,
+    next_asset_check: Option<Instant>,
 
     // Command Queue
     command_receiver: Receiver<EngineCommand>,
@@ -47,54 +131,97 @@ pub struct Engine {
     fps_frame_counter: u32,
     fps_last_update: Instant,
     auto_step_on_redraw: bool,
+    /// Manual loops repaint from the event loop once after a resize.
+    repaint_after_resize: bool,
     active_camera_object_id: Option<u32>,
     pending_camera_viewport_size: Option<Vec2>,
     pending_camera_aspect_mode: CameraAspectMode,
     pending_camera_background_color: Option<Color>,
+    pending_camera_pixel_perfect_resolution: Option<(u32, u32)>,
     source_root: Option<PathBuf>,
     registered_font_families: HashMap<String, FontFamilyDefinition>,
+    /// Default fallback fonts for all text, applied to the renderer
+    font_fallbacks: Vec<String>,
+    /// Fallback fonts from the UI theme, applied to the UI manager
+    ui_font_fallbacks: Vec<String>,
+    /// Registered UI themes by name, including the built-in "light" and "dark"
+    ui_themes: HashMap<String, UITheme>,
+    ui_theme_name: String,
+    /// UI scaling policy, applied to the UI manager
+    ui_scale_mode: UIScaleMode,
+    /// Focus outline, applied to the UI manager
+    ui_focus_ring: FocusRing,
+    frame_limiter: FrameLimiter,
+    /// Paces uncapped windows without a GPU renderer, which have no vsync
+    fallback_frame_limiter: FrameLimiter,
+    pending_vsync: Option<bool>,
+    pending_texture_preloads: Vec<String>,
+    /// UI tweens started before the UI manager exists
+    pending_ui_tweens: Vec<UITween>,
+    /// Interactable flags set before the UI manager exists
+    pending_ui_interactable: HashMap<u32, bool>,
+    pending_texture_budget: Option<u64>,
+    sort_sprites_by_texture: bool,
+    cursor_visible: bool,
+    auto_hide_cursor: bool,
+    cursor_grab: CursorGrabMode,
+    /// Cursor shape shown when no custom image or UI hover cursor applies
+    cursor_icon: CursorIcon,
+    custom_cursor: Option<CustomCursorState>,
+    /// Cursor last set on the window, to skip redundant updates
+    applied_cursor: Option<AppliedCursor>,
+    /// UI hover cursors set before the UI manager exists
+    pending_ui_hover_cursors: HashMap<u32, Option<CursorIcon>>,
+    /// UI input contexts set before the UI manager exists
+    pending_ui_input_contexts: HashMap<u32, Option<String>>,
+    /// Input method requested by the game, e.g. for a console without a text input
+    ime_allowed: bool,
+    /// Input method state last set on the window: allowed, and the caret area
+    applied_ime: (bool, Option<Rect>),
+    input_debug_overlay: InputDebugOverlay,
+    /// Settings read from `pyg_engine.toml`
+    config: EngineConfig,
 }
 
 pub const VERSION: &str = "1.3.2";
 
+/// Frame rate of uncapped windows without a GPU renderer.
+const FALLBACK_DISPLAY_FPS: f32 = 30.0;
+
+fn builtin_ui_themes() -> HashMap<String, UITheme> {
+    HashMap::from([
+        ("light".to_string(), UITheme::default_light()),
+        ("dark".to_string(), UITheme::default_dark()),
+    ])
+}
+
 impl Engine {
-    /// Create a new Engine instance with default logging (console only)
+    /// Create a new Engine instance with default logging (console only),
+    /// configured by `pyg_engine.toml` in the working directory if present
     pub fn new() -> Self {
-        logging::init_default();
-        let (sender, receiver) = unbounded();
-
-        let mut engine = Self {
-            version: VERSION.to_string(),
-            window_manager: None,
-            render_manager: None,
-            object_manager: Arc::new(RwLock::new(ObjectManager::new())),
-            input_manager: Some(InputManager::new()),
-            draw_manager: DrawManager::new(),
-            time: Time::new(),
-            ui_manager: None,
-            collision_world: Some(CollisionWorld::new()),
-            command_receiver: receiver,
-            command_sender: sender,
-            window_config: None,
-            base_window_title: "PyG Engine".to_string(),
-            show_fps_in_title: false,
-            fps_frame_counter: 0,
-            fps_last_update: Instant::now(),
-            auto_step_on_redraw: true,
-            active_camera_object_id: None,
-            pending_camera_viewport_size: None,
-            pending_camera_aspect_mode: CameraAspectMode::default(),
-            pending_camera_background_color: None,
-            source_root: None,
-            registered_font_families: HashMap::new(),
-        };
-        engine.ensure_active_camera_object();
-        engine
+        Self::with_config(EngineConfig::discover())
     }
 
     /// Initialize the engine with custom logging configuration
+    ///
+    /// Other settings still come from `pyg_engine.toml`, if present.
     pub fn with_logging(enable_file: bool, log_dir: Option<String>, level: Option<String>) -> Self {
-        let log_level = level
+        let mut config = EngineConfig::discover();
+        config.logging.file = Some(enable_file);
+        if let Some(log_dir) = log_dir {
+            config.logging.directory = Some(PathBuf::from(log_dir));
+        }
+        if level.is_some() {
+            config.logging.level = level;
+        }
+        Self::with_config(config)
+    }
+
+    /// Create an engine from config file settings, see `EngineConfig`.
+    pub fn with_config(config: EngineConfig) -> Self {
+        let log_level = config
+            .logging
+            .level
             .as_deref()
             .and_then(|s| match s.to_uppercase().as_str() {
                 "TRACE" => Some(Level::TRACE),
@@ -105,30 +232,41 @@ impl Engine {
                 _ => None,
             })
             .unwrap_or(Level::INFO);
-
-        let config = logging::LogConfig {
+        let defaults = logging::LogConfig::default();
+        logging::init_logging(logging::LogConfig {
             level: log_level,
-            enable_file,
-            log_dir: log_dir
-                .map(PathBuf::from)
-                .unwrap_or_else(|| PathBuf::from("logs")),
-            enable_colors: true,
-            enable_json: false,
-        };
-
-        logging::init_logging(config);
+            enable_file: config.logging.file.unwrap_or(defaults.enable_file),
+            log_dir: config.logging.directory.clone().unwrap_or(defaults.log_dir),
+            ..defaults
+        });
+        if let Some(path) = &config.path {
+            logging::log_info(&format!("Loaded engine config from '{}'", path.display()));
+        }
+        for warning in &config.warnings {
+            logging::log_warn(warning);
+        }
 
         let (sender, receiver) = unbounded();
         let mut engine = Self {
             version: VERSION.to_string(),
             window_manager: None,
             render_manager: None,
+            software_display: None,
             object_manager: Arc::new(RwLock::new(ObjectManager::new())),
+            assets: Arc::new(RwLock::new(AssetManager::new())),
             input_manager: Some(InputManager::new()),
+            gamepads: GamepadBackend::new(),
+            audio: AudioSystem::new(),
             draw_manager: DrawManager::new(),
             time: Time::new(),
             ui_manager: None,
             collision_world: Some(CollisionWorld::new()),
+            scene_manager: SceneManager::new(),
+            pending_destroy: Vec::new(),
+            script_calls: Vec::new(),
+            frame_callback: None,
+            event_bus: EventBus::new(),
+            pending_messages: Vec::new(),
             command_receiver: receiver,
             command_sender: sender,
             window_config: None,
@@ -137,17 +275,102 @@ impl Engine {
             fps_frame_counter: 0,
             fps_last_update: Instant::now(),
             auto_step_on_redraw: true,
+            repaint_after_resize: false,
+            quit_requested: false,
+            parallel_updates: false,
+            profiler: FrameProfiler::new(),
+            asset_hot_reload: false,
+            asset_reload_interval: Duration::from_millis(500),
+            next_asset_check: None,
             active_camera_object_id: None,
             pending_camera_viewport_size: None,
             pending_camera_aspect_mode: CameraAspectMode::default(),
             pending_camera_background_color: None,
+            pending_camera_pixel_perfect_resolution: None,
             source_root: None,
             registered_font_families: HashMap::new(),
+            font_fallbacks: Vec::new(),
+            ui_font_fallbacks: Vec::new(),
+            ui_themes: builtin_ui_themes(),
+            ui_theme_name: "light".to_string(),
+            ui_scale_mode: UIScaleMode::default(),
+            ui_focus_ring: FocusRing::default(),
+            frame_limiter: FrameLimiter::new(),
+            fallback_frame_limiter: {
+                let mut limiter = FrameLimiter::new();
+                limiter.set_target_fps(Some(FALLBACK_DISPLAY_FPS));
+                limiter
+            },
+            pending_vsync: None,
+            pending_texture_preloads: Vec::new(),
+            pending_ui_tweens: Vec::new(),
+            pending_ui_interactable: HashMap::new(),
+            pending_texture_budget: None,
+            sort_sprites_by_texture: true,
+            cursor_visible: true,
+            auto_hide_cursor: false,
+            cursor_grab: CursorGrabMode::None,
+            cursor_icon: CursorIcon::Default,
+            custom_cursor: None,
+            applied_cursor: None,
+            pending_ui_hover_cursors: HashMap::new(),
+            pending_ui_input_contexts: HashMap::new(),
+            ime_allowed: false,
+            applied_ime: (false, None),
+            input_debug_overlay: InputDebugOverlay::new(),
+            config: EngineConfig::default(),
         };
+        engine.apply_config(config);
         engine.ensure_active_camera_object();
         engine
     }
 
+    /// Apply the non-window settings of a config and keep it for `window_config`.
+    fn apply_config(&mut self, config: EngineConfig) {
+        if let Some(fixed_timestep) = config.time.fixed_timestep {
+            self.time.set_fixed_timestep(fixed_timestep);
+        }
+        if let Some(max_fixed_steps) = config.time.max_fixed_steps {
+            self.time.set_max_fixed_steps(max_fixed_steps);
+        }
+        if let Some(target_fps) = config.time.target_fps {
+            self.set_target_fps(target_fps);
+        }
+        if let Some(asset_root) = &config.asset_root {
+            self.set_source_root(Some(asset_root.clone()));
+        }
+        if let Some(collisions) = config.physics.collisions {
+            self.set_collisions_enabled(collisions);
+        }
+        if let Some(enabled) = config.profiler.enabled {
+            self.set_profiler_enabled(enabled);
+        }
+        if let Some(budget_ms) = config.profiler.frame_budget_ms {
+            self.set_frame_budget_ms(Some(budget_ms));
+        }
+        if let Some(factor) = config.profiler.spike_factor {
+            self.set_frame_spike_factor(Some(factor));
+        }
+        self.config = config;
+    }
+
+    /// Settings read from `pyg_engine.toml`, or defaults without one.
+    pub fn config(&self) -> &EngineConfig {
+        &self.config
+    }
+
+    /// Turn collision detection on or off. Turning it off drops current contacts
+    /// without exit events.
+    pub fn set_collisions_enabled(&mut self, enabled: bool) {
+        if enabled != self.collision_world.is_some() {
+            self.collision_world = enabled.then(CollisionWorld::new);
+        }
+    }
+
+    pub fn collisions_enabled(&self) -> bool {
+        self.collision_world.is_some()
+    }
+
     /// Get a sender for engine commands
     pub fn get_command_sender(&self) -> Sender<EngineCommand> {
         self.command_sender.clone()
@@ -157,13 +380,122 @@ impl Engine {
         Arc::clone(&self.object_manager)
     }
 
+    /// Get the shared asset manager.
+    pub fn get_asset_manager_handle(&self) -> Arc<RwLock<AssetManager>> {
+        Arc::clone(&self.assets)
+    }
+
     pub fn set_source_root(&mut self, source_root: Option<PathBuf>) {
         self.source_root = source_root;
-        if let Some(render_manager) = &mut self.render_manager {
-            render_manager.set_source_root(self.source_root.clone());
+        let changed = self
+            .assets
+            .write()
+            .is_ok_and(|mut assets| assets.set_source_root(self.source_root.clone()));
+        if changed && let Some(render_manager) = &mut self.render_manager {
+            render_manager.source_root_changed();
         }
     }
 
+    /// Load an asset file, or add a reference to it if it is already loaded.
+    ///
+    /// Textures loaded this way are decoded once and shared with the renderer,
+    /// which draws them by the handle's path.
+    pub fn load_asset(&mut self, kind: AssetKind, path: &str) -> Result<AssetHandle, String> {
+        self.assets
+            .write()
+            .map_err(|_| "asset manager lock poisoned".to_string())?
+            .load(kind, path)
+    }
+
+    /// Add a reference to a loaded asset. Returns false for stale handles.
+    pub fn retain_asset(&mut self, handle: AssetHandle) -> bool {
+        self.assets
+            .write()
+            .is_ok_and(|mut assets| assets.retain(handle))
+    }
+
+    /// Drop a reference to an asset. Returns whether this unloaded it.
+    ///
+    /// Unloading a texture also drops the renderer's GPU copy.
+    pub fn release_asset(&mut self, handle: AssetHandle) -> bool {
+        let Some((kind, path)) = self.assets.write().ok().and_then(|mut assets| {
+            let kind = assets.kind(handle)?;
+            assets.release(handle).map(|path| (kind, path))
+        }) else {
+            return false;
+        };
+        if kind == AssetKind::Texture {
+            self.unload_texture(&path);
+        }
+        true
+    }
+
+    /// Check loaded asset files for changes while the engine runs, for
+    /// editing art during development.
+    pub fn set_asset_hot_reload(&mut self, enabled: bool) {
+        self.asset_hot_reload = enabled;
+        self.next_asset_check = None;
+    }
+
+    pub fn asset_hot_reload(&self) -> bool {
+        self.asset_hot_reload
+    }
+
+    /// Set the minimum time between hot reload checks.
+    pub fn set_asset_reload_interval(&mut self, interval: Duration) {
+        self.asset_reload_interval = interval;
+    }
+
+    pub fn asset_reload_interval(&self) -> Duration {
+        self.asset_reload_interval
+    }
+
+    /// Re-read asset files that changed on disk and drop the renderer's
+    /// copies of them, so changed textures and fonts show on the next frame.
+    ///
+    /// Emits an `asset_reloaded` event with the resolved path of each file.
+    /// @return: The reloaded paths.
+    pub fn reload_changed_assets(&mut self) -> Vec<String> {
+        let reloaded = match self.assets.write() {
+            Ok(mut assets) => assets.reload_changed(),
+            Err(_) => return Vec::new(),
+        };
+
+        let mut paths = Vec::with_capacity(reloaded.len());
+        for (kind, path) in reloaded {
+            if kind == AssetKind::Audio {
+                self.audio.forget_sound(&path);
+            }
+            if let Some(render_manager) = &mut self.render_manager {
+                match kind {
+                    AssetKind::Texture => {
+                        render_manager.unload_texture(&path);
+                    }
+                    AssetKind::Font => render_manager.font_changed(&path),
+                    AssetKind::Audio | AssetKind::Data => {}
+                }
+            }
+            logging::log_info(&format!("Reloaded {} '{path}'", kind.as_str()));
+            self.event_bus
+                .emit("asset_reloaded", EventPayload::Text(path.clone()));
+            paths.push(path);
+        }
+        paths
+    }
+
+    /// Run `reload_changed_assets` if hot reload is on and the interval has passed.
+    fn poll_asset_changes(&mut self) {
+        if !self.asset_hot_reload {
+            return;
+        }
+        let now = Instant::now();
+        if self.next_asset_check.is_some_and(|next| now < next) {
+            return;
+        }
+        self.next_asset_check = Some(now + self.asset_reload_interval);
+        self.reload_changed_assets();
+    }
+
     pub fn resolve_source_path(&self, path: &str) -> PathBuf {
         let path_buf = PathBuf::from(path);
         if path_buf.is_absolute() {
@@ -183,6 +515,101 @@ impl Engine {
         self.window_config = Some(config);
     }
 
+    /// Set logic to run once per frame of `run`, after the update and before
+    /// the render, with the frame's delta time. Commands it sends are applied
+    /// before the frame renders. Returning false exits the loop.
+    pub fn set_frame_callback(&mut self, callback: Option<FrameCallback>) {
+        self.frame_callback = callback;
+    }
+
+    /// Run `update`/`fixed_update` of opted-in components on a thread pool.
+    /// Off by default, as no built-in component opts in yet.
+    ///
+    /// Only objects whose components all return true from
+    /// `ComponentTrait::runs_in_parallel` leave the calling thread; the rest
+    /// update on it afterwards. Few opted-in objects always update serially,
+    /// see `PARALLEL_UPDATE_MIN_OBJECTS`.
+    pub fn set_parallel_updates(&mut self, enabled: bool) {
+        self.parallel_updates = enabled;
+    }
+
+    /// Get whether native component updates run on a thread pool.
+    pub fn parallel_updates(&self) -> bool {
+        self.parallel_updates
+    }
+
+    /// Timings of the last completed frame, per profiler scope.
+    ///
+    /// A frame runs from one `update_frame` to the next, so the stats lag the
+    /// frame in progress by one.
+    pub fn frame_stats(&self) -> FrameStats {
+        self.profiler.last_frame()
+    }
+
+    /// Turn the frame profiler on or off; it is on by default.
+    pub fn set_profiler_enabled(&mut self, enabled: bool) {
+        self.profiler.set_enabled(enabled);
+    }
+
+    pub fn profiler_enabled(&self) -> bool {
+        self.profiler.is_enabled()
+    }
+
+    /// Log a warning with the scope breakdown for frames whose measured work
+    /// exceeds `budget_ms`, or stop with `None`.
+    pub fn set_frame_budget_ms(&mut self, budget_ms: Option<f32>) {
+        self.profiler.set_budget_ms(budget_ms);
+    }
+
+    pub fn frame_budget_ms(&self) -> Option<f32> {
+        self.profiler.budget_ms()
+    }
+
+    /// Log a warning with the scope breakdown for frames whose measured work
+    /// exceeds `factor` times the running average, or stop with `None`.
+    pub fn set_frame_spike_factor(&mut self, factor: Option<f32>) {
+        self.profiler.set_spike_factor(factor);
+    }
+
+    pub fn frame_spike_factor(&self) -> Option<f32> {
+        self.profiler.spike_factor()
+    }
+
+    /// Number of frames flagged as over budget or as spikes so far.
+    pub fn slow_frame_count(&self) -> u64 {
+        self.profiler.spike_count()
+    }
+
+    /// Add time measured by host code, such as the Python frame callback,
+    /// to a profiler scope of the current frame.
+    pub fn add_profile_time(&mut self, scope: ProfileScope, duration: Duration) {
+        self.profiler.add(scope, duration);
+    }
+
+    /// Time host code, such as Python script hooks run outside `update`,
+    /// into a profiler scope of the current frame.
+    pub fn record_profile_scope(&mut self, scope: ProfileScope, started: Option<Instant>) {
+        self.profiler.record(scope, started);
+    }
+
+    /// Start timing a scope for `record_profile_scope`.
+    pub fn start_profile_scope(&self) -> Option<Instant> {
+        self.profiler.start()
+    }
+
+    /// Run the frame callback. Returns false if it asked to exit.
+    fn run_frame_callback(&mut self) -> bool {
+        let Some(mut callback) = self.frame_callback.take() else {
+            return true;
+        };
+        let started = self.profiler.start();
+        let keep_running = callback(self.time.delta_time());
+        self.profiler.record(ProfileScope::Scripts, started);
+        self.frame_callback = Some(callback);
+        self.process_commands();
+        keep_running
+    }
+
     /// Configure whether redraw events should automatically step and render.
     ///
     /// This should remain enabled for `run(...)` mode and be disabled when a host
@@ -191,6 +618,277 @@ impl Engine {
         self.auto_step_on_redraw = enabled;
     }
 
+    /// Enable or disable VSync at runtime.
+    ///
+    /// Reconfigures the surface present mode when the renderer exists; otherwise
+    /// the value is applied when the window is created.
+    pub fn set_vsync(&mut self, enabled: bool) {
+        self.pending_vsync = Some(enabled);
+        if let Some(config) = &mut self.window_config {
+            config.vsync = enabled;
+        }
+        if let Some(render_manager) = &mut self.render_manager {
+            render_manager.configure_vsync(enabled);
+        }
+    }
+
+    /// Get whether VSync is enabled.
+    pub fn is_vsync_enabled(&self) -> bool {
+        if let Some(render_manager) = &self.render_manager {
+            return render_manager.is_vsync_enabled();
+        }
+        if let Some(enabled) = self.pending_vsync {
+            return enabled;
+        }
+        self.window_config.as_ref().is_none_or(|config| config.vsync)
+    }
+
+    /// Cap the frame rate, or pass `None` to render as fast as the present mode allows.
+    ///
+    /// In `run(...)` mode the event loop sleeps until the next frame is due.
+    /// In manual mode `render()` blocks for the remainder of the frame.
+    pub fn set_target_fps(&mut self, target_fps: Option<f32>) {
+        self.frame_limiter.set_target_fps(target_fps);
+        if let Some(window_manager) = &self.window_manager {
+            window_manager.request_redraw();
+        }
+    }
+
+    /// Get the frame rate cap, if any.
+    pub fn target_fps(&self) -> Option<f32> {
+        self.frame_limiter.target_fps()
+    }
+
+    /// Show or hide the mouse cursor over the window.
+    pub fn set_cursor_visible(&mut self, visible: bool) {
+        self.cursor_visible = visible;
+        self.apply_cursor_visibility();
+    }
+
+    /// Get whether the mouse cursor is shown (before auto-hide is applied).
+    pub fn is_cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    /// Hide the cursor automatically while a gamepad is the active input device.
+    pub fn set_auto_hide_cursor(&mut self, enabled: bool) {
+        self.auto_hide_cursor = enabled;
+        self.apply_cursor_visibility();
+    }
+
+    /// Get whether the cursor is hidden automatically during gamepad use.
+    pub fn auto_hide_cursor(&self) -> bool {
+        self.auto_hide_cursor
+    }
+
+    /// Confine the cursor to the window or lock it in place.
+    ///
+    /// While grabbed, mouse movement is read from raw device motion, so
+    /// `mouse_delta` and the "Mouse X/Y" axes keep working for mouse-look
+    /// after the cursor stops moving. Pair with `set_cursor_visible(false)`.
+    pub fn set_cursor_grab(&mut self, mode: CursorGrabMode) {
+        self.cursor_grab = mode;
+        self.apply_cursor_grab();
+    }
+
+    /// Get the requested cursor grab mode.
+    pub fn cursor_grab(&self) -> CursorGrabMode {
+        self.cursor_grab
+    }
+
+    /// Show a built-in cursor shape over the window, replacing any custom
+    /// cursor image. UI hover cursors still take precedence.
+    pub fn set_cursor_icon(&mut self, icon: CursorIcon) {
+        self.cursor_icon = icon;
+        self.custom_cursor = None;
+    }
+
+    /// Get the built-in cursor shape, or `None` while a custom image is shown.
+    pub fn cursor_icon(&self) -> Option<CursorIcon> {
+        self.custom_cursor.is_none().then_some(self.cursor_icon)
+    }
+
+    /// Show a custom cursor image over the window.
+    ///
+    /// `rgba` holds `width * height` straight-alpha RGBA pixels, row by row;
+    /// the hotspot is the pixel that points. UI hover cursors still take
+    /// precedence.
+    pub fn set_custom_cursor(
+        &mut self,
+        rgba: Vec<u8>,
+        width: u16,
+        height: u16,
+        hotspot_x: u16,
+        hotspot_y: u16,
+    ) -> Result<(), String> {
+        let source = CustomCursor::from_rgba(rgba, width, height, hotspot_x, hotspot_y)
+            .map_err(|err| format!("Invalid cursor image: {err}"))?;
+        self.custom_cursor = Some(CustomCursorState::Pending(source));
+        // A new image needs setting even if a custom cursor is already shown.
+        self.applied_cursor = None;
+        Ok(())
+    }
+
+    /// Let the input method (IME) compose text even when no UI text input has
+    /// focus, e.g. for an in-game console reading `InputManager::typed_text`.
+    /// Focusing a text input enables it regardless.
+    pub fn set_ime_allowed(&mut self, allowed: bool) {
+        self.ime_allowed = allowed;
+        self.update_ime();
+    }
+
+    pub fn ime_allowed(&self) -> bool {
+        self.ime_allowed
+    }
+
+    /// Enable the input method while the game asks for it or a text input has
+    /// focus, with its candidate window beside the text input's caret.
+    fn update_ime(&mut self) {
+        let Some(window_manager) = &self.window_manager else {
+            return;
+        };
+        let caret = match (&self.ui_manager, self.object_manager.read()) {
+            (Some(ui_manager), Ok(object_manager)) => ui_manager.text_input_caret(&object_manager),
+            _ => None,
+        };
+        let allowed = self.ime_allowed || caret.is_some();
+        if allowed != self.applied_ime.0 {
+            window_manager.set_ime_allowed(allowed);
+        }
+        if allowed
+            && let Some(caret) = caret
+            && self.applied_ime.1 != Some(caret)
+        {
+            window_manager.set_ime_cursor_area(caret.x, caret.y, caret.width, caret.height);
+        }
+        self.applied_ime = (allowed, caret);
+    }
+
+    /// Text on the system clipboard, or an empty string when it holds no text.
+    pub fn clipboard_get(&self) -> Result<String, String> {
+        clipboard::get_text()
+    }
+
+    /// Put text on the system clipboard.
+    pub fn clipboard_set(&self, text: &str) -> Result<(), String> {
+        clipboard::set_text(text)
+    }
+
+    /// Set the cursor shown while the pointer is over a UI element or its
+    /// children, or `None` to remove it.
+    pub fn set_ui_hover_cursor(&mut self, object_id: u32, cursor: Option<CursorIcon>) {
+        match &mut self.ui_manager {
+            Some(ui_manager) => ui_manager.set_hover_cursor(object_id, cursor),
+            None => {
+                self.pending_ui_hover_cursors.insert(object_id, cursor);
+            }
+        }
+    }
+
+    pub fn ui_hover_cursor(&self, object_id: u32) -> Option<CursorIcon> {
+        match &self.ui_manager {
+            Some(ui_manager) => ui_manager.hover_cursor(object_id),
+            None => self.pending_ui_hover_cursors.get(&object_id).copied().flatten(),
+        }
+    }
+
+    /// Make a UI element and its children take input only while `context`
+    /// is the top input context, or `None` to take input in any context.
+    pub fn set_ui_input_context(&mut self, object_id: u32, context: Option<String>) {
+        match &mut self.ui_manager {
+            Some(ui_manager) => ui_manager.set_input_context(object_id, context),
+            None => {
+                self.pending_ui_input_contexts.insert(object_id, context);
+            }
+        }
+    }
+
+    pub fn ui_input_context(&self, object_id: u32) -> Option<String> {
+        match &self.ui_manager {
+            Some(ui_manager) => ui_manager.input_context(object_id).map(str::to_string),
+            None => self.pending_ui_input_contexts.get(&object_id).cloned().flatten(),
+        }
+    }
+
+    /// Set the window cursor to the UI hover cursor, the custom image or the
+    /// cursor shape, in that order. Custom images are created here because
+    /// that needs the event loop.
+    fn apply_cursor(&mut self, event_loop: &ActiveEventLoop) {
+        let Some(window_manager) = &self.window_manager else {
+            return;
+        };
+        let hover = self.ui_manager.as_ref().and_then(UIManager::active_hover_cursor);
+        let wanted = match (hover, &self.custom_cursor) {
+            (Some(icon), _) => AppliedCursor::Icon(icon),
+            (None, Some(_)) => AppliedCursor::Custom,
+            (None, None) => AppliedCursor::Icon(self.cursor_icon),
+        };
+        if self.applied_cursor == Some(wanted) {
+            return;
+        }
+        match wanted {
+            AppliedCursor::Icon(icon) => window_manager.window().set_cursor(icon),
+            AppliedCursor::Custom => {
+                let cursor = match self.custom_cursor.take() {
+                    Some(CustomCursorState::Pending(source)) => event_loop.create_custom_cursor(source),
+                    Some(CustomCursorState::Ready(cursor)) => cursor,
+                    None => return,
+                };
+                window_manager.window().set_cursor(cursor.clone());
+                self.custom_cursor = Some(CustomCursorState::Ready(cursor));
+            }
+        }
+        self.applied_cursor = Some(wanted);
+    }
+
+    /// Game controller backend, for controller names and availability.
+    pub fn gamepads(&self) -> &GamepadBackend {
+        &self.gamepads
+    }
+
+    /// Show or hide the input debug overlay.
+    pub fn set_input_debug_overlay(&mut self, enabled: bool) {
+        self.input_debug_overlay.set_enabled(enabled);
+        self.request_render_redraw();
+    }
+
+    /// Get whether the input debug overlay is shown.
+    pub fn input_debug_overlay(&self) -> bool {
+        self.input_debug_overlay.is_enabled()
+    }
+
+    /// Set a key that shows or hides the input debug overlay, or `None` for no hotkey.
+    pub fn set_input_debug_overlay_toggle_key(&mut self, key: Option<Key>) {
+        self.input_debug_overlay.set_toggle_key(key);
+    }
+
+    /// Get the key that shows or hides the input debug overlay.
+    pub fn input_debug_overlay_toggle_key(&self) -> Option<&Key> {
+        self.input_debug_overlay.toggle_key()
+    }
+
+    fn apply_cursor_grab(&mut self) {
+        if let Some(input_manager) = &mut self.input_manager {
+            input_manager.set_relative_mouse_mode(self.cursor_grab != CursorGrabMode::None);
+        }
+        if let Some(window_manager) = &self.window_manager
+            && let Err(err) = window_manager.set_cursor_grab(self.cursor_grab)
+        {
+            logging::log_warn(&err);
+        }
+    }
+
+    fn apply_cursor_visibility(&self) {
+        let gamepad_active = self
+            .input_manager
+            .as_ref()
+            .is_some_and(|input| input.active_device() == InputDevice::Gamepad);
+        let visible = self.cursor_visible && !(self.auto_hide_cursor && gamepad_active);
+        if let Some(window_manager) = &self.window_manager {
+            window_manager.set_cursor_visible(visible);
+        }
+    }
+
     /// Set the window title
     pub fn set_window_title(&mut self, title: String) {
         self.base_window_title = title.clone();
@@ -238,11 +936,42 @@ impl Engine {
             self.version, window_config.title, window_config.width, window_config.height
         ));
 
-        self.window_config = Some(window_config);
+        self.begin_session(window_config);
+
+        // Run the event loop until the session ends, then keep it for the next one.
+        let (mut event_loop, _) = Self::take_event_loop()?;
+        let result = event_loop.run_app_on_demand(self);
+        Self::return_event_loop(event_loop, true);
+        self.end_session();
+        result?;
+
+        Ok(())
+    }
+
+    /// Take the window system event loop for `run` or a manual loop, with
+    /// whether an earlier session exited it. An exited loop can still `run`,
+    /// but pumping it for a manual loop ends straight away.
+    ///
+    /// The event loop is created on first use and must go back through
+    /// `return_event_loop` when the session ends: winit allows only one per
+    /// process. It must be created, and pumped, on the main thread.
+    pub fn take_event_loop() -> Result<(EventLoop<()>, bool), EventLoopError> {
+        if let Some(idle) = IDLE_EVENT_LOOP.with_borrow_mut(Option::take) {
+            return Ok((idle.event_loop, idle.exited));
+        }
+        Ok((Self::create_event_loop()?, false))
+    }
+
+    /// Keep the event loop for the next session, see `take_event_loop`.
+    pub fn return_event_loop(event_loop: EventLoop<()>, exited: bool) {
+        IDLE_EVENT_LOOP.set(Some(IdleEventLoop { event_loop, exited }));
+    }
 
-        // Create the event loop.
-        // On macOS, force a regular app activation policy so native fullscreen
-        // integrates with the standard menu bar behavior.
+    /// Create the window system event loop.
+    ///
+    /// On macOS, force a regular app activation policy so native fullscreen
+    /// integrates with the standard menu bar behavior.
+    fn create_event_loop() -> Result<EventLoop<()>, EventLoopError> {
         let event_loop = {
             #[cfg(target_os = "macos")]
             {
@@ -258,15 +987,55 @@ impl Engine {
                 EventLoop::new()?
             }
         };
-        #[cfg(target_os = "macos")]
-        event_loop.set_control_flow(ControlFlow::Wait);
-        #[cfg(not(target_os = "macos"))]
-        event_loop.set_control_flow(ControlFlow::Poll);
+        event_loop.set_control_flow(Self::default_control_flow());
+        Ok(event_loop)
+    }
+
+    /// Destroy the window and its renderer, e.g. once a manual loop's event
+    /// loop has exited. Without this the closed window stays on screen until
+    /// the engine is dropped, which on macOS can be the end of the process.
+    pub fn release_window(&mut self) {
+        self.render_manager = None;
+        self.software_display = None;
+        self.window_manager = None;
+    }
+
+    /// Ask the game loop to end after the current frame. `run` returns, and a
+    /// manual loop's `poll_events` reports it; either way the window closes
+    /// and the engine can start another session.
+    pub fn request_quit(&mut self) {
+        if !self.quit_requested {
+            logging::log_info("Quit requested");
+            self.quit_requested = true;
+        }
+        if let Some(window_manager) = &self.window_manager {
+            window_manager.request_redraw();
+        }
+    }
 
-        // Run the event loop
-        event_loop.run_app(self)?;
+    pub fn quit_requested(&self) -> bool {
+        self.quit_requested
+    }
 
-        Ok(())
+    /// Prepare a session of `run` or a manual loop; the window opens with
+    /// `window_config` once the event loop runs.
+    pub fn begin_session(&mut self, window_config: WindowConfig) {
+        self.set_window_config(window_config);
+        self.quit_requested = false;
+    }
+
+    /// Tear down the window and renderer after a session and release held
+    /// input, so the engine, with its scene, can start another session.
+    pub fn end_session(&mut self) {
+        if self.window_manager.is_some() {
+            logging::log_info("Engine session ended");
+        }
+        self.release_window();
+        if let Some(input_manager) = &mut self.input_manager {
+            input_manager.reset();
+        }
+        self.audio.stop_all();
+        self.quit_requested = false;
     }
 
     /// Open a window (legacy method for backwards compatibility)
@@ -283,7 +1052,10 @@ impl Engine {
         let mut camera = GameObject::new_named("MainCamera".to_string());
         camera.set_object_type(ObjectType::Camera);
         camera.transform_mut().set_position(Vec2::new(0.0, 0.0));
-        self.add_game_object(camera)
+        let camera_id = self.add_game_object(camera)?;
+        // The fallback camera outlives scenes, like objects added with none active.
+        self.scene_manager.move_object(camera_id, None);
+        Some(camera_id)
     }
 
     fn ensure_active_camera_object(&mut self) -> Option<u32> {
@@ -429,6 +1201,45 @@ impl Engine {
         (viewport.x(), viewport.y())
     }
 
+    /// Enable pixel-perfect camera rendering at a fixed virtual resolution.
+    ///
+    /// World-space content renders to an offscreen target of `resolution` pixels
+    /// which is integer-upscaled and centered in the window. Pass `None` to
+    /// render directly to the window again.
+    ///
+    /// Returns false, changing nothing, for a zero dimension or one over the
+    /// device's texture size limit. Before the window opens the limit is
+    /// wgpu's default one, and it is checked again against the device.
+    pub fn set_camera_pixel_perfect_resolution(&mut self, resolution: Option<(u32, u32)>) -> bool {
+        match &mut self.render_manager {
+            Some(render_manager) => {
+                if !render_manager.set_pixel_perfect_resolution(resolution) {
+                    return false;
+                }
+            }
+            None => {
+                let max_dimension = wgpu::Limits::default().max_texture_dimension_2d;
+                if resolution.is_some_and(|resolution| {
+                    !RenderManager::pixel_perfect_resolution_fits(resolution, max_dimension)
+                }) {
+                    return false;
+                }
+            }
+        }
+
+        self.pending_camera_pixel_perfect_resolution = resolution;
+        self.request_render_redraw();
+        true
+    }
+
+    /// Get the pixel-perfect virtual resolution, if enabled.
+    pub fn camera_pixel_perfect_resolution(&self) -> Option<(u32, u32)> {
+        if let Some(render_manager) = &self.render_manager {
+            return render_manager.pixel_perfect_resolution();
+        }
+        self.pending_camera_pixel_perfect_resolution
+    }
+
     /// Set the camera clear/background color.
     pub fn set_camera_background_color(&mut self, color: Color) {
         self.pending_camera_background_color = Some(color);
@@ -508,7 +1319,10 @@ impl Engine {
     ///
     /// Takes ownership of the GameObject and adds it to the engine's object manager.
     /// Returns the ID of the added object, or None if the object manager is not initialized.
-    pub fn add_game_object(&mut self, object: GameObject) -> Option<u32> {
+    pub fn add_game_object(&mut self, mut object: GameObject) -> Option<u32> {
+        if let Some(image) = object.get_component_mut::<ImageComponent>() {
+            self.load_image_texture_size(image);
+        }
         let object_type = object.get_object_type();
         let object_id = self.object_manager.write().ok()?.add_object(object);
         if object_type == ObjectType::Camera && self.active_camera_object_id.is_none() {
@@ -517,9 +1331,45 @@ impl Engine {
         if let Some(render_manager) = &mut self.render_manager {
             render_manager.set_active_camera_object_id(self.active_camera_object_id);
         }
+        if let Some(object_id) = object_id {
+            self.scene_manager.add_object(object_id);
+        }
+        // A re-added object can reuse its id; don't replay its old UI draw commands.
+        if let Some(ui_manager) = &mut self.ui_manager
+            && let Some(object_id) = object_id
+        {
+            ui_manager.mark_dirty(object_id);
+        }
         object_id
     }
 
+    /// Re-render a UI object's widget on the next frame.
+    ///
+    /// UI draw commands are retained between frames. Input, UI update
+    /// commands, layout changes and mutable component access invalidate them
+    /// automatically; call this for changes made through state the object
+    /// does not own, such as a widget callback's captures.
+    pub fn mark_ui_dirty(&mut self, object_id: u32) {
+        if let Some(ui_manager) = &mut self.ui_manager {
+            ui_manager.mark_dirty(object_id);
+        }
+    }
+
+    /// Read the pixel size of a UI image's texture if it isn't known yet,
+    /// so fit, fill and tile scale modes can keep its aspect ratio.
+    fn load_image_texture_size(&self, image: &mut ImageComponent) {
+        if image.texture_size().is_some() {
+            return;
+        }
+        let Some(texture_path) = image.texture() else {
+            return;
+        };
+        let resolved_path = self.resolve_source_path(texture_path);
+        if let Err(error) = image.load_texture_size(&resolved_path) {
+            logging::log_warn(&error);
+        }
+    }
+
     /// Create a new GameObject and add it to the engine
     ///
     /// Creates a new GameObject with a default name and adds it to the engine's object manager.
@@ -619,14 +1469,30 @@ impl Engine {
 
     /// Remove a game object by id.
     pub fn remove_game_object(&mut self, id: u32) {
+        self.remove_game_objects(&[id]);
+    }
+
+    /// Remove game objects and their children in one pass, under one lock.
+    fn remove_game_objects(&mut self, ids: &[u32]) {
         let removed_ids = self
             .object_manager
             .write()
-            .map(|mut object_manager| object_manager.destroy_object_recursive(id))
+            .map(|mut object_manager| {
+                if let Some(collision_world) = &mut self.collision_world {
+                    let subtree_ids = object_manager.collect_subtree_ids_of(ids);
+                    if !subtree_ids.is_empty() {
+                        // Scripts get the exits with the next batch of hooks
+                        let exits = collision_world.remove_objects(&object_manager, &subtree_ids);
+                        script::queue_collision_calls(&object_manager, exits, &mut self.script_calls);
+                    }
+                }
+                object_manager.destroy_objects_recursive(ids)
+            })
             .unwrap_or_default();
         if removed_ids.is_empty() {
             return;
         }
+        self.scene_manager.forget_objects(&removed_ids);
         if self
             .active_camera_object_id
             .is_some_and(|camera_id| removed_ids.contains(&camera_id))
@@ -634,9 +1500,139 @@ impl Engine {
             self.active_camera_object_id = None;
             self.ensure_active_camera_object();
         }
-        if let Some(render_manager) = &mut self.render_manager {
-            render_manager.set_active_camera_object_id(self.active_camera_object_id);
+        if let Some(render_manager) = &mut self.render_manager {
+            render_manager.set_active_camera_object_id(self.active_camera_object_id);
+        }
+    }
+
+    /// Mark a runtime GameObject, and its children, for removal at the end of
+    /// the frame.
+    ///
+    /// Until then the object stays fully usable, so it is safe to call while
+    /// iterating objects or handling its collisions. On removal its
+    /// components get `on_destroy`, its collider leaves the collision world
+    /// and every collision it was part of ends with a final exit event.
+    ///
+    /// Returns false if the object does not exist or is already marked.
+    pub fn destroy_object(&mut self, id: u32) -> bool {
+        if !self.has_game_object(id) || self.pending_destroy.contains(&id) {
+            return false;
+        }
+        self.pending_destroy.push(id);
+        true
+    }
+
+    fn has_game_object(&self, id: u32) -> bool {
+        self.object_manager
+            .read()
+            .is_ok_and(|object_manager| object_manager.get_object_by_id(id).is_some())
+    }
+
+    /// Send a message to the components of a runtime GameObject.
+    ///
+    /// Messages are delivered in the next update phase, before components
+    /// update, through `ComponentTrait::on_message` and script `on_message`
+    /// hooks. Messages sent while messages are delivered wait for the
+    /// following update. A disabled or destroyed target drops the message.
+    ///
+    /// Returns false if the object does not exist.
+    pub fn send_message(&mut self, target_id: u32, name: impl Into<String>, payload: EventPayload) -> bool {
+        if !self.has_game_object(target_id) {
+            return false;
+        }
+        self.pending_messages.push(Message {
+            target_id,
+            name: name.into(),
+            payload,
+        });
+        true
+    }
+
+    /// Whether a runtime GameObject is marked for removal by `destroy_object`.
+    pub fn is_destroy_pending(&self, id: u32) -> bool {
+        self.pending_destroy.contains(&id)
+    }
+
+    /// Remove the objects marked by `destroy_object`, in the order they were
+    /// marked. The engine calls this at frame boundaries: before a frame
+    /// renders and before the next update.
+    pub fn flush_destroyed_objects(&mut self) {
+        if self.pending_destroy.is_empty() {
+            return;
+        }
+        // Children go with their parent, which may have been marked first.
+        let ids = std::mem::take(&mut self.pending_destroy);
+        self.remove_game_objects(&ids);
+        self.request_render_redraw();
+    }
+
+    /// Load a scene and make it active, so objects added next belong to it.
+    ///
+    /// Unless `additive`, every loaded scene is unloaded first. Loading a
+    /// scene that is already loaded reloads it, destroying its objects.
+    pub fn load_scene(&mut self, name: &str, additive: bool) {
+        if additive {
+            self.unload_scene(name);
+        } else {
+            let loaded: Vec<String> = self
+                .scene_manager
+                .scenes()
+                .iter()
+                .map(|scene| scene.name().to_string())
+                .collect();
+            for scene in loaded.iter().rev() {
+                self.unload_scene(scene);
+            }
+        }
+        self.scene_manager.load_scene(name);
+        self.event_bus.emit("scene_loaded", EventPayload::Text(name.to_string()));
+    }
+
+    /// Unload a scene and destroy its objects along with their children.
+    ///
+    /// Returns false if the scene was not loaded.
+    pub fn unload_scene(&mut self, name: &str) -> bool {
+        let Some(object_ids) = self.scene_manager.unload_scene(name) else {
+            return false;
+        };
+        for object_id in object_ids {
+            self.remove_game_object(object_id);
+        }
+        self.event_bus.emit("scene_unloaded", EventPayload::Text(name.to_string()));
+        true
+    }
+
+    /// Save a loaded scene's objects, and everything parented under them, to
+    /// a JSON scene file.
+    pub fn save_scene(&self, name: &str, path: &str) -> Result<(), String> {
+        let scene = self
+            .scene_manager
+            .scene(name)
+            .ok_or_else(|| format!("Scene '{name}' is not loaded"))?;
+        let object_manager = self
+            .object_manager
+            .read()
+            .map_err(|_| "Object manager is unavailable".to_string())?;
+        scene_file::save_scene(&object_manager, name, scene.object_ids(), path)
+    }
+
+    /// Add the objects saved in a scene file to the active scene and return
+    /// their ids in file order, along with the scene name saved in the file.
+    ///
+    /// The file is read in full first; on error no objects are added.
+    pub fn add_scene_file_objects(&mut self, path: &str) -> Result<(Option<String>, Vec<u32>), String> {
+        let (name, objects) = scene_file::load_scene(path)?;
+        let mut ids: Vec<u32> = Vec::with_capacity(objects.len());
+        for scene_object in objects {
+            let Some(id) = self.add_game_object(scene_object.object) else {
+                return Err("Object manager is unavailable".to_string());
+            };
+            if let Some(parent) = scene_object.parent {
+                self.add_child(ids[parent], id)?;
+            }
+            ids.push(id);
         }
+        Ok((name, ids))
     }
 
     /// Update a runtime GameObject position by id.
@@ -655,17 +1651,54 @@ impl Engine {
     }
 
     pub fn set_game_object_name(&mut self, id: u32, name: String) -> bool {
-        {
-            let Ok(mut object_manager) = self.object_manager.write() else {
-                return false;
-            };
-            let Some(object) = object_manager.get_object_by_id_mut(id) else {
-                return false;
-            };
-            object.set_name(name);
+        let renamed = self
+            .object_manager
+            .write()
+            .is_ok_and(|mut object_manager| object_manager.set_object_name(id, name));
+        if renamed {
+            self.request_render_redraw();
         }
-        self.request_render_redraw();
-        true
+        renamed
+    }
+
+    /// Add a tag to a runtime GameObject. Returns false if the object does
+    /// not exist or already has the tag.
+    pub fn add_game_object_tag(&mut self, id: u32, tag: &str) -> bool {
+        self.object_manager
+            .write()
+            .is_ok_and(|mut object_manager| object_manager.add_object_tag(id, tag))
+    }
+
+    /// Remove a tag from a runtime GameObject. Returns false if the object
+    /// does not exist or does not have the tag.
+    pub fn remove_game_object_tag(&mut self, id: u32, tag: &str) -> bool {
+        self.object_manager
+            .write()
+            .is_ok_and(|mut object_manager| object_manager.remove_object_tag(id, tag))
+    }
+
+    /// Get the ids of runtime GameObjects with a tag, in creation order.
+    pub fn find_by_tag(&self, tag: &str) -> Vec<u32> {
+        self.object_manager
+            .read()
+            .map(|object_manager| object_manager.find_by_tag(tag))
+            .unwrap_or_default()
+    }
+
+    /// Get the ids of runtime GameObjects with a name, in creation order.
+    pub fn find_by_name(&self, name: &str) -> Vec<u32> {
+        self.object_manager
+            .read()
+            .map(|object_manager| object_manager.find_by_name(name))
+            .unwrap_or_default()
+    }
+
+    /// Get the ids of every runtime GameObject, in the order they were added.
+    pub fn get_all_ids(&self) -> Vec<u32> {
+        self.object_manager
+            .read()
+            .map(|object_manager| object_manager.get_all_ids())
+            .unwrap_or_default()
     }
 
     /// Update a runtime GameObject rotation by id.
@@ -751,6 +1784,111 @@ impl Engine {
         updated
     }
 
+    pub fn set_audio_source_component(&mut self, object_id: u32, component_id: u32, component: AudioSourceComponent) -> bool {
+        let Ok(mut object_manager) = self.object_manager.write() else {
+            return false;
+        };
+        let Some(source) = object_manager
+            .get_object_by_id_mut(object_id)
+            .and_then(|object| object.get_component_by_id_mut(component_id))
+            .and_then(|existing| existing.as_any_mut().downcast_mut::<AudioSourceComponent>())
+        else {
+            return false;
+        };
+        *source = component;
+        true
+    }
+
+    pub fn set_audio_listener_component(
+        &mut self,
+        object_id: u32,
+        component_id: u32,
+        component: AudioListenerComponent,
+    ) -> bool {
+        let Ok(mut object_manager) = self.object_manager.write() else {
+            return false;
+        };
+        let Some(listener) = object_manager
+            .get_object_by_id_mut(object_id)
+            .and_then(|object| object.get_component_by_id_mut(component_id))
+            .and_then(|existing| existing.as_any_mut().downcast_mut::<AudioListenerComponent>())
+        else {
+            return false;
+        };
+        *listener = component;
+        true
+    }
+
+    /// Play a sound file, read through the asset store and decoded on first use.
+    ///
+    /// With a `position` the sound is heard from that world point: its volume
+    /// falls off with distance from the listener and it pans to the side the
+    /// point is on, see `AudioListenerComponent`.
+    /// @return: The voice id, or an error if the file cannot be read or decoded.
+    pub fn play_sound(
+        &mut self,
+        path: &str,
+        params: VoiceParams,
+        position: Option<(Vec2, Attenuation)>,
+    ) -> Result<VoiceId, String> {
+        let sound = {
+            let mut assets = self.assets.write().map_err(|_| "asset store is unavailable".to_string())?;
+            self.audio.sound(&mut assets, path)?
+        };
+        Ok(self.audio.play(sound, params, position))
+    }
+
+    /// Move sources and positional sounds with their objects and the listener,
+    /// and emit `sound_finished` for the sounds that played to the end.
+    fn update_audio(&mut self) {
+        let camera_position = self.get_camera_position();
+        let finished = {
+            let (Ok(mut object_manager), Ok(mut assets)) = (self.object_manager.write(), self.assets.write()) else {
+                return;
+            };
+            self.audio.update(
+                self.time.unscaled_delta_time(),
+                &mut object_manager,
+                &mut assets,
+                camera_position,
+            )
+        };
+        for voice in finished {
+            self.event_bus.emit("sound_finished", EventPayload::Int(voice as i64));
+        }
+    }
+
+    pub fn set_world_label_component(
+        &mut self,
+        object_id: u32,
+        component_id: u32,
+        component: crate::core::component::WorldLabelComponent,
+    ) -> bool {
+        let updated = {
+            let Ok(mut object_manager) = self.object_manager.write() else {
+                return false;
+            };
+            let Some(object) = object_manager.get_object_by_id_mut(object_id) else {
+                return false;
+            };
+            let Some(existing) = object.get_component_by_id_mut(component_id) else {
+                return false;
+            };
+            let Some(label) = existing
+                .as_any_mut()
+                .downcast_mut::<crate::core::component::WorldLabelComponent>()
+            else {
+                return false;
+            };
+            *label = component;
+            true
+        };
+        if updated {
+            self.request_render_redraw();
+        }
+        updated
+    }
+
     pub fn add_component_to_game_object(
         &mut self,
         object_id: u32,
@@ -785,6 +1923,34 @@ impl Engine {
         removed
     }
 
+    /// Enable or disable a component on a runtime object by component name or type.
+    ///
+    /// Disabled colliders leave the collision world and disabled meshes and labels stop
+    /// rendering on the next frame. Returns false if the object or component is missing.
+    pub fn set_game_object_component_enabled(
+        &mut self,
+        object_id: u32,
+        key: &str,
+        enabled: bool,
+    ) -> bool {
+        let changed = {
+            let Ok(mut object_manager) = self.object_manager.write() else {
+                return false;
+            };
+            let Some(object) = object_manager.get_object_by_id_mut(object_id) else {
+                return false;
+            };
+            match object.set_component_enabled(key, enabled) {
+                Some(changed) => changed,
+                None => return false,
+            }
+        };
+        if changed {
+            self.request_render_redraw();
+        }
+        true
+    }
+
     pub fn remove_component_from_game_object_by_id(
         &mut self,
         object_id: u32,
@@ -984,7 +2150,7 @@ impl Engine {
         width: f32,
         height: f32,
         texture_key: String,
-        rgba: Vec<u8>,
+        rgba: impl Into<Arc<[u8]>>,
         texture_width: u32,
         texture_height: u32,
         draw_order: f32,
@@ -995,7 +2161,7 @@ impl Engine {
             width,
             height,
             texture_key,
-            Arc::from(rgba),
+            rgba.into(),
             texture_width,
             texture_height,
             draw_order,
@@ -1079,6 +2245,21 @@ impl Engine {
         self.request_render_redraw();
     }
 
+    /// Draw text centered on a world-space point, moving and zooming with the camera.
+    pub fn draw_world_text(
+        &mut self,
+        text: String,
+        x: f32,
+        y: f32,
+        style: TextStyle,
+        color: Color,
+        draw_order: f32,
+    ) {
+        self.draw_manager
+            .draw_world_text(text, x, y, style, color, draw_order);
+        self.request_render_redraw();
+    }
+
     pub fn register_font_family(
         &mut self,
         family: String,
@@ -1099,6 +2280,187 @@ impl Engine {
         }
     }
 
+    /// Set the fonts tried, in order, for characters a text style's own font
+    /// lacks. Entries are registered family names or `.ttf`/`.otf` paths.
+    pub fn set_font_fallbacks(&mut self, fallbacks: Vec<String>) {
+        if let Some(render_manager) = &mut self.render_manager {
+            render_manager.set_font_fallbacks(fallbacks.clone());
+        }
+        self.font_fallbacks = fallbacks;
+        self.request_render_redraw();
+    }
+
+    pub fn font_fallbacks(&self) -> &[String] {
+        &self.font_fallbacks
+    }
+
+    /// Set the UI theme's fallback fonts, used by UI text that lists none of its own.
+    ///
+    /// These take the place of the renderer-wide fallbacks for UI text.
+    pub fn set_ui_font_fallbacks(&mut self, fallbacks: Vec<String>) {
+        if let Some(ui_manager) = &mut self.ui_manager {
+            ui_manager.set_font_fallbacks(fallbacks.clone());
+        }
+        self.ui_font_fallbacks = fallbacks;
+        self.request_render_redraw();
+    }
+
+    pub fn ui_font_fallbacks(&self) -> &[String] {
+        &self.ui_font_fallbacks
+    }
+
+    /// Register a UI theme under `name`, replacing any theme with that name.
+    ///
+    /// Replacing the active theme restyles the UI right away.
+    pub fn register_ui_theme(&mut self, name: &str, theme: UITheme) {
+        if name == self.ui_theme_name
+            && let Some(ui_manager) = &mut self.ui_manager
+        {
+            ui_manager.set_theme(theme.clone());
+            self.request_render_redraw();
+        }
+        self.ui_themes.insert(name.to_string(), theme);
+    }
+
+    /// Get a registered UI theme, e.g. as the base for a custom one.
+    pub fn ui_theme(&self, name: &str) -> Option<&UITheme> {
+        self.ui_themes.get(name)
+    }
+
+    /// Switch the UI to a registered theme, restyling existing widgets.
+    ///
+    /// Widget colors that were set explicitly are kept.
+    pub fn set_ui_theme(&mut self, name: &str) -> Result<(), String> {
+        let theme = self
+            .ui_themes
+            .get(name)
+            .ok_or_else(|| format!("Unknown UI theme '{name}'"))?;
+        if let Some(ui_manager) = &mut self.ui_manager {
+            ui_manager.set_theme(theme.clone());
+        }
+        self.ui_theme_name = name.to_string();
+        self.request_render_redraw();
+        Ok(())
+    }
+
+    /// Name of the active UI theme.
+    pub fn ui_theme_name(&self) -> &str {
+        &self.ui_theme_name
+    }
+
+    /// Set how UI units map to window pixels, e.g. to lay a UI designed at
+    /// 1080p out the same way at 720p and 4K.
+    pub fn set_ui_scale_mode(&mut self, scale_mode: UIScaleMode) {
+        if let Some(ui_manager) = &mut self.ui_manager {
+            ui_manager.set_scale_mode(scale_mode);
+        }
+        self.ui_scale_mode = scale_mode;
+        self.request_render_redraw();
+    }
+
+    pub fn ui_scale_mode(&self) -> UIScaleMode {
+        self.ui_scale_mode
+    }
+
+    /// Give a UI widget keyboard focus, or clear focus with `None`.
+    ///
+    /// Returns false if the widget can't take focus: it is hidden,
+    /// non-interactable or not focusable, or the UI doesn't exist yet.
+    pub fn set_ui_focus(&mut self, object_id: Option<u32>) -> bool {
+        let Some(ui_manager) = &mut self.ui_manager else {
+            return object_id.is_none();
+        };
+        let Ok(mut object_manager) = self.object_manager.write() else {
+            return false;
+        };
+        let focused = ui_manager.set_focus(&mut object_manager, object_id);
+        drop(object_manager);
+        self.request_render_redraw();
+        focused
+    }
+
+    /// UI object that has keyboard focus, if any.
+    pub fn ui_focused_object(&self) -> Option<u32> {
+        self.ui_manager.as_ref().and_then(|ui_manager| ui_manager.focused_object())
+    }
+
+    /// Grey out a UI element and its children and stop them taking input,
+    /// while keeping them visible.
+    pub fn set_ui_interactable(&mut self, object_id: u32, interactable: bool) {
+        match &mut self.ui_manager {
+            Some(ui_manager) => {
+                ui_manager.set_interactable(object_id, interactable);
+                self.request_render_redraw();
+            }
+            None => {
+                self.pending_ui_interactable.insert(object_id, interactable);
+            }
+        }
+    }
+
+    pub fn is_ui_interactable(&self, object_id: u32) -> bool {
+        match &self.ui_manager {
+            Some(ui_manager) => ui_manager.is_interactable(object_id),
+            None => self.pending_ui_interactable.get(&object_id).copied().unwrap_or(true),
+        }
+    }
+
+    /// Set the outline drawn around the focused UI widget.
+    pub fn set_ui_focus_ring(&mut self, focus_ring: FocusRing) {
+        if let Some(ui_manager) = &mut self.ui_manager {
+            ui_manager.set_focus_ring(focus_ring);
+        }
+        self.ui_focus_ring = focus_ring;
+        self.request_render_redraw();
+    }
+
+    pub fn ui_focus_ring(&self) -> FocusRing {
+        self.ui_focus_ring
+    }
+
+    /// Start a UI property tween. Returns its id.
+    pub fn start_ui_tween(&mut self, tween: UITween) -> u32 {
+        let id = tween.id();
+        match &mut self.ui_manager {
+            Some(ui_manager) => {
+                ui_manager.add_tween(tween);
+                self.request_render_redraw();
+            }
+            None => {
+                self.pending_ui_tweens
+                    .retain(|other| other.object_id() != tween.object_id() || other.property() != tween.property());
+                self.pending_ui_tweens.push(tween);
+            }
+        }
+        id
+    }
+
+    /// Stop a UI tween where it is. Returns whether it was still running.
+    pub fn cancel_ui_tween(&mut self, tween_id: u32) -> bool {
+        let pending_count = self.pending_ui_tweens.len();
+        self.pending_ui_tweens.retain(|tween| tween.id() != tween_id);
+        self.pending_ui_tweens.len() != pending_count
+            || self
+                .ui_manager
+                .as_mut()
+                .is_some_and(|ui_manager| ui_manager.cancel_tween(tween_id))
+    }
+
+    /// Ids of the UI tweens that ended since the last call.
+    pub fn take_finished_ui_tweens(&mut self) -> Vec<u32> {
+        self.ui_manager
+            .as_mut()
+            .map(|ui_manager| ui_manager.take_finished_tweens())
+            .unwrap_or_default()
+    }
+
+    /// Names of the registered UI themes, sorted.
+    pub fn ui_theme_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.ui_themes.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
     pub fn measure_text(&mut self, text: &str, style: TextStyle) -> (f32, f32) {
         if let Some(render_manager) = &mut self.render_manager {
             return render_manager.measure_text(text, &style);
@@ -1118,6 +2480,151 @@ impl Engine {
         )
     }
 
+    /// Load a texture into the renderer cache before it is first drawn.
+    ///
+    /// Before the window exists the path is queued and loaded on window creation.
+    pub fn preload_texture(&mut self, texture_path: &str) -> Result<(), String> {
+        if let Some(render_manager) = &mut self.render_manager {
+            return render_manager.preload_texture(texture_path);
+        }
+        if !self
+            .pending_texture_preloads
+            .iter()
+            .any(|pending| pending == texture_path)
+        {
+            self.pending_texture_preloads.push(texture_path.to_string());
+        }
+        Ok(())
+    }
+
+    /// Pixel size of an image file, read from its header without decoding it.
+    pub fn texture_size(&self, texture_path: &str) -> Result<(u32, u32), String> {
+        let resolved_path = self.resolve_source_path(texture_path);
+        image::image_dimensions(&resolved_path).map_err(|error| {
+            format!(
+                "Failed to read image size of '{}': {error}",
+                resolved_path.display()
+            )
+        })
+    }
+
+    /// Drop a cached texture. Returns whether anything was removed.
+    pub fn unload_texture(&mut self, texture_path: &str) -> bool {
+        if let Some(render_manager) = &mut self.render_manager {
+            let removed = render_manager.unload_texture(texture_path);
+            if removed {
+                self.request_render_redraw();
+            }
+            return removed;
+        }
+        let pending_count = self.pending_texture_preloads.len();
+        self.pending_texture_preloads
+            .retain(|pending| pending != texture_path);
+        self.pending_texture_preloads.len() != pending_count
+    }
+
+    /// Approximate GPU memory held by cached textures, in bytes.
+    pub fn texture_memory_usage(&self) -> u64 {
+        self.render_manager
+            .as_ref()
+            .map_or(0, RenderManager::texture_memory_usage)
+    }
+
+    /// Number of textures currently resident in the renderer cache.
+    pub fn cached_texture_count(&self) -> usize {
+        self.render_manager
+            .as_ref()
+            .map_or(0, RenderManager::cached_texture_count)
+    }
+
+    /// Set the texture cache budget in bytes (`None` for unlimited).
+    pub fn set_texture_budget(&mut self, budget_bytes: Option<u64>) {
+        self.pending_texture_budget = budget_bytes;
+        if let Some(render_manager) = &mut self.render_manager {
+            render_manager.set_texture_budget(budget_bytes);
+        }
+    }
+
+    /// Get the texture cache budget in bytes, if any.
+    pub fn texture_budget(&self) -> Option<u64> {
+        self.pending_texture_budget
+    }
+
+    /// Group sprites of equal draw order by texture so they batch into fewer
+    /// draw calls (on by default), see [`RenderManager::set_sort_by_texture`].
+    pub fn set_sort_sprites_by_texture(&mut self, enabled: bool) {
+        self.sort_sprites_by_texture = enabled;
+        if let Some(render_manager) = &mut self.render_manager {
+            render_manager.set_sort_by_texture(enabled);
+        }
+    }
+
+    pub fn sort_sprites_by_texture(&self) -> bool {
+        self.sort_sprites_by_texture
+    }
+
+    /// Batching statistics for the last rendered frame; zero before the first frame.
+    pub fn render_stats(&self) -> RenderStats {
+        self.render_manager
+            .as_ref()
+            .map(RenderManager::render_stats)
+            .unwrap_or_default()
+    }
+
+    /// Build a JSON snapshot of the scene, collision world and draw queue.
+    ///
+    /// See `dump_debug_snapshot` for writing it to a file.
+    pub fn debug_snapshot(&self) -> serde_json::Value {
+        let scene = match self.object_manager.read() {
+            Ok(object_manager) => debug_snapshot::scene_snapshot(&object_manager),
+            Err(_) => serde_json::Value::Null,
+        };
+        let collision = self
+            .collision_world
+            .as_ref()
+            .map(debug_snapshot::collision_snapshot)
+            .unwrap_or(serde_json::Value::Null);
+        let clipped = self
+            .draw_manager
+            .clip_rects()
+            .iter()
+            .filter(|clip| clip.is_some())
+            .count();
+        let mut draw =
+            debug_snapshot::draw_snapshot(self.draw_manager.commands(), clipped, self.render_stats());
+        draw["ui_cached_widgets"] = serde_json::json!(
+            self.ui_manager
+                .as_ref()
+                .map(UIManager::cached_widget_count)
+                .unwrap_or(0)
+        );
+        let (display_width, display_height) = self.get_display_size();
+
+        serde_json::json!({
+            "format": debug_snapshot::SNAPSHOT_FORMAT,
+            "version": debug_snapshot::SNAPSHOT_VERSION,
+            "engine_version": VERSION,
+            "time": {
+                "frame": self.time.tick_count(),
+                "elapsed": self.time.elapsed_time(),
+                "delta": self.time.delta_time(),
+            },
+            "display": [display_width, display_height],
+            "active_camera": self.active_camera_object_id(),
+            "objects": scene,
+            "collision": collision,
+            "draw": draw,
+        })
+    }
+
+    /// Write `debug_snapshot()` to `path` as pretty-printed JSON.
+    pub fn dump_debug_snapshot(&self, path: &str) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(&self.debug_snapshot())
+            .map_err(|e| format!("Failed to serialize debug snapshot: {e}"))?;
+        std::fs::write(path, json)
+            .map_err(|e| format!("Failed to write debug snapshot '{path}': {e}"))
+    }
+
     /// Push a fully-custom direct draw command.
     pub fn add_draw_command(&mut self, command: DrawCommand) {
         self.draw_manager.add_command(command);
@@ -1136,6 +2643,11 @@ impl Engine {
     /// Process all queued commands
     fn process_commands(&mut self) {
         while let Ok(command) = self.command_receiver.try_recv() {
+            if let Some(object_id) = command.ui_object_id()
+                && let Some(ui_manager) = &mut self.ui_manager
+            {
+                ui_manager.mark_dirty(object_id);
+            }
             match command {
                 EngineCommand::AddGameObject(object) => {
                     self.add_game_object(object);
@@ -1143,6 +2655,28 @@ impl Engine {
                 EngineCommand::RemoveGameObject(id) => {
                     self.remove_game_object(id);
                 }
+                EngineCommand::EmitEvent { name, payload } => {
+                    self.event_bus.emit(name, payload);
+                }
+                EngineCommand::SendMessage {
+                    target_id,
+                    name,
+                    payload,
+                } => {
+                    let _ = self.send_message(target_id, name, payload);
+                }
+                EngineCommand::RequestQuit => {
+                    self.request_quit();
+                }
+                EngineCommand::SetTimeScale(time_scale) => {
+                    self.time.set_time_scale(time_scale);
+                }
+                EngineCommand::SetPaused(paused) => {
+                    self.time.set_paused(paused);
+                }
+                EngineCommand::SetFixedTimestep(fixed_timestep) => {
+                    self.time.set_fixed_timestep(fixed_timestep);
+                }
                 EngineCommand::SetGameObjectPosition {
                     object_id,
                     position,
@@ -1152,6 +2686,12 @@ impl Engine {
                 EngineCommand::SetGameObjectName { object_id, name } => {
                     let _ = self.set_game_object_name(object_id, name);
                 }
+                EngineCommand::AddGameObjectTag { object_id, tag } => {
+                    let _ = self.add_game_object_tag(object_id, &tag);
+                }
+                EngineCommand::RemoveGameObjectTag { object_id, tag } => {
+                    let _ = self.remove_game_object_tag(object_id, &tag);
+                }
                 EngineCommand::SetGameObjectEnabled { object_id, enabled } => {
                     let _ = self.set_game_object_enabled(object_id, enabled);
                 }
@@ -1174,6 +2714,27 @@ impl Engine {
                 } => {
                     let _ = self.set_text_mesh_component(object_id, component_id, component);
                 }
+                EngineCommand::SetWorldLabelComponent {
+                    object_id,
+                    component_id,
+                    component,
+                } => {
+                    let _ = self.set_world_label_component(object_id, component_id, component);
+                }
+                EngineCommand::SetAudioSourceComponent {
+                    object_id,
+                    component_id,
+                    component,
+                } => {
+                    let _ = self.set_audio_source_component(object_id, component_id, component);
+                }
+                EngineCommand::SetAudioListenerComponent {
+                    object_id,
+                    component_id,
+                    component,
+                } => {
+                    let _ = self.set_audio_listener_component(object_id, component_id, component);
+                }
                 EngineCommand::AddChild {
                     parent_id,
                     child_id,
@@ -1192,6 +2753,13 @@ impl Engine {
                 EngineCommand::RemoveComponentByName { object_id, name } => {
                     let _ = self.remove_component_from_game_object_by_name(object_id, &name);
                 }
+                EngineCommand::SetComponentEnabled {
+                    object_id,
+                    key,
+                    enabled,
+                } => {
+                    let _ = self.set_game_object_component_enabled(object_id, &key, enabled);
+                }
                 EngineCommand::RemoveComponentById {
                     object_id,
                     component_id,
@@ -1348,6 +2916,41 @@ impl Engine {
                 EngineCommand::RegisterFontFamily { family, definition } => {
                     let _ = self.register_font_family(family, definition);
                 }
+                EngineCommand::SetFontFallbacks(fallbacks) => {
+                    self.set_font_fallbacks(fallbacks);
+                }
+                EngineCommand::SetUIFontFallbacks(fallbacks) => {
+                    self.set_ui_font_fallbacks(fallbacks);
+                }
+                EngineCommand::SetUIScaleMode(scale_mode) => {
+                    self.set_ui_scale_mode(scale_mode);
+                }
+                EngineCommand::SetUIFocus(object_id) => {
+                    self.set_ui_focus(object_id);
+                }
+                EngineCommand::SetUIInteractable { object_id, interactable } => {
+                    self.set_ui_interactable(object_id, interactable);
+                }
+                EngineCommand::SetUIHoverCursor { object_id, cursor } => {
+                    self.set_ui_hover_cursor(object_id, cursor);
+                }
+                EngineCommand::SetUIInputContext { object_id, context } => {
+                    self.set_ui_input_context(object_id, context);
+                }
+                EngineCommand::SetUIFocusRing(focus_ring) => {
+                    self.set_ui_focus_ring(focus_ring);
+                }
+                EngineCommand::SetUITheme(name) => {
+                    if let Err(err) = self.set_ui_theme(&name) {
+                        logging::log_warn(&err);
+                    }
+                }
+                EngineCommand::StartUITween(tween) => {
+                    self.start_ui_tween(tween);
+                }
+                EngineCommand::CancelUITween(tween_id) => {
+                    self.cancel_ui_tween(tween_id);
+                }
                 EngineCommand::UpdateUILabelText { object_id, text } => {
                     if let Ok(mut object_manager) = self.object_manager.write() {
                         if let Some(obj) = object_manager.get_object_by_id_mut(object_id) {
@@ -1374,6 +2977,174 @@ impl Engine {
                         }
                     }
                 }
+                EngineCommand::UpdateUITextInputText { object_id, text } => {
+                    if let Ok(mut object_manager) = self.object_manager.write()
+                        && let Some(obj) = object_manager.get_object_by_id_mut(object_id)
+                        && let Some(comp) = obj.get_component_by_name_mut("TextInput")
+                        && let Some(input) = comp
+                            .as_any_mut()
+                            .downcast_mut::<crate::core::ui::text_input::TextInputComponent>()
+                    {
+                        input.set_text(text);
+                    }
+                }
+                EngineCommand::UpdateUISliderValue { object_id, value } => {
+                    if let Ok(mut object_manager) = self.object_manager.write()
+                        && let Some(obj) = object_manager.get_object_by_id_mut(object_id)
+                        && let Some(comp) = obj.get_component_by_name_mut("Slider")
+                        && let Some(slider) = comp
+                            .as_any_mut()
+                            .downcast_mut::<crate::core::ui::slider::SliderComponent>()
+                    {
+                        slider.set_value(value);
+                    }
+                }
+                EngineCommand::UpdateUIChecked { object_id, checked } => {
+                    if let Ok(mut object_manager) = self.object_manager.write()
+                        && let Some(obj) = object_manager.get_object_by_id_mut(object_id)
+                    {
+                        if let Some(comp) = obj.get_component_by_name_mut("Checkbox")
+                            && let Some(checkbox) = comp
+                                .as_any_mut()
+                                .downcast_mut::<crate::core::ui::checkbox::CheckboxComponent>()
+                        {
+                            checkbox.set_checked(checked);
+                        } else if let Some(comp) = obj.get_component_by_name_mut("ToggleSwitch")
+                            && let Some(switch) = comp
+                                .as_any_mut()
+                                .downcast_mut::<crate::core::ui::toggle_switch::ToggleSwitchComponent>()
+                        {
+                            switch.set_checked(checked);
+                        } else if let Some(radio) = obj.get_component_mut::<RadioButtonComponent>() {
+                            if checked {
+                                let deselected =
+                                    UIManager::select_radio_button(&mut object_manager, object_id, false);
+                                if let Some(ui_manager) = &mut self.ui_manager {
+                                    for id in deselected {
+                                        ui_manager.mark_dirty(id);
+                                    }
+                                }
+                            } else {
+                                radio.set_selected(false);
+                            }
+                        }
+                    }
+                }
+                EngineCommand::UpdateUIScrollPosition { object_id, x, y } => {
+                    if let Ok(mut object_manager) = self.object_manager.write()
+                        && let Some(obj) = object_manager.get_object_by_id_mut(object_id)
+                    {
+                        if let Some(comp) = obj.get_component_by_name_mut("ScrollView")
+                            && let Some(scroll_view) = comp
+                                .as_any_mut()
+                                .downcast_mut::<crate::core::ui::scroll_view::ScrollViewComponent>()
+                        {
+                            scroll_view.set_scroll(x, y);
+                        } else if let Some(list) = obj.get_component_mut::<ListViewComponent>() {
+                            list.set_scroll_offset(y);
+                        }
+                    }
+                }
+                EngineCommand::UpdateUIProgressValue { object_id, value } => {
+                    if let Ok(mut object_manager) = self.object_manager.write()
+                        && let Some(obj) = object_manager.get_object_by_id_mut(object_id)
+                        && let Some(comp) = obj.get_component_by_name_mut("ProgressBar")
+                        && let Some(bar) = comp
+                            .as_any_mut()
+                            .downcast_mut::<crate::core::ui::progress_bar::ProgressBarComponent>()
+                    {
+                        bar.set_value(value);
+                    }
+                }
+                EngineCommand::UpdateUIPanelClip { object_id, clip_children } => {
+                    if let Ok(mut object_manager) = self.object_manager.write()
+                        && let Some(obj) = object_manager.get_object_by_id_mut(object_id)
+                        && let Some(panel) = obj.get_component_mut::<PanelComponent>()
+                    {
+                        panel.set_clip_children(clip_children);
+                    }
+                }
+                EngineCommand::UpdateUIImageTexture { object_id, texture_path } => {
+                    if let Ok(mut object_manager) = self.object_manager.write()
+                        && let Some(obj) = object_manager.get_object_by_id_mut(object_id)
+                        && let Some(image) = obj.get_component_mut::<ImageComponent>()
+                    {
+                        image.set_texture(texture_path);
+                        self.load_image_texture_size(image);
+                    }
+                }
+                EngineCommand::UpdateUIImageScaleMode { object_id, scale_mode } => {
+                    if let Ok(mut object_manager) = self.object_manager.write()
+                        && let Some(obj) = object_manager.get_object_by_id_mut(object_id)
+                        && let Some(image) = obj.get_component_mut::<ImageComponent>()
+                    {
+                        image.set_scale_mode(scale_mode);
+                    }
+                }
+                EngineCommand::SetUILayout { object_id, layout } => {
+                    if let Ok(mut object_manager) = self.object_manager.write()
+                        && let Some(obj) = object_manager.get_object_by_id_mut(object_id)
+                    {
+                        obj.remove_component_by_name("UILayout");
+                        if let Some(layout) = layout {
+                            obj.add_component(Box::new(layout));
+                        }
+                    }
+                }
+                EngineCommand::UpdateUIStack {
+                    object_id,
+                    direction,
+                    spacing,
+                    padding,
+                    alignment,
+                    fit_content,
+                } => {
+                    if let Ok(mut object_manager) = self.object_manager.write()
+                        && let Some(obj) = object_manager.get_object_by_id_mut(object_id)
+                        && let Some(stack) = obj.get_component_mut::<StackComponent>()
+                    {
+                        stack.set_direction(direction);
+                        stack.set_spacing(spacing);
+                        stack.set_padding(padding);
+                        stack.set_alignment(alignment);
+                        stack.set_fit_content(fit_content);
+                    }
+                }
+                EngineCommand::UpdateUICurve { object_id, curve } => {
+                    if let Ok(mut object_manager) = self.object_manager.write()
+                        && let Some(obj) = object_manager.get_object_by_id_mut(object_id)
+                        && let Some(editor) = obj.get_component_mut::<CurveEditorComponent>()
+                    {
+                        editor.set_curve(curve);
+                    }
+                }
+                EngineCommand::UpdateUIListItemCount { object_id, item_count } => {
+                    if let Ok(mut object_manager) = self.object_manager.write()
+                        && let Some(obj) = object_manager.get_object_by_id_mut(object_id)
+                        && let Some(list) = obj.get_component_mut::<ListViewComponent>()
+                    {
+                        list.set_item_count(item_count);
+                    }
+                }
+                EngineCommand::UpdateUIListSelection { object_id, selected } => {
+                    if let Ok(mut object_manager) = self.object_manager.write()
+                        && let Some(obj) = object_manager.get_object_by_id_mut(object_id)
+                        && let Some(list) = obj.get_component_mut::<ListViewComponent>()
+                    {
+                        list.set_selected(selected);
+                        if let Some(index) = list.selected() {
+                            list.scroll_to_item(index);
+                        }
+                    }
+                }
+                EngineCommand::RefreshUIList { object_id } => {
+                    if let Ok(mut object_manager) = self.object_manager.write()
+                        && let Some(obj) = object_manager.get_object_by_id_mut(object_id)
+                        && let Some(list) = obj.get_component_mut::<ListViewComponent>()
+                    {
+                        list.refresh();
+                    }
+                }
                 EngineCommand::LogTrace(message) => {
                     logging::log_trace(&message);
                 }
@@ -1394,7 +3165,71 @@ impl Engine {
     }
 
     /// Engine update loop
+    ///
+    /// Runs the update phases in order, with the script hooks each phase
+    /// queues run in between, then dispatches the event bus. The fixed step
+    /// and collision phases repeat for every fixed step that is due. Bindings that
+    /// need to release the engine while scripts and event handlers run call
+    /// the phases, `take_script_calls` and `event_bus.take_dispatch` themselves.
     pub fn update(&mut self) {
+        self.update_frame();
+        self.run_script_calls();
+        while self.update_fixed() {
+            self.run_script_calls();
+            self.update_collisions();
+            self.run_script_calls();
+        }
+        let started = self.profiler.start();
+        self.event_bus.dispatch();
+        self.profiler.record(ProfileScope::Scripts, started);
+
+        // Event System - enqueue physics events (collisions/triggers)
+
+        // GameObjects + Components - post-physics / late update (react, sync transforms, camera, attachments)
+
+        // UI - update layout/animations/data-binding (using final game state)
+
+        // **Frame rate limiting (optional)**
+        // Rendering - world
+        // Rendering - UI
+
+        // ------------------------------------------------------------
+        // IF HEADLESS, DO THE FOLLOWING:
+        // ------------------------------------------------------------
+
+        // Time step/tick management, (i.e., delta time is not based on system time, but rather a fixed timestep)
+        // Input ("virtual" input: network commands, bots, scripted tests)
+        // Event system - enqueue input events
+        // GameObjects + Components - pre-physics (gameplay/AI/scripts)
+        // Physics (often fixed-timestep; may run 0..N steps)
+        // Event System - enqueue physics events (collisions/triggers)
+        // GameObjects + Components - post-physics / late update
+        // Event System - dispatch deffered events (end-of-tick)
+        // Networking/persistance (optional but common): replicate state, process outgoing packets, write snapshots
+
+        // ^^^ Note: Key differences are no rendering, UI is disabled, simulation runs at fixed timestep
+    }
+
+    /// Take the script hooks queued by the last update phase, in call order.
+    pub fn take_script_calls(&mut self) -> Vec<ScriptCall> {
+        std::mem::take(&mut self.script_calls)
+    }
+
+    fn run_script_calls(&mut self) {
+        let started = self.profiler.start();
+        for call in self.take_script_calls() {
+            call.invoke();
+        }
+        self.profiler.record(ProfileScope::Scripts, started);
+    }
+
+    /// First update phase: commands, time, input, UI and the per-frame
+    /// component update. Queues script `on_start` and `update` hooks.
+    ///
+    /// While time is paused only UI objects update and no hooks are queued.
+    pub fn update_frame(&mut self) {
+        self.profiler.begin_frame();
+        let started = self.profiler.start();
         if let Some(render_manager) = &mut self.render_manager {
             // `about_to_wait` can precompute a signature for redraw checks.
             // Simulation updates can change scene state, so invalidate it.
@@ -1405,26 +3240,54 @@ impl Engine {
         // Process Commands First
         // ------------------------------------------------------------
         self.process_commands();
+        // Objects marked last frame after it rendered, or with no render at all
+        self.flush_destroyed_objects();
         self.ensure_active_camera_object();
+        self.poll_asset_changes();
+        self.profiler.record(ProfileScope::Update, started);
 
         // ------------------------------------------------------------
         // IF NOT HEADLESS, DO THE FOLLOWING:
         // ------------------------------------------------------------
 
         // Time step/tick management
+        let started = self.profiler.start();
         self.time.tick();
 
         // Input (collect raw input + build an input snapshot)
         if let Some(input_manager) = &mut self.input_manager {
-            input_manager.update();
+            self.gamepads.poll(input_manager);
+            input_manager.update(self.time.unscaled_delta_time());
+            if input_manager.active_device_changed() && self.auto_hide_cursor {
+                self.apply_cursor_visibility();
+            }
+        }
+        if let Some(input_manager) = &self.input_manager {
+            let was_shown = self.input_debug_overlay.is_enabled();
+            self.input_debug_overlay.record(input_manager, self.time.elapsed_time());
+            // The overlay shows live state, so keep redrawing while it is up.
+            if was_shown || self.input_debug_overlay.is_enabled() {
+                self.request_render_redraw();
+            }
         }
 
+        self.profiler.record(ProfileScope::Input, started);
+
         // Event System - enqueue input events
 
         // UI - input handling / hit-testing (UI gets first right of refusal)
+        let started = self.profiler.start();
         if let (Some(ui_manager), Some(input_manager)) = (&mut self.ui_manager, &self.input_manager) {
+            // Includes the frame a tween reaches its final value.
+            let animating = ui_manager.has_active_tweens();
+            ui_manager.advance_tweens(self.time.unscaled_delta_time());
             let consumed = if let Ok(mut object_manager) = self.object_manager.write() {
                 ui_manager.update(input_manager, &mut object_manager);
+                if let Some(render_manager) = &mut self.render_manager {
+                    ui_manager.fit_label_heights(&mut object_manager, &mut |text, style, layout| {
+                        render_manager.measure_text_layout(text, style, layout)
+                    });
+                }
                 if ui_manager.is_input_consumed() {
                     object_manager.mark_scene_dirty();
                     true
@@ -1434,76 +3297,114 @@ impl Engine {
             } else {
                 false
             };
-            if consumed {
+            if consumed || animating {
                 self.request_render_redraw();
             }
         }
+        self.update_ime();
+        self.profiler.record(ProfileScope::Ui, started);
 
         // Event System - dispatch "unconsumed" gameplay input events
 
         // GameObjects + Components - pre-physics (gameplay/AI/scripts)
+        let started = self.profiler.start();
+        self.update_objects();
+        // Audio - sources follow their objects and the listener
+        self.update_audio();
+        self.profiler.record(ProfileScope::Update, started);
+    }
+
+    /// Deliver messages and run the per-frame component update.
+    fn update_objects(&mut self) {
         if let Ok(mut object_manager) = self.object_manager.write() {
             if object_manager.get_total_objects() > 0 {
                 object_manager.mark_scene_dirty();
             }
 
-            let keys = object_manager.get_keys().to_vec();
-            for key in keys {
-                if let Some(object) = object_manager.get_object_by_id(key) {
-                    object.update(&self.time);
+            // While paused only UI objects update; messages wait for the resume
+            let paused = self.time.is_paused();
+            if paused {
+                for object in object_manager.iter() {
+                    if object.get_object_type() == ObjectType::UIObject {
+                        object.update(&self.time);
+                    }
+                }
+                return;
+            }
+
+            // Messages first, so components can react to them this frame
+            let messages: Vec<Arc<Message>> = std::mem::take(&mut self.pending_messages)
+                .into_iter()
+                .map(Arc::new)
+                .collect();
+            for message in &messages {
+                if let Some(object) = object_manager.get_object_by_id(message.target_id) {
+                    object.receive_message(&message.name, &message.payload);
                 }
             }
+
+            let time = &self.time;
+            object_manager.for_each_scheduled(self.parallel_updates, |object| object.update(time));
+            script::queue_update_calls(&object_manager, self.time.delta_time(), &messages, &mut self.script_calls);
         }
+    }
 
+    /// Second update phase: the fixed-timestep component update, if a fixed
+    /// step is due. Queues script `fixed_update` hooks.
+    ///
+    /// Returns whether a fixed step ran; collisions are only detected then.
+    /// Call it again after each step's collisions until it returns false to
+    /// catch up on every step due this frame.
+    pub fn update_fixed(&mut self) -> bool {
         // **Fixed update:**
         // Physics (often fixed-timestep; may run 0..N steps)
+        let started = self.profiler.start();
         let (is_fixed_time, fixed_time) = self.time.tick_fixed();
         if is_fixed_time && let Ok(mut object_manager) = self.object_manager.write() {
             if object_manager.get_total_objects() > 0 {
                 object_manager.mark_scene_dirty();
             }
 
-            let keys = object_manager.get_keys().to_vec();
-            for key in keys {
-                if let Some(object) = object_manager.get_object_by_id(key) {
-                    object.fixed_update(&self.time, fixed_time);
-                }
-            }
-
-            if let Some(collision_world) = &mut self.collision_world {
-                collision_world.step(&object_manager);
+            let time = &self.time;
+            object_manager.for_each_scheduled(self.parallel_updates, |object| {
+                object.fixed_update(time, fixed_time)
+            });
+            script::queue_fixed_update_calls(&object_manager, fixed_time, &mut self.script_calls);
+        }
+        self.profiler.record(ProfileScope::Fixed, started);
+        is_fixed_time
+    }
+
+    /// Third update phase, after a fixed step: collision detection. Queues
+    /// script collision hooks and plays impact sounds.
+    pub fn update_collisions(&mut self) {
+        let started = self.profiler.start();
+        if let (Some(collision_world), Ok(object_manager)) = (&mut self.collision_world, self.object_manager.read()) {
+            collision_world.step(&object_manager);
+            script::queue_collision_calls(&object_manager, collision_world.collision_events(), &mut self.script_calls);
+            if !self.audio.impact_sounds().is_empty()
+                && let Ok(mut assets) = self.assets.write()
+            {
+                self.audio
+                    .play_impacts(collision_world.collision_events(), &object_manager, &mut assets);
             }
         }
-
-        // Event System - enqueue physics events (collisions/triggers)
-
-        // GameObjects + Components - post-physics / late update (react, sync transforms, camera, attachments)
-
-        // UI - update layout/animations/data-binding (using final game state)
-
-        // **Frame rate limiting (optional)**
-        // Rendering - world
-        // Rendering - UI
-
-        // ------------------------------------------------------------
-        // IF HEADLESS, DO THE FOLLOWING:
-        // ------------------------------------------------------------
-
-        // Time step/tick management, (i.e., delta time is not based on system time, but rather a fixed timestep)
-        // Input ("virtual" input: network commands, bots, scripted tests)
-        // Event system - enqueue input events
-        // GameObjects + Components - pre-physics (gameplay/AI/scripts)
-        // Physics (often fixed-timestep; may run 0..N steps)
-        // Event System - enqueue physics events (collisions/triggers)
-        // GameObjects + Components - post-physics / late update
-        // Event System - dispatch deffered events (end-of-tick)
-        // Networking/persistance (optional but common): replicate state, process outgoing packets, write snapshots
-
-        // ^^^ Note: Key differences are no rendering, UI is disabled, simulation runs at fixed timestep
+        self.profiler.record(ProfileScope::Physics, started);
     }
 
     /// Render a frame
     pub fn render(&mut self) {
+        self.render_frame();
+
+        // Manual loops have no event-loop deadline to wait on, so pace here.
+        if !self.auto_step_on_redraw {
+            self.pacing_frame_limiter().throttle();
+        }
+    }
+
+    fn render_frame(&mut self) {
+        let started = self.profiler.start();
+        self.flush_destroyed_objects();
         self.ensure_active_camera_object();
 
         // Render UI elements
@@ -1513,6 +3414,21 @@ impl Engine {
             ui_manager.render(&mut self.draw_manager, &object_manager);
         }
 
+        // Draw the input debug overlay for this frame only.
+        let overlay_start = match &self.input_manager {
+            Some(input_manager) if self.input_debug_overlay.is_enabled() => {
+                let start = self
+                    .input_debug_overlay
+                    .render(input_manager, &mut self.draw_manager, self.time.elapsed_time());
+                if let Some(window_manager) = &self.window_manager {
+                    self.draw_manager
+                        .scale_commands_from(start, window_manager.scale_factor() as f32);
+                }
+                Some(start)
+            }
+            _ => None,
+        };
+
         if let Some(render_manager) = &mut self.render_manager {
             let render_result = if let Ok(object_manager) = self.object_manager.read() {
                 render_manager.render(&object_manager, Some(&self.draw_manager))
@@ -1542,6 +3458,42 @@ impl Engine {
                     logging::log_warn(&format!("Surface error: {:?}", e));
                 }
             }
+        } else if self.software_display.is_some() {
+            let background = self.camera_background_color();
+            if let Some(software_display) = &mut self.software_display
+                && let Err(err) = software_display.present(&self.draw_manager, background)
+            {
+                logging::log_warn(&format!("Software display failed, running headless: {err}"));
+                self.software_display = None;
+            }
+        }
+
+        if let Some(start) = overlay_start {
+            self.draw_manager.truncate_from(start);
+        }
+        self.profiler.record(ProfileScope::Render, started);
+    }
+
+    /// Frame limiter pacing the loop: the user's cap, or the fallback cap for
+    /// an uncapped window without a GPU renderer, which would otherwise
+    /// redraw as fast as the loop spins.
+    fn pacing_frame_limiter(&mut self) -> &mut FrameLimiter {
+        if self.render_manager.is_none() && self.window_manager.is_some() && !self.frame_limiter.is_enabled() {
+            &mut self.fallback_frame_limiter
+        } else {
+            &mut self.frame_limiter
+        }
+    }
+
+    /// Event-loop control flow used when no frame cap is pending.
+    fn default_control_flow() -> ControlFlow {
+        #[cfg(target_os = "macos")]
+        {
+            ControlFlow::Wait
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            ControlFlow::Poll
         }
     }
 
@@ -1565,9 +3517,13 @@ impl Engine {
 
         if let Some(ui_manager) = &mut self.ui_manager {
             ui_manager.resize(physical_size.width as f32, physical_size.height as f32);
+            if let Some(window_manager) = &self.window_manager {
+                ui_manager.set_scale_factor(window_manager.scale_factor() as f32);
+            }
         }
 
         if let Some(window_manager) = &self.window_manager {
+            self.repaint_after_resize = !self.auto_step_on_redraw;
             window_manager.request_redraw();
         }
     }
@@ -1620,6 +3576,46 @@ impl Engine {
     pub fn version(&self) -> &str {
         &self.version
     }
+
+    /// Report GPU adapter info, supported formats and limits, and enabled
+    /// engine features. The renderer part is `None` until the window exists.
+    pub fn capabilities(&self) -> EngineCapabilities {
+        let mut features = Vec::new();
+        if cfg!(feature = "python-bindings") {
+            features.push("python-bindings");
+        }
+        if self.is_vsync_enabled() {
+            features.push("vsync");
+        }
+        let redraw_on_change_only = match &self.render_manager {
+            Some(render_manager) => render_manager.is_redraw_on_change_only(),
+            None => self.window_config.as_ref().is_some_and(|config| config.redraw_on_change_only),
+        };
+        if redraw_on_change_only {
+            features.push("redraw_on_change_only");
+        }
+        if self.camera_pixel_perfect_resolution().is_some() {
+            features.push("pixel_perfect");
+        }
+        if self.target_fps().is_some() {
+            features.push("frame_limiter");
+        }
+        if self.input_debug_overlay() {
+            features.push("input_debug_overlay");
+        }
+        match &self.render_manager {
+            Some(render_manager) if render_manager.is_safe_mode() => features.push("safe_mode"),
+            None if self.software_display.is_some() => features.push("software_display"),
+            None if self.window_manager.is_some() => features.push("headless"),
+            _ => {}
+        }
+
+        EngineCapabilities {
+            engine_version: self.version.clone(),
+            renderer: self.render_manager.as_ref().map(RenderManager::capabilities),
+            features: features.into_iter().map(str::to_string).collect(),
+        }
+    }
 }
 
 impl Default for Engine {
@@ -1638,7 +3634,7 @@ impl ApplicationHandler for Engine {
                 if let Some(pending_color) = self.pending_camera_background_color {
                     bg_color = Some(pending_color);
                 }
-                let vsync = config.vsync;
+                let vsync = self.pending_vsync.unwrap_or(config.vsync);
                 let redraw_on_change_only = config.redraw_on_change_only;
                 self.base_window_title = config.title.clone();
                 self.show_fps_in_title = config.show_fps_in_title;
@@ -1650,67 +3646,130 @@ impl ApplicationHandler for Engine {
 
                         // Create render manager with the window Arc
                         let window = window_manager.window_arc();
-                        match pollster::block_on(RenderManager::new(
+                        // Without any usable adapter, keep running on the CPU display:
+                        // the game loop, input and UI still work, and simple draw
+                        // commands or the error are shown.
+                        self.render_manager = match pollster::block_on(RenderManager::new(
                             window,
                             bg_color,
                             vsync,
                             redraw_on_change_only,
+                            Arc::clone(&self.assets),
                         )) {
                             Ok(render_manager) => {
-                                logging::log_info("Render manager initialized successfully");
-                                self.render_manager = Some(render_manager);
-
-                                if let Some(render_manager) = &mut self.render_manager {
-                                    for (family, definition) in self.registered_font_families.clone() {
-                                        render_manager.register_font_family(family, definition);
-                                    }
-                                    render_manager.set_source_root(self.source_root.clone());
+                                if render_manager.is_safe_mode() {
+                                    logging::log_warn("Render manager initialized in safe mode (software adapter)");
+                                } else {
+                                    logging::log_info("Render manager initialized successfully");
                                 }
-
-                                // Initialize UI manager with window size and scale factor
-                                let window_size = window_manager.size();
-                                let scale_factor = window_manager.scale_factor() as f32;
-                                self.ui_manager = Some(UIManager::new(
-                                    window_size.width as f32,
-                                    window_size.height as f32,
-                                    scale_factor,
+                                Some(render_manager)
+                            }
+                            Err(e) => {
+                                logging::log_error(&format!(
+                                    "Failed to create render manager, running without a renderer: {}",
+                                    e
                                 ));
-                                logging::log_info("UI manager initialized");
-
-                                self.window_manager = Some(window_manager);
-                                self.ensure_active_camera_object();
+                                self.software_display = match SoftwareDisplay::new(
+                                    window_manager.window_arc(),
+                                    format!("Rendering unavailable: {e}"),
+                                ) {
+                                    Ok(software_display) => Some(software_display),
+                                    Err(err) => {
+                                        logging::log_warn(&format!(
+                                            "Software display unavailable, running headless: {err}"
+                                        ));
+                                        None
+                                    }
+                                };
+                                None
+                            }
+                        };
 
-                                if let Some(viewport_size) = self.pending_camera_viewport_size
-                                    && let Some(render_manager) = &mut self.render_manager
+                        if let Some(render_manager) = &mut self.render_manager {
+                            for (family, definition) in self.registered_font_families.clone() {
+                                render_manager.register_font_family(family, definition);
+                            }
+                            render_manager.set_font_fallbacks(self.font_fallbacks.clone());
+                            render_manager.set_texture_budget(self.pending_texture_budget);
+                            render_manager.set_sort_by_texture(self.sort_sprites_by_texture);
+                            for texture_path in
+                                std::mem::take(&mut self.pending_texture_preloads)
+                            {
+                                if let Err(err) =
+                                    render_manager.preload_texture(&texture_path)
                                 {
-                                    render_manager.set_camera_viewport_size(
-                                        viewport_size.x(),
-                                        viewport_size.y(),
-                                    );
+                                    logging::log_warn(&format!(
+                                        "Texture preload failed: {err}"
+                                    ));
                                 }
+                            }
+                        }
 
-                                if let Some(render_manager) = &mut self.render_manager {
-                                    render_manager
-                                        .set_camera_aspect_mode(self.pending_camera_aspect_mode);
-                                }
+                        // Initialize UI manager with window size and scale factor
+                        let window_size = window_manager.size();
+                        let scale_factor = window_manager.scale_factor() as f32;
+                        let mut ui_manager = UIManager::new(
+                            window_size.width as f32,
+                            window_size.height as f32,
+                            scale_factor,
+                        );
+                        if let Some(theme) = self.ui_themes.get(&self.ui_theme_name) {
+                            ui_manager.set_theme(theme.clone());
+                        }
+                        ui_manager.set_font_fallbacks(self.ui_font_fallbacks.clone());
+                        ui_manager.set_scale_mode(self.ui_scale_mode);
+                        ui_manager.set_focus_ring(self.ui_focus_ring);
+                        for (object_id, interactable) in std::mem::take(&mut self.pending_ui_interactable) {
+                            ui_manager.set_interactable(object_id, interactable);
+                        }
+                        for (object_id, cursor) in std::mem::take(&mut self.pending_ui_hover_cursors) {
+                            ui_manager.set_hover_cursor(object_id, cursor);
+                        }
+                        for (object_id, context) in std::mem::take(&mut self.pending_ui_input_contexts) {
+                            ui_manager.set_input_context(object_id, context);
+                        }
+                        for tween in std::mem::take(&mut self.pending_ui_tweens) {
+                            ui_manager.add_tween(tween);
+                        }
+                        self.ui_manager = Some(ui_manager);
+                        logging::log_info("UI manager initialized");
+
+                        self.window_manager = Some(window_manager);
+                        self.ensure_active_camera_object();
+                        self.apply_cursor_visibility();
+                        self.apply_cursor_grab();
+
+                        if let Some(viewport_size) = self.pending_camera_viewport_size
+                            && let Some(render_manager) = &mut self.render_manager
+                        {
+                            render_manager.set_camera_viewport_size(
+                                viewport_size.x(),
+                                viewport_size.y(),
+                            );
+                        }
 
-                                // Request initial redraw
-                                if let Some(wm) = &self.window_manager {
-                                    wm.request_redraw();
-                                }
-                            }
-                            Err(e) => {
-                                logging::log_error(&format!(
-                                    "Failed to create render manager: {}",
-                                    e
+                        if let Some(render_manager) = &mut self.render_manager {
+                            render_manager
+                                .set_camera_aspect_mode(self.pending_camera_aspect_mode);
+                            if !render_manager.set_pixel_perfect_resolution(
+                                self.pending_camera_pixel_perfect_resolution,
+                            ) {
+                                logging::log_warn(&format!(
+                                    "Pixel-perfect resolution {:?} exceeds this device's texture limit; rendering to the window instead",
+                                    self.pending_camera_pixel_perfect_resolution
                                 ));
-                                event_loop.exit();
+                                self.pending_camera_pixel_perfect_resolution = None;
                             }
                         }
+
+                        // Request initial redraw
+                        if let Some(wm) = &self.window_manager {
+                            wm.request_redraw();
+                        }
                     }
                     Err(e) => {
                         logging::log_error(&format!("Failed to create window: {}", e));
-                        event_loop.exit();
+                        self.request_quit();
                     }
                 }
             }
@@ -1731,7 +3790,7 @@ impl ApplicationHandler for Engine {
         match event {
             WindowEvent::CloseRequested => {
                 logging::log_info("Close requested, shutting down engine");
-                event_loop.exit();
+                self.request_quit();
             }
             WindowEvent::Resized(physical_size) => {
                 self.apply_window_resize(physical_size);
@@ -1754,23 +3813,77 @@ impl ApplicationHandler for Engine {
                     window_manager.window().focus_window();
                     window_manager.request_redraw();
                 }
+                // Some platforms release the grab when the window loses focus.
+                if focused && self.cursor_grab != CursorGrabMode::None {
+                    self.apply_cursor_grab();
+                }
             }
             WindowEvent::RedrawRequested => {
                 if self.auto_step_on_redraw {
+                    self.pacing_frame_limiter().mark_frame(Instant::now());
+
                     // Update engine state
                     self.update();
 
+                    if !self.run_frame_callback() {
+                        event_loop.exit();
+                        return;
+                    }
+
                     // Render the frame
                     self.render();
+                } else if std::mem::take(&mut self.repaint_after_resize) {
+                    // Manual loops render between pumps, but macOS (and Windows
+                    // while dragging) resize inside the event loop and expect the
+                    // window to repaint there. Present the current state at the
+                    // new size; the host's next `render()` continues as usual.
+                    self.render_frame();
                 }
             }
             _ => {}
         }
     }
 
-    fn about_to_wait(&mut self, _event_loop: &ActiveEventLoop) {
+    fn device_event(&mut self, _event_loop: &ActiveEventLoop, _device_id: DeviceId, event: DeviceEvent) {
+        if let DeviceEvent::MouseMotion { delta: (dx, dy) } = event
+            && let Some(input_manager) = &mut self.input_manager
+        {
+            input_manager.handle_mouse_motion(dx, dy);
+        }
+    }
+
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        // `run` exits the loop to return; a manual loop keeps it alive so the
+        // next session can pump it, and sees the quit from `poll_events`.
+        if self.quit_requested {
+            if self.auto_step_on_redraw {
+                event_loop.exit();
+            }
+            return;
+        }
+        // A session on an event loop that is still running gets no `resumed`.
+        if self.window_manager.is_none() && self.window_config.is_some() {
+            self.resumed(event_loop);
+        }
+        self.apply_cursor(event_loop);
+
+        if self.auto_step_on_redraw {
+            // Sleep the loop until the frame cap allows the next redraw.
+            if let Some(deadline) = self.pacing_frame_limiter().next_deadline()
+                && Instant::now() < deadline
+            {
+                event_loop.set_control_flow(ControlFlow::WaitUntil(deadline));
+                return;
+            }
+            if matches!(event_loop.control_flow(), ControlFlow::WaitUntil(_)) {
+                event_loop.set_control_flow(Self::default_control_flow());
+            }
+        }
+
         if let Some(window_manager) = &self.window_manager {
-            if self.show_fps_in_title {
+            // Without a renderer there is no scene change detection, and a frame
+            // callback expects every frame; keep ticking.
+            if self.show_fps_in_title || self.render_manager.is_none() || self.frame_callback.is_some() {
                 window_manager.request_redraw();
                 return;
             }
@@ -0,0 +1,267 @@
+//! Global publish/subscribe event bus.
+//!
+//! Engine systems and game code emit named events with a typed payload and
+//! subscribe handlers to event names. Emitting only queues the event; the
+//! engine dispatches the queue at the end of each update, so handlers never
+//! run in the middle of another system's work. Events emitted while the queue
+//! is dispatched are delivered at the end of the next update.
+//!
+//! # Engine Events
+//!
+//! - `scene_loaded` / `scene_unloaded` with the scene name as `Text`.
+//! - `sound_finished` with the voice id as `Int`, when a sound plays to the end.
+//!
+//! # Messages
+//!
+//! `Message`s are the targeted counterpart of events: the engine delivers
+//! them to the components of one object, during the update phase, through
+//! `ComponentTrait::on_message`. They share the `EventPayload` type.
+//!
+//! # Usage
+//!
+//! ```rust
+//! use pyg_engine_native::core::event_bus::{EventBus, EventPayload};
+//!
+//! let mut events = EventBus::new();
+//! let subscription = events.subscribe("player_died", |event| {
+//!     println!("player died with score {:?}", event.payload().as_int());
+//! });
+//! events.emit("player_died", EventPayload::Int(1200));
+//! events.dispatch();
+//! events.unsubscribe(subscription);
+//! ```
+
+use crate::types::vector::Vec2;
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Data carried by an event.
+#[derive(Clone)]
+pub enum EventPayload {
+    None,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+    Vec2(Vec2),
+    /// Any other value, e.g. a Python object emitted from game code.
+    Any(Arc<dyn Any + Send + Sync>),
+}
+
+impl EventPayload {
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Self::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Get a number payload; integers convert.
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            Self::Float(value) => Some(*value),
+            Self::Int(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_vec2(&self) -> Option<Vec2> {
+        match self {
+            Self::Vec2(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Get an `Any` payload of type `T`.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        match self {
+            Self::Any(value) => value.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Debug for EventPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "None"),
+            Self::Bool(value) => write!(f, "Bool({value})"),
+            Self::Int(value) => write!(f, "Int({value})"),
+            Self::Float(value) => write!(f, "Float({value})"),
+            Self::Text(value) => write!(f, "Text({value:?})"),
+            Self::Vec2(value) => write!(f, "Vec2({}, {})", value.x(), value.y()),
+            Self::Any(_) => write!(f, "Any(..)"),
+        }
+    }
+}
+
+/// A named message for the components of one object.
+#[derive(Debug, Clone)]
+pub struct Message {
+    pub target_id: u32,
+    pub name: String,
+    pub payload: EventPayload,
+}
+
+/// A named event and its payload.
+#[derive(Debug, Clone)]
+pub struct GameEvent {
+    name: String,
+    payload: EventPayload,
+}
+
+impl GameEvent {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &EventPayload {
+        &self.payload
+    }
+}
+
+type Handler = Arc<dyn Fn(&GameEvent) + Send + Sync>;
+
+/// A handler that is due to receive an event, from `EventBus::take_dispatch`.
+pub struct EventCall {
+    handler: Handler,
+    event: Arc<GameEvent>,
+}
+
+impl EventCall {
+    /// Run the handler.
+    pub fn invoke(self) {
+        (self.handler)(&self.event);
+    }
+}
+
+/// Queues events and delivers them to the handlers subscribed to their name.
+#[derive(Default)]
+pub struct EventBus {
+    handlers: HashMap<String, Vec<(u64, Handler)>>,
+    queue: Vec<GameEvent>,
+    next_subscription: u64,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Subscribe a handler to an event name. Handlers of the same event run
+    /// in the order they subscribed.
+    ///
+    /// Returns the subscription id for `unsubscribe`.
+    pub fn subscribe(&mut self, name: impl Into<String>, handler: impl Fn(&GameEvent) + Send + Sync + 'static) -> u64 {
+        self.next_subscription += 1;
+        self.handlers
+            .entry(name.into())
+            .or_default()
+            .push((self.next_subscription, Arc::new(handler)));
+        self.next_subscription
+    }
+
+    /// Remove a subscription. Returns false if it does not exist.
+    pub fn unsubscribe(&mut self, subscription: u64) -> bool {
+        for (name, handlers) in &mut self.handlers {
+            if let Some(index) = handlers.iter().position(|(id, _)| *id == subscription) {
+                handlers.remove(index);
+                if handlers.is_empty() {
+                    let name = name.clone();
+                    self.handlers.remove(&name);
+                }
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Number of handlers subscribed to an event name.
+    pub fn subscriber_count(&self, name: &str) -> usize {
+        self.handlers.get(name).map_or(0, Vec::len)
+    }
+
+    /// Queue an event for the next dispatch.
+    pub fn emit(&mut self, name: impl Into<String>, payload: EventPayload) {
+        self.queue.push(GameEvent {
+            name: name.into(),
+            payload,
+        });
+    }
+
+    /// Number of events waiting for dispatch.
+    pub fn pending(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Drain the queue and pair each event with its handlers, in emit order.
+    ///
+    /// Bindings use this to run handlers without holding the engine; events
+    /// without handlers are dropped.
+    pub fn take_dispatch(&mut self) -> Vec<EventCall> {
+        let mut calls = Vec::new();
+        for event in std::mem::take(&mut self.queue) {
+            let Some(handlers) = self.handlers.get(&event.name) else {
+                continue;
+            };
+            let event = Arc::new(event);
+            calls.extend(handlers.iter().map(|(_, handler)| EventCall {
+                handler: Arc::clone(handler),
+                event: Arc::clone(&event),
+            }));
+        }
+        calls
+    }
+
+    /// Deliver the queued events.
+    pub fn dispatch(&mut self) {
+        for call in self.take_dispatch() {
+            call.invoke();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_events_wait_for_dispatch() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let mut events = EventBus::new();
+        let log = received.clone();
+        let subscription = events.subscribe("score", move |event| {
+            log.lock().unwrap().push(event.payload().as_int().unwrap());
+        });
+        events.emit("score", EventPayload::Int(10));
+        events.emit("ignored", EventPayload::None);
+        events.emit("score", EventPayload::Int(20));
+        assert!(received.lock().unwrap().is_empty(), "emitting only queues");
+
+        events.dispatch();
+        assert_eq!(*received.lock().unwrap(), [10, 20]);
+        assert_eq!(events.pending(), 0);
+
+        assert!(events.unsubscribe(subscription));
+        assert!(!events.unsubscribe(subscription));
+        events.emit("score", EventPayload::Int(30));
+        events.dispatch();
+        assert_eq!(*received.lock().unwrap(), [10, 20]);
+    }
+}
@@ -1,9 +1,12 @@
 use super::component::{ComponentTrait, MeshComponent, TransformComponent};
+use super::event_bus::EventPayload;
 use super::time::Time;
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 
 // Keep track of the next game object id.
 static GO_ID: AtomicU32 = AtomicU32::new(0);
+// Shared by every object, so a revision never repeats across clones.
+static COMPONENT_REVISION: AtomicU64 = AtomicU64::new(0);
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ObjectType {
@@ -15,6 +18,31 @@ pub enum ObjectType {
     Camera,
 }
 
+impl ObjectType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::GameObject => "GameObject",
+            Self::UIObject => "UIObject",
+            Self::ParticleSystem => "ParticleSystem",
+            Self::Sound => "Sound",
+            Self::Light => "Light",
+            Self::Camera => "Camera",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "GameObject" => Some(Self::GameObject),
+            "UIObject" => Some(Self::UIObject),
+            "ParticleSystem" => Some(Self::ParticleSystem),
+            "Sound" => Some(Self::Sound),
+            "Light" => Some(Self::Light),
+            "Camera" => Some(Self::Camera),
+            _ => None,
+        }
+    }
+}
+
 impl Default for ObjectType {
     fn default() -> Self {
         ObjectType::GameObject
@@ -25,6 +53,7 @@ impl Default for ObjectType {
 pub struct GameObject {
     id: u32,
     name: Option<String>,
+    tags: Vec<String>,
     children: Vec<u32>,
     parent: Option<u32>,
     transform: TransformComponent,
@@ -33,6 +62,7 @@ pub struct GameObject {
     object_type: Option<ObjectType>,
     enabled_self: bool,
     enabled_in_hierarchy: bool,
+    revision: u64,
 }
 
 impl GameObject {
@@ -45,6 +75,7 @@ impl GameObject {
         Self {
             id,
             name: Some("GameObject".to_string()),
+            tags: Vec::new(),
             children: Vec::new(),
             parent: None,
             transform: TransformComponent::new("Transform".to_string()),
@@ -53,6 +84,7 @@ impl GameObject {
             object_type: None,
             enabled_self: true,
             enabled_in_hierarchy: true,
+            revision: 0,
         }
     }
 
@@ -66,6 +98,7 @@ impl GameObject {
         Self {
             id,
             name: Some(name),
+            tags: Vec::new(),
             children: Vec::new(),
             parent: None,
             transform: TransformComponent::new("Transform".to_string()),
@@ -74,6 +107,7 @@ impl GameObject {
             object_type: None,
             enabled_self: true,
             enabled_in_hierarchy: true,
+            revision: 0,
         }
     }
 
@@ -101,6 +135,35 @@ impl GameObject {
         self.name.as_deref()
     }
 
+    /// Gets the tags of the game object, in the order they were added.
+    ///
+    /// Change the tags of an object in an `ObjectManager` through the manager
+    /// so its tag index stays current.
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|existing| existing == tag)
+    }
+
+    /// Adds a tag. Returns false if the object already had it.
+    pub fn add_tag(&mut self, tag: impl Into<String>) -> bool {
+        let tag = tag.into();
+        if self.has_tag(&tag) {
+            return false;
+        }
+        self.tags.push(tag);
+        true
+    }
+
+    /// Removes a tag. Returns false if the object did not have it.
+    pub fn remove_tag(&mut self, tag: &str) -> bool {
+        let count = self.tags.len();
+        self.tags.retain(|existing| existing != tag);
+        self.tags.len() != count
+    }
+
     pub fn parent_id(&self) -> Option<u32> {
         self.parent
     }
@@ -128,6 +191,7 @@ impl GameObject {
         @param component: The component to add.
     */
     pub fn add_component(&mut self, component: Box<dyn ComponentTrait>) {
+        self.touch_components();
         if component.as_any().is::<TransformComponent>() {
             let transform = component
                 .into_any()
@@ -200,12 +264,14 @@ impl GameObject {
 
     /// Adds or replaces the mesh component.
     pub fn add_mesh_component(&mut self, mesh: MeshComponent) {
+        self.touch_components();
         self.mesh = Some(mesh);
         self.refresh_component_enabled_states();
     }
 
     /// Removes and returns the mesh component if present.
     pub fn remove_mesh_component(&mut self) -> Option<MeshComponent> {
+        self.touch_components();
         self.mesh.take()
     }
 
@@ -216,6 +282,7 @@ impl GameObject {
 
     /// Gets a mutable mesh component reference.
     pub fn mesh_component_mut(&mut self) -> Option<&mut MeshComponent> {
+        self.touch_components();
         self.mesh.as_mut()
     }
 
@@ -231,7 +298,8 @@ impl GameObject {
     */
     pub fn remove_child_by_id(&mut self, id: u32) -> Option<u32> {
         if let Some(index) = self.children.iter().position(|c| *c == id) {
-            let child_id = self.children.swap_remove(index);
+            // Keep sibling order; UI stacks lay children out in this order.
+            let child_id = self.children.remove(index);
             Some(child_id)
         } else {
             None
@@ -281,6 +349,7 @@ impl GameObject {
         @return: The removed component.
     */
     pub fn remove_component_by_name(&mut self, name: &str) -> Option<Box<dyn ComponentTrait>> {
+        self.touch_components();
         if self.mesh_component().is_some_and(|mesh| mesh.name() == name) {
             return self
                 .remove_mesh_component()
@@ -295,6 +364,7 @@ impl GameObject {
     }
 
     pub fn remove_component_by_id(&mut self, component_id: u32) -> Option<Box<dyn ComponentTrait>> {
+        self.touch_components();
         if self.transform.id() == component_id {
             return None;
         }
@@ -357,6 +427,16 @@ impl GameObject {
         @return: The mutable component.
     */
     pub fn get_component_by_name_mut(&mut self, name: &str) -> Option<&mut (dyn ComponentTrait + '_)> {
+        self.touch_components();
+        self.get_component_by_name_mut_untracked(name)
+    }
+
+    /// Like `get_component_by_name_mut`, but leaves the component revision
+    /// alone. For passes that report their own changes, such as the UI layout.
+    pub(crate) fn get_component_by_name_mut_untracked(
+        &mut self,
+        name: &str,
+    ) -> Option<&mut (dyn ComponentTrait + '_)> {
         if self.transform.name() == name {
             return Some(&mut self.transform);
         }
@@ -379,6 +459,7 @@ impl GameObject {
         &mut self,
         component_id: u32,
     ) -> Option<&mut (dyn ComponentTrait + '_)> {
+        self.touch_components();
         if self.transform.id() == component_id {
             return Some(&mut self.transform);
         }
@@ -425,6 +506,12 @@ impl GameObject {
         @return: The mutable component if found and type matches.
     */
     pub fn get_component_mut<T: ComponentTrait + 'static>(&mut self) -> Option<&mut T> {
+        self.touch_components();
+        self.get_component_mut_untracked()
+    }
+
+    /// Like `get_component_mut`, but leaves the component revision alone.
+    pub(crate) fn get_component_mut_untracked<T: ComponentTrait + 'static>(&mut self) -> Option<&mut T> {
         if let Some(concrete) = self.transform.as_any_mut().downcast_mut::<T>() {
             return Some(concrete);
         }
@@ -549,6 +636,29 @@ impl GameObject {
         self.object_type = Some(object_type);
     }
 
+    /**
+        Gets the component revision, which changes whenever a component is
+        added, removed or borrowed mutably. The transform is not tracked.
+        Caches keyed on it, like the UI's retained draw commands, go stale
+        when it changes.
+        @return: The component revision.
+    */
+    pub fn component_revision(&self) -> u64 {
+        self.revision
+    }
+
+    fn touch_components(&mut self) {
+        self.revision = COMPONENT_REVISION.fetch_add(1, Ordering::Relaxed) + 1;
+    }
+
+    /**
+        Checks whether every component opted in to the parallel update passes.
+        @return: True if the object may update on a worker thread.
+    */
+    pub fn runs_in_parallel(&self) -> bool {
+        !self.components.is_empty() && self.components.iter().all(|component| component.runs_in_parallel())
+    }
+
     /**
         Updates the game object.
     */
@@ -597,6 +707,60 @@ impl GameObject {
         }
     }
 
+    /**
+        Delivers a message to every enabled component.
+        @param name: The message name.
+        @param payload: The message payload.
+    */
+    pub fn receive_message(&self, name: &str, payload: &EventPayload) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        for component in self.all_components() {
+            if component.is_effectively_enabled() {
+                component.on_message(name, payload);
+            }
+        }
+    }
+
+    /**
+        Gets a component by name, or else the first component of that type.
+        @param key: The name or type of the component.
+        @return: The component.
+    */
+    pub fn get_component_by_name_or_type(&self, key: &str) -> Option<&dyn ComponentTrait> {
+        self.get_component_by_name(key).or_else(|| {
+            self.all_components()
+                .into_iter()
+                .find(|component| component.component_type() == key)
+        })
+    }
+
+    /**
+        Enables or disables a component, matched by name or else by component type.
+        Fires the component's on_enable/on_disable hook when its effective state changes.
+        @param key: The name or type of the component.
+        @param enabled: The enabled state to set.
+        @return: Whether the effective state changed, or None if no component matched.
+    */
+    pub fn set_component_enabled(&mut self, key: &str, enabled: bool) -> Option<bool> {
+        let component_id = self.get_component_by_name_or_type(key)?.id();
+        let component = self.get_component_by_id_mut(component_id)?;
+
+        let was_enabled = component.is_effectively_enabled();
+        component.set_enabled_self(enabled);
+        let is_enabled = component.is_effectively_enabled();
+        if was_enabled != is_enabled {
+            if is_enabled {
+                component.on_enable();
+            } else {
+                component.on_disable();
+            }
+        }
+        Some(was_enabled != is_enabled)
+    }
+
     pub fn invoke_on_destroy(&self) {
         for component in self.all_components() {
             component.on_destroy();
@@ -632,3 +796,199 @@ impl GameObject {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::object_manager::ObjectManager;
+    use crate::core::physics::{ColliderComponent, ColliderShape, CollisionWorld};
+
+    #[test]
+    fn test_tag_and_name_queries_follow_changes() {
+        let mut object_manager = ObjectManager::new();
+        let mut goblin = GameObject::new_named("Goblin".to_string());
+        goblin.add_tag("enemy");
+        let goblin_id = object_manager.add_object(goblin).unwrap();
+        let orc_id = object_manager.add_object(GameObject::new_named("Orc".to_string())).unwrap();
+        assert!(object_manager.add_object_tag(orc_id, "enemy"));
+        assert!(!object_manager.add_object_tag(orc_id, "enemy"));
+        assert_eq!(object_manager.find_by_tag("enemy"), [goblin_id, orc_id]);
+
+        assert!(object_manager.set_object_name(goblin_id, "Orc".to_string()));
+        assert!(object_manager.find_by_name("Goblin").is_empty());
+        assert_eq!(object_manager.find_by_name("Orc"), [goblin_id, orc_id]);
+
+        assert!(object_manager.remove_object_tag(goblin_id, "enemy"));
+        object_manager.remove_object(orc_id);
+        assert!(object_manager.find_by_tag("enemy").is_empty());
+        assert_eq!(object_manager.find_by_name("Orc"), [goblin_id]);
+        assert_eq!(object_manager.get_all_ids(), [goblin_id]);
+    }
+
+    #[test]
+    fn test_storage_keeps_insertion_order_through_removals() {
+        let mut object_manager = ObjectManager::new();
+        let ids: Vec<u32> = ["A", "B", "C", "D"]
+            .iter()
+            .map(|name| object_manager.add_object(GameObject::new_named(name.to_string())).unwrap())
+            .collect();
+        let child = object_manager.add_object(GameObject::new_named("B.child".to_string())).unwrap();
+        object_manager.add_child(ids[1], child).unwrap();
+
+        object_manager.remove_object(ids[1]);
+        assert_eq!(object_manager.get_keys().collect::<Vec<_>>(), [ids[0], ids[2], ids[3]]);
+        assert!(object_manager.get_object_by_id(child).is_none());
+        let names: Vec<_> = object_manager.iter().filter_map(GameObject::name).collect();
+        assert_eq!(names, ["A", "C", "D"]);
+
+        let mut replacement = object_manager.get_object_clone(ids[2]).unwrap();
+        replacement.set_name("C2".to_string());
+        object_manager.add_object(replacement);
+        assert_eq!(object_manager.get_object_by_id(ids[3]).unwrap().name(), Some("D"));
+        let names: Vec<_> = object_manager.iter().filter_map(GameObject::name).collect();
+        assert_eq!(names, ["A", "C2", "D"]);
+        assert_eq!(object_manager.get_total_objects(), 3);
+    }
+
+    /// Records the thread each `update` runs on; opts in to parallel passes.
+    #[derive(Debug, Clone)]
+    struct ThreadProbe {
+        threads: std::sync::Arc<std::sync::Mutex<Vec<std::thread::ThreadId>>>,
+    }
+
+    impl ComponentTrait for ThreadProbe {
+        fn new(_name: String) -> Self {
+            Self { threads: Default::default() }
+        }
+        fn name(&self) -> &str {
+            "ThreadProbe"
+        }
+        fn id(&self) -> u32 {
+            0
+        }
+        fn component_type(&self) -> &'static str {
+            "ThreadProbe"
+        }
+        fn is_enabled_self(&self) -> bool {
+            true
+        }
+        fn set_enabled_self(&mut self, _enabled: bool) {}
+        fn is_enabled_in_hierarchy(&self) -> bool {
+            true
+        }
+        fn set_enabled_in_hierarchy(&mut self, _enabled: bool) {}
+        fn update(&self, _time: &Time) {
+            self.threads.lock().unwrap().push(std::thread::current().id());
+        }
+        fn fixed_update(&self, _time: &Time, _fixed_time: f32) {}
+        fn on_start(&self) {}
+        fn on_destroy(&self) {}
+        fn on_enable(&self) {}
+        fn on_disable(&self) {}
+        fn clone_component(&self) -> Box<dyn ComponentTrait> {
+            Box::new(self.clone())
+        }
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+        fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+            self
+        }
+        fn runs_in_parallel(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_scheduled_pass_runs_opted_in_objects_off_the_caller() {
+        use crate::core::ui::button::ButtonComponent;
+        use std::sync::Mutex;
+
+        let probe = ThreadProbe::new(String::new());
+        let mut object_manager = ObjectManager::new();
+        for index in 0..330 {
+            let mut object = GameObject::new_named(format!("Object {index}"));
+            if index % 11 == 0 {
+                object.add_component(Box::new(ButtonComponent::new("Button".to_string())));
+            } else {
+                object.add_component(Box::new(probe.clone()));
+            }
+            object_manager.add_object(object).unwrap();
+        }
+        object_manager.add_object(GameObject::new_named("Empty".to_string())).unwrap();
+
+        let main_thread = std::thread::current().id();
+        let time = Time::new();
+        let visits = Mutex::new(Vec::new());
+        object_manager.for_each_scheduled(true, |object| {
+            visits.lock().unwrap().push((object.get_id(), std::thread::current().id()));
+            object.update(&time);
+        });
+        let visits = visits.into_inner().unwrap();
+        assert_eq!(visits.len(), 331);
+
+        // Opted-in components really updated on pool threads
+        let probe_threads = probe.threads.lock().unwrap().clone();
+        assert_eq!(probe_threads.len(), 300);
+        assert!(probe_threads.iter().all(|thread| *thread != main_thread));
+
+        // Everything else (buttons by default, objects without components)
+        // runs last, in order, on the calling thread
+        let main_thread_ids: Vec<u32> = object_manager
+            .iter()
+            .filter(|object| !object.runs_in_parallel())
+            .map(GameObject::get_id)
+            .collect();
+        assert_eq!(main_thread_ids.len(), 31);
+        let tail = &visits[visits.len() - main_thread_ids.len()..];
+        assert!(tail.iter().all(|(_, thread)| *thread == main_thread));
+        assert_eq!(tail.iter().map(|(id, _)| *id).collect::<Vec<_>>(), main_thread_ids);
+
+        // Serial passes keep every object on the caller
+        probe.threads.lock().unwrap().clear();
+        object_manager.for_each_scheduled(false, |object| object.update(&time));
+        assert!(probe.threads.lock().unwrap().iter().all(|thread| *thread == main_thread));
+    }
+
+    #[test]
+    fn test_disabled_collider_leaves_collision_world() {
+        let mut object_manager = ObjectManager::new();
+        let mut object = GameObject::new_named("Ghost".to_string());
+        object.add_component(Box::new(
+            ColliderComponent::new("Body").with_shape(ColliderShape::circle(1.0)),
+        ));
+        let id = object_manager.add_object(object).unwrap();
+        let mut collision_world = CollisionWorld::new();
+        collision_world.step(&object_manager);
+        assert!(collision_world.aabb_tree().contains(id));
+
+        let object = object_manager.get_object_by_id_mut(id).unwrap();
+        assert_eq!(object.set_component_enabled("Collider", false), Some(true));
+        assert_eq!(object.set_component_enabled("Body", false), Some(false));
+        assert_eq!(object.set_component_enabled("Missing", false), None);
+        collision_world.step(&object_manager);
+        assert!(!collision_world.aabb_tree().contains(id));
+
+        object_manager
+            .get_object_by_id_mut(id)
+            .unwrap()
+            .set_component_enabled("Body", true);
+        collision_world.step(&object_manager);
+        assert!(collision_world.aabb_tree().contains(id));
+    }
+
+    #[test]
+    fn test_component_enabled_change_in_disabled_object_is_not_effective() {
+        let mut object = GameObject::new();
+        object.add_mesh_component(MeshComponent::new("Mesh".to_string()));
+        object.set_enabled_self(false);
+
+        assert_eq!(object.set_component_enabled("Mesh", false), Some(false));
+        assert!(!object.mesh_component().unwrap().is_enabled_self());
+        object.set_enabled_self(true);
+        assert!(!object.mesh_component().unwrap().is_effectively_enabled());
+    }
+}
@@ -0,0 +1,222 @@
+//! Gamepad input through gilrs.
+//!
+//! Controllers are enumerated on the first poll and their buttons, axes and
+//! hot-plug events are fed to [`InputManager`] through its
+//! `handle_joystick_*` methods. Buttons and axes use the standard layout the
+//! input glyphs and default bindings expect: face buttons 0-3 (south, east,
+//! west, north), bumpers 4-5, triggers 6-7, back/start 8-9, stick clicks
+//! 10-11, d-pad 12-15 and the guide button 16; axes are left stick X/Y 0-1,
+//! right stick X/Y 2-3 and the analog triggers 4-5, with sticks positive
+//! right and up. Triggers read 0 to 1, and the input manager presses the
+//! trigger buttons from their travel.
+//!
+//! gilrs is behind the `gamepad` cargo feature (it needs libudev on Linux).
+//! Without it the backend finds no controllers, and games can still feed the
+//! input manager from another library.
+
+use super::input_manager::InputManager;
+use std::collections::HashMap;
+
+/// Reads connected game controllers into the input manager.
+pub struct GamepadBackend {
+    #[cfg(feature = "gamepad")]
+    gilrs: Option<gilrs::Gilrs>,
+    started: bool,
+    /// Controller names by joystick id
+    names: HashMap<u32, String>,
+}
+
+impl Default for GamepadBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GamepadBackend {
+    /// Create the backend. The platform gamepad API is opened on the first
+    /// [`Self::poll`].
+    pub fn new() -> Self {
+        Self {
+            #[cfg(feature = "gamepad")]
+            gilrs: None,
+            started: false,
+            names: HashMap::new(),
+        }
+    }
+
+    /// Whether controllers can be read, i.e. the engine was built with the
+    /// `gamepad` feature and the platform gamepad API opened.
+    pub fn is_available(&self) -> bool {
+        #[cfg(feature = "gamepad")]
+        {
+            self.gilrs.is_some()
+        }
+        #[cfg(not(feature = "gamepad"))]
+        {
+            false
+        }
+    }
+
+    /// Name the controller reports, e.g. "Xbox Wireless Controller".
+    pub fn joystick_name(&self, joystick_id: u32) -> Option<&str> {
+        self.names.get(&joystick_id).map(String::as_str)
+    }
+
+    /// Apply the controller events since the last poll. Call once per frame
+    /// before `InputManager::update` so they land in that frame's events.
+    pub fn poll(&mut self, input: &mut InputManager) {
+        if !self.started {
+            self.started = true;
+            self.start(input);
+        }
+        #[cfg(feature = "gamepad")]
+        if let Some(gilrs) = &mut self.gilrs {
+            while let Some(gilrs::Event { id, event, .. }) = gilrs.next_event() {
+                let joystick_id = usize::from(id) as u32;
+                if let gilrs::EventType::Connected = event {
+                    self.names.insert(joystick_id, gilrs.gamepad(id).name().to_string());
+                }
+                apply_event(input, joystick_id, event);
+            }
+        }
+    }
+
+    #[cfg(feature = "gamepad")]
+    fn start(&mut self, input: &mut InputManager) {
+        let gilrs = match gilrs::Gilrs::new() {
+            Ok(gilrs) => gilrs,
+            Err(err) => {
+                super::logging::log_warn(&format!("Gamepad support unavailable: {err}"));
+                return;
+            }
+        };
+        // Controllers plugged in before startup don't send `Connected`.
+        for (id, gamepad) in gilrs.gamepads() {
+            let joystick_id = usize::from(id) as u32;
+            self.names.insert(joystick_id, gamepad.name().to_string());
+            input.handle_joystick_connected(joystick_id);
+        }
+        self.gilrs = Some(gilrs);
+    }
+
+    #[cfg(not(feature = "gamepad"))]
+    fn start(&mut self, _input: &mut InputManager) {}
+}
+
+#[cfg(feature = "gamepad")]
+fn apply_event(input: &mut InputManager, joystick_id: u32, event: gilrs::EventType) {
+    use gilrs::{Button, EventType};
+
+    match event {
+        EventType::Connected => input.handle_joystick_connected(joystick_id),
+        EventType::Disconnected => input.handle_joystick_disconnected(joystick_id),
+        // Trigger buttons follow the trigger axes, pressing at the input
+        // manager's press point rather than the driver's.
+        EventType::ButtonPressed(Button::LeftTrigger2 | Button::RightTrigger2, _)
+        | EventType::ButtonReleased(Button::LeftTrigger2 | Button::RightTrigger2, _) => {}
+        EventType::ButtonPressed(button, _) => {
+            if let Some(button_id) = button_id(button) {
+                input.handle_joystick_button(joystick_id, button_id, true);
+            }
+        }
+        EventType::ButtonReleased(button, _) => {
+            if let Some(button_id) = button_id(button) {
+                input.handle_joystick_button(joystick_id, button_id, false);
+            }
+        }
+        // Analog triggers report their travel as button values.
+        EventType::ButtonChanged(Button::LeftTrigger2, value, _) => {
+            input.handle_joystick_axis(joystick_id, 4, value);
+        }
+        EventType::ButtonChanged(Button::RightTrigger2, value, _) => {
+            input.handle_joystick_axis(joystick_id, 5, value);
+        }
+        EventType::AxisChanged(axis, value, _) => {
+            if let Some(axis_id) = axis_id(axis) {
+                input.handle_joystick_axis(joystick_id, axis_id, value);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Button id in the standard layout.
+#[cfg(feature = "gamepad")]
+fn button_id(button: gilrs::Button) -> Option<u8> {
+    use gilrs::Button;
+
+    Some(match button {
+        Button::South => 0,
+        Button::East => 1,
+        Button::West => 2,
+        Button::North => 3,
+        // gilrs names the bumpers triggers and the triggers "trigger 2".
+        Button::LeftTrigger => 4,
+        Button::RightTrigger => 5,
+        Button::LeftTrigger2 => 6,
+        Button::RightTrigger2 => 7,
+        Button::Select => 8,
+        Button::Start => 9,
+        Button::LeftThumb => 10,
+        Button::RightThumb => 11,
+        Button::DPadUp => 12,
+        Button::DPadDown => 13,
+        Button::DPadLeft => 14,
+        Button::DPadRight => 15,
+        Button::Mode => 16,
+        _ => return None,
+    })
+}
+
+/// Axis id in the standard layout.
+#[cfg(feature = "gamepad")]
+fn axis_id(axis: gilrs::Axis) -> Option<u8> {
+    use gilrs::Axis;
+
+    Some(match axis {
+        Axis::LeftStickX => 0,
+        Axis::LeftStickY => 1,
+        Axis::RightStickX => 2,
+        Axis::RightStickY => 3,
+        Axis::LeftZ => 4,
+        Axis::RightZ => 5,
+        _ => return None,
+    })
+}
+
+#[cfg(all(test, feature = "gamepad"))]
+mod tests {
+    use super::*;
+    use crate::core::input_glyphs::GlyphIcon;
+    use crate::core::input_manager::InputEvent;
+    use gilrs::{Axis, Button, EventType};
+
+    #[test]
+    fn test_buttons_follow_the_glyph_layout() {
+        assert_eq!(button_id(Button::South).and_then(GlyphIcon::from_gamepad_button), Some(GlyphIcon::FaceSouth));
+        assert_eq!(button_id(Button::LeftTrigger).and_then(GlyphIcon::from_gamepad_button), Some(GlyphIcon::LeftBumper));
+        assert_eq!(
+            button_id(Button::RightTrigger2).and_then(GlyphIcon::from_gamepad_button),
+            Some(GlyphIcon::RightTrigger)
+        );
+        assert_eq!(button_id(Button::DPadRight).and_then(GlyphIcon::from_gamepad_button), Some(GlyphIcon::DpadRight));
+        assert_eq!(axis_id(Axis::LeftStickY), Some(1));
+        assert_eq!(axis_id(Axis::DPadX), None);
+    }
+
+    #[test]
+    fn test_hot_plug_reaches_the_input_manager() {
+        let mut input = InputManager::new();
+        apply_event(&mut input, 3, EventType::Connected);
+        input.handle_joystick_button(3, 0, true);
+        input.update(0.016);
+        assert_eq!(input.frame_events()[0], InputEvent::JoystickConnected { joystick_id: 3 });
+        assert_eq!(input.joystick_connection_changes(true), [3]);
+
+        apply_event(&mut input, 3, EventType::Disconnected);
+        input.update(0.016);
+        assert!(!input.joystick_button_down(3, 0));
+        assert_eq!(input.joystick_connection_changes(false), [3]);
+        assert!(input.connected_joysticks().is_empty());
+    }
+}
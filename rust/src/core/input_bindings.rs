@@ -0,0 +1,642 @@
+//! Saving and loading axis bindings and action mappings as JSON.
+//!
+//! Lets games keep the controls a player customized between sessions. Keys
+//! are stored by the same names scripts use (`"a"`, `"space"`, `"arrowleft"`,
+//! `"f1"`), and modifier chords as `"ctrl+s"` or `"shift+click"`, so a
+//! bindings file can also be written by hand.
+
+use super::input_manager::{
+    AxisBinding, AxisSmoothing, ChordTrigger, InputChord, InputManager, JoystickAxis, JoystickAxisBinding,
+    JoystickButton, KeyboardAxisBinding, MouseAxisBinding, MouseAxisType, MouseButtonType, modifier_flag,
+};
+use serde_json::{Map, Value, json};
+use std::collections::BTreeMap;
+use winit::keyboard::{Key, ModifiersState, NamedKey, NativeKey};
+
+/// Identifies bindings files.
+pub const BINDINGS_FORMAT: &str = "pyg_engine.input_bindings";
+/// Bumped whenever the bindings layout changes incompatibly.
+pub const BINDINGS_VERSION: u32 = 1;
+
+const FUNCTION_KEYS: [NamedKey; 24] = [
+    NamedKey::F1,
+    NamedKey::F2,
+    NamedKey::F3,
+    NamedKey::F4,
+    NamedKey::F5,
+    NamedKey::F6,
+    NamedKey::F7,
+    NamedKey::F8,
+    NamedKey::F9,
+    NamedKey::F10,
+    NamedKey::F11,
+    NamedKey::F12,
+    NamedKey::F13,
+    NamedKey::F14,
+    NamedKey::F15,
+    NamedKey::F16,
+    NamedKey::F17,
+    NamedKey::F18,
+    NamedKey::F19,
+    NamedKey::F20,
+    NamedKey::F21,
+    NamedKey::F22,
+    NamedKey::F23,
+    NamedKey::F24,
+];
+
+/// Parse a key name as scripts write it, e.g. `"a"`, `"Space"`, `"left_shift"`
+/// or `"F5"`. Case, spaces, underscores and dashes are ignored in names
+/// longer than one character. Unknown names give `Key::Unidentified`.
+pub fn parse_key(key_name: &str) -> Key {
+    let trimmed = key_name.trim();
+    if trimmed.chars().count() == 1 {
+        return Key::Character(trimmed.to_lowercase().into());
+    }
+
+    let normalized: String = trimmed
+        .chars()
+        .flat_map(|ch| ch.to_lowercase())
+        .filter(|ch| !matches!(ch, ' ' | '_' | '-'))
+        .collect();
+
+    if let Some(rest) = normalized.strip_prefix('f')
+        && let Ok(index) = rest.parse::<usize>()
+    {
+        return index
+            .checked_sub(1)
+            .and_then(|index| FUNCTION_KEYS.get(index))
+            .map_or(Key::Unidentified(NativeKey::Unidentified), |key| Key::Named(*key));
+    }
+
+    match normalized.as_str() {
+        // Named keys
+        "escape" | "esc" => Key::Named(NamedKey::Escape),
+        "enter" | "return" => Key::Named(NamedKey::Enter),
+        "space" => Key::Named(NamedKey::Space),
+        "backspace" => Key::Named(NamedKey::Backspace),
+        "tab" => Key::Named(NamedKey::Tab),
+        "arrowup" | "up" => Key::Named(NamedKey::ArrowUp),
+        "arrowdown" | "down" => Key::Named(NamedKey::ArrowDown),
+        "arrowleft" | "left" => Key::Named(NamedKey::ArrowLeft),
+        "arrowright" | "right" => Key::Named(NamedKey::ArrowRight),
+        "insert" => Key::Named(NamedKey::Insert),
+        "delete" | "del" => Key::Named(NamedKey::Delete),
+        "home" => Key::Named(NamedKey::Home),
+        "end" => Key::Named(NamedKey::End),
+        "pageup" | "pgup" => Key::Named(NamedKey::PageUp),
+        "pagedown" | "pgdown" => Key::Named(NamedKey::PageDown),
+        "numlock" => Key::Named(NamedKey::NumLock),
+        "scrolllock" => Key::Named(NamedKey::ScrollLock),
+        "pause" => Key::Named(NamedKey::Pause),
+        "printscreen" | "prtsc" | "snapshot" => Key::Named(NamedKey::PrintScreen),
+        "shift" => Key::Named(NamedKey::Shift),
+        "leftshift" | "lshift" | "rightshift" | "rshift" => Key::Named(NamedKey::Shift),
+        "control" | "ctrl" => Key::Named(NamedKey::Control),
+        "leftcontrol" | "lcontrol" | "leftctrl" | "lctrl" => Key::Named(NamedKey::Control),
+        "rightcontrol" | "rcontrol" | "rightctrl" | "rctrl" => Key::Named(NamedKey::Control),
+        "alt" => Key::Named(NamedKey::Alt),
+        "leftalt" | "lalt" | "rightalt" | "ralt" | "altgr" | "option" => Key::Named(NamedKey::Alt),
+        "super" | "meta" | "command" | "cmd" | "win" | "windows" | "os" => {
+            Key::Named(NamedKey::Super)
+        }
+        "capslock" => Key::Named(NamedKey::CapsLock),
+        "menu" | "contextmenu" => Key::Named(NamedKey::ContextMenu),
+
+        // Fallback
+        _ => Key::Unidentified(NativeKey::Unidentified),
+    }
+}
+
+/// Name of a key that [`parse_key`] reads back, or `None` for keys it has no
+/// name for.
+pub fn key_name(key: &Key) -> Option<String> {
+    let named = match key {
+        Key::Character(text) => return Some(text.to_string()),
+        Key::Named(named) => *named,
+        _ => return None,
+    };
+    if let Some(index) = FUNCTION_KEYS.iter().position(|key| *key == named) {
+        return Some(format!("f{}", index + 1));
+    }
+    let name = match named {
+        NamedKey::Escape => "escape",
+        NamedKey::Enter => "enter",
+        NamedKey::Space => "space",
+        NamedKey::Backspace => "backspace",
+        NamedKey::Tab => "tab",
+        NamedKey::ArrowUp => "arrowup",
+        NamedKey::ArrowDown => "arrowdown",
+        NamedKey::ArrowLeft => "arrowleft",
+        NamedKey::ArrowRight => "arrowright",
+        NamedKey::Insert => "insert",
+        NamedKey::Delete => "delete",
+        NamedKey::Home => "home",
+        NamedKey::End => "end",
+        NamedKey::PageUp => "pageup",
+        NamedKey::PageDown => "pagedown",
+        NamedKey::NumLock => "numlock",
+        NamedKey::ScrollLock => "scrolllock",
+        NamedKey::Pause => "pause",
+        NamedKey::PrintScreen => "printscreen",
+        NamedKey::Shift => "shift",
+        NamedKey::Control => "control",
+        NamedKey::Alt => "alt",
+        NamedKey::Super => "super",
+        NamedKey::CapsLock => "capslock",
+        NamedKey::ContextMenu => "menu",
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
+/// Parse a chord as scripts write it: modifier names and a final key or mouse
+/// button joined by `+`, e.g. `"ctrl+s"`, `"Ctrl+Shift+Z"` or `"shift+click"`.
+/// Modifiers accept the same names as [`parse_key`]; mouse buttons are
+/// `click`/`lmb`, `right_click`/`rmb` and `middle_click`/`mmb`. Write the plus
+/// key itself as `"ctrl++"`. Returns `None` for unknown names.
+pub fn parse_chord(chord: &str) -> Option<InputChord> {
+    let chord = chord.trim();
+    let (modifier_names, trigger) = match chord.strip_suffix("++") {
+        Some(rest) => (rest, "+"),
+        None if chord == "+" => ("", "+"),
+        None => chord.rsplit_once('+').unwrap_or(("", chord)),
+    };
+
+    let mut modifiers = ModifiersState::empty();
+    for name in modifier_names.split('+').filter(|name| !name.trim().is_empty()) {
+        modifiers |= modifier_flag(&parse_key(name))?;
+    }
+
+    let normalized: String = trigger
+        .trim()
+        .chars()
+        .flat_map(|ch| ch.to_lowercase())
+        .filter(|ch| !matches!(ch, ' ' | '_' | '-'))
+        .collect();
+    let trigger = match normalized.as_str() {
+        "click" | "leftclick" | "lmb" | "mouseleft" => ChordTrigger::MouseButton(MouseButtonType::Left),
+        "rightclick" | "rmb" | "mouseright" => ChordTrigger::MouseButton(MouseButtonType::Right),
+        "middleclick" | "mmb" | "mousemiddle" => ChordTrigger::MouseButton(MouseButtonType::Middle),
+        _ => match parse_key(trigger) {
+            Key::Unidentified(_) => return None,
+            key => ChordTrigger::Key(key),
+        },
+    };
+    Some(InputChord::new(modifiers, trigger))
+}
+
+/// Name of a chord that [`parse_chord`] reads back, e.g. `"ctrl+shift+z"`.
+pub fn chord_name(chord: &InputChord) -> Option<String> {
+    let mut parts: Vec<String> = [
+        (ModifiersState::CONTROL, "ctrl"),
+        (ModifiersState::SHIFT, "shift"),
+        (ModifiersState::ALT, "alt"),
+        (ModifiersState::SUPER, "super"),
+    ]
+    .into_iter()
+    .filter(|(flag, _)| chord.modifiers.contains(*flag))
+    .map(|(_, name)| name.to_string())
+    .collect();
+    parts.push(match &chord.trigger {
+        ChordTrigger::Key(key) => key_name(key)?,
+        ChordTrigger::MouseButton(button) => format!("mouse_{}", mouse_button_name(*button)?),
+    });
+    Some(parts.join("+"))
+}
+
+fn mouse_button_name(button: MouseButtonType) -> Option<&'static str> {
+    match button {
+        MouseButtonType::Left => Some("left"),
+        MouseButtonType::Right => Some("right"),
+        MouseButtonType::Middle => Some("middle"),
+        MouseButtonType::Other(_) => None,
+    }
+}
+
+fn mouse_axis_name(axis: MouseAxisType) -> &'static str {
+    match axis {
+        MouseAxisType::X => "x",
+        MouseAxisType::Y => "y",
+        MouseAxisType::WheelX => "wheel_x",
+        MouseAxisType::WheelY => "wheel_y",
+    }
+}
+
+fn key_names(keys: &[Key]) -> Vec<String> {
+    keys.iter().filter_map(key_name).collect()
+}
+
+fn axis_json(binding: &AxisBinding, smoothing: Option<AxisSmoothing>) -> Value {
+    let mut axis = Map::new();
+    if let Some(keyboard) = &binding.keyboard {
+        axis.insert(
+            "keyboard".into(),
+            json!({
+                "positive": key_names(&keyboard.positive_keys),
+                "negative": key_names(&keyboard.negative_keys),
+                "sensitivity": keyboard.sensitivity,
+            }),
+        );
+    }
+    if let Some(mouse) = &binding.mouse {
+        axis.insert(
+            "mouse".into(),
+            json!({
+                "axis": mouse_axis_name(mouse.axis),
+                "sensitivity": mouse.sensitivity,
+                "invert": mouse.invert,
+            }),
+        );
+    }
+    if let Some(joystick) = &binding.joystick {
+        axis.insert(
+            "joystick".into(),
+            json!({
+                "joystick_id": joystick.joystick_id,
+                "axis": joystick.axis.axis_id,
+                "deadzone": joystick.deadzone,
+                "sensitivity": joystick.sensitivity,
+                "invert": joystick.invert,
+            }),
+        );
+    }
+    if let Some(smoothing) = smoothing {
+        axis.insert(
+            "smoothing".into(),
+            json!({
+                "gravity": smoothing.gravity,
+                "sensitivity": smoothing.sensitivity,
+                "snap": smoothing.snap,
+            }),
+        );
+    }
+    Value::Object(axis)
+}
+
+/// Every axis binding and action mapping as one JSON document, with sorted
+/// names so saved files diff cleanly.
+pub fn bindings_to_json(input: &InputManager) -> Value {
+    let axes: BTreeMap<String, Value> = input
+        .axis_names()
+        .into_iter()
+        .filter_map(|name| {
+            let binding = input.axis_binding(&name)?;
+            let axis = axis_json(binding, input.axis_smoothing(&name));
+            Some((name, axis))
+        })
+        .collect();
+    let actions: BTreeMap<String, Value> = input
+        .action_names()
+        .into_iter()
+        .map(|name| {
+            let mouse_buttons: Vec<&str> = input
+                .action_mouse_buttons(&name)
+                .iter()
+                .filter_map(|button| mouse_button_name(*button))
+                .collect();
+            let joystick_buttons: Vec<Value> = input
+                .action_joystick_buttons(&name)
+                .iter()
+                .map(|button| json!({ "joystick_id": button.joystick_id, "button": button.button_id }))
+                .collect();
+            let chords: Vec<String> = input.action_chords(&name).iter().filter_map(chord_name).collect();
+            let action = json!({
+                "keys": key_names(input.action_keys(&name)),
+                "mouse_buttons": mouse_buttons,
+                "joystick_buttons": joystick_buttons,
+                "chords": chords,
+            });
+            (name, action)
+        })
+        .collect();
+
+    json!({
+        "format": BINDINGS_FORMAT,
+        "version": BINDINGS_VERSION,
+        "axes": axes,
+        "actions": actions,
+    })
+}
+
+/// Reads fields of one JSON object, naming the object in errors.
+/// A JSON object read field by field, naming the field in every error.
+pub(crate) struct Fields<'a> {
+    pub(crate) object: &'a Map<String, Value>,
+    pub(crate) context: String,
+}
+
+impl<'a> Fields<'a> {
+    pub(crate) fn new(value: &'a Value, context: impl Into<String>) -> Result<Self, String> {
+        let context = context.into();
+        let object = value
+            .as_object()
+            .ok_or_else(|| format!("{context} must be an object"))?;
+        Ok(Self { object, context })
+    }
+
+    pub(crate) fn get(&self, field: &str) -> Option<&'a Value> {
+        self.object.get(field).filter(|value| !value.is_null())
+    }
+
+    pub(crate) fn f32_or(&self, field: &str, default: f32) -> Result<f32, String> {
+        self.get(field).map_or(Ok(default), |value| {
+            value
+                .as_f64()
+                .map(|number| number as f32)
+                .ok_or_else(|| format!("{}.{field} must be a number", self.context))
+        })
+    }
+
+    pub(crate) fn bool_or(&self, field: &str, default: bool) -> Result<bool, String> {
+        self.get(field).map_or(Ok(default), |value| {
+            value
+                .as_bool()
+                .ok_or_else(|| format!("{}.{field} must be true or false", self.context))
+        })
+    }
+
+    pub(crate) fn u64(&self, field: &str) -> Result<Option<u64>, String> {
+        self.get(field)
+            .map(|value| {
+                value
+                    .as_u64()
+                    .ok_or_else(|| format!("{}.{field} must be a whole number", self.context))
+            })
+            .transpose()
+    }
+
+    pub(crate) fn str(&self, field: &str) -> Result<Option<&'a str>, String> {
+        self.get(field)
+            .map(|value| {
+                value
+                    .as_str()
+                    .ok_or_else(|| format!("{}.{field} must be a string", self.context))
+            })
+            .transpose()
+    }
+
+    pub(crate) fn strings(&self, field: &str) -> Result<Vec<&'a str>, String> {
+        let Some(value) = self.get(field) else {
+            return Ok(Vec::new());
+        };
+        let invalid = || format!("{}.{field} must be a list of strings", self.context);
+        value
+            .as_array()
+            .ok_or_else(invalid)?
+            .iter()
+            .map(|item| item.as_str().ok_or_else(invalid))
+            .collect()
+    }
+
+    pub(crate) fn list(&self, field: &str) -> Result<&'a [Value], String> {
+        self.get(field).map_or(Ok(&[]), |value| {
+            value
+                .as_array()
+                .map(Vec::as_slice)
+                .ok_or_else(|| format!("{}.{field} must be a list", self.context))
+        })
+    }
+}
+
+fn parse_keys(names: &[&str], context: &str) -> Result<Vec<Key>, String> {
+    names
+        .iter()
+        .map(|name| match parse_key(name) {
+            Key::Unidentified(_) => Err(format!("Unknown key '{name}' in {context}")),
+            key => Ok(key),
+        })
+        .collect()
+}
+
+fn small_id<T: TryFrom<u64>>(value: Option<u64>, context: &str) -> Result<Option<T>, String> {
+    value
+        .map(|id| T::try_from(id).map_err(|_| format!("{context} is out of range")))
+        .transpose()
+}
+
+fn parse_axis(name: &str, value: &Value) -> Result<(AxisBinding, Option<AxisSmoothing>), String> {
+    let context = format!("axes.{name}");
+    let fields = Fields::new(value, &context)?;
+
+    let keyboard = fields
+        .get("keyboard")
+        .map(|value| {
+            let keyboard = Fields::new(value, format!("{context}.keyboard"))?;
+            Ok::<_, String>(KeyboardAxisBinding {
+                positive_keys: parse_keys(&keyboard.strings("positive")?, &keyboard.context)?,
+                negative_keys: parse_keys(&keyboard.strings("negative")?, &keyboard.context)?,
+                sensitivity: keyboard.f32_or("sensitivity", 1.0)?,
+            })
+        })
+        .transpose()?;
+
+    let mouse = fields
+        .get("mouse")
+        .map(|value| {
+            let mouse = Fields::new(value, format!("{context}.mouse"))?;
+            let axis_name = mouse.get("axis").and_then(Value::as_str).unwrap_or_default();
+            let axis = [MouseAxisType::X, MouseAxisType::Y, MouseAxisType::WheelX, MouseAxisType::WheelY]
+                .into_iter()
+                .find(|axis| mouse_axis_name(*axis) == axis_name)
+                .ok_or_else(|| format!("{}.axis must be 'x', 'y', 'wheel_x' or 'wheel_y'", mouse.context))?;
+            Ok::<_, String>(MouseAxisBinding {
+                axis,
+                sensitivity: mouse.f32_or("sensitivity", 1.0)?,
+                invert: mouse.bool_or("invert", false)?,
+            })
+        })
+        .transpose()?;
+
+    let joystick = fields
+        .get("joystick")
+        .map(|value| {
+            let joystick = Fields::new(value, format!("{context}.joystick"))?;
+            let joystick_id = small_id(joystick.u64("joystick_id")?, &format!("{}.joystick_id", joystick.context))?;
+            let axis_id = small_id(joystick.u64("axis")?, &format!("{}.axis", joystick.context))?
+                .ok_or_else(|| format!("{}.axis is required", joystick.context))?;
+            Ok::<_, String>(JoystickAxisBinding {
+                joystick_id,
+                axis: JoystickAxis {
+                    joystick_id: joystick_id.unwrap_or(0),
+                    axis_id,
+                },
+                deadzone: joystick.f32_or("deadzone", 0.15)?,
+                sensitivity: joystick.f32_or("sensitivity", 1.0)?,
+                invert: joystick.bool_or("invert", false)?,
+            })
+        })
+        .transpose()?;
+
+    let smoothing = fields
+        .get("smoothing")
+        .map(|value| {
+            let smoothing = Fields::new(value, format!("{context}.smoothing"))?;
+            let defaults = AxisSmoothing::default();
+            Ok::<_, String>(AxisSmoothing::new(
+                smoothing.f32_or("gravity", defaults.gravity)?,
+                smoothing.f32_or("sensitivity", defaults.sensitivity)?,
+                smoothing.bool_or("snap", defaults.snap)?,
+            ))
+        })
+        .transpose()?;
+
+    Ok((
+        AxisBinding {
+            keyboard,
+            mouse,
+            joystick,
+        },
+        smoothing,
+    ))
+}
+
+type ActionBindings = (Vec<Key>, Vec<MouseButtonType>, Vec<JoystickButton>, Vec<InputChord>);
+
+fn parse_action(name: &str, value: &Value) -> Result<ActionBindings, String> {
+    let fields = Fields::new(value, format!("actions.{name}"))?;
+    let keys = parse_keys(&fields.strings("keys")?, &fields.context)?;
+    let mouse_buttons = fields
+        .strings("mouse_buttons")?
+        .into_iter()
+        .map(|button| match button.to_ascii_lowercase().as_str() {
+            "left" => Ok(MouseButtonType::Left),
+            "right" => Ok(MouseButtonType::Right),
+            "middle" => Ok(MouseButtonType::Middle),
+            _ => Err(format!("Unknown mouse button '{button}' in {}", fields.context)),
+        })
+        .collect::<Result<_, _>>()?;
+    let joystick_buttons = fields
+        .list("joystick_buttons")?
+        .iter()
+        .map(|value| {
+            let button = Fields::new(value, format!("{}.joystick_buttons", fields.context))?;
+            Ok::<_, String>(JoystickButton {
+                joystick_id: small_id(button.u64("joystick_id")?, &button.context)?.unwrap_or(0),
+                button_id: small_id(button.u64("button")?, &button.context)?
+                    .ok_or_else(|| format!("{}.button is required", button.context))?,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    let chords = fields
+        .strings("chords")?
+        .into_iter()
+        .map(|chord| parse_chord(chord).ok_or_else(|| format!("Unknown chord '{chord}' in {}", fields.context)))
+        .collect::<Result<_, _>>()?;
+    Ok((keys, mouse_buttons, joystick_buttons, chords))
+}
+
+/// Replace every axis binding and action mapping with the ones in `value`.
+///
+/// The document is checked in full first; on error nothing changes.
+pub fn apply_bindings_json(input: &mut InputManager, value: &Value) -> Result<(), String> {
+    let document = Fields::new(value, "Bindings")?;
+    if let Some(format) = document.get("format")
+        && format.as_str() != Some(BINDINGS_FORMAT)
+    {
+        return Err(format!("Not an input bindings file (format {format})"));
+    }
+    if let Some(version) = document.u64("version")?
+        && version > u64::from(BINDINGS_VERSION)
+    {
+        return Err(format!(
+            "Input bindings version {version} is newer than the supported version {BINDINGS_VERSION}"
+        ));
+    }
+
+    let mut axes = Vec::new();
+    if let Some(value) = document.get("axes") {
+        for (name, axis) in Fields::new(value, "axes")?.object {
+            axes.push((name.clone(), parse_axis(name, axis)?));
+        }
+    }
+    let mut actions = Vec::new();
+    if let Some(value) = document.get("actions") {
+        for (name, action) in Fields::new(value, "actions")?.object {
+            actions.push((name.clone(), parse_action(name, action)?));
+        }
+    }
+
+    input.clear_input_bindings();
+    for (name, (binding, smoothing)) in axes {
+        input.set_axis_binding(name.clone(), binding);
+        input.set_axis_smoothing(&name, smoothing);
+    }
+    for (name, (keys, mouse_buttons, joystick_buttons, chords)) in actions {
+        input.set_action_keys(name.clone(), keys);
+        input.set_action_mouse_buttons(name.clone(), mouse_buttons);
+        input.set_action_joystick_buttons(name.clone(), joystick_buttons);
+        input.set_action_chords(name, chords);
+    }
+    Ok(())
+}
+
+/// Write the bindings to a JSON file.
+pub fn save_bindings(input: &InputManager, path: &str) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(&bindings_to_json(input))
+        .map_err(|e| format!("Failed to serialize input bindings: {e}"))?;
+    std::fs::write(path, json).map_err(|e| format!("Failed to write input bindings '{path}': {e}"))
+}
+
+/// Replace the bindings with the ones saved in a JSON file.
+pub fn load_bindings(input: &mut InputManager, path: &str) -> Result<(), String> {
+    let text =
+        std::fs::read_to_string(path).map_err(|e| format!("Failed to read input bindings '{path}': {e}"))?;
+    let value: Value =
+        serde_json::from_str(&text).map_err(|e| format!("Failed to parse input bindings '{path}': {e}"))?;
+    apply_bindings_json(input, &value).map_err(|e| format!("Invalid input bindings '{path}': {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bindings_round_trip() {
+        let mut input = InputManager::new();
+        input.set_axis_keyboard_keys("Horizontal", vec![parse_key("l")], vec![parse_key("j")], 0.5);
+        input.set_axis_smoothing("Horizontal", Some(AxisSmoothing::new(5.0, 2.0, false)));
+        input.set_action_keys("jump", vec![parse_key("F5"), parse_key("-")]);
+        input.set_action_joystick_buttons("jump", vec![JoystickButton { joystick_id: 0, button_id: 3 }]);
+        input.set_action_chords("save", vec![parse_chord("Ctrl+S").unwrap(), parse_chord("shift+click").unwrap()]);
+        let saved = bindings_to_json(&input);
+
+        let mut loaded = InputManager::new();
+        loaded.set_action_keys("extra", vec![parse_key("x")]);
+        apply_bindings_json(&mut loaded, &saved).unwrap();
+        assert_eq!(bindings_to_json(&loaded), saved);
+        assert_eq!(loaded.action_keys("jump"), [Key::Named(NamedKey::F5), Key::Character("-".into())]);
+        assert_eq!(loaded.action_joystick_buttons("jump")[0].button_id, 3);
+        assert_eq!(saved["actions"]["save"]["chords"], json!(["ctrl+s", "shift+mouse_left"]));
+        assert_eq!(loaded.action_chords("save"), input.action_chords("save"));
+        assert_eq!(loaded.axis_smoothing("horizontal"), Some(AxisSmoothing::new(5.0, 2.0, false)));
+        assert!(loaded.action_keys("extra").is_empty());
+    }
+
+    #[test]
+    fn test_parse_chord() {
+        let redo = parse_chord("Ctrl + Shift + Z").unwrap();
+        assert_eq!(redo.modifiers, ModifiersState::CONTROL | ModifiersState::SHIFT);
+        assert_eq!(redo.trigger, ChordTrigger::Key(Key::Character("z".into())));
+        assert_eq!(parse_chord("ctrl++").unwrap().trigger, ChordTrigger::Key(Key::Character("+".into())));
+        assert_eq!(
+            parse_chord("alt+rmb").unwrap().trigger,
+            ChordTrigger::MouseButton(MouseButtonType::Right)
+        );
+        assert!(parse_chord("s+ctrl").is_none());
+        assert!(parse_chord("ctrl+nope").is_none());
+    }
+
+    #[test]
+    fn test_invalid_bindings_change_nothing() {
+        let mut input = InputManager::new();
+        let before = bindings_to_json(&input);
+        let invalid = json!({
+            "axes": { "Horizontal": { "keyboard": { "positive": ["d"] } } },
+            "actions": { "jump": { "keys": ["not a key"] } },
+        });
+        let err = apply_bindings_json(&mut input, &invalid).unwrap_err();
+        assert!(err.contains("not a key"), "{err}");
+        assert_eq!(bindings_to_json(&input), before);
+    }
+}
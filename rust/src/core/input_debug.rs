@@ -0,0 +1,339 @@
+//! On-screen input debug overlay.
+//!
+//! Draws live axis values, held keys and buttons, the active device, player
+//! slot devices and a short log of recent action presses and releases in the
+//! top-left corner of the window. Binding problems ("why doesn't jump fire?")
+//! are otherwise invisible, since the engine only exposes the end result.
+
+use std::collections::{HashSet, VecDeque};
+use winit::keyboard::Key;
+
+use super::draw_manager::DrawManager;
+use super::input_glyphs::InputGlyph;
+use super::input_manager::{InputEvent, InputManager, JoystickButton};
+use super::input_players::MAX_PLAYERS;
+use super::text::{TextLayoutOptions, TextStyle};
+use crate::types::color::Color;
+
+/// Number of recent action events kept in the log.
+const MAX_RECENT_ACTIONS: usize = 8;
+/// Draw order for the overlay, above any game or UI content.
+const OVERLAY_DRAW_ORDER: f32 = 100_000.0;
+const FONT_SIZE: f32 = 14.0;
+const LINE_HEIGHT: f32 = 18.0;
+const PADDING: f32 = 8.0;
+const PANEL_WIDTH: f32 = 340.0;
+/// Width of the bar showing an axis value in [-1, 1].
+const AXIS_BAR_WIDTH: f32 = 80.0;
+
+/// One action press or release shown in the recent-events log.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActionLogEntry {
+    /// Normalized action name
+    pub action: String,
+    /// `true` for a press, `false` for a release
+    pub pressed: bool,
+    /// Label of the key or button that triggered it, e.g. "Space" or "LMB"
+    pub source: String,
+    /// Engine time in seconds when it happened
+    pub time: f32,
+}
+
+/// Input debug overlay state, owned by the engine.
+#[derive(Debug, Default)]
+pub struct InputDebugOverlay {
+    enabled: bool,
+    /// Key that flips `enabled` when pressed
+    toggle_key: Option<Key>,
+    /// Keys seen pressed, used to skip OS key repeats
+    keys_down: HashSet<Key>,
+    /// Newest entries at the front
+    recent_actions: VecDeque<ActionLogEntry>,
+}
+
+impl InputDebugOverlay {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.recent_actions.clear();
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Set a key that shows or hides the overlay when pressed, or `None` for no hotkey.
+    pub fn set_toggle_key(&mut self, key: Option<Key>) {
+        self.toggle_key = key;
+    }
+
+    pub fn toggle_key(&self) -> Option<&Key> {
+        self.toggle_key.as_ref()
+    }
+
+    /// Recent action events, newest first.
+    pub fn recent_actions(&self) -> impl Iterator<Item = &ActionLogEntry> {
+        self.recent_actions.iter()
+    }
+
+    /// Consume this frame's input events. Call once per frame after `InputManager::update`.
+    pub fn record(&mut self, input: &InputManager, time: f32) {
+        for event in input.frame_events() {
+            let (pressed, source) = match event {
+                InputEvent::KeyPressed { key } => {
+                    // OS key repeat sends more presses for a held key.
+                    if !self.keys_down.insert(key.clone()) {
+                        continue;
+                    }
+                    if self.toggle_key.as_ref() == Some(key) {
+                        self.set_enabled(!self.enabled);
+                        continue;
+                    }
+                    (true, InputGlyph::for_key(key).label)
+                }
+                InputEvent::KeyReleased { key } => {
+                    self.keys_down.remove(key);
+                    (false, InputGlyph::for_key(key).label)
+                }
+                InputEvent::MouseButtonPressed { button } => (true, InputGlyph::for_mouse_button(*button).label),
+                InputEvent::MouseButtonReleased { button } => (false, InputGlyph::for_mouse_button(*button).label),
+                InputEvent::JoystickButtonPressed { joystick_id, button_id } => {
+                    (true, Self::joystick_button_label(*joystick_id, *button_id))
+                }
+                InputEvent::JoystickButtonReleased { joystick_id, button_id } => {
+                    (false, Self::joystick_button_label(*joystick_id, *button_id))
+                }
+                _ => continue,
+            };
+
+            if !self.enabled {
+                continue;
+            }
+            // Reversed so one event's actions read in name order, newest event first.
+            for action in input.actions_for_event(event).into_iter().rev() {
+                self.recent_actions.push_front(ActionLogEntry {
+                    action,
+                    pressed,
+                    source: source.clone(),
+                    time,
+                });
+            }
+        }
+        self.recent_actions.truncate(MAX_RECENT_ACTIONS);
+    }
+
+    /// Draw the overlay in logical pixels. Returns the index of the first
+    /// command added, so the caller can scale or remove them after rendering.
+    pub fn render(&self, input: &InputManager, draw_manager: &mut DrawManager, time: f32) -> usize {
+        let start = draw_manager.commands().len();
+        if !self.enabled {
+            return start;
+        }
+
+        let lines = self.lines(input, time);
+        let height = PADDING * 2.0 + LINE_HEIGHT * lines.len() as f32;
+        draw_manager.draw_rectangle_with_options(
+            PADDING,
+            PADDING,
+            PANEL_WIDTH,
+            height,
+            Color::new(0.0, 0.0, 0.0, 0.75),
+            true,
+            1.0,
+            OVERLAY_DRAW_ORDER,
+        );
+
+        let text_x = PADDING * 2.0;
+        let mut y = PADDING * 2.0;
+        for line in lines {
+            let color = match line {
+                OverlayLine::Heading(_) => Color::new(1.0, 0.85, 0.3, 1.0),
+                _ => Color::WHITE,
+            };
+            let text = match &line {
+                OverlayLine::Heading(text) | OverlayLine::Text(text) => text.clone(),
+                OverlayLine::Axis { label, value } => {
+                    Self::draw_axis_bar(draw_manager, PADDING + PANEL_WIDTH - PADDING - AXIS_BAR_WIDTH, y, *value);
+                    label.clone()
+                }
+            };
+            draw_manager.draw_text_with_options(
+                text,
+                text_x,
+                y,
+                TextStyle::new(FONT_SIZE),
+                color,
+                TextLayoutOptions::default(),
+                OVERLAY_DRAW_ORDER + 1.0,
+            );
+            y += LINE_HEIGHT;
+        }
+        start
+    }
+
+    fn lines(&self, input: &InputManager, time: f32) -> Vec<OverlayLine> {
+        let mut lines = vec![OverlayLine::Heading(format!(
+            "Input  (active: {})",
+            input.active_device().as_str()
+        ))];
+
+        lines.push(OverlayLine::Heading("Axes".to_string()));
+        let axis_names = input.axis_names();
+        if axis_names.is_empty() {
+            lines.push(OverlayLine::Text("  (none)".to_string()));
+        }
+        for name in axis_names {
+            let value = input.axis(&name);
+            lines.push(OverlayLine::Axis {
+                label: format!("  {name}: {value:+.2} (raw {:+.2})", input.axis_raw(&name)),
+                value,
+            });
+        }
+
+        lines.push(OverlayLine::Heading("Held".to_string()));
+        let mut held: Vec<String> = input
+            .held_keys()
+            .iter()
+            .map(|key| InputGlyph::for_key(key).label)
+            .chain(
+                input
+                    .held_mouse_buttons()
+                    .into_iter()
+                    .map(|button| InputGlyph::for_mouse_button(button).label),
+            )
+            .collect();
+        held.sort();
+        held.extend(
+            input
+                .held_joystick_buttons()
+                .into_iter()
+                .map(|button| Self::joystick_button_label(button.joystick_id, button.button_id)),
+        );
+        lines.push(OverlayLine::Text(if held.is_empty() {
+            "  (nothing)".to_string()
+        } else {
+            format!("  {}", held.join(", "))
+        }));
+
+        let players: Vec<String> = (1..=MAX_PLAYERS)
+            .map(|player| match input.player_device(player) {
+                Some(device) => match device.joystick_id() {
+                    Some(id) => format!("P{player} {} {id}", device.as_str()),
+                    None => format!("P{player} {}", device.as_str()),
+                },
+                None => format!("P{player} -"),
+            })
+            .collect();
+        lines.push(OverlayLine::Heading("Players".to_string()));
+        lines.push(OverlayLine::Text(format!("  {}", players.join(", "))));
+
+        lines.push(OverlayLine::Heading("Recent actions".to_string()));
+        if self.recent_actions.is_empty() {
+            lines.push(OverlayLine::Text("  (none)".to_string()));
+        }
+        for entry in &self.recent_actions {
+            lines.push(OverlayLine::Text(format!(
+                "  {} {} ({})  {:.1}s ago",
+                entry.action,
+                if entry.pressed { "pressed" } else { "released" },
+                entry.source,
+                (time - entry.time).max(0.0),
+            )));
+        }
+        lines
+    }
+
+    fn joystick_button_label(joystick_id: u32, button_id: u8) -> String {
+        let button = JoystickButton { joystick_id, button_id };
+        format!("Pad{joystick_id} {}", InputGlyph::for_joystick_button(button).label)
+    }
+
+    fn draw_axis_bar(draw_manager: &mut DrawManager, x: f32, y: f32, value: f32) {
+        let bar_y = y + LINE_HEIGHT * 0.3;
+        let bar_height = LINE_HEIGHT * 0.4;
+        let center = x + AXIS_BAR_WIDTH / 2.0;
+        draw_manager.draw_rectangle_with_options(
+            x,
+            bar_y,
+            AXIS_BAR_WIDTH,
+            bar_height,
+            Color::new(1.0, 1.0, 1.0, 0.2),
+            true,
+            1.0,
+            OVERLAY_DRAW_ORDER + 1.0,
+        );
+        let fill = value.clamp(-1.0, 1.0) * AXIS_BAR_WIDTH / 2.0;
+        if fill != 0.0 {
+            draw_manager.draw_rectangle_with_options(
+                center.min(center + fill),
+                bar_y,
+                fill.abs(),
+                bar_height,
+                Color::new(0.3, 0.8, 1.0, 1.0),
+                true,
+                1.0,
+                OVERLAY_DRAW_ORDER + 2.0,
+            );
+        }
+    }
+}
+
+enum OverlayLine {
+    Heading(String),
+    Text(String),
+    /// Text with a value bar on the right
+    Axis { label: String, value: f32 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records_action_events_while_enabled() {
+        let mut input = InputManager::new();
+        let mut overlay = InputDebugOverlay::new();
+
+        // Nothing is logged while the overlay is hidden.
+        input.handle_joystick_button(0, 1, true);
+        input.update(1.0 / 60.0);
+        overlay.record(&input, 0.0);
+        input.handle_joystick_button(0, 1, false);
+        input.update(1.0 / 60.0);
+        overlay.record(&input, 0.5);
+        assert_eq!(overlay.recent_actions().count(), 0);
+
+        overlay.set_enabled(true);
+        input.handle_joystick_button(0, 1, true);
+        input.update(1.0 / 60.0);
+        overlay.record(&input, 1.0);
+        input.handle_joystick_button(0, 1, false);
+        input.update(1.0 / 60.0);
+        overlay.record(&input, 2.0);
+
+        let log: Vec<(&str, bool, f32)> = overlay
+            .recent_actions()
+            .map(|entry| (entry.action.as_str(), entry.pressed, entry.time))
+            .collect();
+        assert_eq!(
+            log,
+            vec![
+                ("cancel", false, 2.0),
+                ("fire2", false, 2.0),
+                ("cancel", true, 1.0),
+                ("fire2", true, 1.0),
+            ]
+        );
+        assert_eq!(overlay.recent_actions().next().unwrap().source, "Pad0 B");
+
+        let mut draw_manager = DrawManager::new();
+        let start = overlay.render(&input, &mut draw_manager, 2.0);
+        assert_eq!(start, 0);
+        assert!(!draw_manager.commands().is_empty());
+    }
+}
@@ -0,0 +1,520 @@
+//! Display glyphs for input bindings.
+//!
+//! Maps keys, mouse buttons, and gamepad buttons to short labels ("Space",
+//! "LMB", "A") and, where available, to an icon in the built-in glyph atlas.
+//! The atlas is rasterized once on first use from simple shapes and the
+//! embedded `font8x8` glyphs, so no asset files are required.
+
+use super::input_manager::{ChordTrigger, InputChord, InputDevice, JoystickButton, MouseButtonType};
+use font8x8::{BASIC_FONTS, UnicodeFonts};
+use std::sync::OnceLock;
+use winit::keyboard::{Key, ModifiersState, NamedKey};
+
+/// Size in pixels of one square icon cell in the glyph atlas.
+pub const GLYPH_ICON_SIZE: u32 = 32;
+/// Number of icon cells per atlas row.
+const ATLAS_COLUMNS: u32 = 8;
+
+/// Icons available in the built-in glyph atlas.
+///
+/// Gamepad buttons follow the common Xbox/SDL layout: button ids 0-3 are the
+/// south/east/west/north face buttons, 4-7 bumpers and triggers, 8-9
+/// back/start, 10-11 stick clicks, and 12-15 the d-pad.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GlyphIcon {
+    FaceSouth,
+    FaceEast,
+    FaceWest,
+    FaceNorth,
+    LeftBumper,
+    RightBumper,
+    LeftTrigger,
+    RightTrigger,
+    Back,
+    Start,
+    LeftStick,
+    RightStick,
+    DpadUp,
+    DpadDown,
+    DpadLeft,
+    DpadRight,
+    MouseLeft,
+    MouseRight,
+    MouseMiddle,
+}
+
+impl GlyphIcon {
+    /// All icons in atlas order.
+    pub const ALL: [GlyphIcon; 19] = [
+        Self::FaceSouth,
+        Self::FaceEast,
+        Self::FaceWest,
+        Self::FaceNorth,
+        Self::LeftBumper,
+        Self::RightBumper,
+        Self::LeftTrigger,
+        Self::RightTrigger,
+        Self::Back,
+        Self::Start,
+        Self::LeftStick,
+        Self::RightStick,
+        Self::DpadUp,
+        Self::DpadDown,
+        Self::DpadLeft,
+        Self::DpadRight,
+        Self::MouseLeft,
+        Self::MouseRight,
+        Self::MouseMiddle,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::FaceSouth => "gamepad_south",
+            Self::FaceEast => "gamepad_east",
+            Self::FaceWest => "gamepad_west",
+            Self::FaceNorth => "gamepad_north",
+            Self::LeftBumper => "gamepad_left_bumper",
+            Self::RightBumper => "gamepad_right_bumper",
+            Self::LeftTrigger => "gamepad_left_trigger",
+            Self::RightTrigger => "gamepad_right_trigger",
+            Self::Back => "gamepad_back",
+            Self::Start => "gamepad_start",
+            Self::LeftStick => "gamepad_left_stick",
+            Self::RightStick => "gamepad_right_stick",
+            Self::DpadUp => "gamepad_dpad_up",
+            Self::DpadDown => "gamepad_dpad_down",
+            Self::DpadLeft => "gamepad_dpad_left",
+            Self::DpadRight => "gamepad_dpad_right",
+            Self::MouseLeft => "mouse_left",
+            Self::MouseRight => "mouse_right",
+            Self::MouseMiddle => "mouse_middle",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        let normalized = value.trim().to_ascii_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|icon| icon.as_str() == normalized)
+    }
+
+    /// Map a gamepad button id to its icon, if the id is part of the standard layout.
+    pub fn from_gamepad_button(button_id: u8) -> Option<Self> {
+        Self::ALL
+            .get(button_id as usize)
+            .copied()
+            .filter(|icon| !icon.is_mouse())
+    }
+
+    /// Map a mouse button to its icon.
+    pub fn from_mouse_button(button: MouseButtonType) -> Option<Self> {
+        match button {
+            MouseButtonType::Left => Some(Self::MouseLeft),
+            MouseButtonType::Right => Some(Self::MouseRight),
+            MouseButtonType::Middle => Some(Self::MouseMiddle),
+            MouseButtonType::Other(_) => None,
+        }
+    }
+
+    fn is_mouse(self) -> bool {
+        matches!(self, Self::MouseLeft | Self::MouseRight | Self::MouseMiddle)
+    }
+
+    fn index(self) -> u32 {
+        Self::ALL.iter().position(|icon| *icon == self).unwrap_or(0) as u32
+    }
+
+    /// Short text label for the icon, used when icons are not drawn.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::FaceSouth => "A",
+            Self::FaceEast => "B",
+            Self::FaceWest => "X",
+            Self::FaceNorth => "Y",
+            Self::LeftBumper => "LB",
+            Self::RightBumper => "RB",
+            Self::LeftTrigger => "LT",
+            Self::RightTrigger => "RT",
+            Self::Back => "Back",
+            Self::Start => "Start",
+            Self::LeftStick => "LS",
+            Self::RightStick => "RS",
+            Self::DpadUp => "D-Up",
+            Self::DpadDown => "D-Down",
+            Self::DpadLeft => "D-Left",
+            Self::DpadRight => "D-Right",
+            Self::MouseLeft => "LMB",
+            Self::MouseRight => "RMB",
+            Self::MouseMiddle => "MMB",
+        }
+    }
+
+    /// Pixel rectangle `(x, y, width, height)` of this icon in the glyph atlas.
+    pub fn atlas_rect(self) -> (u32, u32, u32, u32) {
+        let index = self.index();
+        (
+            (index % ATLAS_COLUMNS) * GLYPH_ICON_SIZE,
+            (index / ATLAS_COLUMNS) * GLYPH_ICON_SIZE,
+            GLYPH_ICON_SIZE,
+            GLYPH_ICON_SIZE,
+        )
+    }
+}
+
+/// One displayable glyph for a bound input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputGlyph {
+    /// Device family the binding belongs to.
+    pub device: InputDevice,
+    /// Short label, e.g. "Space" or "A".
+    pub label: String,
+    /// Atlas icon for the binding, if one exists.
+    pub icon: Option<GlyphIcon>,
+}
+
+impl InputGlyph {
+    pub fn for_key(key: &Key) -> Self {
+        Self {
+            device: InputDevice::KeyboardMouse,
+            label: key_label(key),
+            icon: None,
+        }
+    }
+
+    pub fn for_mouse_button(button: MouseButtonType) -> Self {
+        let icon = GlyphIcon::from_mouse_button(button);
+        let label = match (icon, button) {
+            (Some(icon), _) => icon.label().to_string(),
+            (None, MouseButtonType::Other(id)) => format!("Mouse {id}"),
+            (None, _) => "Mouse".to_string(),
+        };
+        Self {
+            device: InputDevice::KeyboardMouse,
+            label,
+            icon,
+        }
+    }
+
+    /// Glyph for a modifier chord, labelled like "Ctrl+S" or "Shift+LMB".
+    pub fn for_chord(chord: &InputChord) -> Self {
+        let mut parts: Vec<String> = [
+            (ModifiersState::CONTROL, NamedKey::Control),
+            (ModifiersState::SHIFT, NamedKey::Shift),
+            (ModifiersState::ALT, NamedKey::Alt),
+            (ModifiersState::SUPER, NamedKey::Super),
+        ]
+        .into_iter()
+        .filter(|(flag, _)| chord.modifiers.contains(*flag))
+        .map(|(_, key)| key_label(&Key::Named(key)))
+        .collect();
+        parts.push(match &chord.trigger {
+            ChordTrigger::Key(key) => key_label(key),
+            ChordTrigger::MouseButton(button) => Self::for_mouse_button(*button).label,
+        });
+        Self {
+            device: InputDevice::KeyboardMouse,
+            label: parts.join("+"),
+            icon: None,
+        }
+    }
+
+    pub fn for_joystick_button(button: JoystickButton) -> Self {
+        let icon = GlyphIcon::from_gamepad_button(button.button_id);
+        let label = icon
+            .map(|icon| icon.label().to_string())
+            .unwrap_or_else(|| format!("Button {}", button.button_id));
+        Self {
+            device: InputDevice::Gamepad,
+            label,
+            icon,
+        }
+    }
+
+    /// Bracketed prompt text, e.g. "[Space]".
+    pub fn prompt_text(&self) -> String {
+        format!("[{}]", self.label)
+    }
+}
+
+/// Human-readable label for a keyboard key.
+pub fn key_label(key: &Key) -> String {
+    match key {
+        Key::Character(text) => {
+            let upper = text.to_uppercase();
+            match upper.as_str() {
+                " " => "Space".to_string(),
+                _ => upper,
+            }
+        }
+        Key::Named(named) => match named {
+            NamedKey::Space => "Space".to_string(),
+            NamedKey::Enter => "Enter".to_string(),
+            NamedKey::Escape => "Esc".to_string(),
+            NamedKey::Backspace => "Backspace".to_string(),
+            NamedKey::Tab => "Tab".to_string(),
+            NamedKey::ArrowUp => "Up".to_string(),
+            NamedKey::ArrowDown => "Down".to_string(),
+            NamedKey::ArrowLeft => "Left".to_string(),
+            NamedKey::ArrowRight => "Right".to_string(),
+            NamedKey::Shift => "Shift".to_string(),
+            NamedKey::Control => "Ctrl".to_string(),
+            NamedKey::Alt => "Alt".to_string(),
+            NamedKey::Super => "Super".to_string(),
+            NamedKey::Delete => "Del".to_string(),
+            NamedKey::Insert => "Ins".to_string(),
+            NamedKey::PageUp => "PgUp".to_string(),
+            NamedKey::PageDown => "PgDn".to_string(),
+            NamedKey::CapsLock => "Caps Lock".to_string(),
+            NamedKey::PrintScreen => "PrtSc".to_string(),
+            NamedKey::ContextMenu => "Menu".to_string(),
+            other => format!("{other:?}"),
+        },
+        _ => "?".to_string(),
+    }
+}
+
+/// Built-in RGBA atlas containing every [`GlyphIcon`].
+pub struct GlyphAtlas {
+    pub width: u32,
+    pub height: u32,
+    pub rgba: Vec<u8>,
+}
+
+impl GlyphAtlas {
+    /// Texture key used when drawing atlas icons through `draw_image_from_bytes`.
+    pub const TEXTURE_KEY: &'static str = "__pyg_input_glyph_atlas";
+
+    /// Copy a single icon out of the atlas as tightly packed RGBA.
+    pub fn icon_rgba(&self, icon: GlyphIcon) -> Vec<u8> {
+        let (x, y, w, h) = icon.atlas_rect();
+        let mut out = Vec::with_capacity((w * h * 4) as usize);
+        for row in y..y + h {
+            let start = ((row * self.width + x) * 4) as usize;
+            out.extend_from_slice(&self.rgba[start..start + (w * 4) as usize]);
+        }
+        out
+    }
+}
+
+/// Get the built-in glyph atlas, rasterizing it on first use.
+pub fn glyph_atlas() -> &'static GlyphAtlas {
+    static ATLAS: OnceLock<GlyphAtlas> = OnceLock::new();
+    ATLAS.get_or_init(build_glyph_atlas)
+}
+
+struct Canvas<'a> {
+    rgba: &'a mut [u8],
+    stride: u32,
+    origin_x: u32,
+    origin_y: u32,
+}
+
+impl Canvas<'_> {
+    fn put(&mut self, x: i32, y: i32, color: [u8; 4]) {
+        let size = GLYPH_ICON_SIZE as i32;
+        if x < 0 || y < 0 || x >= size || y >= size {
+            return;
+        }
+        let px = self.origin_x + x as u32;
+        let py = self.origin_y + y as u32;
+        let idx = ((py * self.stride + px) * 4) as usize;
+        self.rgba[idx..idx + 4].copy_from_slice(&color);
+    }
+
+    fn fill_with(&mut self, color: [u8; 4], inside: impl Fn(f32, f32) -> bool) {
+        let size = GLYPH_ICON_SIZE as i32;
+        for y in 0..size {
+            for x in 0..size {
+                if inside(x as f32 + 0.5, y as f32 + 0.5) {
+                    self.put(x, y, color);
+                }
+            }
+        }
+    }
+
+    fn fill_circle(&mut self, cx: f32, cy: f32, radius: f32, color: [u8; 4]) {
+        self.fill_with(color, |x, y| {
+            (x - cx).powi(2) + (y - cy).powi(2) <= radius * radius
+        });
+    }
+
+    fn fill_rounded_rect(&mut self, x0: f32, y0: f32, x1: f32, y1: f32, r: f32, color: [u8; 4]) {
+        self.fill_with(color, |x, y| {
+            if x < x0 || x > x1 || y < y0 || y > y1 {
+                return false;
+            }
+            let dx = (x0 + r - x).max(x - (x1 - r)).max(0.0);
+            let dy = (y0 + r - y).max(y - (y1 - r)).max(0.0);
+            dx * dx + dy * dy <= r * r
+        });
+    }
+
+    fn fill_triangle(&mut self, a: (f32, f32), b: (f32, f32), c: (f32, f32), color: [u8; 4]) {
+        let edge = |p: (f32, f32), q: (f32, f32), x: f32, y: f32| {
+            (q.0 - p.0) * (y - p.1) - (q.1 - p.1) * (x - p.0)
+        };
+        self.fill_with(color, |x, y| {
+            let e0 = edge(a, b, x, y);
+            let e1 = edge(b, c, x, y);
+            let e2 = edge(c, a, x, y);
+            (e0 >= 0.0 && e1 >= 0.0 && e2 >= 0.0) || (e0 <= 0.0 && e1 <= 0.0 && e2 <= 0.0)
+        });
+    }
+
+    /// Draw text centered in the cell using the 8x8 bitmap font.
+    fn text(&mut self, text: &str, scale: i32, color: [u8; 4]) {
+        let size = GLYPH_ICON_SIZE as i32;
+        let glyph_count = text.chars().count() as i32;
+        let start_x = (size - glyph_count * 8 * scale) / 2;
+        let start_y = (size - 8 * scale) / 2;
+        for (index, ch) in text.chars().enumerate() {
+            let Some(bitmap) = BASIC_FONTS.get(ch) else {
+                continue;
+            };
+            let pen_x = start_x + index as i32 * 8 * scale;
+            for (row, bits) in bitmap.iter().enumerate() {
+                for col in 0..8 {
+                    if (bits >> col) & 1 == 0 {
+                        continue;
+                    }
+                    for sy in 0..scale {
+                        for sx in 0..scale {
+                            self.put(
+                                pen_x + col * scale + sx,
+                                start_y + row as i32 * scale + sy,
+                                color,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn draw_icon(canvas: &mut Canvas<'_>, icon: GlyphIcon) {
+    const WHITE: [u8; 4] = [255, 255, 255, 255];
+    const DARK: [u8; 4] = [48, 52, 60, 255];
+    const MID: [u8; 4] = [96, 102, 112, 255];
+    let center = GLYPH_ICON_SIZE as f32 / 2.0;
+
+    match icon {
+        GlyphIcon::FaceSouth | GlyphIcon::FaceEast | GlyphIcon::FaceWest | GlyphIcon::FaceNorth => {
+            let fill = match icon {
+                GlyphIcon::FaceSouth => [76, 175, 80, 255],
+                GlyphIcon::FaceEast => [229, 57, 53, 255],
+                GlyphIcon::FaceWest => [30, 136, 229, 255],
+                _ => [253, 216, 53, 255],
+            };
+            canvas.fill_circle(center, center, 14.5, DARK);
+            canvas.fill_circle(center, center, 12.5, fill);
+            canvas.text(icon.label(), 2, WHITE);
+        }
+        GlyphIcon::LeftBumper | GlyphIcon::RightBumper => {
+            canvas.fill_rounded_rect(1.0, 8.0, 31.0, 24.0, 4.0, DARK);
+            canvas.text(icon.label(), 1, WHITE);
+        }
+        GlyphIcon::LeftTrigger | GlyphIcon::RightTrigger => {
+            canvas.fill_rounded_rect(5.0, 1.0, 27.0, 31.0, 9.0, DARK);
+            canvas.text(icon.label(), 1, WHITE);
+        }
+        GlyphIcon::Back | GlyphIcon::Start => {
+            canvas.fill_circle(center, center, 12.0, DARK);
+            let text = if icon == GlyphIcon::Back { "<" } else { ">" };
+            canvas.text(text, 2, WHITE);
+        }
+        GlyphIcon::LeftStick | GlyphIcon::RightStick => {
+            canvas.fill_circle(center, center, 14.5, DARK);
+            canvas.fill_circle(center, center, 10.5, MID);
+            let text = if icon == GlyphIcon::LeftStick { "L" } else { "R" };
+            canvas.text(text, 2, WHITE);
+        }
+        GlyphIcon::DpadUp | GlyphIcon::DpadDown | GlyphIcon::DpadLeft | GlyphIcon::DpadRight => {
+            canvas.fill_rounded_rect(11.0, 1.0, 21.0, 31.0, 2.0, DARK);
+            canvas.fill_rounded_rect(1.0, 11.0, 31.0, 21.0, 2.0, DARK);
+            let (a, b, c) = match icon {
+                GlyphIcon::DpadUp => ((16.0, 3.0), (12.0, 9.0), (20.0, 9.0)),
+                GlyphIcon::DpadDown => ((16.0, 29.0), (12.0, 23.0), (20.0, 23.0)),
+                GlyphIcon::DpadLeft => ((3.0, 16.0), (9.0, 12.0), (9.0, 20.0)),
+                _ => ((29.0, 16.0), (23.0, 12.0), (23.0, 20.0)),
+            };
+            canvas.fill_triangle(a, b, c, WHITE);
+        }
+        GlyphIcon::MouseLeft | GlyphIcon::MouseRight | GlyphIcon::MouseMiddle => {
+            canvas.fill_rounded_rect(7.0, 2.0, 25.0, 30.0, 8.0, DARK);
+            canvas.fill_rounded_rect(9.0, 4.0, 23.0, 28.0, 6.0, MID);
+            let highlight = [255, 193, 7, 255];
+            match icon {
+                GlyphIcon::MouseLeft => {
+                    canvas.fill_rounded_rect(9.0, 4.0, 15.5, 14.0, 5.0, highlight)
+                }
+                GlyphIcon::MouseRight => {
+                    canvas.fill_rounded_rect(16.5, 4.0, 23.0, 14.0, 5.0, highlight)
+                }
+                _ => canvas.fill_rounded_rect(14.0, 6.0, 18.0, 13.0, 2.0, highlight),
+            }
+            canvas.fill_rounded_rect(15.5, 4.0, 16.5, 14.0, 0.0, DARK);
+        }
+    }
+}
+
+fn build_glyph_atlas() -> GlyphAtlas {
+    let rows = (GlyphIcon::ALL.len() as u32).div_ceil(ATLAS_COLUMNS);
+    let width = ATLAS_COLUMNS * GLYPH_ICON_SIZE;
+    let height = rows * GLYPH_ICON_SIZE;
+    let mut rgba = vec![0u8; (width * height * 4) as usize];
+
+    for icon in GlyphIcon::ALL {
+        let (origin_x, origin_y, _, _) = icon.atlas_rect();
+        let mut canvas = Canvas {
+            rgba: &mut rgba,
+            stride: width,
+            origin_x,
+            origin_y,
+        };
+        draw_icon(&mut canvas, icon);
+    }
+
+    GlyphAtlas {
+        width,
+        height,
+        rgba,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_labels() {
+        assert_eq!(key_label(&Key::Character("w".into())), "W");
+        assert_eq!(key_label(&Key::Named(NamedKey::Space)), "Space");
+        assert_eq!(key_label(&Key::Named(NamedKey::Escape)), "Esc");
+        assert_eq!(key_label(&Key::Named(NamedKey::F5)), "F5");
+    }
+
+    #[test]
+    fn test_gamepad_button_icons() {
+        let glyph = InputGlyph::for_joystick_button(JoystickButton {
+            joystick_id: 0,
+            button_id: 0,
+        });
+        assert_eq!(glyph.icon, Some(GlyphIcon::FaceSouth));
+        assert_eq!(glyph.prompt_text(), "[A]");
+        assert_eq!(GlyphIcon::from_gamepad_button(16), None);
+        assert_eq!(GlyphIcon::parse("gamepad_start"), Some(GlyphIcon::Start));
+    }
+
+    #[test]
+    fn test_atlas_contains_every_icon() {
+        let atlas = glyph_atlas();
+        for icon in GlyphIcon::ALL {
+            let (x, y, w, h) = icon.atlas_rect();
+            assert!(x + w <= atlas.width && y + h <= atlas.height);
+            let pixels = atlas.icon_rgba(icon);
+            assert_eq!(pixels.len(), (w * h * 4) as usize);
+            assert!(pixels.chunks(4).any(|px| px[3] > 0));
+        }
+    }
+}
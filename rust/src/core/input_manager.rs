@@ -1,6 +1,9 @@
 use std::collections::{HashMap, HashSet, VecDeque};
-use winit::event::{ElementState, MouseButton, MouseScrollDelta, WindowEvent};
-use winit::keyboard::{Key, NamedKey};
+use winit::event::{ElementState, Ime, MouseButton, MouseScrollDelta, WindowEvent};
+use winit::keyboard::{Key, ModifiersState, NamedKey};
+
+use super::input_glyphs::InputGlyph;
+use super::input_players::{MAX_PLAYERS, PlayerDevice, PlayerSlot};
 
 /// Represents a mouse button state
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -11,6 +14,32 @@ pub enum MouseButtonType {
     Other(u16),
 }
 
+/// Device family the player is using, for prompts and device switching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputDevice {
+    /// Keyboard and mouse.
+    KeyboardMouse,
+    /// Gamepad or joystick.
+    Gamepad,
+}
+
+impl InputDevice {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::KeyboardMouse => "keyboard_mouse",
+            Self::Gamepad => "gamepad",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "keyboard_mouse" | "keyboard" | "mouse" | "kbm" => Some(Self::KeyboardMouse),
+            "gamepad" | "joystick" | "controller" => Some(Self::Gamepad),
+            _ => None,
+        }
+    }
+}
+
 /// Represents a joystick/gamepad button
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct JoystickButton {
@@ -25,6 +54,59 @@ pub struct JoystickAxis {
     pub axis_id: u8,
 }
 
+/// What completes an [`InputChord`] once its modifiers are held.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChordTrigger {
+    Key(Key),
+    MouseButton(MouseButtonType),
+}
+
+/// A key or mouse button that only counts while exactly the given modifier
+/// keys are held, e.g. Ctrl+S or Shift+Click.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InputChord {
+    pub modifiers: ModifiersState,
+    pub trigger: ChordTrigger,
+}
+
+impl InputChord {
+    pub fn new(modifiers: ModifiersState, trigger: ChordTrigger) -> Self {
+        Self { modifiers, trigger }
+    }
+
+    /// Whether `key` is this chord's trigger, ignoring letter case since
+    /// Shift changes the logical key ("S" rather than "s").
+    pub fn triggered_by_key(&self, key: &Key) -> bool {
+        match (&self.trigger, key) {
+            (ChordTrigger::Key(Key::Character(trigger)), Key::Character(text)) => {
+                trigger.to_lowercase() == text.to_lowercase()
+            }
+            (ChordTrigger::Key(trigger), key) => trigger == key,
+            (ChordTrigger::MouseButton(_), _) => false,
+        }
+    }
+}
+
+/// Modifier flag a modifier key sets, or `None` for other keys.
+pub fn modifier_flag(key: &Key) -> Option<ModifiersState> {
+    match key {
+        Key::Named(NamedKey::Shift) => Some(ModifiersState::SHIFT),
+        Key::Named(NamedKey::Control) => Some(ModifiersState::CONTROL),
+        Key::Named(NamedKey::Alt) => Some(ModifiersState::ALT),
+        Key::Named(NamedKey::Super) => Some(ModifiersState::SUPER),
+        _ => None,
+    }
+}
+
+/// Default for how long action presses are kept for [`InputManager::action_buffered`], in seconds.
+const DEFAULT_INPUT_BUFFER_DURATION: f32 = 0.5;
+
+/// Mouse travel in pixels within one frame needed to switch the active device
+/// to keyboard/mouse, so small sensor jitter does not steal it from a gamepad.
+const MOUSE_ACTIVITY_THRESHOLD: f64 = 4.0;
+/// Joystick axis deflection needed to switch the active device to gamepad.
+const JOYSTICK_AXIS_ACTIVITY_THRESHOLD: f32 = 0.5;
+
 /// Logical mouse axes that can contribute to an input axis
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum MouseAxisType {
@@ -56,6 +138,60 @@ pub struct MouseAxisBinding {
     pub invert: bool,
 }
 
+/// Analog trigger on a gamepad with the standard layout.
+///
+/// Triggers read 0.0 (released) to 1.0 (fully pulled) rather than the
+/// -1.0 to 1.0 of a stick, and also drive their trigger buttons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GamepadTrigger {
+    Left,
+    Right,
+}
+
+impl GamepadTrigger {
+    /// Axis the trigger's travel is reported on.
+    pub fn axis_id(self) -> u8 {
+        match self {
+            Self::Left => 4,
+            Self::Right => 5,
+        }
+    }
+
+    /// Button the trigger presses once pulled past [`TRIGGER_PRESS_POINT`].
+    pub fn button_id(self) -> u8 {
+        match self {
+            Self::Left => 6,
+            Self::Right => 7,
+        }
+    }
+
+    pub fn from_axis_id(axis_id: u8) -> Option<Self> {
+        [Self::Left, Self::Right]
+            .into_iter()
+            .find(|trigger| trigger.axis_id() == axis_id)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Left => "left",
+            Self::Right => "right",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "left" | "lt" | "l2" => Some(Self::Left),
+            "right" | "rt" | "r2" => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+/// Trigger value, after its deadzone, at which the trigger button is pressed.
+pub const TRIGGER_PRESS_POINT: f32 = 0.5;
+/// Default trigger deadzone; worn triggers often rest slightly pulled.
+const DEFAULT_TRIGGER_DEADZONE: f32 = 0.1;
+
 /// Joystick axis binding: maps a joystick axis to a logical axis
 #[derive(Debug, Clone)]
 pub struct JoystickAxisBinding {
@@ -63,7 +199,8 @@ pub struct JoystickAxisBinding {
     pub joystick_id: Option<u32>,
     /// Which joystick axis drives this binding
     pub axis: JoystickAxis,
-    /// Deadzone below which values are treated as zero
+    /// Deadzone below which values are treated as zero. Trigger axes use
+    /// their per-trigger deadzone instead.
     pub deadzone: f32,
     /// Multiplier applied to the joystick value
     pub sensitivity: f32,
@@ -71,6 +208,59 @@ pub struct JoystickAxisBinding {
     pub invert: bool,
 }
 
+/// Time-based smoothing for a logical axis (Unity's gravity/sensitivity).
+///
+/// Instead of jumping straight to the raw value, the smoothed value ramps
+/// toward it at `sensitivity` units per second and falls back to zero at
+/// `gravity` units per second once input is released. A rate of zero or less
+/// makes that direction instant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AxisSmoothing {
+    /// Speed (units/second) at which the value returns to zero when input is released
+    pub gravity: f32,
+    /// Speed (units/second) at which the value moves toward a non-zero target
+    pub sensitivity: f32,
+    /// Jump to zero first when the input reverses direction
+    pub snap: bool,
+}
+
+impl AxisSmoothing {
+    pub fn new(gravity: f32, sensitivity: f32, snap: bool) -> Self {
+        Self {
+            gravity,
+            sensitivity,
+            snap,
+        }
+    }
+
+    /// Advance `current` toward `target` over `delta_time` seconds.
+    pub fn step(&self, current: f32, target: f32, delta_time: f32) -> f32 {
+        let mut current = current;
+        if self.snap && target != 0.0 && current != 0.0 && target.signum() != current.signum() {
+            current = 0.0;
+        }
+
+        let rate = if target == 0.0 {
+            self.gravity
+        } else {
+            self.sensitivity
+        };
+        if rate <= 0.0 {
+            return target;
+        }
+
+        let max_delta = rate * delta_time.max(0.0);
+        current + (target - current).clamp(-max_delta, max_delta)
+    }
+}
+
+impl Default for AxisSmoothing {
+    /// Unity's defaults for keyboard axes: gravity 3, sensitivity 3, snap on.
+    fn default() -> Self {
+        Self::new(3.0, 3.0, true)
+    }
+}
+
 /// Complete binding configuration for a single logical axis
 #[derive(Debug, Clone)]
 pub struct AxisBinding {
@@ -83,7 +273,7 @@ pub struct AxisBinding {
 }
 
 /// Input event types that can be queued
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum InputEvent {
     KeyPressed {
         key: Key,
@@ -91,6 +281,15 @@ pub enum InputEvent {
     KeyReleased {
         key: Key,
     },
+    TextInput {
+        text: String,
+    },
+    /// The input method's in-progress composition changed; empty when it
+    /// ended. `cursor` is a character range within `text`, if shown.
+    TextComposition {
+        text: String,
+        cursor: Option<(usize, usize)>,
+    },
     MouseButtonPressed {
         button: MouseButtonType,
     },
@@ -124,6 +323,38 @@ pub enum InputEvent {
         axis_id: u8,
         value: f32,
     },
+    /// The device the player is using switched (see [`InputManager::active_device`]).
+    ActiveDeviceChanged {
+        device: InputDevice,
+    },
+    /// A player slot was assigned a device, or emptied when `device` is `None`.
+    PlayerDeviceChanged {
+        player: u8,
+        device: Option<PlayerDevice>,
+    },
+}
+
+impl InputEvent {
+    /// Snake-case event name, e.g. `"key_pressed"` or `"joystick_axis_moved"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::KeyPressed { .. } => "key_pressed",
+            Self::KeyReleased { .. } => "key_released",
+            Self::TextInput { .. } => "text_input",
+            Self::TextComposition { .. } => "text_composition",
+            Self::MouseButtonPressed { .. } => "mouse_button_pressed",
+            Self::MouseButtonReleased { .. } => "mouse_button_released",
+            Self::MouseMoved { .. } => "mouse_moved",
+            Self::MouseWheel { .. } => "mouse_wheel",
+            Self::JoystickConnected { .. } => "joystick_connected",
+            Self::JoystickDisconnected { .. } => "joystick_disconnected",
+            Self::JoystickButtonPressed { .. } => "joystick_button_pressed",
+            Self::JoystickButtonReleased { .. } => "joystick_button_released",
+            Self::JoystickAxisMoved { .. } => "joystick_axis_moved",
+            Self::ActiveDeviceChanged { .. } => "active_device_changed",
+            Self::PlayerDeviceChanged { .. } => "player_device_changed",
+        }
+    }
 }
 
 /// Manages all input from keyboard, mouse, and joysticks
@@ -150,10 +381,14 @@ pub struct InputManager {
     mouse_buttons_previous: HashMap<MouseButtonType, bool>,
     /// Mouse wheel delta accumulated this frame
     mouse_wheel_delta: (f64, f64),
+    /// Raw mouse motion accumulated this frame, unaffected by the cursor
+    /// position or pointer acceleration
+    mouse_motion_delta: (f64, f64),
+    /// Read mouse movement from raw motion instead of the cursor position
+    relative_mouse_mode: bool,
 
     // Joystick/Gamepad state
     /// Set of connected joystick IDs
-    #[allow(dead_code)]
     connected_joysticks: HashSet<u32>,
     /// Current frame joystick button states - (joystick_id, button_id) -> pressed
     joystick_buttons_current: HashMap<JoystickButton, bool>,
@@ -169,10 +404,24 @@ pub struct InputManager {
     axis_values_current: HashMap<String, f32>,
     /// Previous frame axis values (for detecting changes / edge triggers)
     axis_values_previous: HashMap<String, f32>,
+    /// Current frame axis values before smoothing
+    axis_values_raw: HashMap<String, f32>,
+    /// Smoothed keyboard part of each smoothed axis
+    axis_values_keys: HashMap<String, f32>,
+    /// Per-axis smoothing; axes without an entry report raw values
+    axis_smoothing: HashMap<String, AxisSmoothing>,
 
     // Event queue
-    /// Queue of input events that occurred this frame
+    /// Queue of input events received since the last update
     event_queue: VecDeque<InputEvent>,
+    /// Events received before the most recent update, kept for the frame
+    frame_events: Vec<InputEvent>,
+    /// Text typed or committed by the input method during the last frame
+    typed_text: String,
+    /// Text the input method is composing, not yet committed
+    composition: String,
+    /// Character range of the cursor within `composition`
+    composition_cursor: Option<(usize, usize)>,
 
     // Input action mappings (optional - for action-based input)
     /// Maps action names to sets of keys that trigger them
@@ -181,6 +430,48 @@ pub struct InputManager {
     mouse_action_mappings: HashMap<String, Vec<MouseButtonType>>,
     /// Maps action names to sets of joystick buttons that trigger them
     joystick_action_mappings: HashMap<String, Vec<JoystickButton>>,
+    /// Maps action names to modifier chords that trigger them
+    chord_action_mappings: HashMap<String, Vec<InputChord>>,
+
+    // Action contexts
+    /// Maps context names to the actions that only work while it is on top
+    context_actions: HashMap<String, HashSet<String>>,
+    /// Pushed contexts, top last
+    context_stack: Vec<String>,
+
+    // Active device
+    /// Device that produced the most recent meaningful input
+    active_device: InputDevice,
+    /// Whether `active_device` changed during the last update
+    active_device_changed: bool,
+    /// Device reported via `report_device_activity` since the last update
+    pending_device_activity: Option<InputDevice>,
+
+    // Prompt glyphs
+    /// Forced device for prompt glyphs; `None` follows the active device
+    glyph_device_override: Option<InputDevice>,
+    /// Incremented whenever action bindings or the prompt device change
+    glyph_version: u64,
+
+    // Local multiplayer
+    /// Player slots 1..=MAX_PLAYERS, stored at index `player - 1`
+    player_slots: Vec<PlayerSlot>,
+    /// Whether newly connected gamepads fill the first empty player slot
+    auto_assign_gamepads: bool,
+    /// Deadzones of the left and right triggers
+    trigger_deadzones: [f32; 2],
+    /// Whether auto-assignment waits for a gamepad's first button press
+    assign_gamepads_on_press: bool,
+
+    // Input buffering
+    /// Seconds of updates so far; the clock for buffered action presses
+    input_time: f64,
+    /// How long presses stay in `action_press_history`, in seconds
+    input_buffer_duration: f32,
+    /// Recent action presses as (input time, action name), oldest first
+    action_press_history: VecDeque<(f64, String)>,
+    /// Actions whose press this frame was consumed before it was recorded
+    consumed_presses: HashSet<String>,
 }
 
 impl InputManager {
@@ -206,6 +497,12 @@ impl InputManager {
     /// - "Vertical": W/S, Up/Down arrows, primary gamepad left-stick Y
     /// - "Jump", "Fire1", "Fire2", "Fire3", "Submit", "Cancel",
     ///   "Mouse X", "Mouse Y", "Mouse ScrollWheel", "Escape"
+    ///
+    /// "Horizontal" and "Vertical" use [`AxisSmoothing::default`]; use
+    /// [`Self::axis_raw`] for their unsmoothed values.
+    ///
+    /// Player 1 starts on keyboard and mouse; gamepads fill the remaining
+    /// player slots as they connect.
     pub fn new() -> Self {
         let mut manager = Self {
             keys_current: HashMap::new(),
@@ -215,21 +512,48 @@ impl InputManager {
             mouse_buttons_current: HashMap::new(),
             mouse_buttons_previous: HashMap::new(),
             mouse_wheel_delta: (0.0, 0.0),
+            mouse_motion_delta: (0.0, 0.0),
+            relative_mouse_mode: false,
             connected_joysticks: HashSet::new(),
             joystick_buttons_current: HashMap::new(),
             joystick_buttons_previous: HashMap::new(),
             joystick_axes: HashMap::new(),
             event_queue: VecDeque::new(),
+            frame_events: Vec::new(),
+            typed_text: String::new(),
+            composition: String::new(),
+            composition_cursor: None,
             key_action_mappings: HashMap::new(),
             mouse_action_mappings: HashMap::new(),
             joystick_action_mappings: HashMap::new(),
+            chord_action_mappings: HashMap::new(),
+            context_actions: HashMap::new(),
+            context_stack: Vec::new(),
             axis_bindings: HashMap::new(),
             axis_values_current: HashMap::new(),
             axis_values_previous: HashMap::new(),
+            axis_values_raw: HashMap::new(),
+            axis_values_keys: HashMap::new(),
+            axis_smoothing: HashMap::new(),
+            active_device: InputDevice::KeyboardMouse,
+            active_device_changed: false,
+            pending_device_activity: None,
+            glyph_device_override: None,
+            glyph_version: 0,
+            player_slots: vec![PlayerSlot::default(); MAX_PLAYERS as usize],
+            auto_assign_gamepads: true,
+            trigger_deadzones: [DEFAULT_TRIGGER_DEADZONE; 2],
+            assign_gamepads_on_press: false,
+            input_time: 0.0,
+            input_buffer_duration: DEFAULT_INPUT_BUFFER_DURATION,
+            action_press_history: VecDeque::new(),
+            consumed_presses: HashSet::new(),
         };
+        manager.player_slots[0].device = Some(PlayerDevice::KeyboardMouse);
 
         // Install default axis bindings
         manager.axis_bindings = Self::default_axis_bindings();
+        manager.axis_smoothing = Self::default_axis_smoothing();
         // Install default action mappings (Unity-style names).
         manager.key_action_mappings = Self::default_key_action_mappings();
         manager.mouse_action_mappings = Self::default_mouse_action_mappings();
@@ -250,60 +574,292 @@ impl InputManager {
                 self.clear_on_focus_lost();
             }
             WindowEvent::KeyboardInput { event, .. } => {
-                let key = event.logical_key.clone();
                 let pressed = event.state == ElementState::Pressed;
-                self.keys_current.insert(key.clone(), pressed);
-
-                if pressed {
-                    self.event_queue.push_back(InputEvent::KeyPressed { key });
-                } else {
-                    self.event_queue.push_back(InputEvent::KeyReleased { key });
+                self.handle_key(event.logical_key.clone(), pressed, event.repeat);
+                if pressed
+                    && self.key_down(&event.logical_key)
+                    && let Some(text) = &event.text
+                {
+                    self.inject_text(text);
                 }
             }
+            WindowEvent::Ime(ime) => self.handle_ime(ime),
             WindowEvent::MouseInput { state, button, .. } => {
-                let mapped = Self::map_mouse_button(*button);
-                let pressed = *state == ElementState::Pressed;
-                self.mouse_buttons_current.insert(mapped, pressed);
-
-                if pressed {
-                    self.event_queue
-                        .push_back(InputEvent::MouseButtonPressed { button: mapped });
-                } else {
-                    self.event_queue
-                        .push_back(InputEvent::MouseButtonReleased { button: mapped });
-                }
-            }
-            WindowEvent::CursorMoved { position, .. } => {
-                self.mouse_position = (position.x, position.y);
-                self.event_queue.push_back(InputEvent::MouseMoved {
-                    x: position.x,
-                    y: position.y,
-                });
+                self.inject_mouse_button(Self::map_mouse_button(*button), *state == ElementState::Pressed);
             }
+            WindowEvent::CursorMoved { position, .. } => self.inject_mouse_move(position.x, position.y),
             WindowEvent::MouseWheel { delta, .. } => {
                 let (dx, dy) = match delta {
                     MouseScrollDelta::LineDelta(x, y) => (*x as f64, *y as f64),
                     MouseScrollDelta::PixelDelta(pos) => (pos.x, pos.y),
                 };
+                self.inject_mouse_wheel(dx, dy);
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_key(&mut self, key: Key, pressed: bool, repeat: bool) {
+        // OS key repeat can keep arriving for a key whose press we never
+        // saw (e.g. it was held through a focus change), and some
+        // platforms send releases for keys that were never pressed.
+        // Neither may change held state, or the key sticks down.
+        if !self.key_down(&key) && (repeat || !pressed) {
+            return;
+        }
+        self.keys_current.insert(key.clone(), pressed);
+
+        if pressed {
+            self.event_queue.push_back(InputEvent::KeyPressed { key });
+        } else {
+            self.event_queue.push_back(InputEvent::KeyReleased { key });
+        }
+    }
+
+    /// Press or release a key as if it came from the keyboard.
+    ///
+    /// The `inject_*` methods feed the same pipeline as real devices, so
+    /// headless tests, bots and tutorials see injected input in key and
+    /// action queries, events, the UI and device switching. A press and a
+    /// release injected before the same `update` cancel out, as they would
+    /// for a real key.
+    pub fn inject_key(&mut self, key: Key, pressed: bool) {
+        self.handle_key(key, pressed, false);
+    }
+
+    /// Deliver typed text, as the keyboard does alongside a key press.
+    /// Control characters are ignored.
+    pub fn inject_text(&mut self, text: &str) {
+        if text.chars().any(|ch| !ch.is_control()) {
+            self.event_queue.push_back(InputEvent::TextInput {
+                text: text.to_string(),
+            });
+        }
+    }
+
+    /// Press or release a mouse button.
+    pub fn inject_mouse_button(&mut self, button: MouseButtonType, pressed: bool) {
+        self.mouse_buttons_current.insert(button, pressed);
+
+        if pressed {
+            self.event_queue.push_back(InputEvent::MouseButtonPressed { button });
+        } else {
+            self.event_queue.push_back(InputEvent::MouseButtonReleased { button });
+        }
+    }
+
+    /// Move the mouse cursor to a window position in pixels.
+    pub fn inject_mouse_move(&mut self, x: f64, y: f64) {
+        self.mouse_position = (x, y);
+        self.event_queue.push_back(InputEvent::MouseMoved { x, y });
+    }
 
-                self.mouse_wheel_delta.0 += dx;
-                self.mouse_wheel_delta.1 += dy;
+    /// Scroll the mouse wheel by the given number of lines.
+    pub fn inject_mouse_wheel(&mut self, delta_x: f64, delta_y: f64) {
+        self.mouse_wheel_delta.0 += delta_x;
+        self.mouse_wheel_delta.1 += delta_y;
+        self.event_queue.push_back(InputEvent::MouseWheel { delta_x, delta_y });
+    }
 
-                self.event_queue.push_back(InputEvent::MouseWheel {
-                    delta_x: dx,
-                    delta_y: dy,
-                });
+    /// Track the input method: compositions update as the user types and
+    /// end in a commit, which is delivered like typed text.
+    fn handle_ime(&mut self, ime: &Ime) {
+        match ime {
+            Ime::Preedit(text, cursor) => {
+                let char_index = |byte: usize| text.get(..byte).map_or(0, |prefix| prefix.chars().count());
+                let cursor = cursor.map(|(start, end)| (char_index(start), char_index(end)));
+                self.set_composition(text.clone(), cursor);
             }
-            _ => {}
+            Ime::Commit(text) => {
+                self.set_composition(String::new(), None);
+                if !text.is_empty() {
+                    self.event_queue.push_back(InputEvent::TextInput { text: text.clone() });
+                }
+            }
+            Ime::Disabled => self.set_composition(String::new(), None),
+            Ime::Enabled => {}
+        }
+    }
+
+    fn set_composition(&mut self, text: String, cursor: Option<(usize, usize)>) {
+        if text == self.composition && cursor == self.composition_cursor {
+            return;
         }
+        self.composition.clone_from(&text);
+        self.composition_cursor = cursor;
+        self.event_queue.push_back(InputEvent::TextComposition { text, cursor });
     }
 
     /// Clear transient input state when window focus is lost.
     fn clear_on_focus_lost(&mut self) {
-        self.keys_current.clear();
-        self.mouse_buttons_current.clear();
+        self.release_keyboard_mouse();
+        self.mouse_wheel_delta = (0.0, 0.0);
+        self.mouse_motion_delta = (0.0, 0.0);
+        self.mouse_position_previous = self.mouse_position;
+    }
+
+    /// Release every held key and mouse button, queueing release events.
+    fn release_keyboard_mouse(&mut self) {
+        for (key, pressed) in self.keys_current.iter_mut() {
+            if std::mem::take(pressed) {
+                self.event_queue
+                    .push_back(InputEvent::KeyReleased { key: key.clone() });
+            }
+        }
+        for (&button, pressed) in self.mouse_buttons_current.iter_mut() {
+            if std::mem::take(pressed) {
+                self.event_queue
+                    .push_back(InputEvent::MouseButtonReleased { button });
+            }
+        }
+    }
+
+    /// Release a joystick's held buttons and center its axes, queueing the
+    /// matching release and axis events.
+    fn release_joystick(&mut self, joystick_id: u32) {
+        let mut buttons: Vec<u8> = self
+            .joystick_buttons_current
+            .iter_mut()
+            .filter(|(button, _)| button.joystick_id == joystick_id)
+            .filter_map(|(button, pressed)| std::mem::take(pressed).then_some(button.button_id))
+            .collect();
+        buttons.sort_unstable();
+        for button_id in buttons {
+            self.event_queue.push_back(InputEvent::JoystickButtonReleased {
+                joystick_id,
+                button_id,
+            });
+        }
+
+        let mut axes: Vec<u8> = self
+            .joystick_axes
+            .iter()
+            .filter(|(axis, value)| axis.joystick_id == joystick_id && **value != 0.0)
+            .map(|(axis, _)| axis.axis_id)
+            .collect();
+        axes.sort_unstable();
+        self.joystick_axes
+            .retain(|axis, _| axis.joystick_id != joystick_id);
+        for axis_id in axes {
+            self.event_queue.push_back(InputEvent::JoystickAxisMoved {
+                joystick_id,
+                axis_id,
+                value: 0.0,
+            });
+        }
+    }
+
+    /// Release all held input on every device and zero all axes.
+    ///
+    /// Queues release events for anything that was held, so `*_released`
+    /// queries and event consumers see a normal release on the next update.
+    /// Use it as a fail-safe when input may be stuck, e.g. after a pause
+    /// menu or a platform overlay steals input mid-press.
+    pub fn reset(&mut self) {
+        self.release_keyboard_mouse();
+        let joysticks: Vec<u32> = self
+            .joystick_buttons_current
+            .keys()
+            .map(|button| button.joystick_id)
+            .chain(self.joystick_axes.keys().map(|axis| axis.joystick_id))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        for joystick_id in joysticks {
+            self.release_joystick(joystick_id);
+        }
         self.mouse_wheel_delta = (0.0, 0.0);
+        self.mouse_motion_delta = (0.0, 0.0);
         self.mouse_position_previous = self.mouse_position;
+        self.axis_values_current.clear();
+        self.axis_values_raw.clear();
+        self.axis_values_keys.clear();
+        for slot in &mut self.player_slots {
+            slot.clear_axes();
+        }
+        self.action_press_history.clear();
+        self.consumed_presses.clear();
+    }
+
+    /// Register a newly connected joystick.
+    ///
+    /// The `handle_joystick_*` methods are the entry points for a gamepad
+    /// backend; winit does not report joysticks itself.
+    pub fn handle_joystick_connected(&mut self, joystick_id: u32) {
+        if self.connected_joysticks.insert(joystick_id) {
+            self.event_queue
+                .push_back(InputEvent::JoystickConnected { joystick_id });
+            self.auto_assign_gamepad(joystick_id, true, false);
+        }
+    }
+
+    /// Forget a disconnected joystick and release its buttons and axes.
+    ///
+    /// Held buttons get `JoystickButtonReleased` events and deflected axes a
+    /// `JoystickAxisMoved` back to 0, queued before `JoystickDisconnected`, so
+    /// nothing stays held after a controller is unplugged mid-press.
+    pub fn handle_joystick_disconnected(&mut self, joystick_id: u32) {
+        if self.connected_joysticks.remove(&joystick_id) {
+            self.release_joystick(joystick_id);
+            self.event_queue
+                .push_back(InputEvent::JoystickDisconnected { joystick_id });
+            if let Some(player) = self.player_for_joystick(joystick_id) {
+                self.assign_player(player, None);
+            }
+        }
+    }
+
+    /// Apply a joystick button press or release.
+    pub fn handle_joystick_button(&mut self, joystick_id: u32, button_id: u8, pressed: bool) {
+        let newly_seen = self.connected_joysticks.insert(joystick_id);
+        self.auto_assign_gamepad(joystick_id, newly_seen, pressed);
+        self.joystick_buttons_current.insert(
+            JoystickButton {
+                joystick_id,
+                button_id,
+            },
+            pressed,
+        );
+        let event = if pressed {
+            InputEvent::JoystickButtonPressed {
+                joystick_id,
+                button_id,
+            }
+        } else {
+            InputEvent::JoystickButtonReleased {
+                joystick_id,
+                button_id,
+            }
+        };
+        self.event_queue.push_back(event);
+    }
+
+    /// Apply a joystick axis value in the range -1.0 to 1.0.
+    pub fn handle_joystick_axis(&mut self, joystick_id: u32, axis_id: u8, value: f32) {
+        if self.connected_joysticks.insert(joystick_id) {
+            self.auto_assign_gamepad(joystick_id, true, false);
+        }
+        let value = value.clamp(-1.0, 1.0);
+        self.joystick_axes.insert(
+            JoystickAxis {
+                joystick_id,
+                axis_id,
+            },
+            value,
+        );
+        self.event_queue.push_back(InputEvent::JoystickAxisMoved {
+            joystick_id,
+            axis_id,
+            value,
+        });
+
+        // Triggers press their button at half travel, past the deadzone.
+        if let Some(trigger) = GamepadTrigger::from_axis_id(axis_id) {
+            let pressed = self.joystick_trigger(joystick_id, trigger) >= TRIGGER_PRESS_POINT;
+            if pressed != self.joystick_button_down(joystick_id, trigger.button_id()) {
+                self.handle_joystick_button(joystick_id, trigger.button_id(), pressed);
+            }
+        }
     }
 
     /// Build the default axis bindings used by `new`.
@@ -485,6 +1041,14 @@ impl InputManager {
         bindings
     }
 
+    /// Build the default axis smoothing: "Horizontal" and "Vertical" ramp like Unity's `GetAxis`.
+    fn default_axis_smoothing() -> HashMap<String, AxisSmoothing> {
+        ["Horizontal", "Vertical"]
+            .into_iter()
+            .map(|name| (name.to_string(), AxisSmoothing::default()))
+            .collect()
+    }
+
     fn default_key_action_mappings() -> HashMap<String, Vec<Key>> {
         let mut mappings = HashMap::new();
         mappings.insert("jump".to_string(), vec![Key::Named(NamedKey::Space)]);
@@ -540,17 +1104,29 @@ impl InputManager {
         );
         mappings.insert(
             "fire1".to_string(),
-            vec![JoystickButton {
-                joystick_id: 0,
-                button_id: 0,
-            }],
+            vec![
+                JoystickButton {
+                    joystick_id: 0,
+                    button_id: 0,
+                },
+                JoystickButton {
+                    joystick_id: 0,
+                    button_id: GamepadTrigger::Right.button_id(),
+                },
+            ],
         );
         mappings.insert(
             "fire2".to_string(),
-            vec![JoystickButton {
-                joystick_id: 0,
-                button_id: 1,
-            }],
+            vec![
+                JoystickButton {
+                    joystick_id: 0,
+                    button_id: 1,
+                },
+                JoystickButton {
+                    joystick_id: 0,
+                    button_id: GamepadTrigger::Left.button_id(),
+                },
+            ],
         );
         mappings.insert(
             "fire3".to_string(),
@@ -745,9 +1321,45 @@ impl InputManager {
         let Some(name) = self.find_axis_name_case_insensitive(axis_name) else {
             return false;
         };
+        self.axis_smoothing.remove(&name);
+        self.axis_values_raw.remove(&name);
+        self.axis_values_keys.remove(&name);
         self.axis_bindings.remove(&name).is_some()
     }
 
+    /// Set smoothing for a logical axis, or `None` to report raw values.
+    ///
+    /// Only keyboard input is smoothed; mouse and joystick input is already
+    /// analog and is added on top as is, so sticks respond without lag.
+    /// Returns `false` if the axis does not exist.
+    pub fn set_axis_smoothing(&mut self, axis_name: &str, smoothing: Option<AxisSmoothing>) -> bool {
+        let Some(name) = self.find_axis_name_case_insensitive(axis_name) else {
+            return false;
+        };
+        match smoothing {
+            Some(smoothing) => {
+                self.axis_smoothing.insert(name, smoothing);
+            }
+            None => {
+                self.axis_smoothing.remove(&name);
+                self.axis_values_keys.remove(&name);
+            }
+        }
+        true
+    }
+
+    /// Get the smoothing configured for a logical axis, if any.
+    pub fn axis_smoothing(&self, axis_name: &str) -> Option<AxisSmoothing> {
+        let name = self.find_axis_name_case_insensitive(axis_name)?;
+        self.axis_smoothing.get(&name).copied()
+    }
+
+    /// Get the full binding of a logical axis (name matched case-insensitively).
+    pub fn axis_binding(&self, axis_name: &str) -> Option<&AxisBinding> {
+        let name = self.find_axis_name_case_insensitive(axis_name)?;
+        self.axis_bindings.get(&name)
+    }
+
     /// Return all logical axis names in sorted order.
     pub fn axis_names(&self) -> Vec<String> {
         let mut names: Vec<String> = self.axis_bindings.keys().cloned().collect();
@@ -759,7 +1371,8 @@ impl InputManager {
     ///
     /// This should be called once per frame, after any raw input events have
     /// been applied to the underlying keyboard/mouse/joystick state.
-    pub fn update(&mut self) {
+    /// `delta_time` (seconds since the last update) drives axis smoothing.
+    pub fn update(&mut self, delta_time: f32) {
         // Reuse axis buffers by swapping snapshots before recomputation.
         std::mem::swap(
             &mut self.axis_values_previous,
@@ -769,32 +1382,37 @@ impl InputManager {
         // Recompute axis values from current device state. The previous-axis
         // snapshot was swapped above so edge detection remains valid.
         self.axis_values_current.clear();
+        self.axis_values_raw.clear();
         for (name, binding) in &self.axis_bindings {
-            let mut value: f32 = 0.0;
-
-            // Keyboard contribution
-            if let Some(kb) = &binding.keyboard {
-                value += self.compute_keyboard_axis(kb);
-            }
-
-            // Mouse contribution
-            if let Some(mouse) = &binding.mouse {
-                value += self.compute_mouse_axis(mouse);
-            }
+            // Combine all devices, ramping the keyboard part if smoothed
+            let (keys, analog) = self.compute_axis_parts(binding, None);
+            let raw = (keys + analog).clamp(-1.0, 1.0);
+            let smoothed = match self.axis_smoothing.get(name) {
+                Some(smoothing) => {
+                    Self::smooth_axis(smoothing, &mut self.axis_values_keys, name, keys, analog, delta_time)
+                }
+                None => raw,
+            };
+            self.axis_values_raw.insert(name.clone(), raw);
+            self.axis_values_current.insert(name.clone(), smoothed);
+        }
 
-            // Joystick contribution
-            if let Some(js) = &binding.joystick {
-                value += self.compute_joystick_axis(js);
+        self.update_player_axes(delta_time);
+        self.refresh_active_device();
+
+        // Hand this frame's events to `frame_events` and clear per-frame
+        // accumulators that should not persist.
+        self.frame_events.clear();
+        self.frame_events.extend(self.event_queue.drain(..));
+        self.typed_text.clear();
+        for event in &self.frame_events {
+            if let InputEvent::TextInput { text } = event {
+                self.typed_text.push_str(text);
             }
-
-            // Clamp combined value to [-1.0, 1.0]
-            let clamped = value.clamp(-1.0, 1.0);
-            self.axis_values_current.insert(name.clone(), clamped);
         }
-
-        // Clear per-frame accumulators that should not persist
-        self.event_queue.clear();
         self.mouse_wheel_delta = (0.0, 0.0);
+        self.mouse_motion_delta = (0.0, 0.0);
+        self.record_action_presses(delta_time);
 
         // Carry over current state for next-frame edge detection.
         self.keys_previous.clone_from(&self.keys_current);
@@ -805,7 +1423,46 @@ impl InputManager {
             .clone_from(&self.joystick_buttons_current);
     }
 
-    /// Get the current value of a logical axis.
+    /// Input events collected for the current frame, in arrival order.
+    ///
+    /// Valid from one `update` to the next; includes typed text as
+    /// [`InputEvent::TextInput`].
+    pub fn frame_events(&self) -> &[InputEvent] {
+        &self.frame_events
+    }
+
+    /// Text typed during the last frame, including text committed by an
+    /// input method (IME). Use it instead of key presses for text entry:
+    /// it follows the keyboard layout, dead keys and composed characters.
+    pub fn typed_text(&self) -> &str {
+        &self.typed_text
+    }
+
+    /// Text an input method is composing but has not committed yet, e.g.
+    /// kana awaiting conversion. Empty when nothing is being composed.
+    pub fn composition(&self) -> &str {
+        &self.composition
+    }
+
+    /// Character range of the input method's cursor within
+    /// [`Self::composition`], if it shows one.
+    pub fn composition_cursor(&self) -> Option<(usize, usize)> {
+        self.composition_cursor
+    }
+
+    /// Whether any key, mouse button or joystick button was pressed this frame.
+    pub fn any_pressed(&self) -> bool {
+        self.frame_events.iter().any(|event| {
+            matches!(
+                event,
+                InputEvent::KeyPressed { .. }
+                    | InputEvent::MouseButtonPressed { .. }
+                    | InputEvent::JoystickButtonPressed { .. }
+            )
+        })
+    }
+
+    /// Get the current value of a logical axis, after smoothing.
     ///
     /// Returns 0.0 if the axis is not defined.
     pub fn axis(&self, name: &str) -> f32 {
@@ -825,6 +1482,26 @@ impl InputManager {
             .unwrap_or(0.0)
     }
 
+    /// Get the current value of a logical axis without smoothing.
+    ///
+    /// Digital input reads exactly -1, 0, or 1. Returns 0.0 if the axis is not defined.
+    pub fn axis_raw(&self, name: &str) -> f32 {
+        if let Some(value) = self.axis_values_raw.get(name) {
+            return *value;
+        }
+
+        self.axis_values_raw
+            .iter()
+            .find_map(|(axis_name, value)| {
+                if axis_name.eq_ignore_ascii_case(name) {
+                    Some(*value)
+                } else {
+                    None
+                }
+            })
+            .unwrap_or(0.0)
+    }
+
     /// Get the previous frame's value of a logical axis.
     ///
     /// Returns 0.0 if the axis is not defined.
@@ -848,12 +1525,21 @@ impl InputManager {
     /// Check if an action is currently active (held).
     pub fn action_down(&self, action_name: &str) -> bool {
         let action = Self::normalize_action_name(action_name);
+        if !self.action_in_context(&action) {
+            return false;
+        }
         if let Some(keys) = self.key_action_mappings.get(&action)
             && keys.iter().any(|key| self.key_down(key))
         {
             return true;
         }
 
+        if let Some(chords) = self.chord_action_mappings.get(&action)
+            && chords.iter().any(|chord| self.chord_down(chord))
+        {
+            return true;
+        }
+
         if let Some(buttons) = self.mouse_action_mappings.get(&action)
             && buttons.iter().any(|button| self.mouse_button_down(*button))
         {
@@ -874,12 +1560,21 @@ impl InputManager {
     /// Check if an action was pressed this frame.
     pub fn action_pressed(&self, action_name: &str) -> bool {
         let action = Self::normalize_action_name(action_name);
+        if !self.action_in_context(&action) {
+            return false;
+        }
         if let Some(keys) = self.key_action_mappings.get(&action)
             && keys.iter().any(|key| self.key_pressed(key))
         {
             return true;
         }
 
+        if let Some(chords) = self.chord_action_mappings.get(&action)
+            && chords.iter().any(|chord| self.chord_pressed(chord))
+        {
+            return true;
+        }
+
         if let Some(buttons) = self.mouse_action_mappings.get(&action)
             && buttons
                 .iter()
@@ -902,12 +1597,21 @@ impl InputManager {
     /// Check if an action was released this frame.
     pub fn action_released(&self, action_name: &str) -> bool {
         let action = Self::normalize_action_name(action_name);
+        if !self.action_in_context(&action) {
+            return false;
+        }
         if let Some(keys) = self.key_action_mappings.get(&action)
             && keys.iter().any(|key| self.key_released(key))
         {
             return true;
         }
 
+        if let Some(chords) = self.chord_action_mappings.get(&action)
+            && chords.iter().any(|chord| self.chord_released(chord))
+        {
+            return true;
+        }
+
         if let Some(buttons) = self.mouse_action_mappings.get(&action)
             && buttons
                 .iter()
@@ -927,21 +1631,260 @@ impl InputManager {
         false
     }
 
-    /// Return all action names in sorted order.
-    pub fn action_names(&self) -> Vec<String> {
-        let mut names = HashSet::new();
-        names.extend(self.key_action_mappings.keys().cloned());
-        names.extend(self.mouse_action_mappings.keys().cloned());
-        names.extend(self.joystick_action_mappings.keys().cloned());
-        let mut ordered: Vec<String> = names.into_iter().collect();
-        ordered.sort();
-        ordered
-    }
-
-    /// Replace keyboard bindings for an action.
-    pub fn set_action_keys<S: Into<String>>(&mut self, action_name: S, keys: Vec<Key>) {
+    /// Check if an action was pressed within the last `window` seconds.
+    ///
+    /// Lets a jump pressed just before landing still count, e.g.
+    /// `action_buffered("jump", 0.12)`. Includes a press this frame. Presses
+    /// older than [`Self::input_buffer_duration`] are forgotten, which caps
+    /// `window`.
+    pub fn action_buffered(&self, action_name: &str, window: f32) -> bool {
+        let action = Self::normalize_action_name(action_name);
+        if !self.action_in_context(&action) {
+            return false;
+        }
+        if self.action_pressed(&action) && !self.consumed_presses.contains(&action) {
+            return true;
+        }
+        self.action_press_history
+            .iter()
+            .rev()
+            .take_while(|(time, _)| self.input_time - time <= f64::from(window))
+            .any(|(_, pressed)| *pressed == action)
+    }
+
+    /// Like [`Self::action_buffered`], but forget the press when there is one
+    /// so it triggers only once.
+    pub fn consume_buffered_action(&mut self, action_name: &str, window: f32) -> bool {
+        if !self.action_buffered(action_name, window) {
+            return false;
+        }
+        let action = Self::normalize_action_name(action_name);
+        self.action_press_history.retain(|(_, pressed)| *pressed != action);
+        self.consumed_presses.insert(action);
+        true
+    }
+
+    /// Set how long action presses are remembered for buffering, in seconds
+    /// (0.5 by default).
+    pub fn set_input_buffer_duration(&mut self, seconds: f32) {
+        self.input_buffer_duration = seconds.max(0.0);
+    }
+
+    pub fn input_buffer_duration(&self) -> f32 {
+        self.input_buffer_duration
+    }
+
+    /// Timestamp this frame's action presses, then advance the input clock
+    /// and drop presses older than the buffer duration.
+    fn record_action_presses(&mut self, delta_time: f32) {
+        for action in self.action_names() {
+            if self.action_pressed(&action) && !self.consumed_presses.contains(&action) {
+                self.action_press_history.push_back((self.input_time, action));
+            }
+        }
+        self.consumed_presses.clear();
+
+        self.input_time += f64::from(delta_time);
+        let oldest = self.input_time - f64::from(self.input_buffer_duration);
+        while self
+            .action_press_history
+            .front()
+            .is_some_and(|(time, _)| *time < oldest)
+        {
+            self.action_press_history.pop_front();
+        }
+    }
+
+    /// Actions bound to the key or button behind a press or release event, sorted.
+    ///
+    /// Returns an empty list for events that no action can be bound to.
+    pub fn actions_for_event(&self, event: &InputEvent) -> Vec<String> {
+        let mut actions: Vec<String> = match event {
+            InputEvent::KeyPressed { key } | InputEvent::KeyReleased { key } => self
+                .key_action_mappings
+                .iter()
+                .filter(|(_, keys)| keys.contains(key))
+                .map(|(action, _)| action.clone())
+                .chain(self.chord_actions_for(|chord| chord.triggered_by_key(key)))
+                .collect(),
+            InputEvent::MouseButtonPressed { button } | InputEvent::MouseButtonReleased { button } => self
+                .mouse_action_mappings
+                .iter()
+                .filter(|(_, buttons)| buttons.contains(button))
+                .map(|(action, _)| action.clone())
+                .chain(self.chord_actions_for(|chord| chord.trigger == ChordTrigger::MouseButton(*button)))
+                .collect(),
+            InputEvent::JoystickButtonPressed { joystick_id, button_id }
+            | InputEvent::JoystickButtonReleased { joystick_id, button_id } => {
+                let button = JoystickButton {
+                    joystick_id: *joystick_id,
+                    button_id: *button_id,
+                };
+                self.joystick_action_mappings
+                    .iter()
+                    .filter(|(_, buttons)| buttons.contains(&button))
+                    .map(|(action, _)| action.clone())
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+        actions.retain(|action| self.action_in_context(action));
+        actions.sort();
+        actions.dedup();
+        actions
+    }
+
+    /// Put actions in a named context such as "gameplay" or "menu".
+    ///
+    /// While any context is pushed, an action that belongs to contexts only
+    /// works when one of them is the top context, so the same keys can drive
+    /// different actions in different game states. Actions in no context
+    /// always work. Replaces the context's previous actions.
+    pub fn set_context_actions(&mut self, context: &str, actions: Vec<String>) {
+        let actions = actions.iter().map(|action| Self::normalize_action_name(action)).collect();
+        self.context_actions.insert(Self::normalize_action_name(context), actions);
+    }
+
+    /// Actions in a context, sorted.
+    pub fn context_actions(&self, context: &str) -> Vec<String> {
+        let mut actions: Vec<String> = self
+            .context_actions
+            .get(&Self::normalize_action_name(context))
+            .into_iter()
+            .flatten()
+            .cloned()
+            .collect();
+        actions.sort();
+        actions
+    }
+
+    /// Make a context the top one until it is popped.
+    pub fn push_input_context(&mut self, context: &str) {
+        self.context_stack.push(Self::normalize_action_name(context));
+    }
+
+    /// Remove the top context, returning it.
+    pub fn pop_input_context(&mut self) -> Option<String> {
+        self.context_stack.pop()
+    }
+
+    /// The top context, which decides the active actions and UI.
+    pub fn input_context(&self) -> Option<&str> {
+        self.context_stack.last().map(String::as_str)
+    }
+
+    /// Pushed contexts, bottom first.
+    pub fn input_context_stack(&self) -> &[String] {
+        &self.context_stack
+    }
+
+    /// Whether an action (already normalized) works in the top context.
+    fn action_in_context(&self, action: &str) -> bool {
+        let Some(top) = self.input_context() else {
+            return true;
+        };
+        self.context_actions
+            .get(top)
+            .is_some_and(|actions| actions.contains(action))
+            || !self.context_actions.values().any(|actions| actions.contains(action))
+    }
+
+    /// Actions with a chord that passes `matches` and whose modifiers are held.
+    fn chord_actions_for(&self, matches: impl Fn(&InputChord) -> bool) -> Vec<String> {
+        self.chord_action_mappings
+            .iter()
+            .filter(|(_, chords)| {
+                chords
+                    .iter()
+                    .any(|chord| matches(chord) && self.chord_modifiers_held(chord, &self.keys_current))
+            })
+            .map(|(action, _)| action.clone())
+            .collect()
+    }
+
+    /// Return all action names in sorted order.
+    pub fn action_names(&self) -> Vec<String> {
+        let mut names = HashSet::new();
+        names.extend(self.key_action_mappings.keys().cloned());
+        names.extend(self.mouse_action_mappings.keys().cloned());
+        names.extend(self.joystick_action_mappings.keys().cloned());
+        names.extend(self.chord_action_mappings.keys().cloned());
+        let mut ordered: Vec<String> = names.into_iter().collect();
+        ordered.sort();
+        ordered
+    }
+
+    /// Keys bound to an action.
+    pub fn action_keys(&self, action_name: &str) -> &[Key] {
+        self.key_action_mappings
+            .get(&Self::normalize_action_name(action_name))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Mouse buttons bound to an action.
+    pub fn action_mouse_buttons(&self, action_name: &str) -> &[MouseButtonType] {
+        self.mouse_action_mappings
+            .get(&Self::normalize_action_name(action_name))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Joystick buttons bound to an action.
+    pub fn action_joystick_buttons(&self, action_name: &str) -> &[JoystickButton] {
+        self.joystick_action_mappings
+            .get(&Self::normalize_action_name(action_name))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Replace joystick button bindings for an action.
+    pub fn set_action_joystick_buttons<S: Into<String>>(&mut self, action_name: S, buttons: Vec<JoystickButton>) {
+        self.joystick_action_mappings
+            .insert(Self::normalize_action_name(&action_name.into()), buttons);
+        self.glyph_version += 1;
+    }
+
+    /// Modifier chords bound to an action.
+    pub fn action_chords(&self, action_name: &str) -> &[InputChord] {
+        self.chord_action_mappings
+            .get(&Self::normalize_action_name(action_name))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Replace modifier chord bindings for an action.
+    pub fn set_action_chords<S: Into<String>>(&mut self, action_name: S, chords: Vec<InputChord>) {
+        self.chord_action_mappings
+            .insert(Self::normalize_action_name(&action_name.into()), chords);
+        self.glyph_version += 1;
+    }
+
+    /// Add one modifier chord to an action binding.
+    pub fn add_action_chord<S: Into<String>>(&mut self, action_name: S, chord: InputChord) {
+        let action = Self::normalize_action_name(&action_name.into());
+        let chords = self.chord_action_mappings.entry(action).or_default();
+        if !chords.contains(&chord) {
+            chords.push(chord);
+            self.glyph_version += 1;
+        }
+    }
+
+    /// Remove one modifier chord from an action binding.
+    pub fn remove_action_chord(&mut self, action_name: &str, chord: &InputChord) -> bool {
+        let action = Self::normalize_action_name(action_name);
+        let Some(chords) = self.chord_action_mappings.get_mut(&action) else {
+            return false;
+        };
+        if let Some(index) = chords.iter().position(|existing| existing == chord) {
+            chords.swap_remove(index);
+            self.glyph_version += 1;
+            return true;
+        }
+        false
+    }
+
+    /// Replace keyboard bindings for an action.
+    pub fn set_action_keys<S: Into<String>>(&mut self, action_name: S, keys: Vec<Key>) {
         self.key_action_mappings
             .insert(Self::normalize_action_name(&action_name.into()), keys);
+        self.glyph_version += 1;
     }
 
     /// Add one keyboard key to an action binding.
@@ -950,6 +1893,7 @@ impl InputManager {
         let keys = self.key_action_mappings.entry(action).or_default();
         if !keys.contains(&key) {
             keys.push(key);
+            self.glyph_version += 1;
         }
     }
 
@@ -961,6 +1905,7 @@ impl InputManager {
         };
         if let Some(index) = keys.iter().position(|existing| existing == key) {
             keys.swap_remove(index);
+            self.glyph_version += 1;
             return true;
         }
         false
@@ -974,6 +1919,7 @@ impl InputManager {
     ) {
         self.mouse_action_mappings
             .insert(Self::normalize_action_name(&action_name.into()), buttons);
+        self.glyph_version += 1;
     }
 
     /// Add one mouse button to an action binding.
@@ -986,6 +1932,7 @@ impl InputManager {
         let buttons = self.mouse_action_mappings.entry(action).or_default();
         if !buttons.contains(&button) {
             buttons.push(button);
+            self.glyph_version += 1;
         }
     }
 
@@ -1001,6 +1948,7 @@ impl InputManager {
         };
         if let Some(index) = buttons.iter().position(|existing| *existing == button) {
             buttons.swap_remove(index);
+            self.glyph_version += 1;
             return true;
         }
         false
@@ -1012,14 +1960,454 @@ impl InputManager {
         self.key_action_mappings.remove(&action);
         self.mouse_action_mappings.remove(&action);
         self.joystick_action_mappings.remove(&action);
+        self.chord_action_mappings.remove(&action);
+        self.glyph_version += 1;
+    }
+
+    /// Remove every axis and action binding, e.g. before loading saved ones.
+    pub fn clear_input_bindings(&mut self) {
+        self.axis_bindings.clear();
+        self.axis_smoothing.clear();
+        self.key_action_mappings.clear();
+        self.mouse_action_mappings.clear();
+        self.joystick_action_mappings.clear();
+        self.chord_action_mappings.clear();
+        self.glyph_version += 1;
     }
 
     /// Restore default axis and action bindings.
     pub fn reset_input_bindings_to_defaults(&mut self) {
         self.axis_bindings = Self::default_axis_bindings();
+        self.axis_smoothing = Self::default_axis_smoothing();
         self.key_action_mappings = Self::default_key_action_mappings();
         self.mouse_action_mappings = Self::default_mouse_action_mappings();
         self.joystick_action_mappings = Self::default_joystick_action_mappings();
+        self.chord_action_mappings.clear();
+        self.glyph_version += 1;
+    }
+
+    /// Device the player used most recently.
+    ///
+    /// Key presses, mouse clicks, scrolling, and mouse movement select
+    /// keyboard/mouse; joystick button presses and stick deflection select the
+    /// gamepad. Starts as keyboard/mouse.
+    pub fn active_device(&self) -> InputDevice {
+        self.active_device
+    }
+
+    /// Whether the active device switched during the last `update`.
+    ///
+    /// When it does, an [`InputEvent::ActiveDeviceChanged`] is also included in
+    /// [`Self::frame_events`].
+    pub fn active_device_changed(&self) -> bool {
+        self.active_device_changed
+    }
+
+    /// Report activity on a device, for input that bypasses this manager.
+    ///
+    /// Takes effect on the next `update`, like any other input.
+    pub fn report_device_activity(&mut self, device: InputDevice) {
+        self.pending_device_activity = Some(device);
+    }
+
+    /// Work out which device produced the latest input this frame and switch to it.
+    fn refresh_active_device(&mut self) {
+        let mut detected = self.pending_device_activity.take();
+        for event in &self.event_queue {
+            match event {
+                InputEvent::KeyPressed { .. }
+                | InputEvent::MouseButtonPressed { .. }
+                | InputEvent::MouseWheel { .. } => detected = Some(InputDevice::KeyboardMouse),
+                InputEvent::JoystickButtonPressed { .. } => detected = Some(InputDevice::Gamepad),
+                // Fall back to keyboard/mouse when the last gamepad goes away.
+                InputEvent::JoystickDisconnected { .. } if self.connected_joysticks.is_empty() => {
+                    detected = Some(InputDevice::KeyboardMouse)
+                }
+                InputEvent::JoystickAxisMoved { value, .. }
+                    if value.abs() >= JOYSTICK_AXIS_ACTIVITY_THRESHOLD =>
+                {
+                    detected = Some(InputDevice::Gamepad)
+                }
+                _ => {}
+            }
+        }
+
+        if detected.is_none() {
+            let (dx, dy) = self.mouse_delta();
+            if dx.hypot(dy) >= MOUSE_ACTIVITY_THRESHOLD {
+                detected = Some(InputDevice::KeyboardMouse);
+            }
+        }
+
+        self.active_device_changed = false;
+        if let Some(device) = detected
+            && device != self.active_device
+        {
+            self.active_device = device;
+            self.active_device_changed = true;
+            self.event_queue
+                .push_back(InputEvent::ActiveDeviceChanged { device });
+            if self.glyph_device_override.is_none() {
+                self.glyph_version += 1;
+            }
+        }
+    }
+
+    /// Device whose bindings are shown in prompts.
+    ///
+    /// Uses the override when set; otherwise follows [`Self::active_device`].
+    pub fn glyph_device(&self) -> InputDevice {
+        self.glyph_device_override.unwrap_or(self.active_device)
+    }
+
+    /// Force prompts to a device, or pass `None` to follow the detected device.
+    pub fn set_glyph_device_override(&mut self, device: Option<InputDevice>) {
+        if self.glyph_device_override != device {
+            self.glyph_device_override = device;
+            self.glyph_version += 1;
+        }
+    }
+
+    /// Get the forced prompt device, if any.
+    pub fn glyph_device_override(&self) -> Option<InputDevice> {
+        self.glyph_device_override
+    }
+
+    /// Version counter for prompt glyphs.
+    ///
+    /// Changes whenever action bindings or the prompt device change, so UI can
+    /// cache prompt text and rebuild it only when this value differs.
+    pub fn glyph_version(&self) -> u64 {
+        self.glyph_version
+    }
+
+    /// Get display glyphs for everything bound to an action on a device.
+    ///
+    /// When `device` is `None` the current prompt device is used. If the action
+    /// has no bindings on that device, the other device's bindings are returned
+    /// so a prompt is never silently empty.
+    pub fn action_glyphs(&self, action_name: &str, device: Option<InputDevice>) -> Vec<InputGlyph> {
+        let action = Self::normalize_action_name(action_name);
+        let keyboard_mouse = || {
+            let mut glyphs: Vec<InputGlyph> = self
+                .key_action_mappings
+                .get(&action)
+                .into_iter()
+                .flatten()
+                .map(InputGlyph::for_key)
+                .collect();
+            glyphs.extend(
+                self.chord_action_mappings
+                    .get(&action)
+                    .into_iter()
+                    .flatten()
+                    .map(InputGlyph::for_chord),
+            );
+            glyphs.extend(
+                self.mouse_action_mappings
+                    .get(&action)
+                    .into_iter()
+                    .flatten()
+                    .map(|button| InputGlyph::for_mouse_button(*button)),
+            );
+            glyphs
+        };
+        let gamepad = || -> Vec<InputGlyph> {
+            self.joystick_action_mappings
+                .get(&action)
+                .into_iter()
+                .flatten()
+                .map(|button| InputGlyph::for_joystick_button(*button))
+                .collect()
+        };
+
+        let (preferred, fallback) = match device.unwrap_or_else(|| self.glyph_device()) {
+            InputDevice::KeyboardMouse => (keyboard_mouse(), gamepad()),
+            InputDevice::Gamepad => (gamepad(), keyboard_mouse()),
+        };
+        if preferred.is_empty() {
+            fallback
+        } else {
+            preferred
+        }
+    }
+
+    /// Build prompt text for an action, e.g. "[Space]" or "[Space]/[LMB]".
+    ///
+    /// Returns an empty string if the action has no bindings.
+    pub fn action_prompt(&self, action_name: &str, device: Option<InputDevice>) -> String {
+        self.action_glyphs(action_name, device)
+            .iter()
+            .map(InputGlyph::prompt_text)
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Device assigned to a player (1-based), or `None` for an empty or invalid slot.
+    pub fn player_device(&self, player: u8) -> Option<PlayerDevice> {
+        self.player_slot(player).and_then(|slot| slot.device)
+    }
+
+    /// Assign a device to a player (1-based), or empty the slot with `None`.
+    ///
+    /// A device belongs to one player at a time, so assigning it takes it
+    /// away from any other slot. Gamepads may be assigned before they
+    /// connect. Queues [`InputEvent::PlayerDeviceChanged`] for every slot
+    /// that changed. Returns `false` if `player` is out of range.
+    pub fn assign_player(&mut self, player: u8, device: Option<PlayerDevice>) -> bool {
+        if self.player_slot(player).is_none() {
+            return false;
+        }
+
+        if let Some(device) = device {
+            for other in 1..=MAX_PLAYERS {
+                if other != player && self.player_device(other) == Some(device) {
+                    self.set_player_slot_device(other, None);
+                }
+            }
+        }
+        self.set_player_slot_device(player, device);
+        true
+    }
+
+    /// Player (1-based) that owns a joystick, if any.
+    pub fn player_for_joystick(&self, joystick_id: u32) -> Option<u8> {
+        (1..=MAX_PLAYERS).find(|&player| {
+            self.player_device(player) == Some(PlayerDevice::Gamepad(joystick_id))
+        })
+    }
+
+    /// Choose whether newly connected gamepads fill the first empty player slot (on by default).
+    pub fn set_auto_assign_gamepads(&mut self, enabled: bool) {
+        self.auto_assign_gamepads = enabled;
+    }
+
+    pub fn auto_assign_gamepads(&self) -> bool {
+        self.auto_assign_gamepads
+    }
+
+    /// Choose whether auto-assignment waits for a gamepad's first button
+    /// press instead of happening on connect (off by default).
+    ///
+    /// Lets players join by pressing a button, so a controller that is
+    /// plugged in but unused does not take a slot.
+    pub fn set_assign_gamepads_on_press(&mut self, enabled: bool) {
+        self.assign_gamepads_on_press = enabled;
+    }
+
+    pub fn assign_gamepads_on_press(&self) -> bool {
+        self.assign_gamepads_on_press
+    }
+
+    /// Give a player a gamepad; see [`Self::assign_player`].
+    pub fn assign_gamepad(&mut self, player: u8, joystick_id: u32) -> bool {
+        self.assign_player(player, Some(PlayerDevice::Gamepad(joystick_id)))
+    }
+
+    /// Value of a logical axis for one player, using only their device.
+    ///
+    /// Smoothed like [`Self::axis`]. Returns 0.0 for empty slots and unknown axes.
+    pub fn player_axis(&self, player: u8, name: &str) -> f32 {
+        self.player_slot(player)
+            .map_or(0.0, |slot| Self::axis_value(&slot.axis_values_current, name))
+    }
+
+    /// Unsmoothed value of a logical axis for one player.
+    pub fn player_axis_raw(&self, player: u8, name: &str) -> f32 {
+        self.player_slot(player)
+            .map_or(0.0, |slot| Self::axis_value(&slot.axis_values_raw, name))
+    }
+
+    /// How far a trigger is pulled on a player's gamepad, from 0.0 to 1.0.
+    ///
+    /// Returns 0.0 for players without a gamepad.
+    pub fn player_trigger(&self, player: u8, trigger: GamepadTrigger) -> f32 {
+        self.player_device(player)
+            .and_then(PlayerDevice::joystick_id)
+            .map_or(0.0, |joystick_id| self.joystick_trigger(joystick_id, trigger))
+    }
+
+    /// Check if an action is held on a player's device.
+    pub fn player_action_down(&self, player: u8, action_name: &str) -> bool {
+        self.player_action_matches(
+            player,
+            action_name,
+            Self::key_down,
+            Self::mouse_button_down,
+            Self::joystick_button_down,
+            Self::chord_down,
+        )
+    }
+
+    /// Check if an action was pressed this frame on a player's device.
+    pub fn player_action_pressed(&self, player: u8, action_name: &str) -> bool {
+        self.player_action_matches(
+            player,
+            action_name,
+            Self::key_pressed,
+            Self::mouse_button_pressed,
+            Self::joystick_button_pressed,
+            Self::chord_pressed,
+        )
+    }
+
+    /// Check if an action was released this frame on a player's device.
+    pub fn player_action_released(&self, player: u8, action_name: &str) -> bool {
+        self.player_action_matches(
+            player,
+            action_name,
+            Self::key_released,
+            Self::mouse_button_released,
+            Self::joystick_button_released,
+            Self::chord_released,
+        )
+    }
+
+    fn player_slot(&self, player: u8) -> Option<&PlayerSlot> {
+        self.player_slots.get(player.checked_sub(1)? as usize)
+    }
+
+    fn set_player_slot_device(&mut self, player: u8, device: Option<PlayerDevice>) {
+        let Some(slot) = self.player_slots.get_mut(player as usize - 1) else {
+            return;
+        };
+        if slot.device != device {
+            slot.device = device;
+            slot.clear_axes();
+            self.event_queue
+                .push_back(InputEvent::PlayerDeviceChanged { player, device });
+        }
+    }
+
+    /// Give an unassigned gamepad the first empty player slot when it is
+    /// first seen, or on a button press when assigning on press.
+    fn auto_assign_gamepad(&mut self, joystick_id: u32, newly_seen: bool, button_pressed: bool) {
+        let trigger = if self.assign_gamepads_on_press {
+            button_pressed
+        } else {
+            newly_seen
+        };
+        if !trigger || !self.auto_assign_gamepads || self.player_for_joystick(joystick_id).is_some() {
+            return;
+        }
+        if let Some(player) = (1..=MAX_PLAYERS).find(|&player| self.player_device(player).is_none()) {
+            self.set_player_slot_device(player, Some(PlayerDevice::Gamepad(joystick_id)));
+        }
+    }
+
+    /// Check an action's bindings against one player's device.
+    ///
+    /// Joystick bindings match by button id on the player's own gamepad,
+    /// whatever joystick id they were bound with.
+    fn player_action_matches(
+        &self,
+        player: u8,
+        action_name: &str,
+        key_check: fn(&Self, &Key) -> bool,
+        mouse_check: fn(&Self, MouseButtonType) -> bool,
+        joystick_check: fn(&Self, u32, u8) -> bool,
+        chord_check: fn(&Self, &InputChord) -> bool,
+    ) -> bool {
+        let Some(device) = self.player_device(player) else {
+            return false;
+        };
+        let action = Self::normalize_action_name(action_name);
+        if !self.action_in_context(&action) {
+            return false;
+        }
+
+        if let Some(keys) = self.key_action_mappings.get(&action)
+            && keys
+                .iter()
+                .any(|key| device.owns_key(key) && key_check(self, key))
+        {
+            return true;
+        }
+
+        if device.owns_mouse()
+            && let Some(buttons) = self.mouse_action_mappings.get(&action)
+            && buttons.iter().any(|button| mouse_check(self, *button))
+        {
+            return true;
+        }
+
+        if let Some(chords) = self.chord_action_mappings.get(&action)
+            && chords.iter().any(|chord| {
+                let owned = match &chord.trigger {
+                    ChordTrigger::Key(key) => device.owns_key(key),
+                    ChordTrigger::MouseButton(_) => device.owns_mouse(),
+                };
+                owned && chord_check(self, chord)
+            })
+        {
+            return true;
+        }
+
+        if let Some(joystick_id) = device.joystick_id()
+            && let Some(buttons) = self.joystick_action_mappings.get(&action)
+            && buttons
+                .iter()
+                .any(|button| joystick_check(self, joystick_id, button.button_id))
+        {
+            return true;
+        }
+
+        false
+    }
+
+    /// Recompute every assigned player's axes from their own device.
+    fn update_player_axes(&mut self, delta_time: f32) {
+        let mut slots = std::mem::take(&mut self.player_slots);
+        for slot in &mut slots {
+            std::mem::swap(&mut slot.axis_values_previous, &mut slot.axis_values_current);
+            slot.axis_values_current.clear();
+            slot.axis_values_raw.clear();
+            let Some(device) = slot.device else {
+                continue;
+            };
+
+            for (name, binding) in &self.axis_bindings {
+                let (keys, analog) = self.compute_axis_parts(binding, Some(device));
+                let raw = (keys + analog).clamp(-1.0, 1.0);
+                let smoothed = match self.axis_smoothing.get(name) {
+                    Some(smoothing) => {
+                        Self::smooth_axis(smoothing, &mut slot.axis_values_keys, name, keys, analog, delta_time)
+                    }
+                    None => raw,
+                };
+                slot.axis_values_raw.insert(name.clone(), raw);
+                slot.axis_values_current.insert(name.clone(), smoothed);
+            }
+        }
+        self.player_slots = slots;
+    }
+
+    /// Ramp the keyboard part of an axis toward `keys`, keeping it in
+    /// `smoothed_keys`, and add the analog part unsmoothed.
+    fn smooth_axis(
+        smoothing: &AxisSmoothing,
+        smoothed_keys: &mut HashMap<String, f32>,
+        name: &str,
+        keys: f32,
+        analog: f32,
+        delta_time: f32,
+    ) -> f32 {
+        let previous = smoothed_keys.get(name).copied().unwrap_or(0.0);
+        let keys = smoothing.step(previous, keys, delta_time);
+        smoothed_keys.insert(name.to_string(), keys);
+        (keys + analog).clamp(-1.0, 1.0)
+    }
+
+    /// Look up an axis value by name, falling back to a case-insensitive match.
+    fn axis_value(values: &HashMap<String, f32>, name: &str) -> f32 {
+        if let Some(value) = values.get(name) {
+            return *value;
+        }
+
+        values
+            .iter()
+            .find_map(|(axis_name, value)| axis_name.eq_ignore_ascii_case(name).then_some(*value))
+            .unwrap_or(0.0)
     }
 
     /// Check if a keyboard key is currently held down.
@@ -1041,6 +2429,104 @@ impl InputManager {
         !now && before
     }
 
+    /// Check if a modifier chord is held: its trigger is down and exactly its
+    /// modifiers are held.
+    pub fn chord_down(&self, chord: &InputChord) -> bool {
+        self.chord_held(chord, &self.keys_current, &self.mouse_buttons_current)
+    }
+
+    /// Check if a modifier chord was completed this frame.
+    ///
+    /// Only the trigger going down completes a chord, so holding S and then
+    /// pressing Ctrl does not count as Ctrl+S.
+    pub fn chord_pressed(&self, chord: &InputChord) -> bool {
+        let trigger_pressed = match &chord.trigger {
+            ChordTrigger::Key(key) => {
+                Self::chord_key_in(&self.keys_current, key) && !Self::chord_key_in(&self.keys_previous, key)
+            }
+            ChordTrigger::MouseButton(button) => self.mouse_button_pressed(*button),
+        };
+        trigger_pressed && self.chord_modifiers_held(chord, &self.keys_current)
+    }
+
+    /// Check if a modifier chord stopped being held this frame, whether its
+    /// trigger or one of its modifiers was let go.
+    pub fn chord_released(&self, chord: &InputChord) -> bool {
+        self.chord_held(chord, &self.keys_previous, &self.mouse_buttons_previous) && !self.chord_down(chord)
+    }
+
+    fn chord_held(
+        &self,
+        chord: &InputChord,
+        keys: &HashMap<Key, bool>,
+        mouse_buttons: &HashMap<MouseButtonType, bool>,
+    ) -> bool {
+        let trigger_down = match &chord.trigger {
+            ChordTrigger::Key(key) => Self::chord_key_in(keys, key),
+            ChordTrigger::MouseButton(button) => *mouse_buttons.get(button).unwrap_or(&false),
+        };
+        trigger_down && self.chord_modifiers_held(chord, keys)
+    }
+
+    /// Whether exactly the chord's modifiers are down in `keys`. A trigger
+    /// that is itself a modifier key is not counted as an extra modifier.
+    fn chord_modifiers_held(&self, chord: &InputChord, keys: &HashMap<Key, bool>) -> bool {
+        let mut held = ModifiersState::empty();
+        for (key, pressed) in keys {
+            if *pressed && let Some(flag) = modifier_flag(key) {
+                held |= flag;
+            }
+        }
+        if let ChordTrigger::Key(key) = &chord.trigger
+            && let Some(flag) = modifier_flag(key)
+        {
+            held.remove(flag);
+        }
+        held == chord.modifiers
+    }
+
+    /// Whether a chord's trigger key is down in `keys`, in either letter case.
+    fn chord_key_in(keys: &HashMap<Key, bool>, key: &Key) -> bool {
+        let down = |key: &Key| *keys.get(key).unwrap_or(&false);
+        match key {
+            Key::Character(text) => {
+                down(&Key::Character(text.to_lowercase().into()))
+                    || down(&Key::Character(text.to_uppercase().into()))
+            }
+            _ => down(key),
+        }
+    }
+
+    /// Keys currently held down, in no particular order.
+    pub fn held_keys(&self) -> Vec<Key> {
+        self.keys_current
+            .iter()
+            .filter(|(_, pressed)| **pressed)
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    /// Mouse buttons currently held down, in no particular order.
+    pub fn held_mouse_buttons(&self) -> Vec<MouseButtonType> {
+        self.mouse_buttons_current
+            .iter()
+            .filter(|(_, pressed)| **pressed)
+            .map(|(button, _)| *button)
+            .collect()
+    }
+
+    /// Joystick buttons currently held down, sorted by joystick then button.
+    pub fn held_joystick_buttons(&self) -> Vec<JoystickButton> {
+        let mut buttons: Vec<JoystickButton> = self
+            .joystick_buttons_current
+            .iter()
+            .filter(|(_, pressed)| **pressed)
+            .map(|(button, _)| *button)
+            .collect();
+        buttons.sort_by_key(|button| (button.joystick_id, button.button_id));
+        buttons
+    }
+
     /// Check if a mouse button is currently held down.
     pub fn mouse_button_down(&self, button: MouseButtonType) -> bool {
         *self.mouse_buttons_current.get(&button).unwrap_or(&false)
@@ -1066,13 +2552,41 @@ impl InputManager {
     }
 
     /// Get the mouse movement delta for this frame.
+    ///
+    /// In relative mouse mode this is the raw device motion, so it keeps
+    /// reporting movement while the cursor is locked in place.
     pub fn mouse_delta(&self) -> (f64, f64) {
+        if self.relative_mouse_mode {
+            return self.mouse_motion_delta;
+        }
         (
             self.mouse_position.0 - self.mouse_position_previous.0,
             self.mouse_position.1 - self.mouse_position_previous.1,
         )
     }
 
+    /// Handle raw mouse motion from a device event.
+    ///
+    /// Only read in relative mouse mode; see [`Self::set_relative_mouse_mode`].
+    pub fn handle_mouse_motion(&mut self, delta_x: f64, delta_y: f64) {
+        self.mouse_motion_delta.0 += delta_x;
+        self.mouse_motion_delta.1 += delta_y;
+    }
+
+    /// Read mouse movement (`mouse_delta` and the "Mouse X/Y" axes) from raw
+    /// device motion instead of cursor position changes.
+    ///
+    /// Use it while the cursor is grabbed: a locked cursor stops moving and a
+    /// confined one stops at the window edges, but raw motion continues.
+    pub fn set_relative_mouse_mode(&mut self, enabled: bool) {
+        self.relative_mouse_mode = enabled;
+    }
+
+    /// Whether mouse movement is read from raw device motion.
+    pub fn relative_mouse_mode(&self) -> bool {
+        self.relative_mouse_mode
+    }
+
     /// Get the mouse wheel delta accumulated this frame.
     pub fn mouse_wheel(&self) -> (f64, f64) {
         self.mouse_wheel_delta
@@ -1118,18 +2632,98 @@ impl InputManager {
         *self.joystick_axes.get(&key).unwrap_or(&0.0)
     }
 
+    /// How far a trigger is pulled, from 0.0 to 1.0.
+    ///
+    /// Travel within the trigger's deadzone reads 0.0 and the rest is
+    /// rescaled so a full pull still reads 1.0.
+    pub fn joystick_trigger(&self, joystick_id: u32, trigger: GamepadTrigger) -> f32 {
+        self.trigger_value(trigger, self.joystick_axis(joystick_id, trigger.axis_id()))
+    }
+
+    /// Set a trigger's deadzone, from 0.0 to just under 1.0 (0.1 by default).
+    pub fn set_trigger_deadzone(&mut self, trigger: GamepadTrigger, deadzone: f32) {
+        self.trigger_deadzones[trigger as usize] = deadzone.clamp(0.0, 0.99);
+    }
+
+    pub fn trigger_deadzone(&self, trigger: GamepadTrigger) -> f32 {
+        self.trigger_deadzones[trigger as usize]
+    }
+
+    /// Raw trigger axis value to 0.0..=1.0 travel past the deadzone.
+    fn trigger_value(&self, trigger: GamepadTrigger, raw: f32) -> f32 {
+        let deadzone = self.trigger_deadzone(trigger);
+        ((raw.clamp(0.0, 1.0) - deadzone) / (1.0 - deadzone)).max(0.0)
+    }
+
+    /// Ids of the connected joysticks, sorted.
+    pub fn connected_joysticks(&self) -> Vec<u32> {
+        let mut ids: Vec<u32> = self.connected_joysticks.iter().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Ids of the joysticks connected (`true`) or disconnected (`false`) this frame.
+    pub fn joystick_connection_changes(&self, connected: bool) -> Vec<u32> {
+        self.frame_events
+            .iter()
+            .filter_map(|event| match event {
+                InputEvent::JoystickConnected { joystick_id } if connected => Some(*joystick_id),
+                InputEvent::JoystickDisconnected { joystick_id } if !connected => Some(*joystick_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Compute a logical axis from every bound device as its keyboard part,
+    /// clamped to [-1.0, 1.0], and its analog mouse and joystick part.
+    ///
+    /// With a player device only that device's keys, mouse or gamepad count.
+    fn compute_axis_parts(&self, binding: &AxisBinding, device: Option<PlayerDevice>) -> (f32, f32) {
+        // Keyboard contribution
+        let keys = binding
+            .keyboard
+            .as_ref()
+            .map_or(0.0, |kb| self.compute_keyboard_axis(kb, device).clamp(-1.0, 1.0));
+
+        let mut value: f32 = 0.0;
+
+        // Mouse contribution
+        if let Some(mouse) = &binding.mouse
+            && device.is_none_or(PlayerDevice::owns_mouse)
+        {
+            value += self.compute_mouse_axis(mouse);
+        }
+
+        // Joystick contribution; a player reads only their own gamepad
+        if let Some(js) = &binding.joystick {
+            match device {
+                None => value += self.compute_joystick_axis(js, js.joystick_id),
+                Some(PlayerDevice::Gamepad(joystick_id)) => {
+                    value += self.compute_joystick_axis(js, Some(joystick_id))
+                }
+                Some(_) => {}
+            }
+        }
+
+        (keys, value)
+    }
+
     /// Compute the keyboard contribution to a logical axis.
-    fn compute_keyboard_axis(&self, binding: &KeyboardAxisBinding) -> f32 {
+    fn compute_keyboard_axis(&self, binding: &KeyboardAxisBinding, device: Option<PlayerDevice>) -> f32 {
         let mut value: f32 = 0.0;
+        let counts = |key: &Key| {
+            device.is_none_or(|device| device.owns_key(key))
+                && *self.keys_current.get(key).unwrap_or(&false)
+        };
 
         for key in &binding.positive_keys {
-            if *self.keys_current.get(key).unwrap_or(&false) {
+            if counts(key) {
                 value += 1.0;
             }
         }
 
         for key in &binding.negative_keys {
-            if *self.keys_current.get(key).unwrap_or(&false) {
+            if counts(key) {
                 value -= 1.0;
             }
         }
@@ -1146,11 +2740,11 @@ impl InputManager {
 
     /// Compute the mouse contribution to a logical axis.
     ///
-    /// Mouse X/Y are based on position delta; WheelX/WheelY use accumulated scroll.
+    /// Mouse X/Y are based on `mouse_delta`; WheelX/WheelY use accumulated scroll.
     fn compute_mouse_axis(&self, binding: &MouseAxisBinding) -> f32 {
         let raw = match binding.axis {
-            MouseAxisType::X => (self.mouse_position.0 - self.mouse_position_previous.0) as f32,
-            MouseAxisType::Y => (self.mouse_position.1 - self.mouse_position_previous.1) as f32,
+            MouseAxisType::X => self.mouse_delta().0 as f32,
+            MouseAxisType::Y => self.mouse_delta().1 as f32,
             MouseAxisType::WheelX => self.mouse_wheel_delta.0 as f32,
             MouseAxisType::WheelY => self.mouse_wheel_delta.1 as f32,
         };
@@ -1164,9 +2758,12 @@ impl InputManager {
     }
 
     /// Compute the joystick contribution to a logical axis.
-    fn compute_joystick_axis(&self, binding: &JoystickAxisBinding) -> f32 {
+    ///
+    /// Reads `joystick_id` when given, otherwise the first joystick with a
+    /// matching axis.
+    fn compute_joystick_axis(&self, binding: &JoystickAxisBinding, joystick_id: Option<u32>) -> f32 {
         // Resolve which joystick axis value to read
-        let axis_value = if let Some(joy_id) = binding.joystick_id {
+        let axis_value = if let Some(joy_id) = joystick_id {
             // Specific joystick requested
             let key = JoystickAxis {
                 joystick_id: joy_id,
@@ -1187,8 +2784,10 @@ impl InputManager {
                 .unwrap_or(0.0)
         };
 
-        // Apply deadzone
-        let mut value = if axis_value.abs() < binding.deadzone {
+        // Apply deadzone; triggers read 0..1 past their own deadzone
+        let mut value = if let Some(trigger) = GamepadTrigger::from_axis_id(binding.axis.axis_id) {
+            self.trigger_value(trigger, axis_value)
+        } else if axis_value.abs() < binding.deadzone {
             0.0
         } else {
             axis_value
@@ -1213,3 +2812,442 @@ impl InputManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device_events(input: &InputManager) -> Vec<InputDevice> {
+        input
+            .frame_events()
+            .iter()
+            .filter_map(|event| match event {
+                InputEvent::ActiveDeviceChanged { device } => Some(*device),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_active_device_follows_last_input() {
+        let mut input = InputManager::new();
+        assert_eq!(input.active_device(), InputDevice::KeyboardMouse);
+
+        input.handle_joystick_axis(0, 0, 0.2);
+        input.update(1.0 / 60.0);
+        assert_eq!(input.active_device(), InputDevice::KeyboardMouse);
+        assert!(!input.active_device_changed());
+
+        let version = input.glyph_version();
+        input.handle_joystick_button(0, 0, true);
+        input.update(1.0 / 60.0);
+        assert_eq!(input.active_device(), InputDevice::Gamepad);
+        assert!(input.active_device_changed());
+        assert_eq!(device_events(&input), vec![InputDevice::Gamepad]);
+        assert_eq!(input.glyph_device(), InputDevice::Gamepad);
+        assert_ne!(input.glyph_version(), version);
+
+        input.update(1.0 / 60.0);
+        assert!(!input.active_device_changed());
+        assert!(device_events(&input).is_empty());
+    }
+
+    #[test]
+    fn test_axis_smoothing_ramps_and_snaps() {
+        let mut input = InputManager::new();
+        let right = Key::Named(NamedKey::ArrowRight);
+        let left = Key::Named(NamedKey::ArrowLeft);
+
+        input.keys_current.insert(right.clone(), true);
+        input.update(0.1);
+        assert_eq!(input.axis_raw("Horizontal"), 1.0);
+        assert!((input.axis("horizontal") - 0.3).abs() < 1e-5);
+
+        input.keys_current.insert(right, false);
+        input.update(0.05);
+        assert_eq!(input.axis_raw("Horizontal"), 0.0);
+        assert!((input.axis("Horizontal") - 0.15).abs() < 1e-5);
+
+        // Reversing direction snaps through zero before ramping.
+        input.keys_current.insert(left, true);
+        input.update(0.1);
+        assert!((input.axis("Horizontal") + 0.3).abs() < 1e-5);
+
+        assert!(input.set_axis_smoothing("Horizontal", None));
+        input.update(0.1);
+        assert_eq!(input.axis("Horizontal"), -1.0);
+        assert!(!input.set_axis_smoothing("Missing", Some(AxisSmoothing::default())));
+    }
+
+    #[test]
+    fn test_axis_smoothing_leaves_sticks_unsmoothed() {
+        let mut input = InputManager::new();
+        input.keys_current.insert(Key::Named(NamedKey::ArrowRight), true);
+        input.update(0.1);
+        assert!((input.axis("Horizontal") - 0.3).abs() < 1e-5);
+
+        // The stick applies at once on top of the ramping keyboard part.
+        input.handle_joystick_axis(0, 0, -0.5);
+        input.update(0.1);
+        assert!((input.axis("Horizontal") - 0.1).abs() < 1e-5);
+        assert!((input.player_axis(2, "Horizontal") + 0.5).abs() < 1e-5);
+
+        input.keys_current.insert(Key::Named(NamedKey::ArrowRight), false);
+        input.update(1.0);
+        assert!((input.axis("Horizontal") + 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_disconnect_and_reported_activity() {
+        let mut input = InputManager::new();
+        input.handle_joystick_button(3, 1, true);
+        input.update(1.0 / 60.0);
+        assert_eq!(input.active_device(), InputDevice::Gamepad);
+
+        input.handle_joystick_disconnected(3);
+        input.update(1.0 / 60.0);
+        assert_eq!(input.active_device(), InputDevice::KeyboardMouse);
+        assert!(!input.joystick_button_down(3, 1));
+
+        input.report_device_activity(InputDevice::Gamepad);
+        input.update(1.0 / 60.0);
+        input.update(1.0 / 60.0);
+        assert_eq!(input.active_device(), InputDevice::Gamepad);
+    }
+
+    #[test]
+    fn test_disconnect_releases_held_input() {
+        let mut input = InputManager::new();
+        input.handle_joystick_button(1, 0, true);
+        input.handle_joystick_axis(1, 0, 1.0);
+        input.update(1.0 / 60.0);
+        assert!(input.axis_raw("Horizontal") > 0.0);
+
+        input.handle_joystick_disconnected(1);
+        assert!(input.joystick_button_released(1, 0));
+        input.update(1.0 / 60.0);
+        assert_eq!(input.axis_raw("Horizontal"), 0.0);
+        assert_eq!(
+            input.frame_events(),
+            &[
+                InputEvent::JoystickButtonReleased { joystick_id: 1, button_id: 0 },
+                InputEvent::JoystickAxisMoved { joystick_id: 1, axis_id: 0, value: 0.0 },
+                InputEvent::JoystickDisconnected { joystick_id: 1 },
+                InputEvent::PlayerDeviceChanged { player: 2, device: None },
+                InputEvent::ActiveDeviceChanged { device: InputDevice::KeyboardMouse },
+            ]
+        );
+        assert!(!input.joystick_button_released(1, 0));
+    }
+
+    #[test]
+    fn test_player_slots_route_devices() {
+        let mut input = InputManager::new();
+        input.handle_joystick_connected(7);
+        input.handle_joystick_axis(9, 0, -1.0);
+        assert_eq!(input.player_device(2), Some(PlayerDevice::Gamepad(7)));
+        assert_eq!(input.player_for_joystick(9), Some(3));
+
+        assert!(input.assign_player(1, Some(PlayerDevice::KeyboardLeft)));
+        assert!(input.assign_player(4, Some(PlayerDevice::KeyboardRight)));
+        assert!(!input.assign_player(5, Some(PlayerDevice::KeyboardMouse)));
+        assert!(!input.assign_player(0, None));
+        input.set_axis_smoothing("Horizontal", None);
+
+        input.keys_current.insert(Key::Character("d".into()), true);
+        input.keys_current.insert(Key::Named(NamedKey::Enter), true);
+        input.handle_joystick_button(7, 0, true);
+        input.update(1.0 / 60.0);
+
+        assert_eq!(input.player_axis(1, "horizontal"), 1.0);
+        assert_eq!(input.player_axis(2, "Horizontal"), 0.0);
+        assert_eq!(input.player_axis(3, "Horizontal"), -1.0);
+        assert_eq!(input.player_axis(4, "Horizontal"), 0.0);
+        assert!(input.player_action_down(2, "jump"));
+        assert!(!input.player_action_down(3, "jump"));
+        assert!(input.player_action_down(4, "submit"));
+        assert!(!input.player_action_down(1, "submit"));
+
+        // Moving a device clears its old slot; unplugging empties the slot
+        // and the next gamepad takes the first free one.
+        assert!(input.assign_player(2, Some(PlayerDevice::KeyboardLeft)));
+        assert_eq!(input.player_device(1), None);
+        input.handle_joystick_disconnected(9);
+        assert_eq!(input.player_device(3), None);
+        input.handle_joystick_connected(11);
+        assert_eq!(input.player_for_joystick(11), Some(1));
+
+        input.set_auto_assign_gamepads(false);
+        input.handle_joystick_connected(12);
+        assert_eq!(input.player_for_joystick(12), None);
+    }
+
+    #[test]
+    fn test_gamepads_join_on_first_button_press() {
+        let mut input = InputManager::new();
+        input.set_assign_gamepads_on_press(true);
+        input.handle_joystick_connected(3);
+        input.handle_joystick_axis(5, 0, 1.0);
+        assert_eq!(input.player_for_joystick(3), None);
+        assert_eq!(input.player_for_joystick(5), None);
+
+        input.handle_joystick_button(5, 2, true);
+        input.handle_joystick_button(3, 0, true);
+        assert_eq!(input.player_for_joystick(5), Some(2));
+        assert_eq!(input.player_for_joystick(3), Some(3));
+
+        // A released slot is not retaken until the gamepad presses again.
+        assert!(input.assign_gamepad(4, 3));
+        assert!(input.assign_player(4, None));
+        input.handle_joystick_button(3, 0, false);
+        assert_eq!(input.player_for_joystick(3), None);
+        input.handle_joystick_button(3, 0, true);
+        assert_eq!(input.player_for_joystick(3), Some(3));
+    }
+
+    #[test]
+    fn test_triggers_read_zero_to_one_and_press_fire() {
+        let mut input = InputManager::new();
+        input.set_trigger_deadzone(GamepadTrigger::Right, 0.2);
+
+        input.handle_joystick_axis(0, GamepadTrigger::Right.axis_id(), 0.1);
+        input.handle_joystick_axis(0, GamepadTrigger::Left.axis_id(), -1.0);
+        assert_eq!(input.joystick_trigger(0, GamepadTrigger::Right), 0.0);
+        assert_eq!(input.joystick_trigger(0, GamepadTrigger::Left), 0.0);
+
+        input.handle_joystick_axis(0, GamepadTrigger::Right.axis_id(), 0.6);
+        assert!((input.joystick_trigger(0, GamepadTrigger::Right) - 0.5).abs() < 1e-5);
+        assert!(input.joystick_button_down(0, GamepadTrigger::Right.button_id()));
+        assert!(input.action_pressed("fire1"));
+
+        input.update(1.0 / 60.0);
+        input.handle_joystick_axis(0, GamepadTrigger::Right.axis_id(), 0.3);
+        assert!(input.action_released("fire1"));
+
+        input.set_axis_binding(
+            "Throttle",
+            AxisBinding {
+                keyboard: None,
+                mouse: None,
+                joystick: Some(JoystickAxisBinding {
+                    joystick_id: None,
+                    axis: JoystickAxis {
+                        joystick_id: 0,
+                        axis_id: GamepadTrigger::Right.axis_id(),
+                    },
+                    deadzone: 0.0,
+                    sensitivity: 1.0,
+                    invert: false,
+                }),
+            },
+        );
+        input.handle_joystick_axis(0, GamepadTrigger::Right.axis_id(), 1.0);
+        input.update(1.0 / 60.0);
+        assert_eq!(input.axis("Throttle"), 1.0);
+    }
+
+    #[test]
+    fn test_reset_releases_everything() {
+        let mut input = InputManager::new();
+        let right = Key::Named(NamedKey::ArrowRight);
+        input.keys_current.insert(right.clone(), true);
+        input.mouse_buttons_current.insert(MouseButtonType::Left, true);
+        input.handle_joystick_button(0, 2, true);
+        input.update(1.0 / 60.0);
+        assert!(input.axis("Horizontal") > 0.0);
+
+        input.reset();
+        assert_eq!(input.axis("Horizontal"), 0.0);
+        assert!(input.key_released(&right));
+        assert!(input.mouse_button_released(MouseButtonType::Left));
+        assert!(input.joystick_button_released(0, 2));
+        input.update(1.0 / 60.0);
+        assert!(!input.key_down(&right));
+        assert_eq!(input.axis("Horizontal"), 0.0);
+        assert!(input.frame_events().contains(&InputEvent::KeyReleased { key: right }));
+    }
+
+    #[test]
+    fn test_ime_composition_and_typed_text() {
+        let mut input = InputManager::new();
+        input.handle_ime(&Ime::Enabled);
+        input.handle_ime(&Ime::Preedit("にほ".to_string(), Some((6, 6))));
+        assert_eq!(input.composition(), "にほ");
+        assert_eq!(input.composition_cursor(), Some((2, 2)));
+        input.update(1.0 / 60.0);
+        assert_eq!(input.typed_text(), "");
+
+        input.handle_ime(&Ime::Commit("日本".to_string()));
+        input.event_queue.push_back(InputEvent::TextInput { text: "!".to_string() });
+        input.update(1.0 / 60.0);
+        assert_eq!(input.typed_text(), "日本!");
+        assert_eq!(input.composition(), "");
+        assert!(input.frame_events().contains(&InputEvent::TextComposition { text: String::new(), cursor: None }));
+
+        input.update(1.0 / 60.0);
+        assert_eq!(input.typed_text(), "");
+    }
+
+    #[test]
+    fn test_chord_edges_ignore_bare_key_and_late_modifier() {
+        let mut input = InputManager::new();
+        let ctrl = Key::Named(NamedKey::Control);
+        let shift = Key::Named(NamedKey::Shift);
+        input.set_action_chords(
+            "save",
+            vec![InputChord::new(ModifiersState::CONTROL, ChordTrigger::Key(Key::Character("s".into())))],
+        );
+        input.set_action_chords(
+            "select",
+            vec![InputChord::new(ModifiersState::SHIFT, ChordTrigger::MouseButton(MouseButtonType::Left))],
+        );
+
+        // The bare key, or the modifier pressed after it, never completes the chord.
+        input.keys_current.insert(Key::Character("s".into()), true);
+        assert!(!input.action_pressed("save"));
+        input.update(1.0 / 60.0);
+        input.keys_current.insert(ctrl.clone(), true);
+        assert!(!input.action_pressed("save"));
+        assert!(input.action_down("save"));
+        input.update(1.0 / 60.0);
+
+        // Ctrl held first: pressing S fires once, and letting go of Ctrl releases it.
+        input.keys_current.insert(Key::Character("s".into()), false);
+        input.update(1.0 / 60.0);
+        input.keys_current.insert(Key::Character("s".into()), true);
+        assert!(input.action_pressed("save"));
+        input.update(1.0 / 60.0);
+        assert!(!input.action_pressed("save"));
+        input.keys_current.insert(ctrl.clone(), false);
+        assert!(input.action_released("save"));
+        assert!(!input.action_down("save"));
+        input.keys_current.insert(Key::Character("s".into()), false);
+        input.update(1.0 / 60.0);
+
+        // Extra modifiers do not match, and Shift's uppercase letter still does.
+        input.keys_current.insert(ctrl.clone(), true);
+        input.keys_current.insert(shift.clone(), true);
+        input.keys_current.insert(Key::Character("S".into()), true);
+        assert!(!input.action_down("save"));
+        input.mouse_buttons_current.insert(MouseButtonType::Left, true);
+        assert!(!input.action_pressed("select"));
+        input.keys_current.insert(ctrl, false);
+        assert!(input.action_down("select"));
+        assert!(
+            input
+                .actions_for_event(&InputEvent::MouseButtonPressed { button: MouseButtonType::Left })
+                .contains(&"select".to_string())
+        );
+        assert_eq!(input.action_prompt("select", None), "[Shift+LMB]");
+    }
+
+    #[test]
+    fn test_action_buffer_remembers_recent_presses() {
+        let mut input = InputManager::new();
+        let frame = 0.05;
+        input.set_action_keys("jump", vec![Key::Named(NamedKey::Space)]);
+
+        input.keys_current.insert(Key::Named(NamedKey::Space), true);
+        assert!(input.action_buffered("jump", 0.0));
+        input.update(frame);
+        input.keys_current.insert(Key::Named(NamedKey::Space), false);
+        input.update(frame);
+        assert!(input.action_buffered("Jump", 0.12));
+        assert!(!input.action_buffered("jump", 0.08));
+        input.update(frame);
+        assert!(!input.action_buffered("jump", 0.12));
+
+        // Consuming a press keeps it from triggering again, including the
+        // press of the current frame once it is recorded.
+        input.keys_current.insert(Key::Named(NamedKey::Space), true);
+        assert!(input.consume_buffered_action("jump", 0.12));
+        assert!(!input.action_buffered("jump", 0.12));
+        input.update(frame);
+        assert!(!input.action_buffered("jump", 0.12));
+
+        input.set_input_buffer_duration(0.0);
+        input.keys_current.insert(Key::Named(NamedKey::Space), false);
+        input.update(frame);
+        input.keys_current.insert(Key::Named(NamedKey::Space), true);
+        input.update(frame);
+        assert!(!input.action_buffered("jump", 1.0));
+    }
+
+    #[test]
+    fn test_contexts_switch_actions_on_same_key() {
+        let mut input = InputManager::new();
+        input.set_action_keys("confirm", vec![Key::Named(NamedKey::Space)]);
+        input.set_context_actions("gameplay", vec!["Jump".to_string()]);
+        input.set_context_actions("menu", vec!["confirm".to_string()]);
+        input.keys_current.insert(Key::Named(NamedKey::Space), true);
+
+        // With no context pushed every action works.
+        assert!(input.action_down("jump") && input.action_down("confirm"));
+        input.push_input_context("gameplay");
+        assert!(input.action_pressed("jump"));
+        assert!(!input.action_pressed("confirm"));
+        assert!(input.action_down("submit"), "actions in no context always work");
+        input.push_input_context("Menu");
+        assert_eq!(input.input_context(), Some("menu"));
+        assert!(!input.action_down("jump"));
+        assert_eq!(
+            input.actions_for_event(&InputEvent::KeyPressed { key: Key::Named(NamedKey::Space) }),
+            ["confirm", "submit"]
+        );
+        assert_eq!(input.pop_input_context().as_deref(), Some("menu"));
+        assert!(input.action_down("jump"));
+        assert_eq!(input.input_context_stack(), ["gameplay"]);
+    }
+
+    #[test]
+    fn test_injected_input_drives_keys_actions_and_events() {
+        let mut input = InputManager::new();
+        let space = Key::Named(NamedKey::Space);
+        input.inject_key(space.clone(), true);
+        input.inject_text(" ");
+        input.inject_mouse_move(40.0, 30.0);
+        assert!(input.key_pressed(&space) && input.action_pressed("jump"));
+        assert_eq!(input.mouse_position(), (40.0, 30.0));
+
+        input.update(1.0 / 60.0);
+        assert_eq!(
+            input.frame_events(),
+            [
+                InputEvent::KeyPressed { key: space.clone() },
+                InputEvent::TextInput { text: " ".to_string() },
+                InputEvent::MouseMoved { x: 40.0, y: 30.0 },
+            ]
+        );
+        assert_eq!(input.typed_text(), " ");
+
+        input.inject_key(space.clone(), false);
+        input.inject_mouse_button(MouseButtonType::Left, true);
+        input.inject_mouse_wheel(0.0, -1.0);
+        assert!(input.action_released("jump") && input.action_pressed("fire1"));
+        assert_eq!(input.mouse_wheel(), (0.0, -1.0));
+
+        // A release for a key that was never pressed is dropped, like a real one.
+        input.inject_key(Key::Character("q".into()), false);
+        input.update(1.0 / 60.0);
+        assert_eq!(input.frame_events().len(), 3);
+    }
+
+    #[test]
+    fn test_relative_mouse_mode_reads_raw_motion() {
+        let mut input = InputManager::new();
+        input.mouse_position = (0.75, 0.0);
+        input.handle_mouse_motion(0.25, -0.5);
+        input.update(1.0 / 60.0);
+        assert_eq!(input.axis("Mouse X"), 0.75);
+
+        input.set_relative_mouse_mode(true);
+        input.handle_mouse_motion(0.25, -0.5);
+        input.handle_mouse_motion(0.125, 0.25);
+        assert_eq!(input.mouse_delta(), (0.375, -0.25));
+        input.update(1.0 / 60.0);
+        assert_eq!((input.axis("Mouse X"), input.axis("Mouse Y")), (0.375, -0.25));
+
+        input.update(1.0 / 60.0);
+        assert_eq!(input.axis("Mouse X"), 0.0);
+    }
+}
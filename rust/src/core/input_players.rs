@@ -0,0 +1,131 @@
+//! Player slots for local multiplayer.
+//!
+//! Each numbered player (1 to [`MAX_PLAYERS`]) reads from one device: the
+//! whole keyboard and mouse, one half of a split keyboard, or a gamepad.
+//! Per-player queries on [`InputManager`](super::input_manager::InputManager)
+//! only see input from that device, so games never need to track joystick
+//! ids themselves.
+
+use std::collections::HashMap;
+use winit::keyboard::{Key, NamedKey};
+
+/// Number of player slots.
+pub const MAX_PLAYERS: u8 = 4;
+
+/// Keys on the left half of a QWERTY keyboard, used by [`PlayerDevice::KeyboardLeft`].
+const LEFT_HALF_CHARACTERS: &str = "`12345qwertasdfgzxcvb";
+
+/// Device a player slot reads input from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlayerDevice {
+    /// The whole keyboard plus the mouse.
+    KeyboardMouse,
+    /// Left half of a shared keyboard: WASD, Space, Tab, the modifiers and
+    /// the left-hand letter and digit keys.
+    KeyboardLeft,
+    /// Right half of a shared keyboard: arrows, Enter, the numpad and every
+    /// key not on the left half.
+    KeyboardRight,
+    /// A gamepad by joystick id.
+    Gamepad(u32),
+}
+
+impl PlayerDevice {
+    /// Device kind name: `"keyboard_mouse"`, `"keyboard_left"`, `"keyboard_right"` or `"gamepad"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::KeyboardMouse => "keyboard_mouse",
+            Self::KeyboardLeft => "keyboard_left",
+            Self::KeyboardRight => "keyboard_right",
+            Self::Gamepad(_) => "gamepad",
+        }
+    }
+
+    /// Parse a device kind name. `"gamepad"` needs `joystick_id`; the
+    /// keyboard kinds ignore it.
+    pub fn parse(value: &str, joystick_id: Option<u32>) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "keyboard_mouse" | "keyboard" => Some(Self::KeyboardMouse),
+            "keyboard_left" => Some(Self::KeyboardLeft),
+            "keyboard_right" => Some(Self::KeyboardRight),
+            "gamepad" => joystick_id.map(Self::Gamepad),
+            _ => None,
+        }
+    }
+
+    /// Joystick id for gamepad devices.
+    pub fn joystick_id(self) -> Option<u32> {
+        match self {
+            Self::Gamepad(id) => Some(id),
+            _ => None,
+        }
+    }
+
+    /// Whether this device reads the mouse.
+    pub fn owns_mouse(self) -> bool {
+        self == Self::KeyboardMouse
+    }
+
+    /// Whether this device reads `key`.
+    pub fn owns_key(self, key: &Key) -> bool {
+        match self {
+            Self::KeyboardMouse => true,
+            Self::KeyboardLeft => is_left_half_key(key),
+            Self::KeyboardRight => !is_left_half_key(key),
+            Self::Gamepad(_) => false,
+        }
+    }
+}
+
+/// Whether `key` sits on the left half of the keyboard.
+///
+/// Logical keys do not distinguish left and right modifiers, so Shift,
+/// Control, Alt and Super all count as left-half keys.
+fn is_left_half_key(key: &Key) -> bool {
+    match key {
+        Key::Character(text) => {
+            let mut chars = text.chars();
+            match (chars.next(), chars.next()) {
+                (Some(ch), None) => LEFT_HALF_CHARACTERS.contains(ch.to_ascii_lowercase()),
+                _ => false,
+            }
+        }
+        Key::Named(named) => matches!(
+            named,
+            NamedKey::Space
+                | NamedKey::Tab
+                | NamedKey::Escape
+                | NamedKey::CapsLock
+                | NamedKey::Shift
+                | NamedKey::Control
+                | NamedKey::Alt
+                | NamedKey::Super
+        ),
+        _ => false,
+    }
+}
+
+/// State of one player slot.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PlayerSlot {
+    /// Device this player reads; `None` while the slot is empty
+    pub(crate) device: Option<PlayerDevice>,
+    /// Axis values this frame, after smoothing
+    pub(crate) axis_values_current: HashMap<String, f32>,
+    /// Axis values last frame
+    pub(crate) axis_values_previous: HashMap<String, f32>,
+    /// Axis values this frame, before smoothing
+    pub(crate) axis_values_raw: HashMap<String, f32>,
+    /// Smoothed keyboard part of each smoothed axis
+    pub(crate) axis_values_keys: HashMap<String, f32>,
+}
+
+impl PlayerSlot {
+    /// Drop axis state so a newly assigned device starts from rest.
+    pub(crate) fn clear_axes(&mut self) {
+        self.axis_values_current.clear();
+        self.axis_values_previous.clear();
+        self.axis_values_raw.clear();
+        self.axis_values_keys.clear();
+    }
+}
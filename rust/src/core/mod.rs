@@ -1,16 +1,34 @@
+pub mod asset_manager;
+pub mod audio;
 mod camera;
+pub mod capabilities;
+pub mod clipboard;
 pub mod command;
+pub mod config;
 pub mod component;
+pub mod curve;
+pub mod debug_snapshot;
 pub mod draw_manager;
 pub mod engine;
 mod entity;
+pub mod event_bus;
 pub mod game_object;
+pub mod gamepad;
 mod geometry;
+pub mod input_bindings;
+pub mod input_glyphs;
 pub mod input_manager;
+pub mod input_players;
+pub mod input_debug;
 pub mod logging;
 pub mod object_manager;
 pub mod physics;
+pub mod profiler;
 pub mod render_manager;
+pub mod scene_file;
+pub mod scene_manager;
+pub mod script;
+mod software_display;
 pub mod text;
 mod texture;
 pub mod time;
@@ -18,8 +36,12 @@ pub mod ui;
 pub mod ui_manager;
 pub mod window_manager;
 
+pub use asset_manager::*;
+pub use capabilities::*;
 pub use command::*;
+pub use config::*;
 pub use component::*;
+pub use curve::*;
 pub use draw_manager::*;
 pub use engine::*;
 pub use game_object::*;
@@ -27,6 +49,7 @@ pub use input_manager::*;
 pub use logging::*;
 pub use object_manager::*;
 pub use physics::*;
+pub use profiler::*;
 pub use render_manager::*;
 pub use text::*;
 pub use time::*;
@@ -18,6 +18,31 @@
 //! Each GameObject has a unique ID assigned at creation. The manager uses this
 //! ID as the key for all operations (lookup, removal, etc.).
 //!
+//! # Storage
+//!
+//! Objects live in one contiguous array in insertion order, so per-frame
+//! passes (`iter`) walk the objects linearly instead of chasing hash buckets.
+//! Each object still owns its boxed components; this is dense object storage,
+//! not per-component columns. A paged table indexed by ID maps each object to
+//! its slot, making lookups two array accesses. IDs are never reused, so
+//! pages are freed once their last object goes, keeping the table's size
+//! tied to the live objects rather than every ID ever issued.
+//!
+//! Removing an object leaves a hole in its slot rather than shifting the
+//! objects after it. Once holes make up half the array, one compaction pass
+//! closes them all, so removal is O(1) amortized and the order is kept.
+//!
+//! # Parallel Updates
+//!
+//! `for_each_scheduled` runs a per-object pass across the rayon thread pool.
+//! The caller holds the manager's write lock for the whole pass, so nothing
+//! else can read a half-updated scene. Inside it, every object only reads the
+//! shared frame state (`Time`) and writes to itself, so objects never contend
+//! with each other. Only objects whose components all opt in through
+//! `ComponentTrait::runs_in_parallel` go to the pool; everything else,
+//! including scripts and Python callbacks, runs afterwards on the calling
+//! thread.
+//!
 //! # Usage
 //!
 //! ```rust
@@ -48,7 +73,15 @@
 use super::logging;
 use crate::core::game_object::GameObject;
 use crate::types::vector::Vec2;
-use std::collections::HashMap;
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+const EMPTY_SLOT: u32 = u32::MAX;
+/// IDs per page of the ID index.
+const ID_PAGE_SIZE: usize = 256;
+
+/// Below this many opted-in objects a parallel pass costs more than it saves.
+pub const PARALLEL_UPDATE_MIN_OBJECTS: usize = 256;
 
 #[derive(Clone, Copy, Debug)]
 pub struct WorldTransform {
@@ -57,6 +90,72 @@ pub struct WorldTransform {
     pub scale: Vec2,
 }
 
+/// Maps object IDs to slots in the dense storage.
+///
+/// A flat table indexed by ID would grow with every object ever spawned, as
+/// IDs are never reused. Pages are allocated on first use and dropped once
+/// empty, so a long session costs one empty entry per `ID_PAGE_SIZE` IDs
+/// issued rather than one slot per ID.
+#[derive(Default)]
+struct IdIndex {
+    pages: Vec<Option<IdPage>>,
+}
+
+struct IdPage {
+    slots: Box<[u32; ID_PAGE_SIZE]>,
+    len: usize,
+}
+
+impl IdIndex {
+    fn locate(id: u32) -> (usize, usize) {
+        (id as usize / ID_PAGE_SIZE, id as usize % ID_PAGE_SIZE)
+    }
+
+    fn get(&self, id: u32) -> Option<usize> {
+        let (page, offset) = Self::locate(id);
+        let slot = self.pages.get(page)?.as_ref()?.slots[offset];
+        (slot != EMPTY_SLOT).then_some(slot as usize)
+    }
+
+    fn insert(&mut self, id: u32, slot: u32) {
+        let (page, offset) = Self::locate(id);
+        if page >= self.pages.len() {
+            self.pages.resize_with(page + 1, || None);
+        }
+        let page = self.pages[page].get_or_insert_with(|| IdPage {
+            slots: Box::new([EMPTY_SLOT; ID_PAGE_SIZE]),
+            len: 0,
+        });
+        if page.slots[offset] == EMPTY_SLOT {
+            page.len += 1;
+        }
+        page.slots[offset] = slot;
+    }
+
+    fn remove(&mut self, id: u32) {
+        let (index, offset) = Self::locate(id);
+        let Some(Some(page)) = self.pages.get_mut(index) else {
+            return;
+        };
+        if page.slots[offset] == EMPTY_SLOT {
+            return;
+        }
+        page.slots[offset] = EMPTY_SLOT;
+        page.len -= 1;
+        if page.len == 0 {
+            self.pages[index] = None;
+            while matches!(self.pages.last(), Some(None)) {
+                self.pages.pop();
+            }
+        }
+    }
+
+    #[cfg(test)]
+    fn allocated_pages(&self) -> usize {
+        self.pages.iter().flatten().count()
+    }
+}
+
 /// Manages the lifecycle and storage of game objects.
 ///
 /// `ObjectManager` maintains a registry of all GameObjects in the scene,
@@ -72,26 +171,34 @@ pub struct WorldTransform {
 /// # Object Ordering
 ///
 /// Objects are tracked in both insertion order and sorted by ID:
-/// - **Insertion order**: Maintained for predictable iteration
+/// - **Insertion order**: The storage order, for predictable iteration
 /// - **Sorted by ID**: Enables efficient binary search lookups
 pub struct ObjectManager {
-    objects: HashMap<u32, GameObject>, // id -> object
+    // Dense storage in insertion order; `None` marks a removed object's slot
+    objects: Vec<Option<GameObject>>,
+    // id -> index into `objects`
+    slots: IdIndex,
+    holes: usize,
     total_objects: u32,
-    active_objects: u32,
-    keys_insertion: Vec<u32>,
+    // May hold removed ids until the next compaction
     keys_sorted: Vec<u32>,
     scene_version: u64,
+    // name/tag -> ids, sorted
+    name_index: HashMap<String, Vec<u32>>,
+    tag_index: HashMap<String, Vec<u32>>,
 }
 
 impl ObjectManager {
     pub fn new() -> Self {
         Self {
-            objects: HashMap::new(),
+            objects: Vec::new(),
+            slots: IdIndex::default(),
+            holes: 0,
             total_objects: 0,
-            active_objects: 0,
-            keys_insertion: Vec::new(),
             keys_sorted: Vec::new(),
             scene_version: 0,
+            name_index: HashMap::new(),
+            tag_index: HashMap::new(),
         }
     }
 
@@ -123,33 +230,116 @@ impl ObjectManager {
         self.scene_version
     }
 
-    fn insert_key(&mut self, id: u32) {
-        self.keys_insertion.push(id);
-        match self.keys_sorted.binary_search(&id) {
-            Ok(_) => {}
-            Err(index) => self.keys_sorted.insert(index, id),
+    fn slot(&self, id: u32) -> Option<usize> {
+        self.slots.get(id)
+    }
+
+    fn object(&self, id: u32) -> Option<&GameObject> {
+        self.slot(id).and_then(|slot| self.objects[slot].as_ref())
+    }
+
+    fn object_mut(&mut self, id: u32) -> Option<&mut GameObject> {
+        self.slot(id).and_then(|slot| self.objects[slot].as_mut())
+    }
+
+    fn contains(&self, id: u32) -> bool {
+        self.slot(id).is_some()
+    }
+
+    /// Append an object to the dense storage.
+    fn push_object(&mut self, object: GameObject) {
+        let id = object.get_id();
+        self.slots.insert(id, self.objects.len() as u32);
+        self.objects.push(Some(object));
+        // A re-added id may still have its entry from before it was removed
+        if let Err(index) = self.keys_sorted.binary_search(&id) {
+            self.keys_sorted.insert(index, id);
         }
     }
 
-    fn remove_key(&mut self, id: u32) {
-        if let Some(index) = self.keys_insertion.iter().position(|key| *key == id) {
-            self.keys_insertion.remove(index);
+    /// Remove objects from the dense storage, keeping the order of the rest.
+    ///
+    /// Each removal leaves a hole; returns the removed objects in `ids` order.
+    fn take_objects(&mut self, ids: &[u32]) -> Vec<GameObject> {
+        let mut removed = Vec::with_capacity(ids.len());
+        for &id in ids {
+            let Some(slot) = self.slot(id) else {
+                continue;
+            };
+            self.slots.remove(id);
+            if let Some(object) = self.objects[slot].take() {
+                self.holes += 1;
+                removed.push(object);
+            }
+        }
+
+        if self.holes * 2 > self.objects.len() {
+            self.compact();
         }
-        if let Ok(index) = self.keys_sorted.binary_search(&id) {
-            self.keys_sorted.remove(index);
+        removed
+    }
+
+    /// Close every hole in one pass and drop removed ids from `keys_sorted`.
+    fn compact(&mut self) {
+        self.objects.retain(Option::is_some);
+        for (slot, object) in self.objects.iter().flatten().enumerate() {
+            self.slots.insert(object.get_id(), slot as u32);
+        }
+        let slots = &self.slots;
+        self.keys_sorted.retain(|id| slots.get(*id).is_some());
+        self.holes = 0;
+    }
+
+    fn index_object(&mut self, object: &GameObject) {
+        let id = object.get_id();
+        if let Some(name) = object.name() {
+            Self::index(&mut self.name_index, name, id);
+        }
+        for tag in object.tags() {
+            Self::index(&mut self.tag_index, tag, id);
+        }
+    }
+
+    fn unindex_object(&mut self, object: &GameObject) {
+        let id = object.get_id();
+        if let Some(name) = object.name() {
+            Self::unindex(&mut self.name_index, name, id);
+        }
+        for tag in object.tags() {
+            Self::unindex(&mut self.tag_index, tag, id);
+        }
+    }
+
+    fn index(index: &mut HashMap<String, Vec<u32>>, key: &str, id: u32) {
+        let ids = index.entry(key.to_string()).or_default();
+        if let Err(position) = ids.binary_search(&id) {
+            ids.insert(position, id);
+        }
+    }
+
+    fn unindex(index: &mut HashMap<String, Vec<u32>>, key: &str, id: u32) {
+        if let Some(ids) = index.get_mut(key) {
+            if let Ok(position) = ids.binary_search(&id) {
+                ids.remove(position);
+            }
+            if ids.is_empty() {
+                index.remove(key);
+            }
         }
     }
 
     pub fn add_object(&mut self, object: GameObject) -> Option<u32> {
         let id = object.get_id();
 
-        if self.objects.insert(id, object).is_none() {
-            self.total_objects += 1;
-            self.insert_key(id);
+        self.index_object(&object);
+        if let Some(replaced) = self.object_mut(id) {
+            let replaced = std::mem::replace(replaced, object);
+            self.unindex_object(&replaced);
         } else {
+            self.total_objects += 1;
+            self.push_object(object);
         }
 
-        self.refresh_enabled_counts();
         self.bump_scene_version();
         Some(id)
     }
@@ -180,7 +370,7 @@ impl ObjectManager {
     /// }
     /// ```
     pub fn get_object_by_id(&self, id: u32) -> Option<&GameObject> {
-        self.objects.get(&id)
+        self.object(id)
     }
 
     /// Get a mutable reference to an object by its ID.
@@ -209,25 +399,124 @@ impl ObjectManager {
     /// }
     /// ```
     pub fn get_object_by_id_mut(&mut self, id: u32) -> Option<&mut GameObject> {
-        if self.objects.contains_key(&id) {
+        if self.contains(id) {
             self.bump_scene_version();
         }
-        self.objects.get_mut(&id)
+        self.object_mut(id)
     }
 
     pub fn get_object_clone(&self, id: u32) -> Option<GameObject> {
-        self.objects.get(&id).cloned()
+        self.object(id).cloned()
+    }
+
+    /// Iterate over all objects in insertion order.
+    ///
+    /// Walks the contiguous storage directly; prefer it over looking up each
+    /// of `get_keys()` for per-frame passes.
+    pub fn iter(&self) -> impl Iterator<Item = &GameObject> {
+        self.objects.iter().flatten()
+    }
+
+    /// Run `f` on every object, spreading opted-in objects across the rayon
+    /// pool.
+    ///
+    /// Objects are picked by `GameObject::runs_in_parallel`. The rest run
+    /// afterwards on the calling thread, in insertion order. With `parallel`
+    /// off, or fewer than `PARALLEL_UPDATE_MIN_OBJECTS` opted-in objects, this
+    /// is a plain `iter()` pass; counting them allocates nothing.
+    pub fn for_each_scheduled<F>(&self, parallel: bool, f: F)
+    where
+        F: Fn(&GameObject) + Sync,
+    {
+        let opted_in = if parallel {
+            self.iter()
+                .filter(|object| object.runs_in_parallel())
+                .take(PARALLEL_UPDATE_MIN_OBJECTS)
+                .count()
+        } else {
+            0
+        };
+        if opted_in < PARALLEL_UPDATE_MIN_OBJECTS {
+            self.iter().for_each(f);
+            return;
+        }
+
+        self.objects
+            .par_iter()
+            .flatten()
+            .filter(|object| object.runs_in_parallel())
+            .for_each(&f);
+        self.iter().filter(|object| !object.runs_in_parallel()).for_each(f);
     }
 
     pub fn get_object_ids_by_name(&self, name: &str) -> Vec<u32> {
-        self.keys_insertion
-            .iter()
-            .filter_map(|id| {
-                self.objects
-                    .get(id)
-                    .and_then(|object| (object.name() == Some(name)).then_some(*id))
+        self.find_by_name(name)
+    }
+
+    /// Get the ids of objects with a name, in id order.
+    pub fn find_by_name(&self, name: &str) -> Vec<u32> {
+        self.name_index
+            .get(name)
+            .map(|ids| {
+                ids.iter()
+                    .copied()
+                    .filter(|id| self.object(*id).is_some_and(|object| object.name() == Some(name)))
+                    .collect()
             })
-            .collect()
+            .unwrap_or_default()
+    }
+
+    /// Get the ids of objects with a tag, in id order.
+    pub fn find_by_tag(&self, tag: &str) -> Vec<u32> {
+        self.tag_index
+            .get(tag)
+            .map(|ids| {
+                ids.iter()
+                    .copied()
+                    .filter(|id| self.object(*id).is_some_and(|object| object.has_tag(tag)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Get the ids of every object, in the order they were added.
+    pub fn get_all_ids(&self) -> Vec<u32> {
+        self.get_keys().collect()
+    }
+
+    /// Rename an object, keeping the name index current.
+    pub fn set_object_name(&mut self, id: u32, name: String) -> bool {
+        let Some(object) = self.object_mut(id) else {
+            return false;
+        };
+        let old_name = object.name().map(str::to_string);
+        object.set_name(name.clone());
+        if let Some(old_name) = old_name {
+            Self::unindex(&mut self.name_index, &old_name, id);
+        }
+        Self::index(&mut self.name_index, &name, id);
+        self.bump_scene_version();
+        true
+    }
+
+    /// Add a tag to an object. Returns false if the object is missing or
+    /// already has the tag.
+    pub fn add_object_tag(&mut self, id: u32, tag: &str) -> bool {
+        let added = self.object_mut(id).is_some_and(|object| object.add_tag(tag));
+        if added {
+            Self::index(&mut self.tag_index, tag, id);
+        }
+        added
+    }
+
+    /// Remove a tag from an object. Returns false if the object is missing or
+    /// does not have the tag.
+    pub fn remove_object_tag(&mut self, id: u32, tag: &str) -> bool {
+        let removed = self.object_mut(id).is_some_and(|object| object.remove_tag(tag));
+        if removed {
+            Self::unindex(&mut self.tag_index, tag, id);
+        }
+        removed
     }
 
     pub fn get_object_clones_by_name(&self, name: &str) -> Vec<GameObject> {
@@ -238,7 +527,7 @@ impl ObjectManager {
     }
 
     pub fn world_transform(&self, id: u32) -> Option<WorldTransform> {
-        let object = self.objects.get(&id)?;
+        let object = self.object(id)?;
         let local = WorldTransform {
             position: object.position(),
             rotation: object.rotation(),
@@ -283,24 +572,25 @@ impl ObjectManager {
     /// Get the number of active objects.
     ///
     /// Returns the count of objects where `is_active()` returns `true`.
-    /// Active objects are typically updated and rendered.
+    /// Active objects are typically updated and rendered. Counted on each
+    /// call, so enabling or removing objects doesn't pay for it.
     ///
     /// # Returns
     /// Active object count
     pub fn get_active_objects(&self) -> u32 {
-        self.active_objects
+        self.iter().filter(|object| object.is_enabled()).count() as u32
     }
 
     /// Check if there are any UI objects in the scene
     pub fn has_ui_objects(&self) -> bool {
         use crate::core::game_object::ObjectType;
-        self.objects.values().any(|obj| obj.get_object_type() == ObjectType::UIObject)
+        self.iter().any(|obj| obj.get_object_type() == ObjectType::UIObject)
     }
 
     /// Get references to all objects in the manager.
     ///
     /// Returns a vector of immutable references to all GameObjects, regardless
-    /// of their active state. The order is not guaranteed.
+    /// of their active state, in insertion order.
     ///
     /// # Returns
     /// Vector of immutable GameObject references
@@ -319,40 +609,40 @@ impl ObjectManager {
     /// }
     /// ```
     pub fn get_objects(&self) -> Vec<&GameObject> {
-        self.objects.values().collect::<Vec<&GameObject>>()
+        self.iter().collect::<Vec<&GameObject>>()
     }
 
     /// Get object IDs in insertion order.
     ///
-    /// Returns a slice of object IDs in the order they were added to the manager.
+    /// Iterates the object IDs in the order they were added to the manager.
     /// Useful for deterministic iteration order.
     ///
     /// # Returns
-    /// Slice of object IDs in insertion order
-    pub fn get_keys(&self) -> &[u32] {
-        &self.keys_insertion
+    /// Iterator over object IDs in insertion order
+    pub fn get_keys(&self) -> impl Iterator<Item = u32> + '_ {
+        self.iter().map(GameObject::get_id)
     }
 
     /// Get object IDs sorted numerically.
     ///
-    /// Returns a slice of object IDs sorted in ascending order. This list is
-    /// maintained via binary search for efficient lookups.
+    /// Iterates the object IDs in ascending order. The sorted list is
+    /// maintained via binary search and skips objects removed since the last
+    /// compaction.
     ///
     /// # Returns
-    /// Slice of object IDs in sorted order
-    pub fn get_sorted_keys(&self) -> &[u32] {
-        &self.keys_sorted
+    /// Iterator over object IDs in sorted order
+    pub fn get_sorted_keys(&self) -> impl Iterator<Item = u32> + '_ {
+        self.keys_sorted.iter().copied().filter(|id| self.contains(*id))
     }
 
     pub fn set_object_enabled(&mut self, id: u32, enabled: bool) -> bool {
-        let changed = if let Some(object) = self.objects.get_mut(&id) {
+        let changed = if let Some(object) = self.object_mut(id) {
             object.set_enabled_self(enabled)
         } else {
             return false;
         };
 
         self.refresh_enabled_from(id);
-        self.refresh_enabled_counts();
         if changed {
             self.bump_scene_version();
         }
@@ -364,63 +654,60 @@ impl ObjectManager {
             return Err("Cannot parent an object to itself".to_string());
         }
 
-        if !self.objects.contains_key(&parent_id) {
+        if !self.contains(parent_id) {
             return Err(format!("Parent object {parent_id} not found"));
         }
-        if !self.objects.contains_key(&child_id) {
+        if !self.contains(child_id) {
             return Err(format!("Child object {child_id} not found"));
         }
         if self.would_create_cycle(parent_id, child_id) {
             return Err("Cannot create a parent/child cycle".to_string());
         }
 
-        let old_parent = self.objects.get(&child_id).and_then(GameObject::parent_id);
+        let old_parent = self.object(child_id).and_then(GameObject::parent_id);
         if let Some(old_parent_id) = old_parent
-            && let Some(old_parent_obj) = self.objects.get_mut(&old_parent_id)
+            && let Some(old_parent_obj) = self.object_mut(old_parent_id)
         {
             old_parent_obj.remove_child_by_id(child_id);
         }
 
-        if let Some(parent) = self.objects.get_mut(&parent_id) {
+        if let Some(parent) = self.object_mut(parent_id) {
             parent.add_child_id(child_id);
         }
-        if let Some(child) = self.objects.get_mut(&child_id) {
+        if let Some(child) = self.object_mut(child_id) {
             child.set_parent_id(Some(parent_id));
         }
 
         self.refresh_enabled_from(child_id);
-        self.refresh_enabled_counts();
         self.bump_scene_version();
         Ok(())
     }
 
     pub fn detach_child(&mut self, child_id: u32) -> bool {
-        let parent_id = self.objects.get(&child_id).and_then(GameObject::parent_id);
+        let parent_id = self.object(child_id).and_then(GameObject::parent_id);
         let Some(parent_id) = parent_id else {
             return false;
         };
 
-        if let Some(parent) = self.objects.get_mut(&parent_id) {
+        if let Some(parent) = self.object_mut(parent_id) {
             parent.remove_child_by_id(child_id);
         }
-        if let Some(child) = self.objects.get_mut(&child_id) {
+        if let Some(child) = self.object_mut(child_id) {
             child.set_parent_id(None);
         }
         self.refresh_enabled_from(child_id);
-        self.refresh_enabled_counts();
         self.bump_scene_version();
         true
     }
 
     pub fn get_child_ids(&self, parent_id: u32) -> Vec<u32> {
-        self.objects
-            .get(&parent_id)
+        self.object(parent_id)
             .map(|object| object.children().to_vec())
             .unwrap_or_default()
     }
 
     pub fn get_child_by_id(&self, parent_id: u32, child_id: u32) -> Option<GameObject> {
-        self.objects.get(&parent_id).and_then(|parent| {
+        self.object(parent_id).and_then(|parent| {
             parent
                 .get_child_by_id(child_id)
                 .and_then(|id| self.get_object_clone(id))
@@ -437,52 +724,89 @@ impl ObjectManager {
     }
 
     pub fn destroy_object_recursive(&mut self, id: u32) -> Vec<u32> {
-        if !self.objects.contains_key(&id) {
+        if !self.contains(id) {
             logging::log_warn(&format!("Object {id} not found"));
             return Vec::new();
         }
+        self.destroy_objects_recursive(&[id])
+    }
 
-        let ids = self.collect_subtree_ids(id);
-        for object_id in ids.iter().rev() {
-            if let Some(object) = self.objects.get(object_id) {
-                object.invoke_on_destroy();
-            }
-        }
-
-        if let Some(parent_id) = self.objects.get(&id).and_then(GameObject::parent_id)
-            && let Some(parent) = self.objects.get_mut(&parent_id)
-        {
-            parent.remove_child_by_id(id);
+    /// Destroy several objects and their descendants in one pass.
+    ///
+    /// Roots are handled in order: each subtree gets `on_destroy` children
+    /// first, then all of them leave the storage together. Missing ids and
+    /// roots already inside an earlier subtree are skipped.
+    ///
+    /// # Returns
+    /// The ids of every destroyed object
+    pub fn destroy_objects_recursive(&mut self, root_ids: &[u32]) -> Vec<u32> {
+        let subtrees = self.collect_subtrees(root_ids);
+        if subtrees.is_empty() {
+            return Vec::new();
         }
 
-        for object_id in ids.iter().rev() {
-            if let Some(object) = self.objects.remove(object_id) {
-                if let Some(parent_id) = object.parent_id()
-                    && let Some(parent) = self.objects.get_mut(&parent_id)
-                {
-                    parent.remove_child_by_id(*object_id);
+        for ids in &subtrees {
+            for object_id in ids.iter().rev() {
+                if let Some(object) = self.object(*object_id) {
+                    object.invoke_on_destroy();
                 }
-                self.remove_key(*object_id);
-                self.total_objects = self.total_objects.saturating_sub(1);
             }
+
+            // The rest of the subtree goes with the root, so only its parent needs unlinking
+            let root_id = ids[0];
+            if let Some(parent_id) = self.object(root_id).and_then(GameObject::parent_id)
+                && let Some(parent) = self.object_mut(parent_id)
+            {
+                parent.remove_child_by_id(root_id);
+            }
+        }
+
+        let ids = subtrees.concat();
+        for object in self.take_objects(&ids) {
+            self.unindex_object(&object);
+            self.total_objects = self.total_objects.saturating_sub(1);
         }
 
-        self.refresh_enabled_counts();
         self.bump_scene_version();
         ids
     }
 
-    fn collect_subtree_ids(&self, root_id: u32) -> Vec<u32> {
+    /// Get the ids of an object and all of its descendants, parents first.
+    pub fn collect_subtree_ids(&self, root_id: u32) -> Vec<u32> {
         let mut ids = Vec::new();
-        self.collect_subtree_ids_recursive(root_id, &mut ids);
+        self.collect_subtree_ids_recursive(root_id, &mut ids, &mut HashSet::new());
         ids
     }
 
-    fn collect_subtree_ids_recursive(&self, current_id: u32, out: &mut Vec<u32>) {
+    /// Get the ids of several objects and their descendants, parents first,
+    /// listing each object once.
+    pub fn collect_subtree_ids_of(&self, root_ids: &[u32]) -> Vec<u32> {
+        self.collect_subtrees(root_ids).concat()
+    }
+
+    fn collect_subtrees(&self, root_ids: &[u32]) -> Vec<Vec<u32>> {
+        let mut seen = HashSet::new();
+        let mut subtrees = Vec::new();
+        for &id in root_ids {
+            if !self.contains(id) || seen.contains(&id) {
+                continue;
+            }
+            let mut ids = Vec::new();
+            self.collect_subtree_ids_recursive(id, &mut ids, &mut seen);
+            subtrees.push(ids);
+        }
+        subtrees
+    }
+
+    fn collect_subtree_ids_recursive(&self, current_id: u32, out: &mut Vec<u32>, seen: &mut HashSet<u32>) {
+        // A subtree collected earlier in the batch brings its descendants along
+        if !seen.insert(current_id) {
+            return;
+        }
         out.push(current_id);
-        if let Some(object) = self.objects.get(&current_id) {
+        if let Some(object) = self.object(current_id) {
             for child_id in object.children() {
-                self.collect_subtree_ids_recursive(*child_id, out);
+                self.collect_subtree_ids_recursive(*child_id, out, seen);
             }
         }
     }
@@ -493,28 +817,27 @@ impl ObjectManager {
             if current_id == child_id {
                 return true;
             }
-            current = self.objects.get(&current_id).and_then(GameObject::parent_id);
+            current = self.object(current_id).and_then(GameObject::parent_id);
         }
         false
     }
 
     fn refresh_enabled_from(&mut self, root_id: u32) {
         let parent_enabled = self
-            .objects
-            .get(&root_id)
+            .object(root_id)
             .and_then(GameObject::parent_id)
-            .and_then(|parent_id| self.objects.get(&parent_id))
+            .and_then(|parent_id| self.object(parent_id))
             .map(GameObject::is_enabled)
             .unwrap_or(true);
         self.refresh_enabled_recursive(root_id, parent_enabled);
     }
 
     fn refresh_enabled_recursive(&mut self, object_id: u32, parent_enabled: bool) {
-        let Some(child_ids) = self.objects.get(&object_id).map(|object| object.children().to_vec()) else {
+        let Some(child_ids) = self.object(object_id).map(|object| object.children().to_vec()) else {
             return;
         };
 
-        let current_enabled = if let Some(object) = self.objects.get_mut(&object_id) {
+        let current_enabled = if let Some(object) = self.object_mut(object_id) {
             object.set_enabled_in_hierarchy(parent_enabled);
             object.is_enabled()
         } else {
@@ -525,12 +848,75 @@ impl ObjectManager {
             self.refresh_enabled_recursive(child_id, current_enabled);
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_id_index_frees_pages_of_removed_objects() {
+        let mut object_manager = ObjectManager::new();
+        let keeper = object_manager.add_object(GameObject::new()).unwrap();
+        for _ in 0..20 {
+            let ids: Vec<u32> = (0..ID_PAGE_SIZE)
+                .map(|_| object_manager.add_object(GameObject::new()).unwrap())
+                .collect();
+            for id in ids {
+                object_manager.remove_object(id);
+            }
+        }
 
-    fn refresh_enabled_counts(&mut self) {
-        self.active_objects = self
-            .objects
-            .values()
-            .filter(|object| object.is_enabled())
-            .count() as u32;
+        // Only the keeper's page is left, and nothing past it
+        assert_eq!(object_manager.slots.allocated_pages(), 1);
+        assert_eq!(object_manager.slots.pages.len(), keeper as usize / ID_PAGE_SIZE + 1);
+        assert!(object_manager.get_object_by_id(keeper).is_some());
+        assert_eq!(object_manager.get_keys().collect::<Vec<_>>(), [keeper]);
+    }
+
+    #[test]
+    fn test_batch_destroy_lists_each_object_once() {
+        let mut object_manager = ObjectManager::new();
+        let parent = object_manager.add_object(GameObject::new()).unwrap();
+        let child = object_manager.add_object(GameObject::new()).unwrap();
+        let grandchild = object_manager.add_object(GameObject::new()).unwrap();
+        let other = object_manager.add_object(GameObject::new()).unwrap();
+        let keeper = object_manager.add_object(GameObject::new()).unwrap();
+        object_manager.add_child(parent, child).unwrap();
+        object_manager.add_child(child, grandchild).unwrap();
+
+        // The child is marked before its parent, the parent twice, and one id is gone
+        let removed = object_manager.destroy_objects_recursive(&[child, parent, other, parent, u32::MAX - 1]);
+        assert_eq!(removed, [child, grandchild, parent, other]);
+        assert_eq!(object_manager.get_keys().collect::<Vec<_>>(), [keeper]);
+        assert_eq!(object_manager.get_total_objects(), 1);
+    }
+
+    #[test]
+    fn test_removal_leaves_holes_until_compaction() {
+        let mut object_manager = ObjectManager::new();
+        let ids: Vec<u32> = (0..4)
+            .map(|_| object_manager.add_object(GameObject::new()).unwrap())
+            .collect();
+        let removed = object_manager.get_object_clone(ids[1]).unwrap();
+
+        // One hole in four slots stays open
+        object_manager.remove_object(ids[1]);
+        assert_eq!(object_manager.objects.len(), 4);
+        assert_eq!(object_manager.get_sorted_keys().collect::<Vec<_>>(), [ids[0], ids[2], ids[3]]);
+
+        // A re-added id goes to the back, listed once
+        object_manager.add_object(removed);
+        assert_eq!(object_manager.get_keys().collect::<Vec<_>>(), [ids[0], ids[2], ids[3], ids[1]]);
+        assert_eq!(object_manager.get_sorted_keys().collect::<Vec<_>>(), ids);
+
+        // Holes past half the slots are compacted, and lookups follow the moved objects
+        object_manager.remove_object(ids[0]);
+        object_manager.remove_object(ids[2]);
+        assert_eq!(object_manager.objects.len(), 2);
+        assert_eq!(object_manager.keys_sorted, [ids[1], ids[3]]);
+        assert_eq!(object_manager.get_object_by_id(ids[1]).unwrap().get_id(), ids[1]);
+        assert_eq!(object_manager.get_object_by_id(ids[3]).unwrap().get_id(), ids[3]);
+        assert_eq!(object_manager.get_total_objects(), 2);
     }
 }
@@ -47,6 +47,22 @@ impl TreeNode {
     }
 }
 
+/// Read-only copy of one tree node, for debugging and visualization
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AABBTreeNodeInfo {
+    /// Index of the node in the tree's node storage
+    pub index: usize,
+    /// Fattened bounds for leaves, bounds of both children for internal nodes
+    pub aabb: AABB,
+    /// The object stored in a leaf; None for internal nodes
+    pub object_id: Option<u32>,
+    pub parent: Option<usize>,
+    pub left: Option<usize>,
+    pub right: Option<usize>,
+    /// 0 for leaves, otherwise one more than the taller child
+    pub height: i32,
+}
+
 /// Dynamic AABB tree for efficient spatial partitioning
 #[derive(Debug)]
 pub struct AABBTree {
@@ -158,6 +174,37 @@ impl AABBTree {
         self.object_to_node.keys().copied().collect()
     }
 
+    /// Index of the root node, or None when the tree is empty
+    pub fn root_index(&self) -> Option<usize> {
+        (self.root != NULL_NODE).then_some(self.root)
+    }
+
+    /// Copy the nodes reachable from the root, each parent before its children
+    pub fn nodes(&self) -> Vec<AABBTreeNodeInfo> {
+        let link = |index: usize| (index != NULL_NODE).then_some(index);
+        let mut nodes = Vec::new();
+        let mut queue: std::collections::VecDeque<usize> = self.root_index().into_iter().collect();
+
+        while let Some(index) = queue.pop_front() {
+            let node = &self.nodes[index];
+            nodes.push(AABBTreeNodeInfo {
+                index,
+                aabb: node.aabb,
+                object_id: node.object_id,
+                parent: link(node.parent),
+                left: link(node.left),
+                right: link(node.right),
+                height: node.height,
+            });
+            if !node.is_leaf() {
+                queue.extend(link(node.left));
+                queue.extend(link(node.right));
+            }
+        }
+
+        nodes
+    }
+
     fn allocate_node(&mut self) -> usize {
         if let Some(index) = self.free_list.pop() {
             index
@@ -462,3 +509,32 @@ impl Default for AABBTree {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::vector::Vec2;
+
+    #[test]
+    fn test_nodes_skip_freed_slots() {
+        let mut tree = AABBTree::new();
+        assert_eq!(tree.root_index(), None);
+        assert!(tree.nodes().is_empty());
+
+        for id in 1..=3 {
+            let min = Vec2::new(id as f32 * 10.0, 0.0);
+            tree.insert(id, AABB::new(min, min.add(&Vec2::new(1.0, 1.0))));
+        }
+        tree.remove(2);
+
+        let nodes = tree.nodes();
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(Some(nodes[0].index), tree.root_index());
+        assert_eq!(nodes[0].parent, None);
+        assert_eq!(nodes[0].height, 1);
+        let mut leaves: Vec<u32> = nodes.iter().filter_map(|node| node.object_id).collect();
+        leaves.sort();
+        assert_eq!(leaves, vec![1, 3]);
+        assert!(nodes[1..].iter().all(|node| node.parent == tree.root_index()));
+    }
+}
@@ -20,6 +20,8 @@ pub struct ColliderComponent {
     layer: u32,
     collision_mask: u32,
     is_trigger: bool,
+    // Surface name for impact sounds, e.g. "metal"
+    material: Option<String>,
     enabled_self: bool,
     enabled_in_hierarchy: bool,
     // Cached AABB for broad-phase optimization
@@ -38,6 +40,7 @@ impl std::fmt::Debug for ColliderComponent {
             .field("shape", &self.shape)
             .field("layer", &self.layer)
             .field("is_trigger", &self.is_trigger)
+            .field("material", &self.material)
             .finish()
     }
 }
@@ -52,6 +55,7 @@ impl Clone for ColliderComponent {
             layer: self.layer,
             collision_mask: self.collision_mask,
             is_trigger: self.is_trigger,
+            material: self.material.clone(),
             enabled_self: self.enabled_self,
             enabled_in_hierarchy: self.enabled_in_hierarchy,
             // Clone the cached AABB by reading the lock
@@ -75,6 +79,7 @@ impl ComponentTrait for ColliderComponent {
             layer: 0,
             collision_mask: all(),
             is_trigger: false,
+            material: None,
             enabled_self: true,
             enabled_in_hierarchy: true,
             cached_aabb: RwLock::new(None),
@@ -195,6 +200,12 @@ impl ColliderComponent {
         self
     }
 
+    /// Set the surface material used to pick impact sounds
+    pub fn with_material(mut self, material: Option<String>) -> Self {
+        self.material = material;
+        self
+    }
+
     /// Get the collider shape
     pub fn shape(&self) -> &ColliderShape {
         &self.shape
@@ -247,6 +258,16 @@ impl ColliderComponent {
         self.is_trigger = is_trigger;
     }
 
+    /// Get the surface material
+    pub fn material(&self) -> Option<&str> {
+        self.material.as_deref()
+    }
+
+    /// Set the surface material
+    pub fn set_material(&mut self, material: Option<String>) {
+        self.material = material;
+    }
+
     /// Check if this collider should collide with another
     pub fn should_collide_with(&self, other: &ColliderComponent) -> bool {
         should_collide(self.layer, self.collision_mask, other.layer, other.collision_mask)
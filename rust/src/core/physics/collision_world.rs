@@ -7,7 +7,10 @@ use super::events::{CollisionEvent, CollisionEventType};
 use super::sat::SAT;
 use crate::core::component::ComponentTrait;
 use crate::core::object_manager::ObjectManager;
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
+
+/// Number of past collision events kept for debug snapshots
+const RECENT_EVENT_CAPACITY: usize = 256;
 
 /// Collision pair identifier (always ordered: smaller ID first)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -32,6 +35,10 @@ pub struct CollisionWorld {
 
     // Events to dispatch
     collision_events: Vec<CollisionEvent>,
+
+    // Events of the last few steps with the step they happened in, oldest first
+    recent_events: VecDeque<(u64, CollisionEvent)>,
+    step_count: u64,
 }
 
 impl CollisionWorld {
@@ -40,6 +47,8 @@ impl CollisionWorld {
             aabb_tree: AABBTree::new(),
             collision_pairs: HashSet::new(),
             collision_events: Vec::new(),
+            recent_events: VecDeque::with_capacity(RECENT_EVENT_CAPACITY),
+            step_count: 0,
         }
     }
 
@@ -47,6 +56,7 @@ impl CollisionWorld {
     pub fn step(&mut self, object_manager: &ObjectManager) {
         // Clear previous frame's events
         self.collision_events.clear();
+        self.step_count += 1;
 
         // 1. Update broad-phase (sync AABB tree with transforms)
         self.update_broadphase(object_manager);
@@ -136,9 +146,46 @@ impl CollisionWorld {
 
         // Update collision pairs for next frame
         self.collision_pairs = new_collision_pairs;
+        self.record_recent_events(0);
 
         // 5. Dispatch collision callbacks to components
-        self.dispatch_collision_callbacks(object_manager);
+        Self::dispatch_collision_callbacks(&self.collision_events, object_manager);
+    }
+
+    /// Drop objects that are about to be destroyed.
+    ///
+    /// Their colliders leave the broad-phase and every pair they are part of
+    /// ends with a final exit event, dispatched to Rust components while the
+    /// objects still exist and appended to this step's `collision_events`.
+    /// Call this before the objects are removed from the `ObjectManager`.
+    ///
+    /// Returns the exit events. Script hooks are not run here; pass them to
+    /// `script::queue_collision_calls`, as `Engine::remove_game_object` does,
+    /// so scripts get `on_collision_exit`.
+    pub fn remove_objects(&mut self, object_manager: &ObjectManager, object_ids: &[u32]) -> &[CollisionEvent] {
+        for &object_id in object_ids {
+            self.aabb_tree.remove(object_id);
+        }
+
+        let mut ended: Vec<CollisionPair> = self
+            .collision_pairs
+            .iter()
+            .filter(|pair| object_ids.contains(&pair.0) || object_ids.contains(&pair.1))
+            .copied()
+            .collect();
+        if ended.is_empty() {
+            return &[];
+        }
+        ended.sort_by_key(|pair| (pair.0, pair.1));
+
+        let first_exit = self.collision_events.len();
+        for pair in ended {
+            self.collision_pairs.remove(&pair);
+            self.collision_events.push(CollisionEvent::exit(pair.0, pair.1));
+        }
+        self.record_recent_events(first_exit);
+        Self::dispatch_collision_callbacks(&self.collision_events[first_exit..], object_manager);
+        &self.collision_events[first_exit..]
     }
 
     /// Get collision events from the last step
@@ -146,12 +193,36 @@ impl CollisionWorld {
         &self.collision_events
     }
 
+    /// Get the broad-phase tree
+    pub fn aabb_tree(&self) -> &AABBTree {
+        &self.aabb_tree
+    }
+
+    /// Number of steps run so far
+    pub fn step_count(&self) -> u64 {
+        self.step_count
+    }
+
+    /// Get the last collision events, oldest first, each with the step it happened in
+    pub fn recent_collision_events(&self) -> impl Iterator<Item = (u64, &CollisionEvent)> {
+        self.recent_events.iter().map(|(step, event)| (*step, event))
+    }
+
+    fn record_recent_events(&mut self, first: usize) {
+        for event in &self.collision_events[first..] {
+            if self.recent_events.len() == RECENT_EVENT_CAPACITY {
+                self.recent_events.pop_front();
+            }
+            self.recent_events.push_back((self.step_count, event.clone()));
+        }
+    }
+
     fn update_broadphase(&mut self, object_manager: &ObjectManager) {
         // Get all objects with colliders
         let all_objects = object_manager.get_keys();
         let mut tracked_objects = HashSet::new();
 
-        for &object_id in all_objects {
+        for object_id in all_objects {
             if let Some(obj) = object_manager.get_object_by_id(object_id) {
                 if !obj.is_enabled() {
                     continue;
@@ -228,11 +299,11 @@ impl CollisionWorld {
         pairs
     }
 
-    fn dispatch_collision_callbacks(&self, object_manager: &ObjectManager) {
+    fn dispatch_collision_callbacks(events: &[CollisionEvent], object_manager: &ObjectManager) {
         use crate::core::component::ComponentTrait;
 
         // Dispatch callbacks for all collision events
-        for event in &self.collision_events {
+        for event in events {
             let obj_a = object_manager.get_object_by_id(event.object_id_a);
             let obj_b = object_manager.get_object_by_id(event.object_id_b);
 
@@ -292,3 +363,42 @@ impl Default for CollisionWorld {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game_object::GameObject;
+    use crate::core::physics::ColliderShape;
+    use crate::types::vector::Vec2;
+
+    fn add_circle(object_manager: &mut ObjectManager, x: f32) -> u32 {
+        let mut object = GameObject::new_named("Ball".to_string());
+        object.set_position(Vec2::new(x, 0.0));
+        object.add_component(Box::new(
+            ColliderComponent::new("Collider").with_shape(ColliderShape::circle(1.0)),
+        ));
+        object_manager.add_object(object).unwrap()
+    }
+
+    #[test]
+    fn test_removed_objects_end_their_collisions() {
+        let mut object_manager = ObjectManager::new();
+        let a = add_circle(&mut object_manager, 0.0);
+        let b = add_circle(&mut object_manager, 1.0);
+        let c = add_circle(&mut object_manager, 10.0);
+        let mut collision_world = CollisionWorld::new();
+        collision_world.step(&object_manager);
+        assert_eq!(collision_world.collision_events().len(), 1);
+
+        collision_world.remove_objects(&object_manager, &[b]);
+        let exit = collision_world.collision_events().last().unwrap();
+        assert_eq!(exit.event_type, CollisionEventType::Exit);
+        assert_eq!((exit.object_id_a, exit.object_id_b), (a.min(b), a.max(b)));
+        assert!(!collision_world.aabb_tree().contains(b));
+        assert!(collision_world.aabb_tree().contains(c));
+
+        object_manager.remove_object(b);
+        collision_world.step(&object_manager);
+        assert!(collision_world.collision_events().is_empty(), "the exit is not repeated");
+    }
+}
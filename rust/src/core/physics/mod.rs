@@ -13,7 +13,7 @@ pub mod collision_world;
 // Re-export commonly used types
 pub use shapes::{ColliderShape, AABB};
 pub use collider::ColliderComponent;
-pub use aabb_tree::AABBTree;
+pub use aabb_tree::{AABBTree, AABBTreeNodeInfo};
 pub use sat::{SAT, CollisionManifold};
 pub use layers::PhysicsLayers;
 pub use events::{CollisionEvent, CollisionEventType};
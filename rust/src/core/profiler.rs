@@ -0,0 +1,418 @@
+//! Per-frame timings of the engine's update and render phases.
+//!
+//! The engine times each phase of a frame into a named `ProfileScope` and
+//! keeps the totals of the last completed frame as `FrameStats`. A frame runs
+//! from one `update_frame` to the next, so it covers the update, the fixed
+//! steps and the render in between. Scopes never nest, which makes their sum
+//! the measured work of the frame; the rest of `frame_ms` is time spent
+//! waiting (vsync, frame cap) or in host code outside the engine.
+//!
+//! # Spike Detection
+//!
+//! The profiler flags two kinds of slow frames:
+//!
+//! - With a budget set, frames whose measured work exceeds it.
+//! - With a spike factor set, frames whose measured work exceeds that many
+//!   times the running average, which catches hitches in games that are
+//!   well under budget most of the time.
+//!
+//! A flagged frame is logged as a warning naming the scope that took the
+//! longest, followed by the full breakdown. Warnings are limited to one per
+//! `SPIKE_LOG_INTERVAL`; the next one says how many flagged frames went
+//! unlogged in between, and `spike_count` counts them all.
+
+use super::logging;
+use std::time::{Duration, Instant};
+
+/// Minimum time between two slow-frame warnings.
+pub const SPIKE_LOG_INTERVAL: Duration = Duration::from_secs(1);
+/// Frames averaged before spike detection starts.
+const SPIKE_WARMUP_FRAMES: u64 = 30;
+/// Frames with less work than this are never spikes, however small the average.
+const SPIKE_MIN_MS: f32 = 2.0;
+/// Weight of the newest frame in the running average.
+const AVERAGE_WEIGHT: f32 = 0.05;
+
+/// A named phase of the frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProfileScope {
+    /// Time tick and input polling
+    Input,
+    /// UI input, tweens and layout
+    Ui,
+    /// Commands and the per-frame component update
+    Update,
+    /// Fixed-timestep component updates
+    Fixed,
+    /// Collision detection
+    Physics,
+    /// Script hooks and event handlers
+    Scripts,
+    /// UI draw commands and the GPU frame
+    Render,
+}
+
+impl ProfileScope {
+    /// Every scope, in frame order.
+    pub const ALL: [ProfileScope; 7] = [
+        ProfileScope::Input,
+        ProfileScope::Ui,
+        ProfileScope::Update,
+        ProfileScope::Fixed,
+        ProfileScope::Physics,
+        ProfileScope::Scripts,
+        ProfileScope::Render,
+    ];
+
+    /// Lowercase name used in logs and Python.
+    pub fn name(self) -> &'static str {
+        match self {
+            ProfileScope::Input => "input",
+            ProfileScope::Ui => "ui",
+            ProfileScope::Update => "update",
+            ProfileScope::Fixed => "fixed",
+            ProfileScope::Physics => "physics",
+            ProfileScope::Scripts => "scripts",
+            ProfileScope::Render => "render",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Timings of one completed frame, in milliseconds.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FrameStats {
+    /// Number of the frame, counting from 1; 0 until a frame completes
+    pub frame: u64,
+    /// Wall-clock time from the start of this frame to the start of the next
+    pub frame_ms: f32,
+    /// Whether the frame went over budget or was a spike
+    pub slow: bool,
+    scopes_ms: [f32; ProfileScope::ALL.len()],
+}
+
+impl FrameStats {
+    /// Time spent in one scope.
+    pub fn scope_ms(&self, scope: ProfileScope) -> f32 {
+        self.scopes_ms[scope.index()]
+    }
+
+    /// Measured work of the frame: the sum of every scope.
+    pub fn total_ms(&self) -> f32 {
+        self.scopes_ms.iter().sum()
+    }
+
+    /// Scope names with their time, in frame order.
+    pub fn scopes(&self) -> impl Iterator<Item = (&'static str, f32)> + '_ {
+        ProfileScope::ALL
+            .iter()
+            .map(|scope| (scope.name(), self.scope_ms(*scope)))
+    }
+
+    /// The scope that took the longest.
+    pub fn slowest_scope(&self) -> ProfileScope {
+        ProfileScope::ALL
+            .into_iter()
+            .max_by(|a, b| self.scope_ms(*a).total_cmp(&self.scope_ms(*b)))
+            .unwrap_or(ProfileScope::Update)
+    }
+}
+
+/// Accumulates scope timings for the current frame.
+///
+/// Time a scope with `start` and `record`; both do nothing while the profiler
+/// is disabled, so instrumented code needs no checks of its own.
+pub struct FrameProfiler {
+    enabled: bool,
+    /// Log frames whose measured work exceeds this many milliseconds
+    budget_ms: Option<f32>,
+    /// Log frames whose measured work exceeds this multiple of the average
+    spike_factor: Option<f32>,
+    /// Running average of the measured work per frame
+    average_ms: f32,
+    spike_count: u64,
+    /// Slow frames since the last warning that were not logged
+    unlogged_spikes: u64,
+    last_warning: Option<Instant>,
+    frame_start: Option<Instant>,
+    frame_count: u64,
+    current: [Duration; ProfileScope::ALL.len()],
+    last: FrameStats,
+}
+
+impl FrameProfiler {
+    /// Creates an enabled profiler without a budget.
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            budget_ms: None,
+            spike_factor: None,
+            average_ms: 0.0,
+            spike_count: 0,
+            unlogged_spikes: 0,
+            last_warning: None,
+            frame_start: None,
+            frame_count: 0,
+            current: [Duration::ZERO; ProfileScope::ALL.len()],
+            last: FrameStats::default(),
+        }
+    }
+
+    /// Turn timing on or off. Disabling clears the recorded stats.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.frame_start = None;
+            self.current = [Duration::ZERO; ProfileScope::ALL.len()];
+            self.last = FrameStats::default();
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Set the work budget per frame.
+    /// @param budget_ms: Milliseconds, or `None`/non-positive to stop logging slow frames.
+    pub fn set_budget_ms(&mut self, budget_ms: Option<f32>) {
+        self.budget_ms = budget_ms.filter(|budget| budget.is_finite() && *budget > 0.0);
+    }
+
+    pub fn budget_ms(&self) -> Option<f32> {
+        self.budget_ms
+    }
+
+    /// Flag frames with more than `factor` times the average work.
+    /// @param factor: Multiple of the average above 1, or `None` to stop.
+    pub fn set_spike_factor(&mut self, factor: Option<f32>) {
+        self.spike_factor = factor.filter(|factor| factor.is_finite() && *factor > 1.0);
+    }
+
+    pub fn spike_factor(&self) -> Option<f32> {
+        self.spike_factor
+    }
+
+    /// Number of frames flagged as slow so far, logged or not.
+    pub fn spike_count(&self) -> u64 {
+        self.spike_count
+    }
+
+    /// Start timing a scope.
+    /// @return: The start instant to pass to `record`, or `None` while disabled.
+    pub fn start(&self) -> Option<Instant> {
+        self.enabled.then(Instant::now)
+    }
+
+    /// Add the time since `started` to a scope of the current frame.
+    pub fn record(&mut self, scope: ProfileScope, started: Option<Instant>) {
+        if let Some(started) = started
+            && self.enabled
+        {
+            self.current[scope.index()] += started.elapsed();
+        }
+    }
+
+    /// Add time measured elsewhere to a scope of the current frame.
+    pub fn add(&mut self, scope: ProfileScope, duration: Duration) {
+        if self.enabled {
+            self.current[scope.index()] += duration;
+        }
+    }
+
+    /// Close the current frame, if any, and start the next one.
+    pub fn begin_frame(&mut self) {
+        if !self.enabled {
+            return;
+        }
+
+        let now = Instant::now();
+        if let Some(frame_start) = self.frame_start {
+            self.frame_count += 1;
+            let mut stats = FrameStats {
+                frame: self.frame_count,
+                frame_ms: millis(now - frame_start),
+                ..FrameStats::default()
+            };
+            for (stat, duration) in stats.scopes_ms.iter_mut().zip(&self.current) {
+                *stat = millis(*duration);
+            }
+            self.finish_frame(stats, now);
+        }
+        self.frame_start = Some(now);
+        self.current = [Duration::ZERO; ProfileScope::ALL.len()];
+    }
+
+    /// Timings of the last completed frame.
+    pub fn last_frame(&self) -> FrameStats {
+        self.last
+    }
+
+    /// Flag and log a slow frame, then fold it into the running average.
+    fn finish_frame(&mut self, mut stats: FrameStats, now: Instant) {
+        let total_ms = stats.total_ms();
+        let over_budget = self.budget_ms.is_some_and(|budget_ms| total_ms > budget_ms);
+        let spike = self.spike_factor.is_some_and(|factor| {
+            stats.frame > SPIKE_WARMUP_FRAMES
+                && total_ms >= SPIKE_MIN_MS
+                && total_ms > self.average_ms * factor
+        });
+        stats.slow = over_budget || spike;
+        self.last = stats;
+
+        if stats.slow {
+            self.spike_count += 1;
+            let due = self
+                .last_warning
+                .is_none_or(|last| now.duration_since(last) >= SPIKE_LOG_INTERVAL);
+            if due {
+                self.log_slow_frame(over_budget);
+                self.last_warning = Some(now);
+                self.unlogged_spikes = 0;
+            } else {
+                self.unlogged_spikes += 1;
+            }
+        }
+
+        // Spikes stay out of the average so one hitch does not hide the next.
+        if !spike {
+            self.average_ms = if stats.frame == 1 {
+                total_ms
+            } else {
+                self.average_ms + (total_ms - self.average_ms) * AVERAGE_WEIGHT
+            };
+        }
+    }
+
+    fn log_slow_frame(&self, over_budget: bool) {
+        let stats = &self.last;
+        let total_ms = stats.total_ms();
+        let reason = match self.budget_ms {
+            Some(budget_ms) if over_budget => {
+                format!("over budget: {total_ms:.2} ms of {budget_ms:.2} ms")
+            }
+            _ => format!(
+                "spike: {total_ms:.2} ms against an average of {:.2} ms",
+                self.average_ms
+            ),
+        };
+        let slowest = stats.slowest_scope();
+        let breakdown: Vec<String> = stats
+            .scopes()
+            .filter(|(_, ms)| *ms >= 0.01)
+            .map(|(name, ms)| format!("{name} {ms:.2} ms"))
+            .collect();
+        let mut message = format!(
+            "Frame {} {reason}, mostly {} ({:.2} ms; {}; frame took {:.2} ms)",
+            stats.frame,
+            slowest.name(),
+            stats.scope_ms(slowest),
+            breakdown.join(", "),
+            stats.frame_ms
+        );
+        if self.unlogged_spikes > 0 {
+            message.push_str(&format!(
+                ". {} more slow frames since the last warning",
+                self.unlogged_spikes
+            ));
+        }
+        logging::log_warn(&message);
+    }
+}
+
+impl Default for FrameProfiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn millis(duration: Duration) -> f32 {
+    duration.as_secs_f32() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frames_close_with_their_scope_times() {
+        let mut profiler = FrameProfiler::new();
+        profiler.begin_frame();
+        assert_eq!(profiler.last_frame().frame, 0);
+
+        let started = Some(Instant::now() - Duration::from_millis(4));
+        profiler.record(ProfileScope::Update, started);
+        profiler.record(ProfileScope::Update, started);
+        profiler.begin_frame();
+
+        let stats = profiler.last_frame();
+        assert_eq!(stats.frame, 1);
+        assert!(stats.scope_ms(ProfileScope::Update) >= 8.0);
+        assert_eq!(stats.scope_ms(ProfileScope::Render), 0.0);
+        assert_eq!(stats.total_ms(), stats.scope_ms(ProfileScope::Update));
+        assert_eq!(stats.scopes().count(), ProfileScope::ALL.len());
+
+        profiler.set_enabled(false);
+        assert_eq!(profiler.start(), None);
+        profiler.begin_frame();
+        assert_eq!(profiler.last_frame(), FrameStats::default());
+    }
+
+    fn frame(profiler: &mut FrameProfiler, update_ms: u64, now: Instant) {
+        profiler.add(ProfileScope::Update, Duration::from_millis(update_ms));
+        let mut stats = FrameStats {
+            frame: profiler.frame_count + 1,
+            ..FrameStats::default()
+        };
+        profiler.frame_count += 1;
+        for (stat, duration) in stats.scopes_ms.iter_mut().zip(&profiler.current) {
+            *stat = millis(*duration);
+        }
+        profiler.current = [Duration::ZERO; ProfileScope::ALL.len()];
+        profiler.finish_frame(stats, now);
+    }
+
+    #[test]
+    fn test_spikes_are_flagged_against_the_average() {
+        let mut profiler = FrameProfiler::new();
+        profiler.set_spike_factor(Some(3.0));
+        let now = Instant::now();
+        for _ in 0..SPIKE_WARMUP_FRAMES + 5 {
+            frame(&mut profiler, 4, now);
+        }
+        assert_eq!(profiler.spike_count(), 0);
+
+        frame(&mut profiler, 20, now);
+        assert!(profiler.last_frame().slow);
+        assert_eq!(profiler.last_frame().slowest_scope(), ProfileScope::Update);
+        // Rate limited, but still counted, and kept out of the average.
+        frame(&mut profiler, 20, now);
+        assert_eq!(profiler.spike_count(), 2);
+        assert_eq!(profiler.unlogged_spikes, 1);
+        assert!((profiler.average_ms - 4.0).abs() < 0.01);
+
+        frame(&mut profiler, 5, now);
+        assert!(!profiler.last_frame().slow);
+
+        profiler.set_spike_factor(None);
+        profiler.set_budget_ms(Some(10.0));
+        frame(&mut profiler, 20, now + SPIKE_LOG_INTERVAL);
+        assert!(profiler.last_frame().slow);
+        assert_eq!(profiler.unlogged_spikes, 0);
+    }
+
+    #[test]
+    fn test_budget_ignores_non_positive_values() {
+        let mut profiler = FrameProfiler::new();
+        profiler.set_budget_ms(Some(16.0));
+        assert_eq!(profiler.budget_ms(), Some(16.0));
+        profiler.set_budget_ms(Some(0.0));
+        assert_eq!(profiler.budget_ms(), None);
+        profiler.set_budget_ms(Some(f32::NAN));
+        assert_eq!(profiler.budget_ms(), None);
+        profiler.set_spike_factor(Some(1.0));
+        assert_eq!(profiler.spike_factor(), None);
+    }
+}
@@ -3,24 +3,24 @@ use std::cmp::Ordering;
 use std::collections::{HashMap, hash_map::DefaultHasher};
 use std::f32::consts::TAU;
 use std::hash::{Hash, Hasher};
-use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 use font8x8::{BASIC_FONTS, UnicodeFonts};
 use fontdue::Font;
-use image::GenericImageView;
 use wgpu::{Device, PresentMode, Queue, Surface, SurfaceConfiguration, TextureUsages};
 use winit::dpi::PhysicalSize;
 use winit::window::Window;
 
+use super::asset_manager::{AssetHandle, AssetKind, AssetManager};
+use super::capabilities::RendererCapabilities;
 use super::geometry::Vertex;
 use super::logging;
 use super::text::{
     FontDescriptor, FontFamilyDefinition, TextAlign, TextLayoutOptions, TextStyle,
-    VerticalTextAlign, normalize_font_family_key, normalize_font_path,
+    VerticalTextAlign, normalize_font_family_key, normalize_font_path, wrap_text,
 };
 use crate::core::component::ComponentTrait;
-use crate::core::draw_manager::{DrawCommand, DrawManager};
+use crate::core::draw_manager::{ClipRect, DrawCommand, DrawManager};
 use crate::core::object_manager::ObjectManager;
 use crate::types::Color;
 use crate::types::vector::Vec2;
@@ -43,12 +43,56 @@ struct CachedTextureEntry {
 struct DrawItem {
     draw_order: f32,
     texture_path: Option<String>,
+    /// Screen-space scissor rect; `None` draws unclipped
+    clip: Option<ClipRect>,
     vertices: Vec<Vertex>,
     indices: Vec<u32>,
 }
 
+/// Batching statistics for the last rendered frame.
+///
+/// Consecutive draw items that share a texture and clip rect are merged into
+/// one draw call, so `draw_items / draw_calls` shows how well sprites batch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    /// Meshes, text and draw commands submitted
+    pub draw_items: usize,
+    /// GPU draw calls after batching
+    pub draw_calls: usize,
+    /// Draw calls that bind a different texture than the call before them
+    pub texture_switches: usize,
+}
+
+impl RenderStats {
+    /// Average number of draw items merged into each draw call.
+    pub fn items_per_draw_call(&self) -> f32 {
+        if self.draw_calls == 0 {
+            0.0
+        } else {
+            self.draw_items as f32 / self.draw_calls as f32
+        }
+    }
+
+    /// Count the batches `RenderManager::prepare_draws` makes from `items`.
+    fn record(&mut self, items: &[DrawItem]) {
+        let mut batch: Option<(Option<&str>, Option<ClipRect>)> = None;
+        for item in items {
+            let state = (item.texture_path.as_deref(), item.clip);
+            if batch != Some(state) {
+                if batch.is_none_or(|(texture, _)| texture != state.0) {
+                    self.texture_switches += 1;
+                }
+                self.draw_calls += 1;
+                batch = Some(state);
+            }
+        }
+        self.draw_items += items.len();
+    }
+}
+
 struct PreparedDraw {
     bind_group: wgpu::BindGroup,
+    clip: Option<ClipRect>,
     vertex_buffer: wgpu::Buffer,
     index_buffer: wgpu::Buffer,
     index_count: u32,
@@ -74,6 +118,13 @@ struct GlyphCacheKey {
     font_size_bits: u32,
 }
 
+/// A loadable font in a text style's fallback chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ResolvedFont {
+    path: String,
+    cache_key: String,
+}
+
 #[derive(Clone)]
 struct CachedGlyph {
     metrics: fontdue::Metrics,
@@ -89,6 +140,7 @@ struct TextLayoutCacheKey {
     letter_spacing_bits: u32,
     line_spacing_bits: u32,
     kerning: bool,
+    line_align: TextAlign,
 }
 
 #[derive(Clone)]
@@ -110,10 +162,25 @@ struct PooledBuffer {
     capacity_bytes: usize,
 }
 
+/// Offscreen target used by pixel-perfect camera mode.
+///
+/// World-space geometry renders here at a fixed virtual resolution, then the
+/// result is integer-upscaled onto the surface with nearest-neighbor sampling.
+struct PixelPerfectTarget {
+    width: u32,
+    height: u32,
+    _texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    _sampler: wgpu::Sampler,
+    bind_group: wgpu::BindGroup,
+}
+
 const MIN_POOL_BUFFER_BYTES: usize = 256;
 // Built-in default font comes from the `font8x8` crate (MIT/Apache-2.0).
 const DEFAULT_FONT_NAME: &str = "font8x8-basic";
 const DEFAULT_GLYPH_PIXEL_SIZE: f32 = 8.0;
+// Reserved texture key that resolves to the pixel-perfect offscreen target.
+const PIXEL_PERFECT_TEXTURE_KEY: &str = "__pyg_pixel_perfect_target";
 
 fn hash_f32<H: Hasher>(hasher: &mut H, value: f32) {
     value.to_bits().hash(hasher);
@@ -168,6 +235,8 @@ pub struct RenderManager {
     surface: Surface<'static>,
     surface_config: SurfaceConfiguration,
     surface_present_modes: Vec<PresentMode>,
+    /// Adapter and surface support, queried once at startup
+    capabilities: RendererCapabilities,
     vsync_enabled: bool,
     background_color: Color,
     redraw_on_change_only: bool,
@@ -186,7 +255,12 @@ pub struct RenderManager {
     texture_cache: HashMap<String, Option<CachedTextureEntry>>,
     texture_data_signature_cache: HashMap<String, u64>,
     font_registry: HashMap<String, FontFamilyDefinition>,
-    font_cache: HashMap<String, Option<Font>>,
+    /// Fallback fonts for text styles that don't list their own
+    default_font_fallbacks: Vec<String>,
+    /// Fonts in use, shared with the asset manager
+    font_cache: HashMap<String, Option<Arc<Font>>>,
+    /// References this renderer holds on loaded fonts
+    font_handles: Vec<AssetHandle>,
     glyph_cache: HashMap<GlyphCacheKey, Option<CachedGlyph>>,
     layout_cache: HashMap<TextLayoutCacheKey, CachedTextLayout>,
     vertex_buffer_pool: Vec<PooledBuffer>,
@@ -194,21 +268,95 @@ pub struct RenderManager {
     active_camera_object_id: Option<u32>,
     camera_viewport_size: Option<Vec2>,
     camera_aspect_mode: CameraAspectMode,
-    source_root: Option<PathBuf>,
+    pixel_perfect_target: Option<PixelPerfectTarget>,
+    /// Decodes texture and font files and resolves their paths
+    assets: Arc<RwLock<AssetManager>>,
     current_frame: u64,
     texture_ttl_frames: u64,
+    texture_budget_bytes: Option<u64>,
+    /// Group world sprites by texture within equal draw orders
+    sort_by_texture: bool,
+    render_stats: RenderStats,
+}
+
+impl Drop for RenderManager {
+    fn drop(&mut self) {
+        // Fonts stay loaded in the shared asset manager otherwise.
+        self.release_font_handles();
+    }
 }
 
 impl RenderManager {
+    /// Get a GPU adapter and device, falling back to safe mode when the
+    /// preferred hardware adapter is missing or cannot create a device.
+    ///
+    /// Safe mode uses wgpu's software fallback adapter (WARP, llvmpipe,
+    /// SwiftShader, ...) with downlevel limits, so old drivers and VMs still
+    /// get a working, if slow, renderer. The returned flag is true in safe mode.
+    async fn request_device(
+        instance: &wgpu::Instance,
+        surface: &Surface<'static>,
+    ) -> Result<(wgpu::Adapter, Device, Queue, bool), Box<dyn std::error::Error>> {
+        let hardware_error = match instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                compatible_surface: Some(surface),
+                force_fallback_adapter: false,
+            })
+            .await
+        {
+            Ok(adapter) => match Self::open_device(&adapter, wgpu::Limits::default()).await {
+                Ok((device, queue)) => return Ok((adapter, device, queue, false)),
+                Err(err) => format!("device creation failed on '{}': {err}", adapter.get_info().name),
+            },
+            Err(err) => format!("no suitable GPU adapter: {err}"),
+        };
+        logging::log_warn(&format!(
+            "GPU renderer unavailable ({hardware_error}); falling back to the safe-mode software renderer"
+        ));
+
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::LowPower,
+                compatible_surface: Some(surface),
+                force_fallback_adapter: true,
+            })
+            .await
+            .map_err(|err| format!("{hardware_error}; no software fallback adapter: {err}"))?;
+        let limits = wgpu::Limits::downlevel_webgl2_defaults().using_resolution(adapter.limits());
+        let (device, queue) = Self::open_device(&adapter, limits)
+            .await
+            .map_err(|err| format!("{hardware_error}; software fallback device failed: {err}"))?;
+        Ok((adapter, device, queue, true))
+    }
+
+    async fn open_device(
+        adapter: &wgpu::Adapter,
+        required_limits: wgpu::Limits,
+    ) -> Result<(Device, Queue), wgpu::RequestDeviceError> {
+        adapter
+            .request_device(&wgpu::DeviceDescriptor {
+                label: Some("PyG Engine Device"),
+                required_features: wgpu::Features::empty(),
+                required_limits,
+                memory_hints: Default::default(),
+                experimental_features: Default::default(),
+                trace: Default::default(),
+            })
+            .await
+    }
+
     /// Create a new RenderManager with the given window reference.
     ///
     /// This is an async function because it needs to request a GPU adapter
-    /// and create a device, which are async operations.
+    /// and create a device, which are async operations. If no hardware
+    /// adapter works, it falls back to a software adapter (safe mode).
     pub async fn new(
         window: Arc<Window>,
         background_color: Option<Color>,
         vsync: bool,
         redraw_on_change_only: bool,
+        assets: Arc<RwLock<AssetManager>>,
     ) -> Result<Self, Box<dyn std::error::Error>> {
         let size = window.inner_size();
 
@@ -224,14 +372,7 @@ impl RenderManager {
         // which will be dropped when the engine shuts down.
         let surface = instance.create_surface(Arc::clone(&window))?;
 
-        // Request an adapter (GPU handle).
-        let adapter = instance
-            .request_adapter(&wgpu::RequestAdapterOptions {
-                power_preference: wgpu::PowerPreference::HighPerformance,
-                compatible_surface: Some(&surface),
-                force_fallback_adapter: false,
-            })
-            .await?;
+        let (adapter, device, queue, safe_mode) = Self::request_device(&instance, &surface).await?;
 
         // Log graphics backend information.
         let adapter_info = adapter.get_info();
@@ -240,18 +381,6 @@ impl RenderManager {
             adapter_info.backend, adapter_info.driver_info, adapter_info.name
         ));
 
-        // Request a device and command queue.
-        let (device, queue) = adapter
-            .request_device(&wgpu::DeviceDescriptor {
-                label: Some("PyG Engine Device"),
-                required_features: wgpu::Features::empty(),
-                required_limits: wgpu::Limits::default(),
-                memory_hints: Default::default(),
-                experimental_features: Default::default(),
-                trace: Default::default(),
-            })
-            .await?;
-
         // Get surface capabilities.
         let surface_caps = surface.get_capabilities(&adapter);
 
@@ -295,6 +424,9 @@ impl RenderManager {
         };
 
         surface.configure(&device, &surface_config);
+        let mut capabilities =
+            RendererCapabilities::query(&adapter, &device, &surface_caps, surface_format, present_mode);
+        capabilities.safe_mode = safe_mode;
 
         let texture_bind_group_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -381,6 +513,7 @@ impl RenderManager {
             surface,
             surface_config,
             surface_present_modes: surface_caps.present_modes,
+            capabilities,
             vsync_enabled: vsync,
             background_color: background_color.unwrap_or(Color::BLACK),
             redraw_on_change_only,
@@ -396,7 +529,9 @@ impl RenderManager {
             texture_cache: HashMap::new(),
             texture_data_signature_cache: HashMap::new(),
             font_registry: HashMap::new(),
+            default_font_fallbacks: Vec::new(),
             font_cache: HashMap::new(),
+            font_handles: Vec::new(),
             glyph_cache: HashMap::new(),
             layout_cache: HashMap::new(),
             vertex_buffer_pool: Vec::new(),
@@ -404,9 +539,13 @@ impl RenderManager {
             active_camera_object_id: None,
             camera_viewport_size: None,
             camera_aspect_mode: CameraAspectMode::default(),
-            source_root: None,
+            pixel_perfect_target: None,
+            assets,
             current_frame: 0,
             texture_ttl_frames: 180, // Clean up textures unused for 180 frames (~3 seconds at 60fps)
+            texture_budget_bytes: None,
+            sort_by_texture: true,
+            render_stats: RenderStats::default(),
         })
     }
 
@@ -486,13 +625,13 @@ impl RenderManager {
 
     fn load_texture_from_path(&self, texture_path: &str) -> Result<CachedTexture, String> {
         let resolved_path = self.resolve_source_path(texture_path);
-        let bytes = std::fs::read(&resolved_path)
-            .map_err(|e| format!("failed to read texture '{}': {e}", resolved_path))?;
-
-        let img = image::load_from_memory(&bytes)
-            .map_err(|e| format!("failed to decode texture '{}': {e}", resolved_path))?;
-        let rgba = img.to_rgba8();
-        let (width, height) = img.dimensions();
+        // Reuses the pixels of a texture loaded through the asset manager.
+        let rgba = self
+            .assets
+            .write()
+            .map_err(|_| "asset manager lock poisoned".to_string())?
+            .texture_pixels(&resolved_path)?;
+        let (width, height) = rgba.dimensions();
 
         Ok(Self::create_cached_texture(
             &self.device,
@@ -660,6 +799,12 @@ impl RenderManager {
     }
 
     fn texture_bind_group_for(&mut self, texture_path: Option<&str>) -> wgpu::BindGroup {
+        if texture_path == Some(PIXEL_PERFECT_TEXTURE_KEY)
+            && let Some(target) = &self.pixel_perfect_target
+        {
+            return target.bind_group.clone();
+        }
+
         if let Some(path) = texture_path {
             if let Some(Some(entry)) = self.texture_cache.get_mut(path) {
                 entry.last_used_frame = self.current_frame;
@@ -694,16 +839,27 @@ impl RenderManager {
         [color.r(), color.g(), color.b(), color.a()]
     }
 
+    /// Size in pixels of the target world-space geometry is rendered into.
+    ///
+    /// This is the virtual resolution in pixel-perfect mode, otherwise the surface.
+    fn world_target_size(&self) -> (f32, f32) {
+        if let Some(target) = &self.pixel_perfect_target {
+            return (target.width.max(1) as f32, target.height.max(1) as f32);
+        }
+        (
+            self.surface_config.width.max(1) as f32,
+            self.surface_config.height.max(1) as f32,
+        )
+    }
+
     fn default_camera_viewport_size(&self) -> Vec2 {
-        let width = self.surface_config.width.max(1) as f32;
-        let height = self.surface_config.height.max(1) as f32;
+        let (width, height) = self.world_target_size();
         let aspect = width / height;
         Vec2::new(2.0 * aspect, 2.0)
     }
 
     fn display_aspect_ratio(&self) -> f32 {
-        let width = self.surface_config.width.max(1) as f32;
-        let height = self.surface_config.height.max(1) as f32;
+        let (width, height) = self.world_target_size();
         width / height
     }
 
@@ -816,19 +972,30 @@ impl RenderManager {
     }
 
     pub fn world_to_screen(&self, world_position: Vec2, camera_position: Vec2) -> (f32, f32) {
+        let camera_position = self.snap_camera_to_pixel_grid(camera_position);
         let clip = self.world_to_clip(world_position.x(), world_position.y(), camera_position);
-        let width = self.surface_config.width.max(1) as f32;
-        let height = self.surface_config.height.max(1) as f32;
-        let screen_x = (clip[0] + 1.0) * 0.5 * width;
-        let screen_y = (1.0 - clip[1]) * 0.5 * height;
-        (screen_x, screen_y)
+        let (width, height) = self.world_target_size();
+        let target_x = (clip[0] + 1.0) * 0.5 * width;
+        let target_y = (1.0 - clip[1]) * 0.5 * height;
+        match self.pixel_perfect_layout() {
+            Some((scale, offset_x, offset_y)) => {
+                (offset_x + target_x * scale, offset_y + target_y * scale)
+            }
+            None => (target_x, target_y),
+        }
     }
 
     pub fn screen_to_world(&self, screen_x: f32, screen_y: f32, camera_position: Vec2) -> Vec2 {
-        let width = self.surface_config.width.max(1) as f32;
-        let height = self.surface_config.height.max(1) as f32;
-        let clip_x = (screen_x / width) * 2.0 - 1.0;
-        let clip_y = 1.0 - (screen_y / height) * 2.0;
+        let camera_position = self.snap_camera_to_pixel_grid(camera_position);
+        let (target_x, target_y) = match self.pixel_perfect_layout() {
+            Some((scale, offset_x, offset_y)) => {
+                ((screen_x - offset_x) / scale, (screen_y - offset_y) / scale)
+            }
+            None => (screen_x, screen_y),
+        };
+        let (width, height) = self.world_target_size();
+        let clip_x = (target_x / width) * 2.0 - 1.0;
+        let clip_y = 1.0 - (target_y / height) * 2.0;
 
         let viewport = self.effective_camera_viewport_size();
         let (clip_scale_x, clip_scale_y) = self.world_clip_scale(viewport);
@@ -840,6 +1007,125 @@ impl RenderManager {
         Vec2::new(world_x, world_y)
     }
 
+    /// Enable pixel-perfect rendering at a fixed virtual resolution, or disable it with `None`.
+    ///
+    /// Returns false and keeps the current target if the resolution can't be
+    /// a texture on this device, see `pixel_perfect_resolution_fits`.
+    pub fn set_pixel_perfect_resolution(&mut self, resolution: Option<(u32, u32)>) -> bool {
+        let max_dimension = self.device.limits().max_texture_dimension_2d;
+        if resolution.is_some_and(|resolution| !Self::pixel_perfect_resolution_fits(resolution, max_dimension)) {
+            return false;
+        }
+
+        let current = self
+            .pixel_perfect_target
+            .as_ref()
+            .map(|target| (target.width, target.height));
+        if current == resolution {
+            return true;
+        }
+
+        self.pixel_perfect_target =
+            resolution.map(|(width, height)| self.create_pixel_perfect_target(width, height));
+        self.requires_redraw = true;
+        self.precomputed_scene_version = None;
+        self.bump_render_state_epoch();
+        true
+    }
+
+    /// Whether a pixel-perfect target of `(width, height)` is non-empty and
+    /// within a device's `max_texture_dimension_2d`.
+    pub fn pixel_perfect_resolution_fits((width, height): (u32, u32), max_dimension: u32) -> bool {
+        (1..=max_dimension).contains(&width) && (1..=max_dimension).contains(&height)
+    }
+
+    /// Get the pixel-perfect virtual resolution, if enabled.
+    pub fn pixel_perfect_resolution(&self) -> Option<(u32, u32)> {
+        self.pixel_perfect_target
+            .as_ref()
+            .map(|target| (target.width, target.height))
+    }
+
+    fn create_pixel_perfect_target(&self, width: u32, height: u32) -> PixelPerfectTarget {
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("pixel_perfect_target"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: self.surface_config.format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let sampler = self.device.create_sampler(&wgpu::SamplerDescriptor {
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout: &self.texture_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&sampler),
+                },
+            ],
+            label: Some("pixel_perfect_bind_group"),
+        });
+
+        PixelPerfectTarget {
+            width,
+            height,
+            _texture: texture,
+            view,
+            _sampler: sampler,
+            bind_group,
+        }
+    }
+
+    /// Integer scale and centered letterbox offset of the pixel-perfect target on the surface.
+    fn pixel_perfect_layout(&self) -> Option<(f32, f32, f32)> {
+        let target = self.pixel_perfect_target.as_ref()?;
+        let surface_width = self.surface_config.width.max(1);
+        let surface_height = self.surface_config.height.max(1);
+        let scale = (surface_width / target.width)
+            .min(surface_height / target.height)
+            .max(1);
+        let offset_x = (surface_width as i64 - (target.width * scale) as i64) / 2;
+        let offset_y = (surface_height as i64 - (target.height * scale) as i64) / 2;
+        Some((scale as f32, offset_x as f32, offset_y as f32))
+    }
+
+    /// Snap the camera to whole virtual pixels so scrolling never shimmers.
+    fn snap_camera_to_pixel_grid(&self, camera_position: Vec2) -> Vec2 {
+        let Some(target) = &self.pixel_perfect_target else {
+            return camera_position;
+        };
+        let viewport = self.effective_camera_viewport_size();
+        let effective_viewport = self.effective_world_viewport_size(viewport);
+        let (clip_scale_x, clip_scale_y) = self.world_clip_scale(viewport);
+        let units_per_pixel_x =
+            effective_viewport.x() / (clip_scale_x.max(f32::EPSILON) * target.width as f32);
+        let units_per_pixel_y =
+            effective_viewport.y() / (clip_scale_y.max(f32::EPSILON) * target.height as f32);
+        if units_per_pixel_x <= 0.0 || units_per_pixel_y <= 0.0 {
+            return camera_position;
+        }
+        Vec2::new(
+            (camera_position.x() / units_per_pixel_x).round() * units_per_pixel_x,
+            (camera_position.y() / units_per_pixel_y).round() * units_per_pixel_y,
+        )
+    }
+
     fn pixel_to_clip(&self, x: f32, y: f32) -> [f32; 2] {
         let width = self.surface_config.width.max(1) as f32;
         let height = self.surface_config.height.max(1) as f32;
@@ -881,6 +1167,7 @@ impl RenderManager {
         draw_order: f32,
     ) -> DrawItem {
         DrawItem {
+            clip: None,
             draw_order,
             texture_path,
             vertices: vec![
@@ -1006,15 +1293,19 @@ impl RenderManager {
         (value.clamp(0.0, 1.0) * 255.0).round() as u8
     }
 
-    fn build_text_texture_key(text: &str, style: &TextStyle, color: Color) -> String {
+    fn build_text_texture_key(&self, text: &str, style: &TextStyle, color: Color) -> String {
         let mut hasher = DefaultHasher::new();
         text.hash(&mut hasher);
         style.font.cache_key().hash(&mut hasher);
+        if style.font.fallbacks().is_empty() {
+            self.default_font_fallbacks.hash(&mut hasher);
+        }
         hash_f32(&mut hasher, style.font_size);
         hash_color(&mut hasher, &color);
         hash_f32(&mut hasher, style.letter_spacing);
         hash_f32(&mut hasher, style.line_spacing);
         style.kerning.hash(&mut hasher);
+        style.line_align.hash(&mut hasher);
         format!("__pyg_text_{:016x}", hasher.finish())
     }
 
@@ -1030,32 +1321,48 @@ impl RenderManager {
             letter_spacing_bits: style.letter_spacing.to_bits(),
             line_spacing_bits: style.line_spacing.to_bits(),
             kerning: style.kerning,
+            line_align: style.line_align,
         }
     }
 
     fn clear_resolved_asset_caches(&mut self) {
         self.font_cache.clear();
+        self.release_font_handles();
         self.glyph_cache.clear();
         self.layout_cache.clear();
         self.texture_cache.clear();
         self.texture_data_signature_cache.clear();
     }
 
-    pub fn set_source_root(&mut self, source_root: Option<PathBuf>) {
-        let normalized = source_root.map(|path| {
-            if path.is_absolute() {
-                path
-            } else {
-                std::env::current_dir()
-                    .unwrap_or_else(|_| PathBuf::from("."))
-                    .join(path)
+    fn release_font_handles(&mut self) {
+        if let Ok(mut assets) = self.assets.write() {
+            for handle in self.font_handles.drain(..) {
+                assets.release(handle);
             }
+        }
+    }
+
+    /// Drop everything loaded by path after the asset manager's source root
+    /// changed, so paths resolve against the new root.
+    pub fn source_root_changed(&mut self) {
+        self.clear_resolved_asset_caches();
+        self.request_redraw();
+    }
+
+    /// Pick up a font file the asset manager reloaded, re-rasterizing its glyphs.
+    pub fn font_changed(&mut self, resolved_path: &str) {
+        let font = self.assets.read().ok().and_then(|assets| {
+            let handle = assets.find(AssetKind::Font, resolved_path)?;
+            assets.font(handle).cloned()
         });
-        if self.source_root != normalized {
-            self.source_root = normalized;
-            self.clear_resolved_asset_caches();
-            self.request_redraw();
+        if let Some(font) = font
+            && let Some(cached) = self.font_cache.get_mut(resolved_path)
+        {
+            *cached = Some(font);
         }
+        self.glyph_cache.clear();
+        self.layout_cache.clear();
+        self.request_redraw();
     }
 
     pub fn register_font_family(
@@ -1078,6 +1385,26 @@ impl RenderManager {
         self.font_registry.get(&normalize_font_family_key(family))
     }
 
+    /// Set the fonts tried, in order, for characters a text style's own font
+    /// lacks. Entries are registered family names or `.ttf`/`.otf` paths.
+    /// Styles with their own fallback list ignore these.
+    pub fn set_font_fallbacks(&mut self, fallbacks: Vec<String>) {
+        let fallbacks: Vec<String> = fallbacks
+            .into_iter()
+            .map(|font| font.trim().to_string())
+            .filter(|font| !font.is_empty())
+            .collect();
+        if self.default_font_fallbacks != fallbacks {
+            self.default_font_fallbacks = fallbacks;
+            self.layout_cache.clear();
+            self.request_redraw();
+        }
+    }
+
+    pub fn font_fallbacks(&self) -> &[String] {
+        &self.default_font_fallbacks
+    }
+
     fn is_supported_font_path(font_path: &str) -> bool {
         let extension = std::path::Path::new(font_path)
             .extension()
@@ -1087,14 +1414,10 @@ impl RenderManager {
     }
 
     fn resolve_source_path(&self, path: &str) -> String {
-        let input = Path::new(path);
-        if input.is_absolute() {
-            return normalize_font_path(path);
+        match self.assets.read() {
+            Ok(assets) => assets.resolve_path(path),
+            Err(_) => normalize_font_path(path),
         }
-        if let Some(source_root) = &self.source_root {
-            return normalize_font_path(&source_root.join(input).to_string_lossy());
-        }
-        normalize_font_path(path)
     }
 
     fn load_font_from_path(&mut self, font_path: &str) -> Option<&Font> {
@@ -1106,19 +1429,20 @@ impl RenderManager {
                 ));
                 None
             } else {
-                match std::fs::read(&resolved_path) {
-                    Ok(bytes) => match Font::from_bytes(bytes, fontdue::FontSettings::default()) {
-                        Ok(font) => Some(font),
-                        Err(err) => {
-                            logging::log_warn(&format!(
-                                "Failed to decode font '{resolved_path}': {err}. Falling back to built-in font."
-                            ));
-                            None
-                        }
-                    },
+                let loaded = match self.assets.write() {
+                    Ok(mut assets) => assets
+                        .load(AssetKind::Font, &resolved_path)
+                        .map(|handle| (handle, assets.font(handle).cloned())),
+                    Err(_) => Err("asset manager lock poisoned".to_string()),
+                };
+                match loaded {
+                    Ok((handle, font)) => {
+                        self.font_handles.push(handle);
+                        font
+                    }
                     Err(err) => {
                         logging::log_warn(&format!(
-                            "Failed to read font '{resolved_path}': {err}. Falling back to built-in font."
+                            "Font load failed: {err}. Falling back to built-in font."
                         ));
                         None
                     }
@@ -1129,7 +1453,7 @@ impl RenderManager {
 
         self.font_cache
             .get(&resolved_path)
-            .and_then(|font| font.as_ref())
+            .and_then(|font| font.as_deref())
     }
 
     fn resolve_font_path(&self, descriptor: &FontDescriptor) -> Option<String> {
@@ -1159,6 +1483,76 @@ impl RenderManager {
         }
     }
 
+    fn resolve_fallback_font(&self, font: &str, descriptor: &FontDescriptor) -> Option<ResolvedFont> {
+        let family_key = normalize_font_family_key(font);
+        if let Some(family) = self.font_registry.get(&family_key) {
+            let path = family.resolve(descriptor.weight(), descriptor.style())?;
+            return Some(ResolvedFont {
+                path: self.resolve_source_path(path),
+                cache_key: format!(
+                    "family:{}:{}:{}",
+                    family_key,
+                    descriptor.weight().as_str(),
+                    descriptor.style().as_str()
+                ),
+            });
+        }
+        let path = self.resolve_source_path(font);
+        Some(ResolvedFont {
+            cache_key: format!("path:{path}"),
+            path,
+        })
+    }
+
+    /// Fonts to render `descriptor` with: its own font, then its fallbacks (or
+    /// the default fallbacks), skipping any that fail to load. Empty means the
+    /// built-in bitmap font.
+    fn font_chain(&mut self, descriptor: &FontDescriptor) -> Vec<ResolvedFont> {
+        let mut chain = Vec::new();
+        if let Some(path) = self.resolve_font_path(descriptor) {
+            let cache_key = self.resolved_font_cache_key(descriptor, &path);
+            chain.push(ResolvedFont { path, cache_key });
+        }
+        let fallbacks = if descriptor.fallbacks().is_empty() {
+            self.default_font_fallbacks.clone()
+        } else {
+            descriptor.fallbacks().to_vec()
+        };
+        for font in &fallbacks {
+            if let Some(resolved) = self.resolve_fallback_font(font, descriptor)
+                && !chain.contains(&resolved)
+            {
+                chain.push(resolved);
+            }
+        }
+        chain.retain(|font| self.load_font_from_path(&font.path).is_some());
+        chain
+    }
+
+    fn font_chain_key(chain: &[ResolvedFont]) -> String {
+        chain
+            .iter()
+            .map(|font| font.cache_key.as_str())
+            .collect::<Vec<_>>()
+            .join("|")
+    }
+
+    /// Index of the first font in `chain` that has a glyph for `ch`, or 0 so
+    /// the primary font draws its missing-glyph box.
+    fn font_index_for_char(&mut self, chain: &[ResolvedFont], ch: char) -> usize {
+        if chain.len() > 1 {
+            for (index, font) in chain.iter().enumerate() {
+                if self
+                    .load_font_from_path(&font.path)
+                    .is_some_and(|loaded| loaded.lookup_glyph_index(ch) != 0)
+                {
+                    return index;
+                }
+            }
+        }
+        0
+    }
+
     fn rasterize_text_font8x8(
         text: &str,
         font_size: f32,
@@ -1284,14 +1678,15 @@ impl RenderManager {
 
     fn build_fontdue_text_layout(
         &mut self,
-        font_path: &str,
-        font_cache_key: &str,
+        chain: &[ResolvedFont],
         text: &str,
         style: &TextStyle,
     ) -> Option<CachedTextLayout> {
         if text.is_empty() {
             return None;
         }
+        // Line metrics come from the primary font.
+        let font_path = chain.first()?.path.as_str();
 
         let font_size = style.font_size.max(1.0);
         let letter_spacing = style.letter_spacing.max(-(font_size * 0.95));
@@ -1314,29 +1709,32 @@ impl RenderManager {
         let lines: Vec<&str> = text.split('\n').collect();
 
         let mut glyphs = Vec::new();
-        let mut min_x = 0i32;
-        let mut min_y = 0i32;
-        let mut max_x = 0i32;
-        let mut max_y = 0i32;
-        let mut has_visible_glyph = false;
+        // Size of each glyph in `glyphs`, and (first glyph, pen width) of each line.
+        let mut glyph_sizes = Vec::new();
+        let mut line_extents = Vec::with_capacity(lines.len());
         let mut measured_width = 0.0f32;
 
         for (line_index, line) in lines.iter().enumerate() {
+            line_extents.push((glyphs.len(), 0.0f32));
             let baseline_y = ascent + line_index as f32 * line_stride;
             let chars: Vec<char> = line.chars().collect();
             let mut pen_x = 0.0f32;
-            let mut previous_char: Option<char> = None;
+            let mut previous_char: Option<(char, usize)> = None;
 
             for (char_index, ch) in chars.iter().enumerate() {
                 if *ch == '\t' {
                     pen_x += tab_advance;
                     previous_char = None;
                 } else {
+                    let font_index = self.font_index_for_char(chain, *ch);
+                    let font = &chain[font_index];
                     let glyph =
-                        self.load_cached_glyph(font_path, font_cache_key, *ch, font_size)?;
+                        self.load_cached_glyph(&font.path, &font.cache_key, *ch, font_size)?;
+                    // Kerning pairs only exist within one font.
                     if style.kerning
-                        && let Some(previous) = previous_char
-                        && let Some(font) = self.load_font_from_path(font_path)
+                        && let Some((previous, previous_index)) = previous_char
+                        && previous_index == font_index
+                        && let Some(font) = self.load_font_from_path(&font.path)
                     {
                         pen_x += font
                             .horizontal_kern(previous, *ch, font_size)
@@ -1349,27 +1747,13 @@ impl RenderManager {
                             - glyph.metrics.ymin as f32
                             - glyph.metrics.height as f32)
                             .floor() as i32;
-                        let glyph_right = glyph_x + glyph.metrics.width as i32;
-                        let glyph_bottom = glyph_y + glyph.metrics.height as i32;
-
-                        if !has_visible_glyph {
-                            min_x = glyph_x;
-                            min_y = glyph_y;
-                            max_x = glyph_right;
-                            max_y = glyph_bottom;
-                            has_visible_glyph = true;
-                        } else {
-                            min_x = min_x.min(glyph_x);
-                            min_y = min_y.min(glyph_y);
-                            max_x = max_x.max(glyph_right);
-                            max_y = max_y.max(glyph_bottom);
-                        }
 
+                        glyph_sizes.push((glyph.metrics.width as i32, glyph.metrics.height as i32));
                         glyphs.push(PositionedGlyph {
                             x: glyph_x,
                             y: glyph_y,
                             glyph_key: GlyphCacheKey {
-                                font_cache_key: font_cache_key.to_string(),
+                                font_cache_key: font.cache_key.clone(),
                                 glyph: *ch,
                                 font_size_bits: font_size.to_bits(),
                             },
@@ -1377,7 +1761,7 @@ impl RenderManager {
                     }
 
                     pen_x += glyph.metrics.advance_width.max(font_size * 0.25);
-                    previous_char = Some(*ch);
+                    previous_char = Some((*ch, font_index));
                 }
 
                 if char_index + 1 < chars.len() {
@@ -1386,6 +1770,45 @@ impl RenderManager {
             }
 
             measured_width = measured_width.max(pen_x.max(0.0));
+            if let Some(extent) = line_extents.last_mut() {
+                extent.1 = pen_x.max(0.0);
+            }
+        }
+
+        let line_align_factor = match style.line_align {
+            TextAlign::Left => 0.0,
+            TextAlign::Center => 0.5,
+            TextAlign::Right => 1.0,
+        };
+        if line_align_factor > 0.0 {
+            for (line_index, (first_glyph, line_width)) in line_extents.iter().enumerate() {
+                let end = line_extents
+                    .get(line_index + 1)
+                    .map(|(next_first, _)| *next_first)
+                    .unwrap_or(glyphs.len());
+                let shift = ((measured_width - line_width) * line_align_factor).round() as i32;
+                for glyph in &mut glyphs[*first_glyph..end] {
+                    glyph.x += shift;
+                }
+            }
+        }
+
+        let has_visible_glyph = !glyphs.is_empty();
+        let mut min_x = 0i32;
+        let mut min_y = 0i32;
+        let mut max_x = 0i32;
+        let mut max_y = 0i32;
+        for (index, (glyph, (glyph_width, glyph_height))) in glyphs.iter().zip(&glyph_sizes).enumerate() {
+            let glyph_right = glyph.x + glyph_width;
+            let glyph_bottom = glyph.y + glyph_height;
+            if index == 0 {
+                (min_x, min_y, max_x, max_y) = (glyph.x, glyph.y, glyph_right, glyph_bottom);
+            } else {
+                min_x = min_x.min(glyph.x);
+                min_y = min_y.min(glyph.y);
+                max_x = max_x.max(glyph_right);
+                max_y = max_y.max(glyph_bottom);
+            }
         }
 
         let (width, height) = if has_visible_glyph {
@@ -1420,12 +1843,11 @@ impl RenderManager {
         &mut self,
         text: &str,
         style: &TextStyle,
-        font_path: &str,
-        font_cache_key: &str,
+        chain: &[ResolvedFont],
     ) -> Option<CachedTextLayout> {
-        let key = Self::build_text_layout_cache_key(text, style, font_cache_key);
+        let key = Self::build_text_layout_cache_key(text, style, &Self::font_chain_key(chain));
         if !self.layout_cache.contains_key(&key) {
-            let layout = self.build_fontdue_text_layout(font_path, font_cache_key, text, style)?;
+            let layout = self.build_fontdue_text_layout(chain, text, style)?;
             self.layout_cache.insert(key.clone(), layout);
         }
         self.layout_cache.get(&key).cloned()
@@ -1433,13 +1855,12 @@ impl RenderManager {
 
     fn rasterize_text_fontdue(
         &mut self,
-        font_path: &str,
-        font_cache_key: &str,
+        chain: &[ResolvedFont],
         text: &str,
         style: &TextStyle,
         color: Color,
     ) -> Option<RasterizedText> {
-        let layout = self.cached_text_layout(text, style, font_path, font_cache_key)?;
+        let layout = self.cached_text_layout(text, style, chain)?;
         let mut rgba = vec![0u8; (layout.width as usize) * (layout.height as usize) * 4];
 
         let r = Self::color_component_to_u8(color.r());
@@ -1491,11 +1912,11 @@ impl RenderManager {
         text: &str,
         style: &TextStyle,
     ) -> Option<(u32, u32)> {
-        if let Some(font_path) = self.resolve_font_path(&style.font) {
-            let font_cache_key = self.resolved_font_cache_key(&style.font, &font_path);
-            if let Some(layout) = self.cached_text_layout(text, style, &font_path, &font_cache_key) {
-                return Some((layout.width, layout.height));
-            }
+        let chain = self.font_chain(&style.font);
+        if !chain.is_empty()
+            && let Some(layout) = self.cached_text_layout(text, style, &chain)
+        {
+            return Some((layout.width, layout.height));
         }
 
         Self::rasterize_text_font8x8(
@@ -1514,25 +1935,58 @@ impl RenderManager {
             .unwrap_or((0.0, 0.0))
     }
 
+    /// Measure text as `draw_text_with_options` would lay it out, wrapping
+    /// it to `layout.width` when `layout.wrap` is set.
+    pub fn measure_text_layout(
+        &mut self,
+        text: &str,
+        style: &TextStyle,
+        layout: &TextLayoutOptions,
+    ) -> (f32, f32) {
+        match self.wrapped_text(text, style, layout) {
+            Some(wrapped) => self.measure_text(&wrapped, style),
+            None => self.measure_text(text, style),
+        }
+    }
+
+    /// `text` with line breaks inserted so no line is wider than the layout
+    /// width, or `None` when the layout does not wrap.
+    fn wrapped_text(&mut self, text: &str, style: &TextStyle, layout: &TextLayoutOptions) -> Option<String> {
+        let max_width = layout.width.filter(|_| layout.wrap)?;
+        let chain = self.font_chain(&style.font);
+        Some(wrap_text(text, max_width, style.letter_spacing, |ch| {
+            self.char_advance(&chain, ch, style)
+        }))
+    }
+
+    /// Horizontal pen advance of one character, matching the text layout.
+    fn char_advance(&mut self, chain: &[ResolvedFont], ch: char, style: &TextStyle) -> f32 {
+        let font_size = style.font_size.max(1.0);
+        if chain.is_empty() {
+            let scale = (font_size / DEFAULT_GLYPH_PIXEL_SIZE).max(1.0).round();
+            return DEFAULT_GLYPH_PIXEL_SIZE * scale;
+        }
+        if ch == '\t' {
+            return self.char_advance(chain, ' ', style) * 4.0;
+        }
+        let font_index = self.font_index_for_char(chain, ch);
+        let font = &chain[font_index];
+        self.load_cached_glyph(&font.path, &font.cache_key, ch, font_size)
+            .map(|glyph| glyph.metrics.advance_width.max(font_size * 0.25))
+            .unwrap_or(font_size * 0.5)
+    }
+
     fn rasterize_text(
         &mut self,
         text: &str,
         style: &TextStyle,
         color: Color,
     ) -> Option<RasterizedText> {
-        if let Some(font_path) = self.resolve_font_path(&style.font) {
-            let font_cache_key = self.resolved_font_cache_key(&style.font, &font_path);
-            if self.load_font_from_path(&font_path).is_some()
-                && let Some(rasterized) = self.rasterize_text_fontdue(
-                    &font_path,
-                    &font_cache_key,
-                    text,
-                    style,
-                    color,
-                )
-            {
-                return Some(rasterized);
-            }
+        let chain = self.font_chain(&style.font);
+        if !chain.is_empty()
+            && let Some(rasterized) = self.rasterize_text_fontdue(&chain, text, style, color)
+        {
+            return Some(rasterized);
         }
 
         Self::rasterize_text_font8x8(
@@ -1588,7 +2042,13 @@ impl RenderManager {
             return None;
         }
 
-        let texture_key = Self::build_text_texture_key(text, style, color);
+        let wrapped = self.wrapped_text(text, style, layout);
+        let text = wrapped.as_deref().unwrap_or(text);
+        let style = &TextStyle {
+            line_align: layout.horizontal_align,
+            ..style.clone()
+        };
+        let texture_key = self.build_text_texture_key(text, style, color);
 
         // Fast path: skip CPU rasterization when this text texture is already cached.
         let cached_dimensions = if let Some(Some(entry)) = self.texture_cache.get_mut(&texture_key) {
@@ -1654,7 +2114,7 @@ impl RenderManager {
             return None;
         }
 
-        let texture_key = Self::build_text_texture_key(text, style, color);
+        let texture_key = self.build_text_texture_key(text, style, color);
 
         let cached_dimensions = if let Some(Some(entry)) = self.texture_cache.get_mut(&texture_key) {
             entry.last_used_frame = self.current_frame;
@@ -1786,6 +2246,7 @@ impl RenderManager {
         }
 
         Some(DrawItem {
+            clip: None,
             draw_order,
             texture_path: None,
             vertices,
@@ -1842,6 +2303,7 @@ impl RenderManager {
         }
 
         Some(DrawItem {
+            clip: None,
             draw_order,
             texture_path: None,
             vertices,
@@ -1899,6 +2361,7 @@ impl RenderManager {
         }
 
         Some(DrawItem {
+            clip: None,
             draw_order,
             texture_path: None,
             vertices,
@@ -1962,6 +2425,7 @@ impl RenderManager {
         }
 
         Some(DrawItem {
+            clip: None,
             draw_order,
             texture_path: None,
             vertices,
@@ -1997,6 +2461,7 @@ impl RenderManager {
         }
 
         Some(DrawItem {
+            clip: None,
             draw_order,
             texture_path: None,
             vertices,
@@ -2034,6 +2499,7 @@ impl RenderManager {
         }
 
         Some(DrawItem {
+            clip: None,
             draw_order,
             texture_path: texture_path.map(|path| self.resolve_source_path(&path)),
             vertices: draw_vertices,
@@ -2051,7 +2517,8 @@ impl RenderManager {
             return (items, texture_uploads);
         };
 
-        for command in draw_manager.commands() {
+        for (command, clip) in draw_manager.commands().iter().zip(draw_manager.clip_rects()) {
+            let first_item = items.len();
             match command {
                 DrawCommand::Pixel {
                     x,
@@ -2357,6 +2824,13 @@ impl RenderManager {
                         }
                     }
                 }
+                // Collected with the world items by `collect_world_text_draw_items`.
+                DrawCommand::WorldText { .. } => {}
+            }
+            if clip.is_some() {
+                for item in &mut items[first_item..] {
+                    item.clip = *clip;
+                }
             }
         }
 
@@ -2371,7 +2845,7 @@ impl RenderManager {
         let mut items = Vec::new();
         let keys = objects.get_sorted_keys();
 
-        for &id in keys {
+        for id in keys {
             if self.active_camera_object_id == Some(id) {
                 continue;
             }
@@ -2433,6 +2907,7 @@ impl RenderManager {
             }
 
             items.push(DrawItem {
+                clip: None,
                 draw_order: mesh.draw_order(),
                 texture_path: mesh.image_path().map(|p| self.resolve_source_path(p)),
                 vertices,
@@ -2443,6 +2918,61 @@ impl RenderManager {
         items
     }
 
+    /// World units covered by one pixel of the world render target.
+    fn world_units_per_pixel(&self) -> (f32, f32) {
+        let viewport = self.effective_camera_viewport_size();
+        let (clip_scale_x, clip_scale_y) = self.world_clip_scale(viewport);
+        let effective_viewport = self.effective_world_viewport_size(viewport);
+        let (width, height) = self.world_target_size();
+        (
+            effective_viewport.x() / (width * clip_scale_x.max(f32::EPSILON)),
+            effective_viewport.y() / (height * clip_scale_y.max(f32::EPSILON)),
+        )
+    }
+
+    fn collect_world_text_draw_items(
+        &mut self,
+        draw_manager: Option<&DrawManager>,
+        camera_position: Vec2,
+    ) -> (Vec<DrawItem>, Vec<PendingTextureUpload>) {
+        let mut items = Vec::new();
+        let mut texture_uploads = Vec::new();
+        let Some(draw_manager) = draw_manager else {
+            return (items, texture_uploads);
+        };
+
+        for command in draw_manager.commands() {
+            let DrawCommand::WorldText {
+                text,
+                x,
+                y,
+                style,
+                color,
+                draw_order,
+            } = command
+            else {
+                continue;
+            };
+            if let Some((item, upload)) = self.build_world_text_draw_item(
+                text,
+                Vec2::new(*x, *y),
+                0.0,
+                Vec2::new(1.0, 1.0),
+                style,
+                *color,
+                camera_position,
+                *draw_order,
+            ) {
+                items.push(item);
+                if let Some(upload) = upload {
+                    texture_uploads.push(upload);
+                }
+            }
+        }
+
+        (items, texture_uploads)
+    }
+
     fn collect_text_mesh_draw_items(
         &mut self,
         objects: &ObjectManager,
@@ -2451,8 +2981,9 @@ impl RenderManager {
         let mut items = Vec::new();
         let mut texture_uploads = Vec::new();
         let keys = objects.get_sorted_keys();
+        let units_per_pixel = self.world_units_per_pixel();
 
-        for &id in keys {
+        for id in keys {
             if self.active_camera_object_id == Some(id) {
                 continue;
             }
@@ -2470,6 +3001,46 @@ impl RenderManager {
             };
 
             for component in object.all_components() {
+                if let Some(label) = component
+                    .as_any()
+                    .downcast_ref::<crate::core::component::WorldLabelComponent>()
+                {
+                    if !label.is_effectively_enabled() || !label.visible() {
+                        continue;
+                    }
+
+                    let offset = label.offset();
+                    let position = Vec2::new(
+                        world_transform.position.x() + offset.x(),
+                        world_transform.position.y() + offset.y(),
+                    );
+                    let (rotation, mut scale) = if label.billboard() {
+                        (0.0, Vec2::new(1.0, 1.0))
+                    } else {
+                        (world_transform.rotation, world_transform.scale)
+                    };
+                    if !label.scale_with_zoom() {
+                        scale = Vec2::new(scale.x() * units_per_pixel.0, scale.y() * units_per_pixel.1);
+                    }
+
+                    if let Some((item, upload)) = self.build_world_text_draw_item(
+                        label.text(),
+                        position,
+                        rotation,
+                        scale,
+                        label.text_style(),
+                        label.color(),
+                        camera_position,
+                        label.draw_order(),
+                    ) {
+                        items.push(item);
+                        if let Some(upload) = upload {
+                            texture_uploads.push(upload);
+                        }
+                    }
+                    continue;
+                }
+
                 let Some(text_mesh) = component
                     .as_any()
                     .downcast_ref::<crate::core::component::TextMeshComponent>()
@@ -2502,27 +3073,41 @@ impl RenderManager {
         (items, texture_uploads)
     }
 
+    /// Collect world-space and screen-space draw items, in that order.
     fn collect_draw_items(
         &mut self,
         objects: &ObjectManager,
         draw_manager: Option<&DrawManager>,
-    ) -> (Vec<DrawItem>, Vec<PendingTextureUpload>) {
-        let camera_position = self.active_camera_position(objects);
-        let mut items = self.collect_mesh_draw_items(objects, camera_position);
+    ) -> (Vec<DrawItem>, Vec<DrawItem>, Vec<PendingTextureUpload>) {
+        let camera_position = self.snap_camera_to_pixel_grid(self.active_camera_position(objects));
+        let mut world_items = self.collect_mesh_draw_items(objects, camera_position);
         let (mut text_mesh_items, mut text_mesh_uploads) =
             self.collect_text_mesh_draw_items(objects, camera_position);
-        let (direct_draw_items, mut texture_uploads) = self.collect_direct_draw_items(draw_manager);
-        items.append(&mut text_mesh_items);
-        items.extend(direct_draw_items);
+        let (mut world_text_items, mut world_text_uploads) =
+            self.collect_world_text_draw_items(draw_manager, camera_position);
+        let (screen_items, mut texture_uploads) = self.collect_direct_draw_items(draw_manager);
+        world_items.append(&mut text_mesh_items);
+        world_items.append(&mut world_text_items);
         texture_uploads.append(&mut text_mesh_uploads);
+        texture_uploads.append(&mut world_text_uploads);
 
+        (world_items, screen_items, texture_uploads)
+    }
+
+    /// Stable sort by draw order. With `by_texture`, items of equal draw
+    /// order are also grouped by texture so they merge into fewer batches.
+    fn sort_draw_items(items: &mut [DrawItem], by_texture: bool) {
         items.sort_by(|a, b| {
-            a.draw_order
+            let order = a
+                .draw_order
                 .partial_cmp(&b.draw_order)
-                .unwrap_or(Ordering::Equal)
+                .unwrap_or(Ordering::Equal);
+            if by_texture {
+                order.then_with(|| a.texture_path.cmp(&b.texture_path))
+            } else {
+                order
+            }
         });
-
-        (items, texture_uploads)
     }
 
     fn compute_scene_version(
@@ -2609,6 +3194,123 @@ impl RenderManager {
         }
     }
 
+    fn texture_entry_bytes(entry: &CachedTextureEntry) -> u64 {
+        entry.cached_texture.width as u64 * entry.cached_texture.height as u64 * 4
+    }
+
+    /// Load a texture from disk into the cache ahead of its first draw.
+    pub fn preload_texture(&mut self, texture_path: &str) -> Result<(), String> {
+        let resolved_path = self.resolve_source_path(texture_path);
+        if let Some(Some(entry)) = self.texture_cache.get_mut(&resolved_path) {
+            entry.last_used_frame = self.current_frame;
+            return Ok(());
+        }
+
+        let cached_texture = self.load_texture_from_path(texture_path)?;
+        self.texture_cache.insert(
+            resolved_path,
+            Some(CachedTextureEntry {
+                cached_texture,
+                last_used_frame: self.current_frame,
+            }),
+        );
+        self.enforce_texture_budget();
+        Ok(())
+    }
+
+    /// Drop a cached texture by path or texture key.
+    ///
+    /// Returns whether an entry was removed. The texture reloads on its next draw.
+    pub fn unload_texture(&mut self, texture_path: &str) -> bool {
+        let resolved_path = self.resolve_source_path(texture_path);
+        let mut removed = false;
+        for key in [texture_path, resolved_path.as_str()] {
+            removed |= self.texture_cache.remove(key).is_some();
+            self.texture_data_signature_cache.remove(key);
+        }
+        if removed {
+            self.requires_redraw = true;
+            self.precomputed_scene_version = None;
+        }
+        removed
+    }
+
+    /// Approximate GPU memory held by cached textures, in bytes (RGBA8).
+    pub fn texture_memory_usage(&self) -> u64 {
+        self.texture_cache
+            .values()
+            .flatten()
+            .map(Self::texture_entry_bytes)
+            .sum()
+    }
+
+    /// Number of textures currently resident in the cache.
+    pub fn cached_texture_count(&self) -> usize {
+        self.texture_cache.values().flatten().count()
+    }
+
+    /// Set the texture memory budget in bytes, or `None` for unlimited.
+    ///
+    /// When the cache exceeds the budget, least-recently-used textures are evicted.
+    pub fn set_texture_budget(&mut self, budget_bytes: Option<u64>) {
+        self.texture_budget_bytes = budget_bytes;
+        self.enforce_texture_budget();
+    }
+
+    /// Get the texture memory budget in bytes, if any.
+    pub fn texture_budget(&self) -> Option<u64> {
+        self.texture_budget_bytes
+    }
+
+    /// Evict least-recently-used textures until the cache fits the budget.
+    ///
+    /// Textures drawn in the current or previous frame are never evicted so a
+    /// budget smaller than the visible working set cannot cause reload thrashing.
+    fn enforce_texture_budget(&mut self) {
+        let Some(budget) = self.texture_budget_bytes else {
+            return;
+        };
+
+        let mut usage = self.texture_memory_usage();
+        if usage <= budget {
+            return;
+        }
+
+        let protected_frame = self.current_frame.saturating_sub(1);
+        let mut candidates: Vec<(u64, String, u64)> = self
+            .texture_cache
+            .iter()
+            .filter_map(|(key, entry)| {
+                let entry = entry.as_ref()?;
+                (entry.last_used_frame < protected_frame).then(|| {
+                    (
+                        entry.last_used_frame,
+                        key.clone(),
+                        Self::texture_entry_bytes(entry),
+                    )
+                })
+            })
+            .collect();
+        candidates.sort_by_key(|(last_used_frame, _, _)| *last_used_frame);
+
+        let mut evicted = 0usize;
+        for (_, key, bytes) in candidates {
+            if usage <= budget {
+                break;
+            }
+            self.texture_cache.remove(&key);
+            self.texture_data_signature_cache.remove(&key);
+            usage = usage.saturating_sub(bytes);
+            evicted += 1;
+        }
+
+        if evicted > 0 {
+            logging::log_debug(&format!(
+                "Evicted {evicted} textures to fit budget of {budget} bytes ({usage} bytes in use)"
+            ));
+        }
+    }
+
     /// Invalidate any scene version precomputed before a simulation update.
     pub fn invalidate_precomputed_scene_signature(&mut self) {
         self.precomputed_scene_version = None;
@@ -2647,7 +3349,8 @@ impl RenderManager {
             }
         }
 
-        let (draw_items, pending_texture_uploads) = self.collect_draw_items(objects, draw_manager);
+        let (mut world_items, mut screen_items, pending_texture_uploads) =
+            self.collect_draw_items(objects, draw_manager);
         for upload in pending_texture_uploads {
             if let Err(err) = self.cache_texture_from_rgba(
                 &upload.key,
@@ -2658,61 +3361,134 @@ impl RenderManager {
                 logging::log_warn(&err);
             }
         }
-        let mut prepared_draws = Vec::new();
+
+        // Only world objects are regrouped by texture; direct draw commands and
+        // UI keep their submission order, which overlapping widgets rely on.
+        Self::sort_draw_items(&mut world_items, self.sort_by_texture);
+        self.render_stats = RenderStats::default();
         let mut batch_slot = 0usize;
+        let (offscreen_draws, surface_draws) = if let Some((scale, offset_x, offset_y)) =
+            self.pixel_perfect_layout()
+            && let Some((target_width, target_height)) = self.pixel_perfect_resolution()
+        {
+            // World renders alone into the virtual-resolution target; the upscaled
+            // result then sits beneath all screen-space draws on the surface.
+            self.render_stats.record(&world_items);
+            let offscreen_draws = self.prepare_draws(world_items, &mut batch_slot);
+            screen_items.push(self.build_image_rect_draw_item(
+                offset_x,
+                offset_y,
+                target_width as f32 * scale,
+                target_height as f32 * scale,
+                PIXEL_PERFECT_TEXTURE_KEY.to_string(),
+                f32::NEG_INFINITY,
+            ));
+            Self::sort_draw_items(&mut screen_items, false);
+            self.render_stats.record(&screen_items);
+            let surface_draws = self.prepare_draws(screen_items, &mut batch_slot);
+            (Some(offscreen_draws), surface_draws)
+        } else {
+            // The sort is stable, so world items keep their texture grouping.
+            world_items.append(&mut screen_items);
+            Self::sort_draw_items(&mut world_items, false);
+            self.render_stats.record(&world_items);
+            (None, self.prepare_draws(world_items, &mut batch_slot))
+        };
+
+        // Acquire the next frame.
+        let output = self.surface.get_current_texture()?;
+
+        let view = output
+            .texture
+            .create_view(&wgpu::TextureViewDescriptor::default());
+
+        // Create a command encoder.
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Render Encoder"),
+            });
+
+        let surface_size = (self.surface_config.width, self.surface_config.height);
+        if let Some(offscreen_draws) = &offscreen_draws
+            && let Some(target) = &self.pixel_perfect_target
+        {
+            self.encode_draw_pass(
+                &mut encoder,
+                &target.view,
+                self.background_color.to_wgpu(),
+                offscreen_draws,
+                (target.width, target.height),
+                "Pixel Perfect Pass",
+            );
+            self.encode_draw_pass(
+                &mut encoder,
+                &view,
+                Color::BLACK.to_wgpu(),
+                &surface_draws,
+                surface_size,
+                "Render Pass",
+            );
+        } else {
+            self.encode_draw_pass(
+                &mut encoder,
+                &view,
+                self.background_color.to_wgpu(),
+                &surface_draws,
+                surface_size,
+                "Render Pass",
+            );
+        }
+
+        // Submit the command buffer and present the frame.
+        self.queue.submit(std::iter::once(encoder.finish()));
+        output.present();
+
+        self.requires_redraw = false;
+        self.last_scene_version = Some(scene_version);
+
+        // Increment frame counter and periodically clean up unused textures
+        self.current_frame = self.current_frame.wrapping_add(1);
+        if self.current_frame % 60 == 0 {
+            // Clean up every 60 frames to avoid overhead
+            self.cleanup_unused_textures();
+        }
+        self.enforce_texture_budget();
+
+        Ok(())
+    }
+
+    /// Merge consecutive draw items sharing a texture into pooled GPU batches.
+    fn prepare_draws(&mut self, draw_items: Vec<DrawItem>, batch_slot: &mut usize) -> Vec<PreparedDraw> {
+        let mut prepared_draws = Vec::new();
 
         // Batching State
         let mut batch_vertices: Vec<Vertex> = Vec::new();
         let mut batch_indices: Vec<u32> = Vec::new();
         let mut batch_texture_path: Option<String> = None;
+        let mut batch_clip: Option<ClipRect> = None;
 
         // Iteration and Merging
         for item in draw_items {
             // Determine if we need to switch batches
-            let texture_changed = item.texture_path != batch_texture_path;
+            let state_changed = item.texture_path != batch_texture_path || item.clip != batch_clip;
             let is_first_item = batch_vertices.is_empty() && batch_indices.is_empty();
 
-            if texture_changed && !is_first_item {
-                // 1. Resolve the bind group for the COMPLETED batch
-                let bind_group = self.texture_bind_group_for(batch_texture_path.as_deref());
-
-                // 2. Flush
-                if !batch_vertices.is_empty() {
-                    let vertex_buffer = Self::write_to_pooled_buffer(
-                        &self.device,
-                        &self.queue,
-                        &mut self.vertex_buffer_pool,
-                        batch_slot,
-                        bytemuck::cast_slice(&batch_vertices),
-                        wgpu::BufferUsages::VERTEX,
-                        "batch_vertex_buffer_pool",
-                    );
-                    let index_buffer = Self::write_to_pooled_buffer(
-                        &self.device,
-                        &self.queue,
-                        &mut self.index_buffer_pool,
-                        batch_slot,
-                        bytemuck::cast_slice(&batch_indices),
-                        wgpu::BufferUsages::INDEX,
-                        "batch_index_buffer_pool",
-                    );
-
-                    prepared_draws.push(PreparedDraw {
-                        bind_group,
-                        vertex_buffer,
-                        index_buffer,
-                        index_count: batch_indices.len() as u32,
-                    });
-                    batch_slot += 1;
-
-                    batch_vertices.clear();
-                    batch_indices.clear();
-                }
+            if state_changed && !is_first_item {
+                self.flush_batch(
+                    &mut prepared_draws,
+                    batch_slot,
+                    batch_texture_path.as_deref(),
+                    batch_clip,
+                    &mut batch_vertices,
+                    &mut batch_indices,
+                );
             }
 
             // Update current batch tracker
             if batch_vertices.is_empty() {
                 batch_texture_path = item.texture_path.clone();
+                batch_clip = item.clip;
             }
 
             // MERGE: Append geometry
@@ -2723,95 +3499,116 @@ impl RenderManager {
         }
 
         // Final Flush
-        if !batch_vertices.is_empty() {
-            let bind_group = self.texture_bind_group_for(batch_texture_path.as_deref());
-
-            let vertex_buffer = Self::write_to_pooled_buffer(
-                &self.device,
-                &self.queue,
-                &mut self.vertex_buffer_pool,
-                batch_slot,
-                bytemuck::cast_slice(&batch_vertices),
-                wgpu::BufferUsages::VERTEX,
-                "batch_vertex_buffer_pool",
-            );
+        self.flush_batch(
+            &mut prepared_draws,
+            batch_slot,
+            batch_texture_path.as_deref(),
+            batch_clip,
+            &mut batch_vertices,
+            &mut batch_indices,
+        );
 
-            let index_buffer = Self::write_to_pooled_buffer(
-                &self.device,
-                &self.queue,
-                &mut self.index_buffer_pool,
-                batch_slot,
-                bytemuck::cast_slice(&batch_indices),
-                wgpu::BufferUsages::INDEX,
-                "batch_index_buffer_pool",
-            );
+        prepared_draws
+    }
 
-            prepared_draws.push(PreparedDraw {
-                bind_group,
-                vertex_buffer,
-                index_buffer,
-                index_count: batch_indices.len() as u32,
-            });
+    fn flush_batch(
+        &mut self,
+        prepared_draws: &mut Vec<PreparedDraw>,
+        batch_slot: &mut usize,
+        texture_path: Option<&str>,
+        clip: Option<ClipRect>,
+        batch_vertices: &mut Vec<Vertex>,
+        batch_indices: &mut Vec<u32>,
+    ) {
+        if batch_vertices.is_empty() {
+            return;
         }
 
-        // Acquire the next frame.
-        let output = self.surface.get_current_texture()?;
+        let bind_group = self.texture_bind_group_for(texture_path);
+        let vertex_buffer = Self::write_to_pooled_buffer(
+            &self.device,
+            &self.queue,
+            &mut self.vertex_buffer_pool,
+            *batch_slot,
+            bytemuck::cast_slice(batch_vertices),
+            wgpu::BufferUsages::VERTEX,
+            "batch_vertex_buffer_pool",
+        );
+        let index_buffer = Self::write_to_pooled_buffer(
+            &self.device,
+            &self.queue,
+            &mut self.index_buffer_pool,
+            *batch_slot,
+            bytemuck::cast_slice(batch_indices),
+            wgpu::BufferUsages::INDEX,
+            "batch_index_buffer_pool",
+        );
 
-        let view = output
-            .texture
-            .create_view(&wgpu::TextureViewDescriptor::default());
+        prepared_draws.push(PreparedDraw {
+            bind_group,
+            clip,
+            vertex_buffer,
+            index_buffer,
+            index_count: batch_indices.len() as u32,
+        });
+        *batch_slot += 1;
 
-        // Create a command encoder.
-        let mut encoder = self
-            .device
-            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                label: Some("Render Encoder"),
-            });
+        batch_vertices.clear();
+        batch_indices.clear();
+    }
 
-        // Create a render pass.
-        {
-            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                label: Some("Render Pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &view,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(self.background_color.to_wgpu()),
-                        store: wgpu::StoreOp::Store,
-                    },
-                    depth_slice: None,
-                })],
-                depth_stencil_attachment: None,
-                occlusion_query_set: None,
-                timestamp_writes: None,
-                multiview_mask: None,
-            });
+    fn encode_draw_pass(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &wgpu::TextureView,
+        clear_color: wgpu::Color,
+        draws: &[PreparedDraw],
+        target_size: (u32, u32),
+        label: &str,
+    ) {
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some(label),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(clear_color),
+                    store: wgpu::StoreOp::Store,
+                },
+                depth_slice: None,
+            })],
+            depth_stencil_attachment: None,
+            occlusion_query_set: None,
+            timestamp_writes: None,
+            multiview_mask: None,
+        });
 
-            render_pass.set_pipeline(&self.render_pipeline);
-            for draw in &prepared_draws {
-                render_pass.set_bind_group(0, &draw.bind_group, &[]);
-                render_pass.set_vertex_buffer(0, draw.vertex_buffer.slice(..));
-                render_pass
-                    .set_index_buffer(draw.index_buffer.slice(..), wgpu::IndexFormat::Uint32);
-                render_pass.draw_indexed(0..draw.index_count, 0, 0..1);
+        render_pass.set_pipeline(&self.render_pipeline);
+        let (target_width, target_height) = target_size;
+        for draw in draws {
+            match draw.clip.map(|clip| Self::scissor_rect(clip, target_size)) {
+                Some(Some((x, y, width, height))) => render_pass.set_scissor_rect(x, y, width, height),
+                // Clip rect lies entirely off target: nothing to draw
+                Some(None) => continue,
+                None => render_pass.set_scissor_rect(0, 0, target_width, target_height),
             }
+            render_pass.set_bind_group(0, &draw.bind_group, &[]);
+            render_pass.set_vertex_buffer(0, draw.vertex_buffer.slice(..));
+            render_pass.set_index_buffer(draw.index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+            render_pass.draw_indexed(0..draw.index_count, 0, 0..1);
         }
+    }
 
-        // Submit the command buffer and present the frame.
-        self.queue.submit(std::iter::once(encoder.finish()));
-        output.present();
-
-        self.requires_redraw = false;
-        self.last_scene_version = Some(scene_version);
-
-        // Increment frame counter and periodically clean up unused textures
-        self.current_frame = self.current_frame.wrapping_add(1);
-        if self.current_frame % 60 == 0 {
-            // Clean up every 60 frames to avoid overhead
-            self.cleanup_unused_textures();
-        }
-
-        Ok(())
+    /// Convert a clip rect to a scissor rect clamped to the render target.
+    ///
+    /// Returns `None` when nothing of the clip rect is on the target.
+    fn scissor_rect(clip: ClipRect, target_size: (u32, u32)) -> Option<(u32, u32, u32, u32)> {
+        let (target_width, target_height) = target_size;
+        let left = clip.x.floor().clamp(0.0, target_width as f32) as u32;
+        let top = clip.y.floor().clamp(0.0, target_height as f32) as u32;
+        let right = (clip.x + clip.width).ceil().clamp(0.0, target_width as f32) as u32;
+        let bottom = (clip.y + clip.height).ceil().clamp(0.0, target_height as f32) as u32;
+        (right > left && bottom > top).then(|| (left, top, right - left, bottom - top))
     }
 
     /// Resize the surface to match the new window size.
@@ -2858,6 +3655,40 @@ impl RenderManager {
         self.bump_render_state_epoch();
     }
 
+    /// Group world sprites of equal draw order by texture (on by default).
+    ///
+    /// Sprites sharing an atlas then merge into one draw call instead of
+    /// breaking the batch wherever textures interleave. Turn it off if
+    /// overlapping sprites with the same draw order must keep object order.
+    pub fn set_sort_by_texture(&mut self, enabled: bool) {
+        if self.sort_by_texture != enabled {
+            self.sort_by_texture = enabled;
+            self.request_redraw();
+        }
+    }
+
+    pub fn sort_by_texture(&self) -> bool {
+        self.sort_by_texture
+    }
+
+    /// Batching statistics for the last rendered frame.
+    pub fn render_stats(&self) -> RenderStats {
+        self.render_stats
+    }
+
+    /// Whether the renderer fell back to a software adapter, see [`Self::new`].
+    pub fn is_safe_mode(&self) -> bool {
+        self.capabilities.safe_mode
+    }
+
+    /// GPU adapter and surface capabilities, with the present mode currently in use.
+    pub fn capabilities(&self) -> RendererCapabilities {
+        RendererCapabilities {
+            present_mode: super::capabilities::present_mode_name(self.surface_config.present_mode).to_string(),
+            ..self.capabilities.clone()
+        }
+    }
+
     /// Configure redraw-on-change behavior.
     ///
     /// When enabled, frames are only rendered when scene state changes.
@@ -2906,3 +3737,56 @@ impl RenderManager {
         &self.queue
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sprite(draw_order: f32, texture_path: Option<&str>) -> DrawItem {
+        DrawItem {
+            draw_order,
+            texture_path: texture_path.map(str::to_string),
+            clip: None,
+            vertices: Vec::new(),
+            indices: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_texture_sort_reduces_batches() {
+        let interleaved = || {
+            vec![
+                sprite(0.0, Some("a.png")),
+                sprite(0.0, Some("b.png")),
+                sprite(0.0, Some("a.png")),
+                sprite(0.0, Some("b.png")),
+                sprite(1.0, Some("a.png")),
+            ]
+        };
+
+        let mut items = interleaved();
+        RenderManager::sort_draw_items(&mut items, false);
+        let mut stats = RenderStats::default();
+        stats.record(&items);
+        assert_eq!((stats.draw_calls, stats.texture_switches), (5, 5));
+
+        let mut items = interleaved();
+        RenderManager::sort_draw_items(&mut items, true);
+        let mut stats = RenderStats::default();
+        stats.record(&items);
+        assert_eq!((stats.draw_items, stats.draw_calls, stats.texture_switches), (5, 3, 3));
+        // Draw order still wins over texture.
+        assert_eq!(items[4].draw_order, 1.0);
+        assert_eq!(stats.items_per_draw_call(), 5.0 / 3.0);
+    }
+
+    #[test]
+    fn test_pixel_perfect_resolution_must_fit_the_device() {
+        assert!(RenderManager::pixel_perfect_resolution_fits((320, 180), 2048));
+        assert!(RenderManager::pixel_perfect_resolution_fits((2048, 2048), 2048));
+        assert!(!RenderManager::pixel_perfect_resolution_fits((0, 180), 2048));
+        assert!(!RenderManager::pixel_perfect_resolution_fits((320, 0), 2048));
+        assert!(!RenderManager::pixel_perfect_resolution_fits((2049, 180), 2048));
+        assert!(!RenderManager::pixel_perfect_resolution_fits((320, u32::MAX), 2048));
+    }
+}
@@ -0,0 +1,624 @@
+//! Saving and loading scenes as JSON.
+//!
+//! A scene file lists GameObjects with their name, type, transform and
+//! hierarchy, plus the components that are plain data: mesh settings,
+//! colliders and UI widgets. Levels can then be authored by hand or in a
+//! tool, kept under version control and reloaded instead of being rebuilt in
+//! code every run.
+//!
+//! Objects reference their parent by their index in the file's `objects`
+//! list, and parents always come before their children. Callbacks and
+//! Python-side state are not saved; look loaded objects up by name to wire
+//! them up again.
+//!
+//! Supported widgets are panels, labels, buttons, checkboxes, toggle
+//! switches, sliders, progress bars and images, saved with their bounds,
+//! depth and content. Styles are not saved, so loaded widgets use the current
+//! theme. Objects carrying other widgets are saved without them.
+
+use super::component::{MeshComponent, MeshGeometry, MeshVertex};
+use super::game_object::{GameObject, ObjectType};
+use super::input_bindings::Fields;
+use super::logging;
+use super::object_manager::ObjectManager;
+use super::physics::{ColliderComponent, ColliderShape};
+use super::text::{TextAlign, VerticalTextAlign};
+use super::ui::button::ButtonComponent;
+use super::ui::checkbox::CheckboxComponent;
+use super::ui::image::{ImageComponent, ImageScaleMode};
+use super::ui::label::LabelComponent;
+use super::ui::panel::PanelComponent;
+use super::ui::progress_bar::{FillDirection, ProgressBarComponent};
+use super::ui::slider::{SliderComponent, SliderOrientation};
+use super::ui::toggle_switch::ToggleSwitchComponent;
+use super::ui::{Rect, UIComponentTrait};
+use crate::types::Color;
+use crate::types::vector::Vec2;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+/// Identifies scene files.
+pub const SCENE_FORMAT: &str = "pyg_engine.scene";
+/// Bumped whenever the scene layout changes incompatibly.
+pub const SCENE_VERSION: u32 = 1;
+
+/// A GameObject read from a scene file, not yet added to the engine.
+pub struct SceneObject {
+    pub object: GameObject,
+    /// Index of the parent in the loaded list; always before this object.
+    pub parent: Option<usize>,
+}
+
+fn vec2(v: Vec2) -> Value {
+    json!([v.x(), v.y()])
+}
+
+fn color(c: &Color) -> Value {
+    json!([c.r(), c.g(), c.b(), c.a()])
+}
+
+fn rect(bounds: Rect) -> Value {
+    json!([bounds.x, bounds.y, bounds.width, bounds.height])
+}
+
+fn mesh_json(mesh: &MeshComponent) -> Value {
+    let vertices: Vec<Value> = mesh
+        .geometry()
+        .vertices()
+        .iter()
+        .map(|vertex| {
+            let (position, uv) = (vertex.position(), vertex.uv());
+            json!([position.x(), position.y(), uv.x(), uv.y()])
+        })
+        .collect();
+    json!({
+        "vertices": vertices,
+        "indices": mesh.geometry().indices(),
+        "fill_color": mesh.fill_color().map(color),
+        "image": mesh.image_path(),
+        "visible": mesh.visible(),
+        "draw_order": mesh.draw_order(),
+    })
+}
+
+fn shape_json(shape: &ColliderShape) -> Value {
+    match shape {
+        ColliderShape::Circle { radius } => json!({ "type": "circle", "radius": radius }),
+        ColliderShape::Box { half_extents } => {
+            json!({ "type": "box", "half_extents": vec2(*half_extents) })
+        }
+        ColliderShape::OBB {
+            half_extents,
+            local_rotation,
+        } => json!({
+            "type": "obb",
+            "half_extents": vec2(*half_extents),
+            "local_rotation": local_rotation,
+        }),
+        ColliderShape::Polygon { vertices } => json!({
+            "type": "polygon",
+            "vertices": vertices.iter().copied().map(vec2).collect::<Vec<_>>(),
+        }),
+    }
+}
+
+fn collider_json(collider: &ColliderComponent) -> Value {
+    let mut value = json!({
+        "shape": shape_json(collider.shape()),
+        "offset": vec2(collider.offset()),
+        "layer": collider.layer(),
+        "mask": collider.collision_mask(),
+        "trigger": collider.is_trigger(),
+    });
+    if let Some(material) = collider.material() {
+        value["material"] = json!(material);
+    }
+    value
+}
+
+fn widget_json(object: &GameObject) -> Option<Value> {
+    let common = |kind: &str, widget: &dyn UIComponentTrait| {
+        json!({ "type": kind, "bounds": rect(widget.bounds()), "depth": widget.ui_depth() })
+    };
+    let (mut widget, fields) = if let Some(panel) = object.get_component::<PanelComponent>() {
+        let fields = json!({ "clip_children": panel.clip_children(), "modal": panel.is_modal() });
+        (common("panel", panel), fields)
+    } else if let Some(label) = object.get_component::<LabelComponent>() {
+        let fields = json!({
+            "text": label.text(),
+            "align": label.align().as_str(),
+            "vertical_align": label.vertical_align().as_str(),
+            "wrap": label.wrap(),
+            "auto_height": label.auto_height(),
+        });
+        (common("label", label), fields)
+    } else if let Some(button) = object.get_component::<ButtonComponent>() {
+        (common("button", button), json!({ "text": button.text() }))
+    } else if let Some(checkbox) = object.get_component::<CheckboxComponent>() {
+        let fields = json!({ "label": checkbox.label(), "checked": checkbox.is_checked() });
+        (common("checkbox", checkbox), fields)
+    } else if let Some(toggle) = object.get_component::<ToggleSwitchComponent>() {
+        let fields = json!({ "label": toggle.label(), "checked": toggle.is_checked() });
+        (common("toggle_switch", toggle), fields)
+    } else if let Some(slider) = object.get_component::<SliderComponent>() {
+        let fields = json!({
+            "min": slider.min(),
+            "max": slider.max(),
+            "step": slider.step(),
+            "value": slider.value(),
+            "orientation": slider.orientation().as_str(),
+        });
+        (common("slider", slider), fields)
+    } else if let Some(bar) = object.get_component::<ProgressBarComponent>() {
+        let fields = json!({
+            "value": bar.value(),
+            "text": bar.text(),
+            "fill_direction": bar.fill_direction().as_str(),
+        });
+        (common("progress_bar", bar), fields)
+    } else if let Some(image) = object.get_component::<ImageComponent>() {
+        let fields = json!({ "texture": image.texture(), "scale_mode": image.scale_mode().as_str() });
+        (common("image", image), fields)
+    } else {
+        return None;
+    };
+    if let (Some(widget), Value::Object(fields)) = (widget.as_object_mut(), fields) {
+        widget.extend(fields);
+    }
+    Some(widget)
+}
+
+fn object_json(object: &GameObject, parent: Option<usize>) -> Value {
+    let transform = object.transform();
+    let mut value = json!({
+        "name": object.name(),
+        "type": object.get_object_type().as_str(),
+        "parent": parent,
+        "enabled": object.enabled_self(),
+        "position": vec2(*transform.position()),
+        "rotation": transform.rotation(),
+        "scale": vec2(*transform.scale()),
+    });
+    let fields = value.as_object_mut().expect("object json is an object");
+    if !object.tags().is_empty() {
+        fields.insert("tags".to_string(), json!(object.tags()));
+    }
+    if let Some(mesh) = object.mesh_component() {
+        fields.insert("mesh".to_string(), mesh_json(mesh));
+    }
+    if let Some(collider) = object.get_component::<ColliderComponent>() {
+        fields.insert("collider".to_string(), collider_json(collider));
+    }
+    if let Some(widget) = widget_json(object) {
+        fields.insert("widget".to_string(), widget);
+    }
+    value
+}
+
+fn has_ancestor_in(object_manager: &ObjectManager, object: &GameObject, ids: &[u32]) -> bool {
+    let mut parent_id = object.parent_id();
+    while let Some(id) = parent_id {
+        if ids.contains(&id) {
+            return true;
+        }
+        parent_id = object_manager.get_object_by_id(id).and_then(GameObject::parent_id);
+    }
+    false
+}
+
+/// Build the scene document for `root_ids` and everything parented under them.
+///
+/// Ids that no longer exist, or that are descendants of another root, are
+/// skipped.
+pub fn scene_to_json(object_manager: &ObjectManager, name: &str, root_ids: &[u32]) -> Value {
+    let mut objects = Vec::new();
+    let mut indices: HashMap<u32, usize> = HashMap::new();
+    let mut pending: Vec<(u32, Option<usize>)> = root_ids.iter().rev().map(|&id| (id, None)).collect();
+    while let Some((id, parent)) = pending.pop() {
+        if indices.contains_key(&id) {
+            continue;
+        }
+        let Some(object) = object_manager.get_object_by_id(id) else {
+            continue;
+        };
+        // A root under another saved root is saved with its ancestor.
+        if parent.is_none() && has_ancestor_in(object_manager, object, root_ids) {
+            continue;
+        }
+        let index = objects.len();
+        indices.insert(id, index);
+        objects.push(object_json(object, parent));
+        pending.extend(object.children().iter().rev().map(|&child| (child, Some(index))));
+    }
+
+    json!({
+        "format": SCENE_FORMAT,
+        "version": SCENE_VERSION,
+        "name": name,
+        "objects": objects,
+    })
+}
+
+fn parse_vec2(fields: &Fields, field: &str, default: Vec2) -> Result<Vec2, String> {
+    let Some(value) = fields.get(field) else {
+        return Ok(default);
+    };
+    match parse_numbers(value)?.as_slice() {
+        [x, y] => Ok(Vec2::new(*x, *y)),
+        _ => Err(format!("{}.{field} must be [x, y]", fields.context)),
+    }
+}
+
+fn parse_numbers(value: &Value) -> Result<Vec<f32>, String> {
+    value
+        .as_array()
+        .and_then(|items| items.iter().map(|item| item.as_f64().map(|n| n as f32)).collect())
+        .ok_or_else(|| "expected a list of numbers".to_string())
+}
+
+fn parse_color(fields: &Fields, field: &str) -> Result<Option<Color>, String> {
+    let Some(value) = fields.get(field) else {
+        return Ok(None);
+    };
+    match parse_numbers(value).ok().as_deref() {
+        Some([r, g, b]) => Ok(Some(Color::new(*r, *g, *b, 1.0))),
+        Some([r, g, b, a]) => Ok(Some(Color::new(*r, *g, *b, *a))),
+        _ => Err(format!("{}.{field} must be [r, g, b] or [r, g, b, a]", fields.context)),
+    }
+}
+
+fn parse_mesh(value: &Value, context: &str) -> Result<MeshComponent, String> {
+    let fields = Fields::new(value, context)?;
+    let mut geometry = MeshGeometry::default();
+    if fields.get("vertices").is_some() || fields.get("indices").is_some() {
+        let vertices = fields
+            .list("vertices")?
+            .iter()
+            .map(|vertex| match parse_numbers(vertex).ok().as_deref() {
+                Some([x, y, u, v]) => Ok(MeshVertex::new(Vec2::new(*x, *y), Vec2::new(*u, *v))),
+                _ => Err(format!("{context}.vertices must be a list of [x, y, u, v]")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let indices = fields
+            .list("indices")?
+            .iter()
+            .map(|index| {
+                index
+                    .as_u64()
+                    .and_then(|index| u32::try_from(index).ok())
+                    .filter(|&index| (index as usize) < vertices.len())
+                    .ok_or_else(|| format!("{context}.indices must be vertex indices"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        geometry = MeshGeometry::new(vertices, indices);
+    }
+    let mut mesh = MeshComponent::new("Mesh")
+        .with_geometry(geometry)
+        .with_fill_color(parse_color(&fields, "fill_color")?)
+        .with_image_path(fields.str("image")?.map(str::to_string))
+        .with_draw_order(fields.f32_or("draw_order", 0.0)?);
+    mesh.set_visible(fields.bool_or("visible", true)?);
+    Ok(mesh)
+}
+
+fn parse_shape(value: &Value, context: &str) -> Result<ColliderShape, String> {
+    let fields = Fields::new(value, context)?;
+    match fields.str("type")? {
+        Some("circle") => Ok(ColliderShape::Circle {
+            radius: fields.f32_or("radius", 0.5)?,
+        }),
+        Some("box") => Ok(ColliderShape::Box {
+            half_extents: parse_vec2(&fields, "half_extents", Vec2::new(0.5, 0.5))?,
+        }),
+        Some("obb") => Ok(ColliderShape::OBB {
+            half_extents: parse_vec2(&fields, "half_extents", Vec2::new(0.5, 0.5))?,
+            local_rotation: fields.f32_or("local_rotation", 0.0)?,
+        }),
+        Some("polygon") => {
+            let vertices = fields
+                .list("vertices")?
+                .iter()
+                .map(|vertex| match parse_numbers(vertex).ok().as_deref() {
+                    Some([x, y]) => Ok(Vec2::new(*x, *y)),
+                    _ => Err(format!("{context}.vertices must be a list of [x, y]")),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if vertices.len() < 3 {
+                return Err(format!("{context} polygon needs at least 3 vertices"));
+            }
+            Ok(ColliderShape::Polygon { vertices })
+        }
+        other => Err(format!("Unknown collider shape {other:?} in {context}")),
+    }
+}
+
+fn parse_collider(value: &Value, context: &str) -> Result<ColliderComponent, String> {
+    let fields = Fields::new(value, context)?;
+    let shape = match fields.get("shape") {
+        Some(shape) => parse_shape(shape, &format!("{context}.shape"))?,
+        None => return Err(format!("{context}.shape is required")),
+    };
+    let collider = ColliderComponent::new("Collider")
+        .with_shape(shape)
+        .with_offset(parse_vec2(&fields, "offset", Vec2::new(0.0, 0.0))?)
+        .as_trigger(fields.bool_or("trigger", false)?)
+        .with_material(fields.str("material")?.map(str::to_string));
+    let collider = match u32_field(&fields, "layer")? {
+        Some(layer) => collider.with_layer(layer),
+        None => collider,
+    };
+    Ok(match u32_field(&fields, "mask")? {
+        Some(mask) => collider.with_mask(mask),
+        None => collider,
+    })
+}
+
+fn u32_field(fields: &Fields, field: &str) -> Result<Option<u32>, String> {
+    fields
+        .u64(field)?
+        .map(|value| u32::try_from(value).map_err(|_| format!("{}.{field} is out of range", fields.context)))
+        .transpose()
+}
+
+fn parse_enum<T>(fields: &Fields, field: &str, parse: fn(&str) -> Option<T>) -> Result<Option<T>, String> {
+    fields
+        .str(field)?
+        .map(|value| parse(value).ok_or_else(|| format!("Unknown {}.{field} '{value}'", fields.context)))
+        .transpose()
+}
+
+fn parse_widget(value: &Value, context: &str, name: &str) -> Result<Box<dyn super::component::ComponentTrait>, String> {
+    let fields = Fields::new(value, context)?;
+    let bounds = match fields.get("bounds") {
+        Some(value) => match parse_numbers(value).ok().as_deref() {
+            Some([x, y, width, height]) => Rect::new(*x, *y, *width, *height),
+            _ => return Err(format!("{context}.bounds must be [x, y, width, height]")),
+        },
+        None => Rect::new(0.0, 0.0, 100.0, 30.0),
+    };
+    let (x, y, width, height) = (bounds.x, bounds.y, bounds.width, bounds.height);
+    let depth = fields.f32_or("depth", 0.0)?;
+    let text = fields.str("text")?.unwrap_or_default();
+    let label = fields.str("label")?.unwrap_or_default();
+    let checked = fields.bool_or("checked", false)?;
+
+    let widget: Box<dyn super::component::ComponentTrait> = match fields.str("type")? {
+        Some("panel") => Box::new(
+            PanelComponent::new(name)
+                .with_bounds(x, y, width, height)
+                .with_depth(depth)
+                .with_clip_children(fields.bool_or("clip_children", false)?)
+                .with_modal(fields.bool_or("modal", false)?),
+        ),
+        Some("label") => {
+            let mut widget = LabelComponent::new(name)
+                .with_text(text)
+                .with_bounds(x, y, width, height)
+                .with_depth(depth)
+                .with_wrap(fields.bool_or("wrap", false)?)
+                .with_auto_height(fields.bool_or("auto_height", false)?);
+            if let Some(align) = parse_enum(&fields, "align", TextAlign::parse)? {
+                widget = widget.with_align(align);
+            }
+            if let Some(align) = parse_enum(&fields, "vertical_align", VerticalTextAlign::parse)? {
+                widget = widget.with_vertical_align(align);
+            }
+            Box::new(widget)
+        }
+        Some("button") => Box::new(
+            ButtonComponent::new(name)
+                .with_text(text)
+                .with_bounds(x, y, width, height)
+                .with_depth(depth),
+        ),
+        Some("checkbox") => Box::new(
+            CheckboxComponent::new(name)
+                .with_label(label)
+                .with_checked(checked)
+                .with_bounds(x, y, width, height)
+                .with_depth(depth),
+        ),
+        Some("toggle_switch") => Box::new(
+            ToggleSwitchComponent::new(name)
+                .with_label(label)
+                .with_checked(checked)
+                .with_bounds(x, y, width, height)
+                .with_depth(depth),
+        ),
+        Some("slider") => {
+            let min = fields.f32_or("min", 0.0)?;
+            let mut widget = SliderComponent::new(name)
+                .with_range(min, fields.f32_or("max", 1.0)?)
+                .with_step(fields.f32_or("step", 0.0)?)
+                .with_value(fields.f32_or("value", min)?)
+                .with_bounds(x, y, width, height)
+                .with_depth(depth);
+            if let Some(orientation) = parse_enum(&fields, "orientation", SliderOrientation::parse)? {
+                widget = widget.with_orientation(orientation);
+            }
+            Box::new(widget)
+        }
+        Some("progress_bar") => {
+            let mut widget = ProgressBarComponent::new(name)
+                .with_value(fields.f32_or("value", 0.0)?)
+                .with_bounds(x, y, width, height)
+                .with_depth(depth);
+            if let Some(text) = fields.str("text")? {
+                widget = widget.with_text(text);
+            }
+            if let Some(direction) = parse_enum(&fields, "fill_direction", FillDirection::parse)? {
+                widget = widget.with_fill_direction(direction);
+            }
+            Box::new(widget)
+        }
+        Some("image") => {
+            let mut widget = ImageComponent::new(name)
+                .with_bounds(x, y, width, height)
+                .with_depth(depth);
+            if let Some(texture) = fields.str("texture")? {
+                widget = widget.with_texture(texture);
+            }
+            if let Some(scale_mode) = parse_enum(&fields, "scale_mode", ImageScaleMode::parse)? {
+                widget = widget.with_scale_mode(scale_mode);
+            }
+            Box::new(widget)
+        }
+        other => return Err(format!("Unknown widget type {other:?} in {context}")),
+    };
+    Ok(widget)
+}
+
+fn parse_object(value: &Value, index: usize) -> Result<SceneObject, String> {
+    let context = format!("objects[{index}]");
+    let fields = Fields::new(value, &context)?;
+    let name = fields.str("name")?.unwrap_or("GameObject");
+    let mut object = GameObject::new_named(name.to_string());
+    if let Some(object_type) = parse_enum(&fields, "type", ObjectType::parse)? {
+        object.set_object_type(object_type);
+    }
+    object.set_position(parse_vec2(&fields, "position", Vec2::new(0.0, 0.0))?);
+    object.set_rotation(fields.f32_or("rotation", 0.0)?);
+    object.set_scale(parse_vec2(&fields, "scale", Vec2::new(1.0, 1.0))?);
+    object.set_enabled_self(fields.bool_or("enabled", true)?);
+    for tag in fields.strings("tags")? {
+        object.add_tag(tag);
+    }
+    if let Some(mesh) = fields.get("mesh") {
+        object.add_mesh_component(parse_mesh(mesh, &format!("{context}.mesh"))?);
+    }
+    if let Some(collider) = fields.get("collider") {
+        object.add_component(Box::new(parse_collider(collider, &format!("{context}.collider"))?));
+    }
+    if let Some(widget) = fields.get("widget") {
+        object.add_component(parse_widget(widget, &format!("{context}.widget"), name)?);
+    }
+
+    let parent = fields
+        .u64("parent")?
+        .map(|parent| {
+            usize::try_from(parent)
+                .ok()
+                .filter(|&parent| parent < index)
+                .ok_or_else(|| format!("{context}.parent must be the index of an earlier object"))
+        })
+        .transpose()?;
+    Ok(SceneObject { object, parent })
+}
+
+/// Read the objects in a scene document and the scene name saved with them.
+///
+/// The document is checked in full first; on error no objects are returned.
+pub fn parse_scene_json(value: &Value) -> Result<(Option<String>, Vec<SceneObject>), String> {
+    let document = Fields::new(value, "Scene")?;
+    if let Some(format) = document.get("format")
+        && format.as_str() != Some(SCENE_FORMAT)
+    {
+        return Err(format!("Not a scene file (format {format})"));
+    }
+    if let Some(version) = document.u64("version")?
+        && version > u64::from(SCENE_VERSION)
+    {
+        return Err(format!(
+            "Scene version {version} is newer than the supported version {SCENE_VERSION}"
+        ));
+    }
+    let objects = document
+        .list("objects")?
+        .iter()
+        .enumerate()
+        .map(|(index, object)| parse_object(object, index))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((document.str("name")?.map(str::to_string), objects))
+}
+
+/// Write a scene document to a JSON file.
+pub fn save_scene(object_manager: &ObjectManager, name: &str, root_ids: &[u32], path: &str) -> Result<(), String> {
+    let skipped = root_ids
+        .iter()
+        .filter_map(|&id| object_manager.get_object_by_id(id))
+        .filter(|object| object.get_object_type() == ObjectType::UIObject && widget_json(object).is_none())
+        .count();
+    if skipped > 0 {
+        logging::log_warn(&format!(
+            "Scene '{name}': {skipped} UI object(s) use widgets scene files do not support and are saved without them"
+        ));
+    }
+    let json = serde_json::to_string_pretty(&scene_to_json(object_manager, name, root_ids))
+        .map_err(|e| format!("Failed to serialize scene: {e}"))?;
+    std::fs::write(path, json).map_err(|e| format!("Failed to write scene '{path}': {e}"))
+}
+
+/// Read the objects saved in a scene JSON file.
+pub fn load_scene(path: &str) -> Result<(Option<String>, Vec<SceneObject>), String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("Failed to read scene '{path}': {e}"))?;
+    let value: Value = serde_json::from_str(&text).map_err(|e| format!("Failed to parse scene '{path}': {e}"))?;
+    parse_scene_json(&value).map_err(|e| format!("Invalid scene '{path}': {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scene_round_trips_objects_and_components() {
+        let mut object_manager = ObjectManager::new();
+        let mut player = GameObject::new_named("Player".to_string());
+        player.set_position(Vec2::new(3.0, -2.0));
+        player.set_rotation(0.5);
+        player.add_mesh_component(
+            MeshComponent::new("Mesh")
+                .with_geometry(MeshGeometry::rectangle(2.0, 1.0))
+                .with_fill_color(Some(Color::new(1.0, 0.0, 0.0, 1.0))),
+        );
+        player.add_component(Box::new(
+            ColliderComponent::new("Collider")
+                .with_shape(ColliderShape::circle(0.75))
+                .with_layer(2)
+                .with_material(Some("metal".to_string()))
+                .as_trigger(true),
+        ));
+        player.add_tag("player");
+        let player_id = object_manager.add_object(player).unwrap();
+        let mut title = GameObject::new_named("Title".to_string());
+        title.set_object_type(ObjectType::UIObject);
+        title.add_component(Box::new(LabelComponent::new("Title").with_text("Level 1").with_bounds(
+            10.0, 20.0, 200.0, 40.0,
+        )));
+        let title_id = object_manager.add_object(title).unwrap();
+        object_manager.add_child(player_id, title_id).unwrap();
+
+        let document = scene_to_json(&object_manager, "level", &[player_id, title_id]);
+        let (name, objects) = parse_scene_json(&document).unwrap();
+        assert_eq!(name.as_deref(), Some("level"));
+        assert_eq!(objects.len(), 2, "the child is saved once, under its parent");
+
+        let player = &objects[0].object;
+        assert_eq!((player.position(), player.rotation()), (Vec2::new(3.0, -2.0), 0.5));
+        assert_eq!(player.tags(), ["player"]);
+        let mesh = player.mesh_component().unwrap();
+        assert_eq!(mesh.geometry().indices(), MeshGeometry::rectangle(2.0, 1.0).indices());
+        assert_eq!(mesh.fill_color().map(Color::r), Some(1.0));
+        let collider = player.get_component::<ColliderComponent>().unwrap();
+        assert!(collider.is_trigger() && collider.layer() == 2);
+        assert_eq!(collider.material(), Some("metal"));
+        assert!(matches!(collider.shape(), ColliderShape::Circle { radius } if *radius == 0.75));
+
+        assert_eq!(objects[1].parent, Some(0));
+        let label = objects[1].object.get_component::<LabelComponent>().unwrap();
+        assert_eq!((label.text(), label.bounds()), ("Level 1", Rect::new(10.0, 20.0, 200.0, 40.0)));
+        assert_eq!(objects[1].object.get_object_type(), ObjectType::UIObject);
+    }
+
+    #[test]
+    fn test_invalid_scene_reports_the_field() {
+        let document = json!({
+            "format": SCENE_FORMAT,
+            "objects": [{ "name": "Wall", "collider": { "shape": { "type": "hexagon" } } }],
+        });
+        let error = parse_scene_json(&document).err().unwrap();
+        assert!(error.contains("objects[0].collider.shape"), "{error}");
+
+        let document = json!({ "objects": [{ "parent": 0 }] });
+        assert!(parse_scene_json(&document).is_err(), "an object cannot parent itself");
+    }
+}
@@ -0,0 +1,198 @@
+//! Named scenes: collections of GameObjects that load and unload together.
+//!
+//! The scene manager only keeps the books. The engine owns the objects and
+//! asks the manager which scene a new object belongs to and which objects to
+//! destroy when a scene is unloaded.
+//!
+//! # Active Scene
+//!
+//! Objects added while a scene is active join that scene. Loading a scene
+//! makes it active; objects added while no scene is active belong to none
+//! and survive every unload, which is how persistent objects such as the
+//! default camera or a music player are kept across levels.
+//!
+//! # Usage
+//!
+//! ```rust
+//! use pyg_engine_native::core::scene_manager::SceneManager;
+//!
+//! let mut scenes = SceneManager::new();
+//! scenes.load_scene("level_1");
+//! scenes.add_object(1);
+//! scenes.load_scene("hud");
+//! scenes.add_object(2);
+//!
+//! assert_eq!(scenes.scene_of(1), Some("level_1"));
+//! assert_eq!(scenes.unload_scene("level_1"), Some(vec![1]));
+//! ```
+
+/// A loaded scene and the objects that belong to it.
+#[derive(Debug, Clone)]
+pub struct Scene {
+    name: String,
+    object_ids: Vec<u32>,
+}
+
+impl Scene {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Ids of the scene's objects, in the order they were added.
+    pub fn object_ids(&self) -> &[u32] {
+        &self.object_ids
+    }
+}
+
+/// Tracks loaded scenes, in load order, and the active scene.
+#[derive(Debug, Default)]
+pub struct SceneManager {
+    scenes: Vec<Scene>,
+    active: Option<usize>,
+}
+
+impl SceneManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn index_of(&self, name: &str) -> Option<usize> {
+        self.scenes.iter().position(|scene| scene.name == name)
+    }
+
+    /// Load a scene, empty if it is new, and make it active.
+    ///
+    /// Returns false if the scene was already loaded; it is still made active.
+    pub fn load_scene(&mut self, name: &str) -> bool {
+        if let Some(index) = self.index_of(name) {
+            self.active = Some(index);
+            return false;
+        }
+        self.scenes.push(Scene {
+            name: name.to_string(),
+            object_ids: Vec::new(),
+        });
+        self.active = Some(self.scenes.len() - 1);
+        true
+    }
+
+    /// Forget a scene and return the ids of its objects, or `None` if it was
+    /// not loaded. If it was active, the most recently loaded remaining scene
+    /// becomes active.
+    pub fn unload_scene(&mut self, name: &str) -> Option<Vec<u32>> {
+        let index = self.index_of(name)?;
+        let scene = self.scenes.remove(index);
+        self.active = match self.active {
+            Some(active) if active == index => self.scenes.len().checked_sub(1),
+            Some(active) if active > index => Some(active - 1),
+            active => active,
+        };
+        Some(scene.object_ids)
+    }
+
+    pub fn is_loaded(&self, name: &str) -> bool {
+        self.index_of(name).is_some()
+    }
+
+    /// Get the loaded scenes in load order.
+    pub fn scenes(&self) -> &[Scene] {
+        &self.scenes
+    }
+
+    pub fn scene(&self, name: &str) -> Option<&Scene> {
+        self.scenes.iter().find(|scene| scene.name == name)
+    }
+
+    /// Get the scene new objects are added to.
+    pub fn active_scene(&self) -> Option<&str> {
+        self.active.map(|index| self.scenes[index].name.as_str())
+    }
+
+    /// Set the scene new objects are added to, or `None` for no scene.
+    ///
+    /// Returns false, leaving the active scene unchanged, if `name` is not loaded.
+    pub fn set_active_scene(&mut self, name: Option<&str>) -> bool {
+        match name {
+            Some(name) => match self.index_of(name) {
+                Some(index) => {
+                    self.active = Some(index);
+                    true
+                }
+                None => false,
+            },
+            None => {
+                self.active = None;
+                true
+            }
+        }
+    }
+
+    /// Add a newly created object to the active scene, if any.
+    pub fn add_object(&mut self, object_id: u32) {
+        if let Some(index) = self.active {
+            self.scenes[index].object_ids.push(object_id);
+        }
+    }
+
+    /// Get the name of the scene an object belongs to.
+    pub fn scene_of(&self, object_id: u32) -> Option<&str> {
+        self.scenes
+            .iter()
+            .find(|scene| scene.object_ids.contains(&object_id))
+            .map(|scene| scene.name.as_str())
+    }
+
+    /// Move an object to another loaded scene, or out of every scene with `None`.
+    ///
+    /// Returns false if `scene` is not loaded.
+    pub fn move_object(&mut self, object_id: u32, scene: Option<&str>) -> bool {
+        let target = match scene {
+            Some(name) => match self.index_of(name) {
+                Some(index) => Some(index),
+                None => return false,
+            },
+            None => None,
+        };
+        self.forget_objects(&[object_id]);
+        if let Some(index) = target {
+            self.scenes[index].object_ids.push(object_id);
+        }
+        true
+    }
+
+    /// Drop destroyed objects from their scenes.
+    pub fn forget_objects(&mut self, object_ids: &[u32]) {
+        for scene in &mut self.scenes {
+            scene.object_ids.retain(|id| !object_ids.contains(id));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_objects_join_the_active_scene() {
+        let mut scenes = SceneManager::new();
+        scenes.add_object(1);
+        scenes.load_scene("level");
+        scenes.add_object(2);
+        scenes.load_scene("hud");
+        scenes.add_object(3);
+        assert_eq!(scenes.scene_of(1), None, "objects added with no scene persist");
+        assert_eq!(scenes.scene_of(2), Some("level"));
+
+        assert!(scenes.set_active_scene(Some("level")));
+        assert!(!scenes.set_active_scene(Some("missing")));
+        scenes.add_object(4);
+        assert!(scenes.move_object(3, None));
+        assert_eq!(scenes.scene("level").unwrap().object_ids(), [2, 4]);
+
+        assert_eq!(scenes.unload_scene("level"), Some(vec![2, 4]));
+        assert_eq!(scenes.active_scene(), Some("hud"));
+        assert_eq!(scenes.unload_scene("level"), None);
+        assert_eq!(scenes.unload_scene("hud"), Some(vec![]));
+        assert_eq!(scenes.active_scene(), None);
+    }
+}
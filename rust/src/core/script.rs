@@ -0,0 +1,385 @@
+//! Scripted components: gameplay behaviour written outside the engine, e.g.
+//! as a Python class, attached to GameObjects and driven by the update loop.
+//!
+//! A `ScriptComponent` wraps a `ScriptBehaviour`. The engine does not call
+//! behaviours while it walks the scene, since a script will usually want to
+//! read or change the scene itself. Instead each update phase queues the
+//! `ScriptCall`s that are due, and the engine runs them once the phase has
+//! released the object manager:
+//!
+//! 1. `on_start` once, before a script's first `update` (and messages).
+//! 2. `update(delta_time)` every frame.
+//! 3. `fixed_update(fixed_time)` every fixed step, before collision detection.
+//! 4. `on_collision_enter` / `on_collision_stay` / `on_collision_exit` for
+//!    the collisions of the fixed step.
+//!
+//! `on_message` runs in the update phase, before `update`, for each message
+//! sent to the script's object.
+//!
+//! Scripts on disabled objects, and disabled scripts, are skipped. A script
+//! that is added or enabled mid-frame starts on the next frame.
+//!
+//! # Usage
+//!
+//! ```rust
+//! use pyg_engine_native::core::script::{ScriptBehaviour, ScriptComponent};
+//! use std::any::Any;
+//! use std::sync::Mutex;
+//!
+//! struct Spinner {
+//!     angle: Mutex<f32>,
+//! }
+//!
+//! impl ScriptBehaviour for Spinner {
+//!     fn update(&self, delta_time: f32) {
+//!         *self.angle.lock().unwrap() += delta_time;
+//!     }
+//!
+//!     fn as_any(&self) -> &dyn Any {
+//!         self
+//!     }
+//! }
+//!
+//! let script = ScriptComponent::new("Spinner", Box::new(Spinner { angle: Mutex::new(0.0) }));
+//! ```
+
+use super::component::{ComponentTrait, next_component_id};
+use super::event_bus::{EventPayload, Message};
+use super::game_object::GameObject;
+use super::object_manager::ObjectManager;
+use super::physics::{CollisionEvent, CollisionEventType};
+use super::time::Time;
+use crate::types::vector::Vec2;
+use std::any::Any;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Lifecycle hooks of a scripted component. Every hook is optional.
+///
+/// Hooks take `&self` like `ComponentTrait`'s, so a behaviour can be reached
+/// while one of its hooks runs; keep mutable state behind interior mutability.
+pub trait ScriptBehaviour: Send + Sync {
+    /// Called once, before the first `update`.
+    fn on_start(&self) {}
+
+    /// Called every frame with the frame's delta time.
+    fn update(&self, _delta_time: f32) {}
+
+    /// Called every fixed step, before collision detection.
+    fn fixed_update(&self, _fixed_time: f32) {}
+
+    /// Called when a collision with `other_id` starts. `normal` points from
+    /// this object towards the other.
+    fn on_collision_enter(&self, _other_id: u32, _normal: Vec2, _penetration: f32) {}
+
+    /// Called each fixed step while a collision with `other_id` goes on.
+    fn on_collision_stay(&self, _other_id: u32, _normal: Vec2, _penetration: f32) {}
+
+    /// Called when a collision with `other_id` ends.
+    fn on_collision_exit(&self, _other_id: u32) {}
+
+    /// Called in the update phase for each message sent to the object.
+    fn on_message(&self, _name: &str, _payload: &EventPayload) {}
+
+    /// Downcast to Any, e.g. to get a binding's script object back.
+    fn as_any(&self) -> &dyn Any;
+}
+
+type SharedBehaviour = Arc<dyn ScriptBehaviour>;
+
+/// Component that runs a `ScriptBehaviour`.
+///
+/// Clones share the behaviour, so the copies the object manager hands out
+/// drive the same script.
+#[derive(Clone)]
+pub struct ScriptComponent {
+    component_id: u32,
+    name: String,
+    behaviour: SharedBehaviour,
+    started: Arc<AtomicBool>,
+    enabled_self: bool,
+    enabled_in_hierarchy: bool,
+}
+
+impl std::fmt::Debug for ScriptComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScriptComponent")
+            .field("name", &self.name)
+            .field("started", &self.started.load(Ordering::Relaxed))
+            .field("enabled_self", &self.enabled_self)
+            .finish()
+    }
+}
+
+impl ScriptComponent {
+    pub fn new(name: impl Into<String>, behaviour: Box<dyn ScriptBehaviour>) -> Self {
+        Self {
+            component_id: next_component_id(),
+            name: name.into(),
+            behaviour: Arc::from(behaviour),
+            started: Arc::new(AtomicBool::new(false)),
+            enabled_self: true,
+            enabled_in_hierarchy: true,
+        }
+    }
+
+    pub fn behaviour(&self) -> &dyn ScriptBehaviour {
+        self.behaviour.as_ref()
+    }
+
+    /// Whether `on_start` has been queued for this script.
+    pub fn is_started(&self) -> bool {
+        self.started.load(Ordering::Relaxed)
+    }
+
+    fn call(&self, hook: ScriptHook) -> ScriptCall {
+        ScriptCall {
+            behaviour: Arc::clone(&self.behaviour),
+            hook,
+        }
+    }
+}
+
+impl ComponentTrait for ScriptComponent {
+    fn new(name: String) -> Self {
+        struct Empty;
+        impl ScriptBehaviour for Empty {
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+        }
+        ScriptComponent::new(name, Box::new(Empty))
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn id(&self) -> u32 {
+        self.component_id
+    }
+
+    fn component_type(&self) -> &'static str {
+        "Script"
+    }
+
+    fn is_enabled_self(&self) -> bool {
+        self.enabled_self
+    }
+
+    fn set_enabled_self(&mut self, enabled: bool) {
+        self.enabled_self = enabled;
+    }
+
+    fn is_enabled_in_hierarchy(&self) -> bool {
+        self.enabled_in_hierarchy
+    }
+
+    fn set_enabled_in_hierarchy(&mut self, enabled: bool) {
+        self.enabled_in_hierarchy = enabled;
+    }
+
+    // The engine queues script hooks instead; see the module docs.
+    fn update(&self, _time: &Time) {}
+
+    fn fixed_update(&self, _time: &Time, _fixed_time: f32) {}
+
+    fn on_start(&self) {}
+
+    fn on_destroy(&self) {}
+
+    fn on_enable(&self) {}
+
+    fn on_disable(&self) {}
+
+    fn clone_component(&self) -> Box<dyn ComponentTrait> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+enum ScriptHook {
+    Start,
+    Update(f32),
+    FixedUpdate(f32),
+    CollisionEnter(u32, Vec2, f32),
+    CollisionStay(u32, Vec2, f32),
+    CollisionExit(u32),
+    Message(Arc<Message>),
+}
+
+/// A script hook that is due, queued by the engine's update phases.
+pub struct ScriptCall {
+    behaviour: SharedBehaviour,
+    hook: ScriptHook,
+}
+
+impl ScriptCall {
+    /// Run the hook. Call this without holding the object manager lock.
+    pub fn invoke(self) {
+        let behaviour = self.behaviour.as_ref();
+        match self.hook {
+            ScriptHook::Start => behaviour.on_start(),
+            ScriptHook::Update(delta_time) => behaviour.update(delta_time),
+            ScriptHook::FixedUpdate(fixed_time) => behaviour.fixed_update(fixed_time),
+            ScriptHook::CollisionEnter(other_id, normal, penetration) => {
+                behaviour.on_collision_enter(other_id, normal, penetration)
+            }
+            ScriptHook::CollisionStay(other_id, normal, penetration) => {
+                behaviour.on_collision_stay(other_id, normal, penetration)
+            }
+            ScriptHook::CollisionExit(other_id) => behaviour.on_collision_exit(other_id),
+            ScriptHook::Message(message) => behaviour.on_message(&message.name, &message.payload),
+        }
+    }
+}
+
+fn active_scripts(object_manager: &ObjectManager, object_id: u32) -> Vec<&ScriptComponent> {
+    object_manager
+        .get_object_by_id(object_id)
+        .map(object_scripts)
+        .unwrap_or_default()
+}
+
+fn object_scripts(object: &GameObject) -> Vec<&ScriptComponent> {
+    if !object.is_enabled() {
+        return Vec::new();
+    }
+    object
+        .get_components::<ScriptComponent>()
+        .into_iter()
+        .filter(|script| script.is_effectively_enabled())
+        .collect()
+}
+
+/// Queue `on_start` for scripts that have not started, then the messages
+/// sent to their objects, then `update` for every active script.
+pub fn queue_update_calls(
+    object_manager: &ObjectManager,
+    delta_time: f32,
+    messages: &[Arc<Message>],
+    calls: &mut Vec<ScriptCall>,
+) {
+    let mut updates = Vec::new();
+    for object in object_manager.iter() {
+        for script in object_scripts(object) {
+            if !script.started.swap(true, Ordering::Relaxed) {
+                calls.push(script.call(ScriptHook::Start));
+            }
+            updates.push(script.call(ScriptHook::Update(delta_time)));
+        }
+    }
+    for message in messages {
+        for script in active_scripts(object_manager, message.target_id) {
+            calls.push(script.call(ScriptHook::Message(Arc::clone(message))));
+        }
+    }
+    calls.extend(updates);
+}
+
+/// Queue `fixed_update` for every started, active script.
+pub fn queue_fixed_update_calls(object_manager: &ObjectManager, fixed_time: f32, calls: &mut Vec<ScriptCall>) {
+    for object in object_manager.iter() {
+        for script in object_scripts(object) {
+            if script.is_started() {
+                calls.push(script.call(ScriptHook::FixedUpdate(fixed_time)));
+            }
+        }
+    }
+}
+
+/// Queue the collision hooks of both objects of each event.
+pub fn queue_collision_calls(object_manager: &ObjectManager, events: &[CollisionEvent], calls: &mut Vec<ScriptCall>) {
+    for event in events {
+        let (normal, penetration) = event
+            .manifold
+            .as_ref()
+            .map(|manifold| (manifold.normal, manifold.penetration_depth))
+            .unwrap_or((Vec2::new(0.0, 0.0), 0.0));
+        let sides = [
+            (event.object_id_a, event.object_id_b, normal),
+            (event.object_id_b, event.object_id_a, normal.multiply_scalar(-1.0)),
+        ];
+        for (object_id, other_id, normal) in sides {
+            let hook = match event.event_type {
+                CollisionEventType::Enter => ScriptHook::CollisionEnter(other_id, normal, penetration),
+                CollisionEventType::Stay => ScriptHook::CollisionStay(other_id, normal, penetration),
+                CollisionEventType::Exit => ScriptHook::CollisionExit(other_id),
+            };
+            for script in active_scripts(object_manager, object_id) {
+                calls.push(script.call(hook.clone()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl ScriptBehaviour for Recorder {
+        fn on_start(&self) {
+            self.0.lock().unwrap().push("start".to_string());
+        }
+
+        fn update(&self, delta_time: f32) {
+            self.0.lock().unwrap().push(format!("update {delta_time}"));
+        }
+
+        fn fixed_update(&self, fixed_time: f32) {
+            self.0.lock().unwrap().push(format!("fixed {fixed_time}"));
+        }
+
+        fn on_message(&self, name: &str, payload: &EventPayload) {
+            self.0.lock().unwrap().push(format!("{name} {:?}", payload.as_int()));
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_scripts_start_once_and_skip_disabled_objects() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut object_manager = ObjectManager::new();
+        let mut object = GameObject::new_named("Spinner".to_string());
+        object.add_component(Box::new(ScriptComponent::new("Recorder", Box::new(Recorder(log.clone())))));
+        let id = object_manager.add_object(object).unwrap();
+
+        let message = Arc::new(Message {
+            target_id: id,
+            name: "damage".to_string(),
+            payload: EventPayload::Int(5),
+        });
+        let mut calls = Vec::new();
+        queue_update_calls(&object_manager, 0.5, &[message], &mut calls);
+        queue_fixed_update_calls(&object_manager, 0.02, &mut calls);
+        queue_update_calls(&object_manager, 0.25, &[], &mut calls);
+        calls.into_iter().for_each(ScriptCall::invoke);
+        assert_eq!(
+            *log.lock().unwrap(),
+            ["start", "damage Some(5)", "update 0.5", "fixed 0.02", "update 0.25"]
+        );
+
+        object_manager.get_object_by_id_mut(id).unwrap().set_enabled_self(false);
+        let mut calls = Vec::new();
+        queue_update_calls(&object_manager, 0.5, &[], &mut calls);
+        assert!(calls.is_empty());
+    }
+}
@@ -0,0 +1,499 @@
+//! CPU fallback display for when no GPU adapter or device can be created.
+//!
+//! Rasterizes the screen-space draw commands a game uses for simple UI
+//! (pixels, lines, rectangles, gradients, circles and text in the built-in
+//! `font8x8` face) into a `softbuffer` framebuffer, so the game can still show
+//! an error screen. Images, meshes, polygons, arcs, world text and scene
+//! objects are not drawn. A frame with no draw commands shows the renderer
+//! error instead of a blank window.
+
+use super::draw_manager::{ClipRect, DrawCommand, DrawManager};
+use super::text::{TextAlign, TextLayoutOptions, VerticalTextAlign};
+use crate::types::color::Color;
+use font8x8::{BASIC_FONTS, UnicodeFonts};
+use std::num::NonZeroU32;
+use std::sync::Arc;
+use winit::window::Window;
+
+/// Size in pixels of a glyph of the built-in font at scale 1.
+const GLYPH_SIZE: f32 = 8.0;
+/// Font size of the renderer error shown on empty frames.
+const MESSAGE_FONT_SIZE: f32 = 16.0;
+
+/// Presents frames rasterized on the CPU to a window.
+pub struct SoftwareDisplay {
+    surface: softbuffer::Surface<Arc<Window>, Arc<Window>>,
+    message: String,
+}
+
+impl SoftwareDisplay {
+    /// Create a display for `window` that shows `message` on empty frames.
+    pub fn new(window: Arc<Window>, message: String) -> Result<Self, String> {
+        let context = softbuffer::Context::new(Arc::clone(&window)).map_err(|err| err.to_string())?;
+        let surface = softbuffer::Surface::new(&context, window).map_err(|err| err.to_string())?;
+        Ok(Self { surface, message })
+    }
+
+    /// Draw the current draw commands over `background` and present them.
+    pub fn present(&mut self, draw_manager: &DrawManager, background: Color) -> Result<(), String> {
+        let size = self.surface.window().inner_size();
+        let (Some(width), Some(height)) = (NonZeroU32::new(size.width), NonZeroU32::new(size.height)) else {
+            // Minimized
+            return Ok(());
+        };
+        self.surface.resize(width, height).map_err(|err| err.to_string())?;
+
+        let mut buffer = self.surface.buffer_mut().map_err(|err| err.to_string())?;
+        let mut canvas = Canvas::new(&mut buffer, width.get() as usize, height.get() as usize);
+        canvas.clear(background);
+        if draw_manager.commands().is_empty() {
+            canvas.draw_message(&self.message, background);
+        } else {
+            canvas.draw_commands(draw_manager.commands(), draw_manager.clip_rects());
+        }
+        buffer.present().map_err(|err| err.to_string())
+    }
+}
+
+/// Pixel-space bounds, right and bottom exclusive.
+#[derive(Clone, Copy, Debug)]
+struct Bounds {
+    left: i32,
+    top: i32,
+    right: i32,
+    bottom: i32,
+}
+
+/// A `0RGB` framebuffer that draw commands are rasterized into.
+struct Canvas<'a> {
+    pixels: &'a mut [u32],
+    width: usize,
+    height: usize,
+}
+
+impl<'a> Canvas<'a> {
+    fn new(pixels: &'a mut [u32], width: usize, height: usize) -> Self {
+        debug_assert_eq!(pixels.len(), width * height);
+        Self { pixels, width, height }
+    }
+
+    fn full_bounds(&self) -> Bounds {
+        Bounds {
+            left: 0,
+            top: 0,
+            right: self.width as i32,
+            bottom: self.height as i32,
+        }
+    }
+
+    fn clip_bounds(&self, clip: Option<&ClipRect>) -> Bounds {
+        let full = self.full_bounds();
+        let Some(clip) = clip else {
+            return full;
+        };
+        Bounds {
+            left: (clip.x.round() as i32).max(full.left),
+            top: (clip.y.round() as i32).max(full.top),
+            right: ((clip.x + clip.width).round() as i32).min(full.right),
+            bottom: ((clip.y + clip.height).round() as i32).min(full.bottom),
+        }
+    }
+
+    fn clear(&mut self, color: Color) {
+        self.pixels.fill(pack(color.to_rgba8()));
+    }
+
+    /// Alpha-blend `color` over the pixel at (`x`, `y`) if it is in `bounds`.
+    fn blend(&mut self, x: i32, y: i32, color: Color, bounds: Bounds) {
+        if x < bounds.left || x >= bounds.right || y < bounds.top || y >= bounds.bottom {
+            return;
+        }
+        let index = y as usize * self.width + x as usize;
+        let [r, g, b, a] = color.to_rgba8();
+        if a == u8::MAX {
+            self.pixels[index] = pack([r, g, b, a]);
+            return;
+        }
+        let alpha = a as u32;
+        let destination = self.pixels[index];
+        let mix = |source: u8, shift: u32| {
+            let target = (destination >> shift) & 0xFF;
+            (source as u32 * alpha + target * (255 - alpha)) / 255
+        };
+        self.pixels[index] = (mix(r, 16) << 16) | (mix(g, 8) << 8) | mix(b, 0);
+    }
+
+    /// Fill the pixels whose centers lie in `[x0, x1) x [y0, y1)`.
+    fn fill_rect(&mut self, x0: f32, y0: f32, x1: f32, y1: f32, color: Color, bounds: Bounds) {
+        let left = ((x0 - 0.5).ceil() as i32).max(bounds.left);
+        let top = ((y0 - 0.5).ceil() as i32).max(bounds.top);
+        let right = ((x1 - 0.5).ceil() as i32).min(bounds.right);
+        let bottom = ((y1 - 0.5).ceil() as i32).min(bounds.bottom);
+        for y in top..bottom {
+            for x in left..right {
+                self.blend(x, y, color, bounds);
+            }
+        }
+    }
+
+    fn draw_line(&mut self, start: (f32, f32), end: (f32, f32), thickness: f32, color: Color, bounds: Bounds) {
+        let half = thickness.max(1.0) * 0.5;
+        let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+        let steps = dx.abs().max(dy.abs()).ceil().max(1.0) as i32;
+        for step in 0..=steps {
+            let t = step as f32 / steps as f32;
+            let (x, y) = (start.0 + dx * t, start.1 + dy * t);
+            self.fill_rect(x - half, y - half, x + half, y + half, color, bounds);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_circle(
+        &mut self,
+        center_x: f32,
+        center_y: f32,
+        radius: f32,
+        color: Color,
+        filled: bool,
+        thickness: f32,
+        bounds: Bounds,
+    ) {
+        let inner = if filled { 0.0 } else { (radius - thickness.max(1.0)).max(0.0) };
+        let left = (center_x - radius).floor() as i32;
+        let right = (center_x + radius).ceil() as i32;
+        let top = (center_y - radius).floor() as i32;
+        let bottom = (center_y + radius).ceil() as i32;
+        for y in top.max(bounds.top)..bottom.min(bounds.bottom) {
+            for x in left.max(bounds.left)..right.min(bounds.right) {
+                let distance = (x as f32 + 0.5 - center_x).hypot(y as f32 + 0.5 - center_y);
+                if distance <= radius && distance >= inner {
+                    self.blend(x, y, color, bounds);
+                }
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_gradient_rect(
+        &mut self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        [top_left, bottom_left, bottom_right, top_right]: [Color; 4],
+        bounds: Bounds,
+    ) {
+        let left = ((x - 0.5).ceil() as i32).max(bounds.left);
+        let top = ((y - 0.5).ceil() as i32).max(bounds.top);
+        let right = ((x + width - 0.5).ceil() as i32).min(bounds.right);
+        let bottom = ((y + height - 0.5).ceil() as i32).min(bounds.bottom);
+        for py in top..bottom {
+            let v = ((py as f32 + 0.5 - y) / height.max(f32::EPSILON)).clamp(0.0, 1.0);
+            let left_color = top_left.lerp(&bottom_left, v);
+            let right_color = top_right.lerp(&bottom_right, v);
+            for px in left..right {
+                let u = ((px as f32 + 0.5 - x) / width.max(f32::EPSILON)).clamp(0.0, 1.0);
+                self.blend(px, py, left_color.lerp(&right_color, u), bounds);
+            }
+        }
+    }
+
+    /// Draw `text` in the built-in font, scaled to `font_size` and laid out
+    /// in the box of `layout` (or from `x`, `y` when it has no size).
+    #[allow(clippy::too_many_arguments)]
+    fn draw_text(
+        &mut self,
+        text: &str,
+        x: f32,
+        y: f32,
+        font_size: f32,
+        letter_spacing: f32,
+        line_spacing: f32,
+        line_align: TextAlign,
+        color: Color,
+        layout: &TextLayoutOptions,
+        bounds: Bounds,
+    ) {
+        let scale = font_size.max(1.0) / GLYPH_SIZE;
+        let advance = font_size.max(1.0) + letter_spacing;
+        let line_height = font_size.max(1.0) + line_spacing;
+        let lines: Vec<&str> = text.split('\n').collect();
+        let line_width = |line: &str| (line.chars().count() as f32 * advance - letter_spacing).max(0.0);
+        let block_width = lines.iter().map(|line| line_width(line)).fold(0.0, f32::max);
+        let block_height = lines.len() as f32 * line_height - line_spacing;
+
+        let (box_width, align) = match layout.width {
+            Some(width) => (width, layout.horizontal_align),
+            None => (block_width, line_align),
+        };
+        let top = match (layout.height, layout.vertical_align) {
+            (Some(height), VerticalTextAlign::Center) => y + (height - block_height) * 0.5,
+            (Some(height), VerticalTextAlign::Bottom) => y + height - block_height,
+            _ => y,
+        };
+
+        for (row, line) in lines.iter().enumerate() {
+            let offset = match align {
+                TextAlign::Left => 0.0,
+                TextAlign::Center => (box_width - line_width(line)) * 0.5,
+                TextAlign::Right => box_width - line_width(line),
+            };
+            let pen_y = top + row as f32 * line_height;
+            for (index, ch) in line.chars().enumerate() {
+                let Some(bitmap) = BASIC_FONTS.get(ch) else {
+                    continue;
+                };
+                let pen_x = x + offset + index as f32 * advance;
+                for (glyph_row, bits) in bitmap.iter().enumerate() {
+                    for col in 0..8 {
+                        if (bits >> col) & 1 == 0 {
+                            continue;
+                        }
+                        let cell_x = pen_x + col as f32 * scale;
+                        let cell_y = pen_y + glyph_row as f32 * scale;
+                        self.fill_rect(cell_x, cell_y, cell_x + scale, cell_y + scale, color, bounds);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Draw `message` word-wrapped and centered, in a color that contrasts
+    /// with `background`.
+    fn draw_message(&mut self, message: &str, background: Color) {
+        let color = if background.luminance() > 0.5 {
+            Color::BLACK
+        } else {
+            Color::WHITE
+        };
+        let columns = ((self.width as f32 / MESSAGE_FONT_SIZE) as usize).saturating_sub(2).max(1);
+        let mut lines: Vec<String> = Vec::new();
+        for word in message.split_whitespace() {
+            match lines.last_mut() {
+                Some(line) if line.chars().count() + 1 + word.chars().count() <= columns => {
+                    line.push(' ');
+                    line.push_str(word);
+                }
+                _ => lines.push(word.to_string()),
+            }
+        }
+        let layout = TextLayoutOptions {
+            width: Some(self.width as f32),
+            height: Some(self.height as f32),
+            horizontal_align: TextAlign::Center,
+            vertical_align: VerticalTextAlign::Center,
+            ..TextLayoutOptions::default()
+        };
+        let bounds = self.full_bounds();
+        let line_spacing = MESSAGE_FONT_SIZE * 0.5;
+        self.draw_text(
+            &lines.join("\n"),
+            0.0,
+            0.0,
+            MESSAGE_FONT_SIZE,
+            0.0,
+            line_spacing,
+            TextAlign::Center,
+            color,
+            &layout,
+            bounds,
+        );
+    }
+
+    /// Rasterize the supported commands in draw order.
+    fn draw_commands(&mut self, commands: &[DrawCommand], clips: &[Option<ClipRect>]) {
+        let mut order: Vec<usize> = (0..commands.len()).collect();
+        order.sort_by(|a, b| draw_order(&commands[*a]).total_cmp(&draw_order(&commands[*b])));
+
+        for index in order {
+            let bounds = self.clip_bounds(clips.get(index).and_then(Option::as_ref));
+            if bounds.left >= bounds.right || bounds.top >= bounds.bottom {
+                continue;
+            }
+            match &commands[index] {
+                DrawCommand::Pixel { x, y, color, .. } => {
+                    self.fill_rect(*x, *y, x + 1.0, y + 1.0, *color, bounds);
+                }
+                DrawCommand::Line {
+                    start_x,
+                    start_y,
+                    end_x,
+                    end_y,
+                    thickness,
+                    color,
+                    ..
+                } => self.draw_line((*start_x, *start_y), (*end_x, *end_y), *thickness, *color, bounds),
+                DrawCommand::Rectangle {
+                    x,
+                    y,
+                    width,
+                    height,
+                    color,
+                    filled,
+                    thickness,
+                    ..
+                } => {
+                    let (x1, y1) = (x + width, y + height);
+                    if *filled {
+                        self.fill_rect(*x, *y, x1, y1, *color, bounds);
+                    } else {
+                        let t = thickness.max(1.0).min(width * 0.5).min(height * 0.5);
+                        self.fill_rect(*x, *y, x1, y + t, *color, bounds);
+                        self.fill_rect(*x, y1 - t, x1, y1, *color, bounds);
+                        self.fill_rect(*x, y + t, x + t, y1 - t, *color, bounds);
+                        self.fill_rect(x1 - t, y + t, x1, y1 - t, *color, bounds);
+                    }
+                }
+                DrawCommand::Circle {
+                    center_x,
+                    center_y,
+                    radius,
+                    color,
+                    filled,
+                    thickness,
+                    ..
+                } => self.draw_circle(*center_x, *center_y, *radius, *color, *filled, *thickness, bounds),
+                DrawCommand::GradientRect {
+                    x,
+                    y,
+                    width,
+                    height,
+                    top_left,
+                    bottom_left,
+                    bottom_right,
+                    top_right,
+                    ..
+                } => self.draw_gradient_rect(
+                    *x,
+                    *y,
+                    *width,
+                    *height,
+                    [*top_left, *bottom_left, *bottom_right, *top_right],
+                    bounds,
+                ),
+                DrawCommand::Text {
+                    text,
+                    x,
+                    y,
+                    style,
+                    color,
+                    layout,
+                    ..
+                } => self.draw_text(
+                    text,
+                    *x,
+                    *y,
+                    style.font_size,
+                    style.letter_spacing,
+                    style.line_spacing,
+                    style.line_align,
+                    *color,
+                    layout,
+                    bounds,
+                ),
+                // Needs textures or the world camera
+                _ => {}
+            }
+        }
+    }
+}
+
+fn pack([r, g, b, _]: [u8; 4]) -> u32 {
+    ((r as u32) << 16) | ((g as u32) << 8) | b as u32
+}
+
+fn draw_order(command: &DrawCommand) -> f32 {
+    match command {
+        DrawCommand::Pixel { draw_order, .. }
+        | DrawCommand::Line { draw_order, .. }
+        | DrawCommand::Rectangle { draw_order, .. }
+        | DrawCommand::Circle { draw_order, .. }
+        | DrawCommand::GradientRect { draw_order, .. }
+        | DrawCommand::Text { draw_order, .. } => *draw_order,
+        _ => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::TextStyle;
+
+    const RED: u32 = 0x00FF_0000;
+
+    fn rasterize(width: usize, height: usize, draw_manager: &DrawManager) -> Vec<u32> {
+        let mut pixels = vec![0; width * height];
+        let mut canvas = Canvas::new(&mut pixels, width, height);
+        canvas.clear(Color::BLACK);
+        canvas.draw_commands(draw_manager.commands(), draw_manager.clip_rects());
+        pixels
+    }
+
+    #[test]
+    fn test_rectangles_respect_bounds_clips_and_draw_order() {
+        let mut draw_manager = DrawManager::new();
+        draw_manager.add_command(DrawCommand::Rectangle {
+            x: 2.0,
+            y: 2.0,
+            width: 4.0,
+            height: 3.0,
+            color: Color::BLUE,
+            filled: true,
+            thickness: 1.0,
+            draw_order: 1.0,
+        });
+        draw_manager.add_command(DrawCommand::Rectangle {
+            x: -5.0,
+            y: -5.0,
+            width: 100.0,
+            height: 100.0,
+            color: Color::RED,
+            filled: true,
+            thickness: 1.0,
+            draw_order: 0.0,
+        });
+        draw_manager.push_clip_rect(0.0, 0.0, 3.0, 3.0);
+        draw_manager.add_command(DrawCommand::Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 10.0,
+            height: 10.0,
+            color: Color::WHITE,
+            filled: true,
+            thickness: 1.0,
+            draw_order: 2.0,
+        });
+        draw_manager.pop_clip_rect();
+
+        let pixels = rasterize(10, 8, &draw_manager);
+        let at = |x: usize, y: usize| pixels[y * 10 + x];
+        assert_eq!(at(9, 7), RED);
+        assert_eq!(at(5, 4), 0x0000_00FF);
+        assert_eq!(at(6, 4), RED);
+        assert_eq!(at(2, 2), 0x00FF_FFFF);
+        assert_eq!(at(3, 3), 0x0000_00FF);
+    }
+
+    #[test]
+    fn test_text_and_error_message_put_glyph_pixels_on_screen() {
+        let mut draw_manager = DrawManager::new();
+        draw_manager.draw_text_with_options(
+            "A".to_string(),
+            0.0,
+            0.0,
+            TextStyle::new(16.0),
+            Color::RED,
+            TextLayoutOptions::default(),
+            0.0,
+        );
+        let pixels = rasterize(16, 16, &draw_manager);
+        assert!(pixels.contains(&RED));
+        assert!(pixels[16 * 8..].contains(&RED));
+
+        let mut pixels = vec![0; 320 * 120];
+        let mut canvas = Canvas::new(&mut pixels, 320, 120);
+        canvas.clear(Color::BLACK);
+        canvas.draw_message("No GPU adapter found", Color::BLACK);
+        assert!(pixels.contains(&0x00FF_FFFF));
+    }
+}
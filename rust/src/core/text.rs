@@ -64,6 +64,15 @@ impl TextAlign {
             Self::Right => "right",
         }
     }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "left" => Some(Self::Left),
+            "center" | "middle" => Some(Self::Center),
+            "right" => Some(Self::Right),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
@@ -82,6 +91,15 @@ impl VerticalTextAlign {
             Self::Bottom => "bottom",
         }
     }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "top" => Some(Self::Top),
+            "center" | "middle" => Some(Self::Center),
+            "bottom" => Some(Self::Bottom),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
@@ -90,6 +108,8 @@ pub struct FontDescriptor {
     path: Option<String>,
     weight: FontWeight,
     style: FontStyle,
+    /// Fonts tried in order for characters the primary font lacks
+    fallbacks: Vec<String>,
 }
 
 impl FontDescriptor {
@@ -153,6 +173,21 @@ impl FontDescriptor {
         self.style = style;
     }
 
+    /// Ordered fallback fonts: registered family names or `.ttf`/`.otf` paths.
+    ///
+    /// An empty list means the renderer's default fallbacks are used.
+    pub fn fallbacks(&self) -> &[String] {
+        &self.fallbacks
+    }
+
+    pub fn set_fallbacks(&mut self, fallbacks: Vec<String>) {
+        self.fallbacks = fallbacks
+            .into_iter()
+            .map(|font| font.trim().to_string())
+            .filter(|font| !font.is_empty())
+            .collect();
+    }
+
     pub fn family_key(&self) -> Option<String> {
         self.family
             .as_ref()
@@ -164,6 +199,15 @@ impl FontDescriptor {
     }
 
     pub fn cache_key(&self) -> String {
+        let primary = self.primary_cache_key();
+        if self.fallbacks.is_empty() {
+            primary
+        } else {
+            format!("{primary}|fallbacks:{}", self.fallbacks.join(","))
+        }
+    }
+
+    fn primary_cache_key(&self) -> String {
         if let Some(path) = &self.path {
             format!("path:{}", normalize_font_path(path))
         } else if let Some(family) = self.family_key() {
@@ -186,6 +230,9 @@ pub struct TextStyle {
     pub letter_spacing: f32,
     pub line_spacing: f32,
     pub kerning: bool,
+    /// Alignment of each line within a multi-line block. UI text takes it
+    /// from `TextLayoutOptions::horizontal_align` when drawn.
+    pub line_align: TextAlign,
 }
 
 impl TextStyle {
@@ -196,6 +243,7 @@ impl TextStyle {
             letter_spacing: 0.0,
             line_spacing: 0.0,
             kerning: true,
+            line_align: TextAlign::Left,
         }
     }
 }
@@ -212,6 +260,9 @@ pub struct TextLayoutOptions {
     pub height: Option<f32>,
     pub horizontal_align: TextAlign,
     pub vertical_align: VerticalTextAlign,
+    /// Break lines at spaces so no line is wider than `width`. Words that
+    /// do not fit on a line of their own are broken between characters.
+    pub wrap: bool,
 }
 
 impl Default for TextLayoutOptions {
@@ -221,6 +272,7 @@ impl Default for TextLayoutOptions {
             height: None,
             horizontal_align: TextAlign::Left,
             vertical_align: VerticalTextAlign::Top,
+            wrap: false,
         }
     }
 }
@@ -295,9 +347,61 @@ pub fn normalize_font_path(value: &str) -> String {
         .replace('\\', "/")
 }
 
+/// Insert line breaks into `text` so that no line is wider than `max_width`.
+///
+/// Lines break at spaces; words wider than a whole line break between
+/// characters. `advance` gives a character's pen advance, and
+/// `letter_spacing` is added between characters as in the text layout.
+pub fn wrap_text(
+    text: &str,
+    max_width: f32,
+    letter_spacing: f32,
+    mut advance: impl FnMut(char) -> f32,
+) -> String {
+    let mut advance = |ch: char| advance(ch) + letter_spacing;
+    let space_width = advance(' ');
+
+    let mut wrapped = String::with_capacity(text.len() + 8);
+    for (paragraph_index, paragraph) in text.split('\n').enumerate() {
+        if paragraph_index > 0 {
+            wrapped.push('\n');
+        }
+        let mut line_width = 0.0f32;
+        for (word_index, word) in paragraph.split(' ').enumerate() {
+            let advances: Vec<f32> = word.chars().map(&mut advance).collect();
+            let word_width: f32 = advances.iter().sum();
+            if word_index > 0 {
+                // Break at the space when the next word would overflow;
+                // runs of spaces stay on the line they were typed on.
+                if line_width > 0.0
+                    && !word.is_empty()
+                    && line_width + space_width + word_width - letter_spacing > max_width
+                {
+                    wrapped.push('\n');
+                    line_width = 0.0;
+                } else {
+                    wrapped.push(' ');
+                    line_width += space_width;
+                }
+            }
+            for (ch, ch_width) in word.chars().zip(advances) {
+                if line_width > 0.0 && line_width + ch_width - letter_spacing > max_width {
+                    wrapped.push('\n');
+                    line_width = 0.0;
+                }
+                wrapped.push(ch);
+                line_width += ch_width;
+            }
+        }
+    }
+    wrapped
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{FontFamilyDefinition, FontStyle, FontWeight, normalize_font_family_key};
+    use super::{
+        FontDescriptor, FontFamilyDefinition, FontStyle, FontWeight, normalize_font_family_key, wrap_text,
+    };
 
     #[test]
     fn resolves_exact_variant_first() {
@@ -325,8 +429,30 @@ mod tests {
         assert_eq!(family.resolve(FontWeight::Bold, FontStyle::Normal), Some("regular.ttf"));
     }
 
+    #[test]
+    fn fallbacks_are_trimmed_and_part_of_the_cache_key() {
+        let mut descriptor = FontDescriptor::from_path("fonts/Inter.ttf");
+        let primary_key = descriptor.cache_key();
+        descriptor.set_fallbacks(vec![" Noto Sans CJK ".to_string(), "".to_string()]);
+
+        assert_eq!(descriptor.fallbacks(), ["Noto Sans CJK".to_string()]);
+        assert_eq!(descriptor.cache_key(), format!("{primary_key}|fallbacks:Noto Sans CJK"));
+    }
+
     #[test]
     fn normalizes_family_keys() {
         assert_eq!(normalize_font_family_key("  Inter UI "), "inter ui");
     }
+
+    #[test]
+    fn wraps_at_spaces_and_breaks_long_words() {
+        let wrap = |text: &str, width: f32| wrap_text(text, width, 0.0, |_| 10.0);
+        assert_eq!(wrap("the quick brown fox", 100.0), "the quick\nbrown fox");
+        assert_eq!(wrap("one\n two", 100.0), "one\n two");
+        assert_eq!(wrap("abcdefghijkl", 50.0), "abcde\nfghij\nkl");
+        assert_eq!(wrap("ab   cd", 40.0), "ab  \ncd");
+        // Letter spacing only applies between characters.
+        assert_eq!(wrap_text("ab cd", 80.0, 5.0, |_| 10.0), "ab cd");
+        assert_eq!(wrap_text("ab cd", 65.0, 5.0, |_| 10.0), "ab\ncd");
+    }
 }
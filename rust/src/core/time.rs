@@ -1,18 +1,32 @@
 use super::logging;
-use std::time::SystemTime;
+use std::time::{Duration, Instant, SystemTime};
 /**
     The time class.
 */
 pub struct Time {
     /// System time
     system_time: SystemTime,
-    /// Time since last tick
+    /// Time since last tick, scaled by `time_scale` and zero while paused
     delta_time: f32,
+    /// Real time since last tick
+    unscaled_delta_time: f32,
+    /// Multiplier applied to the delta time
+    time_scale: f32,
+    /// Whether game time is stopped
+    paused: bool,
+    /// The total scaled time since the start of the application.
+    game_time: f32,
     /// The total time since the start of the application.
     elapsed_time: f32,
     /// Time between each fixed update
     fixed_timestep: f32,
-    ///
+    /// Game time the fixed steps still have to catch up on
+    fixed_accumulator: f32,
+    /// Most fixed steps run per tick
+    max_fixed_steps: u32,
+    /// Fixed steps run since the last tick
+    fixed_steps: u32,
+    /// Game time of the last fixed step
     last_fixed_time: f32,
     /// The number of ticks since the start of the application.
     tick_count: u64,
@@ -25,8 +39,15 @@ impl Time {
         Self {
             system_time: SystemTime::now(),
             delta_time: 0.0,
+            unscaled_delta_time: 0.0,
+            time_scale: 1.0,
+            paused: false,
+            game_time: 0.0,
             elapsed_time: 0.0,
             fixed_timestep: 1.0 / 60.0,
+            fixed_accumulator: 0.0,
+            max_fixed_steps: 5,
+            fixed_steps: 0,
             last_fixed_time: 0.0,
             tick_count: 0,
         }
@@ -35,11 +56,26 @@ impl Time {
     /// Tick the time.
     /// @return: The delta time.
     pub fn tick(&mut self) -> f32 {
-        self.delta_time = self.system_time.elapsed().unwrap().as_secs_f32() - self.elapsed_time;
+        let unscaled_delta_time = self.system_time.elapsed().unwrap().as_secs_f32() - self.elapsed_time;
         self.elapsed_time = SystemTime::now()
             .duration_since(self.system_time)
             .unwrap_or_default()
             .as_secs_f32();
+        self.advance(unscaled_delta_time)
+    }
+
+    /// Advance by a real delta time, applying the time scale and pause.
+    /// @return: The delta time.
+    fn advance(&mut self, unscaled_delta_time: f32) -> f32 {
+        self.unscaled_delta_time = unscaled_delta_time;
+        self.delta_time = if self.paused {
+            0.0
+        } else {
+            unscaled_delta_time * self.time_scale
+        };
+        self.game_time += self.delta_time;
+        self.fixed_accumulator += self.delta_time;
+        self.fixed_steps = 0;
         // wrapping_add avoids explicit conditional and efficient increments with overflow wrapping to 0
         self.tick_count = self.tick_count.wrapping_add(1);
 
@@ -47,26 +83,58 @@ impl Time {
     }
 
     /// Tick the time at a fixed timestep.
+    ///
+    /// Each tick adds the game time to an accumulator and every call consumes
+    /// one fixed timestep from it, so callers loop until it returns false and
+    /// long frames run several steps. Fixed steps follow the scaled game time:
+    /// they slow down with `time_scale` and stop while paused. After
+    /// `max_fixed_steps` steps in one tick the remaining backlog is dropped, so
+    /// a slow frame cannot snowball into ever more steps.
     /// @return: A tuple containing a boolean indicating if the fixed timestep has been reached and the fixed timestep.
     pub fn tick_fixed(&mut self) -> (bool, f32) {
-        if self.elapsed_time - self.last_fixed_time >= self.fixed_timestep {
-            self.last_fixed_time = self.elapsed_time;
-            (true, self.fixed_timestep)
-        } else {
-            (false, 0.0)
+        if self.fixed_accumulator < self.fixed_timestep {
+            return (false, 0.0);
         }
+        if self.fixed_steps >= self.max_fixed_steps {
+            self.fixed_accumulator %= self.fixed_timestep;
+            return (false, 0.0);
+        }
+        self.fixed_accumulator -= self.fixed_timestep;
+        self.fixed_steps += 1;
+        self.last_fixed_time = self.game_time - self.fixed_accumulator;
+        (true, self.fixed_timestep)
+    }
+
+    /// Get how far the game time is between the last fixed step and the next one.
+    /// @return: A fraction in [0, 1), for interpolating between fixed steps when rendering.
+    pub fn fixed_alpha(&self) -> f32 {
+        (self.fixed_accumulator / self.fixed_timestep).min(1.0)
     }
 
     /// Get the last fixed time.
-    /// @return: The last fixed time.
+    /// @return: The game time of the last fixed step.
     pub fn last_fixed_time(&self) -> f32 {
         self.last_fixed_time
     }
 
     /// Set the fixed timestep.
-    /// @param fixed_timestep: The fixed timestep.
+    /// @param fixed_timestep: Seconds of game time per fixed step, e.g. 1.0 / 120.0; non-positive values are ignored.
     pub fn set_fixed_timestep(&mut self, fixed_timestep: f32) {
-        self.fixed_timestep = fixed_timestep;
+        if fixed_timestep.is_finite() && fixed_timestep > 0.0 {
+            self.fixed_timestep = fixed_timestep;
+        }
+    }
+
+    /// Get the most fixed steps run per tick.
+    /// @return: The maximum number of catch-up steps.
+    pub fn max_fixed_steps(&self) -> u32 {
+        self.max_fixed_steps
+    }
+
+    /// Set the most fixed steps run per tick.
+    /// @param max_fixed_steps: The maximum number of catch-up steps, at least 1.
+    pub fn set_max_fixed_steps(&mut self, max_fixed_steps: u32) {
+        self.max_fixed_steps = max_fixed_steps.max(1);
     }
 
     /// Get the delta time.
@@ -81,6 +149,44 @@ impl Time {
         self.fixed_timestep
     }
 
+    /// Get the real time since the last tick, ignoring `time_scale` and pause.
+    /// @return: The unscaled delta time.
+    pub fn unscaled_delta_time(&self) -> f32 {
+        self.unscaled_delta_time
+    }
+
+    /// Get the time scale.
+    /// @return: The multiplier applied to the delta time.
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    /// Set the time scale, e.g. 0.5 for slow motion. Takes effect on the next tick.
+    /// @param time_scale: Non-negative multiplier; invalid values are ignored.
+    pub fn set_time_scale(&mut self, time_scale: f32) {
+        if time_scale.is_finite() && time_scale >= 0.0 {
+            self.time_scale = time_scale;
+        }
+    }
+
+    /// Whether game time is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Pause or resume game time. While paused the delta time is zero and no
+    /// fixed steps are due. Takes effect on the next tick.
+    /// @param paused: Whether game time is stopped.
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    /// Get the game time.
+    /// @return: The total scaled time since the start of the application, without paused time.
+    pub fn game_time(&self) -> f32 {
+        self.game_time
+    }
+
     /// Get the elapsed time.
     /// @return: The total time since the start of the application.
     pub fn elapsed_time(&self) -> f32 {
@@ -106,3 +212,163 @@ impl Time {
         logging::log_info("--------------------------------");
     }
 }
+
+/// Paces frame presentation to an optional target frame rate.
+///
+/// The limiter only tracks deadlines; callers decide whether to wait on the
+/// event loop (`next_deadline`) or block the current thread (`throttle`).
+pub struct FrameLimiter {
+    /// Target frames per second, or `None` when uncapped.
+    target_fps: Option<f32>,
+    /// Duration of a single frame at the target rate.
+    frame_duration: Option<Duration>,
+    /// Earliest instant the next frame may start.
+    next_frame: Instant,
+}
+
+impl FrameLimiter {
+    /// Creates an uncapped frame limiter.
+    pub fn new() -> Self {
+        Self {
+            target_fps: None,
+            frame_duration: None,
+            next_frame: Instant::now(),
+        }
+    }
+
+    /// Set the target frame rate.
+    /// @param target_fps: Frames per second, or `None`/non-positive to disable the cap.
+    pub fn set_target_fps(&mut self, target_fps: Option<f32>) {
+        let target_fps = target_fps.filter(|fps| fps.is_finite() && *fps > 0.0);
+        self.target_fps = target_fps;
+        self.frame_duration = target_fps.map(|fps| Duration::from_secs_f64(1.0 / fps as f64));
+        self.next_frame = Instant::now();
+    }
+
+    /// Get the target frame rate.
+    /// @return: The target frames per second, or `None` when uncapped.
+    pub fn target_fps(&self) -> Option<f32> {
+        self.target_fps
+    }
+
+    /// Whether a frame cap is active.
+    pub fn is_enabled(&self) -> bool {
+        self.frame_duration.is_some()
+    }
+
+    /// Whether the next frame may start at `now`.
+    pub fn is_frame_due(&self, now: Instant) -> bool {
+        self.frame_duration.is_none() || now >= self.next_frame
+    }
+
+    /// Get the deadline for the next frame.
+    /// @return: The instant the next frame is due, or `None` when uncapped.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.frame_duration.map(|_| self.next_frame)
+    }
+
+    /// Record that a frame started at `now` and schedule the next deadline.
+    ///
+    /// Deadlines advance by whole frames so the average rate matches the target,
+    /// but never lag behind `now` to avoid a burst of catch-up frames after a stall.
+    pub fn mark_frame(&mut self, now: Instant) {
+        let Some(frame_duration) = self.frame_duration else {
+            return;
+        };
+        self.next_frame += frame_duration;
+        if self.next_frame < now {
+            self.next_frame = now + frame_duration;
+        }
+    }
+
+    /// Block the current thread until the next frame is due, then mark it.
+    pub fn throttle(&mut self) {
+        if self.frame_duration.is_none() {
+            return;
+        }
+        let now = Instant::now();
+        if now < self.next_frame {
+            std::thread::sleep(self.next_frame - now);
+        }
+        self.mark_frame(Instant::now());
+    }
+}
+
+impl Default for FrameLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_scale_and_pause() {
+        let mut time = Time::new();
+        time.set_time_scale(0.5);
+        time.set_time_scale(-1.0);
+        assert_eq!(time.time_scale(), 0.5);
+        std::thread::sleep(Duration::from_millis(20));
+        time.tick();
+        assert!((time.delta_time() - time.unscaled_delta_time() * 0.5).abs() < 1e-6);
+        assert_eq!(time.game_time(), time.delta_time());
+
+        time.set_fixed_timestep(0.001);
+        time.set_paused(true);
+        std::thread::sleep(Duration::from_millis(5));
+        time.tick();
+        assert_eq!(time.delta_time(), 0.0);
+        assert!(time.unscaled_delta_time() > 0.0);
+        assert!(time.tick_fixed().0, "the steps due before pausing still run");
+        while time.tick_fixed().0 {}
+        time.tick();
+        assert!(!time.tick_fixed().0);
+    }
+
+    #[test]
+    fn test_fixed_steps_catch_up_to_the_limit() {
+        let mut time = Time::new();
+        time.set_fixed_timestep(0.25);
+        time.set_max_fixed_steps(3);
+
+        time.advance(0.6);
+        let steps = std::iter::from_fn(|| time.tick_fixed().0.then_some(())).count();
+        assert_eq!(steps, 2);
+        assert!((time.fixed_alpha() - 0.4).abs() < 1e-5);
+
+        time.advance(2.0);
+        let steps = std::iter::from_fn(|| time.tick_fixed().0.then_some(())).count();
+        assert_eq!(steps, 3, "the backlog past the limit is dropped");
+        assert!(time.fixed_alpha() < 1.0);
+        assert!((time.last_fixed_time() - 1.25).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_frame_limiter_disabled_by_default() {
+        let limiter = FrameLimiter::new();
+        assert!(!limiter.is_enabled());
+        assert!(limiter.next_deadline().is_none());
+        assert!(limiter.is_frame_due(Instant::now()));
+    }
+
+    #[test]
+    fn test_frame_limiter_schedules_next_frame() {
+        let mut limiter = FrameLimiter::new();
+        limiter.set_target_fps(Some(10.0));
+        let start = limiter.next_deadline().unwrap();
+        limiter.mark_frame(start);
+        assert!(!limiter.is_frame_due(start + Duration::from_millis(50)));
+        assert!(limiter.is_frame_due(start + Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn test_frame_limiter_rejects_invalid_fps() {
+        let mut limiter = FrameLimiter::new();
+        limiter.set_target_fps(Some(0.0));
+        assert_eq!(limiter.target_fps(), None);
+        limiter.set_target_fps(Some(f32::NAN));
+        assert!(!limiter.is_enabled());
+    }
+}
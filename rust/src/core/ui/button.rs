@@ -1,6 +1,6 @@
 use super::{Rect, StyleState, UIComponentTrait};
 use super::event::UIEvent;
-use super::style::StyleSet;
+use super::style::{StyleSet, UITheme};
 use super::layout::UILayoutComponent;
 use crate::core::component::{ComponentTrait, next_component_id};
 use crate::core::draw_manager::DrawManager;
@@ -9,6 +9,7 @@ use crate::core::time::Time;
 use crate::types::color::Color;
 use std::any::Any;
 use std::sync::{Arc, Mutex};
+use winit::keyboard::{Key, NamedKey};
 
 /// Determines when a button callback is triggered.
 ///
@@ -69,6 +70,16 @@ pub enum ButtonTrigger {
 
 /// Button UI component for clickable buttons.
 ///
+type ButtonCallback = Arc<Mutex<Option<Box<dyn FnMut() + Send + Sync>>>>;
+
+fn fire(callback: &ButtonCallback) {
+    if let Ok(mut guard) = callback.lock()
+        && let Some(callback) = guard.as_mut()
+    {
+        callback();
+    }
+}
+
 /// A clickable button with customizable appearance, text label, and callback.
 /// Supports hover states, press states, enabled/disabled states, and optional
 /// continuous firing while held.
@@ -136,8 +147,13 @@ pub struct ButtonComponent {
     style: StyleSet,
     current_state: StyleState,
     label: String,
-    on_click: Arc<Mutex<Option<Box<dyn FnMut() + Send + Sync>>>>,
+    on_click: ButtonCallback,
+    on_hover_enter: ButtonCallback,
+    on_hover_exit: ButtonCallback,
+    on_press: ButtonCallback,
+    on_release: ButtonCallback,
     is_hovered: bool,
+    is_focused: bool,
     is_pressed: bool,
     enabled: bool,
     enabled_in_hierarchy: bool,
@@ -188,11 +204,16 @@ impl ButtonComponent {
             name: name.into(),
             bounds: Rect::new(0.0, 0.0, 100.0, 30.0),
             layout: UILayoutComponent::with_fixed_size(100.0, 30.0),
-            style: StyleSet::default(),
+            style: UITheme::light().button_style.clone(),
             current_state: StyleState::Normal,
             label: String::new(),
             on_click: Arc::new(Mutex::new(None)),
+            on_hover_enter: Arc::new(Mutex::new(None)),
+            on_hover_exit: Arc::new(Mutex::new(None)),
+            on_press: Arc::new(Mutex::new(None)),
+            on_release: Arc::new(Mutex::new(None)),
             is_hovered: false,
+            is_focused: false,
             is_pressed: false,
             enabled: true,
             enabled_in_hierarchy: true,
@@ -295,76 +316,52 @@ impl ButtonComponent {
         self.style = style;
     }
 
+    pub fn style(&self) -> &StyleSet {
+        &self.style
+    }
+
+    pub fn style_mut(&mut self) -> &mut StyleSet {
+        &mut self.style
+    }
+
     pub fn set_font_size(&mut self, font_size: f32) {
-        for state in [
-            StyleState::Normal,
-            StyleState::Hovered,
-            StyleState::Pressed,
-            StyleState::Focused,
-            StyleState::Disabled,
-        ] {
-            self.style.get_style_mut(state).set_font_size(font_size);
-        }
+        self.style.set_font_size(font_size);
     }
 
     pub fn set_font_path(&mut self, font_path: Option<String>) {
-        for state in [
-            StyleState::Normal,
-            StyleState::Hovered,
-            StyleState::Pressed,
-            StyleState::Focused,
-            StyleState::Disabled,
-        ] {
-            self.style.get_style_mut(state).set_font_path(font_path.clone());
-        }
+        self.style.set_font_path(font_path);
     }
 
     pub fn set_font_family(&mut self, font_family: Option<String>) {
-        for state in [
-            StyleState::Normal,
-            StyleState::Hovered,
-            StyleState::Pressed,
-            StyleState::Focused,
-            StyleState::Disabled,
-        ] {
-            self.style.get_style_mut(state).set_font_family(font_family.clone());
-        }
+        self.style.set_font_family(font_family);
+    }
+
+    /// Override the theme's fallback fonts for this button's text. An empty
+    /// list goes back to the theme's.
+    pub fn set_font_fallbacks(&mut self, fallbacks: Vec<String>) {
+        self.style.set_font_fallbacks(fallbacks);
     }
 
     pub fn set_font_weight(&mut self, font_weight: FontWeight) {
-        for state in [
-            StyleState::Normal,
-            StyleState::Hovered,
-            StyleState::Pressed,
-            StyleState::Focused,
-            StyleState::Disabled,
-        ] {
-            self.style.get_style_mut(state).set_font_weight(font_weight);
-        }
+        self.style.set_font_weight(font_weight);
     }
 
     pub fn set_font_style(&mut self, font_style: FontStyle) {
-        for state in [
-            StyleState::Normal,
-            StyleState::Hovered,
-            StyleState::Pressed,
-            StyleState::Focused,
-            StyleState::Disabled,
-        ] {
-            self.style.get_style_mut(state).set_font_style(font_style);
-        }
+        self.style.set_font_style(font_style);
     }
 
     pub fn set_kerning(&mut self, kerning: bool) {
-        for state in [
-            StyleState::Normal,
-            StyleState::Hovered,
-            StyleState::Pressed,
-            StyleState::Focused,
-            StyleState::Disabled,
-        ] {
-            self.style.get_style_mut(state).set_kerning(kerning);
-        }
+        self.style.set_kerning(kerning);
+    }
+
+    /// Extra space in pixels between characters of the button text.
+    pub fn set_letter_spacing(&mut self, letter_spacing: f32) {
+        self.style.set_letter_spacing(letter_spacing);
+    }
+
+    /// Extra space in pixels between lines of the button text.
+    pub fn set_line_spacing(&mut self, line_spacing: f32) {
+        self.style.set_line_spacing(line_spacing);
     }
 
     pub fn set_on_click<F>(&mut self, callback: F)
@@ -374,6 +371,39 @@ impl ButtonComponent {
         *self.on_click.lock().unwrap() = Some(Box::new(callback));
     }
 
+    /// Called when the mouse starts hovering the button.
+    pub fn set_on_hover_enter<F>(&mut self, callback: F)
+    where
+        F: FnMut() + Send + Sync + 'static,
+    {
+        *self.on_hover_enter.lock().unwrap() = Some(Box::new(callback));
+    }
+
+    /// Called when the mouse stops hovering the button.
+    pub fn set_on_hover_exit<F>(&mut self, callback: F)
+    where
+        F: FnMut() + Send + Sync + 'static,
+    {
+        *self.on_hover_exit.lock().unwrap() = Some(Box::new(callback));
+    }
+
+    /// Called when a mouse button goes down on the button, whatever the trigger mode.
+    pub fn set_on_press<F>(&mut self, callback: F)
+    where
+        F: FnMut() + Send + Sync + 'static,
+    {
+        *self.on_press.lock().unwrap() = Some(Box::new(callback));
+    }
+
+    /// Called once for every press, when the mouse button is released or the
+    /// pointer leaves the button while it is held.
+    pub fn set_on_release<F>(&mut self, callback: F)
+    where
+        F: FnMut() + Send + Sync + 'static,
+    {
+        *self.on_release.lock().unwrap() = Some(Box::new(callback));
+    }
+
     pub fn set_trigger_on(&mut self, trigger: ButtonTrigger) {
         self.trigger_on = trigger;
     }
@@ -386,11 +416,7 @@ impl ButtonComponent {
     }
 
     fn trigger_callback(&mut self) {
-        if let Ok(mut guard) = self.on_click.lock() {
-            if let Some(callback) = guard.as_mut() {
-                callback();
-            }
-        }
+        fire(&self.on_click);
     }
 
     fn update_state(&mut self) {
@@ -400,6 +426,8 @@ impl ButtonComponent {
             self.current_state = StyleState::Pressed;
         } else if self.is_hovered {
             self.current_state = StyleState::Hovered;
+        } else if self.is_focused {
+            self.current_state = StyleState::Focused;
         } else {
             self.current_state = StyleState::Normal;
         }
@@ -460,11 +488,7 @@ impl ComponentTrait for ButtonComponent {
                             drop(last_time_guard); // Release lock before calling callback
 
                             // Trigger the callback
-                            if let Ok(mut guard) = self.on_click.lock() {
-                                if let Some(callback) = guard.as_mut() {
-                                    callback();
-                                }
-                            }
+                            fire(&self.on_click);
                         }
                     }
                     None => {
@@ -517,12 +541,18 @@ impl UIComponentTrait for ButtonComponent {
             UIEvent::MouseEnter { .. } => {
                 self.is_hovered = true;
                 self.update_state();
+                fire(&self.on_hover_enter);
                 true
             }
             UIEvent::MouseExit { .. } => {
+                let was_pressed = self.is_pressed;
                 self.is_hovered = false;
                 self.is_pressed = false;
                 self.update_state();
+                if was_pressed {
+                    fire(&self.on_release);
+                }
+                fire(&self.on_hover_exit);
                 // Clear repeat timer when mouse exits
                 if self.repeat_interval_ms.is_some() {
                     *self.last_repeat_time.lock().unwrap() = None;
@@ -532,6 +562,7 @@ impl UIComponentTrait for ButtonComponent {
             UIEvent::MouseDown { .. } => {
                 self.is_pressed = true;
                 self.update_state();
+                fire(&self.on_press);
 
                 // If trigger on press, fire callback immediately
                 if self.trigger_on == ButtonTrigger::Press {
@@ -541,8 +572,12 @@ impl UIComponentTrait for ButtonComponent {
                 true
             }
             UIEvent::MouseUp { .. } => {
+                let was_pressed = self.is_pressed;
                 self.is_pressed = false;
                 self.update_state();
+                if was_pressed {
+                    fire(&self.on_release);
+                }
                 // Clear repeat timer when button is released
                 if self.repeat_interval_ms.is_some() {
                     *self.last_repeat_time.lock().unwrap() = None;
@@ -557,6 +592,25 @@ impl UIComponentTrait for ButtonComponent {
                 }
                 true
             }
+            UIEvent::KeyDown {
+                key: Key::Named(NamedKey::Enter | NamedKey::Space),
+                ..
+            } => {
+                // Keyboard activation fires once whatever the trigger mode
+                crate::core::logging::log_debug(&format!("Button '{}' activated!", self.label));
+                self.trigger_callback();
+                true
+            }
+            UIEvent::FocusGained => {
+                self.is_focused = true;
+                self.update_state();
+                true
+            }
+            UIEvent::FocusLost => {
+                self.is_focused = false;
+                self.update_state();
+                true
+            }
             _ => false,
         }
     }
@@ -566,45 +620,11 @@ impl UIComponentTrait for ButtonComponent {
         let x = self.bounds.x + offset.0;
         let y = self.bounds.y + offset.1;
 
-        // Draw background
-        if style.background_color[3] > 0.0 {
-            let bg_color = Color::new(
-                style.background_color[0],
-                style.background_color[1],
-                style.background_color[2],
-                style.background_color[3],
-            );
-            draw_manager.draw_rectangle_with_options(
-                x,
-                y,
-                self.bounds.width,
-                self.bounds.height,
-                bg_color,
-                true,
-                1.0,
-                self.depth,
-            );
-        }
-
-        // Draw border
-        if style.border_width > 0.0 {
-            let border_color = Color::new(
-                style.border_color[0],
-                style.border_color[1],
-                style.border_color[2],
-                style.border_color[3],
-            );
-            draw_manager.draw_rectangle_with_options(
-                x,
-                y,
-                self.bounds.width,
-                self.bounds.height,
-                border_color,
-                false,
-                style.border_width,
-                self.depth + 0.005,
-            );
-        }
+        style.render_box(
+            draw_manager,
+            Rect::new(x, y, self.bounds.width, self.bounds.height),
+            self.depth,
+        );
 
         // Draw text (centered)
         if !self.label.is_empty() {
@@ -626,6 +646,7 @@ impl UIComponentTrait for ButtonComponent {
                     height: Some(self.bounds.height),
                     horizontal_align: TextAlign::Center,
                     vertical_align: VerticalTextAlign::Center,
+                    wrap: false,
                 },
                 self.depth + 0.01,
             );
@@ -636,10 +657,18 @@ impl UIComponentTrait for ButtonComponent {
         self.depth
     }
 
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
     fn is_enabled(&self) -> bool {
         self.enabled && self.enabled_in_hierarchy
     }
 
+    fn apply_theme(&mut self, old: &UITheme, new: &UITheme) {
+        self.style.retheme(&old.button_style, &new.button_style);
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -648,3 +677,38 @@ impl UIComponentTrait for ButtonComponent {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::input_manager::MouseButtonType;
+
+    #[test]
+    fn test_hover_and_press_callbacks_fire_in_pairs() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut button = ButtonComponent::new("Button").with_bounds(0.0, 0.0, 100.0, 30.0);
+        let record = |name: &'static str| {
+            let log = Arc::clone(&log);
+            move || log.lock().unwrap().push(name)
+        };
+        button.set_on_hover_enter(record("enter"));
+        button.set_on_hover_exit(record("exit"));
+        button.set_on_press(record("press"));
+        button.set_on_release(record("release"));
+
+        let (x, y, button_type) = (10.0, 10.0, MouseButtonType::Left);
+        button.handle_event(&UIEvent::MouseEnter { x, y });
+        button.handle_event(&UIEvent::MouseDown { x, y, button: button_type });
+        button.handle_event(&UIEvent::MouseUp { x, y, button: button_type });
+        // A release without a matching press is ignored.
+        button.handle_event(&UIEvent::MouseUp { x, y, button: button_type });
+        // Dragging off a held button releases it before the hover ends.
+        button.handle_event(&UIEvent::MouseDown { x, y, button: button_type });
+        button.handle_event(&UIEvent::MouseExit { x: 200.0, y });
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            ["enter", "press", "release", "press", "release", "exit"]
+        );
+    }
+}
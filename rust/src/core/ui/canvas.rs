@@ -0,0 +1,280 @@
+use super::{Rect, UIComponentTrait};
+use super::event::UIEvent;
+use crate::core::component::{ComponentTrait, next_component_id};
+use crate::core::draw_manager::{ClipRect, DrawManager};
+use crate::core::time::Time;
+use std::any::Any;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+type RenderCallback = Arc<Mutex<Option<Box<dyn FnMut(&mut DrawManager, f32, f32) + Send + Sync>>>>;
+
+/// UI widget whose contents are drawn by a callback.
+///
+/// The callback gets its own `DrawManager` in local coordinates, with (0, 0)
+/// at the canvas' top-left corner, plus the canvas' width and height. Its
+/// commands are moved into place, drawn above the canvas' depth and clipped
+/// to the canvas bounds, so charts, minimaps and the like can be drawn inside
+/// the UI layout without tracking where the layout put them.
+///
+/// The UI keeps widget draw commands between frames, so the callback only
+/// runs again after [`Self::request_redraw`], when the canvas moves or
+/// resizes, or on every frame with `redraw_every_frame`.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyg_engine_native::core::ui::canvas::CanvasComponent;
+/// use pyg_engine_native::types::Color;
+///
+/// let mut chart = CanvasComponent::new("Canvas").with_bounds(20.0, 20.0, 200.0, 80.0);
+/// chart.set_on_render(|draw, width, height| {
+///     draw.draw_rectangle_with_options(0.0, 0.0, width, height, Color::BLACK, true, 1.0, 0.0);
+///     draw.draw_line_with_options(0.0, height, width, 0.0, 2.0, Color::GREEN, 0.001);
+/// });
+/// ```
+#[derive(Clone)]
+pub struct CanvasComponent {
+    component_id: u32,
+    name: String,
+    bounds: Rect,
+    on_render: RenderCallback,
+    redraw_requested: Arc<AtomicBool>,
+    redraw_every_frame: bool,
+    enabled: bool,
+    enabled_in_hierarchy: bool,
+    depth: f32,
+}
+
+impl std::fmt::Debug for CanvasComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CanvasComponent")
+            .field("name", &self.name)
+            .field("bounds", &self.bounds)
+            .field("redraw_every_frame", &self.redraw_every_frame)
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+impl CanvasComponent {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            component_id: next_component_id(),
+            name: name.into(),
+            bounds: Rect::new(0.0, 0.0, 100.0, 100.0),
+            on_render: Arc::new(Mutex::new(None)),
+            redraw_requested: Arc::new(AtomicBool::new(false)),
+            redraw_every_frame: false,
+            enabled: true,
+            enabled_in_hierarchy: true,
+            depth: 0.0,
+        }
+    }
+
+    pub fn with_bounds(mut self, x: f32, y: f32, width: f32, height: f32) -> Self {
+        self.bounds = Rect::new(x, y, width, height);
+        self
+    }
+
+    pub fn with_depth(mut self, depth: f32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    pub fn with_redraw_every_frame(mut self, redraw_every_frame: bool) -> Self {
+        self.redraw_every_frame = redraw_every_frame;
+        self
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Called with a local `DrawManager` and the canvas size whenever the
+    /// canvas is drawn. Replacing the callback requests a redraw.
+    pub fn set_on_render<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut DrawManager, f32, f32) + Send + Sync + 'static,
+    {
+        *self.on_render.lock().unwrap() = Some(Box::new(callback));
+        self.request_redraw();
+    }
+
+    /// Run the render callback again on the next frame.
+    ///
+    /// Clones of the component share the request, so a copy held by a
+    /// script can redraw the one in the scene.
+    pub fn request_redraw(&self) {
+        self.redraw_requested.store(true, Ordering::Relaxed);
+    }
+
+    /// Take a pending redraw request. Always true with `redraw_every_frame`.
+    pub fn take_redraw_request(&self) -> bool {
+        self.redraw_requested.swap(false, Ordering::Relaxed) || self.redraw_every_frame
+    }
+
+    pub fn set_redraw_every_frame(&mut self, redraw_every_frame: bool) {
+        self.redraw_every_frame = redraw_every_frame;
+    }
+
+    pub fn redraw_every_frame(&self) -> bool {
+        self.redraw_every_frame
+    }
+}
+
+impl ComponentTrait for CanvasComponent {
+    fn new(name: String) -> Self {
+        Self::new(name)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn id(&self) -> u32 {
+        self.component_id
+    }
+
+    fn component_type(&self) -> &'static str {
+        "Canvas"
+    }
+
+    fn is_enabled_self(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled_self(&mut self, enabled: bool) {
+        self.set_enabled(enabled);
+    }
+
+    fn is_enabled_in_hierarchy(&self) -> bool {
+        self.enabled_in_hierarchy
+    }
+
+    fn set_enabled_in_hierarchy(&mut self, enabled: bool) {
+        self.enabled_in_hierarchy = enabled;
+    }
+
+    fn update(&self, _time: &Time) {}
+    fn fixed_update(&self, _time: &Time, _fixed_time: f32) {}
+    fn on_start(&self) {}
+    fn on_destroy(&self) {}
+    fn on_enable(&self) {}
+    fn on_disable(&self) {}
+
+    fn clone_component(&self) -> Box<dyn ComponentTrait> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl UIComponentTrait for CanvasComponent {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn handle_event(&mut self, _event: &UIEvent) -> bool {
+        false
+    }
+
+    fn render(&self, draw_manager: &mut DrawManager, offset: (f32, f32)) {
+        let Ok(mut guard) = self.on_render.lock() else {
+            return;
+        };
+        let Some(callback) = guard.as_mut() else {
+            return;
+        };
+
+        let (width, height) = (self.bounds.width, self.bounds.height);
+        let mut region = DrawManager::new();
+        region.push_clip_rect(0.0, 0.0, width, height);
+        callback(&mut region, width, height);
+
+        let (x, y) = (self.bounds.x + offset.0, self.bounds.y + offset.1);
+        region.offset_commands_from(0, x, y, self.depth);
+        let canvas_clip = ClipRect::new(x, y, width, height);
+        let outer = draw_manager
+            .current_clip_rect()
+            .map_or(canvas_clip, |outer| outer.intersect(&canvas_clip));
+        let clips: Vec<Option<ClipRect>> = region
+            .clip_rects()
+            .iter()
+            .map(|clip| Some(clip.map_or(outer, |clip| outer.intersect(&clip))))
+            .collect();
+        draw_manager.append_commands_with_clips(region.commands(), &clips);
+    }
+
+    fn ui_depth(&self) -> f32 {
+        self.depth
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled && self.enabled_in_hierarchy
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::draw_manager::DrawCommand;
+    use crate::types::color::Color;
+
+    #[test]
+    fn test_draws_in_local_coordinates_clipped_to_bounds() {
+        let mut canvas = CanvasComponent::new("Canvas")
+            .with_bounds(10.0, 20.0, 100.0, 50.0)
+            .with_depth(2.0);
+        canvas.set_on_render(|draw, width, height| {
+            assert_eq!((width, height), (100.0, 50.0));
+            draw.draw_rectangle_with_options(0.0, 0.0, 400.0, 10.0, Color::RED, true, 1.0, 0.5);
+        });
+
+        let mut draw_manager = DrawManager::new();
+        canvas.render(&mut draw_manager, (5.0, 5.0));
+
+        match &draw_manager.commands()[0] {
+            DrawCommand::Rectangle { x, y, draw_order, .. } => {
+                assert_eq!((*x, *y, *draw_order), (15.0, 25.0, 2.5));
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+        assert_eq!(draw_manager.clip_rects()[0], Some(ClipRect::new(15.0, 25.0, 100.0, 50.0)));
+    }
+
+    #[test]
+    fn test_redraw_requests_are_shared_between_clones() {
+        let mut canvas = CanvasComponent::new("Canvas");
+        canvas.set_on_render(|_, _, _| {});
+        assert!(canvas.take_redraw_request());
+        assert!(!canvas.take_redraw_request());
+
+        canvas.clone().request_redraw();
+        assert!(canvas.take_redraw_request());
+        canvas.set_redraw_every_frame(true);
+        assert!(canvas.take_redraw_request());
+    }
+}
@@ -0,0 +1,579 @@
+use super::{Rect, StyleState, UIComponentTrait};
+use super::event::UIEvent;
+use super::style::{StyleSet, UITheme};
+use crate::core::component::{ComponentTrait, next_component_id};
+use crate::core::curve::AnimationCurve;
+use crate::core::draw_manager::DrawManager;
+use crate::core::input_manager::MouseButtonType;
+use crate::core::time::Time;
+use crate::types::color::Color;
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+use winit::keyboard::{Key, NamedKey};
+
+type CurveCallback = Arc<Mutex<Option<Box<dyn FnMut(&AnimationCurve) + Send + Sync>>>>;
+
+/// Radius of a drawn keyframe handle, in pixels.
+const KEY_RADIUS: f32 = 4.0;
+/// Extra slack around a handle that still counts as hitting it.
+const KEY_PICK_SLACK: f32 = 3.0;
+
+/// Graph editor for an [`AnimationCurve`].
+///
+/// Plots the curve over a time and value range and lets the user edit it live:
+/// left-click on a key to select and drag it, left-click on empty space to add
+/// a key there, and right-click a key (or press Delete with one selected) to
+/// remove it. While focused with a key selected, the arrow keys nudge it by 1%
+/// of the range.
+///
+/// Styling uses the regular [`StyleSet`] fields: `background_color` and the
+/// border draw the plot area, `text_color` draws the curve and keys, and
+/// `border_color` also draws the grid and the selected key's outline.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyg_engine_native::core::curve::AnimationCurve;
+/// use pyg_engine_native::core::ui::curve_editor::CurveEditorComponent;
+///
+/// let mut editor = CurveEditorComponent::new("CurveEditor")
+///     .with_curve(AnimationCurve::linear(0.0, 0.0, 1.0, 1.0))
+///     .with_bounds(20.0, 20.0, 320.0, 180.0);
+///
+/// editor.set_on_curve_changed(|curve| println!("Keys: {:?}", curve.keys()));
+/// ```
+#[derive(Clone)]
+pub struct CurveEditorComponent {
+    component_id: u32,
+    name: String,
+    bounds: Rect,
+    style: StyleSet,
+    current_state: StyleState,
+    curve: AnimationCurve,
+    time_range: (f32, f32),
+    value_range: (f32, f32),
+    /// Number of grid divisions along each axis; 0 hides the grid.
+    grid_divisions: u32,
+    selected_key: Option<usize>,
+    on_curve_changed: CurveCallback,
+    is_hovered: bool,
+    is_focused: bool,
+    is_dragging: bool,
+    enabled: bool,
+    enabled_in_hierarchy: bool,
+    depth: f32,
+}
+
+impl std::fmt::Debug for CurveEditorComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CurveEditorComponent")
+            .field("name", &self.name)
+            .field("bounds", &self.bounds)
+            .field("curve", &self.curve)
+            .field("time_range", &self.time_range)
+            .field("value_range", &self.value_range)
+            .field("selected_key", &self.selected_key)
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+impl CurveEditorComponent {
+    /// Create an editor for an empty curve over `0.0..=1.0` on both axes.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            component_id: next_component_id(),
+            name: name.into(),
+            bounds: Rect::new(0.0, 0.0, 240.0, 160.0),
+            style: UITheme::light().curve_editor_style.clone(),
+            current_state: StyleState::Normal,
+            curve: AnimationCurve::new(),
+            time_range: (0.0, 1.0),
+            value_range: (0.0, 1.0),
+            grid_divisions: 4,
+            selected_key: None,
+            on_curve_changed: Arc::new(Mutex::new(None)),
+            is_hovered: false,
+            is_focused: false,
+            is_dragging: false,
+            enabled: true,
+            enabled_in_hierarchy: true,
+            depth: 0.0,
+        }
+    }
+
+    pub fn with_curve(mut self, curve: AnimationCurve) -> Self {
+        self.set_curve(curve);
+        self
+    }
+
+    pub fn with_time_range(mut self, start: f32, end: f32) -> Self {
+        self.set_time_range(start, end);
+        self
+    }
+
+    pub fn with_value_range(mut self, min: f32, max: f32) -> Self {
+        self.set_value_range(min, max);
+        self
+    }
+
+    pub fn with_bounds(mut self, x: f32, y: f32, width: f32, height: f32) -> Self {
+        self.bounds = Rect::new(x, y, width, height);
+        self
+    }
+
+    pub fn with_style(mut self, style: StyleSet) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_depth(mut self, depth: f32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Replace the curve without firing `on_curve_changed`.
+    pub fn set_curve(&mut self, curve: AnimationCurve) {
+        self.curve = curve;
+        self.selected_key = self.selected_key.filter(|&index| index < self.curve.len());
+        if self.selected_key.is_none() {
+            self.is_dragging = false;
+        }
+    }
+
+    pub fn curve(&self) -> &AnimationCurve {
+        &self.curve
+    }
+
+    /// Set the visible time range. Swaps the ends if `start > end`.
+    pub fn set_time_range(&mut self, start: f32, end: f32) {
+        self.time_range = (start.min(end), start.max(end));
+    }
+
+    pub fn time_range(&self) -> (f32, f32) {
+        self.time_range
+    }
+
+    /// Set the visible value range. Swaps the ends if `min > max`.
+    pub fn set_value_range(&mut self, min: f32, max: f32) {
+        self.value_range = (min.min(max), min.max(max));
+    }
+
+    pub fn value_range(&self) -> (f32, f32) {
+        self.value_range
+    }
+
+    pub fn set_grid_divisions(&mut self, divisions: u32) {
+        self.grid_divisions = divisions;
+    }
+
+    pub fn grid_divisions(&self) -> u32 {
+        self.grid_divisions
+    }
+
+    pub fn selected_key(&self) -> Option<usize> {
+        self.selected_key
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.is_dragging
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.is_dragging = false;
+        }
+        self.update_state();
+    }
+
+    pub fn set_style(&mut self, style: StyleSet) {
+        self.style = style;
+    }
+
+    /// Called with the edited curve whenever the user adds, moves or removes a key.
+    pub fn set_on_curve_changed<F>(&mut self, callback: F)
+    where
+        F: FnMut(&AnimationCurve) + Send + Sync + 'static,
+    {
+        *self.on_curve_changed.lock().unwrap() = Some(Box::new(callback));
+    }
+
+    fn notify_changed(&mut self) {
+        if let Ok(mut guard) = self.on_curve_changed.lock()
+            && let Some(callback) = guard.as_mut()
+        {
+            callback(&self.curve);
+        }
+    }
+
+    /// Map a curve point to a screen position, with `offset` added.
+    fn to_screen(&self, time: f32, value: f32, offset: (f32, f32)) -> (f32, f32) {
+        let (t0, t1) = self.time_range;
+        let (v0, v1) = self.value_range;
+        let tx = if t1 - t0 > f32::EPSILON { (time - t0) / (t1 - t0) } else { 0.0 };
+        let ty = if v1 - v0 > f32::EPSILON { (value - v0) / (v1 - v0) } else { 0.0 };
+        (
+            self.bounds.x + offset.0 + tx * self.bounds.width,
+            self.bounds.y + offset.1 + (1.0 - ty) * self.bounds.height,
+        )
+    }
+
+    /// Map a screen point to a `(time, value)` pair clamped to the visible ranges.
+    fn curve_point_at(&self, x: f64, y: f64) -> (f32, f32) {
+        let (t0, t1) = self.time_range;
+        let (v0, v1) = self.value_range;
+        let fx = ((x as f32 - self.bounds.x) / self.bounds.width.max(1.0)).clamp(0.0, 1.0);
+        let fy = ((y as f32 - self.bounds.y) / self.bounds.height.max(1.0)).clamp(0.0, 1.0);
+        (t0 + fx * (t1 - t0), v1 - fy * (v1 - v0))
+    }
+
+    /// Index of the key drawn closest to a screen point, if one is in reach.
+    fn key_at(&self, x: f64, y: f64) -> Option<usize> {
+        let reach = KEY_RADIUS + KEY_PICK_SLACK;
+        self.curve
+            .keys()
+            .iter()
+            .enumerate()
+            .map(|(index, key)| {
+                let (kx, ky) = self.to_screen(key.time, key.value, (0.0, 0.0));
+                (index, (kx - x as f32).hypot(ky - y as f32))
+            })
+            .filter(|&(_, distance)| distance <= reach)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(index, _)| index)
+    }
+
+    fn move_selected_key(&mut self, time: f32, value: f32) -> bool {
+        let Some(index) = self.selected_key else {
+            return false;
+        };
+        let key = self.curve.keys()[index];
+        if key.time == time && key.value == value {
+            return false;
+        }
+        self.selected_key = self.curve.set_key(index, time, value);
+        self.notify_changed();
+        true
+    }
+
+    fn remove_key(&mut self, index: usize) {
+        if self.curve.remove_key(index).is_some() {
+            self.selected_key = None;
+            self.is_dragging = false;
+            self.notify_changed();
+        }
+    }
+
+    fn update_state(&mut self) {
+        self.current_state = if !(self.enabled && self.enabled_in_hierarchy) {
+            StyleState::Disabled
+        } else if self.is_dragging {
+            StyleState::Pressed
+        } else if self.is_hovered {
+            StyleState::Hovered
+        } else if self.is_focused {
+            StyleState::Focused
+        } else {
+            StyleState::Normal
+        };
+    }
+}
+
+impl ComponentTrait for CurveEditorComponent {
+    fn new(name: String) -> Self {
+        Self::new(name)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn id(&self) -> u32 {
+        self.component_id
+    }
+
+    fn component_type(&self) -> &'static str {
+        "CurveEditor"
+    }
+
+    fn is_enabled_self(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled_self(&mut self, enabled: bool) {
+        self.set_enabled(enabled);
+    }
+
+    fn is_enabled_in_hierarchy(&self) -> bool {
+        self.enabled_in_hierarchy
+    }
+
+    fn set_enabled_in_hierarchy(&mut self, enabled: bool) {
+        self.enabled_in_hierarchy = enabled;
+        if !enabled {
+            self.is_dragging = false;
+        }
+        self.update_state();
+    }
+
+    fn update(&self, _time: &Time) {}
+    fn fixed_update(&self, _time: &Time, _fixed_time: f32) {}
+    fn on_start(&self) {}
+    fn on_destroy(&self) {}
+    fn on_enable(&self) {}
+    fn on_disable(&self) {}
+
+    fn clone_component(&self) -> Box<dyn ComponentTrait> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl UIComponentTrait for CurveEditorComponent {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn handle_event(&mut self, event: &UIEvent) -> bool {
+        if !(self.enabled && self.enabled_in_hierarchy) {
+            return false;
+        }
+
+        let handled = match event {
+            UIEvent::MouseEnter { .. } => {
+                self.is_hovered = true;
+                true
+            }
+            UIEvent::MouseExit { .. } => {
+                self.is_hovered = false;
+                true
+            }
+            UIEvent::MouseDown {
+                x,
+                y,
+                button: MouseButtonType::Left,
+            } => {
+                self.selected_key = match self.key_at(*x, *y) {
+                    Some(index) => Some(index),
+                    None => {
+                        let (time, value) = self.curve_point_at(*x, *y);
+                        let index = self.curve.add_key(time, value);
+                        self.notify_changed();
+                        index
+                    }
+                };
+                self.is_dragging = self.selected_key.is_some();
+                true
+            }
+            UIEvent::MouseDown {
+                x,
+                y,
+                button: MouseButtonType::Right,
+            } => {
+                if let Some(index) = self.key_at(*x, *y) {
+                    self.remove_key(index);
+                }
+                true
+            }
+            UIEvent::MouseMove { x, y, .. } if self.is_dragging => {
+                let (time, value) = self.curve_point_at(*x, *y);
+                self.move_selected_key(time, value);
+                true
+            }
+            UIEvent::MouseUp {
+                button: MouseButtonType::Left,
+                ..
+            } => {
+                self.is_dragging = false;
+                true
+            }
+            UIEvent::FocusGained => {
+                self.is_focused = true;
+                true
+            }
+            UIEvent::FocusLost => {
+                self.is_focused = false;
+                self.is_dragging = false;
+                true
+            }
+            UIEvent::KeyDown { key, .. } => {
+                let Some(index) = self.selected_key else {
+                    return false;
+                };
+                let key_frame = self.curve.keys()[index];
+                let time_step = (self.time_range.1 - self.time_range.0) * 0.01;
+                let value_step = (self.value_range.1 - self.value_range.0) * 0.01;
+                let (time, value) = match key {
+                    Key::Named(NamedKey::Delete | NamedKey::Backspace) => {
+                        self.remove_key(index);
+                        self.update_state();
+                        return true;
+                    }
+                    Key::Named(NamedKey::ArrowLeft) => (key_frame.time - time_step, key_frame.value),
+                    Key::Named(NamedKey::ArrowRight) => (key_frame.time + time_step, key_frame.value),
+                    Key::Named(NamedKey::ArrowUp) => (key_frame.time, key_frame.value + value_step),
+                    Key::Named(NamedKey::ArrowDown) => (key_frame.time, key_frame.value - value_step),
+                    Key::Named(NamedKey::Escape) => {
+                        self.selected_key = None;
+                        return true;
+                    }
+                    _ => return false,
+                };
+                self.move_selected_key(time, value);
+                true
+            }
+            _ => false,
+        };
+
+        self.update_state();
+        handled
+    }
+
+    fn render(&self, draw_manager: &mut DrawManager, offset: (f32, f32)) {
+        let style = self.style.get_style(self.current_state);
+        let x = self.bounds.x + offset.0;
+        let y = self.bounds.y + offset.1;
+        let (width, height) = (self.bounds.width, self.bounds.height);
+        let to_color = |rgba: [f32; 4]| Color::new(rgba[0], rgba[1], rgba[2], rgba[3]);
+
+        if style.background_color[3] > 0.0 {
+            draw_manager.draw_rectangle_with_options(
+                x,
+                y,
+                width,
+                height,
+                to_color(style.background_color),
+                true,
+                1.0,
+                self.depth,
+            );
+        }
+
+        let border = style.border_color;
+        let grid_color = Color::new(border[0], border[1], border[2], border[3] * 0.5);
+        for step in 1..self.grid_divisions {
+            let fraction = step as f32 / self.grid_divisions as f32;
+            let gx = x + fraction * width;
+            let gy = y + fraction * height;
+            draw_manager.draw_line_with_options(gx, y, gx, y + height, 1.0, grid_color, self.depth + 0.002);
+            draw_manager.draw_line_with_options(x, gy, x + width, gy, 1.0, grid_color, self.depth + 0.002);
+        }
+
+        // Sample the curve every few pixels so smooth interpolation reads as a curve.
+        draw_manager.push_clip_rect(x, y, width, height);
+        let curve_color = to_color(style.text_color);
+        let samples = ((width / 4.0).ceil() as usize).max(1);
+        let (t0, t1) = self.time_range;
+        let mut previous = None;
+        for sample in 0..=samples {
+            let time = t0 + (t1 - t0) * sample as f32 / samples as f32;
+            let point = self.to_screen(time, self.curve.evaluate(time), offset);
+            if let Some((px, py)) = previous {
+                draw_manager.draw_line_with_options(px, py, point.0, point.1, 2.0, curve_color, self.depth + 0.005);
+            }
+            previous = Some(point);
+        }
+        draw_manager.pop_clip_rect();
+
+        for (index, key) in self.curve.keys().iter().enumerate() {
+            let (kx, ky) = self.to_screen(key.time, key.value, offset);
+            draw_manager.draw_circle_with_options(kx, ky, KEY_RADIUS, curve_color, true, 1.0, 16, self.depth + 0.01);
+            if self.selected_key == Some(index) {
+                draw_manager.draw_circle_with_options(
+                    kx,
+                    ky,
+                    KEY_RADIUS + 2.0,
+                    to_color(style.border_color),
+                    false,
+                    1.5,
+                    16,
+                    self.depth + 0.015,
+                );
+            }
+        }
+
+        if style.border_width > 0.0 {
+            draw_manager.draw_rectangle_with_options(
+                x,
+                y,
+                width,
+                height,
+                to_color(style.border_color),
+                false,
+                style.border_width,
+                self.depth + 0.02,
+            );
+        }
+    }
+
+    fn ui_depth(&self) -> f32 {
+        self.depth
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled && self.enabled_in_hierarchy
+    }
+
+    fn apply_theme(&mut self, old: &UITheme, new: &UITheme) {
+        self.style.retheme(&old.curve_editor_style, &new.curve_editor_style);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_click_adds_and_drag_moves_key() {
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&changes);
+        let mut editor = CurveEditorComponent::new("CurveEditor")
+            .with_value_range(0.0, 10.0)
+            .with_bounds(0.0, 0.0, 100.0, 100.0);
+        editor.set_on_curve_changed(move |curve| log.lock().unwrap().push(curve.len()));
+
+        // Clicking empty space adds a key under the cursor and grabs it.
+        editor.handle_event(&UIEvent::MouseDown { x: 50.0, y: 50.0, button: MouseButtonType::Left });
+        assert_eq!(editor.selected_key(), Some(0));
+        assert_eq!(editor.curve().keys()[0].value, 5.0);
+
+        editor.handle_event(&UIEvent::MouseMove { x: 75.0, y: 200.0, dx: 25.0, dy: 150.0 });
+        editor.handle_event(&UIEvent::MouseUp { x: 75.0, y: 200.0, button: MouseButtonType::Left });
+        let key = editor.curve().keys()[0];
+        assert_eq!((key.time, key.value), (0.75, 0.0));
+
+        // Right-clicking the key removes it.
+        editor.handle_event(&UIEvent::MouseDown { x: 76.0, y: 99.0, button: MouseButtonType::Right });
+        assert!(editor.curve().is_empty());
+        assert_eq!(editor.selected_key(), None);
+        assert_eq!(*changes.lock().unwrap(), vec![1, 1, 0]);
+    }
+}
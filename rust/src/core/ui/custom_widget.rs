@@ -0,0 +1,228 @@
+use super::{Rect, UIComponentTrait};
+use super::event::{EventPhase, UIEvent};
+use crate::core::component::{ComponentTrait, next_component_id};
+use crate::core::draw_manager::DrawManager;
+use crate::core::time::Time;
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+
+type EventCallback = Arc<Mutex<Option<Box<dyn FnMut(&UIEvent, EventPhase) -> bool + Send + Sync>>>>;
+
+/// UI hit area that forwards its events to a callback.
+///
+/// Takes part in hit testing, focus and mouse wheel routing like the built-in
+/// widgets but draws nothing itself, so widgets can be prototyped outside of
+/// Rust (for example from Python with draw commands) before being ported.
+/// The callback returns whether it handled the event, which stops its
+/// propagation; unhandled mouse wheel events continue to the nearest
+/// enclosing scroll view. With `capture_events` the callback also sees
+/// pointer events headed for the widget's UI descendants, in the capture
+/// phase before they do, so e.g. a draggable card can take over a drag
+/// that starts on one of its buttons.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyg_engine_native::core::ui::custom_widget::CustomWidgetComponent;
+///
+/// let mut knob = CustomWidgetComponent::new("Knob").with_bounds(40.0, 40.0, 64.0, 64.0);
+/// knob.set_on_event(|event, _phase| {
+///     println!("knob got {}", event.name());
+///     true
+/// });
+/// ```
+#[derive(Clone)]
+pub struct CustomWidgetComponent {
+    component_id: u32,
+    name: String,
+    bounds: Rect,
+    on_event: EventCallback,
+    capture_events: bool,
+    enabled: bool,
+    enabled_in_hierarchy: bool,
+    depth: f32,
+}
+
+impl std::fmt::Debug for CustomWidgetComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CustomWidgetComponent")
+            .field("name", &self.name)
+            .field("bounds", &self.bounds)
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+impl CustomWidgetComponent {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            component_id: next_component_id(),
+            name: name.into(),
+            bounds: Rect::new(0.0, 0.0, 100.0, 100.0),
+            on_event: Arc::new(Mutex::new(None)),
+            capture_events: false,
+            enabled: true,
+            enabled_in_hierarchy: true,
+            depth: 0.0,
+        }
+    }
+
+    pub fn with_bounds(mut self, x: f32, y: f32, width: f32, height: f32) -> Self {
+        self.bounds = Rect::new(x, y, width, height);
+        self
+    }
+
+    pub fn with_depth(mut self, depth: f32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Called with every UI event the widget receives and the phase it
+    /// arrived in; return whether it was handled.
+    pub fn set_on_event<F>(&mut self, callback: F)
+    where
+        F: FnMut(&UIEvent, EventPhase) -> bool + Send + Sync + 'static,
+    {
+        *self.on_event.lock().unwrap() = Some(Box::new(callback));
+    }
+
+    /// Also receive descendants' pointer events in the capture phase.
+    pub fn set_capture_events(&mut self, capture_events: bool) {
+        self.capture_events = capture_events;
+    }
+
+    pub fn capture_events(&self) -> bool {
+        self.capture_events
+    }
+}
+
+impl ComponentTrait for CustomWidgetComponent {
+    fn new(name: String) -> Self {
+        Self::new(name)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn id(&self) -> u32 {
+        self.component_id
+    }
+
+    fn component_type(&self) -> &'static str {
+        "CustomWidget"
+    }
+
+    fn is_enabled_self(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled_self(&mut self, enabled: bool) {
+        self.set_enabled(enabled);
+    }
+
+    fn is_enabled_in_hierarchy(&self) -> bool {
+        self.enabled_in_hierarchy
+    }
+
+    fn set_enabled_in_hierarchy(&mut self, enabled: bool) {
+        self.enabled_in_hierarchy = enabled;
+    }
+
+    fn update(&self, _time: &Time) {}
+    fn fixed_update(&self, _time: &Time, _fixed_time: f32) {}
+    fn on_start(&self) {}
+    fn on_destroy(&self) {}
+    fn on_enable(&self) {}
+    fn on_disable(&self) {}
+
+    fn clone_component(&self) -> Box<dyn ComponentTrait> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl UIComponentTrait for CustomWidgetComponent {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn handle_event(&mut self, event: &UIEvent) -> bool {
+        self.handle_event_in_phase(event, EventPhase::Target)
+    }
+
+    fn handle_event_in_phase(&mut self, event: &UIEvent, phase: EventPhase) -> bool {
+        if !(self.enabled && self.enabled_in_hierarchy) || (phase == EventPhase::Capture && !self.capture_events) {
+            return false;
+        }
+
+        match self.on_event.lock() {
+            Ok(mut guard) => guard.as_mut().is_some_and(|callback| callback(event, phase)),
+            Err(_) => false,
+        }
+    }
+
+    fn render(&self, _draw_manager: &mut DrawManager, _offset: (f32, f32)) {}
+
+    fn ui_depth(&self) -> f32 {
+        self.depth
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled && self.enabled_in_hierarchy
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forwards_events_while_enabled() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&received);
+        let mut widget = CustomWidgetComponent::new("Widget");
+        widget.set_on_event(move |event, _phase| {
+            log.lock().unwrap().push(event.name());
+            matches!(event, UIEvent::Click { .. })
+        });
+
+        let click = UIEvent::Click { x: 1.0, y: 1.0, button: crate::core::input_manager::MouseButtonType::Left };
+        assert!(widget.handle_event(&click));
+        assert!(!widget.handle_event(&UIEvent::FocusGained));
+        // Descendants' events only reach it once it captures.
+        assert!(!widget.handle_event_in_phase(&click, EventPhase::Capture));
+        widget.set_capture_events(true);
+        assert!(widget.handle_event_in_phase(&click, EventPhase::Capture));
+        widget.set_enabled(false);
+        assert!(!widget.handle_event(&click));
+        assert_eq!(*received.lock().unwrap(), vec!["click", "focus_gained", "click"]);
+    }
+}
@@ -1,5 +1,6 @@
 use super::UIComponentTrait;
-use crate::core::input_manager::{InputManager, MouseButtonType};
+use crate::core::input_manager::{InputEvent, InputManager, MouseButtonType};
+use winit::keyboard::{Key, NamedKey};
 
 /// UI event types
 #[derive(Debug, Clone, PartialEq)]
@@ -11,8 +12,75 @@ pub enum UIEvent {
     MouseUp { x: f64, y: f64, button: MouseButtonType },
     Click { x: f64, y: f64, button: MouseButtonType },
     DoubleClick { x: f64, y: f64, button: MouseButtonType },
+    /// The mouse wheel moved over the component, in lines (positive is up/right).
+    MouseWheel { x: f64, y: f64, delta_x: f64, delta_y: f64 },
     FocusGained,
     FocusLost,
+    /// A key was pressed (or auto-repeated) while the component has focus.
+    KeyDown { key: Key, shift: bool, ctrl: bool },
+    /// Text was typed while the component has focus.
+    TextInput { text: String },
+    /// The input method's uncommitted composition changed while the component
+    /// has focus; empty when it ended. `cursor` is a character range in `text`.
+    TextComposition { text: String, cursor: Option<(usize, usize)> },
+}
+
+impl UIEvent {
+    /// Snake-case event name, e.g. `"mouse_down"` or `"key_down"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MouseEnter { .. } => "mouse_enter",
+            Self::MouseExit { .. } => "mouse_exit",
+            Self::MouseMove { .. } => "mouse_move",
+            Self::MouseDown { .. } => "mouse_down",
+            Self::MouseUp { .. } => "mouse_up",
+            Self::Click { .. } => "click",
+            Self::DoubleClick { .. } => "double_click",
+            Self::MouseWheel { .. } => "mouse_wheel",
+            Self::FocusGained => "focus_gained",
+            Self::FocusLost => "focus_lost",
+            Self::KeyDown { .. } => "key_down",
+            Self::TextInput { .. } => "text_input",
+            Self::TextComposition { .. } => "text_composition",
+        }
+    }
+
+    /// Whether the event bubbles up to the target's UI ancestors when the
+    /// target doesn't handle it, so e.g. a label inside a button clicks the
+    /// button. Focus and keyboard events stay with the focused component.
+    pub fn bubbles(&self) -> bool {
+        matches!(
+            self,
+            Self::MouseEnter { .. }
+                | Self::MouseExit { .. }
+                | Self::MouseMove { .. }
+                | Self::MouseDown { .. }
+                | Self::MouseUp { .. }
+                | Self::Click { .. }
+                | Self::DoubleClick { .. }
+        )
+    }
+}
+
+/// Where an event is on its trip through the UI tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventPhase {
+    /// Travelling down from the outermost UI ancestor to the target's parent.
+    Capture,
+    /// At the target itself.
+    Target,
+    /// Travelling back up from the target's parent.
+    Bubble,
+}
+
+impl EventPhase {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Capture => "capture",
+            Self::Target => "target",
+            Self::Bubble => "bubble",
+        }
+    }
 }
 
 /// Manages UI events and input processing
@@ -49,29 +117,27 @@ impl UIEventManager {
     /// # Arguments
     /// * `input` - Input manager containing mouse/keyboard state
     /// * `ui_components` - List of UI components with their IDs and depths
-    /// * `scale_factor` - HiDPI scale factor (physical pixels / logical pixels)
+    /// * `mouse_position` - Mouse position in UI coordinates; the input
+    ///   manager reports it in physical window pixels
     pub fn process_input(
         &mut self,
         input: &InputManager,
         ui_components: &[(u32, &dyn UIComponentTrait, f64)], // (id, component, depth)
-        scale_factor: f32,
+        mouse_position: (f64, f64),
     ) -> Vec<(u32, UIEvent)> {
         let mut events = Vec::new();
         self.input_consumed = false;
 
-        // Get mouse position in physical pixels and convert to logical pixels
-        // UI components are positioned in logical pixels, but mouse events are in physical pixels
-        let mouse_pos = input.mouse_position();
-        let mouse_x = mouse_pos.0 / scale_factor as f64;
-        let mouse_y = mouse_pos.1 / scale_factor as f64;
+        let (mouse_x, mouse_y) = mouse_position;
 
-        // Sort components by depth (front to back for hit testing)
+        // Sort components by depth in draw order. Components at equal depth
+        // keep their given order, in which children follow their parents.
         let mut sorted_components: Vec<_> = ui_components.iter().collect();
-        sorted_components.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal));
+        sorted_components.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(std::cmp::Ordering::Equal));
 
-        // Hit test to find hovered component
+        // Hit test front to back to find the hovered component
         let mut new_hovered = None;
-        for &&(id, component, _depth) in &sorted_components {
+        for &&(id, component, _depth) in sorted_components.iter().rev() {
             if component.is_enabled() && component.contains_point(mouse_x, mouse_y) {
                 new_hovered = Some(id);
                 break; // Only the frontmost component
@@ -94,12 +160,13 @@ impl UIEventManager {
             self.hovered_component = new_hovered;
         }
 
-        // Handle mouse movement
+        // Handle mouse movement. The pressed component keeps receiving moves
+        // while the button is held, even outside its bounds, so drags work.
         if (mouse_x, mouse_y) != self.last_mouse_pos {
-            if let Some(hovered_id) = self.hovered_component {
+            if let Some(target_id) = self.pressed_component.or(self.hovered_component) {
                 let dx = mouse_x - self.last_mouse_pos.0;
                 let dy = mouse_y - self.last_mouse_pos.1;
-                events.push((hovered_id, UIEvent::MouseMove { x: mouse_x, y: mouse_y, dx, dy }));
+                events.push((target_id, UIEvent::MouseMove { x: mouse_x, y: mouse_y, dx, dy }));
                 self.input_consumed = true;
             }
             self.last_mouse_pos = (mouse_x, mouse_y);
@@ -113,19 +180,18 @@ impl UIEventManager {
 
             // Mouse down
             if is_down && !was_down {
+                if self.hovered_component.is_none()
+                    && let Some(old_focused) = self.focused_component.take()
+                {
+                    // Clicking empty space blurs the focused component.
+                    events.push((old_focused, UIEvent::FocusLost));
+                }
                 if let Some(hovered_id) = self.hovered_component {
                     events.push((hovered_id, UIEvent::MouseDown { x: mouse_x, y: mouse_y, button }));
                     self.pressed_component = Some(hovered_id);
                     self.input_consumed = true;
 
-                    // Update focus
-                    if self.focused_component != Some(hovered_id) {
-                        if let Some(old_focused) = self.focused_component {
-                            events.push((old_focused, UIEvent::FocusLost));
-                        }
-                        events.push((hovered_id, UIEvent::FocusGained));
-                        self.focused_component = Some(hovered_id);
-                    }
+                    events.extend(self.set_focus(Some(hovered_id)));
                 }
             }
 
@@ -149,9 +215,66 @@ impl UIEventManager {
             self.prev_mouse_buttons[idx] = is_down;
         }
 
+        // Tab moves focus; other keyboard input goes to the focused component
+        let shift = input.key_down(&Key::Named(NamedKey::Shift));
+        let ctrl = input.key_down(&Key::Named(NamedKey::Control))
+            || input.key_down(&Key::Named(NamedKey::Super));
+        for input_event in input.frame_events() {
+            if let InputEvent::KeyPressed { key: Key::Named(NamedKey::Tab) } = input_event {
+                let focus_events = self.move_focus(ui_components, !shift);
+                self.input_consumed |= !focus_events.is_empty();
+                events.extend(focus_events);
+                continue;
+            }
+            let Some(focused_id) = self.focused_component else {
+                continue;
+            };
+            let event = match input_event {
+                InputEvent::KeyPressed { key } => UIEvent::KeyDown {
+                    key: key.clone(),
+                    shift,
+                    ctrl,
+                },
+                InputEvent::TextInput { text } if !ctrl => UIEvent::TextInput { text: text.clone() },
+                InputEvent::TextComposition { text, cursor } => UIEvent::TextComposition {
+                    text: text.clone(),
+                    cursor: *cursor,
+                },
+                _ => continue,
+            };
+            events.push((focused_id, event));
+            self.input_consumed = true;
+        }
+
         events
     }
 
+    /// Deliver `event` along `path`: its target followed by the target's UI
+    /// ancestors, nearest first.
+    ///
+    /// Events that bubble visit the ancestors in the capture phase,
+    /// outermost first, then the target, then the ancestors again in the
+    /// bubble phase. Other events only reach the target. `deliver` returns
+    /// true to stop propagation; the id it stopped at is returned.
+    pub fn propagate(
+        event: &UIEvent,
+        path: &[u32],
+        mut deliver: impl FnMut(u32, EventPhase) -> bool,
+    ) -> Option<u32> {
+        let (&target, ancestors) = path.split_first()?;
+        let bubbles = event.bubbles();
+        if bubbles && let Some(&id) = ancestors.iter().rev().find(|&&id| deliver(id, EventPhase::Capture)) {
+            return Some(id);
+        }
+        if deliver(target, EventPhase::Target) {
+            return Some(target);
+        }
+        if bubbles {
+            return ancestors.iter().copied().find(|&id| deliver(id, EventPhase::Bubble));
+        }
+        None
+    }
+
     /// Check if input was consumed by UI this frame
     pub fn is_input_consumed(&self) -> bool {
         self.input_consumed
@@ -171,6 +294,54 @@ impl UIEventManager {
     pub fn clear_focus(&mut self) -> Option<u32> {
         self.focused_component.take()
     }
+
+    /// Focus a component (or nothing) and return the resulting focus events.
+    pub fn set_focus(&mut self, component_id: Option<u32>) -> Vec<(u32, UIEvent)> {
+        if self.focused_component == component_id {
+            return Vec::new();
+        }
+
+        let mut events = Vec::new();
+        if let Some(old_focused) = self.focused_component {
+            events.push((old_focused, UIEvent::FocusLost));
+        }
+        if let Some(new_focused) = component_id {
+            events.push((new_focused, UIEvent::FocusGained));
+        }
+        self.focused_component = component_id;
+        events
+    }
+
+    /// Move focus to the next (or previous) enabled, focusable component,
+    /// wrapping around. Tab order is the order of `ui_components`.
+    ///
+    /// Returns the resulting focus events, empty if nothing can take focus.
+    pub fn move_focus(
+        &mut self,
+        ui_components: &[(u32, &dyn UIComponentTrait, f64)],
+        forward: bool,
+    ) -> Vec<(u32, UIEvent)> {
+        let count = ui_components.len();
+        let current = self
+            .focused_component
+            .and_then(|focused| ui_components.iter().position(|&(id, _, _)| id == focused));
+        let next = (1..=count)
+            .map(|step| match (current, forward) {
+                (Some(index), true) => (index + step) % count,
+                (Some(index), false) => (index + count - step) % count,
+                (None, true) => step - 1,
+                (None, false) => count - step,
+            })
+            .find(|&index| {
+                let component = ui_components[index].1;
+                component.is_enabled() && component.is_focusable()
+            });
+
+        match next {
+            Some(index) => self.set_focus(Some(ui_components[index].0)),
+            None => Vec::new(),
+        }
+    }
 }
 
 impl Default for UIEventManager {
@@ -178,3 +349,68 @@ impl Default for UIEventManager {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ui::button::ButtonComponent;
+    use crate::core::ui::checkbox::CheckboxComponent;
+    use crate::core::ui::label::LabelComponent;
+
+    #[test]
+    fn test_move_focus_skips_unfocusable_and_wraps() {
+        let button = ButtonComponent::new("Button");
+        let label = LabelComponent::new("Label");
+        let checkbox = CheckboxComponent::new("Checkbox");
+        let mut disabled = ButtonComponent::new("Button");
+        disabled.set_enabled(false);
+        let components: Vec<(u32, &dyn UIComponentTrait, f64)> =
+            vec![(1, &button, 0.0), (2, &label, 0.0), (3, &checkbox, 0.0), (4, &disabled, 0.0)];
+
+        let mut events = UIEventManager::new();
+        assert_eq!(events.move_focus(&components, true), vec![(1, UIEvent::FocusGained)]);
+        assert_eq!(
+            events.move_focus(&components, true),
+            vec![(1, UIEvent::FocusLost), (3, UIEvent::FocusGained)]
+        );
+        events.move_focus(&components, true);
+        assert_eq!(events.focused_component(), Some(1));
+        events.move_focus(&components, false);
+        assert_eq!(events.focused_component(), Some(3));
+    }
+
+    #[test]
+    fn test_propagate_captures_then_bubbles() {
+        let click = UIEvent::Click { x: 0.0, y: 0.0, button: MouseButtonType::Left };
+        // Target 3 inside 2 inside 1.
+        let path = [3, 2, 1];
+        let mut visits = Vec::new();
+        let handler = UIEventManager::propagate(&click, &path, |id, phase| {
+            visits.push((id, phase));
+            false
+        });
+        assert_eq!(handler, None);
+        assert_eq!(
+            visits,
+            [
+                (1, EventPhase::Capture),
+                (2, EventPhase::Capture),
+                (3, EventPhase::Target),
+                (2, EventPhase::Bubble),
+                (1, EventPhase::Bubble),
+            ]
+        );
+
+        // Stopping in the capture phase keeps the event from the target.
+        let handler = UIEventManager::propagate(&click, &path, |id, phase| id == 2 && phase == EventPhase::Capture);
+        assert_eq!(handler, Some(2));
+
+        // Focus events only visit their target.
+        let mut visits = Vec::new();
+        UIEventManager::propagate(&UIEvent::FocusGained, &path, |id, phase| {
+            visits.push((id, phase));
+            false
+        });
+        assert_eq!(visits, [(3, EventPhase::Target)]);
+    }
+}
@@ -0,0 +1,374 @@
+use super::{Rect, UIComponentTrait};
+use super::event::UIEvent;
+use crate::core::component::{ComponentTrait, next_component_id};
+use crate::core::draw_manager::DrawManager;
+use crate::core::time::Time;
+use std::any::Any;
+use std::path::Path;
+
+/// Upper bound on tiles drawn per frame, so a tiny texture in a large widget
+/// can't flood the draw list.
+const MAX_TILES: usize = 4096;
+
+/// How an image is sized inside its widget bounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ImageScaleMode {
+    /// Stretch to the bounds, ignoring the aspect ratio.
+    #[default]
+    Stretch,
+    /// Scale to fit inside the bounds, keeping the aspect ratio (letterboxed).
+    Fit,
+    /// Scale to cover the bounds, keeping the aspect ratio (overflow is clipped).
+    Fill,
+    /// Repeat at the texture's native size from the top-left corner.
+    Tile,
+}
+
+impl ImageScaleMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Stretch => "stretch",
+            Self::Fit => "fit",
+            Self::Fill => "fill",
+            Self::Tile => "tile",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "stretch" => Some(Self::Stretch),
+            "fit" | "contain" => Some(Self::Fit),
+            "fill" | "cover" => Some(Self::Fill),
+            "tile" | "repeat" => Some(Self::Tile),
+            _ => None,
+        }
+    }
+}
+
+/// Non-interactive widget that displays a texture inside its bounds.
+///
+/// Because it is a regular UI component, the image follows its parent panel,
+/// scrolls and clips inside scroll views and sorts by depth like any other
+/// widget, so HUD icons don't need raw draw commands at guessed positions.
+///
+/// `Fit`, `Fill` and `Tile` need the texture's pixel size. The engine reads
+/// it from the file (relative to the source root) when the image is added or
+/// its texture changes; use [`Self::set_texture_size`] to set it yourself.
+/// Until the size is known the image is stretched.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyg_engine_native::core::ui::image::{ImageComponent, ImageScaleMode};
+///
+/// let mut icon = ImageComponent::new("CoinIcon")
+///     .with_bounds(16.0, 16.0, 32.0, 32.0)
+///     .with_texture("assets/coin.png")
+///     .with_scale_mode(ImageScaleMode::Fit);
+///
+/// icon.set_texture_size(64, 48);
+/// let drawn = icon.image_rects(0.0, 0.0);
+/// assert_eq!(drawn[0].height, 24.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ImageComponent {
+    component_id: u32,
+    name: String,
+    bounds: Rect,
+    texture_path: Option<String>,
+    /// Source pixel size of the texture, when known
+    texture_size: Option<(u32, u32)>,
+    scale_mode: ImageScaleMode,
+    enabled: bool,
+    enabled_in_hierarchy: bool,
+    depth: f32,
+}
+
+impl ImageComponent {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            component_id: next_component_id(),
+            name: name.into(),
+            bounds: Rect::new(0.0, 0.0, 64.0, 64.0),
+            texture_path: None,
+            texture_size: None,
+            scale_mode: ImageScaleMode::default(),
+            enabled: true,
+            enabled_in_hierarchy: true,
+            depth: 0.0,
+        }
+    }
+
+    pub fn with_bounds(mut self, x: f32, y: f32, width: f32, height: f32) -> Self {
+        self.bounds = Rect::new(x, y, width, height);
+        self
+    }
+
+    pub fn with_texture(mut self, texture_path: impl Into<String>) -> Self {
+        self.set_texture(Some(texture_path.into()));
+        self
+    }
+
+    pub fn with_scale_mode(mut self, scale_mode: ImageScaleMode) -> Self {
+        self.scale_mode = scale_mode;
+        self
+    }
+
+    pub fn with_depth(mut self, depth: f32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Set the texture path, or `None` to show nothing. Clears the known texture size.
+    pub fn set_texture(&mut self, texture_path: Option<String>) {
+        if self.texture_path != texture_path {
+            self.texture_path = texture_path;
+            self.texture_size = None;
+        }
+    }
+
+    pub fn texture(&self) -> Option<&str> {
+        self.texture_path.as_deref()
+    }
+
+    pub fn set_texture_size(&mut self, width: u32, height: u32) {
+        self.texture_size = (width > 0 && height > 0).then_some((width, height));
+    }
+
+    pub fn texture_size(&self) -> Option<(u32, u32)> {
+        self.texture_size
+    }
+
+    /// Read the texture size from the image file at `resolved_path` without decoding it.
+    pub fn load_texture_size(&mut self, resolved_path: &Path) -> Result<(), String> {
+        let (width, height) = image::image_dimensions(resolved_path)
+            .map_err(|error| format!("Failed to read image size of '{}': {error}", resolved_path.display()))?;
+        self.set_texture_size(width, height);
+        Ok(())
+    }
+
+    pub fn set_scale_mode(&mut self, scale_mode: ImageScaleMode) {
+        self.scale_mode = scale_mode;
+    }
+
+    pub fn scale_mode(&self) -> ImageScaleMode {
+        self.scale_mode
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Screen rects the texture is drawn into for the current scale mode.
+    ///
+    /// `Fill` and `Tile` rects may extend past the bounds; rendering clips them.
+    pub fn image_rects(&self, offset_x: f32, offset_y: f32) -> Vec<Rect> {
+        let bounds = Rect::new(
+            self.bounds.x + offset_x,
+            self.bounds.y + offset_y,
+            self.bounds.width,
+            self.bounds.height,
+        );
+        if bounds.width <= 0.0 || bounds.height <= 0.0 {
+            return Vec::new();
+        }
+
+        let Some((texture_width, texture_height)) = self.texture_size else {
+            return vec![bounds];
+        };
+        let (texture_width, texture_height) = (texture_width as f32, texture_height as f32);
+
+        match self.scale_mode {
+            ImageScaleMode::Stretch => vec![bounds],
+            ImageScaleMode::Fit | ImageScaleMode::Fill => {
+                let scale_x = bounds.width / texture_width;
+                let scale_y = bounds.height / texture_height;
+                let scale = if self.scale_mode == ImageScaleMode::Fit {
+                    scale_x.min(scale_y)
+                } else {
+                    scale_x.max(scale_y)
+                };
+                let width = texture_width * scale;
+                let height = texture_height * scale;
+                vec![Rect::new(
+                    bounds.x + (bounds.width - width) / 2.0,
+                    bounds.y + (bounds.height - height) / 2.0,
+                    width,
+                    height,
+                )]
+            }
+            ImageScaleMode::Tile => {
+                let columns = (bounds.width / texture_width).ceil() as usize;
+                let rows = (bounds.height / texture_height).ceil() as usize;
+                if columns.saturating_mul(rows) > MAX_TILES {
+                    return vec![bounds];
+                }
+                let mut rects = Vec::with_capacity(columns * rows);
+                for row in 0..rows {
+                    for column in 0..columns {
+                        rects.push(Rect::new(
+                            bounds.x + column as f32 * texture_width,
+                            bounds.y + row as f32 * texture_height,
+                            texture_width,
+                            texture_height,
+                        ));
+                    }
+                }
+                rects
+            }
+        }
+    }
+
+    /// Whether the drawn rects can extend past the bounds and need clipping.
+    fn needs_clip(&self) -> bool {
+        self.texture_size.is_some() && matches!(self.scale_mode, ImageScaleMode::Fill | ImageScaleMode::Tile)
+    }
+}
+
+impl ComponentTrait for ImageComponent {
+    fn new(name: String) -> Self {
+        Self::new(name)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn id(&self) -> u32 {
+        self.component_id
+    }
+
+    fn component_type(&self) -> &'static str {
+        "Image"
+    }
+
+    fn is_enabled_self(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled_self(&mut self, enabled: bool) {
+        self.set_enabled(enabled);
+    }
+
+    fn is_enabled_in_hierarchy(&self) -> bool {
+        self.enabled_in_hierarchy
+    }
+
+    fn set_enabled_in_hierarchy(&mut self, enabled: bool) {
+        self.enabled_in_hierarchy = enabled;
+    }
+
+    fn update(&self, _time: &Time) {}
+    fn fixed_update(&self, _time: &Time, _fixed_time: f32) {}
+    fn on_start(&self) {}
+    fn on_destroy(&self) {}
+    fn on_enable(&self) {}
+    fn on_disable(&self) {}
+
+    fn clone_component(&self) -> Box<dyn ComponentTrait> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl UIComponentTrait for ImageComponent {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn handle_event(&mut self, _event: &UIEvent) -> bool {
+        // Images don't handle events
+        false
+    }
+
+    fn render(&self, draw_manager: &mut DrawManager, offset: (f32, f32)) {
+        let Some(texture_path) = &self.texture_path else {
+            return;
+        };
+
+        let clip = self.needs_clip();
+        if clip {
+            draw_manager.push_clip_rect(
+                self.bounds.x + offset.0,
+                self.bounds.y + offset.1,
+                self.bounds.width,
+                self.bounds.height,
+            );
+        }
+        for rect in self.image_rects(offset.0, offset.1) {
+            draw_manager.draw_image_with_options(
+                rect.x,
+                rect.y,
+                rect.width,
+                rect.height,
+                texture_path.clone(),
+                self.depth,
+            );
+        }
+        if clip {
+            draw_manager.pop_clip_rect();
+        }
+    }
+
+    fn ui_depth(&self) -> f32 {
+        self.depth
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled && self.enabled_in_hierarchy
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale_modes() {
+        let mut image = ImageComponent::new("Image")
+            .with_bounds(10.0, 10.0, 100.0, 50.0)
+            .with_texture("icon.png");
+
+        // Unknown size falls back to stretching.
+        image.set_scale_mode(ImageScaleMode::Fit);
+        assert_eq!(image.image_rects(0.0, 0.0), vec![Rect::new(10.0, 10.0, 100.0, 50.0)]);
+
+        image.set_texture_size(20, 20);
+        assert_eq!(image.image_rects(0.0, 0.0), vec![Rect::new(35.0, 10.0, 50.0, 50.0)]);
+
+        image.set_scale_mode(ImageScaleMode::Fill);
+        assert_eq!(image.image_rects(0.0, 0.0), vec![Rect::new(10.0, -15.0, 100.0, 100.0)]);
+
+        image.set_scale_mode(ImageScaleMode::Tile);
+        let tiles = image.image_rects(5.0, 0.0);
+        assert_eq!(tiles.len(), 15);
+        assert_eq!(tiles[0], Rect::new(15.0, 10.0, 20.0, 20.0));
+
+        // Changing the texture forgets the old size.
+        image.set_texture(Some("other.png".to_string()));
+        assert_eq!(image.texture_size(), None);
+    }
+}
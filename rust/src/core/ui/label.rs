@@ -1,15 +1,19 @@
 use super::{Rect, UIComponentTrait};
 use super::event::UIEvent;
-use super::style::UIStyle;
+use super::style::{UIStyle, UITheme};
 use super::layout::UILayoutComponent;
 use crate::core::component::{ComponentTrait, next_component_id};
 use crate::core::draw_manager::DrawManager;
-use crate::core::text::{FontStyle, FontWeight, TextAlign, VerticalTextAlign};
+use crate::core::text::{FontStyle, FontWeight, TextAlign, TextLayoutOptions, TextStyle, VerticalTextAlign};
 use crate::core::time::Time;
 use crate::types::color::Color;
 use std::any::Any;
 
 /// Label UI component for displaying text
+///
+/// With `wrap` the text breaks into lines at the label's width, and with
+/// `auto_height` `UIManager` resizes the label to the height of its text
+/// each frame, overriding the height of any `UILayoutComponent`.
 #[derive(Debug, Clone)]
 pub struct LabelComponent {
     component_id: u32,
@@ -19,6 +23,9 @@ pub struct LabelComponent {
     text: String,
     style: UIStyle,
     text_align: TextAlign,
+    vertical_align: VerticalTextAlign,
+    wrap: bool,
+    auto_height: bool,
     enabled: bool,
     enabled_in_hierarchy: bool,
     depth: f32,
@@ -32,8 +39,11 @@ impl LabelComponent {
             bounds: Rect::new(0.0, 0.0, 100.0, 20.0),
             layout: UILayoutComponent::with_fixed_size(100.0, 20.0),
             text: String::new(),
-            style: UIStyle::transparent(),
+            style: UITheme::light().label_style.clone(),
             text_align: TextAlign::Left,
+            vertical_align: VerticalTextAlign::Top,
+            wrap: false,
+            auto_height: false,
             enabled: true,
             enabled_in_hierarchy: true,
             depth: 0.0,
@@ -67,6 +77,21 @@ impl LabelComponent {
         self
     }
 
+    pub fn with_vertical_align(mut self, align: VerticalTextAlign) -> Self {
+        self.vertical_align = align;
+        self
+    }
+
+    pub fn with_wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    pub fn with_auto_height(mut self, auto_height: bool) -> Self {
+        self.auto_height = auto_height;
+        self
+    }
+
     pub fn with_depth(mut self, depth: f32) -> Self {
         self.depth = depth;
         self
@@ -97,6 +122,16 @@ impl LabelComponent {
         self.style.set_font_family(font_family);
     }
 
+    /// Override the theme's fallback fonts for this label. An empty list
+    /// goes back to the theme's.
+    pub fn set_font_fallbacks(&mut self, fallbacks: Vec<String>) {
+        self.style.set_font_fallbacks(fallbacks);
+    }
+
+    pub fn font_fallbacks(&self) -> &[String] {
+        self.style.font_fallbacks()
+    }
+
     pub fn set_font_weight(&mut self, font_weight: FontWeight) {
         self.style.set_font_weight(font_weight);
     }
@@ -109,6 +144,16 @@ impl LabelComponent {
         self.style.set_kerning(kerning);
     }
 
+    /// Extra space in pixels between characters.
+    pub fn set_letter_spacing(&mut self, letter_spacing: f32) {
+        self.style.set_letter_spacing(letter_spacing);
+    }
+
+    /// Extra space in pixels between lines.
+    pub fn set_line_spacing(&mut self, line_spacing: f32) {
+        self.style.set_line_spacing(line_spacing);
+    }
+
     pub fn set_color(&mut self, color: [f32; 4]) {
         self.style.text_color = color;
     }
@@ -117,6 +162,65 @@ impl LabelComponent {
         self.text_align = align;
     }
 
+    pub fn align(&self) -> TextAlign {
+        self.text_align
+    }
+
+    /// Where the text sits vertically when the label is taller than it.
+    pub fn set_vertical_align(&mut self, align: VerticalTextAlign) {
+        self.vertical_align = align;
+    }
+
+    pub fn vertical_align(&self) -> VerticalTextAlign {
+        self.vertical_align
+    }
+
+    /// Break the text into lines that fit the label's width.
+    pub fn set_wrap(&mut self, wrap: bool) {
+        self.wrap = wrap;
+    }
+
+    pub fn wrap(&self) -> bool {
+        self.wrap
+    }
+
+    /// Size the label's height to its text instead of its bounds.
+    pub fn set_auto_height(&mut self, auto_height: bool) {
+        self.auto_height = auto_height;
+    }
+
+    pub fn auto_height(&self) -> bool {
+        self.auto_height
+    }
+
+    /// How the text is laid out inside the label's bounds.
+    pub fn layout_options(&self) -> TextLayoutOptions {
+        TextLayoutOptions {
+            width: Some(self.bounds.width),
+            height: Some(self.bounds.height),
+            horizontal_align: self.text_align,
+            vertical_align: self.vertical_align,
+            wrap: self.wrap,
+        }
+    }
+
+    /// With `auto_height`, set the label's height to that of its text as
+    /// measured by `measure`. Empty labels keep the height of one line.
+    /// Returns whether the height changed.
+    pub fn fit_height(&mut self, measure: &mut dyn FnMut(&str, &TextStyle, &TextLayoutOptions) -> (f32, f32)) -> bool {
+        if !self.auto_height {
+            return false;
+        }
+        let text = if self.text.is_empty() { " " } else { self.text.as_str() };
+        let (_, height) = measure(text, &self.style.text_style, &self.layout_options());
+        let height = height.ceil();
+        if height == self.bounds.height {
+            return false;
+        }
+        self.bounds.height = height;
+        true
+    }
+
     pub fn set_style(&mut self, style: UIStyle) {
         self.style = style;
     }
@@ -128,13 +232,6 @@ impl LabelComponent {
     pub fn style_mut(&mut self) -> &mut UIStyle {
         &mut self.style
     }
-
-    /// Estimate text width using font8x8 metrics (8px base glyph width).
-    fn estimate_text_width(&self) -> f32 {
-        let scale = (self.style.font_size() / 8.0).max(1.0).round();
-        let glyph_width = 8.0 * scale;
-        self.text.len() as f32 * glyph_width
-    }
 }
 
 impl ComponentTrait for LabelComponent {
@@ -216,17 +313,6 @@ impl UIComponentTrait for LabelComponent {
         let x = self.bounds.x + offset.0;
         let y = self.bounds.y + offset.1;
 
-        // Calculate text position based on alignment
-        // x is the anchor point: left edge for Left, center for Center, right edge for Right
-        let text_width = self.estimate_text_width();
-        let text_x = match self.text_align {
-            TextAlign::Left => x,
-            TextAlign::Center => x - text_width / 2.0,
-            TextAlign::Right => x - text_width,
-        };
-
-        let text_y = y;
-
         let text_color = Color::new(
             self.style.text_color[0],
             self.style.text_color[1],
@@ -240,12 +326,7 @@ impl UIComponentTrait for LabelComponent {
             y,
             self.style.text_style.clone(),
             text_color,
-            crate::core::text::TextLayoutOptions {
-                width: Some(self.bounds.width),
-                height: Some(self.bounds.height),
-                horizontal_align: self.text_align,
-                vertical_align: VerticalTextAlign::Top,
-            },
+            self.layout_options(),
             self.depth + 0.01,
         );
     }
@@ -258,6 +339,10 @@ impl UIComponentTrait for LabelComponent {
         self.enabled && self.enabled_in_hierarchy
     }
 
+    fn apply_theme(&mut self, old: &UITheme, new: &UITheme) {
+        self.style.retheme(&old.label_style, &new.label_style);
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
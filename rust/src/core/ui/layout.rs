@@ -1,4 +1,7 @@
 use super::{Rect, SizeMode};
+use crate::core::component::{ComponentTrait, next_component_id};
+use crate::core::time::Time;
+use std::any::Any;
 
 /// Anchor positions for UI layout
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -12,6 +15,56 @@ pub enum Anchor {
     BottomLeft,
     BottomCenter,
     BottomRight,
+    /// Fill the parent, inset by the offset on each side; size modes are ignored
+    Stretch,
+}
+
+impl Anchor {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Anchor::TopLeft => "top_left",
+            Anchor::TopCenter => "top_center",
+            Anchor::TopRight => "top_right",
+            Anchor::MiddleLeft => "middle_left",
+            Anchor::MiddleCenter => "center",
+            Anchor::MiddleRight => "middle_right",
+            Anchor::BottomLeft => "bottom_left",
+            Anchor::BottomCenter => "bottom_center",
+            Anchor::BottomRight => "bottom_right",
+            Anchor::Stretch => "stretch",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "top_left" => Some(Anchor::TopLeft),
+            "top_center" | "top" => Some(Anchor::TopCenter),
+            "top_right" => Some(Anchor::TopRight),
+            "middle_left" | "left" => Some(Anchor::MiddleLeft),
+            "middle_center" | "center" => Some(Anchor::MiddleCenter),
+            "middle_right" | "right" => Some(Anchor::MiddleRight),
+            "bottom_left" => Some(Anchor::BottomLeft),
+            "bottom_center" | "bottom" => Some(Anchor::BottomCenter),
+            "bottom_right" => Some(Anchor::BottomRight),
+            "stretch" => Some(Anchor::Stretch),
+            _ => None,
+        }
+    }
+
+    /// Pivot that keeps an element inside the parent at this anchor, e.g. (1, 1) for bottom-right.
+    pub fn default_pivot(self) -> (f32, f32) {
+        match self {
+            Anchor::TopLeft | Anchor::Stretch => (0.0, 0.0),
+            Anchor::TopCenter => (0.5, 0.0),
+            Anchor::TopRight => (1.0, 0.0),
+            Anchor::MiddleLeft => (0.0, 0.5),
+            Anchor::MiddleCenter => (0.5, 0.5),
+            Anchor::MiddleRight => (1.0, 0.5),
+            Anchor::BottomLeft => (0.0, 1.0),
+            Anchor::BottomCenter => (0.5, 1.0),
+            Anchor::BottomRight => (1.0, 1.0),
+        }
+    }
 }
 
 impl Default for Anchor {
@@ -44,8 +97,15 @@ impl Default for AnchorOffset {
 }
 
 /// Layout component for positioning UI elements
+///
+/// Attach it (named "UILayout") to a UI game object and `UIManager` resolves
+/// it every frame against the parent element, or the window for root
+/// elements, then overwrites the widget's bounds. Offsets and fixed sizes
+/// are in logical pixels; `FitContent` keeps the widget's current size.
 #[derive(Debug, Clone, PartialEq)]
 pub struct UILayoutComponent {
+    component_id: u32,
+    enabled: bool,
     /// Anchor point
     pub anchor: Anchor,
     /// Offset from anchor
@@ -65,6 +125,8 @@ pub struct UILayoutComponent {
 impl UILayoutComponent {
     pub fn new() -> Self {
         Self {
+            component_id: next_component_id(),
+            enabled: true,
             anchor: Anchor::TopLeft,
             offset: AnchorOffset::zero(),
             pivot: (0.0, 0.0),
@@ -75,11 +137,12 @@ impl UILayoutComponent {
         }
     }
 
-    /// Create with specific anchor and offset
+    /// Create with specific anchor and offset, using the anchor's default pivot
     pub fn with_anchor(anchor: Anchor, offset: AnchorOffset) -> Self {
         Self {
             anchor,
             offset,
+            pivot: anchor.default_pivot(),
             ..Self::new()
         }
     }
@@ -103,20 +166,32 @@ impl UILayoutComponent {
         self.computed_bounds
     }
 
-    /// Calculate bounds based on parent bounds
-    pub fn calculate_bounds(&mut self, parent_bounds: Rect) -> Rect {
+    /// Calculate bounds based on parent bounds and the element's own content size
+    pub fn calculate_bounds(&mut self, parent_bounds: Rect, content_size: (f32, f32)) -> Rect {
+        if self.anchor == Anchor::Stretch {
+            let bounds = Rect::new(
+                parent_bounds.x + self.offset.x,
+                parent_bounds.y + self.offset.y,
+                (parent_bounds.width - self.offset.x * 2.0).max(0.0),
+                (parent_bounds.height - self.offset.y * 2.0).max(0.0),
+            );
+            self.computed_bounds = Some(bounds);
+            self.layout_dirty = false;
+            return bounds;
+        }
+
         // Calculate size
         let width = match self.width_mode {
             SizeMode::Fixed(w) => w,
             SizeMode::Percentage(p) => parent_bounds.width * p,
-            SizeMode::FitContent => 100.0, // TODO: Calculate from content
+            SizeMode::FitContent => content_size.0,
             SizeMode::FillParent => parent_bounds.width,
         };
 
         let height = match self.height_mode {
             SizeMode::Fixed(h) => h,
             SizeMode::Percentage(p) => parent_bounds.height * p,
-            SizeMode::FitContent => 100.0, // TODO: Calculate from content
+            SizeMode::FitContent => content_size.1,
             SizeMode::FillParent => parent_bounds.height,
         };
 
@@ -137,7 +212,7 @@ impl UILayoutComponent {
     /// Calculate the anchor point in parent space
     fn calculate_anchor_point(&self, parent: &Rect) -> (f32, f32) {
         match self.anchor {
-            Anchor::TopLeft => (parent.x, parent.y),
+            Anchor::TopLeft | Anchor::Stretch => (parent.x, parent.y),
             Anchor::TopCenter => (parent.x + parent.width / 2.0, parent.y),
             Anchor::TopRight => (parent.x + parent.width, parent.y),
             Anchor::MiddleLeft => (parent.x, parent.y + parent.height / 2.0),
@@ -160,3 +235,83 @@ impl Default for UILayoutComponent {
         Self::new()
     }
 }
+
+impl ComponentTrait for UILayoutComponent {
+    fn new(_name: String) -> Self {
+        Self::new()
+    }
+
+    fn name(&self) -> &str {
+        "UILayout"
+    }
+
+    fn id(&self) -> u32 {
+        self.component_id
+    }
+
+    fn component_type(&self) -> &'static str {
+        "UILayout"
+    }
+
+    fn is_enabled_self(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled_self(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn is_enabled_in_hierarchy(&self) -> bool {
+        true
+    }
+
+    fn set_enabled_in_hierarchy(&mut self, _enabled: bool) {}
+
+    fn update(&self, _time: &Time) {}
+    fn fixed_update(&self, _time: &Time, _fixed_time: f32) {}
+    fn on_start(&self) {}
+    fn on_destroy(&self) {}
+    fn on_enable(&self) {}
+    fn on_disable(&self) {}
+
+    fn clone_component(&self) -> Box<dyn ComponentTrait> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolves_anchors_and_size_modes() {
+        let window = Rect::new(0.0, 0.0, 800.0, 600.0);
+
+        let mut centered = UILayoutComponent::with_anchor(Anchor::MiddleCenter, AnchorOffset::zero());
+        assert_eq!(centered.calculate_bounds(window, (200.0, 100.0)), Rect::new(350.0, 250.0, 100.0, 100.0));
+        centered.width_mode = SizeMode::FitContent;
+        centered.height_mode = SizeMode::Percentage(0.5);
+        assert_eq!(centered.calculate_bounds(window, (200.0, 100.0)), Rect::new(300.0, 150.0, 200.0, 300.0));
+
+        let mut corner = UILayoutComponent::with_anchor(Anchor::BottomRight, AnchorOffset::new(-10.0, -10.0));
+        corner.width_mode = SizeMode::Fixed(50.0);
+        corner.height_mode = SizeMode::Fixed(20.0);
+        assert_eq!(corner.calculate_bounds(window, (0.0, 0.0)), Rect::new(740.0, 570.0, 50.0, 20.0));
+
+        let mut stretched = UILayoutComponent::with_anchor(Anchor::Stretch, AnchorOffset::new(16.0, 8.0));
+        assert_eq!(stretched.calculate_bounds(window, (0.0, 0.0)), Rect::new(16.0, 8.0, 768.0, 584.0));
+        assert!(!stretched.is_dirty());
+    }
+}
@@ -0,0 +1,769 @@
+use super::{Rect, UIComponentTrait};
+use super::event::UIEvent;
+use super::style::{UIStyle, UITheme, retheme_color};
+use crate::core::component::{ComponentTrait, next_component_id};
+use crate::core::draw_manager::DrawManager;
+use crate::core::input_manager::MouseButtonType;
+use crate::core::text::{FontStyle, FontWeight, TextAlign, TextLayoutOptions, VerticalTextAlign};
+use crate::core::time::Time;
+use crate::types::color::Color;
+use std::any::Any;
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
+use winit::keyboard::{Key, NamedKey};
+
+type ItemProvider = Arc<Mutex<Option<Box<dyn FnMut(usize) -> Option<String> + Send + Sync>>>>;
+type SelectionCallback = Arc<Mutex<Option<Box<dyn FnMut(usize) + Send + Sync>>>>;
+type VisibleRowsCallback = Arc<Mutex<Option<Box<dyn FnMut(usize, usize) + Send + Sync>>>>;
+
+/// Thickness in pixels of the scrollbar thumb.
+const SCROLLBAR_WIDTH: f32 = 6.0;
+/// Shortest the scrollbar thumb gets, so it stays visible for long lists.
+const MIN_THUMB_LENGTH: f32 = 16.0;
+
+/// Virtualized list of fixed-height rows.
+///
+/// The list only knows how many items it has. Row text comes from the item
+/// provider, which is asked for the rows that are on screen and nothing else,
+/// so a list of a hundred thousand items costs as much as one of twenty. Rows
+/// are fetched again when scrolling or resizing shows other rows, after the
+/// item count changes, and after [`Self::refresh`].
+///
+/// A provider that returns `None` leaves the row empty; the Python `ListView`
+/// uses that to place widgets, added as children of the list, in those rows.
+/// Children are positioned relative to the top of the list's content and
+/// move as it scrolls, like in a [`ScrollViewComponent`](super::scroll_view::ScrollViewComponent).
+///
+/// Click a row to select it, or use the arrow, Page Up/Down, Home and End
+/// keys once the list has focus. `background_color` fills the list,
+/// `text_color` draws the rows and the scrollbar thumb is drawn in the
+/// border color.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyg_engine_native::core::ui::list_view::ListViewComponent;
+///
+/// let mut list = ListViewComponent::new("Log")
+///     .with_bounds(20.0, 20.0, 300.0, 400.0)
+///     .with_item_count(100_000)
+///     .with_row_height(22.0);
+///
+/// list.set_item_provider(|index| Some(format!("Entry #{index}")));
+/// list.set_on_selection_changed(|index| println!("Selected entry {index}"));
+/// ```
+#[derive(Clone)]
+pub struct ListViewComponent {
+    component_id: u32,
+    name: String,
+    bounds: Rect,
+    style: UIStyle,
+    item_count: usize,
+    row_height: f32,
+    scroll_y: f32,
+    /// Pixels scrolled per mouse wheel line
+    scroll_speed: f32,
+    selected: Option<usize>,
+    hovered_row: Option<usize>,
+    hover_color: [f32; 4],
+    selection_color: [f32; 4],
+    selected_text_color: [f32; 4],
+    show_scrollbar: bool,
+    /// Rows in `row_range` as returned by the item provider
+    rows: Vec<Option<String>>,
+    row_range: Range<usize>,
+    /// Whether every row must be fetched again on the next sync
+    rows_stale: bool,
+    item_provider: ItemProvider,
+    on_selection_changed: SelectionCallback,
+    on_visible_rows_changed: VisibleRowsCallback,
+    is_focused: bool,
+    enabled: bool,
+    enabled_in_hierarchy: bool,
+    depth: f32,
+}
+
+impl std::fmt::Debug for ListViewComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListViewComponent")
+            .field("name", &self.name)
+            .field("bounds", &self.bounds)
+            .field("item_count", &self.item_count)
+            .field("row_height", &self.row_height)
+            .field("scroll_y", &self.scroll_y)
+            .field("selected", &self.selected)
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+impl ListViewComponent {
+    /// Create an empty list with the default style.
+    pub fn new(name: impl Into<String>) -> Self {
+        let theme = UITheme::light();
+        Self {
+            component_id: next_component_id(),
+            name: name.into(),
+            bounds: Rect::new(0.0, 0.0, 200.0, 200.0),
+            style: theme.list_view_style.clone(),
+            item_count: 0,
+            row_height: 24.0,
+            scroll_y: 0.0,
+            scroll_speed: 40.0,
+            selected: None,
+            hovered_row: None,
+            hover_color: theme.list_view_hover_color,
+            selection_color: theme.list_view_selection_color,
+            selected_text_color: theme.list_view_selected_text_color,
+            show_scrollbar: true,
+            rows: Vec::new(),
+            row_range: 0..0,
+            rows_stale: true,
+            item_provider: Arc::new(Mutex::new(None)),
+            on_selection_changed: Arc::new(Mutex::new(None)),
+            on_visible_rows_changed: Arc::new(Mutex::new(None)),
+            is_focused: false,
+            enabled: true,
+            enabled_in_hierarchy: true,
+            depth: 0.0,
+        }
+    }
+
+    pub fn with_bounds(mut self, x: f32, y: f32, width: f32, height: f32) -> Self {
+        self.set_bounds(Rect::new(x, y, width, height));
+        self
+    }
+
+    pub fn with_style(mut self, style: UIStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_depth(mut self, depth: f32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    pub fn with_item_count(mut self, item_count: usize) -> Self {
+        self.set_item_count(item_count);
+        self
+    }
+
+    pub fn with_row_height(mut self, row_height: f32) -> Self {
+        self.set_row_height(row_height);
+        self
+    }
+
+    pub fn set_style(&mut self, style: UIStyle) {
+        self.style = style;
+    }
+
+    pub fn style(&self) -> &UIStyle {
+        &self.style
+    }
+
+    pub fn style_mut(&mut self) -> &mut UIStyle {
+        &mut self.style
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.hovered_row = None;
+        }
+    }
+
+    pub fn set_depth(&mut self, depth: f32) {
+        self.depth = depth;
+    }
+
+    /// Change how many items the list has. A selection past the new end is cleared.
+    pub fn set_item_count(&mut self, item_count: usize) {
+        self.item_count = item_count;
+        if self.selected.is_some_and(|index| index >= item_count) {
+            self.selected = None;
+        }
+        if self.hovered_row.is_some_and(|index| index >= item_count) {
+            self.hovered_row = None;
+        }
+        self.clamp_scroll();
+        self.rows_stale = true;
+    }
+
+    pub fn item_count(&self) -> usize {
+        self.item_count
+    }
+
+    pub fn set_row_height(&mut self, row_height: f32) {
+        self.row_height = row_height.max(1.0);
+        self.clamp_scroll();
+        self.rows_stale = true;
+    }
+
+    pub fn row_height(&self) -> f32 {
+        self.row_height
+    }
+
+    /// Fetch every visible row from the item provider again on the next update.
+    pub fn refresh(&mut self) {
+        self.rows_stale = true;
+    }
+
+    /// Pixels scrolled per mouse wheel line.
+    pub fn set_scroll_speed(&mut self, speed: f32) {
+        self.scroll_speed = speed.max(0.0);
+    }
+
+    pub fn scroll_speed(&self) -> f32 {
+        self.scroll_speed
+    }
+
+    pub fn set_show_scrollbar(&mut self, show: bool) {
+        self.show_scrollbar = show;
+    }
+
+    pub fn show_scrollbar(&self) -> bool {
+        self.show_scrollbar
+    }
+
+    pub fn set_hover_color(&mut self, color: [f32; 4]) {
+        self.hover_color = color;
+    }
+
+    pub fn hover_color(&self) -> [f32; 4] {
+        self.hover_color
+    }
+
+    pub fn set_selection_color(&mut self, color: [f32; 4]) {
+        self.selection_color = color;
+    }
+
+    pub fn selection_color(&self) -> [f32; 4] {
+        self.selection_color
+    }
+
+    pub fn set_selected_text_color(&mut self, color: [f32; 4]) {
+        self.selected_text_color = color;
+    }
+
+    pub fn selected_text_color(&self) -> [f32; 4] {
+        self.selected_text_color
+    }
+
+    pub fn set_font_size(&mut self, font_size: f32) {
+        self.style.set_font_size(font_size);
+    }
+
+    pub fn set_font_path(&mut self, font_path: Option<String>) {
+        self.style.set_font_path(font_path);
+    }
+
+    pub fn set_font_family(&mut self, font_family: Option<String>) {
+        self.style.set_font_family(font_family);
+    }
+
+    pub fn set_font_weight(&mut self, font_weight: FontWeight) {
+        self.style.set_font_weight(font_weight);
+    }
+
+    pub fn set_font_style(&mut self, font_style: FontStyle) {
+        self.style.set_font_style(font_style);
+    }
+
+    /// Height of all rows together.
+    pub fn content_height(&self) -> f32 {
+        self.item_count as f32 * self.row_height
+    }
+
+    /// Current scroll offset in pixels from the top of the first row.
+    pub fn scroll_offset(&self) -> f32 {
+        self.scroll_y
+    }
+
+    /// Largest scroll offset; 0 when every row fits.
+    pub fn max_scroll(&self) -> f32 {
+        (self.content_height() - self.bounds.height).max(0.0)
+    }
+
+    /// Set the scroll offset, clamped to the rows, without firing callbacks.
+    pub fn set_scroll_offset(&mut self, offset: f32) {
+        self.scroll_y = offset;
+        self.clamp_scroll();
+    }
+
+    /// Scroll by the smallest amount that shows the whole row `index`.
+    pub fn scroll_to_item(&mut self, index: usize) {
+        let top = index.min(self.item_count.saturating_sub(1)) as f32 * self.row_height;
+        let bottom = top + self.row_height;
+        if top < self.scroll_y {
+            self.scroll_y = top;
+        } else if bottom > self.scroll_y + self.bounds.height {
+            self.scroll_y = bottom - self.bounds.height;
+        }
+        self.clamp_scroll();
+    }
+
+    /// Indices of the rows that are at least partly on screen.
+    pub fn visible_range(&self) -> Range<usize> {
+        if self.item_count == 0 || self.bounds.height <= 0.0 {
+            return 0..0;
+        }
+        let first = (self.scroll_y / self.row_height).floor() as usize;
+        let end = ((self.scroll_y + self.bounds.height) / self.row_height).ceil() as usize;
+        let end = end.min(self.item_count);
+        first.min(end)..end
+    }
+
+    /// Text the provider returned for a visible row; `None` for rows that are
+    /// off screen, not fetched yet, or left empty by the provider.
+    pub fn row_text(&self, index: usize) -> Option<&str> {
+        if !self.row_range.contains(&index) {
+            return None;
+        }
+        self.rows[index - self.row_range.start].as_deref()
+    }
+
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Select a row (or clear the selection) without firing `on_selection_changed`.
+    /// Out-of-range indices clear the selection.
+    pub fn set_selected(&mut self, index: Option<usize>) {
+        self.selected = index.filter(|&index| index < self.item_count);
+    }
+
+    /// Called with the row index to get its text. Return `None` to leave the row empty.
+    pub fn set_item_provider<F>(&mut self, provider: F)
+    where
+        F: FnMut(usize) -> Option<String> + Send + Sync + 'static,
+    {
+        *self.item_provider.lock().unwrap() = Some(Box::new(provider));
+        self.rows_stale = true;
+    }
+
+    /// Called with the row index whenever the user selects a different row.
+    pub fn set_on_selection_changed<F>(&mut self, callback: F)
+    where
+        F: FnMut(usize) + Send + Sync + 'static,
+    {
+        *self.on_selection_changed.lock().unwrap() = Some(Box::new(callback));
+    }
+
+    /// Called with the visible range `first..end` each time its rows were
+    /// fetched from the item provider.
+    pub fn set_on_visible_rows_changed<F>(&mut self, callback: F)
+    where
+        F: FnMut(usize, usize) + Send + Sync + 'static,
+    {
+        *self.on_visible_rows_changed.lock().unwrap() = Some(Box::new(callback));
+    }
+
+    /// Fetch rows that came into view from the item provider.
+    ///
+    /// Rows that stay visible keep their text unless the list was refreshed.
+    /// Returns whether any row was fetched.
+    pub(crate) fn sync_rows(&mut self) -> bool {
+        let range = self.visible_range();
+        if !self.rows_stale && range == self.row_range {
+            return false;
+        }
+
+        let mut rows = Vec::with_capacity(range.len());
+        {
+            let mut provider = self.item_provider.lock().unwrap();
+            for index in range.clone() {
+                let row = if !self.rows_stale && self.row_range.contains(&index) {
+                    self.rows[index - self.row_range.start].take()
+                } else {
+                    provider.as_mut().and_then(|provider| provider(index))
+                };
+                rows.push(row);
+            }
+        }
+        self.rows = rows;
+        self.row_range = range.clone();
+        self.rows_stale = false;
+
+        if let Ok(mut guard) = self.on_visible_rows_changed.lock()
+            && let Some(callback) = guard.as_mut()
+        {
+            callback(range.start, range.end);
+        }
+        true
+    }
+
+    fn clamp_scroll(&mut self) {
+        self.scroll_y = self.scroll_y.clamp(0.0, self.max_scroll());
+    }
+
+    fn row_at(&self, x: f64, y: f64) -> Option<usize> {
+        if !self.bounds.contains(x as f32, y as f32) {
+            return None;
+        }
+        let index = ((y as f32 - self.bounds.y + self.scroll_y) / self.row_height).floor() as usize;
+        (index < self.item_count).then_some(index)
+    }
+
+    /// Select a row, scroll it into view and fire `on_selection_changed` if it changed.
+    fn select_row(&mut self, index: usize) {
+        self.scroll_to_item(index);
+        if self.selected == Some(index) {
+            return;
+        }
+        self.selected = Some(index);
+        if let Ok(mut guard) = self.on_selection_changed.lock()
+            && let Some(callback) = guard.as_mut()
+        {
+            callback(index);
+        }
+    }
+
+    fn handle_key(&mut self, key: &Key) -> bool {
+        if self.item_count == 0 {
+            return false;
+        }
+        let last = self.item_count - 1;
+        let page = ((self.bounds.height / self.row_height).floor() as usize).max(1);
+        let index = match (key, self.selected) {
+            (Key::Named(NamedKey::ArrowUp), Some(index)) => index.saturating_sub(1),
+            (Key::Named(NamedKey::ArrowUp), None) => last,
+            (Key::Named(NamedKey::ArrowDown), Some(index)) => (index + 1).min(last),
+            (Key::Named(NamedKey::ArrowDown), None) => 0,
+            (Key::Named(NamedKey::PageUp), selected) => selected.unwrap_or(0).saturating_sub(page),
+            (Key::Named(NamedKey::PageDown), selected) => selected.map_or(0, |index| index + page).min(last),
+            (Key::Named(NamedKey::Home), _) => 0,
+            (Key::Named(NamedKey::End), _) => last,
+            _ => return false,
+        };
+        self.select_row(index);
+        true
+    }
+
+    /// Scrollbar thumb rect `(x, y, width, height)`, or `None` when every row fits.
+    fn scrollbar_thumb(&self, x: f32, y: f32) -> Option<(f32, f32, f32, f32)> {
+        let max = self.max_scroll();
+        if max <= 0.0 {
+            return None;
+        }
+        let viewport = self.bounds.height;
+        let length = (viewport * viewport / self.content_height()).clamp(MIN_THUMB_LENGTH.min(viewport), viewport);
+        let position = (viewport - length) * (self.scroll_y / max);
+        Some((x + self.bounds.width - SCROLLBAR_WIDTH - 2.0, y + position, SCROLLBAR_WIDTH, length))
+    }
+}
+
+impl ComponentTrait for ListViewComponent {
+    fn new(name: String) -> Self {
+        Self::new(name)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn id(&self) -> u32 {
+        self.component_id
+    }
+
+    fn component_type(&self) -> &'static str {
+        "ListView"
+    }
+
+    fn is_enabled_self(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled_self(&mut self, enabled: bool) {
+        self.set_enabled(enabled);
+    }
+
+    fn is_enabled_in_hierarchy(&self) -> bool {
+        self.enabled_in_hierarchy
+    }
+
+    fn set_enabled_in_hierarchy(&mut self, enabled: bool) {
+        self.enabled_in_hierarchy = enabled;
+    }
+
+    fn update(&self, _time: &Time) {}
+    fn fixed_update(&self, _time: &Time, _fixed_time: f32) {}
+    fn on_start(&self) {}
+    fn on_destroy(&self) {}
+    fn on_enable(&self) {}
+    fn on_disable(&self) {}
+
+    fn clone_component(&self) -> Box<dyn ComponentTrait> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl UIComponentTrait for ListViewComponent {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+        self.clamp_scroll();
+    }
+
+    fn handle_event(&mut self, event: &UIEvent) -> bool {
+        if !(self.enabled && self.enabled_in_hierarchy) {
+            return false;
+        }
+
+        match event {
+            UIEvent::MouseMove { x, y, .. } | UIEvent::MouseEnter { x, y } => {
+                self.hovered_row = self.row_at(*x, *y);
+                true
+            }
+            UIEvent::MouseExit { .. } => {
+                self.hovered_row = None;
+                true
+            }
+            UIEvent::MouseWheel { x, y, delta_y, .. } => {
+                let before = self.scroll_y;
+                // Wheel up (positive) moves the rows down, revealing earlier ones.
+                self.scroll_y -= *delta_y as f32 * self.scroll_speed;
+                self.clamp_scroll();
+                self.hovered_row = self.row_at(*x, *y);
+                self.scroll_y != before
+            }
+            UIEvent::MouseDown {
+                x,
+                y,
+                button: MouseButtonType::Left,
+            } => {
+                if let Some(index) = self.row_at(*x, *y) {
+                    self.select_row(index);
+                }
+                true
+            }
+            UIEvent::FocusGained => {
+                self.is_focused = true;
+                true
+            }
+            UIEvent::FocusLost => {
+                self.is_focused = false;
+                true
+            }
+            UIEvent::KeyDown { key, .. } => self.handle_key(key),
+            _ => true,
+        }
+    }
+
+    fn render(&self, draw_manager: &mut DrawManager, offset: (f32, f32)) {
+        let x = self.bounds.x + offset.0;
+        let y = self.bounds.y + offset.1;
+        let width = self.bounds.width;
+        let height = self.bounds.height;
+        let to_color = |rgba: [f32; 4]| Color::new(rgba[0], rgba[1], rgba[2], rgba[3]);
+
+        if self.style.background_color[3] > 0.0 {
+            draw_manager.draw_rectangle_with_options(
+                x,
+                y,
+                width,
+                height,
+                to_color(self.style.background_color),
+                true,
+                1.0,
+                self.depth,
+            );
+        }
+
+        draw_manager.push_clip_rect(x, y, width, height);
+        let padding = self.style.padding;
+        for (index, row) in self.row_range.clone().zip(&self.rows) {
+            let row_y = y + index as f32 * self.row_height - self.scroll_y;
+            let is_selected = self.selected == Some(index);
+            let highlight = if is_selected {
+                Some(self.selection_color)
+            } else if self.hovered_row == Some(index) {
+                Some(self.hover_color)
+            } else {
+                None
+            };
+            if let Some(color) = highlight {
+                draw_manager.draw_rectangle_with_options(
+                    x,
+                    row_y,
+                    width,
+                    self.row_height,
+                    to_color(color),
+                    true,
+                    1.0,
+                    self.depth + 0.01,
+                );
+            }
+
+            if let Some(text) = row {
+                let text_color = if is_selected {
+                    self.selected_text_color
+                } else {
+                    self.style.text_color
+                };
+                draw_manager.draw_text_with_options(
+                    text.clone(),
+                    x + padding.left,
+                    row_y,
+                    self.style.text_style.clone(),
+                    to_color(text_color),
+                    TextLayoutOptions {
+                        width: Some((width - padding.left - padding.right).max(0.0)),
+                        height: Some(self.row_height),
+                        horizontal_align: TextAlign::Left,
+                        vertical_align: VerticalTextAlign::Center,
+                        wrap: false,
+                    },
+                    self.depth + 0.02,
+                );
+            }
+        }
+        draw_manager.pop_clip_rect();
+
+        if self.style.border_width > 0.0 {
+            let border_color = if self.is_focused {
+                self.selection_color
+            } else {
+                self.style.border_color
+            };
+            draw_manager.draw_rectangle_with_options(
+                x,
+                y,
+                width,
+                height,
+                to_color(border_color),
+                false,
+                self.style.border_width,
+                self.depth + 0.03,
+            );
+        }
+
+        // The thumb sits above row widgets, which are rendered by the UIManager.
+        if self.show_scrollbar
+            && let Some((thumb_x, thumb_y, thumb_width, thumb_height)) = self.scrollbar_thumb(x, y)
+        {
+            draw_manager.draw_rectangle_with_options(
+                thumb_x,
+                thumb_y,
+                thumb_width,
+                thumb_height,
+                to_color(self.style.border_color),
+                true,
+                1.0,
+                self.depth + 0.5,
+            );
+        }
+    }
+
+    fn ui_depth(&self) -> f32 {
+        self.depth
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled && self.enabled_in_hierarchy
+    }
+
+    fn apply_theme(&mut self, old: &UITheme, new: &UITheme) {
+        self.style.retheme(&old.list_view_style, &new.list_view_style);
+        retheme_color(&mut self.hover_color, old.list_view_hover_color, new.list_view_hover_color);
+        retheme_color(&mut self.selection_color, old.list_view_selection_color, new.list_view_selection_color);
+        retheme_color(&mut self.selected_text_color, old.list_view_selected_text_color, new.list_view_selected_text_color);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(named: NamedKey) -> UIEvent {
+        UIEvent::KeyDown { key: Key::Named(named), shift: false, ctrl: false }
+    }
+
+    #[test]
+    fn test_only_visible_rows_are_fetched() {
+        let fetched = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&fetched);
+        let mut list = ListViewComponent::new("ListView")
+            .with_bounds(0.0, 0.0, 200.0, 100.0)
+            .with_row_height(20.0)
+            .with_item_count(100_000);
+        list.set_item_provider(move |index| {
+            log.lock().unwrap().push(index);
+            Some(format!("Row {index}"))
+        });
+
+        assert!(list.sync_rows());
+        assert_eq!(*fetched.lock().unwrap(), (0..5).collect::<Vec<_>>());
+        assert_eq!(list.row_text(4), Some("Row 4"));
+        assert!(!list.sync_rows());
+
+        // Half a row down: rows 0..=4 are kept and only row 5 is new.
+        fetched.lock().unwrap().clear();
+        list.set_scroll_offset(10.0);
+        assert_eq!(list.visible_range(), 0..6);
+        list.sync_rows();
+        assert_eq!(*fetched.lock().unwrap(), vec![5]);
+
+        fetched.lock().unwrap().clear();
+        list.refresh();
+        list.sync_rows();
+        assert_eq!(fetched.lock().unwrap().len(), 6);
+
+        list.set_scroll_offset(f32::MAX);
+        assert_eq!(list.visible_range(), 99_995..100_000);
+    }
+
+    #[test]
+    fn test_selection_from_clicks_and_keys() {
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&changes);
+        let mut list = ListViewComponent::new("ListView")
+            .with_bounds(0.0, 0.0, 200.0, 100.0)
+            .with_row_height(20.0)
+            .with_item_count(50);
+        list.set_on_selection_changed(move |index| log.lock().unwrap().push(index));
+
+        list.handle_event(&UIEvent::MouseDown { x: 50.0, y: 45.0, button: MouseButtonType::Left });
+        assert_eq!(list.selected(), Some(2));
+        list.handle_event(&UIEvent::MouseDown { x: 50.0, y: 50.0, button: MouseButtonType::Left });
+
+        list.handle_event(&key(NamedKey::End));
+        assert_eq!(list.selected(), Some(49));
+        assert_eq!(list.scroll_offset(), list.max_scroll());
+        list.handle_event(&key(NamedKey::ArrowDown));
+        list.handle_event(&key(NamedKey::PageUp));
+        assert_eq!(list.selected(), Some(44));
+        assert_eq!(*changes.lock().unwrap(), vec![2, 49, 44]);
+
+        list.set_item_count(10);
+        assert_eq!(list.selected(), None);
+        assert!(!list.handle_event(&key(NamedKey::Enter)));
+    }
+}
@@ -8,6 +8,20 @@ pub mod layout;
 pub mod button;
 pub mod panel;
 pub mod label;
+pub mod text_input;
+pub mod slider;
+pub mod checkbox;
+pub mod toggle_switch;
+pub mod scroll_view;
+pub mod progress_bar;
+pub mod custom_widget;
+pub mod canvas;
+pub mod image;
+pub mod stack;
+pub mod curve_editor;
+pub mod radio_button;
+pub mod list_view;
+pub mod tween;
 
 /// 2D rectangle for bounds and hit detection
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -33,6 +47,15 @@ impl Rect {
     pub fn center(&self) -> (f32, f32) {
         (self.x + self.width / 2.0, self.y + self.height / 2.0)
     }
+
+    /// Overlap of two rectangles; zero-sized when they do not overlap
+    pub fn intersect(&self, other: &Rect) -> Rect {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = (self.x + self.width).min(other.x + other.width);
+        let bottom = (self.y + self.height).min(other.y + other.height);
+        Rect::new(x, y, (right - x).max(0.0), (bottom - y).max(0.0))
+    }
 }
 
 /// Visual state of a UI component
@@ -80,6 +103,17 @@ pub trait UIComponentTrait: ComponentTrait {
     /// Handle a UI event, return true if consumed
     fn handle_event(&mut self, event: &event::UIEvent) -> bool;
 
+    /// Handle an event in one phase of its propagation, return true to stop
+    /// it. Ancestors see bubbling events in the capture phase before the
+    /// target does; by default they ignore them, and the target and bubble
+    /// phases go to `handle_event`.
+    fn handle_event_in_phase(&mut self, event: &event::UIEvent, phase: event::EventPhase) -> bool {
+        match phase {
+            event::EventPhase::Capture => false,
+            event::EventPhase::Target | event::EventPhase::Bubble => self.handle_event(event),
+        }
+    }
+
     /// Render this component
     fn render(&self, draw_manager: &mut DrawManager, offset: (f32, f32));
 
@@ -93,6 +127,15 @@ pub trait UIComponentTrait: ComponentTrait {
         true
     }
 
+    /// Whether Tab and arrow-key navigation can move keyboard focus here
+    fn is_focusable(&self) -> bool {
+        false
+    }
+
+    /// Switch to the `new` theme's colors wherever this component still uses
+    /// the `old` theme's
+    fn apply_theme(&mut self, _old: &style::UITheme, _new: &style::UITheme) {}
+
     /// Downcast to Any for type checking
     fn as_any(&self) -> &dyn Any;
 
@@ -1,11 +1,10 @@
 use super::{Rect, UIComponentTrait};
 use super::event::UIEvent;
-use super::style::UIStyle;
+use super::style::{UIStyle, UITheme, retheme_color};
 use super::layout::UILayoutComponent;
 use crate::core::component::{ComponentTrait, next_component_id};
 use crate::core::draw_manager::DrawManager;
 use crate::core::time::Time;
-use crate::types::color::Color;
 use std::any::Any;
 
 /// Panel UI component - a container for other UI elements
@@ -17,6 +16,10 @@ pub struct PanelComponent {
     layout: UILayoutComponent,
     style: UIStyle,
     clip_children: bool,
+    /// Capture all UI input until the panel is hidden or removed.
+    modal: bool,
+    /// Backdrop drawn over the rest of the screen while the panel is modal.
+    dim_color: [f32; 4],
     enabled: bool,
     enabled_in_hierarchy: bool,
     depth: f32,
@@ -29,8 +32,10 @@ impl PanelComponent {
             name: name.into(),
             bounds: Rect::new(0.0, 0.0, 200.0, 200.0),
             layout: UILayoutComponent::with_fixed_size(200.0, 200.0),
-            style: UIStyle::new(),
+            style: UITheme::light().panel_style.clone(),
             clip_children: false,
+            modal: false,
+            dim_color: UITheme::light().panel_dim_color,
             enabled: true,
             enabled_in_hierarchy: true,
             depth: 0.0,
@@ -58,6 +63,11 @@ impl PanelComponent {
         self
     }
 
+    pub fn with_modal(mut self, modal: bool) -> Self {
+        self.modal = modal;
+        self
+    }
+
     pub fn set_style(&mut self, style: UIStyle) {
         self.style = style;
     }
@@ -74,9 +84,33 @@ impl PanelComponent {
         self.enabled = enabled;
     }
 
+    /// Clip child widgets to the panel's bounds.
+    pub fn set_clip_children(&mut self, clip: bool) {
+        self.clip_children = clip;
+    }
+
     pub fn clip_children(&self) -> bool {
         self.clip_children
     }
+
+    /// Make the panel modal: while it is enabled, only it and its children
+    /// receive UI input, and the rest of the screen is dimmed.
+    pub fn set_modal(&mut self, modal: bool) {
+        self.modal = modal;
+    }
+
+    pub fn is_modal(&self) -> bool {
+        self.modal
+    }
+
+    /// Set the backdrop color drawn behind a modal panel. Alpha 0 disables dimming.
+    pub fn set_dim_color(&mut self, color: [f32; 4]) {
+        self.dim_color = color;
+    }
+
+    pub fn dim_color(&self) -> [f32; 4] {
+        self.dim_color
+    }
 }
 
 impl ComponentTrait for PanelComponent {
@@ -155,45 +189,11 @@ impl UIComponentTrait for PanelComponent {
         let x = self.bounds.x + offset.0;
         let y = self.bounds.y + offset.1;
 
-        // Draw background
-        if self.style.background_color[3] > 0.0 {
-            let bg_color = Color::new(
-                self.style.background_color[0],
-                self.style.background_color[1],
-                self.style.background_color[2],
-                self.style.background_color[3],
-            );
-            draw_manager.draw_rectangle_with_options(
-                x,
-                y,
-                self.bounds.width,
-                self.bounds.height,
-                bg_color,
-                true,
-                1.0,
-                self.depth,
-            );
-        }
-
-        // Draw border
-        if self.style.border_width > 0.0 {
-            let border_color = Color::new(
-                self.style.border_color[0],
-                self.style.border_color[1],
-                self.style.border_color[2],
-                self.style.border_color[3],
-            );
-            draw_manager.draw_rectangle_with_options(
-                x,
-                y,
-                self.bounds.width,
-                self.bounds.height,
-                border_color,
-                false,
-                self.style.border_width,
-                self.depth + 0.005,
-            );
-        }
+        self.style.render_box(
+            draw_manager,
+            Rect::new(x, y, self.bounds.width, self.bounds.height),
+            self.depth,
+        );
 
         // Children will be rendered by the UIManager
     }
@@ -206,6 +206,11 @@ impl UIComponentTrait for PanelComponent {
         self.enabled && self.enabled_in_hierarchy
     }
 
+    fn apply_theme(&mut self, old: &UITheme, new: &UITheme) {
+        self.style.retheme(&old.panel_style, &new.panel_style);
+        retheme_color(&mut self.dim_color, old.panel_dim_color, new.panel_dim_color);
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
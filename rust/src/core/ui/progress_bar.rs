@@ -0,0 +1,431 @@
+use super::{Rect, StyleState, UIComponentTrait};
+use super::event::UIEvent;
+use super::style::{StyleSet, UIStyle, UITheme, retheme_color};
+use crate::core::component::{ComponentTrait, next_component_id};
+use crate::core::draw_manager::DrawManager;
+use crate::core::text::{FontStyle, FontWeight, TextAlign, TextLayoutOptions, VerticalTextAlign};
+use crate::core::time::Time;
+use crate::types::color::Color;
+use std::any::Any;
+
+/// Edge a progress bar fills from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillDirection {
+    LeftToRight,
+    RightToLeft,
+    BottomToTop,
+    TopToBottom,
+}
+
+impl FillDirection {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::LeftToRight => "left_to_right",
+            Self::RightToLeft => "right_to_left",
+            Self::BottomToTop => "bottom_to_top",
+            Self::TopToBottom => "top_to_bottom",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "left_to_right" | "ltr" => Some(Self::LeftToRight),
+            "right_to_left" | "rtl" => Some(Self::RightToLeft),
+            "bottom_to_top" | "btt" => Some(Self::BottomToTop),
+            "top_to_bottom" | "ttb" => Some(Self::TopToBottom),
+            _ => None,
+        }
+    }
+}
+
+/// Non-interactive bar showing a value from 0.0 to 1.0.
+///
+/// Useful for health bars, loading screens and cooldown indicators.
+///
+/// Styling uses the regular [`StyleSet`] fields, like [`SliderComponent`]:
+/// `background_color` and the border draw the track, and `text_color` draws
+/// the fill. The `disabled` style applies while the bar is disabled. The
+/// optional text overlay is centered on the bar in its own color; `{percent}`
+/// and `{value}` in the text are replaced with the current value.
+///
+/// [`SliderComponent`]: super::slider::SliderComponent
+///
+/// # Examples
+///
+/// ```rust
+/// use pyg_engine_native::core::ui::progress_bar::ProgressBarComponent;
+///
+/// let mut health = ProgressBarComponent::new("Health")
+///     .with_bounds(20.0, 20.0, 200.0, 18.0)
+///     .with_value(0.75)
+///     .with_text("HP {percent}%");
+///
+/// health.set_value(0.5);
+/// assert_eq!(health.display_text().as_deref(), Some("HP 50%"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ProgressBarComponent {
+    component_id: u32,
+    name: String,
+    bounds: Rect,
+    style: StyleSet,
+    current_state: StyleState,
+    value: f32,
+    fill_direction: FillDirection,
+    /// Overlay text; `{percent}` and `{value}` are substituted
+    text: Option<String>,
+    text_color: [f32; 4],
+    enabled: bool,
+    enabled_in_hierarchy: bool,
+    depth: f32,
+}
+
+impl ProgressBarComponent {
+    /// Create a new empty bar filling left to right with the default style.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            component_id: next_component_id(),
+            name: name.into(),
+            bounds: Rect::new(0.0, 0.0, 200.0, 20.0),
+            style: UITheme::light().progress_bar_style.clone(),
+            current_state: StyleState::Normal,
+            value: 0.0,
+            fill_direction: FillDirection::LeftToRight,
+            text: None,
+            text_color: UITheme::light().progress_bar_text_color,
+            enabled: true,
+            enabled_in_hierarchy: true,
+            depth: 0.0,
+        }
+    }
+
+    pub fn with_value(mut self, value: f32) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    pub fn with_fill_direction(mut self, direction: FillDirection) -> Self {
+        self.fill_direction = direction;
+        self
+    }
+
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    pub fn with_bounds(mut self, x: f32, y: f32, width: f32, height: f32) -> Self {
+        self.bounds = Rect::new(x, y, width, height);
+        self
+    }
+
+    pub fn with_style(mut self, style: StyleSet) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_depth(mut self, depth: f32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Set the value, clamped to `0.0..=1.0`. NaN counts as 0.
+    pub fn set_value(&mut self, value: f32) {
+        self.value = if value.is_nan() { 0.0 } else { value.clamp(0.0, 1.0) };
+    }
+
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    pub fn set_fill_direction(&mut self, direction: FillDirection) {
+        self.fill_direction = direction;
+    }
+
+    pub fn fill_direction(&self) -> FillDirection {
+        self.fill_direction
+    }
+
+    /// Set the overlay text, or `None` to hide it.
+    pub fn set_text(&mut self, text: Option<String>) {
+        self.text = text;
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Overlay text with `{percent}` and `{value}` filled in.
+    pub fn display_text(&self) -> Option<String> {
+        self.text.as_ref().map(|text| {
+            text.replace("{percent}", &format!("{:.0}", self.value * 100.0))
+                .replace("{value}", &format!("{:.2}", self.value))
+        })
+    }
+
+    pub fn set_text_color(&mut self, color: [f32; 4]) {
+        self.text_color = color;
+    }
+
+    pub fn text_color(&self) -> [f32; 4] {
+        self.text_color
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.update_state();
+    }
+
+    pub fn set_style(&mut self, style: StyleSet) {
+        self.style = style;
+    }
+
+    pub fn style(&self) -> &StyleSet {
+        &self.style
+    }
+
+    pub fn style_mut(&mut self) -> &mut StyleSet {
+        &mut self.style
+    }
+
+    fn for_each_style(&mut self, mut apply: impl FnMut(&mut UIStyle)) {
+        for state in [
+            StyleState::Normal,
+            StyleState::Hovered,
+            StyleState::Pressed,
+            StyleState::Focused,
+            StyleState::Disabled,
+        ] {
+            apply(self.style.get_style_mut(state));
+        }
+    }
+
+    pub fn set_font_size(&mut self, font_size: f32) {
+        self.for_each_style(|style| style.set_font_size(font_size));
+    }
+
+    pub fn set_font_path(&mut self, font_path: Option<String>) {
+        self.for_each_style(|style| style.set_font_path(font_path.clone()));
+    }
+
+    pub fn set_font_family(&mut self, font_family: Option<String>) {
+        self.for_each_style(|style| style.set_font_family(font_family.clone()));
+    }
+
+    pub fn set_font_weight(&mut self, font_weight: FontWeight) {
+        self.for_each_style(|style| style.set_font_weight(font_weight));
+    }
+
+    pub fn set_font_style(&mut self, font_style: FontStyle) {
+        self.for_each_style(|style| style.set_font_style(font_style));
+    }
+
+    /// Filled part of the bar, in the same space as `(x, y)`.
+    fn fill_rect(&self, x: f32, y: f32) -> Rect {
+        let Rect { width, height, .. } = self.bounds;
+        match self.fill_direction {
+            FillDirection::LeftToRight => Rect::new(x, y, width * self.value, height),
+            FillDirection::RightToLeft => {
+                let fill = width * self.value;
+                Rect::new(x + width - fill, y, fill, height)
+            }
+            FillDirection::TopToBottom => Rect::new(x, y, width, height * self.value),
+            FillDirection::BottomToTop => {
+                let fill = height * self.value;
+                Rect::new(x, y + height - fill, width, fill)
+            }
+        }
+    }
+
+    fn update_state(&mut self) {
+        self.current_state = if self.enabled && self.enabled_in_hierarchy {
+            StyleState::Normal
+        } else {
+            StyleState::Disabled
+        };
+    }
+}
+
+impl ComponentTrait for ProgressBarComponent {
+    fn new(name: String) -> Self {
+        Self::new(name)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn id(&self) -> u32 {
+        self.component_id
+    }
+
+    fn component_type(&self) -> &'static str {
+        "ProgressBar"
+    }
+
+    fn is_enabled_self(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled_self(&mut self, enabled: bool) {
+        self.set_enabled(enabled);
+    }
+
+    fn is_enabled_in_hierarchy(&self) -> bool {
+        self.enabled_in_hierarchy
+    }
+
+    fn set_enabled_in_hierarchy(&mut self, enabled: bool) {
+        self.enabled_in_hierarchy = enabled;
+        self.update_state();
+    }
+
+    fn update(&self, _time: &Time) {}
+    fn fixed_update(&self, _time: &Time, _fixed_time: f32) {}
+    fn on_start(&self) {}
+    fn on_destroy(&self) {}
+    fn on_enable(&self) {}
+    fn on_disable(&self) {}
+
+    fn clone_component(&self) -> Box<dyn ComponentTrait> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl UIComponentTrait for ProgressBarComponent {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn handle_event(&mut self, _event: &UIEvent) -> bool {
+        // Display only; never consumes input.
+        false
+    }
+
+    fn render(&self, draw_manager: &mut DrawManager, offset: (f32, f32)) {
+        let style = self.style.get_style(self.current_state);
+        let x = self.bounds.x + offset.0;
+        let y = self.bounds.y + offset.1;
+        let to_color = |rgba: [f32; 4]| Color::new(rgba[0], rgba[1], rgba[2], rgba[3]);
+
+        if style.background_color[3] > 0.0 {
+            draw_manager.draw_rectangle_with_options(
+                x,
+                y,
+                self.bounds.width,
+                self.bounds.height,
+                to_color(style.background_color),
+                true,
+                1.0,
+                self.depth,
+            );
+        }
+
+        let fill = self.fill_rect(x, y);
+        if fill.width > 0.0 && fill.height > 0.0 {
+            draw_manager.draw_rectangle_with_options(
+                fill.x,
+                fill.y,
+                fill.width,
+                fill.height,
+                to_color(style.text_color),
+                true,
+                1.0,
+                self.depth + 0.003,
+            );
+        }
+
+        if style.border_width > 0.0 {
+            draw_manager.draw_rectangle_with_options(
+                x,
+                y,
+                self.bounds.width,
+                self.bounds.height,
+                to_color(style.border_color),
+                false,
+                style.border_width,
+                self.depth + 0.005,
+            );
+        }
+
+        if let Some(text) = self.display_text().filter(|text| !text.is_empty()) {
+            draw_manager.draw_text_with_options(
+                text,
+                x,
+                y,
+                style.text_style.clone(),
+                to_color(self.text_color),
+                TextLayoutOptions {
+                    width: Some(self.bounds.width),
+                    height: Some(self.bounds.height),
+                    horizontal_align: TextAlign::Center,
+                    vertical_align: VerticalTextAlign::Center,
+                    wrap: false,
+                },
+                self.depth + 0.01,
+            );
+        }
+    }
+
+    fn ui_depth(&self) -> f32 {
+        self.depth
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled && self.enabled_in_hierarchy
+    }
+
+    fn apply_theme(&mut self, old: &UITheme, new: &UITheme) {
+        self.style.retheme(&old.progress_bar_style, &new.progress_bar_style);
+        retheme_color(&mut self.text_color, old.progress_bar_text_color, new.progress_bar_text_color);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_clamps_and_fills_from_direction() {
+        let mut bar = ProgressBarComponent::new("Bar").with_bounds(10.0, 20.0, 100.0, 40.0);
+        bar.set_value(1.5);
+        assert_eq!(bar.value(), 1.0);
+        bar.set_value(f32::NAN);
+        assert_eq!(bar.value(), 0.0);
+
+        bar.set_value(0.25);
+        assert_eq!(bar.fill_rect(10.0, 20.0), Rect::new(10.0, 20.0, 25.0, 40.0));
+        bar.set_fill_direction(FillDirection::RightToLeft);
+        assert_eq!(bar.fill_rect(10.0, 20.0), Rect::new(85.0, 20.0, 25.0, 40.0));
+        bar.set_fill_direction(FillDirection::BottomToTop);
+        assert_eq!(bar.fill_rect(10.0, 20.0), Rect::new(10.0, 50.0, 100.0, 10.0));
+
+        bar.set_text(Some("{percent}% ({value})".to_string()));
+        assert_eq!(bar.display_text().as_deref(), Some("25% (0.25)"));
+        assert_eq!(FillDirection::parse("Top_To_Bottom"), Some(FillDirection::TopToBottom));
+    }
+}
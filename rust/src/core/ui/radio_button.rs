@@ -0,0 +1,541 @@
+use super::{Rect, StyleState, UIComponentTrait};
+use super::checkbox::LABEL_GAP;
+use super::event::UIEvent;
+use super::style::{StyleSet, UIStyle, UITheme};
+use crate::core::component::{ComponentTrait, next_component_id};
+use crate::core::draw_manager::DrawManager;
+use crate::core::input_manager::MouseButtonType;
+use crate::core::text::{FontStyle, FontWeight, TextAlign, TextLayoutOptions, VerticalTextAlign};
+use crate::core::time::Time;
+use crate::types::color::Color;
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+use winit::keyboard::{Key, NamedKey};
+
+pub(crate) type SelectionChangedCallback = Arc<Mutex<Option<Box<dyn FnMut(&str) + Send + Sync>>>>;
+
+/// Set of radio buttons of which at most one is selected.
+///
+/// Clones share the same group, so give every member a clone of one
+/// `RadioGroup`. Groups are told apart by identity, not by name.
+#[derive(Clone)]
+pub struct RadioGroup {
+    name: String,
+    on_selection_changed: SelectionChangedCallback,
+}
+
+impl std::fmt::Debug for RadioGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RadioGroup").field("name", &self.name).finish()
+    }
+}
+
+impl RadioGroup {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            on_selection_changed: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Whether `other` is (a clone of) this group.
+    pub fn same_group(&self, other: &RadioGroup) -> bool {
+        Arc::ptr_eq(&self.on_selection_changed, &other.on_selection_changed)
+    }
+
+    /// Called with the selected button's value whenever the user picks a
+    /// different member of the group.
+    pub fn set_on_selection_changed<F>(&mut self, callback: F)
+    where
+        F: FnMut(&str) + Send + Sync + 'static,
+    {
+        *self.on_selection_changed.lock().unwrap() = Some(Box::new(callback));
+    }
+
+    pub(crate) fn notify_selection_changed(&self, value: &str) {
+        if let Ok(mut guard) = self.on_selection_changed.lock()
+            && let Some(callback) = guard.as_mut()
+        {
+            callback(value);
+        }
+    }
+}
+
+/// Radio button with an optional text label to its right.
+///
+/// Clicking the button or its label selects it; Space or Enter selects it
+/// while focused. Selecting never deselects by itself: the UI manager clears
+/// the other members of the button's [`RadioGroup`] and then fires the
+/// group's `on_selection_changed` callback with this button's value.
+///
+/// Styling matches [`CheckboxComponent`](super::checkbox::CheckboxComponent):
+/// `background_color` and the border draw the circle, and `text_color` draws
+/// the selection dot and label.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyg_engine_native::core::ui::radio_button::{RadioButtonComponent, RadioGroup};
+///
+/// let mut difficulty = RadioGroup::new("Difficulty");
+/// difficulty.set_on_selection_changed(|value| println!("Difficulty: {value}"));
+///
+/// let easy = RadioButtonComponent::new("Easy")
+///     .with_label("Easy")
+///     .with_value("easy")
+///     .with_group(difficulty.clone())
+///     .with_selected(true);
+/// let hard = RadioButtonComponent::new("Hard")
+///     .with_label("Hard")
+///     .with_value("hard")
+///     .with_group(difficulty);
+/// ```
+#[derive(Clone)]
+pub struct RadioButtonComponent {
+    component_id: u32,
+    name: String,
+    bounds: Rect,
+    style: StyleSet,
+    current_state: StyleState,
+    label: String,
+    value: String,
+    selected: bool,
+    group: Option<RadioGroup>,
+    /// Set when the user selected this button and the group hasn't been updated yet
+    selection_pending: bool,
+    is_hovered: bool,
+    is_focused: bool,
+    is_pressed: bool,
+    enabled: bool,
+    enabled_in_hierarchy: bool,
+    depth: f32,
+}
+
+impl std::fmt::Debug for RadioButtonComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RadioButtonComponent")
+            .field("name", &self.name)
+            .field("bounds", &self.bounds)
+            .field("label", &self.label)
+            .field("value", &self.value)
+            .field("selected", &self.selected)
+            .field("group", &self.group)
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+impl RadioButtonComponent {
+    /// Create a new unselected radio button with no group and the default style.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            component_id: next_component_id(),
+            name: name.into(),
+            bounds: Rect::new(0.0, 0.0, 160.0, 24.0),
+            style: UITheme::light().radio_button_style.clone(),
+            current_state: StyleState::Normal,
+            label: String::new(),
+            value: String::new(),
+            selected: false,
+            group: None,
+            selection_pending: false,
+            is_hovered: false,
+            is_focused: false,
+            is_pressed: false,
+            enabled: true,
+            enabled_in_hierarchy: true,
+            depth: 0.0,
+        }
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Value reported to the group's callback when this button is selected.
+    pub fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    pub fn with_group(mut self, group: RadioGroup) -> Self {
+        self.group = Some(group);
+        self
+    }
+
+    pub fn with_selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    pub fn with_bounds(mut self, x: f32, y: f32, width: f32, height: f32) -> Self {
+        self.bounds = Rect::new(x, y, width, height);
+        self
+    }
+
+    pub fn with_style(mut self, style: StyleSet) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_depth(mut self, depth: f32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    pub fn set_label(&mut self, label: impl Into<String>) {
+        self.label = label.into();
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        self.value = value.into();
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn set_group(&mut self, group: Option<RadioGroup>) {
+        self.group = group;
+    }
+
+    pub fn group(&self) -> Option<&RadioGroup> {
+        self.group.as_ref()
+    }
+
+    /// Set the selected state without touching the rest of the group or
+    /// firing its callback.
+    pub fn set_selected(&mut self, selected: bool) {
+        self.selected = selected;
+        if !selected {
+            self.selection_pending = false;
+        }
+    }
+
+    pub fn is_selected(&self) -> bool {
+        self.selected
+    }
+
+    /// Select the button as a click would. Returns false if it was already selected.
+    pub fn select(&mut self) -> bool {
+        if self.selected {
+            return false;
+        }
+        self.selected = true;
+        self.selection_pending = true;
+        true
+    }
+
+    /// Whether the user selected this button since the last call.
+    pub(crate) fn take_selection_pending(&mut self) -> bool {
+        std::mem::take(&mut self.selection_pending)
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.is_pressed = false;
+        }
+        self.update_state();
+    }
+
+    pub fn set_style(&mut self, style: StyleSet) {
+        self.style = style;
+    }
+
+    fn for_each_style(&mut self, mut apply: impl FnMut(&mut UIStyle)) {
+        for state in [
+            StyleState::Normal,
+            StyleState::Hovered,
+            StyleState::Pressed,
+            StyleState::Focused,
+            StyleState::Disabled,
+        ] {
+            apply(self.style.get_style_mut(state));
+        }
+    }
+
+    pub fn set_font_size(&mut self, font_size: f32) {
+        self.for_each_style(|style| style.set_font_size(font_size));
+    }
+
+    pub fn set_font_path(&mut self, font_path: Option<String>) {
+        self.for_each_style(|style| style.set_font_path(font_path.clone()));
+    }
+
+    pub fn set_font_family(&mut self, font_family: Option<String>) {
+        self.for_each_style(|style| style.set_font_family(font_family.clone()));
+    }
+
+    pub fn set_font_weight(&mut self, font_weight: FontWeight) {
+        self.for_each_style(|style| style.set_font_weight(font_weight));
+    }
+
+    pub fn set_font_style(&mut self, font_style: FontStyle) {
+        self.for_each_style(|style| style.set_font_style(font_style));
+    }
+
+    fn update_state(&mut self) {
+        self.current_state = if !(self.enabled && self.enabled_in_hierarchy) {
+            StyleState::Disabled
+        } else if self.is_pressed {
+            StyleState::Pressed
+        } else if self.is_hovered {
+            StyleState::Hovered
+        } else if self.is_focused {
+            StyleState::Focused
+        } else {
+            StyleState::Normal
+        };
+    }
+}
+
+impl ComponentTrait for RadioButtonComponent {
+    fn new(name: String) -> Self {
+        Self::new(name)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn id(&self) -> u32 {
+        self.component_id
+    }
+
+    fn component_type(&self) -> &'static str {
+        "RadioButton"
+    }
+
+    fn is_enabled_self(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled_self(&mut self, enabled: bool) {
+        self.set_enabled(enabled);
+    }
+
+    fn is_enabled_in_hierarchy(&self) -> bool {
+        self.enabled_in_hierarchy
+    }
+
+    fn set_enabled_in_hierarchy(&mut self, enabled: bool) {
+        self.enabled_in_hierarchy = enabled;
+        self.update_state();
+    }
+
+    fn update(&self, _time: &Time) {}
+    fn fixed_update(&self, _time: &Time, _fixed_time: f32) {}
+    fn on_start(&self) {}
+    fn on_destroy(&self) {}
+    fn on_enable(&self) {}
+    fn on_disable(&self) {}
+
+    fn clone_component(&self) -> Box<dyn ComponentTrait> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl UIComponentTrait for RadioButtonComponent {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn handle_event(&mut self, event: &UIEvent) -> bool {
+        if !(self.enabled && self.enabled_in_hierarchy) {
+            return false;
+        }
+
+        let handled = match event {
+            UIEvent::MouseEnter { .. } => {
+                self.is_hovered = true;
+                true
+            }
+            UIEvent::MouseExit { .. } => {
+                self.is_hovered = false;
+                self.is_pressed = false;
+                true
+            }
+            UIEvent::MouseDown {
+                button: MouseButtonType::Left,
+                ..
+            } => {
+                self.is_pressed = true;
+                true
+            }
+            UIEvent::MouseUp { .. } => {
+                self.is_pressed = false;
+                true
+            }
+            UIEvent::Click {
+                button: MouseButtonType::Left,
+                ..
+            } => {
+                self.select();
+                true
+            }
+            UIEvent::KeyDown {
+                key: Key::Named(NamedKey::Space | NamedKey::Enter),
+                ..
+            } => {
+                self.select();
+                true
+            }
+            UIEvent::FocusGained => {
+                self.is_focused = true;
+                true
+            }
+            UIEvent::FocusLost => {
+                self.is_focused = false;
+                true
+            }
+            _ => false,
+        };
+
+        self.update_state();
+        handled
+    }
+
+    fn render(&self, draw_manager: &mut DrawManager, offset: (f32, f32)) {
+        let style = self.style.get_style(self.current_state);
+        let x = self.bounds.x + offset.0;
+        let y = self.bounds.y + offset.1;
+        let size = self.bounds.height.min(self.bounds.width).min(24.0);
+        let radius = size * 0.5;
+        let center_x = x + radius;
+        let center_y = y + self.bounds.height * 0.5;
+        let to_color = |rgba: [f32; 4]| Color::new(rgba[0], rgba[1], rgba[2], rgba[3]);
+
+        if style.background_color[3] > 0.0 {
+            draw_manager.draw_circle_with_options(
+                center_x,
+                center_y,
+                radius,
+                to_color(style.background_color),
+                true,
+                1.0,
+                32,
+                self.depth,
+            );
+        }
+        if style.border_width > 0.0 {
+            draw_manager.draw_circle_with_options(
+                center_x,
+                center_y,
+                radius,
+                to_color(style.border_color),
+                false,
+                style.border_width,
+                32,
+                self.depth + 0.005,
+            );
+        }
+
+        if self.selected {
+            draw_manager.draw_circle_with_options(
+                center_x,
+                center_y,
+                radius * 0.5,
+                to_color(style.text_color),
+                true,
+                1.0,
+                24,
+                self.depth + 0.01,
+            );
+        }
+
+        if !self.label.is_empty() {
+            let label_x = x + size + LABEL_GAP;
+            draw_manager.draw_text_with_options(
+                self.label.clone(),
+                label_x,
+                y,
+                style.text_style.clone(),
+                to_color(style.text_color),
+                TextLayoutOptions {
+                    width: Some((self.bounds.width - size - LABEL_GAP).max(0.0)),
+                    height: Some(self.bounds.height),
+                    horizontal_align: TextAlign::Left,
+                    vertical_align: VerticalTextAlign::Center,
+                    wrap: false,
+                },
+                self.depth + 0.01,
+            );
+        }
+    }
+
+    fn ui_depth(&self) -> f32 {
+        self.depth
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled && self.enabled_in_hierarchy
+    }
+
+    fn apply_theme(&mut self, old: &UITheme, new: &UITheme) {
+        self.style.retheme(&old.radio_button_style, &new.radio_button_style);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_click_selects_once() {
+        let mut radio = RadioButtonComponent::new("RadioButton").with_value("easy");
+
+        radio.handle_event(&UIEvent::Click { x: 4.0, y: 4.0, button: MouseButtonType::Right });
+        assert!(!radio.is_selected());
+
+        radio.handle_event(&UIEvent::Click { x: 4.0, y: 4.0, button: MouseButtonType::Left });
+        assert!(radio.is_selected());
+        assert!(radio.take_selection_pending());
+
+        // Clicking a selected radio button leaves it selected and reports nothing.
+        radio.handle_event(&UIEvent::KeyDown {
+            key: Key::Named(NamedKey::Space),
+            shift: false,
+            ctrl: false,
+        });
+        assert!(radio.is_selected());
+        assert!(!radio.take_selection_pending());
+    }
+}
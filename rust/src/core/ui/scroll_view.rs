@@ -0,0 +1,532 @@
+use super::{Rect, UIComponentTrait};
+use super::event::UIEvent;
+use super::style::{UIStyle, UITheme};
+use crate::core::component::{ComponentTrait, next_component_id};
+use crate::core::draw_manager::DrawManager;
+use crate::core::input_manager::MouseButtonType;
+use crate::core::time::Time;
+use crate::types::color::Color;
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+use winit::keyboard::{Key, NamedKey};
+
+type ScrolledCallback = Arc<Mutex<Option<Box<dyn FnMut(f32, f32) + Send + Sync>>>>;
+
+/// Thickness in pixels of the scrollbar thumbs.
+const SCROLLBAR_WIDTH: f32 = 6.0;
+/// Shortest a scrollbar thumb gets, so it stays grabbable by eye.
+const MIN_THUMB_LENGTH: f32 = 16.0;
+
+/// Scrollable container that clips its children to its bounds.
+///
+/// Children are positioned relative to the scroll view's content origin and
+/// move by the scroll offset. Scroll with the mouse wheel over the view or any
+/// of its children, by dragging the view's background, or with the arrow,
+/// Page Up/Down, Home and End keys once the view has been clicked.
+///
+/// The content size is measured from the children each frame unless set
+/// explicitly with [`Self::set_content_size`].
+///
+/// # Examples
+///
+/// ```rust
+/// use pyg_engine_native::core::ui::scroll_view::ScrollViewComponent;
+///
+/// let mut list = ScrollViewComponent::new("Inventory")
+///     .with_bounds(20.0, 20.0, 240.0, 300.0)
+///     .with_content_size(240.0, 1200.0);
+///
+/// list.set_on_scrolled(|x, y| println!("Scrolled to {x}, {y}"));
+/// list.set_scroll(0.0, 400.0);
+/// ```
+#[derive(Clone)]
+pub struct ScrollViewComponent {
+    component_id: u32,
+    name: String,
+    bounds: Rect,
+    style: UIStyle,
+    scroll_x: f32,
+    scroll_y: f32,
+    content_width: f32,
+    content_height: f32,
+    /// Whether the content size follows the children
+    auto_content_size: bool,
+    horizontal: bool,
+    vertical: bool,
+    /// Pixels scrolled per mouse wheel line
+    scroll_speed: f32,
+    show_scrollbars: bool,
+    on_scrolled: ScrolledCallback,
+    is_dragging: bool,
+    enabled: bool,
+    enabled_in_hierarchy: bool,
+    depth: f32,
+}
+
+impl std::fmt::Debug for ScrollViewComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScrollViewComponent")
+            .field("name", &self.name)
+            .field("bounds", &self.bounds)
+            .field("scroll", &(self.scroll_x, self.scroll_y))
+            .field("content_size", &(self.content_width, self.content_height))
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+impl ScrollViewComponent {
+    /// Create a vertical scroll view with the default style.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            component_id: next_component_id(),
+            name: name.into(),
+            bounds: Rect::new(0.0, 0.0, 200.0, 200.0),
+            style: UITheme::light().scroll_view_style.clone(),
+            scroll_x: 0.0,
+            scroll_y: 0.0,
+            content_width: 0.0,
+            content_height: 0.0,
+            auto_content_size: true,
+            horizontal: false,
+            vertical: true,
+            scroll_speed: 40.0,
+            show_scrollbars: true,
+            on_scrolled: Arc::new(Mutex::new(None)),
+            is_dragging: false,
+            enabled: true,
+            enabled_in_hierarchy: true,
+            depth: 0.0,
+        }
+    }
+
+    pub fn with_bounds(mut self, x: f32, y: f32, width: f32, height: f32) -> Self {
+        self.bounds = Rect::new(x, y, width, height);
+        self
+    }
+
+    pub fn with_style(mut self, style: UIStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_depth(mut self, depth: f32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    pub fn with_content_size(mut self, width: f32, height: f32) -> Self {
+        self.set_content_size(width, height);
+        self
+    }
+
+    /// Choose which directions the view scrolls in (vertical only by default).
+    pub fn with_directions(mut self, horizontal: bool, vertical: bool) -> Self {
+        self.set_directions(horizontal, vertical);
+        self
+    }
+
+    pub fn set_style(&mut self, style: UIStyle) {
+        self.style = style;
+    }
+
+    pub fn style(&self) -> &UIStyle {
+        &self.style
+    }
+
+    pub fn style_mut(&mut self) -> &mut UIStyle {
+        &mut self.style
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.is_dragging = false;
+        }
+    }
+
+    pub fn set_directions(&mut self, horizontal: bool, vertical: bool) {
+        self.horizontal = horizontal;
+        self.vertical = vertical;
+        self.clamp_scroll();
+    }
+
+    pub fn directions(&self) -> (bool, bool) {
+        (self.horizontal, self.vertical)
+    }
+
+    /// Fix the content size instead of measuring it from the children.
+    pub fn set_content_size(&mut self, width: f32, height: f32) {
+        self.content_width = width.max(0.0);
+        self.content_height = height.max(0.0);
+        self.auto_content_size = false;
+        self.clamp_scroll();
+    }
+
+    /// Go back to measuring the content size from the children.
+    pub fn set_auto_content_size(&mut self) {
+        self.auto_content_size = true;
+    }
+
+    pub fn is_auto_content_size(&self) -> bool {
+        self.auto_content_size
+    }
+
+    /// Apply the size measured from the children; ignored when the size is fixed.
+    pub(crate) fn fit_content(&mut self, width: f32, height: f32) {
+        if self.auto_content_size {
+            self.content_width = width.max(0.0);
+            self.content_height = height.max(0.0);
+            self.clamp_scroll();
+        }
+    }
+
+    pub fn content_size(&self) -> (f32, f32) {
+        (self.content_width, self.content_height)
+    }
+
+    /// Pixels scrolled per mouse wheel line.
+    pub fn set_scroll_speed(&mut self, speed: f32) {
+        self.scroll_speed = speed.max(0.0);
+    }
+
+    pub fn scroll_speed(&self) -> f32 {
+        self.scroll_speed
+    }
+
+    pub fn set_show_scrollbars(&mut self, show: bool) {
+        self.show_scrollbars = show;
+    }
+
+    pub fn show_scrollbars(&self) -> bool {
+        self.show_scrollbars
+    }
+
+    /// Current scroll offset `(x, y)` in pixels from the content's top-left.
+    pub fn scroll(&self) -> (f32, f32) {
+        (self.scroll_x, self.scroll_y)
+    }
+
+    /// Largest scroll offset in each direction; 0 when the content fits.
+    pub fn max_scroll(&self) -> (f32, f32) {
+        let max_x = if self.horizontal {
+            (self.content_width - self.bounds.width).max(0.0)
+        } else {
+            0.0
+        };
+        let max_y = if self.vertical {
+            (self.content_height - self.bounds.height).max(0.0)
+        } else {
+            0.0
+        };
+        (max_x, max_y)
+    }
+
+    /// Set the scroll offset, clamped to the content, without firing `on_scrolled`.
+    pub fn set_scroll(&mut self, x: f32, y: f32) {
+        self.scroll_x = x;
+        self.scroll_y = y;
+        self.clamp_scroll();
+    }
+
+    /// Scroll by a delta and fire `on_scrolled` if the offset changed.
+    ///
+    /// Returns whether the offset changed.
+    pub fn scroll_by(&mut self, dx: f32, dy: f32) -> bool {
+        let before = self.scroll();
+        self.scroll_x += dx;
+        self.scroll_y += dy;
+        self.clamp_scroll();
+
+        let (x, y) = self.scroll();
+        if (x, y) == before {
+            return false;
+        }
+        if let Ok(mut guard) = self.on_scrolled.lock()
+            && let Some(callback) = guard.as_mut()
+        {
+            callback(x, y);
+        }
+        true
+    }
+
+    /// Called with the new `(x, y)` offset whenever the user scrolls.
+    pub fn set_on_scrolled<F>(&mut self, callback: F)
+    where
+        F: FnMut(f32, f32) + Send + Sync + 'static,
+    {
+        *self.on_scrolled.lock().unwrap() = Some(Box::new(callback));
+    }
+
+    fn clamp_scroll(&mut self) {
+        let (max_x, max_y) = self.max_scroll();
+        self.scroll_x = self.scroll_x.clamp(0.0, max_x);
+        self.scroll_y = self.scroll_y.clamp(0.0, max_y);
+    }
+
+    fn handle_key(&mut self, key: &Key) -> bool {
+        let line = self.scroll_speed;
+        let page = self.bounds.height.max(line);
+        let (dx, dy) = match key {
+            Key::Named(NamedKey::ArrowUp) => (0.0, -line),
+            Key::Named(NamedKey::ArrowDown) => (0.0, line),
+            Key::Named(NamedKey::ArrowLeft) => (-line, 0.0),
+            Key::Named(NamedKey::ArrowRight) => (line, 0.0),
+            Key::Named(NamedKey::PageUp) => (0.0, -page),
+            Key::Named(NamedKey::PageDown) => (0.0, page),
+            Key::Named(NamedKey::Home) => (-self.scroll_x, -self.scroll_y),
+            Key::Named(NamedKey::End) => {
+                let (max_x, max_y) = self.max_scroll();
+                (max_x - self.scroll_x, max_y - self.scroll_y)
+            }
+            _ => return false,
+        };
+        self.scroll_by(dx, dy);
+        true
+    }
+
+    /// Thumb rect `(x, y, width, height)` along one edge, or `None` when the content fits.
+    fn scrollbar_thumb(&self, x: f32, y: f32, vertical: bool) -> Option<(f32, f32, f32, f32)> {
+        let (max_x, max_y) = self.max_scroll();
+        let (viewport, content, offset, max) = if vertical {
+            (self.bounds.height, self.content_height, self.scroll_y, max_y)
+        } else {
+            (self.bounds.width, self.content_width, self.scroll_x, max_x)
+        };
+        if max <= 0.0 {
+            return None;
+        }
+
+        let length = (viewport * viewport / content).clamp(MIN_THUMB_LENGTH.min(viewport), viewport);
+        let position = (viewport - length) * (offset / max);
+        Some(if vertical {
+            (x + self.bounds.width - SCROLLBAR_WIDTH - 2.0, y + position, SCROLLBAR_WIDTH, length)
+        } else {
+            (x + position, y + self.bounds.height - SCROLLBAR_WIDTH - 2.0, length, SCROLLBAR_WIDTH)
+        })
+    }
+}
+
+impl ComponentTrait for ScrollViewComponent {
+    fn new(name: String) -> Self {
+        Self::new(name)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn id(&self) -> u32 {
+        self.component_id
+    }
+
+    fn component_type(&self) -> &'static str {
+        "ScrollView"
+    }
+
+    fn is_enabled_self(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled_self(&mut self, enabled: bool) {
+        self.set_enabled(enabled);
+    }
+
+    fn is_enabled_in_hierarchy(&self) -> bool {
+        self.enabled_in_hierarchy
+    }
+
+    fn set_enabled_in_hierarchy(&mut self, enabled: bool) {
+        self.enabled_in_hierarchy = enabled;
+    }
+
+    fn update(&self, _time: &Time) {}
+    fn fixed_update(&self, _time: &Time, _fixed_time: f32) {}
+    fn on_start(&self) {}
+    fn on_destroy(&self) {}
+    fn on_enable(&self) {}
+    fn on_disable(&self) {}
+
+    fn clone_component(&self) -> Box<dyn ComponentTrait> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl UIComponentTrait for ScrollViewComponent {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+        self.clamp_scroll();
+    }
+
+    fn handle_event(&mut self, event: &UIEvent) -> bool {
+        if !(self.enabled && self.enabled_in_hierarchy) {
+            return false;
+        }
+
+        match event {
+            UIEvent::MouseWheel { delta_x, delta_y, .. } => {
+                let (mut dx, mut dy) = (*delta_x as f32, *delta_y as f32);
+                // A plain wheel scrolls sideways in horizontal-only views.
+                if self.horizontal && !self.vertical && dx == 0.0 {
+                    dx = dy;
+                    dy = 0.0;
+                }
+                // Wheel up (positive) moves the content down, revealing what is above.
+                self.scroll_by(-dx * self.scroll_speed, -dy * self.scroll_speed)
+            }
+            UIEvent::MouseDown {
+                button: MouseButtonType::Left,
+                ..
+            } => {
+                self.is_dragging = true;
+                true
+            }
+            UIEvent::MouseUp { .. } | UIEvent::FocusLost => {
+                self.is_dragging = false;
+                true
+            }
+            UIEvent::MouseMove { dx, dy, .. } if self.is_dragging => {
+                // Dragging pulls the content along with the pointer.
+                self.scroll_by(-*dx as f32, -*dy as f32);
+                true
+            }
+            UIEvent::KeyDown { key, .. } => self.handle_key(key),
+            _ => true,
+        }
+    }
+
+    fn render(&self, draw_manager: &mut DrawManager, offset: (f32, f32)) {
+        let x = self.bounds.x + offset.0;
+        let y = self.bounds.y + offset.1;
+        let to_color = |rgba: [f32; 4]| Color::new(rgba[0], rgba[1], rgba[2], rgba[3]);
+
+        if self.style.background_color[3] > 0.0 {
+            draw_manager.draw_rectangle_with_options(
+                x,
+                y,
+                self.bounds.width,
+                self.bounds.height,
+                to_color(self.style.background_color),
+                true,
+                1.0,
+                self.depth,
+            );
+        }
+
+        if self.style.border_width > 0.0 {
+            draw_manager.draw_rectangle_with_options(
+                x,
+                y,
+                self.bounds.width,
+                self.bounds.height,
+                to_color(self.style.border_color),
+                false,
+                self.style.border_width,
+                self.depth + 0.005,
+            );
+        }
+
+        // Thumbs sit above the children, which are rendered by the UIManager.
+        if self.show_scrollbars {
+            for vertical in [true, false] {
+                if let Some((thumb_x, thumb_y, width, height)) = self.scrollbar_thumb(x, y, vertical) {
+                    draw_manager.draw_rectangle_with_options(
+                        thumb_x,
+                        thumb_y,
+                        width,
+                        height,
+                        to_color(self.style.text_color),
+                        true,
+                        1.0,
+                        self.depth + 0.5,
+                    );
+                }
+            }
+        }
+    }
+
+    fn ui_depth(&self) -> f32 {
+        self.depth
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled && self.enabled_in_hierarchy
+    }
+
+    fn apply_theme(&mut self, old: &UITheme, new: &UITheme) {
+        self.style.retheme(&old.scroll_view_style, &new.scroll_view_style);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wheel(delta_y: f64) -> UIEvent {
+        UIEvent::MouseWheel { x: 10.0, y: 10.0, delta_x: 0.0, delta_y }
+    }
+
+    #[test]
+    fn test_scroll_clamps_to_content() {
+        let mut view = ScrollViewComponent::new("ScrollView")
+            .with_bounds(0.0, 0.0, 100.0, 100.0)
+            .with_content_size(100.0, 250.0);
+        let scrolled = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&scrolled);
+        view.set_on_scrolled(move |x, y| log.lock().unwrap().push((x, y)));
+
+        assert_eq!(view.max_scroll(), (0.0, 150.0));
+        assert!(view.handle_event(&wheel(-2.0)));
+        assert_eq!(view.scroll(), (0.0, 80.0));
+        view.handle_event(&wheel(-5.0));
+        assert_eq!(view.scroll(), (0.0, 150.0));
+        // Already at the end: nothing changes, so the wheel is not handled.
+        assert!(!view.handle_event(&wheel(-1.0)));
+
+        view.set_scroll(0.0, -20.0);
+        assert_eq!(view.scroll(), (0.0, 0.0));
+        assert_eq!(*scrolled.lock().unwrap(), vec![(0.0, 80.0), (0.0, 150.0)]);
+    }
+
+    #[test]
+    fn test_drag_and_auto_content_size() {
+        let mut view = ScrollViewComponent::new("ScrollView").with_bounds(0.0, 0.0, 100.0, 100.0);
+        view.fit_content(100.0, 300.0);
+        assert_eq!(view.content_size(), (100.0, 300.0));
+
+        view.handle_event(&UIEvent::MouseDown { x: 50.0, y: 50.0, button: MouseButtonType::Left });
+        view.handle_event(&UIEvent::MouseMove { x: 50.0, y: 20.0, dx: 0.0, dy: -30.0 });
+        view.handle_event(&UIEvent::MouseUp { x: 50.0, y: 20.0, button: MouseButtonType::Left });
+        view.handle_event(&UIEvent::MouseMove { x: 50.0, y: 0.0, dx: 0.0, dy: -20.0 });
+        assert_eq!(view.scroll(), (0.0, 30.0));
+
+        view.set_content_size(100.0, 120.0);
+        view.fit_content(100.0, 500.0);
+        assert_eq!(view.content_size(), (100.0, 120.0));
+        assert_eq!(view.scroll(), (0.0, 20.0));
+    }
+}
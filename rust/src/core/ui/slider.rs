@@ -0,0 +1,618 @@
+use super::{Rect, StyleState, UIComponentTrait};
+use super::event::UIEvent;
+use super::style::{StyleSet, UITheme};
+use crate::core::component::{ComponentTrait, next_component_id};
+use crate::core::draw_manager::DrawManager;
+use crate::core::input_manager::MouseButtonType;
+use crate::core::time::Time;
+use crate::types::color::Color;
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+use winit::keyboard::{Key, NamedKey};
+
+type ValueCallback = Arc<Mutex<Option<Box<dyn FnMut(f32) + Send + Sync>>>>;
+
+/// Direction a slider's value increases along.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SliderOrientation {
+    /// Minimum on the left, maximum on the right.
+    Horizontal,
+    /// Minimum at the bottom, maximum at the top.
+    Vertical,
+}
+
+impl SliderOrientation {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Horizontal => "horizontal",
+            Self::Vertical => "vertical",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "horizontal" | "h" => Some(Self::Horizontal),
+            "vertical" | "v" => Some(Self::Vertical),
+            _ => None,
+        }
+    }
+}
+
+/// Draggable slider for picking a value in a range.
+///
+/// Click or drag anywhere on the slider to set the value. Once focused, the
+/// arrow keys move by one step (or 1% of the range when `step` is 0) and
+/// Home/End jump to the ends.
+///
+/// Styling uses the regular [`StyleSet`] fields: `background_color` and the
+/// border draw the track, and `text_color` draws the filled part and handle.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyg_engine_native::core::ui::slider::SliderComponent;
+///
+/// let mut volume = SliderComponent::new("Volume")
+///     .with_range(0.0, 100.0)
+///     .with_step(5.0)
+///     .with_value(80.0)
+///     .with_bounds(100.0, 100.0, 240.0, 24.0);
+///
+/// volume.set_on_value_changed(|value| println!("Volume: {value}"));
+/// ```
+#[derive(Clone)]
+pub struct SliderComponent {
+    component_id: u32,
+    name: String,
+    bounds: Rect,
+    style: StyleSet,
+    current_state: StyleState,
+    min: f32,
+    max: f32,
+    /// Snap increment; 0.0 means continuous.
+    step: f32,
+    value: f32,
+    orientation: SliderOrientation,
+    on_value_changed: ValueCallback,
+    is_hovered: bool,
+    is_focused: bool,
+    is_dragging: bool,
+    enabled: bool,
+    enabled_in_hierarchy: bool,
+    depth: f32,
+}
+
+impl std::fmt::Debug for SliderComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SliderComponent")
+            .field("name", &self.name)
+            .field("bounds", &self.bounds)
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .field("step", &self.step)
+            .field("value", &self.value)
+            .field("orientation", &self.orientation)
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+impl SliderComponent {
+    /// Create a new horizontal slider over `0.0..=1.0` with the default style.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            component_id: next_component_id(),
+            name: name.into(),
+            bounds: Rect::new(0.0, 0.0, 200.0, 24.0),
+            style: UITheme::light().slider_style.clone(),
+            current_state: StyleState::Normal,
+            min: 0.0,
+            max: 1.0,
+            step: 0.0,
+            value: 0.0,
+            orientation: SliderOrientation::Horizontal,
+            on_value_changed: Arc::new(Mutex::new(None)),
+            is_hovered: false,
+            is_focused: false,
+            is_dragging: false,
+            enabled: true,
+            enabled_in_hierarchy: true,
+            depth: 0.0,
+        }
+    }
+
+    pub fn with_range(mut self, min: f32, max: f32) -> Self {
+        self.set_range(min, max);
+        self
+    }
+
+    pub fn with_step(mut self, step: f32) -> Self {
+        self.set_step(step);
+        self
+    }
+
+    pub fn with_value(mut self, value: f32) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    pub fn with_orientation(mut self, orientation: SliderOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn with_bounds(mut self, x: f32, y: f32, width: f32, height: f32) -> Self {
+        self.bounds = Rect::new(x, y, width, height);
+        self
+    }
+
+    pub fn with_style(mut self, style: StyleSet) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_depth(mut self, depth: f32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Set the value range. Swaps the bounds if `min > max` and re-clamps the value.
+    pub fn set_range(&mut self, min: f32, max: f32) {
+        self.min = min.min(max);
+        self.max = min.max(max);
+        self.value = self.snap(self.value);
+    }
+
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Set the snap increment. Zero or negative values make the slider continuous.
+    pub fn set_step(&mut self, step: f32) {
+        self.step = step.max(0.0);
+        self.value = self.snap(self.value);
+    }
+
+    pub fn step(&self) -> f32 {
+        self.step
+    }
+
+    /// Set the value without firing `on_value_changed`. The value is clamped and snapped.
+    pub fn set_value(&mut self, value: f32) {
+        self.value = self.snap(value);
+    }
+
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Value as a fraction of the range, from 0.0 to 1.0.
+    pub fn normalized_value(&self) -> f32 {
+        let span = self.max - self.min;
+        if span <= f32::EPSILON {
+            0.0
+        } else {
+            (self.value - self.min) / span
+        }
+    }
+
+    pub fn set_orientation(&mut self, orientation: SliderOrientation) {
+        self.orientation = orientation;
+    }
+
+    pub fn orientation(&self) -> SliderOrientation {
+        self.orientation
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.is_dragging
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.is_dragging = false;
+        }
+        self.update_state();
+    }
+
+    pub fn set_style(&mut self, style: StyleSet) {
+        self.style = style;
+    }
+
+    /// Called with the new value whenever the user changes it.
+    pub fn set_on_value_changed<F>(&mut self, callback: F)
+    where
+        F: FnMut(f32) + Send + Sync + 'static,
+    {
+        *self.on_value_changed.lock().unwrap() = Some(Box::new(callback));
+    }
+
+    fn snap(&self, value: f32) -> f32 {
+        if !value.is_finite() {
+            return self.min;
+        }
+        let mut value = value.clamp(self.min, self.max);
+        if self.step > 0.0 {
+            value = self.min + ((value - self.min) / self.step).round() * self.step;
+            value = value.clamp(self.min, self.max);
+        }
+        value
+    }
+
+    /// Set the value from user input, firing the callback if it changed.
+    fn change_value(&mut self, value: f32) -> bool {
+        let value = self.snap(value);
+        if value == self.value {
+            return false;
+        }
+        self.value = value;
+        if let Ok(mut guard) = self.on_value_changed.lock()
+            && let Some(callback) = guard.as_mut()
+        {
+            callback(value);
+        }
+        true
+    }
+
+    /// Length of the handle along the slider axis.
+    fn handle_length(&self) -> f32 {
+        let (length, thickness) = self.axis_extent();
+        (thickness * 0.5).clamp(4.0, length.max(4.0))
+    }
+
+    /// `(length along the axis, thickness across it)`.
+    fn axis_extent(&self) -> (f32, f32) {
+        match self.orientation {
+            SliderOrientation::Horizontal => (self.bounds.width, self.bounds.height),
+            SliderOrientation::Vertical => (self.bounds.height, self.bounds.width),
+        }
+    }
+
+    /// Map a screen point to a value in the range.
+    fn value_at(&self, x: f64, y: f64) -> f32 {
+        let (length, _) = self.axis_extent();
+        let handle = self.handle_length();
+        let travel = (length - handle).max(1.0);
+        let fraction = match self.orientation {
+            SliderOrientation::Horizontal => (x as f32 - self.bounds.x - handle * 0.5) / travel,
+            SliderOrientation::Vertical => {
+                1.0 - (y as f32 - self.bounds.y - handle * 0.5) / travel
+            }
+        };
+        self.min + fraction.clamp(0.0, 1.0) * (self.max - self.min)
+    }
+
+    /// Amount one arrow key press moves the value.
+    fn keyboard_increment(&self) -> f32 {
+        if self.step > 0.0 {
+            self.step
+        } else {
+            (self.max - self.min) * 0.01
+        }
+    }
+
+    fn update_state(&mut self) {
+        self.current_state = if !(self.enabled && self.enabled_in_hierarchy) {
+            StyleState::Disabled
+        } else if self.is_dragging {
+            StyleState::Pressed
+        } else if self.is_hovered {
+            StyleState::Hovered
+        } else if self.is_focused {
+            StyleState::Focused
+        } else {
+            StyleState::Normal
+        };
+    }
+}
+
+impl ComponentTrait for SliderComponent {
+    fn new(name: String) -> Self {
+        Self::new(name)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn id(&self) -> u32 {
+        self.component_id
+    }
+
+    fn component_type(&self) -> &'static str {
+        "Slider"
+    }
+
+    fn is_enabled_self(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled_self(&mut self, enabled: bool) {
+        self.set_enabled(enabled);
+    }
+
+    fn is_enabled_in_hierarchy(&self) -> bool {
+        self.enabled_in_hierarchy
+    }
+
+    fn set_enabled_in_hierarchy(&mut self, enabled: bool) {
+        self.enabled_in_hierarchy = enabled;
+        if !enabled {
+            self.is_dragging = false;
+        }
+        self.update_state();
+    }
+
+    fn update(&self, _time: &Time) {}
+    fn fixed_update(&self, _time: &Time, _fixed_time: f32) {}
+    fn on_start(&self) {}
+    fn on_destroy(&self) {}
+    fn on_enable(&self) {}
+    fn on_disable(&self) {}
+
+    fn clone_component(&self) -> Box<dyn ComponentTrait> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl UIComponentTrait for SliderComponent {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn handle_event(&mut self, event: &UIEvent) -> bool {
+        if !(self.enabled && self.enabled_in_hierarchy) {
+            return false;
+        }
+
+        let handled = match event {
+            UIEvent::MouseEnter { .. } => {
+                self.is_hovered = true;
+                true
+            }
+            UIEvent::MouseExit { .. } => {
+                self.is_hovered = false;
+                true
+            }
+            UIEvent::MouseDown {
+                x,
+                y,
+                button: MouseButtonType::Left,
+            } => {
+                self.is_dragging = true;
+                self.change_value(self.value_at(*x, *y));
+                true
+            }
+            UIEvent::MouseMove { x, y, .. } if self.is_dragging => {
+                self.change_value(self.value_at(*x, *y));
+                true
+            }
+            UIEvent::MouseUp {
+                button: MouseButtonType::Left,
+                ..
+            } => {
+                self.is_dragging = false;
+                true
+            }
+            UIEvent::FocusGained => {
+                self.is_focused = true;
+                true
+            }
+            UIEvent::FocusLost => {
+                self.is_focused = false;
+                self.is_dragging = false;
+                true
+            }
+            UIEvent::KeyDown { key, .. } => {
+                let increment = self.keyboard_increment();
+                let target = match key {
+                    Key::Named(NamedKey::ArrowLeft | NamedKey::ArrowDown) => self.value - increment,
+                    Key::Named(NamedKey::ArrowRight | NamedKey::ArrowUp) => self.value + increment,
+                    Key::Named(NamedKey::Home) => self.min,
+                    Key::Named(NamedKey::End) => self.max,
+                    _ => return false,
+                };
+                self.change_value(target);
+                true
+            }
+            _ => false,
+        };
+
+        self.update_state();
+        handled
+    }
+
+    fn render(&self, draw_manager: &mut DrawManager, offset: (f32, f32)) {
+        let style = self.style.get_style(self.current_state);
+        let x = self.bounds.x + offset.0;
+        let y = self.bounds.y + offset.1;
+        let (length, thickness) = self.axis_extent();
+        let handle = self.handle_length();
+        let track_thickness = (thickness / 3.0).max(2.0);
+        let travel = (length - handle).max(0.0);
+        let handle_offset = self.normalized_value() * travel;
+        let to_color = |rgba: [f32; 4]| Color::new(rgba[0], rgba[1], rgba[2], rgba[3]);
+
+        // Rectangles as (x, y, width, height) in screen space.
+        let (track, fill, knob) = match self.orientation {
+            SliderOrientation::Horizontal => {
+                let track_y = y + (thickness - track_thickness) * 0.5;
+                (
+                    (x, track_y, length, track_thickness),
+                    (x, track_y, handle_offset + handle * 0.5, track_thickness),
+                    (x + handle_offset, y, handle, thickness),
+                )
+            }
+            SliderOrientation::Vertical => {
+                let track_x = x + (thickness - track_thickness) * 0.5;
+                let knob_y = y + travel - handle_offset;
+                let bottom = y + length;
+                (
+                    (track_x, y, track_thickness, length),
+                    (track_x, knob_y + handle * 0.5, track_thickness, bottom - knob_y - handle * 0.5),
+                    (x, knob_y, thickness, handle),
+                )
+            }
+        };
+
+        if style.background_color[3] > 0.0 {
+            draw_manager.draw_rectangle_with_options(
+                track.0,
+                track.1,
+                track.2,
+                track.3,
+                to_color(style.background_color),
+                true,
+                1.0,
+                self.depth,
+            );
+        }
+        if style.border_width > 0.0 {
+            draw_manager.draw_rectangle_with_options(
+                track.0,
+                track.1,
+                track.2,
+                track.3,
+                to_color(style.border_color),
+                false,
+                style.border_width,
+                self.depth + 0.005,
+            );
+        }
+
+        draw_manager.draw_rectangle_with_options(
+            fill.0,
+            fill.1,
+            fill.2,
+            fill.3,
+            to_color(style.text_color),
+            true,
+            1.0,
+            self.depth + 0.008,
+        );
+        draw_manager.draw_rectangle_with_options(
+            knob.0,
+            knob.1,
+            knob.2,
+            knob.3,
+            to_color(style.text_color),
+            true,
+            1.0,
+            self.depth + 0.01,
+        );
+        if style.border_width > 0.0 {
+            draw_manager.draw_rectangle_with_options(
+                knob.0,
+                knob.1,
+                knob.2,
+                knob.3,
+                to_color(style.border_color),
+                false,
+                style.border_width,
+                self.depth + 0.015,
+            );
+        }
+    }
+
+    fn ui_depth(&self) -> f32 {
+        self.depth
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled && self.enabled_in_hierarchy
+    }
+
+    fn apply_theme(&mut self, old: &UITheme, new: &UITheme) {
+        self.style.retheme(&old.slider_style, &new.slider_style);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_is_clamped_and_snapped() {
+        let mut slider = SliderComponent::new("Slider")
+            .with_range(10.0, 0.0)
+            .with_step(2.5);
+        assert_eq!((slider.min(), slider.max()), (0.0, 10.0));
+
+        slider.set_value(6.0);
+        assert_eq!(slider.value(), 5.0);
+        slider.set_value(42.0);
+        assert_eq!(slider.value(), 10.0);
+        slider.set_value(f32::NAN);
+        assert_eq!(slider.value(), 0.0);
+    }
+
+    #[test]
+    fn test_drag_updates_value_and_fires_callback() {
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&changes);
+        let mut slider = SliderComponent::new("Slider")
+            .with_range(0.0, 100.0)
+            .with_step(1.0)
+            .with_bounds(0.0, 0.0, 112.0, 24.0);
+        slider.set_on_value_changed(move |value| log.lock().unwrap().push(value));
+
+        // Handle is 12px long, so travel spans x = 6..106.
+        slider.handle_event(&UIEvent::MouseDown { x: 56.0, y: 12.0, button: MouseButtonType::Left });
+        assert!(slider.is_dragging());
+        slider.handle_event(&UIEvent::MouseMove { x: 500.0, y: 12.0, dx: 444.0, dy: 0.0 });
+        slider.handle_event(&UIEvent::MouseUp { x: 500.0, y: 12.0, button: MouseButtonType::Left });
+        slider.handle_event(&UIEvent::MouseMove { x: 6.0, y: 12.0, dx: -494.0, dy: 0.0 });
+
+        assert!(!slider.is_dragging());
+        assert_eq!(slider.value(), 100.0);
+        assert_eq!(*changes.lock().unwrap(), vec![50.0, 100.0]);
+    }
+
+    #[test]
+    fn test_vertical_slider_increases_upward() {
+        let mut slider = SliderComponent::new("Slider")
+            .with_orientation(SliderOrientation::Vertical)
+            .with_bounds(0.0, 0.0, 20.0, 110.0);
+        slider.handle_event(&UIEvent::MouseDown { x: 10.0, y: 0.0, button: MouseButtonType::Left });
+        assert_eq!(slider.value(), 1.0);
+        slider.handle_event(&UIEvent::KeyDown {
+            key: Key::Named(NamedKey::ArrowDown),
+            shift: false,
+            ctrl: false,
+        });
+        assert!((slider.value() - 0.99).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,363 @@
+use super::{Rect, UIComponentTrait};
+use super::event::UIEvent;
+use super::style::Padding;
+use crate::core::component::{ComponentTrait, next_component_id};
+use crate::core::draw_manager::DrawManager;
+use crate::core::time::Time;
+use std::any::Any;
+
+/// Axis a stack lays its children out along.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum StackDirection {
+    /// Top to bottom (VStack)
+    #[default]
+    Vertical,
+    /// Left to right (HStack)
+    Horizontal,
+}
+
+impl StackDirection {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Vertical => "vertical",
+            Self::Horizontal => "horizontal",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "vertical" | "v" | "column" => Some(Self::Vertical),
+            "horizontal" | "h" | "row" => Some(Self::Horizontal),
+            _ => None,
+        }
+    }
+}
+
+/// Where children sit across the stack's axis (horizontally in a VStack).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum StackAlignment {
+    #[default]
+    Start,
+    Center,
+    End,
+    /// Resize children to the stack's inner width (VStack) or height (HStack)
+    Stretch,
+}
+
+impl StackAlignment {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Start => "start",
+            Self::Center => "center",
+            Self::End => "end",
+            Self::Stretch => "stretch",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "start" | "left" | "top" => Some(Self::Start),
+            "center" | "middle" => Some(Self::Center),
+            "end" | "right" | "bottom" => Some(Self::End),
+            "stretch" | "fill" => Some(Self::Stretch),
+            _ => None,
+        }
+    }
+}
+
+/// Invisible container that positions its children one after another.
+///
+/// `UIManager` re-arranges the children every frame, in the order they were
+/// added, so adding, removing, hiding or resizing a child re-flows the rest.
+/// Disabled children take no space. The stack sets the children's positions
+/// (and, for `Stretch`, their cross-axis size); a child's own `UILayout` is
+/// ignored while it is inside a stack.
+///
+/// With `fit_content` the stack grows or shrinks along its axis to wrap its
+/// children, which lets a stack inside a `ScrollView` scroll its whole list.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyg_engine_native::core::ui::Rect;
+/// use pyg_engine_native::core::ui::stack::{StackAlignment, StackComponent, StackDirection};
+/// use pyg_engine_native::core::ui::style::Padding;
+///
+/// let stack = StackComponent::new("Stack")
+///     .with_direction(StackDirection::Vertical)
+///     .with_bounds(0.0, 0.0, 200.0, 300.0)
+///     .with_spacing(8.0)
+///     .with_padding(Padding::uniform(10.0))
+///     .with_alignment(StackAlignment::Center);
+///
+/// let (rects, _) = stack.arrange(&[(100.0, 30.0), (140.0, 30.0)]);
+/// assert_eq!(rects[1], Rect::new(30.0, 48.0, 140.0, 30.0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct StackComponent {
+    component_id: u32,
+    name: String,
+    bounds: Rect,
+    direction: StackDirection,
+    spacing: f32,
+    padding: Padding,
+    alignment: StackAlignment,
+    fit_content: bool,
+    enabled: bool,
+    enabled_in_hierarchy: bool,
+    depth: f32,
+}
+
+impl StackComponent {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            component_id: next_component_id(),
+            name: name.into(),
+            bounds: Rect::new(0.0, 0.0, 200.0, 200.0),
+            direction: StackDirection::default(),
+            spacing: 0.0,
+            padding: Padding::zero(),
+            alignment: StackAlignment::default(),
+            fit_content: false,
+            enabled: true,
+            enabled_in_hierarchy: true,
+            depth: 0.0,
+        }
+    }
+
+    pub fn with_bounds(mut self, x: f32, y: f32, width: f32, height: f32) -> Self {
+        self.bounds = Rect::new(x, y, width, height);
+        self
+    }
+
+    pub fn with_direction(mut self, direction: StackDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn with_spacing(mut self, spacing: f32) -> Self {
+        self.set_spacing(spacing);
+        self
+    }
+
+    pub fn with_padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn with_alignment(mut self, alignment: StackAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    pub fn with_fit_content(mut self, fit_content: bool) -> Self {
+        self.fit_content = fit_content;
+        self
+    }
+
+    pub fn with_depth(mut self, depth: f32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    pub fn direction(&self) -> StackDirection {
+        self.direction
+    }
+
+    pub fn set_direction(&mut self, direction: StackDirection) {
+        self.direction = direction;
+    }
+
+    pub fn spacing(&self) -> f32 {
+        self.spacing
+    }
+
+    pub fn set_spacing(&mut self, spacing: f32) {
+        self.spacing = spacing.max(0.0);
+    }
+
+    pub fn padding(&self) -> Padding {
+        self.padding
+    }
+
+    pub fn set_padding(&mut self, padding: Padding) {
+        self.padding = padding;
+    }
+
+    pub fn alignment(&self) -> StackAlignment {
+        self.alignment
+    }
+
+    pub fn set_alignment(&mut self, alignment: StackAlignment) {
+        self.alignment = alignment;
+    }
+
+    pub fn fit_content(&self) -> bool {
+        self.fit_content
+    }
+
+    pub fn set_fit_content(&mut self, fit_content: bool) {
+        self.fit_content = fit_content;
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Lay out children of the given sizes, in order.
+    ///
+    /// Returns their bounds relative to the stack's top-left corner and the
+    /// length along the stack's axis needed to wrap them, padding included.
+    pub fn arrange(&self, child_sizes: &[(f32, f32)]) -> (Vec<Rect>, f32) {
+        let vertical = self.direction == StackDirection::Vertical;
+        let (main_start, main_end, cross_start, cross_end) = if vertical {
+            (self.padding.top, self.padding.bottom, self.padding.left, self.padding.right)
+        } else {
+            (self.padding.left, self.padding.right, self.padding.top, self.padding.bottom)
+        };
+        let cross_size = if vertical { self.bounds.width } else { self.bounds.height };
+        let available = (cross_size - cross_start - cross_end).max(0.0);
+
+        let mut cursor = main_start;
+        let mut rects = Vec::with_capacity(child_sizes.len());
+        for (index, &(width, height)) in child_sizes.iter().enumerate() {
+            if index > 0 {
+                cursor += self.spacing;
+            }
+            let (main, cross) = if vertical { (height, width) } else { (width, height) };
+            let (cross_offset, cross) = match self.alignment {
+                StackAlignment::Start => (0.0, cross),
+                StackAlignment::Center => ((available - cross) / 2.0, cross),
+                StackAlignment::End => (available - cross, cross),
+                StackAlignment::Stretch => (0.0, available),
+            };
+            rects.push(if vertical {
+                Rect::new(cross_start + cross_offset, cursor, cross, main)
+            } else {
+                Rect::new(cursor, cross_start + cross_offset, main, cross)
+            });
+            cursor += main;
+        }
+
+        (rects, cursor + main_end)
+    }
+}
+
+impl ComponentTrait for StackComponent {
+    fn new(name: String) -> Self {
+        Self::new(name)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn id(&self) -> u32 {
+        self.component_id
+    }
+
+    fn component_type(&self) -> &'static str {
+        "Stack"
+    }
+
+    fn is_enabled_self(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled_self(&mut self, enabled: bool) {
+        self.set_enabled(enabled);
+    }
+
+    fn is_enabled_in_hierarchy(&self) -> bool {
+        self.enabled_in_hierarchy
+    }
+
+    fn set_enabled_in_hierarchy(&mut self, enabled: bool) {
+        self.enabled_in_hierarchy = enabled;
+    }
+
+    fn update(&self, _time: &Time) {}
+    fn fixed_update(&self, _time: &Time, _fixed_time: f32) {}
+    fn on_start(&self) {}
+    fn on_destroy(&self) {}
+    fn on_enable(&self) {}
+    fn on_disable(&self) {}
+
+    fn clone_component(&self) -> Box<dyn ComponentTrait> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl UIComponentTrait for StackComponent {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn handle_event(&mut self, _event: &UIEvent) -> bool {
+        // Stacks don't handle events
+        false
+    }
+
+    fn render(&self, _draw_manager: &mut DrawManager, _offset: (f32, f32)) {
+        // Stacks only position their children; wrap one in a Panel for a background
+    }
+
+    fn ui_depth(&self) -> f32 {
+        self.depth
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled && self.enabled_in_hierarchy
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arrange_spacing_padding_and_alignment() {
+        let mut stack = StackComponent::new("Stack")
+            .with_bounds(0.0, 0.0, 100.0, 40.0)
+            .with_direction(StackDirection::Horizontal)
+            .with_spacing(5.0)
+            .with_padding(Padding::new(10.0, 10.0, 4.0, 4.0))
+            .with_alignment(StackAlignment::End);
+
+        let (rects, length) = stack.arrange(&[(20.0, 10.0), (30.0, 32.0)]);
+        assert_eq!(rects, vec![Rect::new(10.0, 26.0, 20.0, 10.0), Rect::new(35.0, 4.0, 30.0, 32.0)]);
+        assert_eq!(length, 75.0);
+
+        stack.set_alignment(StackAlignment::Stretch);
+        let (rects, _) = stack.arrange(&[(20.0, 10.0)]);
+        assert_eq!(rects, vec![Rect::new(10.0, 4.0, 20.0, 32.0)]);
+
+        let (rects, length) = stack.arrange(&[]);
+        assert!(rects.is_empty());
+        assert_eq!(length, 20.0);
+    }
+}
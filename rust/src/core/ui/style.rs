@@ -1,5 +1,13 @@
-use super::StyleState;
+use super::{Rect, StyleState};
+use crate::core::draw_manager::DrawManager;
 use crate::core::text::{FontStyle, FontWeight, TextStyle};
+use crate::types::color::Color;
+use crate::types::vector::Vec2;
+use std::f32::consts::{FRAC_PI_2, PI};
+use std::sync::OnceLock;
+
+/// Most layers stacked to approximate a blurred shadow.
+const MAX_SHADOW_LAYERS: usize = 8;
 
 /// Padding for UI elements
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -42,7 +50,15 @@ pub struct UIStyle {
     pub border_color: [f32; 4],      // RGBA
     pub text_color: [f32; 4],        // RGBA
     pub border_width: f32,
+    /// Corner radius of backgrounds, borders and shadows
     pub border_radius: f32,
+    /// Bottom color of a vertical background gradient starting at `background_color`
+    pub background_gradient: Option<[f32; 4]>,
+    /// Drop shadow color; transparent draws no shadow
+    pub shadow_color: [f32; 4],
+    pub shadow_offset: (f32, f32),
+    /// Distance over which the shadow's edge fades out
+    pub shadow_blur: f32,
     pub padding: Padding,
     pub margin: Padding,
     pub text_style: TextStyle,
@@ -56,6 +72,10 @@ impl UIStyle {
             text_color: [0.0, 0.0, 0.0, 1.0],       // Black
             border_width: 0.0,
             border_radius: 0.0,
+            background_gradient: None,
+            shadow_color: [0.0, 0.0, 0.0, 0.0],
+            shadow_offset: (0.0, 0.0),
+            shadow_blur: 0.0,
             padding: Padding::zero(),
             margin: Padding::zero(),
             text_style: TextStyle::new(16.0),
@@ -133,6 +153,196 @@ impl UIStyle {
     pub fn line_spacing(&self) -> f32 {
         self.text_style.line_spacing
     }
+
+    pub fn font_fallbacks(&self) -> &[String] {
+        self.text_style.font.fallbacks()
+    }
+
+    /// Fonts tried in order for characters the primary font lacks.
+    pub fn set_font_fallbacks(&mut self, fallbacks: Vec<String>) {
+        self.text_style.font.set_fallbacks(fallbacks);
+    }
+
+    pub fn set_shadow(&mut self, color: [f32; 4], offset: (f32, f32), blur: f32) {
+        self.shadow_color = color;
+        self.shadow_offset = offset;
+        self.shadow_blur = blur.max(0.0);
+    }
+
+    /// Draw the shadow, background and border of a box with this style.
+    ///
+    /// The shadow goes just below `depth` and the border just above it, so
+    /// content drawn at `depth + 0.01` stays on top.
+    pub fn render_box(&self, draw_manager: &mut DrawManager, bounds: Rect, depth: f32) {
+        let radius = self.border_radius.clamp(0.0, bounds.width.min(bounds.height) / 2.0);
+
+        if self.shadow_color[3] > 0.0 {
+            self.render_shadow(draw_manager, bounds, radius, depth - 0.0005);
+        }
+
+        let bottom = self.background_gradient.unwrap_or(self.background_color);
+        if self.background_color[3] > 0.0 || bottom[3] > 0.0 {
+            fill_box(draw_manager, bounds, radius, self.background_color, bottom, depth);
+        }
+
+        if self.border_width > 0.0 {
+            stroke_box(draw_manager, bounds, radius, self.border_color, self.border_width, depth + 0.005);
+        }
+    }
+
+    /// Approximate a blurred shadow with stacked boxes that grow by the blur
+    /// distance and together reach the shadow's alpha in the middle.
+    fn render_shadow(&self, draw_manager: &mut DrawManager, bounds: Rect, radius: f32, depth: f32) {
+        let layers = ((self.shadow_blur / 2.0).ceil() as usize).clamp(1, MAX_SHADOW_LAYERS);
+        let [r, g, b, a] = self.shadow_color;
+        let layer_color = [r, g, b, 1.0 - (1.0 - a.min(1.0)).powf(1.0 / layers as f32)];
+        for layer in 0..layers {
+            // From half the blur outside the box to half of it inside.
+            let spread = self.shadow_blur * ((layers - layer) as f32 / layers as f32 - 0.5);
+            let shadow = Rect::new(
+                bounds.x + self.shadow_offset.0 - spread,
+                bounds.y + self.shadow_offset.1 - spread,
+                (bounds.width + spread * 2.0).max(0.0),
+                (bounds.height + spread * 2.0).max(0.0),
+            );
+            let shadow_radius = (radius + spread).clamp(0.0, shadow.width.min(shadow.height) / 2.0);
+            fill_box(draw_manager, shadow, shadow_radius, layer_color, layer_color, depth);
+        }
+    }
+}
+
+fn to_color(rgba: [f32; 4]) -> Color {
+    Color::new(rgba[0], rgba[1], rgba[2], rgba[3])
+}
+
+/// Outline a box, with rounded corners when `radius` is positive.
+pub fn stroke_box(draw_manager: &mut DrawManager, bounds: Rect, radius: f32, color: [f32; 4], width: f32, depth: f32) {
+    let radius = radius.clamp(0.0, bounds.width.min(bounds.height) / 2.0);
+    if radius > 0.0 {
+        draw_manager.draw_polygon_with_options(rounded_rect_points(bounds, radius), to_color(color), false, width, depth);
+    } else {
+        draw_manager.draw_rectangle_with_options(
+            bounds.x,
+            bounds.y,
+            bounds.width,
+            bounds.height,
+            to_color(color),
+            false,
+            width,
+            depth,
+        );
+    }
+}
+
+fn lerp_color(from: [f32; 4], to: [f32; 4], t: f32) -> [f32; 4] {
+    std::array::from_fn(|i| from[i] + (to[i] - from[i]) * t)
+}
+
+/// Fill a box with rounded corners and a vertical gradient from `top` to `bottom`.
+///
+/// Rounded gradient boxes are drawn as a gradient middle band between two
+/// solid caps colored at the cap's center, since filled polygons take one color.
+fn fill_box(draw_manager: &mut DrawManager, bounds: Rect, radius: f32, top: [f32; 4], bottom: [f32; 4], depth: f32) {
+    if bounds.width <= 0.0 || bounds.height <= 0.0 {
+        return;
+    }
+    if radius <= 0.0 {
+        if top == bottom {
+            draw_manager.draw_rectangle_with_options(
+                bounds.x,
+                bounds.y,
+                bounds.width,
+                bounds.height,
+                to_color(top),
+                true,
+                1.0,
+                depth,
+            );
+        } else {
+            let (top, bottom) = (to_color(top), to_color(bottom));
+            draw_manager.draw_gradient_rect_with_options(
+                bounds.x,
+                bounds.y,
+                bounds.width,
+                bounds.height,
+                top,
+                bottom,
+                bottom,
+                top,
+                depth,
+            );
+        }
+        return;
+    }
+    if top == bottom {
+        draw_manager.draw_polygon_with_options(rounded_rect_points(bounds, radius), to_color(top), true, 1.0, depth);
+        return;
+    }
+
+    let (left, right) = (bounds.x, bounds.x + bounds.width);
+    let (upper, lower) = (bounds.y + radius, bounds.y + bounds.height - radius);
+    let segments = corner_segments(radius);
+    let mut top_cap = corner_arc(left + radius, upper, radius, PI, segments);
+    top_cap.extend(corner_arc(right - radius, upper, radius, PI + FRAC_PI_2, segments));
+    let mut bottom_cap = corner_arc(right - radius, lower, radius, 0.0, segments);
+    bottom_cap.extend(corner_arc(left + radius, lower, radius, FRAC_PI_2, segments));
+
+    let cap_share = radius / bounds.height;
+    draw_manager.draw_polygon_with_options(
+        top_cap,
+        to_color(lerp_color(top, bottom, cap_share / 2.0)),
+        true,
+        1.0,
+        depth,
+    );
+    if lower > upper {
+        let band_top = to_color(lerp_color(top, bottom, cap_share));
+        let band_bottom = to_color(lerp_color(top, bottom, 1.0 - cap_share));
+        draw_manager.draw_gradient_rect_with_options(
+            left,
+            upper,
+            bounds.width,
+            lower - upper,
+            band_top,
+            band_bottom,
+            band_bottom,
+            band_top,
+            depth,
+        );
+    }
+    draw_manager.draw_polygon_with_options(
+        bottom_cap,
+        to_color(lerp_color(top, bottom, 1.0 - cap_share / 2.0)),
+        true,
+        1.0,
+        depth,
+    );
+}
+
+/// Outline of a rounded rectangle, clockwise on screen from the top-left corner.
+fn rounded_rect_points(bounds: Rect, radius: f32) -> Vec<Vec2> {
+    let segments = corner_segments(radius);
+    let (left, right) = (bounds.x + radius, bounds.x + bounds.width - radius);
+    let (upper, lower) = (bounds.y + radius, bounds.y + bounds.height - radius);
+    let mut points = corner_arc(left, upper, radius, PI, segments);
+    points.extend(corner_arc(right, upper, radius, PI + FRAC_PI_2, segments));
+    points.extend(corner_arc(right, lower, radius, 0.0, segments));
+    points.extend(corner_arc(left, lower, radius, FRAC_PI_2, segments));
+    points
+}
+
+fn corner_segments(radius: f32) -> usize {
+    ((radius / 2.0).ceil() as usize).clamp(2, 12)
+}
+
+/// Quarter circle around (`center_x`, `center_y`) from `start_angle`, with y pointing down.
+fn corner_arc(center_x: f32, center_y: f32, radius: f32, start_angle: f32, segments: usize) -> Vec<Vec2> {
+    (0..=segments)
+        .map(|step| {
+            let angle = start_angle + FRAC_PI_2 * step as f32 / segments as f32;
+            Vec2::new(center_x + radius * angle.cos(), center_y + radius * angle.sin())
+        })
+        .collect()
 }
 
 impl Default for UIStyle {
@@ -191,64 +401,612 @@ impl Default for StyleSet {
     }
 }
 
+/// Replace `color` with `new` if it still has the `old` value, leaving
+/// colors that were set explicitly alone.
+pub fn retheme_color(color: &mut [f32; 4], old: [f32; 4], new: [f32; 4]) {
+    if *color == old {
+        *color = new;
+    }
+}
+
+impl UIStyle {
+    /// Move the colors that still match `old` over to `new`.
+    pub fn retheme(&mut self, old: &UIStyle, new: &UIStyle) {
+        retheme_color(&mut self.background_color, old.background_color, new.background_color);
+        retheme_color(&mut self.border_color, old.border_color, new.border_color);
+        retheme_color(&mut self.text_color, old.text_color, new.text_color);
+    }
+}
+
+impl StyleSet {
+    pub fn set_font_size(&mut self, font_size: f32) {
+        for state in STYLE_STATES {
+            self.get_style_mut(state).set_font_size(font_size);
+        }
+    }
+
+    pub fn set_font_path(&mut self, font_path: Option<String>) {
+        for state in STYLE_STATES {
+            self.get_style_mut(state).set_font_path(font_path.clone());
+        }
+    }
+
+    pub fn set_font_family(&mut self, font_family: Option<String>) {
+        for state in STYLE_STATES {
+            self.get_style_mut(state).set_font_family(font_family.clone());
+        }
+    }
+
+    /// Fonts tried in order for characters the primary font lacks, in every state.
+    pub fn set_font_fallbacks(&mut self, fallbacks: Vec<String>) {
+        for state in STYLE_STATES {
+            self.get_style_mut(state).set_font_fallbacks(fallbacks.clone());
+        }
+    }
+
+    pub fn set_font_weight(&mut self, font_weight: FontWeight) {
+        for state in STYLE_STATES {
+            self.get_style_mut(state).set_font_weight(font_weight);
+        }
+    }
+
+    pub fn set_font_style(&mut self, font_style: FontStyle) {
+        for state in STYLE_STATES {
+            self.get_style_mut(state).set_font_style(font_style);
+        }
+    }
+
+    pub fn set_kerning(&mut self, kerning: bool) {
+        for state in STYLE_STATES {
+            self.get_style_mut(state).set_kerning(kerning);
+        }
+    }
+
+    pub fn set_letter_spacing(&mut self, letter_spacing: f32) {
+        for state in STYLE_STATES {
+            self.get_style_mut(state).set_letter_spacing(letter_spacing);
+        }
+    }
+
+    pub fn set_line_spacing(&mut self, line_spacing: f32) {
+        for state in STYLE_STATES {
+            self.get_style_mut(state).set_line_spacing(line_spacing);
+        }
+    }
+
+    /// Set the corner radius of every state.
+    pub fn set_border_radius(&mut self, radius: f32) {
+        for state in STYLE_STATES {
+            self.get_style_mut(state).border_radius = radius.max(0.0);
+        }
+    }
+
+    /// Set the drop shadow of every state.
+    pub fn set_shadow(&mut self, color: [f32; 4], offset: (f32, f32), blur: f32) {
+        for state in STYLE_STATES {
+            self.get_style_mut(state).set_shadow(color, offset, blur);
+        }
+    }
+
+    /// Move the colors of every state that still match `old` over to `new`.
+    pub fn retheme(&mut self, old: &StyleSet, new: &StyleSet) {
+        for state in STYLE_STATES {
+            self.get_style_mut(state)
+                .retheme(old.get_style(state), new.get_style(state));
+        }
+    }
+}
+
+const STYLE_STATES: [StyleState; 5] = [
+    StyleState::Normal,
+    StyleState::Hovered,
+    StyleState::Pressed,
+    StyleState::Focused,
+    StyleState::Disabled,
+];
+
 /// UI theme containing default styles for components
+///
+/// Widgets start out with the light theme's styles. Switching themes moves
+/// every widget color that still has the previous theme's value to the new
+/// theme's; sizes, padding and fonts stay as each widget set them.
 #[derive(Debug, Clone)]
 pub struct UITheme {
     pub button_style: StyleSet,
     pub panel_style: UIStyle,
+    /// Backdrop drawn behind modal panels
+    pub panel_dim_color: [f32; 4],
     pub label_style: UIStyle,
+    pub text_input_style: StyleSet,
+    /// Track (background), fill (text) and knob outline (border) colors
+    pub slider_style: StyleSet,
+    /// Box (background and border), check mark and label (text) colors
+    pub checkbox_style: StyleSet,
+    pub radio_button_style: StyleSet,
+    /// Off track (background), outline (border) and label (text) colors
+    pub toggle_switch_style: StyleSet,
+    pub toggle_switch_on_color: [f32; 4],
+    pub toggle_switch_knob_color: [f32; 4],
+    /// The scrollbar uses the text color
+    pub scroll_view_style: UIStyle,
+    /// Track (background) and fill (text) colors
+    pub progress_bar_style: StyleSet,
+    /// Overlay text color
+    pub progress_bar_text_color: [f32; 4],
+    /// Curve and key handles use the text color
+    pub curve_editor_style: StyleSet,
+    pub list_view_style: UIStyle,
+    pub list_view_hover_color: [f32; 4],
+    pub list_view_selection_color: [f32; 4],
+    pub list_view_selected_text_color: [f32; 4],
+    /// Outline drawn around the widget focused from the keyboard
+    pub focus_ring_color: [f32; 4],
+    /// How much color disabled widgets keep, from 0 (grey) to 1 (unchanged)
+    pub disabled_saturation: f32,
+    /// Opacity multiplier for disabled widgets
+    pub disabled_opacity: f32,
+    /// Fallback fonts for UI text whose style lists none, e.g. CJK, Arabic
+    /// and emoji fonts after a Latin primary font.
+    pub font_fallbacks: Vec<String>,
 }
 
 impl UITheme {
+    /// The light theme, shared by widget constructors for their default styles.
+    pub fn light() -> &'static UITheme {
+        static LIGHT: OnceLock<UITheme> = OnceLock::new();
+        LIGHT.get_or_init(Self::default_light)
+    }
+
     /// Create the default light theme
     pub fn default_light() -> Self {
-        // Button styles
-        let mut button_normal = UIStyle::new();
-        button_normal.background_color = [0.90, 0.90, 0.90, 1.0]; // #e5e5e5
-        button_normal.border_color = [0.6, 0.6, 0.6, 1.0];
-        button_normal.border_width = 1.0;
-        button_normal.border_radius = 4.0;
-        button_normal.padding = Padding::uniform(8.0);
-        button_normal.text_color = [0.0, 0.0, 0.0, 1.0];
-        button_normal.set_font_size(14.0);
-
-        let mut button_hovered = button_normal.clone();
-        button_hovered.background_color = [0.95, 0.95, 0.95, 1.0]; // #f2f2f2
-
-        let mut button_pressed = button_normal.clone();
-        button_pressed.background_color = [0.80, 0.80, 0.80, 1.0]; // #cccccc
-
-        let button_focused = button_hovered.clone();
-
-        let mut button_disabled = button_normal.clone();
-        button_disabled.background_color = [0.85, 0.85, 0.85, 1.0];
-        button_disabled.text_color = [0.5, 0.5, 0.5, 1.0];
-
-        let button_style = StyleSet {
-            normal: button_normal,
-            hovered: button_hovered,
-            pressed: button_pressed,
-            focused: button_focused,
-            disabled: button_disabled,
-        };
+        let button_style = StyleSet::default();
 
-        // Panel style
-        let mut panel_style = UIStyle::new();
-        panel_style.background_color = [1.0, 1.0, 1.0, 1.0]; // White
-        panel_style.border_color = [0.8, 0.8, 0.8, 1.0];
-        panel_style.border_width = 1.0;
-        panel_style.border_radius = 0.0;
-        panel_style.padding = Padding::uniform(4.0);
+        let panel_style = UIStyle::new();
 
-        // Label style
         let mut label_style = UIStyle::transparent();
-        label_style.text_color = [0.0, 0.0, 0.0, 1.0];
         label_style.set_font_size(14.0);
 
+        // Text input styles
+        let mut input_normal = UIStyle::new();
+        input_normal.border_color = [0.6, 0.6, 0.6, 1.0];
+        input_normal.border_width = 1.0;
+        input_normal.padding = Padding::new(6.0, 6.0, 4.0, 4.0);
+        input_normal.set_font_size(16.0);
+
+        let mut input_hovered = input_normal.clone();
+        input_hovered.border_color = [0.4, 0.4, 0.4, 1.0];
+
+        let mut input_focused = input_normal.clone();
+        input_focused.border_color = [0.2, 0.5, 0.9, 1.0];
+        input_focused.border_width = 2.0;
+
+        let mut input_disabled = input_normal.clone();
+        input_disabled.background_color = [0.92, 0.92, 0.92, 1.0];
+        input_disabled.text_color = [0.5, 0.5, 0.5, 1.0];
+
+        let text_input_style = StyleSet {
+            normal: input_normal,
+            hovered: input_hovered,
+            pressed: input_focused.clone(),
+            focused: input_focused,
+            disabled: input_disabled,
+        };
+
+        // Slider styles
+        let mut slider_normal = UIStyle::new();
+        slider_normal.background_color = [0.75, 0.75, 0.75, 1.0];
+        slider_normal.border_color = [0.55, 0.55, 0.55, 1.0];
+        slider_normal.border_width = 1.0;
+        slider_normal.text_color = [0.2, 0.5, 0.9, 1.0];
+
+        let mut slider_hovered = slider_normal.clone();
+        slider_hovered.text_color = [0.3, 0.6, 1.0, 1.0];
+
+        let mut slider_pressed = slider_normal.clone();
+        slider_pressed.text_color = [0.15, 0.4, 0.75, 1.0];
+
+        let mut slider_disabled = slider_normal.clone();
+        slider_disabled.background_color = [0.85, 0.85, 0.85, 1.0];
+        slider_disabled.text_color = [0.6, 0.6, 0.6, 1.0];
+
+        let slider_style = StyleSet {
+            normal: slider_normal,
+            hovered: slider_hovered.clone(),
+            pressed: slider_pressed,
+            focused: slider_hovered,
+            disabled: slider_disabled,
+        };
+
+        // Checkbox and radio button styles
+        let mut check_normal = UIStyle::new();
+        check_normal.border_color = [0.5, 0.5, 0.5, 1.0];
+        check_normal.border_width = 1.0;
+        check_normal.set_font_size(16.0);
+
+        let mut check_hovered = check_normal.clone();
+        check_hovered.border_color = [0.2, 0.5, 0.9, 1.0];
+
+        let mut check_pressed = check_hovered.clone();
+        check_pressed.background_color = [0.88, 0.92, 1.0, 1.0];
+
+        let mut check_disabled = check_normal.clone();
+        check_disabled.background_color = [0.9, 0.9, 0.9, 1.0];
+        check_disabled.border_color = [0.7, 0.7, 0.7, 1.0];
+        check_disabled.text_color = [0.55, 0.55, 0.55, 1.0];
+
+        let checkbox_style = StyleSet {
+            normal: check_normal,
+            hovered: check_hovered.clone(),
+            pressed: check_pressed,
+            focused: check_hovered,
+            disabled: check_disabled,
+        };
+
+        // Toggle switch styles
+        let mut switch_normal = UIStyle::new();
+        switch_normal.background_color = [0.75, 0.75, 0.75, 1.0];
+        switch_normal.border_color = [0.5, 0.5, 0.5, 1.0];
+        switch_normal.border_width = 1.0;
+        switch_normal.set_font_size(16.0);
+
+        let mut switch_hovered = switch_normal.clone();
+        switch_hovered.border_color = [0.2, 0.5, 0.9, 1.0];
+
+        let mut switch_pressed = switch_hovered.clone();
+        switch_pressed.background_color = [0.65, 0.65, 0.65, 1.0];
+
+        let mut switch_disabled = switch_normal.clone();
+        switch_disabled.background_color = [0.9, 0.9, 0.9, 1.0];
+        switch_disabled.border_color = [0.7, 0.7, 0.7, 1.0];
+        switch_disabled.text_color = [0.55, 0.55, 0.55, 1.0];
+
+        let toggle_switch_style = StyleSet {
+            normal: switch_normal,
+            hovered: switch_hovered.clone(),
+            pressed: switch_pressed,
+            focused: switch_hovered,
+            disabled: switch_disabled,
+        };
+
+        // Scroll view style
+        let mut scroll_view_style = UIStyle::new();
+        scroll_view_style.background_color = [0.97, 0.97, 0.97, 1.0];
+        scroll_view_style.border_color = [0.6, 0.6, 0.6, 1.0];
+        scroll_view_style.border_width = 1.0;
+        scroll_view_style.text_color = [0.0, 0.0, 0.0, 0.4];
+
+        // Progress bar styles
+        let mut bar_normal = UIStyle::new();
+        bar_normal.background_color = [0.8, 0.8, 0.8, 1.0];
+        bar_normal.border_color = [0.55, 0.55, 0.55, 1.0];
+        bar_normal.border_width = 1.0;
+        bar_normal.text_color = [0.25, 0.7, 0.35, 1.0];
+        bar_normal.set_font_size(12.0);
+
+        let mut bar_disabled = bar_normal.clone();
+        bar_disabled.background_color = [0.88, 0.88, 0.88, 1.0];
+        bar_disabled.text_color = [0.65, 0.65, 0.65, 1.0];
+
+        let progress_bar_style = StyleSet {
+            disabled: bar_disabled,
+            ..StyleSet::new(bar_normal)
+        };
+
+        // List view style
+        let mut list_view_style = UIStyle::new();
+        list_view_style.border_color = [0.6, 0.6, 0.6, 1.0];
+        list_view_style.border_width = 1.0;
+        list_view_style.padding.left = 8.0;
+        list_view_style.padding.right = 8.0;
+        list_view_style.set_font_size(14.0);
+
+        Self {
+            button_style,
+            panel_style,
+            panel_dim_color: [0.0, 0.0, 0.0, 0.5],
+            label_style,
+            text_input_style,
+            slider_style,
+            radio_button_style: checkbox_style.clone(),
+            checkbox_style,
+            toggle_switch_style,
+            toggle_switch_on_color: [0.2, 0.6, 0.3, 1.0],
+            toggle_switch_knob_color: [1.0, 1.0, 1.0, 1.0],
+            scroll_view_style,
+            progress_bar_style,
+            progress_bar_text_color: [0.1, 0.1, 0.1, 1.0],
+            curve_editor_style: Self::curve_editor_style(),
+            list_view_style,
+            list_view_hover_color: [0.92, 0.94, 0.98, 1.0],
+            list_view_selection_color: [0.2, 0.5, 0.9, 1.0],
+            list_view_selected_text_color: [1.0, 1.0, 1.0, 1.0],
+            focus_ring_color: [0.2, 0.5, 0.9, 1.0],
+            disabled_saturation: 0.0,
+            disabled_opacity: 0.5,
+            font_fallbacks: Vec::new(),
+        }
+    }
+
+    /// Create the dark theme, keeping the light theme's sizes and fonts
+    pub fn default_dark() -> Self {
+        let light = Self::default_light();
+        let text = [0.9, 0.91, 0.93, 1.0];
+        let disabled_text = [0.5, 0.52, 0.55, 1.0];
+        let field = [0.1, 0.11, 0.13, 1.0];
+        let disabled_field = [0.16, 0.17, 0.19, 1.0];
+        let border = [0.36, 0.38, 0.42, 1.0];
+        let accent = [0.3, 0.55, 0.95, 1.0];
+
+        let mut button_style = light.button_style.clone();
+        button_style.normal.background_color = [0.22, 0.23, 0.26, 1.0];
+        button_style.hovered.background_color = [0.27, 0.28, 0.32, 1.0];
+        button_style.pressed.background_color = [0.16, 0.17, 0.2, 1.0];
+        button_style.focused.background_color = [0.27, 0.28, 0.32, 1.0];
+        button_style.disabled.background_color = [0.18, 0.19, 0.21, 1.0];
+        for state in STYLE_STATES {
+            let style = button_style.get_style_mut(state);
+            style.border_color = border;
+            style.text_color = text;
+        }
+        button_style.disabled.text_color = disabled_text;
+
+        let mut panel_style = light.panel_style.clone();
+        panel_style.background_color = [0.12, 0.13, 0.15, 1.0];
+        panel_style.border_color = [0.3, 0.32, 0.36, 1.0];
+        panel_style.text_color = text;
+
+        let mut label_style = light.label_style.clone();
+        label_style.text_color = text;
+
+        let mut text_input_style = light.text_input_style.clone();
+        for state in STYLE_STATES {
+            let style = text_input_style.get_style_mut(state);
+            style.background_color = field;
+            style.border_color = border;
+            style.text_color = text;
+        }
+        text_input_style.hovered.border_color = [0.5, 0.52, 0.56, 1.0];
+        text_input_style.pressed.border_color = accent;
+        text_input_style.focused.border_color = accent;
+        text_input_style.disabled.background_color = disabled_field;
+        text_input_style.disabled.text_color = disabled_text;
+
+        let mut slider_style = light.slider_style.clone();
+        for state in STYLE_STATES {
+            let style = slider_style.get_style_mut(state);
+            style.background_color = [0.28, 0.3, 0.33, 1.0];
+            style.border_color = [0.45, 0.47, 0.5, 1.0];
+            style.text_color = accent;
+        }
+        slider_style.hovered.text_color = [0.4, 0.65, 1.0, 1.0];
+        slider_style.focused.text_color = [0.4, 0.65, 1.0, 1.0];
+        slider_style.pressed.text_color = [0.25, 0.45, 0.8, 1.0];
+        slider_style.disabled.background_color = [0.2, 0.21, 0.23, 1.0];
+        slider_style.disabled.text_color = [0.4, 0.42, 0.45, 1.0];
+
+        let mut checkbox_style = light.checkbox_style.clone();
+        for state in STYLE_STATES {
+            let style = checkbox_style.get_style_mut(state);
+            style.background_color = field;
+            style.border_color = [0.45, 0.47, 0.5, 1.0];
+            style.text_color = text;
+        }
+        checkbox_style.hovered.border_color = accent;
+        checkbox_style.focused.border_color = accent;
+        checkbox_style.pressed.border_color = accent;
+        checkbox_style.pressed.background_color = [0.16, 0.22, 0.32, 1.0];
+        checkbox_style.disabled.background_color = disabled_field;
+        checkbox_style.disabled.border_color = [0.3, 0.31, 0.33, 1.0];
+        checkbox_style.disabled.text_color = disabled_text;
+
+        let mut toggle_switch_style = light.toggle_switch_style.clone();
+        for state in STYLE_STATES {
+            let style = toggle_switch_style.get_style_mut(state);
+            style.background_color = [0.3, 0.32, 0.35, 1.0];
+            style.border_color = [0.45, 0.47, 0.5, 1.0];
+            style.text_color = text;
+        }
+        toggle_switch_style.hovered.border_color = accent;
+        toggle_switch_style.focused.border_color = accent;
+        toggle_switch_style.pressed.border_color = accent;
+        toggle_switch_style.pressed.background_color = [0.25, 0.27, 0.3, 1.0];
+        toggle_switch_style.disabled.background_color = [0.2, 0.21, 0.23, 1.0];
+        toggle_switch_style.disabled.border_color = [0.3, 0.31, 0.33, 1.0];
+        toggle_switch_style.disabled.text_color = disabled_text;
+
+        let mut scroll_view_style = light.scroll_view_style.clone();
+        scroll_view_style.background_color = field;
+        scroll_view_style.border_color = [0.3, 0.32, 0.36, 1.0];
+        scroll_view_style.text_color = [1.0, 1.0, 1.0, 0.35];
+
+        let mut progress_bar_style = light.progress_bar_style.clone();
+        for state in STYLE_STATES {
+            let style = progress_bar_style.get_style_mut(state);
+            style.background_color = [0.2, 0.21, 0.23, 1.0];
+            style.border_color = border;
+            style.text_color = [0.3, 0.75, 0.45, 1.0];
+        }
+        progress_bar_style.disabled.background_color = disabled_field;
+        progress_bar_style.disabled.text_color = [0.4, 0.42, 0.45, 1.0];
+
+        let mut list_view_style = light.list_view_style.clone();
+        list_view_style.background_color = field;
+        list_view_style.border_color = [0.3, 0.32, 0.36, 1.0];
+        list_view_style.text_color = text;
+
         Self {
             button_style,
             panel_style,
+            panel_dim_color: [0.0, 0.0, 0.0, 0.6],
             label_style,
+            text_input_style,
+            slider_style,
+            radio_button_style: checkbox_style.clone(),
+            checkbox_style,
+            toggle_switch_style,
+            toggle_switch_on_color: [0.25, 0.65, 0.4, 1.0],
+            toggle_switch_knob_color: [0.92, 0.93, 0.95, 1.0],
+            scroll_view_style,
+            progress_bar_style,
+            progress_bar_text_color: [0.95, 0.95, 0.95, 1.0],
+            // The curve editor is dark in both themes.
+            curve_editor_style: light.curve_editor_style,
+            list_view_style,
+            list_view_hover_color: [0.2, 0.22, 0.26, 1.0],
+            list_view_selection_color: accent,
+            list_view_selected_text_color: [1.0, 1.0, 1.0, 1.0],
+            focus_ring_color: accent,
+            disabled_saturation: 0.0,
+            disabled_opacity: 0.45,
+            font_fallbacks: Vec::new(),
+        }
+    }
+
+    fn curve_editor_style() -> StyleSet {
+        let mut normal = UIStyle::new();
+        normal.background_color = [0.15, 0.15, 0.17, 1.0];
+        normal.border_color = [0.35, 0.35, 0.4, 1.0];
+        normal.border_width = 1.0;
+        normal.text_color = [0.3, 0.7, 1.0, 1.0];
+
+        let mut hovered = normal.clone();
+        hovered.border_color = [0.5, 0.5, 0.58, 1.0];
+
+        let mut pressed = hovered.clone();
+        pressed.text_color = [0.45, 0.8, 1.0, 1.0];
+
+        let mut disabled = normal.clone();
+        disabled.background_color = [0.22, 0.22, 0.22, 1.0];
+        disabled.text_color = [0.5, 0.5, 0.5, 1.0];
+
+        StyleSet {
+            normal,
+            hovered: hovered.clone(),
+            pressed,
+            focused: hovered,
+            disabled,
+        }
+    }
+
+    /// Set one color by key, for building themes from scripts.
+    ///
+    /// Keys are `<widget>.<part>` or `<widget>.<state>.<part>`, e.g.
+    /// `"button.hovered.background"` or `"panel.border"`. Parts are
+    /// `background`, `border` and `text`; leaving out the state sets every
+    /// state. Widget-specific colors are `panel.dim`, `toggle_switch.on`,
+    /// `toggle_switch.knob`, `progress_bar.label`, `list_view.hover`,
+    /// `list_view.selection` and `list_view.selected_text`, plus
+    /// `focus_ring` for the keyboard focus outline.
+    pub fn set_color(&mut self, key: &str, color: [f32; 4]) -> Result<(), String> {
+        let extra = match key {
+            "panel.dim" => Some(&mut self.panel_dim_color),
+            "toggle_switch.on" => Some(&mut self.toggle_switch_on_color),
+            "toggle_switch.knob" => Some(&mut self.toggle_switch_knob_color),
+            "progress_bar.label" => Some(&mut self.progress_bar_text_color),
+            "list_view.hover" => Some(&mut self.list_view_hover_color),
+            "list_view.selection" => Some(&mut self.list_view_selection_color),
+            "list_view.selected_text" => Some(&mut self.list_view_selected_text_color),
+            "focus_ring" => Some(&mut self.focus_ring_color),
+            _ => None,
+        };
+        if let Some(extra) = extra {
+            *extra = color;
+            return Ok(());
+        }
+
+        let invalid = || format!("Unknown theme color key '{key}'");
+        let (target, part) = key.rsplit_once('.').ok_or_else(invalid)?;
+        let (widget, state) = match target.split_once('.') {
+            Some((widget, state)) => (widget, Some(parse_style_state(state).ok_or_else(invalid)?)),
+            None => (target, None),
+        };
+        let style_set = match widget {
+            "button" => Some(&mut self.button_style),
+            "text_input" => Some(&mut self.text_input_style),
+            "slider" => Some(&mut self.slider_style),
+            "checkbox" => Some(&mut self.checkbox_style),
+            "radio_button" => Some(&mut self.radio_button_style),
+            "toggle_switch" => Some(&mut self.toggle_switch_style),
+            "progress_bar" => Some(&mut self.progress_bar_style),
+            "curve_editor" => Some(&mut self.curve_editor_style),
+            _ => None,
+        };
+        let styles: Vec<&mut UIStyle> = match (style_set, state) {
+            (Some(style_set), Some(state)) => vec![style_set.get_style_mut(state)],
+            (Some(style_set), None) => vec![
+                &mut style_set.normal,
+                &mut style_set.hovered,
+                &mut style_set.pressed,
+                &mut style_set.focused,
+                &mut style_set.disabled,
+            ],
+            (None, None) => match widget {
+                "panel" => vec![&mut self.panel_style],
+                "label" => vec![&mut self.label_style],
+                "scroll_view" => vec![&mut self.scroll_view_style],
+                "list_view" => vec![&mut self.list_view_style],
+                _ => return Err(invalid()),
+            },
+            (None, Some(_)) => return Err(invalid()),
+        };
+
+        for style in styles {
+            match part {
+                "background" => style.background_color = color,
+                "border" => style.border_color = color,
+                "text" => style.text_color = color,
+                _ => return Err(invalid()),
+            }
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn parse_style_state(state: &str) -> Option<StyleState> {
+    match state {
+        "normal" => Some(StyleState::Normal),
+        "hovered" => Some(StyleState::Hovered),
+        "pressed" => Some(StyleState::Pressed),
+        "focused" => Some(StyleState::Focused),
+        "disabled" => Some(StyleState::Disabled),
+        _ => None,
+    }
+}
+
+/// Outline drawn around the focused widget.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FocusRing {
+    /// Line width; 0 hides the ring
+    pub width: f32,
+    /// Gap between the widget bounds and the ring
+    pub offset: f32,
+    pub corner_radius: f32,
+}
+
+impl Default for FocusRing {
+    fn default() -> Self {
+        Self {
+            width: 2.0,
+            offset: 2.0,
+            corner_radius: 4.0,
+        }
+    }
+}
+
+impl FocusRing {
+    /// Draw the ring around `bounds`.
+    pub fn render(&self, draw_manager: &mut DrawManager, bounds: Rect, color: [f32; 4], depth: f32) {
+        if self.width <= 0.0 || color[3] <= 0.0 {
+            return;
+        }
+        let ring = Rect::new(
+            bounds.x - self.offset,
+            bounds.y - self.offset,
+            bounds.width + self.offset * 2.0,
+            bounds.height + self.offset * 2.0,
+        );
+        if ring.width > 0.0 && ring.height > 0.0 {
+            stroke_box(draw_manager, ring, self.corner_radius, color, self.width, depth);
         }
     }
 }
@@ -258,3 +1016,92 @@ impl Default for UITheme {
         Self::default_light()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::draw_manager::DrawCommand;
+
+    #[test]
+    fn test_retheme_keeps_custom_colors() {
+        let light = UITheme::default_light();
+        let dark = UITheme::default_dark();
+        let mut style = light.checkbox_style.clone();
+        style.normal.text_color = [1.0, 0.0, 0.0, 1.0];
+
+        style.retheme(&light.checkbox_style, &dark.checkbox_style);
+        assert_eq!(style.normal.background_color, dark.checkbox_style.normal.background_color);
+        assert_eq!(style.hovered.border_color, dark.checkbox_style.hovered.border_color);
+        assert_eq!(style.normal.text_color, [1.0, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_set_color_keys() {
+        let mut theme = UITheme::default_light();
+        let red = [1.0, 0.0, 0.0, 1.0];
+        theme.set_color("button.hovered.background", red).unwrap();
+        assert_eq!(theme.button_style.hovered.background_color, red);
+        assert_ne!(theme.button_style.normal.background_color, red);
+
+        theme.set_color("slider.text", red).unwrap();
+        assert!(STYLE_STATES.iter().all(|state| theme.slider_style.get_style(*state).text_color == red));
+
+        theme.set_color("list_view.selection", red).unwrap();
+        assert_eq!(theme.list_view_selection_color, red);
+
+        assert!(theme.set_color("panel.hovered.background", red).is_err());
+        assert!(theme.set_color("button.shadow", red).is_err());
+        assert!(theme.set_color("window.background", red).is_err());
+    }
+
+    #[test]
+    fn test_style_set_font_settings_apply_to_every_state() {
+        let mut style = UITheme::default_light().button_style;
+        style.set_font_size(22.0);
+        style.set_font_path(Some("fonts/Title.ttf".to_string()));
+        style.set_font_weight(FontWeight::Bold);
+        style.set_letter_spacing(1.5);
+
+        for state in STYLE_STATES {
+            let text_style = &style.get_style(state).text_style;
+            assert_eq!(text_style.font_size, 22.0);
+            assert_eq!(text_style.font.path(), Some("fonts/Title.ttf"));
+            assert_eq!(text_style.font.weight(), FontWeight::Bold);
+            assert_eq!(text_style.letter_spacing, 1.5);
+        }
+    }
+
+    #[test]
+    fn test_render_box_shapes() {
+        let bounds = Rect::new(10.0, 20.0, 100.0, 40.0);
+        let kinds = |style: &UIStyle| {
+            let mut draw_manager = DrawManager::new();
+            style.render_box(&mut draw_manager, bounds, 1.0);
+            draw_manager.commands().iter().map(|command| command.kind()).collect::<Vec<_>>()
+        };
+
+        // Flat boxes draw exactly as before.
+        let mut style = UIStyle::new();
+        style.border_width = 1.0;
+        assert_eq!(kinds(&style), vec!["rectangle", "rectangle"]);
+
+        style.border_radius = 8.0;
+        style.background_gradient = Some([0.0, 0.0, 0.0, 1.0]);
+        style.set_shadow([0.0, 0.0, 0.0, 0.5], (0.0, 4.0), 4.0);
+        assert_eq!(
+            kinds(&style),
+            vec!["polygon", "polygon", "polygon", "gradient_rect", "polygon", "polygon"]
+        );
+
+        // The radius is limited to half the shorter side, so corners stay on the box.
+        style.border_radius = 500.0;
+        let mut draw_manager = DrawManager::new();
+        style.render_box(&mut draw_manager, bounds, 1.0);
+        let Some(DrawCommand::Polygon { points, .. }) = draw_manager.commands().last() else {
+            panic!("expected a rounded border");
+        };
+        assert!(points.iter().all(|point| {
+            (10.0..=110.0).contains(&point.x()) && (20.0 - 1e-4..=60.0 + 1e-4).contains(&point.y())
+        }));
+    }
+}
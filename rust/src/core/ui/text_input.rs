@@ -0,0 +1,839 @@
+use super::{Rect, StyleState, UIComponentTrait};
+use super::event::UIEvent;
+use super::style::{StyleSet, UIStyle, UITheme};
+use crate::core::clipboard;
+use crate::core::component::{ComponentTrait, next_component_id};
+use crate::core::draw_manager::DrawManager;
+use crate::core::logging;
+use crate::core::text::{FontStyle, FontWeight, TextAlign, TextLayoutOptions, VerticalTextAlign};
+use crate::core::time::Time;
+use crate::types::color::Color;
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+use winit::keyboard::{Key, NamedKey};
+
+type TextCallback = Arc<Mutex<Option<Box<dyn FnMut(&str) + Send + Sync>>>>;
+
+/// Single-line text input UI component.
+///
+/// Gains keyboard focus when clicked and loses it when another component or
+/// empty space is clicked. While focused it handles typed text, caret
+/// movement (Left/Right/Home/End), Shift-selection, Ctrl+A, Backspace,
+/// Delete, and copy, cut and paste through the system clipboard
+/// (Ctrl+C/X/V). Enter fires the submit callback. Input method (IME)
+/// compositions are shown underlined at the caret until committed.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyg_engine_native::core::ui::text_input::TextInputComponent;
+///
+/// let mut input = TextInputComponent::new("NameInput")
+///     .with_bounds(100.0, 100.0, 240.0, 32.0)
+///     .with_placeholder("Player name");
+///
+/// input.set_on_submit(|text| println!("Submitted: {text}"));
+/// ```
+#[derive(Clone)]
+pub struct TextInputComponent {
+    component_id: u32,
+    name: String,
+    bounds: Rect,
+    style: StyleSet,
+    current_state: StyleState,
+    text: String,
+    placeholder: String,
+    /// Caret position as a character index into `text`.
+    caret: usize,
+    /// Other end of the selection (character index); `None` when nothing is selected.
+    selection_anchor: Option<usize>,
+    /// First visible character when the text is wider than the box.
+    scroll_offset: usize,
+    /// Uncommitted input method text shown at the caret.
+    composition: String,
+    /// Character range of the input method's cursor within `composition`.
+    composition_cursor: Option<(usize, usize)>,
+    max_length: Option<usize>,
+    on_changed: TextCallback,
+    on_submit: TextCallback,
+    is_hovered: bool,
+    is_focused: bool,
+    enabled: bool,
+    enabled_in_hierarchy: bool,
+    depth: f32,
+}
+
+impl std::fmt::Debug for TextInputComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TextInputComponent")
+            .field("name", &self.name)
+            .field("bounds", &self.bounds)
+            .field("text", &self.text)
+            .field("caret", &self.caret)
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+impl TextInputComponent {
+    /// Create a new empty text input with the default style.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            component_id: next_component_id(),
+            name: name.into(),
+            bounds: Rect::new(0.0, 0.0, 200.0, 30.0),
+            style: UITheme::light().text_input_style.clone(),
+            current_state: StyleState::Normal,
+            text: String::new(),
+            placeholder: String::new(),
+            caret: 0,
+            selection_anchor: None,
+            scroll_offset: 0,
+            composition: String::new(),
+            composition_cursor: None,
+            max_length: None,
+            on_changed: Arc::new(Mutex::new(None)),
+            on_submit: Arc::new(Mutex::new(None)),
+            is_hovered: false,
+            is_focused: false,
+            enabled: true,
+            enabled_in_hierarchy: true,
+            depth: 0.0,
+        }
+    }
+
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.set_text(text);
+        self
+    }
+
+    pub fn with_bounds(mut self, x: f32, y: f32, width: f32, height: f32) -> Self {
+        self.bounds = Rect::new(x, y, width, height);
+        self
+    }
+
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    pub fn with_style(mut self, style: StyleSet) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_depth(mut self, depth: f32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Replace the text without firing `on_changed`. Moves the caret to the end.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = text.into();
+        if let Some(max_length) = self.max_length {
+            self.text = self.text.chars().take(max_length).collect();
+        }
+        self.caret = self.char_count();
+        self.selection_anchor = None;
+        self.scroll_offset = 0;
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn set_placeholder(&mut self, placeholder: impl Into<String>) {
+        self.placeholder = placeholder.into();
+    }
+
+    pub fn placeholder(&self) -> &str {
+        &self.placeholder
+    }
+
+    /// Limit the number of characters, or `None` for no limit. Truncates existing text.
+    pub fn set_max_length(&mut self, max_length: Option<usize>) {
+        self.max_length = max_length;
+        if let Some(max_length) = max_length
+            && self.char_count() > max_length
+        {
+            self.text = self.text.chars().take(max_length).collect();
+            self.caret = self.caret.min(max_length);
+            self.selection_anchor = self.selection_anchor.map(|anchor| anchor.min(max_length));
+        }
+    }
+
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// Caret position as a character index.
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+
+    /// Uncommitted input method text shown at the caret; empty when the
+    /// input method is not composing.
+    pub fn composition(&self) -> &str {
+        &self.composition
+    }
+
+    /// Selected character range `(start, end)`, if any.
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        let anchor = self.selection_anchor?;
+        (anchor != self.caret).then(|| (anchor.min(self.caret), anchor.max(self.caret)))
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.is_focused = false;
+        }
+        self.update_state();
+    }
+
+    pub fn set_style(&mut self, style: StyleSet) {
+        self.style = style;
+    }
+
+    fn for_each_style(&mut self, mut apply: impl FnMut(&mut UIStyle)) {
+        for state in [
+            StyleState::Normal,
+            StyleState::Hovered,
+            StyleState::Pressed,
+            StyleState::Focused,
+            StyleState::Disabled,
+        ] {
+            apply(self.style.get_style_mut(state));
+        }
+    }
+
+    pub fn set_font_size(&mut self, font_size: f32) {
+        self.for_each_style(|style| style.set_font_size(font_size));
+    }
+
+    pub fn set_font_path(&mut self, font_path: Option<String>) {
+        self.for_each_style(|style| style.set_font_path(font_path.clone()));
+    }
+
+    pub fn set_font_family(&mut self, font_family: Option<String>) {
+        self.for_each_style(|style| style.set_font_family(font_family.clone()));
+    }
+
+    pub fn set_font_weight(&mut self, font_weight: FontWeight) {
+        self.for_each_style(|style| style.set_font_weight(font_weight));
+    }
+
+    pub fn set_font_style(&mut self, font_style: FontStyle) {
+        self.for_each_style(|style| style.set_font_style(font_style));
+    }
+
+    pub fn set_kerning(&mut self, kerning: bool) {
+        self.for_each_style(|style| style.set_kerning(kerning));
+    }
+
+    /// Called with the new text whenever the user edits it.
+    pub fn set_on_changed<F>(&mut self, callback: F)
+    where
+        F: FnMut(&str) + Send + Sync + 'static,
+    {
+        *self.on_changed.lock().unwrap() = Some(Box::new(callback));
+    }
+
+    /// Called with the current text when Enter is pressed.
+    pub fn set_on_submit<F>(&mut self, callback: F)
+    where
+        F: FnMut(&str) + Send + Sync + 'static,
+    {
+        *self.on_submit.lock().unwrap() = Some(Box::new(callback));
+    }
+
+    fn fire(callback: &TextCallback, text: &str) {
+        if let Ok(mut guard) = callback.lock()
+            && let Some(callback) = guard.as_mut()
+        {
+            callback(text);
+        }
+    }
+
+    fn char_count(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(char_index)
+            .map_or(self.text.len(), |(index, _)| index)
+    }
+
+    fn selected_text(&self) -> Option<&str> {
+        let (start, end) = self.selection()?;
+        Some(&self.text[self.byte_index(start)..self.byte_index(end)])
+    }
+
+    /// Remove the selected text. Returns true if anything was removed.
+    fn delete_selection(&mut self) -> bool {
+        let Some((start, end)) = self.selection() else {
+            self.selection_anchor = None;
+            return false;
+        };
+        let (start_byte, end_byte) = (self.byte_index(start), self.byte_index(end));
+        self.text.replace_range(start_byte..end_byte, "");
+        self.caret = start;
+        self.selection_anchor = None;
+        true
+    }
+
+    /// Insert typed text at the caret, replacing any selection.
+    pub fn insert_text(&mut self, text: &str) -> bool {
+        let mut changed = self.delete_selection();
+        let filtered: String = text.chars().filter(|ch| !ch.is_control()).collect();
+        let room = self
+            .max_length
+            .map_or(usize::MAX, |max| max.saturating_sub(self.char_count()));
+        let inserted: String = filtered.chars().take(room).collect();
+        if !inserted.is_empty() {
+            let byte = self.byte_index(self.caret);
+            self.text.insert_str(byte, &inserted);
+            self.caret += inserted.chars().count();
+            changed = true;
+        }
+        changed
+    }
+
+    fn move_caret(&mut self, position: usize, extend_selection: bool) {
+        if extend_selection {
+            self.selection_anchor.get_or_insert(self.caret);
+        } else {
+            self.selection_anchor = None;
+        }
+        self.caret = position.min(self.char_count());
+    }
+
+    /// Apply a key press. Returns `(handled, text_changed)`.
+    fn handle_key(&mut self, key: &Key, shift: bool, ctrl: bool) -> (bool, bool) {
+        match key {
+            Key::Named(NamedKey::Backspace) => {
+                if self.delete_selection() {
+                    return (true, true);
+                }
+                if self.caret == 0 {
+                    return (true, false);
+                }
+                let start = self.byte_index(self.caret - 1);
+                let end = self.byte_index(self.caret);
+                self.text.replace_range(start..end, "");
+                self.caret -= 1;
+                (true, true)
+            }
+            Key::Named(NamedKey::Delete) => {
+                if self.delete_selection() {
+                    return (true, true);
+                }
+                if self.caret >= self.char_count() {
+                    return (true, false);
+                }
+                let start = self.byte_index(self.caret);
+                let end = self.byte_index(self.caret + 1);
+                self.text.replace_range(start..end, "");
+                (true, true)
+            }
+            Key::Named(NamedKey::ArrowLeft) => {
+                let target = match (self.selection(), shift) {
+                    (Some((start, _)), false) => start,
+                    _ => self.caret.saturating_sub(1),
+                };
+                self.move_caret(target, shift);
+                (true, false)
+            }
+            Key::Named(NamedKey::ArrowRight) => {
+                let target = match (self.selection(), shift) {
+                    (Some((_, end)), false) => end,
+                    _ => self.caret + 1,
+                };
+                self.move_caret(target, shift);
+                (true, false)
+            }
+            Key::Named(NamedKey::Home) => {
+                self.move_caret(0, shift);
+                (true, false)
+            }
+            Key::Named(NamedKey::End) => {
+                self.move_caret(self.char_count(), shift);
+                (true, false)
+            }
+            Key::Named(NamedKey::Enter) => {
+                Self::fire(&self.on_submit, &self.text);
+                (true, false)
+            }
+            Key::Character(ch) if ctrl && ch.eq_ignore_ascii_case("a") => {
+                self.selection_anchor = Some(0);
+                self.caret = self.char_count();
+                (true, false)
+            }
+            Key::Character(ch) if ctrl && (ch.eq_ignore_ascii_case("c") || ch.eq_ignore_ascii_case("x")) => {
+                let Some(selected) = self.selected_text() else {
+                    return (true, false);
+                };
+                if let Err(err) = clipboard::set_text(selected) {
+                    logging::log_warn(&err);
+                    return (true, false);
+                }
+                let cut = ch.eq_ignore_ascii_case("x");
+                (true, cut && self.delete_selection())
+            }
+            Key::Character(ch) if ctrl && ch.eq_ignore_ascii_case("v") => match clipboard::get_text() {
+                Ok(text) => (true, self.insert_text(&text)),
+                Err(err) => {
+                    logging::log_warn(&err);
+                    (true, false)
+                }
+            },
+            _ => (false, false),
+        }
+    }
+
+    fn update_state(&mut self) {
+        self.current_state = if !(self.enabled && self.enabled_in_hierarchy) {
+            StyleState::Disabled
+        } else if self.is_focused {
+            StyleState::Focused
+        } else if self.is_hovered {
+            StyleState::Hovered
+        } else {
+            StyleState::Normal
+        };
+    }
+
+    /// Estimated width of one character using font8x8 metrics (8px base glyph width).
+    fn glyph_width(&self) -> f32 {
+        let font_size = self.style.get_style(self.current_state).font_size();
+        8.0 * (font_size / 8.0).max(1.0).round()
+    }
+
+    fn inner_rect(&self) -> Rect {
+        let padding = self.style.get_style(self.current_state).padding;
+        Rect::new(
+            self.bounds.x + padding.left,
+            self.bounds.y + padding.top,
+            (self.bounds.width - padding.left - padding.right).max(0.0),
+            (self.bounds.height - padding.top - padding.bottom).max(0.0),
+        )
+    }
+
+    /// Number of characters that fit in the text area.
+    fn visible_chars(&self) -> usize {
+        ((self.inner_rect().width / self.glyph_width()).floor() as usize).max(1)
+    }
+
+    /// Text as shown, with any composition inserted at the caret.
+    fn display_text(&self) -> String {
+        let mut text = self.text.clone();
+        text.insert_str(self.byte_index(self.caret), &self.composition);
+        text
+    }
+
+    /// Caret position in `display_text`: the input method's cursor while
+    /// composing.
+    fn display_caret(&self) -> usize {
+        let composed = self
+            .composition_cursor
+            .map_or(self.composition.chars().count(), |(_, end)| end);
+        self.caret + composed
+    }
+
+    /// Scroll horizontally so the caret stays inside the box.
+    fn scroll_to_caret(&mut self) {
+        let visible = self.visible_chars();
+        let caret = self.display_caret();
+        if caret < self.scroll_offset {
+            self.scroll_offset = caret;
+        } else if caret > self.scroll_offset + visible {
+            self.scroll_offset = caret - visible;
+        }
+        self.scroll_offset = self
+            .scroll_offset
+            .min(self.char_count() + self.composition.chars().count());
+    }
+
+    /// Caret rectangle relative to the component's parent, like `bounds`.
+    /// Used to place the input method's candidate window next to the caret.
+    pub fn caret_rect(&self) -> Rect {
+        let inner = self.inner_rect();
+        let column = self.display_caret().saturating_sub(self.scroll_offset);
+        Rect::new(inner.x + column as f32 * self.glyph_width(), inner.y, 1.5, inner.height)
+    }
+
+    /// Map a screen x coordinate to a caret index.
+    fn caret_at(&self, x: f32) -> usize {
+        let relative = (x - self.inner_rect().x) / self.glyph_width();
+        let index = self.scroll_offset as f32 + relative.max(0.0);
+        (index.round() as usize).min(self.char_count())
+    }
+}
+
+impl ComponentTrait for TextInputComponent {
+    fn new(name: String) -> Self {
+        Self::new(name)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn id(&self) -> u32 {
+        self.component_id
+    }
+
+    fn component_type(&self) -> &'static str {
+        "TextInput"
+    }
+
+    fn is_enabled_self(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled_self(&mut self, enabled: bool) {
+        self.set_enabled(enabled);
+    }
+
+    fn is_enabled_in_hierarchy(&self) -> bool {
+        self.enabled_in_hierarchy
+    }
+
+    fn set_enabled_in_hierarchy(&mut self, enabled: bool) {
+        self.enabled_in_hierarchy = enabled;
+        self.update_state();
+    }
+
+    fn update(&self, _time: &Time) {}
+    fn fixed_update(&self, _time: &Time, _fixed_time: f32) {}
+    fn on_start(&self) {}
+    fn on_destroy(&self) {}
+    fn on_enable(&self) {}
+    fn on_disable(&self) {}
+
+    fn clone_component(&self) -> Box<dyn ComponentTrait> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl UIComponentTrait for TextInputComponent {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn handle_event(&mut self, event: &UIEvent) -> bool {
+        if !(self.enabled && self.enabled_in_hierarchy) {
+            return false;
+        }
+
+        let handled = match event {
+            UIEvent::MouseEnter { .. } => {
+                self.is_hovered = true;
+                true
+            }
+            UIEvent::MouseExit { .. } => {
+                self.is_hovered = false;
+                true
+            }
+            UIEvent::FocusGained => {
+                self.is_focused = true;
+                true
+            }
+            UIEvent::FocusLost => {
+                self.is_focused = false;
+                self.selection_anchor = None;
+                self.composition.clear();
+                self.composition_cursor = None;
+                true
+            }
+            UIEvent::MouseDown { x, .. } => {
+                let position = self.caret_at(*x as f32);
+                self.move_caret(position, false);
+                true
+            }
+            // The input method owns the keyboard while composing.
+            UIEvent::KeyDown { .. } if !self.composition.is_empty() => true,
+            UIEvent::KeyDown { key, shift, ctrl } => {
+                let (handled, changed) = self.handle_key(key, *shift, *ctrl);
+                if changed {
+                    Self::fire(&self.on_changed, &self.text);
+                }
+                handled
+            }
+            UIEvent::TextInput { text } => {
+                if self.insert_text(text) {
+                    Self::fire(&self.on_changed, &self.text);
+                }
+                true
+            }
+            UIEvent::TextComposition { text, cursor } => {
+                // Composing replaces the selection, like typing would.
+                if !text.is_empty() && self.delete_selection() {
+                    Self::fire(&self.on_changed, &self.text);
+                }
+                self.composition.clone_from(text);
+                self.composition_cursor = *cursor;
+                true
+            }
+            _ => false,
+        };
+
+        self.scroll_to_caret();
+        self.update_state();
+        handled
+    }
+
+    fn render(&self, draw_manager: &mut DrawManager, offset: (f32, f32)) {
+        let style = self.style.get_style(self.current_state);
+        let x = self.bounds.x + offset.0;
+        let y = self.bounds.y + offset.1;
+        let inner = self.inner_rect();
+        let inner_x = inner.x + offset.0;
+        let inner_y = inner.y + offset.1;
+        let glyph_width = self.glyph_width();
+        let to_color = |rgba: [f32; 4]| Color::new(rgba[0], rgba[1], rgba[2], rgba[3]);
+
+        if style.background_color[3] > 0.0 {
+            draw_manager.draw_rectangle_with_options(
+                x,
+                y,
+                self.bounds.width,
+                self.bounds.height,
+                to_color(style.background_color),
+                true,
+                1.0,
+                self.depth,
+            );
+        }
+
+        if style.border_width > 0.0 {
+            draw_manager.draw_rectangle_with_options(
+                x,
+                y,
+                self.bounds.width,
+                self.bounds.height,
+                to_color(style.border_color),
+                false,
+                style.border_width,
+                self.depth + 0.005,
+            );
+        }
+
+        let visible = self.visible_chars();
+        let layout = TextLayoutOptions {
+            width: Some(inner.width),
+            height: Some(inner.height),
+            horizontal_align: TextAlign::Left,
+            vertical_align: VerticalTextAlign::Center,
+            wrap: false,
+        };
+
+        if self.text.is_empty() && self.composition.is_empty() {
+            if !self.placeholder.is_empty() && !self.is_focused {
+                let mut placeholder_color = style.text_color;
+                placeholder_color[3] *= 0.45;
+                draw_manager.draw_text_with_options(
+                    self.placeholder.chars().take(visible).collect::<String>(),
+                    inner_x,
+                    inner_y,
+                    style.text_style.clone(),
+                    to_color(placeholder_color),
+                    layout,
+                    self.depth + 0.01,
+                );
+            }
+        } else {
+            if let Some((start, end)) = self.selection() {
+                let start = start.max(self.scroll_offset);
+                let end = end.min(self.scroll_offset + visible);
+                if end > start {
+                    draw_manager.draw_rectangle_with_options(
+                        inner_x + (start - self.scroll_offset) as f32 * glyph_width,
+                        inner_y,
+                        (end - start) as f32 * glyph_width,
+                        inner.height,
+                        Color::new(0.2, 0.5, 0.9, 0.35),
+                        true,
+                        1.0,
+                        self.depth + 0.008,
+                    );
+                }
+            }
+
+            if !self.composition.is_empty() {
+                let start = self.caret.max(self.scroll_offset);
+                let end = (self.caret + self.composition.chars().count()).min(self.scroll_offset + visible);
+                if end > start {
+                    draw_manager.draw_rectangle_with_options(
+                        inner_x + (start - self.scroll_offset) as f32 * glyph_width,
+                        inner_y + inner.height - 2.0,
+                        (end - start) as f32 * glyph_width,
+                        1.0,
+                        to_color(style.text_color),
+                        true,
+                        1.0,
+                        self.depth + 0.012,
+                    );
+                }
+            }
+
+            let shown: String = self
+                .display_text()
+                .chars()
+                .skip(self.scroll_offset)
+                .take(visible)
+                .collect();
+            draw_manager.draw_text_with_options(
+                shown,
+                inner_x,
+                inner_y,
+                style.text_style.clone(),
+                to_color(style.text_color),
+                layout,
+                self.depth + 0.01,
+            );
+        }
+
+        if self.is_focused {
+            let caret_x = inner_x + self.display_caret().saturating_sub(self.scroll_offset) as f32 * glyph_width;
+            draw_manager.draw_rectangle_with_options(
+                caret_x,
+                inner_y,
+                1.5,
+                inner.height,
+                to_color(style.text_color),
+                true,
+                1.0,
+                self.depth + 0.02,
+            );
+        }
+    }
+
+    fn ui_depth(&self) -> f32 {
+        self.depth
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled && self.enabled_in_hierarchy
+    }
+
+    fn apply_theme(&mut self, old: &UITheme, new: &UITheme) {
+        self.style.retheme(&old.text_input_style, &new.text_input_style);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(named: NamedKey) -> UIEvent {
+        UIEvent::KeyDown { key: Key::Named(named), shift: false, ctrl: false }
+    }
+
+    #[test]
+    fn test_typing_and_deleting() {
+        let mut input = TextInputComponent::new("Input");
+        input.handle_event(&UIEvent::TextInput { text: "helo".to_string() });
+        input.handle_event(&key(NamedKey::ArrowLeft));
+        input.handle_event(&UIEvent::TextInput { text: "l".to_string() });
+        assert_eq!(input.text(), "hello");
+        assert_eq!(input.caret(), 4);
+
+        input.handle_event(&key(NamedKey::Delete));
+        input.handle_event(&key(NamedKey::Home));
+        input.handle_event(&key(NamedKey::Backspace));
+        input.handle_event(&key(NamedKey::Delete));
+        assert_eq!(input.text(), "ell");
+    }
+
+    #[test]
+    fn test_selection_replace_and_max_length() {
+        let mut input = TextInputComponent::new("Input").with_text("héllo");
+        input.set_max_length(Some(6));
+        input.handle_event(&UIEvent::KeyDown {
+            key: Key::Character("a".into()),
+            shift: false,
+            ctrl: true,
+        });
+        assert_eq!(input.selection(), Some((0, 5)));
+
+        input.handle_event(&UIEvent::TextInput { text: "worlds!".to_string() });
+        assert_eq!(input.text(), "worlds");
+        assert_eq!(input.selection(), None);
+    }
+
+    #[test]
+    fn test_composition_shows_at_caret_until_committed() {
+        let mut input = TextInputComponent::new("Input").with_text("ab");
+        input.move_caret(1, false);
+        input.handle_event(&UIEvent::TextComposition {
+            text: "にほ".to_string(),
+            cursor: Some((2, 2)),
+        });
+        assert_eq!(input.text(), "ab");
+        assert_eq!(input.display_text(), "aにほb");
+        assert_eq!(input.display_caret(), 3);
+
+        // Keys go to the input method while it composes.
+        input.handle_event(&key(NamedKey::Backspace));
+        assert_eq!(input.text(), "ab");
+
+        input.handle_event(&UIEvent::TextComposition { text: String::new(), cursor: None });
+        input.handle_event(&UIEvent::TextInput { text: "日本".to_string() });
+        assert_eq!(input.text(), "a日本b");
+        assert_eq!(input.caret(), 3);
+        assert_eq!(input.composition(), "");
+    }
+
+    #[test]
+    fn test_callbacks_fire_on_edit_and_submit() {
+        let changed = Arc::new(Mutex::new(Vec::new()));
+        let submitted = Arc::new(Mutex::new(None));
+        let mut input = TextInputComponent::new("Input");
+        let changed_log = Arc::clone(&changed);
+        input.set_on_changed(move |text| changed_log.lock().unwrap().push(text.to_string()));
+        let submitted_log = Arc::clone(&submitted);
+        input.set_on_submit(move |text| *submitted_log.lock().unwrap() = Some(text.to_string()));
+
+        input.handle_event(&UIEvent::TextInput { text: "ok".to_string() });
+        input.handle_event(&key(NamedKey::ArrowLeft));
+        input.handle_event(&key(NamedKey::Enter));
+
+        assert_eq!(*changed.lock().unwrap(), vec!["ok".to_string()]);
+        assert_eq!(submitted.lock().unwrap().as_deref(), Some("ok"));
+    }
+}
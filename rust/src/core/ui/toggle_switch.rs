@@ -0,0 +1,489 @@
+use super::{Rect, StyleState, UIComponentTrait};
+use super::event::UIEvent;
+use super::style::{StyleSet, UIStyle, UITheme, retheme_color};
+use crate::core::component::{ComponentTrait, next_component_id};
+use crate::core::draw_manager::DrawManager;
+use crate::core::input_manager::MouseButtonType;
+use crate::core::text::{FontStyle, FontWeight, TextAlign, TextLayoutOptions, VerticalTextAlign};
+use crate::core::time::Time;
+use crate::types::color::Color;
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+use winit::keyboard::{Key, NamedKey};
+
+use super::checkbox::{LABEL_GAP, ToggledCallback};
+
+/// On/off switch with an optional text label to its right.
+///
+/// Behaves like [`CheckboxComponent`](super::checkbox::CheckboxComponent)
+/// but draws a pill-shaped track with a sliding knob.
+///
+/// Styling uses the regular [`StyleSet`] fields: `background_color` and the
+/// border draw the track while off, `text_color` draws the label, and
+/// `on_color` fills the track while on.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyg_engine_native::core::ui::toggle_switch::ToggleSwitchComponent;
+///
+/// let mut vsync = ToggleSwitchComponent::new("VSync")
+///     .with_label("VSync")
+///     .with_checked(true);
+///
+/// vsync.set_on_toggled(|on| println!("VSync: {on}"));
+/// ```
+#[derive(Clone)]
+pub struct ToggleSwitchComponent {
+    component_id: u32,
+    name: String,
+    bounds: Rect,
+    style: StyleSet,
+    current_state: StyleState,
+    label: String,
+    checked: bool,
+    on_color: [f32; 4],
+    knob_color: [f32; 4],
+    on_toggled: ToggledCallback,
+    is_hovered: bool,
+    is_focused: bool,
+    is_pressed: bool,
+    enabled: bool,
+    enabled_in_hierarchy: bool,
+    depth: f32,
+}
+
+impl std::fmt::Debug for ToggleSwitchComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToggleSwitchComponent")
+            .field("name", &self.name)
+            .field("bounds", &self.bounds)
+            .field("label", &self.label)
+            .field("checked", &self.checked)
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+impl ToggleSwitchComponent {
+    /// Create a new switch in the off position with the default style.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            component_id: next_component_id(),
+            name: name.into(),
+            bounds: Rect::new(0.0, 0.0, 160.0, 24.0),
+            style: UITheme::light().toggle_switch_style.clone(),
+            current_state: StyleState::Normal,
+            label: String::new(),
+            checked: false,
+            on_color: UITheme::light().toggle_switch_on_color,
+            knob_color: UITheme::light().toggle_switch_knob_color,
+            on_toggled: Arc::new(Mutex::new(None)),
+            is_hovered: false,
+            is_focused: false,
+            is_pressed: false,
+            enabled: true,
+            enabled_in_hierarchy: true,
+            depth: 0.0,
+        }
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn with_checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    pub fn with_bounds(mut self, x: f32, y: f32, width: f32, height: f32) -> Self {
+        self.bounds = Rect::new(x, y, width, height);
+        self
+    }
+
+    pub fn with_style(mut self, style: StyleSet) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_depth(mut self, depth: f32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    pub fn with_on_color(mut self, color: [f32; 4]) -> Self {
+        self.on_color = color;
+        self
+    }
+
+    /// Track fill color while the switch is on.
+    pub fn set_on_color(&mut self, color: [f32; 4]) {
+        self.on_color = color;
+    }
+
+    pub fn on_color(&self) -> [f32; 4] {
+        self.on_color
+    }
+
+    pub fn set_knob_color(&mut self, color: [f32; 4]) {
+        self.knob_color = color;
+    }
+
+    pub fn knob_color(&self) -> [f32; 4] {
+        self.knob_color
+    }
+
+    pub fn set_label(&mut self, label: impl Into<String>) {
+        self.label = label.into();
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Set the checked state without firing `on_toggled`.
+    pub fn set_checked(&mut self, checked: bool) {
+        self.checked = checked;
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    /// Flip the checked state and fire `on_toggled`, as a click would.
+    pub fn toggle(&mut self) {
+        self.checked = !self.checked;
+        if let Ok(mut guard) = self.on_toggled.lock()
+            && let Some(callback) = guard.as_mut()
+        {
+            callback(self.checked);
+        }
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.is_pressed = false;
+        }
+        self.update_state();
+    }
+
+    pub fn set_style(&mut self, style: StyleSet) {
+        self.style = style;
+    }
+
+    fn for_each_style(&mut self, mut apply: impl FnMut(&mut UIStyle)) {
+        for state in [
+            StyleState::Normal,
+            StyleState::Hovered,
+            StyleState::Pressed,
+            StyleState::Focused,
+            StyleState::Disabled,
+        ] {
+            apply(self.style.get_style_mut(state));
+        }
+    }
+
+    pub fn set_font_size(&mut self, font_size: f32) {
+        self.for_each_style(|style| style.set_font_size(font_size));
+    }
+
+    pub fn set_font_path(&mut self, font_path: Option<String>) {
+        self.for_each_style(|style| style.set_font_path(font_path.clone()));
+    }
+
+    pub fn set_font_family(&mut self, font_family: Option<String>) {
+        self.for_each_style(|style| style.set_font_family(font_family.clone()));
+    }
+
+    pub fn set_font_weight(&mut self, font_weight: FontWeight) {
+        self.for_each_style(|style| style.set_font_weight(font_weight));
+    }
+
+    pub fn set_font_style(&mut self, font_style: FontStyle) {
+        self.for_each_style(|style| style.set_font_style(font_style));
+    }
+
+    /// Called with the new checked state whenever the user flips the switch.
+    pub fn set_on_toggled<F>(&mut self, callback: F)
+    where
+        F: FnMut(bool) + Send + Sync + 'static,
+    {
+        *self.on_toggled.lock().unwrap() = Some(Box::new(callback));
+    }
+
+    fn update_state(&mut self) {
+        self.current_state = if !(self.enabled && self.enabled_in_hierarchy) {
+            StyleState::Disabled
+        } else if self.is_pressed {
+            StyleState::Pressed
+        } else if self.is_hovered {
+            StyleState::Hovered
+        } else if self.is_focused {
+            StyleState::Focused
+        } else {
+            StyleState::Normal
+        };
+    }
+}
+
+impl ComponentTrait for ToggleSwitchComponent {
+    fn new(name: String) -> Self {
+        Self::new(name)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn id(&self) -> u32 {
+        self.component_id
+    }
+
+    fn component_type(&self) -> &'static str {
+        "ToggleSwitch"
+    }
+
+    fn is_enabled_self(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled_self(&mut self, enabled: bool) {
+        self.set_enabled(enabled);
+    }
+
+    fn is_enabled_in_hierarchy(&self) -> bool {
+        self.enabled_in_hierarchy
+    }
+
+    fn set_enabled_in_hierarchy(&mut self, enabled: bool) {
+        self.enabled_in_hierarchy = enabled;
+        self.update_state();
+    }
+
+    fn update(&self, _time: &Time) {}
+    fn fixed_update(&self, _time: &Time, _fixed_time: f32) {}
+    fn on_start(&self) {}
+    fn on_destroy(&self) {}
+    fn on_enable(&self) {}
+    fn on_disable(&self) {}
+
+    fn clone_component(&self) -> Box<dyn ComponentTrait> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl UIComponentTrait for ToggleSwitchComponent {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn handle_event(&mut self, event: &UIEvent) -> bool {
+        if !(self.enabled && self.enabled_in_hierarchy) {
+            return false;
+        }
+
+        let handled = match event {
+            UIEvent::MouseEnter { .. } => {
+                self.is_hovered = true;
+                true
+            }
+            UIEvent::MouseExit { .. } => {
+                self.is_hovered = false;
+                self.is_pressed = false;
+                true
+            }
+            UIEvent::MouseDown {
+                button: MouseButtonType::Left,
+                ..
+            } => {
+                self.is_pressed = true;
+                true
+            }
+            UIEvent::MouseUp { .. } => {
+                self.is_pressed = false;
+                true
+            }
+            UIEvent::Click {
+                button: MouseButtonType::Left,
+                ..
+            } => {
+                self.toggle();
+                true
+            }
+            UIEvent::KeyDown {
+                key: Key::Named(NamedKey::Space | NamedKey::Enter),
+                ..
+            } => {
+                self.toggle();
+                true
+            }
+            UIEvent::FocusGained => {
+                self.is_focused = true;
+                true
+            }
+            UIEvent::FocusLost => {
+                self.is_focused = false;
+                true
+            }
+            _ => false,
+        };
+
+        self.update_state();
+        handled
+    }
+
+    fn render(&self, draw_manager: &mut DrawManager, offset: (f32, f32)) {
+        let style = self.style.get_style(self.current_state);
+        let x = self.bounds.x + offset.0;
+        let y = self.bounds.y + offset.1;
+        let track_height = self.bounds.height.min(24.0);
+        let track_width = (track_height * 1.8).min(self.bounds.width);
+        let track_y = y + (self.bounds.height - track_height) * 0.5;
+        let radius = track_height * 0.5;
+        let center_y = track_y + radius;
+        let to_color = |rgba: [f32; 4]| Color::new(rgba[0], rgba[1], rgba[2], rgba[3]);
+
+        let track_color = if self.checked && self.current_state != StyleState::Disabled {
+            self.on_color
+        } else {
+            style.background_color
+        };
+
+        // Pill shape: a rectangle with a half-circle cap on each end.
+        draw_manager.draw_rectangle_with_options(
+            x + radius,
+            track_y,
+            (track_width - track_height).max(0.0),
+            track_height,
+            to_color(track_color),
+            true,
+            1.0,
+            self.depth,
+        );
+        for cap_x in [x + radius, x + track_width - radius] {
+            draw_manager.draw_circle_with_options(
+                cap_x,
+                center_y,
+                radius,
+                to_color(track_color),
+                true,
+                1.0,
+                32,
+                self.depth,
+            );
+        }
+
+        let knob_radius = (radius - 2.0).max(1.0);
+        let knob_x = if self.checked {
+            x + track_width - radius
+        } else {
+            x + radius
+        };
+        if style.border_width > 0.0 {
+            draw_manager.draw_circle_with_options(
+                knob_x,
+                center_y,
+                knob_radius,
+                to_color(style.border_color),
+                false,
+                style.border_width,
+                32,
+                self.depth + 0.01,
+            );
+        }
+        draw_manager.draw_circle_with_options(
+            knob_x,
+            center_y,
+            knob_radius,
+            to_color(self.knob_color),
+            true,
+            1.0,
+            32,
+            self.depth + 0.005,
+        );
+
+        if !self.label.is_empty() {
+            let label_x = x + track_width + LABEL_GAP;
+            draw_manager.draw_text_with_options(
+                self.label.clone(),
+                label_x,
+                y,
+                style.text_style.clone(),
+                to_color(style.text_color),
+                TextLayoutOptions {
+                    width: Some((self.bounds.width - track_width - LABEL_GAP).max(0.0)),
+                    height: Some(self.bounds.height),
+                    horizontal_align: TextAlign::Left,
+                    vertical_align: VerticalTextAlign::Center,
+                    wrap: false,
+                },
+                self.depth + 0.01,
+            );
+        }
+    }
+
+    fn ui_depth(&self) -> f32 {
+        self.depth
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled && self.enabled_in_hierarchy
+    }
+
+    fn apply_theme(&mut self, old: &UITheme, new: &UITheme) {
+        self.style.retheme(&old.toggle_switch_style, &new.toggle_switch_style);
+        retheme_color(&mut self.on_color, old.toggle_switch_on_color, new.toggle_switch_on_color);
+        retheme_color(&mut self.knob_color, old.toggle_switch_knob_color, new.toggle_switch_knob_color);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_ignored_while_disabled() {
+        let toggles = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&toggles);
+        let mut switch = ToggleSwitchComponent::new("ToggleSwitch");
+        switch.set_on_toggled(move |on| log.lock().unwrap().push(on));
+
+        switch.handle_event(&UIEvent::Click { x: 4.0, y: 4.0, button: MouseButtonType::Left });
+        switch.set_enabled(false);
+        switch.handle_event(&UIEvent::Click { x: 4.0, y: 4.0, button: MouseButtonType::Left });
+
+        assert!(switch.is_checked());
+        assert_eq!(*toggles.lock().unwrap(), vec![true]);
+    }
+}
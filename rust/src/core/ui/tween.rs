@@ -0,0 +1,317 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static TWEEN_ID: AtomicU32 = AtomicU32::new(0);
+
+/// Easing curve applied to a tween's progress.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Easing {
+    #[default]
+    Linear,
+    EaseInQuad,
+    EaseOutQuad,
+    EaseInOutQuad,
+    EaseInCubic,
+    EaseOutCubic,
+    EaseInOutCubic,
+    /// Overshoots the target slightly before settling.
+    EaseOutBack,
+    /// Bounces against the target like a dropped ball.
+    EaseOutBounce,
+}
+
+impl Easing {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Linear => "linear",
+            Self::EaseInQuad => "ease_in_quad",
+            Self::EaseOutQuad => "ease_out_quad",
+            Self::EaseInOutQuad => "ease_in_out_quad",
+            Self::EaseInCubic => "ease_in",
+            Self::EaseOutCubic => "ease_out",
+            Self::EaseInOutCubic => "ease_in_out",
+            Self::EaseOutBack => "ease_out_back",
+            Self::EaseOutBounce => "ease_out_bounce",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "linear" => Some(Self::Linear),
+            "ease_in_quad" => Some(Self::EaseInQuad),
+            "ease_out_quad" => Some(Self::EaseOutQuad),
+            "ease_in_out_quad" => Some(Self::EaseInOutQuad),
+            "ease_in" | "ease_in_cubic" => Some(Self::EaseInCubic),
+            "ease_out" | "ease_out_cubic" => Some(Self::EaseOutCubic),
+            "ease_in_out" | "ease_in_out_cubic" => Some(Self::EaseInOutCubic),
+            "ease_out_back" => Some(Self::EaseOutBack),
+            "ease_out_bounce" => Some(Self::EaseOutBounce),
+            _ => None,
+        }
+    }
+
+    /// Map linear progress `t` in 0..=1 to eased progress.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::EaseInQuad => t * t,
+            Self::EaseOutQuad => 1.0 - (1.0 - t) * (1.0 - t),
+            Self::EaseInOutQuad => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+            Self::EaseInCubic => t * t * t,
+            Self::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+            Self::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Self::EaseOutBack => {
+                const C1: f32 = 1.70158;
+                const C3: f32 = C1 + 1.0;
+                1.0 + C3 * (t - 1.0).powi(3) + C1 * (t - 1.0).powi(2)
+            }
+            Self::EaseOutBounce => {
+                const N1: f32 = 7.5625;
+                const D1: f32 = 2.75;
+                if t < 1.0 / D1 {
+                    N1 * t * t
+                } else if t < 2.0 / D1 {
+                    let t = t - 1.5 / D1;
+                    N1 * t * t + 0.75
+                } else if t < 2.5 / D1 {
+                    let t = t - 2.25 / D1;
+                    N1 * t * t + 0.9375
+                } else {
+                    let t = t - 2.625 / D1;
+                    N1 * t * t + 0.984375
+                }
+            }
+        }
+    }
+}
+
+/// UI element property a tween animates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TweenProperty {
+    /// Bounds position and size. A layout on the element overrides them again
+    /// once the tween ends.
+    X,
+    Y,
+    Width,
+    Height,
+    /// Translation on top of the element's bounds and layout, kept after the
+    /// tween ends. Moves the element's children and hit area with it.
+    OffsetX,
+    OffsetY,
+    /// Alpha multiplier for the element and its children, kept after the tween ends.
+    Opacity,
+    /// Panel background color.
+    BackgroundColor,
+    /// Panel border color.
+    BorderColor,
+    /// Label text color.
+    TextColor,
+}
+
+impl TweenProperty {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::X => "x",
+            Self::Y => "y",
+            Self::Width => "width",
+            Self::Height => "height",
+            Self::OffsetX => "offset_x",
+            Self::OffsetY => "offset_y",
+            Self::Opacity => "opacity",
+            Self::BackgroundColor => "background_color",
+            Self::BorderColor => "border_color",
+            Self::TextColor => "text_color",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "x" => Some(Self::X),
+            "y" => Some(Self::Y),
+            "width" => Some(Self::Width),
+            "height" => Some(Self::Height),
+            "offset_x" => Some(Self::OffsetX),
+            "offset_y" => Some(Self::OffsetY),
+            "opacity" => Some(Self::Opacity),
+            "background_color" => Some(Self::BackgroundColor),
+            "border_color" => Some(Self::BorderColor),
+            "text_color" | "color" => Some(Self::TextColor),
+            _ => None,
+        }
+    }
+
+    /// Whether the property holds an RGBA color rather than a single number.
+    pub fn is_color(self) -> bool {
+        matches!(
+            self,
+            Self::BackgroundColor | Self::BorderColor | Self::TextColor
+        )
+    }
+}
+
+/// Animates one property of a UI element from its current (or a given)
+/// value to a target over `duration` seconds.
+///
+/// Numeric properties use the first value of the `[f32; 4]` slots.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UITween {
+    id: u32,
+    object_id: u32,
+    property: TweenProperty,
+    from: Option<[f32; 4]>,
+    to: [f32; 4],
+    duration: f32,
+    delay: f32,
+    easing: Easing,
+    elapsed: f32,
+}
+
+impl UITween {
+    pub fn new(object_id: u32, property: TweenProperty, to: [f32; 4], duration: f32) -> Self {
+        Self {
+            id: TWEEN_ID.fetch_add(1, Ordering::SeqCst) + 1,
+            object_id,
+            property,
+            from: None,
+            to,
+            duration: duration.max(0.0),
+            delay: 0.0,
+            easing: Easing::default(),
+            elapsed: 0.0,
+        }
+    }
+
+    /// Start from `from` instead of the property's value when the tween starts.
+    pub fn with_from(mut self, from: [f32; 4]) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Wait `delay` seconds before starting.
+    pub fn with_delay(mut self, delay: f32) -> Self {
+        self.delay = delay.max(0.0);
+        self
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn object_id(&self) -> u32 {
+        self.object_id
+    }
+
+    pub fn property(&self) -> TweenProperty {
+        self.property
+    }
+
+    pub fn start_value(&self) -> Option<[f32; 4]> {
+        self.from
+    }
+
+    pub fn target(&self) -> [f32; 4] {
+        self.to
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// Whether the delay has passed and the tween is changing its property.
+    pub fn is_started(&self) -> bool {
+        self.elapsed >= self.delay
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.delay + self.duration
+    }
+
+    /// Record the starting value if none was given.
+    pub fn capture_from(&mut self, current: [f32; 4]) {
+        self.from.get_or_insert(current);
+    }
+
+    pub fn advance(&mut self, delta_time: f32) {
+        self.elapsed += delta_time.max(0.0);
+    }
+
+    /// Linear progress through the tween, 0 before the delay ends and 1 when done.
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return if self.is_started() { 1.0 } else { 0.0 };
+        }
+        ((self.elapsed - self.delay) / self.duration).clamp(0.0, 1.0)
+    }
+
+    /// Property value for the current progress, or None before the start value is known.
+    pub fn value(&self) -> Option<[f32; 4]> {
+        let from = self.from?;
+        let t = self.easing.apply(self.progress());
+        Some(std::array::from_fn(|i| from[i] + (self.to[i] - from[i]) * t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_easings_start_and_end_at_bounds() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseInQuad,
+            Easing::EaseOutQuad,
+            Easing::EaseInOutQuad,
+            Easing::EaseInCubic,
+            Easing::EaseOutCubic,
+            Easing::EaseInOutCubic,
+            Easing::EaseOutBack,
+            Easing::EaseOutBounce,
+        ] {
+            assert!(easing.apply(0.0).abs() < 1e-5, "{}", easing.as_str());
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-5, "{}", easing.as_str());
+            assert_eq!(Easing::parse(easing.as_str()), Some(easing));
+        }
+        assert!(Easing::EaseOutBack.apply(0.8) > 1.0);
+    }
+
+    #[test]
+    fn test_tween_waits_for_delay_then_interpolates() {
+        let mut tween = UITween::new(1, TweenProperty::Opacity, [0.0; 4], 1.0)
+            .with_from([1.0; 4])
+            .with_delay(0.5);
+
+        tween.advance(0.25);
+        assert!(!tween.is_started());
+        assert_eq!(tween.value().unwrap()[0], 1.0);
+
+        tween.advance(0.75);
+        assert!(tween.is_started());
+        assert!((tween.value().unwrap()[0] - 0.5).abs() < 1e-5);
+
+        tween.advance(1.0);
+        assert!(tween.is_finished());
+        assert_eq!(tween.value().unwrap()[0], 0.0);
+    }
+}
@@ -1,16 +1,59 @@
 use crate::core::component::ComponentTrait;
-use crate::core::draw_manager::DrawManager;
+use crate::core::draw_manager::{ClipRect, DrawCommand, DrawManager};
 use crate::core::time::Time;
 use crate::core::game_object::{GameObject, ObjectType};
-use crate::core::input_manager::InputManager;
+use crate::core::input_manager::{InputEvent, InputManager};
 use crate::core::object_manager::ObjectManager;
 use crate::core::ui::button::ButtonComponent;
 use crate::core::ui::event::{UIEvent, UIEventManager};
 use crate::core::ui::label::LabelComponent;
 use crate::core::ui::panel::PanelComponent;
-use crate::core::ui::style::UITheme;
-use crate::core::ui::{Rect, UIComponentTrait};
+use crate::core::ui::slider::SliderComponent;
+use crate::core::ui::checkbox::CheckboxComponent;
+use crate::core::ui::toggle_switch::ToggleSwitchComponent;
+use crate::core::ui::scroll_view::ScrollViewComponent;
+use crate::core::ui::progress_bar::ProgressBarComponent;
+use crate::core::ui::custom_widget::CustomWidgetComponent;
+use crate::core::ui::canvas::CanvasComponent;
+use crate::core::ui::image::ImageComponent;
+use crate::core::text::{TextLayoutOptions, TextStyle};
+use crate::core::ui::layout::UILayoutComponent;
+use crate::core::ui::stack::{StackComponent, StackDirection};
+use crate::core::ui::curve_editor::CurveEditorComponent;
+use crate::core::ui::radio_button::RadioButtonComponent;
+use crate::core::ui::list_view::ListViewComponent;
+use crate::core::ui::style::{FocusRing, UITheme};
+use crate::core::ui::text_input::TextInputComponent;
+use crate::core::ui::tween::{TweenProperty, UITween};
+use crate::core::ui::{Rect, SizeMode, UIComponentTrait};
 use std::any::Any;
+use std::collections::{HashMap, HashSet, VecDeque};
+use winit::keyboard::{Key, NamedKey};
+use winit::window::CursorIcon;
+
+/// How UI coordinates map to window pixels.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum UIScaleMode {
+    /// One UI unit per logical pixel; the UI keeps its size on any window.
+    #[default]
+    ConstantPixelSize,
+    /// Scale uniformly so the window is always `reference_height` units
+    /// tall; the width follows the window's aspect ratio.
+    ScaleWithHeight { reference_height: f32 },
+    /// Lay out at a fixed `width` x `height` and scale it to fit the window,
+    /// centered with empty margins on the sides that do not fit (letterbox).
+    FixedResolution { width: f32, height: f32 },
+}
+
+impl UIScaleMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ConstantPixelSize => "constant_pixel_size",
+            Self::ScaleWithHeight { .. } => "scale_with_height",
+            Self::FixedResolution { .. } => "fixed_resolution",
+        }
+    }
+}
 
 #[derive(Clone, Copy)]
 struct UIEntry {
@@ -18,13 +61,45 @@ struct UIEntry {
     depth: f64,
     bounds: Rect,
     render_offset: (f32, f32),
+    /// Opacity including every ancestor's
+    opacity: f32,
     enabled: bool,
+    /// False when the entry or an ancestor was made non-interactable
+    interactable: bool,
+    focusable: bool,
+    /// Whether the entry is a modal panel
+    modal: bool,
+    /// Absolute rect the entry is clipped to by a scrolling or clipping ancestor
+    clip: Option<Rect>,
+}
+
+/// Where and in which state a widget was drawn; a change means its cached
+/// commands are stale.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct WidgetDrawKey {
+    object_id: u32,
+    /// `GameObject::component_revision`, so direct widget changes redraw
+    revision: u64,
+    bounds: Rect,
+    render_offset: (f32, f32),
+    opacity: f32,
+    clip: Option<Rect>,
+    interactable: bool,
+    focus_ring: bool,
+}
+
+/// Unscaled draw commands a widget produced the last time it was rendered.
+struct CachedWidgetDraw {
+    key: WidgetDrawKey,
+    commands: Vec<DrawCommand>,
+    clips: Vec<Option<ClipRect>>,
 }
 
 #[derive(Clone, Copy, Debug)]
 struct UIHitProxy {
     bounds: Rect,
     enabled: bool,
+    focusable: bool,
     depth: f32,
 }
 
@@ -36,6 +111,7 @@ impl ComponentTrait for UIHitProxy {
         Self {
             bounds: Rect::new(0.0, 0.0, 0.0, 0.0),
             enabled: true,
+            focusable: false,
             depth: 0.0,
         }
     }
@@ -113,6 +189,10 @@ impl UIComponentTrait for UIHitProxy {
         self.enabled
     }
 
+    fn is_focusable(&self) -> bool {
+        self.focusable
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -128,12 +208,57 @@ pub struct UIManager {
     event_manager: UIEventManager,
     /// UI theme
     theme: UITheme,
+    /// Theme the styled widgets still use, until the next update restyles them
+    previous_theme: Option<UITheme>,
+    /// Widgets styled with the current theme
+    themed: HashSet<u32>,
     /// Screen/root bounds
     root_bounds: Rect,
     /// HiDPI scale factor (logical to physical pixel ratio)
     scale_factor: f32,
+    /// How UI units map to window pixels
+    scale_mode: UIScaleMode,
     /// Start index of UI draw commands from the previous frame
     ui_cmd_start: Option<usize>,
+    /// Draw manager version right after the previous frame's UI was drawn
+    ui_cmd_version: u64,
+    /// Draw keys of the previous frame's rendered widgets, in draw order
+    last_frame: Vec<WidgetDrawKey>,
+    /// Scale factor the previous frame's UI commands were scaled by
+    last_scale_factor: f32,
+    /// Retained draw commands per widget object
+    draw_cache: HashMap<u32, CachedWidgetDraw>,
+    /// Widgets whose state changed since they were last rendered
+    dirty: HashSet<u32>,
+    /// Re-render every widget on the next frame
+    all_dirty: bool,
+    /// Whether a scroll view used the mouse wheel this frame
+    wheel_consumed: bool,
+    /// Topmost modal panel found by the last update
+    active_modal: Option<u32>,
+    /// Running property tweens, at most one per object and property
+    tweens: Vec<UITween>,
+    /// Tweens that ended since `take_finished_tweens` was last called
+    finished_tweens: Vec<u32>,
+    /// Translation added to an element's bounds, moving its children along
+    offsets: HashMap<u32, (f32, f32)>,
+    /// Alpha multiplier of an element and its children
+    opacity: HashMap<u32, f32>,
+    /// Elements that, with their children, are greyed out and ignore input
+    non_interactable: HashSet<u32>,
+    /// Outline drawn around the focused widget
+    focus_ring: FocusRing,
+    /// Whether the focus came from the keyboard or a script rather than a
+    /// click, so the focus ring is shown
+    focus_visible: bool,
+    /// Cursor shown while the pointer is over an element or its children
+    hover_cursors: HashMap<u32, CursorIcon>,
+    /// Hover cursor of the element under the pointer, found by the last update
+    active_hover_cursor: Option<CursorIcon>,
+    /// Input context an element and its children take input in
+    input_contexts: HashMap<u32, String>,
+    /// Top input context as of the last update
+    active_input_context: Option<String>,
 }
 
 impl UIManager {
@@ -141,22 +266,50 @@ impl UIManager {
         Self {
             event_manager: UIEventManager::new(),
             theme: UITheme::default_light(),
+            previous_theme: None,
+            themed: HashSet::new(),
             root_bounds: Rect::new(0.0, 0.0, width, height),
             scale_factor,
+            scale_mode: UIScaleMode::default(),
             ui_cmd_start: None,
+            ui_cmd_version: 0,
+            last_frame: Vec::new(),
+            last_scale_factor: scale_factor,
+            draw_cache: HashMap::new(),
+            dirty: HashSet::new(),
+            all_dirty: true,
+            wheel_consumed: false,
+            active_modal: None,
+            tweens: Vec::new(),
+            finished_tweens: Vec::new(),
+            offsets: HashMap::new(),
+            opacity: HashMap::new(),
+            non_interactable: HashSet::new(),
+            focus_ring: FocusRing::default(),
+            focus_visible: false,
+            hover_cursors: HashMap::new(),
+            active_hover_cursor: None,
+            input_contexts: HashMap::new(),
+            active_input_context: None,
         }
     }
 
     pub fn update(&mut self, input: &InputManager, object_manager: &mut ObjectManager) {
+        self.apply_theme(object_manager);
+        self.apply_layout(object_manager);
+        self.apply_tweens(object_manager);
+        self.fit_scroll_view_content(object_manager);
+
         let entries = self.collect_ui_entries(object_manager);
-        let proxies: Vec<UIHitProxy> = entries
+        let modal_scope = Self::modal_scope(object_manager, &entries);
+        self.active_modal = modal_scope.as_ref().map(|(modal_id, _)| *modal_id);
+        self.active_input_context = input.input_context().map(str::to_string);
+        let out_of_context: HashSet<u32> = entries
             .iter()
-            .map(|entry| UIHitProxy {
-                bounds: entry.bounds,
-                enabled: entry.enabled,
-                depth: entry.depth as f32,
-            })
+            .map(|entry| entry.object_id)
+            .filter(|id| !self.in_active_input_context(object_manager, *id))
             .collect();
+        let proxies = self.hit_proxies(&entries, modal_scope.as_ref(), &out_of_context);
 
         let ui_comp_refs: Vec<(u32, &dyn UIComponentTrait, f64)> = entries
             .iter()
@@ -164,51 +317,626 @@ impl UIManager {
             .map(|(entry, proxy)| (entry.object_id, proxy as &dyn UIComponentTrait, entry.depth))
             .collect();
 
-        let events = self.event_manager.process_input(input, &ui_comp_refs, self.scale_factor);
-        for (target_id, event) in events {
-            if let Some(obj) = object_manager.get_object_by_id_mut(target_id) {
-                Self::dispatch_event(obj, &event);
+        let mut events: VecDeque<(u32, UIEvent)> = VecDeque::new();
+        let focused_before = self.event_manager.focused_component();
+        if let Some(focused_id) = focused_before {
+            // A modal opened, the widget's input context stopped being on top
+            // or it became non-interactable: take focus away.
+            let behind_modal = modal_scope.as_ref().is_some_and(|(_, scope)| !scope.contains(&focused_id));
+            let non_interactable = entries
+                .iter()
+                .any(|entry| entry.object_id == focused_id && !entry.interactable);
+            if behind_modal || out_of_context.contains(&focused_id) || non_interactable {
+                events.extend(self.event_manager.set_focus(None));
+            }
+        }
+        let mouse_position = self.to_ui_point(input.mouse_position());
+        events.extend(self.event_manager.process_input(input, &ui_comp_refs, mouse_position));
+        while let Some((target_id, event)) = events.pop_front() {
+            if object_manager.get_object_by_id(target_id).is_none() {
+                continue;
+            }
+            self.dirty.insert(target_id);
+            if let Some(handler_id) = Self::dispatch_event(object_manager, target_id, &event) {
+                self.dirty.insert(handler_id);
+                let radio_selected = object_manager
+                    .get_object_by_id_mut(handler_id)
+                    .and_then(|object| object.get_component_mut::<RadioButtonComponent>())
+                    .is_some_and(|radio| radio.take_selection_pending());
+                if radio_selected {
+                    self.dirty.extend(Self::select_radio_button(object_manager, handler_id, true));
+                }
+            } else if let Some(forward) = Self::arrow_navigation(&event) {
+                // Arrow keys the focused widget doesn't use move focus instead.
+                events.extend(self.event_manager.move_focus(&ui_comp_refs, forward));
+            }
+        }
+        if self.event_manager.focused_component() != focused_before {
+            // Like CSS `:focus-visible`, clicking a widget doesn't show the ring.
+            let clicked = input
+                .frame_events()
+                .iter()
+                .any(|event| matches!(event, InputEvent::MouseButtonPressed { .. }));
+            self.focus_visible = !clicked;
+        }
+
+        self.wheel_consumed = false;
+        // The wheel accumulator is reset by `InputManager::update`, so sum this frame's events.
+        let (delta_x, delta_y) = input.frame_events().iter().fold((0.0, 0.0), |sum, event| match event {
+            InputEvent::MouseWheel { delta_x, delta_y } => (sum.0 + delta_x, sum.1 + delta_y),
+            _ => sum,
+        });
+        if (delta_x != 0.0 || delta_y != 0.0)
+            && let Some(hovered_id) = self.event_manager.hovered_component()
+        {
+            let (mouse_x, mouse_y) = self.to_ui_point(input.mouse_position());
+            let event = UIEvent::MouseWheel {
+                x: mouse_x,
+                y: mouse_y,
+                delta_x,
+                delta_y,
+            };
+            if let Some(handler_id) = Self::dispatch_wheel(object_manager, hovered_id, &event) {
+                self.dirty.insert(handler_id);
+                self.wheel_consumed = true;
             }
         }
+
+        self.active_hover_cursor = self
+            .event_manager
+            .hovered_component()
+            .and_then(|hovered_id| self.hover_cursor_for(object_manager, hovered_id));
+
+        self.sync_list_views(object_manager);
+        self.sync_canvases(object_manager);
     }
 
+    /// Draw the UI, replaying cached commands for widgets that did not change.
+    ///
+    /// When no widget is dirty, changed or moved, and nothing else touched the
+    /// draw commands since, the previous frame's commands are left in place
+    /// and nothing is rebuilt.
     pub fn render(&mut self, draw_manager: &mut DrawManager, object_manager: &ObjectManager) {
         let mut entries = self.collect_ui_entries(object_manager);
-        if entries.is_empty() {
+        entries.retain(|entry| entry.enabled);
+        entries.sort_by(|a, b| a.depth.partial_cmp(&b.depth).unwrap_or(std::cmp::Ordering::Equal));
+        let ring_target = self.focused_object().filter(|_| self.focus_visible);
+        let frame: Vec<WidgetDrawKey> = entries
+            .iter()
+            .map(|entry| WidgetDrawKey {
+                object_id: entry.object_id,
+                revision: object_manager
+                    .get_object_by_id(entry.object_id)
+                    .map_or(0, GameObject::component_revision),
+                bounds: entry.bounds,
+                render_offset: entry.render_offset,
+                opacity: entry.opacity,
+                clip: entry.clip,
+                interactable: entry.interactable,
+                focus_ring: entry.focusable && ring_target == Some(entry.object_id),
+            })
+            .collect();
+
+        let unchanged = self.ui_cmd_start.is_some()
+            && draw_manager.scene_version() == self.ui_cmd_version
+            && !self.all_dirty
+            && self.dirty.is_empty()
+            && self.last_scale_factor == self.ui_scale()
+            && self.last_frame == frame;
+        if unchanged {
             return;
         }
 
-        entries.sort_by(|a, b| a.depth.partial_cmp(&b.depth).unwrap_or(std::cmp::Ordering::Equal));
         if let Some(prev_start) = self.ui_cmd_start {
             draw_manager.truncate_from(prev_start);
         }
+        if self.all_dirty {
+            self.draw_cache.clear();
+        }
+        self.draw_cache.retain(|id, _| !self.dirty.contains(id));
 
+        let modal_ids: HashSet<u32> = entries
+            .iter()
+            .filter(|entry| entry.modal)
+            .map(|entry| entry.object_id)
+            .collect();
         let cmd_start = draw_manager.commands().len();
-        self.ui_cmd_start = Some(cmd_start);
-
-        for entry in entries {
-            if !entry.enabled {
+        for key in &frame {
+            if let Some(cached) = self.draw_cache.get(&key.object_id)
+                && cached.key == *key
+            {
+                draw_manager.append_commands_with_clips(&cached.commands, &cached.clips);
                 continue;
             }
 
-            if let Some(obj) = object_manager.get_object_by_id(entry.object_id) {
-                Self::render_component(obj, draw_manager, entry.render_offset);
+            let Some(obj) = object_manager.get_object_by_id(key.object_id) else {
+                continue;
+            };
+            let widget_start = draw_manager.commands().len();
+            if modal_ids.contains(&key.object_id) {
+                self.render_modal_backdrop(obj, draw_manager);
+            }
+            if let Some(clip) = key.clip {
+                draw_manager.push_clip_rect(clip.x, clip.y, clip.width, clip.height);
+            }
+            Self::render_component(obj, draw_manager, key.render_offset);
+            if key.focus_ring
+                && let Some(component) = Self::ui_component(obj)
+            {
+                let depth = component.ui_depth() + 0.02;
+                self.focus_ring
+                    .render(draw_manager, key.bounds, self.theme.focus_ring_color, depth);
+            }
+            if key.clip.is_some() {
+                draw_manager.pop_clip_rect();
+            }
+            if !key.interactable {
+                draw_manager.desaturate_from(widget_start, self.theme.disabled_saturation);
+                draw_manager.multiply_alpha_from(widget_start, self.theme.disabled_opacity);
             }
+            if key.opacity < 1.0 {
+                draw_manager.multiply_alpha_from(widget_start, key.opacity);
+            }
+            draw_manager.apply_font_fallbacks_from(widget_start, &self.theme.font_fallbacks);
+            self.draw_cache.insert(
+                key.object_id,
+                CachedWidgetDraw {
+                    key: *key,
+                    commands: draw_manager.commands()[widget_start..].to_vec(),
+                    clips: draw_manager.clip_rects()[widget_start..].to_vec(),
+                },
+            );
         }
 
-        if self.scale_factor != 1.0 {
-            draw_manager.scale_commands_from(cmd_start, self.scale_factor);
+        // Forget widgets that were removed or hidden.
+        let drawn: HashSet<u32> = frame.iter().map(|key| key.object_id).collect();
+        self.draw_cache.retain(|id, _| drawn.contains(id));
+
+        let scale = self.ui_scale();
+        if scale != 1.0 {
+            draw_manager.scale_commands_from(cmd_start, scale);
+        }
+        let (origin_x, origin_y) = self.ui_origin();
+        if origin_x != 0.0 || origin_y != 0.0 {
+            draw_manager.offset_commands_from(cmd_start, origin_x, origin_y, 0.0);
         }
+
+        self.ui_cmd_start = Some(cmd_start);
+        self.ui_cmd_version = draw_manager.scene_version();
+        self.last_frame = frame;
+        self.last_scale_factor = scale;
+        self.dirty.clear();
+        self.all_dirty = false;
+    }
+
+    /// Re-render a widget on the next frame.
+    ///
+    /// Input events, engine UI commands, layout changes and mutable component
+    /// access through `GameObject` mark widgets automatically; call this for
+    /// changes they cannot see, such as state behind a widget's callbacks.
+    pub fn mark_dirty(&mut self, object_id: u32) {
+        self.dirty.insert(object_id);
+    }
+
+    /// Re-render every widget on the next frame.
+    pub fn mark_all_dirty(&mut self) {
+        self.all_dirty = true;
+    }
+
+    /// Number of widgets with retained draw commands.
+    pub fn cached_widget_count(&self) -> usize {
+        self.draw_cache.len()
     }
 
     /// Check if input was consumed by UI this frame
     pub fn is_input_consumed(&self) -> bool {
-        self.event_manager.is_input_consumed()
+        self.event_manager.is_input_consumed() || self.wheel_consumed
+    }
+
+    /// Object that has keyboard focus, if any
+    pub fn focused_object(&self) -> Option<u32> {
+        self.event_manager.focused_component()
+    }
+
+    /// Move keyboard focus to a widget, or clear it with `None`.
+    ///
+    /// The widget must be visible, interactable and focusable. Focus set
+    /// this way shows the focus ring. Returns whether focus is now where
+    /// it was asked to be.
+    pub fn set_focus(&mut self, object_manager: &mut ObjectManager, object_id: Option<u32>) -> bool {
+        if let Some(object_id) = object_id {
+            let focusable = self
+                .collect_ui_entries(object_manager)
+                .iter()
+                .any(|entry| entry.object_id == object_id && entry.enabled && entry.interactable && entry.focusable)
+                && self.in_active_input_context(object_manager, object_id);
+            if !focusable {
+                return false;
+            }
+        }
+        for (target_id, event) in self.event_manager.set_focus(object_id) {
+            self.dirty.insert(target_id);
+            if let Some(handler_id) = Self::dispatch_event(object_manager, target_id, &event) {
+                self.dirty.insert(handler_id);
+            }
+        }
+        self.focus_visible = object_id.is_some();
+        true
+    }
+
+    /// Outline drawn around the focused widget
+    pub fn focus_ring(&self) -> FocusRing {
+        self.focus_ring
+    }
+
+    pub fn set_focus_ring(&mut self, focus_ring: FocusRing) {
+        self.focus_ring = focus_ring;
+        self.all_dirty = true;
+    }
+
+    /// Grey out an element and its children and stop them taking input.
+    ///
+    /// Unlike disabling the object, the element stays visible and keeps its
+    /// place in the layout. Focus inside it is cleared on the next update.
+    pub fn set_interactable(&mut self, object_id: u32, interactable: bool) {
+        let changed = if interactable {
+            self.non_interactable.remove(&object_id)
+        } else {
+            self.non_interactable.insert(object_id)
+        };
+        if changed {
+            // Children inherit the state, so their cached commands are stale too.
+            self.all_dirty = true;
+        }
+    }
+
+    /// Whether the element itself is interactable; ancestors are not checked.
+    pub fn is_interactable(&self, object_id: u32) -> bool {
+        !self.non_interactable.contains(&object_id)
+    }
+
+    /// Show a cursor while the pointer is over an element or its children,
+    /// or `None` to remove it. The nearest element with a cursor wins.
+    pub fn set_hover_cursor(&mut self, object_id: u32, cursor: Option<CursorIcon>) {
+        match cursor {
+            Some(cursor) => self.hover_cursors.insert(object_id, cursor),
+            None => self.hover_cursors.remove(&object_id),
+        };
+    }
+
+    /// Cursor set on the element itself with [`Self::set_hover_cursor`].
+    pub fn hover_cursor(&self, object_id: u32) -> Option<CursorIcon> {
+        self.hover_cursors.get(&object_id).copied()
+    }
+
+    /// Cursor for the element under the pointer as of the last update, if it
+    /// or an ancestor has one.
+    pub fn active_hover_cursor(&self) -> Option<CursorIcon> {
+        self.active_hover_cursor
+    }
+
+    fn hover_cursor_for(&self, object_manager: &ObjectManager, object_id: u32) -> Option<CursorIcon> {
+        let mut current = Some(object_id);
+        while let Some(id) = current {
+            if let Some(cursor) = self.hover_cursor(id) {
+                return Some(cursor);
+            }
+            current = object_manager.get_object_by_id(id)?.parent_id();
+        }
+        None
+    }
+
+    /// Make an element and its children take input only while `context` is
+    /// the top input context, or `None` to take input in any context. The
+    /// nearest element with a context wins. While no context is pushed,
+    /// every element takes input.
+    pub fn set_input_context(&mut self, object_id: u32, context: Option<String>) {
+        match context {
+            Some(context) => self.input_contexts.insert(object_id, context),
+            None => self.input_contexts.remove(&object_id),
+        };
+    }
+
+    /// Input context set on the element itself with [`Self::set_input_context`].
+    pub fn input_context(&self, object_id: u32) -> Option<&str> {
+        self.input_contexts.get(&object_id).map(String::as_str)
+    }
+
+    fn in_active_input_context(&self, object_manager: &ObjectManager, object_id: u32) -> bool {
+        let Some(active) = self.active_input_context.as_ref().filter(|_| !self.input_contexts.is_empty()) else {
+            return true;
+        };
+        let mut current = Some(object_id);
+        while let Some(id) = current {
+            if let Some(context) = self.input_context(id) {
+                return context.trim().eq_ignore_ascii_case(active);
+            }
+            current = object_manager.get_object_by_id(id).and_then(|object| object.parent_id());
+        }
+        true
+    }
+
+    /// Caret of the focused text input in window pixels, or `None` when no
+    /// text input has focus. The engine enables the input method (IME) while
+    /// this is set and places its candidate window here.
+    pub fn text_input_caret(&self, object_manager: &ObjectManager) -> Option<Rect> {
+        let focused_id = self.focused_object()?;
+        let caret = object_manager
+            .get_object_by_id(focused_id)?
+            .get_component_by_name("TextInput")?
+            .as_any()
+            .downcast_ref::<TextInputComponent>()?
+            .caret_rect();
+        let entry = self
+            .collect_ui_entries(object_manager)
+            .into_iter()
+            .find(|entry| entry.object_id == focused_id)?;
+        let scale = self.ui_scale();
+        let (origin_x, origin_y) = self.ui_origin();
+        Some(Rect::new(
+            origin_x + (caret.x + entry.render_offset.0) * scale,
+            origin_y + (caret.y + entry.render_offset.1) * scale,
+            caret.width * scale,
+            caret.height * scale,
+        ))
+    }
+
+    /// Modal panel currently capturing UI input, if any
+    pub fn modal_object(&self) -> Option<u32> {
+        self.active_modal
     }
 
     /// Update screen size
     pub fn resize(&mut self, width: f32, height: f32) {
         self.root_bounds = Rect::new(0.0, 0.0, width, height);
+        // Modal backdrops cover the whole window.
+        self.all_dirty = true;
+    }
+
+    /// Update the HiDPI scale factor, e.g. after the window moved to another monitor
+    pub fn set_scale_factor(&mut self, scale_factor: f32) {
+        self.scale_factor = scale_factor;
+    }
+
+    /// Set how UI units map to window pixels. Applied from the next update.
+    pub fn set_scale_mode(&mut self, scale_mode: UIScaleMode) {
+        self.scale_mode = scale_mode;
+        self.all_dirty = true;
+    }
+
+    pub fn scale_mode(&self) -> UIScaleMode {
+        self.scale_mode
+    }
+
+    /// Window pixels per UI unit under the current scale mode.
+    pub fn ui_scale(&self) -> f32 {
+        let scale = match self.scale_mode {
+            UIScaleMode::ConstantPixelSize => self.scale_factor,
+            UIScaleMode::ScaleWithHeight { reference_height } => self.root_bounds.height / reference_height,
+            UIScaleMode::FixedResolution { width, height } => {
+                (self.root_bounds.width / width).min(self.root_bounds.height / height)
+            }
+        };
+        // A minimized window or a zero reference size must not collapse the UI.
+        if scale.is_finite() && scale > 0.0 { scale } else { self.scale_factor }
+    }
+
+    /// Window position, in physical pixels, of the UI's top-left corner.
+    /// Only a fixed resolution that does not fill the window moves it.
+    pub fn ui_origin(&self) -> (f32, f32) {
+        match self.scale_mode {
+            UIScaleMode::FixedResolution { width, height } => {
+                let scale = self.ui_scale();
+                (
+                    ((self.root_bounds.width - width * scale) / 2.0).max(0.0),
+                    ((self.root_bounds.height - height * scale) / 2.0).max(0.0),
+                )
+            }
+            _ => (0.0, 0.0),
+        }
+    }
+
+    /// Convert a window position in physical pixels to UI coordinates.
+    pub fn to_ui_point(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        let scale = self.ui_scale() as f64;
+        let (origin_x, origin_y) = self.ui_origin();
+        ((x - origin_x as f64) / scale, (y - origin_y as f64) / scale)
+    }
+
+    /// Size of the UI root in UI units, the space UI bounds are expressed in:
+    /// the window in logical pixels unless a scale mode says otherwise.
+    pub fn logical_size(&self) -> (f32, f32) {
+        if let UIScaleMode::FixedResolution { width, height } = self.scale_mode
+            && width > 0.0
+            && height > 0.0
+        {
+            return (width, height);
+        }
+        let scale = self.ui_scale();
+        (self.root_bounds.width / scale, self.root_bounds.height / scale)
+    }
+
+    /// Start animating a property, replacing any tween running on the same
+    /// element and property. Returns the tween's id.
+    pub fn add_tween(&mut self, tween: UITween) -> u32 {
+        self.tweens
+            .retain(|other| other.object_id() != tween.object_id() || other.property() != tween.property());
+        let id = tween.id();
+        self.tweens.push(tween);
+        id
+    }
+
+    /// Stop a tween where it is. Returns whether it was still running.
+    pub fn cancel_tween(&mut self, tween_id: u32) -> bool {
+        let count = self.tweens.len();
+        self.tweens.retain(|tween| tween.id() != tween_id);
+        self.tweens.len() != count
+    }
+
+    /// Stop every tween on an element.
+    pub fn cancel_tweens(&mut self, object_id: u32) {
+        self.tweens.retain(|tween| tween.object_id() != object_id);
+    }
+
+    /// Move the running tweens forward; the next update applies their values.
+    pub fn advance_tweens(&mut self, delta_time: f32) {
+        for tween in &mut self.tweens {
+            tween.advance(delta_time);
+        }
+    }
+
+    pub fn has_active_tweens(&self) -> bool {
+        !self.tweens.is_empty()
+    }
+
+    /// Ids of the tweens that ended since the last call, including ones
+    /// dropped because their element was removed or lacks the property.
+    pub fn take_finished_tweens(&mut self) -> Vec<u32> {
+        std::mem::take(&mut self.finished_tweens)
+    }
+
+    /// Translation added to an element's bounds.
+    pub fn offset(&self, object_id: u32) -> (f32, f32) {
+        self.offsets.get(&object_id).copied().unwrap_or((0.0, 0.0))
+    }
+
+    pub fn set_offset(&mut self, object_id: u32, x: f32, y: f32) {
+        self.offsets.insert(object_id, (x, y));
+    }
+
+    /// Alpha multiplier of an element, not counting its ancestors'.
+    pub fn opacity(&self, object_id: u32) -> f32 {
+        self.opacity.get(&object_id).copied().unwrap_or(1.0)
+    }
+
+    pub fn set_opacity(&mut self, object_id: u32, opacity: f32) {
+        self.opacity.insert(object_id, opacity.clamp(0.0, 1.0));
+        self.dirty.insert(object_id);
+    }
+
+    /// Resolve every `UILayoutComponent` against its parent element (or the
+    /// window for root elements) and write the result into the widget bounds,
+    /// then let each `StackComponent` position its children.
+    ///
+    /// Parents are laid out before their children, so percentage and fill
+    /// sizes follow a parent that was itself resized by its layout.
+    pub fn apply_layout(&self, object_manager: &mut ObjectManager) {
+        let (width, height) = self.logical_size();
+        // (object, parent bounds, positioned by a stack)
+        let mut pending: Vec<(u32, Rect, bool)> = Self::ui_root_ids(object_manager)
+            .into_iter()
+            .map(|id| (id, Rect::new(0.0, 0.0, width, height), false))
+            .collect();
+
+        while let Some((object_id, parent_bounds, stacked)) = pending.pop() {
+            let Some(object) = object_manager.get_object_by_id_mut(object_id) else {
+                continue;
+            };
+            let Some(mut bounds) = Self::ui_component(object).map(|component| component.bounds()) else {
+                continue;
+            };
+
+            // Auto-height labels keep the height of their text.
+            let fit_height = object
+                .get_component::<LabelComponent>()
+                .is_some_and(LabelComponent::auto_height);
+            if !stacked
+                && let Some(layout) = object.get_component_mut_untracked::<UILayoutComponent>()
+                && layout.is_enabled_self()
+            {
+                let height_mode = layout.height_mode;
+                if fit_height {
+                    layout.height_mode = SizeMode::FitContent;
+                }
+                bounds = layout.calculate_bounds(parent_bounds, (bounds.width, bounds.height));
+                layout.height_mode = height_mode;
+                Self::with_ui_component_mut(object, |component| component.set_bounds(bounds));
+            }
+
+            let children = object.children().to_vec();
+            let stack = object
+                .get_component::<StackComponent>()
+                .filter(|stack| stack.is_enabled_self())
+                .cloned();
+            let is_stack = stack.is_some();
+            if let Some(mut stack) = stack {
+                bounds = Self::arrange_stack(object_manager, object_id, &mut stack, bounds, &children);
+            }
+
+            // Children are positioned relative to their parent's top-left corner.
+            let child_parent = Rect::new(0.0, 0.0, bounds.width, bounds.height);
+            pending.extend(children.iter().map(|child_id| (*child_id, child_parent, is_stack)));
+        }
+    }
+
+    /// Position the visible children of a stack and, with `fit_content`,
+    /// resize the stack along its axis. Returns the stack's final bounds.
+    fn arrange_stack(
+        object_manager: &mut ObjectManager,
+        stack_id: u32,
+        stack: &mut StackComponent,
+        mut bounds: Rect,
+        children: &[u32],
+    ) -> Rect {
+        let (visible, sizes): (Vec<u32>, Vec<(f32, f32)>) = Self::stacked_children(object_manager, children)
+            .into_iter()
+            .filter_map(|child_id| Some((child_id, Self::measure(object_manager, child_id)?)))
+            .unzip();
+
+        stack.set_bounds(bounds);
+        let (rects, length) = stack.arrange(&sizes);
+        for (child_id, rect) in visible.into_iter().zip(rects) {
+            if let Some(child) = object_manager.get_object_by_id_mut(child_id) {
+                Self::with_ui_component_mut(child, |component| component.set_bounds(rect));
+            }
+        }
+
+        if stack.fit_content() {
+            match stack.direction() {
+                StackDirection::Vertical => bounds.height = length,
+                StackDirection::Horizontal => bounds.width = length,
+            }
+            if let Some(object) = object_manager.get_object_by_id_mut(stack_id) {
+                Self::with_ui_component_mut(object, |component| component.set_bounds(bounds));
+            }
+        }
+        bounds
+    }
+
+    /// Children a stack lays out: enabled UI objects, in order.
+    fn stacked_children(object_manager: &ObjectManager, children: &[u32]) -> Vec<u32> {
+        children
+            .iter()
+            .copied()
+            .filter(|child_id| {
+                object_manager.get_object_by_id(*child_id).is_some_and(|child| {
+                    child.is_enabled() && Self::ui_component(child).is_some_and(|component| component.is_enabled())
+                })
+            })
+            .collect()
+    }
+
+    /// Size of a UI object as a stack child: its bounds, or for a
+    /// `fit_content` stack the length needed to wrap its own children.
+    fn measure(object_manager: &ObjectManager, object_id: u32) -> Option<(f32, f32)> {
+        let object = object_manager.get_object_by_id(object_id)?;
+        let bounds = Self::ui_component(object)?.bounds();
+        let Some(stack) = object
+            .get_component::<StackComponent>()
+            .filter(|stack| stack.is_enabled_self() && stack.fit_content())
+        else {
+            return Some((bounds.width, bounds.height));
+        };
+
+        let sizes: Vec<(f32, f32)> = Self::stacked_children(object_manager, object.children())
+            .into_iter()
+            .filter_map(|child_id| Self::measure(object_manager, child_id))
+            .collect();
+        let (_, length) = stack.arrange(&sizes);
+        Some(match stack.direction() {
+            StackDirection::Vertical => (bounds.width, length),
+            StackDirection::Horizontal => (length, bounds.height),
+        })
     }
 
     /// Get the current theme
@@ -218,9 +946,27 @@ impl UIManager {
 
     /// Get mutable theme
     pub fn theme_mut(&mut self) -> &mut UITheme {
+        self.all_dirty = true;
         &mut self.theme
     }
 
+    /// Switch to another theme, restyling existing widgets on the next update.
+    ///
+    /// Widget colors that were changed from the old theme's are kept. The
+    /// fallback fonts carry over from the current theme.
+    pub fn set_theme(&mut self, mut theme: UITheme) {
+        theme.font_fallbacks = std::mem::take(&mut self.theme.font_fallbacks);
+        let previous = std::mem::replace(&mut self.theme, theme);
+        // Widgets not restyled yet still use the first theme they were styled with.
+        self.previous_theme.get_or_insert(previous);
+        self.all_dirty = true;
+    }
+
+    /// Set the theme's fallback fonts, used by UI text that lists none of its own.
+    pub fn set_font_fallbacks(&mut self, fallbacks: Vec<String>) {
+        self.theme_mut().font_fallbacks = fallbacks;
+    }
+
     /// Reset UI command tracking when draw commands are cleared
     /// This should be called when clear_draw_commands() is invoked
     pub fn reset_command_tracking(&mut self) {
@@ -229,11 +975,19 @@ impl UIManager {
 
     fn collect_ui_entries(&self, object_manager: &ObjectManager) -> Vec<UIEntry> {
         let mut entries = Vec::new();
-        let root_ids: Vec<u32> = object_manager
+        for root_id in Self::ui_root_ids(object_manager) {
+            self.collect_ui_entries_recursive(object_manager, root_id, (0.0, 0.0), 1.0, true, true, None, &mut entries);
+        }
+
+        entries
+    }
+
+    /// UI objects whose parent is missing or not a UI object.
+    fn ui_root_ids(object_manager: &ObjectManager) -> Vec<u32> {
+        object_manager
             .get_keys()
-            .iter()
             .filter_map(|id| {
-                let object = object_manager.get_object_by_id(*id)?;
+                let object = object_manager.get_object_by_id(id)?;
                 if object.get_object_type() != ObjectType::UIObject {
                     return None;
                 }
@@ -244,23 +998,21 @@ impl UIManager {
                         .map(|parent| parent.get_object_type() != ObjectType::UIObject)
                         .unwrap_or(true)
                 });
-                is_root.then_some(*id)
+                is_root.then_some(id)
             })
-            .collect();
-
-        for root_id in root_ids {
-            self.collect_ui_entries_recursive(object_manager, root_id, (0.0, 0.0), true, &mut entries);
-        }
-
-        entries
+            .collect()
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn collect_ui_entries_recursive(
         &self,
         object_manager: &ObjectManager,
         object_id: u32,
         parent_offset: (f32, f32),
+        inherited_opacity: f32,
         inherited_enabled: bool,
+        inherited_interactable: bool,
+        clip: Option<Rect>,
         entries: &mut Vec<UIEntry>,
     ) {
         let Some(object) = object_manager.get_object_by_id(object_id) else {
@@ -271,93 +1023,1581 @@ impl UIManager {
         };
 
         let local_bounds = component.bounds();
-        let render_offset = parent_offset;
+        let (offset_x, offset_y) = self.offset(object_id);
+        let render_offset = (parent_offset.0 + offset_x, parent_offset.1 + offset_y);
+        let opacity = inherited_opacity * self.opacity(object_id);
         let absolute_bounds = Rect::new(
-            local_bounds.x + parent_offset.0,
-            local_bounds.y + parent_offset.1,
+            local_bounds.x + render_offset.0,
+            local_bounds.y + render_offset.1,
             local_bounds.width,
             local_bounds.height,
         );
-        let enabled = inherited_enabled && object.is_enabled() && component.is_enabled();
+        // Fully transparent elements are neither drawn nor hit.
+        let enabled = inherited_enabled && object.is_enabled() && component.is_enabled() && opacity > 0.0;
+        let interactable = inherited_interactable && self.is_interactable(object_id);
         entries.push(UIEntry {
             object_id,
             depth: component.ui_depth() as f64,
             bounds: absolute_bounds,
             render_offset,
+            opacity,
             enabled,
+            interactable,
+            focusable: component.is_focusable(),
+            modal: Self::modal_panel(object).is_some(),
+            clip,
         });
 
-        let child_offset = (absolute_bounds.x, absolute_bounds.y);
+        let mut child_offset = (absolute_bounds.x, absolute_bounds.y);
+        let mut child_clip = clip;
+        if let Some((scroll_x, scroll_y)) = Self::child_clipping(object) {
+            child_offset.0 -= scroll_x;
+            child_offset.1 -= scroll_y;
+            child_clip = Some(clip.map_or(absolute_bounds, |outer| outer.intersect(&absolute_bounds)));
+        }
         for child_id in object.children() {
             self.collect_ui_entries_recursive(
                 object_manager,
                 *child_id,
                 child_offset,
+                opacity,
                 enabled,
+                interactable,
+                child_clip,
                 entries,
             );
         }
     }
 
-    fn ui_component(object: &GameObject) -> Option<&dyn UIComponentTrait> {
-        if let Some(comp) = object.get_component_by_name("Button") {
-            return comp
-                .as_any()
-                .downcast_ref::<ButtonComponent>()
-                .map(|button| button as &dyn UIComponentTrait);
-        }
-        if let Some(comp) = object.get_component_by_name("Panel") {
-            return comp
-                .as_any()
-                .downcast_ref::<PanelComponent>()
-                .map(|panel| panel as &dyn UIComponentTrait);
-        }
-        if let Some(comp) = object.get_component_by_name("Label") {
-            return comp
-                .as_any()
-                .downcast_ref::<LabelComponent>()
-                .map(|label| label as &dyn UIComponentTrait);
+    /// Hit-test stand-ins for `entries`. While a modal is open, everything
+    /// outside it is disabled and the modal panel covers the whole window, so
+    /// clicks beside it are swallowed instead of reaching the game. Entries
+    /// in `out_of_context` belong to an input context that is not on top.
+    fn hit_proxies(
+        &self,
+        entries: &[UIEntry],
+        modal_scope: Option<&(u32, HashSet<u32>)>,
+        out_of_context: &HashSet<u32>,
+    ) -> Vec<UIHitProxy> {
+        let (width, height) = self.logical_size();
+        entries
+            .iter()
+            .map(|entry| {
+                let bounds = match modal_scope {
+                    Some((modal_id, _)) if *modal_id == entry.object_id => Rect::new(0.0, 0.0, width, height),
+                    // Only the visible part of a clipped entry can be hit.
+                    _ => entry.clip.map_or(entry.bounds, |clip| entry.bounds.intersect(&clip)),
+                };
+                let blocked = modal_scope.is_some_and(|(_, scope)| !scope.contains(&entry.object_id))
+                    || out_of_context.contains(&entry.object_id);
+                UIHitProxy {
+                    bounds,
+                    enabled: entry.enabled
+                        && entry.interactable
+                        && !blocked
+                        && bounds.width > 0.0
+                        && bounds.height > 0.0,
+                    focusable: entry.focusable,
+                    depth: entry.depth as f32,
+                }
+            })
+            .collect()
+    }
+
+    /// The topmost enabled modal panel and the ids of it and all its descendants.
+    fn modal_scope(object_manager: &ObjectManager, entries: &[UIEntry]) -> Option<(u32, HashSet<u32>)> {
+        // `max_by` keeps the last of equal depths, i.e. the most recently added modal.
+        let modal_id = entries
+            .iter()
+            .filter(|entry| entry.modal && entry.enabled)
+            .max_by(|a, b| a.depth.partial_cmp(&b.depth).unwrap_or(std::cmp::Ordering::Equal))?
+            .object_id;
+
+        let mut scope = HashSet::new();
+        let mut pending = vec![modal_id];
+        while let Some(id) = pending.pop() {
+            if scope.insert(id)
+                && let Some(object) = object_manager.get_object_by_id(id)
+            {
+                pending.extend_from_slice(object.children());
+            }
         }
-        None
+        Some((modal_id, scope))
     }
 
-    fn render_component(object: &GameObject, draw_manager: &mut DrawManager, offset: (f32, f32)) {
-        if let Some(comp) = object.get_component_by_name("Button")
-            && let Some(button) = comp.as_any().downcast_ref::<ButtonComponent>()
-        {
-            button.render(draw_manager, offset);
+    fn modal_panel(object: &GameObject) -> Option<&PanelComponent> {
+        object
+            .get_component_by_name("Panel")
+            .and_then(|comp| comp.as_any().downcast_ref::<PanelComponent>())
+            .filter(|panel| panel.is_modal())
+    }
+
+    /// Dim the whole window just beneath a modal panel.
+    fn render_modal_backdrop(&self, object: &GameObject, draw_manager: &mut DrawManager) {
+        let Some(panel) = Self::modal_panel(object) else {
             return;
-        }
-        if let Some(comp) = object.get_component_by_name("Panel")
-            && let Some(panel) = comp.as_any().downcast_ref::<PanelComponent>()
-        {
-            panel.render(draw_manager, offset);
+        };
+        let color = panel.dim_color();
+        if color[3] <= 0.0 {
             return;
         }
-        if let Some(comp) = object.get_component_by_name("Label")
-            && let Some(label) = comp.as_any().downcast_ref::<LabelComponent>()
-        {
-            label.render(draw_manager, offset);
-        }
+        let (width, height) = self.logical_size();
+        draw_manager.draw_rectangle_with_options(
+            0.0,
+            0.0,
+            width,
+            height,
+            crate::types::color::Color::new(color[0], color[1], color[2], color[3]),
+            true,
+            1.0,
+            panel.ui_depth() - 0.001,
+        );
     }
 
-    fn dispatch_event(object: &mut GameObject, event: &UIEvent) {
-        if let Some(comp) = object.get_component_by_name_mut("Button")
-            && let Some(button) = comp.as_any_mut().downcast_mut::<ButtonComponent>()
+    /// Scroll offset to apply to the children of `object` when it clips them.
+    fn child_clipping(object: &GameObject) -> Option<(f32, f32)> {
+        if let Some(comp) = object.get_component_by_name("ScrollView")
+            && let Some(scroll_view) = comp.as_any().downcast_ref::<ScrollViewComponent>()
         {
-            button.handle_event(event);
-            return;
+            return Some(scroll_view.scroll());
         }
-        if let Some(comp) = object.get_component_by_name_mut("Panel")
-            && let Some(panel) = comp.as_any_mut().downcast_mut::<PanelComponent>()
+        if let Some(comp) = object.get_component_by_name("ListView")
+            && let Some(list) = comp.as_any().downcast_ref::<ListViewComponent>()
         {
-            panel.handle_event(event);
-            return;
+            return Some((0.0, list.scroll_offset()));
         }
-        if let Some(comp) = object.get_component_by_name_mut("Label")
-            && let Some(label) = comp.as_any_mut().downcast_mut::<LabelComponent>()
+        if let Some(comp) = object.get_component_by_name("Panel")
+            && let Some(panel) = comp.as_any().downcast_ref::<PanelComponent>()
+            && panel.clip_children()
         {
-            label.handle_event(event);
+            return Some((0.0, 0.0));
+        }
+        None
+    }
+
+    /// Measure scroll view content from the children's local bounds.
+    /// Resize `auto_height` labels to their text as measured by `measure`,
+    /// normally the renderer's text layout, and lay the UI out again when
+    /// any label changed height. Runs after `update` so wrapping uses the
+    /// widths from this frame's layout.
+    pub fn fit_label_heights(
+        &mut self,
+        object_manager: &mut ObjectManager,
+        measure: &mut dyn FnMut(&str, &TextStyle, &TextLayoutOptions) -> (f32, f32),
+    ) {
+        let mut changed = false;
+        for id in object_manager.get_keys().collect::<Vec<_>>() {
+            if let Some(object) = object_manager.get_object_by_id_mut(id)
+                && let Some(label) = object.get_component_mut_untracked::<LabelComponent>()
+                && label.fit_height(measure)
+            {
+                self.dirty.insert(id);
+                changed = true;
+            }
+        }
+        if changed {
+            self.apply_layout(object_manager);
+            self.apply_tweens(object_manager);
+            self.fit_scroll_view_content(object_manager);
         }
     }
+
+    fn fit_scroll_view_content(&mut self, object_manager: &mut ObjectManager) {
+        let mut sizes = Vec::new();
+        for id in object_manager.get_keys() {
+            let Some(object) = object_manager.get_object_by_id(id) else {
+                continue;
+            };
+            if object.get_component_by_name("ScrollView").is_none() {
+                continue;
+            }
+
+            let mut size = (0.0_f32, 0.0_f32);
+            for child_id in object.children() {
+                if let Some(child) = object_manager.get_object_by_id(*child_id)
+                    && let Some(component) = Self::ui_component(child)
+                {
+                    let bounds = component.bounds();
+                    size.0 = size.0.max(bounds.x + bounds.width);
+                    size.1 = size.1.max(bounds.y + bounds.height);
+                }
+            }
+            sizes.push((id, size));
+        }
+
+        for (id, (width, height)) in sizes {
+            if let Some(object) = object_manager.get_object_by_id_mut(id)
+                && let Some(comp) = object.get_component_by_name_mut_untracked("ScrollView")
+                && let Some(scroll_view) = comp.as_any_mut().downcast_mut::<ScrollViewComponent>()
+            {
+                let before = (scroll_view.content_size(), scroll_view.scroll());
+                scroll_view.fit_content(width, height);
+                if (scroll_view.content_size(), scroll_view.scroll()) != before {
+                    self.dirty.insert(id);
+                }
+            }
+        }
+    }
+
+    /// Move widgets to the current theme: all of them after a theme switch,
+    /// otherwise only the ones created since the last update, which start out
+    /// with the light theme's styles.
+    fn apply_theme(&mut self, object_manager: &mut ObjectManager) {
+        let previous = self.previous_theme.take();
+        let ids: Vec<u32> = object_manager.get_keys().collect();
+        for id in ids {
+            let styled = self.themed.contains(&id);
+            let old = match (&previous, styled) {
+                (Some(previous), true) => previous,
+                (None, true) => continue,
+                (_, false) => UITheme::light(),
+            };
+            let Some(object) = object_manager.get_object_by_id_mut(id) else {
+                continue;
+            };
+            if object.get_object_type() != ObjectType::UIObject {
+                continue;
+            }
+            let theme = &self.theme;
+            Self::with_ui_component_mut(object, |component| component.apply_theme(old, theme));
+            self.themed.insert(id);
+            self.dirty.insert(id);
+        }
+        if previous.is_some() {
+            self.themed.retain(|id| object_manager.get_object_by_id(*id).is_some());
+        }
+    }
+
+    /// Write the running tweens' values into their elements. Runs after the
+    /// layout pass, so a tween on the bounds overrides the layout while it lasts.
+    fn apply_tweens(&mut self, object_manager: &mut ObjectManager) {
+        let mut tweens = std::mem::take(&mut self.tweens);
+        tweens.retain_mut(|tween| {
+            let object_id = tween.object_id();
+            if !tween.is_started() {
+                return object_manager.get_object_by_id(object_id).is_some();
+            }
+            let applied = object_manager.get_object_by_id_mut(object_id).is_some_and(|object| {
+                if tween.start_value().is_none()
+                    && let Some(current) = self.property_value(object, tween.property())
+                {
+                    tween.capture_from(current);
+                }
+                tween
+                    .value()
+                    .is_some_and(|value| self.set_property_value(object, tween.property(), value))
+            });
+            if !applied || tween.is_finished() {
+                self.finished_tweens.push(tween.id());
+                return false;
+            }
+            true
+        });
+        self.tweens = tweens;
+
+        self.offsets.retain(|id, _| object_manager.get_object_by_id(*id).is_some());
+        self.opacity.retain(|id, _| object_manager.get_object_by_id(*id).is_some());
+        self.hover_cursors.retain(|id, _| object_manager.get_object_by_id(*id).is_some());
+        self.input_contexts.retain(|id, _| object_manager.get_object_by_id(*id).is_some());
+    }
+
+    fn property_value(&self, object: &GameObject, property: TweenProperty) -> Option<[f32; 4]> {
+        let number = |value: f32| Some([value, 0.0, 0.0, 0.0]);
+        match property {
+            TweenProperty::X => number(Self::ui_component(object)?.bounds().x),
+            TweenProperty::Y => number(Self::ui_component(object)?.bounds().y),
+            TweenProperty::Width => number(Self::ui_component(object)?.bounds().width),
+            TweenProperty::Height => number(Self::ui_component(object)?.bounds().height),
+            TweenProperty::OffsetX => number(self.offset(object.get_id()).0),
+            TweenProperty::OffsetY => number(self.offset(object.get_id()).1),
+            TweenProperty::Opacity => number(self.opacity(object.get_id())),
+            TweenProperty::BackgroundColor => Some(object.get_component::<PanelComponent>()?.style().background_color),
+            TweenProperty::BorderColor => Some(object.get_component::<PanelComponent>()?.style().border_color),
+            TweenProperty::TextColor => Some(object.get_component::<LabelComponent>()?.style().text_color),
+        }
+    }
+
+    /// Returns false when the element has no such property.
+    fn set_property_value(&mut self, object: &mut GameObject, property: TweenProperty, value: [f32; 4]) -> bool {
+        let object_id = object.get_id();
+        let set_bounds = |object: &mut GameObject, update: fn(&mut Rect, f32)| {
+            Self::with_ui_component_mut(object, |component| {
+                let mut bounds = component.bounds();
+                update(&mut bounds, value[0]);
+                component.set_bounds(bounds);
+            })
+            .is_some()
+        };
+        let applied = match property {
+            TweenProperty::X => set_bounds(object, |bounds, value| bounds.x = value),
+            TweenProperty::Y => set_bounds(object, |bounds, value| bounds.y = value),
+            TweenProperty::Width => set_bounds(object, |bounds, value| bounds.width = value.max(0.0)),
+            TweenProperty::Height => set_bounds(object, |bounds, value| bounds.height = value.max(0.0)),
+            TweenProperty::OffsetX => {
+                let (_, y) = self.offset(object_id);
+                self.set_offset(object_id, value[0], y);
+                true
+            }
+            TweenProperty::OffsetY => {
+                let (x, _) = self.offset(object_id);
+                self.set_offset(object_id, x, value[0]);
+                true
+            }
+            TweenProperty::Opacity => {
+                self.set_opacity(object_id, value[0]);
+                true
+            }
+            TweenProperty::BackgroundColor | TweenProperty::BorderColor => {
+                let Some(panel) = object.get_component_mut::<PanelComponent>() else {
+                    return false;
+                };
+                let style = panel.style_mut();
+                if property == TweenProperty::BackgroundColor {
+                    style.background_color = value;
+                } else {
+                    style.border_color = value;
+                }
+                true
+            }
+            TweenProperty::TextColor => {
+                let Some(label) = object.get_component_mut::<LabelComponent>() else {
+                    return false;
+                };
+                label.style_mut().text_color = value;
+                true
+            }
+        };
+        if applied {
+            self.dirty.insert(object_id);
+        }
+        applied
+    }
+
+    /// Fetch the rows list views scrolled into view this frame, so rendering
+    /// only reads cached row text.
+    fn sync_list_views(&mut self, object_manager: &mut ObjectManager) {
+        let ids: Vec<u32> = object_manager.get_keys().collect();
+        for id in ids {
+            if let Some(object) = object_manager.get_object_by_id_mut(id)
+                && let Some(comp) = object.get_component_by_name_mut_untracked("ListView")
+                && let Some(list) = comp.as_any_mut().downcast_mut::<ListViewComponent>()
+                && list.sync_rows()
+            {
+                self.dirty.insert(id);
+            }
+        }
+    }
+
+    /// Re-render canvases that asked for a redraw or redraw every frame.
+    fn sync_canvases(&mut self, object_manager: &ObjectManager) {
+        for id in object_manager.get_keys() {
+            if let Some(object) = object_manager.get_object_by_id(id)
+                && let Some(comp) = object.get_component_by_name("Canvas")
+                && let Some(canvas) = comp.as_any().downcast_ref::<CanvasComponent>()
+                && canvas.take_redraw_request()
+            {
+                self.dirty.insert(id);
+            }
+        }
+    }
+
+    /// Send a wheel event to the nearest custom widget, scroll view or list view at or
+    /// above `object_id` that handles it. Returns the id of the one that did.
+    fn dispatch_wheel(object_manager: &mut ObjectManager, object_id: u32, event: &UIEvent) -> Option<u32> {
+        let mut current = Some(object_id);
+        while let Some(id) = current {
+            let object = object_manager.get_object_by_id_mut(id)?;
+            if let Some(comp) = object.get_component_by_name_mut("ScrollView")
+                && let Some(scroll_view) = comp.as_any_mut().downcast_mut::<ScrollViewComponent>()
+                && scroll_view.handle_event(event)
+            {
+                return Some(id);
+            }
+            if let Some(comp) = object.get_component_by_name_mut("CustomWidget")
+                && let Some(widget) = comp.as_any_mut().downcast_mut::<CustomWidgetComponent>()
+                && widget.handle_event(event)
+            {
+                return Some(id);
+            }
+            if let Some(comp) = object.get_component_by_name_mut("ListView")
+                && let Some(list) = comp.as_any_mut().downcast_mut::<ListViewComponent>()
+                && list.handle_event(event)
+            {
+                return Some(id);
+            }
+            current = object.parent_id();
+        }
+        None
+    }
+
+    fn ui_component(object: &GameObject) -> Option<&dyn UIComponentTrait> {
+        if let Some(comp) = object.get_component_by_name("Button") {
+            return comp
+                .as_any()
+                .downcast_ref::<ButtonComponent>()
+                .map(|button| button as &dyn UIComponentTrait);
+        }
+        if let Some(comp) = object.get_component_by_name("Panel") {
+            return comp
+                .as_any()
+                .downcast_ref::<PanelComponent>()
+                .map(|panel| panel as &dyn UIComponentTrait);
+        }
+        if let Some(comp) = object.get_component_by_name("Label") {
+            return comp
+                .as_any()
+                .downcast_ref::<LabelComponent>()
+                .map(|label| label as &dyn UIComponentTrait);
+        }
+        if let Some(comp) = object.get_component_by_name("TextInput") {
+            return comp
+                .as_any()
+                .downcast_ref::<TextInputComponent>()
+                .map(|input| input as &dyn UIComponentTrait);
+        }
+        if let Some(comp) = object.get_component_by_name("Slider") {
+            return comp
+                .as_any()
+                .downcast_ref::<SliderComponent>()
+                .map(|slider| slider as &dyn UIComponentTrait);
+        }
+        if let Some(comp) = object.get_component_by_name("Checkbox") {
+            return comp
+                .as_any()
+                .downcast_ref::<CheckboxComponent>()
+                .map(|checkbox| checkbox as &dyn UIComponentTrait);
+        }
+        if let Some(comp) = object.get_component_by_name("ToggleSwitch") {
+            return comp
+                .as_any()
+                .downcast_ref::<ToggleSwitchComponent>()
+                .map(|switch| switch as &dyn UIComponentTrait);
+        }
+        if let Some(comp) = object.get_component_by_name("ScrollView") {
+            return comp
+                .as_any()
+                .downcast_ref::<ScrollViewComponent>()
+                .map(|scroll_view| scroll_view as &dyn UIComponentTrait);
+        }
+        if let Some(comp) = object.get_component_by_name("ProgressBar") {
+            return comp
+                .as_any()
+                .downcast_ref::<ProgressBarComponent>()
+                .map(|bar| bar as &dyn UIComponentTrait);
+        }
+        if let Some(comp) = object.get_component_by_name("CustomWidget") {
+            return comp
+                .as_any()
+                .downcast_ref::<CustomWidgetComponent>()
+                .map(|widget| widget as &dyn UIComponentTrait);
+        }
+        if let Some(comp) = object.get_component_by_name("Canvas") {
+            return comp
+                .as_any()
+                .downcast_ref::<CanvasComponent>()
+                .map(|canvas| canvas as &dyn UIComponentTrait);
+        }
+        if let Some(comp) = object.get_component_by_name("Image") {
+            return comp
+                .as_any()
+                .downcast_ref::<ImageComponent>()
+                .map(|image| image as &dyn UIComponentTrait);
+        }
+        if let Some(comp) = object.get_component_by_name("Stack") {
+            return comp
+                .as_any()
+                .downcast_ref::<StackComponent>()
+                .map(|stack| stack as &dyn UIComponentTrait);
+        }
+        if let Some(comp) = object.get_component_by_name("CurveEditor") {
+            return comp
+                .as_any()
+                .downcast_ref::<CurveEditorComponent>()
+                .map(|editor| editor as &dyn UIComponentTrait);
+        }
+        if let Some(comp) = object.get_component_by_name("RadioButton") {
+            return comp
+                .as_any()
+                .downcast_ref::<RadioButtonComponent>()
+                .map(|radio| radio as &dyn UIComponentTrait);
+        }
+        if let Some(comp) = object.get_component_by_name("ListView") {
+            return comp
+                .as_any()
+                .downcast_ref::<ListViewComponent>()
+                .map(|list| list as &dyn UIComponentTrait);
+        }
+        None
+    }
+
+    fn render_component(object: &GameObject, draw_manager: &mut DrawManager, offset: (f32, f32)) {
+        if let Some(comp) = object.get_component_by_name("Button")
+            && let Some(button) = comp.as_any().downcast_ref::<ButtonComponent>()
+        {
+            button.render(draw_manager, offset);
+            return;
+        }
+        if let Some(comp) = object.get_component_by_name("Panel")
+            && let Some(panel) = comp.as_any().downcast_ref::<PanelComponent>()
+        {
+            panel.render(draw_manager, offset);
+            return;
+        }
+        if let Some(comp) = object.get_component_by_name("Label")
+            && let Some(label) = comp.as_any().downcast_ref::<LabelComponent>()
+        {
+            label.render(draw_manager, offset);
+            return;
+        }
+        if let Some(comp) = object.get_component_by_name("TextInput")
+            && let Some(input) = comp.as_any().downcast_ref::<TextInputComponent>()
+        {
+            input.render(draw_manager, offset);
+            return;
+        }
+        if let Some(comp) = object.get_component_by_name("Slider")
+            && let Some(slider) = comp.as_any().downcast_ref::<SliderComponent>()
+        {
+            slider.render(draw_manager, offset);
+            return;
+        }
+        if let Some(comp) = object.get_component_by_name("Checkbox")
+            && let Some(checkbox) = comp.as_any().downcast_ref::<CheckboxComponent>()
+        {
+            checkbox.render(draw_manager, offset);
+            return;
+        }
+        if let Some(comp) = object.get_component_by_name("ToggleSwitch")
+            && let Some(switch) = comp.as_any().downcast_ref::<ToggleSwitchComponent>()
+        {
+            switch.render(draw_manager, offset);
+            return;
+        }
+        if let Some(comp) = object.get_component_by_name("ScrollView")
+            && let Some(scroll_view) = comp.as_any().downcast_ref::<ScrollViewComponent>()
+        {
+            scroll_view.render(draw_manager, offset);
+            return;
+        }
+        if let Some(comp) = object.get_component_by_name("ProgressBar")
+            && let Some(bar) = comp.as_any().downcast_ref::<ProgressBarComponent>()
+        {
+            bar.render(draw_manager, offset);
+            return;
+        }
+        if let Some(comp) = object.get_component_by_name("CustomWidget")
+            && let Some(widget) = comp.as_any().downcast_ref::<CustomWidgetComponent>()
+        {
+            widget.render(draw_manager, offset);
+            return;
+        }
+        if let Some(comp) = object.get_component_by_name("Canvas")
+            && let Some(canvas) = comp.as_any().downcast_ref::<CanvasComponent>()
+        {
+            canvas.render(draw_manager, offset);
+            return;
+        }
+        if let Some(comp) = object.get_component_by_name("Image")
+            && let Some(image) = comp.as_any().downcast_ref::<ImageComponent>()
+        {
+            image.render(draw_manager, offset);
+            return;
+        }
+        if let Some(comp) = object.get_component_by_name("Stack")
+            && let Some(stack) = comp.as_any().downcast_ref::<StackComponent>()
+        {
+            stack.render(draw_manager, offset);
+            return;
+        }
+        if let Some(comp) = object.get_component_by_name("CurveEditor")
+            && let Some(editor) = comp.as_any().downcast_ref::<CurveEditorComponent>()
+        {
+            editor.render(draw_manager, offset);
+            return;
+        }
+        if let Some(comp) = object.get_component_by_name("RadioButton")
+            && let Some(radio) = comp.as_any().downcast_ref::<RadioButtonComponent>()
+        {
+            radio.render(draw_manager, offset);
+            return;
+        }
+        if let Some(comp) = object.get_component_by_name("ListView")
+            && let Some(list) = comp.as_any().downcast_ref::<ListViewComponent>()
+        {
+            list.render(draw_manager, offset);
+        }
+    }
+
+    /// Select a radio button and deselect the other members of its group.
+    ///
+    /// With `notify` set, the group's `on_selection_changed` callback fires
+    /// afterwards. Returns the ids of the buttons that were deselected.
+    pub fn select_radio_button(object_manager: &mut ObjectManager, object_id: u32, notify: bool) -> Vec<u32> {
+        let Some(radio) = object_manager
+            .get_object_by_id_mut(object_id)
+            .and_then(|object| object.get_component_mut::<RadioButtonComponent>())
+        else {
+            return Vec::new();
+        };
+        radio.set_selected(true);
+        let Some(group) = radio.group().cloned() else {
+            return Vec::new();
+        };
+        let value = radio.value().to_string();
+
+        let mut deselected = Vec::new();
+        for id in object_manager.get_keys().collect::<Vec<_>>() {
+            if id == object_id {
+                continue;
+            }
+            if let Some(other) = object_manager
+                .get_object_by_id_mut(id)
+                .and_then(|object| object.get_component_mut::<RadioButtonComponent>())
+                && other.is_selected()
+                && other.group().is_some_and(|other_group| other_group.same_group(&group))
+            {
+                other.set_selected(false);
+                deselected.push(id);
+            }
+        }
+
+        if notify {
+            group.notify_selection_changed(&value);
+        }
+        deselected
+    }
+
+    /// Focus direction for an arrow key press: `true` for next, `false` for previous.
+    fn arrow_navigation(event: &UIEvent) -> Option<bool> {
+        let UIEvent::KeyDown { key: Key::Named(key), .. } = event else {
+            return None;
+        };
+        match key {
+            NamedKey::ArrowDown | NamedKey::ArrowRight => Some(true),
+            NamedKey::ArrowUp | NamedKey::ArrowLeft => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Send an event to `object_id` through the capture, target and bubble
+    /// phases (see `UIEventManager::propagate`). Propagation covers the
+    /// target's chain of UI ancestors. Returns the id of the handler.
+    fn dispatch_event(object_manager: &mut ObjectManager, object_id: u32, event: &UIEvent) -> Option<u32> {
+        let mut path = Vec::new();
+        let mut current = Some(object_id);
+        while let Some(id) = current
+            && let Some(object) = object_manager.get_object_by_id(id)
+            && object.get_object_type() == ObjectType::UIObject
+        {
+            path.push(id);
+            current = object.parent_id();
+        }
+
+        UIEventManager::propagate(event, &path, |id, phase| {
+            object_manager.get_object_by_id_mut(id).is_some_and(|object| {
+                Self::with_ui_component_mut(object, |component| component.handle_event_in_phase(event, phase))
+                    == Some(true)
+            })
+        })
+    }
+
+    /// Run `f` on the object's UI component, if it has one.
+    ///
+    /// Leaves the object's component revision alone; callers mark what they
+    /// change dirty themselves.
+    fn with_ui_component_mut<R>(
+        object: &mut GameObject,
+        f: impl FnOnce(&mut dyn UIComponentTrait) -> R,
+    ) -> Option<R> {
+        if let Some(comp) = object.get_component_by_name_mut_untracked("Button")
+            && let Some(button) = comp.as_any_mut().downcast_mut::<ButtonComponent>()
+        {
+            return Some(f(button));
+        }
+        if let Some(comp) = object.get_component_by_name_mut_untracked("Panel")
+            && let Some(panel) = comp.as_any_mut().downcast_mut::<PanelComponent>()
+        {
+            return Some(f(panel));
+        }
+        if let Some(comp) = object.get_component_by_name_mut_untracked("Label")
+            && let Some(label) = comp.as_any_mut().downcast_mut::<LabelComponent>()
+        {
+            return Some(f(label));
+        }
+        if let Some(comp) = object.get_component_by_name_mut_untracked("TextInput")
+            && let Some(input) = comp.as_any_mut().downcast_mut::<TextInputComponent>()
+        {
+            return Some(f(input));
+        }
+        if let Some(comp) = object.get_component_by_name_mut_untracked("Slider")
+            && let Some(slider) = comp.as_any_mut().downcast_mut::<SliderComponent>()
+        {
+            return Some(f(slider));
+        }
+        if let Some(comp) = object.get_component_by_name_mut_untracked("Checkbox")
+            && let Some(checkbox) = comp.as_any_mut().downcast_mut::<CheckboxComponent>()
+        {
+            return Some(f(checkbox));
+        }
+        if let Some(comp) = object.get_component_by_name_mut_untracked("ToggleSwitch")
+            && let Some(switch) = comp.as_any_mut().downcast_mut::<ToggleSwitchComponent>()
+        {
+            return Some(f(switch));
+        }
+        if let Some(comp) = object.get_component_by_name_mut_untracked("ScrollView")
+            && let Some(scroll_view) = comp.as_any_mut().downcast_mut::<ScrollViewComponent>()
+        {
+            return Some(f(scroll_view));
+        }
+        if let Some(comp) = object.get_component_by_name_mut_untracked("ProgressBar")
+            && let Some(bar) = comp.as_any_mut().downcast_mut::<ProgressBarComponent>()
+        {
+            return Some(f(bar));
+        }
+        if let Some(comp) = object.get_component_by_name_mut_untracked("CustomWidget")
+            && let Some(widget) = comp.as_any_mut().downcast_mut::<CustomWidgetComponent>()
+        {
+            return Some(f(widget));
+        }
+        if let Some(comp) = object.get_component_by_name_mut_untracked("Canvas")
+            && let Some(canvas) = comp.as_any_mut().downcast_mut::<CanvasComponent>()
+        {
+            return Some(f(canvas));
+        }
+        if let Some(comp) = object.get_component_by_name_mut_untracked("Image")
+            && let Some(image) = comp.as_any_mut().downcast_mut::<ImageComponent>()
+        {
+            return Some(f(image));
+        }
+        if let Some(comp) = object.get_component_by_name_mut_untracked("Stack")
+            && let Some(stack) = comp.as_any_mut().downcast_mut::<StackComponent>()
+        {
+            return Some(f(stack));
+        }
+        if let Some(comp) = object.get_component_by_name_mut_untracked("CurveEditor")
+            && let Some(editor) = comp.as_any_mut().downcast_mut::<CurveEditorComponent>()
+        {
+            return Some(f(editor));
+        }
+        if let Some(comp) = object.get_component_by_name_mut_untracked("RadioButton")
+            && let Some(radio) = comp.as_any_mut().downcast_mut::<RadioButtonComponent>()
+        {
+            return Some(f(radio));
+        }
+        if let Some(comp) = object.get_component_by_name_mut_untracked("ListView")
+            && let Some(list) = comp.as_any_mut().downcast_mut::<ListViewComponent>()
+        {
+            return Some(f(list));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ui::layout::{Anchor, AnchorOffset};
+    use crate::core::ui::SizeMode;
+    use crate::core::ui::stack::StackAlignment;
+
+    fn add_ui_object(
+        object_manager: &mut ObjectManager,
+        component: Box<dyn ComponentTrait>,
+        layout: UILayoutComponent,
+    ) -> u32 {
+        let mut object = GameObject::new();
+        object.set_object_type(ObjectType::UIObject);
+        object.add_component(component);
+        object.add_component(Box::new(layout));
+        object_manager.add_object(object).unwrap()
+    }
+
+    #[test]
+    fn test_layout_follows_window_and_parent() {
+        let mut object_manager = ObjectManager::new();
+        let mut ui_manager = UIManager::new(1600.0, 1200.0, 2.0);
+
+        let panel_id = add_ui_object(
+            &mut object_manager,
+            Box::new(PanelComponent::new("Panel")),
+            UILayoutComponent::with_anchor(Anchor::Stretch, AnchorOffset::new(10.0, 10.0)),
+        );
+        let mut corner = UILayoutComponent::with_anchor(Anchor::BottomRight, AnchorOffset::zero());
+        corner.width_mode = SizeMode::Percentage(0.5);
+        corner.height_mode = SizeMode::Fixed(20.0);
+        let label_id = add_ui_object(&mut object_manager, Box::new(LabelComponent::new("Label")), corner);
+        object_manager.add_child(panel_id, label_id).unwrap();
+
+        let bounds_of = |object_manager: &ObjectManager, id: u32| {
+            UIManager::ui_component(object_manager.get_object_by_id(id).unwrap())
+                .unwrap()
+                .bounds()
+        };
+
+        // Layout works in logical pixels: 1600x1200 at 2x is 800x600.
+        ui_manager.apply_layout(&mut object_manager);
+        assert_eq!(bounds_of(&object_manager, panel_id), Rect::new(10.0, 10.0, 780.0, 580.0));
+        assert_eq!(bounds_of(&object_manager, label_id), Rect::new(390.0, 560.0, 390.0, 20.0));
+
+        ui_manager.resize(1000.0, 800.0);
+        ui_manager.apply_layout(&mut object_manager);
+        assert_eq!(bounds_of(&object_manager, panel_id), Rect::new(10.0, 10.0, 480.0, 380.0));
+        assert_eq!(bounds_of(&object_manager, label_id), Rect::new(240.0, 360.0, 240.0, 20.0));
+    }
+
+    #[test]
+    fn test_scale_modes_map_reference_layout_to_window() {
+        let mut object_manager = ObjectManager::new();
+        let mut ui_manager = UIManager::new(3840.0, 2160.0, 1.0);
+        let mut draw_manager = DrawManager::new();
+        let panel = PanelComponent::new("Panel").with_bounds(100.0, 50.0, 200.0, 100.0);
+        add_ui_object(&mut object_manager, Box::new(panel), UILayoutComponent::new());
+
+        let panel_rect = |draw_manager: &DrawManager| match draw_manager.commands()[0] {
+            DrawCommand::Rectangle { x, y, width, height, .. } => (x, y, width, height),
+            ref other => panic!("unexpected command {other:?}"),
+        };
+
+        // A UI designed at 1080p doubles in size at 4K.
+        ui_manager.set_scale_mode(UIScaleMode::ScaleWithHeight { reference_height: 1080.0 });
+        assert_eq!(ui_manager.logical_size(), (1920.0, 1080.0));
+        ui_manager.render(&mut draw_manager, &object_manager);
+        assert_eq!(panel_rect(&draw_manager), (200.0, 100.0, 400.0, 200.0));
+        assert_eq!(ui_manager.to_ui_point((400.0, 200.0)), (200.0, 100.0));
+
+        // A 4:3 window letterboxes a fixed 16:9 layout, centered vertically.
+        ui_manager.resize(960.0, 720.0);
+        ui_manager.set_scale_mode(UIScaleMode::FixedResolution { width: 1920.0, height: 1080.0 });
+        assert_eq!(ui_manager.ui_scale(), 0.5);
+        assert_eq!(ui_manager.ui_origin(), (0.0, 90.0));
+        assert_eq!(ui_manager.logical_size(), (1920.0, 1080.0));
+        ui_manager.render(&mut draw_manager, &object_manager);
+        assert_eq!(panel_rect(&draw_manager), (50.0, 115.0, 100.0, 50.0));
+        assert_eq!(ui_manager.to_ui_point((50.0, 115.0)), (100.0, 50.0));
+
+        ui_manager.set_scale_mode(UIScaleMode::ConstantPixelSize);
+        assert_eq!(ui_manager.logical_size(), (960.0, 720.0));
+        ui_manager.render(&mut draw_manager, &object_manager);
+        assert_eq!(panel_rect(&draw_manager), (100.0, 50.0, 200.0, 100.0));
+    }
+
+    #[test]
+    fn test_unhandled_events_bubble_to_parent() {
+        use crate::core::input_manager::MouseButtonType;
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut object_manager = ObjectManager::new();
+        let clicks = Arc::new(AtomicUsize::new(0));
+        let mut button = ButtonComponent::new("Button").with_bounds(0.0, 0.0, 100.0, 40.0);
+        let counter = Arc::clone(&clicks);
+        button.set_on_click(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        let button_id = add_ui_object(&mut object_manager, Box::new(button), UILayoutComponent::new());
+        let label_id = add_ui_object(
+            &mut object_manager,
+            Box::new(LabelComponent::new("Label")),
+            UILayoutComponent::new(),
+        );
+        object_manager.add_child(button_id, label_id).unwrap();
+
+        let click = UIEvent::Click { x: 10.0, y: 10.0, button: MouseButtonType::Left };
+        assert_eq!(UIManager::dispatch_event(&mut object_manager, label_id, &click), Some(button_id));
+        assert_eq!(clicks.load(Ordering::SeqCst), 1);
+
+        // Focus stays with its target even when unhandled.
+        assert_eq!(UIManager::dispatch_event(&mut object_manager, label_id, &UIEvent::FocusGained), None);
+    }
+
+    #[test]
+    fn test_capturing_parent_sees_events_before_children() {
+        use crate::core::input_manager::MouseButtonType;
+        use crate::core::ui::event::EventPhase;
+        use std::sync::{Arc, Mutex};
+
+        let mut object_manager = ObjectManager::new();
+        let phases = Arc::new(Mutex::new(Vec::new()));
+        let mut card = CustomWidgetComponent::new("CustomWidget").with_bounds(0.0, 0.0, 200.0, 100.0);
+        card.set_capture_events(true);
+        let log = Arc::clone(&phases);
+        card.set_on_event(move |event, phase| {
+            log.lock().unwrap().push((event.name(), phase));
+            // Swallow clicks on the way down, let everything else through.
+            phase == EventPhase::Capture && matches!(event, UIEvent::Click { .. })
+        });
+        let card_id = add_ui_object(&mut object_manager, Box::new(card), UILayoutComponent::new());
+        let clicks = Arc::new(Mutex::new(0));
+        let mut button = ButtonComponent::new("Button").with_bounds(10.0, 10.0, 80.0, 30.0);
+        let counter = Arc::clone(&clicks);
+        button.set_on_click(move || *counter.lock().unwrap() += 1);
+        let button_id = add_ui_object(&mut object_manager, Box::new(button), UILayoutComponent::new());
+        object_manager.add_child(card_id, button_id).unwrap();
+
+        let down = UIEvent::MouseDown { x: 20.0, y: 20.0, button: MouseButtonType::Left };
+        let click = UIEvent::Click { x: 20.0, y: 20.0, button: MouseButtonType::Left };
+        let double_click = UIEvent::DoubleClick { x: 20.0, y: 20.0, button: MouseButtonType::Left };
+        assert_eq!(UIManager::dispatch_event(&mut object_manager, button_id, &down), Some(button_id));
+        assert_eq!(UIManager::dispatch_event(&mut object_manager, button_id, &click), Some(card_id));
+        assert_eq!(*clicks.lock().unwrap(), 0);
+        assert_eq!(UIManager::dispatch_event(&mut object_manager, button_id, &double_click), None);
+
+        // The card hears every event on the way down, and on the way back up
+        // the ones the button leaves unhandled.
+        assert_eq!(
+            *phases.lock().unwrap(),
+            [
+                ("mouse_down", EventPhase::Capture),
+                ("click", EventPhase::Capture),
+                ("double_click", EventPhase::Capture),
+                ("double_click", EventPhase::Bubble),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_modal_panel_blocks_other_widgets() {
+        let mut object_manager = ObjectManager::new();
+        let ui_manager = UIManager::new(800.0, 600.0, 1.0);
+
+        let behind_id = add_ui_object(
+            &mut object_manager,
+            Box::new(ButtonComponent::new("Button").with_bounds(0.0, 0.0, 100.0, 40.0).with_depth(5.0)),
+            UILayoutComponent::new(),
+        );
+        let modal_id = add_ui_object(
+            &mut object_manager,
+            Box::new(PanelComponent::new("Panel").with_bounds(300.0, 200.0, 200.0, 100.0).with_modal(true)),
+            UILayoutComponent::new(),
+        );
+        let ok_id = add_ui_object(
+            &mut object_manager,
+            Box::new(ButtonComponent::new("Button").with_bounds(10.0, 50.0, 80.0, 30.0)),
+            UILayoutComponent::new(),
+        );
+        object_manager.add_child(modal_id, ok_id).unwrap();
+
+        let entries = ui_manager.collect_ui_entries(&object_manager);
+        let scope = UIManager::modal_scope(&object_manager, &entries);
+        assert_eq!(scope.as_ref().map(|(id, ids)| (*id, ids.len())), Some((modal_id, 2)));
+
+        let proxies = ui_manager.hit_proxies(&entries, scope.as_ref(), &HashSet::new());
+        let proxy_of = |id: u32| {
+            let index = entries.iter().position(|entry| entry.object_id == id).unwrap();
+            proxies[index]
+        };
+        // The button behind is blocked even though it is drawn in front.
+        assert!(!proxy_of(behind_id).enabled);
+        assert!(proxy_of(ok_id).enabled && proxy_of(ok_id).focusable);
+        assert_eq!(proxy_of(modal_id).bounds, Rect::new(0.0, 0.0, 800.0, 600.0));
+    }
+
+    #[test]
+    fn test_radio_group_keeps_one_selected() {
+        use crate::core::input_manager::MouseButtonType;
+        use crate::core::ui::radio_button::RadioGroup;
+        use std::sync::{Arc, Mutex};
+
+        let mut object_manager = ObjectManager::new();
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&changes);
+        let mut group = RadioGroup::new("Difficulty");
+        group.set_on_selection_changed(move |value| log.lock().unwrap().push(value.to_string()));
+
+        let mut add_radio = |value: &str, group: RadioGroup, selected: bool| {
+            let radio = RadioButtonComponent::new("RadioButton")
+                .with_value(value)
+                .with_group(group)
+                .with_selected(selected);
+            add_ui_object(&mut object_manager, Box::new(radio), UILayoutComponent::new())
+        };
+        let easy_id = add_radio("easy", group.clone(), true);
+        let hard_id = add_radio("hard", group, false);
+        // Same name, different group: left alone.
+        let other_id = add_radio("other", RadioGroup::new("Difficulty"), true);
+
+        let click = UIEvent::Click { x: 4.0, y: 4.0, button: MouseButtonType::Left };
+        assert_eq!(UIManager::dispatch_event(&mut object_manager, hard_id, &click), Some(hard_id));
+        let pending = object_manager
+            .get_object_by_id_mut(hard_id)
+            .and_then(|object| object.get_component_mut::<RadioButtonComponent>())
+            .is_some_and(|radio| radio.take_selection_pending());
+        assert!(pending);
+        assert_eq!(UIManager::select_radio_button(&mut object_manager, hard_id, true), vec![easy_id]);
+
+        let selected = |object_manager: &ObjectManager, id: u32| {
+            object_manager
+                .get_object_by_id(id)
+                .and_then(|object| object.get_component::<RadioButtonComponent>())
+                .is_some_and(|radio| radio.is_selected())
+        };
+        assert!(!selected(&object_manager, easy_id));
+        assert!(selected(&object_manager, hard_id));
+        assert!(selected(&object_manager, other_id));
+        assert_eq!(*changes.lock().unwrap(), vec!["hard".to_string()]);
+
+        // Programmatic selection updates the group without notifying.
+        assert_eq!(UIManager::select_radio_button(&mut object_manager, easy_id, false), vec![hard_id]);
+        assert_eq!(changes.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_stack_reflows_children() {
+        let mut object_manager = ObjectManager::new();
+        let ui_manager = UIManager::new(800.0, 600.0, 1.0);
+
+        let mut object = GameObject::new();
+        object.set_object_type(ObjectType::UIObject);
+        object.add_component(Box::new(
+            StackComponent::new("Stack")
+                .with_bounds(20.0, 20.0, 100.0, 0.0)
+                .with_spacing(4.0)
+                .with_alignment(StackAlignment::Stretch)
+                .with_fit_content(true),
+        ));
+        let stack_id = object_manager.add_object(object).unwrap();
+
+        let mut child_ids = Vec::new();
+        for height in [10.0, 20.0, 30.0] {
+            let mut child = GameObject::new();
+            child.set_object_type(ObjectType::UIObject);
+            child.add_component(Box::new(PanelComponent::new("Panel").with_bounds(0.0, 0.0, 50.0, height)));
+            let child_id = object_manager.add_object(child).unwrap();
+            object_manager.add_child(stack_id, child_id).unwrap();
+            child_ids.push(child_id);
+        }
+
+        let bounds_of = |object_manager: &ObjectManager, id: u32| {
+            UIManager::ui_component(object_manager.get_object_by_id(id).unwrap())
+                .unwrap()
+                .bounds()
+        };
+
+        ui_manager.apply_layout(&mut object_manager);
+        assert_eq!(bounds_of(&object_manager, child_ids[2]), Rect::new(0.0, 38.0, 100.0, 30.0));
+        assert_eq!(bounds_of(&object_manager, stack_id), Rect::new(20.0, 20.0, 100.0, 68.0));
+
+        // Removing a child closes the gap and shrinks the stack.
+        object_manager.remove_object(child_ids[1]);
+        ui_manager.apply_layout(&mut object_manager);
+        assert_eq!(bounds_of(&object_manager, child_ids[2]), Rect::new(0.0, 14.0, 100.0, 30.0));
+        assert_eq!(bounds_of(&object_manager, stack_id), Rect::new(20.0, 20.0, 100.0, 44.0));
+    }
+
+    #[test]
+    fn test_auto_height_labels_wrap_and_reflow() {
+        use crate::core::text::wrap_text;
+
+        let mut object_manager = ObjectManager::new();
+        let mut ui_manager = UIManager::new(800.0, 600.0, 1.0);
+
+        let mut object = GameObject::new();
+        object.set_object_type(ObjectType::UIObject);
+        object.add_component(Box::new(
+            StackComponent::new("Stack")
+                .with_bounds(0.0, 0.0, 100.0, 0.0)
+                .with_spacing(4.0)
+                .with_alignment(StackAlignment::Stretch)
+                .with_fit_content(true),
+        ));
+        let stack_id = object_manager.add_object(object).unwrap();
+        let label = LabelComponent::new("Label")
+            .with_text("the quick brown fox")
+            .with_wrap(true)
+            .with_auto_height(true);
+        let label_id = add_ui_object(&mut object_manager, Box::new(label), UILayoutComponent::new());
+        let panel_id = add_ui_object(
+            &mut object_manager,
+            Box::new(PanelComponent::new("Panel").with_bounds(0.0, 0.0, 100.0, 10.0)),
+            UILayoutComponent::new(),
+        );
+        object_manager.add_child(stack_id, label_id).unwrap();
+        object_manager.add_child(stack_id, panel_id).unwrap();
+
+        // A fixed-height layout does not override an auto-height label.
+        let caption = LabelComponent::new("Label").with_text("a").with_auto_height(true);
+        let caption_id = add_ui_object(
+            &mut object_manager,
+            Box::new(caption),
+            UILayoutComponent::with_fixed_size(100.0, 100.0),
+        );
+
+        // 10px per character and 20px per line.
+        let mut measure = |text: &str, _: &TextStyle, layout: &TextLayoutOptions| {
+            let text = match layout.width.filter(|_| layout.wrap) {
+                Some(width) => wrap_text(text, width, 0.0, |_| 10.0),
+                None => text.to_string(),
+            };
+            let width = text.lines().map(|line| line.len()).max().unwrap_or(0) as f32 * 10.0;
+            (width, text.lines().count() as f32 * 20.0)
+        };
+        let bounds_of = |object_manager: &ObjectManager, id: u32| {
+            UIManager::ui_component(object_manager.get_object_by_id(id).unwrap())
+                .unwrap()
+                .bounds()
+        };
+
+        ui_manager.apply_layout(&mut object_manager);
+        ui_manager.fit_label_heights(&mut object_manager, &mut measure);
+        assert_eq!(bounds_of(&object_manager, label_id), Rect::new(0.0, 0.0, 100.0, 40.0));
+        assert_eq!(bounds_of(&object_manager, panel_id).y, 44.0);
+        assert_eq!(bounds_of(&object_manager, stack_id).height, 54.0);
+        assert_eq!(bounds_of(&object_manager, caption_id).height, 20.0);
+
+        // Shorter text shrinks the label and moves its siblings up.
+        object_manager
+            .get_object_by_id_mut(label_id)
+            .unwrap()
+            .get_component_mut::<LabelComponent>()
+            .unwrap()
+            .set_text("fox");
+        ui_manager.fit_label_heights(&mut object_manager, &mut measure);
+        assert_eq!(bounds_of(&object_manager, label_id).height, 20.0);
+        assert_eq!(bounds_of(&object_manager, panel_id).y, 24.0);
+    }
+
+    #[test]
+    fn test_render_retains_unchanged_widgets() {
+        let mut object_manager = ObjectManager::new();
+        let mut ui_manager = UIManager::new(800.0, 600.0, 1.0);
+        let mut draw_manager = DrawManager::new();
+
+        let mut object = GameObject::new();
+        object.set_object_type(ObjectType::UIObject);
+        object.add_component(Box::new(LabelComponent::new("Label").with_text("Score: 0")));
+        let label_id = object_manager.add_object(object).unwrap();
+
+        let label_text = |draw_manager: &DrawManager| {
+            draw_manager.commands().iter().find_map(|command| match command {
+                DrawCommand::Text { text, .. } => Some(text.clone()),
+                _ => None,
+            })
+        };
+
+        ui_manager.render(&mut draw_manager, &object_manager);
+        assert_eq!(label_text(&draw_manager).as_deref(), Some("Score: 0"));
+        assert_eq!(ui_manager.cached_widget_count(), 1);
+
+        // Nothing changed: the previous commands are kept as they are.
+        let version = draw_manager.scene_version();
+        ui_manager.render(&mut draw_manager, &object_manager);
+        assert_eq!(draw_manager.scene_version(), version);
+
+        object_manager
+            .get_object_by_id_mut(label_id)
+            .unwrap()
+            .get_component_mut::<LabelComponent>()
+            .unwrap()
+            .set_text("Score: 10");
+        ui_manager.mark_dirty(label_id);
+        ui_manager.render(&mut draw_manager, &object_manager);
+        assert_eq!(label_text(&draw_manager).as_deref(), Some("Score: 10"));
+        assert_eq!(draw_manager.commands().len(), 1);
+    }
+
+    #[test]
+    fn test_render_redraws_widgets_changed_directly() {
+        let mut object_manager = ObjectManager::new();
+        let mut ui_manager = UIManager::new(800.0, 600.0, 1.0);
+        let mut draw_manager = DrawManager::new();
+
+        let mut object = GameObject::new();
+        object.set_object_type(ObjectType::UIObject);
+        object.add_component(Box::new(LabelComponent::new("Label").with_text("Score: 0")));
+        let label_id = object_manager.add_object(object).unwrap();
+
+        let label_text = |draw_manager: &DrawManager| {
+            draw_manager.commands().iter().find_map(|command| match command {
+                DrawCommand::Text { text, .. } => Some(text.clone()),
+                _ => None,
+            })
+        };
+        ui_manager.render(&mut draw_manager, &object_manager);
+
+        // No `mark_dirty`: the mutable access alone invalidates the cache.
+        object_manager
+            .get_object_by_id_mut(label_id)
+            .unwrap()
+            .get_component_mut::<LabelComponent>()
+            .unwrap()
+            .set_text("Score: 10");
+        ui_manager.render(&mut draw_manager, &object_manager);
+        assert_eq!(label_text(&draw_manager).as_deref(), Some("Score: 10"));
+
+        // Same number of commands, different content: the UI is drawn again.
+        draw_manager.truncate_from(0);
+        draw_manager.draw_text("Other".to_string(), 0.0, 0.0, crate::types::Color::WHITE);
+        ui_manager.render(&mut draw_manager, &object_manager);
+        assert_eq!(label_text(&draw_manager).as_deref(), Some("Score: 10"));
+        assert_eq!(draw_manager.commands().len(), 1);
+    }
+
+    #[test]
+    fn test_list_view_renders_only_visible_rows() {
+        let mut object_manager = ObjectManager::new();
+        let mut ui_manager = UIManager::new(800.0, 600.0, 1.0);
+        let mut draw_manager = DrawManager::new();
+
+        let mut list = ListViewComponent::new("ListView")
+            .with_bounds(0.0, 0.0, 200.0, 100.0)
+            .with_row_height(25.0)
+            .with_item_count(10_000);
+        list.set_item_provider(|index| Some(format!("Item {index}")));
+        let list_id = add_ui_object(&mut object_manager, Box::new(list), UILayoutComponent::new());
+        // A row widget: wheel events over it scroll the list.
+        let row_id = add_ui_object(
+            &mut object_manager,
+            Box::new(LabelComponent::new("Label").with_bounds(0.0, 25.0, 200.0, 25.0)),
+            UILayoutComponent::new(),
+        );
+        object_manager.get_object_by_id_mut(list_id).unwrap().add_child_id(row_id);
+        object_manager.get_object_by_id_mut(row_id).unwrap().set_parent_id(Some(list_id));
+
+        let rendered_rows = |ui_manager: &mut UIManager, draw_manager: &mut DrawManager, object_manager: &mut ObjectManager| {
+            ui_manager.sync_list_views(object_manager);
+            ui_manager.render(draw_manager, object_manager);
+            draw_manager
+                .commands()
+                .iter()
+                .filter_map(|command| match command {
+                    DrawCommand::Text { text, .. } if text.starts_with("Item") => Some(text.clone()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            rendered_rows(&mut ui_manager, &mut draw_manager, &mut object_manager),
+            vec!["Item 0", "Item 1", "Item 2", "Item 3"]
+        );
+
+        let wheel = UIEvent::MouseWheel { x: 10.0, y: 30.0, delta_x: 0.0, delta_y: -5.0 };
+        assert_eq!(UIManager::dispatch_wheel(&mut object_manager, row_id, &wheel), Some(list_id));
+        let rows = rendered_rows(&mut ui_manager, &mut draw_manager, &mut object_manager);
+        assert_eq!(rows.first().map(String::as_str), Some("Item 8"));
+        assert_eq!(rows.len(), 4);
+
+        let list = object_manager.get_object_by_id(list_id).unwrap();
+        assert_eq!(UIManager::child_clipping(list), Some((0.0, 200.0)));
+    }
+
+    #[test]
+    fn test_theme_font_fallbacks_fill_in_unset_labels() {
+        let mut object_manager = ObjectManager::new();
+        let mut ui_manager = UIManager::new(800.0, 600.0, 1.0);
+        let mut draw_manager = DrawManager::new();
+
+        let mut localized = LabelComponent::new("Label").with_text("こんにちは");
+        localized.set_font_fallbacks(vec!["fonts/NotoSansJP.otf".to_string()]);
+        for label in [LabelComponent::new("Label").with_text("Hello"), localized] {
+            let mut object = GameObject::new();
+            object.set_object_type(ObjectType::UIObject);
+            object.add_component(Box::new(label));
+            object_manager.add_object(object).unwrap();
+        }
+
+        ui_manager.set_font_fallbacks(vec!["Noto Sans".to_string(), "Noto Color Emoji".to_string()]);
+        ui_manager.render(&mut draw_manager, &object_manager);
+
+        let fallbacks: Vec<Vec<String>> = draw_manager
+            .commands()
+            .iter()
+            .filter_map(|command| match command {
+                DrawCommand::Text { style, .. } => Some(style.font.fallbacks().to_vec()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            fallbacks,
+            vec![
+                vec!["Noto Sans".to_string(), "Noto Color Emoji".to_string()],
+                vec!["fonts/NotoSansJP.otf".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_theme_switch_restyles_existing_widgets() {
+        let mut object_manager = ObjectManager::new();
+        let mut ui_manager = UIManager::new(800.0, 600.0, 1.0);
+        let light = UITheme::default_light();
+        let dark = UITheme::default_dark();
+
+        let panel = PanelComponent::new("Panel");
+        let panel_id = add_ui_object(&mut object_manager, Box::new(panel), UILayoutComponent::new());
+        let mut custom = LabelComponent::new("Label");
+        custom.set_color([1.0, 0.0, 0.0, 1.0]);
+        let custom_id = add_ui_object(&mut object_manager, Box::new(custom), UILayoutComponent::new());
+        ui_manager.apply_theme(&mut object_manager);
+
+        ui_manager.set_theme(dark.clone());
+        // Created after the switch but before the next update.
+        let label = LabelComponent::new("Label");
+        let label_id = add_ui_object(&mut object_manager, Box::new(label), UILayoutComponent::new());
+        ui_manager.apply_theme(&mut object_manager);
+
+        let panel_background = |object_manager: &ObjectManager| {
+            let object = object_manager.get_object_by_id(panel_id).unwrap();
+            object.get_component::<PanelComponent>().unwrap().style().background_color
+        };
+        let label_color = |object_manager: &ObjectManager, id: u32| {
+            let object = object_manager.get_object_by_id(id).unwrap();
+            object.get_component::<LabelComponent>().unwrap().style().text_color
+        };
+        assert_eq!(panel_background(&object_manager), dark.panel_style.background_color);
+        assert_eq!(label_color(&object_manager, label_id), dark.label_style.text_color);
+        assert_eq!(label_color(&object_manager, custom_id), [1.0, 0.0, 0.0, 1.0]);
+
+        ui_manager.set_theme(light.clone());
+        ui_manager.apply_theme(&mut object_manager);
+        assert_eq!(panel_background(&object_manager), light.panel_style.background_color);
+        assert_eq!(label_color(&object_manager, label_id), light.label_style.text_color);
+    }
+
+    #[test]
+    fn test_clipping_panel_scissors_children() {
+        let mut object_manager = ObjectManager::new();
+        let mut ui_manager = UIManager::new(800.0, 600.0, 1.0);
+        let mut draw_manager = DrawManager::new();
+
+        let panel = PanelComponent::new("Panel")
+            .with_bounds(20.0, 20.0, 100.0, 40.0)
+            .with_clip_children(true);
+        let panel_id = add_ui_object(&mut object_manager, Box::new(panel), UILayoutComponent::new());
+        let label = LabelComponent::new("Label")
+            .with_text("A label much wider than its panel")
+            .with_bounds(8.0, 12.0, 400.0, 20.0);
+        let label_id = add_ui_object(&mut object_manager, Box::new(label), UILayoutComponent::new());
+        object_manager.get_object_by_id_mut(panel_id).unwrap().add_child_id(label_id);
+        object_manager.get_object_by_id_mut(label_id).unwrap().set_parent_id(Some(panel_id));
+
+        let text_clip = |draw_manager: &DrawManager| {
+            let index = draw_manager
+                .commands()
+                .iter()
+                .position(|command| matches!(command, DrawCommand::Text { .. }))
+                .unwrap();
+            draw_manager.clip_rects()[index]
+        };
+        ui_manager.render(&mut draw_manager, &object_manager);
+        assert_eq!(text_clip(&draw_manager), Some(ClipRect::new(20.0, 20.0, 100.0, 40.0)));
+
+        // Turning clipping off at runtime re-renders the child unclipped.
+        object_manager
+            .get_object_by_id_mut(panel_id)
+            .unwrap()
+            .get_component_mut::<PanelComponent>()
+            .unwrap()
+            .set_clip_children(false);
+        ui_manager.mark_dirty(panel_id);
+        ui_manager.render(&mut draw_manager, &object_manager);
+        assert_eq!(text_clip(&draw_manager), None);
+    }
+
+    #[test]
+    fn test_tweens_move_and_fade_elements_with_children() {
+        let mut object_manager = ObjectManager::new();
+        let mut ui_manager = UIManager::new(800.0, 600.0, 1.0);
+        let mut draw_manager = DrawManager::new();
+
+        let panel = PanelComponent::new("Panel").with_bounds(10.0, 10.0, 100.0, 100.0);
+        let panel_id = add_ui_object(&mut object_manager, Box::new(panel), UILayoutComponent::new());
+        let label = LabelComponent::new("Label").with_text("Hi").with_bounds(5.0, 5.0, 50.0, 20.0);
+        let label_id = add_ui_object(&mut object_manager, Box::new(label), UILayoutComponent::new());
+        object_manager.get_object_by_id_mut(panel_id).unwrap().add_child_id(label_id);
+        object_manager.get_object_by_id_mut(label_id).unwrap().set_parent_id(Some(panel_id));
+
+        let slide = UITween::new(panel_id, TweenProperty::OffsetX, [50.0, 0.0, 0.0, 0.0], 1.0);
+        let fade = UITween::new(panel_id, TweenProperty::Opacity, [0.0; 4], 1.0).with_from([1.0; 4]);
+        let slide_id = ui_manager.add_tween(slide);
+        let fade_id = ui_manager.add_tween(fade);
+        ui_manager.advance_tweens(0.5);
+        ui_manager.apply_tweens(&mut object_manager);
+        assert_eq!(ui_manager.offset(panel_id), (25.0, 0.0));
+
+        let entries = ui_manager.collect_ui_entries(&object_manager);
+        let label_entry = entries.iter().find(|entry| entry.object_id == label_id).unwrap();
+        assert_eq!(label_entry.bounds, Rect::new(40.0, 15.0, 50.0, 20.0));
+        assert_eq!(label_entry.opacity, 0.5);
+
+        ui_manager.render(&mut draw_manager, &object_manager);
+        let text_alpha = draw_manager.commands().iter().find_map(|command| match command {
+            DrawCommand::Text { color, .. } => Some(color.a()),
+            _ => None,
+        });
+        assert_eq!(text_alpha, Some(0.5));
+
+        ui_manager.advance_tweens(0.5);
+        ui_manager.apply_tweens(&mut object_manager);
+        assert!(!ui_manager.has_active_tweens());
+        assert_eq!(ui_manager.take_finished_tweens(), vec![slide_id, fade_id]);
+        let entries = ui_manager.collect_ui_entries(&object_manager);
+        assert!(entries.iter().all(|entry| !entry.enabled));
+    }
+
+    #[test]
+    fn test_canvas_redraws_only_when_requested() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let mut object_manager = ObjectManager::new();
+        let mut ui_manager = UIManager::new(800.0, 600.0, 1.0);
+        let mut draw_manager = DrawManager::new();
+
+        let renders = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&renders);
+        let mut canvas = CanvasComponent::new("Canvas").with_bounds(30.0, 40.0, 120.0, 60.0);
+        canvas.set_on_render(move |draw, width, height| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            draw.draw_line_with_options(0.0, height, width, 0.0, 1.0, crate::types::color::Color::GREEN, 0.0);
+        });
+        let handle = canvas.clone();
+        let canvas_id = add_ui_object(&mut object_manager, Box::new(canvas), UILayoutComponent::new());
+
+        ui_manager.sync_canvases(&object_manager);
+        ui_manager.render(&mut draw_manager, &object_manager);
+        assert_eq!(renders.load(Ordering::Relaxed), 1);
+        match &draw_manager.commands()[0] {
+            DrawCommand::Line { start_x, start_y, end_x, end_y, .. } => {
+                assert_eq!((*start_x, *start_y, *end_x, *end_y), (30.0, 100.0, 150.0, 40.0));
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+        assert_eq!(draw_manager.clip_rects()[0], Some(ClipRect::new(30.0, 40.0, 120.0, 60.0)));
+
+        // The cached commands are replayed until the canvas asks for a redraw.
+        ui_manager.sync_canvases(&object_manager);
+        ui_manager.render(&mut draw_manager, &object_manager);
+        assert_eq!(renders.load(Ordering::Relaxed), 1);
+
+        handle.request_redraw();
+        ui_manager.sync_canvases(&object_manager);
+        ui_manager.render(&mut draw_manager, &object_manager);
+        assert_eq!(renders.load(Ordering::Relaxed), 2);
+
+        object_manager
+            .get_object_by_id_mut(canvas_id)
+            .unwrap()
+            .get_component_mut::<CanvasComponent>()
+            .unwrap()
+            .set_redraw_every_frame(true);
+        for _ in 0..2 {
+            ui_manager.sync_canvases(&object_manager);
+            ui_manager.render(&mut draw_manager, &object_manager);
+        }
+        assert_eq!(renders.load(Ordering::Relaxed), 4);
+    }
+
+    #[test]
+    fn test_focus_ring_and_non_interactable_widgets() {
+        use crate::core::input_manager::InputManager;
+        use crate::types::color::Color;
+
+        let mut object_manager = ObjectManager::new();
+        let mut ui_manager = UIManager::new(800.0, 600.0, 1.0);
+        let mut draw_manager = DrawManager::new();
+        let button_id = add_ui_object(
+            &mut object_manager,
+            Box::new(ButtonComponent::new("Button").with_bounds(10.0, 10.0, 80.0, 30.0)),
+            UILayoutComponent::new(),
+        );
+        let label_id = add_ui_object(
+            &mut object_manager,
+            Box::new(LabelComponent::new("Label").with_text("Name")),
+            UILayoutComponent::new(),
+        );
+
+        let [r, g, b, a] = ui_manager.theme().focus_ring_color;
+        let ring_color = Color::new(r, g, b, a);
+        let has_ring = |draw_manager: &DrawManager| {
+            draw_manager.commands().iter().any(|command| {
+                matches!(command, DrawCommand::Polygon { color, filled: false, .. } if *color == ring_color)
+            })
+        };
+
+        assert!(!ui_manager.set_focus(&mut object_manager, Some(label_id)));
+        assert!(ui_manager.set_focus(&mut object_manager, Some(button_id)));
+        assert_eq!(ui_manager.focused_object(), Some(button_id));
+        ui_manager.render(&mut draw_manager, &object_manager);
+        assert!(has_ring(&draw_manager));
+
+        // Greyed out: focus moves away and the button is drawn grey and faded.
+        ui_manager.set_interactable(button_id, false);
+        ui_manager.update(&InputManager::new(), &mut object_manager);
+        assert_eq!(ui_manager.focused_object(), None);
+        ui_manager.render(&mut draw_manager, &object_manager);
+        assert!(!has_ring(&draw_manager));
+        let background = draw_manager
+            .commands()
+            .iter()
+            .find_map(|command| match command {
+                DrawCommand::Rectangle { color, filled: true, .. } | DrawCommand::Polygon { color, filled: true, .. } => {
+                    Some(*color)
+                }
+                _ => None,
+            })
+            .unwrap();
+        assert!((background.r() - background.g()).abs() < 1e-5 && (background.g() - background.b()).abs() < 1e-5);
+        assert!(background.a() <= ui_manager.theme().disabled_opacity);
+        assert!(!ui_manager.set_focus(&mut object_manager, Some(button_id)));
+    }
+
+    #[test]
+    fn test_hover_cursor_comes_from_nearest_ancestor() {
+        let mut object_manager = ObjectManager::new();
+        let mut ui_manager = UIManager::new(800.0, 600.0, 1.0);
+        let panel_id = add_ui_object(&mut object_manager, Box::new(PanelComponent::new("Panel")), UILayoutComponent::new());
+        let button_id = add_ui_object(
+            &mut object_manager,
+            Box::new(ButtonComponent::new("Button")),
+            UILayoutComponent::new(),
+        );
+        let label_id = add_ui_object(&mut object_manager, Box::new(LabelComponent::new("Label")), UILayoutComponent::new());
+        object_manager.add_child(panel_id, button_id).unwrap();
+        object_manager.add_child(button_id, label_id).unwrap();
+
+        assert_eq!(ui_manager.hover_cursor_for(&object_manager, label_id), None);
+        ui_manager.set_hover_cursor(panel_id, Some(CursorIcon::Move));
+        ui_manager.set_hover_cursor(button_id, Some(CursorIcon::Pointer));
+        assert_eq!(ui_manager.hover_cursor_for(&object_manager, label_id), Some(CursorIcon::Pointer));
+        ui_manager.set_hover_cursor(button_id, None);
+        assert_eq!(ui_manager.hover_cursor_for(&object_manager, label_id), Some(CursorIcon::Move));
+
+        object_manager.remove_object(panel_id);
+        ui_manager.update(&InputManager::new(), &mut object_manager);
+        assert_eq!(ui_manager.hover_cursor(panel_id), None);
+    }
+
+    #[test]
+    fn test_ui_takes_input_only_in_top_context() {
+        use crate::core::input_manager::InputManager;
+
+        let mut object_manager = ObjectManager::new();
+        let mut ui_manager = UIManager::new(800.0, 600.0, 1.0);
+        let menu_id = add_ui_object(&mut object_manager, Box::new(PanelComponent::new("Panel")), UILayoutComponent::new());
+        let resume_id = add_ui_object(
+            &mut object_manager,
+            Box::new(ButtonComponent::new("Button")),
+            UILayoutComponent::new(),
+        );
+        let hud_id = add_ui_object(
+            &mut object_manager,
+            Box::new(ButtonComponent::new("Button")),
+            UILayoutComponent::new(),
+        );
+        object_manager.add_child(menu_id, resume_id).unwrap();
+        ui_manager.set_input_context(menu_id, Some("menu".to_string()));
+        ui_manager.set_input_context(hud_id, Some("gameplay".to_string()));
+
+        let mut input = InputManager::new();
+        input.push_input_context("Gameplay");
+        ui_manager.update(&input, &mut object_manager);
+        assert!(!ui_manager.set_focus(&mut object_manager, Some(resume_id)));
+        assert!(ui_manager.set_focus(&mut object_manager, Some(hud_id)));
+
+        // Pushing the menu takes focus from the HUD.
+        input.push_input_context("menu");
+        ui_manager.update(&input, &mut object_manager);
+        assert_eq!(ui_manager.focused_object(), None);
+        assert!(ui_manager.set_focus(&mut object_manager, Some(resume_id)));
+    }
 }
@@ -3,7 +3,7 @@ use crate::types::Color;
 use image::load_from_memory;
 use std::path::Path;
 use std::sync::Arc;
-use winit::dpi::{LogicalSize, PhysicalSize};
+use winit::dpi::{LogicalSize, PhysicalPosition, PhysicalSize};
 use winit::event_loop::ActiveEventLoop;
 #[cfg(target_os = "macos")]
 use winit::platform::macos::{WindowAttributesExtMacOS, WindowExtMacOS};
@@ -11,7 +11,7 @@ use winit::platform::macos::{WindowAttributesExtMacOS, WindowExtMacOS};
 use winit::platform::wayland::WindowAttributesExtWayland;
 #[cfg(target_os = "linux")]
 use winit::platform::x11::WindowAttributesExtX11;
-use winit::window::{Fullscreen, Icon, Window};
+use winit::window::{CursorGrabMode, Fullscreen, Icon, Window};
 
 const DEFAULT_WINDOW_ICON_BYTES: &[u8] = include_bytes!("../../../images/pyg_logo.png");
 
@@ -324,6 +324,42 @@ impl WindowManager {
         self.window.request_redraw();
     }
 
+    /// Show or hide the mouse cursor while it is over the window
+    pub fn set_cursor_visible(&self, visible: bool) {
+        self.window.set_cursor_visible(visible);
+    }
+
+    /// Let the input method (IME) compose text in the window. While allowed,
+    /// winit sends `WindowEvent::Ime` instead of some key presses.
+    pub fn set_ime_allowed(&self, allowed: bool) {
+        self.window.set_ime_allowed(allowed);
+    }
+
+    /// Area, in physical pixels, the input method should not cover, e.g. the
+    /// caret of the text being edited; its candidate window goes beside it.
+    pub fn set_ime_cursor_area(&self, x: f32, y: f32, width: f32, height: f32) {
+        self.window.set_ime_cursor_area(
+            PhysicalPosition::new(x.round() as i32, y.round() as i32),
+            PhysicalSize::new(width.ceil() as u32, height.ceil() as u32),
+        );
+    }
+
+    /// Confine or lock the cursor to the window.
+    ///
+    /// Platforms support only some modes (Windows and X11 cannot lock, macOS
+    /// cannot confine), so an unsupported mode falls back to the other one.
+    pub fn set_cursor_grab(&self, mode: CursorGrabMode) -> Result<(), String> {
+        let fallback = match mode {
+            CursorGrabMode::None => CursorGrabMode::None,
+            CursorGrabMode::Confined => CursorGrabMode::Locked,
+            CursorGrabMode::Locked => CursorGrabMode::Confined,
+        };
+        self.window
+            .set_cursor_grab(mode)
+            .or_else(|_| self.window.set_cursor_grab(fallback))
+            .map_err(|err| format!("Failed to grab cursor: {err}"))
+    }
+
     /// Set the window's resizable state
     pub fn set_resizable(&self, resizable: bool) {
         self.window.set_resizable(resizable);
@@ -176,6 +176,49 @@ impl Color {
         )
     }
 
+    /// Scale RGB by `factor`, keeping alpha.
+    pub fn scale(&self, factor: f32) -> Self {
+        Self::new(self.r * factor, self.g * factor, self.b * factor, self.a)
+    }
+
+    /// Blend RGB toward black by `amount` (0.0-1.0), keeping alpha.
+    pub fn darken(&self, amount: f32) -> Self {
+        self.scale(1.0 - amount.clamp(0.0, 1.0))
+    }
+
+    /// Blend RGB toward white by `amount` (0.0-1.0), keeping alpha.
+    pub fn lighten(&self, amount: f32) -> Self {
+        let t = amount.clamp(0.0, 1.0);
+        Self::new(
+            self.r + (1.0 - self.r) * t,
+            self.g + (1.0 - self.g) * t,
+            self.b + (1.0 - self.b) * t,
+            self.a,
+        )
+    }
+
+    /// Perceived brightness (0.0-1.0), using the Rec. 601 luma weights
+    pub fn luminance(&self) -> f32 {
+        0.299 * self.r + 0.587 * self.g + 0.114 * self.b
+    }
+
+    /// The gray of the same luminance, keeping alpha.
+    pub fn grayscale(&self) -> Self {
+        let luminance = self.luminance();
+        Self::new(luminance, luminance, luminance, self.a)
+    }
+
+    /// The photographic negative: each RGB component becomes `1.0 - c`,
+    /// alpha is kept.
+    pub fn inverted(&self) -> Self {
+        Self::new(1.0 - self.r, 1.0 - self.g, 1.0 - self.b, self.a)
+    }
+
+    /// Components as bytes in the range 0-255, rounded to the nearest.
+    pub fn to_rgba8(&self) -> [u8; 4] {
+        [self.r, self.g, self.b, self.a].map(|c| (c * 255.0).round() as u8)
+    }
+
     /// Convert this Color to a wgpu::Color
     pub fn to_wgpu(&self) -> wgpu::Color {
         wgpu::Color {
@@ -767,6 +810,15 @@ impl Mul for Color {
     }
 }
 
+/// Scales RGB, keeping alpha, so `color * 0.5` is a darker, still opaque color.
+impl Mul<f32> for Color {
+    type Output = Self;
+
+    fn mul(self, factor: f32) -> Self {
+        self.scale(factor)
+    }
+}
+
 impl Div for Color {
     type Output = Self;
 
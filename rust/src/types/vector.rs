@@ -120,8 +120,36 @@ impl<const N: usize> Vector<f32, N> {
         sum
     }
 
-    pub fn angle(&self, other: &Self) -> f32 {
-        self.dot(other) / (self.length() * other.length())
+    /// Reflect off a surface with the given normal, which needn't be unit
+    /// length. A zero normal leaves the vector unchanged.
+    pub fn reflect(&self, normal: &Self) -> Self {
+        match normal.try_normalize() {
+            Some(normal) => self.subtract(&normal.multiply_scalar(2.0 * self.dot(&normal))),
+            None => *self,
+        }
+    }
+
+    /// Shorten to `max` length if longer, keeping the direction.
+    pub fn clamp_length(&self, max: f32) -> Self {
+        let max = max.max(0.0);
+        let len = self.length();
+        if len > max {
+            self.multiply_scalar(max / len)
+        } else {
+            *self
+        }
+    }
+
+    /// Step toward `target` by at most `max_delta`, landing on it exactly
+    /// once it is that close.
+    pub fn move_towards(&self, target: &Self, max_delta: f32) -> Self {
+        let offset = target.subtract(self);
+        let len = offset.length();
+        if len <= max_delta || len == 0.0 {
+            *target
+        } else {
+            self.add(&offset.multiply_scalar(max_delta / len))
+        }
     }
 
     pub fn distance(&self, other: &Self) -> f32 {
@@ -245,6 +273,31 @@ impl Vector<f32, 2> {
     pub fn cross(&self, other: &Self) -> f32 {
         self.data[0] * other.data[1] - self.data[1] * other.data[0]
     }
+
+    /// Rotate counter-clockwise (with y up) by `radians`.
+    pub fn rotate(&self, radians: f32) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self::new(
+            self.x() * cos - self.y() * sin,
+            self.x() * sin + self.y() * cos,
+        )
+    }
+
+    /// Direction in radians from the +x axis, from -π to π.
+    pub fn angle(&self) -> f32 {
+        self.y().atan2(self.x())
+    }
+
+    /// Signed angle in radians to rotate this vector onto `other`, from -π
+    /// to π; positive is counter-clockwise.
+    pub fn angle_to(&self, other: &Self) -> f32 {
+        self.cross(other).atan2(self.dot(other))
+    }
+
+    /// This vector rotated 90° counter-clockwise.
+    pub fn perpendicular(&self) -> Self {
+        Self::new(-self.y(), self.x())
+    }
 }
 
 impl Vector<f32, 3> {
@@ -273,4 +326,23 @@ impl Vector<f32, 3> {
             ],
         }
     }
+
+    /// Rotate by `radians` around `axis` (right-handed), which needn't be
+    /// unit length. A zero axis leaves the vector unchanged.
+    pub fn rotate(&self, axis: &Self, radians: f32) -> Self {
+        let Some(axis) = axis.try_normalize() else {
+            return *self;
+        };
+        // Rodrigues' rotation formula
+        let (sin, cos) = radians.sin_cos();
+        self.multiply_scalar(cos)
+            .add(&axis.cross(self).multiply_scalar(sin))
+            .add(&axis.multiply_scalar(axis.dot(self) * (1.0 - cos)))
+    }
+
+    /// Unsigned angle in radians between this vector and `other`, from 0 to
+    /// π, or 0 if either has zero length.
+    pub fn angle_to(&self, other: &Self) -> f32 {
+        self.cross(other).length().atan2(self.dot(other))
+    }
 }
@@ -1,14 +1,36 @@
+import runpy
+from pathlib import Path
+
 from setuptools import setup, find_packages
+from setuptools.command.build import build
 from setuptools_rust import Binding, RustExtension
 
 with open("README.md", "r", encoding="utf-8") as fh:
     long_description = fh.read()
 
+
+class build_with_stubs(build):
+    """Regenerate the type stubs from the freshly built native module."""
+
+    def run(self):
+        super().run()
+        package = Path(self.build_lib) / "pyg_engine"
+        built = sorted(package.glob("pyg_engine_native*.so")) + sorted(package.glob("pyg_engine_native*.pyd"))
+        if not built:
+            return
+        generator = runpy.run_path(str(Path(__file__).parent / "tools" / "generate_stubs.py"))
+        try:
+            generator["main"](["--module", str(built[0]), "--output", str(package / "pyg_engine_native.pyi")])
+        except Exception as error:  # e.g. cross-compiling, where the module can't be imported
+            print(f"warning: keeping the committed type stubs, couldn't regenerate them: {error}")
+
+
 setup(
     # Version is auto-generated from git tags via setuptools-scm
     # All other metadata is in pyproject.toml
     packages=find_packages(where="python"),
     package_dir={"": "python"},
+    cmdclass={"build": build_with_stubs},
     rust_extensions=[
         RustExtension(
             "pyg_engine.pyg_engine_native",
@@ -44,7 +66,7 @@ setup(
     },
     include_package_data=True,
     package_data={
-        "pyg_engine": ["etc/*.png"],
+        "pyg_engine": ["etc/*.png", "*.pyi", "py.typed"],
     },
     entry_points={
         "console_scripts": [
@@ -9,7 +9,13 @@ This module tests:
 - TransformComponent usage
 """
 
+import ast
+import copy
+import inspect
+import math
+import pickle
 import time as py_time
+from pathlib import Path
 from typing import TYPE_CHECKING
 
 import pytest
@@ -163,6 +169,111 @@ def test_vec2_string_representation() -> None:
     assert "4.0" in s or "4" in s
 
 
+def test_vec2_equality_hashing_and_unpacking() -> None:
+    """
+    Test that Vec2 compares by value, works as a dict key and unpacks like a tuple.
+    """
+    v = pyg.Vec2(3.0, 4.0)
+
+    assert v == pyg.Vec2(3.0, 4.0)
+    assert v != pyg.Vec2(4.0, 3.0)
+    assert v != (3.0, 4.0)
+    assert hash(pyg.Vec2(0.0, 1.0)) == hash(pyg.Vec2(-0.0, 1.0))
+
+    cells = {pyg.Vec2(1.0, 2.0): "tree"}
+    assert cells[pyg.Vec2(1.0, 2.0)] == "tree"
+    assert len({v, pyg.Vec2(3.0, 4.0)}) == 1
+
+    # Keys are immutable, so nothing done through another name can move them
+    key = next(iter(cells))
+    key += pyg.Vec2(1.0, 1.0)
+    assert cells[pyg.Vec2(1.0, 2.0)] == "tree"
+    with pytest.raises(AttributeError):
+        v.x = 5.0
+
+    x, y = v
+    assert (x, y) == (3.0, 4.0)
+    assert tuple(v) == (3.0, 4.0)
+    assert len(v) == 2
+    assert v[0] == 3.0 and v[-1] == 4.0
+    with pytest.raises(IndexError):
+        v[2]
+
+
+def test_vec2_unary_and_augmented_assignment() -> None:
+    """
+    Test Vec2 negation, abs() and augmented assignment, which rebinds to a new vector.
+    """
+    velocity = pyg.Vec2(3.0, -4.0)
+    assert -velocity == pyg.Vec2(-3.0, 4.0)
+    assert abs(velocity) == pyg.Vec2(3.0, 4.0)
+
+    position = pyg.Vec2(1.0, 1.0)
+    alias = position
+    position += velocity
+    assert position is not alias
+    assert alias == pyg.Vec2(1.0, 1.0)
+    assert position == pyg.Vec2(4.0, -3.0)
+    position -= 1.0
+    assert position == pyg.Vec2(3.0, -4.0)
+    position *= 2.0
+    assert position == pyg.Vec2(6.0, -8.0)
+    position /= pyg.Vec2(2.0, 4.0)
+    assert position == pyg.Vec2(3.0, -2.0)
+    position += position
+    assert position == pyg.Vec2(6.0, -4.0)
+
+    with pytest.raises(TypeError):
+        position += "up"
+
+    # Constants are shared, so augmented assignment must never change them
+    origin = pyg.Vec2.ZERO
+    origin += velocity
+    assert pyg.Vec2.ZERO == pyg.Vec2(0.0, 0.0)
+
+
+def test_vec2_rotation_and_angles() -> None:
+    """
+    Test rotate, angle, angle_to and perpendicular, counter-clockwise with y up.
+    """
+    right = pyg.Vec2(1.0, 0.0)
+    up = right.rotate(math.pi / 2)
+    assert tuple(up) == pytest.approx((0.0, 1.0), abs=1e-6)
+    assert right.perpendicular() == pyg.Vec2(0.0, 1.0)
+    assert pyg.Vec2(2.0, -3.0).perpendicular() == pyg.Vec2(3.0, 2.0)
+
+    assert pyg.Vec2(0.0, 2.0).angle() == pytest.approx(math.pi / 2)
+    assert pyg.Vec2(-1.0, 0.0).angle() == pytest.approx(math.pi)
+    assert pyg.Vec2(0.0, 0.0).angle() == 0.0
+
+    assert right.angle_to(pyg.Vec2(0.0, 5.0)) == pytest.approx(math.pi / 2)
+    assert right.angle_to(pyg.Vec2(0.0, -5.0)) == pytest.approx(-math.pi / 2)
+    assert right.angle_to(pyg.Vec2(0.0, 0.0)) == 0.0
+    aim = pyg.Vec2(3.0, 1.0)
+    target = pyg.Vec2(-2.0, 4.0)
+    assert tuple(aim.rotate(aim.angle_to(target)).normalize()) == pytest.approx(tuple(target.normalize()), abs=1e-6)
+
+
+def test_vec2_reflect_clamp_and_move_towards() -> None:
+    """
+    Test reflect, clamp_length and move_towards.
+    """
+    velocity = pyg.Vec2(3.0, -4.0)
+    assert velocity.reflect(pyg.Vec2(0.0, 1.0)) == pyg.Vec2(3.0, 4.0)
+    assert velocity.reflect(pyg.Vec2(0.0, 10.0)) == pyg.Vec2(3.0, 4.0)
+    assert velocity.reflect(pyg.Vec2(0.0, 0.0)) == velocity
+
+    assert velocity.clamp_length(2.5) == pyg.Vec2(1.5, -2.0)
+    assert velocity.clamp_length(10.0) == velocity
+    assert velocity.clamp_length(-1.0) == pyg.Vec2(0.0, 0.0)
+
+    start = pyg.Vec2(0.0, 0.0)
+    target = pyg.Vec2(6.0, 8.0)
+    assert start.move_towards(target, 5.0) == pyg.Vec2(3.0, 4.0)
+    assert start.move_towards(target, 20.0) == target
+    assert target.move_towards(target, 1.0) == target
+
+
 # ========== Vec3 Tests ==========
 
 def test_vec3_creation() -> None:
@@ -265,6 +376,115 @@ def test_vec3_string_representation() -> None:
     assert "3.0" in s or "3" in s
 
 
+def test_vec3_equality_hashing_and_unpacking() -> None:
+    """
+    Test that Vec3 compares by value, works as a dict key and unpacks like a tuple.
+    """
+    v = pyg.Vec3(1.0, 2.0, 3.0)
+
+    assert v == pyg.Vec3(1.0, 2.0, 3.0)
+    assert v != pyg.Vec3(1.0, 2.0, 0.0)
+    assert {v: "spawn"}[pyg.Vec3(1.0, 2.0, 3.0)] == "spawn"
+    with pytest.raises(AttributeError):
+        v.z = 0.0
+
+    x, y, z = v
+    assert (x, y, z) == (1.0, 2.0, 3.0)
+    assert list(v) == [1.0, 2.0, 3.0]
+    assert len(v) == 3
+    assert v[2] == 3.0 and v[-3] == 1.0
+    with pytest.raises(IndexError):
+        v[-4]
+
+
+def test_vec3_unary_and_augmented_assignment() -> None:
+    """
+    Test Vec3 negation, abs() and augmented assignment.
+    """
+    v = pyg.Vec3(1.0, -2.0, 3.0)
+    assert -v == pyg.Vec3(-1.0, 2.0, -3.0)
+    assert abs(v) == pyg.Vec3(1.0, 2.0, 3.0)
+
+    v += pyg.Vec3(1.0, 1.0, 1.0)
+    v *= 2.0
+    v -= pyg.Vec3.UP
+    v /= 2.0
+    assert v == pyg.Vec3(2.0, -1.5, 4.0)
+
+    up = pyg.Vec3.UP
+    up *= 2.0
+    assert pyg.Vec3.UP == pyg.Vec3(0.0, 1.0, 0.0)
+
+
+def test_vec3_rotation_and_vector_utilities() -> None:
+    """
+    Test rotate around an axis, angle_to, reflect, clamp_length and move_towards.
+    """
+    x_axis = pyg.Vec3(1.0, 0.0, 0.0)
+    assert tuple(x_axis.rotate(math.pi / 2)) == pytest.approx((0.0, 1.0, 0.0), abs=1e-6)
+    rotated = x_axis.rotate(math.pi / 2, pyg.Vec3(0.0, 2.0, 0.0))
+    assert tuple(rotated) == pytest.approx((0.0, 0.0, -1.0), abs=1e-6)
+    assert x_axis.rotate(1.0, pyg.Vec3(0.0, 0.0, 0.0)) == x_axis
+
+    assert x_axis.angle_to(pyg.Vec3(0.0, 0.0, 3.0)) == pytest.approx(math.pi / 2)
+    assert x_axis.angle_to(pyg.Vec3(-2.0, 0.0, 0.0)) == pytest.approx(math.pi)
+
+    assert pyg.Vec3(1.0, -1.0, 2.0).reflect(pyg.Vec3(0.0, 1.0, 0.0)) == pyg.Vec3(1.0, 1.0, 2.0)
+    assert pyg.Vec3(0.0, 3.0, 4.0).clamp_length(2.5) == pyg.Vec3(0.0, 1.5, 2.0)
+    assert pyg.Vec3(0.0, 0.0, 0.0).move_towards(pyg.Vec3(0.0, 0.0, 10.0), 4.0) == pyg.Vec3(0.0, 0.0, 4.0)
+
+
+# ========== Point Data Tests ==========
+
+def test_point_data_accepts_float_buffers_and_sequences() -> None:
+    """
+    Test that bulk point APIs read float buffers and mixed point sequences alike.
+    """
+    from array import array
+
+    square = [(0.0, 0.0), pyg.Vec2(1.0, 0.0), [1.0, 1.0], (0.0, 1.0)]
+    flat = array("f", [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0])
+    doubles = memoryview(array("d", flat).tobytes()).cast("d", (4, 2))
+
+    for vertices in (square, flat, doubles):
+        mesh = pyg.MeshGeometry(vertices, [0, 1, 2, 0, 2, 3], uvs=flat)
+        assert mesh.vertices() == [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]
+
+    vertex_array = mesh.vertex_array()
+    assert vertex_array.format == "f"
+    assert vertex_array.shape == (4, 2)
+    assert vertex_array.tolist() == [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]
+    assert len(pyg.MeshGeometry().vertex_array()) == 0
+
+    pyg.DrawCommand.polygon(flat, pyg.Color.RED)
+    pyg.ColliderShape.polygon(doubles)
+
+    with pytest.raises(ValueError):
+        pyg.MeshGeometry(array("f", [0.0, 1.0, 2.0]))
+    with pytest.raises(TypeError):
+        pyg.DrawCommand.polygon([(0.0, 0.0, 0.0)], pyg.Color.RED)
+
+
+def test_point_data_round_trips_through_numpy() -> None:
+    """
+    Test that numpy arrays go in directly and vertex buffers come back as arrays.
+    """
+    np = pytest.importorskip("numpy")
+
+    vertices = np.array([[0.0, 0.0], [2.0, 0.0], [2.0, 2.0]])
+    mesh = pyg.MeshGeometry(vertices, [0, 1, 2])
+    assert mesh.vertices() == [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0)]
+
+    result = np.asarray(mesh.vertex_array())
+    assert result.dtype == np.float32
+    assert result.shape == (3, 2)
+    assert np.array_equal(result, vertices)
+
+    # Non-contiguous views are read correctly too
+    mesh = pyg.MeshGeometry(vertices.astype(np.float32)[::-1], [0, 1, 2])
+    assert mesh.vertices()[0] == (2.0, 2.0)
+
+
 # ========== Color Tests ==========
 
 def test_color_creation() -> None:
@@ -735,6 +955,98 @@ def test_color_equality_with_operations() -> None:
     assert c7 == c8
 
 
+def test_color_unary_and_augmented_assignment() -> None:
+    """
+    Test that -color is the photographic negative and augmented assignment makes a new color.
+    """
+    assert -pyg.Color.RED == pyg.Color.CYAN
+    assert -pyg.Color(0.25, 0.5, 1.0, 0.5) == pyg.Color(0.75, 0.5, 0.0, 0.5)
+    assert abs(pyg.Color.ORANGE) == pyg.Color.ORANGE
+
+    tint = pyg.Color(0.5, 0.5, 0.5, 1.0)
+    alias = tint
+    tint += pyg.Color(0.25, 0.0, 0.0, 0.0)
+    assert tint is not alias
+    assert alias == pyg.Color(0.5, 0.5, 0.5, 1.0)
+    assert tint == pyg.Color(0.75, 0.5, 0.5, 1.0)
+    tint -= pyg.Color(0.0, 0.5, 0.0, 0.0)
+    tint *= pyg.Color(1.0, 1.0, 0.5, 0.5)
+    assert tint == pyg.Color(0.75, 0.0, 0.25, 0.5)
+    tint /= pyg.Color(1.0, 1.0, 0.5, 1.0)
+    assert tint == pyg.Color(0.75, 0.0, 0.5, 0.5)
+    tint += tint
+    assert tint == pyg.Color(1.0, 0.0, 1.0, 1.0)
+
+    white = pyg.Color.WHITE
+    white *= pyg.Color.RED
+    assert pyg.Color.WHITE == pyg.Color(1.0, 1.0, 1.0, 1.0)
+
+
+def test_color_scaling_and_shades() -> None:
+    """
+    Test scaling by a number, darken/lighten, grayscale, inverted and to_rgba8.
+    """
+    color = pyg.Color(0.8, 0.4, 0.2, 0.5)
+    assert color * 0.5 == pyg.Color(0.4, 0.2, 0.1, 0.5)
+    assert 0.5 * color == color * 0.5
+    assert color * 2 == pyg.Color(1.0, 0.8, 0.4, 0.5)
+    assert pyg.Color.WHITE * pyg.Color.RED == pyg.Color.RED
+    with pytest.raises(TypeError):
+        color * "red"
+
+    scaled = copy.copy(color)
+    scaled *= 0.5
+    assert scaled == pyg.Color(0.4, 0.2, 0.1, 0.5)
+
+    assert color.darken(0.5) == pyg.Color(0.4, 0.2, 0.1, 0.5)
+    assert color.darken(1.0) == pyg.Color(0.0, 0.0, 0.0, 0.5)
+    assert color.lighten(0.5) == pyg.Color(0.9, 0.7, 0.6, 0.5)
+    assert color.lighten(2.0) == pyg.Color(1.0, 1.0, 1.0, 0.5)
+    assert color.darken(0.0) == color
+
+    gray = pyg.Color.GREEN.grayscale()
+    assert gray.r == pytest.approx(0.587, abs=1e-6)
+    assert gray.r == gray.g == gray.b
+    assert gray.a == 1.0
+
+    assert pyg.Color.RED.inverted() == pyg.Color.CYAN
+    assert color.inverted() == -color
+
+    assert pyg.Color.rgba(255, 128, 0, 64).to_rgba8() == (255, 128, 0, 64)
+    assert pyg.Color.TRANSPARENT.to_rgba8() == (0, 0, 0, 0)
+
+
+def test_value_types_pickle_and_copy() -> None:
+    """
+    Test that vectors, colors and collider shapes survive pickling and copying.
+    """
+    values = [
+        pyg.Vec2(1.5, -2.0),
+        pyg.Vec3(1.0, 2.0, 3.0),
+        pyg.Color(0.1, 0.2, 0.3, 0.4),
+        pyg.ColliderShape.circle(0.5),
+        pyg.ColliderShape.box_shape(1.0, 2.0),
+        pyg.ColliderShape.obb(1.0, 2.0, 0.25),
+        pyg.ColliderShape.polygon([(0.0, 0.5), (-0.5, -0.5), (0.5, -0.5)]),
+    ]
+    for value in values:
+        for protocol in range(pickle.HIGHEST_PROTOCOL + 1):
+            restored = pickle.loads(pickle.dumps(value, protocol))
+            assert type(restored) is type(value)
+            assert restored == value
+
+        duplicate = copy.copy(value)
+        assert duplicate == value and duplicate is not value
+        assert copy.deepcopy(value) == value
+
+    assert pyg.ColliderShape.circle(0.5) != pyg.ColliderShape.circle(1.0)
+    assert repr(pyg.ColliderShape.box_shape(1.0, 2.0)) == "ColliderShape.box_shape(1, 2)"
+
+    # Containers holding value types copy like any other
+    waypoints = {"spawn": pyg.Vec2(1.0, 2.0), "tint": pyg.Color.RED}
+    assert copy.deepcopy(waypoints) == waypoints
+
+
 # ========== Time Tests ==========
 
 def test_time_creation() -> None:
@@ -1112,3 +1424,46 @@ def test_text_draw_api_no_crash() -> None:
 
     engine.clear_draw_commands()
 
+
+
+# ========== Type Stub Tests ==========
+
+def test_type_stubs_cover_native_module():
+    """Test the shipped .pyi declares every class, method and constant of the native module."""
+    native = pyg.pyg_engine_native
+    stub_path = Path(native.__file__).with_name("pyg_engine_native.pyi")
+    assert stub_path.with_name("py.typed").exists()
+    tree = ast.parse(stub_path.read_text(encoding="utf-8"))
+
+    declared = {}
+    for node in tree.body:
+        if isinstance(node, ast.ClassDef):
+            declared[node.name] = {
+                item.name if isinstance(item, ast.FunctionDef) else item.target.id
+                for item in node.body
+                if isinstance(item, ast.FunctionDef)
+                or (isinstance(item, ast.AnnAssign) and isinstance(item.target, ast.Name))
+            }
+        elif isinstance(node, ast.FunctionDef):
+            declared[node.name] = set()
+
+    missing = []
+    for name, value in vars(native).items():
+        if name.startswith("_"):
+            continue
+        if name not in declared:
+            missing.append(name)
+        elif inspect.isclass(value):
+            missing += [
+                f"{name}.{member}"
+                for member in vars(value)
+                if not member.startswith("_") and member not in declared[name]
+            ]
+    assert missing == [], "stale stubs, run tools/generate_stubs.py"
+
+    # Signatures carry the Rust types
+    vec2 = next(node for node in tree.body if isinstance(node, ast.ClassDef) and node.name == "Vec2")
+    lerp = next(item for item in vec2.body if isinstance(item, ast.FunctionDef) and item.name == "lerp")
+    params = [(arg.arg, getattr(arg.annotation, "id", None)) for arg in lerp.args.args]
+    assert params == [("self", None), ("other", "Vec2"), ("t", "float")]
+    assert lerp.returns.id == "Vec2"
@@ -9,9 +9,14 @@ Python's stdout capture, so we test for basic functionality rather than
 captured output.
 """
 
+import os
 import tempfile
+import threading
+import time
+import wave
+from array import array
 from pathlib import Path
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Any
 
 import pytest
 
@@ -87,6 +92,29 @@ def test_engine_render_api_methods_exist() -> None:
     assert not hasattr(engine, "initialize")
 
 
+def test_image_bytes_accept_any_byte_buffer() -> None:
+    """Test that image bytes come from any byte buffer, contiguous or not."""
+    engine = pyg.Engine()
+    handle = engine.get_handle()
+    red = bytes([255, 0, 0, 255] * 4)
+    for rgba in (
+        red,
+        bytearray(red),
+        memoryview(red),
+        array("B", red),
+        memoryview(red * 2)[::2],
+        list(red),
+    ):
+        pyg.DrawCommand.image_from_bytes(0, 0, 2, 2, "red", rgba, 2, 2)
+        engine.draw_image_from_bytes(0, 0, 2, 2, "red", rgba, 2, 2)
+        handle.draw_image_from_bytes(0, 0, 2, 2, "red", rgba, 2, 2)
+
+    with pytest.raises(RuntimeError, match="expected 16 bytes"):
+        pyg.DrawCommand.image_from_bytes(0, 0, 2, 2, "red", bytearray(3), 2, 2)
+    with pytest.raises(TypeError):
+        engine.draw_image_from_bytes(0, 0, 2, 2, "red", array("f", [0.0] * 16), 2, 2)
+
+
 def test_camera_aspect_mode_constants_exposed() -> None:
     """Test that camera aspect mode constants are exposed at the top level."""
     assert hasattr(pyg, "CameraAspectMode")
@@ -108,6 +136,25 @@ def test_camera_aspect_mode_set_get_round_trip() -> None:
     assert engine.get_camera_aspect_mode() == "fit_both"
 
 
+def test_camera_pixel_perfect_rejects_unusable_resolutions() -> None:
+    """Test zero and oversized pixel-perfect resolutions are rejected and leave the mode unchanged."""
+    engine = pyg.Engine()
+
+    assert engine.set_camera_pixel_perfect(320, 180) is True
+    assert engine.set_camera_pixel_perfect(0, 180) is False
+    assert engine.set_camera_pixel_perfect(320, 1_000_000) is False
+    assert engine.get_camera_pixel_perfect() == (320, 180)
+
+    with pytest.raises(ValueError, match="maximum texture size"):
+        engine.camera.pixel_perfect_resolution = (100_000, 100_000)
+    with pytest.raises(ValueError):
+        engine.camera.pixel_perfect_resolution = (0, 0)
+    assert engine.camera.pixel_perfect_resolution == (320, 180)
+
+    engine.camera.pixel_perfect_resolution = None
+    assert engine.get_camera_pixel_perfect() is None
+
+
 def test_set_window_icon_invalid_path_raises() -> None:
     """Test set_window_icon raises for missing icon files."""
     engine = pyg.Engine()
@@ -124,6 +171,30 @@ def test_start_manual_guard_raises_when_already_running() -> None:
         engine.start_manual()
 
 
+def test_ending_a_session_runs_quit_callbacks_and_resets_quit() -> None:
+    """Test on_quit callbacks run once the loop ends and the engine can start again."""
+    engine = pyg.Engine()
+    engine.get_callback_errors()
+    seen = []
+
+    @engine.on_quit
+    def record() -> None:
+        seen.append(engine.quit_requested)
+
+    engine.on_quit(lambda: 1 / 0)
+    engine.request_quit()
+    engine.get_handle().request_quit()
+    engine.update()
+    assert engine.quit_requested
+
+    engine._runtime_state = "manual"  # type: ignore[attr-defined]
+    engine._end_session()  # type: ignore[attr-defined]
+    assert seen == [True]
+    assert engine.is_running is False
+    assert engine.quit_requested is False
+    assert [error.source for error in engine.get_callback_errors()] == ["on_quit callback"]
+
+
 def test_run_guard_raises_when_already_running() -> None:
     """Test that run fails fast when engine is already running."""
     engine = pyg.Engine()
@@ -133,6 +204,169 @@ def test_run_guard_raises_when_already_running() -> None:
         engine.run()
 
 
+def test_run_rejects_both_update_callbacks() -> None:
+    """Test that run takes a Python-loop or a native-loop callback, not both."""
+    engine = pyg.Engine()
+
+    with pytest.raises(ValueError, match="update or on_update"):
+        engine.run(update=lambda: None, on_update=lambda dt, handle: None)
+    assert engine.is_running is False
+
+
+def test_parallel_updates_toggle_keeps_scripts_running() -> None:
+    """Test scripts still update with parallel component updates on and off."""
+    engine = pyg.Engine()
+    assert engine.parallel_updates is False
+    engine.parallel_updates = True
+    updates = []
+
+    class Counter(pyg.Script):
+        def update(self, dt: float) -> None:
+            updates.append(dt)
+
+    for index in range(300):
+        game_object = pyg.GameObject(f"Object {index}")
+        if index % 100 == 0:
+            game_object.add_component(Counter())
+        engine.add_game_object(game_object)
+
+    engine.update()
+    engine.parallel_updates = False
+    engine.update()
+    assert engine.parallel_updates is False
+    assert len(updates) == 6
+
+
+def test_destroying_a_touching_object_calls_script_collision_exit() -> None:
+    """Test a script gets on_collision_exit when the object it touches is destroyed."""
+    engine = pyg.Engine()
+    engine.set_fixed_timestep(0.001)
+    events = []
+
+    class Sensor(pyg.Script):
+        def on_collision_enter(self, other_id: int, normal: Any, penetration: float) -> None:
+            events.append(("enter", other_id))
+
+        def on_collision_exit(self, other_id: int) -> None:
+            events.append(("exit", other_id))
+
+    ids = []
+    for name, script in (("Sensor", Sensor()), ("Crate", None)):
+        game_object = pyg.GameObject(name)
+        collider = pyg.Collider(f"{name}Collider")
+        collider.set_shape(pyg.ColliderShape.circle(1.0))
+        game_object.add_component(collider)
+        if script is not None:
+            game_object.add_component(script)
+        ids.append(engine.add_game_object(game_object))
+    sensor_id, crate_id = ids
+
+    for _ in range(100):
+        time.sleep(0.002)
+        engine.update()
+        if events:
+            break
+    assert events == [("enter", crate_id)]
+
+    assert engine.destroy_object(crate_id)
+    engine.render()
+    engine.update()
+    assert events == [("enter", crate_id), ("exit", crate_id)]
+    assert engine.objects.get_id(crate_id) is None
+    assert engine.objects.get_id(sensor_id) is not None
+
+
+def test_frame_stats_time_script_hooks() -> None:
+    """Test frame stats cover the last completed frame, scripts included."""
+    engine = pyg.Engine()
+
+    class Sleeper(pyg.Script):
+        def update(self, dt: float) -> None:
+            time.sleep(0.002)
+
+    game_object = pyg.GameObject("Sleeper")
+    game_object.add_component(Sleeper())
+    engine.add_game_object(game_object)
+
+    assert engine.get_frame_stats().frame == 0
+    engine.update()
+    engine.update()
+    stats = engine.get_frame_stats()
+    assert stats.frame == 1
+    assert stats.scripts_ms >= 2.0
+    assert stats.total_ms == pytest.approx(sum(ms for _, ms in stats.scopes))
+
+    with pytest.raises(ValueError):
+        engine.frame_budget_ms = 0
+    engine.profiler_enabled = False
+    engine.update()
+    assert engine.get_frame_stats().frame == 0
+
+
+def test_callback_errors_are_queued_and_raised_by_policy() -> None:
+    """Test callback exceptions are recorded and re-raised under the raise policy."""
+    engine = pyg.Engine()
+    engine.get_callback_errors()
+
+    class Broken(pyg.Script):
+        def update(self, dt: float) -> None:
+            raise KeyError("missing")
+
+    game_object = pyg.GameObject("Broken")
+    game_object.add_component(Broken())
+    engine.add_game_object(game_object)
+    engine.timers.start("boom", 0.0, on_expired=lambda name: 1 / 0)
+
+    engine.update()
+    errors = engine.get_callback_errors()
+    assert [error.exception_type for error in errors] == ["KeyError", "ZeroDivisionError"]
+    assert errors[0].source == "script update hook"
+    assert "in update" in errors[0].traceback
+    assert errors[1].source == "timer 'boom' callback"
+    assert engine.get_callback_errors() == []
+
+    with pytest.raises(ValueError):
+        engine.callback_error_policy = "ignore"
+    engine.callback_error_policy = "raise"
+    try:
+        engine.update()
+        with pytest.raises(KeyError):
+            engine.render()
+        assert len(engine.get_callback_errors(clear=False)) == 1
+    finally:
+        engine.callback_error_policy = "log"
+    engine.update()
+    engine.render()
+
+
+def test_slow_frames_are_flagged_with_their_slowest_scope() -> None:
+    """Test frames over budget are flagged and counted, naming the slowest scope."""
+    engine = pyg.Engine()
+
+    class Sleeper(pyg.Script):
+        def update(self, dt: float) -> None:
+            time.sleep(0.003)
+
+    game_object = pyg.GameObject("Sleeper")
+    game_object.add_component(Sleeper())
+    engine.add_game_object(game_object)
+
+    engine.frame_budget_ms = 1.0
+    for _ in range(3):
+        engine.update()
+    stats = engine.get_frame_stats()
+    assert stats.slow
+    assert stats.slowest_scope == "scripts"
+    assert engine.slow_frame_count == 2
+
+    engine.frame_spike_factor = 3.0
+    assert engine.frame_spike_factor == pytest.approx(3.0)
+    with pytest.raises(ValueError):
+        engine.frame_spike_factor = 1.0
+    engine.frame_spike_factor = None
+    assert engine.frame_spike_factor is None
+
+
 def test_is_running_reflects_runtime_state() -> None:
     """Test Engine.is_running reflects lifecycle state transitions."""
     engine = pyg.Engine()
@@ -145,6 +379,19 @@ def test_is_running_reflects_runtime_state() -> None:
     assert engine.is_running is False
 
 
+def test_manual_loop_steps_without_a_window() -> None:
+    """Test the manual-loop calls: polling needs start_manual, stepping does not."""
+    engine = pyg.Engine()
+    with pytest.raises(RuntimeError, match="start_manual"):
+        engine.poll_events()
+
+    object_id = engine.create_game_object("Stepped")
+    engine.destroy_object(object_id)
+    engine.update()
+    engine.render()
+    assert engine.find_by_name("Stepped") == []
+
+
 def test_update_context_is_exposed() -> None:
     """Test that UpdateContext is importable from the top-level module."""
     assert hasattr(pyg, "UpdateContext")
@@ -196,6 +443,542 @@ def test_update_callback_compiler_single_arg_falls_back_to_context() -> None:
     assert received[0] is context
 
 
+def test_script_reloader_rebinds_tracked_instances(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    """Test edited script modules are reimported and live instances keep their state."""
+    import os
+    import sys
+
+    script = tmp_path / "reload_target_enemy.py"
+    script.write_text("class Enemy:\n    def speed(self):\n        return 1\n")
+    monkeypatch.syspath_prepend(str(tmp_path))
+    monkeypatch.delitem(sys.modules, "reload_target_enemy", raising=False)
+
+    engine = pyg.Engine()
+    module = engine.scripts.watch("reload_target_enemy")
+    enemy = engine.scripts.track(module.Enemy())
+    enemy.hp = 7
+    assert engine.scripts.check() == []
+
+    script.write_text(
+        "class Enemy:\n"
+        "    def speed(self):\n"
+        "        return 2\n"
+        "    def on_reload(self):\n"
+        "        self.reloaded = True\n"
+    )
+    # Bump the mtime so the change is seen even within the same timestamp tick.
+    stat = script.stat()
+    os.utime(script, (stat.st_atime, stat.st_mtime + 10))
+
+    assert engine.scripts.check() == ["reload_target_enemy"]
+    assert enemy.speed() == 2
+    assert enemy.hp == 7
+    assert enemy.reloaded is True
+
+    # A broken edit keeps the previous version running.
+    script.write_text("class Enemy(:\n")
+    os.utime(script, (stat.st_atime, stat.st_mtime + 20))
+    assert engine.scripts.check() == []
+    assert enemy.speed() == 2
+
+
+def test_timers_follow_clock_policies_and_persist(tmp_path: Path) -> None:
+    """Test named timers respect pause/time scale and survive a save/load round trip."""
+    engine = pyg.Engine()
+    expired = []
+    engine.timers.add_listener(expired.append)
+    engine.timers.start("spawn_wave", 2.0, repeat=True)
+    engine.timers.start("menu_fade", 1.0, clock="unscaled")
+    engine.timers.start("daily_chest", 86400, clock="realtime")
+
+    engine.timers.paused = True
+    assert engine.timers.tick(1.5) == ["menu_fade"]
+    assert engine.timers.remaining("spawn_wave") == 2.0
+
+    engine.timers.paused = False
+    engine.timers.time_scale = 2.0
+    assert engine.timers.tick(1.0) == ["spawn_wave"]
+    assert engine.timers.is_running("spawn_wave")
+    assert expired == ["menu_fade", "spawn_wave"]
+
+    engine.timers.tick(0.25)
+    save_path = tmp_path / "timers.json"
+    engine.timers.save(str(save_path))
+
+    restored = pyg.Engine()
+    assert restored.timers.load(str(save_path))
+    assert sorted(restored.timers.names()) == ["daily_chest", "spawn_wave"]
+    assert restored.timers.remaining("spawn_wave") == pytest.approx(1.5)
+    assert restored.timers.remaining("daily_chest") > 86000
+    assert not restored.timers.load(str(tmp_path / "missing.json"))
+
+
+def test_jobs_run_in_the_background_and_dispatch_on_update() -> None:
+    """Test job results and errors reach their callbacks in the engine update."""
+    engine = pyg.Engine()
+    engine.get_callback_errors()
+    results = []
+    errors = []
+    main_thread = threading.get_ident()
+
+    def work(value: int, scale: int = 1) -> tuple[int, bool]:
+        return value * scale, threading.get_ident() != main_thread
+
+    def fail() -> None:
+        raise ValueError("bad seed")
+
+    job = engine.run_job(work, 21, scale=2, on_complete=results.append)
+    failed = engine.run_job(fail, on_error=errors.append)
+    unhandled = engine.run_job(fail, name="generate")
+    assert job.result(timeout=5) == (42, True)
+    assert engine.jobs.wait_all(timeout=5)
+    assert results == [(42, True)]
+    assert job.status == "succeeded" and job.dispatched
+    assert failed.status == "failed"
+    assert [str(error) for error in errors] == ["bad seed"]
+    assert [error.source for error in engine.get_callback_errors()] == ["job 'generate'"]
+    assert unhandled.error is not None
+    assert engine.jobs.active_count == 0
+
+    gate = threading.Event()
+    engine.jobs.max_workers = 1
+    blocker = engine.run_job(gate.wait)
+    queued = engine.run_job(work, 1, on_complete=results.append)
+    assert queued.cancel()
+    gate.set()
+    blocker.result(timeout=5)
+    engine.update()
+    assert queued.status == "cancelled"
+    assert results == [(42, True)]
+    engine.jobs.shutdown()
+
+
+def test_save_data_round_trips_and_survives_damage(tmp_path: Path) -> None:
+    """Test save data persists values and blobs, restores backups and rotates autosaves."""
+    engine = pyg.Engine()
+    save_data = engine.save_data
+    save_data.directory = tmp_path
+    assert save_data.get("progress") is None
+
+    progress = {"level": 3, "items": ["sword"]}
+    save_data.set("progress", progress)
+    progress["level"] = 99
+    save_data.set_blob("thumbnail", b"\x89PNG")
+    save_data.save()
+    save_data.set("progress", {"level": 4, "items": []})
+    save_data.save()
+    with pytest.raises(TypeError):
+        save_data.set("callback", print)
+    assert save_data.slots() == ["default"]
+
+    (tmp_path / "default" / "values.json").write_text("{truncated", encoding="utf-8")
+    restored = pyg.Engine()
+    restored.save_data.directory = tmp_path
+    assert restored.save_data.get("progress") == {"level": 3, "items": ["sword"]}
+    assert restored.save_data.get_blob("thumbnail") == b"\x89PNG"
+
+    save_data.enable_autosave(1.0, slots=2)
+    for level in range(5, 8):
+        save_data.set("progress", {"level": level})
+        save_data.tick(1.0)
+    assert save_data.latest_autosave() == "autosave_1"
+    assert save_data.load("autosave_2")
+    assert save_data.get("progress") == {"level": 6}
+    assert not save_data.load("empty")
+    with pytest.raises(ValueError):
+        save_data.load("../outside")
+
+
+def test_assets_share_handles_and_unload_on_last_release(tmp_path: Path) -> None:
+    """Test assets load once per file, count references and unload on the last release."""
+    engine = pyg.Engine(source_root=str(tmp_path))
+    assets = engine.assets
+    (tmp_path / "level.json").write_text('{"width": 12}', encoding="utf-8")
+
+    level = assets.load_data("level.json")
+    again = assets.load_data(str(tmp_path / "level.json"))
+    assert level == again
+    assert level.kind == "data"
+    assert assets.ref_count(level) == 2
+    assert assets.read_json(level) == {"width": 12}
+    assert assets.loaded_count == 1
+    assert assets.memory_usage >= len('{"width": 12}')
+
+    assert not assets.release(again)
+    assert assets.release(level)
+    assert not assets.is_loaded(level)
+    assert not assets.retain(level)
+    with pytest.raises(ValueError):
+        assets.read_bytes(level)
+    with pytest.raises(ValueError):
+        assets.load("shader", "level.json")
+    with pytest.raises(RuntimeError):
+        assets.load_texture("missing.png")
+
+
+def test_asset_hot_reload_rereads_files_and_rebuilds_ui_documents(tmp_path: Path) -> None:
+    """Test hot reload keeps handles for changed files and rebuilds UI documents in place."""
+    engine = pyg.Engine(source_root=str(tmp_path))
+    assets = engine.assets
+    level_path = tmp_path / "level.json"
+    level_path.write_text('{"width": 12}', encoding="utf-8")
+    menu_path = tmp_path / "menu.json"
+    menu_path.write_text('{"widgets": [{"type": "label", "id": "title", "text": "Old"}]}', encoding="utf-8")
+
+    level = assets.load_data("level.json")
+    menu = engine.ui.load(menu_path)
+    rebuilt = []
+    menu.set_on_reload(rebuilt.append)
+    assert assets.check() == []
+
+    level_path.write_text('{"width": 30}', encoding="utf-8")
+    menu_path.write_text('{"widgets": [{"type": "label", "id": "title", "text": "New"}]}', encoding="utf-8")
+    os.utime(level_path, (1_000, 1_000))
+    os.utime(menu_path, (1_000, 1_000))
+    assert len(assets.check()) == 2
+    assert assets.read_json(level) == {"width": 30}
+    assert assets.ref_count(level) == 1
+    assert menu["title"].text == "New"
+    assert rebuilt == [menu]
+
+    menu_path.write_text("{broken", encoding="utf-8")
+    os.utime(menu_path, (2_000, 2_000))
+    assert assets.check() == []
+    assert menu["title"].text == "New"
+
+    assets.hot_reload_interval = 0.0
+    assets.hot_reload = True
+    assert assets.hot_reload
+    with pytest.raises(ValueError):
+        assets.hot_reload_interval = -1.0
+
+
+def _write_tone(path: Path, frames: int, sample_rate: int = 8000) -> None:
+    with wave.open(str(path), "wb") as out:
+        out.setnchannels(1)
+        out.setsampwidth(2)
+        out.setframerate(sample_rate)
+        out.writeframes(b"\x00\x10" * frames)
+
+
+def test_positional_audio_sources_follow_the_listener(tmp_path: Path) -> None:
+    """Test sounds play on the engine clock and sources follow the listener."""
+    engine = pyg.Engine(source_root=str(tmp_path))
+    _write_tone(tmp_path / "tone.wav", frames=80)
+
+    voice = engine.audio.play("tone.wav", volume=0.5, position=pyg.Vec2(200, 0))
+    assert engine.audio.is_playing(voice)
+    assert not engine.audio.set_pan(voice, 0.5)
+    assert engine.audio.stop(voice)
+    assert not engine.audio.is_playing(voice)
+    with pytest.raises(RuntimeError):
+        engine.audio.play("missing.wav")
+    with pytest.raises(ValueError):
+        engine.audio.play("tone.wav", attenuation="cubic")
+    with pytest.raises(ValueError):
+        engine.audio.master_volume = -1.0
+
+    listener = pyg.GameObject("Player")
+    listener.position = pyg.Vec2(50, 25)
+    listener.add_component(pyg.AudioListener(pan_width=300))
+    engine.add_game_object(listener)
+    speaker = pyg.GameObject("Speaker")
+    source = pyg.AudioSourceComponent("tone.wav", looping=True, autoplay=True, attenuation="inverse")
+    speaker.add_component(source)
+    engine.add_game_object(speaker)
+    assert source.playing and source.attenuation == "inverse"
+
+    engine.update()
+    assert engine.audio.is_playing(source)
+    listener_position = engine.audio.listener_position
+    assert (listener_position.x, listener_position.y) == (50, 25)
+    runtime_source = speaker.get_component("AudioSource")
+    runtime_source.stop()
+    engine.update()
+    assert not engine.audio.is_playing(runtime_source)
+    assert engine.audio.playing_count == 0
+
+
+
+def test_audio_buses_hold_settings_for_menus(tmp_path: Path) -> None:
+    """Test bus settings validate, round-trip through dicts and route sounds."""
+    engine = pyg.Engine(source_root=str(tmp_path))
+    _write_tone(tmp_path / "tone.wav", frames=80)
+    audio = engine.audio
+
+    music = audio.bus("Music")
+    music.volume = 0.5
+    music.low_pass = 800.0
+    music.reverb_send = 0.25
+    audio.bus("ui").muted = True
+    assert (music.volume, music.low_pass, music.reverb_send) == (0.5, 800.0, 0.25)
+    assert [bus.name for bus in audio.buses] == ["master", "music", "sfx", "ui"]
+    audio.master_volume = 0.75
+    assert audio.bus("master").volume == 0.75
+
+    saved = audio.get_bus_settings()
+    assert saved["ui"] == {"volume": 1.0, "muted": True, "low_pass": None, "reverb_send": 0.0}
+    music.low_pass = None
+    audio.bus("ui").muted = False
+    audio.set_bus_settings(saved)
+    assert music.low_pass == 800.0 and audio.bus("ui").muted
+
+    with pytest.raises(ValueError):
+        audio.bus("voice")
+    with pytest.raises(ValueError):
+        music.reverb_send = 2.0
+    with pytest.raises(ValueError):
+        music.low_pass = 0.0
+    with pytest.raises(ValueError):
+        audio.set_bus_settings({"sfx": {"pitch": 2.0}})
+    with pytest.raises(ValueError):
+        audio.play("tone.wav", bus="voice")
+
+    voice = audio.play("tone.wav", bus="music")
+    assert audio.is_playing(voice)
+    source = pyg.AudioSourceComponent("tone.wav", bus="ui")
+    assert source.bus == "ui"
+    source.bus = "music"
+    assert source.bus == "music"
+
+
+def test_generated_sounds_and_streams_play_without_files() -> None:
+    """Test sample buffers play by name and streams pull samples each update."""
+    engine = pyg.Engine()
+    engine.get_callback_errors()
+    audio = engine.audio
+
+    assert audio.create_sound("beep", [0.5, -0.5] * 400, sample_rate=8000) == "beep"
+    voice = audio.play("beep")
+    assert audio.is_playing(voice)
+    audio.create_sound("click", b"\x00\x10\x00\xf0", sample_rate=8000, channels=2)
+    with pytest.raises(ValueError):
+        audio.create_sound("odd", b"\x00\x10\x00", sample_rate=8000)
+    with pytest.raises(ValueError):
+        audio.create_sound("wide", b"\x00\x00" * 6, channels=3)
+    assert audio.remove_sound("click")
+    assert not audio.remove_sound("click")
+    with pytest.raises(RuntimeError):
+        audio.play("click")
+
+    requests = []
+
+    def generate(frames: int) -> list[float]:
+        requests.append(frames)
+        return [0.25] * frames if len(requests) < 3 else None
+
+    stream = audio.play_stream(generate, sample_rate=1000, buffer_seconds=0.05)
+    assert requests == [50]
+    assert stream.playing and stream.queued_frames == 50
+    for _ in range(10):
+        time.sleep(0.02)
+        engine.update()
+    # The third call returned None, ending the stream once its samples played
+    assert len(requests) == 3
+    assert not stream.playing
+
+    def broken(frames: int) -> list[float]:
+        raise RuntimeError("synth failed")
+
+    failing = audio.play_stream(broken, sample_rate=1000)
+    assert [error.source for error in engine.get_callback_errors()] == ["sound stream generator"]
+    assert not failing.queue([0.0])
+
+
+def test_sound_pitch_is_set_or_randomized_per_play(tmp_path: Path) -> None:
+    """Test pitch speeds sounds up and pitch ranges validate on sounds and sources."""
+    engine = pyg.Engine(source_root=str(tmp_path))
+    _write_tone(tmp_path / "tone.wav", frames=800)
+    audio = engine.audio
+
+    fast = audio.play("tone.wav", pitch=4.0)
+    normal = audio.play("tone.wav", pitch_range=(0.95, 1.05))
+    assert audio.set_pitch(normal, 1.0)
+    with pytest.raises(ValueError):
+        audio.set_pitch(normal, 0.0)
+    with pytest.raises(ValueError):
+        audio.play("tone.wav", pitch_range=(1.2, 0.8))
+    time.sleep(0.05)
+    engine.update()
+    assert not audio.is_playing(fast)
+    assert audio.is_playing(normal)
+
+    source = pyg.AudioSourceComponent("tone.wav", pitch=1.5, pitch_range=(0.9, 1.1))
+    assert source.pitch == 1.5
+    assert source.pitch_range == pytest.approx((0.9, 1.1))
+    source.pitch_range = None
+    assert source.pitch_range is None
+    with pytest.raises(ValueError):
+        source.pitch = -1.0
+
+
+def test_finished_sounds_run_callbacks_and_queue_for_polling() -> None:
+    """Test sounds that play to the end call on_finished, queue and emit events."""
+    engine = pyg.Engine()
+    engine.get_callback_errors()
+    audio = engine.audio
+    audio.create_sound("blip", [0.5] * 10, sample_rate=1000)
+    finished = []
+    events = []
+    engine.on("sound_finished", events.append)
+
+    first = audio.play("blip", on_finished=finished.append)
+    stopped = audio.play("blip", on_finished=finished.append)
+    failing = audio.play("blip", on_finished=lambda voice_id: 1 / 0)
+    assert audio.stop(stopped)
+    time.sleep(0.02)
+    engine.update()
+    engine.update()
+
+    assert finished == [first]
+    assert sorted(events) == [first, failing]
+    assert audio.take_finished() == [first, failing]
+    assert audio.take_finished() == []
+    assert [error.source for error in engine.get_callback_errors()] == ["sound on_finished callback"]
+
+    stream = audio.play_stream(lambda frames: None, sample_rate=1000, on_finished=finished.append)
+    engine.update()
+    assert finished == [first, stream.id]
+
+
+def test_sound_limit_cuts_off_low_priority_sounds_first() -> None:
+    """Test rapid-fire sounds past max_sounds replace each other, not higher priorities."""
+    engine = pyg.Engine()
+    audio = engine.audio
+    audio.create_sound("shot", [0.5] * 1000, sample_rate=1000)
+    assert audio.max_sounds == 64
+    audio.max_sounds = 3
+
+    music = audio.play("shot", looping=True, priority=10)
+    cut_off = []
+    shots = [audio.play("shot", on_finished=cut_off.append) for _ in range(5)]
+    assert audio.playing_count == 3
+    assert audio.is_playing(music)
+    assert [audio.is_playing(shot) for shot in shots] == [False, False, False, True, True]
+    quiet = audio.play("shot", priority=-1)
+    assert not audio.is_playing(quiet)
+
+    engine.update()
+    assert cut_off == shots[:3]
+    assert audio.take_finished() == shots[:3] + [quiet]
+    with pytest.raises(ValueError):
+        audio.max_sounds = 0
+
+    source = pyg.AudioSourceComponent("shot", priority=5)
+    assert source.priority == 5
+    source.priority = -2
+    assert source.priority == -2
+
+
+def test_colliders_meeting_play_impact_sounds_by_material_or_layer() -> None:
+    """Test impact sounds play once when colliders meet, picked by materials before layers."""
+    engine = pyg.Engine()
+    audio = engine.audio
+    audio.create_sound("clang", [0.5] * 1000, sample_rate=1000)
+    audio.create_sound("thud", [0.5] * 1000, sample_rate=1000)
+    audio.set_impact_sound("thud", layers=(2, 1), spatial=False)
+    audio.set_impact_sound("clang", materials=("metal", "metal"), full_volume_depth=0.5)
+    with pytest.raises(ValueError):
+        audio.set_impact_sound("thud")
+    with pytest.raises(ValueError):
+        audio.set_impact_sound("thud", layers=(1, 2), materials=("metal", "wood"))
+
+    def add_ball(x: float, layer: int, material: str | None) -> None:
+        collider = pyg.Collider("Ball")
+        collider.set_shape(pyg.ColliderShape.circle(1.0))
+        collider.set_layer(layer)
+        collider.set_material(material)
+        ball = pyg.GameObject("Ball")
+        ball.position = pyg.Vec2(x, 0.0)
+        ball.add_component(collider)
+        engine.add_game_object(ball)
+
+    add_ball(0.0, 1, "metal")
+    add_ball(1.0, 2, "metal")
+    assert pyg.Collider("Probe").material is None
+    for _ in range(3):
+        time.sleep(0.02)
+        engine.update()
+    # One sound for the pair, only on the step they met
+    assert audio.playing_count == 1
+
+    assert audio.remove_impact_sound(materials=("metal", "metal"))
+    assert not audio.remove_impact_sound(materials=("metal", "metal"))
+    add_ball(50.0, 1, None)
+    add_ball(51.0, 2, None)
+    for _ in range(3):
+        time.sleep(0.02)
+        engine.update()
+    assert audio.playing_count == 2
+    audio.clear_impact_sounds()
+    assert not audio.remove_impact_sound(layers=(1, 2))
+
+
+def test_audio_snapshots_fade_buses_and_follow_scenes() -> None:
+    """Test snapshots change only the settings they list and scenes can fade to one."""
+    engine = pyg.Engine()
+    audio = engine.audio
+    audio.bus("music").volume = 0.7
+    audio.add_snapshot("underwater", {"music": {"low_pass": 1000.0}, "sfx": {"muted": True}})
+    audio.add_snapshot("surface", {"music": {"low_pass": None}, "sfx": {"muted": False}})
+    assert audio.snapshots == ["surface", "underwater"]
+    with pytest.raises(ValueError):
+        audio.add_snapshot("bad", {"music": {"pitch": 2.0}})
+    with pytest.raises(ValueError):
+        audio.add_snapshot("bad", {"voice": {"volume": 1.0}})
+    with pytest.raises(ValueError):
+        audio.transition_to("missing")
+
+    audio.transition_to("underwater", 0.05)
+    assert audio.snapshot == "underwater" and audio.transitioning
+    for _ in range(3):
+        time.sleep(0.03)
+        engine.update()
+    assert not audio.transitioning
+    assert audio.bus("music").low_pass == pytest.approx(1000.0)
+    assert audio.bus("music").volume == pytest.approx(0.7)
+    assert audio.bus("sfx").muted
+
+    audio.add_snapshot("saved")
+    engine.scenes.register("beach", audio_snapshot="surface", audio_fade=0.0)
+    engine.scenes.load_scene("beach")
+    assert audio.snapshot == "surface"
+    assert audio.bus("music").low_pass is None and not audio.bus("sfx").muted
+    audio.transition_to("saved")
+    assert audio.bus("music").low_pass == pytest.approx(1000.0)
+    assert audio.remove_snapshot("saved") and not audio.remove_snapshot("saved")
+
+
+def test_config_file_sets_defaults_that_python_overrides(tmp_path: Path) -> None:
+    """Test pyg_engine.toml settings apply at startup and stay overridable."""
+    (tmp_path / "game").mkdir()
+    (tmp_path / "game" / "level.json").write_text("{}", encoding="utf-8")
+    config_path = tmp_path / "pyg_engine.toml"
+    config_path.write_text(
+        "[time]\nfixed_timestep = 0.025\ntarget_fps = 30\n"
+        "[assets]\nroot = \"game\"\n"
+        "[physics]\ncollisions = false\n",
+        encoding="utf-8",
+    )
+
+    engine = pyg.Engine(config=config_path)
+    assert engine.config_path == str(config_path)
+    assert engine.fixed_timestep == pytest.approx(0.025)
+    assert engine.get_target_fps() == pytest.approx(30.0)
+    assert not engine.collisions_enabled
+    assert engine.assets.read_json(engine.assets.load_data("level.json")) == {}
+
+    engine.set_fixed_timestep(0.01)
+    engine.collisions_enabled = True
+    assert engine.fixed_timestep == pytest.approx(0.01)
+    assert engine.collisions_enabled
+
+    config_path.write_text("[window]\nwidth = \"wide\"\n", encoding="utf-8")
+    fallback = pyg.Engine(config=config_path)
+    assert fallback.config_path is None
+    assert fallback.collisions_enabled
+
+
 def test_engine_log_info_no_crash() -> None:
     """
     Test that log_info doesn't crash.
@@ -0,0 +1,621 @@
+#!/usr/bin/env python3
+"""
+Generate `pyg_engine_native.pyi`, the type stubs for the compiled module.
+
+The stubs come from two places:
+
+- The built module, which knows every class, method, property and constant
+  it exports, and the signature (parameter names, defaults, `/` and `*`)
+  pyo3 gave each function.
+- The pyo3 sources in `rust/src/bindings`, which know the Rust types of the
+  parameters and return values. They are mapped to Python types, e.g.
+  `Option<Vec<PyVec2>>` becomes `Sequence[Vec2] | None` for a parameter.
+
+Anything the sources don't pin down, like a `Bound<PyAny>` argument, is typed
+`Any`. `setup.py` runs this after every build; to refresh the committed stub
+by hand after building in place:
+
+    python tools/generate_stubs.py
+    python tools/generate_stubs.py --check   # fail if the stub is stale
+"""
+
+from __future__ import annotations
+
+import argparse
+import importlib
+import importlib.machinery
+import importlib.util
+import inspect
+import re
+import sys
+from dataclasses import dataclass, field
+from pathlib import Path
+from types import ModuleType
+from typing import Dict, List, Optional, Tuple
+
+ROOT = Path(__file__).resolve().parent.parent
+BINDINGS = ROOT / "rust" / "src" / "bindings"
+MODULE_NAME = "pyg_engine.pyg_engine_native"
+STUB_PATH = ROOT / "python" / "pyg_engine" / "pyg_engine_native.pyi"
+
+HEADER = '''"""
+Type stubs for the compiled `pyg_engine.pyg_engine_native` module.
+
+Generated by `tools/generate_stubs.py` from the pyo3 bindings; don't edit by
+hand.
+"""
+
+from typing import Any, ClassVar, Iterator, Mapping, Sequence
+'''
+
+# Rust types that map straight to a Python type
+_SCALARS = {
+    **{name: "int" for name in (
+        "i8", "i16", "i32", "i64", "i128", "isize",
+        "u8", "u16", "u32", "u64", "u128", "usize",
+    )},
+    "f32": "float",
+    "f64": "float",
+    "bool": "bool",
+    "char": "str",
+    "str": "str",
+    "String": "str",
+    "PathBuf": "str",
+    "Path": "str",
+    "PyString": "str",
+    "PyBytes": "bytes",
+    "PyByteArray": "bytearray",
+    "PyFloat": "float",
+    "PyInt": "int",
+    "PyLong": "int",
+    "PyBool": "bool",
+    "PyType": "type",
+    "PyModule": "Any",
+    "PyAny": "Any",
+    "PyObject": "Any",
+    "PyIterator": "Iterator[Any]",
+    "PyMemoryView": "memoryview",
+    "PyList": "list[Any]",
+    "PyTuple": "tuple[Any, ...]",
+    "PyDict": "dict[Any, Any]",
+    "PySet": "set[Any]",
+}
+# Wrappers that extract to or return whatever they hold
+_TRANSPARENT = {"PyResult", "Bound", "Py", "PyRef", "PyRefMut", "Borrowed", "Box", "Arc", "Rc", "Cow"}
+# In-place operators hand back `self` whatever the Rust function returns
+_IN_PLACE = {
+    "__iadd__", "__isub__", "__imul__", "__imatmul__", "__itruediv__", "__ifloordiv__",
+    "__imod__", "__ipow__", "__ilshift__", "__irshift__", "__iand__", "__ixor__", "__ior__",
+}
+_DUNDER_RETURNS = {
+    "__repr__": "str",
+    "__str__": "str",
+    "__hash__": "int",
+    "__len__": "int",
+    "__bool__": "bool",
+    "__contains__": "bool",
+}
+# Attributes every class has, which the stubs leave to `object`
+_SKIPPED = {
+    "__doc__", "__module__", "__new__", "__init__", "__dict__", "__weakref__",
+    "__qualname__", "__text_signature__", "__getattribute__", "__setattr__",
+    "__delattr__", "__dir__", "__class__", "__init_subclass__", "__subclasshook__",
+    "__reduce_ex__", "__sizeof__", "__format__",
+}
+
+
+# ========== Rust Source Parsing ==========
+
+@dataclass
+class RustFn:
+    """A function in a `#[pymethods]` block, or a `#[pyo3(get)]` field."""
+
+    params: List[Tuple[str, str]]
+    returns: str
+    static: bool = False
+
+
+@dataclass
+class RustClass:
+    rust_name: str
+    python_name: str
+    methods: Dict[str, RustFn] = field(default_factory=dict)
+    getters: Dict[str, str] = field(default_factory=dict)
+    setters: Dict[str, str] = field(default_factory=dict)
+    new: Optional[RustFn] = None
+
+
+def _mask(source: str) -> str:
+    """
+    Blank out comments, string and char literals, keeping every offset, so
+    braces and brackets can be matched on the result.
+    """
+    out = list(source)
+    i, n = 0, len(source)
+
+    def blank(start: int, end: int) -> None:
+        for j in range(start, end):
+            if out[j] != "\n":
+                out[j] = " "
+
+    while i < n:
+        if source.startswith("//", i):
+            end = source.find("\n", i)
+            end = n if end < 0 else end
+            blank(i, end)
+            i = end
+        elif source.startswith("/*", i):
+            depth, j = 1, i + 2
+            while j < n and depth:
+                if source.startswith("/*", j):
+                    depth, j = depth + 1, j + 2
+                elif source.startswith("*/", j):
+                    depth, j = depth - 1, j + 2
+                else:
+                    j += 1
+            blank(i, j)
+            i = j
+        elif re.match(r'(?:b|c)?r#*"', source[i:i + 8]) and (i == 0 or not (source[i - 1].isalnum() or source[i - 1] == "_")):
+            prefix = re.match(r'(?:b|c)?r(#*)"', source[i:]).group(0)
+            closing = '"' + prefix.count("#") * "#"
+            end = source.find(closing, i + len(prefix))
+            end = n if end < 0 else end + len(closing)
+            blank(i + len(prefix), end - len(closing))
+            i = end
+        elif source[i] == '"':
+            j = i + 1
+            while j < n and source[j] != '"':
+                j += 2 if source[j] == "\\" else 1
+            blank(i + 1, j)
+            i = j + 1
+        elif source[i] == "'":
+            # A char literal, or a lifetime which has no closing quote
+            literal = re.match(r"'(?:\\(?:x[0-9a-fA-F]{2}|u\{[0-9a-fA-F]+\}|.)|[^\\'\n])'", source[i:])
+            if literal:
+                blank(i + 1, i + literal.end() - 1)
+                i += literal.end()
+            else:
+                i += 1
+        else:
+            i += 1
+    return "".join(out)
+
+
+def _closing(masked: str, start: int) -> int:
+    """Index just past the bracket matching the one at `start`."""
+    pairs = {"(": ")", "[": "]", "{": "}", "<": ">"}
+    opening = masked[start]
+    closing = pairs[opening]
+    depth = 0
+    for i in range(start, len(masked)):
+        char = masked[i]
+        if char == opening:
+            depth += 1
+        elif char == closing and not (opening == "<" and masked[i - 1] == "-"):
+            depth -= 1
+            if depth == 0:
+                return i + 1
+    raise ValueError(f"unbalanced {opening!r} at offset {start}")
+
+
+def _split_top_level(text: str) -> List[str]:
+    """Split on commas outside any brackets."""
+    parts, depth, current = [], 0, []
+    for i, char in enumerate(text):
+        if char in "([{<":
+            depth += 1
+        elif char in ")]}>" and not (char == ">" and i > 0 and text[i - 1] == "-"):
+            depth -= 1
+        if char == "," and depth == 0:
+            parts.append("".join(current).strip())
+            current = []
+        else:
+            current.append(char)
+    if "".join(current).strip():
+        parts.append("".join(current).strip())
+    return parts
+
+
+def _attr_value(attribute: str, key: str) -> Optional[str]:
+    match = re.search(rf'\b{key}\s*=\s*"([^"]*)"', attribute)
+    return match.group(1) if match else None
+
+
+def _leading_attributes(source: str, masked: str, start: int, end: int) -> Tuple[List[str], int]:
+    """The `#[...]` attributes from `start`, and where the item after them begins."""
+    attributes = []
+    i = start
+    while True:
+        while i < end and masked[i].isspace():
+            i += 1
+        if masked.startswith("#[", i):
+            close = _closing(masked, i + 1)
+            attributes.append(source[i:close])
+            i = close
+        else:
+            return attributes, i
+
+
+def _parse_params(source: str) -> List[Tuple[str, str]]:
+    params = []
+    for param in _split_top_level(source):
+        param = re.sub(r"#\[[^\]]*\]\s*", "", param).strip()
+        if re.fullmatch(r"&?\s*('\w+\s+)?(mut\s+)?self", param):
+            continue
+        name, _, rust_type = param.partition(":")
+        name = re.sub(r"^mut\s+", "", name.strip())
+        rust_type = rust_type.strip()
+        if name == "slf" or rust_type.startswith("Python"):
+            continue
+        params.append((name, rust_type))
+    return params
+
+
+def _parse_pymethods(source: str, masked: str, body_start: int, body_end: int, rust_class: RustClass) -> None:
+    i = body_start
+    while i < body_end:
+        attributes, item = _leading_attributes(source, masked, i, body_end)
+        fn = re.compile(r"(?:pub(?:\([^)]*\))?\s+)?(?:const\s+|async\s+|unsafe\s+)*fn\s+(\w+)").match(masked, item)
+        if not fn:
+            # Not a function (a const or the end of the block): skip the item
+            end = masked.find(";", item, body_end)
+            brace = masked.find("{", item, body_end)
+            if brace >= 0 and (end < 0 or brace < end):
+                end = _closing(masked, brace) - 1
+            if end < 0:
+                return
+            i = end + 1
+            continue
+        rust_name = fn.group(1)
+        j = fn.end()
+        while masked[j].isspace():
+            j += 1
+        if masked[j] == "<":
+            j = _closing(masked, j)
+        paren = masked.index("(", j)
+        params_end = _closing(masked, paren)
+        body = masked.index("{", params_end)
+        signature_tail = source[params_end:body]
+        returns = signature_tail.split("where")[0].strip()
+        returns = returns[2:].strip() if returns.startswith("->") else "()"
+        function = RustFn(_parse_params(source[paren + 1:params_end - 1]), returns)
+        i = _closing(masked, body)
+
+        joined = " ".join(attributes)
+        python_name = _attr_value(joined, "name") or rust_name
+        if "#[new]" in joined:
+            rust_class.new = function
+            continue
+        getter = re.search(r"#\[getter(?:\((\w+)\))?\]", joined)
+        setter = re.search(r"#\[setter(?:\((\w+)\))?\]", joined)
+        if getter:
+            name = getter.group(1) or re.sub(r"^get_", "", rust_name)
+            rust_class.getters[name] = returns
+        elif setter:
+            name = setter.group(1) or re.sub(r"^set_", "", rust_name)
+            value_types = [rust_type for _, rust_type in function.params]
+            rust_class.setters[name] = value_types[0] if value_types else "PyAny"
+        elif "#[classattr]" not in joined:
+            function.static = "#[staticmethod]" in joined
+            rust_class.methods[python_name] = function
+
+
+def _parse_fields(source: str, masked: str, start: int, end: int, rust_class: RustClass) -> None:
+    for field_source in _split_top_level(source[start:end]):
+        attributes = re.findall(r"#\[pyo3\(([^\]]*)\)\]", field_source)
+        declaration = re.sub(r"#\[[^\]]*\]|///[^\n]*|//[^\n]*", "", field_source).strip()
+        match = re.match(r"(?:pub(?:\([^)]*\))?\s+)?(\w+)\s*:\s*(.+)", declaration, re.S)
+        if not match or not attributes:
+            continue
+        options = " ".join(attributes)
+        name = _attr_value(options, "name") or match.group(1)
+        rust_type = " ".join(match.group(2).split())
+        if re.search(r"\bget\b", options):
+            rust_class.getters[name] = rust_type
+        if re.search(r"\bset\b", options):
+            rust_class.setters[name] = rust_type
+
+
+def parse_bindings(directory: Path = BINDINGS) -> Tuple[Dict[str, RustClass], Dict[str, RustFn]]:
+    """The `#[pyclass]` types by Python name, and the `#[pyfunction]`s."""
+    by_rust_name: Dict[str, RustClass] = {}
+    impls: List[Tuple[str, str, str, int, int]] = []
+    functions: Dict[str, RustFn] = {}
+    for path in sorted(directory.glob("*.rs")):
+        source = path.read_text(encoding="utf-8")
+        masked = _mask(source)
+        for match in re.finditer(r"#\[pyclass\b", masked):
+            attributes, item = _leading_attributes(source, masked, match.start(), len(masked))
+            declaration = re.compile(r"(?:pub(?:\([^)]*\))?\s+)?(struct|enum)\s+(\w+)").match(masked, item)
+            if not declaration:
+                continue
+            pyclass = next(attribute for attribute in attributes if attribute.startswith("#[pyclass"))
+            rust_name = declaration.group(2)
+            rust_class = RustClass(rust_name, _attr_value(pyclass, "name") or rust_name)
+            by_rust_name[rust_name] = rust_class
+            brace = masked.find("{", declaration.end())
+            semicolon = masked.find(";", declaration.end())
+            if declaration.group(1) == "struct" and brace >= 0 and (semicolon < 0 or brace < semicolon):
+                _parse_fields(source, masked, brace + 1, _closing(masked, brace) - 1, rust_class)
+        for match in re.finditer(r"#\[pymethods\]", masked):
+            header = re.compile(r"\s*(?:#\[[^\]]*\]\s*)*impl\s+(\w+)\s*\{").match(masked, match.end())
+            if header:
+                brace = header.end() - 1
+                impls.append((source, masked, header.group(1), brace + 1, _closing(masked, brace) - 1))
+        for match in re.finditer(r"#\[pyfunction\]", masked):
+            attributes, item = _leading_attributes(source, masked, match.start(), len(masked))
+            fn = re.compile(r"(?:pub(?:\([^)]*\))?\s+)?fn\s+(\w+)").match(masked, item)
+            if not fn:
+                continue
+            paren = masked.index("(", fn.end())
+            params_end = _closing(masked, paren)
+            returns = source[params_end:masked.index("{", params_end)].strip()
+            returns = returns[2:].strip() if returns.startswith("->") else "()"
+            name = _attr_value(" ".join(attributes), "name") or fn.group(1)
+            functions[name] = RustFn(_parse_params(source[paren + 1:params_end - 1]), returns)
+    for source, masked, rust_name, start, end in impls:
+        if rust_name in by_rust_name:
+            _parse_pymethods(source, masked, start, end, by_rust_name[rust_name])
+    return {rust_class.python_name: rust_class for rust_class in by_rust_name.values()}, functions
+
+
+# ========== Type Mapping ==========
+
+class TypeMapper:
+    """Maps Rust types in the bindings to Python annotations."""
+
+    def __init__(self, classes: Dict[str, RustClass]):
+        self.python_names = {rust_class.rust_name: rust_class.python_name for rust_class in classes.values()}
+
+    def map(self, rust_type: str, owner: str, argument: bool = False) -> str:
+        rust_type = rust_type.strip()
+        rust_type = re.sub(r"^&\s*(?:'\w+\s+)?(?:mut\s+)?", "", rust_type)
+        rust_type = re.sub(r"^(?:dyn|impl)\s+", "", rust_type).strip()
+        if rust_type.startswith("("):
+            items = [item for item in _split_top_level(rust_type[1:-1]) if item]
+            if not items:
+                return "None"
+            return f"tuple[{', '.join(self.map(item, owner, argument) for item in items)}]"
+        if rust_type.startswith("["):
+            item = _split_top_level(rust_type[1:-1].split(";")[0])[0]
+            return f"{'Sequence' if argument else 'list'}[{self.map(item, owner, argument)}]"
+        match = re.match(r"(?:\w+::)*(\w+)\s*(?:<(.*)>)?$", rust_type, re.S)
+        if not match:
+            return "Any"
+        name, generics = match.group(1), match.group(2)
+        arguments = [
+            argument_type for argument_type in _split_top_level(generics or "")
+            if not argument_type.startswith("'")
+        ]
+        if name == "Self":
+            return owner
+        if name in self.python_names:
+            return self.python_names[name]
+        if name in _SCALARS:
+            return _SCALARS[name]
+        if name in _TRANSPARENT:
+            return self.map(arguments[-1], owner, argument) if arguments else "Any"
+        if name == "Option" and arguments:
+            inner = self.map(arguments[0], owner, argument)
+            return inner if inner in ("Any", "None") or inner.endswith("| None") else f"{inner} | None"
+        if name in ("Vec", "VecDeque", "SmallVec") and arguments:
+            item = self.map(arguments[0], owner, argument)
+            return f"Sequence[{item}]" if argument else f"list[{item}]"
+        if name in ("HashSet", "BTreeSet", "IndexSet") and arguments:
+            return f"set[{self.map(arguments[0], owner, argument)}]"
+        if name in ("HashMap", "BTreeMap", "IndexMap") and len(arguments) >= 2:
+            key, value = (self.map(item, owner, argument) for item in arguments[:2])
+            return f"Mapping[{key}, {value}]" if argument else f"dict[{key}, {value}]"
+        return "Any"
+
+
+# ========== Stub Rendering ==========
+
+def _load_module(path: Optional[Path]) -> ModuleType:
+    if path is None:
+        sys.path.insert(0, str(ROOT / "python"))
+        return importlib.import_module(MODULE_NAME)
+    loader = importlib.machinery.ExtensionFileLoader(MODULE_NAME, str(path))
+    spec = importlib.util.spec_from_file_location(MODULE_NAME, str(path), loader=loader)
+    module = importlib.util.module_from_spec(spec)
+    loader.exec_module(module)
+    return module
+
+
+def _summary(obj: object) -> Optional[str]:
+    """The first paragraph of a docstring, to show on hover."""
+    doc = inspect.getdoc(obj)
+    if not doc:
+        return None
+    paragraph = doc.split("\n\n")[0].strip()
+    if not paragraph or paragraph.startswith(("```", "#", "-", "|")):
+        return None
+    return paragraph.replace("\\", "\\\\").replace('"""', '\\"\\"\\"')
+
+
+def _docstring(obj: object, indent: str) -> List[str]:
+    summary = _summary(obj)
+    if summary is None:
+        return []
+    lines = summary.splitlines()
+    if len(lines) == 1 and len(indent) + len(lines[0]) + 6 <= 100:
+        return [f'{indent}"""{lines[0]}"""']
+    return [f'{indent}"""'] + [f"{indent}{line}".rstrip() for line in lines] + [f'{indent}"""']
+
+
+def _text_signature(obj: object) -> Optional[List[str]]:
+    signature = getattr(obj, "__text_signature__", None)
+    if not signature or not signature.startswith("("):
+        return None
+    return _split_top_level(signature.strip()[1:-1])
+
+
+def _render_params(
+    text_params: Optional[List[str]],
+    rust_fn: Optional[RustFn],
+    owner: str,
+    mapper: TypeMapper,
+    receiver: Optional[str],
+    method: str = "",
+) -> str:
+    """Parameters for a stub, typed by name or else by position from `rust_fn`."""
+    rust_params = rust_fn.params if rust_fn else []
+    rust_types = dict(rust_params)
+    if text_params is None:
+        text_params = [name for name, _ in rust_params]
+        if receiver:
+            text_params.insert(0, "$" + receiver)
+    rendered = [receiver] if receiver else []
+    position = 0
+    for param in text_params:
+        if param.startswith("$"):
+            continue
+        if param in ("/", "*"):
+            rendered.append(param)
+            continue
+        name, _, default = param.partition("=")
+        stars = len(name) - len(name.lstrip("*"))
+        bare = name.lstrip("*")
+        rust_type = rust_types.get(bare)
+        if rust_type is None and position < len(rust_params):
+            rust_type = rust_params[position][1]
+        position += 1
+        if stars:
+            annotation = "Any"
+        elif method in ("__eq__", "__ne__"):
+            # pyo3 returns NotImplemented for other types rather than raising
+            annotation = "object"
+        else:
+            annotation = mapper.map(rust_type, owner, argument=True) if rust_type else "Any"
+        text = f"{name}: {annotation}"
+        if default:
+            text += " = ..."
+        rendered.append(text)
+    return ", ".join(rendered)
+
+
+def _returns(name: str, rust_fn: Optional[RustFn], owner: str, mapper: TypeMapper) -> str:
+    if name in _IN_PLACE:
+        return owner
+    if name in _DUNDER_RETURNS:
+        return _DUNDER_RETURNS[name]
+    if name in ("__copy__", "__deepcopy__", "__enter__"):
+        return owner
+    if rust_fn is None:
+        return "Any"
+    return mapper.map(rust_fn.returns, owner)
+
+
+def _render_class(cls: type, rust_class: Optional[RustClass], mapper: TypeMapper) -> List[str]:
+    name = cls.__name__
+    rust_class = rust_class or RustClass(name, name)
+    bases = [base.__name__ for base in cls.__bases__ if base is not object]
+    lines = [f"class {name}{'(' + ', '.join(bases) + ')' if bases else ''}:"]
+    lines += _docstring(cls, "    ")
+    body: List[str] = []
+
+    members = vars(cls)
+    constants = sorted(
+        member for member, value in members.items()
+        if not member.startswith("_") and not callable(value) and not inspect.isdatadescriptor(value)
+    )
+    for member in constants:
+        value_type = type(members[member])
+        annotation = value_type.__name__ if value_type.__module__ in ("builtins", MODULE_NAME) else "Any"
+        body.append(f"    {member}: ClassVar[{annotation}]")
+    if "__hash__" in members and members["__hash__"] is None:
+        body.append("    __hash__: ClassVar[None]  # type: ignore[assignment]")
+    if constants or body:
+        body.append("")
+
+    if rust_class.new is not None:
+        params = _render_params(_text_signature(cls), rust_class.new, name, mapper, "self")
+        body.append(f"    def __init__({params}) -> None: ...")
+
+    for member in sorted(members, key=lambda member: (member.startswith("__"), member)):
+        value = members[member]
+        if member in _SKIPPED or (member.startswith("_") and not member.startswith("__")):
+            continue
+        if member.startswith("__") and member not in rust_class.methods:
+            # Slot wrappers pyo3 fills in next to the ones a class defines
+            continue
+        if inspect.isdatadescriptor(value):
+            getter_type = rust_class.getters.get(member)
+            setter_type = rust_class.setters.get(member)
+            annotation = mapper.map(getter_type or setter_type or "PyAny", name)
+            body.append("    @property")
+            body.append(f"    def {member}(self) -> {annotation}: ...")
+            if setter_type is not None:
+                body.append(f"    @{member}.setter")
+                value_annotation = mapper.map(setter_type, name, argument=True)
+                body.append(f"    def {member}(self, value: {value_annotation}) -> None: ...")
+            continue
+        if not callable(value) and not isinstance(value, (staticmethod, classmethod)):
+            continue
+        rust_fn = rust_class.methods.get(member)
+        if isinstance(value, staticmethod) or (rust_fn is not None and rust_fn.static):
+            decorator, receiver = "@staticmethod", None
+        elif type(value).__name__ == "classmethod_descriptor" or isinstance(value, classmethod):
+            decorator, receiver = "@classmethod", "cls"
+        else:
+            decorator, receiver = None, "self"
+        function = getattr(cls, member)
+        params = _render_params(_text_signature(function), rust_fn, name, mapper, receiver, member)
+        if decorator:
+            body.append(f"    {decorator}")
+        returns = _returns(member, rust_fn, name, mapper)
+        doc = _docstring(function, "        ") if not member.startswith("__") else []
+        if doc:
+            body.append(f"    def {member}({params}) -> {returns}:")
+            body += doc
+            body.append("        ...")
+        else:
+            body.append(f"    def {member}({params}) -> {returns}: ...")
+
+    while body and body[-1] == "":
+        body.pop()
+    return lines + (body or ["    ..."])
+
+
+def generate(module: ModuleType, directory: Path = BINDINGS) -> str:
+    """The stub source for `module`, typed from the bindings in `directory`."""
+    classes, functions = parse_bindings(directory)
+    mapper = TypeMapper(classes)
+    sections = [HEADER]
+    exported = sorted(name for name in vars(module) if not name.startswith("_"))
+    for name in exported:
+        value = getattr(module, name)
+        if inspect.isclass(value):
+            sections.append("\n".join(_render_class(value, classes.get(name), mapper)))
+        elif callable(value):
+            rust_fn = functions.get(name)
+            params = _render_params(_text_signature(value), rust_fn, name, mapper, None)
+            returns = mapper.map(rust_fn.returns, name) if rust_fn else "Any"
+            lines = [f"def {name}({params}) -> {returns}:"] + _docstring(value, "    ") + ["    ..."]
+            if len(lines) == 2:
+                lines = [f"def {name}({params}) -> {returns}: ..."]
+            sections.append("\n".join(lines))
+    return "\n\n".join(sections) + "\n"
+
+
+def main(argv: Optional[List[str]] = None) -> int:
+    parser = argparse.ArgumentParser(description=__doc__.strip().splitlines()[0])
+    parser.add_argument("--module", type=Path, help="built extension to read (default: import pyg_engine)")
+    parser.add_argument("--output", type=Path, default=STUB_PATH, help="where to write the stub")
+    parser.add_argument("--check", action="store_true", help="fail if the stub on disk is out of date")
+    args = parser.parse_args(argv)
+
+    stub = generate(_load_module(args.module))
+    if args.check:
+        current = args.output.read_text(encoding="utf-8") if args.output.exists() else ""
+        if current != stub:
+            print(f"{args.output} is out of date; run tools/generate_stubs.py", file=sys.stderr)
+            return 1
+        return 0
+    args.output.write_text(stub, encoding="utf-8")
+    print(f"Wrote {args.output}")
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())